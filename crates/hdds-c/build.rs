@@ -38,8 +38,10 @@ fn try_main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=src/abi.rs");
     println!("cargo:rerun-if-changed=src/qos.rs");
     println!("cargo:rerun-if-changed=src/rmw.rs");
+    println!("cargo:rerun-if-changed=src/last_error.rs");
     println!("cargo:rerun-if-changed=cbindgen.toml");
     println!("Generated C header: {}", out_path.display());
 
@@ -33,6 +33,32 @@ typedef struct rosidl_runtime_c__U16String {
 typedef struct rosidl_message_type_support_t rosidl_message_type_support_t;
 #endif
 
+/* ABI checks (HDDS_ABI_VERSION* are defined below) */
+#ifdef __cplusplus
+#define HDDS_STATIC_ASSERT(cond, msg) static_assert(cond, msg)
+#else
+#define HDDS_STATIC_ASSERT(cond, msg) _Static_assert(cond, msg)
+#endif
+/* Compile-time: fail the build unless this header provides ABI major.minor or a compatible newer one */
+#define HDDS_ABI_REQUIRE(major, minor) HDDS_STATIC_ASSERT(HDDS_ABI_VERSION_MAJOR == (major) && HDDS_ABI_VERSION_MINOR >= (minor), "hdds.h does not provide the required HDDS ABI version")
+/* Run-time: true when the loaded library serves the ABI of this header */
+#define HDDS_ABI_CHECK() hdds_abi_compatible(HDDS_ABI_VERSION)
+
+
+/**
+ * ABI major version (incompatible changes).
+ */
+#define HDDS_ABI_VERSION_MAJOR 1
+
+/**
+ * ABI minor version (backward compatible additions).
+ */
+#define HDDS_ABI_VERSION_MINOR 1
+
+/**
+ * ABI version as `major << 16 | minor`.
+ */
+#define HDDS_ABI_VERSION ((HDDS_ABI_VERSION_MAJOR << 16) | HDDS_ABI_VERSION_MINOR)
 
 /**
  * Error codes (C-compatible enum)
@@ -167,6 +193,24 @@ typedef enum HddsLogLevel {
   HDDS_LOG_TRACE = 5,
 } HddsLogLevel;
 
+/**
+ * Primitive type kinds for the dynamic type builder.
+ */
+typedef enum HddsPrimitiveKind {
+  HDDS_PRIM_BOOL = 0,
+  HDDS_PRIM_U8 = 1,
+  HDDS_PRIM_U16 = 2,
+  HDDS_PRIM_U32 = 3,
+  HDDS_PRIM_U64 = 4,
+  HDDS_PRIM_I8 = 5,
+  HDDS_PRIM_I16 = 6,
+  HDDS_PRIM_I32 = 7,
+  HDDS_PRIM_I64 = 8,
+  HDDS_PRIM_F32 = 9,
+  HDDS_PRIM_F64 = 10,
+  HDDS_PRIM_STRING = 11,
+} HddsPrimitiveKind;
+
 /**
  * Shared memory policy for C FFI.
  */
@@ -215,6 +259,14 @@ typedef enum HddsTransportMode {
    * UDP multicast for network discovery and communication (default for DDS interop)
    */
   HDDS_TRANSPORT_UDP_MULTICAST = 1,
+  /**
+   * RTPS over TCP for discovery and communication (no UDP)
+   */
+  HDDS_TRANSPORT_TCP = 2,
+  /**
+   * RTPS over an in-memory bus between participants of one process (no sockets)
+   */
+  HDDS_TRANSPORT_LOOPBACK_BUS = 3,
 } HddsTransportMode;
 
 /**
@@ -241,6 +293,10 @@ typedef enum HddsTransportPreference {
    * SHM for local, TCP for remote.
    */
   HDDS_TRANSPORT_PREF_SHM_LOCAL_TCP_REMOTE = 4,
+  /**
+   * Prefer Unix domain sockets for local, UDP for remote.
+   */
+  HDDS_TRANSPORT_PREF_UDS_PREFERRED = 5,
 } HddsTransportPreference;
 
 typedef struct Option_HddsLocatorVisitor Option_HddsLocatorVisitor;
@@ -348,6 +404,27 @@ typedef struct HddsRmwWaitSet {
 } HddsRmwWaitSet;
 #endif
 
+/**
+ * Opaque handle to a type descriptor builder.
+ */
+typedef struct HddsTypeBuilder {
+  uint8_t PRIVATE[0];
+} HddsTypeBuilder;
+
+/**
+ * Opaque handle to a type descriptor.
+ */
+typedef struct HddsTypeDescriptor {
+  uint8_t PRIVATE[0];
+} HddsTypeDescriptor;
+
+/**
+ * Opaque handle to dynamic data.
+ */
+typedef struct HddsDynamicData {
+  uint8_t PRIVATE[0];
+} HddsDynamicData;
+
 /**
  * Callback for data available events.
  *
@@ -770,6 +847,35 @@ typedef struct ParameterSequence {
 } ParameterSequence;
 #endif
 
+/**
+ * Metadata of a taken sample (C-compatible subset of the Rust `SampleInfo`).
+ */
+typedef struct HddsSampleInfo {
+  /**
+   * GUID of the writer (12-byte prefix, 4-byte entity id); all zero if
+   * unknown.
+   */
+  uint8_t WRITER_GUID[16];
+  /**
+   * Writer-side timestamp (ns since the UNIX epoch); 0 if the writer sent
+   * none.
+   */
+  int64_t SOURCE_TIMESTAMP_NS;
+  /**
+   * Reception timestamp (ns since the UNIX epoch).
+   */
+  int64_t RECEPTION_TIMESTAMP_NS;
+  /**
+   * Writer sequence number of the sample.
+   */
+  uint64_t SEQUENCE_NUMBER;
+  /**
+   * Whether the sample carries data (always true: only data samples are
+   * taken).
+   */
+  bool VALID_DATA;
+} HddsSampleInfo;
+
 /**
  * Opaque handle to a security configuration.
  */
@@ -868,6 +974,8 @@ typedef struct HddsParticipantConfig {
  * * `transport` - Transport mode:
  *   - `HddsTransportMode::HddsTransportIntraProcess` (0): No network, intra-process only
  *   - `HddsTransportMode::HddsTransportUdpMulticast` (1): UDP multicast for network discovery
+ *   - `HddsTransportMode::HddsTransportTcp` (2): RTPS over TCP, no UDP
+ *   - `HddsTransportMode::HddsTransportLoopbackBus` (3): RTPS in-process, no sockets
  *
  * # Returns
  * Opaque participant handle, or NULL on failure
@@ -932,6 +1040,9 @@ enum HddsError hdds_type_object_hash(const struct HddsTypeObject *aHandle,
 /**
  * Get HDDS library version string
  *
+ * This is the release version; check compatibility with
+ * [`hdds_abi_version`] instead.
+ *
  * # Safety
  * The returned pointer is valid for the lifetime of the process (static storage).
  */
@@ -960,6 +1071,38 @@ enum HddsError hdds_writer_write(struct HddsDataWriter *aWriter,
                                  const void *aData,
                                  uintptr_t aLen);
 
+/**
+ * Dispose an instance, identified by its serialized key
+ *
+ * `key` is the big-endian CDR of the topic type's key fields (the bytes
+ * hashed into the instance handle). Readers see the instance as
+ * NOT_ALIVE_DISPOSED until it is written again.
+ *
+ * # Safety
+ * - `writer` must be a valid pointer returned from `hdds_writer_create`
+ * - `key` must point to valid memory of at least `len` bytes
+ */
+
+enum HddsError hdds_writer_dispose(struct HddsDataWriter *aWriter,
+                                   const void *aKey,
+                                   uintptr_t aLen);
+
+/**
+ * Unregister an instance, identified by its serialized key
+ *
+ * Same key format as `hdds_writer_dispose`. Readers see the instance as
+ * NOT_ALIVE_NO_WRITERS, or NOT_ALIVE_DISPOSED when the writer's
+ * autodispose_unregistered_instances QoS is enabled (the default).
+ *
+ * # Safety
+ * - `writer` must be a valid pointer returned from `hdds_writer_create`
+ * - `key` must point to valid memory of at least `len` bytes
+ */
+
+enum HddsError hdds_writer_unregister(struct HddsDataWriter *aWriter,
+                                      const void *aKey,
+                                      uintptr_t aLen);
+
 /**
  * Destroy a `DataWriter`
  *
@@ -1047,6 +1190,9 @@ struct HddsDataReader *hdds_reader_create_with_type(struct HddsParticipant *aPar
 /**
  * Take data from a topic (non-blocking)
  *
+ * See `hdds_reader_take_with_info` to also get the writer GUID, timestamps
+ * and sequence number, and `hdds_reader_take_loan` to skip the copy.
+ *
  * # Safety
  * - `reader` must be a valid pointer returned from `hdds_reader_create`
  * - `data_out` must point to a valid buffer of at least `max_len` bytes
@@ -1162,6 +1308,25 @@ enum HddsError hdds_waitset_attach_guard_condition(struct HddsWaitSet *aWaitset,
  */
  enum HddsError hdds_waitset_detach_condition(struct HddsWaitSet *aWaitset, const void *aCondition);
 
+/**
+ * Get a descriptor to watch a waitset from a `poll`/`epoll`/`select` loop.
+ *
+ * The descriptor becomes readable when an attached condition triggers.
+ * Never read it: once it polls readable, call `hdds_waitset_wait` with a
+ * zero timeout to get the triggered conditions. It stays readable while
+ * `hdds_waitset_wait` returns conditions and is cleared by a call finding
+ * none, so a wakeup may be spurious (`out_len` of 0). The descriptor
+ * belongs to the waitset: do not close it, and remove it from the event
+ * loop before `hdds_waitset_destroy`.
+ *
+ * Returns -1 if `waitset` is invalid, or on platforms without pollable
+ * descriptors (Windows).
+ *
+ * # Safety
+ * - `waitset` must be a valid handle from `hdds_waitset_create`.
+ */
+ int32_t hdds_waitset_get_fd(struct HddsWaitSet *aWaitset);
+
 /**
  * Wait for any attached condition to trigger.
  *
@@ -1741,6 +1906,543 @@ enum HddsError hdds_rmw_waitset_wait(struct HddsRmwWaitSet *aWaitset,
                                      bool *aOutGuardTriggered);
 #endif
 
+/**
+ * Get the ABI version of the loaded library (`major << 16 | minor`).
+ *
+ * Compare with the `HDDS_ABI_VERSION` the binding was built against, or
+ * use [`hdds_abi_compatible`].
+ */
+ uint32_t hdds_abi_version(void);
+
+/**
+ * Check whether the loaded library can serve a binding built against
+ * `expected` (`major << 16 | minor`).
+ *
+ * True when the majors are equal and the library minor is at least the
+ * expected one.
+ */
+ bool hdds_abi_compatible(uint32_t aExpected);
+
+/**
+ * ABI 1.x marker symbol; returns the full ABI version.
+ *
+ * Removed when the ABI major changes, so resolving it at load time
+ * rejects an incompatible library.
+ */
+ uint32_t hdds_abi_v1(void);
+
+/**
+ * Whether handle auditing is compiled in (`handle-audit` feature).
+ */
+ bool hdds_audit_enabled(void);
+
+/**
+ * Number of audited handles currently alive (always 0 without the
+ * `handle-audit` feature).
+ */
+ uintptr_t hdds_audit_live_handles(void);
+
+/**
+ * Print every audited handle still alive to stderr and return their count.
+ *
+ * The same report runs automatically at process exit when the
+ * `handle-audit` feature is enabled.
+ */
+ uintptr_t hdds_audit_report_leaks(void);
+
+/**
+ * Create a new type descriptor builder.
+ *
+ * # Safety
+ * - `name` must be a valid null-terminated C string.
+ */
+ struct HddsTypeBuilder *hdds_type_builder_new(const char *aName);
+
+/**
+ * Add a primitive field to the type builder.
+ *
+ * # Safety
+ * - `builder` must be valid. `name` must be null-terminated.
+ */
+
+enum HddsError hdds_type_builder_add_field(struct HddsTypeBuilder *aBuilder,
+                                           const char *aName,
+                                           enum HddsPrimitiveKind aKind);
+
+/**
+ * Add a string field to the type builder.
+ *
+ * # Safety
+ * - `builder` must be valid. `name` must be null-terminated.
+ */
+
+enum HddsError hdds_type_builder_add_string_field(struct HddsTypeBuilder *aBuilder,
+                                                  const char *aName);
+
+/**
+ * Add a sequence field to the type builder.
+ *
+ * # Safety
+ * - `builder` must be valid. `name` must be null-terminated.
+ */
+
+enum HddsError hdds_type_builder_add_sequence_field(struct HddsTypeBuilder *aBuilder,
+                                                    const char *aName,
+                                                    enum HddsPrimitiveKind aElementKind);
+
+/**
+ * Add an array field to the type builder.
+ *
+ * # Safety
+ * - `builder` must be valid. `name` must be null-terminated.
+ */
+
+enum HddsError hdds_type_builder_add_array_field(struct HddsTypeBuilder *aBuilder,
+                                                 const char *aName,
+                                                 enum HddsPrimitiveKind aElementKind,
+                                                 uintptr_t aLength);
+
+/**
+ * Build the type descriptor (consumes the builder).
+ *
+ * Returns NULL if the builder was already consumed or invalid.
+ *
+ * # Safety
+ * - `builder` must be valid. After this call, `builder` is invalid.
+ */
+ struct HddsTypeDescriptor *hdds_type_builder_build(struct HddsTypeBuilder *aBuilder);
+
+/**
+ * Destroy a type builder without building.
+ *
+ * # Safety
+ * - `builder` must be valid or NULL.
+ */
+ void hdds_type_builder_destroy(struct HddsTypeBuilder *aBuilder);
+
+/**
+ * Get the type name. Returns the number of bytes needed (excluding null).
+ *
+ * # Safety
+ * - `desc` must be valid. `out_buf` and `capacity` must be valid.
+ */
+
+uintptr_t hdds_type_descriptor_get_name(const struct HddsTypeDescriptor *aDesc,
+                                        char *aOutBuf,
+                                        uintptr_t aCapacity);
+
+/**
+ * Get the number of fields (for struct types). Returns 0 for non-struct types.
+ *
+ * # Safety
+ * - `desc` must be valid.
+ */
+ uintptr_t hdds_type_descriptor_get_field_count(const struct HddsTypeDescriptor *aDesc);
+
+/**
+ * Destroy a type descriptor.
+ *
+ * # Safety
+ * - `desc` must be valid or NULL.
+ */
+ void hdds_type_descriptor_destroy(struct HddsTypeDescriptor *aDesc);
+
+/**
+ * Create new dynamic data with default values.
+ *
+ * # Safety
+ * - `desc` must be valid (not consumed, still valid after this call).
+ */
+ struct HddsDynamicData *hdds_dynamic_data_new(const struct HddsTypeDescriptor *aDesc);
+
+/**
+ * Destroy dynamic data.
+ *
+ * # Safety
+ * - `data` must be valid or NULL.
+ */
+ void hdds_dynamic_data_destroy(struct HddsDynamicData *aData);
+
+/**
+ * Set a `bool` field value.
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` must be null-terminated.
+ */
+
+enum HddsError hdds_dynamic_data_set_bool(struct HddsDynamicData *aData,
+                                          const char *aFieldName,
+                                          bool aValue);
+
+/**
+ * Set a `u8` field value.
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` must be null-terminated.
+ */
+
+enum HddsError hdds_dynamic_data_set_u8(struct HddsDynamicData *aData,
+                                        const char *aFieldName,
+                                        uint8_t aValue);
+
+/**
+ * Set a `u16` field value.
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` must be null-terminated.
+ */
+
+enum HddsError hdds_dynamic_data_set_u16(struct HddsDynamicData *aData,
+                                         const char *aFieldName,
+                                         uint16_t aValue);
+
+/**
+ * Set a `u32` field value.
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` must be null-terminated.
+ */
+
+enum HddsError hdds_dynamic_data_set_u32(struct HddsDynamicData *aData,
+                                         const char *aFieldName,
+                                         uint32_t aValue);
+
+/**
+ * Set a `u64` field value.
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` must be null-terminated.
+ */
+
+enum HddsError hdds_dynamic_data_set_u64(struct HddsDynamicData *aData,
+                                         const char *aFieldName,
+                                         uint64_t aValue);
+
+/**
+ * Set a `i8` field value.
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` must be null-terminated.
+ */
+
+enum HddsError hdds_dynamic_data_set_i8(struct HddsDynamicData *aData,
+                                        const char *aFieldName,
+                                        int8_t aValue);
+
+/**
+ * Set a `i16` field value.
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` must be null-terminated.
+ */
+
+enum HddsError hdds_dynamic_data_set_i16(struct HddsDynamicData *aData,
+                                         const char *aFieldName,
+                                         int16_t aValue);
+
+/**
+ * Set a `i32` field value.
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` must be null-terminated.
+ */
+
+enum HddsError hdds_dynamic_data_set_i32(struct HddsDynamicData *aData,
+                                         const char *aFieldName,
+                                         int32_t aValue);
+
+/**
+ * Set a `i64` field value.
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` must be null-terminated.
+ */
+
+enum HddsError hdds_dynamic_data_set_i64(struct HddsDynamicData *aData,
+                                         const char *aFieldName,
+                                         int64_t aValue);
+
+/**
+ * Set a `f32` field value.
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` must be null-terminated.
+ */
+
+enum HddsError hdds_dynamic_data_set_f32(struct HddsDynamicData *aData,
+                                         const char *aFieldName,
+                                         float aValue);
+
+/**
+ * Set a `f64` field value.
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` must be null-terminated.
+ */
+
+enum HddsError hdds_dynamic_data_set_f64(struct HddsDynamicData *aData,
+                                         const char *aFieldName,
+                                         double aValue);
+
+/**
+ * Set a string field value.
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` and `value` must be null-terminated.
+ */
+
+enum HddsError hdds_dynamic_data_set_string(struct HddsDynamicData *aData,
+                                            const char *aFieldName,
+                                            const char *aValue);
+
+/**
+ * Get a `bool` field value.
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` must be null-terminated.
+ * - `out_value` must be valid.
+ */
+
+enum HddsError hdds_dynamic_data_get_bool(const struct HddsDynamicData *aData,
+                                          const char *aFieldName,
+                                          bool *aOutValue);
+
+/**
+ * Get a `u8` field value.
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` must be null-terminated.
+ * - `out_value` must be valid.
+ */
+
+enum HddsError hdds_dynamic_data_get_u8(const struct HddsDynamicData *aData,
+                                        const char *aFieldName,
+                                        uint8_t *aOutValue);
+
+/**
+ * Get a `u16` field value.
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` must be null-terminated.
+ * - `out_value` must be valid.
+ */
+
+enum HddsError hdds_dynamic_data_get_u16(const struct HddsDynamicData *aData,
+                                         const char *aFieldName,
+                                         uint16_t *aOutValue);
+
+/**
+ * Get a `u32` field value.
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` must be null-terminated.
+ * - `out_value` must be valid.
+ */
+
+enum HddsError hdds_dynamic_data_get_u32(const struct HddsDynamicData *aData,
+                                         const char *aFieldName,
+                                         uint32_t *aOutValue);
+
+/**
+ * Get a `u64` field value.
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` must be null-terminated.
+ * - `out_value` must be valid.
+ */
+
+enum HddsError hdds_dynamic_data_get_u64(const struct HddsDynamicData *aData,
+                                         const char *aFieldName,
+                                         uint64_t *aOutValue);
+
+/**
+ * Get a `i8` field value.
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` must be null-terminated.
+ * - `out_value` must be valid.
+ */
+
+enum HddsError hdds_dynamic_data_get_i8(const struct HddsDynamicData *aData,
+                                        const char *aFieldName,
+                                        int8_t *aOutValue);
+
+/**
+ * Get a `i16` field value.
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` must be null-terminated.
+ * - `out_value` must be valid.
+ */
+
+enum HddsError hdds_dynamic_data_get_i16(const struct HddsDynamicData *aData,
+                                         const char *aFieldName,
+                                         int16_t *aOutValue);
+
+/**
+ * Get a `i32` field value.
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` must be null-terminated.
+ * - `out_value` must be valid.
+ */
+
+enum HddsError hdds_dynamic_data_get_i32(const struct HddsDynamicData *aData,
+                                         const char *aFieldName,
+                                         int32_t *aOutValue);
+
+/**
+ * Get a `i64` field value.
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` must be null-terminated.
+ * - `out_value` must be valid.
+ */
+
+enum HddsError hdds_dynamic_data_get_i64(const struct HddsDynamicData *aData,
+                                         const char *aFieldName,
+                                         int64_t *aOutValue);
+
+/**
+ * Get a `f32` field value.
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` must be null-terminated.
+ * - `out_value` must be valid.
+ */
+
+enum HddsError hdds_dynamic_data_get_f32(const struct HddsDynamicData *aData,
+                                         const char *aFieldName,
+                                         float *aOutValue);
+
+/**
+ * Get a `f64` field value.
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` must be null-terminated.
+ * - `out_value` must be valid.
+ */
+
+enum HddsError hdds_dynamic_data_get_f64(const struct HddsDynamicData *aData,
+                                         const char *aFieldName,
+                                         double *aOutValue);
+
+/**
+ * Get a string field value. Returns the number of bytes needed (excluding null).
+ *
+ * # Safety
+ * - `data` must be valid. `field_name` must be null-terminated.
+ * - `out_buf` can be NULL to query the required size.
+ */
+
+uintptr_t hdds_dynamic_data_get_string(const struct HddsDynamicData *aData,
+                                       const char *aFieldName,
+                                       char *aOutBuf,
+                                       uintptr_t aCapacity);
+
+/**
+ * Encode dynamic data to CDR2 format.
+ *
+ * Returns HddsOk on success, writes the number of bytes to `out_len`.
+ *
+ * # Safety
+ * - `data` must be valid. `buf` must point to `capacity` writable bytes.
+ * - `out_len` must be valid.
+ */
+
+enum HddsError hdds_dynamic_data_encode(const struct HddsDynamicData *aData,
+                                        uint8_t *aBuf,
+                                        uintptr_t aCapacity,
+                                        uintptr_t *aOutLen);
+
+/**
+ * Decode CDR2 data into a new dynamic data instance.
+ *
+ * Returns NULL on failure.
+ *
+ * # Safety
+ * - `desc` must be valid. `buf` must point to `len` readable bytes.
+ */
+
+struct HddsDynamicData *hdds_dynamic_data_decode(const struct HddsTypeDescriptor *aDesc,
+                                                 const uint8_t *aBuf,
+                                                 uintptr_t aLen);
+
+/**
+ * Create a type descriptor from a registered type object.
+ *
+ * Returns NULL if `handle` is NULL.
+ *
+ * # Safety
+ * - `handle` must be a valid handle from `hdds_participant_register_type_support`.
+ */
+
+struct HddsTypeDescriptor *hdds_type_descriptor_from_type_object(const struct HddsTypeObject *aHandle);
+
+/**
+ * Create a `DataWriter` publishing `HddsDynamicData` samples of type `desc`.
+ *
+ * The descriptor's name is announced as the type name via SEDP. Write with
+ * `hdds_writer_write_sample`; destroy with `hdds_writer_destroy`.
+ *
+ * # Safety
+ * - `participant` must be a valid pointer returned from `hdds_participant_create`
+ * - `topic_name` must be a valid null-terminated C string
+ * - `desc` must be a valid type descriptor (it is not consumed)
+ * - `qos` must be a valid pointer returned from `hdds_qos_*` functions (or NULL for default)
+ */
+
+struct HddsDataWriter *hdds_writer_create_typed(struct HddsParticipant *aParticipant,
+                                                const char *aTopicName,
+                                                const struct HddsTypeDescriptor *aDesc,
+                                                const struct HddsQoS *aQos);
+
+/**
+ * Serialize and publish a dynamic data sample.
+ *
+ * # Safety
+ * - `writer` must be a valid pointer returned from `hdds_writer_create_typed`
+ *   (or any other `hdds_writer_create*` function)
+ * - `data` must be valid.
+ */
+
+enum HddsError hdds_writer_write_sample(struct HddsDataWriter *aWriter,
+                                        const struct HddsDynamicData *aData);
+
+/**
+ * Create a `DataReader` taking `HddsDynamicData` samples of type `desc`.
+ *
+ * The descriptor's name is announced as the type name via SEDP. Take with
+ * `hdds_reader_take_sample`; destroy with `hdds_reader_destroy`.
+ *
+ * # Safety
+ * - `participant` must be a valid pointer returned from `hdds_participant_create`
+ * - `topic_name` must be a valid null-terminated C string
+ * - `desc` must be a valid type descriptor (it is not consumed)
+ * - `qos` must be a valid pointer returned from `hdds_qos_*` functions (or NULL for default)
+ */
+
+struct HddsDataReader *hdds_reader_create_typed(struct HddsParticipant *aParticipant,
+                                                const char *aTopicName,
+                                                const struct HddsTypeDescriptor *aDesc,
+                                                const struct HddsQoS *aQos);
+
+/**
+ * Take the next sample from a typed reader (non-blocking).
+ *
+ * On `HddsOk`, `*out_data` receives a new dynamic data instance that must be
+ * released with `hdds_dynamic_data_destroy`. Returns `HddsNotFound` if no
+ * sample is available and `HddsInvalidState` if the reader was not created
+ * with `hdds_reader_create_typed`.
+ *
+ * # Safety
+ * - `reader` must be a valid pointer returned from `hdds_reader_create_typed`
+ * - `out_data` must be a valid pointer to write the result.
+ */
+
+enum HddsError hdds_reader_take_sample(struct HddsDataReader *aReader,
+                                       struct HddsDynamicData **aOutData);
+
 /**
  * Get the participant name
  *
@@ -1809,6 +2511,24 @@ enum HddsError hdds_reader_topic_name(struct HddsDataReader *aReader,
                                       uintptr_t aBufLen,
                                       uintptr_t *aOutLen);
 
+/**
+ * Get the message of the last error raised on the calling thread
+ *
+ * Failing calls record their error here; successful calls leave it
+ * untouched, so only read it right after a call returned an error.
+ *
+ * # Returns
+ * Pointer to a null-terminated message, or NULL if no call failed on this
+ * thread since the last `hdds_clear_last_error`. The string is owned by
+ * HDDS and stays valid until the next failing call on the same thread.
+ */
+ const char *hdds_last_error_message(void);
+
+/**
+ * Forget the calling thread's last error
+ */
+ void hdds_clear_last_error(void);
+
 /**
  * Install a listener on a DataReader.
  *
@@ -2571,6 +3291,55 @@ extern bool rosidl_runtime_c__U16String__assignn(rosidl_runtime_c__U16String *aS
                                                  uintptr_t aLen);
 #endif
 
+/**
+ * Take data from a topic together with its sample info (non-blocking).
+ *
+ * Same as `hdds_reader_take`, and `info_out` receives the writer GUID,
+ * timestamps and sequence number of the sample. If the payload does not
+ * fit in `max_len` bytes, returns `HddsOutOfMemory` with the required
+ * size in `len_out`; the sample is consumed.
+ *
+ * # Safety
+ * - `reader` must be a valid pointer returned from `hdds_reader_create`
+ * - `data_out` must point to a valid buffer of at least `max_len` bytes
+ * - `len_out` and `info_out` must be valid pointers
+ */
+
+enum HddsError hdds_reader_take_with_info(struct HddsDataReader *aReader,
+                                          uint8_t *aDataOut,
+                                          uintptr_t aMaxLen,
+                                          uintptr_t *aLenOut,
+                                          struct HddsSampleInfo *aInfoOut);
+
+/**
+ * Take the next sample without copying its payload (non-blocking).
+ *
+ * On `HddsOk`, `*data_out` points to `*len_out` bytes owned by the reader
+ * and `info_out` receives the sample info. The payload stays valid until
+ * passed to `hdds_reader_return_loan` or the reader is destroyed. Returns
+ * `HddsNotFound` if no sample is available.
+ *
+ * # Safety
+ * - `reader` must be a valid pointer returned from `hdds_reader_create`
+ * - `data_out`, `len_out` and `info_out` must be valid pointers
+ */
+
+enum HddsError hdds_reader_take_loan(struct HddsDataReader *aReader,
+                                     const uint8_t **aDataOut,
+                                     uintptr_t *aLenOut,
+                                     struct HddsSampleInfo *aInfoOut);
+
+/**
+ * Return a payload loaned by `hdds_reader_take_loan`.
+ *
+ * Returns `HddsNotFound` if `data` is not a loan of this reader.
+ *
+ * # Safety
+ * - `reader` must be a valid pointer returned from `hdds_reader_create`
+ * - `data` must not be used after this call
+ */
+ enum HddsError hdds_reader_return_loan(struct HddsDataReader *aReader, const uint8_t *aData);
+
 /**
  * Create a new security configuration.
  *
@@ -2649,6 +3418,20 @@ enum HddsError hdds_security_config_set_permissions_xml(struct HddsSecurityConfi
 enum HddsError hdds_security_config_enable_encryption(struct HddsSecurityConfig *aConfig,
                                                       bool aEnabled);
 
+/**
+ * Set the master key path (optional, 32 raw bytes).
+ *
+ * Protected topics get keys derived from it, so participants exchanging
+ * them must share it. Without one, encrypted topics are only readable
+ * within the participant.
+ *
+ * # Safety
+ * - `config` must be valid. `path` must be null-terminated.
+ */
+
+enum HddsError hdds_security_config_set_master_key(struct HddsSecurityConfig *aConfig,
+                                                   const char *aPath);
+
 /**
  * Enable or disable audit logging (default: false).
  *
@@ -2691,6 +3474,33 @@ enum HddsError hdds_security_config_require_authentication(struct HddsSecurityCo
 enum HddsError hdds_security_config_check_revocation(struct HddsSecurityConfig *aConfig,
                                                      bool aEnabled);
 
+#if defined(HDDS_WITH_SECURITY)
+/**
+ * Create a secured Participant with the specified transport mode.
+ *
+ * Same as `hdds_participant_create_with_transport`, with DDS Security
+ * configured from `security`. **This consumes the security config
+ * handle**, also on failure. Do NOT call `hdds_security_config_destroy`
+ * after this.
+ *
+ * Requires the `security` feature to be enabled at compile time.
+ *
+ * # Returns
+ * Opaque participant handle, or NULL if the name is invalid, the security
+ * configuration is incomplete (identity certificate, private key and CA
+ * are required) or a security file cannot be loaded.
+ *
+ * # Safety
+ * - `name` must be a valid null-terminated C string.
+ * - `security` must be a valid pointer from `hdds_security_config_create`.
+ * - The returned handle must be released with `hdds_participant_destroy`.
+ */
+
+struct HddsParticipant *hdds_participant_create_secure(const char *aName,
+                                                       enum HddsTransportMode aTransport,
+                                                       struct HddsSecurityConfig *aSecurity);
+#endif
+
 /**
  * Initialize the global metrics collector
  *
@@ -2824,7 +3634,7 @@ enum HddsError hdds_config_set_participant_id(struct HddsParticipantConfig *aCon
                                               uint8_t aParticipantId);
 
 /**
- * Set the transport mode (IntraProcess or UdpMulticast).
+ * Set the transport mode (IntraProcess, UdpMulticast or Tcp).
  *
  * # Safety
  * - `config` must be a valid pointer from `hdds_config_create`.
@@ -2925,6 +3735,7 @@ enum HddsError hdds_config_set_tcp_role(struct HddsParticipantConfig *aConfig,
 enum HddsError hdds_config_set_transport_preference(struct HddsParticipantConfig *aConfig,
                                                     enum HddsTransportPreference aPref);
 
+#if defined(HDDS_WITH_SECURITY)
 /**
  * Attach a security configuration to this participant config.
  *
@@ -2940,6 +3751,7 @@ enum HddsError hdds_config_set_transport_preference(struct HddsParticipantConfig
 
 enum HddsError hdds_config_set_security(struct HddsParticipantConfig *aConfig,
                                         struct HddsSecurityConfig *aSecurity);
+#endif
 
 /**
  * Build a Participant from the configuration.
@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! ABI versioning for HDDS C FFI
+//!
+//! The ABI version is independent of the crate version. The minor number
+//! grows when functions or enum values are added; the major number grows
+//! when an existing signature, enum value or `#[repr(C)]` struct layout
+//! changes. A binding built against `major.minor` works with any library of
+//! the same major and a minor at least as large.
+//!
+//! Each major also exports a marker symbol, `hdds_abi_v<major>`. Bindings
+//! that resolve symbols when the library is loaded (jextract, ctypes,
+//! `dlsym`) fail on the missing marker instead of crashing later on a
+//! changed struct layout.
+
+/// ABI major version (incompatible changes).
+pub const HDDS_ABI_VERSION_MAJOR: u32 = 1;
+
+/// ABI minor version (backward compatible additions).
+pub const HDDS_ABI_VERSION_MINOR: u32 = 1;
+
+/// ABI version as `major << 16 | minor`.
+pub const HDDS_ABI_VERSION: u32 = (HDDS_ABI_VERSION_MAJOR << 16) | HDDS_ABI_VERSION_MINOR;
+
+/// Get the ABI version of the loaded library (`major << 16 | minor`).
+///
+/// Compare with the `HDDS_ABI_VERSION` the binding was built against, or
+/// use [`hdds_abi_compatible`].
+#[no_mangle]
+pub extern "C" fn hdds_abi_version() -> u32 {
+    HDDS_ABI_VERSION
+}
+
+/// Check whether the loaded library can serve a binding built against
+/// `expected` (`major << 16 | minor`).
+///
+/// True when the majors are equal and the library minor is at least the
+/// expected one.
+#[no_mangle]
+pub extern "C" fn hdds_abi_compatible(expected: u32) -> bool {
+    // Same major: the minor decides
+    expected >> 16 == HDDS_ABI_VERSION_MAJOR && expected <= HDDS_ABI_VERSION
+}
+
+/// ABI 1.x marker symbol; returns the full ABI version.
+///
+/// Removed when the ABI major changes, so resolving it at load time
+/// rejects an incompatible library.
+#[no_mangle]
+pub extern "C" fn hdds_abi_v1() -> u32 {
+    HDDS_ABI_VERSION
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_abi_version_encoding() {
+        assert_eq!(hdds_abi_version() >> 16, HDDS_ABI_VERSION_MAJOR);
+        assert_eq!(hdds_abi_version() & 0xFFFF, HDDS_ABI_VERSION_MINOR);
+        assert_eq!(hdds_abi_v1(), HDDS_ABI_VERSION);
+    }
+
+    #[test]
+    fn test_abi_compatible() {
+        assert!(hdds_abi_compatible(HDDS_ABI_VERSION));
+        assert!(hdds_abi_compatible(HDDS_ABI_VERSION_MAJOR << 16));
+        assert!(!hdds_abi_compatible(HDDS_ABI_VERSION + 1));
+        assert!(!hdds_abi_compatible((HDDS_ABI_VERSION_MAJOR + 1) << 16));
+        assert!(!hdds_abi_compatible(0));
+    }
+
+    #[test]
+    fn test_marker_matches_major() {
+        // Rename hdds_abi_v1 (and update hdds.h) when bumping the major
+        assert_eq!(HDDS_ABI_VERSION_MAJOR, 1);
+    }
+}
@@ -0,0 +1,251 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Handle audit mode for binding development.
+//!
+//! With the `handle-audit` feature, every participant, writer, reader,
+//! publisher, subscriber, waitset and QoS handle returned by the C API is
+//! recorded in a registry with its kind and a generation number. Calls check
+//! their handle against the registry before dereferencing it, so a double
+//! destroy, a use after destroy or a handle of the wrong kind is logged and
+//! rejected (`HddsInvalidArgument`, or a no-op for `void` functions) instead
+//! of touching freed memory. Handles still alive when the process exits are
+//! reported on stderr.
+//!
+//! A stale pointer whose address was reused by a new handle of the same kind
+//! cannot be told apart from the new handle; the generation in the reports
+//! shows which allocation a message refers to.
+//!
+//! Without the feature the checks reduce to the usual null checks.
+
+use std::fmt;
+
+/// Kind of an audited handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum HandleKind {
+    Participant,
+    Writer,
+    Reader,
+    Publisher,
+    Subscriber,
+    WaitSet,
+    QoS,
+}
+
+impl fmt::Display for HandleKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Participant => "participant",
+            Self::Writer => "writer",
+            Self::Reader => "reader",
+            Self::Publisher => "publisher",
+            Self::Subscriber => "subscriber",
+            Self::WaitSet => "waitset",
+            Self::QoS => "qos",
+        };
+        f.write_str(name)
+    }
+}
+
+#[cfg(feature = "handle-audit")]
+mod registry {
+    use super::HandleKind;
+    use std::collections::HashMap;
+    use std::sync::{Mutex, MutexGuard, Once, OnceLock};
+
+    #[derive(Debug, Clone, Copy)]
+    struct Record {
+        kind: HandleKind,
+        generation: u64,
+    }
+
+    #[derive(Default)]
+    struct Registry {
+        last_generation: u64,
+        live: HashMap<usize, Record>,
+        /// Last handle destroyed at each address, to name the culprit of a
+        /// use after destroy.
+        destroyed: HashMap<usize, Record>,
+    }
+
+    fn registry() -> MutexGuard<'static, Registry> {
+        static REGISTRY: OnceLock<Mutex<Registry>> = OnceLock::new();
+        REGISTRY
+            .get_or_init(|| Mutex::new(Registry::default()))
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+    }
+
+    extern "C" fn report_at_exit() {
+        let leaks = report();
+        if leaks > 0 {
+            eprintln!("[hdds-c audit] {} handle(s) leaked at exit", leaks);
+        }
+    }
+
+    pub(crate) fn track(addr: usize, kind: HandleKind) {
+        static AT_EXIT: Once = Once::new();
+        AT_EXIT.call_once(|| {
+            if unsafe { libc::atexit(report_at_exit) } != 0 {
+                log::warn!("[hdds-c audit] cannot register the exit leak report");
+            }
+        });
+
+        let mut registry = registry();
+        registry.last_generation += 1;
+        let record = Record {
+            kind,
+            generation: registry.last_generation,
+        };
+        registry.destroyed.remove(&addr);
+        registry.live.insert(addr, record);
+    }
+
+    pub(crate) fn check(addr: usize, kind: HandleKind, release: bool) -> bool {
+        let mut registry = registry();
+        match registry.live.get(&addr).copied() {
+            Some(record) if record.kind == kind => {
+                if release {
+                    registry.live.remove(&addr);
+                    registry.destroyed.insert(addr, record);
+                }
+                true
+            }
+            Some(record) => {
+                log::error!(
+                    "[hdds-c audit] {} #{} at {:#x} passed where a {} was expected",
+                    record.kind,
+                    record.generation,
+                    addr,
+                    kind
+                );
+                false
+            }
+            None => {
+                let action = if release { "destroy" } else { "use" };
+                match registry.destroyed.get(&addr) {
+                    Some(record) => log::error!(
+                        "[hdds-c audit] {} after destroy of {} #{} at {:#x}",
+                        action,
+                        record.kind,
+                        record.generation,
+                        addr
+                    ),
+                    None => log::error!(
+                        "[hdds-c audit] {} of unknown {} handle {:#x}",
+                        action,
+                        kind,
+                        addr
+                    ),
+                }
+                false
+            }
+        }
+    }
+
+    pub(crate) fn live_count() -> usize {
+        registry().live.len()
+    }
+
+    pub(crate) fn report() -> usize {
+        let registry = registry();
+        let mut live: Vec<(usize, Record)> = registry
+            .live
+            .iter()
+            .map(|(addr, record)| (*addr, *record))
+            .collect();
+        live.sort_by_key(|(_, record)| record.generation);
+        for (addr, record) in &live {
+            eprintln!(
+                "[hdds-c audit] leaked {} #{} at {:#x}",
+                record.kind, record.generation, addr
+            );
+        }
+        live.len()
+    }
+}
+
+/// Record a handle returned to C. Returns `ptr` unchanged.
+#[cfg_attr(not(feature = "handle-audit"), allow(unused_variables))]
+pub(crate) fn track<T>(ptr: *mut T, kind: HandleKind) -> *mut T {
+    #[cfg(feature = "handle-audit")]
+    if !ptr.is_null() {
+        registry::track(ptr as usize, kind);
+    }
+    ptr
+}
+
+/// Whether `ptr` is a live handle of `kind`.
+#[cfg_attr(not(feature = "handle-audit"), allow(unused_variables))]
+pub(crate) fn valid<T>(ptr: *const T, kind: HandleKind) -> bool {
+    if ptr.is_null() {
+        return false;
+    }
+    #[cfg(feature = "handle-audit")]
+    return registry::check(ptr as usize, kind, false);
+    #[cfg(not(feature = "handle-audit"))]
+    true
+}
+
+/// Whether `ptr` is a live handle of `kind` that may be destroyed now; the
+/// handle is no longer valid afterwards.
+#[cfg_attr(not(feature = "handle-audit"), allow(unused_variables))]
+pub(crate) fn release<T>(ptr: *const T, kind: HandleKind) -> bool {
+    if ptr.is_null() {
+        return false;
+    }
+    #[cfg(feature = "handle-audit")]
+    return registry::check(ptr as usize, kind, true);
+    #[cfg(not(feature = "handle-audit"))]
+    true
+}
+
+/// Whether handle auditing is compiled in (`handle-audit` feature).
+#[no_mangle]
+pub extern "C" fn hdds_audit_enabled() -> bool {
+    cfg!(feature = "handle-audit")
+}
+
+/// Number of audited handles currently alive (always 0 without the
+/// `handle-audit` feature).
+#[no_mangle]
+pub extern "C" fn hdds_audit_live_handles() -> usize {
+    #[cfg(feature = "handle-audit")]
+    return registry::live_count();
+    #[cfg(not(feature = "handle-audit"))]
+    0
+}
+
+/// Print every audited handle still alive to stderr and return their count.
+///
+/// The same report runs automatically at process exit when the
+/// `handle-audit` feature is enabled.
+#[no_mangle]
+pub extern "C" fn hdds_audit_report_leaks() -> usize {
+    #[cfg(feature = "handle-audit")]
+    return registry::report();
+    #[cfg(not(feature = "handle-audit"))]
+    0
+}
+
+#[cfg(all(test, feature = "handle-audit"))]
+mod tests {
+    use crate::qos::{hdds_qos_default, hdds_qos_destroy, hdds_qos_set_history_depth};
+    use crate::HddsError;
+
+    #[test]
+    fn test_destroyed_qos_is_rejected() {
+        unsafe {
+            let qos = hdds_qos_default();
+            assert_eq!(hdds_qos_set_history_depth(qos, 4), HddsError::HddsOk);
+            hdds_qos_destroy(qos);
+
+            // Use after destroy and double destroy are caught, not UB
+            assert_eq!(
+                hdds_qos_set_history_depth(qos, 4),
+                HddsError::HddsInvalidArgument
+            );
+            hdds_qos_destroy(qos);
+        }
+    }
+}
@@ -29,18 +29,40 @@
 //! hdds_dynamic_data_destroy(data);
 //! hdds_type_descriptor_destroy(desc);
 //! ```
+//!
+//! # Typed endpoints
+//!
+//! Writers and readers created from a type descriptor exchange
+//! `HddsDynamicData` samples directly, so no hand-written CDR is needed:
+//!
+//! ```c
+//! HddsDataWriter* w = hdds_writer_create_typed(participant, "sensors", desc, NULL);
+//! hdds_writer_write_sample(w, data);
+//!
+//! HddsDataReader* r = hdds_reader_create_typed(participant, "sensors", desc, NULL);
+//! HddsDynamicData* sample = NULL;
+//! if (hdds_reader_take_sample(r, &sample) == HDDS_OK) {
+//!     double temp;
+//!     hdds_dynamic_data_get_f64(sample, "temperature", &temp);
+//!     hdds_dynamic_data_destroy(sample);
+//! }
+//! ```
 
-use std::ffi::CStr;
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
 use std::ptr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, OnceLock};
 
+use hdds::api::DataReader;
 use hdds::dynamic::{
-    DynamicData, PrimitiveKind, TypeDescriptor, TypeDescriptorBuilder,
-    encode_dynamic, decode_dynamic,
+    decode_dynamic, encode_dynamic, DynamicData, FromDynamicValue, IntoDynamicValue, PrimitiveKind,
+    TypeDescriptor, TypeDescriptorBuilder,
 };
 
-use crate::HddsError;
+use crate::audit::{self, HandleKind};
+use crate::last_error::set_last_error;
+use crate::{BytePayload, HddsDataReader, HddsDataWriter, HddsError, HddsParticipant, HddsQoS};
 
 // =============================================================================
 // Primitive Kind enum for C
@@ -117,9 +139,7 @@ struct TypeBuilderWrapper {
 /// # Safety
 /// - `name` must be a valid null-terminated C string.
 #[no_mangle]
-pub unsafe extern "C" fn hdds_type_builder_new(
-    name: *const c_char,
-) -> *mut HddsTypeBuilder {
+pub unsafe extern "C" fn hdds_type_builder_new(name: *const c_char) -> *mut HddsTypeBuilder {
     if name.is_null() {
         return ptr::null_mut();
     }
@@ -279,7 +299,7 @@ pub unsafe extern "C" fn hdds_type_descriptor_get_name(
     }
     let arc = &*desc.cast::<Arc<TypeDescriptor>>();
     let name = &arc.name;
-    let needed = name.as_bytes().len();
+    let needed = name.len();
     if !out_buf.is_null() && capacity > 0 {
         let copy_len = needed.min(capacity - 1);
         ptr::copy_nonoverlapping(name.as_bytes().as_ptr(), out_buf.cast::<u8>(), copy_len);
@@ -349,40 +369,170 @@ pub unsafe extern "C" fn hdds_dynamic_data_destroy(data: *mut HddsDynamicData) {
 // Dynamic Data - Setters
 // =============================================================================
 
-macro_rules! dynamic_setter {
-    ($fn_name:ident, $ty:ty, $set_ty:ty) => {
-        #[no_mangle]
-        pub unsafe extern "C" fn $fn_name(
-            data: *mut HddsDynamicData,
-            field_name: *const c_char,
-            value: $ty,
-        ) -> HddsError {
-            if data.is_null() || field_name.is_null() {
-                return HddsError::HddsInvalidArgument;
-            }
-            let Ok(name) = CStr::from_ptr(field_name).to_str() else {
-                return HddsError::HddsInvalidArgument;
-            };
-            let dd = &mut *data.cast::<DynamicData>();
-            match dd.set(name, value as $set_ty) {
-                Ok(()) => HddsError::HddsOk,
-                Err(_) => HddsError::HddsInvalidArgument,
-            }
-        }
+/// Shared body of the `hdds_dynamic_data_set_*` functions.
+unsafe fn set_field<V: IntoDynamicValue>(
+    data: *mut HddsDynamicData,
+    field_name: *const c_char,
+    value: V,
+) -> HddsError {
+    if data.is_null() || field_name.is_null() {
+        return HddsError::HddsInvalidArgument;
+    }
+    let Ok(name) = CStr::from_ptr(field_name).to_str() else {
+        return HddsError::HddsInvalidArgument;
     };
+    let dd = &mut *data.cast::<DynamicData>();
+    match dd.set(name, value) {
+        Ok(()) => HddsError::HddsOk,
+        Err(err) => {
+            set_last_error(&err);
+            HddsError::HddsInvalidArgument
+        }
+    }
+}
+
+/// Set a `bool` field value.
+///
+/// # Safety
+/// - `data` must be valid. `field_name` must be null-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_dynamic_data_set_bool(
+    data: *mut HddsDynamicData,
+    field_name: *const c_char,
+    value: bool,
+) -> HddsError {
+    set_field(data, field_name, value)
+}
+
+/// Set a `u8` field value.
+///
+/// # Safety
+/// - `data` must be valid. `field_name` must be null-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_dynamic_data_set_u8(
+    data: *mut HddsDynamicData,
+    field_name: *const c_char,
+    value: u8,
+) -> HddsError {
+    set_field(data, field_name, value)
+}
+
+/// Set a `u16` field value.
+///
+/// # Safety
+/// - `data` must be valid. `field_name` must be null-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_dynamic_data_set_u16(
+    data: *mut HddsDynamicData,
+    field_name: *const c_char,
+    value: u16,
+) -> HddsError {
+    set_field(data, field_name, value)
+}
+
+/// Set a `u32` field value.
+///
+/// # Safety
+/// - `data` must be valid. `field_name` must be null-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_dynamic_data_set_u32(
+    data: *mut HddsDynamicData,
+    field_name: *const c_char,
+    value: u32,
+) -> HddsError {
+    set_field(data, field_name, value)
+}
+
+/// Set a `u64` field value.
+///
+/// # Safety
+/// - `data` must be valid. `field_name` must be null-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_dynamic_data_set_u64(
+    data: *mut HddsDynamicData,
+    field_name: *const c_char,
+    value: u64,
+) -> HddsError {
+    set_field(data, field_name, value)
+}
+
+/// Set a `i8` field value.
+///
+/// # Safety
+/// - `data` must be valid. `field_name` must be null-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_dynamic_data_set_i8(
+    data: *mut HddsDynamicData,
+    field_name: *const c_char,
+    value: i8,
+) -> HddsError {
+    set_field(data, field_name, value)
+}
+
+/// Set a `i16` field value.
+///
+/// # Safety
+/// - `data` must be valid. `field_name` must be null-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_dynamic_data_set_i16(
+    data: *mut HddsDynamicData,
+    field_name: *const c_char,
+    value: i16,
+) -> HddsError {
+    set_field(data, field_name, value)
+}
+
+/// Set a `i32` field value.
+///
+/// # Safety
+/// - `data` must be valid. `field_name` must be null-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_dynamic_data_set_i32(
+    data: *mut HddsDynamicData,
+    field_name: *const c_char,
+    value: i32,
+) -> HddsError {
+    set_field(data, field_name, value)
 }
 
-dynamic_setter!(hdds_dynamic_data_set_bool, bool, bool);
-dynamic_setter!(hdds_dynamic_data_set_u8, u8, u8);
-dynamic_setter!(hdds_dynamic_data_set_u16, u16, u16);
-dynamic_setter!(hdds_dynamic_data_set_u32, u32, u32);
-dynamic_setter!(hdds_dynamic_data_set_u64, u64, u64);
-dynamic_setter!(hdds_dynamic_data_set_i8, i8, i8);
-dynamic_setter!(hdds_dynamic_data_set_i16, i16, i16);
-dynamic_setter!(hdds_dynamic_data_set_i32, i32, i32);
-dynamic_setter!(hdds_dynamic_data_set_i64, i64, i64);
-dynamic_setter!(hdds_dynamic_data_set_f32, f32, f32);
-dynamic_setter!(hdds_dynamic_data_set_f64, f64, f64);
+/// Set a `i64` field value.
+///
+/// # Safety
+/// - `data` must be valid. `field_name` must be null-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_dynamic_data_set_i64(
+    data: *mut HddsDynamicData,
+    field_name: *const c_char,
+    value: i64,
+) -> HddsError {
+    set_field(data, field_name, value)
+}
+
+/// Set a `f32` field value.
+///
+/// # Safety
+/// - `data` must be valid. `field_name` must be null-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_dynamic_data_set_f32(
+    data: *mut HddsDynamicData,
+    field_name: *const c_char,
+    value: f32,
+) -> HddsError {
+    set_field(data, field_name, value)
+}
+
+/// Set a `f64` field value.
+///
+/// # Safety
+/// - `data` must be valid. `field_name` must be null-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_dynamic_data_set_f64(
+    data: *mut HddsDynamicData,
+    field_name: *const c_char,
+    value: f64,
+) -> HddsError {
+    set_field(data, field_name, value)
+}
 
 /// Set a string field value.
 ///
@@ -406,7 +556,10 @@ pub unsafe extern "C" fn hdds_dynamic_data_set_string(
     let dd = &mut *data.cast::<DynamicData>();
     match dd.set(name, val) {
         Ok(()) => HddsError::HddsOk,
-        Err(_) => HddsError::HddsInvalidArgument,
+        Err(err) => {
+            set_last_error(&err);
+            HddsError::HddsInvalidArgument
+        }
     }
 }
 
@@ -414,43 +567,184 @@ pub unsafe extern "C" fn hdds_dynamic_data_set_string(
 // Dynamic Data - Getters
 // =============================================================================
 
-macro_rules! dynamic_getter {
-    ($fn_name:ident, $ty:ty) => {
-        #[no_mangle]
-        pub unsafe extern "C" fn $fn_name(
-            data: *const HddsDynamicData,
-            field_name: *const c_char,
-            out_value: *mut $ty,
-        ) -> HddsError {
-            if data.is_null() || field_name.is_null() || out_value.is_null() {
-                return HddsError::HddsInvalidArgument;
-            }
-            let Ok(name) = CStr::from_ptr(field_name).to_str() else {
-                return HddsError::HddsInvalidArgument;
-            };
-            let dd = &*data.cast::<DynamicData>();
-            match dd.get::<$ty>(name) {
-                Ok(v) => {
-                    *out_value = v;
-                    HddsError::HddsOk
-                }
-                Err(_) => HddsError::HddsInvalidArgument,
-            }
-        }
+/// Shared body of the `hdds_dynamic_data_get_*` functions.
+unsafe fn get_field<V: FromDynamicValue>(
+    data: *const HddsDynamicData,
+    field_name: *const c_char,
+    out_value: *mut V,
+) -> HddsError {
+    if data.is_null() || field_name.is_null() || out_value.is_null() {
+        return HddsError::HddsInvalidArgument;
+    }
+    let Ok(name) = CStr::from_ptr(field_name).to_str() else {
+        return HddsError::HddsInvalidArgument;
     };
+    let dd = &*data.cast::<DynamicData>();
+    match dd.get::<V>(name) {
+        Ok(v) => {
+            *out_value = v;
+            HddsError::HddsOk
+        }
+        Err(err) => {
+            set_last_error(&err);
+            HddsError::HddsInvalidArgument
+        }
+    }
+}
+
+/// Get a `bool` field value.
+///
+/// # Safety
+/// - `data` must be valid. `field_name` must be null-terminated.
+/// - `out_value` must be valid.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_dynamic_data_get_bool(
+    data: *const HddsDynamicData,
+    field_name: *const c_char,
+    out_value: *mut bool,
+) -> HddsError {
+    get_field(data, field_name, out_value)
+}
+
+/// Get a `u8` field value.
+///
+/// # Safety
+/// - `data` must be valid. `field_name` must be null-terminated.
+/// - `out_value` must be valid.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_dynamic_data_get_u8(
+    data: *const HddsDynamicData,
+    field_name: *const c_char,
+    out_value: *mut u8,
+) -> HddsError {
+    get_field(data, field_name, out_value)
+}
+
+/// Get a `u16` field value.
+///
+/// # Safety
+/// - `data` must be valid. `field_name` must be null-terminated.
+/// - `out_value` must be valid.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_dynamic_data_get_u16(
+    data: *const HddsDynamicData,
+    field_name: *const c_char,
+    out_value: *mut u16,
+) -> HddsError {
+    get_field(data, field_name, out_value)
+}
+
+/// Get a `u32` field value.
+///
+/// # Safety
+/// - `data` must be valid. `field_name` must be null-terminated.
+/// - `out_value` must be valid.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_dynamic_data_get_u32(
+    data: *const HddsDynamicData,
+    field_name: *const c_char,
+    out_value: *mut u32,
+) -> HddsError {
+    get_field(data, field_name, out_value)
+}
+
+/// Get a `u64` field value.
+///
+/// # Safety
+/// - `data` must be valid. `field_name` must be null-terminated.
+/// - `out_value` must be valid.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_dynamic_data_get_u64(
+    data: *const HddsDynamicData,
+    field_name: *const c_char,
+    out_value: *mut u64,
+) -> HddsError {
+    get_field(data, field_name, out_value)
+}
+
+/// Get a `i8` field value.
+///
+/// # Safety
+/// - `data` must be valid. `field_name` must be null-terminated.
+/// - `out_value` must be valid.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_dynamic_data_get_i8(
+    data: *const HddsDynamicData,
+    field_name: *const c_char,
+    out_value: *mut i8,
+) -> HddsError {
+    get_field(data, field_name, out_value)
+}
+
+/// Get a `i16` field value.
+///
+/// # Safety
+/// - `data` must be valid. `field_name` must be null-terminated.
+/// - `out_value` must be valid.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_dynamic_data_get_i16(
+    data: *const HddsDynamicData,
+    field_name: *const c_char,
+    out_value: *mut i16,
+) -> HddsError {
+    get_field(data, field_name, out_value)
+}
+
+/// Get a `i32` field value.
+///
+/// # Safety
+/// - `data` must be valid. `field_name` must be null-terminated.
+/// - `out_value` must be valid.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_dynamic_data_get_i32(
+    data: *const HddsDynamicData,
+    field_name: *const c_char,
+    out_value: *mut i32,
+) -> HddsError {
+    get_field(data, field_name, out_value)
+}
+
+/// Get a `i64` field value.
+///
+/// # Safety
+/// - `data` must be valid. `field_name` must be null-terminated.
+/// - `out_value` must be valid.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_dynamic_data_get_i64(
+    data: *const HddsDynamicData,
+    field_name: *const c_char,
+    out_value: *mut i64,
+) -> HddsError {
+    get_field(data, field_name, out_value)
+}
+
+/// Get a `f32` field value.
+///
+/// # Safety
+/// - `data` must be valid. `field_name` must be null-terminated.
+/// - `out_value` must be valid.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_dynamic_data_get_f32(
+    data: *const HddsDynamicData,
+    field_name: *const c_char,
+    out_value: *mut f32,
+) -> HddsError {
+    get_field(data, field_name, out_value)
 }
 
-dynamic_getter!(hdds_dynamic_data_get_bool, bool);
-dynamic_getter!(hdds_dynamic_data_get_u8, u8);
-dynamic_getter!(hdds_dynamic_data_get_u16, u16);
-dynamic_getter!(hdds_dynamic_data_get_u32, u32);
-dynamic_getter!(hdds_dynamic_data_get_u64, u64);
-dynamic_getter!(hdds_dynamic_data_get_i8, i8);
-dynamic_getter!(hdds_dynamic_data_get_i16, i16);
-dynamic_getter!(hdds_dynamic_data_get_i32, i32);
-dynamic_getter!(hdds_dynamic_data_get_i64, i64);
-dynamic_getter!(hdds_dynamic_data_get_f32, f32);
-dynamic_getter!(hdds_dynamic_data_get_f64, f64);
+/// Get a `f64` field value.
+///
+/// # Safety
+/// - `data` must be valid. `field_name` must be null-terminated.
+/// - `out_value` must be valid.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_dynamic_data_get_f64(
+    data: *const HddsDynamicData,
+    field_name: *const c_char,
+    out_value: *mut f64,
+) -> HddsError {
+    get_field(data, field_name, out_value)
+}
 
 /// Get a string field value. Returns the number of bytes needed (excluding null).
 ///
@@ -474,7 +768,7 @@ pub unsafe extern "C" fn hdds_dynamic_data_get_string(
     let Ok(val) = dd.get::<String>(name) else {
         return 0;
     };
-    let needed = val.as_bytes().len();
+    let needed = val.len();
     if !out_buf.is_null() && capacity > 0 {
         let copy_len = needed.min(capacity - 1);
         ptr::copy_nonoverlapping(val.as_bytes().as_ptr(), out_buf.cast::<u8>(), copy_len);
@@ -547,6 +841,178 @@ pub unsafe extern "C" fn hdds_dynamic_data_decode(
     }
 }
 
+// =============================================================================
+// Typed endpoints
+// =============================================================================
+
+/// Descriptors of readers created with `hdds_reader_create_typed`, keyed by handle.
+fn typed_readers() -> &'static Mutex<HashMap<usize, Arc<TypeDescriptor>>> {
+    static REGISTRY: OnceLock<Mutex<HashMap<usize, Arc<TypeDescriptor>>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Drop the descriptor of a typed reader (called by `hdds_reader_destroy`).
+pub(crate) fn forget_typed_reader(reader: *mut HddsDataReader) {
+    typed_readers()
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .remove(&(reader as usize));
+}
+
+/// Create a type descriptor from a registered type object.
+///
+/// Returns NULL if `handle` is NULL.
+///
+/// # Safety
+/// - `handle` must be a valid handle from `hdds_participant_register_type_support`.
+#[cfg(feature = "xtypes")]
+#[no_mangle]
+pub unsafe extern "C" fn hdds_type_descriptor_from_type_object(
+    handle: *const crate::HddsTypeObject,
+) -> *mut HddsTypeDescriptor {
+    if handle.is_null() {
+        return ptr::null_mut();
+    }
+    let type_object = &*handle.cast::<hdds::core::types::TypeObjectHandle>();
+    let desc = hdds::dynamic::type_descriptor_from_xtypes(&type_object.complete);
+    Box::into_raw(Box::new(desc)).cast::<HddsTypeDescriptor>()
+}
+
+/// Create a `DataWriter` publishing `HddsDynamicData` samples of type `desc`.
+///
+/// The descriptor's name is announced as the type name via SEDP. Write with
+/// `hdds_writer_write_sample`; destroy with `hdds_writer_destroy`.
+///
+/// # Safety
+/// - `participant` must be a valid pointer returned from `hdds_participant_create`
+/// - `topic_name` must be a valid null-terminated C string
+/// - `desc` must be a valid type descriptor (it is not consumed)
+/// - `qos` must be a valid pointer returned from `hdds_qos_*` functions (or NULL for default)
+#[no_mangle]
+pub unsafe extern "C" fn hdds_writer_create_typed(
+    participant: *mut HddsParticipant,
+    topic_name: *const c_char,
+    desc: *const HddsTypeDescriptor,
+    qos: *const HddsQoS,
+) -> *mut HddsDataWriter {
+    if desc.is_null() {
+        return ptr::null_mut();
+    }
+    let arc = &*desc.cast::<Arc<TypeDescriptor>>();
+    let Ok(type_name) = CString::new(arc.name.as_str()) else {
+        return ptr::null_mut();
+    };
+    crate::hdds_writer_create_with_type(participant, topic_name, type_name.as_ptr(), qos)
+}
+
+/// Serialize and publish a dynamic data sample.
+///
+/// # Safety
+/// - `writer` must be a valid pointer returned from `hdds_writer_create_typed`
+///   (or any other `hdds_writer_create*` function)
+/// - `data` must be valid.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_writer_write_sample(
+    writer: *mut HddsDataWriter,
+    data: *const HddsDynamicData,
+) -> HddsError {
+    if !audit::valid(writer, HandleKind::Writer) || data.is_null() {
+        return HddsError::HddsInvalidArgument;
+    }
+    let dd = &*data.cast::<DynamicData>();
+    match encode_dynamic(dd) {
+        Ok(encoded) => crate::hdds_writer_write(writer, encoded.as_ptr().cast(), encoded.len()),
+        Err(e) => {
+            log::error!("hdds_writer_write_sample: {:?}", e);
+            HddsError::HddsSerializationError
+        }
+    }
+}
+
+/// Create a `DataReader` taking `HddsDynamicData` samples of type `desc`.
+///
+/// The descriptor's name is announced as the type name via SEDP. Take with
+/// `hdds_reader_take_sample`; destroy with `hdds_reader_destroy`.
+///
+/// # Safety
+/// - `participant` must be a valid pointer returned from `hdds_participant_create`
+/// - `topic_name` must be a valid null-terminated C string
+/// - `desc` must be a valid type descriptor (it is not consumed)
+/// - `qos` must be a valid pointer returned from `hdds_qos_*` functions (or NULL for default)
+#[no_mangle]
+pub unsafe extern "C" fn hdds_reader_create_typed(
+    participant: *mut HddsParticipant,
+    topic_name: *const c_char,
+    desc: *const HddsTypeDescriptor,
+    qos: *const HddsQoS,
+) -> *mut HddsDataReader {
+    if desc.is_null() {
+        return ptr::null_mut();
+    }
+    let arc = &*desc.cast::<Arc<TypeDescriptor>>();
+    let Ok(type_name) = CString::new(arc.name.as_str()) else {
+        return ptr::null_mut();
+    };
+    let reader =
+        crate::hdds_reader_create_with_type(participant, topic_name, type_name.as_ptr(), qos);
+    if !reader.is_null() {
+        typed_readers()
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .insert(reader as usize, Arc::clone(arc));
+    }
+    reader
+}
+
+/// Take the next sample from a typed reader (non-blocking).
+///
+/// On `HddsOk`, `*out_data` receives a new dynamic data instance that must be
+/// released with `hdds_dynamic_data_destroy`. Returns `HddsNotFound` if no
+/// sample is available and `HddsInvalidState` if the reader was not created
+/// with `hdds_reader_create_typed`.
+///
+/// # Safety
+/// - `reader` must be a valid pointer returned from `hdds_reader_create_typed`
+/// - `out_data` must be a valid pointer to write the result.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_reader_take_sample(
+    reader: *mut HddsDataReader,
+    out_data: *mut *mut HddsDynamicData,
+) -> HddsError {
+    if !audit::valid(reader, HandleKind::Reader) || out_data.is_null() {
+        return HddsError::HddsInvalidArgument;
+    }
+    *out_data = ptr::null_mut();
+
+    let Some(desc) = typed_readers()
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .get(&(reader as usize))
+        .cloned()
+    else {
+        return HddsError::HddsInvalidState;
+    };
+
+    let reader_ref = &*reader.cast::<DataReader<BytePayload>>();
+    match reader_ref.take() {
+        Ok(Some(payload)) => match decode_dynamic(&payload.data, &desc) {
+            Ok(dd) => {
+                *out_data = Box::into_raw(Box::new(dd)).cast::<HddsDynamicData>();
+                HddsError::HddsOk
+            }
+            Err(e) => {
+                log::error!("hdds_reader_take_sample: {:?}", e);
+                HddsError::HddsSerializationError
+            }
+        },
+        Ok(None) => HddsError::HddsNotFound,
+        Err(err) => {
+            set_last_error(&err);
+            HddsError::HddsOperationFailed
+        }
+    }
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -600,25 +1066,41 @@ mod tests {
             hdds_type_builder_add_field(builder, f_id.as_ptr(), HddsPrimitiveKind::HddsPrimU32);
             hdds_type_builder_add_field(builder, f_temp.as_ptr(), HddsPrimitiveKind::HddsPrimF64);
             hdds_type_builder_add_string_field(builder, f_label.as_ptr());
-            hdds_type_builder_add_field(builder, f_active.as_ptr(), HddsPrimitiveKind::HddsPrimBool);
+            hdds_type_builder_add_field(
+                builder,
+                f_active.as_ptr(),
+                HddsPrimitiveKind::HddsPrimBool,
+            );
 
             let desc = hdds_type_builder_build(builder);
             let data = hdds_dynamic_data_new(desc);
             assert!(!data.is_null());
 
             // Set values
-            assert_eq!(hdds_dynamic_data_set_u32(data, f_id.as_ptr(), 42), HddsError::HddsOk);
-            assert_eq!(hdds_dynamic_data_set_f64(data, f_temp.as_ptr(), 23.5), HddsError::HddsOk);
+            assert_eq!(
+                hdds_dynamic_data_set_u32(data, f_id.as_ptr(), 42),
+                HddsError::HddsOk
+            );
+            assert_eq!(
+                hdds_dynamic_data_set_f64(data, f_temp.as_ptr(), 23.5),
+                HddsError::HddsOk
+            );
             let label_val = CString::new("living_room").unwrap();
             assert_eq!(
                 hdds_dynamic_data_set_string(data, f_label.as_ptr(), label_val.as_ptr()),
                 HddsError::HddsOk,
             );
-            assert_eq!(hdds_dynamic_data_set_bool(data, f_active.as_ptr(), true), HddsError::HddsOk);
+            assert_eq!(
+                hdds_dynamic_data_set_bool(data, f_active.as_ptr(), true),
+                HddsError::HddsOk
+            );
 
             // Get values
             let mut id_out: u32 = 0;
-            assert_eq!(hdds_dynamic_data_get_u32(data, f_id.as_ptr(), &mut id_out), HddsError::HddsOk);
+            assert_eq!(
+                hdds_dynamic_data_get_u32(data, f_id.as_ptr(), &mut id_out),
+                HddsError::HddsOk
+            );
             assert_eq!(id_out, 42);
 
             let mut temp_out: f64 = 0.0;
@@ -679,8 +1161,14 @@ mod tests {
 
             let mut x_out: f32 = 0.0;
             let mut y_out: f32 = 0.0;
-            assert_eq!(hdds_dynamic_data_get_f32(data2, fx.as_ptr(), &mut x_out), HddsError::HddsOk);
-            assert_eq!(hdds_dynamic_data_get_f32(data2, fy.as_ptr(), &mut y_out), HddsError::HddsOk);
+            assert_eq!(
+                hdds_dynamic_data_get_f32(data2, fx.as_ptr(), &mut x_out),
+                HddsError::HddsOk
+            );
+            assert_eq!(
+                hdds_dynamic_data_get_f32(data2, fy.as_ptr(), &mut y_out),
+                HddsError::HddsOk
+            );
             assert!((x_out - 1.5).abs() < f32::EPSILON);
             assert!((y_out - 2.5).abs() < f32::EPSILON);
 
@@ -690,6 +1178,81 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_typed_pubsub_roundtrip() {
+        unsafe {
+            let name = CString::new("SensorReading").unwrap();
+            let builder = hdds_type_builder_new(name.as_ptr());
+            let f_id = CString::new("sensor_id").unwrap();
+            let f_label = CString::new("label").unwrap();
+            hdds_type_builder_add_field(builder, f_id.as_ptr(), HddsPrimitiveKind::HddsPrimU32);
+            hdds_type_builder_add_string_field(builder, f_label.as_ptr());
+            let desc = hdds_type_builder_build(builder);
+
+            let participant_name = CString::new("typed_ffi").unwrap();
+            let participant = crate::hdds_participant_create_with_transport(
+                participant_name.as_ptr(),
+                crate::HddsTransportMode::HddsTransportIntraProcess,
+            );
+            assert!(!participant.is_null());
+
+            let topic = CString::new("typed_ffi/sensors").unwrap();
+            let reader = hdds_reader_create_typed(participant, topic.as_ptr(), desc, ptr::null());
+            let writer = hdds_writer_create_typed(participant, topic.as_ptr(), desc, ptr::null());
+            assert!(!reader.is_null());
+            assert!(!writer.is_null());
+
+            let mut sample = ptr::null_mut();
+            assert_eq!(
+                hdds_reader_take_sample(reader, &mut sample),
+                HddsError::HddsNotFound
+            );
+
+            let data = hdds_dynamic_data_new(desc);
+            hdds_dynamic_data_set_u32(data, f_id.as_ptr(), 7);
+            let label = CString::new("kitchen").unwrap();
+            hdds_dynamic_data_set_string(data, f_label.as_ptr(), label.as_ptr());
+            assert_eq!(hdds_writer_write_sample(writer, data), HddsError::HddsOk);
+
+            let deadline = std::time::Instant::now() + std::time::Duration::from_secs(1);
+            while hdds_reader_take_sample(reader, &mut sample) == HddsError::HddsNotFound {
+                assert!(std::time::Instant::now() < deadline, "no sample received");
+                std::thread::sleep(std::time::Duration::from_millis(5));
+            }
+            assert!(!sample.is_null());
+
+            let mut id_out: u32 = 0;
+            assert_eq!(
+                hdds_dynamic_data_get_u32(sample, f_id.as_ptr(), &mut id_out),
+                HddsError::HddsOk
+            );
+            assert_eq!(id_out, 7);
+            let mut str_buf = [0u8; 16];
+            let str_len = hdds_dynamic_data_get_string(
+                sample,
+                f_label.as_ptr(),
+                str_buf.as_mut_ptr().cast(),
+                16,
+            );
+            assert_eq!(&str_buf[..str_len], b"kitchen");
+
+            // Untyped readers cannot decode samples
+            let raw_reader = crate::hdds_reader_create(participant, topic.as_ptr());
+            assert_eq!(
+                hdds_reader_take_sample(raw_reader, &mut sample),
+                HddsError::HddsInvalidState
+            );
+
+            hdds_dynamic_data_destroy(sample);
+            hdds_dynamic_data_destroy(data);
+            crate::hdds_reader_destroy(raw_reader);
+            crate::hdds_reader_destroy(reader);
+            crate::hdds_writer_destroy(writer);
+            crate::hdds_participant_destroy(participant);
+            hdds_type_descriptor_destroy(desc);
+        }
+    }
+
     #[test]
     fn test_null_safety() {
         unsafe {
@@ -697,7 +1260,11 @@ mod tests {
             hdds_type_builder_destroy(ptr::null_mut());
 
             assert_eq!(
-                hdds_type_builder_add_field(ptr::null_mut(), ptr::null(), HddsPrimitiveKind::HddsPrimI32),
+                hdds_type_builder_add_field(
+                    ptr::null_mut(),
+                    ptr::null(),
+                    HddsPrimitiveKind::HddsPrimI32
+                ),
                 HddsError::HddsInvalidArgument,
             );
 
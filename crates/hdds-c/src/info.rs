@@ -11,6 +11,7 @@ use std::sync::Arc;
 use hdds::api::{DataReader, DataWriter, Participant};
 
 use super::{BytePayload, HddsDataReader, HddsDataWriter, HddsError, HddsParticipant};
+use crate::audit::{self, HandleKind};
 
 // =============================================================================
 // Participant Information
@@ -26,7 +27,7 @@ use super::{BytePayload, HddsDataReader, HddsDataWriter, HddsError, HddsParticip
 /// Pointer to null-terminated participant name, or NULL on error
 #[no_mangle]
 pub unsafe extern "C" fn hdds_participant_name(participant: *mut HddsParticipant) -> *const c_char {
-    if participant.is_null() {
+    if !audit::valid(participant, HandleKind::Participant) {
         return ptr::null();
     }
 
@@ -53,7 +54,7 @@ pub unsafe extern "C" fn hdds_participant_name(participant: *mut HddsParticipant
 /// Domain ID (default 0), or 0xFFFFFFFF on error
 #[no_mangle]
 pub unsafe extern "C" fn hdds_participant_domain_id(participant: *mut HddsParticipant) -> u32 {
-    if participant.is_null() {
+    if !audit::valid(participant, HandleKind::Participant) {
         return 0xFFFF_FFFF;
     }
 
@@ -70,7 +71,7 @@ pub unsafe extern "C" fn hdds_participant_domain_id(participant: *mut HddsPartic
 /// Participant ID, or 0xFF on error
 #[no_mangle]
 pub unsafe extern "C" fn hdds_participant_id(participant: *mut HddsParticipant) -> u8 {
-    if participant.is_null() {
+    if !audit::valid(participant, HandleKind::Participant) {
         return 0xFF;
     }
 
@@ -98,7 +99,7 @@ pub unsafe extern "C" fn hdds_writer_topic_name(
     buf_len: usize,
     out_len: *mut usize,
 ) -> HddsError {
-    if writer.is_null() || buf.is_null() || out_len.is_null() {
+    if !audit::valid(writer, HandleKind::Writer) || buf.is_null() || out_len.is_null() {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -138,7 +139,7 @@ pub unsafe extern "C" fn hdds_reader_topic_name(
     buf_len: usize,
     out_len: *mut usize,
 ) -> HddsError {
-    if reader.is_null() || buf.is_null() || out_len.is_null() {
+    if !audit::valid(reader, HandleKind::Reader) || buf.is_null() || out_len.is_null() {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Last error message for HDDS C FFI
+//!
+//! `HddsError` only tells the caller which class of failure happened; the
+//! message of the underlying error (and its context) is kept per thread,
+//! errno-style, until the next failing call on that thread.
+
+use std::cell::RefCell;
+use std::ffi::CString;
+use std::fmt::Display;
+use std::os::raw::c_char;
+use std::ptr;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Record `err` as the calling thread's last error.
+pub(crate) fn set_last_error(err: &dyn Display) {
+    // Interior NULs would truncate the message on the C side anyway
+    let message = err.to_string().replace('\0', " ");
+    let message = CString::new(message).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+/// Get the message of the last error raised on the calling thread
+///
+/// Failing calls record their error here; successful calls leave it
+/// untouched, so only read it right after a call returned an error.
+///
+/// # Returns
+/// Pointer to a null-terminated message, or NULL if no call failed on this
+/// thread since the last `hdds_clear_last_error`. The string is owned by
+/// HDDS and stays valid until the next failing call on the same thread.
+#[no_mangle]
+pub extern "C" fn hdds_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|last| last.borrow().as_ref().map_or(ptr::null(), |m| m.as_ptr()))
+}
+
+/// Forget the calling thread's last error
+#[no_mangle]
+pub extern "C" fn hdds_clear_last_error() {
+    LAST_ERROR.with(|last| *last.borrow_mut() = None);
+}
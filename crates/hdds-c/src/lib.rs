@@ -10,28 +10,40 @@
 //! All public functions are `unsafe` and require the caller to uphold the
 //! invariants documented in each function's safety comment.
 
+mod abi;
+mod audit;
+mod dynamic_ffi;
 mod info;
+mod last_error;
 mod listener;
 mod logging;
 mod pubsub;
 mod qos;
 #[cfg(feature = "rmw")]
 mod rmw;
+mod sample_ffi;
 mod security_config;
 mod telemetry;
 mod transport_config;
 mod waitset;
 
 // Re-export new modules
+pub use abi::*;
+pub use audit::*;
+pub use dynamic_ffi::*;
 pub use info::*;
+pub use last_error::*;
 pub use listener::*;
 pub use logging::*;
 pub use pubsub::*;
+pub use sample_ffi::*;
 pub use telemetry::*;
 
 // Re-export QoS types
 pub use qos::HddsQoS;
 
+use audit::HandleKind;
+use last_error::set_last_error;
 use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::ffi::CStr;
@@ -304,6 +316,7 @@ fn distro_from_u32(value: u32) -> Option<Distro> {
 
 #[cfg(feature = "xtypes")]
 fn map_rosidl_error(err: &RosidlError) -> HddsError {
+    set_last_error(&format_args!("rosidl type support: {:?}", err));
     match err {
         RosidlError::NullTypeSupport
         | RosidlError::NullMembers
@@ -437,6 +450,10 @@ pub enum HddsTransportMode {
     HddsTransportIntraProcess = 0,
     /// UDP multicast for network discovery and communication (default for DDS interop)
     HddsTransportUdpMulticast = 1,
+    /// RTPS over TCP for discovery and communication (no UDP)
+    HddsTransportTcp = 2,
+    /// RTPS over an in-memory bus between participants of one process (no sockets)
+    HddsTransportLoopbackBus = 3,
 }
 
 /// Create a new DDS Participant with default settings (UdpMulticast transport)
@@ -463,6 +480,8 @@ pub unsafe extern "C" fn hdds_participant_create(name: *const c_char) -> *mut Hd
 /// * `transport` - Transport mode:
 ///   - `HddsTransportMode::HddsTransportIntraProcess` (0): No network, intra-process only
 ///   - `HddsTransportMode::HddsTransportUdpMulticast` (1): UDP multicast for network discovery
+///   - `HddsTransportMode::HddsTransportTcp` (2): RTPS over TCP, no UDP
+///   - `HddsTransportMode::HddsTransportLoopbackBus` (3): RTPS in-process, no sockets
 ///
 /// # Returns
 /// Opaque participant handle, or NULL on failure
@@ -471,13 +490,6 @@ pub unsafe extern "C" fn hdds_participant_create_with_transport(
     name: *const c_char,
     transport: HddsTransportMode,
 ) -> *mut HddsParticipant {
-    // Initialize logger (only once, subsequent calls are no-op)
-    use std::sync::Once;
-    static INIT: Once = Once::new();
-    INIT.call_once(|| {
-        let _ = env_logger::try_init();
-    });
-
     if name.is_null() {
         return ptr::null_mut();
     }
@@ -486,10 +498,36 @@ pub unsafe extern "C" fn hdds_participant_create_with_transport(
         return ptr::null_mut();
     };
 
+    let Ok(participant) = participant_builder(name_str, transport).build() else {
+        return ptr::null_mut();
+    };
+
+    // Store Arc<Participant> in a Box so we can get a stable pointer to the Arc itself
+    audit::track(
+        Box::into_raw(Box::new(participant)).cast::<HddsParticipant>(),
+        HandleKind::Participant,
+    )
+}
+
+/// Participant builder of `hdds_participant_create_with_transport`
+/// (domain from `HDDS_DOMAIN_ID`, ports assigned by the builder).
+pub(crate) fn participant_builder(
+    name: &str,
+    transport: HddsTransportMode,
+) -> hdds::dds::ParticipantBuilder {
+    // Initialize logger (only once, subsequent calls are no-op)
+    use std::sync::Once;
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        let _ = env_logger::try_init();
+    });
+
     use hdds::TransportMode;
     let mode = match transport {
         HddsTransportMode::HddsTransportIntraProcess => TransportMode::IntraProcess,
         HddsTransportMode::HddsTransportUdpMulticast => TransportMode::UdpMulticast,
+        HddsTransportMode::HddsTransportTcp => TransportMode::Tcp,
+        HddsTransportMode::HddsTransportLoopbackBus => TransportMode::LoopbackBus,
     };
 
     // Port assignment evolution:
@@ -518,16 +556,9 @@ pub unsafe extern "C" fn hdds_participant_create_with_transport(
         );
     }
 
-    let Ok(participant) = Participant::builder(name_str)
+    Participant::builder(name)
         .domain_id(domain_id)
         .with_transport(mode)
-        .build()
-    else {
-        return ptr::null_mut();
-    };
-
-    // Store Arc<Participant> in a Box so we can get a stable pointer to the Arc itself
-    Box::into_raw(Box::new(participant)).cast::<HddsParticipant>()
 }
 
 /// Destroy a Participant
@@ -537,7 +568,7 @@ pub unsafe extern "C" fn hdds_participant_create_with_transport(
 /// - Must not be called more than once with the same pointer.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_participant_destroy(participant: *mut HddsParticipant) {
-    if !participant.is_null() {
+    if audit::release(participant, HandleKind::Participant) {
         // Participant was stored as Box<Arc<Participant>>
         let _ = Box::from_raw(participant.cast::<Arc<Participant>>());
     }
@@ -551,7 +582,7 @@ pub unsafe extern "C" fn hdds_participant_destroy(participant: *mut HddsParticip
 pub unsafe extern "C" fn hdds_participant_graph_guard_condition(
     participant: *mut HddsParticipant,
 ) -> *const HddsGuardCondition {
-    if participant.is_null() {
+    if !audit::valid(participant, HandleKind::Participant) {
         return ptr::null();
     }
 
@@ -576,7 +607,10 @@ pub unsafe extern "C" fn hdds_participant_register_type_support(
     type_support: *const rosidl_message_type_support_t,
     out_handle: *mut *const HddsTypeObject,
 ) -> HddsError {
-    if participant.is_null() || type_support.is_null() || out_handle.is_null() {
+    if !audit::valid(participant, HandleKind::Participant)
+        || type_support.is_null()
+        || out_handle.is_null()
+    {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -641,6 +675,9 @@ pub unsafe extern "C" fn hdds_type_object_hash(
 
 /// Get HDDS library version string
 ///
+/// This is the release version; check compatibility with
+/// [`hdds_abi_version`] instead.
+///
 /// # Safety
 /// The returned pointer is valid for the lifetime of the process (static storage).
 #[no_mangle]
@@ -659,7 +696,7 @@ pub unsafe extern "C" fn hdds_writer_create(
     participant: *mut HddsParticipant,
     topic_name: *const c_char,
 ) -> *mut HddsDataWriter {
-    if participant.is_null() || topic_name.is_null() {
+    if !audit::valid(participant, HandleKind::Participant) || topic_name.is_null() {
         return ptr::null_mut();
     }
 
@@ -676,7 +713,10 @@ pub unsafe extern "C" fn hdds_writer_create(
         return ptr::null_mut();
     };
 
-    Box::into_raw(Box::new(writer)).cast::<HddsDataWriter>()
+    audit::track(
+        Box::into_raw(Box::new(writer)).cast::<HddsDataWriter>(),
+        HandleKind::Writer,
+    )
 }
 
 /// Write data to a topic
@@ -690,7 +730,7 @@ pub unsafe extern "C" fn hdds_writer_write(
     data: *const c_void,
     len: usize,
 ) -> HddsError {
-    if writer.is_null() || data.is_null() {
+    if !audit::valid(writer, HandleKind::Writer) || data.is_null() {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -703,7 +743,72 @@ pub unsafe extern "C" fn hdds_writer_write(
 
     match writer_ref.write(&payload) {
         Ok(()) => HddsError::HddsOk,
-        Err(_) => HddsError::HddsOperationFailed,
+        Err(err) => {
+            set_last_error(&err);
+            HddsError::HddsOperationFailed
+        }
+    }
+}
+
+/// Dispose an instance, identified by its serialized key
+///
+/// `key` is the big-endian CDR of the topic type's key fields (the bytes
+/// hashed into the instance handle). Readers see the instance as
+/// NOT_ALIVE_DISPOSED until it is written again.
+///
+/// # Safety
+/// - `writer` must be a valid pointer returned from `hdds_writer_create`
+/// - `key` must point to valid memory of at least `len` bytes
+#[no_mangle]
+pub unsafe extern "C" fn hdds_writer_dispose(
+    writer: *mut HddsDataWriter,
+    key: *const c_void,
+    len: usize,
+) -> HddsError {
+    if !audit::valid(writer, HandleKind::Writer) || key.is_null() || len == 0 {
+        return HddsError::HddsInvalidArgument;
+    }
+
+    let writer_ref = &*writer.cast::<DataWriter<BytePayload>>();
+    let key_slice = std::slice::from_raw_parts(key.cast::<u8>(), len);
+
+    match writer_ref.dispose_serialized_key(key_slice) {
+        Ok(()) => HddsError::HddsOk,
+        Err(err) => {
+            set_last_error(&err);
+            HddsError::HddsOperationFailed
+        }
+    }
+}
+
+/// Unregister an instance, identified by its serialized key
+///
+/// Same key format as `hdds_writer_dispose`. Readers see the instance as
+/// NOT_ALIVE_NO_WRITERS, or NOT_ALIVE_DISPOSED when the writer's
+/// autodispose_unregistered_instances QoS is enabled (the default).
+///
+/// # Safety
+/// - `writer` must be a valid pointer returned from `hdds_writer_create`
+/// - `key` must point to valid memory of at least `len` bytes
+#[no_mangle]
+pub unsafe extern "C" fn hdds_writer_unregister(
+    writer: *mut HddsDataWriter,
+    key: *const c_void,
+    len: usize,
+) -> HddsError {
+    if !audit::valid(writer, HandleKind::Writer) || key.is_null() || len == 0 {
+        return HddsError::HddsInvalidArgument;
+    }
+
+    let writer_ref = &*writer.cast::<DataWriter<BytePayload>>();
+    let key_slice = std::slice::from_raw_parts(key.cast::<u8>(), len);
+
+    match writer_ref.unregister_serialized_key(key_slice) {
+        Ok(()) => HddsError::HddsOk,
+        Err(err) => {
+            set_last_error(&err);
+            HddsError::HddsOperationFailed
+        }
     }
 }
 
@@ -714,7 +819,7 @@ pub unsafe extern "C" fn hdds_writer_write(
 /// - Must not be called more than once with the same pointer
 #[no_mangle]
 pub unsafe extern "C" fn hdds_writer_destroy(writer: *mut HddsDataWriter) {
-    if !writer.is_null() {
+    if audit::release(writer, HandleKind::Writer) {
         let _ = Box::from_raw(writer.cast::<DataWriter<BytePayload>>());
     }
 }
@@ -731,7 +836,7 @@ pub unsafe extern "C" fn hdds_writer_create_with_qos(
     topic_name: *const c_char,
     qos: *const HddsQoS,
 ) -> *mut HddsDataWriter {
-    if participant.is_null() || topic_name.is_null() {
+    if !audit::valid(participant, HandleKind::Participant) || topic_name.is_null() {
         return ptr::null_mut();
     }
 
@@ -741,6 +846,9 @@ pub unsafe extern "C" fn hdds_writer_create_with_qos(
 
     let participant_ref = &*participant.cast::<Arc<Participant>>();
 
+    if !qos.is_null() && !audit::valid(qos, HandleKind::QoS) {
+        return ptr::null_mut();
+    }
     // Use provided QoS or default
     let qos_value = if qos.is_null() {
         QoS::default()
@@ -755,7 +863,10 @@ pub unsafe extern "C" fn hdds_writer_create_with_qos(
         return ptr::null_mut();
     };
 
-    Box::into_raw(Box::new(writer)).cast::<HddsDataWriter>()
+    audit::track(
+        Box::into_raw(Box::new(writer)).cast::<HddsDataWriter>(),
+        HandleKind::Writer,
+    )
 }
 
 /// Create a `DataWriter` for a topic with custom QoS and explicit type name.
@@ -776,7 +887,7 @@ pub unsafe extern "C" fn hdds_writer_create_with_type(
     type_name: *const c_char,
     qos: *const HddsQoS,
 ) -> *mut HddsDataWriter {
-    if participant.is_null() || topic_name.is_null() {
+    if !audit::valid(participant, HandleKind::Participant) || topic_name.is_null() {
         return ptr::null_mut();
     }
 
@@ -786,6 +897,9 @@ pub unsafe extern "C" fn hdds_writer_create_with_type(
 
     let participant_ref = &*participant.cast::<Arc<Participant>>();
 
+    if !qos.is_null() && !audit::valid(qos, HandleKind::QoS) {
+        return ptr::null_mut();
+    }
     let qos_value = if qos.is_null() {
         QoS::default()
     } else {
@@ -806,7 +920,10 @@ pub unsafe extern "C" fn hdds_writer_create_with_type(
                 else {
                     return ptr::null_mut();
                 };
-                return Box::into_raw(Box::new(writer)).cast::<HddsDataWriter>();
+                return audit::track(
+                    Box::into_raw(Box::new(writer)).cast::<HddsDataWriter>(),
+                    HandleKind::Writer,
+                );
             }
         }
     }
@@ -819,7 +936,10 @@ pub unsafe extern "C" fn hdds_writer_create_with_type(
         return ptr::null_mut();
     };
 
-    Box::into_raw(Box::new(writer)).cast::<HddsDataWriter>()
+    audit::track(
+        Box::into_raw(Box::new(writer)).cast::<HddsDataWriter>(),
+        HandleKind::Writer,
+    )
 }
 
 /// Create a `DataReader` for a topic
@@ -832,7 +952,7 @@ pub unsafe extern "C" fn hdds_reader_create(
     participant: *mut HddsParticipant,
     topic_name: *const c_char,
 ) -> *mut HddsDataReader {
-    if participant.is_null() || topic_name.is_null() {
+    if !audit::valid(participant, HandleKind::Participant) || topic_name.is_null() {
         return ptr::null_mut();
     }
 
@@ -849,7 +969,10 @@ pub unsafe extern "C" fn hdds_reader_create(
         return ptr::null_mut();
     };
 
-    Box::into_raw(Box::new(reader)).cast::<HddsDataReader>()
+    audit::track(
+        Box::into_raw(Box::new(reader)).cast::<HddsDataReader>(),
+        HandleKind::Reader,
+    )
 }
 
 /// Create a `DataReader` for a topic with custom QoS
@@ -864,7 +987,7 @@ pub unsafe extern "C" fn hdds_reader_create_with_qos(
     topic_name: *const c_char,
     qos: *const HddsQoS,
 ) -> *mut HddsDataReader {
-    if participant.is_null() || topic_name.is_null() {
+    if !audit::valid(participant, HandleKind::Participant) || topic_name.is_null() {
         return ptr::null_mut();
     }
 
@@ -874,6 +997,9 @@ pub unsafe extern "C" fn hdds_reader_create_with_qos(
 
     let participant_ref = &*participant.cast::<Arc<Participant>>();
 
+    if !qos.is_null() && !audit::valid(qos, HandleKind::QoS) {
+        return ptr::null_mut();
+    }
     // Use provided QoS or default
     let qos_value = if qos.is_null() {
         QoS::default()
@@ -888,7 +1014,10 @@ pub unsafe extern "C" fn hdds_reader_create_with_qos(
         return ptr::null_mut();
     };
 
-    Box::into_raw(Box::new(reader)).cast::<HddsDataReader>()
+    audit::track(
+        Box::into_raw(Box::new(reader)).cast::<HddsDataReader>(),
+        HandleKind::Reader,
+    )
 }
 
 /// Create a `DataReader` for a topic with custom QoS and explicit type name.
@@ -909,7 +1038,7 @@ pub unsafe extern "C" fn hdds_reader_create_with_type(
     type_name: *const c_char,
     qos: *const HddsQoS,
 ) -> *mut HddsDataReader {
-    if participant.is_null() || topic_name.is_null() {
+    if !audit::valid(participant, HandleKind::Participant) || topic_name.is_null() {
         return ptr::null_mut();
     }
 
@@ -919,6 +1048,9 @@ pub unsafe extern "C" fn hdds_reader_create_with_type(
 
     let participant_ref = &*participant.cast::<Arc<Participant>>();
 
+    if !qos.is_null() && !audit::valid(qos, HandleKind::QoS) {
+        return ptr::null_mut();
+    }
     let qos_value = if qos.is_null() {
         QoS::default()
     } else {
@@ -939,7 +1071,10 @@ pub unsafe extern "C" fn hdds_reader_create_with_type(
                 else {
                     return ptr::null_mut();
                 };
-                return Box::into_raw(Box::new(reader)).cast::<HddsDataReader>();
+                return audit::track(
+                    Box::into_raw(Box::new(reader)).cast::<HddsDataReader>(),
+                    HandleKind::Reader,
+                );
             }
         }
     }
@@ -952,11 +1087,17 @@ pub unsafe extern "C" fn hdds_reader_create_with_type(
         return ptr::null_mut();
     };
 
-    Box::into_raw(Box::new(reader)).cast::<HddsDataReader>()
+    audit::track(
+        Box::into_raw(Box::new(reader)).cast::<HddsDataReader>(),
+        HandleKind::Reader,
+    )
 }
 
 /// Take data from a topic (non-blocking)
 ///
+/// See `hdds_reader_take_with_info` to also get the writer GUID, timestamps
+/// and sequence number, and `hdds_reader_take_loan` to skip the copy.
+///
 /// # Safety
 /// - `reader` must be a valid pointer returned from `hdds_reader_create`
 /// - `data_out` must point to a valid buffer of at least `max_len` bytes
@@ -968,7 +1109,7 @@ pub unsafe extern "C" fn hdds_reader_take(
     max_len: usize,
     len_out: *mut usize,
 ) -> HddsError {
-    if reader.is_null() || data_out.is_null() || len_out.is_null() {
+    if !audit::valid(reader, HandleKind::Reader) || data_out.is_null() || len_out.is_null() {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -986,7 +1127,10 @@ pub unsafe extern "C" fn hdds_reader_take(
             HddsError::HddsOk
         }
         Ok(None) => HddsError::HddsNotFound,
-        Err(_) => HddsError::HddsOperationFailed,
+        Err(err) => {
+            set_last_error(&err);
+            HddsError::HddsOperationFailed
+        }
     }
 }
 
@@ -997,7 +1141,9 @@ pub unsafe extern "C" fn hdds_reader_take(
 /// - Must not be called more than once with the same pointer
 #[no_mangle]
 pub unsafe extern "C" fn hdds_reader_destroy(reader: *mut HddsDataReader) {
-    if !reader.is_null() {
+    if audit::release(reader, HandleKind::Reader) {
+        dynamic_ffi::forget_typed_reader(reader);
+        sample_ffi::forget_reader_loans(reader);
         let _ = Box::from_raw(reader.cast::<DataReader<BytePayload>>());
     }
 }
@@ -1010,7 +1156,7 @@ pub unsafe extern "C" fn hdds_reader_destroy(reader: *mut HddsDataReader) {
 pub unsafe extern "C" fn hdds_reader_get_status_condition(
     reader: *mut HddsDataReader,
 ) -> *const HddsStatusCondition {
-    if reader.is_null() {
+    if !audit::valid(reader, HandleKind::Reader) {
         return ptr::null();
     }
 
@@ -1090,7 +1236,10 @@ pub unsafe extern "C" fn hdds_guard_condition_get_trigger(
 /// The returned handle must be released with `hdds_waitset_destroy`.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_waitset_create() -> *mut HddsWaitSet {
-    Box::into_raw(Box::new(ForeignWaitSet::new())) as *mut HddsWaitSet
+    audit::track(
+        Box::into_raw(Box::new(ForeignWaitSet::new())).cast::<HddsWaitSet>(),
+        HandleKind::WaitSet,
+    )
 }
 
 /// Destroy a waitset.
@@ -1099,7 +1248,7 @@ pub unsafe extern "C" fn hdds_waitset_create() -> *mut HddsWaitSet {
 /// - `waitset` must be a valid handle from `hdds_waitset_create`, or NULL (no-op).
 #[no_mangle]
 pub unsafe extern "C" fn hdds_waitset_destroy(waitset: *mut HddsWaitSet) {
-    if !waitset.is_null() {
+    if audit::release(waitset, HandleKind::WaitSet) {
         let _ = Box::from_raw(waitset.cast::<ForeignWaitSet>());
     }
 }
@@ -1114,7 +1263,7 @@ pub unsafe extern "C" fn hdds_waitset_attach_status_condition(
     waitset: *mut HddsWaitSet,
     condition: *const HddsStatusCondition,
 ) -> HddsError {
-    if waitset.is_null() || condition.is_null() {
+    if !audit::valid(waitset, HandleKind::WaitSet) || condition.is_null() {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -1139,7 +1288,7 @@ pub unsafe extern "C" fn hdds_waitset_attach_guard_condition(
     waitset: *mut HddsWaitSet,
     condition: *const HddsGuardCondition,
 ) -> HddsError {
-    if waitset.is_null() || condition.is_null() {
+    if !audit::valid(waitset, HandleKind::WaitSet) || condition.is_null() {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -1164,7 +1313,7 @@ pub unsafe extern "C" fn hdds_waitset_detach_condition(
     waitset: *mut HddsWaitSet,
     condition: *const c_void,
 ) -> HddsError {
-    if waitset.is_null() || condition.is_null() {
+    if !audit::valid(waitset, HandleKind::WaitSet) || condition.is_null() {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -1175,6 +1324,37 @@ pub unsafe extern "C" fn hdds_waitset_detach_condition(
     }
 }
 
+/// Get a descriptor to watch a waitset from a `poll`/`epoll`/`select` loop.
+///
+/// The descriptor becomes readable when an attached condition triggers.
+/// Never read it: once it polls readable, call `hdds_waitset_wait` with a
+/// zero timeout to get the triggered conditions. It stays readable while
+/// `hdds_waitset_wait` returns conditions and is cleared by a call finding
+/// none, so a wakeup may be spurious (`out_len` of 0). The descriptor
+/// belongs to the waitset: do not close it, and remove it from the event
+/// loop before `hdds_waitset_destroy`.
+///
+/// Returns -1 if `waitset` is invalid, or on platforms without pollable
+/// descriptors (Windows).
+///
+/// # Safety
+/// - `waitset` must be a valid handle from `hdds_waitset_create`.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_waitset_get_fd(waitset: *mut HddsWaitSet) -> i32 {
+    if !audit::valid(waitset, HandleKind::WaitSet) {
+        return -1;
+    }
+
+    #[cfg(unix)]
+    {
+        (*waitset.cast::<ForeignWaitSet>()).raw_fd()
+    }
+    #[cfg(not(unix))]
+    {
+        -1
+    }
+}
+
 /// Wait for any attached condition to trigger.
 ///
 /// # Safety
@@ -1189,7 +1369,8 @@ pub unsafe extern "C" fn hdds_waitset_wait(
     max_conditions: usize,
     out_len: *mut usize,
 ) -> HddsError {
-    if waitset.is_null() || out_conditions.is_null() || out_len.is_null() {
+    if !audit::valid(waitset, HandleKind::WaitSet) || out_conditions.is_null() || out_len.is_null()
+    {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -1583,8 +1764,9 @@ pub unsafe extern "C" fn hdds_rmw_context_bind_topic_type(
     let ctx_ref = &*ctx.cast::<Arc<ForeignRmwContext>>();
     match ctx_ref.bind_topic_type(topic_str, type_support) {
         Ok(()) => HddsError::HddsOk,
-        Err(ApiError::Config) => HddsError::HddsOk,
-        Err(ApiError::Unsupported) => HddsError::HddsOk,
+        Err(ApiError::InvalidConfig { .. } | ApiError::UnsupportedOperation { .. }) => {
+            HddsError::HddsOk
+        }
         Err(err) => map_api_error(err),
     }
 }
@@ -1635,7 +1817,10 @@ pub unsafe extern "C" fn hdds_rmw_context_register_node(
     } else {
         match CStr::from_ptr(node_enclave).to_str() {
             Ok(value) => value,
-            Err(_) => return HddsError::HddsInvalidArgument,
+            Err(err) => {
+                set_last_error(&err);
+                return HddsError::HddsInvalidArgument;
+            }
         }
     };
 
@@ -1721,8 +1906,9 @@ pub unsafe extern "C" fn hdds_rmw_context_register_publisher_endpoint(
         qos,
     ) {
         Ok(()) => HddsError::HddsOk,
-        Err(ApiError::Config) => HddsError::HddsOk,
-        Err(ApiError::Unsupported) => HddsError::HddsOk,
+        Err(ApiError::InvalidConfig { .. } | ApiError::UnsupportedOperation { .. }) => {
+            HddsError::HddsOk
+        }
         Err(err) => map_api_error(err),
     }
 }
@@ -1816,8 +2002,9 @@ pub unsafe extern "C" fn hdds_rmw_context_register_subscription_endpoint(
         qos,
     ) {
         Ok(()) => HddsError::HddsOk,
-        Err(ApiError::Config) => HddsError::HddsOk,
-        Err(ApiError::Unsupported) => HddsError::HddsOk,
+        Err(ApiError::InvalidConfig { .. } | ApiError::UnsupportedOperation { .. }) => {
+            HddsError::HddsOk
+        }
         Err(err) => map_api_error(err),
     }
 }
@@ -2152,7 +2339,8 @@ pub unsafe extern "C" fn hdds_rmw_context_for_each_topic(
 
         let topic_c = match CString::new(topic) {
             Ok(value) => value,
-            Err(_) => {
+            Err(err) => {
+                set_last_error(&err);
                 status = HddsError::HddsInvalidArgument;
                 return;
             }
@@ -2160,7 +2348,8 @@ pub unsafe extern "C" fn hdds_rmw_context_for_each_topic(
 
         let type_c = match CString::new(type_name) {
             Ok(value) => value,
-            Err(_) => {
+            Err(err) => {
+                set_last_error(&err);
                 status = HddsError::HddsInvalidArgument;
                 return;
             }
@@ -2236,7 +2425,7 @@ pub unsafe extern "C" fn hdds_rmw_context_publish(
     let ctx_ref = &*ctx.cast::<Arc<ForeignRmwContext>>();
     let payload = match serialize_from_ros(type_support, ros_message) {
         Ok(p) => Some(p),
-        Err(ApiError::Config) => None,
+        Err(ApiError::InvalidConfig { .. }) => None,
         Err(err) => return map_api_error(err),
     };
 
@@ -2316,7 +2505,10 @@ pub unsafe extern "C" fn hdds_rmw_context_shm_try_take(
     let ctx_ref = &*ctx.cast::<Arc<ForeignRmwContext>>();
     let topic_str = match CStr::from_ptr(topic).to_str() {
         Ok(s) => s,
-        Err(_) => return HddsError::HddsInvalidArgument,
+        Err(err) => {
+            set_last_error(&err);
+            return HddsError::HddsInvalidArgument;
+        }
     };
 
     let buf = std::slice::from_raw_parts_mut(data_out.cast::<u8>(), max_len);
@@ -2433,7 +2625,10 @@ pub unsafe extern "C" fn hdds_rmw_deserialize_dynamic(
 
     let type_str = match CStr::from_ptr(type_name).to_str() {
         Ok(s) => s,
-        Err(_) => return HddsError::HddsInvalidArgument,
+        Err(err) => {
+            set_last_error(&err);
+            return HddsError::HddsInvalidArgument;
+        }
     };
 
     let slice = if data_len == 0 {
@@ -2446,7 +2641,10 @@ pub unsafe extern "C" fn hdds_rmw_deserialize_dynamic(
 
     match deserialize_dynamic_to_ros(type_str, slice, ros_message) {
         Ok(()) => HddsError::HddsOk,
-        Err(_) => HddsError::HddsOperationFailed,
+        Err(err) => {
+            set_last_error(&err);
+            HddsError::HddsOperationFailed
+        }
     }
 }
 
@@ -2973,6 +3171,20 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(unix)]
+    fn test_waitset_get_fd() {
+        unsafe {
+            assert_eq!(hdds_waitset_get_fd(ptr::null_mut()), -1);
+
+            let waitset = hdds_waitset_create();
+            let fd = hdds_waitset_get_fd(waitset);
+            assert!(fd >= 0);
+            assert_eq!(hdds_waitset_get_fd(waitset), fd);
+            hdds_waitset_destroy(waitset);
+        }
+    }
+
     #[test]
     #[cfg(feature = "xtypes")]
     fn test_register_type_support_and_hash() {
@@ -3130,4 +3342,40 @@ mod tests {
             hdds_participant_destroy(participant);
         }
     }
+
+    #[test]
+    fn test_writer_dispose_unregister() {
+        unsafe {
+            let key = 42u32.to_be_bytes();
+            let result =
+                hdds_writer_dispose(ptr::null_mut(), key.as_ptr().cast::<c_void>(), key.len());
+            assert_eq!(result, HddsError::HddsInvalidArgument);
+
+            let name = CString::new("test_participant_dispose").unwrap();
+            let participant = hdds_participant_create(name.as_ptr());
+            let topic = CString::new("test_topic_dispose").unwrap();
+            let writer = hdds_writer_create(participant, topic.as_ptr());
+            assert!(!writer.is_null());
+
+            assert_eq!(
+                hdds_writer_dispose(writer, ptr::null(), 4),
+                HddsError::HddsInvalidArgument
+            );
+            assert_eq!(
+                hdds_writer_unregister(writer, key.as_ptr().cast::<c_void>(), 0),
+                HddsError::HddsInvalidArgument
+            );
+            assert_eq!(
+                hdds_writer_dispose(writer, key.as_ptr().cast::<c_void>(), key.len()),
+                HddsError::HddsOk
+            );
+            assert_eq!(
+                hdds_writer_unregister(writer, key.as_ptr().cast::<c_void>(), key.len()),
+                HddsError::HddsOk
+            );
+
+            hdds_writer_destroy(writer);
+            hdds_participant_destroy(participant);
+        }
+    }
 }
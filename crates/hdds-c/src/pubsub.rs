@@ -15,6 +15,7 @@ use std::sync::Arc;
 use hdds::api::{Participant, Publisher, QoS, Subscriber};
 
 use super::{BytePayload, HddsDataReader, HddsDataWriter, HddsParticipant, HddsQoS};
+use crate::audit::{self, HandleKind};
 
 /// Opaque handle to a Publisher
 #[repr(C)]
@@ -59,12 +60,15 @@ pub unsafe extern "C" fn hdds_publisher_create_with_qos(
     participant: *mut HddsParticipant,
     qos: *const HddsQoS,
 ) -> *mut HddsPublisher {
-    if participant.is_null() {
+    if !audit::valid(participant, HandleKind::Participant) {
         return ptr::null_mut();
     }
 
     let participant_ref = &*participant.cast::<Arc<Participant>>();
 
+    if !qos.is_null() && !audit::valid(qos, HandleKind::QoS) {
+        return ptr::null_mut();
+    }
     let qos_value = if qos.is_null() {
         QoS::default()
     } else {
@@ -72,7 +76,10 @@ pub unsafe extern "C" fn hdds_publisher_create_with_qos(
     };
 
     match participant_ref.create_publisher(qos_value) {
-        Ok(publisher) => Box::into_raw(Box::new(publisher)).cast::<HddsPublisher>(),
+        Ok(publisher) => audit::track(
+            Box::into_raw(Box::new(publisher)).cast::<HddsPublisher>(),
+            HandleKind::Publisher,
+        ),
         Err(e) => {
             log::error!("Failed to create publisher: {:?}", e);
             ptr::null_mut()
@@ -86,7 +93,7 @@ pub unsafe extern "C" fn hdds_publisher_create_with_qos(
 /// - `publisher` must be a valid pointer or NULL
 #[no_mangle]
 pub unsafe extern "C" fn hdds_publisher_destroy(publisher: *mut HddsPublisher) {
-    if !publisher.is_null() {
+    if audit::release(publisher, HandleKind::Publisher) {
         let _ = Box::from_raw(publisher.cast::<Publisher>());
     }
 }
@@ -122,12 +129,15 @@ pub unsafe extern "C" fn hdds_subscriber_create_with_qos(
     participant: *mut HddsParticipant,
     qos: *const HddsQoS,
 ) -> *mut HddsSubscriber {
-    if participant.is_null() {
+    if !audit::valid(participant, HandleKind::Participant) {
         return ptr::null_mut();
     }
 
     let participant_ref = &*participant.cast::<Arc<Participant>>();
 
+    if !qos.is_null() && !audit::valid(qos, HandleKind::QoS) {
+        return ptr::null_mut();
+    }
     let qos_value = if qos.is_null() {
         QoS::default()
     } else {
@@ -135,7 +145,10 @@ pub unsafe extern "C" fn hdds_subscriber_create_with_qos(
     };
 
     match participant_ref.create_subscriber(qos_value) {
-        Ok(subscriber) => Box::into_raw(Box::new(subscriber)).cast::<HddsSubscriber>(),
+        Ok(subscriber) => audit::track(
+            Box::into_raw(Box::new(subscriber)).cast::<HddsSubscriber>(),
+            HandleKind::Subscriber,
+        ),
         Err(e) => {
             log::error!("Failed to create subscriber: {:?}", e);
             ptr::null_mut()
@@ -149,7 +162,7 @@ pub unsafe extern "C" fn hdds_subscriber_create_with_qos(
 /// - `subscriber` must be a valid pointer or NULL
 #[no_mangle]
 pub unsafe extern "C" fn hdds_subscriber_destroy(subscriber: *mut HddsSubscriber) {
-    if !subscriber.is_null() {
+    if audit::release(subscriber, HandleKind::Subscriber) {
         let _ = Box::from_raw(subscriber.cast::<Subscriber>());
     }
 }
@@ -189,7 +202,7 @@ pub unsafe extern "C" fn hdds_publisher_create_writer_with_qos(
     topic_name: *const c_char,
     qos: *const HddsQoS,
 ) -> *mut HddsDataWriter {
-    if publisher.is_null() || topic_name.is_null() {
+    if !audit::valid(publisher, HandleKind::Publisher) || topic_name.is_null() {
         return ptr::null_mut();
     }
 
@@ -199,6 +212,9 @@ pub unsafe extern "C" fn hdds_publisher_create_writer_with_qos(
 
     let publisher_ref = &*publisher.cast::<Publisher>();
 
+    if !qos.is_null() && !audit::valid(qos, HandleKind::QoS) {
+        return ptr::null_mut();
+    }
     let qos_value = if qos.is_null() {
         QoS::default()
     } else {
@@ -214,7 +230,10 @@ pub unsafe extern "C" fn hdds_publisher_create_writer_with_qos(
         .topic::<BytePayload>(topic_str)
         .and_then(|t| t.writer().qos(qos_value).build())
     {
-        Ok(writer) => Box::into_raw(Box::new(writer)).cast::<HddsDataWriter>(),
+        Ok(writer) => audit::track(
+            Box::into_raw(Box::new(writer)).cast::<HddsDataWriter>(),
+            HandleKind::Writer,
+        ),
         Err(e) => {
             log::error!("Failed to create writer from publisher: {:?}", e);
             ptr::null_mut()
@@ -257,7 +276,7 @@ pub unsafe extern "C" fn hdds_subscriber_create_reader_with_qos(
     topic_name: *const c_char,
     qos: *const HddsQoS,
 ) -> *mut HddsDataReader {
-    if subscriber.is_null() || topic_name.is_null() {
+    if !audit::valid(subscriber, HandleKind::Subscriber) || topic_name.is_null() {
         return ptr::null_mut();
     }
 
@@ -267,6 +286,9 @@ pub unsafe extern "C" fn hdds_subscriber_create_reader_with_qos(
 
     let subscriber_ref = &*subscriber.cast::<Subscriber>();
 
+    if !qos.is_null() && !audit::valid(qos, HandleKind::QoS) {
+        return ptr::null_mut();
+    }
     let qos_value = if qos.is_null() {
         QoS::default()
     } else {
@@ -282,7 +304,10 @@ pub unsafe extern "C" fn hdds_subscriber_create_reader_with_qos(
         .topic::<BytePayload>(topic_str)
         .and_then(|t| t.reader().qos(qos_value).build())
     {
-        Ok(reader) => Box::into_raw(Box::new(reader)).cast::<HddsDataReader>(),
+        Ok(reader) => audit::track(
+            Box::into_raw(Box::new(reader)).cast::<HddsDataReader>(),
+            HandleKind::Reader,
+        ),
         Err(e) => {
             log::error!("Failed to create reader from subscriber: {:?}", e);
             ptr::null_mut()
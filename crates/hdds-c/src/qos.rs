@@ -11,6 +11,7 @@ use std::ptr;
 
 use hdds::api::QoS;
 
+use crate::audit::{self, HandleKind};
 use crate::HddsError;
 
 /// Opaque handle to a QoS profile.
@@ -30,7 +31,7 @@ pub struct HddsQoS {
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_default() -> *mut HddsQoS {
     let qos = Box::new(QoS::default());
-    Box::into_raw(qos).cast::<HddsQoS>()
+    audit::track(Box::into_raw(qos).cast::<HddsQoS>(), HandleKind::QoS)
 }
 
 /// Create a best-effort QoS profile.
@@ -42,7 +43,7 @@ pub unsafe extern "C" fn hdds_qos_default() -> *mut HddsQoS {
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_best_effort() -> *mut HddsQoS {
     let qos = Box::new(QoS::best_effort());
-    Box::into_raw(qos).cast::<HddsQoS>()
+    audit::track(Box::into_raw(qos).cast::<HddsQoS>(), HandleKind::QoS)
 }
 
 /// Create a reliable QoS profile.
@@ -54,7 +55,7 @@ pub unsafe extern "C" fn hdds_qos_best_effort() -> *mut HddsQoS {
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_reliable() -> *mut HddsQoS {
     let qos = Box::new(QoS::reliable());
-    Box::into_raw(qos).cast::<HddsQoS>()
+    audit::track(Box::into_raw(qos).cast::<HddsQoS>(), HandleKind::QoS)
 }
 
 /// Create an RTI Connext-compatible QoS profile.
@@ -66,7 +67,7 @@ pub unsafe extern "C" fn hdds_qos_reliable() -> *mut HddsQoS {
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_rti_defaults() -> *mut HddsQoS {
     let qos = Box::new(QoS::rti_defaults());
-    Box::into_raw(qos).cast::<HddsQoS>()
+    audit::track(Box::into_raw(qos).cast::<HddsQoS>(), HandleKind::QoS)
 }
 
 /// Destroy a QoS profile.
@@ -76,7 +77,7 @@ pub unsafe extern "C" fn hdds_qos_rti_defaults() -> *mut HddsQoS {
 /// - Must not be called more than once with the same pointer.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_destroy(qos: *mut HddsQoS) {
-    if !qos.is_null() {
+    if audit::release(qos, HandleKind::QoS) {
         let _ = Box::from_raw(qos.cast::<QoS>());
     }
 }
@@ -112,7 +113,10 @@ pub unsafe extern "C" fn hdds_qos_load_fastdds_xml(path: *const c_char) -> *mut
     };
 
     match QoS::load_fastdds(path_str) {
-        Ok(qos) => Box::into_raw(Box::new(qos)).cast::<HddsQoS>(),
+        Ok(qos) => audit::track(
+            Box::into_raw(Box::new(qos)).cast::<HddsQoS>(),
+            HandleKind::QoS,
+        ),
         Err(err) => {
             eprintln!("[hdds_c] Failed to load FastDDS XML: {}", err);
             ptr::null_mut()
@@ -147,7 +151,10 @@ pub unsafe extern "C" fn hdds_qos_from_xml(path: *const c_char) -> *mut HddsQoS
     };
 
     match QoS::from_xml(path_str) {
-        Ok(qos) => Box::into_raw(Box::new(qos)).cast::<HddsQoS>(),
+        Ok(qos) => audit::track(
+            Box::into_raw(Box::new(qos)).cast::<HddsQoS>(),
+            HandleKind::QoS,
+        ),
         Err(err) => {
             eprintln!("[hdds_c] Failed to load QoS from XML: {}", err);
             ptr::null_mut()
@@ -165,7 +172,7 @@ pub unsafe extern "C" fn hdds_qos_from_xml(path: *const c_char) -> *mut HddsQoS
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_set_history_depth(qos: *mut HddsQoS, depth: u32) -> HddsError {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -180,7 +187,7 @@ pub unsafe extern "C" fn hdds_qos_set_history_depth(qos: *mut HddsQoS, depth: u3
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_set_history_keep_all(qos: *mut HddsQoS) -> HddsError {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -195,7 +202,7 @@ pub unsafe extern "C" fn hdds_qos_set_history_keep_all(qos: *mut HddsQoS) -> Hdd
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_set_volatile(qos: *mut HddsQoS) -> HddsError {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -210,7 +217,7 @@ pub unsafe extern "C" fn hdds_qos_set_volatile(qos: *mut HddsQoS) -> HddsError {
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_set_transient_local(qos: *mut HddsQoS) -> HddsError {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -225,7 +232,7 @@ pub unsafe extern "C" fn hdds_qos_set_transient_local(qos: *mut HddsQoS) -> Hdds
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_set_persistent(qos: *mut HddsQoS) -> HddsError {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -240,7 +247,7 @@ pub unsafe extern "C" fn hdds_qos_set_persistent(qos: *mut HddsQoS) -> HddsError
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_set_reliable(qos: *mut HddsQoS) -> HddsError {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -255,7 +262,7 @@ pub unsafe extern "C" fn hdds_qos_set_reliable(qos: *mut HddsQoS) -> HddsError {
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_set_best_effort(qos: *mut HddsQoS) -> HddsError {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -270,7 +277,7 @@ pub unsafe extern "C" fn hdds_qos_set_best_effort(qos: *mut HddsQoS) -> HddsErro
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_set_deadline_ns(qos: *mut HddsQoS, period_ns: u64) -> HddsError {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -288,7 +295,7 @@ pub unsafe extern "C" fn hdds_qos_set_lifespan_ns(
     qos: *mut HddsQoS,
     duration_ns: u64,
 ) -> HddsError {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -303,7 +310,7 @@ pub unsafe extern "C" fn hdds_qos_set_lifespan_ns(
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_set_ownership_shared(qos: *mut HddsQoS) -> HddsError {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -321,7 +328,7 @@ pub unsafe extern "C" fn hdds_qos_set_ownership_exclusive(
     qos: *mut HddsQoS,
     strength: i32,
 ) -> HddsError {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -341,7 +348,7 @@ pub unsafe extern "C" fn hdds_qos_add_partition(
     qos: *mut HddsQoS,
     partition: *const c_char,
 ) -> HddsError {
-    if qos.is_null() || partition.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) || partition.is_null() {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -367,7 +374,7 @@ pub unsafe extern "C" fn hdds_qos_add_partition(
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_is_reliable(qos: *const HddsQoS) -> bool {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return false;
     }
 
@@ -381,7 +388,7 @@ pub unsafe extern "C" fn hdds_qos_is_reliable(qos: *const HddsQoS) -> bool {
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_is_transient_local(qos: *const HddsQoS) -> bool {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return false;
     }
 
@@ -398,7 +405,7 @@ pub unsafe extern "C" fn hdds_qos_is_transient_local(qos: *const HddsQoS) -> boo
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_get_history_depth(qos: *const HddsQoS) -> u32 {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return 0;
     }
 
@@ -417,7 +424,7 @@ pub unsafe extern "C" fn hdds_qos_get_history_depth(qos: *const HddsQoS) -> u32
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_get_deadline_ns(qos: *const HddsQoS) -> u64 {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return u64::MAX;
     }
 
@@ -433,7 +440,7 @@ pub unsafe extern "C" fn hdds_qos_get_deadline_ns(qos: *const HddsQoS) -> u64 {
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_get_lifespan_ns(qos: *const HddsQoS) -> u64 {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return u64::MAX;
     }
 
@@ -447,7 +454,7 @@ pub unsafe extern "C" fn hdds_qos_get_lifespan_ns(qos: *const HddsQoS) -> u64 {
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_is_ownership_exclusive(qos: *const HddsQoS) -> bool {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return false;
     }
 
@@ -464,7 +471,7 @@ pub unsafe extern "C" fn hdds_qos_is_ownership_exclusive(qos: *const HddsQoS) ->
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_get_ownership_strength(qos: *const HddsQoS) -> i32 {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return 0;
     }
 
@@ -491,7 +498,7 @@ pub enum HddsLivelinessKind {
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_get_liveliness_kind(qos: *const HddsQoS) -> HddsLivelinessKind {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return HddsLivelinessKind::HddsLivelinessAutomatic;
     }
 
@@ -515,7 +522,7 @@ pub unsafe extern "C" fn hdds_qos_get_liveliness_kind(qos: *const HddsQoS) -> Hd
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_get_liveliness_lease_ns(qos: *const HddsQoS) -> u64 {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return u64::MAX;
     }
 
@@ -531,7 +538,7 @@ pub unsafe extern "C" fn hdds_qos_get_liveliness_lease_ns(qos: *const HddsQoS) -
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_get_time_based_filter_ns(qos: *const HddsQoS) -> u64 {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return 0;
     }
 
@@ -547,7 +554,7 @@ pub unsafe extern "C" fn hdds_qos_get_time_based_filter_ns(qos: *const HddsQoS)
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_get_latency_budget_ns(qos: *const HddsQoS) -> u64 {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return 0;
     }
 
@@ -561,7 +568,7 @@ pub unsafe extern "C" fn hdds_qos_get_latency_budget_ns(qos: *const HddsQoS) ->
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_get_transport_priority(qos: *const HddsQoS) -> i32 {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return 0;
     }
 
@@ -577,7 +584,7 @@ pub unsafe extern "C" fn hdds_qos_get_transport_priority(qos: *const HddsQoS) ->
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_get_max_samples(qos: *const HddsQoS) -> usize {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return usize::MAX;
     }
 
@@ -593,7 +600,7 @@ pub unsafe extern "C" fn hdds_qos_get_max_samples(qos: *const HddsQoS) -> usize
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_get_max_instances(qos: *const HddsQoS) -> usize {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return usize::MAX;
     }
 
@@ -609,7 +616,7 @@ pub unsafe extern "C" fn hdds_qos_get_max_instances(qos: *const HddsQoS) -> usiz
 /// - `qos` must be a valid pointer from `hdds_qos_*` functions.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_get_max_samples_per_instance(qos: *const HddsQoS) -> usize {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return usize::MAX;
     }
 
@@ -630,7 +637,7 @@ pub unsafe extern "C" fn hdds_qos_set_liveliness_automatic_ns(
     qos: *mut HddsQoS,
     lease_ns: u64,
 ) -> HddsError {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -649,7 +656,7 @@ pub unsafe extern "C" fn hdds_qos_set_liveliness_manual_participant_ns(
     qos: *mut HddsQoS,
     lease_ns: u64,
 ) -> HddsError {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -669,7 +676,7 @@ pub unsafe extern "C" fn hdds_qos_set_liveliness_manual_topic_ns(
     qos: *mut HddsQoS,
     lease_ns: u64,
 ) -> HddsError {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -688,7 +695,7 @@ pub unsafe extern "C" fn hdds_qos_set_time_based_filter_ns(
     qos: *mut HddsQoS,
     min_separation_ns: u64,
 ) -> HddsError {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -707,7 +714,7 @@ pub unsafe extern "C" fn hdds_qos_set_latency_budget_ns(
     qos: *mut HddsQoS,
     budget_ns: u64,
 ) -> HddsError {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -726,7 +733,7 @@ pub unsafe extern "C" fn hdds_qos_set_transport_priority(
     qos: *mut HddsQoS,
     priority: i32,
 ) -> HddsError {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -748,7 +755,7 @@ pub unsafe extern "C" fn hdds_qos_set_resource_limits(
     max_instances: usize,
     max_samples_per_instance: usize,
 ) -> HddsError {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return HddsError::HddsInvalidArgument;
     }
 
@@ -770,13 +777,13 @@ pub unsafe extern "C" fn hdds_qos_set_resource_limits(
 /// - The returned pointer must be freed with `hdds_qos_destroy`.
 #[no_mangle]
 pub unsafe extern "C" fn hdds_qos_clone(qos: *const HddsQoS) -> *mut HddsQoS {
-    if qos.is_null() {
+    if !audit::valid(qos, HandleKind::QoS) {
         return ptr::null_mut();
     }
 
     let qos_ref = &*qos.cast::<QoS>();
     let cloned = Box::new(qos_ref.clone());
-    Box::into_raw(cloned).cast::<HddsQoS>()
+    audit::track(Box::into_raw(cloned).cast::<HddsQoS>(), HandleKind::QoS)
 }
 
 #[cfg(test)]
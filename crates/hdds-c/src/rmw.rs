@@ -1,8 +1,11 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 // Copyright (c) 2025-2026 naskel.com
 
+use crate::last_error::set_last_error;
 use crate::{BytePayload, HddsError, HddsRmwQosProfile};
-use hdds::api::{DataReader, DataWriter, Error as ApiError, GuardCondition, QoS, StatusCondition};
+use hdds::api::{
+    DataReader, DataWriter, Error as ApiError, ErrorContext, GuardCondition, QoS, StatusCondition,
+};
 use hdds::core::rt::TopicMerger;
 #[cfg(feature = "xtypes")]
 use hdds::core::types::TypeObjectHandle;
@@ -173,9 +176,11 @@ pub struct ForeignRmwWaitSet {
 fn map_rosidl_error(err: RosidlError) -> ApiError {
     match err {
         RosidlError::NullTypeSupport | RosidlError::NullMembers | RosidlError::MissingHash => {
-            ApiError::Config
+            ApiError::config(format!("rosidl type support: {:?}", err))
+        }
+        RosidlError::UnsupportedType(_) => {
+            ApiError::unsupported(format!("rosidl type support: {:?}", err))
         }
-        RosidlError::UnsupportedType(_) => ApiError::Unsupported,
         RosidlError::InvalidUtf8(_)
         | RosidlError::BoundOverflow { .. }
         | RosidlError::Builder(_) => ApiError::SerializationError,
@@ -183,8 +188,11 @@ fn map_rosidl_error(err: RosidlError) -> ApiError {
 }
 
 #[cfg(not(feature = "xtypes"))]
-fn map_rosidl_error(_err: RosidlError) -> ApiError {
-    ApiError::Unsupported
+fn map_rosidl_error(err: RosidlError) -> ApiError {
+    ApiError::unsupported(format!(
+        "rosidl type support without the xtypes feature: {:?}",
+        err
+    ))
 }
 
 /// Generate a deterministic 16-byte pseudo-GUID from a topic name.
@@ -260,7 +268,7 @@ impl ForeignRmwContext {
         type_support: *const rosidl_message_type_support_t,
     ) -> Result<Arc<RosMessageMetadata>, ApiError> {
         if type_support.is_null() {
-            return Err(ApiError::Config);
+            return Err(ApiError::config("null type support"));
         }
 
         let mut cache = self
@@ -275,13 +283,16 @@ impl ForeignRmwContext {
         unsafe {
             let id_ptr = (*type_support).typesupport_identifier;
             if id_ptr.is_null() {
-                return Err(ApiError::Config);
+                return Err(ApiError::config("type support has no identifier"));
             }
             let id = CStr::from_ptr(id_ptr)
                 .to_str()
-                .map_err(|_| ApiError::Config)?;
+                .map_err(|_| ApiError::config("type support identifier is not UTF-8"))?;
             if id != "rosidl_typesupport_introspection_c" {
-                return Err(ApiError::Config);
+                return Err(ApiError::config(format!(
+                    "type support '{}' is not rosidl_typesupport_introspection_c",
+                    id
+                )));
             }
         }
 
@@ -355,9 +366,10 @@ impl ForeignRmwContext {
     ) -> Result<(), ApiError> {
         let metadata = match self.metadata_for(type_support) {
             Ok(meta) => meta,
-            Err(ApiError::Config) => {
+            Err(ApiError::InvalidConfig { .. }) => {
                 if type_support.is_null() {
-                    return Err(ApiError::Config);
+                    return Err(ApiError::config("null type support")
+                        .with_context(ErrorContext::new().topic(topic)));
                 }
 
                 #[allow(clippy::arc_with_non_send_sync)]
@@ -405,9 +417,10 @@ impl ForeignRmwContext {
     ) -> Result<(), ApiError> {
         let metadata = match self.metadata_for(type_support) {
             Ok(meta) => meta,
-            Err(ApiError::Config) => {
+            Err(ApiError::InvalidConfig { .. }) => {
                 if type_support.is_null() {
-                    return Err(ApiError::Config);
+                    return Err(ApiError::config("null type support")
+                        .with_context(ErrorContext::new().topic(topic)));
                 }
 
                 #[allow(clippy::arc_with_non_send_sync)]
@@ -775,7 +788,8 @@ impl ForeignRmwContext {
         type_support: *const rosidl_message_type_support_t,
     ) -> Result<(), ApiError> {
         if type_support.is_null() {
-            return Err(ApiError::Config);
+            return Err(ApiError::config("null type support")
+                .with_context(ErrorContext::new().topic(topic)));
         }
 
         let handle = {
@@ -806,13 +820,15 @@ impl ForeignRmwContext {
     }
 
     #[cfg(not(feature = "xtypes"))]
-    #[allow(unused_variables)]
     pub fn bind_topic_type(
         &self,
         topic: &str,
         type_support: *const rosidl_message_type_support_t,
     ) -> Result<(), ApiError> {
-        Err(ApiError::Unsupported)
+        Err(
+            ApiError::unsupported("binding a topic type without the xtypes feature")
+                .with_context(ErrorContext::new().topic(topic)),
+        )
     }
 
     pub fn destroy_writer_raw(&self, writer_ptr: *const c_void) -> Result<(), ApiError> {
@@ -849,7 +865,7 @@ impl ForeignRmwContext {
         payload: &BytePayload,
     ) -> Result<(), ApiError> {
         if writer_ptr.is_null() {
-            return Err(ApiError::Config);
+            return Err(ApiError::config("null writer"));
         }
 
         let writer_ref = unsafe { &*writer_ptr.cast::<DataWriter<BytePayload>>() };
@@ -948,7 +964,8 @@ impl ForeignRmwContext {
         let mut map = self.reader_map.lock().map_err(|_| ApiError::WouldBlock)?;
         if map.insert(reader_ptr, key).is_some() {
             let _ = self.detach_condition(key);
-            return Err(ApiError::Config);
+            return Err(ApiError::config("reader already attached")
+                .with_context(ErrorContext::new().entity("rmw waitset")));
         }
 
         Ok(key)
@@ -967,9 +984,13 @@ impl ForeignRmwContext {
             Ok(())
         } else if key == self.ctx.graph_guard_key() {
             // Graph guard is owned by the context; detaching is not allowed.
-            Err(ApiError::Config)
+            Err(
+                ApiError::config("the graph guard condition cannot be detached")
+                    .with_context(ErrorContext::new().entity("rmw waitset")),
+            )
         } else {
-            Err(ApiError::Config)
+            Err(ApiError::config("condition not attached")
+                .with_context(ErrorContext::new().entity("rmw waitset")))
         }
     }
 
@@ -978,7 +999,10 @@ impl ForeignRmwContext {
             let mut map = self.reader_map.lock().map_err(|_| ApiError::WouldBlock)?;
             match map.remove(&reader_ptr) {
                 Some(key) => key,
-                None => return Err(ApiError::Config),
+                None => {
+                    return Err(ApiError::config("reader not attached")
+                        .with_context(ErrorContext::new().entity("rmw waitset")))
+                }
             }
         };
 
@@ -1008,13 +1032,15 @@ impl ForeignRmwContext {
         reader_ptr: *const c_void,
     ) -> Result<Arc<StatusCondition>, ApiError> {
         if reader_ptr.is_null() {
-            return Err(ApiError::Config);
+            return Err(ApiError::config("null reader"));
         }
         let map = self
             .reader_status
             .lock()
             .map_err(|_| ApiError::WouldBlock)?;
-        map.get(&reader_ptr).cloned().ok_or(ApiError::Config)
+        map.get(&reader_ptr)
+            .cloned()
+            .ok_or_else(|| ApiError::config("reader has no status condition"))
     }
 
     pub fn wait(&self, timeout: Option<Duration>) -> Result<Vec<WaitHit>, ApiError> {
@@ -1058,7 +1084,7 @@ impl ForeignRmwContext {
         }
 
         if let Err(err) = self.detach_reader(reader_ptr) {
-            if !matches!(err, ApiError::Config) {
+            if !matches!(err, ApiError::InvalidConfig { .. }) {
                 return Err(err);
             }
         }
@@ -1259,8 +1285,12 @@ impl ForeignRmwWaitSet {
 }
 
 pub fn map_api_error(err: ApiError) -> HddsError {
-    match err {
-        ApiError::Config | ApiError::TypeMismatch | ApiError::QosIncompatible => {
+    // The C status code drops the message and its context: log them and
+    // keep them for `hdds_last_error_message`
+    log::debug!("[hdds-c] {}", err);
+    set_last_error(&err);
+    match err.root() {
+        ApiError::InvalidConfig { .. } | ApiError::TypeMismatch | ApiError::QosIncompatible => {
             HddsError::HddsInvalidArgument
         }
         ApiError::WouldBlock => HddsError::HddsOperationFailed,
@@ -1574,7 +1604,7 @@ impl fmt::Display for DeserializeError {
 fn map_deserialize_error(err: DeserializeError) -> ApiError {
     match err {
         DeserializeError::UnsupportedType(_) | DeserializeError::LengthExceeded(_) => {
-            ApiError::Unsupported
+            ApiError::unsupported(format!("ROS message deserialization: {:?}", err))
         }
         _ => ApiError::SerializationError,
     }
@@ -1635,11 +1665,13 @@ impl fmt::Display for SerializeError {
 fn map_serialize_error(err: SerializeError) -> ApiError {
     match err {
         SerializeError::UnsupportedType(_) | SerializeError::LengthExceeded(_) => {
-            ApiError::Unsupported
+            ApiError::unsupported(format!("ROS message serialization: {}", err))
         }
         SerializeError::MissingIntrospection
         | SerializeError::MissingSizeFunction
-        | SerializeError::MissingGetFunction => ApiError::Config,
+        | SerializeError::MissingGetFunction => {
+            ApiError::config(format!("ROS message serialization: {}", err))
+        }
         SerializeError::Rosidl(err) => map_deserialize_error(DeserializeError::from(err)),
         SerializeError::BufferOverflow => ApiError::SerializationError,
     }
@@ -1747,7 +1779,7 @@ pub fn deserialize_into_ros(
     ros_message: *mut c_void,
 ) -> Result<(), ApiError> {
     if ros_message.is_null() {
-        return Err(ApiError::Config);
+        return Err(ApiError::config("null ROS message"));
     }
 
     if data.is_empty() {
@@ -1769,7 +1801,7 @@ pub fn serialize_from_ros(
     ros_message: *const c_void,
 ) -> Result<BytePayload, ApiError> {
     if ros_message.is_null() {
-        return Err(ApiError::Config);
+        return Err(ApiError::config("null ROS message"));
     }
 
     unsafe {
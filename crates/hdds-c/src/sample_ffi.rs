@@ -0,0 +1,208 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Take with sample info and loaned samples for the C FFI.
+//!
+//! `hdds_reader_take` only returns the payload. These variants also fill an
+//! [`HddsSampleInfo`] (writer GUID, timestamps, sequence number), which the
+//! rmw layer maps onto `rmw_message_info_t`:
+//!
+//! ```c
+//! HddsSampleInfo info;
+//! size_t len = 0;
+//! if (hdds_reader_take_with_info(reader, buf, sizeof(buf), &len, &info) == HDDS_OK) {
+//!     // info.WRITER_GUID, info.SOURCE_TIMESTAMP_NS, info.SEQUENCE_NUMBER
+//! }
+//!
+//! // Zero-copy: the payload stays owned by the reader until returned
+//! const uint8_t *data = NULL;
+//! if (hdds_reader_take_loan(reader, &data, &len, &info) == HDDS_OK) {
+//!     process(data, len);
+//!     hdds_reader_return_loan(reader, data);
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use hdds::api::DataReader;
+use hdds::dds::SampleInfo;
+
+use crate::audit::{self, HandleKind};
+use crate::{BytePayload, HddsDataReader, HddsError};
+
+/// Metadata of a taken sample (C-compatible subset of the Rust `SampleInfo`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HddsSampleInfo {
+    /// GUID of the writer (12-byte prefix, 4-byte entity id); all zero if
+    /// unknown.
+    pub writer_guid: [u8; 16],
+    /// Writer-side timestamp (ns since the UNIX epoch); 0 if the writer sent
+    /// none.
+    pub source_timestamp_ns: i64,
+    /// Reception timestamp (ns since the UNIX epoch).
+    pub reception_timestamp_ns: i64,
+    /// Writer sequence number of the sample.
+    pub sequence_number: u64,
+    /// Whether the sample carries data (always true: only data samples are
+    /// taken).
+    pub valid_data: bool,
+}
+
+impl From<&SampleInfo> for HddsSampleInfo {
+    fn from(info: &SampleInfo) -> Self {
+        let to_i64 = |ns: u64| i64::try_from(ns).unwrap_or(i64::MAX);
+        Self {
+            writer_guid: info.writer_guid.map(|g| g.as_bytes()).unwrap_or_default(),
+            source_timestamp_ns: info.source_timestamp_ns.map(to_i64).unwrap_or(0),
+            reception_timestamp_ns: to_i64(info.timestamp_ns),
+            sequence_number: info.seq,
+            valid_data: true,
+        }
+    }
+}
+
+/// Payloads loaned out by `hdds_reader_take_loan`, per reader.
+fn loans() -> &'static Mutex<HashMap<usize, Vec<Vec<u8>>>> {
+    static LOANS: OnceLock<Mutex<HashMap<usize, Vec<Vec<u8>>>>> = OnceLock::new();
+    LOANS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Drop the loans still held on a reader (called by `hdds_reader_destroy`).
+pub(crate) fn forget_reader_loans(reader: *mut HddsDataReader) {
+    loans()
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .remove(&(reader as usize));
+}
+
+/// Take the next sample, writing its info to `info_out` when `HddsOk`.
+unsafe fn take_with_info(
+    reader: *mut HddsDataReader,
+    info_out: *mut HddsSampleInfo,
+) -> Result<Vec<u8>, HddsError> {
+    let reader_ref = &*reader.cast::<DataReader<BytePayload>>();
+    match reader_ref.take_with_info() {
+        Ok(Some((payload, info))) => {
+            *info_out = HddsSampleInfo::from(&info);
+            Ok(payload.data)
+        }
+        Ok(None) => Err(HddsError::HddsNotFound),
+        Err(_) => Err(HddsError::HddsOperationFailed),
+    }
+}
+
+/// Take data from a topic together with its sample info (non-blocking).
+///
+/// Same as `hdds_reader_take`, and `info_out` receives the writer GUID,
+/// timestamps and sequence number of the sample. If the payload does not
+/// fit in `max_len` bytes, returns `HddsOutOfMemory` with the required
+/// size in `len_out`; the sample is consumed.
+///
+/// # Safety
+/// - `reader` must be a valid pointer returned from `hdds_reader_create`
+/// - `data_out` must point to a valid buffer of at least `max_len` bytes
+/// - `len_out` and `info_out` must be valid pointers
+#[no_mangle]
+pub unsafe extern "C" fn hdds_reader_take_with_info(
+    reader: *mut HddsDataReader,
+    data_out: *mut u8,
+    max_len: usize,
+    len_out: *mut usize,
+    info_out: *mut HddsSampleInfo,
+) -> HddsError {
+    if !audit::valid(reader, HandleKind::Reader)
+        || data_out.is_null()
+        || len_out.is_null()
+        || info_out.is_null()
+    {
+        return HddsError::HddsInvalidArgument;
+    }
+
+    match take_with_info(reader, info_out) {
+        Ok(data) => {
+            *len_out = data.len();
+            if data.len() > max_len {
+                return HddsError::HddsOutOfMemory;
+            }
+            std::ptr::copy_nonoverlapping(data.as_ptr(), data_out, data.len());
+            HddsError::HddsOk
+        }
+        Err(err) => err,
+    }
+}
+
+/// Take the next sample without copying its payload (non-blocking).
+///
+/// On `HddsOk`, `*data_out` points to `*len_out` bytes owned by the reader
+/// and `info_out` receives the sample info. The payload stays valid until
+/// passed to `hdds_reader_return_loan` or the reader is destroyed. Returns
+/// `HddsNotFound` if no sample is available.
+///
+/// # Safety
+/// - `reader` must be a valid pointer returned from `hdds_reader_create`
+/// - `data_out`, `len_out` and `info_out` must be valid pointers
+#[no_mangle]
+pub unsafe extern "C" fn hdds_reader_take_loan(
+    reader: *mut HddsDataReader,
+    data_out: *mut *const u8,
+    len_out: *mut usize,
+    info_out: *mut HddsSampleInfo,
+) -> HddsError {
+    if !audit::valid(reader, HandleKind::Reader)
+        || data_out.is_null()
+        || len_out.is_null()
+        || info_out.is_null()
+    {
+        return HddsError::HddsInvalidArgument;
+    }
+    *data_out = std::ptr::null();
+    *len_out = 0;
+
+    match take_with_info(reader, info_out) {
+        Ok(data) => {
+            // The heap buffer does not move when the Vec is stored
+            *data_out = data.as_ptr();
+            *len_out = data.len();
+            loans()
+                .lock()
+                .unwrap_or_else(|err| err.into_inner())
+                .entry(reader as usize)
+                .or_default()
+                .push(data);
+            HddsError::HddsOk
+        }
+        Err(err) => err,
+    }
+}
+
+/// Return a payload loaned by `hdds_reader_take_loan`.
+///
+/// Returns `HddsNotFound` if `data` is not a loan of this reader.
+///
+/// # Safety
+/// - `reader` must be a valid pointer returned from `hdds_reader_create`
+/// - `data` must not be used after this call
+#[no_mangle]
+pub unsafe extern "C" fn hdds_reader_return_loan(
+    reader: *mut HddsDataReader,
+    data: *const u8,
+) -> HddsError {
+    if !audit::valid(reader, HandleKind::Reader) || data.is_null() {
+        return HddsError::HddsInvalidArgument;
+    }
+
+    let mut loans = loans().lock().unwrap_or_else(|err| err.into_inner());
+    let Some(held) = loans.get_mut(&(reader as usize)) else {
+        return HddsError::HddsNotFound;
+    };
+    let Some(index) = held.iter().position(|loan| loan.as_ptr() == data) else {
+        return HddsError::HddsNotFound;
+    };
+    held.swap_remove(index);
+    if held.is_empty() {
+        loans.remove(&(reader as usize));
+    }
+    HddsError::HddsOk
+}
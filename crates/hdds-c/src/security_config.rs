@@ -4,7 +4,11 @@
 //! DDS Security configuration C FFI bindings.
 //!
 //! Provides opaque handle and setter API for configuring DDS Security v1.1.
-//! The security config is attached to a `HddsParticipantConfig` before building.
+//! The security config is attached to a `HddsParticipantConfig` before
+//! building, or passed to `hdds_participant_create_secure`.
+//!
+//! Participants only apply it when the library is built with the `security`
+//! feature (`HDDS_WITH_SECURITY` in `hdds.h`).
 //!
 //! # Usage from C
 //!
@@ -16,6 +20,9 @@
 //! hdds_security_config_enable_encryption(sec, true);
 //! hdds_config_set_security(cfg, sec); // attach to participant config
 //! // sec is consumed, do NOT destroy it
+//!
+//! // or directly:
+//! HddsParticipant* p = hdds_participant_create_secure("app", HDDS_TRANSPORT_UDP_MULTICAST, sec);
 //! ```
 
 use std::ffi::CStr;
@@ -23,6 +30,8 @@ use std::os::raw::c_char;
 use std::path::PathBuf;
 
 use crate::HddsError;
+#[cfg(feature = "security")]
+use crate::{audit, HandleKind, HddsParticipant, HddsTransportMode};
 
 /// Internal security config accumulator with pub fields.
 /// Translated to `SecurityConfig` at participant build time.
@@ -33,6 +42,7 @@ pub(crate) struct SecurityConfigInner {
     pub governance_xml: Option<PathBuf>,
     pub permissions_xml: Option<PathBuf>,
     pub enable_encryption: bool,
+    pub master_key: Option<PathBuf>,
     pub enable_audit_log: bool,
     pub audit_log_path: Option<PathBuf>,
     pub require_authentication: bool,
@@ -48,6 +58,7 @@ impl SecurityConfigInner {
             governance_xml: None,
             permissions_xml: None,
             enable_encryption: false,
+            master_key: None,
             enable_audit_log: false,
             audit_log_path: None,
             require_authentication: true,
@@ -56,6 +67,43 @@ impl SecurityConfigInner {
     }
 }
 
+#[cfg(feature = "security")]
+impl SecurityConfigInner {
+    /// Validate into the participant's `SecurityConfig`.
+    ///
+    /// Fails with `Error::InvalidConfig` when a required path is missing or a file
+    /// does not exist.
+    pub(crate) fn into_security_config(self) -> Result<hdds::SecurityConfig, hdds::api::Error> {
+        let mut builder = hdds::SecurityConfig::builder();
+        if let Some(p) = self.identity_certificate {
+            builder = builder.identity_certificate(p);
+        }
+        if let Some(p) = self.private_key {
+            builder = builder.private_key(p);
+        }
+        if let Some(p) = self.ca_certificates {
+            builder = builder.ca_certificates(p);
+        }
+        if let Some(p) = self.governance_xml {
+            builder = builder.governance_xml(p);
+        }
+        if let Some(p) = self.permissions_xml {
+            builder = builder.permissions_xml(p);
+        }
+        builder = builder.enable_encryption(self.enable_encryption);
+        if let Some(p) = self.master_key {
+            builder = builder.master_key(p);
+        }
+        builder = builder.enable_audit_log(self.enable_audit_log);
+        if let Some(p) = self.audit_log_path {
+            builder = builder.audit_log_path(p);
+        }
+        builder = builder.require_authentication(self.require_authentication);
+        builder = builder.check_certificate_revocation(self.check_certificate_revocation);
+        builder.build()
+    }
+}
+
 /// Opaque handle to a security configuration.
 #[repr(C)]
 pub struct HddsSecurityConfig {
@@ -214,6 +262,30 @@ pub unsafe extern "C" fn hdds_security_config_enable_encryption(
     HddsError::HddsOk
 }
 
+/// Set the master key path (optional, 32 raw bytes).
+///
+/// Protected topics get keys derived from it, so participants exchanging
+/// them must share it. Without one, encrypted topics are only readable
+/// within the participant.
+///
+/// # Safety
+/// - `config` must be valid. `path` must be null-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn hdds_security_config_set_master_key(
+    config: *mut HddsSecurityConfig,
+    path: *const c_char,
+) -> HddsError {
+    if config.is_null() || path.is_null() {
+        return HddsError::HddsInvalidArgument;
+    }
+    let Ok(path_str) = CStr::from_ptr(path).to_str() else {
+        return HddsError::HddsInvalidArgument;
+    };
+    let inner = &mut *config.cast::<SecurityConfigInner>();
+    inner.master_key = Some(PathBuf::from(path_str));
+    HddsError::HddsOk
+}
+
 /// Enable or disable audit logging (default: false).
 ///
 /// # Safety
@@ -287,6 +359,75 @@ pub unsafe extern "C" fn hdds_security_config_check_revocation(
     HddsError::HddsOk
 }
 
+// =============================================================================
+// Participant
+// =============================================================================
+
+/// Create a secured Participant with the specified transport mode.
+///
+/// Same as `hdds_participant_create_with_transport`, with DDS Security
+/// configured from `security`. **This consumes the security config
+/// handle**, also on failure. Do NOT call `hdds_security_config_destroy`
+/// after this.
+///
+/// Requires the `security` feature to be enabled at compile time.
+///
+/// # Returns
+/// Opaque participant handle, or NULL if the name is invalid, the security
+/// configuration is incomplete (identity certificate, private key and CA
+/// are required) or a security file cannot be loaded.
+///
+/// # Safety
+/// - `name` must be a valid null-terminated C string.
+/// - `security` must be a valid pointer from `hdds_security_config_create`.
+/// - The returned handle must be released with `hdds_participant_destroy`.
+#[cfg(feature = "security")]
+#[no_mangle]
+pub unsafe extern "C" fn hdds_participant_create_secure(
+    name: *const c_char,
+    transport: HddsTransportMode,
+    security: *mut HddsSecurityConfig,
+) -> *mut HddsParticipant {
+    if security.is_null() {
+        return std::ptr::null_mut();
+    }
+    let security = *Box::from_raw(security.cast::<SecurityConfigInner>());
+    if name.is_null() {
+        return std::ptr::null_mut();
+    }
+    let Ok(name_str) = CStr::from_ptr(name).to_str() else {
+        return std::ptr::null_mut();
+    };
+
+    let security = match security.into_security_config() {
+        Ok(security) => security,
+        Err(e) => {
+            log::error!(
+                "hdds_participant_create_secure: invalid security config: {:?}",
+                e
+            );
+            return std::ptr::null_mut();
+        }
+    };
+
+    match crate::participant_builder(name_str, transport)
+        .with_security(security)
+        .build()
+    {
+        Ok(participant) => audit::track(
+            Box::into_raw(Box::new(participant)).cast::<HddsParticipant>(),
+            HandleKind::Participant,
+        ),
+        Err(e) => {
+            log::error!(
+                "hdds_participant_create_secure: failed to create participant: {:?}",
+                e
+            );
+            std::ptr::null_mut()
+        }
+    }
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -409,4 +550,39 @@ mod tests {
             hdds_security_config_destroy(config);
         }
     }
+
+    #[cfg(feature = "security")]
+    #[test]
+    fn test_participant_create_secure_rejects_invalid_config() {
+        unsafe {
+            let name = CString::new("secure_participant").unwrap();
+            assert!(hdds_participant_create_secure(
+                name.as_ptr(),
+                HddsTransportMode::HddsTransportIntraProcess,
+                ptr::null_mut(),
+            )
+            .is_null());
+
+            // Identity files are required and must exist (handle consumed)
+            let config = hdds_security_config_create();
+            assert!(hdds_participant_create_secure(
+                name.as_ptr(),
+                HddsTransportMode::HddsTransportIntraProcess,
+                config,
+            )
+            .is_null());
+
+            let config = hdds_security_config_create();
+            let missing = CString::new("/nonexistent/participant.pem").unwrap();
+            hdds_security_config_set_identity_cert(config, missing.as_ptr());
+            hdds_security_config_set_private_key(config, missing.as_ptr());
+            hdds_security_config_set_ca_cert(config, missing.as_ptr());
+            assert!(hdds_participant_create_secure(
+                name.as_ptr(),
+                HddsTransportMode::HddsTransportIntraProcess,
+                config,
+            )
+            .is_null());
+        }
+    }
 }
@@ -36,6 +36,7 @@ use hdds::TcpRole;
 use hdds::TransportMode;
 use hdds::TransportPreference;
 
+use crate::audit::{self, HandleKind};
 use crate::{HddsError, HddsParticipant};
 
 // =============================================================================
@@ -116,6 +117,8 @@ pub enum HddsTransportPreference {
     HddsTransportPrefShmPreferred = 3,
     /// SHM for local, TCP for remote.
     HddsTransportPrefShmLocalTcpRemote = 4,
+    /// Prefer Unix domain sockets for local, UDP for remote.
+    HddsTransportPrefUdsPreferred = 5,
 }
 
 // =============================================================================
@@ -213,7 +216,7 @@ pub unsafe extern "C" fn hdds_config_set_participant_id(
     HddsError::HddsOk
 }
 
-/// Set the transport mode (IntraProcess or UdpMulticast).
+/// Set the transport mode (IntraProcess, UdpMulticast or Tcp).
 ///
 /// # Safety
 /// - `config` must be a valid pointer from `hdds_config_create`.
@@ -229,6 +232,8 @@ pub unsafe extern "C" fn hdds_config_set_transport_mode(
     inner.transport_mode = match mode {
         crate::HddsTransportMode::HddsTransportIntraProcess => TransportMode::IntraProcess,
         crate::HddsTransportMode::HddsTransportUdpMulticast => TransportMode::UdpMulticast,
+        crate::HddsTransportMode::HddsTransportTcp => TransportMode::Tcp,
+        crate::HddsTransportMode::HddsTransportLoopbackBus => TransportMode::LoopbackBus,
     };
     HddsError::HddsOk
 }
@@ -450,6 +455,7 @@ pub unsafe extern "C" fn hdds_config_set_transport_preference(
         HddsTransportPreference::HddsTransportPrefShmLocalTcpRemote => {
             TransportPreference::ShmLocalTcpRemote
         }
+        HddsTransportPreference::HddsTransportPrefUdsPreferred => TransportPreference::UdsPreferred,
     };
     HddsError::HddsOk
 }
@@ -537,32 +543,7 @@ pub unsafe extern "C" fn hdds_config_build(
     // Apply security configuration if provided
     #[cfg(feature = "security")]
     if let Some(sec) = inner.security_config {
-        let sec_builder = hdds::SecurityConfig::builder();
-        let mut sec_builder = sec_builder;
-        if let Some(p) = sec.identity_certificate {
-            sec_builder = sec_builder.identity_certificate(p);
-        }
-        if let Some(p) = sec.private_key {
-            sec_builder = sec_builder.private_key(p);
-        }
-        if let Some(p) = sec.ca_certificates {
-            sec_builder = sec_builder.ca_certificates(p);
-        }
-        if let Some(p) = sec.governance_xml {
-            sec_builder = sec_builder.governance_xml(p);
-        }
-        if let Some(p) = sec.permissions_xml {
-            sec_builder = sec_builder.permissions_xml(p);
-        }
-        sec_builder = sec_builder.enable_encryption(sec.enable_encryption);
-        sec_builder = sec_builder.enable_audit_log(sec.enable_audit_log);
-        if let Some(p) = sec.audit_log_path {
-            sec_builder = sec_builder.audit_log_path(p);
-        }
-        sec_builder = sec_builder.require_authentication(sec.require_authentication);
-        sec_builder = sec_builder.check_certificate_revocation(sec.check_certificate_revocation);
-
-        match sec_builder.build() {
+        match sec.into_security_config() {
             Ok(security_config) => {
                 builder = builder.with_security(security_config);
             }
@@ -574,7 +555,10 @@ pub unsafe extern "C" fn hdds_config_build(
     }
 
     match builder.build() {
-        Ok(participant) => Box::into_raw(Box::new(participant)).cast::<HddsParticipant>(),
+        Ok(participant) => audit::track(
+            Box::into_raw(Box::new(participant)).cast::<HddsParticipant>(),
+            HandleKind::Participant,
+        ),
         Err(e) => {
             log::error!("hdds_config_build: failed to create participant: {:?}", e);
             ptr::null_mut()
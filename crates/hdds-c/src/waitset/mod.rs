@@ -5,6 +5,8 @@
 
 use hdds::api::{Condition, Error as ApiError, GuardCondition, StatusCondition, WaitSet};
 use std::collections::HashMap;
+#[cfg(unix)]
+use std::os::fd::{AsRawFd, RawFd};
 use std::os::raw::c_void;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
@@ -134,6 +136,12 @@ impl ForeignWaitSet {
         Ok(pointers)
     }
 
+    /// Descriptor polling readable when an attached condition triggers.
+    #[cfg(unix)]
+    pub fn raw_fd(&self) -> RawFd {
+        self.waitset.as_raw_fd()
+    }
+
     fn attach_condition(
         &self,
         kind: ConditionKind,
@@ -202,6 +210,38 @@ mod tests {
         assert!(result.is_empty());
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn fd_wait_collects_triggered_guard() {
+        let waitset = ForeignWaitSet::new();
+        let guard = Arc::new(GuardCondition::new());
+        let raw = Arc::into_raw(guard.clone()) as *const c_void;
+        waitset
+            .attach_guard(guard.clone(), raw)
+            .expect("attach guard");
+
+        let mut pollfd = libc::pollfd {
+            fd: waitset.raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        guard.set_trigger_value(true);
+        // SAFETY: pollfd is a valid stack-allocated structure.
+        assert_eq!(unsafe { libc::poll(&mut pollfd, 1, 1000) }, 1);
+        let triggered = waitset.wait(Some(Duration::ZERO)).expect("wait");
+        assert_eq!(triggered, [raw]);
+
+        guard.set_trigger_value(false);
+        assert!(waitset.wait(Some(Duration::ZERO)).expect("wait").is_empty());
+        // SAFETY: as above.
+        assert_eq!(unsafe { libc::poll(&mut pollfd, 1, 0) }, 0);
+
+        waitset.detach(raw).expect("detach");
+        unsafe {
+            Arc::from_raw(raw.cast::<GuardCondition>());
+        }
+    }
+
     #[test]
     fn detach_unknown_returns_error() {
         let waitset = ForeignWaitSet::new();
@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+// Sample info and loaned takes through the C API, end to end over the
+// intra-process transport. Runs in its own process so the global slab pool
+// is not shared with the rmw tests in the library.
+
+use hdds_c::{
+    hdds_participant_create_with_transport, hdds_participant_destroy, hdds_reader_create,
+    hdds_reader_destroy, hdds_reader_return_loan, hdds_reader_take_loan,
+    hdds_reader_take_with_info, hdds_writer_create, hdds_writer_destroy, hdds_writer_write,
+    HddsError, HddsSampleInfo, HddsTransportMode,
+};
+use std::ffi::{c_void, CString};
+use std::ptr;
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[test]
+fn test_take_with_info_and_loans() {
+    unsafe {
+        let name = CString::new("test_sample_info_ffi").unwrap();
+        let participant = hdds_participant_create_with_transport(
+            name.as_ptr(),
+            HddsTransportMode::HddsTransportIntraProcess,
+        );
+        assert!(!participant.is_null());
+        let topic = CString::new("test_sample_info_topic").unwrap();
+        let reader = hdds_reader_create(participant, topic.as_ptr());
+        let writer = hdds_writer_create(participant, topic.as_ptr());
+        assert!(!reader.is_null() && !writer.is_null());
+
+        let mut buf = [0u8; 16];
+        let mut len = 0usize;
+        let mut info = HddsSampleInfo::default();
+        assert_eq!(
+            hdds_reader_take_with_info(
+                reader,
+                buf.as_mut_ptr(),
+                buf.len(),
+                &mut len,
+                ptr::null_mut()
+            ),
+            HddsError::HddsInvalidArgument
+        );
+        assert_eq!(
+            hdds_reader_take_with_info(reader, buf.as_mut_ptr(), buf.len(), &mut len, &mut info),
+            HddsError::HddsNotFound
+        );
+
+        for payload in [b"first".as_slice(), b"second".as_slice()] {
+            assert_eq!(
+                hdds_writer_write(writer, payload.as_ptr().cast::<c_void>(), payload.len()),
+                HddsError::HddsOk
+            );
+        }
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        loop {
+            match hdds_reader_take_with_info(
+                reader,
+                buf.as_mut_ptr(),
+                buf.len(),
+                &mut len,
+                &mut info,
+            ) {
+                HddsError::HddsOk => break,
+                HddsError::HddsNotFound if Instant::now() < deadline => {
+                    thread::sleep(Duration::from_millis(10));
+                }
+                err => panic!("take_with_info failed: {:?}", err),
+            }
+        }
+        assert_eq!(&buf[..len], b"first");
+        assert!(info.valid_data);
+        assert!(info.reception_timestamp_ns > 0);
+        let first_seq = info.sequence_number;
+
+        let mut data: *const u8 = ptr::null();
+        assert_eq!(
+            hdds_reader_take_loan(reader, &mut data, &mut len, &mut info),
+            HddsError::HddsOk
+        );
+        assert_eq!(std::slice::from_raw_parts(data, len), b"second");
+        assert_eq!(info.sequence_number, first_seq + 1);
+
+        assert_eq!(hdds_reader_return_loan(reader, data), HddsError::HddsOk);
+        assert_eq!(
+            hdds_reader_return_loan(reader, data),
+            HddsError::HddsNotFound
+        );
+        assert_eq!(
+            hdds_reader_take_loan(reader, &mut data, &mut len, &mut info),
+            HddsError::HddsNotFound
+        );
+        assert!(data.is_null());
+
+        hdds_writer_destroy(writer);
+        hdds_reader_destroy(reader);
+        hdds_participant_destroy(participant);
+    }
+}
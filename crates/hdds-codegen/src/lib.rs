@@ -5,7 +5,11 @@ extern crate proc_macro;
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, Data, DeriveInput, Fields, GenericArgument, PathArguments, Type};
+use syn::{
+    parse_macro_input, Data, DataEnum, DeriveInput, Fields, GenericArgument, PathArguments, Type,
+};
+
+mod service;
 
 /// Field kind for code generation
 #[derive(Clone)]
@@ -20,14 +24,70 @@ enum FieldKind {
     String,
     /// Vec<u8> type (variable size byte array)
     ByteVec,
+    /// Nested `#[derive(DDS)]` struct or enum, `[T; N]`, `Vec<T>` or
+    /// `Option<T>` (XTypes optional member), encoded through `IdlCdr`
+    Composite {
+        field_type: proc_macro2::TokenStream,
+        type_id: proc_macro2::TokenStream,
+        optional: bool,
+    },
+}
+
+/// Code generated for a struct or an enum, assembled by `derive_dds`
+struct Expansion {
+    /// `TypeDescriptor` initializer
+    descriptor: proc_macro2::TokenStream,
+    /// `CompleteTypeObject` expression
+    type_object: proc_macro2::TokenStream,
+    /// Methods of the `IdlCdr` impl
+    idl_methods: proc_macro2::TokenStream,
+    /// `DDS::get_fields` entries (filter expressions, query conditions)
+    field_values: Vec<proc_macro2::TokenStream>,
+    has_key: bool,
 }
 
 /// `#[derive(DDS)]` macro: generates `TypeDescriptor` + encode/decode impl
 ///
-/// Supports:
+/// Supports structs with named fields of these types:
 /// - Primitive types: i8, i16, i32, i64, u8, u16, u32, u64, f32, f64, bool
 /// - String type: variable-length UTF-8 string
 /// - Vec<u8>: variable-length byte array
+/// - Other `#[derive(DDS)]` structs and enums (and types generated by
+///   `hdds-gen rust`)
+/// - `[T; N]` arrays and `Vec<T>` sequences of any of these
+/// - `Option<T>`: XTypes optional member (`IS_OPTIONAL`), encoded as a
+///   presence flag followed by the value
+///
+/// C-like enums derive too: they are encoded as their `u32` discriminant
+/// and described by an enumerated TypeObject.
+///
+/// Alongside the `DDS` impl, the type implements
+/// `hdds::core::ser::idl::IdlCdr` and gets an inherent `type_object()`, which
+/// is what enclosing types encode and describe it with. Primitive and
+/// `String` fields are returned by `get_fields()`, so content filters and
+/// query conditions can refer to them by name.
+///
+/// Fields marked `#[key]` (or `#[dds(key)]`, the IDL `@key` annotation) make
+/// the type keyed: `compute_key()` hashes them into the instance handle and
+/// the TypeObject carries `IS_KEY` on those members. A nested struct used
+/// as a key contributes its own key fields, or all of its fields if it has
+/// none.
+///
+/// Other field-level `#[dds(...)]` options:
+/// - `id = N`: member ID in the TypeObject (IDL `@id`); members without one
+///   take the previous member's ID + 1, starting at 0. Pin IDs to keep them
+///   stable when fields are reordered or inserted
+/// - `bound = N`: maximum length of a `String` or `Vec<T>` field (or an
+///   `Option` of one), announced as a bounded string/sequence and checked
+///   when encoding and decoding
+///
+/// Type-level `#[dds(...)]` options:
+/// - `topic = "name"`: default topic, used by `Participant::create_writer_for`
+///   and `create_reader_for`; also emitted as the `DDS_TOPIC` constant
+/// - `qos = "reliable"` (or `"best_effort"`, `"rti_defaults"`) or any
+///   expression evaluating to `hdds::QoS`: default QoS for those endpoints
+/// - `ros_package = "pkg"`: package of the `ROS_TYPE_NAME` constant
+///   (`pkg::msg::dds_::Type_`), the crate name by default
 ///
 /// # Panics
 ///
@@ -38,15 +98,34 @@ enum FieldKind {
 /// use hdds_codegen::DDS;
 ///
 /// #[derive(DDS)]
+/// enum PixelFormat {
+///     Mono8,
+///     Rgb8,
+/// }
+///
+/// #[derive(DDS)]
+/// struct Roi {
+///     x: u16,
+///     y: u16,
+/// }
+///
+/// #[derive(DDS)]
+/// #[dds(topic = "camera/image_meta", qos = "reliable", ros_package = "vision_msgs")]
 /// struct ImageMeta {
+///     #[key]
 ///     image_id: u32,
+///     #[dds(id = 10)]
 ///     width: u16,
 ///     height: u16,
-///     format: String,      // Variable-length string
-///     data: Vec<u8>,       // Variable-length byte array
+///     format: PixelFormat,     // Nested enum
+///     rois: Vec<Roi>,          // Sequence of nested structs
+///     exposure: Option<f32>,   // Optional member
+///     #[dds(bound = 64)]
+///     label: String,           // Bounded string
+///     data: Vec<u8>,           // Variable-length byte array
 /// }
 /// ```
-#[proc_macro_derive(DDS, attributes(dds))]
+#[proc_macro_derive(DDS, attributes(dds, key))]
 #[allow(clippy::too_many_lines)]
 pub fn derive_dds(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -55,51 +134,233 @@ pub fn derive_dds(input: TokenStream) -> TokenStream {
     let type_name = name.to_string();
     let type_id = compute_fnv1a_hash(&type_name);
 
-    // Parse struct fields
-    let fields = match &input.data {
+    let type_attrs = match TypeAttrs::parse(&input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let expansion = match &input.data {
         Data::Struct(data) => match &data.fields {
-            Fields::Named(f) => &f.named,
-            _ => {
-                return syn::Error::new_spanned(&input, "Only named fields are supported")
-                    .to_compile_error()
-                    .into()
+            Fields::Named(f) => expand_struct(&type_name, type_id, &f.named),
+            _ => Err(syn::Error::new_spanned(
+                &input,
+                "Only named fields are supported",
+            )),
+        },
+        Data::Enum(data) => expand_enum(&type_name, type_id, data),
+        Data::Union(_) => Err(syn::Error::new_spanned(
+            &input,
+            "Only structs and C-like enums are supported",
+        )),
+    };
+    let Expansion {
+        descriptor,
+        type_object,
+        idl_methods,
+        field_values,
+        has_key,
+    } = match expansion {
+        Ok(expansion) => expansion,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let key_methods = if has_key {
+        quote! {
+            fn compute_key(&self) -> [u8; 16] {
+                ::hdds::core::ser::idl::key_hash(self)
+            }
+
+            fn has_key() -> bool {
+                true
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let get_fields = if field_values.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn get_fields(
+                &self,
+            ) -> ::std::collections::HashMap<String, ::hdds::dds::filter::FieldValue> {
+                let mut fields = ::std::collections::HashMap::new();
+                #(#field_values)*
+                fields
+            }
+        }
+    };
+
+    // Type-level defaults (#[dds(topic = ..., qos = ..., ros_package = ...)])
+    let ros_type_name = match &type_attrs.ros_package {
+        Some(package) => {
+            let full = format!("{}::msg::dds_::{type_name}_", package.value());
+            quote! { #full }
+        }
+        None => {
+            let suffix = format!("::msg::dds_::{type_name}_");
+            quote! { concat!(env!("CARGO_CRATE_NAME"), #suffix) }
+        }
+    };
+    let (topic_const, default_topic) = match &type_attrs.topic {
+        Some(topic) => (
+            quote! {
+                /// Default topic name (`#[dds(topic = "...")]`)
+                pub const DDS_TOPIC: &'static str = #topic;
+            },
+            quote! {
+                fn default_topic() -> Option<&'static str> {
+                    Some(#topic)
+                }
+            },
+        ),
+        None => (quote! {}, quote! {}),
+    };
+    let default_qos = match &type_attrs.qos {
+        Some(qos) => quote! {
+            fn default_qos() -> Option<::hdds::QoS> {
+                Some(#qos)
             }
         },
-        _ => {
-            return syn::Error::new_spanned(&input, "Only structs are supported")
-                .to_compile_error()
-                .into()
+        None => quote! {},
+    };
+
+    let expanded = quote! {
+        impl ::hdds::api::DDS for #name {
+            fn type_descriptor() -> &'static ::hdds::core::types::TypeDescriptor {
+                static DESCRIPTOR: ::hdds::core::types::TypeDescriptor = #descriptor;
+                &DESCRIPTOR
+            }
+
+            fn encode_cdr2(&self, buf: &mut [u8]) -> ::hdds::api::Result<usize> {
+                ::hdds::core::ser::idl::encode_sample(self, buf)
+            }
+
+            fn decode_cdr2(buf: &[u8]) -> ::hdds::api::Result<Self> {
+                ::hdds::core::ser::idl::decode_sample(buf)
+            }
+
+            /// Get XTypes v1.3 TypeObject for this type
+            ///
+            /// Auto-generated by #[derive(DDS)] proc-macro (Phase 8b).
+            /// Returns CompleteTypeObject::Struct with all field metadata
+            /// (CompleteTypeObject::Enumerated for enums).
+            ///
+            /// # XTypes v1.3 Integration
+            ///
+            /// This enables:
+            /// - Runtime type discovery via SEDP announcements
+            /// - Structural type equivalence checking (EquivalenceHash)
+            /// - Multi-vendor interoperability (FastDDS, RTI, etc.)
+            ///
+            /// # Generated Structure
+            ///
+            /// - Extensibility: IS_FINAL (MVP, future: @appendable/@mutable)
+            /// - Members: Sequential member_id assignment (0, 1, 2, ...), or #[dds(id = N)]
+            /// - Member flags: IS_KEY for #[key] fields, IS_OPTIONAL for Option<T>
+            /// - Type IDs: Primitive TypeIdentifier constants (TK_INT32, TK_FLOAT32, etc.),
+            ///   anonymous sequences/arrays, nested types inlined
+            fn get_type_object() -> Option<::hdds::xtypes::CompleteTypeObject> {
+                Some(Self::type_object())
+            }
+
+            #key_methods
+
+            #get_fields
+
+            #default_topic
+
+            #default_qos
+        }
+
+        impl ::hdds::core::ser::idl::IdlCdr for #name {
+            #idl_methods
+        }
+
+        #[allow(dead_code)]
+        impl #name {
+            /// ROS 2 compatible DDS type name (`package::msg::dds_::Type_`)
+            pub const ROS_TYPE_NAME: &'static str = #ros_type_name;
+
+            #topic_const
+
+            /// XTypes TypeObject of this type (also inlined in the TypeObject
+            /// of enclosing `#[derive(DDS)]` types)
+            pub fn type_object() -> ::hdds::xtypes::CompleteTypeObject {
+                #type_object
+            }
         }
     };
 
+    TokenStream::from(expanded)
+}
+
+/// Struct with named fields: members encoded in declaration order
+#[allow(clippy::too_many_lines)]
+fn expand_struct(
+    type_name: &str,
+    type_id: u32,
+    fields: &syn::punctuated::Punctuated<syn::Field, syn::token::Comma>,
+) -> syn::Result<Expansion> {
     // Generate field info with proper CDR2 alignment
     struct FieldInfo {
         name: syn::Ident,
         ty: syn::Type,
         kind: FieldKind,
         offset: usize, // Only valid for fixed-size fields
+        is_key: bool,
+        member_id: u32,
+        /// `#[dds(bound = N)]` and the matching bounded `TypeIdentifier`
+        bound: Option<(u32, proc_macro2::TokenStream)>,
     }
 
-    let mut field_infos = Vec::new();
+    let mut field_infos: Vec<FieldInfo> = Vec::new();
     let mut current_offset = 0usize;
     let mut max_alignment = 1usize;
     let mut has_variable_size = false;
+    // Members without `#[dds(id = N)]` follow the previous member
+    // (IDL @autoid(SEQUENTIAL))
+    let mut next_member_id = 0u32;
 
     for field in fields {
         let Some(field_name) = field.ident.as_ref() else {
-            return syn::Error::new_spanned(field, "Field must have a name")
-                .to_compile_error()
-                .into();
+            return Err(syn::Error::new_spanned(field, "Field must have a name"));
         };
         let field_type = &field.ty;
 
-        let Some(kind) = get_field_kind(field_type) else {
-            return syn::Error::new_spanned(
-                field_type,
-                format!("Unsupported type: {field_type:?}. Supported types: primitives, String, Vec<u8>."),
-            )
-            .to_compile_error()
-            .into();
+        let attrs = FieldAttrs::parse(field)?;
+        let is_key = attrs.key;
+
+        let member_id = match &attrs.id {
+            Some(id) => id.base10_parse::<u32>()?,
+            None if next_member_id > MAX_MEMBER_ID => {
+                return Err(syn::Error::new_spanned(
+                    field_name,
+                    "member ID exceeds 0x0FFF_FFFF, set one with #[dds(id = N)]",
+                ));
+            }
+            None => next_member_id,
+        };
+        if let Some(other) = field_infos.iter().find(|f| f.member_id == member_id) {
+            return Err(syn::Error::new_spanned(
+                field_name,
+                format!("member ID {member_id} is already used by `{}`", other.name),
+            ));
+        }
+        next_member_id = member_id + 1;
+
+        let bound = match &attrs.bound {
+            Some(bound) => Some((
+                bound.base10_parse::<u32>()?,
+                bounded_type_identifier(field_type, bound)?,
+            )),
+            None => None,
+        };
+
+        let kind = match get_field_kind(field_type) {
+            Some(kind) => kind,
+            None => composite_field_kind(field_type)?,
         };
 
         let (size, alignment) = match &kind {
@@ -110,6 +371,16 @@ pub fn derive_dds(input: TokenStream) -> TokenStream {
                 has_variable_size = true;
                 (0, 4) // Length prefix is u32, aligned to 4
             }
+            FieldKind::Composite { optional, .. } => {
+                if is_key && *optional {
+                    return Err(syn::Error::new_spanned(
+                        field_type,
+                        "Key fields cannot be optional",
+                    ));
+                }
+                has_variable_size = true;
+                (0, 1) // Alignment is up to the members
+            }
         };
 
         // Align offset to field alignment (CDR2 requirement)
@@ -121,6 +392,9 @@ pub fn derive_dds(input: TokenStream) -> TokenStream {
             ty: field_type.clone(),
             kind,
             offset: current_offset,
+            is_key,
+            member_id,
+            bound,
         });
 
         if !has_variable_size {
@@ -184,142 +458,136 @@ pub fn derive_dds(input: TokenStream) -> TokenStream {
                         }
                     }
                 }
+                FieldKind::Composite { field_type, .. } => {
+                    quote! {
+                        ::hdds::core::types::FieldLayout {
+                            name: #name_str,
+                            offset_bytes: #offset,
+                            field_type: #field_type,
+                            alignment: 1,
+                            size_bytes: 0xFFFF_FFFF, // Variable
+                            element_type: None,
+                        }
+                    }
+                }
             }
         })
         .collect();
 
-    // Generate encode_cdr2 implementation
+    // Generate IdlCdr::encode_cdr: padding is relative to the sample start,
+    // so nested members line up with the enclosing sample
     let encode_fields: Vec<_> = field_infos
         .iter()
         .map(|f| {
             let field_name = &f.name;
+            let check_bound = bound_check(&f.ty, f.bound.as_ref(), &quote! { self.#field_name });
 
-            match &f.kind {
-                FieldKind::Primitive { alignment, .. } => {
-                    quote! {
-                        // Align cursor to field alignment
-                        while cursor.offset() % #alignment != 0 {
-                            cursor.write_u8(0)?;
-                        }
-                        // Write field value as little-endian
-                        cursor.write_bytes(&self.#field_name.to_le_bytes())?;
-                    }
-                }
-                FieldKind::String => {
+            let encode = match &f.kind {
+                FieldKind::ByteVec => {
                     quote! {
-                        // Align to 4 bytes for length prefix
-                        while cursor.offset() % 4 != 0 {
-                            cursor.write_u8(0)?;
-                        }
-                        // Write string: length (u32) + bytes + null terminator
-                        let str_bytes = self.#field_name.as_bytes();
-                        let str_len = (str_bytes.len() + 1) as u32; // Include null terminator
-                        cursor.write_bytes(&str_len.to_le_bytes())?;
-                        cursor.write_bytes(str_bytes)?;
-                        cursor.write_u8(0)?; // Null terminator
+                        // Write Vec<u8>: length (u32) + bytes, in one copy
+                        ::hdds::core::ser::idl::pad(cursor, 4)?;
+                        cursor.write_u32_le(self.#field_name.len() as u32)?;
+                        cursor.write_bytes(&self.#field_name)?;
                     }
                 }
-                FieldKind::ByteVec => {
+                _ => {
                     quote! {
-                        // Align to 4 bytes for length prefix
-                        while cursor.offset() % 4 != 0 {
-                            cursor.write_u8(0)?;
-                        }
-                        // Write Vec<u8>: length (u32) + bytes
-                        let vec_len = self.#field_name.len() as u32;
-                        cursor.write_bytes(&vec_len.to_le_bytes())?;
-                        cursor.write_bytes(&self.#field_name)?;
+                        ::hdds::core::ser::idl::IdlCdr::encode_cdr(&self.#field_name, cursor)?;
                     }
                 }
+            };
+            quote! {
+                #check_bound
+                #encode
             }
         })
         .collect();
 
-    // Generate decode_cdr2 implementation
+    // Generate IdlCdr::decode_cdr
     let decode_fields: Vec<_> = field_infos
         .iter()
         .map(|f| {
             let field_name = &f.name;
             let field_type = &f.ty;
+            let check_bound = bound_check(field_type, f.bound.as_ref(), &quote! { #field_name });
 
-            match &f.kind {
-                FieldKind::Primitive { size, alignment, .. } => {
+            let decode = match &f.kind {
+                FieldKind::ByteVec => {
                     quote! {
-                        // Align cursor to field alignment
-                        while cursor.offset() % #alignment != 0 {
-                            let _ = cursor.read_u8()?;
-                        }
-                        // Read field value as little-endian
+                        // Read Vec<u8>: length (u32) + bytes
                         let #field_name = {
-                            let bytes_slice = cursor.read_bytes(#size)?;
-                            let mut bytes = [0u8; #size];
-                            bytes.copy_from_slice(bytes_slice);
-                            <#field_type>::from_le_bytes(bytes)
+                            cursor.align(4)?;
+                            let vec_len = cursor.read_u32_le()? as usize;
+                            cursor.read_bytes(vec_len)?.to_vec()
                         };
                     }
                 }
-                FieldKind::String => {
+                _ => {
                     quote! {
-                        // Align to 4 bytes for length prefix
-                        while cursor.offset() % 4 != 0 {
-                            let _ = cursor.read_u8()?;
-                        }
-                        // Read string: length (u32) + bytes + null terminator
-                        let #field_name = {
-                            let len_bytes = cursor.read_bytes(4)?;
-                            let str_len = u32::from_le_bytes([len_bytes[0], len_bytes[1], len_bytes[2], len_bytes[3]]) as usize;
-                            if str_len == 0 {
-                                String::new()
-                            } else {
-                                let str_bytes = cursor.read_bytes(str_len - 1)?; // Exclude null terminator
-                                let _ = cursor.read_u8()?; // Skip null terminator
-                                String::from_utf8(str_bytes.to_vec())
-                                    .map_err(|_| ::hdds::dds::Error::SerializationError)?
-                            }
-                        };
+                        let #field_name =
+                            <#field_type as ::hdds::core::ser::idl::IdlCdr>::decode_cdr(cursor)?;
                     }
                 }
+            };
+            quote! {
+                #decode
+                #check_bound
+            }
+        })
+        .collect();
+
+    let field_names: Vec<_> = field_infos.iter().map(|f| &f.name).collect();
+
+    // Generate IdlCdr::encode_key: big-endian CDR of the key fields (all
+    // fields for an unkeyed struct nested in a key), aligned relative to
+    // the key stream (XCDR2 caps alignment at 4)
+    let has_key = field_infos.iter().any(|f| f.is_key);
+    let encode_key_fields: Vec<_> = field_infos
+        .iter()
+        .filter(|f| f.is_key || !has_key)
+        .map(|f| {
+            let field_name = &f.name;
+            match &f.kind {
                 FieldKind::ByteVec => {
                     quote! {
-                        // Align to 4 bytes for length prefix
-                        while cursor.offset() % 4 != 0 {
-                            let _ = cursor.read_u8()?;
-                        }
-                        // Read Vec<u8>: length (u32) + bytes
-                        let #field_name = {
-                            let len_bytes = cursor.read_bytes(4)?;
-                            let vec_len = u32::from_le_bytes([len_bytes[0], len_bytes[1], len_bytes[2], len_bytes[3]]) as usize;
-                            let data = cursor.read_bytes(vec_len)?;
-                            data.to_vec()
-                        };
+                        ::hdds::core::ser::idl::pad_key(key, 4);
+                        key.extend_from_slice(&(self.#field_name.len() as u32).to_be_bytes());
+                        key.extend_from_slice(&self.#field_name);
+                    }
+                }
+                _ => {
+                    quote! {
+                        ::hdds::core::ser::idl::IdlCdr::encode_key(&self.#field_name, key);
                     }
                 }
             }
         })
         .collect();
 
-    let field_names: Vec<_> = field_infos.iter().map(|f| &f.name).collect();
-
     // Generate CompleteStructMembers for TypeObject (Phase 8b)
     let type_object_members: Vec<_> = field_infos
         .iter()
-        .enumerate()
-        .map(|(idx, f)| {
-            let Ok(member_id) = u32::try_from(idx) else {
-                return syn::Error::new_spanned(
-                    &f.name,
-                    format!("Struct has too many fields (index {idx} exceeds u32::MAX)"),
-                )
-                .to_compile_error();
-            };
+        .map(|f| {
+            let member_id = f.member_id;
             let name_str = f.name.to_string();
-            let type_id_const = get_type_identifier_for_kind(&f.kind);
+            let type_id_const = match &f.bound {
+                Some((_, type_id)) => type_id.clone(),
+                None => get_type_identifier_for_kind(&f.kind),
+            };
+            let member_flags = match &f.kind {
+                _ if f.is_key => quote! { ::hdds::xtypes::MemberFlag::IS_KEY },
+                FieldKind::Composite { optional: true, .. } => {
+                    quote! { ::hdds::xtypes::MemberFlag::IS_OPTIONAL }
+                }
+                _ => quote! { ::hdds::xtypes::MemberFlag::empty() },
+            };
 
             quote! {
                 ::hdds::xtypes::CompleteStructMember {
                     common: ::hdds::xtypes::CommonStructMember {
                         member_id: #member_id,
-                        member_flags: ::hdds::xtypes::MemberFlag::empty(),
+                        member_flags: #member_flags,
                         member_type_id: #type_id_const,
                     },
                     detail: ::hdds::xtypes::CompleteMemberDetail::new(#name_str),
@@ -328,38 +596,59 @@ pub fn derive_dds(input: TokenStream) -> TokenStream {
         })
         .collect();
 
+    // Primitive and string members are visible to filter expressions
+    let field_values: Vec<_> = field_infos
+        .iter()
+        .filter_map(|f| {
+            let field_name = &f.name;
+            let name_str = field_name.to_string();
+            let value = field_value(&f.ty, &quote! { self.#field_name })?;
+            Some(quote! {
+                fields.insert(#name_str.to_string(), #value);
+            })
+        })
+        .collect();
+
     let max_alignment_u8 = max_alignment as u8;
 
-    let expanded = quote! {
-        impl ::hdds::api::DDS for #name {
-            fn type_descriptor() -> &'static ::hdds::core::types::TypeDescriptor {
-                static DESCRIPTOR: ::hdds::core::types::TypeDescriptor = ::hdds::core::types::TypeDescriptor {
-                    type_id: #type_id,
-                    type_name: #type_name,
-                    size_bytes: #total_size,
-                    alignment: #max_alignment_u8,
-                    is_variable_size: #has_variable_size,
-                    fields: &[#(#field_layouts),*],
-                };
-                &DESCRIPTOR
+    Ok(Expansion {
+        descriptor: quote! {
+            ::hdds::core::types::TypeDescriptor {
+                type_id: #type_id,
+                type_name: #type_name,
+                size_bytes: #total_size,
+                alignment: #max_alignment_u8,
+                is_variable_size: #has_variable_size,
+                fields: &[#(#field_layouts),*],
             }
-
-            fn encode_cdr2(&self, buf: &mut [u8]) -> ::hdds::api::Result<usize> {
-                use ::hdds::core::ser::cursor::CursorMut;
-
-                let mut cursor = CursorMut::new(buf);
-
+        },
+        type_object: quote! {
+            ::hdds::xtypes::CompleteTypeObject::Struct(
+                ::hdds::xtypes::CompleteStructType {
+                    struct_flags: ::hdds::xtypes::StructTypeFlag::IS_FINAL,
+                    header: ::hdds::xtypes::CompleteStructHeader {
+                        base_type: None, // No inheritance (Phase 8b MVP)
+                        detail: ::hdds::xtypes::CompleteTypeDetail::new(#type_name),
+                    },
+                    member_seq: vec![
+                        #(#type_object_members),*
+                    ],
+                }
+            )
+        },
+        idl_methods: quote! {
+            fn encode_cdr(
+                &self,
+                cursor: &mut ::hdds::core::ser::cursor::CursorMut<'_>,
+            ) -> ::hdds::core::ser::SerResult<()> {
                 // Encode each field with proper alignment
                 #(#encode_fields)*
-
-                Ok(cursor.offset())
+                Ok(())
             }
 
-            fn decode_cdr2(buf: &[u8]) -> ::hdds::api::Result<Self> {
-                use ::hdds::core::ser::cursor::Cursor;
-
-                let mut cursor = Cursor::new(buf);
-
+            fn decode_cdr(
+                cursor: &mut ::hdds::core::ser::cursor::Cursor<'_>,
+            ) -> ::hdds::core::ser::SerResult<Self> {
                 // Decode each field with proper alignment
                 #(#decode_fields)*
 
@@ -368,50 +657,259 @@ pub fn derive_dds(input: TokenStream) -> TokenStream {
                 })
             }
 
-            /// Get XTypes v1.3 TypeObject for this type
-            ///
-            /// Auto-generated by #[derive(DDS)] proc-macro (Phase 8b).
-            /// Returns CompleteTypeObject::Struct with all field metadata.
-            ///
-            /// # XTypes v1.3 Integration
-            ///
-            /// This enables:
-            /// - Runtime type discovery via SEDP announcements
-            /// - Structural type equivalence checking (EquivalenceHash)
-            /// - Multi-vendor interoperability (FastDDS, RTI, etc.)
-            ///
-            /// # Generated Structure
-            ///
-            /// - Extensibility: IS_FINAL (MVP, future: @appendable/@mutable)
-            /// - Members: Sequential member_id assignment (0, 1, 2, ...)
-            /// - Member flags: Empty (future: @key, @optional, @must_understand)
-            /// - Type IDs: Primitive TypeIdentifier constants (TK_INT32, TK_FLOAT32, etc.)
-            fn get_type_object() -> Option<::hdds::xtypes::CompleteTypeObject> {
-                Some(::hdds::xtypes::CompleteTypeObject::Struct(
-                    ::hdds::xtypes::CompleteStructType {
-                        struct_flags: ::hdds::xtypes::StructTypeFlag::IS_FINAL,
-                        header: ::hdds::xtypes::CompleteStructHeader {
-                            base_type: None, // No inheritance (Phase 8b MVP)
-                            detail: ::hdds::xtypes::CompleteTypeDetail::new(#type_name),
-                        },
-                        member_seq: vec![
-                            #(#type_object_members),*
-                        ],
+            fn encode_key(&self, key: &mut Vec<u8>) {
+                #(#encode_key_fields)*
+            }
+        },
+        field_values,
+        has_key,
+    })
+}
+
+/// C-like enum: the discriminant, as a `u32`
+fn expand_enum(type_name: &str, type_id: u32, data: &DataEnum) -> syn::Result<Expansion> {
+    if let Some(variant) = data
+        .variants
+        .iter()
+        .find(|variant| !matches!(variant.fields, Fields::Unit))
+    {
+        return Err(syn::Error::new_spanned(
+            variant,
+            "Only C-like enums (unit variants) are supported",
+        ));
+    }
+    if data.variants.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &data.variants,
+            "Enums need at least one variant",
+        ));
+    }
+
+    let variants: Vec<_> = data.variants.iter().map(|variant| &variant.ident).collect();
+    let literal_names: Vec<_> = variants.iter().map(ToString::to_string).collect();
+
+    Ok(Expansion {
+        descriptor: quote! {
+            ::hdds::core::types::TypeDescriptor {
+                type_id: #type_id,
+                type_name: #type_name,
+                size_bytes: 4,
+                alignment: 4,
+                is_variable_size: false,
+                fields: &[],
+            }
+        },
+        type_object: quote! {
+            ::hdds::xtypes::CompleteTypeObject::Enumerated(
+                ::hdds::xtypes::CompleteEnumeratedType {
+                    header: ::hdds::xtypes::CompleteEnumeratedHeader {
+                        bit_bound: 32,
+                        detail: ::hdds::xtypes::CompleteTypeDetail::new(#type_name),
+                    },
+                    literal_seq: vec![
+                        #(::hdds::xtypes::CompleteEnumeratedLiteral {
+                            common: ::hdds::xtypes::CommonEnumeratedLiteral {
+                                value: Self::#variants as i32,
+                                flags: ::hdds::xtypes::EnumeratedLiteralFlag::empty(),
+                            },
+                            detail: ::hdds::xtypes::CompleteMemberDetail::new(#literal_names),
+                        }),*
+                    ],
+                }
+            )
+        },
+        idl_methods: quote! {
+            fn encode_cdr(
+                &self,
+                cursor: &mut ::hdds::core::ser::cursor::CursorMut<'_>,
+            ) -> ::hdds::core::ser::SerResult<()> {
+                let value = match self {
+                    #(Self::#variants => Self::#variants as u32),*
+                };
+                ::hdds::core::ser::idl::IdlCdr::encode_cdr(&value, cursor)
+            }
+
+            fn decode_cdr(
+                cursor: &mut ::hdds::core::ser::cursor::Cursor<'_>,
+            ) -> ::hdds::core::ser::SerResult<Self> {
+                let value = <u32 as ::hdds::core::ser::idl::IdlCdr>::decode_cdr(cursor)?;
+                #(
+                    if value == Self::#variants as u32 {
+                        return Ok(Self::#variants);
                     }
-                ))
+                )*
+                Err(::hdds::core::ser::SerError::InvalidData {
+                    reason: format!("invalid {} value {}", #type_name, value),
+                })
             }
+
+            fn encode_key(&self, key: &mut Vec<u8>) {
+                let value = match self {
+                    #(Self::#variants => Self::#variants as u32),*
+                };
+                ::hdds::core::ser::idl::IdlCdr::encode_key(&value, key);
+            }
+        },
+        field_values: Vec::new(),
+        has_key: false,
+    })
+}
+
+/// Type-level `#[dds(...)]` options
+#[derive(Default)]
+struct TypeAttrs {
+    topic: Option<syn::LitStr>,
+    qos: Option<proc_macro2::TokenStream>,
+    ros_package: Option<syn::LitStr>,
+}
+
+impl TypeAttrs {
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut parsed = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("dds")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("topic") {
+                    let topic: syn::LitStr = meta.value()?.parse()?;
+                    if topic.value().trim().is_empty() {
+                        return Err(syn::Error::new_spanned(topic, "topic name cannot be empty"));
+                    }
+                    parsed.topic = Some(topic);
+                } else if meta.path.is_ident("qos") {
+                    let expr: syn::Expr = meta.value()?.parse()?;
+                    parsed.qos = Some(qos_tokens(&expr)?);
+                } else if meta.path.is_ident("ros_package") {
+                    let package: syn::LitStr = meta.value()?.parse()?;
+                    let valid = !package.value().is_empty()
+                        && package
+                            .value()
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || c == '_');
+                    if !valid {
+                        return Err(syn::Error::new_spanned(
+                            package,
+                            "ROS package names contain only letters, digits and '_'",
+                        ));
+                    }
+                    parsed.ros_package = Some(package);
+                } else {
+                    return Err(meta.error(
+                        "unsupported dds attribute, expected `topic`, `qos` or `ros_package`",
+                    ));
+                }
+                Ok(())
+            })?;
         }
+        Ok(parsed)
+    }
+}
+
+/// QoS expression for `qos = ...`: a named profile or an arbitrary expression
+fn qos_tokens(expr: &syn::Expr) -> syn::Result<proc_macro2::TokenStream> {
+    let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Str(profile),
+        ..
+    }) = expr
+    else {
+        return Ok(quote! { #expr });
     };
+    match profile.value().as_str() {
+        "reliable" => Ok(quote! { ::hdds::QoS::reliable() }),
+        "best_effort" => Ok(quote! { ::hdds::QoS::best_effort() }),
+        "rti_defaults" => Ok(quote! { ::hdds::QoS::rti_defaults() }),
+        other => Err(syn::Error::new_spanned(
+            profile,
+            format!("unknown QoS profile \"{other}\", expected \"reliable\", \"best_effort\" or \"rti_defaults\""),
+        )),
+    }
+}
 
-    TokenStream::from(expanded)
+/// Field-level `#[key]` / `#[dds(...)]` options
+#[derive(Default)]
+struct FieldAttrs {
+    /// `#[key]` or `#[dds(key)]`
+    key: bool,
+    /// `#[dds(id = N)]`: explicit member ID
+    id: Option<syn::LitInt>,
+    /// `#[dds(bound = N)]`: maximum length of a string or sequence
+    bound: Option<syn::LitInt>,
+}
+
+impl FieldAttrs {
+    fn parse(field: &syn::Field) -> syn::Result<Self> {
+        let mut parsed = Self::default();
+        for attr in &field.attrs {
+            if attr.path().is_ident("key") {
+                attr.meta.require_path_only()?;
+                parsed.key = true;
+            } else if attr.path().is_ident("dds") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("key") {
+                        parsed.key = true;
+                    } else if meta.path.is_ident("id") {
+                        let id: syn::LitInt = meta.value()?.parse()?;
+                        // EMHEADER member IDs are 28 bits wide
+                        if id.base10_parse::<u32>()? > MAX_MEMBER_ID {
+                            return Err(syn::Error::new_spanned(
+                                id,
+                                "member IDs must be at most 0x0FFF_FFFF",
+                            ));
+                        }
+                        parsed.id = Some(id);
+                    } else if meta.path.is_ident("bound") {
+                        let bound: syn::LitInt = meta.value()?.parse()?;
+                        if bound.base10_parse::<u32>()? == 0 {
+                            return Err(syn::Error::new_spanned(
+                                bound,
+                                "bound must be greater than 0",
+                            ));
+                        }
+                        parsed.bound = Some(bound);
+                    } else {
+                        return Err(meta
+                            .error("unsupported dds attribute, expected `key`, `id` or `bound`"));
+                    }
+                    Ok(())
+                })?;
+            }
+        }
+        Ok(parsed)
+    }
 }
 
+/// Highest XTypes member ID (28-bit EMHEADER field)
+const MAX_MEMBER_ID: u32 = 0x0FFF_FFFF;
+
 /// Get field kind for a Rust type
 ///
 /// Supports:
 /// - Primitive types: i8, i16, i32, i64, u8, u16, u32, u64, f32, f64, bool
 /// - String: variable-length UTF-8 string
 /// - Vec<u8>: variable-length byte array
+///
+/// Other types are composites (see `composite_field_kind`).
+/// `FieldValue` of a primitive or string member, `None` for other types
+fn field_value(
+    ty: &syn::Type,
+    value: &proc_macro2::TokenStream,
+) -> Option<proc_macro2::TokenStream> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let ident = type_path.path.segments.last()?.ident.to_string();
+    let field_value = quote! { ::hdds::dds::filter::FieldValue };
+    Some(match ident.as_str() {
+        "i8" | "i16" | "i32" => quote! { #field_value::from_i32(#value as i32) },
+        "i64" => quote! { #field_value::from_i64(#value) },
+        "u8" | "u16" | "u32" => quote! { #field_value::from_u32(#value as u32) },
+        "u64" => quote! { #field_value::from_u64(#value) },
+        "f32" => quote! { #field_value::from_f32(#value) },
+        "f64" => quote! { #field_value::from_f64(#value) },
+        "bool" => quote! { #field_value::from_bool(#value) },
+        "String" => quote! { #field_value::from_string(#value.clone()) },
+        _ => return None,
+    })
+}
+
 fn get_field_kind(ty: &syn::Type) -> Option<FieldKind> {
     if let Type::Path(type_path) = ty {
         let segment = type_path.path.segments.last()?;
@@ -508,7 +1006,7 @@ fn get_field_kind(ty: &syn::Type) -> Option<FieldKind> {
                         }
                     }
                 }
-                return None; // Vec<T> where T != u8 is not supported
+                return None; // Vec<T> where T != u8 is a composite
             }
             _ => return None,
         }
@@ -516,6 +1014,133 @@ fn get_field_kind(ty: &syn::Type) -> Option<FieldKind> {
     None
 }
 
+/// Kind of a field that is not a primitive, `String` or `Vec<u8>`
+fn composite_field_kind(ty: &Type) -> syn::Result<FieldKind> {
+    let (member, optional) = match generic_argument(ty, "Option") {
+        Some(inner) => (inner, true),
+        None => (ty, false),
+    };
+    let field_type = match get_field_kind(member) {
+        Some(FieldKind::Primitive { kind_tokens, .. }) => {
+            quote! { ::hdds::core::types::FieldType::Primitive(#kind_tokens) }
+        }
+        Some(FieldKind::String) => quote! { ::hdds::core::types::FieldType::String },
+        _ if matches!(member, Type::Array(_)) => {
+            quote! { ::hdds::core::types::FieldType::Array }
+        }
+        _ if generic_argument(member, "Vec").is_some() => {
+            quote! { ::hdds::core::types::FieldType::Sequence }
+        }
+        _ => quote! { ::hdds::core::types::FieldType::Struct },
+    };
+    Ok(FieldKind::Composite {
+        field_type,
+        type_id: type_identifier(member)?,
+        optional,
+    })
+}
+
+/// `TypeIdentifier` expression of a member type: primitives and strings,
+/// anonymous sequences and arrays, nested types inlined
+fn type_identifier(ty: &Type) -> syn::Result<proc_macro2::TokenStream> {
+    if let Type::Array(array) = ty {
+        // [[T; 3]; 2] is the IDL array T[2][3]: dimensions outermost first
+        let mut dims = vec![&array.len];
+        let mut element = &*array.elem;
+        while let Type::Array(inner) = element {
+            dims.push(&inner.len);
+            element = &inner.elem;
+        }
+        let element = type_identifier(element)?;
+        return Ok(quote! {
+            ::hdds::core::ser::idl::array_type_id(#element, vec![#((#dims) as u32),*])
+        });
+    }
+    if !matches!(ty, Type::Path(_)) {
+        return Err(syn::Error::new_spanned(
+            ty,
+            "Unsupported type. Supported types: primitives, String, [T; N], Vec<T>, Option<T> and #[derive(DDS)] types.",
+        ));
+    }
+    if let Some(kind @ (FieldKind::Primitive { .. } | FieldKind::String)) = get_field_kind(ty) {
+        return Ok(get_type_identifier_for_kind(&kind));
+    }
+    if let Some(element) = generic_argument(ty, "Vec") {
+        let element = type_identifier(element)?;
+        return Ok(quote! { ::hdds::core::ser::idl::sequence_type_id(#element, 0) });
+    }
+    if generic_argument(ty, "Option").is_some() {
+        return Err(syn::Error::new_spanned(
+            ty,
+            "Option<T> is only supported as a struct field",
+        ));
+    }
+    Ok(quote! {
+        ::hdds::xtypes::TypeIdentifier::Inline(Box::new(<#ty>::type_object()))
+    })
+}
+
+/// `TypeIdentifier` of a `#[dds(bound = N)]` field: a `String` or `Vec<T>`,
+/// optional or not
+fn bounded_type_identifier(
+    ty: &Type,
+    bound: &syn::LitInt,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let member = generic_argument(ty, "Option").unwrap_or(ty);
+    let bound_value = bound.base10_parse::<u32>()?;
+    if let Some(FieldKind::String) = get_field_kind(member) {
+        return Ok(quote! { ::hdds::xtypes::TypeIdentifier::string(#bound_value) });
+    }
+    if let Some(element) = generic_argument(member, "Vec") {
+        let element = type_identifier(element)?;
+        return Ok(quote! { ::hdds::core::ser::idl::sequence_type_id(#element, #bound_value) });
+    }
+    Err(syn::Error::new_spanned(
+        bound,
+        "bound applies to String and Vec<T> fields",
+    ))
+}
+
+/// Length check of a bounded field, `value` being the field expression
+fn bound_check(
+    ty: &Type,
+    bound: Option<&(u32, proc_macro2::TokenStream)>,
+    value: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let Some((bound, _)) = bound else {
+        return quote! {};
+    };
+    if generic_argument(ty, "Option").is_some() {
+        quote! {
+            if let Some(value) = &#value {
+                ::hdds::core::ser::idl::check_bound(value.len(), #bound as usize)?;
+            }
+        }
+    } else {
+        quote! {
+            ::hdds::core::ser::idl::check_bound(#value.len(), #bound as usize)?;
+        }
+    }
+}
+
+/// `T` of a `wrapper<T>` type path
+fn generic_argument<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
 /// Align offset to the specified alignment (round up to next multiple)
 #[allow(clippy::integer_division_remainder_used, clippy::integer_division)]
 const fn align_to(offset: usize, alignment: usize) -> usize {
@@ -527,6 +1152,57 @@ const fn align_to(offset: usize, alignment: usize) -> usize {
     }
 }
 
+/// `#[service]` attribute: DDS-RPC service from a trait
+///
+/// Each method of the trait is an operation of the service. For a trait
+/// `Calculator`, generates:
+/// - `CalculatorRequest`: enum of the method arguments, with `dispatch()`
+///   calling the matching method of an implementation
+/// - `CalculatorReply`: enum of the method results
+/// - `CalculatorClient`: one blocking method and one `_async` method per
+///   operation, on top of `hdds::rpc::Client`
+/// - `Calculator::into_service()`: serves an implementation as an
+///   `hdds::rpc::Service`
+///
+/// Methods take `&self` and arguments implementing `Cdr2Encode` and
+/// `Cdr2Decode` (primitives, `String`, `Vec<T>`, arrays, `Option<T>`, types
+/// generated by hdds_gen...). They return the reply value, `()`, or a
+/// `Result` whose error converts into `hdds::rpc::RpcError`; errors reach
+/// the client as `RpcError::RemoteException`.
+///
+/// Requires the `rpc` feature of hdds.
+///
+/// Example:
+/// ```ignore
+/// #[hdds::rpc::service]
+/// pub trait Calculator {
+///     fn add(&self, a: i64, b: i64) -> i64;
+///     fn divide(&self, a: f64, b: f64) -> hdds::rpc::RpcResult<f64>;
+/// }
+///
+/// struct Calc;
+/// impl Calculator for Calc { /* ... */ }
+///
+/// let service = Calc.into_service(&participant, "calculator")?;
+/// let client = CalculatorClient::new(&participant, "calculator")?;
+/// assert_eq!(client.add(2, 3)?, 5);
+/// ```
+#[proc_macro_attribute]
+pub fn service(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "#[service] takes no arguments",
+        )
+        .to_compile_error()
+        .into();
+    }
+    let item = parse_macro_input!(item as syn::ItemTrait);
+    service::expand(item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 /// Compute FNV-1a hash (32-bit) for type ID
 fn compute_fnv1a_hash(s: &str) -> u32 {
     let mut hash = 2_166_136_261_u32;
@@ -543,6 +1219,7 @@ fn compute_fnv1a_hash(s: &str) -> u32 {
 /// - Primitives: I8 -> TK_INT8, etc.
 /// - String -> TK_STRING8
 /// - ByteVec -> TK_SEQUENCE (of u8)
+/// - Composite -> anonymous sequence/array, or the inlined nested type
 fn get_type_identifier_for_kind(kind: &FieldKind) -> proc_macro2::TokenStream {
     match kind {
         FieldKind::Primitive { kind_tokens, .. } => {
@@ -574,5 +1251,6 @@ fn get_type_identifier_for_kind(kind: &FieldKind) -> proc_macro2::TokenStream {
             // Note: Full XTypes would encode element type in a PlainSequence/Sequence variant
             quote! { ::hdds::xtypes::TypeIdentifier::Primitive(::hdds::xtypes::TypeKind::TK_SEQUENCE) }
         }
+        FieldKind::Composite { type_id, .. } => type_id.clone(),
     }
 }
@@ -0,0 +1,480 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! `#[service]`: DDS-RPC request/reply types and client from a trait.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    FnArg, GenericArgument, Ident, ItemTrait, Pat, PathArguments, ReturnType, Signature, TraitItem,
+    Type,
+};
+
+/// One operation of the service (a trait method).
+struct Method {
+    name: Ident,
+    variant: Ident,
+    args: Vec<(Ident, Type)>,
+    /// Reply value, `None` for `()`
+    reply: Option<Type>,
+    /// Returns `Result<_, E>` with `E: Into<RpcError>`
+    fallible: bool,
+}
+
+impl Method {
+    fn parse(sig: &Signature) -> syn::Result<Self> {
+        if sig.asyncness.is_some() || !sig.generics.params.is_empty() {
+            return Err(syn::Error::new_spanned(
+                sig,
+                "#[service] methods cannot be async or generic",
+            ));
+        }
+
+        let mut inputs = sig.inputs.iter();
+        match inputs.next() {
+            Some(FnArg::Receiver(receiver))
+                if receiver.reference.is_some() && receiver.mutability.is_none() => {}
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    sig,
+                    "#[service] methods must take `&self`",
+                ))
+            }
+        }
+
+        let mut args = Vec::new();
+        for input in inputs {
+            let FnArg::Typed(arg) = input else {
+                return Err(syn::Error::new_spanned(input, "unexpected receiver"));
+            };
+            let Pat::Ident(pat) = arg.pat.as_ref() else {
+                return Err(syn::Error::new_spanned(
+                    &arg.pat,
+                    "#[service] arguments must be plain identifiers",
+                ));
+            };
+            args.push((pat.ident.clone(), arg.ty.as_ref().clone()));
+        }
+
+        let (reply, fallible) = match &sig.output {
+            ReturnType::Default => (None, false),
+            ReturnType::Type(_, ty) => match result_value(ty) {
+                Some(value) => (non_unit(value), true),
+                None => (non_unit(ty), false),
+            },
+        };
+
+        Ok(Self {
+            name: sig.ident.clone(),
+            variant: format_ident!("{}", upper_camel(&sig.ident.to_string())),
+            args,
+            reply,
+            fallible,
+        })
+    }
+}
+
+/// `T` of `Result<T, E>` / `RpcResult<T>`.
+fn result_value(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let last = path.path.segments.last()?;
+    if last.ident != "Result" && last.ident != "RpcResult" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &last.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(value) => Some(value),
+        _ => None,
+    }
+}
+
+fn non_unit(ty: &Type) -> Option<Type> {
+    match ty {
+        Type::Tuple(tuple) if tuple.elems.is_empty() => None,
+        _ => Some(ty.clone()),
+    }
+}
+
+/// `set_speed` -> `SetSpeed`
+fn upper_camel(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_uppercase().chain(chars).collect()
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn expand(mut item: ItemTrait) -> syn::Result<TokenStream> {
+    if !item.generics.params.is_empty() || item.generics.where_clause.is_some() {
+        return Err(syn::Error::new_spanned(
+            &item.generics,
+            "#[service] traits cannot be generic",
+        ));
+    }
+
+    let methods = item
+        .items
+        .iter()
+        .map(|trait_item| match trait_item {
+            TraitItem::Fn(method) => Method::parse(&method.sig),
+            other => Err(syn::Error::new_spanned(
+                other,
+                "#[service] traits may only contain methods",
+            )),
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    if methods.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &item.ident,
+            "#[service] traits need at least one method",
+        ));
+    }
+    if let Some(method) = methods.iter().find(|m| m.name == "into_service") {
+        return Err(syn::Error::new_spanned(
+            &method.name,
+            "`into_service` is generated by #[service]",
+        ));
+    }
+
+    let trait_name = item.ident.clone();
+    let request = format_ident!("{}Request", trait_name);
+    let reply = format_ident!("{}Reply", trait_name);
+
+    item.items.push(syn::parse_quote! {
+        /// Serve this implementation as the service `service_name`.
+        fn into_service(
+            self,
+            participant: &::std::sync::Arc<::hdds::Participant>,
+            service_name: &str,
+        ) -> ::hdds::rpc::RpcResult<::hdds::rpc::Service<#request, #reply>>
+        where
+            Self: Sized + Send + Sync + 'static,
+        {
+            ::hdds::rpc::Service::new(participant, service_name, move |request: #request| {
+                request.dispatch(&self)
+            })
+        }
+    });
+
+    let request_type = expand_request(&item, &request, &reply, &methods);
+    let reply_type = expand_reply(&item, &reply, &methods);
+    let client_type = expand_client(&item, &request, &reply, &methods);
+
+    Ok(quote! {
+        #item
+        #request_type
+        #reply_type
+        #client_type
+    })
+}
+
+/// `impl DDS` with the given type name and encode/decode bodies.
+fn dds_impl(
+    ty: &Ident,
+    type_name: &str,
+    encode: &TokenStream,
+    decode: &TokenStream,
+) -> TokenStream {
+    let type_id = crate::compute_fnv1a_hash(type_name);
+    quote! {
+        impl ::hdds::dds::DDS for #ty {
+            fn type_descriptor() -> &'static ::hdds::core::types::TypeDescriptor {
+                static DESCRIPTOR: ::hdds::core::types::TypeDescriptor =
+                    ::hdds::core::types::TypeDescriptor::new(#type_id, #type_name, 0, 4, true, &[]);
+                &DESCRIPTOR
+            }
+
+            fn encode_cdr2(&self, buf: &mut [u8]) -> ::hdds::Result<usize> {
+                let mut offset = 0usize;
+                #encode
+                Ok(offset)
+            }
+
+            fn decode_cdr2(buf: &[u8]) -> ::hdds::Result<Self> {
+                let mut src = buf;
+                #decode
+            }
+        }
+    }
+}
+
+fn expand_request(
+    item: &ItemTrait,
+    request: &Ident,
+    reply: &Ident,
+    methods: &[Method],
+) -> TokenStream {
+    let vis = &item.vis;
+    let trait_name = &item.ident;
+    let type_name = format!("{trait_name}_Request");
+
+    let variants = methods.iter().map(|m| {
+        let variant = &m.variant;
+        let doc = format!("Arguments of `{trait_name}::{}`.", m.name);
+        let fields = m.args.iter().map(|(name, ty)| quote! { #name: #ty });
+        quote! {
+            #[doc = #doc]
+            #variant { #(#fields),* }
+        }
+    });
+
+    let encode_arms = methods.iter().enumerate().map(|(index, m)| {
+        let variant = &m.variant;
+        let discriminant = index as u32;
+        let names: Vec<_> = m.args.iter().map(|(name, _)| name).collect();
+        quote! {
+            Self::#variant { #(#names),* } => {
+                ::hdds::rpc::encode_field(&#discriminant, buf, &mut offset)?;
+                #(::hdds::rpc::encode_field(#names, buf, &mut offset)?;)*
+            }
+        }
+    });
+
+    let decode_arms = methods.iter().enumerate().map(|(index, m)| {
+        let variant = &m.variant;
+        let discriminant = index as u32;
+        let values = m.args.iter().map(|(name, ty)| {
+            quote! { #name: ::hdds::rpc::decode_field::<#ty>(&mut src)? }
+        });
+        quote! {
+            #discriminant => Ok(Self::#variant { #(#values),* }),
+        }
+    });
+
+    let dispatch_arms = methods.iter().map(|m| {
+        let variant = &m.variant;
+        let name = &m.name;
+        let names: Vec<_> = m.args.iter().map(|(name, _)| name).collect();
+        let call = quote! { service.#name(#(#names),*) };
+        let call = if m.fallible {
+            quote! { #call.map_err(::std::convert::Into::<::hdds::rpc::RpcError>::into)? }
+        } else {
+            call
+        };
+        let body = if m.reply.is_some() {
+            quote! { Ok(#reply::#variant(#call)) }
+        } else {
+            quote! {{
+                #call;
+                Ok(#reply::#variant)
+            }}
+        };
+        quote! { Self::#variant { #(#names),* } => #body, }
+    });
+
+    let encode = quote! {
+        match self {
+            #(#encode_arms)*
+        }
+    };
+    let decode = quote! {
+        match ::hdds::rpc::decode_field::<u32>(&mut src)? {
+            #(#decode_arms)*
+            _ => Err(::hdds::Error::SerializationError),
+        }
+    };
+    let dds = dds_impl(request, &type_name, &encode, &decode);
+    let doc = format!("Requests of [`{trait_name}`], one variant per method.");
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone)]
+        #vis enum #request {
+            #(#variants,)*
+        }
+
+        impl #request {
+            /// Call the matching method of `service`.
+            pub fn dispatch<S: #trait_name + ?Sized>(
+                self,
+                service: &S,
+            ) -> ::hdds::rpc::RpcResult<#reply> {
+                match self {
+                    #(#dispatch_arms)*
+                }
+            }
+        }
+
+        #dds
+    }
+}
+
+fn expand_reply(item: &ItemTrait, reply: &Ident, methods: &[Method]) -> TokenStream {
+    let vis = &item.vis;
+    let trait_name = &item.ident;
+    let type_name = format!("{trait_name}_Reply");
+
+    let variants = methods.iter().map(|m| {
+        let variant = &m.variant;
+        let doc = format!("Result of `{trait_name}::{}`.", m.name);
+        match &m.reply {
+            Some(ty) => quote! { #[doc = #doc] #variant(#ty) },
+            None => quote! { #[doc = #doc] #variant },
+        }
+    });
+
+    let encode_arms = methods.iter().enumerate().map(|(index, m)| {
+        let variant = &m.variant;
+        let discriminant = index as u32;
+        match &m.reply {
+            Some(_) => quote! {
+                Self::#variant(value) => {
+                    ::hdds::rpc::encode_field(&#discriminant, buf, &mut offset)?;
+                    ::hdds::rpc::encode_field(value, buf, &mut offset)?;
+                }
+            },
+            None => quote! {
+                Self::#variant => ::hdds::rpc::encode_field(&#discriminant, buf, &mut offset)?,
+            },
+        }
+    });
+
+    let decode_arms = methods.iter().enumerate().map(|(index, m)| {
+        let variant = &m.variant;
+        let discriminant = index as u32;
+        match &m.reply {
+            Some(ty) => quote! {
+                #discriminant => Ok(Self::#variant(::hdds::rpc::decode_field::<#ty>(&mut src)?)),
+            },
+            None => quote! { #discriminant => Ok(Self::#variant), },
+        }
+    });
+
+    let encode = quote! {
+        match self {
+            #(#encode_arms)*
+        }
+    };
+    let decode = quote! {
+        match ::hdds::rpc::decode_field::<u32>(&mut src)? {
+            #(#decode_arms)*
+            _ => Err(::hdds::Error::SerializationError),
+        }
+    };
+    let dds = dds_impl(reply, &type_name, &encode, &decode);
+    let doc = format!("Replies of [`{trait_name}`], one variant per method.");
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone)]
+        #vis enum #reply {
+            #(#variants,)*
+        }
+
+        #dds
+    }
+}
+
+fn expand_client(
+    item: &ItemTrait,
+    request: &Ident,
+    reply: &Ident,
+    methods: &[Method],
+) -> TokenStream {
+    let vis = &item.vis;
+    let trait_name = &item.ident;
+    let client = format_ident!("{}Client", trait_name);
+
+    let calls = methods.iter().map(|m| {
+        let name = &m.name;
+        let name_async = format_ident!("{}_async", name);
+        let variant = &m.variant;
+        let params = m.args.iter().map(|(name, ty)| quote! { #name: #ty });
+        let params_async = params.clone();
+        let names: Vec<_> = m.args.iter().map(|(name, _)| name).collect();
+        let output = m
+            .reply
+            .as_ref()
+            .map_or_else(|| quote! { () }, |ty| quote! { #ty });
+        let unexpected = format!("reply does not match {trait_name}::{name}");
+
+        // Every variant listed, so that single-method services have no
+        // unreachable arm
+        let arms: Vec<_> = methods
+            .iter()
+            .map(|other| {
+                let other_variant = &other.variant;
+                if other.name == m.name {
+                    match &m.reply {
+                        Some(_) => quote! { #reply::#other_variant(value) => Ok(value), },
+                        None => quote! { #reply::#other_variant => Ok(()), },
+                    }
+                } else {
+                    let pattern = match &other.reply {
+                        Some(_) => quote! { #reply::#other_variant(..) },
+                        None => quote! { #reply::#other_variant },
+                    };
+                    quote! {
+                        #pattern => Err(::hdds::rpc::RpcError::DeserializationError(
+                            #unexpected.to_string(),
+                        )),
+                    }
+                }
+            })
+            .collect();
+        let doc = format!("Call `{trait_name}::{name}` and wait for the reply.");
+        let doc_async = format!("Call `{trait_name}::{name}` without blocking the executor.");
+
+        quote! {
+            #[doc = #doc]
+            pub fn #name(&self, #(#params),*) -> ::hdds::rpc::RpcResult<#output> {
+                match self.client.call(&#request::#variant { #(#names),* })? {
+                    #(#arms)*
+                }
+            }
+
+            #[doc = #doc_async]
+            pub async fn #name_async(&self, #(#params_async),*) -> ::hdds::rpc::RpcResult<#output> {
+                match self.client.call_async(&#request::#variant { #(#names),* }).await? {
+                    #(#arms)*
+                }
+            }
+        }
+    });
+
+    let doc = format!("Typed client of a [`{trait_name}`] service.");
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Clone)]
+        #vis struct #client {
+            client: ::hdds::rpc::Client<#request, #reply>,
+        }
+
+        impl #client {
+            /// Create a client of the service `service_name`.
+            pub fn new(
+                participant: &::std::sync::Arc<::hdds::Participant>,
+                service_name: &str,
+            ) -> ::hdds::rpc::RpcResult<Self> {
+                Ok(Self {
+                    client: ::hdds::rpc::Client::new(participant, service_name)?,
+                })
+            }
+
+            /// Set the reply timeout of every call.
+            #[must_use]
+            pub fn timeout(self, timeout: ::std::time::Duration) -> Self {
+                Self {
+                    client: self.client.timeout(timeout),
+                }
+            }
+
+            /// Underlying untyped-operation client, for [`send`](::hdds::rpc::Client::send)
+            /// and [`wait_for_service`](::hdds::rpc::Client::wait_for_service).
+            pub fn client(&self) -> &::hdds::rpc::Client<#request, #reply> {
+                &self.client
+            }
+
+            #(#calls)*
+        }
+    }
+}
@@ -14,7 +14,7 @@ pub mod registry;
 pub mod relay;
 
 pub use connection::ClientConnection;
-use protocol::{DiscoveryMessage, ParticipantInfoWire};
+use protocol::{DiscoveryMessage, EndpointInfoWire, ParticipantInfoWire};
 pub use registry::{ParticipantInfo, ParticipantRegistry};
 pub use relay::{RelayRouter, RelayStats};
 
@@ -267,7 +267,7 @@ impl DiscoveryServer {
                 })
                 .await?;
 
-                // Send current participant list to new participant
+                // Send current participant and endpoint database to new participant
                 {
                     let reg = registry.read().await;
                     for (_, participant) in reg.participants() {
@@ -277,6 +277,13 @@ impl DiscoveryServer {
                                 .await?;
                         }
                     }
+                    for (guid, endpoint) in reg.endpoints() {
+                        if guid.prefix != guid_prefix {
+                            let wire: EndpointInfoWire = endpoint.clone().into();
+                            conn.send_message(DiscoveryMessage::EndpointAnnounce(wire))
+                                .await?;
+                        }
+                    }
                 }
 
                 // Broadcast new participant to existing participants
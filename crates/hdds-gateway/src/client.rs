@@ -19,6 +19,9 @@ pub enum Command {
     GetHealth = 0x04,
     GetWriters = 0x05,
     GetReaders = 0x06,
+    GetAllocations = 0x07,
+    GetLocatorStats = 0x09,
+    GetPrometheusMetrics = 0x0B,
 }
 
 /// Admin API client
@@ -115,6 +118,18 @@ impl AdminClient {
     pub fn get_readers(&self) -> Result<String, std::io::Error> {
         self.request(Command::GetReaders)
     }
+
+    pub fn get_allocations(&self) -> Result<String, std::io::Error> {
+        self.request(Command::GetAllocations)
+    }
+
+    pub fn get_locator_stats(&self) -> Result<String, std::io::Error> {
+        self.request(Command::GetLocatorStats)
+    }
+
+    pub fn get_prometheus_metrics(&self) -> Result<String, std::io::Error> {
+        self.request(Command::GetPrometheusMetrics)
+    }
 }
 
 #[cfg(test)]
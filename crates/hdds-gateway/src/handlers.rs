@@ -92,6 +92,19 @@ pub async fn metrics(State(state): State<Arc<AppState>>) -> Result<Response, Api
         .into_response())
 }
 
+/// GET /api/v1/metrics/prometheus - metrics in the Prometheus text format
+pub async fn prometheus_metrics(State(state): State<Arc<AppState>>) -> Result<Response, ApiError> {
+    let client = state.get_client().await?;
+    let text = client.get_prometheus_metrics()?;
+
+    Ok((
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        text,
+    )
+        .into_response())
+}
+
 /// GET /api/v1/writers - `DataWriters`
 pub async fn writers(State(state): State<Arc<AppState>>) -> Result<Response, ApiError> {
     let client = state.get_client().await?;
@@ -118,6 +131,32 @@ pub async fn readers(State(state): State<Arc<AppState>>) -> Result<Response, Api
         .into_response())
 }
 
+/// GET /api/v1/allocations - Live bytes per subsystem (alloc-tracking builds)
+pub async fn allocations(State(state): State<Arc<AppState>>) -> Result<Response, ApiError> {
+    let client = state.get_client().await?;
+    let json = client.get_allocations()?;
+
+    Ok((
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "application/json")],
+        json,
+    )
+        .into_response())
+}
+
+/// GET /api/v1/locators - Sequence gaps, reordering and loss per remote locator
+pub async fn locators(State(state): State<Arc<AppState>>) -> Result<Response, ApiError> {
+    let client = state.get_client().await?;
+    let json = client.get_locator_stats()?;
+
+    Ok((
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "application/json")],
+        json,
+    )
+        .into_response())
+}
+
 /// GET /api/v1/info - Gateway info
 pub async fn info() -> Response {
     let info = serde_json::json!({
@@ -129,8 +168,11 @@ pub async fn info() -> Response {
             "/api/v1/mesh",
             "/api/v1/topics",
             "/api/v1/metrics",
+            "/api/v1/metrics/prometheus",
             "/api/v1/writers",
             "/api/v1/readers",
+            "/api/v1/allocations",
+            "/api/v1/locators",
             "/api/v1/info"
         ]
     });
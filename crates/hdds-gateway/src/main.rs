@@ -23,6 +23,7 @@
 //! - `GET /api/v1/mesh` - Discovered participants
 //! - `GET /api/v1/topics` - Active topics
 //! - `GET /api/v1/metrics` - Runtime metrics
+//! - `GET /api/v1/metrics/prometheus` - Runtime metrics for Prometheus scraping
 //! - `GET /api/v1/writers` - `DataWriters`
 //! - `GET /api/v1/readers` - `DataReaders`
 
@@ -25,8 +25,14 @@ pub fn api_routes() -> Router<Arc<AppState>> {
         .route("/api/v1/mesh", get(handlers::mesh))
         .route("/api/v1/topics", get(handlers::topics))
         .route("/api/v1/metrics", get(handlers::metrics))
+        .route(
+            "/api/v1/metrics/prometheus",
+            get(handlers::prometheus_metrics),
+        )
         .route("/api/v1/writers", get(handlers::writers))
         .route("/api/v1/readers", get(handlers::readers))
+        .route("/api/v1/allocations", get(handlers::allocations))
+        .route("/api/v1/locators", get(handlers::locators))
         .route("/api/v1/info", get(handlers::info))
         // Legacy routes (compatibility with hdds-debugger)
         .route("/health", get(handlers::health))
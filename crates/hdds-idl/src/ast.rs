@@ -0,0 +1,265 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Declarations found in an IDL file.
+//!
+//! The model keeps what the text says; whether a declaration can be used
+//! (code generation, schema registration) is up to the consumer.
+
+/// Constants, enums and types found in an IDL file.
+#[derive(Debug, Clone, Default)]
+pub struct IdlItems {
+    pub consts: Vec<ConstSpec>,
+    pub enums: Vec<EnumSpec>,
+    /// Structs, unions and typedefs, in declaration order.
+    pub types: Vec<TypeDecl>,
+    /// Declarations that could not be parsed.
+    pub skipped: Vec<SkippedType>,
+}
+
+/// A declaration the front-end does not support.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkippedType {
+    /// Qualified name
+    pub name: String,
+    pub reason: String,
+}
+
+/// Primitive member type, strings included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrimitiveKind {
+    Bool,
+    Char,
+    WChar,
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    I64,
+    U64,
+    F32,
+    F64,
+    LongDouble,
+    String { max_length: Option<usize> },
+    WString { max_length: Option<usize> },
+}
+
+/// Type of a struct member, union case or typedef.
+///
+/// Typedefs are resolved: members use the aliased type directly.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IdlType {
+    /// Primitive or (bounded) string
+    Primitive(PrimitiveKind),
+    /// `octet` (`u8`, but `TK_BYTE` in TypeObjects)
+    Octet,
+    Sequence {
+        element: Box<IdlType>,
+        bound: Option<usize>,
+    },
+    Array {
+        element: Box<IdlType>,
+        length: usize,
+    },
+    /// Enum, struct or union; names not declared in the file (e.g. from an
+    /// `#include`) are kept as written.
+    Named {
+        namespace: Vec<String>,
+        name: String,
+    },
+}
+
+/// XTypes extensibility of a struct or union.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Extensibility {
+    #[default]
+    Final,
+    Appendable,
+    Mutable,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemberDef {
+    pub name: String,
+    pub ty: IdlType,
+    /// `@key`
+    pub key: bool,
+    /// `@optional`
+    pub optional: bool,
+    /// `@external`
+    pub external: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructDef {
+    pub namespace: Vec<String>,
+    pub name: String,
+    pub extensibility: Extensibility,
+    /// Base struct (`struct Child : Base`), an [`IdlType::Named`]
+    pub base: Option<IdlType>,
+    pub members: Vec<MemberDef>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnionCase {
+    pub name: String,
+    pub ty: IdlType,
+    /// Label values (enumerators, chars and booleans as integers)
+    pub labels: Vec<i32>,
+    /// `default:` case
+    pub is_default: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnionDef {
+    pub namespace: Vec<String>,
+    pub name: String,
+    pub extensibility: Extensibility,
+    /// Integer, `boolean`, `char`, `octet` or enum type
+    pub discriminator: IdlType,
+    pub cases: Vec<UnionCase>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypedefDef {
+    pub namespace: Vec<String>,
+    pub name: String,
+    pub ty: IdlType,
+}
+
+/// Struct, union or typedef declaration.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeDecl {
+    Struct(StructDef),
+    Union(UnionDef),
+    Typedef(TypedefDef),
+}
+
+impl TypeDecl {
+    pub fn namespace(&self) -> &[String] {
+        match self {
+            TypeDecl::Struct(def) => &def.namespace,
+            TypeDecl::Union(def) => &def.namespace,
+            TypeDecl::Typedef(def) => &def.namespace,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            TypeDecl::Struct(def) => &def.name,
+            TypeDecl::Union(def) => &def.name,
+            TypeDecl::Typedef(def) => &def.name,
+        }
+    }
+
+    /// Qualified IDL name (`sensors::Reading`).
+    pub fn qualified_name(&self) -> String {
+        qualified(self.namespace(), self.name())
+    }
+}
+
+/// Type of an IDL constant.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstType {
+    Primitive(PrimitiveConst),
+    String,
+    /// Enum-typed constant; path relative to the constant's module.
+    Enum(String),
+}
+
+/// IDL primitive types allowed in `const` declarations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrimitiveConst {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    I64,
+    U64,
+    F32,
+    F64,
+    Bool,
+    Char,
+}
+
+/// Evaluated value of an IDL constant.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstValue {
+    Int(i128),
+    Float(f64),
+    Bool(bool),
+    Char(char),
+    Str(String),
+    /// Enumerator name (for enum-typed constants).
+    Enumerator(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct ConstSpec {
+    pub namespace: Vec<String>,
+    pub name: String,
+    pub ty: ConstType,
+    pub value: ConstValue,
+}
+
+impl ConstSpec {
+    pub fn new(
+        namespace: Vec<String>,
+        name: impl Into<String>,
+        ty: ConstType,
+        value: ConstValue,
+    ) -> Self {
+        Self {
+            namespace,
+            name: name.into(),
+            ty,
+            value,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EnumSpec {
+    pub namespace: Vec<String>,
+    pub name: String,
+    /// Enumerators with their values (IDL default: declaration order).
+    pub variants: Vec<(String, u32)>,
+}
+
+impl EnumSpec {
+    pub fn new(namespace: Vec<String>, name: impl Into<String>) -> Self {
+        Self {
+            namespace,
+            name: name.into(),
+            variants: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_variants(mut self, variants: Vec<(String, u32)>) -> Self {
+        self.variants = variants;
+        self
+    }
+}
+
+/// `namespace::name`, or `name` at the root.
+pub fn qualified(namespace: &[String], name: &str) -> String {
+    if namespace.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}::{}", namespace.join("::"), name)
+    }
+}
+
+/// Path of `scope::name` as seen from module `from` (`super::` based).
+pub fn relative_path(from: &[String], scope: &[String], name: &str) -> String {
+    let common = from.iter().zip(scope).take_while(|(a, b)| a == b).count();
+    let mut parts: Vec<&str> = vec!["super"; from.len() - common];
+    parts.extend(scope[common..].iter().map(String::as_str));
+    parts.push(name);
+    parts.join("::")
+}
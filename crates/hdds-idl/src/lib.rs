@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! IDL front-end shared by `hdds-gen` and the schema registry.
+//!
+//! Parses `module`s, `const`s, enums, structs, unions and typedefs into
+//! [`IdlItems`]. Constant expressions are evaluated: literals, references
+//! to earlier constants and enumerators, and the IDL operators
+//! `| ^ & << >> + - * / % ~`. Enumerators honor `@value(N)`; struct members
+//! carry `@key`, `@optional` and `@external`, and structs their base and
+//! extensibility.
+//!
+//! Names are resolved against earlier declarations, innermost scope first;
+//! typedefs are replaced by the aliased type. What a consumer supports is
+//! up to it: `hdds-gen` skips what it cannot emit, the registry rejects
+//! what it cannot store.
+//!
+//! ```
+//! let items = hdds_idl::parse_idl("module m { struct S { @key long id; }; };").unwrap();
+//! assert_eq!(items.types[0].qualified_name(), "m::S");
+//! ```
+
+mod ast;
+mod parser;
+
+pub use ast::{
+    qualified, relative_path, ConstSpec, ConstType, ConstValue, EnumSpec, Extensibility, IdlItems,
+    IdlType, MemberDef, PrimitiveConst, PrimitiveKind, SkippedType, StructDef, TypeDecl,
+    TypedefDef, UnionCase, UnionDef,
+};
+pub use parser::parse_idl;
@@ -0,0 +1,1190 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Tokenizer and recursive-descent parser.
+
+use crate::ast::{
+    relative_path, ConstSpec, ConstType, ConstValue, EnumSpec, Extensibility, IdlItems, IdlType,
+    MemberDef, PrimitiveConst, PrimitiveKind, SkippedType, StructDef, TypeDecl, TypedefDef,
+    UnionCase, UnionDef,
+};
+use anyhow::{anyhow, bail, Result};
+use std::collections::HashMap;
+
+/// Parse the declarations of an IDL text.
+///
+/// A struct, union or typedef that fails to parse is skipped and listed in
+/// [`IdlItems::skipped`], as are declarations the front-end does not know
+/// (`interface`, `bitmask`, ...). Other errors fail the whole text.
+pub fn parse_idl(text: &str) -> Result<IdlItems> {
+    let mut parser = Parser {
+        tokens: tokenize(text)?,
+        pos: 0,
+        scope: Vec::new(),
+        items: IdlItems::default(),
+        values: HashMap::new(),
+        enumerators: HashMap::new(),
+        kinds: HashMap::new(),
+        aliases: HashMap::new(),
+    };
+    parser.parse_definitions(false)?;
+    Ok(parser.items)
+}
+
+// ---------------------------------------------------------------------------
+// Tokenizer
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Int(i128),
+    Float(f64),
+    Str(String),
+    Char(char),
+    Punct(char),
+}
+
+/// Scope separator (`::` is tokenized as a single `$` punct).
+const SCOPE: Token = Token::Punct('$');
+
+fn tokenize(text: &str) -> Result<Vec<Token>> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '/' && chars.get(i + 1) == Some(&'/') || c == '#' {
+            // Line comment or preprocessor directive
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && chars.get(i + 1) == Some(&'*') {
+            i += 2;
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                i += 1;
+            }
+            if i >= chars.len() {
+                bail!("unterminated block comment");
+            }
+            i += 2;
+        } else if c == '"' || c == '\'' {
+            i += 1;
+            let mut literal = String::new();
+            while i < chars.len() && chars[i] != c {
+                if chars[i] == '\\' {
+                    i += 1;
+                    let escaped = chars
+                        .get(i)
+                        .ok_or_else(|| anyhow!("unterminated literal"))?;
+                    literal.push(match escaped {
+                        'n' => '\n',
+                        't' => '\t',
+                        'r' => '\r',
+                        '0' => '\0',
+                        other => *other,
+                    });
+                } else {
+                    literal.push(chars[i]);
+                }
+                i += 1;
+            }
+            if i >= chars.len() {
+                bail!("unterminated literal");
+            }
+            i += 1;
+            if c == '"' {
+                tokens.push(Token::Str(literal));
+            } else {
+                let mut it = literal.chars();
+                match (it.next(), it.next()) {
+                    (Some(ch), None) => tokens.push(Token::Char(ch)),
+                    _ => bail!("invalid character literal '{}'", literal),
+                }
+            }
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push(Token::Ident(chars[start..i].iter().collect()));
+        } else if c.is_ascii_digit()
+            || c == '.' && chars.get(i + 1).is_some_and(char::is_ascii_digit)
+        {
+            let start = i;
+            let hex = c == '0' && matches!(chars.get(i + 1), Some('x' | 'X'));
+            while i < chars.len() {
+                let ch = chars[i];
+                let exponent_sign = !hex
+                    && (ch == '+' || ch == '-')
+                    && matches!(chars.get(i.wrapping_sub(1)), Some('e' | 'E'));
+                if ch.is_ascii_alphanumeric() || ch == '.' || exponent_sign {
+                    i += 1;
+                } else {
+                    break;
+                }
+            }
+            let literal: String = chars[start..i].iter().collect();
+            tokens.push(parse_number(&literal)?);
+        } else if c == ':' && chars.get(i + 1) == Some(&':') {
+            tokens.push(SCOPE);
+            i += 2;
+        } else {
+            tokens.push(Token::Punct(c));
+            i += 1;
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn parse_number(literal: &str) -> Result<Token> {
+    let invalid = || anyhow!("invalid number literal '{}'", literal);
+    if let Some(hex) = literal
+        .strip_prefix("0x")
+        .or_else(|| literal.strip_prefix("0X"))
+    {
+        return i128::from_str_radix(hex, 16)
+            .map(Token::Int)
+            .map_err(|_| invalid());
+    }
+    if literal.contains(['.', 'e', 'E']) {
+        let trimmed = literal.trim_end_matches(['d', 'D', 'f', 'F']);
+        return trimmed.parse().map(Token::Float).map_err(|_| invalid());
+    }
+    let digits = literal.trim_end_matches(['l', 'L', 'u', 'U']);
+    if digits.len() > 1 && digits.starts_with('0') {
+        return i128::from_str_radix(&digits[1..], 8)
+            .map(Token::Int)
+            .map_err(|_| invalid());
+    }
+    digits.parse().map(Token::Int).map_err(|_| invalid())
+}
+
+// ---------------------------------------------------------------------------
+// Parser
+// ---------------------------------------------------------------------------
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    scope: Vec<String>,
+    items: IdlItems,
+    /// Fully qualified constant name -> value
+    values: HashMap<String, Value>,
+    /// Fully qualified enumerator name -> (enum scope, enum name, enumerator, value)
+    enumerators: HashMap<String, (Vec<String>, String, String, u32)>,
+    /// Fully qualified enum, struct or union name -> kind
+    kinds: HashMap<String, NamedKind>,
+    /// Fully qualified typedef name -> aliased type
+    aliases: HashMap<String, IdlType>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NamedKind {
+    Enum,
+    Struct,
+    Union,
+}
+
+/// Annotations preceding a declaration or member.
+#[derive(Default)]
+struct Annotations {
+    names: Vec<String>,
+    /// `@value(N)` argument
+    value: Option<i128>,
+    /// `@extensibility(KIND)` argument
+    extensibility: Option<String>,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.pos + offset)
+    }
+
+    fn next(&mut self) -> Result<Token> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or_else(|| anyhow!("unexpected end of IDL"))?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn is_punct(&self, c: char) -> bool {
+        self.peek() == Some(&Token::Punct(c))
+    }
+
+    fn eat_punct(&mut self, c: char) -> bool {
+        let found = self.is_punct(c);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn is_ident(&self, word: &str) -> bool {
+        matches!(self.peek(), Some(Token::Ident(w)) if w == word)
+    }
+
+    fn expect_punct(&mut self, c: char) -> Result<()> {
+        match self.next()? {
+            Token::Punct(p) if p == c => Ok(()),
+            other => bail!("expected '{}', found {:?}", c, other),
+        }
+    }
+
+    fn expect_ident(&mut self) -> Result<String> {
+        match self.next()? {
+            Token::Ident(w) => Ok(w),
+            other => bail!("expected identifier, found {:?}", other),
+        }
+    }
+
+    fn qualify(scope: &[String], name: &str) -> String {
+        if scope.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}::{}", scope.join("::"), name)
+        }
+    }
+
+    /// Skip tokens up to and including the next `;` at nesting depth 0.
+    fn skip_statement(&mut self) -> Result<()> {
+        let mut depth = 0usize;
+        loop {
+            match self.next()? {
+                Token::Punct('{' | '(') => depth += 1,
+                Token::Punct('}' | ')') => depth = depth.saturating_sub(1),
+                Token::Punct(';') if depth == 0 => return Ok(()),
+                _ => {}
+            }
+        }
+    }
+
+    fn parse_annotations(&mut self) -> Result<Annotations> {
+        let mut annotations = Annotations::default();
+        while self.eat_punct('@') {
+            let name = self.expect_ident()?;
+            annotations.names.push(name.clone());
+            if !self.is_punct('(') {
+                continue;
+            }
+            if name == "value" {
+                self.pos += 1;
+                annotations.value = Some(self.expect_int()?);
+                self.expect_punct(')')?;
+                continue;
+            }
+            if name == "extensibility" {
+                self.pos += 1;
+                annotations.extensibility = Some(self.expect_ident()?);
+                self.expect_punct(')')?;
+                continue;
+            }
+            let mut depth = 0usize;
+            loop {
+                match self.next()? {
+                    Token::Punct('(') => depth += 1,
+                    Token::Punct(')') => {
+                        depth -= 1;
+                        if depth == 0 {
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(annotations)
+    }
+
+    fn parse_definitions(&mut self, nested: bool) -> Result<()> {
+        loop {
+            let annotations = self.parse_annotations()?;
+            match self.peek() {
+                None if !nested => return Ok(()),
+                None => bail!("unexpected end of IDL inside module"),
+                Some(Token::Punct('}')) if nested => return Ok(()),
+                Some(Token::Punct(';')) => self.pos += 1,
+                Some(Token::Ident(word)) => match word.as_str() {
+                    "module" => self.parse_module()?,
+                    "const" => self.parse_const()?,
+                    "enum" => self.parse_enum()?,
+                    "struct" => self.parse_struct(&annotations)?,
+                    "union" => self.parse_union(&annotations)?,
+                    "typedef" => self.parse_typedef()?,
+                    "import" => self.skip_statement()?,
+                    _ => self.skip_declaration()?,
+                },
+                Some(other) => bail!("unexpected token {:?}", other),
+            }
+        }
+    }
+
+    /// Skip an unknown declaration (`interface`, `bitmask`, ...), listing it
+    /// in [`IdlItems::skipped`].
+    fn skip_declaration(&mut self) -> Result<()> {
+        let keyword = self.expect_ident()?;
+        let name = match self.peek() {
+            Some(Token::Ident(name)) => name.clone(),
+            _ => keyword.clone(),
+        };
+        self.skip_statement()?;
+        self.items.skipped.push(SkippedType {
+            name: Self::qualify(&self.scope, &name),
+            reason: format!("unsupported declaration '{}'", keyword),
+        });
+        Ok(())
+    }
+
+    fn parse_module(&mut self) -> Result<()> {
+        self.pos += 1; // module
+        let name = self.expect_ident()?;
+        self.expect_punct('{')?;
+        self.scope.push(name);
+        self.parse_definitions(true)?;
+        self.scope.pop();
+        self.expect_punct('}')?;
+        self.eat_punct(';');
+        Ok(())
+    }
+
+    fn parse_enum(&mut self) -> Result<()> {
+        self.pos += 1; // enum
+        let name = self.expect_ident()?;
+        self.expect_punct('{')?;
+        let mut variants: Vec<(String, u32)> = Vec::new();
+        let mut next_value: u32 = 0;
+        while !self.is_punct('}') {
+            let explicit = self.parse_annotations()?.value;
+            let variant = self.expect_ident()?;
+            let value = match explicit {
+                Some(v) => u32::try_from(v).map_err(|_| {
+                    anyhow!("enumerator {}::{} value {} out of range", name, variant, v)
+                })?,
+                None => next_value,
+            };
+            if variants.iter().any(|(_, existing)| *existing == value) {
+                bail!("duplicate value {} in enum {}", value, name);
+            }
+            next_value = value.wrapping_add(1);
+            variants.push((variant, value));
+            if !self.eat_punct(',') {
+                break;
+            }
+        }
+        self.expect_punct('}')?;
+        self.eat_punct(';');
+        if variants.is_empty() {
+            bail!("enum {} has no enumerators", name);
+        }
+
+        // Enumerators live in the enclosing scope (IDL 3) and in the enum's
+        // own scope (IDL 4 `Enum::VARIANT`).
+        let enum_path = Self::qualify(&self.scope, &name);
+        for (variant, value) in &variants {
+            let entry = (self.scope.clone(), name.clone(), variant.clone(), *value);
+            self.enumerators
+                .insert(Self::qualify(&self.scope, variant), entry.clone());
+            self.enumerators
+                .insert(format!("{}::{}", enum_path, variant), entry);
+        }
+        self.kinds.insert(enum_path, NamedKind::Enum);
+        self.items
+            .enums
+            .push(EnumSpec::new(self.scope.clone(), name).with_variants(variants));
+        Ok(())
+    }
+
+    /// Run `parse` on a type declaration, skipping it on failure.
+    fn parse_type_decl(
+        &mut self,
+        name: String,
+        parse: impl FnOnce(&mut Self) -> Result<()>,
+    ) -> Result<()> {
+        let start = self.pos;
+        if let Err(err) = parse(self) {
+            self.pos = start;
+            self.skip_statement()?;
+            self.items.skipped.push(SkippedType {
+                name: Self::qualify(&self.scope, &name),
+                reason: err.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Extensibility from `@final`/`@appendable`/`@mutable` or
+    /// `@extensibility(KIND)`.
+    fn extensibility(annotations: &Annotations) -> Result<Extensibility> {
+        let kind = annotations.extensibility.as_deref().or_else(|| {
+            annotations
+                .names
+                .iter()
+                .map(String::as_str)
+                .find(|name| matches!(*name, "final" | "appendable" | "mutable"))
+        });
+        match kind.map(str::to_ascii_lowercase).as_deref() {
+            None | Some("final") => Ok(Extensibility::Final),
+            Some("appendable") => Ok(Extensibility::Appendable),
+            Some("mutable") => Ok(Extensibility::Mutable),
+            Some(other) => bail!("unknown extensibility '{}'", other),
+        }
+    }
+
+    /// Name of a struct or union, or `None` after skipping a forward
+    /// declaration.
+    fn type_name(&mut self, keyword: &str) -> Result<Option<String>> {
+        let name = match self.peek_at(1) {
+            Some(Token::Ident(name)) => name.clone(),
+            other => bail!("expected {} name, found {:?}", keyword, other),
+        };
+        if self.peek_at(2) == Some(&Token::Punct(';')) {
+            self.pos += 3; // forward declaration
+            return Ok(None);
+        }
+        Ok(Some(name))
+    }
+
+    fn parse_struct(&mut self, annotations: &Annotations) -> Result<()> {
+        let Some(name) = self.type_name("struct")? else {
+            return Ok(());
+        };
+        let extensibility = Self::extensibility(annotations);
+
+        self.parse_type_decl(name.clone(), |parser| {
+            let extensibility = extensibility?;
+            parser.pos += 2; // struct Name
+            let base = if parser.eat_punct(':') {
+                let base_name = parser.parse_scoped_name()?;
+                match parser.resolve_type(&base_name) {
+                    base @ IdlType::Named { .. } => Some(base),
+                    _ => bail!("base of struct {} is not a struct", name),
+                }
+            } else {
+                None
+            };
+            parser.expect_punct('{')?;
+            let mut members = Vec::new();
+            while !parser.eat_punct('}') {
+                let annotations = parser.parse_annotations()?;
+                let flag = |flag: &str| annotations.names.iter().any(|name| name == flag);
+                let (key, optional, external) = (flag("key"), flag("optional"), flag("external"));
+                let ty = parser.parse_member_type()?;
+                loop {
+                    let name = parser.expect_ident()?;
+                    let ty = parser.parse_array_dims(&ty)?;
+                    members.push(MemberDef {
+                        name,
+                        ty,
+                        key,
+                        optional,
+                        external,
+                    });
+                    if !parser.eat_punct(',') {
+                        break;
+                    }
+                }
+                parser.expect_punct(';')?;
+            }
+            parser.expect_punct(';')?;
+
+            parser
+                .kinds
+                .insert(Self::qualify(&parser.scope, &name), NamedKind::Struct);
+            parser.items.types.push(TypeDecl::Struct(StructDef {
+                namespace: parser.scope.clone(),
+                name: name.clone(),
+                extensibility,
+                base,
+                members,
+            }));
+            Ok(())
+        })
+    }
+
+    fn parse_union(&mut self, annotations: &Annotations) -> Result<()> {
+        let Some(name) = self.type_name("union")? else {
+            return Ok(());
+        };
+        let extensibility = Self::extensibility(annotations);
+
+        self.parse_type_decl(name.clone(), |parser| {
+            let extensibility = extensibility?;
+            parser.pos += 2; // union Name
+            if !parser.is_ident("switch") {
+                bail!("expected 'switch' after union {}", name);
+            }
+            parser.pos += 1;
+            parser.expect_punct('(')?;
+            let discriminator = parser.parse_member_type()?;
+            parser.expect_punct(')')?;
+            let (unsigned, enum_path) = match &discriminator {
+                IdlType::Primitive(
+                    PrimitiveKind::U8
+                    | PrimitiveKind::U16
+                    | PrimitiveKind::U32
+                    | PrimitiveKind::U64,
+                )
+                | IdlType::Octet => (true, None),
+                IdlType::Primitive(
+                    PrimitiveKind::I8
+                    | PrimitiveKind::I16
+                    | PrimitiveKind::I32
+                    | PrimitiveKind::I64
+                    | PrimitiveKind::Bool
+                    | PrimitiveKind::Char,
+                ) => (false, None),
+                IdlType::Named { namespace, name }
+                    if parser.kinds.get(&Self::qualify(namespace, name))
+                        == Some(&NamedKind::Enum) =>
+                {
+                    (true, Some((namespace.clone(), name.clone())))
+                }
+                other => bail!("unsupported union discriminator {:?}", other),
+            };
+
+            parser.expect_punct('{')?;
+            let mut cases: Vec<UnionCase> = Vec::new();
+            while !parser.eat_punct('}') {
+                let mut labels = Vec::new();
+                let mut is_default = false;
+                loop {
+                    if parser.is_ident("case") {
+                        parser.pos += 1;
+                        let label = parser.parse_expr()?;
+                        labels.push(Self::label_value(label, unsigned, enum_path.as_ref())?);
+                        parser.expect_punct(':')?;
+                    } else if parser.is_ident("default") {
+                        parser.pos += 1;
+                        parser.expect_punct(':')?;
+                        is_default = true;
+                    } else {
+                        break;
+                    }
+                }
+                if labels.is_empty() && !is_default {
+                    bail!("expected 'case' or 'default' in union {}", name);
+                }
+                parser.parse_annotations()?;
+                let ty = parser.parse_member_type()?;
+                let case_name = parser.expect_ident()?;
+                let ty = parser.parse_array_dims(&ty)?;
+                parser.expect_punct(';')?;
+                cases.push(UnionCase {
+                    name: case_name,
+                    ty,
+                    labels,
+                    is_default,
+                });
+            }
+            parser.expect_punct(';')?;
+
+            if cases.iter().filter(|case| case.is_default).count() > 1 {
+                bail!("union {} has several default cases", name);
+            }
+            if cases.iter().all(|case| case.labels.is_empty()) {
+                bail!("union {} has no case label", name);
+            }
+            let mut seen = Vec::new();
+            for label in cases.iter().flat_map(|case| &case.labels) {
+                if seen.contains(label) {
+                    bail!("duplicate label {} in union {}", label, name);
+                }
+                seen.push(*label);
+            }
+
+            parser
+                .kinds
+                .insert(Self::qualify(&parser.scope, &name), NamedKind::Union);
+            parser.items.types.push(TypeDecl::Union(UnionDef {
+                namespace: parser.scope.clone(),
+                name: name.clone(),
+                extensibility,
+                discriminator,
+                cases,
+            }));
+            Ok(())
+        })
+    }
+
+    /// Integer value of a union case label.
+    fn label_value(
+        label: Value,
+        unsigned: bool,
+        enum_path: Option<&(Vec<String>, String)>,
+    ) -> Result<i32> {
+        let value = match (label, enum_path) {
+            (
+                Value::Enumerator {
+                    scope,
+                    enum_name,
+                    variant,
+                    value,
+                },
+                Some((enum_scope, name)),
+            ) => {
+                if scope != *enum_scope || enum_name != *name {
+                    bail!("label {} is not an enumerator of {}", variant, name);
+                }
+                i128::from(value)
+            }
+            (label, Some((_, name))) => bail!("label {:?} is not an enumerator of {}", label, name),
+            (Value::Int(n), None) => n,
+            (Value::Char(c), None) => i128::from(u32::from(c)),
+            (Value::Bool(b), None) => i128::from(b),
+            (other, None) => bail!("invalid union label {:?}", other),
+        };
+        if unsigned && value < 0 {
+            bail!("negative label {} for an unsigned discriminator", value);
+        }
+        i32::try_from(value).map_err(|_| anyhow!("union label {} out of range", value))
+    }
+
+    fn parse_typedef(&mut self) -> Result<()> {
+        let start = self.pos;
+        self.pos += 1; // typedef
+                       // The declared name follows the type; find it for error reports
+        let name = self.tokens[self.pos..]
+            .iter()
+            .take_while(|token| **token != Token::Punct(';'))
+            .filter_map(|token| match token {
+                Token::Ident(word) => Some(word.clone()),
+                _ => None,
+            })
+            .last()
+            .unwrap_or_default();
+        self.pos = start;
+
+        self.parse_type_decl(name, |parser| {
+            parser.pos += 1; // typedef
+            let ty = parser.parse_member_type()?;
+            loop {
+                let alias = parser.expect_ident()?;
+                let ty = parser.parse_array_dims(&ty)?;
+                parser
+                    .aliases
+                    .insert(Self::qualify(&parser.scope, &alias), ty.clone());
+                parser.items.types.push(TypeDecl::Typedef(TypedefDef {
+                    namespace: parser.scope.clone(),
+                    name: alias,
+                    ty,
+                }));
+                if !parser.eat_punct(',') {
+                    break;
+                }
+            }
+            parser.expect_punct(';')
+        })
+    }
+
+    /// Parse a member type (without array dimensions).
+    fn parse_member_type(&mut self) -> Result<IdlType> {
+        let word = match self.peek() {
+            Some(Token::Ident(_)) => self.expect_ident()?,
+            Some(Token::Punct('$')) => {
+                let name = self.parse_scoped_name()?;
+                return Ok(self.resolve_type(&name));
+            }
+            other => bail!("expected member type, found {:?}", other),
+        };
+        let kind = match word.as_str() {
+            "unsigned" => {
+                let inner = self.expect_ident()?;
+                match inner.as_str() {
+                    "short" => PrimitiveKind::U16,
+                    "long" if self.is_ident("long") => {
+                        self.pos += 1;
+                        PrimitiveKind::U64
+                    }
+                    "long" => PrimitiveKind::U32,
+                    other => bail!("unsupported member type 'unsigned {}'", other),
+                }
+            }
+            "long" if self.is_ident("long") => {
+                self.pos += 1;
+                PrimitiveKind::I64
+            }
+            "long" if self.is_ident("double") => {
+                self.pos += 1;
+                PrimitiveKind::LongDouble
+            }
+            "long" | "int32" => PrimitiveKind::I32,
+            "short" | "int16" => PrimitiveKind::I16,
+            "int8" => PrimitiveKind::I8,
+            "octet" => return Ok(IdlType::Octet),
+            "uint8" => PrimitiveKind::U8,
+            "uint16" => PrimitiveKind::U16,
+            "uint32" => PrimitiveKind::U32,
+            "int64" => PrimitiveKind::I64,
+            "uint64" => PrimitiveKind::U64,
+            "float" => PrimitiveKind::F32,
+            "double" => PrimitiveKind::F64,
+            "boolean" => PrimitiveKind::Bool,
+            "char" => PrimitiveKind::Char,
+            "wchar" => PrimitiveKind::WChar,
+            "string" => PrimitiveKind::String {
+                max_length: self.parse_bound()?,
+            },
+            "wstring" => PrimitiveKind::WString {
+                max_length: self.parse_bound()?,
+            },
+            "sequence" => {
+                self.expect_punct('<')?;
+                let element = self.parse_member_type()?;
+                let bound = if self.eat_punct(',') {
+                    Some(self.expect_size()?)
+                } else {
+                    None
+                };
+                self.expect_punct('>')?;
+                return Ok(IdlType::Sequence {
+                    element: Box::new(element),
+                    bound,
+                });
+            }
+            "any" | "map" | "fixed" | "Object" | "ValueBase" => {
+                bail!("unsupported member type '{}'", word)
+            }
+            _ => {
+                self.pos -= 1;
+                let name = self.parse_scoped_name()?;
+                return Ok(self.resolve_type(&name));
+            }
+        };
+        Ok(IdlType::Primitive(kind))
+    }
+
+    /// Parse an optional `<N>` string bound.
+    fn parse_bound(&mut self) -> Result<Option<usize>> {
+        if !self.eat_punct('<') {
+            return Ok(None);
+        }
+        let bound = self.expect_size()?;
+        self.expect_punct('>')?;
+        Ok(Some(bound))
+    }
+
+    /// Parse a size (bound or array dimension) expression.
+    ///
+    /// Stops before shifts so that the `>` closing a template is not read
+    /// as `>>`.
+    fn expect_size(&mut self) -> Result<usize> {
+        match self.parse_add()? {
+            Value::Int(n) => usize::try_from(n).map_err(|_| anyhow!("invalid size {}", n)),
+            other => bail!("expected size, found {:?}", other),
+        }
+    }
+
+    /// Wrap `ty` in the `[N]...` dimensions following a declarator.
+    fn parse_array_dims(&mut self, ty: &IdlType) -> Result<IdlType> {
+        let mut dims = Vec::new();
+        while self.eat_punct('[') {
+            dims.push(self.expect_size()?);
+            self.expect_punct(']')?;
+        }
+        Ok(dims
+            .into_iter()
+            .rev()
+            .fold(ty.clone(), |inner, length| IdlType::Array {
+                element: Box::new(inner),
+                length,
+            }))
+    }
+
+    /// Resolve a (possibly relative) enum, struct, union or typedef name.
+    ///
+    /// An unknown name is kept as written; it may come from another file.
+    fn resolve_type(&self, name: &str) -> IdlType {
+        let candidate = (0..=self.scope.len())
+            .rev()
+            .map(|depth| Self::qualify(&self.scope[..depth], name))
+            .find(|candidate| {
+                self.aliases.contains_key(candidate) || self.kinds.contains_key(candidate)
+            });
+        if let Some(ty) = candidate.as_ref().and_then(|path| self.aliases.get(path)) {
+            return ty.clone();
+        }
+        let path = candidate.unwrap_or_else(|| name.to_string());
+        let mut namespace: Vec<String> = path.split("::").map(str::to_string).collect();
+        let name = namespace.pop().unwrap_or_default();
+        IdlType::Named { namespace, name }
+    }
+
+    fn parse_const(&mut self) -> Result<()> {
+        self.pos += 1; // const
+        let ty = self.parse_const_type()?;
+        let name = self.expect_ident()?;
+        self.expect_punct('=')?;
+        let raw = self.parse_expr()?;
+        self.expect_punct(';')?;
+
+        let value = raw.clone();
+        let (ty, value) = match ty {
+            ParsedType::Known(ty) => {
+                let value = match (&ty, value) {
+                    (ConstType::Primitive(p), Value::Enumerator { .. }) => {
+                        bail!("const {} of type {:?} is set to an enumerator", name, p)
+                    }
+                    (_, value) => value.into_const(),
+                };
+                (ty, value)
+            }
+            ParsedType::Scoped(type_name) => {
+                let (enum_scope, enum_name) = self.resolve_enum(&type_name)?;
+                match value {
+                    Value::Enumerator {
+                        scope,
+                        enum_name: value_enum,
+                        variant,
+                        ..
+                    } if scope == enum_scope && value_enum == enum_name => (
+                        ConstType::Enum(relative_path(&self.scope, &enum_scope, &enum_name)),
+                        ConstValue::Enumerator(variant),
+                    ),
+                    other => bail!(
+                        "const {} of enum type {} has value {:?}",
+                        name,
+                        type_name,
+                        other
+                    ),
+                }
+            }
+        };
+
+        self.values.insert(Self::qualify(&self.scope, &name), raw);
+        self.items
+            .consts
+            .push(ConstSpec::new(self.scope.clone(), name, ty, value));
+        Ok(())
+    }
+
+    fn parse_const_type(&mut self) -> Result<ParsedType> {
+        let word = match self.peek() {
+            Some(Token::Ident(_)) => self.expect_ident()?,
+            Some(Token::Punct('$')) => return Ok(ParsedType::Scoped(self.parse_scoped_name()?)),
+            other => bail!("expected const type, found {:?}", other),
+        };
+        let primitive = |p| Ok(ParsedType::Known(ConstType::Primitive(p)));
+        match word.as_str() {
+            "unsigned" => {
+                let inner = self.expect_ident()?;
+                match inner.as_str() {
+                    "short" => primitive(PrimitiveConst::U16),
+                    "long" if self.is_ident("long") => {
+                        self.pos += 1;
+                        primitive(PrimitiveConst::U64)
+                    }
+                    "long" => primitive(PrimitiveConst::U32),
+                    other => bail!("unsupported const type 'unsigned {}'", other),
+                }
+            }
+            "long" if self.is_ident("long") => {
+                self.pos += 1;
+                primitive(PrimitiveConst::I64)
+            }
+            "long" if self.is_ident("double") => {
+                self.pos += 1;
+                primitive(PrimitiveConst::F64)
+            }
+            "long" | "int32" => primitive(PrimitiveConst::I32),
+            "short" | "int16" => primitive(PrimitiveConst::I16),
+            "int8" => primitive(PrimitiveConst::I8),
+            "octet" | "uint8" => primitive(PrimitiveConst::U8),
+            "uint16" => primitive(PrimitiveConst::U16),
+            "uint32" => primitive(PrimitiveConst::U32),
+            "int64" => primitive(PrimitiveConst::I64),
+            "uint64" => primitive(PrimitiveConst::U64),
+            "float" => primitive(PrimitiveConst::F32),
+            "double" => primitive(PrimitiveConst::F64),
+            "boolean" => primitive(PrimitiveConst::Bool),
+            "char" | "wchar" => primitive(PrimitiveConst::Char),
+            "string" | "wstring" => {
+                if self.eat_punct('<') {
+                    while !self.eat_punct('>') {
+                        self.next()?;
+                    }
+                }
+                Ok(ParsedType::Known(ConstType::String))
+            }
+            _ => {
+                self.pos -= 1;
+                Ok(ParsedType::Scoped(self.parse_scoped_name()?))
+            }
+        }
+    }
+
+    fn parse_scoped_name(&mut self) -> Result<String> {
+        let mut parts = Vec::new();
+        self.eat_punct('$');
+        loop {
+            parts.push(self.expect_ident()?);
+            if self.peek() == Some(&SCOPE) {
+                self.pos += 1;
+            } else {
+                return Ok(parts.join("::"));
+            }
+        }
+    }
+
+    /// Resolve a (possibly relative) enum type name to its scope and name.
+    fn resolve_enum(&self, type_name: &str) -> Result<(Vec<String>, String)> {
+        for depth in (0..=self.scope.len()).rev() {
+            let candidate = Self::qualify(&self.scope[..depth], type_name);
+            if let Some(spec) = self
+                .items
+                .enums
+                .iter()
+                .find(|spec| Self::qualify(&spec.namespace, &spec.name) == candidate)
+            {
+                return Ok((spec.namespace.clone(), spec.name.clone()));
+            }
+        }
+        bail!("unsupported const type '{}' (not a known enum)", type_name)
+    }
+
+    /// Resolve a name used in an expression, innermost scope first.
+    fn resolve_value(&self, name: &str) -> Result<Value> {
+        for depth in (0..=self.scope.len()).rev() {
+            let candidate = Self::qualify(&self.scope[..depth], name);
+            if let Some(value) = self.values.get(&candidate) {
+                return Ok(value.clone());
+            }
+            if let Some((scope, enum_name, variant, value)) = self.enumerators.get(&candidate) {
+                return Ok(Value::Enumerator {
+                    scope: scope.clone(),
+                    enum_name: enum_name.clone(),
+                    variant: variant.clone(),
+                    value: *value,
+                });
+            }
+        }
+        bail!("unknown identifier '{}' in constant expression", name)
+    }
+
+    fn expect_int(&mut self) -> Result<i128> {
+        match self.parse_expr()? {
+            Value::Int(n) => Ok(n),
+            other => bail!("expected integer, found {:?}", other),
+        }
+    }
+
+    // ----- Constant expressions (IDL precedence, lowest first) -----
+
+    fn parse_expr(&mut self) -> Result<Value> {
+        let mut lhs = self.parse_xor()?;
+        while self.eat_punct('|') {
+            let rhs = self.parse_xor()?;
+            lhs = int_op(lhs, rhs, "|", |a, b| Some(a | b))?;
+        }
+        Ok(lhs)
+    }
+
+    fn parse_xor(&mut self) -> Result<Value> {
+        let mut lhs = self.parse_and()?;
+        while self.eat_punct('^') {
+            let rhs = self.parse_and()?;
+            lhs = int_op(lhs, rhs, "^", |a, b| Some(a ^ b))?;
+        }
+        Ok(lhs)
+    }
+
+    fn parse_and(&mut self) -> Result<Value> {
+        let mut lhs = self.parse_shift()?;
+        while self.eat_punct('&') {
+            let rhs = self.parse_shift()?;
+            lhs = int_op(lhs, rhs, "&", |a, b| Some(a & b))?;
+        }
+        Ok(lhs)
+    }
+
+    fn parse_shift(&mut self) -> Result<Value> {
+        let mut lhs = self.parse_add()?;
+        loop {
+            let op = match (self.peek(), self.peek_at(1)) {
+                (Some(Token::Punct('<')), Some(Token::Punct('<'))) => "<<",
+                (Some(Token::Punct('>')), Some(Token::Punct('>'))) => ">>",
+                _ => return Ok(lhs),
+            };
+            self.pos += 2;
+            let rhs = self.parse_add()?;
+            lhs = if op == "<<" {
+                int_op(lhs, rhs, op, |a, b| {
+                    u32::try_from(b).ok().and_then(|b| a.checked_shl(b))
+                })?
+            } else {
+                int_op(lhs, rhs, op, |a, b| {
+                    u32::try_from(b).ok().and_then(|b| a.checked_shr(b))
+                })?
+            };
+        }
+    }
+
+    fn parse_add(&mut self) -> Result<Value> {
+        let mut lhs = self.parse_mul()?;
+        loop {
+            lhs = if self.eat_punct('+') {
+                let rhs = self.parse_mul()?;
+                arith_op(lhs, rhs, "+", i128::checked_add, |a, b| a + b)?
+            } else if self.eat_punct('-') {
+                let rhs = self.parse_mul()?;
+                arith_op(lhs, rhs, "-", i128::checked_sub, |a, b| a - b)?
+            } else {
+                return Ok(lhs);
+            };
+        }
+    }
+
+    fn parse_mul(&mut self) -> Result<Value> {
+        let mut lhs = self.parse_unary()?;
+        loop {
+            lhs = if self.eat_punct('*') {
+                let rhs = self.parse_unary()?;
+                arith_op(lhs, rhs, "*", i128::checked_mul, |a, b| a * b)?
+            } else if self.eat_punct('/') {
+                let rhs = self.parse_unary()?;
+                arith_op(lhs, rhs, "/", i128::checked_div, |a, b| a / b)?
+            } else if self.eat_punct('%') {
+                let rhs = self.parse_unary()?;
+                int_op(lhs, rhs, "%", i128::checked_rem)?
+            } else {
+                return Ok(lhs);
+            };
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<Value> {
+        if self.eat_punct('-') {
+            return match self.parse_unary()? {
+                Value::Int(n) => Ok(Value::Int(-n)),
+                Value::Float(f) => Ok(Value::Float(-f)),
+                other => bail!("cannot negate {:?}", other),
+            };
+        }
+        if self.eat_punct('+') {
+            return self.parse_unary();
+        }
+        if self.eat_punct('~') {
+            return match self.parse_unary()? {
+                Value::Int(n) => Ok(Value::Int(!n)),
+                other => bail!("cannot complement {:?}", other),
+            };
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<Value> {
+        match self.peek().cloned() {
+            Some(Token::Punct('(')) => {
+                self.pos += 1;
+                let value = self.parse_expr()?;
+                self.expect_punct(')')?;
+                Ok(value)
+            }
+            Some(Token::Int(n)) => {
+                self.pos += 1;
+                Ok(Value::Int(n))
+            }
+            Some(Token::Float(f)) => {
+                self.pos += 1;
+                Ok(Value::Float(f))
+            }
+            Some(Token::Str(s)) => {
+                self.pos += 1;
+                Ok(Value::Str(s))
+            }
+            Some(Token::Char(c)) => {
+                self.pos += 1;
+                Ok(Value::Char(c))
+            }
+            Some(Token::Ident(word)) if word == "TRUE" || word == "FALSE" => {
+                self.pos += 1;
+                Ok(Value::Bool(word == "TRUE"))
+            }
+            Some(Token::Ident(_) | Token::Punct('$')) => {
+                let name = self.parse_scoped_name()?;
+                self.resolve_value(&name)
+            }
+            other => bail!("expected constant expression, found {:?}", other),
+        }
+    }
+}
+
+enum ParsedType {
+    Known(ConstType),
+    /// Scoped name, must resolve to an enum
+    Scoped(String),
+}
+
+/// Intermediate value of a constant expression.
+#[derive(Debug, Clone)]
+enum Value {
+    Int(i128),
+    Float(f64),
+    Bool(bool),
+    Char(char),
+    Str(String),
+    Enumerator {
+        scope: Vec<String>,
+        enum_name: String,
+        variant: String,
+        value: u32,
+    },
+}
+
+impl Value {
+    fn into_const(self) -> ConstValue {
+        match self {
+            Value::Int(n) => ConstValue::Int(n),
+            Value::Float(f) => ConstValue::Float(f),
+            Value::Bool(b) => ConstValue::Bool(b),
+            Value::Char(c) => ConstValue::Char(c),
+            Value::Str(s) => ConstValue::Str(s),
+            Value::Enumerator { value, .. } => ConstValue::Int(i128::from(value)),
+        }
+    }
+}
+
+fn int_op(
+    lhs: Value,
+    rhs: Value,
+    op: &str,
+    f: impl Fn(i128, i128) -> Option<i128>,
+) -> Result<Value> {
+    match (lhs, rhs) {
+        (Value::Int(a), Value::Int(b)) => f(a, b)
+            .map(Value::Int)
+            .ok_or_else(|| anyhow!("overflow or division by zero in '{} {} {}'", a, op, b)),
+        (a, b) => bail!(
+            "operator '{}' needs integers, found {:?} and {:?}",
+            op,
+            a,
+            b
+        ),
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn arith_op(
+    lhs: Value,
+    rhs: Value,
+    op: &str,
+    int: impl Fn(i128, i128) -> Option<i128>,
+    float: impl Fn(f64, f64) -> f64,
+) -> Result<Value> {
+    match (lhs, rhs) {
+        (Value::Float(a), Value::Float(b)) => Ok(Value::Float(float(a, b))),
+        (Value::Float(a), Value::Int(b)) => Ok(Value::Float(float(a, b as f64))),
+        (Value::Int(a), Value::Float(b)) => Ok(Value::Float(float(a as f64, b))),
+        (lhs, rhs) => int_op(lhs, rhs, op, int),
+    }
+}
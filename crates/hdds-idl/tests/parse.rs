@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+use hdds_idl::{parse_idl, Extensibility, IdlType, PrimitiveKind, TypeDecl};
+
+fn named(namespace: &[&str], name: &str) -> IdlType {
+    IdlType::Named {
+        namespace: namespace.iter().map(|part| part.to_string()).collect(),
+        name: name.to_string(),
+    }
+}
+
+#[test]
+fn test_struct_base_annotations_and_extensibility() {
+    let items = parse_idl(
+        "module m {
+             struct Base { long a; };
+             @mutable struct Child : Base {
+                 @key long id;
+                 @optional wchar initial;
+                 @external long double weight;
+             };
+         };",
+    )
+    .expect("valid IDL");
+    assert!(items.skipped.is_empty());
+    let TypeDecl::Struct(child) = &items.types[1] else {
+        panic!("Child is not a struct");
+    };
+    assert_eq!(child.extensibility, Extensibility::Mutable);
+    assert_eq!(child.base, Some(named(&["m"], "Base")));
+    let flags: Vec<_> = child
+        .members
+        .iter()
+        .map(|member| (member.key, member.optional, member.external))
+        .collect();
+    assert_eq!(
+        flags,
+        [
+            (true, false, false),
+            (false, true, false),
+            (false, false, true)
+        ]
+    );
+    assert_eq!(
+        child.members[1].ty,
+        IdlType::Primitive(PrimitiveKind::WChar)
+    );
+    assert_eq!(
+        child.members[2].ty,
+        IdlType::Primitive(PrimitiveKind::LongDouble)
+    );
+}
+
+#[test]
+fn test_names_resolve_innermost_first_and_unknown_are_kept() {
+    let items = parse_idl(
+        "module a {
+             enum Color { RED };
+             typedef sequence<octet, 4> Blob;
+             module b {
+                 struct S { Color c; Blob blob; ::common::Pose pose; Other other; };
+             };
+         };",
+    )
+    .expect("valid IDL");
+    let TypeDecl::Struct(def) = &items.types[1] else {
+        panic!("S is not a struct");
+    };
+    let types: Vec<_> = def.members.iter().map(|member| member.ty.clone()).collect();
+    assert_eq!(
+        types,
+        [
+            named(&["a"], "Color"),
+            IdlType::Sequence {
+                element: Box::new(IdlType::Octet),
+                bound: Some(4),
+            },
+            named(&["common"], "Pose"),
+            named(&[], "Other"),
+        ]
+    );
+}
+
+#[test]
+fn test_unknown_declarations_and_broken_types_are_skipped() {
+    let items = parse_idl(
+        "import other;
+         module m {
+             interface Service { void ping(); };
+             union U switch (double) { case 1: long a; };
+             struct Ok { long a; };
+         };",
+    )
+    .expect("valid IDL");
+    let skipped: Vec<_> = items.skipped.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(skipped, ["m::Service", "m::U"]);
+    assert_eq!(
+        items.skipped[0].reason,
+        "unsupported declaration 'interface'"
+    );
+    assert_eq!(items.types.len(), 1);
+
+    assert!(parse_idl("struct S { long x; ").is_err());
+}
@@ -0,0 +1,426 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! InfluxDB v2 write API client.
+//!
+//! Batches are POSTed to `/api/v2/write` with token authentication from a
+//! delivery thread. Failed writes are retried with exponential backoff;
+//! the delivery queue is bounded, so a slow or unreachable server holds
+//! back the sink instead of growing its memory.
+
+use crate::config::{DeliveryConfig, InfluxDbConfig};
+use reqwest::blocking::Client;
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE, RETRY_AFTER};
+use reqwest::StatusCode;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, SyncSender};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Upper bound of the delay between two attempts.
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Errors of a write to InfluxDB.
+#[derive(Debug)]
+pub enum WriteError {
+    /// The request could not be sent or its response not read.
+    Http(reqwest::Error),
+    /// The server answered with an error status.
+    Status {
+        /// HTTP status code.
+        status: u16,
+        /// Response body (InfluxDB explains the error there).
+        body: String,
+    },
+}
+
+impl WriteError {
+    /// Whether retrying the same write may succeed: transport failures,
+    /// `429 Too Many Requests` and server errors.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            WriteError::Http(_) => true,
+            WriteError::Status { status, .. } => {
+                *status == StatusCode::TOO_MANY_REQUESTS.as_u16() || *status >= 500
+            }
+        }
+    }
+}
+
+impl fmt::Display for WriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WriteError::Http(e) => write!(f, "HTTP error: {}", e),
+            WriteError::Status { status, body } => {
+                write!(f, "InfluxDB answered {}: {}", status, body.trim())
+            }
+        }
+    }
+}
+
+impl std::error::Error for WriteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WriteError::Http(e) => Some(e),
+            WriteError::Status { .. } => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for WriteError {
+    fn from(e: reqwest::Error) -> Self {
+        WriteError::Http(e)
+    }
+}
+
+/// Blocking client of the InfluxDB v2 write API.
+pub struct InfluxClient {
+    http: Client,
+    write_url: String,
+    org: String,
+    bucket: String,
+    token: String,
+    delivery: DeliveryConfig,
+}
+
+impl InfluxClient {
+    /// Create a client for the configured server, organization and bucket.
+    pub fn new(config: &InfluxDbConfig) -> Result<Self, WriteError> {
+        let http = Client::builder()
+            .timeout(Duration::from_millis(config.delivery.timeout_ms))
+            .build()?;
+        Ok(Self {
+            http,
+            write_url: format!("{}/api/v2/write", config.url.trim_end_matches('/')),
+            org: config.org.clone(),
+            bucket: config.bucket.clone(),
+            token: config.token.clone(),
+            delivery: config.delivery.clone(),
+        })
+    }
+
+    /// Write a batch of Line Protocol lines (nanosecond timestamps),
+    /// retrying retryable failures up to `max_retries` times.
+    ///
+    /// The delay doubles from `retry_backoff_ms` on each retry, unless the
+    /// server asks for another one with `Retry-After`.
+    pub fn write(&self, lines: &[String]) -> Result<(), WriteError> {
+        let mut backoff = Duration::from_millis(self.delivery.retry_backoff_ms);
+        let mut attempt = 0;
+        loop {
+            let (err, retry_after) = match self.post(lines) {
+                Ok(()) => return Ok(()),
+                Err(failure) => failure,
+            };
+            if !err.is_retryable() || attempt >= self.delivery.max_retries {
+                return Err(err);
+            }
+            attempt += 1;
+            let delay = retry_after.unwrap_or(backoff).min(MAX_BACKOFF);
+            log::warn!(
+                "InfluxDB write failed ({}), retry {}/{} in {:?}",
+                err,
+                attempt,
+                self.delivery.max_retries,
+                delay
+            );
+            thread::sleep(delay);
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+    }
+
+    /// POST `lines`; on failure, also return the `Retry-After` delay.
+    fn post(&self, lines: &[String]) -> Result<(), (WriteError, Option<Duration>)> {
+        let response = self
+            .http
+            .post(&self.write_url)
+            .query(&[
+                ("org", self.org.as_str()),
+                ("bucket", self.bucket.as_str()),
+                ("precision", "ns"),
+            ])
+            .header(AUTHORIZATION, format!("Token {}", self.token))
+            .header(CONTENT_TYPE, "text/plain; charset=utf-8")
+            .body(lines.join("\n"))
+            .send()
+            .map_err(|e| (WriteError::Http(e), None))?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        let retry_after = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse().ok())
+            .map(Duration::from_secs);
+        let body = response.text().unwrap_or_default();
+        Err((
+            WriteError::Status {
+                status: status.as_u16(),
+                body,
+            },
+            retry_after,
+        ))
+    }
+}
+
+/// Delivery counters, shared with the delivery thread.
+#[derive(Debug, Default)]
+pub struct DeliveryStats {
+    batches_written: AtomicU64,
+    lines_written: AtomicU64,
+    batches_dropped: AtomicU64,
+    lines_dropped: AtomicU64,
+}
+
+impl DeliveryStats {
+    /// Batches written to InfluxDB.
+    pub fn batches_written(&self) -> u64 {
+        self.batches_written.load(Ordering::Relaxed)
+    }
+
+    /// Lines written to InfluxDB.
+    pub fn lines_written(&self) -> u64 {
+        self.lines_written.load(Ordering::Relaxed)
+    }
+
+    /// Batches given up on after a non-retryable error or the last retry.
+    pub fn batches_dropped(&self) -> u64 {
+        self.batches_dropped.load(Ordering::Relaxed)
+    }
+
+    /// Lines of the dropped batches.
+    pub fn lines_dropped(&self) -> u64 {
+        self.lines_dropped.load(Ordering::Relaxed)
+    }
+}
+
+/// Background delivery of batches through an [`InfluxClient`].
+///
+/// Batches are written in order by one thread. At most `queue_capacity`
+/// batches wait for it: [`send`](Delivery::send) blocks beyond that.
+pub struct Delivery {
+    tx: Option<SyncSender<Vec<String>>>,
+    thread: Option<JoinHandle<()>>,
+    stats: Arc<DeliveryStats>,
+}
+
+impl Delivery {
+    /// Start the delivery thread.
+    pub fn start(client: InfluxClient) -> Self {
+        let (tx, rx) = mpsc::sync_channel::<Vec<String>>(client.delivery.queue_capacity);
+        let stats = Arc::new(DeliveryStats::default());
+        let thread_stats = Arc::clone(&stats);
+        let thread = thread::Builder::new()
+            .name("influx-delivery".to_string())
+            .spawn(move || {
+                for batch in rx {
+                    let lines = batch.len() as u64;
+                    match client.write(&batch) {
+                        Ok(()) => {
+                            thread_stats.batches_written.fetch_add(1, Ordering::Relaxed);
+                            thread_stats
+                                .lines_written
+                                .fetch_add(lines, Ordering::Relaxed);
+                        }
+                        Err(e) => {
+                            log::error!("Dropping batch of {} lines: {}", lines, e);
+                            thread_stats.batches_dropped.fetch_add(1, Ordering::Relaxed);
+                            thread_stats
+                                .lines_dropped
+                                .fetch_add(lines, Ordering::Relaxed);
+                        }
+                    }
+                }
+            })
+            .expect("spawn delivery thread");
+        Self {
+            tx: Some(tx),
+            thread: Some(thread),
+            stats,
+        }
+    }
+
+    /// Queue a batch, waiting for room if the queue is full.
+    pub fn send(&self, batch: Vec<String>) {
+        if batch.is_empty() {
+            return;
+        }
+        if let Some(tx) = &self.tx {
+            // Fails only if the delivery thread panicked
+            if tx.send(batch).is_err() {
+                log::error!("Delivery thread stopped, batch lost");
+            }
+        }
+    }
+
+    /// Delivery counters.
+    pub fn stats(&self) -> &DeliveryStats {
+        &self.stats
+    }
+
+    /// Write the queued batches, then stop the delivery thread.
+    pub fn shutdown(mut self) -> Arc<DeliveryStats> {
+        self.stop();
+        Arc::clone(&self.stats)
+    }
+
+    fn stop(&mut self) {
+        drop(self.tx.take());
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for Delivery {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    /// A request received by [`serve`].
+    struct Request {
+        head: String,
+        body: String,
+    }
+
+    /// Answer one request per status in `statuses`, on a local port.
+    fn serve(statuses: Vec<&'static str>) -> (String, JoinHandle<Vec<Request>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+        let url = format!("http://{}/", listener.local_addr().expect("addr"));
+        let server = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for status in statuses {
+                let (stream, _) = listener.accept().expect("accept");
+                let mut reader = BufReader::new(stream);
+                let mut head = String::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).expect("read");
+                    if line == "\r\n" {
+                        break;
+                    }
+                    head.push_str(&line);
+                }
+                let length = head
+                    .lines()
+                    .find_map(|l| {
+                        l.to_ascii_lowercase()
+                            .strip_prefix("content-length: ")
+                            .map(str::to_string)
+                    })
+                    .and_then(|l| l.trim().parse().ok())
+                    .unwrap_or(0);
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).expect("body");
+                let response = format!(
+                    "HTTP/1.1 {}\r\nRetry-After: 0\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                    status
+                );
+                reader
+                    .get_mut()
+                    .write_all(response.as_bytes())
+                    .expect("write");
+                requests.push(Request {
+                    head,
+                    body: String::from_utf8(body).expect("utf8"),
+                });
+            }
+            requests
+        });
+        (url, server)
+    }
+
+    fn config(url: String, max_retries: u32) -> InfluxDbConfig {
+        InfluxDbConfig {
+            url,
+            org: "my org".to_string(),
+            bucket: "telemetry".to_string(),
+            token: "secret".to_string(),
+            delivery: DeliveryConfig {
+                max_retries,
+                retry_backoff_ms: 1,
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_write_posts_line_protocol() {
+        let (url, server) = serve(vec!["204 No Content"]);
+        let client = InfluxClient::new(&config(url, 0)).expect("client");
+        let lines = vec!["m v=1i 1".to_string(), "m v=2i 2".to_string()];
+        client.write(&lines).expect("write");
+
+        let requests = server.join().expect("server");
+        let head = requests[0].head.to_ascii_lowercase();
+        assert!(head.starts_with(
+            "post /api/v2/write?org=my+org&bucket=telemetry&precision=ns http/1.1\r\n"
+        ));
+        assert!(head.contains("authorization: token secret\r\n"));
+        assert!(head.contains("content-type: text/plain; charset=utf-8\r\n"));
+        assert_eq!(requests[0].body, "m v=1i 1\nm v=2i 2");
+    }
+
+    #[test]
+    fn test_write_retries_server_errors() {
+        let (url, server) = serve(vec![
+            "503 Service Unavailable",
+            "429 Too Many Requests",
+            "204 No Content",
+        ]);
+        let client = InfluxClient::new(&config(url, 2)).expect("client");
+        client.write(&["m v=1i 1".to_string()]).expect("write");
+        assert_eq!(server.join().expect("server").len(), 3);
+
+        // Out of retries
+        let (url, server) = serve(vec!["500 Internal Server Error"; 2]);
+        let client = InfluxClient::new(&config(url, 1)).expect("client");
+        let err = client.write(&["m v=1i 1".to_string()]).unwrap_err();
+        assert!(matches!(err, WriteError::Status { status: 500, .. }));
+        assert_eq!(server.join().expect("server").len(), 2);
+    }
+
+    #[test]
+    fn test_write_does_not_retry_client_errors() {
+        let (url, server) = serve(vec!["401 Unauthorized"]);
+        let client = InfluxClient::new(&config(url, 3)).expect("client");
+        let err = client.write(&["m v=1i 1".to_string()]).unwrap_err();
+        assert!(!err.is_retryable());
+        assert_eq!(server.join().expect("server").len(), 1);
+    }
+
+    #[test]
+    fn test_delivery_writes_queued_batches() {
+        let (url, server) = serve(vec!["204 No Content", "400 Bad Request", "204 No Content"]);
+        let delivery = Delivery::start(InfluxClient::new(&config(url, 0)).expect("client"));
+        delivery.send(vec!["m v=1i 1".to_string()]);
+        delivery.send(Vec::new());
+        delivery.send(vec!["m v=bad 2".to_string(), "m v=3i 3".to_string()]);
+        delivery.send(vec!["m v=4i 4".to_string()]);
+
+        let stats = delivery.shutdown();
+        assert_eq!(stats.batches_written(), 2);
+        assert_eq!(stats.lines_written(), 2);
+        assert_eq!(stats.batches_dropped(), 1);
+        assert_eq!(stats.lines_dropped(), 2);
+        let bodies: Vec<String> = server
+            .join()
+            .expect("server")
+            .into_iter()
+            .map(|r| r.body)
+            .collect();
+        assert_eq!(bodies, ["m v=1i 1", "m v=bad 2\nm v=3i 3", "m v=4i 4"]);
+    }
+}
@@ -14,6 +14,9 @@ pub struct SinkConfig {
     pub sinks: Vec<TopicSinkConfig>,
     /// InfluxDB connection settings.
     pub influxdb: InfluxDbConfig,
+    /// DDS domain to subscribe in (default: 0).
+    #[serde(default)]
+    pub domain_id: u32,
 }
 
 /// Configuration for a single DDS topic sink.
@@ -33,6 +36,14 @@ pub struct TopicSinkConfig {
     pub batch_size: Option<usize>,
     /// Flush interval in milliseconds. None = default (1000).
     pub flush_interval_ms: Option<u64>,
+    /// Map the instance key fields set with `DdsSink::set_key_fields` to
+    /// tags (default: true), keeping one series per instance.
+    #[serde(default = "default_key_tags")]
+    pub key_tags: bool,
+}
+
+fn default_key_tags() -> bool {
+    true
 }
 
 /// InfluxDB v2 connection configuration.
@@ -46,6 +57,36 @@ pub struct InfluxDbConfig {
     pub bucket: String,
     /// Authentication token.
     pub token: String,
+    /// Delivery of the batches to the write API.
+    #[serde(default)]
+    pub delivery: DeliveryConfig,
+}
+
+/// Delivery settings of the InfluxDB write API client.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct DeliveryConfig {
+    /// HTTP request timeout in milliseconds (default: 10000).
+    pub timeout_ms: u64,
+    /// Retries of a failed write before its batch is dropped (default: 5).
+    pub max_retries: u32,
+    /// Delay before the first retry in milliseconds, doubled on each
+    /// following one (default: 500).
+    pub retry_backoff_ms: u64,
+    /// Batches queued for delivery before the sink stops taking samples
+    /// (default: 16).
+    pub queue_capacity: usize,
+}
+
+impl Default for DeliveryConfig {
+    fn default() -> Self {
+        Self {
+            timeout_ms: 10_000,
+            max_retries: 5,
+            retry_backoff_ms: 500,
+            queue_capacity: 16,
+        }
+    }
 }
 
 /// Configuration parsing errors.
@@ -126,6 +167,11 @@ influxdb:
   org: "example-org"
   bucket: "telemetry"
   token: "test-token-placeholder"
+  delivery:
+    timeout_ms: 2000
+    max_retries: 3
+    queue_capacity: 4
+domain_id: 7
 sinks:
   - topic: "Temperature"
     measurement: "temperature"
@@ -148,6 +194,7 @@ sinks:
     sample_rate: 5
     batch_size: 200
     flush_interval_ms: 5000
+    key_tags: false
 "#;
 
     #[test]
@@ -160,6 +207,8 @@ sinks:
         assert_eq!(config.influxdb.token, "mytoken");
 
         assert_eq!(config.sinks.len(), 1);
+        assert_eq!(config.domain_id, 0);
+        assert_eq!(config.influxdb.delivery.max_retries, 5);
         assert_eq!(config.sinks[0].topic, "Temperature");
         assert_eq!(config.sinks[0].measurement, "temperature");
         assert_eq!(config.sinks[0].tags, vec!["sensor_id"]);
@@ -167,6 +216,7 @@ sinks:
         assert!(config.sinks[0].sample_rate.is_none());
         assert!(config.sinks[0].batch_size.is_none());
         assert!(config.sinks[0].flush_interval_ms.is_none());
+        assert!(config.sinks[0].key_tags);
     }
 
     #[test]
@@ -174,9 +224,14 @@ sinks:
         let config = SinkConfig::from_yaml(FULL_YAML).expect("parse full yaml");
 
         assert_eq!(config.influxdb.url, "http://influx.example.com:8086");
-        assert_eq!(config.influxdb.org, "prod-org");
+        assert_eq!(config.influxdb.org, "example-org");
         assert_eq!(config.influxdb.bucket, "telemetry");
         assert_eq!(config.influxdb.token, "test-token-placeholder");
+        assert_eq!(config.influxdb.delivery.timeout_ms, 2000);
+        assert_eq!(config.influxdb.delivery.max_retries, 3);
+        assert_eq!(config.influxdb.delivery.retry_backoff_ms, 500);
+        assert_eq!(config.influxdb.delivery.queue_capacity, 4);
+        assert_eq!(config.domain_id, 7);
 
         assert_eq!(config.sinks.len(), 2);
 
@@ -197,5 +252,6 @@ sinks:
         assert_eq!(press.sample_rate, Some(5));
         assert_eq!(press.batch_size, Some(200));
         assert_eq!(press.flush_interval_ms, Some(5000));
+        assert!(!press.key_tags);
     }
 }
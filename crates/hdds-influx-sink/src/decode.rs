@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Dynamic decoding of DDS samples to JSON.
+//!
+//! Samples of subscribed topics are decoded with the TypeObject announced
+//! by their writers, so the sink handles any type without generated code.
+
+use hdds::dynamic::{decode_dynamic, type_descriptor_from_xtypes, DynamicValue, TypeDescriptor};
+use hdds::xtypes::{CompleteTypeObject, MemberFlag};
+use std::sync::Arc;
+
+/// Decoder of the samples of one topic type.
+pub struct SampleDecoder {
+    descriptor: Arc<TypeDescriptor>,
+    key_fields: Vec<String>,
+}
+
+impl SampleDecoder {
+    /// Create a decoder from the discovered TypeObject of the topic type.
+    pub fn from_type_object(type_object: &CompleteTypeObject) -> Self {
+        Self {
+            descriptor: type_descriptor_from_xtypes(type_object),
+            key_fields: key_fields(type_object),
+        }
+    }
+
+    /// Names of the `IS_KEY` members of the type.
+    pub fn key_fields(&self) -> &[String] {
+        &self.key_fields
+    }
+
+    /// Decode a serialized sample (without encapsulation header) to JSON.
+    pub fn decode(&self, payload: &[u8]) -> Result<serde_json::Value, String> {
+        let data = decode_dynamic(payload, &self.descriptor).map_err(|e| e.to_string())?;
+        Ok(value_to_json(data.value()))
+    }
+}
+
+/// Names of the `IS_KEY` members of a struct type; empty for other types.
+pub fn key_fields(type_object: &CompleteTypeObject) -> Vec<String> {
+    match type_object {
+        CompleteTypeObject::Struct(st) => st
+            .member_seq
+            .iter()
+            .filter(|m| m.common.member_flags.contains(MemberFlag::IS_KEY))
+            .map(|m| m.detail.name.clone())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Convert a dynamic value to JSON.
+///
+/// Enums become their variant name and unions their active member. Long
+/// doubles have no JSON representation and become `null`, which the field
+/// mapper skips.
+pub fn value_to_json(value: &DynamicValue) -> serde_json::Value {
+    use serde_json::Value;
+
+    match value {
+        DynamicValue::Bool(b) => Value::Bool(*b),
+        DynamicValue::U8(n) => Value::from(*n),
+        DynamicValue::U16(n) => Value::from(*n),
+        DynamicValue::U32(n) => Value::from(*n),
+        DynamicValue::U64(n) => Value::from(*n),
+        DynamicValue::I8(n) => Value::from(*n),
+        DynamicValue::I16(n) => Value::from(*n),
+        DynamicValue::I32(n) => Value::from(*n),
+        DynamicValue::I64(n) => Value::from(*n),
+        DynamicValue::F32(n) => Value::from(*n),
+        DynamicValue::F64(n) => Value::from(*n),
+        DynamicValue::LongDouble(_) | DynamicValue::Null => Value::Null,
+        DynamicValue::Char(c) => Value::String(c.to_string()),
+        DynamicValue::String(s) | DynamicValue::WString(s) => Value::String(s.clone()),
+        DynamicValue::Struct(members) => Value::Object(
+            members
+                .iter()
+                .map(|(name, member)| (name.clone(), value_to_json(member)))
+                .collect(),
+        ),
+        DynamicValue::Sequence(items) | DynamicValue::Array(items) => {
+            Value::Array(items.iter().map(value_to_json).collect())
+        }
+        DynamicValue::Enum(_, name) => Value::String(name.clone()),
+        DynamicValue::Union(_, _, value) => value_to_json(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hdds::dynamic::encode_dynamic;
+    use hdds::DdsTrait;
+    use serde_json::json;
+
+    #[derive(hdds::DDS)]
+    struct Reading {
+        #[key]
+        sensor_id: u32,
+        location: String,
+        value: f64,
+    }
+
+    #[test]
+    fn test_decoder_round_trip() {
+        let type_object = Reading::get_type_object().expect("type object");
+        let decoder = SampleDecoder::from_type_object(&type_object);
+        assert_eq!(decoder.key_fields(), ["sensor_id"]);
+
+        let mut data = hdds::dynamic::DynamicData::new(&decoder.descriptor);
+        data.set("sensor_id", 7u32).expect("set");
+        data.set("location", "lab".to_string()).expect("set");
+        data.set("value", 21.5f64).expect("set");
+        let payload = encode_dynamic(&data).expect("encode");
+
+        assert_eq!(
+            decoder.decode(&payload).expect("decode"),
+            json!({ "sensor_id": 7, "location": "lab", "value": 21.5 })
+        );
+        assert!(decoder.decode(&payload[..2]).is_err());
+    }
+
+    #[test]
+    fn test_value_to_json_composites() {
+        let value = DynamicValue::Struct(
+            [
+                (
+                    "mode".to_string(),
+                    DynamicValue::Enum(1, "ACTIVE".to_string()),
+                ),
+                (
+                    "samples".to_string(),
+                    DynamicValue::Sequence(vec![DynamicValue::I16(-1), DynamicValue::I16(2)]),
+                ),
+                (
+                    "choice".to_string(),
+                    DynamicValue::Union(0, "c".to_string(), Box::new(DynamicValue::Char('x'))),
+                ),
+                (
+                    "precise".to_string(),
+                    DynamicValue::LongDouble([0; hdds::dynamic::LONG_DOUBLE_SIZE]),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        assert_eq!(
+            value_to_json(&value),
+            json!({ "mode": "ACTIVE", "samples": [-1, 2], "choice": "x", "precise": null })
+        );
+    }
+}
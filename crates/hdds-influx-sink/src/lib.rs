@@ -11,22 +11,32 @@
 //! - Field mapping from JSON DDS samples to tags/fields
 //! - Batching with size and time-based flushing
 //! - Downsampling via configurable sample rates
+//! - Dynamic decoding of DDS samples from their discovered TypeObject
+//! - Delivery to the InfluxDB v2 write API, with retries and backpressure
 //!
 //! # Overview
 //!
-//! The sink does NOT perform HTTP requests to InfluxDB. It produces
-//! Line Protocol strings that can be sent via any HTTP client.
+//! [`SinkRuntime`] (and the `hdds-influx-sink` binary) subscribes to the
+//! configured topics and writes their samples to InfluxDB:
 //!
 //! ```text
-//! DDS Sample (JSON) --> FieldMapper --> LineProtocolWriter --> BatchBuffer --> Vec<String>
+//! DDS Sample --> SampleDecoder --> FieldMapper --> LineProtocolWriter --> BatchBuffer --> Delivery --> InfluxDB
 //! ```
+//!
+//! The stages can also be used on their own: [`DdsSink`] turns JSON
+//! samples into batches of Line Protocol strings for any HTTP client.
 
 pub mod buffer;
+pub mod client;
 pub mod config;
+pub mod decode;
 pub mod influx;
 pub mod mapping;
 pub mod recorder;
+pub mod runtime;
 
+pub use client::{Delivery, InfluxClient, WriteError};
 pub use config::SinkConfig;
 pub use influx::LineProtocolWriter;
 pub use recorder::DdsSink;
+pub use runtime::{SinkRuntime, StopHandle};
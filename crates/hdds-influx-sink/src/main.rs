@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! HDDS InfluxDB Sink CLI
+//!
+//! Subscribe to DDS topics and write their samples to InfluxDB v2.
+//!
+//! # Usage
+//!
+//! ```bash
+//! # Run with a YAML configuration (see `SinkConfig`)
+//! hdds-influx-sink --config sink.yaml
+//!
+//! # Override the domain and the token of the configuration
+//! INFLUX_TOKEN=... hdds-influx-sink --config sink.yaml --domain 3
+//! ```
+
+use clap::Parser;
+use hdds_influx_sink::{SinkConfig, SinkRuntime};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(name = "hdds-influx-sink")]
+#[command(author = "naskel.com")]
+#[command(version = env!("CARGO_PKG_VERSION"))]
+#[command(about = "Write DDS topics to InfluxDB v2")]
+struct Cli {
+    /// YAML configuration file
+    #[arg(short, long)]
+    config: PathBuf,
+
+    /// DDS domain ID (overrides the configuration)
+    #[arg(short, long)]
+    domain: Option<u32>,
+
+    /// InfluxDB token (overrides the configuration)
+    #[arg(long, env = "INFLUX_TOKEN", hide_env_values = true)]
+    token: Option<String>,
+}
+
+fn main() -> ExitCode {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+    let cli = Cli::parse();
+
+    let mut config = match SinkConfig::from_file(&cli.config) {
+        Ok(config) => config,
+        Err(e) => {
+            log::error!("Failed to load {}: {}", cli.config.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Some(domain) = cli.domain {
+        config.domain_id = domain;
+    }
+    if let Some(token) = cli.token {
+        config.influxdb.token = token;
+    }
+
+    let runtime = match SinkRuntime::new(config) {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            log::error!("Failed to create InfluxDB client: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let stop = runtime.stop_handle();
+    if let Err(e) = ctrlc::set_handler(move || stop.stop()) {
+        log::warn!("Failed to install Ctrl+C handler: {}", e);
+    }
+
+    match runtime.run() {
+        Ok(_) => ExitCode::SUCCESS,
+        Err(e) => {
+            log::error!("Influx sink failed: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -4,7 +4,8 @@
 //! DDS sample field mapping to InfluxDB tags and fields.
 //!
 //! Maps JSON-like DDS samples to InfluxDB-compatible tag and field sets
-//! based on a configured list of field names.
+//! based on a configured list of field names. Instance key fields of keyed
+//! topic types can be added as tags, so every instance gets its own series.
 
 use crate::influx::FieldValue;
 
@@ -20,6 +21,8 @@ pub struct FieldMapper {
     tag_fields: Vec<String>,
     /// Field names to extract as InfluxDB fields (values).
     value_fields: Vec<String>,
+    /// Instance key fields of the topic type, extracted as InfluxDB tags.
+    key_fields: Vec<String>,
 }
 
 impl FieldMapper {
@@ -28,21 +31,37 @@ impl FieldMapper {
         Self {
             tag_fields: tags,
             value_fields: fields,
+            key_fields: Vec::new(),
         }
     }
 
+    /// Set the instance key fields of the topic type (e.g. the `IS_KEY`
+    /// members of its TypeObject), mapped to tags by [`map_sample`].
+    ///
+    /// Keys already configured as tags are not repeated. A key holding a
+    /// nested struct yields one tag per member, named by its dot path
+    /// (e.g. `"origin.x"`).
+    ///
+    /// [`map_sample`]: FieldMapper::map_sample
+    pub fn set_key_fields(&mut self, keys: Vec<String>) {
+        self.key_fields = keys;
+    }
+
+    /// Instance key fields mapped to tags.
+    pub fn key_fields(&self) -> &[String] {
+        &self.key_fields
+    }
+
     /// Map a JSON DDS sample to InfluxDB tags and fields.
     ///
-    /// - Tags are extracted as `(key, value_string)` pairs.
+    /// - Tags are extracted as `(key, value_string)` pairs, configured tags
+    ///   first, then instance key fields.
     ///   Missing or null tag fields are silently skipped.
     /// - Fields are extracted as `(key, FieldValue)` pairs with type inference.
     ///   Missing or null fields are silently skipped.
     ///
     /// Supports nested fields using dot notation (e.g., `"location.lat"`).
-    pub fn map_sample(
-        &self,
-        sample_json: &serde_json::Value,
-    ) -> TagsAndFields {
+    pub fn map_sample(&self, sample_json: &serde_json::Value) -> TagsAndFields {
         let mut tags = Vec::new();
         let mut fields = Vec::new();
 
@@ -55,6 +74,13 @@ impl FieldMapper {
             }
         }
 
+        // Extract instance keys (one series per instance)
+        for key_name in &self.key_fields {
+            if let Some(val) = resolve_field(sample_json, key_name) {
+                self.push_key_tags(&mut tags, key_name, val);
+            }
+        }
+
         // Extract fields (preserve types)
         for field_name in &self.value_fields {
             if let Some(val) = resolve_field(sample_json, field_name) {
@@ -66,6 +92,25 @@ impl FieldMapper {
 
         (tags, fields)
     }
+
+    /// Add the tags of key field `path`, flattening nested structs.
+    fn push_key_tags(&self, tags: &mut Vec<(String, String)>, path: &str, val: &serde_json::Value) {
+        if self.tag_fields.iter().any(|tag| tag == path) {
+            return;
+        }
+        match val {
+            serde_json::Value::Object(members) => {
+                for (name, member) in members {
+                    self.push_key_tags(tags, &format!("{}.{}", path, name), member);
+                }
+            }
+            _ => {
+                if let Some(s) = json_to_string(val) {
+                    tags.push((path.to_string(), s));
+                }
+            }
+        }
+    }
 }
 
 /// Resolve a potentially dot-separated field path in a JSON value.
@@ -97,7 +142,9 @@ fn json_to_field_value(val: &serde_json::Value) -> Option<FieldValue> {
         serde_json::Value::Number(n) => {
             if let Some(i) = n.as_i64() {
                 Some(FieldValue::Integer(i))
-            } else { n.as_f64().map(FieldValue::Float) }
+            } else {
+                n.as_f64().map(FieldValue::Float)
+            }
         }
         serde_json::Value::String(s) => Some(FieldValue::String(s.clone())),
         serde_json::Value::Bool(b) => Some(FieldValue::Boolean(*b)),
@@ -114,10 +161,7 @@ mod tests {
 
     #[test]
     fn test_map_sample_basic() {
-        let mapper = FieldMapper::new(
-            vec!["sensor_id".to_string()],
-            vec!["value".to_string()],
-        );
+        let mapper = FieldMapper::new(vec!["sensor_id".to_string()], vec!["value".to_string()]);
 
         let sample = json!({
             "sensor_id": "sensor-42",
@@ -235,6 +279,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_map_sample_key_fields_as_tags() {
+        let mut mapper = FieldMapper::new(vec!["site".to_string()], vec!["value".to_string()]);
+        mapper.set_key_fields(vec![
+            "site".to_string(),
+            "sensor_id".to_string(),
+            "origin".to_string(),
+        ]);
+
+        let sample = json!({
+            "site": "lab",
+            "sensor_id": 7,
+            "origin": { "x": 1.5, "y": -2 },
+            "value": 23.5
+        });
+
+        let (tags, fields) = mapper.map_sample(&sample);
+        let tags: Vec<(&str, &str)> = tags.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+
+        // Configured tags first, keys not repeated, nested keys flattened
+        assert_eq!(
+            tags,
+            vec![
+                ("site", "lab"),
+                ("sensor_id", "7"),
+                ("origin.x", "1.5"),
+                ("origin.y", "-2"),
+            ]
+        );
+        assert_eq!(fields.len(), 1);
+    }
+
     #[test]
     fn test_map_sample_null_and_objects_skipped() {
         let mapper = FieldMapper::new(
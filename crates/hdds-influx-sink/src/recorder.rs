@@ -85,6 +85,8 @@ pub struct DdsSink {
     config: SinkConfig,
     writer: LineProtocolWriter,
     buffers: HashMap<String, BatchBuffer>,
+    /// Batches of the buffers that filled up, not taken yet.
+    full_batches: Vec<Vec<String>>,
     mappers: HashMap<String, FieldMapper>,
     rate_limiters: HashMap<String, RateLimiter>,
     samples_recorded: u64,
@@ -100,7 +102,9 @@ impl DdsSink {
 
         for sink_cfg in &config.sinks {
             let batch_size = sink_cfg.batch_size.unwrap_or(DEFAULT_BATCH_SIZE);
-            let flush_ms = sink_cfg.flush_interval_ms.unwrap_or(DEFAULT_FLUSH_INTERVAL_MS);
+            let flush_ms = sink_cfg
+                .flush_interval_ms
+                .unwrap_or(DEFAULT_FLUSH_INTERVAL_MS);
 
             buffers.insert(
                 sink_cfg.topic.clone(),
@@ -121,6 +125,7 @@ impl DdsSink {
             config,
             writer: LineProtocolWriter::new(),
             buffers,
+            full_batches: Vec::new(),
             mappers,
             rate_limiters,
             samples_recorded: 0,
@@ -170,10 +175,8 @@ impl DdsSink {
         }
 
         // Build tag refs for the writer
-        let tag_refs: Vec<(&str, &str)> = tags
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.as_str()))
-            .collect();
+        let tag_refs: Vec<(&str, &str)> =
+            tags.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
         let field_owned: Vec<(String, FieldValue)> = fields;
         let field_refs: Vec<(&str, FieldValue)> = field_owned
             .iter()
@@ -188,7 +191,9 @@ impl DdsSink {
         let lines = self.writer.flush();
         if let Some(buffer) = self.buffers.get_mut(topic) {
             for line in lines {
-                buffer.add(line);
+                if let Some(batch) = buffer.add(line) {
+                    self.full_batches.push(batch);
+                }
             }
         }
 
@@ -196,9 +201,21 @@ impl DdsSink {
         Ok(())
     }
 
+    /// Take the batches ready for transmission: those of the topic buffers
+    /// that filled up, then those whose flush interval elapsed.
+    pub fn poll_batches(&mut self) -> Vec<Vec<String>> {
+        let mut batches = std::mem::take(&mut self.full_batches);
+        for buffer in self.buffers.values_mut() {
+            if buffer.should_flush() {
+                batches.push(buffer.flush());
+            }
+        }
+        batches
+    }
+
     /// Flush all topic buffers, returning all pending Line Protocol lines.
     pub fn flush_all(&mut self) -> Vec<String> {
-        let mut all_lines = Vec::new();
+        let mut all_lines: Vec<String> = self.full_batches.drain(..).flatten().collect();
 
         for buffer in self.buffers.values_mut() {
             all_lines.extend(buffer.flush());
@@ -210,6 +227,28 @@ impl DdsSink {
         all_lines
     }
 
+    /// Set the instance key fields of the topic type, e.g. the `IS_KEY`
+    /// members of its TypeObject.
+    ///
+    /// They become tags of every point (see [`FieldMapper::set_key_fields`]),
+    /// so each instance is stored as its own series. Ignored when the topic
+    /// sink has `key_tags: false`.
+    pub fn set_key_fields(&mut self, topic: &str, keys: Vec<String>) -> Result<(), SinkError> {
+        let topic_cfg = self
+            .config
+            .sinks
+            .iter()
+            .find(|s| s.topic == topic)
+            .ok_or_else(|| SinkError::UnknownTopic(topic.to_string()))?;
+        if !topic_cfg.key_tags {
+            return Ok(());
+        }
+        if let Some(mapper) = self.mappers.get_mut(topic) {
+            mapper.set_key_fields(keys);
+        }
+        Ok(())
+    }
+
     /// Get the total number of samples successfully recorded.
     pub fn samples_recorded(&self) -> u64 {
         self.samples_recorded
@@ -238,7 +277,9 @@ mod tests {
                 org: "test".to_string(),
                 bucket: "test".to_string(),
                 token: "test-token".to_string(),
+                delivery: Default::default(),
             },
+            domain_id: 0,
             sinks: vec![crate::config::TopicSinkConfig {
                 topic: "Temperature".to_string(),
                 measurement: "temperature".to_string(),
@@ -247,6 +288,7 @@ mod tests {
                 sample_rate: None,
                 batch_size: Some(100),
                 flush_interval_ms: Some(1000),
+                key_tags: true,
             }],
         }
     }
@@ -258,7 +300,9 @@ mod tests {
                 org: "test".to_string(),
                 bucket: "test".to_string(),
                 token: "test-token".to_string(),
+                delivery: Default::default(),
             },
+            domain_id: 0,
             sinks: vec![crate::config::TopicSinkConfig {
                 topic: "FastSensor".to_string(),
                 measurement: "fast_sensor".to_string(),
@@ -267,6 +311,7 @@ mod tests {
                 sample_rate: Some(10), // 10 samples/sec = 100ms interval
                 batch_size: Some(100),
                 flush_interval_ms: Some(1000),
+                key_tags: true,
             }],
         }
     }
@@ -289,10 +334,7 @@ mod tests {
 
         let lines = sink.flush_all();
         assert_eq!(lines.len(), 1);
-        assert_eq!(
-            lines[0],
-            "temperature,sensor_id=S1 value=22.5 1000000000"
-        );
+        assert_eq!(lines[0], "temperature,sensor_id=S1 value=22.5 1000000000");
     }
 
     #[test]
@@ -358,6 +400,73 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_dds_sink_key_fields_split_series() {
+        let mut config = test_config();
+        config.sinks[0].tags.clear();
+        let mut sink = DdsSink::from_config(config.clone());
+        sink.set_key_fields("Temperature", vec!["sensor_id".to_string()])
+            .expect("known topic");
+
+        for id in ["S1", "S2"] {
+            let sample = json!({ "sensor_id": id, "value": 1.0 });
+            sink.record_sample("Temperature", &sample, 1)
+                .expect("record");
+        }
+        assert_eq!(
+            sink.flush_all(),
+            vec![
+                "temperature,sensor_id=S1 value=1 1",
+                "temperature,sensor_id=S2 value=1 1"
+            ]
+        );
+
+        // Opted out: instances share the measurement series
+        config.sinks[0].key_tags = false;
+        let mut sink = DdsSink::from_config(config);
+        sink.set_key_fields("Temperature", vec!["sensor_id".to_string()])
+            .expect("known topic");
+        let sample = json!({ "sensor_id": "S1", "value": 1.0 });
+        sink.record_sample("Temperature", &sample, 1)
+            .expect("record");
+        assert_eq!(sink.flush_all(), vec!["temperature value=1 1"]);
+
+        assert!(matches!(
+            sink.set_key_fields("NonExistent", vec![]),
+            Err(SinkError::UnknownTopic(_))
+        ));
+    }
+
+    #[test]
+    fn test_dds_sink_poll_batches() {
+        let mut config = test_config();
+        config.sinks[0].batch_size = Some(2);
+        config.sinks[0].flush_interval_ms = Some(60_000);
+        let mut sink = DdsSink::from_config(config);
+
+        for i in 0..5 {
+            let sample = json!({ "sensor_id": "S1", "value": i });
+            sink.record_sample("Temperature", &sample, i as u64)
+                .expect("record");
+        }
+
+        // Full batches are kept until taken, the partial one waits
+        let batches = sink.poll_batches();
+        assert_eq!(batches.len(), 2);
+        assert_eq!(
+            batches[1],
+            vec![
+                "temperature,sensor_id=S1 value=2i 2",
+                "temperature,sensor_id=S1 value=3i 3"
+            ]
+        );
+        assert!(sink.poll_batches().is_empty());
+        assert_eq!(
+            sink.flush_all(),
+            vec!["temperature,sensor_id=S1 value=4i 4"]
+        );
+    }
+
     #[test]
     fn test_dds_sink_multiple_samples() {
         let config = test_config();
@@ -0,0 +1,209 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Sink runtime: subscribes to the configured DDS topics and delivers
+//! their samples to InfluxDB.
+//!
+//! ```text
+//! DDS topic --> RawDataReader --> SampleDecoder --> DdsSink --> Delivery --> InfluxDB
+//! ```
+//!
+//! Topics are subscribed once discovered with the TypeObject of their
+//! type, which drives the decoding and provides the instance key fields.
+//! When InfluxDB falls behind and the delivery queue is full, the runtime
+//! stops taking samples until it drains: the readers' history, then DDS
+//! flow control, absorb the backlog.
+//!
+//! Points are written with the source timestamp of their sample, so a
+//! sample received twice (e.g. repaired by the writer) yields the same
+//! point, which InfluxDB stores once.
+
+use crate::client::{Delivery, DeliveryStats, InfluxClient, WriteError};
+use crate::config::SinkConfig;
+use crate::decode::SampleDecoder;
+use crate::recorder::DdsSink;
+use hdds::{Participant, RawDataReader, TransportMode};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant, UNIX_EPOCH};
+
+/// Interval between two topic discoveries.
+const DISCOVERY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Interval between two polls of the readers.
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// A subscribed topic.
+struct Subscription {
+    reader: RawDataReader,
+    decoder: SampleDecoder,
+}
+
+/// Runs a [`DdsSink`] against a DDS domain and an InfluxDB server.
+pub struct SinkRuntime {
+    sink: DdsSink,
+    delivery: Delivery,
+    running: Arc<AtomicBool>,
+}
+
+impl SinkRuntime {
+    /// Create a runtime from configuration.
+    pub fn new(config: SinkConfig) -> Result<Self, WriteError> {
+        let client = InfluxClient::new(&config.influxdb)?;
+        Ok(Self {
+            sink: DdsSink::from_config(config),
+            delivery: Delivery::start(client),
+            running: Arc::new(AtomicBool::new(true)),
+        })
+    }
+
+    /// Get a handle to stop the runtime from another thread.
+    pub fn stop_handle(&self) -> StopHandle {
+        StopHandle {
+            running: self.running.clone(),
+        }
+    }
+
+    /// Run in the configured domain over UDP (blocking) until stopped.
+    pub fn run(self) -> hdds::Result<Arc<DeliveryStats>> {
+        let participant = Participant::builder("hdds-influx-sink")
+            .with_transport(TransportMode::UdpMulticast)
+            .domain_id(self.sink.config().domain_id)
+            .build()?;
+        self.run_on(&participant)
+    }
+
+    /// Run with `participant` (blocking) until stopped.
+    ///
+    /// Pending lines are delivered before returning the delivery counters.
+    pub fn run_on(mut self, participant: &Arc<Participant>) -> hdds::Result<Arc<DeliveryStats>> {
+        let topics: HashSet<String> = self
+            .sink
+            .config()
+            .sinks
+            .iter()
+            .map(|s| s.topic.clone())
+            .collect();
+        let mut subscriptions: HashMap<String, Subscription> = HashMap::new();
+        let mut last_discovery = Instant::now()
+            .checked_sub(DISCOVERY_INTERVAL)
+            .unwrap_or_else(Instant::now);
+
+        log::info!(
+            "Influx sink started in domain {} for {} topic(s)",
+            participant.domain_id(),
+            topics.len()
+        );
+
+        while self.running.load(Ordering::SeqCst) {
+            if subscriptions.len() < topics.len() && last_discovery.elapsed() >= DISCOVERY_INTERVAL
+            {
+                self.subscribe(participant, &topics, &mut subscriptions);
+                last_discovery = Instant::now();
+            }
+
+            for (topic, subscription) in &subscriptions {
+                match subscription.reader.try_take_raw() {
+                    Ok(samples) => {
+                        for sample in samples {
+                            let timestamp_ns = sample
+                                .source_timestamp
+                                .duration_since(UNIX_EPOCH)
+                                .map_or(0, |d| d.as_nanos() as u64);
+                            let json = match subscription.decoder.decode(&sample.payload) {
+                                Ok(json) => json,
+                                Err(e) => {
+                                    log::warn!("Failed to decode sample of {}: {}", topic, e);
+                                    continue;
+                                }
+                            };
+                            if let Err(e) = self.sink.record_sample(topic, &json, timestamp_ns) {
+                                log::warn!("{}", e);
+                            }
+                        }
+                    }
+                    Err(e) => log::debug!("DDS read failed for {}: {}", topic, e),
+                }
+            }
+
+            // Blocks while the delivery queue is full
+            for batch in self.sink.poll_batches() {
+                self.delivery.send(batch);
+            }
+
+            std::thread::sleep(POLL_INTERVAL);
+        }
+
+        self.delivery.send(self.sink.flush_all());
+        let stats = self.delivery.shutdown();
+        log::info!(
+            "Influx sink stopped: {} samples recorded, {} lines written, {} dropped",
+            self.sink.samples_recorded(),
+            stats.lines_written(),
+            stats.lines_dropped()
+        );
+        Ok(stats)
+    }
+
+    /// Subscribe to the configured topics discovered since the last call.
+    fn subscribe(
+        &mut self,
+        participant: &Arc<Participant>,
+        topics: &HashSet<String>,
+        subscriptions: &mut HashMap<String, Subscription>,
+    ) {
+        let discovered = match participant.discover_topics() {
+            Ok(discovered) => discovered,
+            Err(e) => {
+                log::warn!("DDS discovery failed: {}", e);
+                return;
+            }
+        };
+        for info in discovered {
+            if !topics.contains(&info.name) || subscriptions.contains_key(&info.name) {
+                continue;
+            }
+            // Retried on the next discovery, writers may announce it later
+            let Some(type_object) = &info.type_object else {
+                log::debug!("No TypeObject for {} yet, cannot decode it", info.name);
+                continue;
+            };
+            let decoder = SampleDecoder::from_type_object(type_object);
+            let reader = match participant.create_raw_reader_with_type(
+                &info.name,
+                &info.type_name,
+                Some(info.qos.clone()),
+                info.type_object.clone(),
+            ) {
+                Ok(reader) => reader,
+                Err(e) => {
+                    log::warn!("Failed to create raw reader for {}: {}", info.name, e);
+                    continue;
+                }
+            };
+            if let Err(e) = self
+                .sink
+                .set_key_fields(&info.name, decoder.key_fields().to_vec())
+            {
+                log::warn!("{}", e);
+            }
+
+            log::info!("Subscribed to {} ({})", info.name, info.type_name);
+            subscriptions.insert(info.name, Subscription { reader, decoder });
+        }
+    }
+}
+
+/// Handle to stop a running [`SinkRuntime`].
+#[derive(Clone)]
+pub struct StopHandle {
+    running: Arc<AtomicBool>,
+}
+
+impl StopHandle {
+    /// Stop the runtime; it delivers the pending lines, then returns.
+    pub fn stop(&self) {
+        self.running.store(false, Ordering::SeqCst);
+    }
+}
@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Sink runtime writing a DDS topic to a local InfluxDB stand-in, over the
+//! loopback bus.
+
+use hdds::{Participant, QoS, TransportMode};
+use hdds_influx_sink::{SinkConfig, SinkRuntime};
+use std::collections::BTreeSet;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const DOMAIN: u32 = 102;
+const TOPIC: &str = "influx_sink/reading";
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Reading {
+    #[key]
+    sensor_id: u32,
+    location: String,
+    value: f64,
+}
+
+fn participant(name: &str) -> Arc<Participant> {
+    Participant::builder(name)
+        .domain_id(DOMAIN)
+        .with_transport(TransportMode::LoopbackBus)
+        .build()
+        .expect("participant")
+}
+
+/// Accept write requests on a local port, sending each body to `bodies`.
+fn serve_influx(bodies: mpsc::Sender<String>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+    let url = format!("http://{}", listener.local_addr().expect("addr"));
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut reader = BufReader::new(stream.expect("accept"));
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).expect("read");
+                if line == "\r\n" {
+                    break;
+                }
+                if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    length = value.trim().parse().expect("length");
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).expect("body");
+            reader
+                .get_mut()
+                .write_all(b"HTTP/1.1 204 No Content\r\nConnection: close\r\n\r\n")
+                .expect("write");
+            let _ = bodies.send(String::from_utf8(body).expect("utf8"));
+        }
+    });
+    url
+}
+
+#[test]
+fn test_runtime_writes_samples_to_influx() {
+    let (tx, bodies) = mpsc::channel();
+    let url = serve_influx(tx);
+    let config = SinkConfig::from_yaml(&format!(
+        r#"
+influxdb:
+  url: "{url}"
+  org: "org"
+  bucket: "telemetry"
+  token: "token"
+domain_id: {DOMAIN}
+sinks:
+  - topic: "{TOPIC}"
+    measurement: "reading"
+    tags: [location]
+    fields: [value]
+    batch_size: 2
+    flush_interval_ms: 60000
+"#
+    ))
+    .expect("config");
+
+    let publisher = participant("influx_sink_publisher");
+    let writer = publisher
+        .topic::<Reading>(TOPIC)
+        .expect("topic")
+        .writer()
+        .qos(QoS::reliable().keep_all())
+        .build()
+        .expect("writer");
+
+    // The runtime subscribes once it discovers the writer and its type
+    let sink = participant("influx_sink_sink");
+    let runtime = SinkRuntime::new(config).expect("runtime");
+    let stop = runtime.stop_handle();
+    let run = thread::spawn(move || runtime.run_on(&sink));
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while writer.matched_readers_count() == 0 {
+        assert!(Instant::now() < deadline, "sink never subscribed");
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    for (sensor_id, value) in [(1, 20.5), (2, 21.0), (1, 22.0)] {
+        let sample = Reading {
+            sensor_id,
+            location: "lab 1".to_string(),
+            value,
+        };
+        writer
+            .write_with_timestamp(&sample, u64::from(sensor_id) * 1_000 + value as u64)
+            .expect("write");
+    }
+
+    // The first two samples fill a batch, delivered while running
+    let first = bodies
+        .recv_timeout(Duration::from_secs(10))
+        .expect("first batch");
+    assert_eq!(first.lines().count(), 2);
+
+    // The rest is flushed on stop. Same-process participants get each
+    // sample twice, through the loopback bus and from the writer itself:
+    // the copies are the same points.
+    thread::sleep(Duration::from_millis(200));
+    stop.stop();
+    let stats = run.join().expect("run").expect("runtime");
+    let mut points: BTreeSet<String> = first.lines().map(str::to_string).collect();
+    while let Ok(body) = bodies.recv_timeout(Duration::from_millis(500)) {
+        points.extend(body.lines().map(str::to_string));
+    }
+    assert_eq!(
+        points.into_iter().collect::<Vec<_>>(),
+        [
+            "reading,location=lab\\ 1,sensor_id=1 value=20.5 1020",
+            "reading,location=lab\\ 1,sensor_id=1 value=22 1022",
+            "reading,location=lab\\ 1,sensor_id=2 value=21 2021",
+        ]
+    );
+    assert_eq!(stats.lines_dropped(), 0);
+    assert!(stats.lines_written() >= 3);
+}
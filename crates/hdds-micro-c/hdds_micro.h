@@ -287,6 +287,32 @@ enum HddsMicroError hdds_micro_take(struct HddsMicroReader *reader,
                                     uintptr_t *out_len,
                                     struct HddsMicroSampleInfo *out_info);
 
+/**
+ * Drain pending packets into the reader's history cache (non-blocking)
+ *
+ * Call this from a fast loop or timer tick; `hdds_micro_read` then returns
+ * cached samples oldest first. When the cache is full the oldest sample is
+ * overwritten (see `hdds_micro_reader_overwrite_count`).
+ *
+ * Returns the number of samples cached, or -1 on error.
+ *
+ * # Safety
+ *
+ * - `reader` must be a valid pointer
+ */
+int32_t hdds_micro_reader_poll(struct HddsMicroReader *reader);
+
+/**
+ * Get the number of samples dropped because the reader's history cache was full
+ *
+ * Returns 0 if `reader` is NULL.
+ *
+ * # Safety
+ *
+ * - `reader` must be a valid pointer
+ */
+uint32_t hdds_micro_reader_overwrite_count(const struct HddsMicroReader *reader);
+
 /**
  * Get reader's topic name
  *
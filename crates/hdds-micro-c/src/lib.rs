@@ -44,7 +44,7 @@ use std::slice;
 use hdds_micro::cdr::{CdrDecoder, CdrEncoder};
 use hdds_micro::rtps::Locator;
 use hdds_micro::transport::{NullTransport, Transport};
-use hdds_micro::{Error, MicroParticipant, MicroReader, MicroWriter};
+use hdds_micro::{Error, MicroParticipant, MicroReader, MicroWriter, MAX_HISTORY_DEPTH};
 
 // =============================================================================
 // ERROR CODES
@@ -489,8 +489,10 @@ pub unsafe extern "C" fn hdds_micro_writer_destroy(writer: *mut HddsMicroWriter)
 // =============================================================================
 
 /// Internal reader with reference to participant's transport
+///
+/// Readers created through the C API keep the maximum history depth.
 struct ReaderHandle {
-    reader: MicroReader,
+    reader: MicroReader<MAX_HISTORY_DEPTH>,
     participant: *mut MicroParticipant<BoxedTransport>,
 }
 
@@ -618,6 +620,49 @@ pub unsafe extern "C" fn hdds_micro_take(
     hdds_micro_read(reader, out_data, max_len, out_len, out_info)
 }
 
+/// Drain pending packets into the reader's history cache (non-blocking)
+///
+/// Call this from a fast loop or timer tick; `hdds_micro_read` then returns
+/// cached samples oldest first. When the cache is full the oldest sample is
+/// overwritten (see `hdds_micro_reader_overwrite_count`).
+///
+/// Returns the number of samples cached, or -1 on error.
+///
+/// # Safety
+///
+/// - `reader` must be a valid pointer
+#[no_mangle]
+pub unsafe extern "C" fn hdds_micro_reader_poll(reader: *mut HddsMicroReader) -> i32 {
+    if reader.is_null() {
+        return -1;
+    }
+
+    let handle = &mut *(reader as *mut ReaderHandle);
+    let participant = &mut *handle.participant;
+
+    match handle.reader.poll(participant.transport_mut()) {
+        Ok(cached) => i32::try_from(cached).unwrap_or(i32::MAX),
+        Err(_) => -1,
+    }
+}
+
+/// Get the number of samples dropped because the reader's history cache was full
+///
+/// Returns 0 if `reader` is NULL.
+///
+/// # Safety
+///
+/// - `reader` must be a valid pointer
+#[no_mangle]
+pub unsafe extern "C" fn hdds_micro_reader_overwrite_count(reader: *const HddsMicroReader) -> u32 {
+    if reader.is_null() {
+        return 0;
+    }
+
+    let handle = &*(reader as *const ReaderHandle);
+    handle.reader.overwrite_count()
+}
+
 /// Get reader's topic name
 ///
 /// # Safety
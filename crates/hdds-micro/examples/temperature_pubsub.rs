@@ -163,7 +163,8 @@ fn run_subscriber() -> Result<()> {
 
     // Create reader
     let reader_id = participant.allocate_entity_id(false);
-    let mut reader = MicroReader::new(participant.guid_prefix(), reader_id, "Temperature")?;
+    let mut reader: MicroReader =
+        MicroReader::new(participant.guid_prefix(), reader_id, "Temperature")?;
 
     println!("[OK] Reader created for topic 'Temperature'");
     println!("[<] Waiting for samples...\n");
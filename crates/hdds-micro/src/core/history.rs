@@ -0,0 +1,165 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Fixed-depth history cache for MicroReader
+//!
+//! KEEP_LAST semantics: when all `DEPTH` slots are occupied, the oldest
+//! sample is overwritten and the overwrite counter is incremented.
+
+use crate::rtps::{SequenceNumber, GUID};
+use crate::{MAX_HISTORY_DEPTH, MAX_PACKET_SIZE};
+
+/// One cached sample
+#[derive(Debug, PartialEq)]
+pub(crate) struct Slot {
+    pub(crate) writer_guid: GUID,
+    pub(crate) sequence_number: SequenceNumber,
+    len: usize,
+    payload: [u8; MAX_PACKET_SIZE],
+}
+
+impl Slot {
+    const EMPTY: Self = Self {
+        writer_guid: GUID::UNKNOWN,
+        sequence_number: SequenceNumber::UNKNOWN,
+        len: 0,
+        payload: [0u8; MAX_PACKET_SIZE],
+    };
+
+    /// Cached payload (CDR-encoded)
+    pub(crate) fn payload(&self) -> &[u8] {
+        &self.payload[0..self.len]
+    }
+}
+
+/// Ring of `DEPTH` sample slots (no heap)
+#[derive(Debug, PartialEq)]
+pub(crate) struct HistoryCache<const DEPTH: usize> {
+    slots: [Slot; DEPTH],
+    /// Index of the oldest sample
+    head: usize,
+    len: usize,
+    overwrites: u32,
+}
+
+impl<const DEPTH: usize> HistoryCache<DEPTH> {
+    const VALID_DEPTH: () = assert!(
+        DEPTH > 0 && DEPTH <= MAX_HISTORY_DEPTH,
+        "history depth must be in 1..=MAX_HISTORY_DEPTH"
+    );
+
+    /// Create an empty cache
+    pub(crate) const fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_DEPTH;
+
+        Self {
+            slots: [Slot::EMPTY; DEPTH],
+            head: 0,
+            len: 0,
+            overwrites: 0,
+        }
+    }
+
+    /// Number of cached samples
+    pub(crate) const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Number of samples overwritten because the cache was full
+    pub(crate) const fn overwrites(&self) -> u32 {
+        self.overwrites
+    }
+
+    /// Store a sample, overwriting the oldest one if the cache is full
+    ///
+    /// `payload` must fit in `MAX_PACKET_SIZE` (always true for payloads
+    /// extracted from a received packet).
+    pub(crate) fn push(
+        &mut self,
+        writer_guid: GUID,
+        sequence_number: SequenceNumber,
+        payload: &[u8],
+    ) {
+        let index = if self.len == DEPTH {
+            let oldest = self.head;
+            self.head = (self.head + 1) % DEPTH;
+            self.overwrites = self.overwrites.saturating_add(1);
+            oldest
+        } else {
+            let free = (self.head + self.len) % DEPTH;
+            self.len += 1;
+            free
+        };
+
+        let slot = &mut self.slots[index];
+        slot.writer_guid = writer_guid;
+        slot.sequence_number = sequence_number;
+        slot.len = payload.len();
+        slot.payload[0..payload.len()].copy_from_slice(payload);
+    }
+
+    /// Remove the oldest sample, returning its slot index
+    ///
+    /// The slot stays readable through `slot()` until the next `push`.
+    pub(crate) fn pop(&mut self) -> Option<usize> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let oldest = self.head;
+        self.head = (self.head + 1) % DEPTH;
+        self.len -= 1;
+
+        Some(oldest)
+    }
+
+    /// Slot at `index` (as returned by `pop`)
+    pub(crate) fn slot(&self, index: usize) -> &Slot {
+        &self.slots[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guid() -> GUID {
+        GUID::UNKNOWN
+    }
+
+    #[test]
+    fn test_history_fifo_order() {
+        let mut cache = HistoryCache::<4>::new();
+
+        cache.push(guid(), SequenceNumber::new(1), &[1]);
+        cache.push(guid(), SequenceNumber::new(2), &[2, 2]);
+        assert_eq!(cache.len(), 2);
+
+        let slot = cache.pop().map(|index| cache.slot(index)).unwrap();
+        assert_eq!(slot.sequence_number, SequenceNumber::new(1));
+        assert_eq!(slot.payload(), &[1]);
+
+        let slot = cache.pop().map(|index| cache.slot(index)).unwrap();
+        assert_eq!(slot.payload(), &[2, 2]);
+        assert!(cache.pop().is_none());
+        assert_eq!(cache.overwrites(), 0);
+    }
+
+    #[test]
+    fn test_history_overwrites_oldest() {
+        let mut cache = HistoryCache::<2>::new();
+
+        for seq in 1..=5 {
+            cache.push(guid(), SequenceNumber::new(seq), &[seq as u8]);
+        }
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.overwrites(), 3);
+        let index = cache.pop().unwrap();
+        assert_eq!(cache.slot(index).sequence_number, SequenceNumber::new(4));
+        let index = cache.pop().unwrap();
+        assert_eq!(cache.slot(index).sequence_number, SequenceNumber::new(5));
+        assert!(cache.pop().is_none());
+    }
+}
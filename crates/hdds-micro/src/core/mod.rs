@@ -3,6 +3,7 @@
 
 //! Core DDS structs for HDDS Micro
 
+mod history;
 mod participant;
 mod reader;
 mod writer;
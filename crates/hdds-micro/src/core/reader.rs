@@ -3,9 +3,10 @@
 
 //! MicroReader - DDS DataReader for embedded
 
+use super::history::HistoryCache;
 use crate::error::{Error, Result};
 use crate::rtps::submessages::Data;
-use crate::rtps::{EntityId, GuidPrefix, RtpsHeader, GUID};
+use crate::rtps::{EntityId, GuidPrefix, RtpsHeader, SequenceNumber, GUID};
 use crate::transport::Transport;
 use crate::MAX_PACKET_SIZE;
 
@@ -29,13 +30,18 @@ pub struct Sample<'a> {
 /// # Design
 ///
 /// - BEST_EFFORT QoS (no acknowledgments)
-/// - No history cache (process immediately)
+/// - KEEP_LAST history cache of `DEPTH` samples (1..=`MAX_HISTORY_DEPTH`)
 /// - Fixed-size receive buffer
 ///
+/// `read()` returns cached samples first, then receives directly from the
+/// transport. Call `poll()` from a fast loop (or timer tick) to drain the
+/// transport into the cache; when the cache is full the oldest sample is
+/// overwritten and counted in `overwrite_count()`.
+///
 /// # Example
 ///
 /// ```ignore
-/// let reader = MicroReader::new(
+/// let mut reader: MicroReader<4> = MicroReader::new(
 ///     participant.guid_prefix(),
 ///     reader_entity_id,
 ///     "Temperature",
@@ -49,7 +55,7 @@ pub struct Sample<'a> {
 /// }
 /// ```
 #[derive(Debug, PartialEq)]
-pub struct MicroReader {
+pub struct MicroReader<const DEPTH: usize = 1> {
     /// Reader GUID
     guid: GUID,
 
@@ -59,9 +65,27 @@ pub struct MicroReader {
 
     /// Receive buffer (reusable)
     rx_buffer: [u8; MAX_PACKET_SIZE],
+
+    /// Samples received by `poll()` and not yet read
+    history: HistoryCache<DEPTH>,
+}
+
+/// Outcome of receiving one packet into the receive buffer
+enum Received {
+    /// No packet available
+    Empty,
+    /// Packet addressed to another reader
+    Ignored,
+    /// DATA for this reader, payload at `rx_buffer[start..end]`
+    Sample {
+        writer_guid: GUID,
+        sequence_number: SequenceNumber,
+        start: usize,
+        end: usize,
+    },
 }
 
-impl MicroReader {
+impl<const DEPTH: usize> MicroReader<DEPTH> {
     /// Create a new reader
     ///
     /// # Arguments
@@ -82,6 +106,7 @@ impl MicroReader {
             topic_name: topic_name_buf,
             topic_len: topic_name.len(),
             rx_buffer: [0u8; MAX_PACKET_SIZE],
+            history: HistoryCache::new(),
         })
     }
 
@@ -95,14 +120,84 @@ impl MicroReader {
         core::str::from_utf8(&self.topic_name[0..self.topic_len]).unwrap_or("")
     }
 
+    /// History depth (number of samples the cache can hold)
+    pub const fn history_depth(&self) -> usize {
+        DEPTH
+    }
+
+    /// Number of samples waiting in the history cache
+    pub const fn history_len(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Number of samples overwritten because the history cache was full
+    ///
+    /// Saturates at `u32::MAX`.
+    pub const fn overwrite_count(&self) -> u32 {
+        self.history.overwrites()
+    }
+
+    /// Drain all pending packets from the transport into the history cache
+    /// (non-blocking)
+    ///
+    /// Returns the number of samples cached by this call, including those
+    /// that overwrote older samples.
+    pub fn poll<T: Transport>(&mut self, transport: &mut T) -> Result<usize> {
+        let mut cached = 0;
+
+        loop {
+            match self.receive(transport)? {
+                Received::Empty => return Ok(cached),
+                Received::Ignored => {}
+                Received::Sample {
+                    writer_guid,
+                    sequence_number,
+                    start,
+                    end,
+                } => {
+                    self.history
+                        .push(writer_guid, sequence_number, &self.rx_buffer[start..end]);
+                    cached += 1;
+                }
+            }
+        }
+    }
+
     /// Read a sample (non-blocking)
     ///
-    /// Returns `None` if no sample available.
+    /// Returns the oldest cached sample if any, otherwise receives one packet
+    /// from the transport. Returns `None` if no sample available.
     pub fn read<T: Transport>(&mut self, transport: &mut T) -> Result<Option<Sample<'_>>> {
+        if let Some(index) = self.history.pop() {
+            let slot = self.history.slot(index);
+            return Ok(Some(Sample {
+                writer_guid: slot.writer_guid,
+                sequence_number: slot.sequence_number,
+                payload: slot.payload(),
+            }));
+        }
+
+        match self.receive(transport)? {
+            Received::Empty | Received::Ignored => Ok(None),
+            Received::Sample {
+                writer_guid,
+                sequence_number,
+                start,
+                end,
+            } => Ok(Some(Sample {
+                writer_guid,
+                sequence_number,
+                payload: &self.rx_buffer[start..end],
+            })),
+        }
+    }
+
+    /// Receive and decode one packet into the receive buffer
+    fn receive<T: Transport>(&mut self, transport: &mut T) -> Result<Received> {
         // Try to receive packet
         let (bytes_received, _source_locator) = match transport.try_recv(&mut self.rx_buffer) {
             Ok(result) => result,
-            Err(Error::ResourceExhausted) => return Ok(None), // No packet available
+            Err(Error::ResourceExhausted) => return Ok(Received::Empty), // No packet available
             Err(e) => return Err(e),
         };
 
@@ -114,7 +209,7 @@ impl MicroReader {
 
         // Filter by entity ID (if specified)
         if data.reader_id != EntityId::UNKNOWN && data.reader_id != self.guid.entity_id {
-            return Ok(None); // Not for us
+            return Ok(Received::Ignored); // Not for us
         }
 
         // Extract payload
@@ -125,16 +220,15 @@ impl MicroReader {
             return Err(Error::DecodingError);
         }
 
-        let payload = &self.rx_buffer[payload_start..payload_end];
-
         // Build writer GUID
         let writer_guid = GUID::new(header.guid_prefix, data.writer_id);
 
-        Ok(Some(Sample {
+        Ok(Received::Sample {
             writer_guid,
             sequence_number: data.writer_sn,
-            payload,
-        }))
+            start: payload_start,
+            end: payload_end,
+        })
     }
 
     // Note: Blocking read is not provided due to borrow checker limitations.
@@ -145,11 +239,13 @@ impl MicroReader {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::MicroWriter;
+    use crate::rtps::Locator;
     use crate::transport::NullTransport;
 
     #[test]
     fn test_reader_creation() {
-        let reader = MicroReader::new(
+        let reader: MicroReader = MicroReader::new(
             GuidPrefix::new([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]),
             EntityId::new([0, 0, 0, 0xc7]),
             "TestTopic",
@@ -161,7 +257,7 @@ mod tests {
 
     #[test]
     fn test_reader_no_data() {
-        let mut reader =
+        let mut reader: MicroReader =
             MicroReader::new(GuidPrefix::default(), EntityId::default(), "TestTopic").unwrap();
 
         let mut transport = NullTransport::default();
@@ -173,8 +269,119 @@ mod tests {
     #[test]
     fn test_reader_topic_name_too_long() {
         let long_name = "a".repeat(100);
-        let result = MicroReader::new(GuidPrefix::default(), EntityId::default(), &long_name);
+        let result: Result<MicroReader> =
+            MicroReader::new(GuidPrefix::default(), EntityId::default(), &long_name);
 
         assert_eq!(result, Err(Error::InvalidParameter));
     }
+
+    /// Loopback transport holding up to 8 packets
+    struct QueueTransport {
+        packets: [[u8; MAX_PACKET_SIZE]; 8],
+        lens: [usize; 8],
+        head: usize,
+        len: usize,
+    }
+
+    impl QueueTransport {
+        fn new() -> Self {
+            Self {
+                packets: [[0u8; MAX_PACKET_SIZE]; 8],
+                lens: [0; 8],
+                head: 0,
+                len: 0,
+            }
+        }
+    }
+
+    impl Transport for QueueTransport {
+        fn init(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        fn send(&mut self, data: &[u8], _dest: &Locator) -> Result<usize> {
+            let index = (self.head + self.len) % 8;
+            self.packets[index][0..data.len()].copy_from_slice(data);
+            self.lens[index] = data.len();
+            self.len += 1;
+            Ok(data.len())
+        }
+
+        fn recv(&mut self, buf: &mut [u8]) -> Result<(usize, Locator)> {
+            self.try_recv(buf)
+        }
+
+        fn try_recv(&mut self, buf: &mut [u8]) -> Result<(usize, Locator)> {
+            if self.len == 0 {
+                return Err(Error::ResourceExhausted);
+            }
+            let len = self.lens[self.head];
+            buf[0..len].copy_from_slice(&self.packets[self.head][0..len]);
+            self.head = (self.head + 1) % 8;
+            self.len -= 1;
+            Ok((len, Locator::default()))
+        }
+
+        fn local_locator(&self) -> Locator {
+            Locator::default()
+        }
+
+        fn mtu(&self) -> usize {
+            MAX_PACKET_SIZE
+        }
+
+        fn shutdown(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    fn publish(transport: &mut QueueTransport, count: u8) {
+        let mut writer = MicroWriter::new(
+            GuidPrefix::new([1; 12]),
+            EntityId::new([0, 0, 0, 0xc2]),
+            "TestTopic",
+            Locator::default(),
+        )
+        .unwrap();
+
+        for value in 0..count {
+            writer.write(&[value, 0, 0, 0], transport).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_reader_poll_overwrites_oldest() {
+        let mut reader: MicroReader<2> =
+            MicroReader::new(GuidPrefix::default(), EntityId::default(), "TestTopic").unwrap();
+        let mut transport = QueueTransport::new();
+
+        publish(&mut transport, 5);
+        assert_eq!(reader.poll(&mut transport).unwrap(), 5);
+        assert_eq!(reader.history_depth(), 2);
+        assert_eq!(reader.history_len(), 2);
+        assert_eq!(reader.overwrite_count(), 3);
+
+        // Only the two most recent samples survive, oldest first
+        let sample = reader.read(&mut transport).unwrap().unwrap();
+        assert_eq!(sample.sequence_number, SequenceNumber::new(4));
+        assert_eq!(sample.payload, &[3, 0, 0, 0]);
+        let sample = reader.read(&mut transport).unwrap().unwrap();
+        assert_eq!(sample.sequence_number, SequenceNumber::new(5));
+        assert!(reader.read(&mut transport).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_reader_read_without_poll() {
+        let mut reader: MicroReader =
+            MicroReader::new(GuidPrefix::default(), EntityId::default(), "TestTopic").unwrap();
+        let mut transport = QueueTransport::new();
+
+        // Samples are received one by one, nothing is overwritten
+        publish(&mut transport, 3);
+        for seq in 1..=3 {
+            let sample = reader.read(&mut transport).unwrap().unwrap();
+            assert_eq!(sample.sequence_number, SequenceNumber::new(seq));
+        }
+        assert_eq!(reader.overwrite_count(), 0);
+    }
 }
@@ -3,6 +3,7 @@
 
 //! Persistence service configuration
 
+use crate::coordination::CoordinationMode;
 use serde::{Deserialize, Serialize};
 
 /// Persistence service configuration
@@ -28,6 +29,22 @@ pub struct Config {
 
     /// Subscribe to volatile writers (default: false, only TRANSIENT_LOCAL)
     pub subscribe_volatile: bool,
+
+    /// How replicas share replay (default: none, single instance)
+    #[serde(default)]
+    pub coordination: CoordinationMode,
+
+    /// Unique id of this replica (empty = participant GUID)
+    #[serde(default)]
+    pub instance_id: String,
+
+    /// Coordination lease duration in milliseconds (failover delay)
+    #[serde(default = "default_lease_duration_ms")]
+    pub lease_duration_ms: u64,
+}
+
+fn default_lease_duration_ms() -> u64 {
+    3000
 }
 
 impl Default for Config {
@@ -40,6 +57,9 @@ impl Default for Config {
             domain_id: 0,
             participant_name: "PersistenceService".to_string(),
             subscribe_volatile: false,
+            coordination: CoordinationMode::None,
+            instance_id: String::new(),
+            lease_duration_ms: default_lease_duration_ms(),
         }
     }
 }
@@ -61,6 +81,9 @@ pub struct ConfigBuilder {
     domain_id: Option<u32>,
     participant_name: Option<String>,
     subscribe_volatile: Option<bool>,
+    coordination: Option<CoordinationMode>,
+    instance_id: Option<String>,
+    lease_duration_ms: Option<u64>,
 }
 
 impl ConfigBuilder {
@@ -106,6 +129,24 @@ impl ConfigBuilder {
         self
     }
 
+    /// Set the coordination mode between replicas
+    pub fn coordination(mut self, mode: CoordinationMode) -> Self {
+        self.coordination = Some(mode);
+        self
+    }
+
+    /// Set the replica instance id (must be unique among replicas)
+    pub fn instance_id(mut self, id: impl Into<String>) -> Self {
+        self.instance_id = Some(id.into());
+        self
+    }
+
+    /// Set the coordination lease duration in milliseconds
+    pub fn lease_duration_ms(mut self, ms: u64) -> Self {
+        self.lease_duration_ms = Some(ms);
+        self
+    }
+
     /// Build the configuration
     pub fn build(self) -> Config {
         let defaults = Config::default();
@@ -124,6 +165,9 @@ impl ConfigBuilder {
             subscribe_volatile: self
                 .subscribe_volatile
                 .unwrap_or(defaults.subscribe_volatile),
+            coordination: self.coordination.unwrap_or(defaults.coordination),
+            instance_id: self.instance_id.unwrap_or(defaults.instance_id),
+            lease_duration_ms: self.lease_duration_ms.unwrap_or(defaults.lease_duration_ms),
         }
     }
 }
@@ -157,5 +201,7 @@ mod tests {
         assert_eq!(config.retention_count, 10000);
         assert_eq!(config.retention_time_secs, 0);
         assert_eq!(config.domain_id, 0);
+        assert_eq!(config.coordination, CoordinationMode::None);
+        assert_eq!(config.lease_duration_ms, 3000);
     }
 }
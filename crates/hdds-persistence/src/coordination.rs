@@ -0,0 +1,398 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Multi-instance coordination
+//!
+//! Lets several persistence services run side by side for high availability
+//! without replaying the same history twice. Every replica keeps storing all
+//! samples, so any of them can take over; only replay is coordinated.
+//!
+//! # Leases
+//!
+//! Each replica publishes a lease (its instance id and lease duration) on
+//! [`COORDINATION_TOPIC`] every third of the lease duration. A replica is
+//! live while its last lease has not expired; a crashed replica drops out
+//! once its lease runs out, which is the failover delay.
+//!
+//! # Modes
+//!
+//! - [`CoordinationMode::LeaderElection`] -- the live replica with the
+//!   smallest instance id replays every topic.
+//! - [`CoordinationMode::Partitioned`] -- each topic is replayed by one live
+//!   replica chosen by rendezvous hashing, so the replay load is spread and
+//!   only the topics of a failed replica move.
+//!
+//! Leases can also come from outside DDS (e.g. an external lock service)
+//! through [`Coordinator::observe`].
+
+use crate::config::Config;
+use crate::dds_interface::{DataReader, DataWriter, DdsInterface, DurabilityKind};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::time::interval;
+
+/// Topic the replicas exchange leases on
+pub const COORDINATION_TOPIC: &str = "hdds/persistence/coordination";
+
+/// Type name of the lease samples
+pub const COORDINATION_TYPE: &str = "hdds::persistence::Lease";
+
+/// Shortest lease announcement period
+const MIN_TICK: Duration = Duration::from_millis(10);
+
+/// How replicas share the replay work
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CoordinationMode {
+    /// No coordination: every instance replays (single-instance deployments)
+    #[default]
+    None,
+    /// One elected leader replays all topics
+    LeaderElection,
+    /// Topics are partitioned across the live replicas
+    Partitioned,
+}
+
+/// Lease announced by a replica
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lease {
+    /// Instance id of the replica
+    pub instance_id: String,
+    /// Time the lease stays valid after it was received
+    pub duration: Duration,
+}
+
+impl Lease {
+    /// Encode as `duration_ms (u64 LE) || instance_id (UTF-8)`
+    pub fn encode(&self) -> Vec<u8> {
+        let millis = u64::try_from(self.duration.as_millis()).unwrap_or(u64::MAX);
+        let mut buf = Vec::with_capacity(8 + self.instance_id.len());
+        buf.extend_from_slice(&millis.to_le_bytes());
+        buf.extend_from_slice(self.instance_id.as_bytes());
+        buf
+    }
+
+    /// Decode a lease, or `None` if the payload is malformed
+    pub fn decode(buf: &[u8]) -> Option<Self> {
+        let millis = u64::from_le_bytes(buf.get(..8)?.try_into().ok()?);
+        let instance_id = std::str::from_utf8(&buf[8..]).ok()?;
+        if instance_id.is_empty() {
+            return None;
+        }
+        Some(Self {
+            instance_id: instance_id.to_string(),
+            duration: Duration::from_millis(millis),
+        })
+    }
+}
+
+/// Membership view of one replica
+///
+/// Decides which topics this replica replays.
+pub struct Coordinator {
+    instance_id: String,
+    mode: CoordinationMode,
+    lease_duration: Duration,
+    /// Lease expiry of the other replicas
+    peers: Mutex<HashMap<String, Instant>>,
+}
+
+impl Coordinator {
+    /// Create the coordinator of the replica `instance_id`
+    pub fn new(
+        instance_id: impl Into<String>,
+        mode: CoordinationMode,
+        lease_duration: Duration,
+    ) -> Self {
+        Self {
+            instance_id: instance_id.into(),
+            mode,
+            lease_duration,
+            peers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Create the coordinator configured in `config`
+    ///
+    /// Returns `None` when coordination is disabled. An empty instance id
+    /// defaults to the participant `guid`.
+    pub fn from_config(config: &Config, guid: [u8; 16]) -> Option<Self> {
+        if config.coordination == CoordinationMode::None {
+            return None;
+        }
+        let instance_id = if config.instance_id.is_empty() {
+            guid.iter().map(|b| format!("{:02x}", b)).collect()
+        } else {
+            config.instance_id.clone()
+        };
+        Some(Self::new(
+            instance_id,
+            config.coordination,
+            Duration::from_millis(config.lease_duration_ms),
+        ))
+    }
+
+    /// Instance id of this replica
+    pub fn instance_id(&self) -> &str {
+        &self.instance_id
+    }
+
+    /// Coordination mode
+    pub fn mode(&self) -> CoordinationMode {
+        self.mode
+    }
+
+    /// Lease duration announced by this replica
+    pub fn lease_duration(&self) -> Duration {
+        self.lease_duration
+    }
+
+    /// Lease announced by this replica
+    pub fn own_lease(&self) -> Lease {
+        Lease {
+            instance_id: self.instance_id.clone(),
+            duration: self.lease_duration,
+        }
+    }
+
+    /// Record a lease received at `now`
+    ///
+    /// Leases carrying our own instance id are ignored.
+    pub fn observe(&self, lease: &Lease, now: Instant) {
+        if lease.instance_id == self.instance_id {
+            return;
+        }
+        let mut peers = self.lock_peers();
+        let is_new = !peers.contains_key(&lease.instance_id);
+        peers.insert(lease.instance_id.clone(), now + lease.duration);
+        if is_new {
+            tracing::info!("Persistence replica '{}' joined", lease.instance_id);
+        }
+    }
+
+    /// Drop the replicas whose lease expired before `now`
+    ///
+    /// Returns the instance ids that were removed.
+    pub fn expire(&self, now: Instant) -> Vec<String> {
+        let mut peers = self.lock_peers();
+        let expired: Vec<String> = peers
+            .iter()
+            .filter(|(_, expiry)| **expiry < now)
+            .map(|(id, _)| id.clone())
+            .collect();
+        for id in &expired {
+            peers.remove(id);
+            tracing::warn!("Persistence replica '{}' lease expired", id);
+        }
+        expired
+    }
+
+    /// Live replicas at `now` (including this one), sorted by instance id
+    pub fn live_members(&self, now: Instant) -> Vec<String> {
+        let peers = self.lock_peers();
+        let mut members: Vec<String> = peers
+            .iter()
+            .filter(|(_, expiry)| **expiry >= now)
+            .map(|(id, _)| id.clone())
+            .collect();
+        members.push(self.instance_id.clone());
+        members.sort();
+        members
+    }
+
+    /// Current leader: the live replica with the smallest instance id
+    pub fn leader(&self, now: Instant) -> String {
+        self.live_members(now)
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| self.instance_id.clone())
+    }
+
+    /// Whether this replica replays `topic` at `now`
+    pub fn owns_topic(&self, topic: &str, now: Instant) -> bool {
+        match self.mode {
+            CoordinationMode::None => true,
+            CoordinationMode::LeaderElection => self.leader(now) == self.instance_id,
+            CoordinationMode::Partitioned => self
+                .live_members(now)
+                .into_iter()
+                .max_by_key(|member| rendezvous_weight(member, topic))
+                .is_none_or(|owner| owner == self.instance_id),
+        }
+    }
+
+    fn lock_peers(&self) -> std::sync::MutexGuard<'_, HashMap<String, Instant>> {
+        match self.peers.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+/// Rendezvous (highest random weight) hash of a member for a topic
+///
+/// FNV-1a with a murmur3 finalizer, so every replica computes the same owner
+/// regardless of build and ids differing in one character spread evenly.
+fn rendezvous_weight(member: &str, topic: &str) -> u64 {
+    const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = member
+        .bytes()
+        .chain(std::iter::once(0))
+        .chain(topic.bytes())
+        .fold(FNV_OFFSET, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+        });
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    hash ^ (hash >> 33)
+}
+
+/// Publishes this replica's lease and tracks the other replicas' leases
+pub struct CoordinationAgent<D: DdsInterface> {
+    coordinator: Arc<Coordinator>,
+    dds: Arc<D>,
+}
+
+impl<D: DdsInterface> CoordinationAgent<D> {
+    /// Create an agent for `coordinator`
+    pub fn new(coordinator: Arc<Coordinator>, dds: Arc<D>) -> Self {
+        Self { coordinator, dds }
+    }
+
+    /// Run the agent
+    pub async fn run(self) -> Result<()> {
+        let writer = self.dds.create_writer(
+            COORDINATION_TOPIC,
+            COORDINATION_TYPE,
+            DurabilityKind::Volatile,
+        )?;
+        let reader = self.dds.create_reader(
+            COORDINATION_TOPIC,
+            COORDINATION_TYPE,
+            DurabilityKind::Volatile,
+        )?;
+
+        tracing::info!(
+            "Coordination started: instance '{}' mode={:?} lease={:?}",
+            self.coordinator.instance_id(),
+            self.coordinator.mode(),
+            self.coordinator.lease_duration()
+        );
+
+        let mut ticker = interval((self.coordinator.lease_duration() / 3).max(MIN_TICK));
+        let mut leader = None;
+        loop {
+            ticker.tick().await;
+            self.tick(writer.as_ref(), reader.as_ref());
+
+            if self.coordinator.mode() == CoordinationMode::LeaderElection {
+                let current = self.coordinator.leader(Instant::now());
+                if leader.as_ref() != Some(&current) {
+                    tracing::info!("Persistence leader is now '{}'", current);
+                    leader = Some(current);
+                }
+            }
+        }
+    }
+
+    /// Announce our lease, then apply the leases received since the last tick
+    fn tick(&self, writer: &dyn DataWriter, reader: &dyn DataReader) {
+        if let Err(e) = writer.write(&self.coordinator.own_lease().encode()) {
+            tracing::warn!("Failed to announce coordination lease: {}", e);
+        }
+
+        let now = Instant::now();
+        match reader.take() {
+            Ok(samples) => {
+                for sample in samples {
+                    match Lease::decode(&sample.payload) {
+                        Some(lease) => self.coordinator.observe(&lease, now),
+                        None => tracing::debug!("Ignoring malformed coordination lease"),
+                    }
+                }
+            }
+            Err(e) => tracing::warn!("Failed to take coordination leases: {}", e),
+        }
+        self.coordinator.expire(now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEASE: Duration = Duration::from_secs(3);
+
+    fn lease(id: &str) -> Lease {
+        Lease {
+            instance_id: id.to_string(),
+            duration: LEASE,
+        }
+    }
+
+    #[test]
+    fn test_lease_roundtrip() {
+        let encoded = lease("replica-a").encode();
+        assert_eq!(Lease::decode(&encoded), Some(lease("replica-a")));
+        assert_eq!(Lease::decode(&encoded[..7]), None);
+        assert_eq!(Lease::decode(&encoded[..8]), None);
+    }
+
+    #[test]
+    fn test_leader_election_failover() {
+        let now = Instant::now();
+        let a = Coordinator::new("replica-a", CoordinationMode::LeaderElection, LEASE);
+        let b = Coordinator::new("replica-b", CoordinationMode::LeaderElection, LEASE);
+        a.observe(&b.own_lease(), now);
+        b.observe(&a.own_lease(), now);
+
+        assert!(a.owns_topic("State/Temperature", now));
+        assert!(!b.owns_topic("State/Temperature", now));
+
+        // replica-a stops announcing: replica-b takes over once the lease runs out
+        let later = now + LEASE + Duration::from_millis(1);
+        assert!(!b.owns_topic("State/Temperature", now + LEASE));
+        assert!(b.owns_topic("State/Temperature", later));
+        assert_eq!(b.expire(later), vec!["replica-a".to_string()]);
+        assert_eq!(b.live_members(later), vec!["replica-b".to_string()]);
+    }
+
+    #[test]
+    fn test_partitioned_topics_have_one_owner() {
+        let now = Instant::now();
+        let replicas: Vec<Coordinator> = ["replica-a", "replica-b", "replica-c"]
+            .iter()
+            .map(|id| Coordinator::new(*id, CoordinationMode::Partitioned, LEASE))
+            .collect();
+        for replica in &replicas {
+            for peer in &replicas {
+                replica.observe(&peer.own_lease(), now);
+            }
+        }
+
+        let topics: Vec<String> = (0..64).map(|i| format!("State/Topic{}", i)).collect();
+        let mut owned = vec![0; replicas.len()];
+        for topic in &topics {
+            let owners: Vec<usize> = (0..replicas.len())
+                .filter(|&i| replicas[i].owns_topic(topic, now))
+                .collect();
+            assert_eq!(owners.len(), 1, "topic {} has owners {:?}", topic, owners);
+            owned[owners[0]] += 1;
+        }
+        assert!(owned.iter().all(|&count| count > 0), "{:?}", owned);
+    }
+
+    #[test]
+    fn test_no_coordination_owns_everything() {
+        let now = Instant::now();
+        let a = Coordinator::new("replica-a", CoordinationMode::None, LEASE);
+        a.observe(&lease("replica-0"), now);
+        assert!(a.owns_topic("any/topic", now));
+    }
+}
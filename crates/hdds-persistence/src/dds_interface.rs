@@ -34,11 +34,13 @@ pub struct ReceivedSample {
     pub type_name: String,
     /// Serialized payload (CDR)
     pub payload: Vec<u8>,
-    /// Source writer GUID
+    /// GUID of the writer that first published the sample, which differs
+    /// from the sending writer for samples replayed by a persistence service
     pub writer_guid: [u8; 16],
-    /// Sequence number
+    /// Sequence number the sample was first published with
     pub sequence: u64,
-    /// Reception timestamp (Unix nanoseconds)
+    /// Source timestamp, or reception time if the writer sent none (Unix
+    /// nanoseconds)
     pub timestamp_ns: u64,
 }
 
@@ -130,6 +132,15 @@ pub trait DataWriter: Send + Sync {
     /// Write a sample with timestamp
     fn write_with_timestamp(&self, payload: &[u8], timestamp_ns: u64) -> Result<()>;
 
+    /// Replay a stored sample on behalf of its original writer
+    ///
+    /// Implementations announce the original writer and sequence number so
+    /// PERSISTENT readers drop copies they already received. The default
+    /// only keeps the source timestamp.
+    fn write_replay(&self, sample: &Sample) -> Result<()> {
+        self.write_with_timestamp(&sample.payload, sample.timestamp_ns)
+    }
+
     /// Get topic name
     fn topic(&self) -> &str;
 
@@ -7,11 +7,12 @@ use crate::dds_interface::{
     DataReader, DataWriter, DdsInterface, DiscoveredReader, DiscoveredWriter, DiscoveryCallback,
     DurabilityKind, ReceivedSample,
 };
-use crate::store::RetentionPolicy;
+use crate::store::{RetentionPolicy, Sample};
 use anyhow::{anyhow, Result};
 use hdds::core::discovery::multicast::{DiscoveryListener, EndpointInfo, EndpointKind};
+use hdds::core::discovery::GUID;
 use hdds::dds::qos::{Durability, DurabilityService, History};
-use hdds::{Participant, QoS, RawDataReader, RawDataWriter};
+use hdds::{OriginalWriterInfo, Participant, QoS, RawDataReader, RawDataWriter};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
@@ -50,13 +51,20 @@ impl DataReader for HddsDataReader {
 
         Ok(samples
             .into_iter()
-            .map(|sample| ReceivedSample {
-                topic: self.topic.clone(),
-                type_name: self.type_name.clone(),
-                payload: sample.payload,
-                writer_guid: sample.writer_guid.as_bytes(),
-                sequence: sample.sequence_number.unwrap_or(0),
-                timestamp_ns: system_time_to_ns(sample.reception_timestamp),
+            .map(|sample| {
+                // Keyed by the original writer, whoever relayed the sample
+                let (writer_guid, sequence) = match sample.original_writer {
+                    Some(original) => (original.writer_guid, original.sequence),
+                    None => (sample.writer_guid, sample.sequence_number.unwrap_or(0)),
+                };
+                ReceivedSample {
+                    topic: self.topic.clone(),
+                    type_name: self.type_name.clone(),
+                    payload: sample.payload.to_vec(),
+                    writer_guid: writer_guid.as_bytes(),
+                    sequence,
+                    timestamp_ns: system_time_to_ns(sample.source_timestamp),
+                }
             })
             .collect())
     }
@@ -80,19 +88,37 @@ struct HddsDataWriter {
     type_name: String,
 }
 
-impl DataWriter for HddsDataWriter {
-    fn write(&self, payload: &[u8]) -> Result<()> {
-        let writer = match self.inner.lock() {
+impl HddsDataWriter {
+    fn writer(&self) -> std::sync::MutexGuard<'_, RawDataWriter> {
+        match self.inner.lock() {
             Ok(guard) => guard,
             Err(poisoned) => poisoned.into_inner(),
-        };
-        writer
+        }
+    }
+}
+
+impl DataWriter for HddsDataWriter {
+    fn write(&self, payload: &[u8]) -> Result<()> {
+        self.writer()
             .write_raw(payload)
             .map_err(|e| anyhow!("RawDataWriter::write_raw failed: {:?}", e))
     }
 
-    fn write_with_timestamp(&self, payload: &[u8], _timestamp_ns: u64) -> Result<()> {
-        self.write(payload)
+    fn write_with_timestamp(&self, payload: &[u8], timestamp_ns: u64) -> Result<()> {
+        self.writer()
+            .write_raw_with_timestamp(payload, timestamp_ns)
+            .map_err(|e| anyhow!("RawDataWriter::write_raw_with_timestamp failed: {:?}", e))
+    }
+
+    fn write_replay(&self, sample: &Sample) -> Result<()> {
+        if sample.source_guid == [0u8; 16] {
+            return self.write_with_timestamp(&sample.payload, sample.timestamp_ns);
+        }
+        let original =
+            OriginalWriterInfo::new(GUID::from_bytes(sample.source_guid), sample.sequence);
+        self.writer()
+            .write_relayed(&sample.payload, sample.timestamp_ns, original)
+            .map_err(|e| anyhow!("RawDataWriter::write_relayed failed: {:?}", e))
     }
 
     fn topic(&self) -> &str {
@@ -8,9 +8,12 @@
 //! # Features
 //!
 //! - **SQLite Backend** -- Zero-dependency, production-ready persistent storage
-//! - **RocksDB Backend** -- High-performance embedded database (feature flag)
+//! - **RocksDB Backend** -- High-throughput embedded database, a column family
+//!   per topic (feature `rocksdb-backend`)
 //! - **Late-joiner Support** -- Replay historical samples to new readers
 //! - **Retention Policies** -- Time-based, count-based, and size-based limits
+//! - **Durable Reader Positions** -- Resume consumers from their last committed sample
+//! - **Replica Coordination** -- Leader election or topic partitioning between HA instances
 //!
 //! # Architecture
 //!
@@ -18,6 +21,7 @@
 //! PersistenceService
 //! +-- DurabilitySubscriber  (listens to TRANSIENT/PERSISTENT topics)
 //! +-- LateJoinerPublisher   (replays history to new readers)
+//! +-- CoordinationAgent     (exchanges leases with other replicas, optional)
 //! +-- PersistenceStore      (SQLite or RocksDB backend)
 //! ```
 //!
@@ -37,19 +41,27 @@
 //! ```
 
 pub mod config;
+pub mod coordination;
 pub mod dds_interface;
 pub mod hdds_interface;
+pub mod position;
 pub mod publisher;
+#[cfg(feature = "rocksdb-backend")]
+pub mod rocksdb;
 pub mod sqlite;
 pub mod store;
 pub mod subscriber;
 
+#[cfg(feature = "rocksdb-backend")]
+pub use self::rocksdb::RocksDbStore;
 pub use config::Config;
+pub use coordination::{CoordinationAgent, CoordinationMode, Coordinator, Lease};
 pub use dds_interface::{
     DataReader, DataWriter, DdsInterface, DiscoveredReader, DiscoveredWriter, DurabilityKind,
     MockDdsInterface, ReceivedSample,
 };
 pub use hdds_interface::HddsDdsInterface;
+pub use position::{DurableReader, PositionStore, ReaderPosition};
 pub use publisher::{LateJoinerPublisher, PublisherStats, StandalonePublisher};
 pub use sqlite::SqliteStore;
 pub use store::{PersistenceStore, Sample};
@@ -100,14 +112,32 @@ impl<S: PersistenceStore + Send + Sync + 'static, D: DdsInterface + 'static>
             Arc::clone(&self.dds),
         );
 
-        let publisher = LateJoinerPublisher::new(
+        let mut publisher = LateJoinerPublisher::new(
             self.config.clone(),
             Arc::clone(&self.store),
             Arc::clone(&self.dds),
         );
 
-        // Run subscriber and publisher concurrently
-        tokio::try_join!(subscriber.run(), publisher.run(),)?;
+        let mut agent = None;
+        if let Some(coordinator) = Coordinator::from_config(&self.config, self.dds.guid()) {
+            let coordinator = Arc::new(coordinator);
+            tracing::info!(
+                "  Coordination: {:?} as {}",
+                coordinator.mode(),
+                coordinator.instance_id()
+            );
+            publisher = publisher.with_coordinator(Arc::clone(&coordinator));
+            agent = Some(CoordinationAgent::new(coordinator, Arc::clone(&self.dds)));
+        }
+        let coordination = async {
+            match agent {
+                Some(agent) => agent.run().await,
+                None => Ok(()),
+            }
+        };
+
+        // Run subscriber, publisher and coordination concurrently
+        tokio::try_join!(subscriber.run(), publisher.run(), coordination)?;
 
         Ok(())
     }
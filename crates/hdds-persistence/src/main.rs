@@ -16,14 +16,22 @@
 //!
 //! # Specify DDS domain
 //! hdds-persistence --domain 0 --topics "*"
+//!
+//! # RocksDB backend for sustained ingest (feature `rocksdb-backend`)
+//! hdds-persistence --backend rocksdb --db hdds_persist.rocksdb
+//!
+//! # Two replicas electing a replay leader
+//! hdds-persistence --db a.db --coordination leader --instance-id a
+//! hdds-persistence --db b.db --coordination leader --instance-id b
 //! ```
 
 use anyhow::Result;
 use clap::{Parser, Subcommand, ValueEnum};
 use hdds::{Participant, TransportMode};
 use hdds_persistence::{
-    Config, DurabilitySubscriber, HddsDdsInterface, LateJoinerPublisher, MockDdsInterface,
-    PersistenceService, SqliteStore,
+    Config, CoordinationAgent, CoordinationMode, Coordinator, DurabilitySubscriber,
+    HddsDdsInterface, LateJoinerPublisher, MockDdsInterface, PersistenceService, PersistenceStore,
+    SqliteStore,
 };
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -35,14 +43,42 @@ enum ServiceMode {
     Publisher,
 }
 
+#[derive(ValueEnum, Debug, Clone, Copy)]
+enum Backend {
+    Sqlite,
+    #[cfg(feature = "rocksdb-backend")]
+    Rocksdb,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+enum Coordination {
+    None,
+    Leader,
+    Partitioned,
+}
+
+impl From<Coordination> for CoordinationMode {
+    fn from(coordination: Coordination) -> Self {
+        match coordination {
+            Coordination::None => CoordinationMode::None,
+            Coordination::Leader => CoordinationMode::LeaderElection,
+            Coordination::Partitioned => CoordinationMode::Partitioned,
+        }
+    }
+}
+
 #[derive(Parser, Debug)]
 #[command(name = "hdds-persistence")]
 #[command(about = "HDDS Persistence Service - TRANSIENT/PERSISTENT durability", long_about = None)]
 struct Args {
-    /// Database path (SQLite file)
+    /// Database path (SQLite file or RocksDB directory)
     #[arg(short, long, default_value = "hdds_persist.db")]
     db: String,
 
+    /// Storage backend
+    #[arg(long, value_enum, default_value_t = Backend::Sqlite)]
+    backend: Backend,
+
     /// Topic filter (supports wildcards: "State/*", "*")
     #[arg(short, long, default_value = "*")]
     topics: String,
@@ -75,6 +111,18 @@ struct Args {
     #[arg(long, value_enum, default_value_t = ServiceMode::All)]
     mode: ServiceMode,
 
+    /// Replica coordination: none, leader, or partitioned
+    #[arg(long, value_enum, default_value_t = Coordination::None)]
+    coordination: Coordination,
+
+    /// Replica instance id (default: participant GUID)
+    #[arg(long, default_value = "")]
+    instance_id: String,
+
+    /// Replica lease duration in milliseconds
+    #[arg(long, default_value_t = 3000)]
+    lease_ms: u64,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -110,16 +158,30 @@ async fn main() -> Result<()> {
 
     let args = Args::parse();
 
-    // Create SQLite store
-    let store = SqliteStore::new(&args.db)?;
+    match args.backend {
+        Backend::Sqlite => {
+            let store = SqliteStore::new(&args.db)?;
+            run(args, store).await
+        }
+        #[cfg(feature = "rocksdb-backend")]
+        Backend::Rocksdb => {
+            let store = hdds_persistence::RocksDbStore::new(&args.db)?;
+            run(args, store).await
+        }
+    }
+}
 
+async fn run<S>(args: Args, store: S) -> Result<()>
+where
+    S: PersistenceStore + Send + Sync + 'static,
+{
     // Handle subcommands
     if let Some(cmd) = args.command {
         return handle_command(cmd, store).await;
     }
 
     tracing::info!("HDDS Persistence Service starting...");
-    tracing::info!("  Database: {}", args.db);
+    tracing::info!("  Database: {} ({:?})", args.db, args.backend);
     tracing::info!("  Topics: {}", args.topics);
     tracing::info!("  Retention: {} samples", args.retention_count);
     if args.retention_size > 0 {
@@ -135,6 +197,9 @@ async fn main() -> Result<()> {
         .retention_size_bytes(args.retention_size)
         .domain_id(args.domain)
         .participant_name(&args.name)
+        .coordination(args.coordination.into())
+        .instance_id(&args.instance_id)
+        .lease_duration_ms(args.lease_ms)
         .build();
 
     if args.mock {
@@ -157,8 +222,9 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
-async fn run_service<D>(mode: ServiceMode, config: Config, store: SqliteStore, dds: D) -> Result<()>
+async fn run_service<S, D>(mode: ServiceMode, config: Config, store: S, dds: D) -> Result<()>
 where
+    S: PersistenceStore + Send + Sync + 'static,
     D: hdds_persistence::DdsInterface + 'static,
 {
     match mode {
@@ -175,15 +241,22 @@ where
         ServiceMode::Publisher => {
             let store = Arc::new(RwLock::new(store));
             let dds = Arc::new(dds);
-            let publisher = LateJoinerPublisher::new(config, store, dds);
-            publisher.run().await
+            let coordinator = Coordinator::from_config(&config, dds.guid()).map(Arc::new);
+            let publisher = LateJoinerPublisher::new(config, store, Arc::clone(&dds));
+            match coordinator {
+                Some(coordinator) => {
+                    let publisher = publisher.with_coordinator(Arc::clone(&coordinator));
+                    let agent = CoordinationAgent::new(coordinator, dds);
+                    tokio::try_join!(publisher.run(), agent.run())?;
+                    Ok(())
+                }
+                None => publisher.run().await,
+            }
         }
     }
 }
 
-async fn handle_command(cmd: Commands, store: SqliteStore) -> Result<()> {
-    use hdds_persistence::PersistenceStore;
-
+async fn handle_command(cmd: Commands, store: impl PersistenceStore) -> Result<()> {
     match cmd {
         Commands::Replay { topic } => {
             let samples = store.load(&topic)?;
@@ -0,0 +1,350 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Durable reader positions
+//!
+//! Stores the last consumed sequence number of a named consumer so that an
+//! event-processing service can resume where it left off after a restart.
+//!
+//! # Semantics
+//!
+//! Positions are tracked per `(consumer, topic, source)`, where `source` is
+//! the writer GUID that owns the sequence number space. A position only
+//! moves forward once the application calls [`DurableReader::commit`] after
+//! it has finished processing a sample, which gives at-least-once delivery:
+//! a crash between processing and commit replays the sample on restart,
+//! while committed samples are never handed out again.
+//!
+//! The position is a high-water mark, so samples of a writer must be
+//! committed in sequence order: committing a sample while an earlier one
+//! handed out by `take` or `replay` is still uncommitted is rejected, as it
+//! would silently mark the earlier one consumed.
+//!
+//! Positions are not kept per instance: RTPS sequence numbers count every
+//! sample of a writer, whatever its instance, so one position per writer
+//! covers all the instances it publishes. Payloads are opaque here, so no
+//! instance key is available to split them further.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let store = Arc::new(SqliteStore::new("consumer.db")?);
+//! let reader = dds.create_reader("Orders/New", "Order", DurabilityKind::Persistent)?;
+//! let mut durable = DurableReader::resume("billing", reader, Arc::clone(&store))?;
+//!
+//! // Catch up on samples persisted while we were down.
+//! for sample in durable.replay(&*history)? {
+//!     process(&sample)?;
+//!     durable.commit(&sample)?;
+//! }
+//!
+//! loop {
+//!     for sample in durable.take()? {
+//!         process(&sample)?;
+//!         durable.commit(&sample)?;
+//!     }
+//! }
+//! ```
+
+use crate::dds_interface::{DataReader, ReceivedSample};
+use crate::store::PersistenceStore;
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, Mutex};
+
+/// Last consumed sequence number of a consumer for one topic source
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReaderPosition {
+    /// Consumer name (stable across restarts)
+    pub consumer: String,
+
+    /// Topic name
+    pub topic: String,
+
+    /// Writer GUID owning the sequence number space
+    pub source_guid: [u8; 16],
+
+    /// Last committed sequence number
+    pub sequence: u64,
+}
+
+/// Storage for durable reader positions
+///
+/// Implemented by persistence backends next to [`PersistenceStore`].
+pub trait PositionStore {
+    /// Persist a position
+    ///
+    /// Positions are monotonic: saving a sequence lower than or equal to the
+    /// stored one is a no-op.
+    fn save_position(&self, position: &ReaderPosition) -> Result<()>;
+
+    /// Load all positions of a consumer for a topic
+    fn load_positions(&self, consumer: &str, topic: &str) -> Result<Vec<ReaderPosition>>;
+
+    /// Forget all positions of a consumer for a topic (rewind to the beginning)
+    fn clear_positions(&self, consumer: &str, topic: &str) -> Result<()>;
+}
+
+/// Data reader that remembers what it has consumed
+///
+/// Wraps a [`DataReader`] and filters out samples at or below the committed
+/// position of their source writer.
+pub struct DurableReader<S: PositionStore> {
+    consumer: String,
+    reader: Box<dyn DataReader>,
+    store: Arc<S>,
+    /// Committed sequence per source writer GUID
+    positions: HashMap<[u8; 16], u64>,
+    /// Sequences handed out by `take`/`replay` but not committed yet
+    delivered: Mutex<HashMap<[u8; 16], BTreeSet<u64>>>,
+}
+
+impl<S: PositionStore> DurableReader<S> {
+    /// Wrap `reader` and restore the positions stored for `consumer`
+    pub fn resume(consumer: &str, reader: Box<dyn DataReader>, store: Arc<S>) -> Result<Self> {
+        let positions = store
+            .load_positions(consumer, reader.topic())?
+            .into_iter()
+            .map(|p| (p.source_guid, p.sequence))
+            .collect::<HashMap<_, _>>();
+
+        tracing::debug!(
+            "Resuming consumer '{}' on {} from {} stored position(s)",
+            consumer,
+            reader.topic(),
+            positions.len()
+        );
+
+        Ok(Self {
+            consumer: consumer.to_string(),
+            reader,
+            store,
+            positions,
+            delivered: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Consumer name
+    pub fn consumer(&self) -> &str {
+        &self.consumer
+    }
+
+    /// Topic name
+    pub fn topic(&self) -> &str {
+        self.reader.topic()
+    }
+
+    /// Committed sequence for a source writer, if any
+    pub fn position(&self, source_guid: &[u8; 16]) -> Option<u64> {
+        self.positions.get(source_guid).copied()
+    }
+
+    /// Take samples that have not been committed yet
+    ///
+    /// Taking does not advance the position; call [`commit`](Self::commit)
+    /// once a sample has been processed.
+    pub fn take(&self) -> Result<Vec<ReceivedSample>> {
+        let samples = self.reader.take()?;
+        let pending: Vec<_> = samples
+            .into_iter()
+            .filter(|s| self.is_pending(&s.writer_guid, s.sequence))
+            .collect();
+        self.deliver(pending.iter().map(|s| (s.writer_guid, s.sequence)));
+        Ok(pending)
+    }
+
+    /// Samples persisted in `history` that this consumer has not committed
+    ///
+    /// Used after a restart to catch up on samples published while the
+    /// consumer was down. Samples are returned in storage order.
+    pub fn replay<P: PersistenceStore>(&self, history: &P) -> Result<Vec<ReceivedSample>> {
+        let stored = history.load(self.reader.topic())?;
+        let pending: Vec<_> = stored
+            .into_iter()
+            .filter(|s| self.is_pending(&s.source_guid, s.sequence))
+            .map(|s| ReceivedSample {
+                topic: s.topic,
+                type_name: s.type_name,
+                payload: s.payload,
+                writer_guid: s.source_guid,
+                sequence: s.sequence,
+                timestamp_ns: s.timestamp_ns,
+            })
+            .collect();
+        self.deliver(pending.iter().map(|s| (s.writer_guid, s.sequence)));
+        Ok(pending)
+    }
+
+    /// Mark a sample as consumed and persist the new position
+    ///
+    /// Samples of a writer must be committed in sequence order: the
+    /// position only records the highest committed sequence, so committing
+    /// past a sample handed out earlier and not committed yet would lose it.
+    /// Such a commit fails and leaves the position unchanged.
+    pub fn commit(&mut self, sample: &ReceivedSample) -> Result<()> {
+        if !self.is_pending(&sample.writer_guid, sample.sequence) {
+            return Ok(());
+        }
+
+        let delivered = self.delivered.get_mut().unwrap();
+        if let Some(earlier) = delivered
+            .get(&sample.writer_guid)
+            .and_then(|seqs| seqs.first().copied())
+            .filter(|&earlier| earlier < sample.sequence)
+        {
+            bail!(
+                "commit of sequence {} on {} skips uncommitted sequence {} of the same writer",
+                sample.sequence,
+                self.reader.topic(),
+                earlier
+            );
+        }
+
+        self.store.save_position(&ReaderPosition {
+            consumer: self.consumer.clone(),
+            topic: self.reader.topic().to_string(),
+            source_guid: sample.writer_guid,
+            sequence: sample.sequence,
+        })?;
+        self.positions.insert(sample.writer_guid, sample.sequence);
+        if let Some(seqs) = self
+            .delivered
+            .get_mut()
+            .unwrap()
+            .get_mut(&sample.writer_guid)
+        {
+            seqs.retain(|&seq| seq > sample.sequence);
+        }
+        Ok(())
+    }
+
+    /// Forget all committed positions and start over from the beginning
+    pub fn reset(&mut self) -> Result<()> {
+        self.store
+            .clear_positions(&self.consumer, self.reader.topic())?;
+        self.positions.clear();
+        self.delivered.get_mut().unwrap().clear();
+        Ok(())
+    }
+
+    fn deliver(&self, samples: impl Iterator<Item = ([u8; 16], u64)>) {
+        let mut delivered = self.delivered.lock().unwrap();
+        for (source_guid, sequence) in samples {
+            delivered.entry(source_guid).or_default().insert(sequence);
+        }
+    }
+
+    fn is_pending(&self, source_guid: &[u8; 16], sequence: u64) -> bool {
+        self.positions
+            .get(source_guid)
+            .is_none_or(|&committed| sequence > committed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dds_interface::{DdsInterface, DurabilityKind, MockDdsInterface};
+    use crate::sqlite::SqliteStore;
+    use crate::store::Sample;
+
+    fn sample(writer: u8, sequence: u64) -> ReceivedSample {
+        ReceivedSample {
+            topic: "Orders/New".to_string(),
+            type_name: "Order".to_string(),
+            payload: vec![sequence as u8],
+            writer_guid: [writer; 16],
+            sequence,
+            timestamp_ns: sequence * 1000,
+        }
+    }
+
+    fn durable(mock: &MockDdsInterface, store: &Arc<SqliteStore>) -> DurableReader<SqliteStore> {
+        let reader = mock
+            .create_reader("Orders/New", "Order", DurabilityKind::Persistent)
+            .unwrap();
+        DurableReader::resume("billing", reader, Arc::clone(store)).unwrap()
+    }
+
+    #[test]
+    fn test_resume_skips_committed_samples() {
+        let mock = MockDdsInterface::new();
+        let store = Arc::new(SqliteStore::new_in_memory().unwrap());
+
+        mock.add_sample(sample(0xAA, 1));
+        mock.add_sample(sample(0xAA, 2));
+        let mut reader = durable(&mock, &store);
+        let taken = reader.take().unwrap();
+        assert_eq!(taken.len(), 2);
+        // Only the first one is processed before the "crash".
+        reader.commit(&taken[0]).unwrap();
+        drop(reader);
+
+        // The writer redelivers everything (e.g. durability replay).
+        mock.add_sample(sample(0xBB, 1));
+        let reader = durable(&mock, &store);
+        assert_eq!(reader.position(&[0xAA; 16]), Some(1));
+
+        let taken = reader.take().unwrap();
+        let seqs: Vec<_> = taken
+            .iter()
+            .map(|s| (s.writer_guid[0], s.sequence))
+            .collect();
+        assert_eq!(seqs, vec![(0xAA, 2), (0xBB, 1)]);
+    }
+
+    #[test]
+    fn test_commit_rejects_skipping_pending_samples() {
+        let mock = MockDdsInterface::new();
+        let store = Arc::new(SqliteStore::new_in_memory().unwrap());
+
+        for seq in 1..=3 {
+            mock.add_sample(sample(0xAA, seq));
+        }
+        mock.add_sample(sample(0xBB, 7));
+        let mut reader = durable(&mock, &store);
+        let taken = reader.take().unwrap();
+        assert_eq!(taken.len(), 4);
+
+        // Committing 2 before 1 would mark 1 consumed without processing it
+        assert!(reader.commit(&taken[1]).is_err());
+        assert_eq!(reader.position(&[0xAA; 16]), None);
+        // Other writers have their own sequence space
+        reader.commit(&taken[3]).unwrap();
+
+        reader.commit(&taken[0]).unwrap();
+        reader.commit(&taken[1]).unwrap();
+        assert_eq!(reader.position(&[0xAA; 16]), Some(2));
+        drop(reader);
+
+        let reader = durable(&mock, &store);
+        let seqs: Vec<_> = reader.take().unwrap().iter().map(|s| s.sequence).collect();
+        assert_eq!(seqs, vec![3]);
+    }
+
+    #[test]
+    fn test_replay_from_history_and_reset() {
+        let mock = MockDdsInterface::new();
+        let store = Arc::new(SqliteStore::new_in_memory().unwrap());
+
+        for seq in 1..=4 {
+            store.save(&Sample::from(sample(0xAA, seq))).unwrap();
+        }
+
+        let mut reader = durable(&mock, &store);
+        for s in reader.replay(&*store).unwrap().iter().take(3) {
+            reader.commit(s).unwrap();
+        }
+
+        let reader = durable(&mock, &store);
+        let pending = reader.replay(&*store).unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].sequence, 4);
+
+        let mut reader = reader;
+        reader.reset().unwrap();
+        assert_eq!(reader.position(&[0xAA; 16]), None);
+        assert_eq!(reader.replay(&*store).unwrap().len(), 4);
+    }
+}
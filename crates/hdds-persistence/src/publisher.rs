@@ -8,10 +8,20 @@
 //! # Operation
 //!
 //! 1. Monitor for new DataReaders via discovery
-//! 2. When a TRANSIENT_LOCAL reader joins, query store for historical samples
-//! 3. Replay historical samples to the new reader via DataWriter
+//! 2. When a PERSISTENT reader joins, query store for historical samples
+//! 3. Replay historical samples to the new reader via DataWriter, in source
+//!    timestamp order, on behalf of their original writers
+//!
+//! Replayed samples carry the writer and sequence number they were first
+//! published with, so the reader drops those it also receives from a
+//! durable writer still alive (or from another replica).
+//!
+//! With a [`Coordinator`], readers of topics owned by another replica are
+//! deferred for two lease periods and replayed here only if this replica
+//! takes the topic over in the meantime (failover).
 
 use crate::config::Config;
+use crate::coordination::Coordinator;
 use crate::dds_interface::{
     DataWriter, DdsInterface, DiscoveredReader, DiscoveredWriter, DiscoveryCallback, DurabilityKind,
 };
@@ -19,8 +29,10 @@ use crate::store::{PersistenceStore, Sample};
 use anyhow::Result;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 use tokio::sync::RwLock;
+use tokio::time::interval;
 
 /// Late-joiner publisher
 ///
@@ -33,6 +45,10 @@ pub struct LateJoinerPublisher<S: PersistenceStore, D: DdsInterface> {
     replayed_readers: HashSet<[u8; 16]>,
     /// Writers we've created (by topic)
     writers: HashMap<String, WriterState>,
+    /// Replica coordination (None = replay everything)
+    coordinator: Option<Arc<Coordinator>>,
+    /// Readers of topics owned by another replica, with their discovery time
+    deferred_readers: HashMap<[u8; 16], (DiscoveredReader, Instant)>,
     /// Statistics
     stats: PublisherStats,
 }
@@ -55,6 +71,8 @@ pub struct PublisherStats {
     pub samples_replayed: u64,
     /// Replay errors
     pub replay_errors: u64,
+    /// Readers left to the replica owning their topic
+    pub readers_deferred: u64,
 }
 
 #[allow(dead_code)]
@@ -94,10 +112,18 @@ impl<S: PersistenceStore + Send + Sync, D: DdsInterface> LateJoinerPublisher<S,
             dds,
             replayed_readers: HashSet::new(),
             writers: HashMap::new(),
+            coordinator: None,
+            deferred_readers: HashMap::new(),
             stats: PublisherStats::default(),
         }
     }
 
+    /// Only replay the topics `coordinator` assigns to this replica
+    pub fn with_coordinator(mut self, coordinator: Arc<Coordinator>) -> Self {
+        self.coordinator = Some(coordinator);
+        self
+    }
+
     /// Get publisher statistics
     pub fn stats(&self) -> &PublisherStats {
         &self.stats
@@ -117,6 +143,13 @@ impl<S: PersistenceStore + Send + Sync, D: DdsInterface> LateJoinerPublisher<S,
         // Initial snapshot
         self.discover_and_replay().await?;
 
+        let takeover_period = self
+            .coordinator
+            .as_ref()
+            .map_or(Duration::from_secs(1), |c| c.lease_duration() / 3)
+            .max(Duration::from_millis(10));
+        let mut takeover_interval = interval(takeover_period);
+
         loop {
             tokio::select! {
                 Some(event) = event_rx.recv() => {
@@ -126,6 +159,9 @@ impl<S: PersistenceStore + Send + Sync, D: DdsInterface> LateJoinerPublisher<S,
                         }
                     }
                 }
+                _ = takeover_interval.tick() => {
+                    self.replay_taken_over(Instant::now()).await;
+                }
             }
         }
     }
@@ -149,14 +185,61 @@ impl<S: PersistenceStore + Send + Sync, D: DdsInterface> LateJoinerPublisher<S,
 
         self.stats.readers_discovered += 1;
 
-        if !self.should_replay(&reader)? {
+        if !self.should_replay(&reader) {
             self.replayed_readers.insert(reader.guid);
             return Ok(());
         }
 
+        if let Some(ref coordinator) = self.coordinator {
+            if !coordinator.owns_topic(&reader.topic, Instant::now()) {
+                tracing::debug!(
+                    "Deferring reader {} for topic {} (owned by another replica)",
+                    hex(&reader.guid[0..4]),
+                    reader.topic
+                );
+                self.stats.readers_deferred += 1;
+                self.deferred_readers
+                    .insert(reader.guid, (reader, Instant::now()));
+                return Ok(());
+            }
+        }
+
         tracing::info!("New durable reader discovered for topic: {}", reader.topic);
+        self.replay_and_record(reader).await;
+        Ok(())
+    }
+
+    /// Replay to the deferred readers whose topic this replica took over
+    ///
+    /// Readers deferred for more than two lease periods are dropped: the
+    /// owner at the time had that long to serve them.
+    async fn replay_taken_over(&mut self, now: Instant) {
+        let Some(coordinator) = self.coordinator.clone() else {
+            return;
+        };
+        let window = coordinator.lease_duration() * 2;
+        self.deferred_readers
+            .retain(|_, (_, deferred_at)| now.duration_since(*deferred_at) <= window);
+
+        let taken_over: Vec<[u8; 16]> = self
+            .deferred_readers
+            .iter()
+            .filter(|(_, (reader, _))| coordinator.owns_topic(&reader.topic, now))
+            .map(|(guid, _)| *guid)
+            .collect();
+        for guid in taken_over {
+            if let Some((reader, _)) = self.deferred_readers.remove(&guid) {
+                tracing::info!(
+                    "Took over topic {}, replaying to deferred reader {}",
+                    reader.topic,
+                    hex(&reader.guid[0..4])
+                );
+                self.replay_and_record(reader).await;
+            }
+        }
+    }
 
-        // Replay history
+    async fn replay_and_record(&mut self, reader: DiscoveredReader) {
         match self.replay_to_reader(&reader).await {
             Ok(count) => {
                 self.stats.readers_replayed += 1;
@@ -179,10 +262,9 @@ impl<S: PersistenceStore + Send + Sync, D: DdsInterface> LateJoinerPublisher<S,
         }
 
         self.replayed_readers.insert(reader.guid);
-        Ok(())
     }
 
-    fn should_replay(&self, reader: &DiscoveredReader) -> Result<bool> {
+    fn should_replay(&self, reader: &DiscoveredReader) -> bool {
         match reader.durability {
             DurabilityKind::Volatile => {
                 tracing::debug!(
@@ -190,7 +272,7 @@ impl<S: PersistenceStore + Send + Sync, D: DdsInterface> LateJoinerPublisher<S,
                     hex(&reader.guid[0..4]),
                     reader.topic
                 );
-                return Ok(false);
+                false
             }
             DurabilityKind::TransientLocal => {
                 tracing::debug!(
@@ -198,25 +280,12 @@ impl<S: PersistenceStore + Send + Sync, D: DdsInterface> LateJoinerPublisher<S,
                     hex(&reader.guid[0..4]),
                     reader.topic
                 );
-                return Ok(false);
+                false
             }
-            DurabilityKind::Persistent => {}
-        }
-
-        let writers = self.dds.discovered_writers(&reader.topic)?;
-        let has_viable_writer = writers
-            .iter()
-            .any(|writer| writer.durability.rank() >= reader.durability.rank());
-
-        if has_viable_writer {
-            tracing::debug!(
-                "Skipping persistence replay for topic {} (durable writer present)",
-                reader.topic
-            );
-            return Ok(false);
+            // Replayed even if a durable writer is alive: the reader drops
+            // the samples it receives twice
+            DurabilityKind::Persistent => true,
         }
-
-        Ok(true)
     }
 
     /// Replay historical samples to a specific reader
@@ -239,7 +308,7 @@ impl<S: PersistenceStore + Send + Sync, D: DdsInterface> LateJoinerPublisher<S,
         // Replay samples in order
         let mut replayed = 0;
         for sample in &samples {
-            match writer.write_with_timestamp(&sample.payload, sample.timestamp_ns) {
+            match writer.write_replay(sample) {
                 Ok(()) => {
                     replayed += 1;
                     tracing::trace!(
@@ -309,10 +378,7 @@ impl<S: PersistenceStore + Send + Sync, D: DdsInterface> LateJoinerPublisher<S,
 
         let mut replayed = 0;
         for sample in &samples {
-            if writer
-                .write_with_timestamp(&sample.payload, sample.timestamp_ns)
-                .is_ok()
-            {
+            if writer.write_replay(sample).is_ok() {
                 replayed += 1;
             }
         }
@@ -492,6 +558,36 @@ mod tests {
         assert_eq!(publisher.stats.samples_replayed, 5);
     }
 
+    #[tokio::test]
+    async fn test_replay_despite_durable_writer() {
+        use crate::dds_interface::DiscoveredWriter;
+
+        let config = Config::builder().topic_filter("State/*").build();
+        let dds = Arc::new(MockDdsInterface::new());
+        dds.add_writer(DiscoveredWriter {
+            guid: [0xAA; 16],
+            topic: "State/Temperature".to_string(),
+            type_name: "Temperature".to_string(),
+            durability: DurabilityKind::Persistent,
+            retention_hint: None,
+        });
+        dds.add_reader(DiscoveredReader {
+            guid: [0x01; 16],
+            topic: "State/Temperature".to_string(),
+            type_name: "Temperature".to_string(),
+            durability: DurabilityKind::Persistent,
+        });
+
+        let mut publisher =
+            LateJoinerPublisher::new(config, seeded_store("State/Temperature", 3), dds);
+        publisher.discover_and_replay().await.unwrap();
+
+        // The writer's history may be shorter than the store's: replay,
+        // the reader drops what it gets twice
+        assert_eq!(publisher.stats.readers_replayed, 1);
+        assert_eq!(publisher.stats.samples_replayed, 3);
+    }
+
     #[tokio::test]
     async fn test_skip_volatile_readers() {
         let config = Config::builder().topic_filter("*").build();
@@ -585,6 +681,112 @@ mod tests {
         assert_eq!(count, 4); // Samples at 2000, 3000, 4000, 5000
     }
 
+    fn seeded_store(topic: &str, count: u64) -> Arc<RwLock<SqliteStore>> {
+        let store = SqliteStore::new_in_memory().unwrap();
+        for i in 0..count {
+            store
+                .save(&Sample {
+                    topic: topic.to_string(),
+                    type_name: "Temperature".to_string(),
+                    payload: vec![i as u8],
+                    timestamp_ns: i * 1000,
+                    sequence: i,
+                    source_guid: [0xAA; 16],
+                })
+                .unwrap();
+        }
+        Arc::new(RwLock::new(store))
+    }
+
+    #[tokio::test]
+    async fn test_follower_defers_then_takes_over() {
+        use crate::coordination::{CoordinationMode, Coordinator, Lease};
+        use std::time::{Duration, Instant};
+
+        let lease = Duration::from_millis(100);
+        let coordinator = Arc::new(Coordinator::new(
+            "b",
+            CoordinationMode::LeaderElection,
+            lease,
+        ));
+        let start = Instant::now();
+        coordinator.observe(
+            &Lease {
+                instance_id: "a".to_string(),
+                duration: lease,
+            },
+            start,
+        );
+
+        let dds = Arc::new(MockDdsInterface::new());
+        dds.add_reader(DiscoveredReader {
+            guid: [0x01; 16],
+            topic: "State/Temperature".to_string(),
+            type_name: "Temperature".to_string(),
+            durability: DurabilityKind::Persistent,
+        });
+
+        let config = Config::builder().topic_filter("State/*").build();
+        let mut publisher =
+            LateJoinerPublisher::new(config, seeded_store("State/Temperature", 3), dds)
+                .with_coordinator(Arc::clone(&coordinator));
+
+        // "a" leads: the follower leaves the reader to it
+        publisher.discover_and_replay().await.unwrap();
+        assert_eq!(publisher.stats.readers_deferred, 1);
+        assert_eq!(publisher.stats.readers_replayed, 0);
+        publisher.replay_taken_over(start).await;
+        assert_eq!(publisher.stats.readers_replayed, 0);
+
+        // "a" stops renewing its lease: the follower takes over the reader
+        publisher
+            .replay_taken_over(start + lease + Duration::from_millis(10))
+            .await;
+        assert_eq!(publisher.stats.readers_replayed, 1);
+        assert_eq!(publisher.stats.samples_replayed, 3);
+        assert!(publisher.deferred_readers.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_deferred_readers_expire() {
+        use crate::coordination::{CoordinationMode, Coordinator, Lease};
+        use std::time::{Duration, Instant};
+
+        let lease = Duration::from_millis(100);
+        let coordinator = Arc::new(Coordinator::new(
+            "b",
+            CoordinationMode::LeaderElection,
+            lease,
+        ));
+        let start = Instant::now();
+        coordinator.observe(
+            &Lease {
+                instance_id: "a".to_string(),
+                duration: lease,
+            },
+            start,
+        );
+
+        let dds = Arc::new(MockDdsInterface::new());
+        dds.add_reader(DiscoveredReader {
+            guid: [0x02; 16],
+            topic: "State/Temperature".to_string(),
+            type_name: "Temperature".to_string(),
+            durability: DurabilityKind::Persistent,
+        });
+
+        let config = Config::builder().topic_filter("State/*").build();
+        let mut publisher =
+            LateJoinerPublisher::new(config, seeded_store("State/Temperature", 3), dds)
+                .with_coordinator(coordinator);
+        publisher.discover_and_replay().await.unwrap();
+
+        // The leader had two lease periods to serve the reader
+        publisher.replay_taken_over(start + lease * 3).await;
+        assert_eq!(publisher.stats.readers_replayed, 0);
+        assert!(publisher.deferred_readers.is_empty());
+    }
+
     #[test]
     fn test_hex() {
         assert_eq!(hex(&[0xde, 0xad, 0xbe, 0xef]), "deadbeef");
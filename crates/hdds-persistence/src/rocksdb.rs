@@ -0,0 +1,707 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! RocksDB persistence backend
+//!
+//! High-throughput storage for sustained ingest (feature `rocksdb-backend`).
+//! Each topic lives in its own column family, so retention and compaction
+//! of one topic never touch the others.
+
+use crate::dds_interface::topic_matches;
+use crate::position::{PositionStore, ReaderPosition};
+use crate::store::{PersistenceStore, RetentionPolicy, Sample};
+use ::rocksdb::{
+    BlockBasedOptions, ColumnFamilyDescriptor, DBWithThreadMode, Direction, IteratorMode,
+    MultiThreaded, Options, WriteBatch, DEFAULT_COLUMN_FAMILY_NAME,
+};
+use anyhow::{anyhow, Context, Result};
+use std::collections::BTreeSet;
+use std::sync::{mpsc, Arc, Mutex, RwLock};
+use std::thread::JoinHandle;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+type Db = DBWithThreadMode<MultiThreaded>;
+
+/// Column family name prefix of topics
+const TOPIC_CF_PREFIX: &str = "topic:";
+/// Column family of durable reader positions
+const POSITIONS_CF: &str = "positions";
+/// Default column family key of the next sample id
+const NEXT_ID_KEY: &[u8] = b"next_id";
+
+/// Sample key tag: tag, timestamp_ns, source_guid, sequence, id
+const SAMPLE_TAG: u8 = b'S';
+const SAMPLE_KEY_LEN: usize = 1 + 8 + 16 + 8 + 8;
+/// Origin index key tag: tag, source_guid, sequence
+const ORIGIN_TAG: u8 = b'O';
+const ORIGIN_KEY_LEN: usize = 1 + 16 + 8;
+
+/// RocksDB persistence store
+///
+/// Stores DDS samples in one column family per topic, keyed so that they
+/// iterate in source timestamp order. An origin index skips samples already
+/// stored under the same original writer and sequence number.
+///
+/// Retention deletes leave tombstones behind, which slow down the scans of
+/// replays; a background thread compacts the topics retention deleted from.
+///
+/// # Layout
+///
+/// ```text
+/// default        next_id -> id (u64 BE)
+/// topic:<topic>  'S' timestamp_ns (BE) source_guid sequence (BE) id (BE)
+///                    -> type_name length (u32 LE) type_name payload
+///                'O' source_guid sequence (BE) -> sample key
+/// positions      consumer length (u32 LE) consumer topic length (u32 LE) topic source_guid
+///                    -> sequence (BE)
+/// ```
+///
+/// The id tells apart samples of unknown writers (zero GUID) sharing a
+/// timestamp and sequence number; they are not indexed.
+pub struct RocksDbStore {
+    db: Arc<Db>,
+    /// Topics with a column family
+    topics: RwLock<BTreeSet<String>>,
+    /// Next sample id; the lock serializes writes, keeping the origin
+    /// index consistent with the samples
+    next_id: Mutex<u64>,
+    /// Topics to compact (None once dropping)
+    compaction_tx: Option<mpsc::Sender<String>>,
+    compaction_thread: Option<JoinHandle<()>>,
+}
+
+impl RocksDbStore {
+    /// Open or create a RocksDB store in the directory `path`
+    pub fn new(path: &str) -> Result<Self> {
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.create_missing_column_families(true);
+        let parallelism = std::thread::available_parallelism().map_or(2, |n| n.get());
+        opts.increase_parallelism(parallelism as i32);
+        opts.set_max_background_jobs(4);
+        opts.set_level_compaction_dynamic_level_bytes(true);
+
+        // A new database has no column family to list
+        let mut names = Db::list_cf(&opts, path).unwrap_or_default();
+        for required in [DEFAULT_COLUMN_FAMILY_NAME, POSITIONS_CF] {
+            if !names.iter().any(|name| name == required) {
+                names.push(required.to_string());
+            }
+        }
+        let topics = names
+            .iter()
+            .filter_map(|name| name.strip_prefix(TOPIC_CF_PREFIX))
+            .map(str::to_string)
+            .collect();
+        let descriptors = names.into_iter().map(|name| {
+            let opts = if name.starts_with(TOPIC_CF_PREFIX) {
+                topic_cf_options()
+            } else {
+                Options::default()
+            };
+            ColumnFamilyDescriptor::new(name, opts)
+        });
+        let db = Db::open_cf_descriptors(&opts, path, descriptors)
+            .with_context(|| format!("Failed to open RocksDB database at {}", path))?;
+
+        let next_id = match db.get(NEXT_ID_KEY)? {
+            Some(bytes) => be_u64(&bytes)?,
+            None => 0,
+        };
+
+        let db = Arc::new(db);
+        let (compaction_tx, compaction_rx) = mpsc::channel();
+        let compaction_db = Arc::clone(&db);
+        let compaction_thread = std::thread::Builder::new()
+            .name("hdds-persist-compact".to_string())
+            .spawn(move || compaction_loop(&compaction_db, &compaction_rx))
+            .context("Failed to spawn RocksDB compaction thread")?;
+
+        Ok(Self {
+            db,
+            topics: RwLock::new(topics),
+            next_id: Mutex::new(next_id),
+            compaction_tx: Some(compaction_tx),
+            compaction_thread: Some(compaction_thread),
+        })
+    }
+
+    /// Topics with a column family
+    fn topics(&self) -> Vec<String> {
+        let topics = match self.topics.read() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        topics.iter().cloned().collect()
+    }
+
+    /// Samples of `topic` with a timestamp in `[start_ns, end_ns]`, in order
+    fn scan(&self, topic: &str, start_ns: u64, end_ns: u64) -> Result<Vec<Sample>> {
+        let Some(cf) = self.db.cf_handle(&topic_cf_name(topic)) else {
+            return Ok(Vec::new());
+        };
+
+        let mut from = [SAMPLE_TAG; 9];
+        from[1..].copy_from_slice(&start_ns.to_be_bytes());
+
+        let mut samples = Vec::new();
+        for item in self
+            .db
+            .iterator_cf(&cf, IteratorMode::From(&from, Direction::Forward))
+        {
+            let (key, value) = item?;
+            let Some(timestamp_ns) = sample_timestamp(&key) else {
+                break;
+            };
+            if timestamp_ns > end_ns {
+                break;
+            }
+            samples.push(decode_sample(topic, &key, &value)?);
+        }
+        Ok(samples)
+    }
+
+    /// Delete the oldest samples of `topic` beyond any of the limits
+    fn retain(
+        &self,
+        topic: &str,
+        keep_count: Option<usize>,
+        min_timestamp_ns: Option<u64>,
+        max_bytes: Option<u64>,
+    ) -> Result<()> {
+        let Some(cf) = self.db.cf_handle(&topic_cf_name(topic)) else {
+            return Ok(());
+        };
+        let _writing = self.next_id.lock().unwrap();
+
+        // Newest first: keep samples while every limit holds, then delete
+        // everything older
+        let mut kept = 0usize;
+        let mut kept_bytes = 0u64;
+        let mut evicting = false;
+        let mut batch = WriteBatch::default();
+        let mut deleted = 0usize;
+        for item in self.db.iterator_cf(
+            &cf,
+            IteratorMode::From(&[SAMPLE_TAG + 1], Direction::Reverse),
+        ) {
+            let (key, value) = item?;
+            let Some(timestamp_ns) = sample_timestamp(&key) else {
+                break;
+            };
+            if !evicting {
+                let len = payload_len(&value)? as u64;
+                evicting = keep_count.is_some_and(|keep| kept >= keep)
+                    || min_timestamp_ns.is_some_and(|min| timestamp_ns < min)
+                    || max_bytes.is_some_and(|max| kept_bytes.saturating_add(len) > max);
+                if !evicting {
+                    kept += 1;
+                    kept_bytes = kept_bytes.saturating_add(len);
+                    continue;
+                }
+            }
+            batch.delete_cf(&cf, &key);
+            let (source_guid, sequence) = sample_origin(&key);
+            if source_guid != [0u8; 16] {
+                batch.delete_cf(&cf, origin_key(&source_guid, sequence));
+            }
+            deleted += 1;
+        }
+
+        if deleted > 0 {
+            self.db.write(batch)?;
+            tracing::debug!("Retention deleted {} samples from {}", deleted, topic);
+            self.request_compaction(topic);
+        }
+        Ok(())
+    }
+
+    /// Have the background thread compact `topic`
+    fn request_compaction(&self, topic: &str) {
+        if let Some(tx) = &self.compaction_tx {
+            let _ = tx.send(topic.to_string());
+        }
+    }
+
+    fn positions_cf(&self) -> Result<Arc<::rocksdb::BoundColumnFamily<'_>>> {
+        self.db
+            .cf_handle(POSITIONS_CF)
+            .ok_or_else(|| anyhow!("Missing column family {}", POSITIONS_CF))
+    }
+}
+
+impl Drop for RocksDbStore {
+    fn drop(&mut self) {
+        // Closing the channel stops the thread once it is done
+        self.compaction_tx = None;
+        if let Some(thread) = self.compaction_thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl PersistenceStore for RocksDbStore {
+    fn save(&self, sample: &Sample) -> Result<()> {
+        let mut next_id = self.next_id.lock().unwrap();
+
+        let name = topic_cf_name(&sample.topic);
+        let cf = match self.db.cf_handle(&name) {
+            Some(cf) => cf,
+            None => {
+                self.db.create_cf(&name, &topic_cf_options())?;
+                let mut topics = match self.topics.write() {
+                    Ok(guard) => guard,
+                    Err(poisoned) => poisoned.into_inner(),
+                };
+                topics.insert(sample.topic.clone());
+                self.db
+                    .cf_handle(&name)
+                    .ok_or_else(|| anyhow!("Missing column family {}", name))?
+            }
+        };
+
+        let known_writer = sample.source_guid != [0u8; 16];
+        let origin = origin_key(&sample.source_guid, sample.sequence);
+        if known_writer && self.db.get_cf(&cf, origin)?.is_some() {
+            return Ok(());
+        }
+
+        let key = sample_key(sample, *next_id);
+        let mut value = Vec::with_capacity(4 + sample.type_name.len() + sample.payload.len());
+        value.extend_from_slice(&(sample.type_name.len() as u32).to_le_bytes());
+        value.extend_from_slice(sample.type_name.as_bytes());
+        value.extend_from_slice(&sample.payload);
+
+        let mut batch = WriteBatch::default();
+        batch.put_cf(&cf, key, value);
+        if known_writer {
+            batch.put_cf(&cf, origin, key);
+        }
+        batch.put(NEXT_ID_KEY, (*next_id + 1).to_be_bytes());
+        self.db.write(batch)?;
+        *next_id += 1;
+
+        Ok(())
+    }
+
+    fn load(&self, topic: &str) -> Result<Vec<Sample>> {
+        self.scan(topic, 0, u64::MAX)
+    }
+
+    fn query_range(&self, topic: &str, start_ns: u64, end_ns: u64) -> Result<Vec<Sample>> {
+        let mut samples = Vec::new();
+        for stored in self.topics() {
+            if topic_matches(topic, &stored) {
+                samples.extend(self.scan(&stored, start_ns, end_ns)?);
+            }
+        }
+        samples.sort_by(|a, b| {
+            (a.timestamp_ns, a.source_guid, a.sequence).cmp(&(
+                b.timestamp_ns,
+                b.source_guid,
+                b.sequence,
+            ))
+        });
+        Ok(samples)
+    }
+
+    fn apply_retention(&self, topic: &str, keep_count: usize) -> Result<()> {
+        self.retain(topic, Some(keep_count), None, None)
+    }
+
+    fn apply_retention_policy(&self, topic: &str, policy: &RetentionPolicy) -> Result<()> {
+        if policy.is_noop() {
+            return Ok(());
+        }
+
+        let min_timestamp_ns = policy.max_age_ns.map(|max_age_ns| {
+            let now_ns = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos() as u64;
+            now_ns.saturating_sub(max_age_ns)
+        });
+        self.retain(
+            topic,
+            (policy.keep_count > 0).then_some(policy.keep_count),
+            min_timestamp_ns,
+            policy.max_bytes,
+        )
+    }
+
+    fn count(&self) -> Result<usize> {
+        let mut count = 0;
+        for topic in self.topics() {
+            let Some(cf) = self.db.cf_handle(&topic_cf_name(&topic)) else {
+                continue;
+            };
+            for item in self
+                .db
+                .iterator_cf(&cf, IteratorMode::From(&[SAMPLE_TAG], Direction::Forward))
+            {
+                let (key, _) = item?;
+                if sample_timestamp(&key).is_none() {
+                    break;
+                }
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    fn clear(&self) -> Result<()> {
+        let _writing = self.next_id.lock().unwrap();
+        for topic in self.topics() {
+            let Some(cf) = self.db.cf_handle(&topic_cf_name(&topic)) else {
+                continue;
+            };
+            let mut batch = WriteBatch::default();
+            batch.delete_range_cf(&cf, [0x00u8], [0xFFu8]);
+            self.db.write(batch)?;
+            self.request_compaction(&topic);
+        }
+        Ok(())
+    }
+}
+
+impl PositionStore for RocksDbStore {
+    fn save_position(&self, position: &ReaderPosition) -> Result<()> {
+        let cf = self.positions_cf()?;
+        let _writing = self.next_id.lock().unwrap();
+
+        let mut key = position_prefix(&position.consumer, &position.topic);
+        key.extend_from_slice(&position.source_guid);
+        if let Some(stored) = self.db.get_cf(&cf, &key)? {
+            if be_u64(&stored)? >= position.sequence {
+                return Ok(());
+            }
+        }
+        self.db.put_cf(&cf, key, position.sequence.to_be_bytes())?;
+
+        Ok(())
+    }
+
+    fn load_positions(&self, consumer: &str, topic: &str) -> Result<Vec<ReaderPosition>> {
+        let cf = self.positions_cf()?;
+        let prefix = position_prefix(consumer, topic);
+
+        let mut positions = Vec::new();
+        for item in self
+            .db
+            .iterator_cf(&cf, IteratorMode::From(&prefix, Direction::Forward))
+        {
+            let (key, value) = item?;
+            if !key.starts_with(&prefix) {
+                break;
+            }
+            let source_guid = <[u8; 16]>::try_from(&key[prefix.len()..])
+                .map_err(|_| anyhow!("Corrupt position key"))?;
+            positions.push(ReaderPosition {
+                consumer: consumer.to_string(),
+                topic: topic.to_string(),
+                source_guid,
+                sequence: be_u64(&value)?,
+            });
+        }
+
+        Ok(positions)
+    }
+
+    fn clear_positions(&self, consumer: &str, topic: &str) -> Result<()> {
+        let cf = self.positions_cf()?;
+        let prefix = position_prefix(consumer, topic);
+
+        let mut batch = WriteBatch::default();
+        for item in self
+            .db
+            .iterator_cf(&cf, IteratorMode::From(&prefix, Direction::Forward))
+        {
+            let (key, _) = item?;
+            if !key.starts_with(&prefix) {
+                break;
+            }
+            batch.delete_cf(&cf, key);
+        }
+        self.db.write(batch)?;
+        Ok(())
+    }
+}
+
+/// Compact the topics requested by retention, until the store is dropped
+fn compaction_loop(db: &Db, requests: &mpsc::Receiver<String>) {
+    while let Ok(first) = requests.recv() {
+        // Coalesce the requests queued in the meantime
+        let topics: BTreeSet<String> = std::iter::once(first).chain(requests.try_iter()).collect();
+        for topic in topics {
+            if let Some(cf) = db.cf_handle(&topic_cf_name(&topic)) {
+                db.compact_range_cf(&cf, None::<&[u8]>, None::<&[u8]>);
+                tracing::debug!("Compacted topic {}", topic);
+            }
+        }
+    }
+}
+
+fn topic_cf_name(topic: &str) -> String {
+    format!("{}{}", TOPIC_CF_PREFIX, topic)
+}
+
+/// Column family options of topics: bloom filters for the origin lookups
+/// of every save
+fn topic_cf_options() -> Options {
+    let mut table = BlockBasedOptions::default();
+    table.set_bloom_filter(10.0, false);
+    let mut opts = Options::default();
+    opts.set_block_based_table_factory(&table);
+    opts
+}
+
+fn sample_key(sample: &Sample, id: u64) -> [u8; SAMPLE_KEY_LEN] {
+    let mut key = [0u8; SAMPLE_KEY_LEN];
+    key[0] = SAMPLE_TAG;
+    key[1..9].copy_from_slice(&sample.timestamp_ns.to_be_bytes());
+    key[9..25].copy_from_slice(&sample.source_guid);
+    key[25..33].copy_from_slice(&sample.sequence.to_be_bytes());
+    key[33..].copy_from_slice(&id.to_be_bytes());
+    key
+}
+
+fn origin_key(source_guid: &[u8; 16], sequence: u64) -> [u8; ORIGIN_KEY_LEN] {
+    let mut key = [0u8; ORIGIN_KEY_LEN];
+    key[0] = ORIGIN_TAG;
+    key[1..17].copy_from_slice(source_guid);
+    key[17..].copy_from_slice(&sequence.to_be_bytes());
+    key
+}
+
+/// Timestamp of a sample key, `None` for other keys
+fn sample_timestamp(key: &[u8]) -> Option<u64> {
+    if key.len() != SAMPLE_KEY_LEN || key[0] != SAMPLE_TAG {
+        return None;
+    }
+    key[1..9].try_into().ok().map(u64::from_be_bytes)
+}
+
+/// Original writer and sequence number of a sample key
+fn sample_origin(key: &[u8]) -> ([u8; 16], u64) {
+    let mut source_guid = [0u8; 16];
+    source_guid.copy_from_slice(&key[9..25]);
+    let mut sequence = [0u8; 8];
+    sequence.copy_from_slice(&key[25..33]);
+    (source_guid, u64::from_be_bytes(sequence))
+}
+
+fn type_name_len(value: &[u8]) -> Result<usize> {
+    let len = value
+        .get(..4)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u32::from_le_bytes)
+        .ok_or_else(|| anyhow!("Corrupt sample value"))? as usize;
+    if value.len() < 4 + len {
+        return Err(anyhow!("Corrupt sample value"));
+    }
+    Ok(len)
+}
+
+fn payload_len(value: &[u8]) -> Result<usize> {
+    Ok(value.len() - 4 - type_name_len(value)?)
+}
+
+fn decode_sample(topic: &str, key: &[u8], value: &[u8]) -> Result<Sample> {
+    let type_name_len = type_name_len(value)?;
+    let (source_guid, sequence) = sample_origin(key);
+    Ok(Sample {
+        topic: topic.to_string(),
+        type_name: String::from_utf8(value[4..4 + type_name_len].to_vec())?,
+        payload: value[4 + type_name_len..].to_vec(),
+        timestamp_ns: sample_timestamp(key).unwrap_or_default(),
+        sequence,
+        source_guid,
+    })
+}
+
+fn position_prefix(consumer: &str, topic: &str) -> Vec<u8> {
+    let mut prefix = Vec::with_capacity(8 + consumer.len() + topic.len() + 16);
+    prefix.extend_from_slice(&(consumer.len() as u32).to_le_bytes());
+    prefix.extend_from_slice(consumer.as_bytes());
+    prefix.extend_from_slice(&(topic.len() as u32).to_le_bytes());
+    prefix.extend_from_slice(topic.as_bytes());
+    prefix
+}
+
+fn be_u64(bytes: &[u8]) -> Result<u64> {
+    bytes
+        .try_into()
+        .map(u64::from_be_bytes)
+        .map_err(|_| anyhow!("Corrupt integer value ({} bytes)", bytes.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    /// Database directory removed on drop
+    struct TempDb(PathBuf);
+
+    impl TempDb {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!(
+                "hdds-persistence-{}-{}",
+                name,
+                std::process::id()
+            ));
+            let _ = std::fs::remove_dir_all(&path);
+            Self(path)
+        }
+
+        fn open(&self) -> RocksDbStore {
+            RocksDbStore::new(self.0.to_str().unwrap()).unwrap()
+        }
+    }
+
+    impl Drop for TempDb {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn sample(topic: &str, source_guid: [u8; 16], sequence: u64, timestamp_ns: u64) -> Sample {
+        Sample {
+            topic: topic.to_string(),
+            type_name: "TestType".to_string(),
+            payload: vec![sequence as u8; 10],
+            timestamp_ns,
+            sequence,
+            source_guid,
+        }
+    }
+
+    #[test]
+    fn test_rocksdb_store_save_and_load() {
+        let dir = TempDb::new("save_and_load");
+        let store = dir.open();
+
+        store
+            .save(&sample("test/topic", [0xBB; 16], 2, 1000))
+            .unwrap();
+        store
+            .save(&sample("test/topic", [0xAA; 16], 7, 1000))
+            .unwrap();
+        store
+            .save(&sample("test/topic", [0xAA; 16], 7, 1000))
+            .unwrap();
+        store
+            .save(&sample("test/topic", [0xAA; 16], 6, 500))
+            .unwrap();
+        store.save(&sample("test/topic", [0; 16], 0, 2000)).unwrap();
+        store.save(&sample("test/topic", [0; 16], 0, 2000)).unwrap();
+        store
+            .save(&sample("other/topic", [0xAA; 16], 7, 1000))
+            .unwrap();
+
+        let loaded: Vec<([u8; 16], u64)> = store
+            .load("test/topic")
+            .unwrap()
+            .into_iter()
+            .map(|s| (s.source_guid, s.sequence))
+            .collect();
+        assert_eq!(
+            loaded,
+            [
+                ([0xAA; 16], 6),
+                ([0xAA; 16], 7),
+                ([0xBB; 16], 2),
+                ([0; 16], 0),
+                ([0; 16], 0)
+            ]
+        );
+        assert_eq!(store.count().unwrap(), 6);
+
+        // Reopened, ids keep unknown writers' samples apart
+        drop(store);
+        let store = dir.open();
+        store.save(&sample("test/topic", [0; 16], 0, 2000)).unwrap();
+        assert_eq!(store.load("test/topic").unwrap().len(), 6);
+        assert_eq!(store.load("test/topic").unwrap()[0].type_name, "TestType");
+    }
+
+    #[test]
+    fn test_rocksdb_store_wildcard_query() {
+        let dir = TempDb::new("wildcard_query");
+        let store = dir.open();
+
+        for (i, topic) in ["State/Temperature", "State/Pressure", "Command/Set"]
+            .iter()
+            .enumerate()
+        {
+            store
+                .save(&sample(topic, [0xCC; 16], i as u64, 1000 * i as u64))
+                .unwrap();
+        }
+
+        assert_eq!(store.query_range("State/*", 0, 10000).unwrap().len(), 2);
+        assert_eq!(store.query_range("*", 0, 10000).unwrap().len(), 3);
+        let range = store.query_range("*", 500, 1500).unwrap();
+        assert_eq!(range.len(), 1);
+        assert_eq!(range[0].topic, "State/Pressure");
+    }
+
+    #[test]
+    fn test_rocksdb_store_retention() {
+        let dir = TempDb::new("retention");
+        let store = dir.open();
+
+        for i in 0..10 {
+            store
+                .save(&sample("test/topic", [0xDD; 16], i, i * 1000))
+                .unwrap();
+        }
+
+        store.apply_retention("test/topic", 8).unwrap();
+        assert_eq!(store.count().unwrap(), 8);
+
+        // 10 bytes per payload
+        let policy = RetentionPolicy {
+            keep_count: 0,
+            max_age_ns: None,
+            max_bytes: Some(45),
+        };
+        store.apply_retention_policy("test/topic", &policy).unwrap();
+        let remaining = store.load("test/topic").unwrap();
+        assert_eq!(remaining.len(), 4);
+        assert_eq!(remaining[0].sequence, 6);
+
+        // Evicted samples are stored again if received again
+        store.save(&sample("test/topic", [0xDD; 16], 0, 0)).unwrap();
+        assert_eq!(store.count().unwrap(), 5);
+    }
+
+    #[test]
+    fn test_rocksdb_store_positions_are_monotonic() {
+        let dir = TempDb::new("positions");
+        let store = dir.open();
+        let position = |sequence| ReaderPosition {
+            consumer: "billing".to_string(),
+            topic: "Orders/New".to_string(),
+            source_guid: [0xAA; 16],
+            sequence,
+        };
+
+        store.save_position(&position(5)).unwrap();
+        store.save_position(&position(3)).unwrap();
+        assert_eq!(
+            store.load_positions("billing", "Orders/New").unwrap(),
+            [position(5)]
+        );
+        assert!(store
+            .load_positions("billing", "Orders")
+            .unwrap()
+            .is_empty());
+
+        store.clear_positions("billing", "Orders/New").unwrap();
+        assert!(store
+            .load_positions("billing", "Orders/New")
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -5,8 +5,9 @@
 //!
 //! Production-ready persistent storage with zero external dependencies.
 
+use crate::position::{PositionStore, ReaderPosition};
 use crate::store::{PersistenceStore, RetentionPolicy, Sample};
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use rusqlite::{params, Connection};
 use std::sync::Mutex;
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -31,6 +32,14 @@ use std::time::{SystemTime, UNIX_EPOCH};
 /// );
 /// CREATE INDEX idx_topic ON samples(topic);
 /// CREATE INDEX idx_timestamp ON samples(timestamp_ns);
+/// CREATE INDEX idx_origin ON samples(topic, source_guid, sequence);
+/// CREATE TABLE reader_positions (
+///     consumer TEXT NOT NULL,
+///     topic TEXT NOT NULL,
+///     source_guid BLOB NOT NULL,
+///     sequence INTEGER NOT NULL,
+///     PRIMARY KEY (consumer, topic, source_guid)
+/// );
 /// ```
 pub struct SqliteStore {
     conn: Mutex<Connection>,
@@ -85,6 +94,22 @@ impl SqliteStore {
             [],
         )?;
 
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_origin ON samples(topic, source_guid, sequence)",
+            [],
+        )?;
+
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS reader_positions (
+                consumer TEXT NOT NULL,
+                topic TEXT NOT NULL,
+                source_guid BLOB NOT NULL,
+                sequence INTEGER NOT NULL,
+                PRIMARY KEY (consumer, topic, source_guid)
+            )",
+            [],
+        )?;
+
         Ok(())
     }
 
@@ -108,9 +133,14 @@ impl SqliteStore {
 impl PersistenceStore for SqliteStore {
     fn save(&self, sample: &Sample) -> Result<()> {
         let conn = self.conn.lock().unwrap();
+        let known_writer = sample.source_guid != [0u8; 16];
         conn.execute(
             "INSERT INTO samples (topic, type_name, payload, timestamp_ns, sequence, source_guid)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+             SELECT ?1, ?2, ?3, ?4, ?5, ?6
+             WHERE NOT ?7 OR NOT EXISTS (
+                 SELECT 1 FROM samples
+                 WHERE topic = ?1 AND source_guid = ?6 AND sequence = ?5
+             )",
             params![
                 sample.topic,
                 sample.type_name,
@@ -118,6 +148,7 @@ impl PersistenceStore for SqliteStore {
                 sample.timestamp_ns as i64,
                 sample.sequence as i64,
                 &sample.source_guid[..],
+                known_writer,
             ],
         )?;
 
@@ -130,7 +161,7 @@ impl PersistenceStore for SqliteStore {
             "SELECT topic, type_name, payload, timestamp_ns, sequence, source_guid
              FROM samples
              WHERE topic = ?1
-             ORDER BY timestamp_ns ASC",
+             ORDER BY timestamp_ns ASC, source_guid ASC, sequence ASC",
         )?;
 
         let samples = stmt
@@ -154,20 +185,20 @@ impl PersistenceStore for SqliteStore {
                 "SELECT topic, type_name, payload, timestamp_ns, sequence, source_guid
                  FROM samples
                  WHERE topic LIKE '{}/%' AND timestamp_ns BETWEEN ?1 AND ?2
-                 ORDER BY timestamp_ns ASC",
+                 ORDER BY timestamp_ns ASC, source_guid ASC, sequence ASC",
                 prefix
             )
         } else if topic == "*" {
             "SELECT topic, type_name, payload, timestamp_ns, sequence, source_guid
              FROM samples
              WHERE timestamp_ns BETWEEN ?1 AND ?2
-             ORDER BY timestamp_ns ASC"
+             ORDER BY timestamp_ns ASC, source_guid ASC, sequence ASC"
                 .to_string()
         } else {
             "SELECT topic, type_name, payload, timestamp_ns, sequence, source_guid
              FROM samples
              WHERE topic = ?3 AND timestamp_ns BETWEEN ?1 AND ?2
-             ORDER BY timestamp_ns ASC"
+             ORDER BY timestamp_ns ASC, source_guid ASC, sequence ASC"
                 .to_string()
         };
 
@@ -293,6 +324,75 @@ impl PersistenceStore for SqliteStore {
     }
 }
 
+impl PositionStore for SqliteStore {
+    fn save_position(&self, position: &ReaderPosition) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO reader_positions (consumer, topic, source_guid, sequence)
+             VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT (consumer, topic, source_guid)
+             DO UPDATE SET sequence = excluded.sequence
+             WHERE excluded.sequence > reader_positions.sequence",
+            params![
+                position.consumer,
+                position.topic,
+                &position.source_guid[..],
+                position.sequence.min(i64::MAX as u64) as i64,
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    fn load_positions(&self, consumer: &str, topic: &str) -> Result<Vec<ReaderPosition>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT consumer, topic, source_guid, sequence
+             FROM reader_positions
+             WHERE consumer = ?1 AND topic = ?2",
+        )?;
+
+        let rows = stmt
+            .query_map(params![consumer, topic], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Vec<u8>>(2)?,
+                    row.get::<_, i64>(3)? as u64,
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        rows.into_iter()
+            .map(|(consumer, topic, source_guid, sequence)| {
+                let source_guid = source_guid.try_into().map_err(|blob: Vec<u8>| {
+                    anyhow!(
+                        "Corrupt position of consumer '{}' on {}: {}-byte source GUID",
+                        consumer,
+                        topic,
+                        blob.len()
+                    )
+                })?;
+                Ok(ReaderPosition {
+                    consumer,
+                    topic,
+                    source_guid,
+                    sequence,
+                })
+            })
+            .collect()
+    }
+
+    fn clear_positions(&self, consumer: &str, topic: &str) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "DELETE FROM reader_positions WHERE consumer = ?1 AND topic = ?2",
+            params![consumer, topic],
+        )?;
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -365,6 +465,45 @@ mod tests {
         assert_eq!(all_samples.len(), 3);
     }
 
+    #[test]
+    fn test_sqlite_store_skips_copies() {
+        let store = SqliteStore::new_in_memory().unwrap();
+        let sample = |source_guid: [u8; 16], sequence: u64, timestamp_ns: u64| Sample {
+            topic: "test/topic".to_string(),
+            type_name: "TestType".to_string(),
+            payload: vec![sequence as u8],
+            timestamp_ns,
+            sequence,
+            source_guid,
+        };
+
+        // Received live, then relayed by another replica
+        store.save(&sample([0xBB; 16], 2, 1000)).unwrap();
+        store.save(&sample([0xAA; 16], 7, 1000)).unwrap();
+        store.save(&sample([0xAA; 16], 7, 1000)).unwrap();
+        store.save(&sample([0xAA; 16], 6, 500)).unwrap();
+        // Unknown writers are never deduplicated
+        store.save(&sample([0; 16], 0, 2000)).unwrap();
+        store.save(&sample([0; 16], 0, 2000)).unwrap();
+
+        let loaded: Vec<([u8; 16], u64)> = store
+            .load("test/topic")
+            .unwrap()
+            .into_iter()
+            .map(|s| (s.source_guid, s.sequence))
+            .collect();
+        assert_eq!(
+            loaded,
+            [
+                ([0xAA; 16], 6),
+                ([0xAA; 16], 7),
+                ([0xBB; 16], 2),
+                ([0; 16], 0),
+                ([0; 16], 0)
+            ]
+        );
+    }
+
     #[test]
     fn test_sqlite_store_retention() {
         let store = SqliteStore::new_in_memory().unwrap();
@@ -390,6 +529,53 @@ mod tests {
         assert_eq!(remaining[0].sequence, 5); // oldest kept is sequence 5
     }
 
+    #[test]
+    fn test_sqlite_store_positions_are_monotonic() {
+        let store = SqliteStore::new_in_memory().unwrap();
+
+        let mut position = ReaderPosition {
+            consumer: "billing".to_string(),
+            topic: "Orders/New".to_string(),
+            source_guid: [0xAB; 16],
+            sequence: 10,
+        };
+        store.save_position(&position).unwrap();
+
+        position.sequence = 7;
+        store.save_position(&position).unwrap();
+
+        let loaded = store.load_positions("billing", "Orders/New").unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].sequence, 10);
+        assert!(store
+            .load_positions("audit", "Orders/New")
+            .unwrap()
+            .is_empty());
+
+        store.clear_positions("billing", "Orders/New").unwrap();
+        assert!(store
+            .load_positions("billing", "Orders/New")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_sqlite_store_rejects_corrupt_position() {
+        let store = SqliteStore::new_in_memory().unwrap();
+        store
+            .conn
+            .lock()
+            .unwrap()
+            .execute(
+                "INSERT INTO reader_positions (consumer, topic, source_guid, sequence)
+                 VALUES ('billing', 'Orders/New', x'ABCD', 3)",
+                [],
+            )
+            .unwrap();
+
+        assert!(store.load_positions("billing", "Orders/New").is_err());
+    }
+
     #[test]
     fn test_sqlite_store_clear() {
         let store = SqliteStore::new_in_memory().unwrap();
@@ -20,13 +20,13 @@ pub struct Sample {
     /// Serialized payload (CDR)
     pub payload: Vec<u8>,
 
-    /// Timestamp (Unix nanoseconds)
+    /// Source timestamp (Unix nanoseconds)
     pub timestamp_ns: u64,
 
-    /// Sequence number
+    /// Sequence number the original writer published the sample with
     pub sequence: u64,
 
-    /// Source GUID (participant ID)
+    /// GUID of the original writer (zero if unknown)
     pub source_guid: [u8; 16],
 }
 
@@ -55,15 +55,19 @@ impl RetentionPolicy {
 /// # Implementations
 ///
 /// - `SqliteStore` -- Default, zero-dependency
-/// - `RocksDbStore` -- High-performance (feature flag)
+/// - `RocksDbStore` -- High-throughput (feature `rocksdb-backend`)
 pub trait PersistenceStore {
     /// Save a sample to persistent storage
+    ///
+    /// A sample already stored under the same topic, original writer and
+    /// sequence number (received live and from another replica's replay)
+    /// is skipped, unless the writer is unknown.
     fn save(&self, sample: &Sample) -> Result<()>;
 
-    /// Load all samples for a topic
+    /// Load all samples for a topic, by source timestamp
     fn load(&self, topic: &str) -> Result<Vec<Sample>>;
 
-    /// Query samples within a time range
+    /// Query samples within a time range, by source timestamp
     ///
     /// # Arguments
     ///
@@ -7,12 +7,21 @@
 //!   hdds-record --domain 0 --output capture.hdds
 //!   hdds-record --domain 0 --output capture.hdds --topics "rt/*"
 //!   hdds-record --domain 0 --output capture.hdds --rotate-size 100
+//!   hdds-record --domain 0 --output capture.hdds --rotate-size 100 --max-disk-mb 2048
+//!   hdds-record --domain 0 --output capture.hdds --topic-quota "debug/*=rate:50,bw:1000000"
+//!   hdds-record --domain 0 --output capture.hdds --name lidar-rec --idle
+//!
+//! The recorder listens on `hdds/record/control` (unless `--no-control`):
+//! another node can start, stop, pause and split the recording, change the
+//! topic filter and query the status through `hdds_recording::RecorderClient`.
 
 use clap::Parser;
 use hdds::{Participant, TransportMode};
 use hdds_recording::{
+    control::{ControlService, RecorderHandle},
     filter::TopicFilter,
-    recorder::{Recorder, RecorderConfig},
+    quota::{DiskBudget, TopicQuota},
+    recorder::{Recorder, RecorderConfig, RecorderState},
     rotation::RotationPolicy,
 };
 use std::collections::HashMap;
@@ -63,10 +72,36 @@ struct Args {
     #[arg(long, default_value = "0")]
     max_files: u32,
 
+    /// Global disk budget across all files (MB)
+    #[arg(long)]
+    max_disk_mb: Option<u64>,
+
+    /// What to do when the disk budget is full: drop-oldest or stop
+    #[arg(long, default_value = "drop-oldest")]
+    disk_policy: String,
+
+    /// Per-topic quota PATTERN=LIMITS, LIMITS being comma-separated
+    /// rate:<msg/s>, bw:<bytes/s>, size:<max message bytes>, total:<MB>
+    /// (repeatable, first matching pattern applies)
+    #[arg(long)]
+    topic_quota: Vec<String>,
+
     /// Duration to record (seconds, 0 = indefinite)
     #[arg(long, default_value = "0")]
     duration: u64,
 
+    /// Recorder name on the control topic
+    #[arg(long, default_value = "hdds-record")]
+    name: String,
+
+    /// Do not serve the remote control topic
+    #[arg(long)]
+    no_control: bool,
+
+    /// Wait for a remote start command instead of recording right away
+    #[arg(long, conflicts_with = "no_control")]
+    idle: bool,
+
     /// Log level (trace, debug, info, warn, error)
     #[arg(long, default_value = "info")]
     log_level: String,
@@ -118,8 +153,22 @@ fn main() -> anyhow::Result<()> {
         config = config.rotation(policy);
     }
 
+    // Quotas and disk budget
+    for spec in &args.topic_quota {
+        let (pattern, quota) = parse_topic_quota(spec)?;
+        config = config.topic_quota(pattern, quota);
+    }
+    if let Some(max_mb) = args.max_disk_mb {
+        let budget = match args.disk_policy.as_str() {
+            "drop-oldest" => DiskBudget::drop_oldest(max_mb),
+            "stop" => DiskBudget::stop(max_mb),
+            other => anyhow::bail!("Unknown disk policy '{}' (drop-oldest, stop)", other),
+        };
+        config = config.disk_budget(budget);
+    }
+
     // Create recorder
-    let mut recorder = Recorder::new(config);
+    let recorder = RecorderHandle::new(&args.name, Recorder::new(config));
 
     if !args.quiet {
         info!("HDDS Recording Service v{}", env!("CARGO_PKG_VERSION"));
@@ -131,10 +180,15 @@ fn main() -> anyhow::Result<()> {
     }
 
     // Start recording
-    recorder.start()?;
-
-    if !args.quiet {
-        info!("Recording started. Press Ctrl+C to stop.");
+    if args.idle {
+        if !args.quiet {
+            info!("Waiting for a start command on recorder '{}'", args.name);
+        }
+    } else {
+        recorder.start()?;
+        if !args.quiet {
+            info!("Recording started. Press Ctrl+C to stop.");
+        }
     }
 
     let running = Arc::new(AtomicBool::new(true));
@@ -148,8 +202,12 @@ fn main() -> anyhow::Result<()> {
         .domain_id(args.domain)
         .build()?;
 
-    let topic_filter = recorder.config().topic_filter.clone();
-    let type_filter = recorder.config().type_filter.clone();
+    let mut control = if args.no_control {
+        None
+    } else {
+        Some(ControlService::new(&participant, recorder.clone())?)
+    };
+
     let mut readers: HashMap<String, RecordingReader> = HashMap::new();
     let mut last_discovery = Instant::now()
         .checked_sub(Duration::from_secs(1))
@@ -173,7 +231,20 @@ fn main() -> anyhow::Result<()> {
             }
         }
 
+        if let Some(service) = control.as_mut() {
+            if let Err(err) = service.poll() {
+                warn!("Control topic failed: {}", err);
+            }
+        }
+
         if last_discovery.elapsed() >= Duration::from_secs(1) {
+            // Filters may be changed remotely
+            let (topic_filter, type_filter) = recorder.with_recorder(|r| {
+                (
+                    r.config().topic_filter.clone(),
+                    r.config().type_filter.clone(),
+                )
+            });
             match participant.discover_topics() {
                 Ok(topics) => {
                     for info in topics {
@@ -255,7 +326,7 @@ fn main() -> anyhow::Result<()> {
 
         // Print periodic stats
         if !args.quiet && last_report.elapsed() >= Duration::from_secs(10) {
-            let stats = recorder.stats();
+            let stats = recorder.with_recorder(|r| r.live_stats());
             if stats.message_count > 0 {
                 info!(
                     "Recorded {} messages ({:.1} MB)",
@@ -263,22 +334,84 @@ fn main() -> anyhow::Result<()> {
                     stats.bytes_written as f64 / 1_048_576.0
                 );
             }
+            if stats.quota.total_dropped() > 0 {
+                info!(
+                    "Dropped {} messages by quota ({} files deleted)",
+                    stats.quota.total_dropped(),
+                    stats.quota.files_deleted
+                );
+            }
             last_report = Instant::now();
         }
 
         std::thread::sleep(Duration::from_millis(20));
     }
 
-    // Stop recording
-    let stats = recorder.stop()?;
+    // Stop recording (unless stopped remotely)
+    let stats = if recorder.status().state == RecorderState::Stopped {
+        recorder.with_recorder(|r| r.stats().clone())
+    } else {
+        recorder.stop()?
+    };
 
     if !args.quiet {
         info!("Recording stopped");
         info!("  Messages: {}", stats.message_count);
         info!("  Duration: {:.1}s", stats.duration_secs);
         info!("  Throughput: {:.1} msg/s", stats.messages_per_second);
-        info!("  File: {}", args.output.display());
+        if stats.quota.total_dropped() > 0 {
+            info!(
+                "  Dropped: {} (rate {}, bandwidth {}, size {}, topic budget {}, disk budget {})",
+                stats.quota.total_dropped(),
+                stats.quota.dropped_rate,
+                stats.quota.dropped_bandwidth,
+                stats.quota.dropped_oversize,
+                stats.quota.dropped_topic_budget,
+                stats.quota.dropped_disk_budget
+            );
+            let mut by_topic: Vec<_> = stats.quota.dropped_by_topic.iter().collect();
+            by_topic.sort();
+            for (topic, count) in by_topic {
+                info!("    {}: {}", topic, count);
+            }
+        }
+        if stats.quota.files_deleted > 0 {
+            info!(
+                "  Deleted: {} files ({:.1} MB) to stay within disk budget",
+                stats.quota.files_deleted,
+                stats.quota.bytes_deleted as f64 / 1_048_576.0
+            );
+        }
+        info!(
+            "  File: {}",
+            recorder.with_recorder(|r| r.current_path().display().to_string())
+        );
     }
 
     Ok(())
 }
+
+/// Parse a `--topic-quota` value: `PATTERN=rate:N,bw:N,size:N,total:MB`.
+fn parse_topic_quota(spec: &str) -> anyhow::Result<(String, TopicQuota)> {
+    let (pattern, limits) = spec.split_once('=').ok_or_else(|| {
+        anyhow::anyhow!("Invalid topic quota '{}': expected PATTERN=LIMITS", spec)
+    })?;
+
+    let mut quota = TopicQuota::new();
+    for limit in limits.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        let (key, value) = limit.split_once(':').ok_or_else(|| {
+            anyhow::anyhow!("Invalid quota limit '{}': expected KEY:VALUE", limit)
+        })?;
+        let value: u64 = value
+            .parse()
+            .map_err(|_| anyhow::anyhow!("Invalid quota value in '{}'", limit))?;
+        quota = match key {
+            "rate" => quota.messages_per_sec(value),
+            "bw" => quota.bytes_per_sec(value),
+            "size" => quota.max_message_bytes(value),
+            "total" => quota.max_total_bytes(value * 1024 * 1024),
+            other => anyhow::bail!("Unknown quota limit '{}' (rate, bw, size, total)", other),
+        };
+    }
+    Ok((pattern.trim().to_string(), quota))
+}
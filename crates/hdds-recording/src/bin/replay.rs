@@ -7,6 +7,10 @@
 //!   hdds-replay --input capture.hdds
 //!   hdds-replay --input capture.hdds --speed 2.0
 //!   hdds-replay --input capture.hdds --loop
+//!   hdds-replay --input capture.hdds --start 12.5 --end 40 --loop-count 3
+//!   hdds-replay --input capture.hdds --remap "camera/*=replay/camera/*"
+//!   hdds-replay --input capture.hdds --topic-prefix replay/
+//!   hdds-replay --input bag.mcap          (requires the `mcap` feature)
 
 use clap::Parser;
 use hdds::dds::Durability as HddsDurability;
@@ -14,6 +18,7 @@ use hdds::{Participant, QoS, TransportMode};
 use hdds_recording::{
     filter::TopicFilter,
     player::{PlaybackSpeed, Player, PlayerConfig},
+    remap::TopicRemap,
 };
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -27,7 +32,7 @@ use tracing::{info, warn};
 #[command(about = "Replay recorded DDS messages")]
 #[command(version)]
 struct Args {
-    /// Input recording file (.hdds)
+    /// Input recording file (.hdds, or .mcap with the `mcap` feature)
     #[arg(short, long)]
     input: PathBuf,
 
@@ -39,17 +44,30 @@ struct Args {
     #[arg(short, long)]
     loop_playback: bool,
 
+    /// Play the recording N times (implies looping)
+    #[arg(long)]
+    loop_count: Option<u32>,
+
     /// Topic filter (include pattern, supports wildcards)
     #[arg(short, long)]
     topics: Option<String>,
 
+    /// Publish topic FROM as TO; FROM may end in `*` to rewrite a prefix
+    /// (FROM=TO, repeatable, first match applies)
+    #[arg(long)]
+    remap: Vec<String>,
+
+    /// Prefix for the topics no --remap rule matches
+    #[arg(long)]
+    topic_prefix: Option<String>,
+
     /// Start offset (seconds from beginning)
     #[arg(long, default_value = "0")]
-    start: u64,
+    start: f64,
 
     /// End time (seconds from beginning, 0 = play all)
     #[arg(long, default_value = "0")]
-    end: u64,
+    end: f64,
 
     /// DDS domain ID for publishing
     #[arg(short, long, default_value = "0")]
@@ -100,12 +118,40 @@ fn main() -> anyhow::Result<()> {
         .speed(speed)
         .loop_playback(args.loop_playback);
 
+    if let Some(count) = args.loop_count {
+        config = config.loop_count(count);
+    }
+
     // Time range
-    if args.start > 0 {
-        config = config.start_offset(Duration::from_secs(args.start));
+    if args.start < 0.0 || args.end < 0.0 {
+        anyhow::bail!("--start and --end must not be negative");
+    }
+    if args.end > 0.0 && args.end <= args.start {
+        anyhow::bail!(
+            "--end ({}s) must be after --start ({}s)",
+            args.end,
+            args.start
+        );
     }
-    if args.end > 0 {
-        config = config.end_time(Duration::from_secs(args.end));
+    if args.start > 0.0 {
+        config = config.start_offset(Duration::from_secs_f64(args.start));
+    }
+    if args.end > 0.0 {
+        config = config.end_time(Duration::from_secs_f64(args.end));
+    }
+
+    // Topic remapping
+    let mut remap = TopicRemap::new();
+    for spec in &args.remap {
+        remap = remap
+            .parse_rule(spec)
+            .ok_or_else(|| anyhow::anyhow!("Invalid remap '{}': expected FROM=TO", spec))?;
+    }
+    if let Some(prefix) = &args.topic_prefix {
+        remap = remap.prefix(prefix.clone());
+    }
+    if !remap.is_empty() {
+        config = config.topic_remap(remap);
     }
 
     // Topic filter
@@ -152,8 +198,19 @@ fn main() -> anyhow::Result<()> {
         );
         info!("Playback speed: {}", format_speed(speed));
 
-        if args.loop_playback {
-            info!("Loop: enabled");
+        match args.loop_count {
+            Some(count) if count > 0 => info!("Loop: {} passes", count),
+            Some(_) => info!("Loop: enabled"),
+            None if args.loop_playback => info!("Loop: enabled"),
+            None => {}
+        }
+        if let Some(meta) = player.metadata() {
+            for topic in &meta.topics {
+                let published = player.config().remapped_topic(&topic.name);
+                if published != topic.name {
+                    info!("Remap: {} -> {}", topic.name, published);
+                }
+            }
         }
     }
 
@@ -177,7 +234,7 @@ fn main() -> anyhow::Result<()> {
         .domain_id(args.domain)
         .build()?;
 
-    let qos_map = qos_map_from_metadata(player.metadata());
+    let qos_map = qos_map_from_metadata(player.metadata(), player.config());
     let mut writers: HashMap<String, hdds::RawDataWriter> = HashMap::new();
 
     // Playback loop
@@ -262,15 +319,17 @@ fn format_speed(speed: PlaybackSpeed) -> String {
     }
 }
 
+/// Recorded QoS, keyed by the published (remapped) topic name.
 fn qos_map_from_metadata(
     metadata: Option<&hdds_recording::RecordingMetadata>,
+    config: &PlayerConfig,
 ) -> HashMap<String, QoS> {
     let mut map = HashMap::new();
 
     if let Some(metadata) = metadata {
         for topic in &metadata.topics {
             map.insert(
-                topic.name.clone(),
+                config.remapped_topic(&topic.name).into_owned(),
                 qos_from_strings(&topic.reliability, &topic.durability),
             );
         }
@@ -0,0 +1,735 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Runtime control of a recorder.
+//!
+//! [`RecorderHandle`] is the in-process API: a cloneable handle that starts,
+//! stops, pauses, resumes and splits a shared [`Recorder`], changes its topic
+//! filter and reports its status.
+//!
+//! [`ControlService`] exposes the same operations over DDS. It takes
+//! [`ControlRequest`]s from [`CONTROL_TOPIC`] and publishes a
+//! [`StatusReport`] on [`STATUS_TOPIC`] for each request addressed to it,
+//! plus one every status period. [`RecorderClient`] is the remote side.
+//!
+//! ```no_run
+//! use hdds_recording::control::{ControlCommand, RecorderClient};
+//! use std::time::Duration;
+//!
+//! let participant = hdds::Participant::builder("ops").build()?;
+//! let mut client = RecorderClient::new(&participant)?;
+//! client.request("hdds-record", &ControlCommand::Split, Duration::from_secs(2))?;
+//! let status = client.request("hdds-record", &ControlCommand::Status, Duration::from_secs(2))?;
+//! println!("{} messages in {}", status.message_count, status.current_file.display());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::filter::TopicFilter;
+use crate::recorder::{Recorder, RecorderError, RecorderState, RecordingStats};
+use hdds::{DataReader, DataWriter, Participant, QoS};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// Topic carrying [`ControlRequest`]s.
+pub const CONTROL_TOPIC: &str = "hdds/record/control";
+
+/// Topic carrying [`StatusReport`]s.
+pub const STATUS_TOPIC: &str = "hdds/record/status";
+
+/// Default period of unsolicited status reports.
+pub const DEFAULT_STATUS_PERIOD: Duration = Duration::from_secs(1);
+
+/// Command codes of [`ControlRequest::command`].
+const CMD_START: u32 = 1;
+const CMD_STOP: u32 = 2;
+const CMD_PAUSE: u32 = 3;
+const CMD_RESUME: u32 = 4;
+const CMD_SET_TOPICS: u32 = 5;
+const CMD_SPLIT: u32 = 6;
+const CMD_STATUS: u32 = 7;
+
+/// Control request sample.
+#[derive(Debug, Clone, PartialEq, hdds::DDS)]
+pub struct ControlRequest {
+    /// Correlates the status reply; 0 is reserved for periodic reports.
+    pub request_id: u64,
+    /// Recorder name, empty for every recorder on the domain.
+    pub target: String,
+    /// Command code.
+    pub command: u32,
+    /// Output path for start, filter spec for set-topics, empty otherwise.
+    pub argument: String,
+}
+
+/// Status report sample.
+#[derive(Debug, Clone, PartialEq, hdds::DDS)]
+pub struct StatusReport {
+    /// Recorder name.
+    pub recorder: String,
+    /// Request answered, 0 for periodic reports.
+    pub request_id: u64,
+    /// Why the request was rejected, empty when it was applied.
+    pub error: String,
+    /// Recorder state code.
+    pub state: u32,
+    pub message_count: u64,
+    pub bytes_written: u64,
+    pub dropped: u64,
+    pub file_index: u32,
+    pub duration_secs: f64,
+    pub current_file: String,
+    /// Topic filter spec, empty when recording all topics.
+    pub topic_filter: String,
+}
+
+/// Control errors.
+#[derive(Debug, Error)]
+pub enum ControlError {
+    #[error("DDS error: {0}")]
+    Dds(String),
+
+    #[error("Invalid command: {0}")]
+    InvalidCommand(String),
+
+    #[error("Rejected by {recorder}: {reason}")]
+    Rejected { recorder: String, reason: String },
+
+    #[error("No reply within {0:?}")]
+    Timeout(Duration),
+}
+
+impl From<hdds::Error> for ControlError {
+    fn from(e: hdds::Error) -> Self {
+        ControlError::Dds(e.to_string())
+    }
+}
+
+/// Recorder command.
+#[derive(Debug, Clone)]
+pub enum ControlCommand {
+    /// Start recording, optionally to a new output file.
+    Start { output: Option<PathBuf> },
+    /// Stop recording and finalize the file.
+    Stop,
+    /// Discard incoming messages, keeping the file open.
+    Pause,
+    /// Resume after `Pause`.
+    Resume,
+    /// Replace the topic filter (None = all topics).
+    SetTopicFilter(Option<TopicFilter>),
+    /// Close the current file and continue in the next one.
+    Split,
+    /// Report status only.
+    Status,
+}
+
+impl ControlCommand {
+    /// Wire command code and argument.
+    pub fn encode(&self) -> (u32, String) {
+        match self {
+            ControlCommand::Start { output } => (
+                CMD_START,
+                output
+                    .as_ref()
+                    .map(|p| p.display().to_string())
+                    .unwrap_or_default(),
+            ),
+            ControlCommand::Stop => (CMD_STOP, String::new()),
+            ControlCommand::Pause => (CMD_PAUSE, String::new()),
+            ControlCommand::Resume => (CMD_RESUME, String::new()),
+            ControlCommand::SetTopicFilter(filter) => {
+                (CMD_SET_TOPICS, filter_spec(filter.as_ref()))
+            }
+            ControlCommand::Split => (CMD_SPLIT, String::new()),
+            ControlCommand::Status => (CMD_STATUS, String::new()),
+        }
+    }
+
+    /// Decode a wire command.
+    pub fn decode(command: u32, argument: &str) -> Result<Self, ControlError> {
+        Ok(match command {
+            CMD_START => ControlCommand::Start {
+                output: (!argument.is_empty()).then(|| PathBuf::from(argument)),
+            },
+            CMD_STOP => ControlCommand::Stop,
+            CMD_PAUSE => ControlCommand::Pause,
+            CMD_RESUME => ControlCommand::Resume,
+            CMD_SET_TOPICS => ControlCommand::SetTopicFilter(parse_filter_spec(argument)?),
+            CMD_SPLIT => ControlCommand::Split,
+            CMD_STATUS => ControlCommand::Status,
+            other => {
+                return Err(ControlError::InvalidCommand(format!(
+                    "unknown command code {}",
+                    other
+                )))
+            }
+        })
+    }
+}
+
+/// Topic filter as `include:a,b` / `exclude:a,b`, empty for no filter.
+pub fn filter_spec(filter: Option<&TopicFilter>) -> String {
+    let Some(filter) = filter else {
+        return String::new();
+    };
+    let mut patterns: Vec<&str> = filter.patterns().iter().map(String::as_str).collect();
+    patterns.sort_unstable();
+    let mode = if filter.is_include() {
+        "include"
+    } else {
+        "exclude"
+    };
+    format!("{}:{}", mode, patterns.join(","))
+}
+
+/// Parse a [`filter_spec`] string.
+pub fn parse_filter_spec(spec: &str) -> Result<Option<TopicFilter>, ControlError> {
+    let spec = spec.trim();
+    if spec.is_empty() {
+        return Ok(None);
+    }
+    let (mode, patterns) = spec.split_once(':').ok_or_else(|| {
+        ControlError::InvalidCommand(format!(
+            "topic filter '{}': expected include:PATTERNS or exclude:PATTERNS",
+            spec
+        ))
+    })?;
+    let patterns: Vec<String> = patterns
+        .split(',')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(String::from)
+        .collect();
+    match mode {
+        "include" => Ok(Some(TopicFilter::include(patterns))),
+        "exclude" => Ok(Some(TopicFilter::exclude(patterns))),
+        other => Err(ControlError::InvalidCommand(format!(
+            "topic filter mode '{}' (include, exclude)",
+            other
+        ))),
+    }
+}
+
+fn state_code(state: RecorderState) -> u32 {
+    match state {
+        RecorderState::Stopped => 0,
+        RecorderState::Recording => 1,
+        RecorderState::Paused => 2,
+    }
+}
+
+fn state_from_code(code: u32) -> RecorderState {
+    match code {
+        1 => RecorderState::Recording,
+        2 => RecorderState::Paused,
+        _ => RecorderState::Stopped,
+    }
+}
+
+/// Recorder status.
+#[derive(Debug, Clone)]
+pub struct RecorderStatus {
+    /// Recorder name.
+    pub recorder: String,
+    pub state: RecorderState,
+    pub message_count: u64,
+    /// Bytes written to the current file.
+    pub bytes_written: u64,
+    /// Messages dropped by quotas and the disk budget.
+    pub dropped: u64,
+    pub file_index: u32,
+    pub duration_secs: f64,
+    pub current_file: PathBuf,
+    pub topic_filter: Option<TopicFilter>,
+}
+
+impl RecorderStatus {
+    fn report(&self, request_id: u64, error: Option<String>) -> StatusReport {
+        StatusReport {
+            recorder: self.recorder.clone(),
+            request_id,
+            error: error.unwrap_or_default(),
+            state: state_code(self.state),
+            message_count: self.message_count,
+            bytes_written: self.bytes_written,
+            dropped: self.dropped,
+            file_index: self.file_index,
+            duration_secs: self.duration_secs,
+            current_file: self.current_file.display().to_string(),
+            topic_filter: filter_spec(self.topic_filter.as_ref()),
+        }
+    }
+
+    /// Status carried by a report (an unparsable filter reads as none).
+    pub fn from_report(report: &StatusReport) -> Self {
+        Self {
+            recorder: report.recorder.clone(),
+            state: state_from_code(report.state),
+            message_count: report.message_count,
+            bytes_written: report.bytes_written,
+            dropped: report.dropped,
+            file_index: report.file_index,
+            duration_secs: report.duration_secs,
+            current_file: PathBuf::from(&report.current_file),
+            topic_filter: parse_filter_spec(&report.topic_filter).unwrap_or(None),
+        }
+    }
+}
+
+/// Shared handle to a recorder.
+///
+/// Clones control the same recorder; the recording loop feeds it through
+/// [`record_sample`](Self::record_sample) or [`with_recorder`](Self::with_recorder).
+#[derive(Clone)]
+pub struct RecorderHandle {
+    name: Arc<str>,
+    recorder: Arc<Mutex<Recorder>>,
+}
+
+impl RecorderHandle {
+    /// Wrap a recorder; `name` addresses it on the control topic.
+    pub fn new(name: &str, recorder: Recorder) -> Self {
+        Self {
+            name: Arc::from(name),
+            recorder: Arc::new(Mutex::new(recorder)),
+        }
+    }
+
+    /// Recorder name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Recorder> {
+        self.recorder.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Run `f` with exclusive access to the recorder.
+    pub fn with_recorder<R>(&self, f: impl FnOnce(&mut Recorder) -> R) -> R {
+        f(&mut self.lock())
+    }
+
+    /// Start recording to the configured output file.
+    pub fn start(&self) -> Result<(), RecorderError> {
+        self.lock().start()
+    }
+
+    /// Start recording to `path`.
+    pub fn start_to<P: AsRef<Path>>(&self, path: P) -> Result<(), RecorderError> {
+        let mut recorder = self.lock();
+        recorder.set_output_path(path)?;
+        recorder.start()
+    }
+
+    /// Stop recording and finalize the file.
+    pub fn stop(&self) -> Result<RecordingStats, RecorderError> {
+        self.lock().stop()
+    }
+
+    /// Discard incoming messages, keeping the file open.
+    pub fn pause(&self) -> Result<(), RecorderError> {
+        self.lock().pause()
+    }
+
+    /// Resume after [`pause`](Self::pause).
+    pub fn resume(&self) -> Result<(), RecorderError> {
+        self.lock().resume()
+    }
+
+    /// Close the current file and continue in the next one.
+    pub fn split(&self) -> Result<PathBuf, RecorderError> {
+        self.lock().split()
+    }
+
+    /// Replace the topic filter (None = all topics).
+    pub fn set_topic_filter(&self, filter: Option<TopicFilter>) {
+        self.lock().set_topic_filter(filter);
+    }
+
+    /// Record a raw DDS sample if recording; ignored otherwise.
+    pub fn record_sample(
+        &self,
+        topic_name: &str,
+        type_name: &str,
+        writer_guid: &str,
+        sequence_number: u64,
+        payload: &[u8],
+        qos_hash: u32,
+    ) -> Result<(), RecorderError> {
+        let mut recorder = self.lock();
+        if !recorder.is_recording() {
+            return Ok(());
+        }
+        recorder.record_sample(
+            topic_name,
+            type_name,
+            writer_guid,
+            sequence_number,
+            payload,
+            qos_hash,
+        )
+    }
+
+    /// Apply a command.
+    pub fn execute(&self, command: &ControlCommand) -> Result<(), RecorderError> {
+        match command {
+            ControlCommand::Start { output: Some(path) } => self.start_to(path),
+            ControlCommand::Start { output: None } => self.start(),
+            ControlCommand::Stop => self.stop().map(|_| ()),
+            ControlCommand::Pause => self.pause(),
+            ControlCommand::Resume => self.resume(),
+            ControlCommand::SetTopicFilter(filter) => {
+                self.set_topic_filter(filter.clone());
+                Ok(())
+            }
+            ControlCommand::Split => self.split().map(|_| ()),
+            ControlCommand::Status => Ok(()),
+        }
+    }
+
+    /// Current status.
+    pub fn status(&self) -> RecorderStatus {
+        let recorder = self.lock();
+        let stats = recorder.live_stats();
+        RecorderStatus {
+            recorder: self.name.to_string(),
+            state: recorder.state(),
+            message_count: stats.message_count,
+            bytes_written: stats.bytes_written,
+            dropped: stats.quota.total_dropped(),
+            file_index: stats.file_index,
+            duration_secs: stats.duration_secs,
+            current_file: recorder.current_path().to_path_buf(),
+            topic_filter: recorder.config().topic_filter.clone(),
+        }
+    }
+}
+
+/// Serves [`CONTROL_TOPIC`] for one recorder.
+pub struct ControlService {
+    handle: RecorderHandle,
+    requests: DataReader<ControlRequest>,
+    reports: DataWriter<StatusReport>,
+    status_period: Duration,
+    last_report: Option<Instant>,
+}
+
+impl ControlService {
+    /// Create the control reader and status writer on `participant`.
+    pub fn new(
+        participant: &Arc<Participant>,
+        handle: RecorderHandle,
+    ) -> Result<Self, ControlError> {
+        let requests = participant
+            .topic::<ControlRequest>(CONTROL_TOPIC)?
+            .reader()
+            .qos(QoS::reliable())
+            .build()?;
+        let reports = participant
+            .topic::<StatusReport>(STATUS_TOPIC)?
+            .writer()
+            .qos(QoS::reliable())
+            .build()?;
+        Ok(Self {
+            handle,
+            requests,
+            reports,
+            status_period: DEFAULT_STATUS_PERIOD,
+            last_report: None,
+        })
+    }
+
+    /// Set the period of unsolicited status reports (zero disables them).
+    pub fn status_period(mut self, period: Duration) -> Self {
+        self.status_period = period;
+        self
+    }
+
+    /// The controlled recorder.
+    pub fn handle(&self) -> &RecorderHandle {
+        &self.handle
+    }
+
+    /// Apply pending requests and publish due status reports.
+    ///
+    /// Call it from the recording loop; returns the number of requests
+    /// addressed to this recorder.
+    pub fn poll(&mut self) -> Result<usize, ControlError> {
+        let mut handled = 0;
+        while let Some(request) = self.requests.take()? {
+            if !request.target.is_empty() && request.target != self.handle.name() {
+                continue;
+            }
+            handled += 1;
+            let error = match ControlCommand::decode(request.command, &request.argument) {
+                Ok(command) => {
+                    tracing::info!("Control request {}: {:?}", request.request_id, command);
+                    self.handle.execute(&command).err().map(|e| e.to_string())
+                }
+                Err(e) => Some(e.to_string()),
+            };
+            if let Some(ref error) = error {
+                tracing::warn!("Control request {} rejected: {}", request.request_id, error);
+            }
+            self.publish(request.request_id, error)?;
+        }
+
+        let due = self
+            .last_report
+            .is_none_or(|last| last.elapsed() >= self.status_period);
+        if !self.status_period.is_zero() && due {
+            self.publish(0, None)?;
+        }
+        Ok(handled)
+    }
+
+    fn publish(&mut self, request_id: u64, error: Option<String>) -> Result<(), ControlError> {
+        let report = self.handle.status().report(request_id, error);
+        self.reports.write(&report)?;
+        self.last_report = Some(Instant::now());
+        Ok(())
+    }
+}
+
+/// Remote side of [`ControlService`].
+pub struct RecorderClient {
+    requests: DataWriter<ControlRequest>,
+    reports: DataReader<StatusReport>,
+    next_id: u64,
+}
+
+impl RecorderClient {
+    /// Create the control writer and status reader on `participant`.
+    pub fn new(participant: &Arc<Participant>) -> Result<Self, ControlError> {
+        let requests = participant
+            .topic::<ControlRequest>(CONTROL_TOPIC)?
+            .writer()
+            .qos(QoS::reliable())
+            .build()?;
+        let reports = participant
+            .topic::<StatusReport>(STATUS_TOPIC)?
+            .reader()
+            .qos(QoS::reliable())
+            .build()?;
+        // Keep request ids of concurrent clients apart
+        let next_id = (u64::from(std::process::id()) << 32) | 1;
+        Ok(Self {
+            requests,
+            reports,
+            next_id,
+        })
+    }
+
+    /// Send `command` to recorder `target` (empty for all); returns the request id.
+    pub fn send(&mut self, target: &str, command: &ControlCommand) -> Result<u64, ControlError> {
+        let request_id = self.next_id;
+        self.next_id += 1;
+        let (code, argument) = command.encode();
+        self.requests.write(&ControlRequest {
+            request_id,
+            target: target.to_string(),
+            command: code,
+            argument,
+        })?;
+        Ok(request_id)
+    }
+
+    /// Send `command` to `target` and wait for its reply.
+    ///
+    /// With an empty target the first recorder to answer wins. Reports not
+    /// answering this request are discarded.
+    pub fn request(
+        &mut self,
+        target: &str,
+        command: &ControlCommand,
+        timeout: Duration,
+    ) -> Result<RecorderStatus, ControlError> {
+        let request_id = self.send(target, command)?;
+        let deadline = Instant::now() + timeout;
+        loop {
+            while let Some(report) = self.reports.take()? {
+                if report.request_id != request_id {
+                    continue;
+                }
+                if !report.error.is_empty() {
+                    return Err(ControlError::Rejected {
+                        recorder: report.recorder,
+                        reason: report.error,
+                    });
+                }
+                return Ok(RecorderStatus::from_report(&report));
+            }
+            if Instant::now() >= deadline {
+                return Err(ControlError::Timeout(timeout));
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    /// Take the status reports received so far.
+    pub fn take_reports(&self) -> Result<Vec<StatusReport>, ControlError> {
+        let mut reports = Vec::new();
+        while let Some(report) = self.reports.take()? {
+            reports.push(report);
+        }
+        Ok(reports)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recorder::RecorderConfig;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_command_roundtrip() {
+        let commands = [
+            ControlCommand::Start {
+                output: Some(PathBuf::from("/data/run2.hdds")),
+            },
+            ControlCommand::Start { output: None },
+            ControlCommand::Pause,
+            ControlCommand::SetTopicFilter(Some(TopicFilter::exclude(vec![
+                "debug/*".into(),
+                "rt/tf".into(),
+            ]))),
+            ControlCommand::SetTopicFilter(None),
+        ];
+        for command in commands {
+            let (code, argument) = command.encode();
+            let decoded = ControlCommand::decode(code, &argument).expect("decode");
+            assert_eq!(decoded.encode(), (code, argument));
+        }
+
+        assert_eq!(
+            filter_spec(Some(&TopicFilter::include(vec!["b".into(), "a".into()]))),
+            "include:a,b"
+        );
+        assert!(ControlCommand::decode(99, "").is_err());
+        assert!(parse_filter_spec("only:a").is_err());
+    }
+
+    #[test]
+    fn test_handle_controls_recorder() {
+        let dir = tempdir().expect("tempdir");
+        let handle = RecorderHandle::new(
+            "rec",
+            Recorder::new(RecorderConfig::new(dir.path().join("run.hdds"))),
+        );
+        assert!(handle.pause().is_err());
+        // Samples are ignored while stopped
+        handle
+            .record_sample("pose", "Pose", "guid", 1, &[1], 0)
+            .expect("stopped");
+
+        handle.start().expect("start");
+        handle
+            .record_sample("pose", "Pose", "guid", 1, &[1], 0)
+            .expect("record");
+        handle.pause().expect("pause");
+        handle
+            .record_sample("pose", "Pose", "guid", 2, &[1], 0)
+            .expect("paused");
+        assert_eq!(handle.status().state, RecorderState::Paused);
+        handle.resume().expect("resume");
+
+        handle
+            .execute(&ControlCommand::SetTopicFilter(Some(TopicFilter::include(
+                vec!["scan".into()],
+            ))))
+            .expect("filter");
+        handle
+            .record_sample("pose", "Pose", "guid", 3, &[1], 0)
+            .expect("filtered");
+        handle
+            .record_sample("scan", "Scan", "guid", 1, &[1], 0)
+            .expect("record");
+
+        let split = handle.split().expect("split");
+        assert_eq!(split, dir.path().join("run_0001.hdds"));
+        assert!(dir.path().join("run.hdds").exists());
+
+        let status = handle.status();
+        assert_eq!(status.recorder, "rec");
+        assert_eq!(status.state, RecorderState::Recording);
+        assert_eq!(status.message_count, 2);
+        assert_eq!(status.file_index, 1);
+        assert_eq!(status.current_file, split);
+
+        handle.stop().expect("stop");
+        handle
+            .execute(&ControlCommand::Start {
+                output: Some(dir.path().join("second.hdds")),
+            })
+            .expect("restart");
+        assert!(dir.path().join("second.hdds").exists());
+        assert_eq!(handle.status().message_count, 0);
+    }
+
+    #[test]
+    fn test_remote_control() {
+        let dir = tempdir().expect("tempdir");
+        let recorder_participant = Participant::builder("record-control-test")
+            .with_transport(hdds::TransportMode::IntraProcess)
+            .domain_id(41)
+            .build()
+            .expect("participant");
+        let client_participant = Participant::builder("record-control-client")
+            .with_transport(hdds::TransportMode::IntraProcess)
+            .domain_id(41)
+            .build()
+            .expect("participant");
+
+        let handle = RecorderHandle::new(
+            "rec",
+            Recorder::new(RecorderConfig::new(dir.path().join("remote.hdds"))),
+        );
+        let mut service = ControlService::new(&recorder_participant, handle.clone())
+            .expect("service")
+            .status_period(Duration::ZERO);
+        let mut client = RecorderClient::new(&client_participant).expect("client");
+
+        let serve = |service: &mut ControlService| {
+            let deadline = Instant::now() + Duration::from_secs(2);
+            while service.poll().expect("poll") == 0 && Instant::now() < deadline {
+                std::thread::sleep(Duration::from_millis(5));
+            }
+        };
+
+        // Requests for other recorders are ignored
+        client
+            .send("other", &ControlCommand::Start { output: None })
+            .expect("send");
+        client
+            .send("rec", &ControlCommand::Start { output: None })
+            .expect("send");
+        serve(&mut service);
+        assert_eq!(handle.status().state, RecorderState::Recording);
+
+        std::thread::scope(|scope| {
+            let server = scope.spawn(|| serve(&mut service));
+            let status = client
+                .request("", &ControlCommand::Pause, Duration::from_secs(2))
+                .expect("pause");
+            assert_eq!(status.recorder, "rec");
+            assert_eq!(status.state, RecorderState::Paused);
+            server.join().expect("server");
+        });
+
+        std::thread::scope(|scope| {
+            let server = scope.spawn(|| serve(&mut service));
+            let err = client
+                .request(
+                    "rec",
+                    &ControlCommand::Start { output: None },
+                    Duration::from_secs(2),
+                )
+                .expect_err("already recording");
+            assert!(matches!(err, ControlError::Rejected { .. }));
+            server.join().expect("server");
+        });
+    }
+}
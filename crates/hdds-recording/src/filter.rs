@@ -61,7 +61,7 @@ impl TopicFilter {
     /// Supports:
     /// - `*` matches any substring
     /// - Exact match otherwise
-    fn pattern_match(pattern: &str, topic: &str) -> bool {
+    pub(crate) fn pattern_match(pattern: &str, topic: &str) -> bool {
         if pattern == "*" {
             return true;
         }
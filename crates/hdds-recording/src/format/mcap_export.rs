@@ -26,12 +26,20 @@ pub enum McapError {
 
     #[error("Source format error: {0}")]
     Source(String),
+
+    #[error("Invalid MCAP file: {0}")]
+    Invalid(String),
 }
 
+/// CDR encapsulation header (PLAIN_CDR, little-endian) of `cdr` messages.
+const CDR_LE_HEADER: [u8; 4] = [0x00, 0x01, 0x00, 0x00];
+
 /// MCAP file exporter.
 pub struct McapExporter<'a> {
     writer: mcap::Writer<'a, BufWriter<File>>,
     channels: HashMap<String, u16>,
+    /// Encapsulated payload of the message being written.
+    buffer: Vec<u8>,
 }
 
 impl<'a> McapExporter<'a> {
@@ -59,6 +67,7 @@ impl<'a> McapExporter<'a> {
         Ok(Self {
             writer: mcap_writer,
             channels: HashMap::new(),
+            buffer: Vec::new(),
         })
     }
 
@@ -67,7 +76,12 @@ impl<'a> McapExporter<'a> {
         // Get or create channel for this topic
         let channel_id = self.get_or_create_channel(&msg.topic_name, &msg.type_name)?;
 
-        // Write message
+        // MCAP `cdr` messages carry the encapsulation header, recorded
+        // payloads do not
+        self.buffer.clear();
+        self.buffer.extend_from_slice(&CDR_LE_HEADER);
+        self.buffer.extend_from_slice(&msg.payload);
+
         self.writer.write_to_known_channel(
             &mcap::records::MessageHeader {
                 channel_id,
@@ -75,7 +89,7 @@ impl<'a> McapExporter<'a> {
                 log_time: msg.timestamp_nanos,
                 publish_time: msg.timestamp_nanos,
             },
-            &msg.payload,
+            &self.buffer,
         )?;
 
         Ok(())
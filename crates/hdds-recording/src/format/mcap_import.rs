@@ -0,0 +1,629 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! MCAP import support.
+//!
+//! Reads `.mcap` files written by rosbag2, Foxglove or [`McapExporter`] so
+//! they can be replayed like `.hdds` recordings.
+//!
+//! Indexed files are read one chunk at a time; files without chunk indexes
+//! are loaded whole. Only CDR channels are replayed: their little-endian
+//! encapsulation header is stripped, as hdds writers add their own.
+//!
+//! ROS 2 channels (`ros2msg` / `ros2idl` schemas) are renamed to the names
+//! ROS 2 uses on the DDS wire: `/odom` carrying `nav_msgs/msg/Odometry`
+//! becomes `rt/odom` carrying `nav_msgs::msg::dds_::Odometry_`.
+//!
+//! [`McapExporter`]: super::McapExporter
+
+use super::mcap_export::McapError;
+use super::{Message, RecordingMetadata, TopicInfo};
+use mcap::read::{ChunkFlattener, ChunkReader, LinearReader};
+use mcap::records::{ChunkIndex, MessageHeader, Record};
+use std::collections::{BTreeMap, VecDeque};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+/// Footer record length (opcode, length, 20-byte body).
+const FOOTER_LEN: u64 = 1 + 8 + 20;
+
+/// Attachment holding the metadata of files exported by hdds.
+const HDDS_METADATA_ATTACHMENT: &str = "hdds_metadata.json";
+
+/// A channel of an MCAP file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct McapChannel {
+    /// Channel ID.
+    pub id: u16,
+    /// Topic as recorded.
+    pub topic: String,
+    /// Topic the messages are replayed on.
+    pub dds_topic: String,
+    /// Type the messages are replayed as.
+    pub dds_type: String,
+    /// Message encoding (`cdr`, `json`, `protobuf`, ...).
+    pub message_encoding: String,
+    /// Schema name (empty without schema).
+    pub schema_name: String,
+    /// Schema encoding (`ros2msg`, `ros2idl`, `omgidl`, ...).
+    pub schema_encoding: String,
+    /// Schema definition (message definition text for ROS 2).
+    pub schema_data: Vec<u8>,
+    /// Channel metadata (rosbag2 stores `offered_qos_profiles` here).
+    pub metadata: BTreeMap<String, String>,
+}
+
+impl McapChannel {
+    /// Check if the messages can be replayed into DDS.
+    pub fn is_cdr(&self) -> bool {
+        self.message_encoding == "cdr"
+    }
+
+    /// Check if the schema is a ROS 2 message definition.
+    pub fn is_ros2(&self) -> bool {
+        matches!(self.schema_encoding.as_str(), "ros2msg" | "ros2idl")
+    }
+
+    /// Reliability and durability of the first offered QoS profile, as
+    /// rosbag2 records them (numeric before Jazzy, names since).
+    fn offered_qos(&self) -> (&'static str, &'static str) {
+        let profiles = self
+            .metadata
+            .get("offered_qos_profiles")
+            .map(String::as_str)
+            .unwrap_or("");
+        let reliability = match yaml_value(profiles, "reliability") {
+            Some("2" | "best_effort") => "BEST_EFFORT",
+            _ => "RELIABLE",
+        };
+        let durability = match yaml_value(profiles, "durability") {
+            Some("1" | "transient_local") => "TRANSIENT_LOCAL",
+            _ => "VOLATILE",
+        };
+        (reliability, durability)
+    }
+}
+
+/// First value of `key:` in a YAML document.
+fn yaml_value<'a>(yaml: &'a str, key: &str) -> Option<&'a str> {
+    yaml.lines().find_map(|line| {
+        let line = line.trim_start().trim_start_matches("- ");
+        let value = line.strip_prefix(key)?.strip_prefix(':')?;
+        Some(value.trim().trim_matches(|c| c == '"' || c == '\''))
+    })
+}
+
+/// DDS topic of a ROS 2 topic (`/odom` -> `rt/odom`).
+fn ros2_dds_topic(topic: &str) -> String {
+    format!("rt/{}", topic.trim_start_matches('/'))
+}
+
+/// DDS type of a ROS 2 type (`nav_msgs/msg/Odometry` ->
+/// `nav_msgs::msg::dds_::Odometry_`).
+fn ros2_dds_type(type_name: &str) -> String {
+    let parts: Vec<&str> = type_name.split('/').collect();
+    match parts.as_slice() {
+        [package, kind, name] => format!("{}::{}::dds_::{}_", package, kind, name),
+        [package, name] => format!("{}::msg::dds_::{}_", package, name),
+        _ => type_name.to_string(),
+    }
+}
+
+/// Payload without its CDR encapsulation header.
+///
+/// Only plain little-endian CDR is accepted: hdds writers announce their
+/// samples as such.
+fn strip_encapsulation(data: &[u8]) -> Option<&[u8]> {
+    match data {
+        [0x00, 0x01, _, _, payload @ ..] => Some(payload),
+        _ => None,
+    }
+}
+
+/// MCAP file reader.
+pub struct McapReader {
+    file: File,
+    schemas: BTreeMap<u16, (String, String, Vec<u8>)>,
+    channels: BTreeMap<u16, McapChannel>,
+    /// Chunks left to read, in time order.
+    chunks: VecDeque<ChunkIndex>,
+    /// Messages of the current chunk, in time order.
+    pending: VecDeque<Message>,
+    metadata: RecordingMetadata,
+    start_time: u64,
+    end_time: u64,
+    message_count: u64,
+    skipped: u64,
+}
+
+impl McapReader {
+    /// Open an MCAP file for reading.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, McapError> {
+        let mut file = File::open(path)?;
+        let len = file.metadata()?.len();
+
+        let mut magic = [0u8; 8];
+        file.read_exact(&mut magic)
+            .map_err(|_| McapError::Invalid("file too short".into()))?;
+        if magic != mcap::MAGIC {
+            return Err(McapError::Invalid("bad magic".into()));
+        }
+
+        let mut reader = Self {
+            file,
+            schemas: BTreeMap::new(),
+            channels: BTreeMap::new(),
+            chunks: VecDeque::new(),
+            pending: VecDeque::new(),
+            metadata: RecordingMetadata::default(),
+            start_time: u64::MAX,
+            end_time: 0,
+            message_count: 0,
+            skipped: 0,
+        };
+
+        let hdds_metadata = match reader.read_summary(len)? {
+            Some(attachment) => reader.read_hdds_metadata(attachment)?,
+            None => None,
+        };
+        if reader.chunks.is_empty() {
+            // No chunk index: read everything now
+            reader.load_whole_file()?;
+        }
+        if reader.start_time == u64::MAX {
+            reader.start_time = 0;
+        }
+        reader.metadata = hdds_metadata.unwrap_or_else(|| reader.build_metadata());
+
+        for channel in reader.channels.values().filter(|c| !c.is_cdr()) {
+            tracing::warn!(
+                "Skipping MCAP channel {} ({} encoding)",
+                channel.topic,
+                channel.message_encoding
+            );
+        }
+        Ok(reader)
+    }
+
+    /// Read the summary section: schemas, channels, chunk indexes and
+    /// statistics. Returns the hdds metadata attachment location, if any.
+    fn read_summary(&mut self, len: u64) -> Result<Option<(u64, u64)>, McapError> {
+        let magic_len = mcap::MAGIC.len() as u64;
+        if len < 2 * magic_len + FOOTER_LEN {
+            return Ok(None);
+        }
+        let footer_start = len - magic_len - FOOTER_LEN;
+        let tail = self.read_at(footer_start, FOOTER_LEN + magic_len)?;
+        if !tail.ends_with(mcap::MAGIC) {
+            // Truncated file (recording interrupted)
+            return Ok(None);
+        }
+        let footer = match LinearReader::sans_magic(&tail[..FOOTER_LEN as usize]).next() {
+            Some(Ok(Record::Footer(footer))) => footer,
+            _ => return Ok(None),
+        };
+        if footer.summary_start == 0 {
+            return Ok(None);
+        }
+
+        let summary_end = match footer.summary_offset_start {
+            0 => footer_start,
+            offset => offset,
+        };
+        let summary_len = summary_end
+            .checked_sub(footer.summary_start)
+            .ok_or_else(|| McapError::Invalid("bad summary offset".into()))?;
+        let summary = self.read_at(footer.summary_start, summary_len)?;
+
+        let mut attachment = None;
+        let mut chunks = Vec::new();
+        for record in LinearReader::sans_magic(&summary) {
+            match record? {
+                Record::Statistics(stats) => {
+                    self.message_count = stats.message_count;
+                    self.start_time = stats.message_start_time;
+                    self.end_time = stats.message_end_time;
+                }
+                Record::ChunkIndex(index) => chunks.push(index),
+                Record::AttachmentIndex(index) if index.name == HDDS_METADATA_ATTACHMENT => {
+                    attachment = Some((index.offset, index.length));
+                }
+                other => self.add_definition(other),
+            }
+        }
+
+        chunks.sort_by_key(|c| (c.message_start_time, c.chunk_start_offset));
+        if self.message_count == 0 {
+            self.start_time = chunks.first().map_or(u64::MAX, |c| c.message_start_time);
+            self.end_time = chunks.iter().map(|c| c.message_end_time).max().unwrap_or(0);
+        }
+        self.chunks = chunks.into();
+        Ok(attachment)
+    }
+
+    /// Read the metadata of a file exported by hdds.
+    fn read_hdds_metadata(
+        &mut self,
+        (offset, length): (u64, u64),
+    ) -> Result<Option<RecordingMetadata>, McapError> {
+        let bytes = self.read_at(offset, length)?;
+        match LinearReader::sans_magic(&bytes).next() {
+            Some(Ok(Record::Attachment { data, .. })) => Ok(serde_json::from_slice(&data).ok()),
+            _ => Ok(None),
+        }
+    }
+
+    /// Load every message of a file without chunk index.
+    fn load_whole_file(&mut self) -> Result<(), McapError> {
+        let mut data = Vec::new();
+        self.file.seek(SeekFrom::Start(0))?;
+        self.file.read_to_end(&mut data)?;
+
+        let mut messages = Vec::new();
+        let options = mcap::read::Options::IgnoreEndMagic.into();
+        for record in ChunkFlattener::new_with_options(&data, options)? {
+            match record? {
+                Record::Message { header, data } => {
+                    self.start_time = self.start_time.min(header.log_time);
+                    self.end_time = self.end_time.max(header.log_time);
+                    messages.push((header, data.into_owned()));
+                }
+                other => self.add_definition(other),
+            }
+        }
+
+        self.message_count = messages.len() as u64;
+        messages.sort_by_key(|(header, _)| header.log_time);
+        for (header, data) in messages {
+            if let Some(msg) = self.convert(&header, &data) {
+                self.pending.push_back(msg);
+            }
+        }
+        Ok(())
+    }
+
+    /// Record a schema or channel definition.
+    fn add_definition(&mut self, record: Record<'_>) {
+        match record {
+            Record::Schema { header, data } => {
+                self.schemas
+                    .insert(header.id, (header.name, header.encoding, data.into_owned()));
+            }
+            Record::Channel(channel) if !self.channels.contains_key(&channel.id) => {
+                let (schema_name, schema_encoding, schema_data) = self
+                    .schemas
+                    .get(&channel.schema_id)
+                    .cloned()
+                    .unwrap_or_default();
+                let mut info = McapChannel {
+                    id: channel.id,
+                    dds_topic: channel.topic.clone(),
+                    dds_type: schema_name.clone(),
+                    topic: channel.topic,
+                    message_encoding: channel.message_encoding,
+                    schema_name,
+                    schema_encoding,
+                    schema_data,
+                    metadata: channel.metadata,
+                };
+                if info.is_ros2() {
+                    info.dds_topic = ros2_dds_topic(&info.topic);
+                    info.dds_type = ros2_dds_type(&info.schema_name);
+                }
+                self.channels.insert(info.id, info);
+            }
+            _ => {}
+        }
+    }
+
+    /// Convert an MCAP message, or count it as skipped.
+    fn convert(&mut self, header: &MessageHeader, data: &[u8]) -> Option<Message> {
+        let payload = self
+            .channels
+            .get(&header.channel_id)
+            .filter(|channel| channel.is_cdr())
+            .and_then(|_| strip_encapsulation(data));
+        let (Some(channel), Some(payload)) = (self.channels.get(&header.channel_id), payload)
+        else {
+            self.skipped += 1;
+            return None;
+        };
+        Some(Message {
+            timestamp_nanos: header.log_time.saturating_sub(self.start_time),
+            topic_name: channel.dds_topic.clone(),
+            type_name: channel.dds_type.clone(),
+            writer_guid: String::new(),
+            sequence_number: u64::from(header.sequence),
+            payload: payload.to_vec(),
+            qos_hash: 0,
+        })
+    }
+
+    /// Metadata of a file not written by hdds.
+    fn build_metadata(&self) -> RecordingMetadata {
+        let start_time = i64::try_from(self.start_time)
+            .map(chrono::DateTime::from_timestamp_nanos)
+            .map(|t| t.to_rfc3339())
+            .unwrap_or_default();
+        RecordingMetadata {
+            start_time,
+            domain_id: 0,
+            hostname: None,
+            hdds_version: String::new(),
+            topics: self
+                .channels
+                .values()
+                .filter(|c| c.is_cdr())
+                .map(|c| {
+                    let (reliability, durability) = c.offered_qos();
+                    TopicInfo {
+                        name: c.dds_topic.clone(),
+                        type_name: c.dds_type.clone(),
+                        message_count: 0,
+                        reliability: reliability.into(),
+                        durability: durability.into(),
+                    }
+                })
+                .collect(),
+            description: Some("Imported from MCAP".into()),
+        }
+    }
+
+    fn read_at(&mut self, offset: u64, len: u64) -> Result<Vec<u8>, McapError> {
+        let len =
+            usize::try_from(len).map_err(|_| McapError::Invalid("record too large".into()))?;
+        let mut buf = vec![0u8; len];
+        self.file.seek(SeekFrom::Start(offset))?;
+        self.file.read_exact(&mut buf)?;
+        Ok(buf)
+    }
+
+    /// Decode the next chunk into `pending`.
+    fn load_next_chunk(&mut self) -> Result<bool, McapError> {
+        let Some(index) = self.chunks.pop_front() else {
+            return Ok(false);
+        };
+        let bytes = self.read_at(index.chunk_start_offset, index.chunk_length)?;
+        let (header, data) = match LinearReader::sans_magic(&bytes).next() {
+            Some(Ok(Record::Chunk { header, data })) => (header, data),
+            Some(Err(e)) => return Err(e.into()),
+            _ => return Err(McapError::Invalid("chunk index points to no chunk".into())),
+        };
+
+        let mut messages = Vec::new();
+        for record in ChunkReader::new(header, &data)? {
+            match record? {
+                Record::Message { header, data } => messages.push((header, data.into_owned())),
+                other => self.add_definition(other),
+            }
+        }
+        // Chunks are time-ordered; messages within a chunk need not be
+        messages.sort_by_key(|(header, _)| header.log_time);
+        for (header, data) in messages {
+            if let Some(msg) = self.convert(&header, &data) {
+                self.pending.push_back(msg);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Read next message.
+    pub fn read_message(&mut self) -> Result<Option<Message>, McapError> {
+        loop {
+            if let Some(msg) = self.pending.pop_front() {
+                return Ok(Some(msg));
+            }
+            if !self.load_next_chunk()? {
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Get recording metadata.
+    pub fn metadata(&self) -> &RecordingMetadata {
+        &self.metadata
+    }
+
+    /// Get the channels seen so far (all of them for indexed files).
+    pub fn channels(&self) -> impl Iterator<Item = &McapChannel> {
+        self.channels.values()
+    }
+
+    /// Get total message count (0 when the file has no statistics).
+    pub fn message_count(&self) -> u64 {
+        self.message_count
+    }
+
+    /// Get recording duration in nanoseconds.
+    pub fn duration_nanos(&self) -> u64 {
+        self.end_time.saturating_sub(self.start_time)
+    }
+
+    /// Number of messages skipped so far (non-CDR channel or unsupported
+    /// encapsulation).
+    pub fn skipped_messages(&self) -> u64 {
+        self.skipped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mcap::{Channel, Schema};
+    use std::borrow::Cow;
+    use std::io::BufWriter;
+    use std::sync::Arc;
+    use tempfile::tempdir;
+
+    const QOS_PROFILES: &str = "- history: keep_last\n  depth: 10\n  reliability: best_effort\n  durability: transient_local\n";
+
+    /// Write a rosbag2-style file: ros2msg schema, CDR messages.
+    fn write_rosbag(path: &Path, options: mcap::WriteOptions) {
+        let file = BufWriter::new(File::create(path).expect("create"));
+        let mut writer = options.profile("ros2").create(file).expect("writer");
+
+        let schema = Arc::new(Schema {
+            name: "std_msgs/msg/String".into(),
+            encoding: "ros2msg".into(),
+            data: Cow::Borrowed(b"string data"),
+        });
+        let chatter = Arc::new(Channel {
+            topic: "/chatter".into(),
+            schema: Some(schema),
+            message_encoding: "cdr".into(),
+            metadata: BTreeMap::from([("offered_qos_profiles".into(), QOS_PROFILES.into())]),
+        });
+        let json = Arc::new(Channel {
+            topic: "/diagnostics_json".into(),
+            schema: None,
+            message_encoding: "json".into(),
+            metadata: BTreeMap::new(),
+        });
+
+        let base = 1_700_000_000_000_000_000u64;
+        for i in 0..20u32 {
+            let text = format!("hello {}", i);
+            let mut data = vec![0x00, 0x01, 0x00, 0x00];
+            data.extend_from_slice(&(text.len() as u32 + 1).to_le_bytes());
+            data.extend_from_slice(text.as_bytes());
+            data.push(0);
+            let time = base + u64::from(i) * 10_000_000;
+            writer
+                .write(&mcap::Message {
+                    channel: chatter.clone(),
+                    sequence: i,
+                    log_time: time,
+                    publish_time: time,
+                    data: Cow::Owned(data),
+                })
+                .expect("write");
+            if i % 5 == 0 {
+                writer
+                    .write(&mcap::Message {
+                        channel: json.clone(),
+                        sequence: i,
+                        log_time: time,
+                        publish_time: time,
+                        data: Cow::Borrowed(b"{}"),
+                    })
+                    .expect("write");
+            }
+        }
+        writer.finish().expect("finish");
+    }
+
+    fn check_rosbag(reader: &mut McapReader) {
+        let chatter = reader
+            .channels()
+            .find(|c| c.topic == "/chatter")
+            .expect("channel")
+            .clone();
+        assert_eq!(chatter.dds_topic, "rt/chatter");
+        assert_eq!(chatter.dds_type, "std_msgs::msg::dds_::String_");
+        assert_eq!(chatter.schema_data, b"string data");
+
+        let topics = &reader.metadata().topics;
+        assert_eq!(topics.len(), 1);
+        assert_eq!(topics[0].reliability, "BEST_EFFORT");
+        assert_eq!(topics[0].durability, "TRANSIENT_LOCAL");
+        assert_eq!(reader.duration_nanos(), 190_000_000);
+
+        let mut count = 0u64;
+        while let Some(msg) = reader.read_message().expect("read") {
+            assert_eq!(msg.topic_name, "rt/chatter");
+            assert_eq!(msg.timestamp_nanos, count * 10_000_000);
+            let text = format!("hello {}", count);
+            assert_eq!(&msg.payload[4..4 + text.len()], text.as_bytes());
+            count += 1;
+        }
+        assert_eq!(count, 20);
+        assert_eq!(reader.skipped_messages(), 4);
+    }
+
+    #[test]
+    fn test_read_rosbag2_chunked() {
+        let dir = tempdir().expect("tempdir");
+        let path = dir.path().join("bag.mcap");
+        // Small chunks: several chunk indexes, compressed
+        write_rosbag(&path, mcap::WriteOptions::new().chunk_size(Some(128)));
+
+        let mut reader = McapReader::open(&path).expect("open");
+        assert!(reader.chunks.len() > 1);
+        assert_eq!(reader.message_count(), 24);
+        check_rosbag(&mut reader);
+    }
+
+    #[test]
+    fn test_read_unchunked() {
+        let dir = tempdir().expect("tempdir");
+        let path = dir.path().join("bag.mcap");
+        write_rosbag(&path, mcap::WriteOptions::new().use_chunks(false));
+
+        let mut reader = McapReader::open(&path).expect("open");
+        check_rosbag(&mut reader);
+    }
+
+    #[test]
+    fn test_hdds_export_roundtrip() {
+        use crate::format::McapExporter;
+
+        let dir = tempdir().expect("tempdir");
+        let path = dir.path().join("export.mcap");
+        let mut metadata = RecordingMetadata {
+            domain_id: 7,
+            ..Default::default()
+        };
+        metadata.topics.push(TopicInfo {
+            name: "Temperature".into(),
+            type_name: "sensor::Temperature".into(),
+            message_count: 3,
+            reliability: "RELIABLE".into(),
+            durability: "VOLATILE".into(),
+        });
+
+        let mut exporter = McapExporter::create(&path, &metadata).expect("create");
+        for i in 0..3u64 {
+            exporter
+                .write_message(&Message {
+                    timestamp_nanos: i * 1_000,
+                    topic_name: "Temperature".into(),
+                    type_name: "sensor::Temperature".into(),
+                    writer_guid: String::new(),
+                    sequence_number: i,
+                    payload: vec![i as u8; 8],
+                    qos_hash: 0,
+                })
+                .expect("write");
+        }
+        exporter.finalize().expect("finalize");
+
+        let mut reader = McapReader::open(&path).expect("open");
+        assert_eq!(reader.metadata().domain_id, 7);
+        let mut payloads = Vec::new();
+        while let Some(msg) = reader.read_message().expect("read") {
+            assert_eq!(msg.topic_name, "Temperature");
+            assert_eq!(msg.type_name, "sensor::Temperature");
+            payloads.push(msg.payload);
+        }
+        assert_eq!(payloads, vec![vec![0; 8], vec![1; 8], vec![2; 8]]);
+    }
+
+    #[test]
+    fn test_name_mapping() {
+        assert_eq!(ros2_dds_topic("/tf"), "rt/tf");
+        assert_eq!(ros2_dds_topic("robot/odom"), "rt/robot/odom");
+        assert_eq!(
+            ros2_dds_type("nav_msgs/msg/Odometry"),
+            "nav_msgs::msg::dds_::Odometry_"
+        );
+        assert_eq!(
+            ros2_dds_type("std_msgs/Header"),
+            "std_msgs::msg::dds_::Header_"
+        );
+        assert_eq!(strip_encapsulation(&[0, 1, 0, 0, 42]), Some(&[42u8][..]));
+        // Big-endian CDR cannot be relabelled
+        assert_eq!(strip_encapsulation(&[0, 0, 0, 0, 42]), None);
+        assert_eq!(yaml_value(QOS_PROFILES, "depth"), Some("10"));
+    }
+}
@@ -5,12 +5,14 @@
 //!
 //! Supports:
 //! - Native `.hdds` format (default)
-//! - MCAP export (optional feature)
+//! - MCAP export and import (optional feature)
 
 pub mod hdds;
 
 #[cfg(feature = "mcap")]
 mod mcap_export;
+#[cfg(feature = "mcap")]
+mod mcap_import;
 
 pub use hdds::{
     FileHeader, FormatError, HddsFormat, HddsReader, HddsWriter, IndexEntry, SegmentHeader,
@@ -19,6 +21,8 @@ pub use hdds::{
 
 #[cfg(feature = "mcap")]
 pub use mcap_export::{convert_hdds_to_mcap, McapError, McapExporter};
+#[cfg(feature = "mcap")]
+pub use mcap_import::{McapChannel, McapReader};
 
 use serde::{Deserialize, Serialize};
 
@@ -5,7 +5,9 @@
 //!
 //! Record and replay DDS messages with support for:
 //! - Native `.hdds` format (efficient, self-contained)
-//! - MCAP export (industry standard, Foxglove compatible)
+//! - MCAP export and playback (industry standard, Foxglove and rosbag2
+//!   compatible)
+//! - Per-topic rate/size quotas and a global disk budget
 //!
 //! # Quick Start
 //!
@@ -16,10 +18,30 @@
 //! # Replay at 2x speed
 //! hdds-replay --input capture.hdds --speed 2.0
 //!
+//! # Replay seconds 30-90 three times under replay/ next to the live system
+//! hdds-replay --input capture.hdds --start 30 --end 90 --loop-count 3 --topic-prefix replay/
+//!
 //! # Convert to MCAP (if feature enabled)
 //! hdds-record --domain 0 --output capture.mcap --format mcap
+//!
+//! # Replay a rosbag2 MCAP bag into domain 0 (if feature enabled)
+//! hdds-replay --input rosbag2_2026_01_01/rosbag2_2026_01_01_0.mcap
 //! ```
 //!
+//! # MCAP Playback
+//!
+//! With the `mcap` feature, [`Player`] also reads `.mcap` files. CDR
+//! channels are replayed; ROS 2 channels get their DDS names (`/odom` ->
+//! `rt/odom`, `nav_msgs/msg/Odometry` -> `nav_msgs::msg::dds_::Odometry_`)
+//! and the reliability and durability rosbag2 recorded.
+//!
+//! # Remote Control
+//!
+//! A running `hdds-record` serves the `hdds/record/control` topic: other
+//! nodes start, stop, pause and split recordings, change the topic filter
+//! and read the recorder status with [`RecorderClient`]. In-process, the
+//! same operations are available on [`RecorderHandle`].
+//!
 //! # Format Comparison
 //!
 //! | Feature | .hdds | .mcap |
@@ -31,18 +53,26 @@
 //! | ROS2 compatible | [X] | [OK] |
 //! | Minimal deps | [OK] | [X] |
 
+pub mod control;
 pub mod filter;
 pub mod format;
 pub mod player;
+pub mod quota;
 pub mod recorder;
+pub mod remap;
 pub mod rotation;
 
+pub use control::{
+    ControlCommand, ControlError, ControlService, RecorderClient, RecorderHandle, RecorderStatus,
+};
 pub use filter::{TopicFilter, TypeFilter};
 pub use format::{HddsFormat, Message, RecordingMetadata};
 pub use player::{PlaybackSpeed, Player, PlayerConfig};
-pub use recorder::{Recorder, RecorderConfig};
+pub use quota::{BudgetPolicy, DiskBudget, QuotaStats, TopicQuota};
+pub use recorder::{Recorder, RecorderConfig, RecorderState};
+pub use remap::TopicRemap;
 pub use rotation::{RotationPolicy, RotationTrigger};
 
 // MCAP support (requires "mcap" feature)
 #[cfg(feature = "mcap")]
-pub use format::{convert_hdds_to_mcap, McapError, McapExporter};
+pub use format::{convert_hdds_to_mcap, McapChannel, McapError, McapExporter, McapReader};
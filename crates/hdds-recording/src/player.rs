@@ -3,10 +3,18 @@
 
 //! DDS message replay/playback.
 //!
-//! Reads recorded messages and publishes them with timing control.
+//! Reads recorded messages and publishes them with timing control, optionally
+//! clipped to a time range, looped and under remapped topic names.
+//!
+//! Plays `.hdds` recordings, and `.mcap` files (rosbag2, Foxglove) with the
+//! `mcap` feature.
 
 use crate::filter::TopicFilter;
-use crate::format::{HddsReader, Message};
+#[cfg(feature = "mcap")]
+use crate::format::McapReader;
+use crate::format::{HddsReader, Message, OutputFormat, RecordingMetadata};
+use crate::remap::TopicRemap;
+use std::borrow::Cow;
 use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 use thiserror::Error;
@@ -58,12 +66,18 @@ pub struct PlayerConfig {
     /// Playback speed.
     pub speed: PlaybackSpeed,
 
-    /// Topic filter (None = all topics).
+    /// Topic filter (None = all topics), applied to the recorded names.
     pub topic_filter: Option<TopicFilter>,
 
+    /// Topic renaming (None = publish on the recorded names).
+    pub topic_remap: Option<TopicRemap>,
+
     /// Loop playback.
     pub loop_playback: bool,
 
+    /// Number of passes when looping (0 = indefinitely).
+    pub loop_count: u32,
+
     /// Start offset (skip first N nanoseconds).
     pub start_offset_nanos: u64,
 
@@ -78,7 +92,9 @@ impl PlayerConfig {
             input_path: input_path.as_ref().to_path_buf(),
             speed: PlaybackSpeed::Realtime,
             topic_filter: None,
+            topic_remap: None,
             loop_playback: false,
+            loop_count: 0,
             start_offset_nanos: 0,
             end_time_nanos: 0,
         }
@@ -108,12 +124,25 @@ impl PlayerConfig {
         self
     }
 
+    /// Set topic remapping.
+    pub fn topic_remap(mut self, remap: TopicRemap) -> Self {
+        self.topic_remap = Some(remap);
+        self
+    }
+
     /// Enable loop playback.
     pub fn loop_playback(mut self, enable: bool) -> Self {
         self.loop_playback = enable;
         self
     }
 
+    /// Loop `count` times in total (0 = indefinitely); enables looping.
+    pub fn loop_count(mut self, count: u32) -> Self {
+        self.loop_playback = true;
+        self.loop_count = count;
+        self
+    }
+
     /// Set start offset.
     pub fn start_offset(mut self, offset: Duration) -> Self {
         self.start_offset_nanos = offset.as_nanos() as u64;
@@ -125,6 +154,19 @@ impl PlayerConfig {
         self.end_time_nanos = end.as_nanos() as u64;
         self
     }
+
+    /// Play only `start..=end`, both offsets from the recording start.
+    pub fn time_range(self, start: Duration, end: Duration) -> Self {
+        self.start_offset(start).end_time(end)
+    }
+
+    /// Name a recorded topic is published under.
+    pub fn remapped_topic<'a>(&self, topic: &'a str) -> Cow<'a, str> {
+        match &self.topic_remap {
+            Some(remap) => remap.apply(topic),
+            None => Cow::Borrowed(topic),
+        }
+    }
 }
 
 /// Player errors.
@@ -136,6 +178,13 @@ pub enum PlayerError {
     #[error("Format error: {0}")]
     Format(#[from] crate::format::FormatError),
 
+    #[cfg(feature = "mcap")]
+    #[error("MCAP error: {0}")]
+    Mcap(#[from] crate::format::McapError),
+
+    #[error("Unsupported format: {0}")]
+    UnsupportedFormat(String),
+
     #[error("File not found: {0}")]
     FileNotFound(PathBuf),
 
@@ -165,10 +214,64 @@ pub struct PlaybackStats {
     pub loops_completed: u32,
 }
 
+/// Recording being played.
+enum Source {
+    Hdds(HddsReader),
+    #[cfg(feature = "mcap")]
+    Mcap(McapReader),
+}
+
+impl Source {
+    /// Open `path`, picking the format from its extension.
+    fn open(path: &Path) -> Result<Self, PlayerError> {
+        match OutputFormat::from_extension(path) {
+            #[cfg(feature = "mcap")]
+            Some(OutputFormat::Mcap) => Ok(Self::Mcap(McapReader::open(path)?)),
+            #[cfg(not(feature = "mcap"))]
+            Some(OutputFormat::Mcap) => Err(PlayerError::UnsupportedFormat(
+                "MCAP playback requires the `mcap` feature".into(),
+            )),
+            _ => Ok(Self::Hdds(HddsReader::open(path)?)),
+        }
+    }
+
+    fn read_message(&mut self) -> Result<Option<Message>, PlayerError> {
+        match self {
+            Self::Hdds(reader) => Ok(reader.read_message()?),
+            #[cfg(feature = "mcap")]
+            Self::Mcap(reader) => Ok(reader.read_message()?),
+        }
+    }
+
+    fn metadata(&self) -> &RecordingMetadata {
+        match self {
+            Self::Hdds(reader) => reader.metadata(),
+            #[cfg(feature = "mcap")]
+            Self::Mcap(reader) => reader.metadata(),
+        }
+    }
+
+    fn message_count(&self) -> u64 {
+        match self {
+            Self::Hdds(reader) => reader.message_count(),
+            #[cfg(feature = "mcap")]
+            Self::Mcap(reader) => reader.message_count(),
+        }
+    }
+
+    fn duration_nanos(&self) -> u64 {
+        match self {
+            Self::Hdds(reader) => reader.duration_nanos(),
+            #[cfg(feature = "mcap")]
+            Self::Mcap(reader) => reader.duration_nanos(),
+        }
+    }
+}
+
 /// DDS message player.
 pub struct Player {
     config: PlayerConfig,
-    reader: Option<HddsReader>,
+    reader: Option<Source>,
     last_timestamp: u64,
     playback_start: Option<Instant>,
     stats: PlaybackStats,
@@ -194,11 +297,12 @@ impl Player {
             return Err(PlayerError::FileNotFound(self.config.input_path.clone()));
         }
 
-        let reader = HddsReader::open(&self.config.input_path)?;
+        let reader = Source::open(&self.config.input_path)?;
 
         self.stats.recording_duration_secs = reader.duration_nanos() as f64 / 1_000_000_000.0;
         self.reader = Some(reader);
-        self.last_timestamp = 0;
+        // Clipped playback starts right away, not after the skipped range
+        self.last_timestamp = self.config.start_offset_nanos;
         self.playback_start = Some(Instant::now());
 
         tracing::info!(
@@ -238,10 +342,11 @@ impl Player {
                         && msg.timestamp_nanos > self.config.end_time_nanos
                     {
                         // End time reached
-                        if self.config.loop_playback {
+                        if self.should_loop() {
                             self.restart()?;
                             continue;
                         }
+                        self.finish();
                         return Ok(None);
                     }
 
@@ -264,36 +369,51 @@ impl Player {
                     self.last_timestamp = msg.timestamp_nanos;
                     self.stats.messages_played += 1;
 
+                    let mut msg = msg;
+                    if let Some(ref remap) = self.config.topic_remap {
+                        if let Cow::Owned(name) = remap.apply(&msg.topic_name) {
+                            msg.topic_name = name;
+                        }
+                    }
                     return Ok(Some(msg));
                 }
                 None => {
                     // End of file
-                    if self.config.loop_playback {
+                    if self.should_loop() {
                         self.restart()?;
                         continue;
                     }
-
-                    // Update final stats
-                    if let Some(start) = self.playback_start {
-                        self.stats.duration_secs = start.elapsed().as_secs_f64();
-                        if self.stats.duration_secs > 0.0 {
-                            self.stats.messages_per_second =
-                                self.stats.messages_played as f64 / self.stats.duration_secs;
-                        }
-                    }
-
+                    self.finish();
                     return Ok(None);
                 }
             }
         }
     }
 
+    /// Check if another pass should follow the current one.
+    fn should_loop(&self) -> bool {
+        self.config.loop_playback
+            && (self.config.loop_count == 0
+                || self.stats.loops_completed + 1 < self.config.loop_count)
+    }
+
+    /// Update final stats.
+    fn finish(&mut self) {
+        if let Some(start) = self.playback_start {
+            self.stats.duration_secs = start.elapsed().as_secs_f64();
+            if self.stats.duration_secs > 0.0 {
+                self.stats.messages_per_second =
+                    self.stats.messages_played as f64 / self.stats.duration_secs;
+            }
+        }
+    }
+
     /// Restart playback from beginning.
     fn restart(&mut self) -> Result<(), PlayerError> {
         self.reader = None;
-        let reader = HddsReader::open(&self.config.input_path)?;
+        let reader = Source::open(&self.config.input_path)?;
         self.reader = Some(reader);
-        self.last_timestamp = 0;
+        self.last_timestamp = self.config.start_offset_nanos;
         self.stats.loops_completed += 1;
 
         tracing::debug!("Restarting playback (loop {})", self.stats.loops_completed);
@@ -317,7 +437,7 @@ impl Player {
     }
 
     /// Get recording metadata.
-    pub fn metadata(&self) -> Option<&crate::format::RecordingMetadata> {
+    pub fn metadata(&self) -> Option<&RecordingMetadata> {
         self.reader.as_ref().map(|r| r.metadata())
     }
 
@@ -461,6 +581,97 @@ mod tests {
         assert_eq!(player.stats().messages_skipped, 5);
     }
 
+    #[test]
+    fn test_player_remap() {
+        let dir = tempdir().expect("tempdir");
+        let path = dir.path().join("test.hdds");
+
+        create_test_recording(&path, 3);
+
+        let config = PlayerConfig::new(&path)
+            .speed(PlaybackSpeed::Unlimited)
+            .topic_filter(TopicFilter::include(vec!["TestTopic".into()]))
+            .topic_remap(TopicRemap::new().prefix("replay/"));
+        assert_eq!(config.remapped_topic("TestTopic"), "replay/TestTopic");
+
+        let mut player = Player::new(config);
+        player.open().expect("open");
+
+        let mut count = 0;
+        while let Some(msg) = player.next_message().expect("next") {
+            assert_eq!(msg.topic_name, "replay/TestTopic");
+            count += 1;
+        }
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn test_player_time_range_and_loop_count() {
+        let dir = tempdir().expect("tempdir");
+        let path = dir.path().join("test.hdds");
+
+        // Timestamps 0, 1, ..., 9 ms
+        create_test_recording(&path, 10);
+
+        let config = PlayerConfig::new(&path)
+            .speed(PlaybackSpeed::Realtime)
+            .time_range(Duration::from_millis(3), Duration::from_millis(5))
+            .loop_count(2);
+
+        let mut player = Player::new(config);
+        player.open().expect("open");
+
+        let begin = Instant::now();
+        let mut sequences = Vec::new();
+        while let Some(msg) = player.next_message().expect("next") {
+            sequences.push(msg.sequence_number);
+        }
+
+        assert_eq!(sequences, vec![3, 4, 5, 3, 4, 5]);
+        assert_eq!(player.stats().loops_completed, 1);
+        // The clipped-out 3 ms are not waited for
+        assert!(begin.elapsed() < Duration::from_millis(500));
+    }
+
+    #[cfg(feature = "mcap")]
+    #[test]
+    fn test_player_mcap() {
+        let dir = tempdir().expect("tempdir");
+        let hdds_path = dir.path().join("test.hdds");
+        let mcap_path = dir.path().join("test.mcap");
+
+        create_test_recording(&hdds_path, 10);
+        crate::format::convert_hdds_to_mcap(&hdds_path, &mcap_path).expect("convert");
+
+        let config = PlayerConfig::new(&mcap_path)
+            .speed(PlaybackSpeed::Unlimited)
+            .start_offset(Duration::from_millis(2));
+        let mut player = Player::new(config);
+        player.open().expect("open");
+        assert_eq!(player.total_messages(), 10);
+
+        let mut payloads = Vec::new();
+        while let Some(msg) = player.next_message().expect("next") {
+            assert_eq!(msg.topic_name, "TestTopic");
+            payloads.push(msg.payload[0]);
+        }
+        assert_eq!(payloads, (2..10).collect::<Vec<u8>>());
+    }
+
+    #[cfg(not(feature = "mcap"))]
+    #[test]
+    fn test_player_mcap_requires_feature() {
+        let dir = tempdir().expect("tempdir");
+        let path = dir.path().join("test.mcap");
+        std::fs::write(&path, b"").expect("write");
+
+        let mut player = Player::new(PlayerConfig::new(&path));
+        assert!(matches!(
+            player.open(),
+            Err(PlayerError::UnsupportedFormat(_))
+        ));
+    }
+
     #[test]
     fn test_player_cancel() {
         let dir = tempdir().expect("tempdir");
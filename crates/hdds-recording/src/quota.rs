@@ -0,0 +1,342 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Bandwidth and storage budgets for recording.
+//!
+//! Supports:
+//! - Per-topic quotas: message rate, bandwidth, maximum message size and
+//!   total bytes recorded for the topic
+//! - A global disk budget across the current and rotated files, enforced by
+//!   deleting the oldest rotated files or by stopping the recording
+//!
+//! Rates are measured over fixed one-second windows.
+
+use crate::filter::TopicFilter;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Per-topic recording quota. Unset limits are unlimited.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TopicQuota {
+    /// Maximum messages recorded per second.
+    pub max_messages_per_sec: Option<u64>,
+
+    /// Maximum bytes recorded per second.
+    pub max_bytes_per_sec: Option<u64>,
+
+    /// Maximum size of a single message in bytes.
+    pub max_message_bytes: Option<u64>,
+
+    /// Maximum bytes recorded for the topic over the whole recording.
+    pub max_total_bytes: Option<u64>,
+}
+
+impl TopicQuota {
+    /// Create a quota with no limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit the message rate.
+    pub fn messages_per_sec(mut self, max: u64) -> Self {
+        self.max_messages_per_sec = Some(max);
+        self
+    }
+
+    /// Limit the bandwidth in bytes per second.
+    pub fn bytes_per_sec(mut self, max: u64) -> Self {
+        self.max_bytes_per_sec = Some(max);
+        self
+    }
+
+    /// Drop messages larger than `max` bytes.
+    pub fn max_message_bytes(mut self, max: u64) -> Self {
+        self.max_message_bytes = Some(max);
+        self
+    }
+
+    /// Stop recording the topic after `max` bytes.
+    pub fn max_total_bytes(mut self, max: u64) -> Self {
+        self.max_total_bytes = Some(max);
+        self
+    }
+}
+
+/// Action taken when the disk budget is exhausted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BudgetPolicy {
+    /// Delete the oldest rotated files to make room (ring buffer).
+    DropOldest,
+
+    /// Keep what was recorded and drop every further message.
+    Stop,
+}
+
+/// Global disk budget across all files of a recording.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiskBudget {
+    /// Maximum bytes on disk.
+    pub max_bytes: u64,
+
+    /// Action when the budget is exhausted.
+    pub policy: BudgetPolicy,
+}
+
+impl DiskBudget {
+    /// Budget that deletes the oldest files once full.
+    ///
+    /// # Arguments
+    /// * `max_size_mb` - Maximum total size in megabytes.
+    pub fn drop_oldest(max_size_mb: u64) -> Self {
+        Self {
+            max_bytes: max_size_mb * 1024 * 1024,
+            policy: BudgetPolicy::DropOldest,
+        }
+    }
+
+    /// Budget that stops recording once full.
+    ///
+    /// # Arguments
+    /// * `max_size_mb` - Maximum total size in megabytes.
+    pub fn stop(max_size_mb: u64) -> Self {
+        Self {
+            max_bytes: max_size_mb * 1024 * 1024,
+            policy: BudgetPolicy::Stop,
+        }
+    }
+}
+
+/// Reason a message was not recorded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuotaDrop {
+    /// Topic message rate exceeded.
+    Rate,
+
+    /// Topic bandwidth exceeded.
+    Bandwidth,
+
+    /// Message larger than the topic's maximum size.
+    Oversize,
+
+    /// Topic total byte quota used up.
+    TopicBudget,
+
+    /// Global disk budget exhausted.
+    DiskBudget,
+}
+
+/// Quota and budget counters.
+#[derive(Debug, Clone, Default)]
+pub struct QuotaStats {
+    /// Messages dropped by a topic rate limit.
+    pub dropped_rate: u64,
+
+    /// Messages dropped by a topic bandwidth limit.
+    pub dropped_bandwidth: u64,
+
+    /// Messages dropped for exceeding the maximum message size.
+    pub dropped_oversize: u64,
+
+    /// Messages dropped after a topic used up its total quota.
+    pub dropped_topic_budget: u64,
+
+    /// Messages dropped by the disk budget.
+    pub dropped_disk_budget: u64,
+
+    /// Dropped messages per topic.
+    pub dropped_by_topic: HashMap<String, u64>,
+
+    /// Rotated files deleted by the drop-oldest policy.
+    pub files_deleted: u32,
+
+    /// Bytes freed by deleting files.
+    pub bytes_deleted: u64,
+
+    /// Set once a stop policy has ended the recording.
+    pub budget_exhausted: bool,
+}
+
+impl QuotaStats {
+    /// Total messages dropped for any quota reason.
+    pub fn total_dropped(&self) -> u64 {
+        self.dropped_rate
+            + self.dropped_bandwidth
+            + self.dropped_oversize
+            + self.dropped_topic_budget
+            + self.dropped_disk_budget
+    }
+
+    pub(crate) fn record_drop(&mut self, topic: &str, reason: QuotaDrop) {
+        match reason {
+            QuotaDrop::Rate => self.dropped_rate += 1,
+            QuotaDrop::Bandwidth => self.dropped_bandwidth += 1,
+            QuotaDrop::Oversize => self.dropped_oversize += 1,
+            QuotaDrop::TopicBudget => self.dropped_topic_budget += 1,
+            QuotaDrop::DiskBudget => self.dropped_disk_budget += 1,
+        }
+        *self.dropped_by_topic.entry(topic.to_string()).or_insert(0) += 1;
+    }
+}
+
+/// Usage of one topic.
+#[derive(Debug)]
+struct TopicUsage {
+    window_start: Instant,
+    window_messages: u64,
+    window_bytes: u64,
+    total_bytes: u64,
+}
+
+/// Per-topic quota enforcement.
+#[derive(Debug, Default)]
+pub(crate) struct QuotaTracker {
+    /// (topic pattern, quota); the first matching pattern applies.
+    quotas: Vec<(String, TopicQuota)>,
+    usage: HashMap<String, TopicUsage>,
+}
+
+impl QuotaTracker {
+    pub(crate) fn new(quotas: Vec<(String, TopicQuota)>) -> Self {
+        Self {
+            quotas,
+            usage: HashMap::new(),
+        }
+    }
+
+    fn quota_for(&self, topic: &str) -> Option<&TopicQuota> {
+        self.quotas
+            .iter()
+            .find(|(pattern, _)| TopicFilter::pattern_match(pattern, topic))
+            .map(|(_, quota)| quota)
+    }
+
+    /// Check whether a message of `size` bytes fits the topic's quota.
+    pub(crate) fn check(&mut self, topic: &str, size: u64, now: Instant) -> Result<(), QuotaDrop> {
+        let Some(quota) = self.quota_for(topic).cloned() else {
+            return Ok(());
+        };
+
+        if quota.max_message_bytes.is_some_and(|max| size > max) {
+            return Err(QuotaDrop::Oversize);
+        }
+
+        let usage = self.usage_mut(topic, now);
+        if now.duration_since(usage.window_start) >= Duration::from_secs(1) {
+            usage.window_start = now;
+            usage.window_messages = 0;
+            usage.window_bytes = 0;
+        }
+
+        if quota
+            .max_total_bytes
+            .is_some_and(|max| usage.total_bytes + size > max)
+        {
+            return Err(QuotaDrop::TopicBudget);
+        }
+        if quota
+            .max_messages_per_sec
+            .is_some_and(|max| usage.window_messages >= max)
+        {
+            return Err(QuotaDrop::Rate);
+        }
+        if quota
+            .max_bytes_per_sec
+            .is_some_and(|max| usage.window_bytes + size > max)
+        {
+            return Err(QuotaDrop::Bandwidth);
+        }
+        Ok(())
+    }
+
+    /// Account a recorded message.
+    pub(crate) fn commit(&mut self, topic: &str, size: u64, now: Instant) {
+        if self.quota_for(topic).is_none() {
+            return;
+        }
+        let usage = self.usage_mut(topic, now);
+        usage.window_messages += 1;
+        usage.window_bytes += size;
+        usage.total_bytes += size;
+    }
+
+    fn usage_mut(&mut self, topic: &str, now: Instant) -> &mut TopicUsage {
+        self.usage
+            .entry(topic.to_string())
+            .or_insert_with(|| TopicUsage {
+                window_start: now,
+                window_messages: 0,
+                window_bytes: 0,
+                total_bytes: 0,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn admit(tracker: &mut QuotaTracker, topic: &str, size: u64, now: Instant) -> bool {
+        let ok = tracker.check(topic, size, now).is_ok();
+        if ok {
+            tracker.commit(topic, size, now);
+        }
+        ok
+    }
+
+    #[test]
+    fn test_rate_limit_resets_each_second() {
+        let mut tracker = QuotaTracker::new(vec![(
+            "debug/*".into(),
+            TopicQuota::new().messages_per_sec(2),
+        )]);
+        let t0 = Instant::now();
+
+        assert!(admit(&mut tracker, "debug/trace", 10, t0));
+        assert!(admit(&mut tracker, "debug/trace", 10, t0));
+        assert_eq!(tracker.check("debug/trace", 10, t0), Err(QuotaDrop::Rate));
+        // Other topics are unaffected.
+        assert!(admit(&mut tracker, "pose", 10, t0));
+
+        let t1 = t0 + Duration::from_secs(1);
+        assert!(admit(&mut tracker, "debug/trace", 10, t1));
+    }
+
+    #[test]
+    fn test_bandwidth_size_and_total_limits() {
+        let mut tracker = QuotaTracker::new(vec![(
+            "camera".into(),
+            TopicQuota::new()
+                .bytes_per_sec(100)
+                .max_message_bytes(80)
+                .max_total_bytes(150),
+        )]);
+        let t0 = Instant::now();
+
+        assert_eq!(tracker.check("camera", 81, t0), Err(QuotaDrop::Oversize));
+        assert!(admit(&mut tracker, "camera", 60, t0));
+        assert_eq!(tracker.check("camera", 60, t0), Err(QuotaDrop::Bandwidth));
+
+        let t1 = t0 + Duration::from_secs(1);
+        assert!(admit(&mut tracker, "camera", 60, t1));
+        let t2 = t1 + Duration::from_secs(1);
+        assert_eq!(tracker.check("camera", 60, t2), Err(QuotaDrop::TopicBudget));
+    }
+
+    #[test]
+    fn test_quota_stats_counters() {
+        let mut stats = QuotaStats::default();
+        stats.record_drop("a", QuotaDrop::Rate);
+        stats.record_drop("a", QuotaDrop::DiskBudget);
+        stats.record_drop("b", QuotaDrop::Oversize);
+
+        assert_eq!(stats.total_dropped(), 3);
+        assert_eq!(stats.dropped_by_topic.get("a"), Some(&2));
+        assert_eq!(stats.dropped_disk_budget, 1);
+    }
+
+    #[test]
+    fn test_disk_budget_constructors() {
+        assert_eq!(DiskBudget::drop_oldest(2).max_bytes, 2 * 1024 * 1024);
+        assert_eq!(DiskBudget::stop(1).policy, BudgetPolicy::Stop);
+    }
+}
@@ -7,7 +7,9 @@
 
 use crate::filter::{TopicFilter, TypeFilter};
 use crate::format::{HddsFormat, HddsWriter, Message, OutputFormat, RecordingMetadata};
+use crate::quota::{BudgetPolicy, DiskBudget, QuotaDrop, QuotaStats, QuotaTracker, TopicQuota};
 use crate::rotation::{RotationPolicy, RotationTrigger};
+use std::collections::VecDeque;
 use std::path::{Path, PathBuf};
 use std::time::Instant;
 use thiserror::Error;
@@ -35,6 +37,12 @@ pub struct RecorderConfig {
 
     /// Optional description for metadata.
     pub description: Option<String>,
+
+    /// Per-topic quotas as (topic pattern, quota); first match applies.
+    pub topic_quotas: Vec<(String, TopicQuota)>,
+
+    /// Global disk budget (None = unlimited).
+    pub disk_budget: Option<DiskBudget>,
 }
 
 impl RecorderConfig {
@@ -51,6 +59,8 @@ impl RecorderConfig {
             type_filter: None,
             rotation: None,
             description: None,
+            topic_quotas: Vec::new(),
+            disk_budget: None,
         }
     }
 
@@ -83,8 +93,26 @@ impl RecorderConfig {
         self.description = Some(desc.into());
         self
     }
+
+    /// Add a quota for topics matching `pattern` (supports wildcards).
+    ///
+    /// Patterns are checked in insertion order; list specific topics
+    /// before broad wildcards.
+    pub fn topic_quota(mut self, pattern: impl Into<String>, quota: TopicQuota) -> Self {
+        self.topic_quotas.push((pattern.into(), quota));
+        self
+    }
+
+    /// Set the global disk budget.
+    pub fn disk_budget(mut self, budget: DiskBudget) -> Self {
+        self.disk_budget = Some(budget);
+        self
+    }
 }
 
+/// Estimated per-message framing overhead in the output file.
+const MESSAGE_OVERHEAD: u64 = 50;
+
 /// Recorder errors.
 #[derive(Debug, Error)]
 pub enum RecorderError {
@@ -104,6 +132,17 @@ pub enum RecorderError {
     AlreadyRecording,
 }
 
+/// Recorder state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecorderState {
+    /// Not recording (before `start` or after `stop`).
+    Stopped,
+    /// Writing messages.
+    Recording,
+    /// File open, incoming messages discarded.
+    Paused,
+}
+
 /// Recording statistics.
 #[derive(Debug, Clone, Default)]
 pub struct RecordingStats {
@@ -124,6 +163,9 @@ pub struct RecordingStats {
 
     /// Current file index (for rotation).
     pub file_index: u32,
+
+    /// Quota and disk budget counters.
+    pub quota: QuotaStats,
 }
 
 /// DDS message recorder.
@@ -133,17 +175,34 @@ pub struct Recorder {
     start_time: Option<Instant>,
     start_nanos: u64,
     stats: RecordingStats,
+    quotas: QuotaTracker,
+    /// File currently being written.
+    current_path: PathBuf,
+    /// Finalized files still on disk, oldest first, with their size.
+    closed_files: VecDeque<(PathBuf, u64)>,
+    closed_bytes: u64,
+    paused: bool,
+    /// Start of the recording (not reset by rotation).
+    started_at: Option<Instant>,
 }
 
 impl Recorder {
     /// Create a new recorder.
     pub fn new(config: RecorderConfig) -> Self {
+        let quotas = QuotaTracker::new(config.topic_quotas.clone());
+        let current_path = config.output_path.clone();
         Self {
             config,
             writer: None,
             start_time: None,
             start_nanos: 0,
             stats: RecordingStats::default(),
+            quotas,
+            current_path,
+            closed_files: VecDeque::new(),
+            closed_bytes: 0,
+            paused: false,
+            started_at: None,
         }
     }
 
@@ -161,7 +220,13 @@ impl Recorder {
 
         let writer = HddsWriter::create(&self.config.output_path, metadata)?;
         self.writer = Some(writer);
+        self.current_path = self.config.output_path.clone();
+        self.closed_files.clear();
+        self.closed_bytes = 0;
+        self.quotas = QuotaTracker::new(self.config.topic_quotas.clone());
         self.start_time = Some(Instant::now());
+        self.started_at = self.start_time;
+        self.paused = false;
         self.start_nanos = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .map(|d| d.as_nanos() as u64)
@@ -179,13 +244,10 @@ impl Recorder {
         writer.finalize()?;
 
         // Update final stats
-        if let Some(start) = self.start_time.take() {
-            self.stats.duration_secs = start.elapsed().as_secs_f64();
-            if self.stats.duration_secs > 0.0 {
-                self.stats.messages_per_second =
-                    self.stats.message_count as f64 / self.stats.duration_secs;
-            }
-        }
+        self.stats = self.live_stats();
+        self.start_time = None;
+        self.started_at = None;
+        self.paused = false;
 
         tracing::info!(
             "Stopped recording: {} messages, {:.1}s",
@@ -196,8 +258,67 @@ impl Recorder {
         Ok(self.stats.clone())
     }
 
+    /// Stop writing incoming messages, keeping the file open.
+    pub fn pause(&mut self) -> Result<(), RecorderError> {
+        if self.writer.is_none() {
+            return Err(RecorderError::NotRecording);
+        }
+        if !self.paused {
+            self.paused = true;
+            tracing::info!("Recording paused");
+        }
+        Ok(())
+    }
+
+    /// Resume writing after [`pause`](Self::pause).
+    pub fn resume(&mut self) -> Result<(), RecorderError> {
+        if self.writer.is_none() {
+            return Err(RecorderError::NotRecording);
+        }
+        if self.paused {
+            self.paused = false;
+            tracing::info!("Recording resumed");
+        }
+        Ok(())
+    }
+
+    /// Close the current file and continue in the next one.
+    ///
+    /// The new file is named like a rotated one (`<stem>_0001.hdds`).
+    pub fn split(&mut self) -> Result<PathBuf, RecorderError> {
+        if self.writer.is_none() {
+            return Err(RecorderError::NotRecording);
+        }
+        self.rotate()?;
+        Ok(self.current_path.clone())
+    }
+
+    /// Replace the topic filter (None = all topics); applies to the next message.
+    pub fn set_topic_filter(&mut self, filter: Option<TopicFilter>) {
+        self.config.topic_filter = filter;
+    }
+
+    /// Replace the type filter (None = all types); applies to the next message.
+    pub fn set_type_filter(&mut self, filter: Option<TypeFilter>) {
+        self.config.type_filter = filter;
+    }
+
+    /// Set the output path of the next recording.
+    pub fn set_output_path<P: AsRef<Path>>(&mut self, path: P) -> Result<(), RecorderError> {
+        if self.writer.is_some() {
+            return Err(RecorderError::AlreadyRecording);
+        }
+        self.config.output_path = path.as_ref().to_path_buf();
+        self.current_path = self.config.output_path.clone();
+        Ok(())
+    }
+
     /// Record a message.
     pub fn record(&mut self, msg: Message) -> Result<(), RecorderError> {
+        if self.paused {
+            return Ok(());
+        }
+
         // Apply filters
         if let Some(ref filter) = self.config.topic_filter {
             if !filter.matches(&msg.topic_name) {
@@ -211,6 +332,18 @@ impl Recorder {
             }
         }
 
+        if self.writer.is_none() {
+            return Err(RecorderError::NotRecording);
+        }
+
+        // Apply per-topic quota
+        let size = msg.payload.len() as u64 + MESSAGE_OVERHEAD;
+        let now = Instant::now();
+        if let Err(reason) = self.quotas.check(&msg.topic_name, size, now) {
+            self.stats.quota.record_drop(&msg.topic_name, reason);
+            return Ok(());
+        }
+
         // Check rotation
         if let Some(ref policy) = self.config.rotation {
             if self.should_rotate(policy) {
@@ -218,17 +351,79 @@ impl Recorder {
             }
         }
 
+        // Apply disk budget
+        if !self.reserve_disk_budget(size)? {
+            self.stats
+                .quota
+                .record_drop(&msg.topic_name, QuotaDrop::DiskBudget);
+            return Ok(());
+        }
+
         // Write message
         let writer = self.writer.as_mut().ok_or(RecorderError::NotRecording)?;
         writer.write_message(&msg)?;
 
         // Update stats
+        self.quotas.commit(&msg.topic_name, size, now);
         self.stats.message_count += 1;
-        self.stats.bytes_written += msg.payload.len() as u64 + 50; // ~50 bytes overhead
+        self.stats.bytes_written += size;
 
         Ok(())
     }
 
+    /// Make room for `size` bytes under the disk budget.
+    ///
+    /// Returns `false` if the message must be dropped.
+    fn reserve_disk_budget(&mut self, size: u64) -> Result<bool, RecorderError> {
+        let Some(budget) = self.config.disk_budget else {
+            return Ok(true);
+        };
+        if self.stats.quota.budget_exhausted {
+            return Ok(false);
+        }
+        if self.disk_usage() + size <= budget.max_bytes {
+            return Ok(true);
+        }
+
+        match budget.policy {
+            BudgetPolicy::Stop => {
+                self.stats.quota.budget_exhausted = true;
+                tracing::warn!(
+                    "Disk budget of {} bytes exhausted, recording stopped",
+                    budget.max_bytes
+                );
+                Ok(false)
+            }
+            BudgetPolicy::DropOldest => {
+                // Only finalized files can be deleted: close the current one
+                // if it is the only thing on disk.
+                if self.closed_files.is_empty() && self.stats.bytes_written > 0 {
+                    self.rotate()?;
+                }
+                while self.disk_usage() + size > budget.max_bytes {
+                    let Some((path, len)) = self.closed_files.pop_front() else {
+                        break;
+                    };
+                    match std::fs::remove_file(&path) {
+                        Ok(()) => {}
+                        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                        Err(e) => return Err(e.into()),
+                    }
+                    self.closed_bytes -= len;
+                    self.stats.quota.files_deleted += 1;
+                    self.stats.quota.bytes_deleted += len;
+                    tracing::info!("Disk budget: deleted {}", path.display());
+                }
+                Ok(self.disk_usage() + size <= budget.max_bytes)
+            }
+        }
+    }
+
+    /// Bytes on disk across finalized files and the current file.
+    fn disk_usage(&self) -> u64 {
+        self.closed_bytes + self.stats.bytes_written
+    }
+
     /// Record a raw DDS sample.
     pub fn record_sample(
         &mut self,
@@ -275,6 +470,12 @@ impl Recorder {
         // Finalize current file
         if let Some(writer) = self.writer.take() {
             writer.finalize()?;
+            let len = std::fs::metadata(&self.current_path)
+                .map(|m| m.len())
+                .unwrap_or(self.stats.bytes_written);
+            self.closed_files
+                .push_back((self.current_path.clone(), len));
+            self.closed_bytes += len;
         }
 
         // Increment file index
@@ -291,6 +492,7 @@ impl Recorder {
 
         let writer = HddsWriter::create(&new_path, metadata)?;
         self.writer = Some(writer);
+        self.current_path = new_path.clone();
 
         // Reset per-file stats
         self.stats.bytes_written = 0;
@@ -325,11 +527,37 @@ impl Recorder {
         self.writer.is_some()
     }
 
+    /// Current state.
+    pub fn state(&self) -> RecorderState {
+        match (self.writer.is_some(), self.paused) {
+            (false, _) => RecorderState::Stopped,
+            (true, false) => RecorderState::Recording,
+            (true, true) => RecorderState::Paused,
+        }
+    }
+
+    /// File currently being written (the last one after `stop`).
+    pub fn current_path(&self) -> &Path {
+        &self.current_path
+    }
+
     /// Get current statistics.
     pub fn stats(&self) -> &RecordingStats {
         &self.stats
     }
 
+    /// Statistics with duration and rate as of now.
+    pub fn live_stats(&self) -> RecordingStats {
+        let mut stats = self.stats.clone();
+        if let Some(started) = self.started_at {
+            stats.duration_secs = started.elapsed().as_secs_f64();
+            if stats.duration_secs > 0.0 {
+                stats.messages_per_second = stats.message_count as f64 / stats.duration_secs;
+            }
+        }
+        stats
+    }
+
     /// Get configuration.
     pub fn config(&self) -> &RecorderConfig {
         &self.config
@@ -422,6 +650,111 @@ mod tests {
         assert_eq!(stats.message_count, 1);
     }
 
+    #[test]
+    fn test_recorder_topic_quota() {
+        let dir = tempdir().expect("tempdir");
+        let path = dir.path().join("test.hdds");
+
+        let config = RecorderConfig::new(&path)
+            .topic_quota("debug/*", TopicQuota::new().messages_per_sec(5));
+        let mut recorder = Recorder::new(config);
+
+        recorder.start().expect("start");
+        for i in 0..20 {
+            recorder
+                .record_sample("debug/trace", "Trace", "guid", i, &[1, 2, 3, 4], 0)
+                .expect("record");
+            recorder
+                .record_sample("pose", "Pose", "guid", i, &[1, 2, 3, 4], 0)
+                .expect("record");
+        }
+
+        let stats = recorder.stop().expect("stop");
+        assert_eq!(stats.message_count, 25);
+        assert_eq!(stats.quota.dropped_rate, 15);
+        assert_eq!(stats.quota.dropped_by_topic.get("debug/trace"), Some(&15));
+        assert_eq!(stats.quota.dropped_by_topic.get("pose"), None);
+    }
+
+    #[test]
+    fn test_recorder_disk_budget_stop() {
+        let dir = tempdir().expect("tempdir");
+        let path = dir.path().join("test.hdds");
+
+        // 54 bytes per message: three fit in 200 bytes.
+        let config = RecorderConfig::new(&path).disk_budget(DiskBudget {
+            max_bytes: 200,
+            policy: BudgetPolicy::Stop,
+        });
+        let mut recorder = Recorder::new(config);
+
+        recorder.start().expect("start");
+        for i in 0..10 {
+            recorder
+                .record_sample("TestTopic", "TestType", "guid", i, &[1, 2, 3, 4], 0)
+                .expect("record");
+        }
+
+        let stats = recorder.stop().expect("stop");
+        assert_eq!(stats.message_count, 3);
+        assert_eq!(stats.quota.dropped_disk_budget, 7);
+        assert!(stats.quota.budget_exhausted);
+        assert!(path.exists());
+    }
+
+    #[test]
+    fn test_recorder_disk_budget_drop_oldest() {
+        let dir = tempdir().expect("tempdir");
+        let path = dir.path().join("test.hdds");
+
+        let config = RecorderConfig::new(&path)
+            .rotation(RotationPolicy::by_messages(5))
+            .disk_budget(DiskBudget {
+                max_bytes: 4096,
+                policy: BudgetPolicy::DropOldest,
+            });
+        let mut recorder = Recorder::new(config);
+
+        recorder.start().expect("start");
+        for i in 0..200 {
+            recorder
+                .record_sample("TestTopic", "TestType", "guid", i, &[0u8; 64], 0)
+                .expect("record");
+        }
+
+        let stats = recorder.stop().expect("stop");
+        assert_eq!(stats.message_count, 200);
+        assert_eq!(stats.quota.total_dropped(), 0);
+        assert!(stats.quota.files_deleted > 0);
+        // The first file went first.
+        assert!(!path.exists());
+        assert!(recorder.disk_usage() <= 4096);
+    }
+
+    #[test]
+    fn test_recorder_pause_resume() {
+        let dir = tempdir().expect("tempdir");
+        let mut recorder = Recorder::new(RecorderConfig::new(dir.path().join("pause.hdds")));
+        assert!(matches!(recorder.pause(), Err(RecorderError::NotRecording)));
+
+        recorder.start().expect("start");
+        recorder
+            .record_sample("T", "Type", "guid", 1, &[1, 2], 0)
+            .expect("record");
+        recorder.pause().expect("pause");
+        assert_eq!(recorder.state(), RecorderState::Paused);
+        recorder
+            .record_sample("T", "Type", "guid", 2, &[1, 2], 0)
+            .expect("paused");
+        recorder.resume().expect("resume");
+        assert_eq!(recorder.state(), RecorderState::Recording);
+        assert_eq!(recorder.live_stats().message_count, 1);
+
+        let stats = recorder.stop().expect("stop");
+        assert_eq!(stats.message_count, 1);
+        assert_eq!(recorder.state(), RecorderState::Stopped);
+    }
+
     #[test]
     fn test_rotated_path() {
         let config = RecorderConfig::new("/tmp/capture.hdds");
@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Topic name remapping for replay.
+//!
+//! Replaying on the recorded topic names collides with the live system, so
+//! the player can publish under other names:
+//!
+//! ```
+//! use hdds_recording::TopicRemap;
+//!
+//! let remap = TopicRemap::new()
+//!     .rule("camera/*", "replay/camera/*")
+//!     .rule("rt/tf", "rt/tf_replay")
+//!     .prefix("sim/");
+//!
+//! assert_eq!(remap.apply("camera/front"), "replay/camera/front");
+//! assert_eq!(remap.apply("rt/tf"), "rt/tf_replay");
+//! assert_eq!(remap.apply("odom"), "sim/odom");
+//! ```
+
+use std::borrow::Cow;
+
+/// Remapping rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum RemapRule {
+    /// `from` -> `to`.
+    Exact { from: String, to: String },
+    /// `from*` -> `to*`: replace the prefix, keep the rest.
+    Prefix { from: String, to: String },
+}
+
+/// Topic name remapping.
+///
+/// Rules are tried in insertion order and the first match wins. Topics no
+/// rule matches get the fallback prefix, if any, and keep their name
+/// otherwise.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TopicRemap {
+    rules: Vec<RemapRule>,
+    prefix: Option<String>,
+}
+
+impl TopicRemap {
+    /// Create an empty remapping (every name unchanged).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a rule.
+    ///
+    /// `from` is an exact name, or a prefix ending in `*`. For a prefix rule
+    /// the matched prefix is replaced by `to` (its trailing `*` optional).
+    pub fn rule(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        let from = from.into();
+        let to = to.into();
+        let rule = match from.strip_suffix('*') {
+            Some(from_prefix) => RemapRule::Prefix {
+                from: from_prefix.to_string(),
+                to: to.strip_suffix('*').unwrap_or(&to).to_string(),
+            },
+            None => RemapRule::Exact { from, to },
+        };
+        self.rules.push(rule);
+        self
+    }
+
+    /// Prefix the topics no rule matches.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Add a rule given as `FROM=TO`.
+    pub fn parse_rule(self, spec: &str) -> Option<Self> {
+        let (from, to) = spec.split_once('=')?;
+        let (from, to) = (from.trim(), to.trim());
+        if from.is_empty() || to.is_empty() {
+            return None;
+        }
+        Some(self.rule(from, to))
+    }
+
+    /// Check if no name is changed.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty() && self.prefix.as_deref().is_none_or(str::is_empty)
+    }
+
+    /// Remapped name of `topic`.
+    pub fn apply<'a>(&self, topic: &'a str) -> Cow<'a, str> {
+        for rule in &self.rules {
+            match rule {
+                RemapRule::Exact { from, to } if from == topic => {
+                    return Cow::Owned(to.clone());
+                }
+                RemapRule::Prefix { from, to } => {
+                    if let Some(rest) = topic.strip_prefix(from.as_str()) {
+                        return Cow::Owned(format!("{}{}", to, rest));
+                    }
+                }
+                RemapRule::Exact { .. } => {}
+            }
+        }
+        match &self.prefix {
+            Some(prefix) if !prefix.is_empty() => Cow::Owned(format!("{}{}", prefix, topic)),
+            _ => Cow::Borrowed(topic),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remap_rules() {
+        let remap = TopicRemap::new()
+            .rule("camera/front", "cam_front")
+            .rule("camera/*", "replay/camera/*")
+            .rule("lidar/*", "replay/");
+
+        assert_eq!(remap.apply("camera/front"), "cam_front");
+        assert_eq!(remap.apply("camera/rear"), "replay/camera/rear");
+        assert_eq!(remap.apply("lidar/top"), "replay/top");
+        assert!(matches!(remap.apply("odom"), Cow::Borrowed("odom")));
+    }
+
+    #[test]
+    fn test_remap_prefix_fallback() {
+        let remap = TopicRemap::new()
+            .rule("rt/tf", "rt/tf_static")
+            .prefix("replay/");
+        assert_eq!(remap.apply("rt/tf"), "rt/tf_static");
+        assert_eq!(remap.apply("rt/odom"), "replay/rt/odom");
+        assert!(!remap.is_empty());
+        assert!(TopicRemap::new().prefix("").is_empty());
+    }
+
+    #[test]
+    fn test_remap_parse_rule() {
+        let remap = TopicRemap::new()
+            .parse_rule("camera/* = replay/camera/*")
+            .expect("rule");
+        assert_eq!(remap.apply("camera/front"), "replay/camera/front");
+        assert!(TopicRemap::new().parse_rule("camera").is_none());
+        assert!(TopicRemap::new().parse_rule("=x").is_none());
+    }
+}
@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! builtin_interfaces: time stamps and durations.
+
+use std::time::{Duration as StdDuration, SystemTime, UNIX_EPOCH};
+
+ros_message! {
+    /// builtin_interfaces/msg/Time
+    #[derive(Default, Copy, Eq)]
+    pub struct Time in "builtin_interfaces" {
+        pub sec: i32,
+        pub nanosec: u32,
+    }
+
+    /// builtin_interfaces/msg/Duration
+    #[derive(Default, Copy, Eq)]
+    pub struct Duration in "builtin_interfaces" {
+        pub sec: i32,
+        pub nanosec: u32,
+    }
+}
+
+impl Time {
+    /// Current system time.
+    pub fn now() -> Self {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(Self::from)
+            .unwrap_or_default()
+    }
+}
+
+impl From<StdDuration> for Time {
+    /// Time elapsed since the epoch (seconds saturate at `i32::MAX`).
+    fn from(since_epoch: StdDuration) -> Self {
+        Self {
+            sec: i32::try_from(since_epoch.as_secs()).unwrap_or(i32::MAX),
+            nanosec: since_epoch.subsec_nanos(),
+        }
+    }
+}
+
+impl From<StdDuration> for Duration {
+    /// Seconds saturate at `i32::MAX`.
+    fn from(duration: StdDuration) -> Self {
+        Self {
+            sec: i32::try_from(duration.as_secs()).unwrap_or(i32::MAX),
+            nanosec: duration.subsec_nanos(),
+        }
+    }
+}
@@ -0,0 +1,291 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! XCDR1 little-endian encoding of ROS 2 messages.
+//!
+//! The ROS 2 RMW layers use plain CDR with natural alignment (8-byte
+//! alignment for 64-bit values), measured from the start of the payload,
+//! after the 4-byte encapsulation header written by hdds.
+
+use crate::description::{type_id, FieldType, TypeDescription};
+use hdds::{Error, Result};
+use std::collections::BTreeMap;
+
+/// Referenced type descriptions, keyed (and sorted) by type name.
+pub type ReferencedTypes = BTreeMap<&'static str, TypeDescription>;
+
+/// CDR writer over a caller-provided buffer.
+pub struct CdrWriter<'a> {
+    buf: &'a mut [u8],
+    pos: usize,
+}
+
+impl<'a> CdrWriter<'a> {
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    /// Bytes written so far.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Pad with zeros up to a multiple of `align`.
+    pub fn align(&mut self, align: usize) -> Result<()> {
+        let padded = self.pos.next_multiple_of(align);
+        let pad = padded - self.pos;
+        self.write_bytes(&[0u8; 8][..pad])
+    }
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> Result<()> {
+        let end = self.pos + bytes.len();
+        self.buf
+            .get_mut(self.pos..end)
+            .ok_or(Error::BufferTooSmall)?
+            .copy_from_slice(bytes);
+        self.pos = end;
+        Ok(())
+    }
+}
+
+/// CDR reader over a received payload.
+pub struct CdrReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> CdrReader<'a> {
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    /// Bytes left to read.
+    pub fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    /// Skip padding up to a multiple of `align`.
+    pub fn align(&mut self, align: usize) -> Result<()> {
+        let padded = self.pos.next_multiple_of(align);
+        self.read_bytes(padded - self.pos).map(|_| ())
+    }
+
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        let end = self.pos.checked_add(len).ok_or(Error::SerializationError)?;
+        let bytes = self
+            .buf
+            .get(self.pos..end)
+            .ok_or(Error::SerializationError)?;
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    /// Read a sequence length, rejecting lengths the payload cannot hold.
+    fn read_len(&mut self, min_element_size: usize) -> Result<usize> {
+        let len = u32::read_cdr(self)? as usize;
+        if len.saturating_mul(min_element_size) > self.remaining() {
+            return Err(Error::SerializationError);
+        }
+        Ok(len)
+    }
+}
+
+/// Field of a ROS 2 message: its type description and CDR encoding.
+///
+/// Implemented for the primitives, `String`, `Vec<T>`, `[T; N]` and every
+/// message type.
+pub trait RosField: Sized {
+    /// Field type, as it appears in the type description.
+    fn field_type() -> FieldType;
+
+    /// Add the descriptions of the message types this field references.
+    fn referenced_types(_out: &mut ReferencedTypes) {}
+
+    fn write_cdr(&self, w: &mut CdrWriter<'_>) -> Result<()>;
+
+    fn read_cdr(r: &mut CdrReader<'_>) -> Result<Self>;
+}
+
+macro_rules! primitive_field {
+    ($($ty:ty => $id:ident),* $(,)?) => {
+        $(
+            impl RosField for $ty {
+                fn field_type() -> FieldType {
+                    FieldType::primitive(type_id::$id)
+                }
+
+                fn write_cdr(&self, w: &mut CdrWriter<'_>) -> Result<()> {
+                    w.align(std::mem::size_of::<$ty>())?;
+                    w.write_bytes(&self.to_le_bytes())
+                }
+
+                fn read_cdr(r: &mut CdrReader<'_>) -> Result<Self> {
+                    const SIZE: usize = std::mem::size_of::<$ty>();
+                    r.align(SIZE)?;
+                    let bytes = r.read_bytes(SIZE)?;
+                    let mut raw = [0u8; SIZE];
+                    raw.copy_from_slice(bytes);
+                    Ok(<$ty>::from_le_bytes(raw))
+                }
+            }
+        )*
+    };
+}
+
+primitive_field! {
+    i8 => INT8,
+    u8 => UINT8,
+    i16 => INT16,
+    u16 => UINT16,
+    i32 => INT32,
+    u32 => UINT32,
+    i64 => INT64,
+    u64 => UINT64,
+    f32 => FLOAT,
+    f64 => DOUBLE,
+}
+
+impl RosField for bool {
+    fn field_type() -> FieldType {
+        FieldType::primitive(type_id::BOOLEAN)
+    }
+
+    fn write_cdr(&self, w: &mut CdrWriter<'_>) -> Result<()> {
+        w.write_bytes(&[u8::from(*self)])
+    }
+
+    fn read_cdr(r: &mut CdrReader<'_>) -> Result<Self> {
+        Ok(r.read_bytes(1)?[0] != 0)
+    }
+}
+
+impl RosField for String {
+    fn field_type() -> FieldType {
+        FieldType::primitive(type_id::STRING)
+    }
+
+    fn write_cdr(&self, w: &mut CdrWriter<'_>) -> Result<()> {
+        // Length includes the NUL terminator
+        let len = u32::try_from(self.len() + 1).map_err(|_| Error::SerializationError)?;
+        len.write_cdr(w)?;
+        w.write_bytes(self.as_bytes())?;
+        w.write_bytes(&[0])
+    }
+
+    fn read_cdr(r: &mut CdrReader<'_>) -> Result<Self> {
+        let len = r.read_len(1)?;
+        let bytes = r.read_bytes(len)?;
+        let text = match bytes.split_last() {
+            Some((0, text)) => text,
+            Some(_) => return Err(Error::SerializationError),
+            None => &[],
+        };
+        String::from_utf8(text.to_vec()).map_err(|_| Error::SerializationError)
+    }
+}
+
+impl<T: RosField> RosField for Vec<T> {
+    fn field_type() -> FieldType {
+        T::field_type().sequence()
+    }
+
+    fn referenced_types(out: &mut ReferencedTypes) {
+        T::referenced_types(out);
+    }
+
+    fn write_cdr(&self, w: &mut CdrWriter<'_>) -> Result<()> {
+        let len = u32::try_from(self.len()).map_err(|_| Error::SerializationError)?;
+        len.write_cdr(w)?;
+        self.iter().try_for_each(|item| item.write_cdr(w))
+    }
+
+    fn read_cdr(r: &mut CdrReader<'_>) -> Result<Self> {
+        let len = r.read_len(1)?;
+        (0..len).map(|_| T::read_cdr(r)).collect()
+    }
+}
+
+impl<T: RosField, const N: usize> RosField for [T; N] {
+    fn field_type() -> FieldType {
+        T::field_type().array(N as u64)
+    }
+
+    fn referenced_types(out: &mut ReferencedTypes) {
+        T::referenced_types(out);
+    }
+
+    fn write_cdr(&self, w: &mut CdrWriter<'_>) -> Result<()> {
+        self.iter().try_for_each(|item| item.write_cdr(w))
+    }
+
+    fn read_cdr(r: &mut CdrReader<'_>) -> Result<Self> {
+        let items = (0..N).map(|_| T::read_cdr(r)).collect::<Result<Vec<T>>>()?;
+        items.try_into().map_err(|_| Error::SerializationError)
+    }
+}
+
+/// Encode a message into `buf`, returning the number of bytes written.
+pub fn encode<T: RosField>(msg: &T, buf: &mut [u8]) -> Result<usize> {
+    let mut w = CdrWriter::new(buf);
+    msg.write_cdr(&mut w)?;
+    Ok(w.position())
+}
+
+/// Decode a message from `buf`.
+pub fn decode<T: RosField>(buf: &[u8]) -> Result<T> {
+    T::read_cdr(&mut CdrReader::new(buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alignment_from_payload_start() {
+        let mut buf = [0xffu8; 32];
+        let mut w = CdrWriter::new(&mut buf);
+        1u8.write_cdr(&mut w).expect("u8");
+        2u32.write_cdr(&mut w).expect("u32");
+        3.0f64.write_cdr(&mut w).expect("f64");
+        assert_eq!(w.position(), 16);
+        assert_eq!(&buf[..8], &[1, 0, 0, 0, 2, 0, 0, 0]);
+        assert_eq!(&buf[8..16], &3.0f64.to_le_bytes());
+
+        let mut r = CdrReader::new(&buf[..16]);
+        assert_eq!(u8::read_cdr(&mut r).expect("u8"), 1);
+        assert_eq!(u32::read_cdr(&mut r).expect("u32"), 2);
+        assert_eq!(f64::read_cdr(&mut r).expect("f64"), 3.0);
+        assert_eq!(r.remaining(), 0);
+    }
+
+    #[test]
+    fn test_string_and_sequence() {
+        let mut buf = [0u8; 64];
+        let len = encode(&"hi".to_string(), &mut buf).expect("encode");
+        assert_eq!(&buf[..len], &[3, 0, 0, 0, b'h', b'i', 0]);
+        assert_eq!(decode::<String>(&buf[..len]).expect("decode"), "hi");
+        // Some vendors send a zero length for empty strings
+        assert_eq!(decode::<String>(&[0, 0, 0, 0]).expect("decode"), "");
+
+        let values = vec![1.5f64, -2.0];
+        let len = encode(&values, &mut buf).expect("encode");
+        assert_eq!(len, 24);
+        assert_eq!(decode::<Vec<f64>>(&buf[..len]).expect("decode"), values);
+
+        let array = [1u16, 2, 3];
+        let len = encode(&array, &mut buf).expect("encode");
+        assert_eq!(&buf[..len], &[1, 0, 2, 0, 3, 0]);
+        assert_eq!(decode::<[u16; 3]>(&buf[..len]).expect("decode"), array);
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        // Sequence length larger than the payload
+        assert!(decode::<Vec<u8>>(&[0xff, 0xff, 0xff, 0x7f, 1]).is_err());
+        // Missing NUL terminator
+        assert!(decode::<String>(&[2, 0, 0, 0, b'h', b'i']).is_err());
+        assert!(decode::<u64>(&[1, 2, 3]).is_err());
+        let mut small = [0u8; 2];
+        assert!(encode(&1u32, &mut small).is_err());
+    }
+}
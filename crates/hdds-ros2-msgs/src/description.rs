@@ -0,0 +1,253 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! ROS 2 type descriptions and RIHS01 type hashes.
+//!
+//! ROS 2 (Iron and later) identifies a message type by the SHA-256 of its
+//! `type_description_interfaces/TypeDescription`, serialized as JSON with
+//! Python's `json.dumps` separators and without the default values or
+//! comments. The description lists the fields of the type itself and of
+//! every type it references, directly or not.
+
+use sha2::{Digest, Sha256};
+use std::fmt;
+
+/// `type_description_interfaces/FieldType` ids.
+pub mod type_id {
+    pub const NESTED: u8 = 1;
+    pub const INT8: u8 = 2;
+    pub const UINT8: u8 = 3;
+    pub const INT16: u8 = 4;
+    pub const UINT16: u8 = 5;
+    pub const INT32: u8 = 6;
+    pub const UINT32: u8 = 7;
+    pub const INT64: u8 = 8;
+    pub const UINT64: u8 = 9;
+    pub const FLOAT: u8 = 10;
+    pub const DOUBLE: u8 = 11;
+    pub const CHAR: u8 = 13;
+    pub const BOOLEAN: u8 = 15;
+    pub const BYTE: u8 = 16;
+    pub const STRING: u8 = 17;
+
+    /// Offset of fixed-size arrays (`T[N]`).
+    pub const ARRAY: u8 = 48;
+    /// Offset of bounded sequences (`T[<=N]`).
+    pub const BOUNDED_SEQUENCE: u8 = 96;
+    /// Offset of unbounded sequences (`T[]`).
+    pub const UNBOUNDED_SEQUENCE: u8 = 144;
+}
+
+/// Type of a message field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldType {
+    /// `type_id::*` value, array offsets included.
+    pub type_id: u8,
+    /// Array length or sequence bound (0 otherwise).
+    pub capacity: u64,
+    /// Bound of bounded strings (0 otherwise).
+    pub string_capacity: u64,
+    /// `pkg/msg/Name` of nested types (empty otherwise).
+    pub nested_type_name: &'static str,
+}
+
+impl FieldType {
+    /// Primitive or string field.
+    pub const fn primitive(type_id: u8) -> Self {
+        Self {
+            type_id,
+            capacity: 0,
+            string_capacity: 0,
+            nested_type_name: "",
+        }
+    }
+
+    /// Nested message field.
+    pub const fn nested(type_name: &'static str) -> Self {
+        Self {
+            type_id: type_id::NESTED,
+            capacity: 0,
+            string_capacity: 0,
+            nested_type_name: type_name,
+        }
+    }
+
+    /// Fixed-size array of this type.
+    pub const fn array(self, len: u64) -> Self {
+        Self {
+            type_id: self.type_id + type_id::ARRAY,
+            capacity: len,
+            ..self
+        }
+    }
+
+    /// Unbounded sequence of this type.
+    pub const fn sequence(self) -> Self {
+        Self {
+            type_id: self.type_id + type_id::UNBOUNDED_SEQUENCE,
+            ..self
+        }
+    }
+}
+
+/// Message field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Field {
+    pub name: &'static str,
+    pub field_type: FieldType,
+}
+
+impl Field {
+    pub const fn new(name: &'static str, field_type: FieldType) -> Self {
+        Self { name, field_type }
+    }
+}
+
+/// Description of one message type (`pkg/msg/Name` and its fields).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeDescription {
+    pub type_name: &'static str,
+    pub fields: Vec<Field>,
+}
+
+impl TypeDescription {
+    fn write_json(&self, out: &mut String) {
+        out.push_str("{\"type_name\": ");
+        push_json_str(out, self.type_name);
+        out.push_str(", \"fields\": [");
+        for (i, field) in self.fields.iter().enumerate() {
+            if i > 0 {
+                out.push_str(", ");
+            }
+            let ty = &field.field_type;
+            out.push_str("{\"name\": ");
+            push_json_str(out, field.name);
+            out.push_str(&format!(
+                ", \"type\": {{\"type_id\": {}, \"capacity\": {}, \"string_capacity\": {}, \"nested_type_name\": ",
+                ty.type_id, ty.capacity, ty.string_capacity
+            ));
+            push_json_str(out, ty.nested_type_name);
+            out.push_str("}}");
+        }
+        out.push_str("]}");
+    }
+}
+
+fn push_json_str(out: &mut String, s: &str) {
+    // ROS names and field names are plain identifiers, no escaping needed
+    out.push('"');
+    out.push_str(s);
+    out.push('"');
+}
+
+/// RIHS01 type hash (`RIHS01_<sha256 hex>`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TypeHash(pub [u8; 32]);
+
+impl TypeHash {
+    /// RIHS version implemented here.
+    pub const VERSION: u8 = 1;
+
+    /// Hash `description` with its `referenced` types.
+    ///
+    /// `referenced` must hold every type reachable from `description`,
+    /// sorted by type name.
+    pub fn compute<'a>(
+        description: &TypeDescription,
+        referenced: impl IntoIterator<Item = &'a TypeDescription>,
+    ) -> Self {
+        let json = Self::json(description, referenced);
+        Self(Sha256::digest(json.as_bytes()).into())
+    }
+
+    /// Hashed JSON document.
+    pub fn json<'a>(
+        description: &TypeDescription,
+        referenced: impl IntoIterator<Item = &'a TypeDescription>,
+    ) -> String {
+        let mut json = String::from("{\"type_description\": ");
+        description.write_json(&mut json);
+        json.push_str(", \"referenced_type_descriptions\": [");
+        for (i, referenced) in referenced.into_iter().enumerate() {
+            if i > 0 {
+                json.push_str(", ");
+            }
+            referenced.write_json(&mut json);
+        }
+        json.push_str("]}");
+        json
+    }
+
+    /// Parse `RIHS01_<hex>`.
+    pub fn parse(s: &str) -> Option<Self> {
+        let hex = s.strip_prefix("RIHS01_")?;
+        if hex.len() != 64 {
+            return None;
+        }
+        let mut bytes = [0u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(hex.get(i * 2..i * 2 + 2)?, 16).ok()?;
+        }
+        Some(Self(bytes))
+    }
+
+    /// `typehash=RIHS01_<hex>;` entry of the endpoint USER_DATA, as the ROS
+    /// 2 RMW layers announce it.
+    pub fn user_data(&self) -> String {
+        format!("typehash={};", self)
+    }
+}
+
+impl fmt::Display for TypeHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RIHS{:02}_", Self::VERSION)?;
+        for byte in &self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_layout() {
+        let description = TypeDescription {
+            type_name: "std_msgs/msg/String",
+            fields: vec![Field::new("data", FieldType::primitive(type_id::STRING))],
+        };
+        assert_eq!(
+            TypeHash::json(&description, []),
+            "{\"type_description\": {\"type_name\": \"std_msgs/msg/String\", \"fields\": \
+             [{\"name\": \"data\", \"type\": {\"type_id\": 17, \"capacity\": 0, \
+             \"string_capacity\": 0, \"nested_type_name\": \"\"}}]}, \
+             \"referenced_type_descriptions\": []}"
+        );
+        assert_eq!(
+            TypeHash::compute(&description, []).to_string(),
+            "RIHS01_df668c740482bbd48fb39d76a70dfd4bd59db1288021743503259e948f6b1a18"
+        );
+    }
+
+    #[test]
+    fn test_field_type_offsets() {
+        let covariance = FieldType::primitive(type_id::DOUBLE).array(36);
+        assert_eq!(covariance.type_id, 59);
+        assert_eq!(covariance.capacity, 36);
+        let poses = FieldType::nested("geometry_msgs/msg/Pose").sequence();
+        assert_eq!(poses.type_id, 145);
+        assert_eq!(poses.nested_type_name, "geometry_msgs/msg/Pose");
+    }
+
+    #[test]
+    fn test_hash_parse_roundtrip() {
+        let hash = TypeHash([0xab; 32]);
+        let text = hash.to_string();
+        assert_eq!(TypeHash::parse(&text), Some(hash));
+        assert_eq!(hash.user_data(), format!("typehash={};", text));
+        assert!(TypeHash::parse("RIHS01_abc").is_none());
+        assert!(TypeHash::parse(&text.replace("RIHS01", "RIHS02")).is_none());
+    }
+}
@@ -0,0 +1,279 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! geometry_msgs: points, poses, transforms, velocities and forces.
+
+use crate::std_msgs::Header;
+
+ros_message! {
+    /// geometry_msgs/msg/Point
+    #[derive(Default, Copy)]
+    pub struct Point in "geometry_msgs" {
+        pub x: f64,
+        pub y: f64,
+        pub z: f64,
+    }
+
+    /// geometry_msgs/msg/Point32
+    #[derive(Default, Copy)]
+    pub struct Point32 in "geometry_msgs" {
+        pub x: f32,
+        pub y: f32,
+        pub z: f32,
+    }
+
+    /// geometry_msgs/msg/Vector3
+    #[derive(Default, Copy)]
+    pub struct Vector3 in "geometry_msgs" {
+        pub x: f64,
+        pub y: f64,
+        pub z: f64,
+    }
+
+    /// geometry_msgs/msg/Quaternion
+    ///
+    /// Defaults to the identity rotation, as in the `.msg` file.
+    #[derive(Copy)]
+    pub struct Quaternion in "geometry_msgs" {
+        pub x: f64,
+        pub y: f64,
+        pub z: f64,
+        pub w: f64,
+    }
+
+    /// geometry_msgs/msg/Pose
+    #[derive(Default, Copy)]
+    pub struct Pose in "geometry_msgs" {
+        pub position: Point,
+        pub orientation: Quaternion,
+    }
+
+    /// geometry_msgs/msg/Pose2D
+    #[derive(Default, Copy)]
+    pub struct Pose2D in "geometry_msgs" {
+        pub x: f64,
+        pub y: f64,
+        pub theta: f64,
+    }
+
+    /// geometry_msgs/msg/PoseStamped
+    #[derive(Default)]
+    pub struct PoseStamped in "geometry_msgs" {
+        pub header: Header,
+        pub pose: Pose,
+    }
+
+    /// geometry_msgs/msg/PoseArray
+    #[derive(Default)]
+    pub struct PoseArray in "geometry_msgs" {
+        pub header: Header,
+        pub poses: Vec<Pose>,
+    }
+
+    /// geometry_msgs/msg/PoseWithCovariance
+    ///
+    /// Row-major 6x6 covariance of (x, y, z, rot x, rot y, rot z).
+    #[derive(Copy)]
+    pub struct PoseWithCovariance in "geometry_msgs" {
+        pub pose: Pose,
+        pub covariance: [f64; 36],
+    }
+
+    /// geometry_msgs/msg/PoseWithCovarianceStamped
+    #[derive(Default)]
+    pub struct PoseWithCovarianceStamped in "geometry_msgs" {
+        pub header: Header,
+        pub pose: PoseWithCovariance,
+    }
+
+    /// geometry_msgs/msg/Transform
+    #[derive(Default, Copy)]
+    pub struct Transform in "geometry_msgs" {
+        pub translation: Vector3,
+        pub rotation: Quaternion,
+    }
+
+    /// geometry_msgs/msg/TransformStamped
+    #[derive(Default)]
+    pub struct TransformStamped in "geometry_msgs" {
+        pub header: Header,
+        pub child_frame_id: String,
+        pub transform: Transform,
+    }
+
+    /// geometry_msgs/msg/Twist
+    #[derive(Default, Copy)]
+    pub struct Twist in "geometry_msgs" {
+        pub linear: Vector3,
+        pub angular: Vector3,
+    }
+
+    /// geometry_msgs/msg/TwistStamped
+    #[derive(Default)]
+    pub struct TwistStamped in "geometry_msgs" {
+        pub header: Header,
+        pub twist: Twist,
+    }
+
+    /// geometry_msgs/msg/TwistWithCovariance
+    #[derive(Copy)]
+    pub struct TwistWithCovariance in "geometry_msgs" {
+        pub twist: Twist,
+        pub covariance: [f64; 36],
+    }
+
+    /// geometry_msgs/msg/TwistWithCovarianceStamped
+    #[derive(Default)]
+    pub struct TwistWithCovarianceStamped in "geometry_msgs" {
+        pub header: Header,
+        pub twist: TwistWithCovariance,
+    }
+
+    /// geometry_msgs/msg/Accel
+    #[derive(Default, Copy)]
+    pub struct Accel in "geometry_msgs" {
+        pub linear: Vector3,
+        pub angular: Vector3,
+    }
+
+    /// geometry_msgs/msg/AccelStamped
+    #[derive(Default)]
+    pub struct AccelStamped in "geometry_msgs" {
+        pub header: Header,
+        pub accel: Accel,
+    }
+
+    /// geometry_msgs/msg/Wrench
+    #[derive(Default, Copy)]
+    pub struct Wrench in "geometry_msgs" {
+        pub force: Vector3,
+        pub torque: Vector3,
+    }
+
+    /// geometry_msgs/msg/WrenchStamped
+    #[derive(Default)]
+    pub struct WrenchStamped in "geometry_msgs" {
+        pub header: Header,
+        pub wrench: Wrench,
+    }
+
+    /// geometry_msgs/msg/PointStamped
+    #[derive(Default)]
+    pub struct PointStamped in "geometry_msgs" {
+        pub header: Header,
+        pub point: Point,
+    }
+
+    /// geometry_msgs/msg/Vector3Stamped
+    #[derive(Default)]
+    pub struct Vector3Stamped in "geometry_msgs" {
+        pub header: Header,
+        pub vector: Vector3,
+    }
+
+    /// geometry_msgs/msg/QuaternionStamped
+    #[derive(Default)]
+    pub struct QuaternionStamped in "geometry_msgs" {
+        pub header: Header,
+        pub quaternion: Quaternion,
+    }
+
+    /// geometry_msgs/msg/Polygon
+    #[derive(Default)]
+    pub struct Polygon in "geometry_msgs" {
+        pub points: Vec<Point32>,
+    }
+
+    /// geometry_msgs/msg/PolygonStamped
+    #[derive(Default)]
+    pub struct PolygonStamped in "geometry_msgs" {
+        pub header: Header,
+        pub polygon: Polygon,
+    }
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            w: 1.0,
+        }
+    }
+}
+
+impl Default for PoseWithCovariance {
+    fn default() -> Self {
+        Self {
+            pose: Pose::default(),
+            covariance: [0.0; 36],
+        }
+    }
+}
+
+impl Default for TwistWithCovariance {
+    fn default() -> Self {
+        Self {
+            twist: Twist::default(),
+            covariance: [0.0; 36],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cdr::{decode, encode};
+    use crate::description::type_id;
+    use crate::RosMessage;
+
+    #[test]
+    fn test_twist_hash() {
+        assert_eq!(Twist::DDS_TYPE_NAME, "geometry_msgs::msg::dds_::Twist_");
+        assert_eq!(
+            Twist::type_hash().to_string(),
+            "RIHS01_9c45bf16fe0983d80e3cfe750d6835843d265a9a6c46bd2e609fcddde6fb8d2a"
+        );
+    }
+
+    #[test]
+    fn test_referenced_types_sorted() {
+        let mut referenced = crate::cdr::ReferencedTypes::new();
+        <PoseWithCovarianceStamped as crate::cdr::RosField>::referenced_types(&mut referenced);
+        let names: Vec<_> = referenced.keys().copied().collect();
+        assert_eq!(
+            names,
+            [
+                "builtin_interfaces/msg/Time",
+                "geometry_msgs/msg/Point",
+                "geometry_msgs/msg/Pose",
+                "geometry_msgs/msg/PoseWithCovariance",
+                "geometry_msgs/msg/PoseWithCovarianceStamped",
+                "geometry_msgs/msg/Quaternion",
+                "std_msgs/msg/Header",
+            ]
+        );
+        let covariance = PoseWithCovariance::type_description().fields[1].field_type;
+        assert_eq!(covariance.type_id, type_id::DOUBLE + type_id::ARRAY);
+        assert_eq!(covariance.capacity, 36);
+    }
+
+    #[test]
+    fn test_covariance_roundtrip() {
+        let mut msg = PoseWithCovarianceStamped::default();
+        msg.header.frame_id = "odom".into();
+        msg.pose.pose.position.x = 1.5;
+        msg.pose.covariance[0] = 0.1;
+        msg.pose.covariance[35] = 0.2;
+        let mut buf = [0u8; 512];
+        let len = encode(&msg, &mut buf).expect("encode");
+        // "odom" ends at 17, the pose is aligned to 24
+        assert_eq!(len, 24 + 7 * 8 + 36 * 8);
+        assert_eq!(
+            decode::<PoseWithCovarianceStamped>(&buf[..len]).expect("decode"),
+            msg
+        );
+        assert_eq!(Quaternion::default().w, 1.0);
+    }
+}
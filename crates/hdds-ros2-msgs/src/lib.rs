@@ -0,0 +1,182 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Precompiled ROS 2 message types for HDDS
+//!
+//! Rust types for the common_interfaces packages, ready to use with
+//! [`hdds::Participant`], so that pure-Rust nodes can exchange messages with
+//! a ROS 2 graph without running rosidl code generation.
+//!
+//! # Packages
+//!
+//! | Module | Feature |
+//! |--------|---------|
+//! | [`builtin_interfaces`] | always |
+//! | [`std_msgs`] | `std_msgs` |
+//! | [`geometry_msgs`] | `geometry_msgs` |
+//! | [`sensor_msgs`] | `sensor_msgs` |
+//! | [`nav_msgs`] | `nav_msgs` |
+//!
+//! All features are enabled by default.
+//!
+//! # Naming
+//!
+//! Each type carries the names ROS 2 expects on the wire:
+//!
+//! - DDS type name: `geometry_msgs::msg::dds_::Twist_`
+//! - ROS type name: `geometry_msgs/msg/Twist`
+//! - RIHS01 type hash of the Jazzy definition, see [`RosMessage::type_hash`]
+//!
+//! and [`ros_topic`] maps a ROS topic name to its DDS topic (`/cmd_vel` ->
+//! `rt/cmd_vel`).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use hdds::{Participant, QoS};
+//! use hdds_ros2_msgs::geometry_msgs::{Twist, Vector3};
+//! use hdds_ros2_msgs::ros_topic;
+//!
+//! let participant = Participant::builder("teleop").domain_id(0).build()?;
+//! let writer = participant
+//!     .topic::<Twist>(&ros_topic("/cmd_vel"))?
+//!     .writer()
+//!     .qos(QoS::reliable())
+//!     .build()?;
+//!
+//! writer.write(&Twist {
+//!     linear: Vector3 { x: 0.5, y: 0.0, z: 0.0 },
+//!     angular: Vector3 { x: 0.0, y: 0.0, z: 0.3 },
+//! })?;
+//! # Ok::<(), hdds::Error>(())
+//! ```
+//!
+//! # Custom Messages
+//!
+//! [`ros_message!`] declares further message types with the same support.
+//!
+//! # Limitations
+//!
+//! - Definitions follow ROS 2 Jazzy. Humble peers match on the type name
+//!   only and ignore hashes.
+//! - The type hash is not announced in the endpoint USER_DATA yet; ROS 2
+//!   treats endpoints without a hash as compatible.
+//! - Typed writers are limited to 64 KB samples, which rules out large
+//!   images and point clouds.
+
+#[doc(hidden)]
+pub use hdds;
+
+#[macro_use]
+mod macros;
+
+pub mod cdr;
+pub mod description;
+
+pub mod builtin_interfaces;
+#[cfg(feature = "geometry_msgs")]
+pub mod geometry_msgs;
+#[cfg(feature = "nav_msgs")]
+pub mod nav_msgs;
+#[cfg(feature = "sensor_msgs")]
+pub mod sensor_msgs;
+#[cfg(feature = "std_msgs")]
+pub mod std_msgs;
+
+pub use description::{Field, FieldType, TypeDescription, TypeHash};
+
+/// ROS 2 message type.
+pub trait RosMessage: cdr::RosField + hdds::dds::DDS {
+    /// ROS type name (`pkg/msg/Name`).
+    const TYPE_NAME: &'static str;
+
+    /// DDS type name (`pkg::msg::dds_::Name_`).
+    const DDS_TYPE_NAME: &'static str;
+
+    /// Description of this type's fields.
+    fn type_description() -> TypeDescription;
+
+    /// RIHS01 hash of this type and the types it references.
+    fn type_hash() -> TypeHash;
+}
+
+/// DDS topic name of a ROS 2 topic (`/chatter` -> `rt/chatter`).
+pub fn ros_topic(name: &str) -> String {
+    format!("rt/{}", name.trim_start_matches('/'))
+}
+
+/// FNV-1a (32-bit) of the DDS type name, as used for `#[derive(DDS)]` type ids.
+#[doc(hidden)]
+pub const fn dds_type_id(name: &str) -> u32 {
+    let bytes = name.as_bytes();
+    let mut hash = 2_166_136_261_u32;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash = (hash ^ bytes[i] as u32).wrapping_mul(16_777_619);
+        i += 1;
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ros_topic() {
+        assert_eq!(ros_topic("/chatter"), "rt/chatter");
+        assert_eq!(ros_topic("robot1/odom"), "rt/robot1/odom");
+    }
+
+    #[cfg(feature = "geometry_msgs")]
+    #[test]
+    fn test_pubsub_roundtrip() {
+        use crate::geometry_msgs::{Twist, Vector3};
+        use hdds::{Participant, QoS, TransportMode};
+        use std::time::{Duration, Instant};
+
+        let participant = Participant::builder("ros2-msgs-test")
+            .with_transport(TransportMode::IntraProcess)
+            .domain_id(98)
+            .build()
+            .expect("participant");
+        let reader = participant
+            .topic::<Twist>(&ros_topic("/cmd_vel"))
+            .expect("topic")
+            .reader()
+            .qos(QoS::reliable())
+            .build()
+            .expect("reader");
+        let writer = participant
+            .topic::<Twist>(&ros_topic("/cmd_vel"))
+            .expect("topic")
+            .writer()
+            .qos(QoS::reliable())
+            .build()
+            .expect("writer");
+
+        let twist = Twist {
+            linear: Vector3 {
+                x: 0.5,
+                y: -1.0,
+                z: 0.0,
+            },
+            angular: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: 0.25,
+            },
+        };
+        writer.write(&twist).expect("write");
+
+        let deadline = Instant::now() + Duration::from_secs(2);
+        let received = loop {
+            if let Some(sample) = reader.take().expect("take") {
+                break sample;
+            }
+            assert!(Instant::now() < deadline, "no sample received");
+            std::thread::sleep(Duration::from_millis(5));
+        };
+        assert_eq!(received, twist);
+    }
+}
@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! `ros_message!`: declare ROS 2 message structs.
+
+/// Declare ROS 2 message types.
+///
+/// Each struct gets [`RosMessage`](crate::RosMessage), the CDR encoding of
+/// [`RosField`](crate::cdr::RosField) and [`hdds::dds::DDS`], with the ROS type
+/// name `<package>/msg/<Struct>` and the DDS type name
+/// `<package>::msg::dds_::<Struct>_`. Fields must be declared in the order
+/// of the `.msg` file, with the types of the `.msg` mapping (`bool`,
+/// integers, floats, `String`, `Vec<T>`, `[T; N]` or another message).
+///
+/// ```
+/// use hdds_ros2_msgs::{ros_message, RosMessage};
+///
+/// ros_message! {
+///     /// my_robot_msgs/msg/Battery
+///     #[derive(Default)]
+///     pub struct Battery in "my_robot_msgs" {
+///         pub voltage: f32,
+///         pub cells: Vec<f32>,
+///         pub charging: bool,
+///     }
+/// }
+///
+/// assert_eq!(Battery::TYPE_NAME, "my_robot_msgs/msg/Battery");
+/// assert_eq!(Battery::DDS_TYPE_NAME, "my_robot_msgs::msg::dds_::Battery_");
+/// ```
+#[macro_export]
+macro_rules! ros_message {
+    ($(
+        $(#[$meta:meta])*
+        pub struct $name:ident in $package:literal {
+            $(
+                $(#[$field_meta:meta])*
+                pub $field:ident: $ty:ty,
+            )*
+        }
+    )*) => {$(
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $name {
+            $(
+                $(#[$field_meta])*
+                pub $field: $ty,
+            )*
+        }
+
+        impl $crate::RosMessage for $name {
+            const TYPE_NAME: &'static str = concat!($package, "/msg/", stringify!($name));
+            const DDS_TYPE_NAME: &'static str =
+                concat!($package, "::msg::dds_::", stringify!($name), "_");
+
+            fn type_description() -> $crate::TypeDescription {
+                $crate::TypeDescription {
+                    type_name: <Self as $crate::RosMessage>::TYPE_NAME,
+                    fields: vec![$(
+                        $crate::Field::new(
+                            stringify!($field),
+                            <$ty as $crate::cdr::RosField>::field_type(),
+                        ),
+                    )*],
+                }
+            }
+
+            fn type_hash() -> $crate::TypeHash {
+                static HASH: ::std::sync::OnceLock<$crate::TypeHash> =
+                    ::std::sync::OnceLock::new();
+                *HASH.get_or_init(|| {
+                    let mut referenced = $crate::cdr::ReferencedTypes::new();
+                    $(<$ty as $crate::cdr::RosField>::referenced_types(&mut referenced);)*
+                    $crate::TypeHash::compute(
+                        &<Self as $crate::RosMessage>::type_description(),
+                        referenced.values(),
+                    )
+                })
+            }
+        }
+
+        impl $crate::cdr::RosField for $name {
+            fn field_type() -> $crate::FieldType {
+                $crate::FieldType::nested(<Self as $crate::RosMessage>::TYPE_NAME)
+            }
+
+            fn referenced_types(out: &mut $crate::cdr::ReferencedTypes) {
+                let name = <Self as $crate::RosMessage>::TYPE_NAME;
+                if !out.contains_key(name) {
+                    out.insert(name, <Self as $crate::RosMessage>::type_description());
+                    $(<$ty as $crate::cdr::RosField>::referenced_types(out);)*
+                }
+            }
+
+            fn write_cdr(&self, w: &mut $crate::cdr::CdrWriter<'_>) -> $crate::hdds::Result<()> {
+                $($crate::cdr::RosField::write_cdr(&self.$field, w)?;)*
+                Ok(())
+            }
+
+            fn read_cdr(r: &mut $crate::cdr::CdrReader<'_>) -> $crate::hdds::Result<Self> {
+                Ok(Self {
+                    $($field: <$ty as $crate::cdr::RosField>::read_cdr(r)?,)*
+                })
+            }
+        }
+
+        impl $crate::hdds::dds::DDS for $name {
+            fn type_descriptor() -> &'static $crate::hdds::core::types::TypeDescriptor {
+                static DESCRIPTOR: $crate::hdds::core::types::TypeDescriptor =
+                    $crate::hdds::core::types::TypeDescriptor::new(
+                        $crate::dds_type_id(<$name as $crate::RosMessage>::DDS_TYPE_NAME),
+                        <$name as $crate::RosMessage>::DDS_TYPE_NAME,
+                        0,
+                        8,
+                        true,
+                        &[],
+                    );
+                &DESCRIPTOR
+            }
+
+            fn encode_cdr2(&self, buf: &mut [u8]) -> $crate::hdds::Result<usize> {
+                $crate::cdr::encode(self, buf)
+            }
+
+            fn decode_cdr2(buf: &[u8]) -> $crate::hdds::Result<Self> {
+                $crate::cdr::decode(buf)
+            }
+        }
+    )*};
+}
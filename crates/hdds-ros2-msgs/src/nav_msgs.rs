@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! nav_msgs: odometry, paths and occupancy grids.
+
+use crate::builtin_interfaces::Time;
+use crate::geometry_msgs::{Point, Pose, PoseStamped, PoseWithCovariance, TwistWithCovariance};
+use crate::std_msgs::Header;
+
+ros_message! {
+    /// nav_msgs/msg/Odometry
+    #[derive(Default)]
+    pub struct Odometry in "nav_msgs" {
+        pub header: Header,
+        pub child_frame_id: String,
+        pub pose: PoseWithCovariance,
+        pub twist: TwistWithCovariance,
+    }
+
+    /// nav_msgs/msg/Path
+    #[derive(Default)]
+    pub struct Path in "nav_msgs" {
+        pub header: Header,
+        pub poses: Vec<PoseStamped>,
+    }
+
+    /// nav_msgs/msg/MapMetaData
+    #[derive(Default)]
+    pub struct MapMetaData in "nav_msgs" {
+        pub map_load_time: Time,
+        pub resolution: f32,
+        pub width: u32,
+        pub height: u32,
+        pub origin: Pose,
+    }
+
+    /// nav_msgs/msg/OccupancyGrid
+    ///
+    /// Row-major cells, -1 for unknown and 0..=100 for the occupancy
+    /// probability.
+    #[derive(Default)]
+    pub struct OccupancyGrid in "nav_msgs" {
+        pub header: Header,
+        pub info: MapMetaData,
+        pub data: Vec<i8>,
+    }
+
+    /// nav_msgs/msg/GridCells
+    #[derive(Default)]
+    pub struct GridCells in "nav_msgs" {
+        pub header: Header,
+        pub cell_width: f32,
+        pub cell_height: f32,
+        pub cells: Vec<Point>,
+    }
+}
+
+impl OccupancyGrid {
+    /// Cell at column `x`, row `y`.
+    pub fn cell(&self, x: u32, y: u32) -> Option<i8> {
+        if x >= self.info.width {
+            return None;
+        }
+        let index = y as usize * self.info.width as usize + x as usize;
+        self.data.get(index).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cdr::{decode, encode};
+    use crate::RosMessage;
+
+    #[test]
+    fn test_odometry_roundtrip() {
+        let mut odom = Odometry {
+            child_frame_id: "base_link".into(),
+            ..Default::default()
+        };
+        odom.header.frame_id = "odom".into();
+        odom.pose.pose.position.x = 3.0;
+        odom.twist.twist.linear.x = 0.4;
+        odom.twist.covariance[7] = 0.01;
+        let mut buf = [0u8; 1024];
+        let len = encode(&odom, &mut buf).expect("encode");
+        assert_eq!(decode::<Odometry>(&buf[..len]).expect("decode"), odom);
+        assert_eq!(Odometry::TYPE_NAME, "nav_msgs/msg/Odometry");
+    }
+
+    #[test]
+    fn test_occupancy_grid_cell() {
+        let mut grid = OccupancyGrid::default();
+        grid.info.width = 2;
+        grid.info.height = 2;
+        grid.data = vec![0, 100, -1, 50];
+        assert_eq!(grid.cell(1, 0), Some(100));
+        assert_eq!(grid.cell(0, 1), Some(-1));
+        assert_eq!(grid.cell(2, 0), None);
+        assert_eq!(grid.cell(0, 2), None);
+    }
+}
@@ -0,0 +1,297 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! sensor_msgs: IMU, range finders, cameras, point clouds and GNSS.
+
+use crate::builtin_interfaces::Time;
+use crate::geometry_msgs::{Quaternion, Vector3};
+use crate::std_msgs::Header;
+
+ros_message! {
+    /// sensor_msgs/msg/Imu
+    #[derive(Default)]
+    pub struct Imu in "sensor_msgs" {
+        pub header: Header,
+        pub orientation: Quaternion,
+        pub orientation_covariance: [f64; 9],
+        pub angular_velocity: Vector3,
+        pub angular_velocity_covariance: [f64; 9],
+        pub linear_acceleration: Vector3,
+        pub linear_acceleration_covariance: [f64; 9],
+    }
+
+    /// sensor_msgs/msg/LaserScan
+    #[derive(Default)]
+    pub struct LaserScan in "sensor_msgs" {
+        pub header: Header,
+        pub angle_min: f32,
+        pub angle_max: f32,
+        pub angle_increment: f32,
+        pub time_increment: f32,
+        pub scan_time: f32,
+        pub range_min: f32,
+        pub range_max: f32,
+        pub ranges: Vec<f32>,
+        pub intensities: Vec<f32>,
+    }
+
+    /// sensor_msgs/msg/Image
+    #[derive(Default, Eq)]
+    pub struct Image in "sensor_msgs" {
+        pub header: Header,
+        pub height: u32,
+        pub width: u32,
+        pub encoding: String,
+        pub is_bigendian: u8,
+        pub step: u32,
+        pub data: Vec<u8>,
+    }
+
+    /// sensor_msgs/msg/CompressedImage
+    #[derive(Default, Eq)]
+    pub struct CompressedImage in "sensor_msgs" {
+        pub header: Header,
+        pub format: String,
+        pub data: Vec<u8>,
+    }
+
+    /// sensor_msgs/msg/RegionOfInterest
+    #[derive(Default, Copy, Eq)]
+    pub struct RegionOfInterest in "sensor_msgs" {
+        pub x_offset: u32,
+        pub y_offset: u32,
+        pub height: u32,
+        pub width: u32,
+        pub do_rectify: bool,
+    }
+
+    /// sensor_msgs/msg/CameraInfo
+    #[derive(Default)]
+    pub struct CameraInfo in "sensor_msgs" {
+        pub header: Header,
+        pub height: u32,
+        pub width: u32,
+        pub distortion_model: String,
+        pub d: Vec<f64>,
+        pub k: [f64; 9],
+        pub r: [f64; 9],
+        pub p: [f64; 12],
+        pub binning_x: u32,
+        pub binning_y: u32,
+        pub roi: RegionOfInterest,
+    }
+
+    /// sensor_msgs/msg/PointField
+    #[derive(Default, Eq)]
+    pub struct PointField in "sensor_msgs" {
+        pub name: String,
+        pub offset: u32,
+        /// One of the `PointField::*` datatype constants.
+        pub datatype: u8,
+        pub count: u32,
+    }
+
+    /// sensor_msgs/msg/PointCloud2
+    #[derive(Default, Eq)]
+    pub struct PointCloud2 in "sensor_msgs" {
+        pub header: Header,
+        pub height: u32,
+        pub width: u32,
+        pub fields: Vec<PointField>,
+        pub is_bigendian: bool,
+        pub point_step: u32,
+        pub row_step: u32,
+        pub data: Vec<u8>,
+        pub is_dense: bool,
+    }
+
+    /// sensor_msgs/msg/NavSatStatus
+    #[derive(Default, Copy, Eq)]
+    pub struct NavSatStatus in "sensor_msgs" {
+        /// One of the `NavSatStatus::STATUS_*` constants.
+        pub status: i8,
+        /// Bit mask of `NavSatStatus::SERVICE_*`.
+        pub service: u16,
+    }
+
+    /// sensor_msgs/msg/NavSatFix
+    #[derive(Default)]
+    pub struct NavSatFix in "sensor_msgs" {
+        pub header: Header,
+        pub status: NavSatStatus,
+        pub latitude: f64,
+        pub longitude: f64,
+        pub altitude: f64,
+        pub position_covariance: [f64; 9],
+        /// One of the `NavSatFix::COVARIANCE_TYPE_*` constants.
+        pub position_covariance_type: u8,
+    }
+
+    /// sensor_msgs/msg/JointState
+    #[derive(Default)]
+    pub struct JointState in "sensor_msgs" {
+        pub header: Header,
+        pub name: Vec<String>,
+        pub position: Vec<f64>,
+        pub velocity: Vec<f64>,
+        pub effort: Vec<f64>,
+    }
+
+    /// sensor_msgs/msg/Temperature
+    #[derive(Default)]
+    pub struct Temperature in "sensor_msgs" {
+        pub header: Header,
+        pub temperature: f64,
+        pub variance: f64,
+    }
+
+    /// sensor_msgs/msg/FluidPressure
+    #[derive(Default)]
+    pub struct FluidPressure in "sensor_msgs" {
+        pub header: Header,
+        pub fluid_pressure: f64,
+        pub variance: f64,
+    }
+
+    /// sensor_msgs/msg/Illuminance
+    #[derive(Default)]
+    pub struct Illuminance in "sensor_msgs" {
+        pub header: Header,
+        pub illuminance: f64,
+        pub variance: f64,
+    }
+
+    /// sensor_msgs/msg/RelativeHumidity
+    #[derive(Default)]
+    pub struct RelativeHumidity in "sensor_msgs" {
+        pub header: Header,
+        pub relative_humidity: f64,
+        pub variance: f64,
+    }
+
+    /// sensor_msgs/msg/MagneticField
+    #[derive(Default)]
+    pub struct MagneticField in "sensor_msgs" {
+        pub header: Header,
+        pub magnetic_field: Vector3,
+        pub magnetic_field_covariance: [f64; 9],
+    }
+
+    /// sensor_msgs/msg/Joy
+    #[derive(Default)]
+    pub struct Joy in "sensor_msgs" {
+        pub header: Header,
+        pub axes: Vec<f32>,
+        pub buttons: Vec<i32>,
+    }
+}
+
+impl PointField {
+    pub const INT8: u8 = 1;
+    pub const UINT8: u8 = 2;
+    pub const INT16: u8 = 3;
+    pub const UINT16: u8 = 4;
+    pub const INT32: u8 = 5;
+    pub const UINT32: u8 = 6;
+    pub const FLOAT32: u8 = 7;
+    pub const FLOAT64: u8 = 8;
+}
+
+impl NavSatStatus {
+    pub const STATUS_NO_FIX: i8 = -1;
+    pub const STATUS_FIX: i8 = 0;
+    pub const STATUS_SBAS_FIX: i8 = 1;
+    pub const STATUS_GBAS_FIX: i8 = 2;
+
+    pub const SERVICE_GPS: u16 = 1;
+    pub const SERVICE_GLONASS: u16 = 2;
+    pub const SERVICE_COMPASS: u16 = 4;
+    pub const SERVICE_GALILEO: u16 = 8;
+}
+
+impl NavSatFix {
+    pub const COVARIANCE_TYPE_UNKNOWN: u8 = 0;
+    pub const COVARIANCE_TYPE_APPROXIMATED: u8 = 1;
+    pub const COVARIANCE_TYPE_DIAGONAL_KNOWN: u8 = 2;
+    pub const COVARIANCE_TYPE_KNOWN: u8 = 3;
+}
+
+impl Imu {
+    /// Set `orientation_covariance[0]` to -1: no orientation estimate.
+    pub fn without_orientation(mut self) -> Self {
+        self.orientation_covariance[0] = -1.0;
+        self
+    }
+}
+
+impl LaserScan {
+    /// Time of the `index`-th measurement.
+    pub fn measurement_time(&self, index: usize) -> Time {
+        let offset = f64::from(self.time_increment) * index as f64;
+        let nanos = i64::from(self.header.stamp.sec) * 1_000_000_000
+            + i64::from(self.header.stamp.nanosec)
+            + (offset * 1e9) as i64;
+        Time {
+            sec: nanos.div_euclid(1_000_000_000) as i32,
+            nanosec: nanos.rem_euclid(1_000_000_000) as u32,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cdr::{decode, encode};
+    use crate::RosMessage;
+
+    #[test]
+    fn test_point_cloud_roundtrip() {
+        let cloud = PointCloud2 {
+            header: Header {
+                stamp: Time {
+                    sec: 10,
+                    nanosec: 0,
+                },
+                frame_id: "lidar".into(),
+            },
+            height: 1,
+            width: 2,
+            fields: vec![PointField {
+                name: "x".into(),
+                offset: 0,
+                datatype: PointField::FLOAT32,
+                count: 1,
+            }],
+            is_bigendian: false,
+            point_step: 4,
+            row_step: 8,
+            data: vec![0, 0, 128, 63, 0, 0, 0, 64],
+            is_dense: true,
+        };
+        let mut buf = [0u8; 256];
+        let len = encode(&cloud, &mut buf).expect("encode");
+        assert_eq!(decode::<PointCloud2>(&buf[..len]).expect("decode"), cloud);
+        assert_eq!(
+            PointCloud2::DDS_TYPE_NAME,
+            "sensor_msgs::msg::dds_::PointCloud2_"
+        );
+    }
+
+    #[test]
+    fn test_laser_scan_measurement_time() {
+        let scan = LaserScan {
+            header: Header {
+                stamp: Time {
+                    sec: 1,
+                    nanosec: 900_000_000,
+                },
+                frame_id: String::new(),
+            },
+            time_increment: 0.05,
+            ..Default::default()
+        };
+        let time = scan.measurement_time(4);
+        assert_eq!(time.sec, 2);
+        assert!((time.nanosec as i64 - 100_000_000).abs() < 1_000);
+    }
+}
@@ -0,0 +1,225 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! std_msgs: primitive wrappers, `Header` and multi-arrays.
+//!
+//! `std_msgs::String` shadows the standard `String` in this module, hence
+//! the fully qualified field types.
+
+use crate::builtin_interfaces::Time;
+
+ros_message! {
+    /// std_msgs/msg/Bool
+    #[derive(Default, Copy, Eq)]
+    pub struct Bool in "std_msgs" {
+        pub data: bool,
+    }
+
+    /// std_msgs/msg/Empty
+    ///
+    /// rosidl gives empty messages a placeholder byte.
+    #[derive(Default, Copy, Eq)]
+    pub struct Empty in "std_msgs" {
+        pub structure_needs_at_least_one_member: u8,
+    }
+
+    /// std_msgs/msg/Int8
+    #[derive(Default, Copy, Eq)]
+    pub struct Int8 in "std_msgs" {
+        pub data: i8,
+    }
+
+    /// std_msgs/msg/Int16
+    #[derive(Default, Copy, Eq)]
+    pub struct Int16 in "std_msgs" {
+        pub data: i16,
+    }
+
+    /// std_msgs/msg/Int32
+    #[derive(Default, Copy, Eq)]
+    pub struct Int32 in "std_msgs" {
+        pub data: i32,
+    }
+
+    /// std_msgs/msg/Int64
+    #[derive(Default, Copy, Eq)]
+    pub struct Int64 in "std_msgs" {
+        pub data: i64,
+    }
+
+    /// std_msgs/msg/UInt8
+    #[derive(Default, Copy, Eq)]
+    pub struct UInt8 in "std_msgs" {
+        pub data: u8,
+    }
+
+    /// std_msgs/msg/UInt16
+    #[derive(Default, Copy, Eq)]
+    pub struct UInt16 in "std_msgs" {
+        pub data: u16,
+    }
+
+    /// std_msgs/msg/UInt32
+    #[derive(Default, Copy, Eq)]
+    pub struct UInt32 in "std_msgs" {
+        pub data: u32,
+    }
+
+    /// std_msgs/msg/UInt64
+    #[derive(Default, Copy, Eq)]
+    pub struct UInt64 in "std_msgs" {
+        pub data: u64,
+    }
+
+    /// std_msgs/msg/Float32
+    #[derive(Default, Copy)]
+    pub struct Float32 in "std_msgs" {
+        pub data: f32,
+    }
+
+    /// std_msgs/msg/Float64
+    #[derive(Default, Copy)]
+    pub struct Float64 in "std_msgs" {
+        pub data: f64,
+    }
+
+    /// std_msgs/msg/String
+    #[derive(Default, Eq)]
+    pub struct String in "std_msgs" {
+        pub data: std::string::String,
+    }
+
+    /// std_msgs/msg/Header
+    #[derive(Default, Eq)]
+    pub struct Header in "std_msgs" {
+        pub stamp: Time,
+        pub frame_id: std::string::String,
+    }
+
+    /// std_msgs/msg/ColorRGBA
+    #[derive(Default, Copy)]
+    pub struct ColorRGBA in "std_msgs" {
+        pub r: f32,
+        pub g: f32,
+        pub b: f32,
+        pub a: f32,
+    }
+
+    /// std_msgs/msg/MultiArrayDimension
+    #[derive(Default, Eq)]
+    pub struct MultiArrayDimension in "std_msgs" {
+        pub label: std::string::String,
+        pub size: u32,
+        pub stride: u32,
+    }
+
+    /// std_msgs/msg/MultiArrayLayout
+    #[derive(Default, Eq)]
+    pub struct MultiArrayLayout in "std_msgs" {
+        pub dim: Vec<MultiArrayDimension>,
+        pub data_offset: u32,
+    }
+
+    /// std_msgs/msg/Float32MultiArray
+    #[derive(Default)]
+    pub struct Float32MultiArray in "std_msgs" {
+        pub layout: MultiArrayLayout,
+        pub data: Vec<f32>,
+    }
+
+    /// std_msgs/msg/Float64MultiArray
+    #[derive(Default)]
+    pub struct Float64MultiArray in "std_msgs" {
+        pub layout: MultiArrayLayout,
+        pub data: Vec<f64>,
+    }
+
+    /// std_msgs/msg/Int32MultiArray
+    #[derive(Default, Eq)]
+    pub struct Int32MultiArray in "std_msgs" {
+        pub layout: MultiArrayLayout,
+        pub data: Vec<i32>,
+    }
+
+    /// std_msgs/msg/UInt8MultiArray
+    #[derive(Default, Eq)]
+    pub struct UInt8MultiArray in "std_msgs" {
+        pub layout: MultiArrayLayout,
+        pub data: Vec<u8>,
+    }
+}
+
+impl Header {
+    /// Header stamped with the current time.
+    pub fn now(frame_id: impl Into<std::string::String>) -> Self {
+        Self {
+            stamp: Time::now(),
+            frame_id: frame_id.into(),
+        }
+    }
+}
+
+impl From<&str> for String {
+    fn from(data: &str) -> Self {
+        Self { data: data.into() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cdr::{decode, encode};
+    use crate::RosMessage;
+
+    #[test]
+    fn test_type_names_and_hashes() {
+        assert_eq!(String::TYPE_NAME, "std_msgs/msg/String");
+        assert_eq!(String::DDS_TYPE_NAME, "std_msgs::msg::dds_::String_");
+        assert_eq!(
+            String::type_hash().to_string(),
+            "RIHS01_df668c740482bbd48fb39d76a70dfd4bd59db1288021743503259e948f6b1a18"
+        );
+        assert_eq!(
+            Header::type_hash().to_string(),
+            "RIHS01_f49fb3ae2cf070f793645ff749683ac6b06203e41c891e17701b1cb597ce6a01"
+        );
+        let empty = Empty::type_description();
+        assert_eq!(empty.fields[0].name, "structure_needs_at_least_one_member");
+    }
+
+    #[test]
+    fn test_header_layout() {
+        let header = Header {
+            stamp: Time { sec: 1, nanosec: 2 },
+            frame_id: "map".into(),
+        };
+        let mut buf = [0u8; 64];
+        let len = encode(&header, &mut buf).expect("encode");
+        assert_eq!(
+            &buf[..len],
+            &[1, 0, 0, 0, 2, 0, 0, 0, 4, 0, 0, 0, b'm', b'a', b'p', 0]
+        );
+        assert_eq!(decode::<Header>(&buf[..len]).expect("decode"), header);
+    }
+
+    #[test]
+    fn test_multi_array_roundtrip() {
+        let array = Float64MultiArray {
+            layout: MultiArrayLayout {
+                dim: vec![MultiArrayDimension {
+                    label: "x".into(),
+                    size: 2,
+                    stride: 2,
+                }],
+                data_offset: 0,
+            },
+            data: vec![1.0, 2.0],
+        };
+        let mut buf = [0u8; 128];
+        let len = encode(&array, &mut buf).expect("encode");
+        assert_eq!(
+            decode::<Float64MultiArray>(&buf[..len]).expect("decode"),
+            array
+        );
+    }
+}
@@ -5,6 +5,7 @@
 //!
 //! Supports both programmatic and file-based configuration.
 
+use crate::filter::RouteFilter;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::Path;
@@ -113,6 +114,10 @@ impl RouterConfig {
                 topics: TopicSelection::All,
                 remaps: Vec::new(),
                 qos_transform: None,
+                transforms: Vec::new(),
+                filter: None,
+                filter_parameters: Vec::new(),
+                max_rate: None,
             }],
             ..Default::default()
         }
@@ -128,6 +133,10 @@ impl RouterConfig {
                 topics: TopicSelection::All,
                 remaps: Vec::new(),
                 qos_transform: None,
+                transforms: Vec::new(),
+                filter: None,
+                filter_parameters: Vec::new(),
+                max_rate: None,
             }],
             ..Default::default()
         }
@@ -162,6 +171,28 @@ impl RouterConfig {
                     )));
                 }
             }
+
+            if route.transforms.iter().any(String::is_empty) {
+                return Err(ConfigError::Invalid(format!(
+                    "Route {} has empty transform name",
+                    i
+                )));
+            }
+
+            if let Some(expression) = &route.filter {
+                RouteFilter::new(expression, route.filter_parameters.clone()).map_err(|e| {
+                    ConfigError::Invalid(format!("Route {} has invalid filter: {}", i, e))
+                })?;
+            }
+
+            if let Some(rate) = route.max_rate {
+                if !(rate.is_finite() && rate > 0.0) {
+                    return Err(ConfigError::Invalid(format!(
+                        "Route {} has invalid max_rate ({})",
+                        i, rate
+                    )));
+                }
+            }
         }
 
         Ok(())
@@ -204,6 +235,26 @@ pub struct RouteConfig {
     /// QoS transformation.
     #[serde(default)]
     pub qos_transform: Option<QosTransformConfig>,
+
+    /// Names of message transformation hooks, applied in order.
+    ///
+    /// Hooks are user code registered with
+    /// [`Router::register_transform`](crate::Router::register_transform).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub transforms: Vec<String>,
+
+    /// Content filter expression (`hdds::dds::filter` syntax); samples not
+    /// matching it are dropped.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+
+    /// Values of the filter's `%0`, `%1`, ... parameters.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub filter_parameters: Vec<String>,
+
+    /// Maximum messages per second forwarded for each topic.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_rate: Option<f64>,
 }
 
 impl RouteConfig {
@@ -216,6 +267,10 @@ impl RouteConfig {
             topics: TopicSelection::All,
             remaps: Vec::new(),
             qos_transform: None,
+            transforms: Vec::new(),
+            filter: None,
+            filter_parameters: Vec::new(),
+            max_rate: None,
         }
     }
 
@@ -245,6 +300,30 @@ impl RouteConfig {
         self.qos_transform = Some(transform);
         self
     }
+
+    /// Append a message transformation hook (by registered name).
+    pub fn transform(mut self, name: impl Into<String>) -> Self {
+        self.transforms.push(name.into());
+        self
+    }
+
+    /// Set the content filter expression.
+    pub fn filter(mut self, expression: impl Into<String>) -> Self {
+        self.filter = Some(expression.into());
+        self
+    }
+
+    /// Set the content filter parameters.
+    pub fn filter_parameters(mut self, parameters: Vec<String>) -> Self {
+        self.filter_parameters = parameters;
+        self
+    }
+
+    /// Limit each topic to `max_rate` messages per second.
+    pub fn max_rate(mut self, max_rate: f64) -> Self {
+        self.max_rate = Some(max_rate);
+        self
+    }
 }
 
 /// Topic selection for routing.
@@ -487,6 +566,59 @@ mod tests {
         assert_eq!(route.remaps.len(), 1);
     }
 
+    #[test]
+    fn test_route_config_transforms() {
+        let toml_str = r#"
+[[routes]]
+from_domain = 0
+to_domain = 1
+transforms = ["celsius_to_kelvin", "scrub_pii"]
+"#;
+        let config: RouterConfig = toml::from_str(toml_str).expect("parse");
+        assert_eq!(
+            config.routes[0].transforms,
+            vec!["celsius_to_kelvin".to_string(), "scrub_pii".to_string()]
+        );
+
+        let mut config = RouterConfig::default();
+        config.add_route(RouteConfig::new(0, 1).transform(""));
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_route_config_filter_and_rate() {
+        let toml_str = r#"
+[[routes]]
+from_domain = 0
+to_domain = 1
+filter = "speed > %0 AND vehicle.id = 'rover'"
+filter_parameters = ["5"]
+max_rate = 2.5
+"#;
+        let config: RouterConfig = toml::from_str(toml_str).expect("parse");
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            config.routes[0].filter.as_deref(),
+            Some("speed > %0 AND vehicle.id = 'rover'")
+        );
+        assert_eq!(config.routes[0].filter_parameters, vec!["5".to_string()]);
+        assert_eq!(config.routes[0].max_rate, Some(2.5));
+
+        let serialized = toml::to_string_pretty(&config).expect("serialize");
+        let reparsed: RouterConfig = toml::from_str(&serialized).expect("reparse");
+        assert_eq!(reparsed.routes[0].max_rate, Some(2.5));
+
+        let mut config = RouterConfig::default();
+        config.add_route(RouteConfig::new(0, 1).filter("speed >"));
+        assert!(config.validate().is_err());
+
+        for rate in [0.0, -1.0, f64::NAN] {
+            let mut config = RouterConfig::default();
+            config.add_route(RouteConfig::new(0, 1).max_rate(rate));
+            assert!(config.validate().is_err());
+        }
+    }
+
     #[test]
     fn test_config_serialization() {
         let config = RouterConfig::bridge(0, 1);
@@ -0,0 +1,239 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Per-route content filtering and rate limiting.
+//!
+//! A content filter is a DDS filter expression (`hdds::dds::filter` syntax)
+//! evaluated on every sample, decoded through the type discovered with the
+//! topic. Nested members are addressed with dots (`pose.position.x`), enums
+//! compare by enumerator name and unions by their active case; sequences and
+//! arrays cannot be filtered on.
+//!
+//! A rate limit caps each routed topic at `max_rate` messages per second,
+//! evenly spaced: a 100 Hz topic limited to 10 Hz forwards every tenth
+//! sample.
+//!
+//! The filter sees samples as published in the source domain, before message
+//! transforms; the rate limit applies last, to what is about to be written.
+
+use hdds::dds::filter::{ContentFilter, FieldValue};
+use hdds::dynamic::{decode_dynamic, DynamicValue, TypeDescriptor};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// Content filter errors.
+#[derive(Debug, Error)]
+pub enum FilterError {
+    #[error("{0}")]
+    Expression(#[from] hdds::dds::FilterError),
+
+    #[error("Sample decode failed: {0}")]
+    Decode(String),
+}
+
+/// Content filter of a route.
+#[derive(Debug, Clone)]
+pub struct RouteFilter {
+    filter: ContentFilter,
+}
+
+impl RouteFilter {
+    /// Parse a filter expression; `parameters` replace `%0`, `%1`, ...
+    pub fn new(expression: &str, parameters: Vec<String>) -> Result<Self, FilterError> {
+        Ok(Self {
+            filter: ContentFilter::with_parameters(expression, parameters)?,
+        })
+    }
+
+    /// The filter expression.
+    pub fn expression(&self) -> &str {
+        self.filter.expression()
+    }
+
+    /// Check whether a serialized sample of type `descriptor` passes.
+    pub fn matches(
+        &self,
+        payload: &[u8],
+        descriptor: &Arc<TypeDescriptor>,
+    ) -> Result<bool, FilterError> {
+        let sample =
+            decode_dynamic(payload, descriptor).map_err(|e| FilterError::Decode(e.to_string()))?;
+        Ok(self
+            .filter
+            .evaluator()
+            .matches(&sample_fields(sample.value()))?)
+    }
+}
+
+/// Filterable members of a sample, keyed by dotted member path.
+pub fn sample_fields(value: &DynamicValue) -> HashMap<String, FieldValue> {
+    let mut fields = HashMap::new();
+    collect_fields("", value, &mut fields);
+    fields
+}
+
+fn collect_fields(path: &str, value: &DynamicValue, fields: &mut HashMap<String, FieldValue>) {
+    let field = match value {
+        DynamicValue::Struct(members) => {
+            for (name, member) in members {
+                collect_fields(&member_path(path, name), member, fields);
+            }
+            return;
+        }
+        DynamicValue::Union(_, case, member) => {
+            collect_fields(&member_path(path, case), member, fields);
+            return;
+        }
+        DynamicValue::Bool(v) => FieldValue::Boolean(*v),
+        DynamicValue::U8(v) => FieldValue::Unsigned(u64::from(*v)),
+        DynamicValue::U16(v) => FieldValue::Unsigned(u64::from(*v)),
+        DynamicValue::U32(v) => FieldValue::Unsigned(u64::from(*v)),
+        DynamicValue::U64(v) => FieldValue::Unsigned(*v),
+        DynamicValue::I8(v) => FieldValue::Integer(i64::from(*v)),
+        DynamicValue::I16(v) => FieldValue::Integer(i64::from(*v)),
+        DynamicValue::I32(v) => FieldValue::Integer(i64::from(*v)),
+        DynamicValue::I64(v) => FieldValue::Integer(*v),
+        DynamicValue::F32(v) => FieldValue::Float(f64::from(*v)),
+        DynamicValue::F64(v) => FieldValue::Float(*v),
+        DynamicValue::Char(v) => FieldValue::String(v.to_string()),
+        DynamicValue::String(v) | DynamicValue::WString(v) => FieldValue::String(v.clone()),
+        DynamicValue::Enum(_, name) => FieldValue::String(name.clone()),
+        DynamicValue::LongDouble(_)
+        | DynamicValue::Sequence(_)
+        | DynamicValue::Array(_)
+        | DynamicValue::Null => return,
+    };
+    if !path.is_empty() {
+        fields.insert(path.to_string(), field);
+    }
+}
+
+fn member_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", path, name)
+    }
+}
+
+/// Rate limiter of one routed topic.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    interval: Duration,
+    next: Option<Instant>,
+}
+
+impl RateLimiter {
+    /// Limit to `max_rate` messages per second (must be positive).
+    pub fn new(max_rate: f64) -> Self {
+        Self {
+            interval: Duration::from_secs_f64(1.0 / max_rate),
+            next: None,
+        }
+    }
+
+    /// Whether a message arriving at `now` may be forwarded.
+    pub fn allow(&mut self, now: Instant) -> bool {
+        match self.next {
+            Some(next) if now < next => false,
+            Some(next) if now < next + self.interval => {
+                // Keep the slots evenly spaced despite arrival jitter
+                self.next = Some(next + self.interval);
+                true
+            }
+            _ => {
+                self.next = Some(now + self.interval);
+                true
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hdds::dynamic::{encode_dynamic, DynamicData, PrimitiveKind, TypeDescriptorBuilder};
+
+    fn descriptor() -> Arc<TypeDescriptor> {
+        let position = Arc::new(
+            TypeDescriptorBuilder::new("Position")
+                .field("x", PrimitiveKind::F64)
+                .field("y", PrimitiveKind::F64)
+                .build(),
+        );
+        Arc::new(
+            TypeDescriptorBuilder::new("Telemetry")
+                .field("vehicle_id", PrimitiveKind::U32)
+                .field("speed", PrimitiveKind::F32)
+                .string_field("status")
+                .nested_field("position", position)
+                .build(),
+        )
+    }
+
+    fn sample(descriptor: &Arc<TypeDescriptor>, vehicle_id: u32, speed: f32, x: f64) -> Vec<u8> {
+        let mut data = DynamicData::new(descriptor);
+        data.set("vehicle_id", vehicle_id).expect("vehicle_id");
+        data.set("speed", speed).expect("speed");
+        data.set("status", "moving".to_string()).expect("status");
+        let position = data.get_field_mut("position").expect("position");
+        position.set_field("x", DynamicValue::F64(x));
+        position.set_field("y", DynamicValue::F64(0.0));
+        encode_dynamic(&data).expect("encode")
+    }
+
+    #[test]
+    fn test_sample_fields_flattens_nested_members() {
+        let descriptor = descriptor();
+        let payload = sample(&descriptor, 7, 12.5, 3.0);
+        let data = decode_dynamic(&payload, &descriptor).expect("decode");
+        let fields = sample_fields(data.value());
+
+        assert_eq!(fields.get("vehicle_id"), Some(&FieldValue::Unsigned(7)));
+        assert_eq!(fields.get("speed"), Some(&FieldValue::Float(12.5)));
+        assert_eq!(fields.get("position.x"), Some(&FieldValue::Float(3.0)));
+        assert_eq!(
+            fields.get("status"),
+            Some(&FieldValue::String("moving".into()))
+        );
+    }
+
+    #[test]
+    fn test_route_filter_matches_payload() {
+        let descriptor = descriptor();
+        let filter =
+            RouteFilter::new("speed > %0 AND position.x < 10", vec!["5".into()]).expect("filter");
+        assert_eq!(filter.expression(), "speed > %0 AND position.x < 10");
+
+        let fast = sample(&descriptor, 1, 20.0, 1.0);
+        let slow = sample(&descriptor, 1, 2.0, 1.0);
+        let far = sample(&descriptor, 1, 20.0, 50.0);
+        assert!(filter.matches(&fast, &descriptor).expect("fast"));
+        assert!(!filter.matches(&slow, &descriptor).expect("slow"));
+        assert!(!filter.matches(&far, &descriptor).expect("far"));
+
+        assert!(matches!(
+            filter.matches(&[1, 2], &descriptor),
+            Err(FilterError::Decode(_))
+        ));
+        assert!(RouteFilter::new("speed >", Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_rate_limiter_downsamples() {
+        let mut limiter = RateLimiter::new(10.0);
+        let start = Instant::now();
+
+        // 100 Hz for one second: every tenth sample goes through
+        let forwarded = (0..100)
+            .filter(|i| limiter.allow(start + Duration::from_millis(i * 10)))
+            .count();
+        assert_eq!(forwarded, 10);
+
+        // After a pause the next sample goes through immediately
+        assert!(limiter.allow(start + Duration::from_secs(5)));
+        assert!(!limiter.allow(start + Duration::from_millis(5050)));
+    }
+}
@@ -11,7 +11,12 @@
 //! - **Domain Bridging**: Route messages between DDS domains
 //! - **Topic Remapping**: Rename topics during routing
 //! - **QoS Transformation**: Modify QoS policies during routing
-//! - **Content Filtering**: Filter messages based on content
+//! - **Content Filtering**: Drop messages not matching a per-route filter
+//!   expression (see [`filter`])
+//! - **Rate Limiting**: Downsample each topic of a route to `max_rate`
+//!   messages per second
+//! - **Transformation Hooks**: User code rewriting or dropping messages per
+//!   route ([`MessageTransform`], registered with [`Router::register_transform`])
 //!
 //! # Quick Start
 //!
@@ -40,14 +45,31 @@
 //! [[routes.remaps]]
 //! from = "Sensor/Temperature"
 //! to = "Vehicle/Engine/Temperature"
+//!
+//! [[routes]]
+//! from_domain = 1
+//! to_domain = 2
+//! transforms = ["celsius_to_kelvin"]  # registered by the embedding application
+//!
+//! [[routes]]
+//! from_domain = 0
+//! to_domain = 3
+//! filter = "speed > %0 AND position.z < 100"
+//! filter_parameters = ["2.5"]
+//! max_rate = 5.0  # messages per second, per topic
 //! ```
 
 pub mod config;
+pub mod filter;
 pub mod route;
 pub mod router;
 pub mod transform;
 
 pub use config::{DomainConfig, RouteConfig, RouterConfig, TopicRemap};
+pub use filter::{RateLimiter, RouteFilter};
 pub use route::{Route, RouteStats, RouteStatsSnapshot};
-pub use router::{Router, RouterError, RouterHandle};
-pub use transform::{QosTransform, TopicTransform};
+pub use router::{RoutedMessage, Router, RouterError, RouterHandle};
+pub use transform::{
+    MessageTransform, QosTransform, TopicTransform, TransformAction, TransformChain,
+    TransformContext,
+};
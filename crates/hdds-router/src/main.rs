@@ -22,6 +22,9 @@
 //!
 //! # Filter specific topics
 //! hdds-router --from-domain 0 --to-domain 1 --topics Temperature,Pressure
+//!
+//! # Filter on content and downsample to 5 msg/s per topic
+//! hdds-router --from-domain 0 --to-domain 1 --filter "speed > 2.5" --max-rate 5
 //! ```
 
 use clap::{Parser, Subcommand};
@@ -64,6 +67,14 @@ struct Args {
     #[arg(long, value_delimiter = ',')]
     exclude: Option<Vec<String>>,
 
+    /// Content filter expression samples must match (e.g. "speed > 2.5")
+    #[arg(long, conflicts_with = "config")]
+    filter: Option<String>,
+
+    /// Maximum messages per second routed for each topic
+    #[arg(long, conflicts_with = "config")]
+    max_rate: Option<f64>,
+
     /// Discovery Server for a domain (format: "domain_id:host:port", can repeat)
     #[arg(long = "discovery-server", value_name = "DOMAIN:ADDR")]
     discovery_servers: Option<Vec<String>>,
@@ -229,6 +240,10 @@ fn build_config(args: &Args) -> Result<RouterConfig, RouterError> {
         topics,
         remaps,
         qos_transform: None,
+        transforms: Vec::new(),
+        filter: args.filter.clone(),
+        filter_parameters: Vec::new(),
+        max_rate: args.max_rate,
     };
 
     let mut config = RouterConfig::default();
@@ -295,6 +310,10 @@ fn cmd_gen_config(output: PathBuf) -> Result<(), Box<dyn std::error::Error>> {
                     deadline_us: None,
                     lifespan_us: None,
                 }),
+                transforms: Vec::new(),
+                filter: Some("value > %0".into()),
+                filter_parameters: vec!["-40".into()],
+                max_rate: Some(10.0),
             },
             RouteConfig {
                 from_domain: 2,
@@ -303,6 +322,10 @@ fn cmd_gen_config(output: PathBuf) -> Result<(), Box<dyn std::error::Error>> {
                 topics: hdds_router::config::TopicSelection::Exclude(vec!["Internal/*".into()]),
                 remaps: Vec::new(),
                 qos_transform: None,
+                transforms: Vec::new(),
+                filter: None,
+                filter_parameters: Vec::new(),
+                max_rate: None,
             },
         ],
         domains,
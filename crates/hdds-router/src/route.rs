@@ -4,7 +4,9 @@
 //! Route definition and statistics.
 
 use crate::config::{RouteConfig, TopicSelection};
-use crate::transform::{QosTransform, TopicTransform};
+use crate::filter::RouteFilter;
+use crate::transform::{MessageTransform, QosTransform, TopicTransform, TransformChain};
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
@@ -27,6 +29,18 @@ pub struct Route {
     /// QoS transformer.
     pub qos_transform: QosTransform,
 
+    /// Message transformation hooks named in the configuration.
+    pub transform_names: Vec<String>,
+
+    /// Registered hooks of `transform_names`, in order.
+    pub transforms: TransformChain,
+
+    /// Content filter samples must match to be routed.
+    pub filter: Option<RouteFilter>,
+
+    /// Maximum messages per second routed for each topic.
+    pub max_rate: Option<f64>,
+
     /// Route statistics.
     pub stats: Arc<RouteStats>,
 }
@@ -40,6 +54,13 @@ impl Route {
             topics: config.topics.clone(),
             topic_transform: TopicTransform::new(config.remaps.clone()),
             qos_transform: QosTransform::new(config.qos_transform.clone()),
+            transform_names: config.transforms.clone(),
+            transforms: TransformChain::new(),
+            // Expression checked by `RouterConfig::validate`
+            filter: config.filter.as_deref().and_then(|expression| {
+                RouteFilter::new(expression, config.filter_parameters.clone()).ok()
+            }),
+            max_rate: config.max_rate,
             stats: Arc::new(RouteStats::new(config.from_domain, config.to_domain)),
         }
     }
@@ -54,6 +75,25 @@ impl Route {
         self.topic_transform.transform(topic)
     }
 
+    /// Rebuild the hook chain from the registered transforms.
+    pub fn resolve_transforms(&mut self, registered: &HashMap<String, Arc<dyn MessageTransform>>) {
+        let mut chain = TransformChain::new();
+        for name in &self.transform_names {
+            if let Some(hook) = registered.get(name) {
+                chain.push(name.clone(), Arc::clone(hook));
+            }
+        }
+        self.transforms = chain;
+    }
+
+    /// First configured transform that is not registered, if any.
+    pub fn missing_transform(&self) -> Option<&str> {
+        self.transform_names
+            .iter()
+            .map(String::as_str)
+            .find(|name| !self.transforms.names().any(|resolved| resolved == *name))
+    }
+
     /// Record a routed message.
     pub fn record_message(&self, bytes: u64) {
         self.stats.messages_routed.fetch_add(1, Ordering::Relaxed);
@@ -171,6 +211,24 @@ mod tests {
         assert_eq!(route.to_domain, 1);
         assert!(route.matches_topic("Temperature"));
         assert!(!route.matches_topic("Pressure"));
+        assert!(route.filter.is_none());
+        assert_eq!(route.max_rate, None);
+    }
+
+    #[test]
+    fn test_route_filter_and_rate_from_config() {
+        let config = RouteConfig::new(0, 1)
+            .filter("speed > %0")
+            .filter_parameters(vec!["5".into()])
+            .max_rate(10.0);
+
+        let route = Route::from_config(&config);
+
+        assert_eq!(
+            route.filter.as_ref().map(RouteFilter::expression),
+            Some("speed > %0")
+        );
+        assert_eq!(route.max_rate, Some(10.0));
     }
 
     #[test]
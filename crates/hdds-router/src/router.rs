@@ -6,12 +6,17 @@
 //! The Router manages DDS participants and routes messages between domains.
 
 use crate::config::{RouteConfig, RouterConfig, TopicSelection};
+use crate::filter::{RateLimiter, RouteFilter};
 use crate::route::{Route, RouteStats, RouteStatsSnapshot};
-use crate::transform::{QosTransform, TopicTransform};
+use crate::transform::{
+    ChainOutcome, MessageTransform, QosTransform, TopicTransform, TransformChain, TransformContext,
+};
 use hdds::dds::{
     Deadline, Durability as HddsDurability, History, Lifespan, QoS, Reliability as HddsReliability,
 };
-use hdds::{Participant, RawDataReader, RawDataWriter, TransportMode};
+use hdds::dynamic::{type_descriptor_from_xtypes, TypeDescriptor};
+use hdds::xtypes::CompleteTypeObject;
+use hdds::{Participant, RawDataReader, RawDataWriter, RawSample, TransportMode};
 use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
@@ -37,6 +42,9 @@ pub enum RouterError {
     #[error("Router already running")]
     AlreadyRunning,
 
+    #[error("Transform not registered: {0}")]
+    UnknownTransform(String),
+
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
 }
@@ -105,6 +113,9 @@ struct RouteRuntime {
     topics: TopicSelection,
     topic_transform: TopicTransform,
     qos_transform: QosTransform,
+    transforms: TransformChain,
+    filter: Option<RouteFilter>,
+    max_rate: Option<f64>,
     stats: Arc<RouteStats>,
 }
 
@@ -116,6 +127,9 @@ impl RouteRuntime {
             topics: route.topics.clone(),
             topic_transform: route.topic_transform.clone(),
             qos_transform: route.qos_transform.clone(),
+            transforms: route.transforms.clone(),
+            filter: route.filter.clone(),
+            max_rate: route.max_rate,
             stats: route.stats.clone(),
         }
     }
@@ -175,6 +189,10 @@ impl RouteRuntime {
         self.stats.bytes_routed.fetch_add(bytes, Ordering::Relaxed);
     }
 
+    fn record_dropped(&self) {
+        self.stats.messages_dropped.fetch_add(1, Ordering::Relaxed);
+    }
+
     fn record_error(&self) {
         self.stats.errors.fetch_add(1, Ordering::Relaxed);
     }
@@ -183,14 +201,47 @@ impl RouteRuntime {
 struct RouteEndpoint {
     source_topic: String,
     dest_topic: String,
+    type_name: String,
+    type_object: Option<CompleteTypeObject>,
+    /// Sample type for the route's content filter (`None` without filter
+    /// or without type information).
+    descriptor: Option<Arc<TypeDescriptor>>,
+    rate_limiter: Option<RateLimiter>,
+    writer_qos: QoS,
     reader: RawDataReader,
-    writer: RawDataWriter,
+    /// Writers by destination topic: `dest_topic`, plus topics chosen by
+    /// message transforms.
+    writers: HashMap<String, RawDataWriter>,
+}
+
+impl RouteEndpoint {
+    /// Writer for `topic`, created on first use.
+    fn writer_for(
+        &mut self,
+        route: &RouteRuntime,
+        to: &Arc<Participant>,
+        topic: &str,
+    ) -> Option<&RawDataWriter> {
+        if !self.writers.contains_key(topic) {
+            let writer = create_writer(
+                route,
+                to,
+                topic,
+                &self.type_name,
+                &self.writer_qos,
+                &self.type_object,
+            )?;
+            self.writers.insert(topic.to_string(), writer);
+        }
+        self.writers.get(topic)
+    }
 }
 
 /// DDS Routing Service.
 pub struct Router {
     config: RouterConfig,
     routes: Vec<Route>,
+    transforms: HashMap<String, Arc<dyn MessageTransform>>,
     running: Arc<AtomicBool>,
     start_time: Option<Instant>,
 }
@@ -222,6 +273,10 @@ impl Router {
                         })
                         .collect(),
                     qos_transform: route_config.qos_transform.clone(),
+                    transforms: route_config.transforms.clone(),
+                    filter: route_config.filter.clone(),
+                    filter_parameters: route_config.filter_parameters.clone(),
+                    max_rate: route_config.max_rate,
                 };
                 routes.push(Route::from_config(&reverse_config));
             }
@@ -230,6 +285,7 @@ impl Router {
         Ok(Self {
             config,
             routes,
+            transforms: HashMap::new(),
             running: Arc::new(AtomicBool::new(false)),
             start_time: None,
         })
@@ -257,6 +313,22 @@ impl Router {
         &self.routes
     }
 
+    /// Register a message transformation hook under `name`.
+    ///
+    /// Routes listing `name` in their `transforms` run the hook on every
+    /// message; registering the same name again replaces the hook. All
+    /// configured transforms must be registered before [`run`](Self::run).
+    pub fn register_transform(
+        &mut self,
+        name: impl Into<String>,
+        transform: impl MessageTransform + 'static,
+    ) {
+        self.transforms.insert(name.into(), Arc::new(transform));
+        for route in &mut self.routes {
+            route.resolve_transforms(&self.transforms);
+        }
+    }
+
     /// Get route statistics.
     pub fn route_stats(&self) -> Vec<RouteStatsSnapshot> {
         self.routes.iter().map(|r| r.stats.snapshot()).collect()
@@ -270,20 +342,24 @@ impl Router {
     }
 
     /// Route a message.
+    ///
+    /// Content filters and rate limits are not applied here: they need the
+    /// discovered sample type and the per-topic state of a running route.
     pub fn route_message(&self, msg: &RoutedMessage) -> Result<Option<RoutedMessage>, RouterError> {
         let route = match self.find_route(msg.source_domain, &msg.topic_name) {
             Some(r) => r,
             None => return Ok(None), // No route for this message
         };
 
+        if let Some(name) = route.missing_transform() {
+            return Err(RouterError::UnknownTransform(name.to_string()));
+        }
+
         // Transform topic name
         let dest_topic = route.transform_topic(&msg.topic_name);
 
-        // Record stats
-        route.record_message(msg.payload.len() as u64);
-
         // Create routed message
-        let routed = RoutedMessage {
+        let mut routed = RoutedMessage {
             source_domain: route.to_domain,
             topic_name: dest_topic,
             type_name: msg.type_name.clone(),
@@ -292,6 +368,27 @@ impl Router {
             writer_guid: msg.writer_guid.clone(),
         };
 
+        // Apply message transforms
+        let ctx = TransformContext {
+            from_domain: route.from_domain,
+            to_domain: route.to_domain,
+            source_topic: &msg.topic_name,
+        };
+        match route.transforms.apply(&ctx, &mut routed) {
+            ChainOutcome::Forward => {}
+            ChainOutcome::Dropped => {
+                route.record_dropped();
+                return Ok(None);
+            }
+            ChainOutcome::Failed => {
+                route.record_error();
+                return Ok(None);
+            }
+        }
+
+        // Record stats
+        route.record_message(routed.payload.len() as u64);
+
         Ok(Some(routed))
     }
 
@@ -304,6 +401,10 @@ impl Router {
             return Err(RouterError::AlreadyRunning);
         }
 
+        if let Some(name) = self.routes.iter().find_map(Route::missing_transform) {
+            return Err(RouterError::UnknownTransform(name.to_string()));
+        }
+
         self.running.store(true, Ordering::Relaxed);
         self.start_time = Some(Instant::now());
 
@@ -324,6 +425,12 @@ impl Router {
                     "no"
                 }
             );
+            if let Some(filter) = &route.filter {
+                tracing::info!("    filter: {}", filter.expression());
+            }
+            if let Some(max_rate) = route.max_rate {
+                tracing::info!("    max rate: {} msg/s per topic", max_rate);
+            }
         }
 
         // Create stats channel
@@ -504,22 +611,15 @@ async fn run_route(
                             }
                         };
 
-                        let writer = match to.create_raw_writer_with_type(
+                        let Some(writer) = create_writer(
+                            &route,
+                            &to,
                             &dest_topic,
                             &info.type_name,
-                            Some(writer_qos),
-                            info.type_object.clone(),
-                        ) {
-                            Ok(writer) => writer,
-                            Err(err) => {
-                                route.record_error();
-                                tracing::warn!(
-                                    "Failed to create raw writer for {}: {}",
-                                    dest_topic,
-                                    err
-                                );
-                                continue;
-                            }
+                            &writer_qos,
+                            &info.type_object,
+                        ) else {
+                            continue;
                         };
 
                         tracing::info!(
@@ -530,13 +630,28 @@ async fn run_route(
                             info.type_name
                         );
 
+                        let descriptor = route.filter.as_ref().and_then(|_| {
+                            info.type_object.as_ref().map(type_descriptor_from_xtypes)
+                        });
+                        if route.filter.is_some() && descriptor.is_none() {
+                            tracing::warn!(
+                                "No type information for {}: its samples cannot be filtered and are dropped",
+                                info.name
+                            );
+                        }
+
                         endpoints.insert(
                             info.name.clone(),
                             RouteEndpoint {
                                 source_topic: info.name,
+                                writers: HashMap::from([(dest_topic.clone(), writer)]),
                                 dest_topic,
+                                type_name: info.type_name,
+                                type_object: info.type_object,
+                                descriptor,
+                                rate_limiter: route.max_rate.map(RateLimiter::new),
+                                writer_qos,
                                 reader,
-                                writer,
                             },
                         );
                     }
@@ -555,23 +670,11 @@ async fn run_route(
             last_discovery = Instant::now();
         }
 
-        for endpoint in endpoints.values() {
+        for endpoint in endpoints.values_mut() {
             match endpoint.reader.try_take_raw() {
                 Ok(samples) => {
                     for sample in samples {
-                        let payload_len = sample.payload.len() as u64;
-                        if let Err(err) = endpoint.writer.write_raw(&sample.payload) {
-                            route.record_error();
-                            tracing::debug!(
-                                "Route {} -> {} write failed for {}: {}",
-                                route.from_domain,
-                                route.to_domain,
-                                endpoint.dest_topic,
-                                err
-                            );
-                        } else {
-                            route.record_message(payload_len);
-                        }
+                        forward_sample(&route, &to, endpoint, sample);
                     }
                 }
                 Err(err) => {
@@ -591,10 +694,116 @@ async fn run_route(
     }
 }
 
+fn create_writer(
+    route: &RouteRuntime,
+    to: &Arc<Participant>,
+    topic: &str,
+    type_name: &str,
+    qos: &QoS,
+    type_object: &Option<CompleteTypeObject>,
+) -> Option<RawDataWriter> {
+    match to.create_raw_writer_with_type(topic, type_name, Some(qos.clone()), type_object.clone()) {
+        Ok(writer) => Some(writer),
+        Err(err) => {
+            route.record_error();
+            tracing::warn!("Failed to create raw writer for {}: {}", topic, err);
+            None
+        }
+    }
+}
+
+/// Run the route's content filter, message transforms and rate limit on
+/// `sample` and write it to the destination domain.
+fn forward_sample(
+    route: &RouteRuntime,
+    to: &Arc<Participant>,
+    endpoint: &mut RouteEndpoint,
+    sample: RawSample,
+) {
+    if let Some(filter) = &route.filter {
+        let Some(descriptor) = &endpoint.descriptor else {
+            route.record_error();
+            return;
+        };
+        match filter.matches(&sample.payload, descriptor) {
+            Ok(true) => {}
+            Ok(false) => {
+                route.record_dropped();
+                return;
+            }
+            Err(err) => {
+                route.record_error();
+                tracing::debug!(
+                    "Route {} -> {} filter failed for {}: {}",
+                    route.from_domain,
+                    route.to_domain,
+                    endpoint.source_topic,
+                    err
+                );
+                return;
+            }
+        }
+    }
+
+    let (topic, payload) = if route.transforms.is_empty() {
+        (endpoint.dest_topic.clone(), sample.payload)
+    } else {
+        let mut msg = RoutedMessage {
+            source_domain: route.from_domain,
+            topic_name: endpoint.dest_topic.clone(),
+            type_name: endpoint.type_name.clone(),
+            payload: sample.payload.to_vec(),
+            sequence_number: sample.sequence_number.unwrap_or(0),
+            writer_guid: sample.writer_guid.to_string(),
+        };
+        let ctx = TransformContext {
+            from_domain: route.from_domain,
+            to_domain: route.to_domain,
+            source_topic: &endpoint.source_topic,
+        };
+        match route.transforms.apply(&ctx, &mut msg) {
+            ChainOutcome::Forward => (msg.topic_name, msg.payload.into()),
+            ChainOutcome::Dropped => {
+                route.record_dropped();
+                return;
+            }
+            ChainOutcome::Failed => {
+                route.record_error();
+                return;
+            }
+        }
+    };
+
+    if let Some(limiter) = &mut endpoint.rate_limiter {
+        if !limiter.allow(Instant::now()) {
+            route.record_dropped();
+            return;
+        }
+    }
+
+    let Some(writer) = endpoint.writer_for(route, to, &topic) else {
+        return;
+    };
+    let payload_len = payload.len() as u64;
+    if let Err(err) = writer.write_bytes(payload) {
+        route.record_error();
+        tracing::debug!(
+            "Route {} -> {} write failed for {}: {}",
+            route.from_domain,
+            route.to_domain,
+            topic,
+            err
+        );
+    } else {
+        route.record_message(payload_len);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::config::TopicSelection;
+    use crate::transform::TransformAction;
 
     #[test]
     fn test_router_creation() {
@@ -688,6 +897,80 @@ mod tests {
         assert_eq!(stats[0].bytes_routed, 8); // 4 bytes * 2
     }
 
+    #[test]
+    fn test_route_message_applies_transforms() {
+        let config = RouterConfig {
+            routes: vec![RouteConfig::new(0, 1)
+                .remap("Temperature", "Vehicle/Temperature")
+                .transform("c_to_k")
+                .transform("drop_internal")],
+            ..Default::default()
+        };
+        let mut router = Router::new(config).expect("create router");
+
+        let msg = RoutedMessage {
+            source_domain: 0,
+            topic_name: "Temperature".into(),
+            type_name: "sensor_msgs/Temperature".into(),
+            payload: vec![20],
+            sequence_number: 1,
+            writer_guid: "guid".into(),
+        };
+        assert!(matches!(
+            router.route_message(&msg),
+            Err(RouterError::UnknownTransform(name)) if name == "c_to_k"
+        ));
+
+        router.register_transform(
+            "c_to_k",
+            |ctx: &TransformContext<'_>, msg: &mut RoutedMessage| {
+                assert_eq!(ctx.source_topic, "Temperature");
+                msg.payload[0] += 10;
+                msg.topic_name.push_str("/Kelvin");
+                TransformAction::Forward
+            },
+        );
+        router.register_transform(
+            "drop_internal",
+            |_: &TransformContext<'_>, msg: &mut RoutedMessage| {
+                if msg.writer_guid == "internal" {
+                    TransformAction::Drop
+                } else {
+                    TransformAction::Forward
+                }
+            },
+        );
+
+        let routed = router.route_message(&msg).expect("route").expect("some");
+        assert_eq!(routed.topic_name, "Vehicle/Temperature/Kelvin");
+        assert_eq!(routed.payload, vec![30]);
+
+        let internal = RoutedMessage {
+            writer_guid: "internal".into(),
+            ..msg
+        };
+        assert!(router.route_message(&internal).expect("route").is_none());
+
+        let stats = router.route_stats();
+        assert_eq!(stats[0].messages_routed, 1);
+        assert_eq!(stats[0].messages_dropped, 1);
+    }
+
+    #[tokio::test]
+    async fn test_router_run_requires_registered_transforms() {
+        let config = RouterConfig {
+            routes: vec![RouteConfig::new(0, 1).transform("missing")],
+            ..Default::default()
+        };
+        let mut router = Router::new(config).expect("create router");
+
+        assert!(matches!(
+            router.run().await,
+            Err(RouterError::UnknownTransform(name)) if name == "missing"
+        ));
+        assert!(!router.is_running());
+    }
+
     #[tokio::test]
     async fn test_router_run_stop() {
         let mut router = Router::bridge(0, 1).expect("create router");
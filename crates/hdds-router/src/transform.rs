@@ -1,9 +1,17 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 // Copyright (c) 2025-2026 naskel.com
 
-//! Transformations for topic names and QoS policies.
+//! Transformations for topic names, QoS policies and messages.
+//!
+//! Message transformations are user hooks ([`MessageTransform`]) registered
+//! on the [`Router`](crate::Router) under a name and enabled per route with
+//! `transforms = ["name", ...]`. They run in order on every routed sample and
+//! may rewrite its payload and destination topic, or drop it.
 
 use crate::config::{QosTransformConfig, TopicRemap};
+use crate::router::RoutedMessage;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Arc;
 
 /// Topic name transformer.
 #[derive(Debug, Clone)]
@@ -98,6 +106,142 @@ impl QosTransform {
     }
 }
 
+/// Route a message is travelling on, as seen by a [`MessageTransform`].
+#[derive(Debug, Clone, Copy)]
+pub struct TransformContext<'a> {
+    /// Source domain ID.
+    pub from_domain: u32,
+    /// Destination domain ID.
+    pub to_domain: u32,
+    /// Topic name in the source domain (before remapping).
+    pub source_topic: &'a str,
+}
+
+/// Outcome of a [`MessageTransform`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransformAction {
+    /// Pass the (possibly modified) message on.
+    Forward,
+    /// Drop the message; it is counted as dropped in the route stats.
+    Drop,
+}
+
+/// User hook transforming routed messages.
+///
+/// `msg.topic_name` holds the destination topic (after remapping) and
+/// `msg.payload` the serialized sample (CDR encapsulation header included).
+/// Both may be modified; a new topic gets its own writer in the destination
+/// domain, with the type and QoS of the source topic.
+///
+/// Closures `Fn(&TransformContext, &mut RoutedMessage) -> TransformAction`
+/// implement this trait. A hook that panics drops the message and counts as
+/// a route error.
+///
+/// # Example
+///
+/// ```
+/// use hdds_router::{
+///     RouteConfig, RoutedMessage, Router, RouterConfig, TransformAction, TransformContext,
+/// };
+///
+/// let mut config = RouterConfig::default();
+/// config.add_route(RouteConfig::new(0, 1).transform("scrub"));
+///
+/// let mut router = Router::new(config)?;
+/// router.register_transform("scrub", |_: &TransformContext<'_>, msg: &mut RoutedMessage| {
+///     if msg.topic_name.starts_with("Internal/") {
+///         return TransformAction::Drop;
+///     }
+///     msg.payload.truncate(64);
+///     TransformAction::Forward
+/// });
+/// # Ok::<(), hdds_router::RouterError>(())
+/// ```
+pub trait MessageTransform: Send + Sync {
+    /// Transform `msg`, routed on `ctx`.
+    fn apply(&self, ctx: &TransformContext<'_>, msg: &mut RoutedMessage) -> TransformAction;
+}
+
+impl<F> MessageTransform for F
+where
+    F: Fn(&TransformContext<'_>, &mut RoutedMessage) -> TransformAction + Send + Sync,
+{
+    fn apply(&self, ctx: &TransformContext<'_>, msg: &mut RoutedMessage) -> TransformAction {
+        self(ctx, msg)
+    }
+}
+
+/// Result of running a [`TransformChain`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainOutcome {
+    /// All hooks forwarded the message.
+    Forward,
+    /// A hook dropped the message.
+    Dropped,
+    /// A hook panicked; the message is dropped.
+    Failed,
+}
+
+/// Ordered message transformation hooks of a route.
+#[derive(Clone, Default)]
+pub struct TransformChain {
+    hooks: Vec<(String, Arc<dyn MessageTransform>)>,
+}
+
+impl TransformChain {
+    /// Create an empty chain.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a hook.
+    pub fn push(&mut self, name: impl Into<String>, hook: Arc<dyn MessageTransform>) {
+        self.hooks.push((name.into(), hook));
+    }
+
+    /// Check if the chain has no hooks.
+    pub fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+
+    /// Number of hooks.
+    pub fn len(&self) -> usize {
+        self.hooks.len()
+    }
+
+    /// Names of the hooks, in order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.hooks.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Run the hooks in order, stopping at the first that drops `msg`.
+    pub fn apply(&self, ctx: &TransformContext<'_>, msg: &mut RoutedMessage) -> ChainOutcome {
+        for (name, hook) in &self.hooks {
+            match catch_unwind(AssertUnwindSafe(|| hook.apply(ctx, msg))) {
+                Ok(TransformAction::Forward) => {}
+                Ok(TransformAction::Drop) => return ChainOutcome::Dropped,
+                Err(_) => {
+                    tracing::warn!(
+                        "Transform '{}' panicked on {} (domain {} -> {})",
+                        name,
+                        ctx.source_topic,
+                        ctx.from_domain,
+                        ctx.to_domain
+                    );
+                    return ChainOutcome::Failed;
+                }
+            }
+        }
+        ChainOutcome::Forward
+    }
+}
+
+impl std::fmt::Debug for TransformChain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
+
 /// Reliability QoS kind.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Reliability {
@@ -174,6 +318,71 @@ mod tests {
         assert_eq!(transform.durability(), Some(Durability::TransientLocal));
     }
 
+    fn message(topic: &str, payload: Vec<u8>) -> RoutedMessage {
+        RoutedMessage {
+            source_domain: 0,
+            topic_name: topic.into(),
+            type_name: "Temperature".into(),
+            payload,
+            sequence_number: 1,
+            writer_guid: "guid".into(),
+        }
+    }
+
+    const CTX: TransformContext<'static> = TransformContext {
+        from_domain: 0,
+        to_domain: 1,
+        source_topic: "Temperature",
+    };
+
+    #[test]
+    fn test_transform_chain_order_and_drop() {
+        let mut chain = TransformChain::new();
+        chain.push(
+            "double",
+            Arc::new(|_: &TransformContext<'_>, msg: &mut RoutedMessage| {
+                msg.payload.iter_mut().for_each(|b| *b *= 2);
+                TransformAction::Forward
+            }),
+        );
+        chain.push(
+            "retopic",
+            Arc::new(|_: &TransformContext<'_>, msg: &mut RoutedMessage| {
+                if msg.payload.is_empty() {
+                    return TransformAction::Drop;
+                }
+                msg.topic_name = format!("Converted/{}", msg.topic_name);
+                TransformAction::Forward
+            }),
+        );
+        assert_eq!(chain.names().collect::<Vec<_>>(), ["double", "retopic"]);
+
+        let mut msg = message("Temperature", vec![1, 2]);
+        assert_eq!(chain.apply(&CTX, &mut msg), ChainOutcome::Forward);
+        assert_eq!(msg.payload, vec![2, 4]);
+        assert_eq!(msg.topic_name, "Converted/Temperature");
+
+        let mut empty = message("Temperature", Vec::new());
+        assert_eq!(chain.apply(&CTX, &mut empty), ChainOutcome::Dropped);
+        assert_eq!(empty.topic_name, "Temperature");
+    }
+
+    #[test]
+    fn test_transform_chain_catches_panic() {
+        let mut chain = TransformChain::new();
+        chain.push(
+            "broken",
+            Arc::new(
+                |_: &TransformContext<'_>, _: &mut RoutedMessage| -> TransformAction {
+                    panic!("bad hook")
+                },
+            ),
+        );
+
+        let mut msg = message("Temperature", vec![1]);
+        assert_eq!(chain.apply(&CTX, &mut msg), ChainOutcome::Failed);
+    }
+
     #[test]
     fn test_qos_transform_inactive() {
         let transform = QosTransform::default();
@@ -1,6 +1,9 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 // Copyright (c) 2025-2026 naskel.com
 
+use crate::ingest::parse_ros_msg;
+use crate::json_schema::check_json_schema_compatibility;
+use crate::protobuf::check_protobuf_compatibility;
 use crate::registry::SchemaFormat;
 
 // ---------------------------------------------------------------------------
@@ -50,6 +53,9 @@ pub fn check_compatibility(
     match format {
         SchemaFormat::Idl4 => check_idl4_compatibility(old, new),
         SchemaFormat::Json => check_json_compatibility(old, new),
+        SchemaFormat::RosMsg => check_ros_msg_compatibility(old, new),
+        SchemaFormat::Protobuf => check_protobuf_compatibility(old, new),
+        SchemaFormat::JsonSchema => check_json_schema_compatibility(old, new),
         SchemaFormat::XTypesHash => CompatibilityResult {
             compatibility: if old == new {
                 Compatibility::Full
@@ -61,6 +67,28 @@ pub fn check_compatibility(
     }
 }
 
+/// Result for a change set, from whether new readers can still read old
+/// data (`backward`) and old readers new data (`forward`).
+pub(crate) fn directional_result(
+    backward: bool,
+    forward: bool,
+    mut details: Vec<String>,
+) -> CompatibilityResult {
+    let compatibility = match (backward, forward) {
+        (true, true) => Compatibility::Full,
+        (true, false) => Compatibility::Backward,
+        (false, true) => Compatibility::Forward,
+        (false, false) => Compatibility::Breaking,
+    };
+    if details.is_empty() {
+        details.push("schemas are identical".to_string());
+    }
+    CompatibilityResult {
+        compatibility,
+        details,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // IDL4 simplified compatibility checker
 // ---------------------------------------------------------------------------
@@ -74,8 +102,8 @@ struct IdlField {
 
 /// Very simple IDL field extractor.
 ///
-/// Recognises declarations of the form `<type> <name>;` inside a struct
-/// body.  Works with both multi-line and single-line struct definitions
+/// Recognises declarations of the form `[@annotation] <type> <name>;` inside
+/// a struct body.  Works with both multi-line and single-line struct definitions
 /// (e.g. `struct S { long x; string y; };`).
 ///
 /// This is intentionally naive -- real IDL parsing is done elsewhere.
@@ -111,12 +139,20 @@ fn extract_idl_fields(schema: &str) -> Vec<IdlField> {
             None => trimmed,
         };
 
-        let parts: Vec<&str> = without_default.split_whitespace().collect();
-        if parts.len() >= 2 {
-            fields.push(IdlField {
-                type_name: parts[0].to_string(),
-                field_name: parts[1].to_string(),
-            });
+        // Annotations (`@key`, `@optional`, ...) do not change the wire type.
+        // The last token is the name; everything before it is the type, so
+        // multi-word types (`unsigned long`, `sequence<long, 10>`) stay whole.
+        let parts: Vec<&str> = without_default
+            .split_whitespace()
+            .filter(|p| !p.starts_with('@'))
+            .collect();
+        if let Some((name, type_parts)) = parts.split_last() {
+            if !type_parts.is_empty() {
+                fields.push(IdlField {
+                    type_name: type_parts.join(" "),
+                    field_name: name.to_string(),
+                });
+            }
         }
     }
 
@@ -199,6 +235,24 @@ fn check_idl4_compatibility(old: &str, new: &str) -> CompatibilityResult {
     }
 }
 
+// ---------------------------------------------------------------------------
+// ROS .msg compatibility checker
+// ---------------------------------------------------------------------------
+
+/// ROS messages are compared through their canonical IDL rendering so the
+/// same field rules apply as for IDL schemas.
+fn check_ros_msg_compatibility(old: &str, new: &str) -> CompatibilityResult {
+    match (parse_ros_msg("Msg", old), parse_ros_msg("Msg", new)) {
+        (Ok(old_obj), Ok(new_obj)) => {
+            check_idl4_compatibility(&old_obj.to_idl(), &new_obj.to_idl())
+        }
+        (Err(e), _) | (_, Err(e)) => CompatibilityResult {
+            compatibility: Compatibility::Breaking,
+            details: vec![format!("cannot parse ROS message: {}", e)],
+        },
+    }
+}
+
 // ---------------------------------------------------------------------------
 // JSON simplified compatibility checker
 // ---------------------------------------------------------------------------
@@ -313,6 +367,27 @@ mod tests {
         assert_eq!(result.compatibility, Compatibility::Breaking);
     }
 
+    #[test]
+    fn annotations_and_multi_word_types_are_parsed() {
+        let old = "struct S {\n  @key unsigned long id;\n};";
+        let new = "struct S {\n  unsigned long id;\n  unsigned long long ts;\n};";
+        let result = check_compatibility(old, new, SchemaFormat::Idl4);
+        assert_eq!(result.compatibility, Compatibility::Backward);
+        assert_eq!(result.details, vec!["added field: unsigned long long ts"]);
+    }
+
+    #[test]
+    fn ros_msg_added_field_is_backward() {
+        let old = "# pose\nfloat64 x\nfloat64 y\n";
+        let new = "float64 x\nfloat64 y\nfloat64[<=3] extra\n";
+        let result = check_compatibility(old, new, SchemaFormat::RosMsg);
+        assert_eq!(result.compatibility, Compatibility::Backward);
+        assert_eq!(result.details, vec!["added field: sequence<double,3> extra"]);
+
+        let changed = check_compatibility(old, "float32 x\nfloat64 y\n", SchemaFormat::RosMsg);
+        assert_eq!(changed.compatibility, Compatibility::Breaking);
+    }
+
     #[test]
     fn json_identical_is_full() {
         let json = r#"{"id": "long", "name": "string"}"#;
@@ -0,0 +1,506 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! DDS-native registration of XTypes TypeObjects (feature `hdds`).
+//!
+//! Participants announce a `CompleteTypeObject` for their writers and
+//! readers through SEDP.  [`TypeSync`] publishes the types of a local
+//! participant to the registry at startup and registers the ones announced
+//! by remote endpoints as they are discovered, keyed by type name and
+//! equivalence hash.  Tools that see an endpoint with a type they cannot
+//! decode (recorder, CLI echo) resolve it with [`lookup_endpoint_type`] and
+//! hand the result to `hdds::dynamic::type_descriptor_from_xtypes`.
+//!
+//! Each type is stored as canonical IDL (so the IDL compatibility checks
+//! apply) together with its simplified [`TypeObject`] view and the
+//! CDR2-encoded `CompleteTypeObject`.  Nested types referenced by hash are
+//! rendered as their hex hash.
+
+use std::fmt::Write as _;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use hdds::core::discovery::multicast::EndpointInfo;
+use hdds::xtypes::{CompleteTypeObject, TypeIdentifier, TypeKind};
+use hdds::{Cdr2Decode, Cdr2Encode, DiscoveryEvent, DiscoveryEvents, Participant};
+
+use crate::registry::{RegistryError, SchemaEntry, SchemaRegistry, XTypesIdentity};
+use crate::type_object::{MemberDescriptor, PrimitiveKind, TypeObject, TypeObjectKind, TypeSpec};
+
+// ---------------------------------------------------------------------------
+// CompleteTypeObject conversion
+// ---------------------------------------------------------------------------
+
+fn unsupported(what: &str) -> RegistryError {
+    RegistryError::ParseError(format!("unsupported XTypes type: {}", what))
+}
+
+/// Convert an XTypes `CompleteTypeObject` into the registry's `TypeObject`.
+///
+/// Structs, enums and aliases are supported; other top-level kinds
+/// (unions, bitmasks, ...) return `ParseError`.
+pub fn type_object_from_xtypes(
+    type_object: &CompleteTypeObject,
+) -> Result<TypeObject, RegistryError> {
+    let (name, kind) = match type_object {
+        CompleteTypeObject::Struct(s) => {
+            let base = s
+                .header
+                .base_type
+                .as_ref()
+                .map(|base| match type_spec_from_xtypes(base)? {
+                    TypeSpec::Named(name) => Ok(name),
+                    other => Err(unsupported(&format!("struct base {}", other.to_idl()))),
+                })
+                .transpose()?;
+            let members = s
+                .member_seq
+                .iter()
+                .map(|m| {
+                    let flags = m.common.member_flags;
+                    Ok(MemberDescriptor {
+                        id: m.common.member_id,
+                        name: m.detail.name.clone(),
+                        type_spec: type_spec_from_xtypes(&m.common.member_type_id)?,
+                        key: flags.contains(hdds::xtypes::MemberFlag::IS_KEY),
+                        optional: flags.contains(hdds::xtypes::MemberFlag::IS_OPTIONAL),
+                    })
+                })
+                .collect::<Result<Vec<_>, RegistryError>>()?;
+            (
+                s.header.detail.type_name.clone(),
+                TypeObjectKind::Structure { base, members },
+            )
+        }
+        CompleteTypeObject::Enumerated(e) => {
+            let mut literals: Vec<_> = e.literal_seq.iter().collect();
+            literals.sort_by_key(|l| l.common.value);
+            (
+                e.header.detail.type_name.clone(),
+                TypeObjectKind::Enumeration {
+                    literals: literals.iter().map(|l| l.detail.name.clone()).collect(),
+                },
+            )
+        }
+        CompleteTypeObject::Alias(a) => (
+            a.header.detail.type_name.clone(),
+            TypeObjectKind::Alias {
+                target: type_spec_from_xtypes(&a.body.common.related_type)?,
+            },
+        ),
+        CompleteTypeObject::Union(_) => return Err(unsupported("union")),
+        CompleteTypeObject::Bitmask(_) => return Err(unsupported("bitmask")),
+        CompleteTypeObject::Bitset(_) => return Err(unsupported("bitset")),
+        CompleteTypeObject::Sequence(_) => return Err(unsupported("top-level sequence")),
+        CompleteTypeObject::Array(_) => return Err(unsupported("top-level array")),
+        CompleteTypeObject::Map(_) => return Err(unsupported("map")),
+        CompleteTypeObject::Annotation(_) => return Err(unsupported("annotation")),
+    };
+    Ok(TypeObject { name, kind })
+}
+
+fn bound(bound: u32) -> Option<u32> {
+    (bound != 0).then_some(bound)
+}
+
+fn type_spec_from_xtypes(type_id: &TypeIdentifier) -> Result<TypeSpec, RegistryError> {
+    Ok(match type_id {
+        TypeIdentifier::Primitive(TypeKind::TK_STRING8) => TypeSpec::String {
+            wide: false,
+            bound: None,
+        },
+        TypeIdentifier::Primitive(TypeKind::TK_STRING16) => TypeSpec::String {
+            wide: true,
+            bound: None,
+        },
+        TypeIdentifier::Primitive(kind) => TypeSpec::Primitive(primitive_from_xtypes(*kind)?),
+        TypeIdentifier::StringSmall { bound: n } => TypeSpec::String {
+            wide: false,
+            bound: bound(u32::from(*n)),
+        },
+        TypeIdentifier::StringLarge { bound: n } => TypeSpec::String {
+            wide: false,
+            bound: bound(*n),
+        },
+        TypeIdentifier::WStringSmall { bound: n } => TypeSpec::String {
+            wide: true,
+            bound: bound(u32::from(*n)),
+        },
+        TypeIdentifier::WStringLarge { bound: n } => TypeSpec::String {
+            wide: true,
+            bound: bound(*n),
+        },
+        TypeIdentifier::Minimal(hash) | TypeIdentifier::Complete(hash) => {
+            TypeSpec::Named(hash.to_string())
+        }
+        TypeIdentifier::StronglyConnected(scc) => TypeSpec::Named(scc.sc_component_id.to_string()),
+        TypeIdentifier::Inline(inner) => match inner.as_ref() {
+            CompleteTypeObject::Sequence(s) => TypeSpec::Sequence {
+                element: Box::new(type_spec_from_xtypes(&s.element.type_id)?),
+                bound: bound(s.header.bound),
+            },
+            CompleteTypeObject::Array(a) => TypeSpec::Array {
+                element: Box::new(type_spec_from_xtypes(&a.element.type_id)?),
+                dims: a.bound_seq.clone(),
+            },
+            other => TypeSpec::Named(type_name(other)?),
+        },
+    })
+}
+
+fn primitive_from_xtypes(kind: TypeKind) -> Result<PrimitiveKind, RegistryError> {
+    Ok(match kind {
+        TypeKind::TK_BOOLEAN => PrimitiveKind::Bool,
+        TypeKind::TK_BYTE => PrimitiveKind::Octet,
+        TypeKind::TK_CHAR8 => PrimitiveKind::Char,
+        TypeKind::TK_CHAR16 => PrimitiveKind::WChar,
+        TypeKind::TK_INT8 => PrimitiveKind::Int8,
+        TypeKind::TK_UINT8 => PrimitiveKind::UInt8,
+        TypeKind::TK_INT16 => PrimitiveKind::Int16,
+        TypeKind::TK_UINT16 => PrimitiveKind::UInt16,
+        TypeKind::TK_INT32 => PrimitiveKind::Int32,
+        TypeKind::TK_UINT32 => PrimitiveKind::UInt32,
+        TypeKind::TK_INT64 => PrimitiveKind::Int64,
+        TypeKind::TK_UINT64 => PrimitiveKind::UInt64,
+        TypeKind::TK_FLOAT32 => PrimitiveKind::Float32,
+        TypeKind::TK_FLOAT64 => PrimitiveKind::Float64,
+        TypeKind::TK_FLOAT128 => PrimitiveKind::Float128,
+        other => return Err(unsupported(&format!("primitive {:?}", other))),
+    })
+}
+
+/// Declared name of a named XTypes type.
+fn type_name(type_object: &CompleteTypeObject) -> Result<String, RegistryError> {
+    let detail = match type_object {
+        CompleteTypeObject::Struct(s) => &s.header.detail,
+        CompleteTypeObject::Union(u) => &u.header.detail,
+        CompleteTypeObject::Enumerated(e) => &e.header.detail,
+        CompleteTypeObject::Bitmask(b) => &b.header.detail,
+        CompleteTypeObject::Bitset(b) => &b.header.detail,
+        CompleteTypeObject::Alias(a) => &a.header.detail,
+        CompleteTypeObject::Annotation(a) => &a.header.detail,
+        CompleteTypeObject::Sequence(_)
+        | CompleteTypeObject::Array(_)
+        | CompleteTypeObject::Map(_) => return Err(unsupported("anonymous collection")),
+    };
+    Ok(detail.type_name.clone())
+}
+
+// ---------------------------------------------------------------------------
+// XTypes identity
+// ---------------------------------------------------------------------------
+
+fn cdr_error(err: hdds::CdrError) -> RegistryError {
+    RegistryError::ParseError(format!("CDR2 TypeObject: {}", err))
+}
+
+/// Equivalence hash and CDR2 encoding of a `CompleteTypeObject`.
+pub fn xtypes_identity(type_object: &CompleteTypeObject) -> Result<XTypesIdentity, RegistryError> {
+    let mut buf = vec![0u8; type_object.max_cdr2_size()];
+    let len = type_object.encode_cdr2_le(&mut buf).map_err(cdr_error)?;
+    let hash = type_object.compute_equivalence_hash().map_err(cdr_error)?;
+
+    let mut cdr2 = String::with_capacity(len * 2);
+    for byte in &buf[..len] {
+        let _ = write!(cdr2, "{:02x}", byte);
+    }
+    Ok(XTypesIdentity {
+        equivalence_hash: hash.to_string(),
+        type_object_cdr2: cdr2,
+    })
+}
+
+/// Decode the `CompleteTypeObject` stored with a schema registered from DDS.
+///
+/// Returns `None` for schemas without an XTypes identity.
+pub fn complete_type_object(entry: &SchemaEntry) -> Option<CompleteTypeObject> {
+    let hex = &entry.xtypes.as_ref()?.type_object_cdr2;
+    let bytes = (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            hex.get(i..i + 2)
+                .and_then(|b| u8::from_str_radix(b, 16).ok())
+        })
+        .collect::<Option<Vec<u8>>>()?;
+    CompleteTypeObject::decode_cdr2_le(&bytes)
+        .ok()
+        .map(|(type_object, _)| type_object)
+}
+
+// ---------------------------------------------------------------------------
+// Registration
+// ---------------------------------------------------------------------------
+
+/// Register a `CompleteTypeObject` announced under `type_name`.
+///
+/// Returns the version holding its equivalence hash, which is the existing
+/// one when the type is already known.
+pub fn register_complete_type_object(
+    registry: &mut SchemaRegistry,
+    type_name: &str,
+    type_object: &CompleteTypeObject,
+) -> Result<u32, RegistryError> {
+    let xtypes = xtypes_identity(type_object)?;
+    if let Some(entry) = registry.get_by_equivalence_hash(type_name, &xtypes.equivalence_hash) {
+        return Ok(entry.version);
+    }
+
+    let mut converted = type_object_from_xtypes(type_object)?;
+    // Register under the announced name, which may be qualified differently
+    // from the name inside the TypeObject (e.g. ROS 2 `pkg::msg::dds_::T_`).
+    converted.name = type_name.to_string();
+    let content = converted.to_idl();
+    registry.register_xtypes(type_name, &content, Some(converted), xtypes)
+}
+
+/// Type announced by a discovered endpoint, as known to the registry.
+///
+/// Uses the endpoint's own TypeObject when it announced one and the
+/// registry knows its hash; otherwise falls back to the latest version of
+/// its type name registered from DDS.
+pub fn lookup_endpoint_type(
+    registry: &SchemaRegistry,
+    endpoint: &EndpointInfo,
+) -> Option<CompleteTypeObject> {
+    if let Some(type_object) = &endpoint.type_object {
+        if let Ok(hash) = type_object.compute_equivalence_hash() {
+            if let Some(entry) =
+                registry.get_by_equivalence_hash(&endpoint.type_name, &hash.to_string())
+            {
+                return complete_type_object(entry);
+            }
+        }
+    }
+    registry
+        .list_versions(&endpoint.type_name)
+        .into_iter()
+        .rev()
+        .filter_map(|v| registry.get_version(&endpoint.type_name, v))
+        .find_map(complete_type_object)
+}
+
+// ---------------------------------------------------------------------------
+// TypeSync
+// ---------------------------------------------------------------------------
+
+/// Keeps a registry in sync with the types seen by a participant.
+///
+/// Created with [`TypeSync::start`], which registers the participant's own
+/// types.  [`poll`](Self::poll) registers the types of endpoints
+/// discovered since; types announced without a TypeObject, or that cannot
+/// be converted, are skipped.
+pub struct TypeSync {
+    registry: Arc<RwLock<SchemaRegistry>>,
+    events: DiscoveryEvents,
+}
+
+impl TypeSync {
+    /// Register the local types of `participant` and subscribe to its
+    /// discovery events.
+    ///
+    /// Create it after the participant's writers and readers so that their
+    /// types are registered right away; later ones are picked up by
+    /// [`register_local`](Self::register_local).
+    pub fn start(
+        participant: &Participant,
+        registry: Arc<RwLock<SchemaRegistry>>,
+    ) -> Result<Self, RegistryError> {
+        let events = participant
+            .discovery_events()
+            .map_err(|e| RegistryError::IoError(format!("discovery events: {}", e)))?;
+        let sync = TypeSync { registry, events };
+        sync.register_local(participant);
+        Ok(sync)
+    }
+
+    /// Register the types announced by the writers and readers of
+    /// `participant`.  Returns the `(name, version)` of each.
+    pub fn register_local(&self, participant: &Participant) -> Vec<(String, u32)> {
+        let mut reg = self.registry.write().expect("registry lock poisoned");
+        participant
+            .local_type_objects()
+            .into_iter()
+            .filter_map(|(name, type_object)| {
+                register_logged(&mut reg, &name, &type_object).map(|v| (name, v))
+            })
+            .collect()
+    }
+
+    /// Register the types of endpoints discovered since the last call,
+    /// without blocking.  Returns how many endpoints were processed.
+    pub fn poll(&self) -> usize {
+        let mut processed = 0;
+        while let Some(event) = self.events.try_next() {
+            processed += usize::from(self.handle(event));
+        }
+        processed
+    }
+
+    /// Like [`poll`](Self::poll), but waits up to `timeout` for the first
+    /// event.
+    pub fn poll_timeout(&self, timeout: Duration) -> usize {
+        match self.events.next_timeout(timeout) {
+            Some(event) => usize::from(self.handle(event)) + self.poll(),
+            None => 0,
+        }
+    }
+
+    fn handle(&self, event: DiscoveryEvent) -> bool {
+        let endpoint = match event {
+            DiscoveryEvent::WriterDiscovered(e) | DiscoveryEvent::ReaderDiscovered(e) => e,
+            _ => return false,
+        };
+        if let Some(type_object) = &endpoint.type_object {
+            let mut reg = self.registry.write().expect("registry lock poisoned");
+            register_logged(&mut reg, &endpoint.type_name, type_object);
+        }
+        true
+    }
+}
+
+fn register_logged(
+    reg: &mut SchemaRegistry,
+    type_name: &str,
+    type_object: &CompleteTypeObject,
+) -> Option<u32> {
+    match register_complete_type_object(reg, type_name, type_object) {
+        Ok(version) => Some(version),
+        Err(e) => {
+            log::debug!("schema registry: skipping type {}: {}", type_name, e);
+            None
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hdds::xtypes::{
+        CollectionElementFlag, CommonEnumeratedLiteral, CommonStructMember,
+        CompleteCollectionElement, CompleteCollectionHeader, CompleteEnumeratedHeader,
+        CompleteEnumeratedLiteral, CompleteEnumeratedType, CompleteMemberDetail,
+        CompleteSequenceType, CompleteStructHeader, CompleteStructMember, CompleteStructType,
+        CompleteTypeDetail, EnumeratedLiteralFlag, MemberFlag, StructTypeFlag,
+    };
+
+    fn member(
+        id: u32,
+        name: &str,
+        type_id: TypeIdentifier,
+        flags: MemberFlag,
+    ) -> CompleteStructMember {
+        CompleteStructMember {
+            common: CommonStructMember {
+                member_id: id,
+                member_flags: flags,
+                member_type_id: type_id,
+            },
+            detail: CompleteMemberDetail::new(name),
+        }
+    }
+
+    fn reading(extra_member: bool) -> CompleteTypeObject {
+        let mut members = vec![
+            member(0, "id", TypeIdentifier::TK_INT32, MemberFlag::IS_KEY),
+            member(
+                1,
+                "samples",
+                TypeIdentifier::Inline(Box::new(CompleteTypeObject::Sequence(
+                    CompleteSequenceType {
+                        header: CompleteCollectionHeader {
+                            bound: 16,
+                            detail: CompleteTypeDetail::new(""),
+                        },
+                        element: CompleteCollectionElement {
+                            flags: CollectionElementFlag::empty(),
+                            type_id: TypeIdentifier::TK_FLOAT64,
+                        },
+                    },
+                ))),
+                MemberFlag::empty(),
+            ),
+        ];
+        if extra_member {
+            members.push(member(
+                2,
+                "label",
+                TypeIdentifier::string(32),
+                MemberFlag::empty(),
+            ));
+        }
+        CompleteTypeObject::Struct(CompleteStructType {
+            struct_flags: StructTypeFlag::IS_FINAL,
+            header: CompleteStructHeader {
+                base_type: None,
+                detail: CompleteTypeDetail::new("sensors::Reading"),
+            },
+            member_seq: members,
+        })
+    }
+
+    #[test]
+    fn struct_converts_to_canonical_idl() {
+        let obj = type_object_from_xtypes(&reading(true)).unwrap();
+        assert_eq!(
+            obj.to_idl(),
+            "// sensors::Reading\nstruct Reading {\n    @key int32 id;\n    \
+             sequence<double,16> samples;\n    string<32> label;\n};\n"
+        );
+    }
+
+    #[test]
+    fn enum_literals_follow_values() {
+        let literal = |name: &str, value| CompleteEnumeratedLiteral {
+            common: CommonEnumeratedLiteral {
+                value,
+                flags: EnumeratedLiteralFlag::empty(),
+            },
+            detail: CompleteMemberDetail::new(name),
+        };
+        let color = CompleteTypeObject::Enumerated(CompleteEnumeratedType {
+            header: CompleteEnumeratedHeader {
+                bit_bound: 32,
+                detail: CompleteTypeDetail::new("Color"),
+            },
+            literal_seq: vec![literal("GREEN", 1), literal("RED", 0)],
+        });
+
+        let obj = type_object_from_xtypes(&color).unwrap();
+        assert_eq!(
+            obj.kind,
+            TypeObjectKind::Enumeration {
+                literals: vec!["RED".to_string(), "GREEN".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn register_is_keyed_by_equivalence_hash() {
+        let mut reg = SchemaRegistry::new();
+        let v1 = reading(false);
+        let v2 = reading(true);
+
+        assert_eq!(
+            register_complete_type_object(&mut reg, "Reading", &v1).unwrap(),
+            1
+        );
+        assert_eq!(
+            register_complete_type_object(&mut reg, "Reading", &v1).unwrap(),
+            1
+        );
+        assert_eq!(
+            register_complete_type_object(&mut reg, "Reading", &v2).unwrap(),
+            2
+        );
+
+        let hash = v1.compute_equivalence_hash().unwrap().to_string();
+        let entry = reg.get_by_equivalence_hash("Reading", &hash).unwrap();
+        assert_eq!(entry.version, 1);
+        assert_eq!(complete_type_object(entry), Some(v1));
+        assert_eq!(entry.type_object.as_ref().unwrap().name, "Reading");
+    }
+}
@@ -0,0 +1,442 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Server-side parsing of raw IDL and ROS `.msg` text into `TypeObject`s.
+//!
+//! Lets teams without a Rust toolchain register schemas by uploading the
+//! source text they already have.  IDL goes through the `hdds-idl`
+//! front-end shared with `hdds_gen`: modules, structs (with single
+//! inheritance), enums, typedefs, `@key`/`@optional` annotations, bounded
+//! strings, sequences and arrays are stored.  Constants and other
+//! annotations are skipped; unions, interfaces and declarations the
+//! front-end cannot parse are rejected.
+
+use crate::registry::RegistryError;
+use crate::type_object::{MemberDescriptor, PrimitiveKind, TypeObject, TypeObjectKind, TypeSpec};
+use hdds_idl::{qualified, IdlType, TypeDecl};
+
+fn parse_error(msg: impl Into<String>) -> RegistryError {
+    RegistryError::ParseError(msg.into())
+}
+
+// ---------------------------------------------------------------------------
+// IDL
+// ---------------------------------------------------------------------------
+
+/// Parse IDL text into one `TypeObject` per enum, struct and typedef.
+///
+/// Enums come first, then structs and typedefs in declaration order, all
+/// with fully-qualified names (`module::Type`).  Names are resolved against
+/// the declarations of the text; names it does not declare (e.g. from an
+/// `#include`) are kept as written, and members use the aliased type of a
+/// typedef.
+pub fn parse_idl(text: &str) -> Result<Vec<TypeObject>, RegistryError> {
+    let items = hdds_idl::parse_idl(text).map_err(|err| parse_error(err.to_string()))?;
+    if let Some(skipped) = items.skipped.first() {
+        return Err(parse_error(format!("{}: {}", skipped.name, skipped.reason)));
+    }
+
+    let mut types: Vec<TypeObject> = items
+        .enums
+        .iter()
+        .map(|spec| TypeObject {
+            name: qualified(&spec.namespace, &spec.name),
+            kind: TypeObjectKind::Enumeration {
+                literals: spec.variants.iter().map(|(name, _)| name.clone()).collect(),
+            },
+        })
+        .collect();
+    for decl in &items.types {
+        let kind = match decl {
+            TypeDecl::Struct(def) => {
+                let base = match &def.base {
+                    Some(IdlType::Named { namespace, name }) => Some(qualified(namespace, name)),
+                    _ => None,
+                };
+                let members = def
+                    .members
+                    .iter()
+                    .enumerate()
+                    .map(|(id, member)| {
+                        Ok(MemberDescriptor {
+                            id: id as u32,
+                            name: member.name.clone(),
+                            type_spec: type_spec(&member.ty)?,
+                            key: member.key,
+                            optional: member.optional,
+                        })
+                    })
+                    .collect::<Result<_, RegistryError>>()?;
+                TypeObjectKind::Structure { base, members }
+            }
+            TypeDecl::Typedef(def) => TypeObjectKind::Alias {
+                target: type_spec(&def.ty)?,
+            },
+            TypeDecl::Union(_) => {
+                return Err(parse_error(format!(
+                    "union {} is not supported",
+                    decl.qualified_name()
+                )))
+            }
+        };
+        types.push(TypeObject {
+            name: decl.qualified_name(),
+            kind,
+        });
+    }
+
+    if types.is_empty() {
+        return Err(parse_error("IDL defines no types"));
+    }
+    Ok(types)
+}
+
+/// Registry form of a parsed member or alias type.
+fn type_spec(ty: &IdlType) -> Result<TypeSpec, RegistryError> {
+    use hdds_idl::PrimitiveKind as Idl;
+
+    let primitive = |kind| Ok(TypeSpec::Primitive(kind));
+    match ty {
+        IdlType::Primitive(kind) => match *kind {
+            Idl::Bool => primitive(PrimitiveKind::Bool),
+            Idl::Char => primitive(PrimitiveKind::Char),
+            Idl::WChar => primitive(PrimitiveKind::WChar),
+            Idl::I8 => primitive(PrimitiveKind::Int8),
+            Idl::U8 => primitive(PrimitiveKind::UInt8),
+            Idl::I16 => primitive(PrimitiveKind::Int16),
+            Idl::U16 => primitive(PrimitiveKind::UInt16),
+            Idl::I32 => primitive(PrimitiveKind::Int32),
+            Idl::U32 => primitive(PrimitiveKind::UInt32),
+            Idl::I64 => primitive(PrimitiveKind::Int64),
+            Idl::U64 => primitive(PrimitiveKind::UInt64),
+            Idl::F32 => primitive(PrimitiveKind::Float32),
+            Idl::F64 => primitive(PrimitiveKind::Float64),
+            Idl::LongDouble => primitive(PrimitiveKind::Float128),
+            Idl::String { max_length } => Ok(TypeSpec::String {
+                wide: false,
+                bound: max_length.map(size).transpose()?,
+            }),
+            Idl::WString { max_length } => Ok(TypeSpec::String {
+                wide: true,
+                bound: max_length.map(size).transpose()?,
+            }),
+        },
+        IdlType::Octet => primitive(PrimitiveKind::Octet),
+        IdlType::Sequence { element, bound } => Ok(TypeSpec::Sequence {
+            element: Box::new(type_spec(element)?),
+            bound: bound.map(size).transpose()?,
+        }),
+        IdlType::Array { .. } => {
+            // Nested arrays are one multi-dimensional array
+            let mut dims = Vec::new();
+            let mut element = ty;
+            while let IdlType::Array {
+                element: inner,
+                length,
+            } = element
+            {
+                dims.push(size(*length)?);
+                element = inner;
+            }
+            Ok(TypeSpec::Array {
+                element: Box::new(type_spec(element)?),
+                dims,
+            })
+        }
+        IdlType::Named { namespace, name } => Ok(TypeSpec::Named(qualified(namespace, name))),
+    }
+}
+
+fn size(value: usize) -> Result<u32, RegistryError> {
+    u32::try_from(value).map_err(|_| parse_error(format!("size {} out of range", value)))
+}
+
+// ---------------------------------------------------------------------------
+// ROS .msg parser
+// ---------------------------------------------------------------------------
+
+/// Split a ROS type name (`pkg/Name`, `pkg/msg/Name` or `Name`) into its
+/// package and short name.
+fn split_ros_name(name: &str) -> (Option<&str>, &str) {
+    let parts: Vec<&str> = name.split('/').collect();
+    match parts.as_slice() {
+        [short] => (None, short),
+        [pkg, short] | [pkg, _, short] => (Some(pkg), short),
+        _ => (None, name),
+    }
+}
+
+fn ros_fq_name(package: Option<&str>, short: &str) -> String {
+    match package {
+        Some(pkg) => format!("{}::msg::{}", pkg, short),
+        None => short.to_string(),
+    }
+}
+
+fn parse_ros_base_type(
+    base: &str,
+    package: Option<&str>,
+    line: usize,
+) -> Result<TypeSpec, RegistryError> {
+    let primitive = |kind| Ok(TypeSpec::Primitive(kind));
+    if let Some(bound) = base
+        .strip_prefix("string<=")
+        .or_else(|| base.strip_prefix("wstring<="))
+    {
+        let bound = bound
+            .parse()
+            .map_err(|_| parse_error(format!("line {}: invalid string bound", line)))?;
+        return Ok(TypeSpec::String {
+            wide: base.starts_with('w'),
+            bound: Some(bound),
+        });
+    }
+    match base {
+        "bool" => primitive(PrimitiveKind::Bool),
+        "byte" => primitive(PrimitiveKind::Octet),
+        "char" | "uint8" => primitive(PrimitiveKind::UInt8),
+        "int8" => primitive(PrimitiveKind::Int8),
+        "int16" => primitive(PrimitiveKind::Int16),
+        "uint16" => primitive(PrimitiveKind::UInt16),
+        "int32" => primitive(PrimitiveKind::Int32),
+        "uint32" => primitive(PrimitiveKind::UInt32),
+        "int64" => primitive(PrimitiveKind::Int64),
+        "uint64" => primitive(PrimitiveKind::UInt64),
+        "float32" => primitive(PrimitiveKind::Float32),
+        "float64" => primitive(PrimitiveKind::Float64),
+        "string" => Ok(TypeSpec::String {
+            wide: false,
+            bound: None,
+        }),
+        "wstring" => Ok(TypeSpec::String {
+            wide: true,
+            bound: None,
+        }),
+        "Header" => Ok(TypeSpec::Named("std_msgs::msg::Header".to_string())),
+        "time" => Ok(TypeSpec::Named("builtin_interfaces::msg::Time".to_string())),
+        "duration" => Ok(TypeSpec::Named("builtin_interfaces::msg::Duration".to_string())),
+        other if other.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '/') => {
+            let (pkg, short) = split_ros_name(other);
+            Ok(TypeSpec::Named(ros_fq_name(pkg.or(package), short)))
+        }
+        other => Err(parse_error(format!("line {}: invalid type '{}'", line, other))),
+    }
+}
+
+fn parse_ros_type(
+    token: &str,
+    package: Option<&str>,
+    line: usize,
+) -> Result<TypeSpec, RegistryError> {
+    let Some(open) = token.find('[') else {
+        return parse_ros_base_type(token, package, line);
+    };
+    let element = Box::new(parse_ros_base_type(&token[..open], package, line)?);
+    let inner = token[open + 1..]
+        .strip_suffix(']')
+        .ok_or_else(|| parse_error(format!("line {}: malformed array type", line)))?;
+    let invalid = || parse_error(format!("line {}: invalid array size", line));
+
+    if inner.is_empty() {
+        Ok(TypeSpec::Sequence {
+            element,
+            bound: None,
+        })
+    } else if let Some(bound) = inner.strip_prefix("<=") {
+        Ok(TypeSpec::Sequence {
+            element,
+            bound: Some(bound.parse().map_err(|_| invalid())?),
+        })
+    } else {
+        Ok(TypeSpec::Array {
+            element,
+            dims: vec![inner.parse().map_err(|_| invalid())?],
+        })
+    }
+}
+
+/// Parse ROS `.msg` text into a `TypeObject`.
+///
+/// `type_name` is the ROS name of the message (`pkg/msg/Name`, `pkg/Name` or
+/// `Name`); it becomes `pkg::msg::Name`, and unqualified nested types are
+/// resolved against the same package.  Constants and default values are
+/// skipped.
+pub fn parse_ros_msg(type_name: &str, text: &str) -> Result<TypeObject, RegistryError> {
+    let (package, short) = split_ros_name(type_name);
+    if short.is_empty() {
+        return Err(parse_error("empty message name"));
+    }
+
+    let mut members = Vec::new();
+    for (idx, raw_line) in text.lines().enumerate() {
+        let line_no = idx + 1;
+        let line = raw_line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+        if line.starts_with("---") {
+            return Err(parse_error(format!(
+                "line {}: service/action definitions are not supported",
+                line_no
+            )));
+        }
+
+        let mut parts = line.split_whitespace();
+        let (Some(type_token), Some(name_token)) = (parts.next(), parts.next()) else {
+            return Err(parse_error(format!("line {}: expected '<type> <name>'", line_no)));
+        };
+        // Constants: `int32 FOO=1` / `int32 FOO = 1`.
+        if name_token.contains('=') || parts.next().is_some_and(|p| p.starts_with('=')) {
+            continue;
+        }
+
+        members.push(MemberDescriptor {
+            id: members.len() as u32,
+            name: name_token.to_string(),
+            type_spec: parse_ros_type(type_token, package, line_no)?,
+            key: false,
+            optional: false,
+        });
+    }
+
+    Ok(TypeObject {
+        name: ros_fq_name(package, short),
+        kind: TypeObjectKind::Structure {
+            base: None,
+            members,
+        },
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn members(obj: &TypeObject) -> &[MemberDescriptor] {
+        match &obj.kind {
+            TypeObjectKind::Structure { members, .. } => members,
+            other => panic!("expected structure, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn idl_modules_structs_and_annotations() {
+        let idl = r#"
+            #include "common.idl"
+            module sensors {
+                /* reading from one probe */
+                @appendable
+                struct Reading {
+                    @key unsigned long id;   // probe id
+                    @optional string<32> label;
+                    sequence<double, 8> samples;
+                    float matrix[3][3];
+                    long long ts, seq;
+                    ::common::Pose pose;
+                };
+            };
+        "#;
+
+        let types = parse_idl(idl).unwrap();
+        assert_eq!(types.len(), 1);
+        assert_eq!(types[0].name, "sensors::Reading");
+
+        let m = members(&types[0]);
+        assert_eq!(m.len(), 7);
+        assert!(m[0].key);
+        assert_eq!(m[0].type_spec, TypeSpec::Primitive(PrimitiveKind::UInt32));
+        assert!(m[1].optional);
+        assert_eq!(
+            m[1].type_spec,
+            TypeSpec::String {
+                wide: false,
+                bound: Some(32)
+            }
+        );
+        assert_eq!(m[2].type_spec.to_idl(), "sequence<double,8>");
+        assert!(matches!(&m[3].type_spec, TypeSpec::Array { dims, .. } if dims == &[3, 3]));
+        assert_eq!(m[4].name, "ts");
+        assert_eq!(m[5].name, "seq");
+        assert_eq!(m[5].id, 5);
+        assert_eq!(m[6].type_spec, TypeSpec::Named("common::Pose".to_string()));
+    }
+
+    #[test]
+    fn idl_enums_typedefs_and_inheritance() {
+        let idl = "module m { enum Color { RED, @value(5) GREEN }; \
+                   typedef sequence<octet> Blob; \
+                   const long MAX = 4; \
+                   struct Base { long a; }; \
+                   struct Child : m::Base { Color c; Blob b; }; };";
+        let types = parse_idl(idl).unwrap();
+        let names: Vec<&str> = types.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["m::Color", "m::Blob", "m::Base", "m::Child"]);
+        assert_eq!(
+            types[0].kind,
+            TypeObjectKind::Enumeration {
+                literals: vec!["RED".to_string(), "GREEN".to_string()]
+            }
+        );
+        assert!(matches!(
+            &types[3].kind,
+            TypeObjectKind::Structure { base: Some(b), .. } if b == "m::Base"
+        ));
+        // Names resolve within the module; typedefs resolve to their target
+        let m = members(&types[3]);
+        assert_eq!(m[0].type_spec, TypeSpec::Named("m::Color".to_string()));
+        assert_eq!(m[1].type_spec.to_idl(), "sequence<octet>");
+    }
+
+    #[test]
+    fn idl_rejects_unsupported_and_malformed_input() {
+        assert!(matches!(
+            parse_idl("union U switch (long) { case 1: long a; };"),
+            Err(RegistryError::ParseError(_))
+        ));
+        assert!(parse_idl("struct S { long x; ").is_err());
+        assert!(parse_idl("// nothing here").is_err());
+    }
+
+    #[test]
+    fn ros_msg_fields_arrays_and_constants() {
+        let msg = "# IMU sample\n\
+                   uint8 MODE_FAST=1\n\
+                   std_msgs/Header header\n\
+                   Vector3 angular_velocity\n\
+                   float64[9] covariance\n\
+                   float32[] samples\n\
+                   int16[<=4] bounded\n\
+                   string<=16 frame  # trailing comment\n\
+                   bool valid true\n";
+        let obj = parse_ros_msg("sensor_msgs/msg/Imu", msg).unwrap();
+        assert_eq!(obj.name, "sensor_msgs::msg::Imu");
+
+        let m = members(&obj);
+        let names: Vec<&str> = m.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["header", "angular_velocity", "covariance", "samples", "bounded", "frame", "valid"]
+        );
+        assert_eq!(m[0].type_spec, TypeSpec::Named("std_msgs::msg::Header".to_string()));
+        assert_eq!(
+            m[1].type_spec,
+            TypeSpec::Named("sensor_msgs::msg::Vector3".to_string())
+        );
+        assert_eq!(m[2].type_spec.to_idl(), "double");
+        assert!(matches!(&m[2].type_spec, TypeSpec::Array { dims, .. } if dims == &[9]));
+        assert_eq!(m[3].type_spec.to_idl(), "sequence<float>");
+        assert_eq!(m[4].type_spec.to_idl(), "sequence<int16,4>");
+        assert_eq!(m[5].type_spec.to_idl(), "string<16>");
+    }
+
+    #[test]
+    fn ros_msg_rejects_services_and_bad_types() {
+        assert!(parse_ros_msg("pkg/Srv", "int32 a\n---\nint32 b\n").is_err());
+        assert!(parse_ros_msg("pkg/Bad", "float64[x] values\n").is_err());
+        assert!(parse_ros_msg("pkg/Bad", "lonely\n").is_err());
+    }
+}
@@ -0,0 +1,379 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! JSON Schema ingestion and compatibility.
+//!
+//! Documents are validated to be JSON Schemas and stored as compact JSON
+//! with sorted keys, so formatting changes do not create new versions.
+//! Compatibility understands the keywords that decide which documents a
+//! schema accepts -- `type`, `properties`, `required`,
+//! `additionalProperties`, `enum` and `items` -- recursively.  Any other
+//! keyword change (`$ref`, `allOf`, ...) is reported as breaking.
+
+use serde_json::{Map, Value};
+
+use crate::compatibility::{directional_result, CompatibilityResult};
+use crate::registry::RegistryError;
+
+/// Keywords compared structurally; differences in any other keyword are
+/// treated as breaking.
+const KNOWN_KEYWORDS: &[&str] = &[
+    "type",
+    "properties",
+    "required",
+    "additionalProperties",
+    "enum",
+    "items",
+    // Annotations without validation effect.
+    "$schema",
+    "$id",
+    "title",
+    "description",
+    "default",
+    "examples",
+];
+
+/// Keywords of which at least one must be present at the root.
+const SCHEMA_KEYWORDS: &[&str] = &[
+    "$schema",
+    "$ref",
+    "type",
+    "properties",
+    "items",
+    "enum",
+    "allOf",
+    "anyOf",
+    "oneOf",
+];
+
+fn parse_error(msg: impl Into<String>) -> RegistryError {
+    RegistryError::ParseError(msg.into())
+}
+
+/// Parse and validate a JSON Schema document.
+///
+/// Returns the canonical content to store: compact JSON with sorted keys.
+pub fn parse_json_schema(text: &str) -> Result<String, RegistryError> {
+    let value: Value =
+        serde_json::from_str(text).map_err(|e| parse_error(format!("invalid JSON: {}", e)))?;
+    let Value::Object(root) = &value else {
+        return Err(parse_error("JSON Schema must be an object"));
+    };
+    if !SCHEMA_KEYWORDS.iter().any(|k| root.contains_key(*k)) {
+        return Err(parse_error(
+            "document has none of the JSON Schema keywords ($schema, type, properties, ...)",
+        ));
+    }
+    // serde_json maps are ordered by key, so this is canonical.
+    Ok(value.to_string())
+}
+
+// ---------------------------------------------------------------------------
+// Compatibility
+// ---------------------------------------------------------------------------
+
+struct Verdict {
+    backward: bool,
+    forward: bool,
+    details: Vec<String>,
+}
+
+impl Verdict {
+    /// New readers may reject documents valid under the old schema.
+    fn not_backward(&mut self, detail: String) {
+        self.backward = false;
+        self.details.push(detail);
+    }
+
+    /// Old readers may reject documents valid under the new schema.
+    fn not_forward(&mut self, detail: String) {
+        self.forward = false;
+        self.details.push(detail);
+    }
+
+    fn breaking(&mut self, detail: String) {
+        self.backward = false;
+        self.forward = false;
+        self.details.push(detail);
+    }
+}
+
+/// Compare two JSON Schema documents.
+pub(crate) fn check_json_schema_compatibility(old: &str, new: &str) -> CompatibilityResult {
+    let parse = |text: &str| serde_json::from_str::<Value>(text);
+    match (parse(old), parse(new)) {
+        (Ok(old), Ok(new)) => {
+            let mut verdict = Verdict {
+                backward: true,
+                forward: true,
+                details: Vec::new(),
+            };
+            compare(&old, &new, "$", &mut verdict);
+            directional_result(verdict.backward, verdict.forward, verdict.details)
+        }
+        (Err(e), _) | (_, Err(e)) => CompatibilityResult {
+            compatibility: crate::compatibility::Compatibility::Breaking,
+            details: vec![format!("cannot parse JSON Schema: {}", e)],
+        },
+    }
+}
+
+/// Set of `type` names, `None` when unconstrained.
+fn types(schema: &Map<String, Value>) -> Option<Vec<&str>> {
+    match schema.get("type")? {
+        Value::String(t) => Some(vec![t.as_str()]),
+        Value::Array(ts) => Some(ts.iter().filter_map(Value::as_str).collect()),
+        _ => None,
+    }
+}
+
+/// Whether a schema of type set `wide` accepts every value of type set
+/// `narrow` (`number` covers `integer`).
+fn accepts_types(wide: &Option<Vec<&str>>, narrow: &Option<Vec<&str>>) -> bool {
+    match (wide, narrow) {
+        (None, _) => true,
+        (Some(_), None) => false,
+        (Some(wide), Some(narrow)) => narrow
+            .iter()
+            .all(|t| wide.contains(t) || (*t == "integer" && wide.contains(&"number"))),
+    }
+}
+
+fn required(schema: &Map<String, Value>) -> Vec<&str> {
+    schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|r| r.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default()
+}
+
+/// Whether properties not listed in `properties` are rejected.
+fn closed(schema: &Map<String, Value>) -> bool {
+    schema.get("additionalProperties") == Some(&Value::Bool(false))
+}
+
+fn compare(old: &Value, new: &Value, path: &str, v: &mut Verdict) {
+    if old == new {
+        return;
+    }
+    let (Value::Object(old), Value::Object(new)) = (old, new) else {
+        // Boolean schemas (`true` / `false`).
+        v.breaking(format!("{}: schema changed", path));
+        return;
+    };
+
+    let (old_types, new_types) = (types(old), types(new));
+    if old_types != new_types {
+        let detail = format!("{}: type changed {:?} -> {:?}", path, old_types, new_types);
+        match (
+            accepts_types(&new_types, &old_types),
+            accepts_types(&old_types, &new_types),
+        ) {
+            (true, _) => v.not_forward(detail),
+            (false, true) => v.not_backward(detail),
+            (false, false) => v.breaking(detail),
+        }
+    }
+
+    compare_properties(old, new, path, v);
+
+    if closed(old) != closed(new) {
+        let detail = format!("{}: additionalProperties changed", path);
+        if closed(new) {
+            v.not_backward(detail);
+        } else {
+            v.not_forward(detail);
+        }
+    }
+
+    match (old.get("enum"), new.get("enum")) {
+        (Some(Value::Array(old_values)), Some(Value::Array(new_values))) => {
+            for value in old_values.iter().filter(|x| !new_values.contains(x)) {
+                v.not_backward(format!("{}: removed enum value {}", path, value));
+            }
+            for value in new_values.iter().filter(|x| !old_values.contains(x)) {
+                v.not_forward(format!("{}: added enum value {}", path, value));
+            }
+        }
+        (None, Some(_)) => v.not_backward(format!("{}: enum added", path)),
+        (Some(_), None) => v.not_forward(format!("{}: enum removed", path)),
+        _ => {}
+    }
+
+    match (old.get("items"), new.get("items")) {
+        (Some(old_items), Some(new_items)) => {
+            compare(old_items, new_items, &format!("{}[]", path), v);
+        }
+        (None, Some(_)) => v.not_backward(format!("{}: items constrained", path)),
+        (Some(_), None) => v.not_forward(format!("{}: items unconstrained", path)),
+        (None, None) => {}
+    }
+
+    let keys = old
+        .keys()
+        .chain(new.keys().filter(|k| !old.contains_key(*k)));
+    for key in keys.filter(|k| !KNOWN_KEYWORDS.contains(&k.as_str())) {
+        if old.get(key) != new.get(key) {
+            v.breaking(format!("{}: {} changed", path, key));
+        }
+    }
+}
+
+fn compare_properties(
+    old: &Map<String, Value>,
+    new: &Map<String, Value>,
+    path: &str,
+    v: &mut Verdict,
+) {
+    let empty = Map::new();
+    let old_props = old
+        .get("properties")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+    let new_props = new
+        .get("properties")
+        .and_then(Value::as_object)
+        .unwrap_or(&empty);
+    let (old_required, new_required) = (required(old), required(new));
+
+    for (name, old_prop) in old_props {
+        let prop_path = format!("{}.{}", path, name);
+        match new_props.get(name) {
+            Some(new_prop) => compare(old_prop, new_prop, &prop_path, v),
+            None => {
+                let detail = format!("{}: property removed", prop_path);
+                if closed(new) {
+                    v.not_backward(detail);
+                } else if old_required.contains(&name.as_str()) {
+                    v.not_forward(detail);
+                } else {
+                    v.details.push(detail);
+                }
+            }
+        }
+    }
+    for name in new_props.keys().filter(|k| !old_props.contains_key(*k)) {
+        let detail = format!("{}.{}: property added", path, name);
+        if closed(old) {
+            v.not_forward(detail);
+        } else if new_required.contains(&name.as_str()) {
+            v.not_backward(detail);
+        } else {
+            v.details.push(detail);
+        }
+    }
+
+    for name in new_required.iter().filter(|n| !old_required.contains(n)) {
+        if old_props.contains_key(*name) || !new_props.contains_key(*name) {
+            v.not_backward(format!("{}.{}: now required", path, name));
+        }
+    }
+    for name in old_required.iter().filter(|n| !new_required.contains(n)) {
+        if new_props.contains_key(*name) || !old_props.contains_key(*name) {
+            v.not_forward(format!("{}.{}: no longer required", path, name));
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compatibility::Compatibility;
+
+    const READING: &str = r#"{
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "type": "object",
+        "properties": {
+            "id": {"type": "integer"},
+            "value": {"type": "number"},
+            "status": {"enum": ["OK", "FAILED"]}
+        },
+        "required": ["id"]
+    }"#;
+
+    fn check(old: &str, new: &str) -> Compatibility {
+        check_json_schema_compatibility(
+            &parse_json_schema(old).unwrap(),
+            &parse_json_schema(new).unwrap(),
+        )
+        .compatibility
+    }
+
+    fn with(patch: impl FnOnce(&mut Map<String, Value>)) -> String {
+        let mut value: Value = serde_json::from_str(READING).unwrap();
+        patch(value.as_object_mut().unwrap());
+        value.to_string()
+    }
+
+    fn properties(schema: &mut Map<String, Value>) -> &mut Map<String, Value> {
+        schema["properties"].as_object_mut().unwrap()
+    }
+
+    #[test]
+    fn canonical_content_ignores_formatting() {
+        let compact = r#"{"required":["id"],"type":"object","properties":{"status":{"enum":["OK","FAILED"]},"value":{"type":"number"},"id":{"type":"integer"}},"$schema":"https://json-schema.org/draft/2020-12/schema"}"#;
+        assert_eq!(
+            parse_json_schema(READING).unwrap(),
+            parse_json_schema(compact).unwrap()
+        );
+        assert!(parse_json_schema(r#"{"id": 1}"#).is_err());
+        assert!(parse_json_schema("[1]").is_err());
+    }
+
+    #[test]
+    fn optional_property_added_is_full() {
+        let new = with(|s| {
+            properties(s).insert("unit".into(), serde_json::json!({"type": "string"}));
+        });
+        assert_eq!(check(READING, &new), Compatibility::Full);
+    }
+
+    #[test]
+    fn required_property_added_is_forward() {
+        let new = with(|s| {
+            properties(s).insert("unit".into(), serde_json::json!({"type": "string"}));
+            s["required"] = serde_json::json!(["id", "unit"]);
+        });
+        assert_eq!(check(READING, &new), Compatibility::Forward);
+        assert_eq!(check(&new, READING), Compatibility::Backward);
+    }
+
+    #[test]
+    fn widened_type_is_backward() {
+        let new = with(|s| {
+            properties(s)["id"] = serde_json::json!({"type": "number"});
+        });
+        assert_eq!(check(READING, &new), Compatibility::Backward);
+        assert_eq!(check(&new, READING), Compatibility::Forward);
+    }
+
+    #[test]
+    fn closed_schema_and_enum_changes() {
+        let closed = with(|s| {
+            s.insert("additionalProperties".into(), Value::Bool(false));
+        });
+        assert_eq!(check(READING, &closed), Compatibility::Forward);
+
+        let more_status = with(|s| {
+            properties(s)["status"] = serde_json::json!({"enum": ["OK", "FAILED", "STALE"]});
+        });
+        assert_eq!(check(READING, &more_status), Compatibility::Backward);
+    }
+
+    #[test]
+    fn type_change_and_unknown_keywords_are_breaking() {
+        let new = with(|s| {
+            properties(s)["value"] = serde_json::json!({"type": "string"});
+        });
+        assert_eq!(check(READING, &new), Compatibility::Breaking);
+
+        let with_ref = with(|s| {
+            properties(s)["value"] = serde_json::json!({"$ref": "#/$defs/Value"});
+        });
+        assert_eq!(check(READING, &with_ref), Compatibility::Breaking);
+    }
+}
@@ -10,9 +10,17 @@
 //! # Features
 //!
 //! - **Schema storage**: Register and retrieve type schemas by name and version
-//! - **Compatibility checking**: Validate schema evolution against DDS XTypes rules
-//!   (FULL, BACKWARD, FORWARD, NONE compatibility levels)
+//! - **Compatibility checking**: Validate schema evolution against DDS XTypes,
+//!   protobuf wire and JSON Schema rules (FULL, BACKWARD, FORWARD, NONE
+//!   compatibility levels)
+//! - **Ingestion**: Upload raw IDL or ROS `.msg` text; it is parsed into
+//!   `TypeObject`s server-side
+//! - **Protobuf / JSON Schema**: Ingest `FileDescriptorSet`s and JSON Schema
+//!   documents, versioned per subject with format-specific compatibility
+//!   rules, so DDS-to-Kafka bridges can share one registry
 //! - **Persistence**: Optional durable storage for schema history
+//! - **DDS registration** (feature `hdds`): Register the TypeObjects that
+//!   participants announce, keyed by type name and equivalence hash
 //! - **HTTP server**: REST API for schema operations (Confluent-compatible subset)
 //!
 //! # Architecture
@@ -29,8 +37,18 @@
 
 pub mod registry;
 pub mod compatibility;
+#[cfg(feature = "hdds")]
+pub mod dds;
+pub mod ingest;
+pub mod json_schema;
 pub mod persistence;
+pub mod protobuf;
 pub mod server;
+pub mod type_object;
 
-pub use registry::{SchemaRegistry, SchemaEntry, SchemaFormat, RegistryError};
+pub use registry::{SchemaRegistry, SchemaEntry, SchemaFormat, RegistryError, XTypesIdentity};
 pub use compatibility::{Compatibility, CompatibilityResult, check_compatibility};
+pub use ingest::{parse_idl, parse_ros_msg};
+pub use json_schema::parse_json_schema;
+pub use protobuf::{parse_file_descriptor_set, ProtoSchema};
+pub use type_object::{MemberDescriptor, PrimitiveKind, TypeObject, TypeObjectKind, TypeSpec};
@@ -0,0 +1,681 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Protobuf schema ingestion from `FileDescriptorSet`s.
+//!
+//! Lets teams bridging DDS to Kafka register the same `.proto` schemas
+//! their Kafka producers use: the uploaded `FileDescriptorSet` (the output
+//! of `protoc --descriptor_set_out`) is decoded into a [`ProtoSchema`] whose
+//! canonical JSON rendering is stored as schema content.  Only the parts of
+//! `descriptor.proto` that affect the wire format are kept -- messages,
+//! their fields and enums; services, options and source info are skipped.
+//!
+//! Compatibility follows the protobuf wire rules: fields are identified by
+//! number, so renaming a field is compatible and reusing a number with an
+//! incompatible type is not.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::compatibility::{directional_result, CompatibilityResult};
+use crate::registry::RegistryError;
+
+// ---------------------------------------------------------------------------
+// Schema model
+// ---------------------------------------------------------------------------
+
+/// Field cardinality (`FieldDescriptorProto.Label`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProtoLabel {
+    /// `optional` (and every proto3 singular field).
+    Optional,
+    /// proto2 `required`.
+    Required,
+    /// `repeated` (including map fields).
+    Repeated,
+}
+
+/// One field of a message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProtoField {
+    /// Field number (wire tag).
+    pub number: u32,
+    /// Field name.
+    pub name: String,
+    /// Scalar type name (`int32`, `string`, ...) or fully-qualified
+    /// message/enum name (`pkg.Msg`).
+    pub type_name: String,
+    /// Cardinality.
+    pub label: ProtoLabel,
+}
+
+/// A message, with its nested types flattened into [`ProtoSchema`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProtoMessage {
+    /// Fully-qualified name (`pkg.Outer.Inner`).
+    pub name: String,
+    /// Fields sorted by number.
+    pub fields: Vec<ProtoField>,
+}
+
+/// An enum and its values.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProtoEnum {
+    /// Fully-qualified name.
+    pub name: String,
+    /// `(name, number)` pairs sorted by number.
+    pub values: Vec<(String, i32)>,
+}
+
+/// Wire-relevant content of a `FileDescriptorSet`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProtoSchema {
+    /// Messages sorted by name.
+    pub messages: Vec<ProtoMessage>,
+    /// Enums sorted by name.
+    pub enums: Vec<ProtoEnum>,
+}
+
+impl ProtoSchema {
+    /// Canonical JSON rendering, stored as schema content.
+    ///
+    /// Independent of file order, comments and options, so re-uploading an
+    /// equivalent descriptor set yields identical content.
+    pub fn to_canonical_json(&self) -> String {
+        serde_json::to_string(self).expect("ProtoSchema serialization cannot fail")
+    }
+
+    /// Parse content produced by [`to_canonical_json`](Self::to_canonical_json).
+    pub fn from_canonical_json(content: &str) -> Result<Self, RegistryError> {
+        serde_json::from_str(content)
+            .map_err(|e| RegistryError::ParseError(format!("invalid protobuf schema: {}", e)))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Protobuf wire decoding
+// ---------------------------------------------------------------------------
+
+fn parse_error(msg: impl Into<String>) -> RegistryError {
+    RegistryError::ParseError(msg.into())
+}
+
+/// Value of one field of an encoded protobuf message.
+enum WireValue<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+    /// Fixed32/fixed64 values (never needed for descriptors).
+    Fixed,
+}
+
+struct WireReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> WireReader<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        WireReader { buf, pos: 0 }
+    }
+
+    fn varint(&mut self) -> Result<u64, RegistryError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = *self
+                .buf
+                .get(self.pos)
+                .ok_or_else(|| parse_error("truncated varint"))?;
+            self.pos += 1;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(parse_error("varint too long"))
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], RegistryError> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|&end| end <= self.buf.len())
+            .ok_or_else(|| parse_error("truncated field"))?;
+        let bytes = &self.buf[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    /// Next `(field number, value)`, or `None` at the end of the message.
+    fn next_field(&mut self) -> Result<Option<(u32, WireValue<'a>)>, RegistryError> {
+        if self.pos >= self.buf.len() {
+            return Ok(None);
+        }
+        let key = self.varint()?;
+        let number = u32::try_from(key >> 3).map_err(|_| parse_error("invalid field number"))?;
+        let value = match key & 7 {
+            0 => WireValue::Varint(self.varint()?),
+            1 => {
+                self.take(8)?;
+                WireValue::Fixed
+            }
+            2 => {
+                let len = usize::try_from(self.varint()?)
+                    .map_err(|_| parse_error("invalid field length"))?;
+                WireValue::Bytes(self.take(len)?)
+            }
+            5 => {
+                self.take(4)?;
+                WireValue::Fixed
+            }
+            other => return Err(parse_error(format!("unsupported wire type {}", other))),
+        };
+        Ok(Some((number, value)))
+    }
+}
+
+fn utf8(bytes: &[u8]) -> Result<String, RegistryError> {
+    String::from_utf8(bytes.to_vec()).map_err(|_| parse_error("invalid UTF-8 in descriptor"))
+}
+
+fn qualify(scope: &str, name: &str) -> String {
+    if scope.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", scope, name)
+    }
+}
+
+/// Scalar name of a `FieldDescriptorProto.Type`, `None` for message/enum
+/// (or an unset type, resolved through `type_name`).
+fn scalar_name(field_type: u64) -> Result<Option<&'static str>, RegistryError> {
+    Ok(Some(match field_type {
+        1 => "double",
+        2 => "float",
+        3 => "int64",
+        4 => "uint64",
+        5 => "int32",
+        6 => "fixed64",
+        7 => "fixed32",
+        8 => "bool",
+        9 => "string",
+        12 => "bytes",
+        13 => "uint32",
+        15 => "sfixed32",
+        16 => "sfixed64",
+        17 => "sint32",
+        18 => "sint64",
+        0 | 11 | 14 => return Ok(None),
+        10 => return Err(parse_error("proto2 groups are not supported")),
+        other => return Err(parse_error(format!("unknown field type {}", other))),
+    }))
+}
+
+fn decode_field(buf: &[u8]) -> Result<ProtoField, RegistryError> {
+    let mut name = String::new();
+    let mut number = 0u32;
+    let mut label = ProtoLabel::Optional;
+    let mut field_type = 0u64;
+    let mut type_name = String::new();
+
+    let mut reader = WireReader::new(buf);
+    while let Some((tag, value)) = reader.next_field()? {
+        match (tag, value) {
+            (1, WireValue::Bytes(b)) => name = utf8(b)?,
+            (3, WireValue::Varint(v)) => {
+                number = u32::try_from(v).map_err(|_| parse_error("invalid field number"))?;
+            }
+            (4, WireValue::Varint(v)) => {
+                label = match v {
+                    2 => ProtoLabel::Required,
+                    3 => ProtoLabel::Repeated,
+                    _ => ProtoLabel::Optional,
+                };
+            }
+            (5, WireValue::Varint(v)) => field_type = v,
+            (6, WireValue::Bytes(b)) => type_name = utf8(b)?,
+            _ => {}
+        }
+    }
+
+    let type_name = match scalar_name(field_type)? {
+        Some(scalar) => scalar.to_string(),
+        None if !type_name.is_empty() => type_name.trim_start_matches('.').to_string(),
+        None => return Err(parse_error(format!("field '{}' has no type name", name))),
+    };
+    Ok(ProtoField {
+        number,
+        name,
+        type_name,
+        label,
+    })
+}
+
+fn decode_enum(buf: &[u8], scope: &str, schema: &mut ProtoSchema) -> Result<(), RegistryError> {
+    let mut name = String::new();
+    let mut values = Vec::new();
+
+    let mut reader = WireReader::new(buf);
+    while let Some((tag, value)) = reader.next_field()? {
+        match (tag, value) {
+            (1, WireValue::Bytes(b)) => name = utf8(b)?,
+            (2, WireValue::Bytes(b)) => {
+                let mut value_name = String::new();
+                let mut number = 0i32;
+                let mut value_reader = WireReader::new(b);
+                while let Some((tag, value)) = value_reader.next_field()? {
+                    match (tag, value) {
+                        (1, WireValue::Bytes(b)) => value_name = utf8(b)?,
+                        // int32 is sign-extended to 64 bits on the wire.
+                        (2, WireValue::Varint(v)) => number = v as i64 as i32,
+                        _ => {}
+                    }
+                }
+                values.push((value_name, number));
+            }
+            _ => {}
+        }
+    }
+
+    values.sort_by_key(|(_, number)| *number);
+    schema.enums.push(ProtoEnum {
+        name: qualify(scope, &name),
+        values,
+    });
+    Ok(())
+}
+
+fn decode_message(buf: &[u8], scope: &str, schema: &mut ProtoSchema) -> Result<(), RegistryError> {
+    // The name may follow nested types on the wire; collect them first.
+    let mut name = String::new();
+    let mut fields = Vec::new();
+    let mut nested_messages = Vec::new();
+    let mut nested_enums = Vec::new();
+
+    let mut reader = WireReader::new(buf);
+    while let Some((tag, value)) = reader.next_field()? {
+        match (tag, value) {
+            (1, WireValue::Bytes(b)) => name = utf8(b)?,
+            (2, WireValue::Bytes(b)) => fields.push(decode_field(b)?),
+            (3, WireValue::Bytes(b)) => nested_messages.push(b),
+            (4, WireValue::Bytes(b)) => nested_enums.push(b),
+            _ => {}
+        }
+    }
+
+    let full_name = qualify(scope, &name);
+    for nested in nested_messages {
+        decode_message(nested, &full_name, schema)?;
+    }
+    for nested in nested_enums {
+        decode_enum(nested, &full_name, schema)?;
+    }
+
+    fields.sort_by_key(|f| f.number);
+    schema.messages.push(ProtoMessage {
+        name: full_name,
+        fields,
+    });
+    Ok(())
+}
+
+fn decode_file(buf: &[u8], schema: &mut ProtoSchema) -> Result<(), RegistryError> {
+    let mut package = String::new();
+    let mut messages = Vec::new();
+    let mut enums = Vec::new();
+
+    let mut reader = WireReader::new(buf);
+    while let Some((tag, value)) = reader.next_field()? {
+        match (tag, value) {
+            (2, WireValue::Bytes(b)) => package = utf8(b)?,
+            (4, WireValue::Bytes(b)) => messages.push(b),
+            (5, WireValue::Bytes(b)) => enums.push(b),
+            _ => {}
+        }
+    }
+
+    for message in messages {
+        decode_message(message, &package, schema)?;
+    }
+    for e in enums {
+        decode_enum(e, &package, schema)?;
+    }
+    Ok(())
+}
+
+/// Decode a binary `FileDescriptorSet` into a [`ProtoSchema`].
+///
+/// Every file of the set contributes its messages and enums, so a set
+/// built with `--include_imports` registers imported types as well.
+pub fn parse_file_descriptor_set(bytes: &[u8]) -> Result<ProtoSchema, RegistryError> {
+    let mut schema = ProtoSchema::default();
+    let mut reader = WireReader::new(bytes);
+    while let Some((tag, value)) = reader.next_field()? {
+        if let (1, WireValue::Bytes(file)) = (tag, value) {
+            decode_file(file, &mut schema)?;
+        }
+    }
+
+    if schema.messages.is_empty() && schema.enums.is_empty() {
+        return Err(parse_error("FileDescriptorSet defines no types"));
+    }
+    schema.messages.sort_by(|a, b| a.name.cmp(&b.name));
+    schema.enums.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(schema)
+}
+
+// ---------------------------------------------------------------------------
+// Compatibility
+// ---------------------------------------------------------------------------
+
+/// Scalar types sharing a wire encoding; changing between members of a
+/// group keeps old data readable (values may be truncated or reinterpreted).
+const WIRE_GROUPS: &[&[&str]] = &[
+    &["int32", "uint32", "int64", "uint64", "bool"],
+    &["sint32", "sint64"],
+    &["fixed32", "sfixed32"],
+    &["fixed64", "sfixed64"],
+    &["string", "bytes"],
+];
+
+fn wire_compatible(old: &str, new: &str) -> bool {
+    old == new
+        || WIRE_GROUPS
+            .iter()
+            .any(|group| group.contains(&old) && group.contains(&new))
+}
+
+/// Compare two schemas stored as canonical JSON.
+pub(crate) fn check_protobuf_compatibility(old: &str, new: &str) -> CompatibilityResult {
+    match (
+        ProtoSchema::from_canonical_json(old),
+        ProtoSchema::from_canonical_json(new),
+    ) {
+        (Ok(old), Ok(new)) => compare_schemas(&old, &new),
+        (Err(e), _) | (_, Err(e)) => CompatibilityResult {
+            compatibility: crate::compatibility::Compatibility::Breaking,
+            details: vec![format!("cannot parse protobuf schema: {}", e)],
+        },
+    }
+}
+
+fn compare_schemas(old: &ProtoSchema, new: &ProtoSchema) -> CompatibilityResult {
+    let mut backward = true;
+    let mut forward = true;
+    let mut details = Vec::new();
+
+    let new_messages: BTreeMap<&str, &ProtoMessage> =
+        new.messages.iter().map(|m| (m.name.as_str(), m)).collect();
+    for old_msg in &old.messages {
+        let Some(new_msg) = new_messages.get(old_msg.name.as_str()) else {
+            details.push(format!("removed message: {}", old_msg.name));
+            backward = false;
+            forward = false;
+            continue;
+        };
+
+        let new_fields: BTreeMap<u32, &ProtoField> =
+            new_msg.fields.iter().map(|f| (f.number, f)).collect();
+        for of in &old_msg.fields {
+            let field = format!("{}.{} = {}", old_msg.name, of.name, of.number);
+            let Some(nf) = new_fields.get(&of.number) else {
+                details.push(format!("removed field: {}", field));
+                if of.label == ProtoLabel::Required {
+                    // Old readers reject data without it.
+                    forward = false;
+                }
+                continue;
+            };
+
+            if of.type_name != nf.type_name {
+                details.push(format!(
+                    "changed type of {}: {} -> {}",
+                    field, of.type_name, nf.type_name
+                ));
+                if !wire_compatible(&of.type_name, &nf.type_name) {
+                    backward = false;
+                    forward = false;
+                }
+            }
+            if of.label != nf.label {
+                details.push(format!(
+                    "changed label of {}: {:?} -> {:?}",
+                    field, of.label, nf.label
+                ));
+                match (of.label, nf.label) {
+                    (ProtoLabel::Repeated, _) | (_, ProtoLabel::Repeated) => {
+                        backward = false;
+                        forward = false;
+                    }
+                    (_, ProtoLabel::Required) => backward = false,
+                    (ProtoLabel::Required, _) => forward = false,
+                    _ => {}
+                }
+            }
+            if of.name != nf.name {
+                details.push(format!("renamed field {}: -> {}", field, nf.name));
+            }
+        }
+
+        for nf in &new_msg.fields {
+            if !old_msg.fields.iter().any(|f| f.number == nf.number) {
+                details.push(format!(
+                    "added field: {}.{} = {}",
+                    new_msg.name, nf.name, nf.number
+                ));
+                if nf.label == ProtoLabel::Required {
+                    // New readers reject data written without it.
+                    backward = false;
+                }
+            }
+        }
+    }
+    for new_msg in &new.messages {
+        if !old.messages.iter().any(|m| m.name == new_msg.name) {
+            details.push(format!("added message: {}", new_msg.name));
+        }
+    }
+
+    let new_enums: BTreeMap<&str, &ProtoEnum> =
+        new.enums.iter().map(|e| (e.name.as_str(), e)).collect();
+    for old_enum in &old.enums {
+        let Some(new_enum) = new_enums.get(old_enum.name.as_str()) else {
+            details.push(format!("removed enum: {}", old_enum.name));
+            backward = false;
+            forward = false;
+            continue;
+        };
+        for (name, number) in &old_enum.values {
+            if !new_enum.values.iter().any(|(_, n)| n == number) {
+                details.push(format!(
+                    "removed value {}.{} = {}",
+                    old_enum.name, name, number
+                ));
+                backward = false;
+            }
+        }
+        for (name, number) in &new_enum.values {
+            if !old_enum.values.iter().any(|(_, n)| n == number) {
+                details.push(format!(
+                    "added value {}.{} = {}",
+                    new_enum.name, name, number
+                ));
+                forward = false;
+            }
+        }
+    }
+    for new_enum in &new.enums {
+        if !old.enums.iter().any(|e| e.name == new_enum.name) {
+            details.push(format!("added enum: {}", new_enum.name));
+        }
+    }
+
+    directional_result(backward, forward, details)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::compatibility::Compatibility;
+
+    // Minimal protobuf encoder for building descriptor sets.
+
+    fn varint(out: &mut Vec<u8>, mut v: u64) {
+        while v >= 0x80 {
+            out.push((v as u8) | 0x80);
+            v >>= 7;
+        }
+        out.push(v as u8);
+    }
+
+    fn int_field(out: &mut Vec<u8>, tag: u64, v: u64) {
+        varint(out, tag << 3);
+        varint(out, v);
+    }
+
+    fn bytes_field(out: &mut Vec<u8>, tag: u64, b: &[u8]) {
+        varint(out, (tag << 3) | 2);
+        varint(out, b.len() as u64);
+        out.extend_from_slice(b);
+    }
+
+    /// `(name, number, label, type, type_name)`
+    pub(crate) type FieldSpec<'a> = (&'a str, u64, u64, u64, &'a str);
+
+    fn message(name: &str, fields: &[FieldSpec<'_>], nested: &[Vec<u8>]) -> Vec<u8> {
+        let mut out = Vec::new();
+        for nested in nested {
+            bytes_field(&mut out, 3, nested);
+        }
+        bytes_field(&mut out, 1, name.as_bytes());
+        for (fname, number, label, ftype, type_name) in fields {
+            let mut f = Vec::new();
+            bytes_field(&mut f, 1, fname.as_bytes());
+            int_field(&mut f, 3, *number);
+            int_field(&mut f, 4, *label);
+            int_field(&mut f, 5, *ftype);
+            if !type_name.is_empty() {
+                bytes_field(&mut f, 6, type_name.as_bytes());
+            }
+            bytes_field(&mut out, 2, &f);
+        }
+        out
+    }
+
+    fn enum_type(name: &str, values: &[(&str, i64)]) -> Vec<u8> {
+        let mut out = Vec::new();
+        bytes_field(&mut out, 1, name.as_bytes());
+        for (vname, number) in values {
+            let mut v = Vec::new();
+            bytes_field(&mut v, 1, vname.as_bytes());
+            int_field(&mut v, 2, *number as u64);
+            bytes_field(&mut out, 2, &v);
+        }
+        out
+    }
+
+    /// Descriptor set of one file in package `telemetry` with one
+    /// top-level message and the `Status` enum.
+    pub(crate) fn descriptor_set(fields: &[FieldSpec<'_>]) -> Vec<u8> {
+        let inner = message("Point", &[("x", 1, 1, 1, "")], &[]);
+        let mut file = Vec::new();
+        bytes_field(&mut file, 1, b"telemetry.proto");
+        bytes_field(&mut file, 2, b"telemetry");
+        bytes_field(&mut file, 4, &message("Reading", fields, &[inner]));
+        bytes_field(
+            &mut file,
+            5,
+            &enum_type("Status", &[("OK", 0), ("FAILED", -1)]),
+        );
+        let mut set = Vec::new();
+        bytes_field(&mut set, 1, &file);
+        set
+    }
+
+    pub(crate) const READING_V1: &[FieldSpec<'static>] = &[
+        ("id", 1, 1, 13, ""),
+        ("value", 2, 1, 1, ""),
+        ("points", 3, 3, 11, ".telemetry.Reading.Point"),
+    ];
+
+    fn schema(fields: &[FieldSpec<'_>]) -> String {
+        parse_file_descriptor_set(&descriptor_set(fields))
+            .unwrap()
+            .to_canonical_json()
+    }
+
+    #[test]
+    fn decodes_messages_nested_types_and_enums() {
+        let schema = parse_file_descriptor_set(&descriptor_set(READING_V1)).unwrap();
+
+        let names: Vec<&str> = schema.messages.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, vec!["telemetry.Reading", "telemetry.Reading.Point"]);
+        let fields = &schema.messages[0].fields;
+        assert_eq!(fields[0].type_name, "uint32");
+        assert_eq!(fields[2].type_name, "telemetry.Reading.Point");
+        assert_eq!(fields[2].label, ProtoLabel::Repeated);
+        assert_eq!(
+            schema.enums[0].values,
+            vec![("FAILED".to_string(), -1), ("OK".to_string(), 0)]
+        );
+
+        let json = schema.to_canonical_json();
+        assert_eq!(ProtoSchema::from_canonical_json(&json).unwrap(), schema);
+    }
+
+    #[test]
+    fn rejects_malformed_descriptor_sets() {
+        assert!(matches!(
+            parse_file_descriptor_set(&[0x0a, 0x05, 0x01]),
+            Err(RegistryError::ParseError(_))
+        ));
+        assert!(parse_file_descriptor_set(&[]).is_err());
+    }
+
+    #[test]
+    fn added_optional_field_is_full() {
+        let mut v2 = READING_V1.to_vec();
+        v2.push(("unit", 4, 1, 9, ""));
+        let result = check_protobuf_compatibility(&schema(READING_V1), &schema(&v2));
+        assert_eq!(result.compatibility, Compatibility::Full);
+        assert!(result.details[0].contains("added field"));
+    }
+
+    #[test]
+    fn renamed_and_widened_fields_stay_compatible() {
+        let v2 = [
+            ("probe_id", 1, 1, 4, ""),
+            ("value", 2, 1, 1, ""),
+            ("points", 3, 3, 11, ".telemetry.Reading.Point"),
+        ];
+        let result = check_protobuf_compatibility(&schema(READING_V1), &schema(&v2));
+        assert_eq!(result.compatibility, Compatibility::Full);
+        assert_eq!(result.details.len(), 2);
+    }
+
+    #[test]
+    fn reused_number_with_other_type_is_breaking() {
+        let v2 = [
+            ("id", 1, 1, 9, ""),
+            ("value", 2, 1, 1, ""),
+            ("points", 3, 3, 11, ".telemetry.Reading.Point"),
+        ];
+        let result = check_protobuf_compatibility(&schema(READING_V1), &schema(&v2));
+        assert_eq!(result.compatibility, Compatibility::Breaking);
+    }
+
+    #[test]
+    fn required_fields_are_directional() {
+        let mut v2 = READING_V1.to_vec();
+        v2.push(("unit", 4, 2, 9, ""));
+        let added = check_protobuf_compatibility(&schema(READING_V1), &schema(&v2));
+        assert_eq!(added.compatibility, Compatibility::Forward);
+
+        let removed = check_protobuf_compatibility(&schema(&v2), &schema(READING_V1));
+        assert_eq!(removed.compatibility, Compatibility::Backward);
+    }
+}
@@ -9,6 +9,8 @@ use std::time::SystemTime;
 
 use serde::{Deserialize, Serialize};
 
+use crate::type_object::TypeObject;
+
 // ---------------------------------------------------------------------------
 // SchemaFormat
 // ---------------------------------------------------------------------------
@@ -22,6 +24,13 @@ pub enum SchemaFormat {
     Json,
     /// XTypes type hash (opaque identifier).
     XTypesHash,
+    /// ROS 2 `.msg` definition text.
+    RosMsg,
+    /// Protobuf schema ingested from a `FileDescriptorSet`, stored as the
+    /// canonical JSON of [`ProtoSchema`](crate::protobuf::ProtoSchema).
+    Protobuf,
+    /// JSON Schema document (compact, sorted keys).
+    JsonSchema,
 }
 
 // ---------------------------------------------------------------------------
@@ -43,6 +52,24 @@ pub struct SchemaEntry {
     pub hash: u64,
     /// Timestamp of registration.
     pub registered_at: SystemTime,
+    /// Type description parsed server-side from IDL / ROS `.msg` uploads.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub type_object: Option<TypeObject>,
+    /// XTypes identity of a type registered from DDS discovery.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub xtypes: Option<XTypesIdentity>,
+}
+
+/// XTypes identity of a schema announced by DDS endpoints.
+///
+/// Lets a tool that only knows the type name and equivalence hash of a
+/// discovered endpoint fetch the exact `CompleteTypeObject` it announced.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct XTypesIdentity {
+    /// Hex-encoded 14-byte XTypes equivalence hash.
+    pub equivalence_hash: String,
+    /// Hex-encoded CDR2 (little-endian) `CompleteTypeObject`.
+    pub type_object_cdr2: String,
 }
 
 // ---------------------------------------------------------------------------
@@ -60,6 +87,8 @@ pub enum RegistryError {
     IoError(String),
     /// Schema with the given name was not found.
     NotFound(String),
+    /// Uploaded IDL / ROS `.msg` text could not be parsed.
+    ParseError(String),
 }
 
 impl fmt::Display for RegistryError {
@@ -71,6 +100,7 @@ impl fmt::Display for RegistryError {
             }
             RegistryError::IoError(msg) => write!(f, "I/O error: {}", msg),
             RegistryError::NotFound(name) => write!(f, "schema not found: {}", name),
+            RegistryError::ParseError(msg) => write!(f, "parse error: {}", msg),
         }
     }
 }
@@ -115,6 +145,19 @@ impl SchemaRegistry {
         name: &str,
         content: &str,
         format: SchemaFormat,
+    ) -> Result<u32, RegistryError> {
+        self.register_with_type_object(name, content, format, None)
+    }
+
+    /// Register a new schema version together with its parsed `TypeObject`.
+    ///
+    /// Same semantics as [`register`](Self::register).
+    pub fn register_with_type_object(
+        &mut self,
+        name: &str,
+        content: &str,
+        format: SchemaFormat,
+        type_object: Option<TypeObject>,
     ) -> Result<u32, RegistryError> {
         if content.is_empty() {
             return Err(RegistryError::EmptyContent);
@@ -141,12 +184,61 @@ impl SchemaRegistry {
             content: content.to_string(),
             hash,
             registered_at: SystemTime::now(),
+            type_object,
+            xtypes: None,
         };
 
         versions.push(entry);
         Ok(version)
     }
 
+    /// Register a type announced by DDS endpoints, keyed by name and
+    /// equivalence hash.
+    ///
+    /// Returns the version already holding `xtypes.equivalence_hash` when
+    /// there is one, so every participant announcing the same type maps to
+    /// the same version.  A version with the same `content` but no XTypes
+    /// identity yet (e.g. ingested from IDL) is reused and given this one.
+    pub fn register_xtypes(
+        &mut self,
+        name: &str,
+        content: &str,
+        type_object: Option<TypeObject>,
+        xtypes: XTypesIdentity,
+    ) -> Result<u32, RegistryError> {
+        if let Some(entry) = self.get_by_equivalence_hash(name, &xtypes.equivalence_hash) {
+            return Ok(entry.version);
+        }
+
+        if let Some(entry) = self.schemas.get_mut(name).and_then(|versions| {
+            versions
+                .iter_mut()
+                .find(|e| e.xtypes.is_none() && e.content == content)
+        }) {
+            entry.xtypes = Some(xtypes);
+            return Ok(entry.version);
+        }
+
+        let version =
+            self.register_with_type_object(name, content, SchemaFormat::Idl4, type_object)?;
+        if let Some(entry) = self.schemas.get_mut(name).and_then(|v| v.last_mut()) {
+            entry.xtypes = Some(xtypes);
+        }
+        Ok(version)
+    }
+
+    /// Return the version of `name` registered with the given hex-encoded
+    /// equivalence hash.
+    pub fn get_by_equivalence_hash(&self, name: &str, hash: &str) -> Option<&SchemaEntry> {
+        self.schemas.get(name).and_then(|versions| {
+            versions.iter().find(|e| {
+                e.xtypes
+                    .as_ref()
+                    .is_some_and(|x| x.equivalence_hash.eq_ignore_ascii_case(hash))
+            })
+        })
+    }
+
     /// Return the latest version of a schema, or `None` if not found.
     pub fn get_latest(&self, name: &str) -> Option<&SchemaEntry> {
         self.schemas.get(name).and_then(|v| v.last())
@@ -177,6 +269,24 @@ impl SchemaRegistry {
         }
     }
 
+    /// Highest registered version of `name` that appears in both `offered`
+    /// and `supported`.
+    ///
+    /// This is the version an HDDS reader selects when a writer publishes
+    /// side-by-side type versions (`WriterBuilder::publish_type_version`).
+    /// Versions unknown to the registry are ignored.
+    pub fn highest_common_version(
+        &self,
+        name: &str,
+        offered: &[u32],
+        supported: &[u32],
+    ) -> Option<u32> {
+        self.list_versions(name)
+            .into_iter()
+            .filter(|v| offered.contains(v) && supported.contains(v))
+            .max()
+    }
+
     /// Total number of distinct schema names.
     pub fn schema_count(&self) -> usize {
         self.schemas.len()
@@ -231,6 +341,50 @@ mod tests {
         assert_eq!(v2, 2);
     }
 
+    #[test]
+    fn highest_common_version() {
+        let mut reg = SchemaRegistry::new();
+        for idl in [
+            "struct Pose { long x; };",
+            "struct Pose { long x; long y; };",
+            "struct Pose { long x; long y; long z; };",
+        ] {
+            reg.register("Pose", idl, SchemaFormat::Idl4).unwrap();
+        }
+
+        assert_eq!(reg.highest_common_version("Pose", &[1, 2, 3], &[1, 2]), Some(2));
+        assert_eq!(reg.highest_common_version("Pose", &[2, 3], &[1]), None);
+        // Version 4 is not registered.
+        assert_eq!(reg.highest_common_version("Pose", &[3, 4], &[3, 4]), Some(3));
+        assert_eq!(reg.highest_common_version("Other", &[1], &[1]), None);
+    }
+
+    fn xtypes(hash: &str) -> XTypesIdentity {
+        XTypesIdentity {
+            equivalence_hash: hash.to_string(),
+            type_object_cdr2: "00".to_string(),
+        }
+    }
+
+    #[test]
+    fn register_xtypes_keyed_by_equivalence_hash() {
+        let mut reg = SchemaRegistry::new();
+        let idl = "struct Pose { long x; };";
+        reg.register("Pose", idl, SchemaFormat::Idl4).unwrap();
+
+        // Same content as the ingested v1: the hash is attached to it.
+        assert_eq!(reg.register_xtypes("Pose", idl, None, xtypes("aa01")).unwrap(), 1);
+        // Another participant announcing the same type.
+        assert_eq!(reg.register_xtypes("Pose", idl, None, xtypes("AA01")).unwrap(), 1);
+
+        let v2 = "struct Pose { long x; long y; };";
+        assert_eq!(reg.register_xtypes("Pose", v2, None, xtypes("bb02")).unwrap(), 2);
+        assert_eq!(reg.get_by_equivalence_hash("Pose", "bb02").unwrap().version, 2);
+        assert_eq!(reg.get_latest("Pose").unwrap().format, SchemaFormat::Idl4);
+        assert!(reg.get_by_equivalence_hash("Pose", "cc03").is_none());
+        assert!(reg.get_by_equivalence_hash("Other", "aa01").is_none());
+    }
+
     #[test]
     fn get_latest() {
         let mut reg = SchemaRegistry::new();
@@ -4,7 +4,12 @@
 use std::sync::{Arc, RwLock};
 
 use crate::compatibility::{check_compatibility, CompatibilityResult};
+use crate::compatibility::Compatibility;
+use crate::ingest::{parse_idl, parse_ros_msg};
+use crate::json_schema::parse_json_schema;
+use crate::protobuf::parse_file_descriptor_set;
 use crate::registry::{RegistryError, SchemaEntry, SchemaFormat, SchemaRegistry};
+use crate::type_object::TypeObject;
 
 // ---------------------------------------------------------------------------
 // SchemaRegistryApi
@@ -23,6 +28,16 @@ use crate::registry::{RegistryError, SchemaEntry, SchemaFormat, SchemaRegistry};
 ///   POST /schemas                       -> `register_schema(name, content, format)`
 ///   GET  /schemas/{name}/versions       -> `list_versions(name)`
 ///   GET  /schemas/{name}/compatibility  -> `check_compatibility(name, content)`
+///   GET  /schemas/{name}/type-object    -> `get_type_object(name)`
+///   GET  /schemas/{name}/xtypes/{hash}  -> `get_by_equivalence_hash(name, hash)`
+///   POST /schemas/ingest/idl            -> `ingest_idl(content)`
+///   POST /schemas/ingest/ros-msg/{name} -> `ingest_ros_msg(name, content)`
+///   POST /subjects/{subject}/protobuf   -> `ingest_protobuf(subject, descriptor_set)`
+///   POST /subjects/{subject}/json-schema -> `ingest_json_schema(subject, content)`
+///   POST /compatibility/subjects/{subject}/protobuf
+///                                       -> `check_protobuf_compatibility(subject, descriptor_set)`
+///   POST /compatibility/subjects/{subject}/json-schema
+///                                       -> `check_json_schema_compatibility(subject, content)`
 pub struct SchemaRegistryApi {
     registry: Arc<RwLock<SchemaRegistry>>,
 }
@@ -62,6 +77,131 @@ impl SchemaRegistryApi {
         reg.list_versions(name)
     }
 
+    /// GET /schemas/{name}/type-object -- parsed type of the latest version.
+    ///
+    /// Only schemas registered through the ingestion endpoints carry one.
+    pub fn get_type_object(&self, name: &str) -> Option<TypeObject> {
+        let reg = self.registry.read().expect("registry lock poisoned");
+        reg.get_latest(name).and_then(|e| e.type_object.clone())
+    }
+
+    /// GET /schemas/{name}/xtypes/{hash} -- the version registered from DDS
+    /// discovery with the given equivalence hash.
+    pub fn get_by_equivalence_hash(&self, name: &str, hash: &str) -> Option<SchemaEntry> {
+        let reg = self.registry.read().expect("registry lock poisoned");
+        reg.get_by_equivalence_hash(name, hash).cloned()
+    }
+
+    /// POST /schemas/ingest/idl -- parse raw IDL and register every type.
+    ///
+    /// Each struct/enum/typedef is registered under its fully-qualified name
+    /// with its canonical IDL rendering as content, so unchanged types in a
+    /// re-uploaded file keep their current version.  Returns the
+    /// `(name, version)` of every type in declaration order.  Nothing is
+    /// registered if the text does not parse.
+    pub fn ingest_idl(&self, content: &str) -> Result<Vec<(String, u32)>, RegistryError> {
+        let types = parse_idl(content)?;
+        let mut reg = self.registry.write().expect("registry lock poisoned");
+        types
+            .into_iter()
+            .map(|obj| {
+                let name = obj.name.clone();
+                let canonical = obj.to_idl();
+                let version = register_or_existing(
+                    &mut reg,
+                    &name,
+                    &canonical,
+                    SchemaFormat::Idl4,
+                    Some(obj),
+                )?;
+                Ok((name, version))
+            })
+            .collect()
+    }
+
+    /// POST /schemas/ingest/ros-msg/{name} -- parse a ROS `.msg` and register it.
+    ///
+    /// `ros_name` is the ROS type name (`pkg/msg/Name`); the schema is stored
+    /// as `pkg::msg::Name` with the raw message text as content.
+    pub fn ingest_ros_msg(
+        &self,
+        ros_name: &str,
+        content: &str,
+    ) -> Result<(String, u32), RegistryError> {
+        let obj = parse_ros_msg(ros_name, content)?;
+        let name = obj.name.clone();
+        let mut reg = self.registry.write().expect("registry lock poisoned");
+        let version = register_or_existing(&mut reg, &name, content, SchemaFormat::RosMsg, Some(obj))?;
+        Ok((name, version))
+    }
+
+    /// POST /subjects/{subject}/protobuf -- register a binary
+    /// `FileDescriptorSet` as the next version of `subject`.
+    ///
+    /// Every message and enum of the set is part of the version, stored as
+    /// canonical JSON; re-uploading an equivalent set returns the current
+    /// version.
+    pub fn ingest_protobuf(&self, subject: &str, descriptor_set: &[u8]) -> Result<u32, RegistryError> {
+        let content = parse_file_descriptor_set(descriptor_set)?.to_canonical_json();
+        let mut reg = self.registry.write().expect("registry lock poisoned");
+        register_or_existing(&mut reg, subject, &content, SchemaFormat::Protobuf, None)
+    }
+
+    /// POST /subjects/{subject}/json-schema -- register a JSON Schema
+    /// document as the next version of `subject`.
+    pub fn ingest_json_schema(&self, subject: &str, content: &str) -> Result<u32, RegistryError> {
+        let content = parse_json_schema(content)?;
+        let mut reg = self.registry.write().expect("registry lock poisoned");
+        register_or_existing(&mut reg, subject, &content, SchemaFormat::JsonSchema, None)
+    }
+
+    /// POST /compatibility/subjects/{subject}/protobuf -- check a
+    /// `FileDescriptorSet` against the latest version of `subject`.
+    pub fn check_protobuf_compatibility(
+        &self,
+        subject: &str,
+        descriptor_set: &[u8],
+    ) -> Result<CompatibilityResult, RegistryError> {
+        let content = parse_file_descriptor_set(descriptor_set)?.to_canonical_json();
+        Ok(self.check_against_latest(subject, &content, SchemaFormat::Protobuf))
+    }
+
+    /// POST /compatibility/subjects/{subject}/json-schema -- check a JSON
+    /// Schema document against the latest version of `subject`.
+    pub fn check_json_schema_compatibility(
+        &self,
+        subject: &str,
+        content: &str,
+    ) -> Result<CompatibilityResult, RegistryError> {
+        let content = parse_json_schema(content)?;
+        Ok(self.check_against_latest(subject, &content, SchemaFormat::JsonSchema))
+    }
+
+    /// Compatibility of canonical `content` in `format` with the latest
+    /// version of `subject`; switching formats is always breaking.
+    fn check_against_latest(
+        &self,
+        subject: &str,
+        content: &str,
+        format: SchemaFormat,
+    ) -> CompatibilityResult {
+        let reg = self.registry.read().expect("registry lock poisoned");
+        match reg.get_latest(subject) {
+            Some(latest) if latest.format != format => CompatibilityResult {
+                compatibility: Compatibility::Breaking,
+                details: vec![format!(
+                    "format changed: {:?} -> {:?}",
+                    latest.format, format
+                )],
+            },
+            Some(latest) => check_compatibility(&latest.content, content, format),
+            None => CompatibilityResult {
+                compatibility: Compatibility::Full,
+                details: vec!["no previous version exists; trivially compatible".to_string()],
+            },
+        }
+    }
+
     /// GET /schemas/{name}/compatibility -- check compatibility of new
     /// content against the latest registered version.
     pub fn check_compatibility(
@@ -82,6 +222,24 @@ impl SchemaRegistryApi {
     }
 }
 
+/// Register `content`, or return the version that already holds it.
+fn register_or_existing(
+    reg: &mut SchemaRegistry,
+    name: &str,
+    content: &str,
+    format: SchemaFormat,
+    type_object: Option<TypeObject>,
+) -> Result<u32, RegistryError> {
+    match reg.register_with_type_object(name, content, format, type_object) {
+        Err(RegistryError::DuplicateContent) => reg
+            .list_versions(name)
+            .into_iter()
+            .find(|&v| reg.get_version(name, v).is_some_and(|e| e.content == content))
+            .ok_or(RegistryError::DuplicateContent),
+        other => other,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -152,4 +310,117 @@ mod tests {
             crate::compatibility::Compatibility::Full
         );
     }
+
+    #[test]
+    fn api_ingest_idl_registers_each_type() {
+        let api = make_api();
+        let idl = "module geo { struct Point { double x; double y; }; \
+                   struct Path { sequence<geo::Point> points; }; };";
+
+        let registered = api.ingest_idl(idl).unwrap();
+        assert_eq!(
+            registered,
+            vec![("geo::Point".to_string(), 1), ("geo::Path".to_string(), 1)]
+        );
+        assert_eq!(api.list_schemas(), vec!["geo::Path", "geo::Point"]);
+
+        let point = api.get_type_object("geo::Point").unwrap();
+        assert_eq!(point.short_name(), "Point");
+        assert!(api.get_schema("geo::Point").unwrap().content.contains("double x;"));
+
+        // Re-upload with one struct changed: only that one gets a new version.
+        let idl_v2 = "module geo { struct Point { double x; double y; double z; }; \
+                      /* unchanged */ struct Path { sequence<geo::Point> points; }; };";
+        let registered = api.ingest_idl(idl_v2).unwrap();
+        assert_eq!(
+            registered,
+            vec![("geo::Point".to_string(), 2), ("geo::Path".to_string(), 1)]
+        );
+        assert_eq!(
+            api.check_compatibility("geo::Point", "struct Point { double x; };")
+                .compatibility,
+            crate::compatibility::Compatibility::Forward
+        );
+    }
+
+    #[test]
+    fn api_ingest_ros_msg() {
+        let api = make_api();
+        let (name, version) = api
+            .ingest_ros_msg("geometry_msgs/msg/Vector3", "float64 x\nfloat64 y\nfloat64 z\n")
+            .unwrap();
+        assert_eq!(name, "geometry_msgs::msg::Vector3");
+        assert_eq!(version, 1);
+
+        let entry = api.get_schema(&name).unwrap();
+        assert_eq!(entry.format, SchemaFormat::RosMsg);
+        assert!(entry.type_object.is_some());
+
+        // Identical re-upload resolves to the existing version.
+        let (_, again) = api
+            .ingest_ros_msg("geometry_msgs/msg/Vector3", "float64 x\nfloat64 y\nfloat64 z\n")
+            .unwrap();
+        assert_eq!(again, 1);
+    }
+
+    #[test]
+    fn api_ingest_rejects_invalid_text() {
+        let api = make_api();
+        assert!(matches!(
+            api.ingest_idl("struct Broken { long x "),
+            Err(RegistryError::ParseError(_))
+        ));
+        assert!(matches!(
+            api.ingest_ros_msg("pkg/msg/Bad", "float64[x] values\n"),
+            Err(RegistryError::ParseError(_))
+        ));
+        assert!(api.list_schemas().is_empty());
+    }
+
+    #[test]
+    fn protobuf_subjects_are_versioned() {
+        use crate::protobuf::tests::{descriptor_set, READING_V1};
+
+        let api = make_api();
+        let v1 = descriptor_set(READING_V1);
+        assert_eq!(api.ingest_protobuf("telemetry-value", &v1).unwrap(), 1);
+        // Equivalent upload keeps the current version.
+        assert_eq!(api.ingest_protobuf("telemetry-value", &v1).unwrap(), 1);
+
+        let mut fields = READING_V1.to_vec();
+        fields.push(("unit", 4, 2, 9, ""));
+        let v2 = descriptor_set(&fields);
+        let result = api
+            .check_protobuf_compatibility("telemetry-value", &v2)
+            .unwrap();
+        assert_eq!(result.compatibility, Compatibility::Forward);
+
+        assert_eq!(api.ingest_protobuf("telemetry-value", &v2).unwrap(), 2);
+        assert_eq!(
+            api.get_schema("telemetry-value").unwrap().format,
+            SchemaFormat::Protobuf
+        );
+        assert!(matches!(
+            api.ingest_protobuf("telemetry-value", b"\x0a\x05"),
+            Err(RegistryError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn json_schema_subjects_and_format_changes() {
+        let api = make_api();
+        let v1 = r#"{"type": "object", "properties": {"id": {"type": "integer"}}}"#;
+        let v2 = r#"{"type": "object", "properties": {"id": {"type": "integer"},
+                     "name": {"type": "string"}}}"#;
+
+        assert_eq!(api.ingest_json_schema("sensor", v1).unwrap(), 1);
+        let result = api.check_json_schema_compatibility("sensor", v2).unwrap();
+        assert_eq!(result.compatibility, Compatibility::Full);
+        assert_eq!(api.ingest_json_schema("sensor", v2).unwrap(), 2);
+
+        let proto = crate::protobuf::tests::descriptor_set(crate::protobuf::tests::READING_V1);
+        let switched = api.check_protobuf_compatibility("sensor", &proto).unwrap();
+        assert_eq!(switched.compatibility, Compatibility::Breaking);
+        assert!(switched.details[0].contains("format changed"));
+    }
 }
@@ -0,0 +1,281 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+use std::fmt::Write as _;
+
+use serde::{Deserialize, Serialize};
+
+// ---------------------------------------------------------------------------
+// PrimitiveKind
+// ---------------------------------------------------------------------------
+
+/// XTypes primitive type kinds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PrimitiveKind {
+    Bool,
+    Octet,
+    Char,
+    WChar,
+    Int8,
+    UInt8,
+    Int16,
+    UInt16,
+    Int32,
+    UInt32,
+    Int64,
+    UInt64,
+    Float32,
+    Float64,
+    Float128,
+}
+
+impl PrimitiveKind {
+    /// Canonical IDL4 spelling of the primitive.
+    pub fn idl_name(self) -> &'static str {
+        match self {
+            PrimitiveKind::Bool => "boolean",
+            PrimitiveKind::Octet => "octet",
+            PrimitiveKind::Char => "char",
+            PrimitiveKind::WChar => "wchar",
+            PrimitiveKind::Int8 => "int8",
+            PrimitiveKind::UInt8 => "uint8",
+            PrimitiveKind::Int16 => "int16",
+            PrimitiveKind::UInt16 => "uint16",
+            PrimitiveKind::Int32 => "int32",
+            PrimitiveKind::UInt32 => "uint32",
+            PrimitiveKind::Int64 => "int64",
+            PrimitiveKind::UInt64 => "uint64",
+            PrimitiveKind::Float32 => "float",
+            PrimitiveKind::Float64 => "double",
+            PrimitiveKind::Float128 => "long double",
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// TypeSpec
+// ---------------------------------------------------------------------------
+
+/// Type reference used by members and aliases.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TypeSpec {
+    /// Primitive type.
+    Primitive(PrimitiveKind),
+    /// `string` / `wstring`, optionally bounded.
+    String { wide: bool, bound: Option<u32> },
+    /// `sequence<T>` / `sequence<T, N>`.
+    Sequence {
+        element: Box<TypeSpec>,
+        bound: Option<u32>,
+    },
+    /// Fixed-size (possibly multi-dimensional) array.
+    Array { element: Box<TypeSpec>, dims: Vec<u32> },
+    /// Reference to another named type (fully-qualified, `::`-separated).
+    Named(String),
+}
+
+impl TypeSpec {
+    /// Canonical IDL4 spelling of the type, without array dimensions.
+    pub fn to_idl(&self) -> String {
+        match self {
+            TypeSpec::Primitive(kind) => kind.idl_name().to_string(),
+            TypeSpec::String { wide, bound } => {
+                let base = if *wide { "wstring" } else { "string" };
+                match bound {
+                    Some(n) => format!("{}<{}>", base, n),
+                    None => base.to_string(),
+                }
+            }
+            TypeSpec::Sequence { element, bound } => match bound {
+                Some(n) => format!("sequence<{},{}>", element.to_idl(), n),
+                None => format!("sequence<{}>", element.to_idl()),
+            },
+            TypeSpec::Array { element, .. } => element.to_idl(),
+            TypeSpec::Named(name) => name.clone(),
+        }
+    }
+
+    fn dims_suffix(&self) -> String {
+        match self {
+            TypeSpec::Array { dims, .. } => dims.iter().map(|d| format!("[{}]", d)).collect(),
+            _ => String::new(),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// TypeObject
+// ---------------------------------------------------------------------------
+
+/// One member of a structure.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemberDescriptor {
+    /// Member id (declaration order, starting at 0).
+    pub id: u32,
+    /// Member name.
+    pub name: String,
+    /// Member type.
+    pub type_spec: TypeSpec,
+    /// `@key` annotation.
+    pub key: bool,
+    /// `@optional` annotation.
+    pub optional: bool,
+}
+
+/// Kind-specific part of a `TypeObject`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TypeObjectKind {
+    /// `struct` with optional base type.
+    Structure {
+        base: Option<String>,
+        members: Vec<MemberDescriptor>,
+    },
+    /// `enum` with its literals in declaration order.
+    Enumeration { literals: Vec<String> },
+    /// `typedef`.
+    Alias { target: TypeSpec },
+}
+
+/// Server-side type description produced from uploaded IDL or ROS `.msg`
+/// text.
+///
+/// This is a simplified, JSON-friendly view of an XTypes complete
+/// TypeObject: enough for tooling to introspect a schema without the
+/// uploader having to generate TypeObjects locally.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TypeObject {
+    /// Fully-qualified type name (e.g. "sensor_msgs::msg::Imu").
+    pub name: String,
+    /// Type definition.
+    pub kind: TypeObjectKind,
+}
+
+impl TypeObject {
+    /// Unqualified type name (last `::` segment).
+    pub fn short_name(&self) -> &str {
+        self.name.rsplit("::").next().unwrap_or(&self.name)
+    }
+
+    /// Render the type as canonical IDL4 text.
+    ///
+    /// The output is what the registry stores as schema content for
+    /// ingested types, so re-uploading an equivalent definition (different
+    /// whitespace, comments, ROS vs IDL spelling) yields identical content.
+    pub fn to_idl(&self) -> String {
+        let mut out = format!("// {}\n", self.name);
+        match &self.kind {
+            TypeObjectKind::Structure { base, members } => {
+                match base {
+                    Some(base) => {
+                        let _ = writeln!(out, "struct {} : {} {{", self.short_name(), base);
+                    }
+                    None => {
+                        let _ = writeln!(out, "struct {} {{", self.short_name());
+                    }
+                }
+                for member in members {
+                    out.push_str("    ");
+                    if member.key {
+                        out.push_str("@key ");
+                    }
+                    if member.optional {
+                        out.push_str("@optional ");
+                    }
+                    let _ = writeln!(
+                        out,
+                        "{} {}{};",
+                        member.type_spec.to_idl(),
+                        member.name,
+                        member.type_spec.dims_suffix()
+                    );
+                }
+                out.push_str("};\n");
+            }
+            TypeObjectKind::Enumeration { literals } => {
+                let _ = writeln!(out, "enum {} {{", self.short_name());
+                let _ = writeln!(out, "    {}", literals.join(",\n    "));
+                out.push_str("};\n");
+            }
+            TypeObjectKind::Alias { target } => {
+                let _ = writeln!(
+                    out,
+                    "typedef {} {}{};",
+                    target.to_idl(),
+                    self.short_name(),
+                    target.dims_suffix()
+                );
+            }
+        }
+        out
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(id: u32, name: &str, type_spec: TypeSpec) -> MemberDescriptor {
+        MemberDescriptor {
+            id,
+            name: name.to_string(),
+            type_spec,
+            key: false,
+            optional: false,
+        }
+    }
+
+    #[test]
+    fn struct_renders_canonical_idl() {
+        let mut id = member(0, "id", TypeSpec::Primitive(PrimitiveKind::Int32));
+        id.key = true;
+        let obj = TypeObject {
+            name: "sensors::Reading".to_string(),
+            kind: TypeObjectKind::Structure {
+                base: None,
+                members: vec![
+                    id,
+                    member(
+                        1,
+                        "samples",
+                        TypeSpec::Sequence {
+                            element: Box::new(TypeSpec::Primitive(PrimitiveKind::Float64)),
+                            bound: Some(16),
+                        },
+                    ),
+                    member(
+                        2,
+                        "matrix",
+                        TypeSpec::Array {
+                            element: Box::new(TypeSpec::Primitive(PrimitiveKind::Float32)),
+                            dims: vec![3, 3],
+                        },
+                    ),
+                ],
+            },
+        };
+
+        assert_eq!(obj.short_name(), "Reading");
+        assert_eq!(
+            obj.to_idl(),
+            "// sensors::Reading\nstruct Reading {\n    @key int32 id;\n    \
+             sequence<double,16> samples;\n    float matrix[3][3];\n};\n"
+        );
+    }
+
+    #[test]
+    fn type_object_json_roundtrip() {
+        let obj = TypeObject {
+            name: "Color".to_string(),
+            kind: TypeObjectKind::Enumeration {
+                literals: vec!["RED".to_string(), "GREEN".to_string()],
+            },
+        };
+        let json = serde_json::to_string(&obj).unwrap();
+        let back: TypeObject = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, obj);
+    }
+}
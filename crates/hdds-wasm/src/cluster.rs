@@ -0,0 +1,513 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+// HDDS WASM SDK - Relay cluster state (server-side, native only)
+//
+// Several relay instances can run behind a load balancer. Each one keeps its
+// browser clients in its own RelayHandler; RelayCluster shares which topics
+// have subscribers on which relay over a shared state channel (a DDS topic
+// or a Redis pub/sub channel), and forwards client publications only to the
+// relays that have subscribers for them.
+//
+// Topic IDs stay local to a relay; relays talk about topics by name.
+// Interest changes are announced as they happen and the full set is repeated
+// in heartbeats, so a relay that missed a message or joined late converges.
+// Relays that stop sending heartbeats are forgotten after a timeout.
+
+use crate::error::WasmError;
+use crate::protocol::{MessageHeader, HEADER_SIZE};
+use crate::relay::RelayHandler;
+use std::collections::{HashMap, HashSet};
+use std::sync::{mpsc, Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// --- Cluster message type constants ---
+
+pub const MSG_CLUSTER_INTEREST: u8 = 0x21;
+pub const MSG_CLUSTER_DATA: u8 = 0x22;
+pub const MSG_CLUSTER_HEARTBEAT: u8 = 0x23;
+pub const MSG_CLUSTER_LEAVE: u8 = 0x24;
+
+/// Suggested name of the shared state channel (DDS topic or Redis channel).
+pub const CLUSTER_CHANNEL_NAME: &str = "hdds_wasm_relay_cluster";
+
+/// Default interval between heartbeats.
+pub const DEFAULT_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Default time after which a silent relay is forgotten.
+pub const DEFAULT_PEER_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Message exchanged between relays on the shared state channel.
+///
+/// Encoded with the client protocol header (`sequence_nr` counts messages
+/// per relay), followed by the sending relay ID (u32 LE).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClusterMessage {
+    /// A relay gained its first or lost its last subscriber for a topic.
+    Interest {
+        relay_id: u32,
+        topic_name: String,
+        subscribed: bool,
+    },
+    /// A client publication forwarded to relays with subscribers.
+    Data {
+        relay_id: u32,
+        topic_name: String,
+        payload: Vec<u8>,
+    },
+    /// Liveness and the full set of topics with subscribers on a relay.
+    Heartbeat { relay_id: u32, topics: Vec<String> },
+    /// A relay is shutting down.
+    Leave { relay_id: u32 },
+}
+
+impl ClusterMessage {
+    /// ID of the relay that sent the message.
+    pub fn relay_id(&self) -> u32 {
+        match self {
+            ClusterMessage::Interest { relay_id, .. }
+            | ClusterMessage::Data { relay_id, .. }
+            | ClusterMessage::Heartbeat { relay_id, .. }
+            | ClusterMessage::Leave { relay_id } => *relay_id,
+        }
+    }
+
+    /// Encode the message.
+    pub fn encode(&self, sequence_nr: u32) -> Vec<u8> {
+        let (message_type, flags) = match self {
+            ClusterMessage::Interest { subscribed, .. } => {
+                (MSG_CLUSTER_INTEREST, u8::from(*subscribed))
+            }
+            ClusterMessage::Data { .. } => (MSG_CLUSTER_DATA, 0),
+            ClusterMessage::Heartbeat { .. } => (MSG_CLUSTER_HEARTBEAT, 0),
+            ClusterMessage::Leave { .. } => (MSG_CLUSTER_LEAVE, 0),
+        };
+        let header = MessageHeader::new(message_type, flags, 0, sequence_nr);
+        let mut msg = Vec::with_capacity(HEADER_SIZE + 4);
+        msg.extend_from_slice(&header.encode());
+        msg.extend_from_slice(&self.relay_id().to_le_bytes());
+
+        match self {
+            ClusterMessage::Interest { topic_name, .. } => put_string(&mut msg, topic_name),
+            ClusterMessage::Data {
+                topic_name,
+                payload,
+                ..
+            } => {
+                put_string(&mut msg, topic_name);
+                msg.extend_from_slice(payload);
+            }
+            ClusterMessage::Heartbeat { topics, .. } => {
+                msg.extend_from_slice(&(topics.len() as u16).to_le_bytes());
+                for topic_name in topics {
+                    put_string(&mut msg, topic_name);
+                }
+            }
+            ClusterMessage::Leave { .. } => {}
+        }
+        msg
+    }
+
+    /// Decode a message.
+    pub fn decode(data: &[u8]) -> Result<Self, WasmError> {
+        let header = MessageHeader::decode(data)?;
+        let mut reader = Reader {
+            data,
+            offset: HEADER_SIZE,
+        };
+        let relay_id = u32::from_le_bytes(reader.take_array()?);
+
+        match header.message_type {
+            MSG_CLUSTER_INTEREST => Ok(ClusterMessage::Interest {
+                relay_id,
+                topic_name: reader.take_string()?,
+                subscribed: header.flags != 0,
+            }),
+            MSG_CLUSTER_DATA => Ok(ClusterMessage::Data {
+                relay_id,
+                topic_name: reader.take_string()?,
+                payload: data[reader.offset..].to_vec(),
+            }),
+            MSG_CLUSTER_HEARTBEAT => {
+                let count = u16::from_le_bytes(reader.take_array()?);
+                let topics = (0..count)
+                    .map(|_| reader.take_string())
+                    .collect::<Result<_, _>>()?;
+                Ok(ClusterMessage::Heartbeat { relay_id, topics })
+            }
+            MSG_CLUSTER_LEAVE => Ok(ClusterMessage::Leave { relay_id }),
+            unknown => Err(WasmError::UnknownMessageType(unknown)),
+        }
+    }
+}
+
+/// Append a string as name_len (u16 LE) + bytes.
+fn put_string(msg: &mut Vec<u8>, value: &str) {
+    msg.extend_from_slice(&(value.len() as u16).to_le_bytes());
+    msg.extend_from_slice(value.as_bytes());
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl Reader<'_> {
+    fn take(&mut self, len: usize) -> Result<&[u8], WasmError> {
+        let end = self.offset + len;
+        if self.data.len() < end {
+            return Err(WasmError::MessageTooShort {
+                expected: end,
+                actual: self.data.len(),
+            });
+        }
+        let bytes = &self.data[self.offset..end];
+        self.offset = end;
+        Ok(bytes)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], WasmError> {
+        let mut out = [0u8; N];
+        out.copy_from_slice(self.take(N)?);
+        Ok(out)
+    }
+
+    fn take_string(&mut self) -> Result<String, WasmError> {
+        let len = u16::from_le_bytes(self.take_array()?) as usize;
+        Ok(String::from_utf8_lossy(self.take(len)?).to_string())
+    }
+}
+
+/// Shared state channel between relay instances.
+///
+/// Every published message must reach every relay of the cluster (the
+/// sender may receive its own messages, they are ignored). Implement it over
+/// a DDS topic carrying opaque byte sequences, or a Redis pub/sub channel,
+/// typically named [`CLUSTER_CHANNEL_NAME`]. Delivery may be best-effort:
+/// heartbeats repair lost interest announcements.
+pub trait ClusterChannel {
+    /// Publish a message to all relays.
+    fn publish(&self, message: &[u8]) -> Result<(), WasmError>;
+
+    /// Messages received since the last poll.
+    fn poll(&self) -> Vec<Vec<u8>>;
+}
+
+/// In-process [`ClusterChannel`] bus, for relays sharing one process and tests.
+#[derive(Clone, Default)]
+pub struct MemoryBus {
+    subscribers: Arc<Mutex<Vec<mpsc::Sender<Vec<u8>>>>>,
+}
+
+impl MemoryBus {
+    /// Create an empty bus.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attach a new channel to the bus.
+    pub fn channel(&self) -> MemoryChannel {
+        let (sender, inbox) = mpsc::channel();
+        self.subscribers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(sender);
+        MemoryChannel {
+            bus: self.clone(),
+            inbox,
+        }
+    }
+}
+
+/// One relay's end of a [`MemoryBus`].
+pub struct MemoryChannel {
+    bus: MemoryBus,
+    inbox: mpsc::Receiver<Vec<u8>>,
+}
+
+impl ClusterChannel for MemoryChannel {
+    fn publish(&self, message: &[u8]) -> Result<(), WasmError> {
+        // Dropped channels are pruned
+        self.bus
+            .subscribers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|sender| sender.send(message.to_vec()).is_ok());
+        Ok(())
+    }
+
+    fn poll(&self) -> Vec<Vec<u8>> {
+        self.inbox.try_iter().collect()
+    }
+}
+
+/// Another relay of the cluster, as seen from this one.
+#[derive(Debug)]
+struct PeerRelay {
+    /// Topics with subscribers on that relay.
+    topics: HashSet<String>,
+    last_seen: Instant,
+}
+
+/// Cluster membership and subscription routing for one relay instance.
+///
+/// Like [`RelayHandler`], this does no I/O: the relay server feeds it the
+/// messages of its [`ClusterChannel`] and publishes what it returns, or
+/// calls [`RelayCluster::pump`] to do both.
+pub struct RelayCluster {
+    /// ID of this relay, unique in the cluster.
+    relay_id: u32,
+    sequence_nr: u32,
+    /// Topics with local subscribers, as last announced.
+    announced: HashSet<String>,
+    /// Other relays: relay_id -> state.
+    peers: HashMap<u32, PeerRelay>,
+    heartbeat_interval: Duration,
+    peer_timeout: Duration,
+    last_heartbeat: Option<Instant>,
+}
+
+impl RelayCluster {
+    /// Create the cluster state of relay `relay_id`.
+    pub fn new(relay_id: u32) -> Self {
+        Self {
+            relay_id,
+            sequence_nr: 0,
+            announced: HashSet::new(),
+            peers: HashMap::new(),
+            heartbeat_interval: DEFAULT_HEARTBEAT_INTERVAL,
+            peer_timeout: DEFAULT_PEER_TIMEOUT,
+            last_heartbeat: None,
+        }
+    }
+
+    /// Set the heartbeat interval and the timeout after which silent relays
+    /// are forgotten (a few heartbeat intervals).
+    pub fn with_timing(mut self, heartbeat_interval: Duration, peer_timeout: Duration) -> Self {
+        self.heartbeat_interval = heartbeat_interval;
+        self.peer_timeout = peer_timeout;
+        self
+    }
+
+    /// ID of this relay.
+    pub fn relay_id(&self) -> u32 {
+        self.relay_id
+    }
+
+    fn encode(&mut self, message: &ClusterMessage) -> Vec<u8> {
+        let msg = message.encode(self.sequence_nr);
+        self.sequence_nr = self.sequence_nr.wrapping_add(1);
+        msg
+    }
+
+    /// Announce changes of the local subscriptions.
+    ///
+    /// `topics` are the topic names with local subscribers
+    /// ([`RelayHandler::subscribed_topic_names`]). Returns the messages to
+    /// publish, one per topic gained or lost since the last call.
+    pub fn sync_subscriptions(&mut self, topics: HashSet<String>) -> Vec<Vec<u8>> {
+        let mut changes: Vec<(String, bool)> = topics
+            .difference(&self.announced)
+            .map(|name| (name.clone(), true))
+            .chain(
+                self.announced
+                    .difference(&topics)
+                    .map(|name| (name.clone(), false)),
+            )
+            .collect();
+        changes.sort();
+        self.announced = topics;
+
+        changes
+            .into_iter()
+            .map(|(topic_name, subscribed)| {
+                self.encode(&ClusterMessage::Interest {
+                    relay_id: self.relay_id,
+                    topic_name,
+                    subscribed,
+                })
+            })
+            .collect()
+    }
+
+    /// Forward a client publication to the relays with subscribers.
+    ///
+    /// Returns `None` when no other relay is interested in the topic.
+    pub fn forward_publication(&mut self, topic_name: &str, payload: &[u8]) -> Option<Vec<u8>> {
+        if self.interested_relays(topic_name).is_empty() {
+            return None;
+        }
+        Some(self.encode(&ClusterMessage::Data {
+            relay_id: self.relay_id,
+            topic_name: topic_name.to_string(),
+            payload: payload.to_vec(),
+        }))
+    }
+
+    /// Process a message from the channel.
+    ///
+    /// Returns the topic name and payload of forwarded data that local
+    /// clients are subscribed to, for [`RelayHandler::route_dds_data`].
+    /// Messages sent by this relay are ignored.
+    pub fn handle_message(
+        &mut self,
+        data: &[u8],
+        now: Instant,
+    ) -> Result<Option<(String, Vec<u8>)>, WasmError> {
+        let message = ClusterMessage::decode(data)?;
+        let relay_id = message.relay_id();
+        if relay_id == self.relay_id {
+            return Ok(None);
+        }
+        if let ClusterMessage::Leave { .. } = message {
+            self.peers.remove(&relay_id);
+            return Ok(None);
+        }
+
+        let peer = self.peers.entry(relay_id).or_insert_with(|| PeerRelay {
+            topics: HashSet::new(),
+            last_seen: now,
+        });
+        peer.last_seen = now;
+
+        match message {
+            ClusterMessage::Interest {
+                topic_name,
+                subscribed,
+                ..
+            } => {
+                if subscribed {
+                    peer.topics.insert(topic_name);
+                } else {
+                    peer.topics.remove(&topic_name);
+                }
+                Ok(None)
+            }
+            ClusterMessage::Heartbeat { topics, .. } => {
+                peer.topics = topics.into_iter().collect();
+                Ok(None)
+            }
+            ClusterMessage::Data {
+                topic_name,
+                payload,
+                ..
+            } => Ok(self
+                .announced
+                .contains(&topic_name)
+                .then_some((topic_name, payload))),
+            ClusterMessage::Leave { .. } => Ok(None),
+        }
+    }
+
+    /// Heartbeat with the full set of topics with local subscribers.
+    pub fn heartbeat(&mut self, now: Instant) -> Vec<u8> {
+        self.last_heartbeat = Some(now);
+        let mut topics: Vec<String> = self.announced.iter().cloned().collect();
+        topics.sort();
+        self.encode(&ClusterMessage::Heartbeat {
+            relay_id: self.relay_id,
+            topics,
+        })
+    }
+
+    /// Whether the heartbeat interval has elapsed.
+    pub fn heartbeat_due(&self, now: Instant) -> bool {
+        self.last_heartbeat
+            .is_none_or(|last| now.saturating_duration_since(last) >= self.heartbeat_interval)
+    }
+
+    /// Forget relays silent for longer than the peer timeout.
+    /// Returns their IDs.
+    pub fn expire_peers(&mut self, now: Instant) -> Vec<u32> {
+        let timeout = self.peer_timeout;
+        let mut expired: Vec<u32> = self
+            .peers
+            .iter()
+            .filter(|(_, peer)| now.saturating_duration_since(peer.last_seen) > timeout)
+            .map(|(id, _)| *id)
+            .collect();
+        expired.sort_unstable();
+        for id in &expired {
+            self.peers.remove(id);
+        }
+        expired
+    }
+
+    /// Message announcing that this relay leaves the cluster.
+    pub fn leave(&mut self) -> Vec<u8> {
+        self.encode(&ClusterMessage::Leave {
+            relay_id: self.relay_id,
+        })
+    }
+
+    /// Other relays with subscribers for a topic, sorted.
+    pub fn interested_relays(&self, topic_name: &str) -> Vec<u32> {
+        let mut relays: Vec<u32> = self
+            .peers
+            .iter()
+            .filter(|(_, peer)| peer.topics.contains(topic_name))
+            .map(|(id, _)| *id)
+            .collect();
+        relays.sort_unstable();
+        relays
+    }
+
+    /// Number of other relays known to be alive.
+    pub fn peer_count(&self) -> usize {
+        self.peers.len()
+    }
+
+    /// Forward a client publication on a local topic ID over the channel.
+    ///
+    /// Returns whether another relay was interested.
+    pub fn publish(
+        &mut self,
+        handler: &RelayHandler,
+        channel: &dyn ClusterChannel,
+        topic_id: u16,
+        payload: &[u8],
+    ) -> Result<bool, WasmError> {
+        let topic_name = handler
+            .get_topic_name(topic_id)
+            .ok_or(WasmError::UnknownTopic(topic_id))?;
+        match self.forward_publication(topic_name, payload) {
+            Some(msg) => channel.publish(&msg).map(|()| true),
+            None => Ok(false),
+        }
+    }
+
+    /// Run one round of cluster I/O for a relay.
+    ///
+    /// Announces subscription changes of `handler`, sends a heartbeat when
+    /// due, expires silent relays and processes the channel's messages.
+    /// Returns `(client_id, data_message)` pairs for forwarded data, like
+    /// [`RelayHandler::route_dds_data`].
+    pub fn pump(
+        &mut self,
+        handler: &RelayHandler,
+        channel: &dyn ClusterChannel,
+        now: Instant,
+    ) -> Result<Vec<(u32, Vec<u8>)>, WasmError> {
+        for msg in self.sync_subscriptions(handler.subscribed_topic_names()) {
+            channel.publish(&msg)?;
+        }
+        if self.heartbeat_due(now) {
+            let msg = self.heartbeat(now);
+            channel.publish(&msg)?;
+        }
+        self.expire_peers(now);
+
+        let mut deliveries = Vec::new();
+        for data in channel.poll() {
+            match self.handle_message(&data, now) {
+                Ok(Some((topic_name, payload))) => {
+                    deliveries.extend(handler.route_dds_data(&topic_name, &payload));
+                }
+                Ok(None) => {}
+                // One malformed message must not stall the channel
+                Err(err) => log::warn!("[relay {}] bad cluster message: {}", self.relay_id, err),
+            }
+        }
+        Ok(deliveries)
+    }
+}
@@ -22,6 +22,8 @@
 //! - Client connection management via WebSocket
 //! - Topic registration and routing
 //! - Bridging to native DDS participants
+//! - Clustering: several relays behind a load balancer share subscription
+//!   state over a DDS topic or Redis channel (`cluster` module)
 //!
 //! # Design Decisions
 //!
@@ -41,6 +43,9 @@ pub mod writer;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod relay;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub mod cluster;
+
 // Re-export main types for convenience
 pub use cdr::{CdrDecoder, CdrEncoder};
 pub use error::WasmError;
@@ -53,5 +58,8 @@ pub use writer::WasmWriter;
 #[cfg(not(target_arch = "wasm32"))]
 pub use relay::{RelayClient, RelayHandler, TopicInfo};
 
+#[cfg(not(target_arch = "wasm32"))]
+pub use cluster::{ClusterChannel, ClusterMessage, MemoryBus, MemoryChannel, RelayCluster};
+
 #[cfg(test)]
 mod tests;
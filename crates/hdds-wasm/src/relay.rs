@@ -235,6 +235,24 @@ impl RelayHandler {
     pub fn get_topic_id(&self, topic_name: &str) -> Option<u16> {
         self.global_topics.get(topic_name).copied()
     }
+
+    /// Get the topic name for a global topic ID, if it exists.
+    pub fn get_topic_name(&self, topic_id: u16) -> Option<&str> {
+        self.topic_names.get(&topic_id).map(String::as_str)
+    }
+
+    /// Names of the topics at least one connected client is subscribed to.
+    ///
+    /// This is the interest a clustered relay shares with its peers
+    /// (see [`crate::cluster::RelayCluster`]).
+    pub fn subscribed_topic_names(&self) -> HashSet<String> {
+        self.clients
+            .values()
+            .flat_map(|client| client.subscriptions.iter())
+            .filter_map(|topic_id| self.topic_names.get(topic_id))
+            .cloned()
+            .collect()
+    }
 }
 
 impl Default for RelayHandler {
@@ -4,11 +4,14 @@
 // HDDS WASM SDK - Comprehensive test suite
 
 use crate::cdr::{CdrDecoder, CdrEncoder};
+use crate::cluster::{ClusterChannel, ClusterMessage, MemoryBus, RelayCluster};
 use crate::error::WasmError;
 use crate::participant::WasmParticipant;
 use crate::protocol::{self, MessageHeader, RelayMessage, HEADER_SIZE};
 use crate::qos::{WasmDurability, WasmQos, WasmReliability};
 use crate::relay::RelayHandler;
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
 
 // ============================================================
 // Protocol tests
@@ -1094,3 +1097,151 @@ fn test_relay_same_topic_shared_id() {
     // Same topic name -> same topic ID
     assert_eq!(tid1, tid2);
 }
+
+// ============================================================
+// Relay cluster tests
+// ============================================================
+
+/// Connect a client, create `topic_name` and optionally subscribe to it.
+fn cluster_client(relay: &mut RelayHandler, topic_name: &str, subscribe: bool) -> (u32, u16) {
+    let (client_id, _) = relay.accept_client();
+    relay
+        .process_client_message(client_id, &protocol::build_connect(0, 0))
+        .unwrap();
+    let responses = relay
+        .process_client_message(client_id, &protocol::build_create_topic(topic_name, "T", 1))
+        .unwrap();
+    let topic_id = match protocol::parse_message(&responses[0]).unwrap() {
+        RelayMessage::TopicAck { topic_id, .. } => topic_id,
+        other => panic!("expected TopicAck, got {:?}", other),
+    };
+    if subscribe {
+        relay
+            .process_client_message(client_id, &protocol::build_subscribe(topic_id, 2))
+            .unwrap();
+    }
+    (client_id, topic_id)
+}
+
+#[test]
+fn test_cluster_message_roundtrip() {
+    let messages = [
+        ClusterMessage::Interest {
+            relay_id: 7,
+            topic_name: "sensors".to_string(),
+            subscribed: true,
+        },
+        ClusterMessage::Data {
+            relay_id: 7,
+            topic_name: "sensors".to_string(),
+            payload: vec![1, 2, 3],
+        },
+        ClusterMessage::Heartbeat {
+            relay_id: 7,
+            topics: vec!["a".to_string(), "b".to_string()],
+        },
+        ClusterMessage::Leave { relay_id: 7 },
+    ];
+    for message in messages {
+        let encoded = message.encode(3);
+        assert_eq!(ClusterMessage::decode(&encoded).unwrap(), message);
+        assert!(ClusterMessage::decode(&encoded[..HEADER_SIZE + 2]).is_err());
+    }
+    assert!(ClusterMessage::decode(&protocol::build_ping(0)).is_err());
+}
+
+#[test]
+fn test_cluster_forwards_publications_between_relays() {
+    let bus = MemoryBus::new();
+    let (channel_a, channel_b) = (bus.channel(), bus.channel());
+    let (mut relay_a, mut relay_b) = (RelayHandler::new(), RelayHandler::new());
+    let mut cluster_a = RelayCluster::new(1);
+    let mut cluster_b = RelayCluster::new(2);
+    let now = Instant::now();
+
+    // Browser on relay A subscribes, browser on relay B publishes
+    let (subscriber, _) = cluster_client(&mut relay_a, "chat", true);
+    let (_, chat_on_b) = cluster_client(&mut relay_b, "chat", false);
+    cluster_a.pump(&relay_a, &channel_a, now).unwrap();
+    cluster_b.pump(&relay_b, &channel_b, now).unwrap();
+    assert_eq!(cluster_b.interested_relays("chat"), vec![1]);
+    assert!(cluster_a.interested_relays("chat").is_empty());
+
+    let payload = vec![0xCA, 0xFE];
+    assert!(cluster_b
+        .publish(&relay_b, &channel_b, chat_on_b, &payload)
+        .unwrap());
+    let delivered = cluster_a.pump(&relay_a, &channel_a, now).unwrap();
+    assert_eq!(delivered.len(), 1);
+    assert_eq!(delivered[0].0, subscriber);
+    match protocol::parse_message(&delivered[0].1).unwrap() {
+        RelayMessage::Data { payload: p, .. } => assert_eq!(p, payload),
+        other => panic!("expected Data, got {:?}", other),
+    }
+
+    // Once A loses its subscriber, B stops forwarding
+    relay_a.remove_client(subscriber);
+    cluster_a.pump(&relay_a, &channel_a, now).unwrap();
+    cluster_b.pump(&relay_b, &channel_b, now).unwrap();
+    assert!(!cluster_b
+        .publish(&relay_b, &channel_b, chat_on_b, &payload)
+        .unwrap());
+    assert!(cluster_b
+        .publish(&relay_b, &channel_b, 999, &payload)
+        .is_err());
+}
+
+#[test]
+fn test_cluster_heartbeat_resync_and_peer_expiry() {
+    let start = Instant::now();
+    let mut cluster = RelayCluster::new(1)
+        .with_timing(Duration::from_secs(1), Duration::from_secs(3));
+
+    // A lost interest announcement is repaired by the next heartbeat
+    let mut peer = RelayCluster::new(2);
+    let _lost = peer.sync_subscriptions(HashSet::from(["metrics".to_string()]));
+    let heartbeat = peer.heartbeat(start);
+    cluster.handle_message(&heartbeat, start).unwrap();
+    assert_eq!(cluster.interested_relays("metrics"), vec![2]);
+
+    // Own messages are ignored
+    let own = cluster.heartbeat(start);
+    cluster.handle_message(&own, start).unwrap();
+    assert_eq!(cluster.peer_count(), 1);
+    assert!(!cluster.heartbeat_due(start + Duration::from_millis(500)));
+    assert!(cluster.heartbeat_due(start + Duration::from_secs(1)));
+
+    // Data for topics without local subscribers is dropped
+    let data = ClusterMessage::Data {
+        relay_id: 2,
+        topic_name: "metrics".to_string(),
+        payload: vec![1],
+    }
+    .encode(0);
+    assert_eq!(cluster.handle_message(&data, start).unwrap(), None);
+    cluster.sync_subscriptions(HashSet::from(["metrics".to_string()]));
+    assert_eq!(
+        cluster.handle_message(&data, start).unwrap(),
+        Some(("metrics".to_string(), vec![1]))
+    );
+
+    assert!(cluster.expire_peers(start + Duration::from_secs(2)).is_empty());
+    assert_eq!(cluster.expire_peers(start + Duration::from_secs(5)), vec![2]);
+    assert_eq!(cluster.peer_count(), 0);
+
+    // Leave removes a peer right away
+    cluster.handle_message(&heartbeat, start).unwrap();
+    let leave = peer.leave();
+    cluster.handle_message(&leave, start).unwrap();
+    assert_eq!(cluster.peer_count(), 0);
+}
+
+#[test]
+fn test_memory_bus_delivers_to_every_channel() {
+    let bus = MemoryBus::new();
+    let (a, b) = (bus.channel(), bus.channel());
+    a.publish(&[1, 2]).unwrap();
+    assert_eq!(a.poll(), vec![vec![1, 2]]);
+    assert_eq!(b.poll(), vec![vec![1, 2]]);
+    assert!(b.poll().is_empty());
+}
@@ -10,11 +10,11 @@ use std::sync::Arc;
 
 use crate::config::XrceAgentConfig;
 use crate::protocol::{
-    self, CreatePayload, DataPayload, MessageHeader, ObjectKind,
-    StatusCode, StatusPayload, Submessage, XrceError, XrceMessage,
+    self, CreatePayload, DataPayload, FragmentPayload, MessageHeader, ObjectKind,
+    ReassemblyBuffer, StatusCode, StatusPayload, Submessage, XrceError, XrceMessage,
 };
 use crate::proxy::ProxyBridge;
-use crate::session::{SessionTable, XrceObject};
+use crate::session::{SessionTable, StreamKind, XrceObject};
 use crate::transport::{TransportAddr, XrceTransport};
 
 /// The XRCE agent. Bridges resource-constrained clients to DDS.
@@ -65,6 +65,11 @@ impl XrceAgent {
                         replies.push((from.clone(), r));
                     }
                 }
+                Submessage::Fragment(payload) => {
+                    for r in self.handle_fragment(from, &msg.header, payload) {
+                        replies.push((from.clone(), r));
+                    }
+                }
                 _ => {
                     // All other submessages require an existing session.
                     if let Some(reply) =
@@ -86,7 +91,8 @@ impl XrceAgent {
         _hdr: &MessageHeader,
         payload: &protocol::CreateClientPayload,
     ) -> Option<Vec<u8>> {
-        let result = self.sessions.create_session(payload.client_key);
+        let quota = self.config.quota_for(&payload.client_key).clone();
+        let result = self.sessions.create_session_with_quota(payload.client_key, quota);
         let (session_id, status) = match result {
             Ok(id) => {
                 self.addr_map.insert(from.clone(), id);
@@ -156,6 +162,80 @@ impl XrceAgent {
         }
     }
 
+    /// Handle FRAGMENT: buffer the chunk and, once its message is complete,
+    /// dispatch the reassembled submessages.
+    ///
+    /// A new message is rejected with `ErrResources` when the stream already
+    /// holds `max_queue_depth` pending reassemblies.
+    fn handle_fragment(
+        &mut self,
+        from: &TransportAddr,
+        hdr: &MessageHeader,
+        payload: &FragmentPayload,
+    ) -> Vec<Vec<u8>> {
+        let (session_id, stream_id) = (hdr.session_id, hdr.stream_id);
+        let Some(session) = self.sessions.get_mut(session_id) else {
+            log::warn!("Fragment for unknown session {}", session_id);
+            return Vec::new();
+        };
+        session.touch();
+        let stream = session.stream(stream_id);
+
+        // Chunks of one message carry consecutive sequence numbers.
+        let first_seq = hdr.sequence_nr.wrapping_sub(payload.header.fragment_nr);
+        let buffer = ReassemblyBuffer::new(payload.header.total_fragments);
+        if !stream.reassembly.contains_key(&first_seq)
+            && !stream.begin_reassembly(first_seq, buffer)
+        {
+            log::debug!(
+                "FRAGMENT dropped: session {} stream {} reassembly queue full ({})",
+                session_id,
+                stream_id,
+                stream.max_queue_depth
+            );
+            return vec![self.make_status_reply(
+                session_id,
+                stream_id,
+                0,
+                StatusCode::ErrResources,
+            )];
+        }
+
+        let Some(buffer) = stream.reassembly.get_mut(&first_seq) else {
+            return Vec::new();
+        };
+        let assembled = match buffer.insert(payload.header.fragment_nr, payload.data.clone()) {
+            Ok(false) => return Vec::new(),
+            Ok(true) => buffer.assemble(),
+            Err(e) => Err(e),
+        };
+        stream.reassembly.remove(&first_seq);
+        let data = match assembled {
+            Ok(data) => data,
+            Err(e) => {
+                log::warn!("FRAGMENT dropped: session {}: {}", session_id, e);
+                return Vec::new();
+            }
+        };
+
+        let mut replies = Vec::new();
+        let mut offset = 0;
+        while offset < data.len() {
+            let (submsg, consumed) = match protocol::parse_submessage(&data[offset..]) {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    log::warn!("Failed to parse reassembled XRCE message: {}", e);
+                    break;
+                }
+            };
+            offset += consumed;
+            if let Some(reply) = self.handle_session_submessage(from, hdr, &submsg) {
+                replies.push(reply);
+            }
+        }
+        replies
+    }
+
     /// Handle CREATE submessage: create a DDS entity through the bridge.
     fn handle_create(
         &mut self,
@@ -163,6 +243,24 @@ impl XrceAgent {
         stream_id: u8,
         payload: &CreatePayload,
     ) -> Option<Vec<u8>> {
+        let within_quota = self
+            .sessions
+            .get(session_id)
+            .is_some_and(|s| s.can_add_object(payload.object_id));
+        if !within_quota {
+            log::warn!(
+                "CREATE rejected for object {}: session {} entity quota reached",
+                payload.object_id,
+                session_id
+            );
+            return Some(self.make_status_reply(
+                session_id,
+                stream_id,
+                payload.object_id,
+                StatusCode::ErrResources,
+            ));
+        }
+
         let result = match payload.kind {
             ObjectKind::Participant => {
                 self.bridge.create_participant(payload.parent_id)
@@ -184,16 +282,22 @@ impl XrceAgent {
             ObjectKind::DataWriter => {
                 let (p_handle, t_handle) =
                     self.find_writer_reader_parents(session_id, payload.parent_id);
-                match (p_handle, t_handle) {
-                    (Some(p), Some(t)) => self.bridge.create_writer(p, t),
+                match (p_handle, t_handle, self.resolve_qos_profile(payload)) {
+                    (_, _, Err(e)) => Err(e),
+                    (Some(p), Some(t), Ok(profile)) => {
+                        self.bridge.create_writer_with_profile(p, t, profile.as_deref())
+                    }
                     _ => Err(XrceError::ObjectNotFound(payload.parent_id)),
                 }
             }
             ObjectKind::DataReader => {
                 let (p_handle, t_handle) =
                     self.find_writer_reader_parents(session_id, payload.parent_id);
-                match (p_handle, t_handle) {
-                    (Some(p), Some(t)) => self.bridge.create_reader(p, t),
+                match (p_handle, t_handle, self.resolve_qos_profile(payload)) {
+                    (_, _, Err(e)) => Err(e),
+                    (Some(p), Some(t), Ok(profile)) => {
+                        self.bridge.create_reader_with_profile(p, t, profile.as_deref())
+                    }
                     _ => Err(XrceError::ObjectNotFound(payload.parent_id)),
                 }
             }
@@ -210,6 +314,10 @@ impl XrceAgent {
                 }
                 StatusCode::Ok
             }
+            Err(XrceError::QuotaExceeded(reason)) => {
+                log::warn!("CREATE rejected for object {}: {}", payload.object_id, reason);
+                StatusCode::ErrIncompatible
+            }
             Err(e) => {
                 log::warn!("CREATE failed for object {}: {}", payload.object_id, e);
                 StatusCode::ErrInvalidData
@@ -248,8 +356,15 @@ impl XrceAgent {
         stream_id: u8,
         payload: &protocol::WriteDataPayload,
     ) -> Option<Vec<u8>> {
-        let status = if let Some(session) = self.sessions.get(session_id) {
-            if let Some(obj) = session.get_object(payload.writer_id) {
+        let status = if let Some(session) = self.sessions.get_mut(session_id) {
+            if !session.bandwidth.try_consume(payload.data.len()) {
+                log::debug!(
+                    "WRITE_DATA dropped: session {} over bandwidth quota ({} B/s)",
+                    session_id,
+                    session.quota.max_bytes_per_sec
+                );
+                StatusCode::ErrResources
+            } else if let Some(obj) = session.get_object(payload.writer_id) {
                 match self.bridge.write_data(obj.bridge_handle, &payload.data) {
                     Ok(()) => StatusCode::Ok,
                     Err(_) => StatusCode::ErrInvalidData,
@@ -264,6 +379,10 @@ impl XrceAgent {
     }
 
     /// Handle READ_DATA submessage.
+    ///
+    /// On reliable streams the DATA reply is numbered and kept for
+    /// retransmission; the read is refused with `ErrResources` while the
+    /// stream already holds `max_queue_depth` unacknowledged messages.
     fn handle_read_data(
         &mut self,
         session_id: u8,
@@ -276,44 +395,73 @@ impl XrceAgent {
             .and_then(|s| s.get_object(payload.reader_id))
             .map(|o| o.bridge_handle);
 
-        match bridge_handle {
-            Some(handle) => match self.bridge.read_data(handle) {
-                Ok(Some(data)) => {
-                    let data_submsg = Submessage::Data(DataPayload {
-                        reader_id: payload.reader_id,
-                        data,
-                    });
-                    let msg = XrceMessage {
-                        header: MessageHeader {
-                            session_id,
-                            stream_id,
-                            sequence_nr: 0,
-                        },
-                        submessages: vec![data_submsg],
-                    };
-                    Some(protocol::serialize_message(&msg))
+        let Some(handle) = bridge_handle else {
+            return Some(self.make_status_reply(
+                session_id,
+                stream_id,
+                payload.reader_id,
+                StatusCode::ErrUnknownRef,
+            ));
+        };
+
+        let has_capacity = self
+            .sessions
+            .get_mut(session_id)
+            .is_some_and(|s| s.stream(stream_id).can_record_sent());
+        if !has_capacity {
+            log::debug!(
+                "READ_DATA refused: session {} stream {} unacked queue full",
+                session_id,
+                stream_id
+            );
+            return Some(self.make_status_reply(
+                session_id,
+                stream_id,
+                payload.reader_id,
+                StatusCode::ErrResources,
+            ));
+        }
+
+        match self.bridge.read_data(handle) {
+            Ok(Some(data)) => {
+                let data_submsg = Submessage::Data(DataPayload {
+                    reader_id: payload.reader_id,
+                    data,
+                });
+                let mut sequence_nr = 0;
+                if let Some(session) = self.sessions.get_mut(session_id) {
+                    let stream = session.stream(stream_id);
+                    if stream.kind == StreamKind::Reliable {
+                        sequence_nr = stream.alloc_send_seq();
+                        // Capacity was checked above, so this always records.
+                        let submsg_bytes = protocol::serialize_submessage(&data_submsg);
+                        stream.record_sent(sequence_nr, submsg_bytes);
+                    }
                 }
-                Ok(None) => {
-                    // No data available; send empty status OK.
-                    Some(self.make_status_reply(
+                let msg = XrceMessage {
+                    header: MessageHeader {
                         session_id,
                         stream_id,
-                        payload.reader_id,
-                        StatusCode::Ok,
-                    ))
-                }
-                Err(_) => Some(self.make_status_reply(
+                        sequence_nr,
+                    },
+                    submessages: vec![data_submsg],
+                };
+                Some(protocol::serialize_message(&msg))
+            }
+            Ok(None) => {
+                // No data available; send empty status OK.
+                Some(self.make_status_reply(
                     session_id,
                     stream_id,
                     payload.reader_id,
-                    StatusCode::ErrInvalidData,
-                )),
-            },
-            None => Some(self.make_status_reply(
+                    StatusCode::Ok,
+                ))
+            }
+            Err(_) => Some(self.make_status_reply(
                 session_id,
                 stream_id,
                 payload.reader_id,
-                StatusCode::ErrUnknownRef,
+                StatusCode::ErrInvalidData,
             )),
         }
     }
@@ -387,6 +535,15 @@ impl XrceAgent {
         protocol::serialize_message(&msg)
     }
 
+    /// Map the QoS profile requested in a writer/reader CREATE payload
+    /// onto an allowed server-side profile.
+    fn resolve_qos_profile(&self, payload: &CreatePayload) -> Result<Option<String>, XrceError> {
+        let requested = protocol::decode_string(&payload.string_data)
+            .ok()
+            .map(|(name, _)| name);
+        self.config.qos_profiles.resolve(requested.as_deref())
+    }
+
     /// Find the bridge handle for an object in the session.
     fn find_bridge_handle(&self, session_id: u8, object_id: u16) -> Option<u32> {
         self.sessions
@@ -3,7 +3,10 @@
 
 // Agent configuration with validation.
 
+use std::collections::HashMap;
+
 use crate::protocol::XrceError;
+use crate::quota::{ClientQuota, QosProfileMap};
 
 /// Configuration for the XRCE agent.
 #[derive(Debug, Clone)]
@@ -24,6 +27,12 @@ pub struct XrceAgentConfig {
     pub heartbeat_period_ms: u64,
     /// Maximum message size in bytes (default: 512, typical MCU limit).
     pub max_message_size: usize,
+    /// Resource limits applied to every client without an override.
+    pub default_quota: ClientQuota,
+    /// Per-client quota overrides, keyed by client_key.
+    pub client_quotas: HashMap<[u8; 4], ClientQuota>,
+    /// Mapping of client-requested QoS onto allowed server-side profiles.
+    pub qos_profiles: QosProfileMap,
 }
 
 impl Default for XrceAgentConfig {
@@ -37,6 +46,9 @@ impl Default for XrceAgentConfig {
             session_timeout_ms: 30_000,
             heartbeat_period_ms: 200,
             max_message_size: 512,
+            default_quota: ClientQuota::default(),
+            client_quotas: HashMap::new(),
+            qos_profiles: QosProfileMap::default(),
         }
     }
 }
@@ -76,6 +88,18 @@ impl XrceAgentConfig {
                 "serial_baud must be > 0".into(),
             ));
         }
+        self.default_quota.validate()?;
+        for quota in self.client_quotas.values() {
+            quota.validate()?;
+        }
+        self.qos_profiles.validate()?;
         Ok(())
     }
+
+    /// Quota for the given client (override if present, default otherwise).
+    pub fn quota_for(&self, client_key: &[u8; 4]) -> &ClientQuota {
+        self.client_quotas
+            .get(client_key)
+            .unwrap_or(&self.default_quota)
+    }
 }
@@ -26,6 +26,8 @@
 //! - **DDS-agnostic**: Any DDS implementation can be plugged in via [`ProxyBridge`]
 //! - **Fragmentation**: Large payloads are fragmented and reassembled transparently
 //! - **Session management**: Reliable delivery with sequence numbers and heartbeats
+//! - **Resource quotas**: Per-client entity, queue and bandwidth caps, with
+//!   client QoS mapped onto operator-approved profiles ([`ClientQuota`], [`QosProfileMap`])
 //!
 //! # Transports
 //!
@@ -39,6 +41,7 @@ pub mod agent;
 pub mod config;
 pub mod protocol;
 pub mod proxy;
+pub mod quota;
 pub mod session;
 pub mod transport;
 
@@ -53,7 +56,7 @@ pub use protocol::{
     // Payload types
     CreateClientPayload, CreatePayload, DeletePayload,
     WriteDataPayload, ReadDataPayload, DataPayload,
-    StatusPayload, HeartbeatPayload, AcknackPayload,
+    StatusPayload, HeartbeatPayload, AcknackPayload, FragmentPayload,
     // Enums
     ObjectKind, StatusCode,
     // Fragmentation
@@ -65,6 +68,7 @@ pub use protocol::{
     encode_string, decode_string,
 };
 pub use proxy::{ProxyBridge, NullBridge};
+pub use quota::{BandwidthLimiter, ClientQuota, QosProfileMap};
 pub use session::{ClientSession, SessionTable, StreamState, StreamKind, XrceObject};
 pub use transport::{TransportAddr, XrceTransport, UdpTransport, SerialTransport, TcpTransport};
 
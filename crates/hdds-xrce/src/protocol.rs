@@ -40,6 +40,8 @@ pub enum XrceError {
     SessionTimeout,
     /// Configuration validation error.
     ConfigError(String),
+    /// A per-client quota or QoS policy rejected the request.
+    QuotaExceeded(String),
 }
 
 impl fmt::Display for XrceError {
@@ -58,6 +60,7 @@ impl fmt::Display for XrceError {
             Self::BridgeError(msg) => write!(f, "bridge error: {}", msg),
             Self::SessionTimeout => write!(f, "session timeout"),
             Self::ConfigError(msg) => write!(f, "config error: {}", msg),
+            Self::QuotaExceeded(msg) => write!(f, "quota exceeded: {}", msg),
         }
     }
 }
@@ -88,6 +91,7 @@ pub const SUBMSG_STATUS: u8 = 0x05;
 pub const SUBMSG_WRITE_DATA: u8 = 0x07;
 pub const SUBMSG_READ_DATA: u8 = 0x08;
 pub const SUBMSG_DATA: u8 = 0x09;
+pub const SUBMSG_FRAGMENT: u8 = 0x0C;
 pub const SUBMSG_HEARTBEAT: u8 = 0x0D;
 pub const SUBMSG_ACKNACK: u8 = 0x0E;
 
@@ -296,6 +300,16 @@ pub struct DataPayload {
     pub data: Vec<u8>,
 }
 
+/// FRAGMENT (0x0C) - one chunk of a message larger than the transport MTU.
+///
+/// The chunks of one message are sent with consecutive sequence numbers;
+/// once all have arrived their data is parsed as submessages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FragmentPayload {
+    pub header: FragmentHeader,
+    pub data: Vec<u8>,
+}
+
 /// STATUS (0x05)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct StatusPayload {
@@ -330,6 +344,7 @@ pub enum Submessage {
     WriteData(WriteDataPayload),
     ReadData(ReadDataPayload),
     Data(DataPayload),
+    Fragment(FragmentPayload),
     Status(StatusPayload),
     Heartbeat(HeartbeatPayload),
     Acknack(AcknackPayload),
@@ -474,6 +489,11 @@ pub fn parse_submessage(buf: &[u8]) -> Result<(Submessage, usize), XrceError> {
             let data = payload[2..].to_vec();
             Submessage::Data(DataPayload { reader_id, data })
         }
+        SUBMSG_FRAGMENT => {
+            let header = FragmentHeader::parse(payload)?;
+            let data = payload[FRAGMENT_HEADER_SIZE..].to_vec();
+            Submessage::Fragment(FragmentPayload { header, data })
+        }
         SUBMSG_STATUS => {
             if payload.len() < 3 {
                 return Err(XrceError::PayloadLengthMismatch);
@@ -578,6 +598,12 @@ pub fn serialize_submessage(submsg: &Submessage) -> Vec<u8> {
             pl.extend_from_slice(&p.data);
             (SUBMSG_DATA, pl)
         }
+        Submessage::Fragment(p) => {
+            let mut pl = Vec::with_capacity(FRAGMENT_HEADER_SIZE + p.data.len());
+            p.header.write_to(&mut pl);
+            pl.extend_from_slice(&p.data);
+            (SUBMSG_FRAGMENT, pl)
+        }
         Submessage::Status(p) => {
             let mut pl = Vec::with_capacity(3);
             pl.extend_from_slice(&p.related_object_id.to_le_bytes());
@@ -39,6 +39,34 @@ pub trait ProxyBridge: Send + Sync {
         topic_id: u32,
     ) -> Result<u32, XrceError>;
 
+    /// Create a DDS DataWriter using a server-side QoS profile.
+    ///
+    /// `profile` is already resolved against the agent's allowed profiles;
+    /// `None` means default QoS. The default implementation ignores the
+    /// profile and calls [`ProxyBridge::create_writer`].
+    fn create_writer_with_profile(
+        &self,
+        participant_id: u32,
+        topic_id: u32,
+        profile: Option<&str>,
+    ) -> Result<u32, XrceError> {
+        let _ = profile;
+        self.create_writer(participant_id, topic_id)
+    }
+
+    /// Create a DDS DataReader using a server-side QoS profile.
+    ///
+    /// See [`ProxyBridge::create_writer_with_profile`].
+    fn create_reader_with_profile(
+        &self,
+        participant_id: u32,
+        topic_id: u32,
+        profile: Option<&str>,
+    ) -> Result<u32, XrceError> {
+        let _ = profile;
+        self.create_reader(participant_id, topic_id)
+    }
+
     /// Write serialized data through the given writer.
     fn write_data(&self, writer_id: u32, data: &[u8]) -> Result<(), XrceError>;
 
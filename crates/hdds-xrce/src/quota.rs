@@ -0,0 +1,185 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+// Per-client resource quotas and client QoS -> server profile mapping.
+//
+// Protects the agent from a single misbehaving client: each session is
+// capped in entity count, per-stream queue depth and inbound bandwidth,
+// and client-requested QoS is only honoured when it maps onto a profile
+// the operator explicitly allowed.
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use crate::protocol::XrceError;
+
+// ---------------------------------------------------------------------------
+// Client quota
+// ---------------------------------------------------------------------------
+
+/// Resource limits applied to one client session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientQuota {
+    /// Maximum number of proxy objects (participants, topics, writers, ...)
+    /// a client may hold at once (default: 64).
+    pub max_entities: usize,
+    /// Maximum queued messages per stream: outbound unacked messages on
+    /// reliable streams and inbound pending reassemblies (default: 32).
+    pub max_queue_depth: usize,
+    /// Maximum inbound WRITE_DATA payload bytes per second.
+    /// 0 disables the limit (default: 0).
+    pub max_bytes_per_sec: u64,
+}
+
+impl Default for ClientQuota {
+    fn default() -> Self {
+        Self {
+            max_entities: 64,
+            max_queue_depth: 32,
+            max_bytes_per_sec: 0,
+        }
+    }
+}
+
+impl ClientQuota {
+    /// Validate the quota. Returns Ok(()) if valid.
+    pub fn validate(&self) -> Result<(), XrceError> {
+        if self.max_entities == 0 {
+            return Err(XrceError::ConfigError(
+                "quota max_entities must be > 0".into(),
+            ));
+        }
+        if self.max_queue_depth == 0 {
+            return Err(XrceError::ConfigError(
+                "quota max_queue_depth must be > 0".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Bandwidth limiter
+// ---------------------------------------------------------------------------
+
+/// Token bucket limiting inbound payload bytes for one session.
+///
+/// The bucket holds one second worth of tokens, so a client may burst up to
+/// `max_bytes_per_sec` and is then throttled to the sustained rate.
+#[derive(Debug, Clone)]
+pub struct BandwidthLimiter {
+    rate: u64,
+    tokens: u64,
+    last_refill: Instant,
+}
+
+impl BandwidthLimiter {
+    /// Create a limiter with the given rate (0 = unlimited).
+    pub fn new(bytes_per_sec: u64) -> Self {
+        Self {
+            rate: bytes_per_sec,
+            tokens: bytes_per_sec,
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// Try to consume `bytes` tokens. Returns false if the client is over budget.
+    pub fn try_consume(&mut self, bytes: usize) -> bool {
+        if self.rate == 0 {
+            return true;
+        }
+        self.refill();
+        let bytes = bytes as u64;
+        if bytes > self.tokens {
+            return false;
+        }
+        self.tokens -= bytes;
+        true
+    }
+
+    fn refill(&mut self) {
+        let elapsed_us = self.last_refill.elapsed().as_micros() as u64;
+        let earned = self.rate.saturating_mul(elapsed_us) / 1_000_000;
+        if earned > 0 {
+            self.tokens = self.tokens.saturating_add(earned).min(self.rate);
+            self.last_refill = Instant::now();
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// QoS profile mapping
+// ---------------------------------------------------------------------------
+
+/// Maps client-requested QoS profile names onto server-side profiles.
+///
+/// XRCE clients reference QoS by name in the CREATE payload of a
+/// DataWriter/DataReader. The agent only forwards names listed in
+/// `allowed_profiles`, after applying `aliases`. Requests that name nothing
+/// get `default_profile`; unknown names fall back to `default_profile`
+/// unless `reject_unknown` is set.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QosProfileMap {
+    /// Server-side profile names clients may use.
+    pub allowed_profiles: Vec<String>,
+    /// Client-requested name -> server-side profile name.
+    pub aliases: HashMap<String, String>,
+    /// Profile used when the client requests none (None = bridge default QoS).
+    pub default_profile: Option<String>,
+    /// Reject CREATE requests naming a profile that is neither allowed nor aliased.
+    pub reject_unknown: bool,
+}
+
+impl QosProfileMap {
+    /// Resolve the profile to create the entity with.
+    ///
+    /// Returns `Ok(None)` when the bridge default QoS should be used.
+    pub fn resolve(&self, requested: Option<&str>) -> Result<Option<String>, XrceError> {
+        let requested = match requested {
+            Some(name) if !name.is_empty() => name,
+            _ => return Ok(self.default_profile.clone()),
+        };
+        let mapped = self
+            .aliases
+            .get(requested)
+            .map(String::as_str)
+            .unwrap_or(requested);
+        if self.allowed_profiles.iter().any(|p| p == mapped) {
+            return Ok(Some(mapped.to_string()));
+        }
+        if self.reject_unknown {
+            return Err(XrceError::QuotaExceeded(format!(
+                "QoS profile '{}' not allowed",
+                requested
+            )));
+        }
+        log::debug!(
+            "QoS profile '{}' not allowed, using default {:?}",
+            requested,
+            self.default_profile
+        );
+        Ok(self.default_profile.clone())
+    }
+
+    /// Validate that every alias target and the default are allowed profiles.
+    pub fn validate(&self) -> Result<(), XrceError> {
+        let is_allowed = |name: &str| self.allowed_profiles.iter().any(|p| p == name);
+        for (from, to) in &self.aliases {
+            if !is_allowed(to) {
+                return Err(XrceError::ConfigError(format!(
+                    "QoS alias '{}' targets unknown profile '{}'",
+                    from, to
+                )));
+            }
+        }
+        if let Some(default) = &self.default_profile {
+            if !is_allowed(default) {
+                return Err(XrceError::ConfigError(format!(
+                    "default QoS profile '{}' is not in allowed_profiles",
+                    default
+                )));
+            }
+        }
+        Ok(())
+    }
+}
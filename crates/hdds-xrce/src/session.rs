@@ -12,6 +12,7 @@ use std::time::Instant;
 use crate::protocol::{
     AcknackPayload, HeartbeatPayload, ObjectKind, ReassemblyBuffer, XrceError,
 };
+use crate::quota::{BandwidthLimiter, ClientQuota};
 
 // ---------------------------------------------------------------------------
 // Stream state
@@ -49,16 +50,24 @@ pub struct StreamState {
     pub unacked: HashMap<u16, Vec<u8>>,
     /// Inbound reassembly buffers. Key = sequence number.
     pub reassembly: HashMap<u16, ReassemblyBuffer>,
+    /// Maximum entries in `unacked` and in `reassembly` (client quota).
+    pub max_queue_depth: usize,
 }
 
 impl StreamState {
     pub fn new(kind: StreamKind) -> Self {
+        Self::with_queue_depth(kind, usize::MAX)
+    }
+
+    /// Create a stream whose queues are capped at `max_queue_depth` entries.
+    pub fn with_queue_depth(kind: StreamKind, max_queue_depth: usize) -> Self {
         Self {
             kind,
             next_send_seq: 0,
             next_recv_seq: 0,
             unacked: HashMap::new(),
             reassembly: HashMap::new(),
+            max_queue_depth,
         }
     }
 
@@ -69,11 +78,35 @@ impl StreamState {
         seq
     }
 
+    /// Check whether another outbound message fits the unacked queue.
+    ///
+    /// Always true on best-effort streams, which keep nothing for retransmission.
+    pub fn can_record_sent(&self) -> bool {
+        self.kind == StreamKind::BestEffort || self.unacked.len() < self.max_queue_depth
+    }
+
     /// Record an outbound message for potential retransmission.
-    pub fn record_sent(&mut self, seq: u16, data: Vec<u8>) {
+    ///
+    /// Returns false (and does not record) when the unacked queue is full.
+    pub fn record_sent(&mut self, seq: u16, data: Vec<u8>) -> bool {
+        if !self.can_record_sent() {
+            return false;
+        }
         if self.kind == StreamKind::Reliable {
             self.unacked.insert(seq, data);
         }
+        true
+    }
+
+    /// Start tracking an inbound fragmented message.
+    ///
+    /// Returns false (and does not track) when the reassembly queue is full.
+    pub fn begin_reassembly(&mut self, seq: u16, buffer: ReassemblyBuffer) -> bool {
+        if !self.reassembly.contains_key(&seq) && self.reassembly.len() >= self.max_queue_depth {
+            return false;
+        }
+        self.reassembly.insert(seq, buffer);
+        true
     }
 
     /// Process an ACKNACK from the client, removing acknowledged messages.
@@ -188,19 +221,33 @@ pub struct ClientSession {
     pub stream_states: HashMap<u8, StreamState>,
     pub objects: HashMap<u16, XrceObject>,
     pub last_activity: Instant,
+    /// Resource limits for this client.
+    pub quota: ClientQuota,
+    /// Inbound payload rate limiter derived from `quota`.
+    pub bandwidth: BandwidthLimiter,
 }
 
 impl ClientSession {
     /// Create a new session. The best-effort stream (id=0) is created automatically.
     pub fn new(session_id: u8, client_key: [u8; 4]) -> Self {
+        Self::with_quota(session_id, client_key, ClientQuota::default())
+    }
+
+    /// Create a new session bound to the given resource quota.
+    pub fn with_quota(session_id: u8, client_key: [u8; 4], quota: ClientQuota) -> Self {
         let mut stream_states = HashMap::new();
-        stream_states.insert(0, StreamState::new(StreamKind::BestEffort));
+        stream_states.insert(
+            0,
+            StreamState::with_queue_depth(StreamKind::BestEffort, quota.max_queue_depth),
+        );
         Self {
             session_id,
             client_key,
             stream_states,
             objects: HashMap::new(),
             last_activity: Instant::now(),
+            bandwidth: BandwidthLimiter::new(quota.max_bytes_per_sec),
+            quota,
         }
     }
 
@@ -216,14 +263,22 @@ impl ClientSession {
 
     /// Get or create a stream state.
     pub fn stream(&mut self, stream_id: u8) -> &mut StreamState {
+        let max_queue_depth = self.quota.max_queue_depth;
         self.stream_states
             .entry(stream_id)
             .or_insert_with(|| {
                 let kind = StreamKind::from_stream_id(stream_id);
-                StreamState::new(kind)
+                StreamState::with_queue_depth(kind, max_queue_depth)
             })
     }
 
+    /// Check whether another proxy object with `object_id` fits the entity quota.
+    ///
+    /// Re-creating an existing object id replaces it and is always allowed.
+    pub fn can_add_object(&self, object_id: u16) -> bool {
+        self.objects.contains_key(&object_id) || self.objects.len() < self.quota.max_entities
+    }
+
     /// Add a proxy object.
     pub fn add_object(&mut self, obj: XrceObject) {
         self.objects.insert(obj.object_id, obj);
@@ -265,6 +320,15 @@ impl SessionTable {
 
     /// Allocate a new session for the client. Returns the session_id.
     pub fn create_session(&mut self, client_key: [u8; 4]) -> Result<u8, XrceError> {
+        self.create_session_with_quota(client_key, ClientQuota::default())
+    }
+
+    /// Allocate a new session bound to a resource quota. Returns the session_id.
+    pub fn create_session_with_quota(
+        &mut self,
+        client_key: [u8; 4],
+        quota: ClientQuota,
+    ) -> Result<u8, XrceError> {
         if self.sessions.len() >= self.max_clients {
             return Err(XrceError::SessionFull);
         }
@@ -278,7 +342,7 @@ impl SessionTable {
                 self.next_session_id + 1
             };
             if let std::collections::hash_map::Entry::Vacant(e) = self.sessions.entry(id) {
-                let session = ClientSession::new(id, client_key);
+                let session = ClientSession::with_quota(id, client_key, quota);
                 e.insert(session);
                 return Ok(id);
             }
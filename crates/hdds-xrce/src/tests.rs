@@ -13,6 +13,7 @@ use crate::agent::XrceAgent;
 use crate::config::XrceAgentConfig;
 use crate::protocol::*;
 use crate::proxy::{NullBridge, ProxyBridge};
+use crate::quota::{BandwidthLimiter, ClientQuota, QosProfileMap};
 use crate::session::*;
 use crate::transport::TransportAddr;
 
@@ -831,6 +832,288 @@ fn test_fragment_empty_payload() {
     }
 }
 
+// -----------------------------------------------------------------------
+// Quotas: entity cap per client
+// -----------------------------------------------------------------------
+#[test]
+fn test_quota_entity_limit() {
+    let config = XrceAgentConfig {
+        default_quota: ClientQuota {
+            max_entities: 2,
+            ..ClientQuota::default()
+        },
+        ..XrceAgentConfig::default()
+    };
+    let mut agent = XrceAgent::new(config, RecordingBridge::new()).unwrap();
+    let from = TransportAddr::Udp("127.0.0.1:5100".parse().unwrap());
+    let replies = agent.process_incoming(&from, &make_create_client_msg());
+    let session_id = parse_message(&replies[0].1).unwrap().header.session_id;
+
+    let expected = [
+        (1, StatusCode::Ok),
+        (2, StatusCode::Ok),
+        (3, StatusCode::ErrResources),
+    ];
+    for (object_id, expected) in expected {
+        let msg = make_create_msg(session_id, object_id, ObjectKind::Publisher, 0, &[]);
+        let replies = agent.process_incoming(&from, &msg);
+        assert_eq!(reply_status(&replies[0].1), expected);
+    }
+
+    // Re-creating an existing object id replaces it and stays within quota.
+    let msg = make_create_msg(session_id, 2, ObjectKind::Publisher, 0, &[]);
+    let replies = agent.process_incoming(&from, &msg);
+    assert_eq!(reply_status(&replies[0].1), StatusCode::Ok);
+    assert_eq!(agent.sessions().get(session_id).unwrap().objects.len(), 2);
+}
+
+// -----------------------------------------------------------------------
+// Quotas: per-client override
+// -----------------------------------------------------------------------
+#[test]
+fn test_quota_per_client_override() {
+    let vip = [1, 2, 3, 4];
+    let mut config = XrceAgentConfig::default();
+    config.client_quotas.insert(
+        vip,
+        ClientQuota {
+            max_entities: 1,
+            ..ClientQuota::default()
+        },
+    );
+    assert_eq!(config.quota_for(&vip).max_entities, 1);
+    assert_eq!(config.quota_for(&[9, 9, 9, 9]).max_entities, 64);
+
+    let mut agent = XrceAgent::new(config, RecordingBridge::new()).unwrap();
+    let from = TransportAddr::Udp("127.0.0.1:5101".parse().unwrap());
+    let replies = agent.process_incoming(&from, &make_create_client_msg_with_key(vip));
+    let session_id = parse_message(&replies[0].1).unwrap().header.session_id;
+    assert_eq!(agent.sessions().get(session_id).unwrap().quota.max_entities, 1);
+}
+
+// -----------------------------------------------------------------------
+// Quotas: inbound bandwidth
+// -----------------------------------------------------------------------
+#[test]
+fn test_quota_bandwidth_limit() {
+    let config = XrceAgentConfig {
+        default_quota: ClientQuota {
+            max_bytes_per_sec: 100,
+            ..ClientQuota::default()
+        },
+        ..XrceAgentConfig::default()
+    };
+    let bridge = RecordingBridge::new();
+    let mut agent = XrceAgent::new(config, bridge.clone()).unwrap();
+    let from = TransportAddr::Udp("127.0.0.1:5102".parse().unwrap());
+    let replies = agent.process_incoming(&from, &make_create_client_msg());
+    let session_id = parse_message(&replies[0].1).unwrap().header.session_id;
+
+    create_participant_and_topic(&mut agent, &from, session_id);
+    let create_writer = make_create_msg(session_id, 10, ObjectKind::DataWriter, 2, &[]);
+    agent.process_incoming(&from, &create_writer);
+
+    let replies = agent.process_incoming(&from, &make_write_data_msg(session_id, 10, &[0u8; 80]));
+    assert_eq!(reply_status(&replies[0].1), StatusCode::Ok);
+    let replies = agent.process_incoming(&from, &make_write_data_msg(session_id, 10, &[0u8; 80]));
+    assert_eq!(reply_status(&replies[0].1), StatusCode::ErrResources);
+
+    let writes = bridge.calls.lock().unwrap().iter().filter(|c| *c == "write_data").count();
+    assert_eq!(writes, 1);
+}
+
+#[test]
+fn test_bandwidth_limiter_unlimited() {
+    let mut limiter = BandwidthLimiter::new(0);
+    for _ in 0..1000 {
+        assert!(limiter.try_consume(usize::MAX / 2));
+    }
+}
+
+// -----------------------------------------------------------------------
+// Quotas: per-stream queue depth
+// -----------------------------------------------------------------------
+#[test]
+fn test_quota_queue_depth_unacked() {
+    let config = XrceAgentConfig {
+        default_quota: ClientQuota {
+            max_queue_depth: 2,
+            ..ClientQuota::default()
+        },
+        ..XrceAgentConfig::default()
+    };
+    let mut agent = XrceAgent::new(config, Arc::new(DataBridge::new(vec![0xBE, 0xEF]))).unwrap();
+    let from = TransportAddr::Udp("127.0.0.1:5104".parse().unwrap());
+    let replies = agent.process_incoming(&from, &make_create_client_msg());
+    let session_id = parse_message(&replies[0].1).unwrap().header.session_id;
+    create_participant_and_topic(&mut agent, &from, session_id);
+    let create_reader = make_create_msg(session_id, 20, ObjectKind::DataReader, 2, &[]);
+    agent.process_incoming(&from, &create_reader);
+
+    let read = Submessage::ReadData(ReadDataPayload {
+        reader_id: 20,
+        max_samples: 1,
+    });
+    // Reliable stream 1: two DATA replies stay unacknowledged, the third read is refused.
+    for expected_seq in 0..2 {
+        let msg = make_stream_msg(session_id, 1, 0, read.clone());
+        let replies = agent.process_incoming(&from, &msg);
+        let reply = parse_message(&replies[0].1).unwrap();
+        assert!(matches!(reply.submessages[0], Submessage::Data(_)));
+        assert_eq!(reply.header.sequence_nr, expected_seq);
+    }
+    let replies = agent.process_incoming(&from, &make_stream_msg(session_id, 1, 0, read.clone()));
+    assert_eq!(reply_status(&replies[0].1), StatusCode::ErrResources);
+
+    // Best-effort stream keeps nothing for retransmission.
+    let replies = agent.process_incoming(&from, &make_stream_msg(session_id, 0, 0, read.clone()));
+    assert!(matches!(parse_message(&replies[0].1).unwrap().submessages[0], Submessage::Data(_)));
+
+    // Acknowledging both frees the queue.
+    let ack = Submessage::Acknack(AcknackPayload {
+        first_unacked_seq: 2,
+        nack_bitmap: 0,
+    });
+    agent.process_incoming(&from, &make_stream_msg(session_id, 1, 0, ack));
+    let replies = agent.process_incoming(&from, &make_stream_msg(session_id, 1, 0, read));
+    let reply = parse_message(&replies[0].1).unwrap();
+    assert!(matches!(reply.submessages[0], Submessage::Data(_)));
+    assert_eq!(reply.header.sequence_nr, 2);
+}
+
+#[test]
+fn test_quota_queue_depth_reassembly() {
+    let config = XrceAgentConfig {
+        default_quota: ClientQuota {
+            max_queue_depth: 2,
+            ..ClientQuota::default()
+        },
+        ..XrceAgentConfig::default()
+    };
+    let bridge = RecordingBridge::new();
+    let mut agent = XrceAgent::new(config, bridge.clone()).unwrap();
+    let from = TransportAddr::Udp("127.0.0.1:5105".parse().unwrap());
+    let replies = agent.process_incoming(&from, &make_create_client_msg());
+    let session_id = parse_message(&replies[0].1).unwrap().header.session_id;
+    create_participant_and_topic(&mut agent, &from, session_id);
+    let create_writer = make_create_msg(session_id, 10, ObjectKind::DataWriter, 2, &[]);
+    agent.process_incoming(&from, &create_writer);
+
+    let write = serialize_submessage(&Submessage::WriteData(WriteDataPayload {
+        writer_id: 10,
+        data: vec![0xAB; 18],
+    }));
+    let fragments: Vec<Submessage> = fragment_payload(&write, 8)
+        .unwrap()
+        .iter()
+        .map(|chunk| {
+            Submessage::Fragment(FragmentPayload {
+                header: FragmentHeader::parse(chunk).unwrap(),
+                data: chunk[FRAGMENT_HEADER_SIZE..].to_vec(),
+            })
+        })
+        .collect();
+    assert_eq!(fragments.len(), 3);
+
+    // Two messages pending reassembly fill the queue; a third is refused.
+    for first_seq in [0, 10] {
+        let msg = make_stream_msg(session_id, 1, first_seq, fragments[0].clone());
+        assert!(agent.process_incoming(&from, &msg).is_empty());
+    }
+    let msg = make_stream_msg(session_id, 1, 20, fragments[0].clone());
+    let replies = agent.process_incoming(&from, &msg);
+    assert_eq!(reply_status(&replies[0].1), StatusCode::ErrResources);
+
+    // Completing the first message dispatches its WRITE_DATA.
+    let mut replies = Vec::new();
+    for (nr, fragment) in fragments.iter().enumerate().skip(1) {
+        let msg = make_stream_msg(session_id, 1, nr as u16, fragment.clone());
+        replies.extend(agent.process_incoming(&from, &msg));
+    }
+    assert_eq!(replies.len(), 1);
+    assert_eq!(reply_status(&replies[0].1), StatusCode::Ok);
+    let writes = bridge.calls.lock().unwrap().iter().filter(|c| *c == "write_data").count();
+    assert_eq!(writes, 1);
+}
+
+// -----------------------------------------------------------------------
+// QoS mapping: client-requested profile -> allowed server profile
+// -----------------------------------------------------------------------
+#[test]
+fn test_qos_profile_resolution() {
+    let mut map = QosProfileMap {
+        allowed_profiles: vec!["sensor_be".into(), "control_rel".into()],
+        default_profile: Some("sensor_be".into()),
+        ..QosProfileMap::default()
+    };
+    map.aliases.insert("reliable".into(), "control_rel".into());
+    map.validate().unwrap();
+
+    assert_eq!(map.resolve(None).unwrap().as_deref(), Some("sensor_be"));
+    assert_eq!(map.resolve(Some("control_rel")).unwrap().as_deref(), Some("control_rel"));
+    assert_eq!(map.resolve(Some("reliable")).unwrap().as_deref(), Some("control_rel"));
+    assert_eq!(map.resolve(Some("keep_all_huge")).unwrap().as_deref(), Some("sensor_be"));
+
+    map.reject_unknown = true;
+    assert!(matches!(map.resolve(Some("keep_all_huge")), Err(XrceError::QuotaExceeded(_))));
+
+    map.aliases.insert("bad".into(), "missing".into());
+    assert!(map.validate().is_err());
+}
+
+#[test]
+fn test_qos_profile_passed_to_bridge() {
+    let mut config = XrceAgentConfig::default();
+    config.qos_profiles.allowed_profiles = vec!["control_rel".into()];
+    config.qos_profiles.reject_unknown = true;
+    let bridge = Arc::new(ProfileBridge::default());
+    let mut agent = XrceAgent::new(config, bridge.clone()).unwrap();
+    let from = TransportAddr::Udp("127.0.0.1:5103".parse().unwrap());
+    let replies = agent.process_incoming(&from, &make_create_client_msg());
+    let session_id = parse_message(&replies[0].1).unwrap().header.session_id;
+
+    create_participant_and_topic(&mut agent, &from, session_id);
+
+    let profile = encode_string("control_rel");
+    let ok = make_create_msg(session_id, 10, ObjectKind::DataWriter, 2, &profile);
+    let replies = agent.process_incoming(&from, &ok);
+    assert_eq!(reply_status(&replies[0].1), StatusCode::Ok);
+
+    let profile = encode_string("keep_all_huge");
+    let denied = make_create_msg(session_id, 11, ObjectKind::DataReader, 2, &profile);
+    let replies = agent.process_incoming(&from, &denied);
+    assert_eq!(reply_status(&replies[0].1), StatusCode::ErrIncompatible);
+
+    let profiles = bridge.profiles.lock().unwrap();
+    assert_eq!(profiles.as_slice(), &[Some("control_rel".to_string())]);
+}
+
+#[test]
+fn test_config_quota_validation() {
+    let bad = XrceAgentConfig {
+        default_quota: ClientQuota {
+            max_entities: 0,
+            ..ClientQuota::default()
+        },
+        ..XrceAgentConfig::default()
+    };
+    assert!(bad.validate().is_err());
+
+    let mut bad = XrceAgentConfig::default();
+    bad.client_quotas.insert(
+        [0; 4],
+        ClientQuota {
+            max_queue_depth: 0,
+            ..ClientQuota::default()
+        },
+    );
+    assert!(bad.validate().is_err());
+
+    let mut bad = XrceAgentConfig::default();
+    bad.qos_profiles.default_profile = Some("nope".into());
+    assert!(bad.validate().is_err());
+}
+
 // -----------------------------------------------------------------------
 // Test helpers
 // -----------------------------------------------------------------------
@@ -900,6 +1183,39 @@ fn make_write_data_msg(session_id: u8, writer_id: u16, data: &[u8]) -> Vec<u8> {
     serialize_message(&msg)
 }
 
+/// Create participant (object 1) and topic (object 2) for a session.
+fn create_participant_and_topic(agent: &mut XrceAgent, from: &TransportAddr, session_id: u8) {
+    let create_part = make_create_msg(session_id, 1, ObjectKind::Participant, 0, &[]);
+    agent.process_incoming(from, &create_part);
+    let topic_data = {
+        let mut d = encode_string("T");
+        d.extend_from_slice(&encode_string("U"));
+        d
+    };
+    let create_topic = make_create_msg(session_id, 2, ObjectKind::Topic, 1, &topic_data);
+    agent.process_incoming(from, &create_topic);
+}
+
+fn reply_status(reply: &[u8]) -> StatusCode {
+    match &parse_message(reply).unwrap().submessages[0] {
+        Submessage::Status(status) => status.status,
+        other => panic!("expected STATUS, got {:?}", other),
+    }
+}
+
+/// Serialize a single-submessage message on `stream_id`.
+fn make_stream_msg(session_id: u8, stream_id: u8, sequence_nr: u16, submsg: Submessage) -> Vec<u8> {
+    let msg = XrceMessage {
+        header: MessageHeader {
+            session_id,
+            stream_id,
+            sequence_nr,
+        },
+        submessages: vec![submsg],
+    };
+    serialize_message(&msg)
+}
+
 fn make_read_data_msg(session_id: u8, reader_id: u16, max_samples: u16) -> Vec<u8> {
     let msg = XrceMessage {
         header: MessageHeader {
@@ -1028,3 +1344,51 @@ impl ProxyBridge for DataBridge {
         Ok(())
     }
 }
+
+/// A bridge that records the QoS profile each writer/reader was created with.
+#[derive(Default)]
+struct ProfileBridge {
+    profiles: Mutex<Vec<Option<String>>>,
+}
+
+impl ProxyBridge for ProfileBridge {
+    fn create_participant(&self, _domain_id: u16) -> Result<u32, XrceError> {
+        Ok(1)
+    }
+    fn create_topic(&self, _pid: u32, _name: &str, _tn: &str) -> Result<u32, XrceError> {
+        Ok(2)
+    }
+    fn create_writer(&self, _pid: u32, _tid: u32) -> Result<u32, XrceError> {
+        Ok(3)
+    }
+    fn create_reader(&self, _pid: u32, _tid: u32) -> Result<u32, XrceError> {
+        Ok(4)
+    }
+    fn create_writer_with_profile(
+        &self,
+        pid: u32,
+        tid: u32,
+        profile: Option<&str>,
+    ) -> Result<u32, XrceError> {
+        self.profiles.lock().unwrap().push(profile.map(String::from));
+        self.create_writer(pid, tid)
+    }
+    fn create_reader_with_profile(
+        &self,
+        pid: u32,
+        tid: u32,
+        profile: Option<&str>,
+    ) -> Result<u32, XrceError> {
+        self.profiles.lock().unwrap().push(profile.map(String::from));
+        self.create_reader(pid, tid)
+    }
+    fn write_data(&self, _wid: u32, _data: &[u8]) -> Result<(), XrceError> {
+        Ok(())
+    }
+    fn read_data(&self, _rid: u32) -> Result<Option<Vec<u8>>, XrceError> {
+        Ok(None)
+    }
+    fn delete_entity(&self, _eid: u32) -> Result<(), XrceError> {
+        Ok(())
+    }
+}
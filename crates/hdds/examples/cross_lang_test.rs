@@ -56,7 +56,7 @@ fn run_sub(topic: &str, count: usize) -> Result<(), Box<dyn std::error::Error>>
             std::thread::sleep(Duration::from_millis(50));
         } else {
             for s in samples {
-                received.push(s.payload);
+                received.push(s.payload.to_vec());
             }
         }
     }
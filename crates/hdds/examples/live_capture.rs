@@ -37,9 +37,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     println!("      Participant created successfully!\n");
 
-    // Wait for discovery to propagate
-    println!("[2/3] Waiting 2 seconds for topic discovery...");
-    std::thread::sleep(Duration::from_secs(2));
+    // Wait for the first topic to be discovered ("#" matches any name)
+    println!("[2/3] Waiting up to 5 seconds for topic discovery...");
+    if let Err(hdds::Error::DiscoveryTimeout) =
+        participant.wait_for_topic("#", Duration::from_secs(5))
+    {
+        println!("No topics discovered.");
+        println!("\nHINT: Start a publisher in another terminal:");
+        println!("  cargo run --example generated_types_demo");
+        return Ok(());
+    }
 
     // Discover all active topics on the bus
     println!("[3/3] Discovering topics...\n");
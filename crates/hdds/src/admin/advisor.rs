@@ -0,0 +1,560 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! QoS tuning advisor.
+//!
+//! Turns runtime observations (NACK rates, deadline misses, queue depths,
+//! receive worker drops, per-locator loss) into concrete QoS/config
+//! recommendations for field engineers. Recommendations are served by the
+//! admin API (`Command::GetAdvice`) and are never applied automatically.
+//!
+//! # Example
+//!
+//! ```
+//! use hdds::admin::advisor::{EndpointObservation, QosAdvisor};
+//! use hdds::QoS;
+//!
+//! let advisor = QosAdvisor::new();
+//! let mut writer = EndpointObservation::writer("sensors/imu", &QoS::reliable().keep_last(4));
+//! writer.samples = 1_000;
+//! writer.nacks = 200;
+//! advisor.observe(writer);
+//!
+//! let advice = advisor.recommend(None, None);
+//! assert!(advice[0].suggestion.contains("KEEP_LAST(8)"));
+//! ```
+
+use super::snapshot::{LocatorStatsSnapshot, RxWorkersSnapshot};
+use crate::dds::qos::{History, Reliability};
+use crate::dds::QoS;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+/// Kind of an observed endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EndpointKind {
+    Writer,
+    Reader,
+}
+
+impl EndpointKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            EndpointKind::Writer => "writer",
+            EndpointKind::Reader => "reader",
+        }
+    }
+}
+
+/// Runtime counters of one endpoint, reported to [`QosAdvisor::observe`].
+///
+/// Counters are cumulative since the endpoint was created; ratios are
+/// computed from them, so a new observation simply replaces the last one.
+#[derive(Debug, Clone)]
+pub struct EndpointObservation {
+    pub kind: EndpointKind,
+    pub topic: String,
+    /// Entity name, distinguishes endpoints sharing a topic
+    pub name: Option<String>,
+    /// QoS the endpoint runs with (recommendations are relative to it)
+    pub qos: QoS,
+    /// Samples written (writer) or received (reader)
+    pub samples: u64,
+    /// Sequence numbers requested again by readers (writer only)
+    pub nacks: u64,
+    /// Deadline periods that elapsed without a sample
+    pub deadline_missed: u64,
+    /// Samples waiting in the history cache
+    pub queue_depth: usize,
+}
+
+impl EndpointObservation {
+    /// Empty observation of a writer on `topic`.
+    #[must_use]
+    pub fn writer(topic: &str, qos: &QoS) -> Self {
+        Self::new(EndpointKind::Writer, topic, qos)
+    }
+
+    /// Empty observation of a reader on `topic`.
+    #[must_use]
+    pub fn reader(topic: &str, qos: &QoS) -> Self {
+        Self::new(EndpointKind::Reader, topic, qos)
+    }
+
+    fn new(kind: EndpointKind, topic: &str, qos: &QoS) -> Self {
+        Self {
+            kind,
+            topic: topic.to_string(),
+            name: qos.entity_name.clone(),
+            qos: qos.clone(),
+            samples: 0,
+            nacks: 0,
+            deadline_missed: 0,
+            queue_depth: 0,
+        }
+    }
+
+    fn target(&self) -> String {
+        match self.name {
+            Some(ref name) => format!("{} '{}' on '{}'", self.kind.as_str(), name, self.topic),
+            None => format!("{} on '{}'", self.kind.as_str(), self.topic),
+        }
+    }
+
+    /// Samples the history cache holds before it drops or blocks.
+    fn capacity(&self) -> usize {
+        match self.qos.history {
+            History::KeepLast(depth) => depth as usize,
+            History::KeepAll => self.qos.resource_limits.max_samples,
+        }
+    }
+}
+
+/// How urgent a recommendation is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// Degraded behavior worth tuning.
+    Warning,
+    /// Data is being lost or deadlines are routinely missed.
+    Critical,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Severity::Warning => "warning",
+            Severity::Critical => "critical",
+        })
+    }
+}
+
+/// One tuning recommendation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recommendation {
+    pub severity: Severity,
+    /// What the recommendation is about ("writer on 'sensors/imu'", "rx_workers", ...)
+    pub target: String,
+    /// Observed behavior
+    pub issue: String,
+    /// Concrete QoS/config change to try
+    pub suggestion: String,
+}
+
+/// Ratios above which the advisor speaks up.
+///
+/// A ratio four times over its threshold is reported as [`Severity::Critical`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdvisorThresholds {
+    /// NACKed sequence numbers per sample written
+    pub nack_ratio: f64,
+    /// Missed deadline periods per period observed
+    pub deadline_miss_ratio: f64,
+    /// History cache fill level
+    pub queue_fill_ratio: f64,
+    /// Estimated loss per locator
+    pub loss_ratio: f64,
+}
+
+impl Default for AdvisorThresholds {
+    fn default() -> Self {
+        Self {
+            nack_ratio: 0.05,
+            deadline_miss_ratio: 0.01,
+            queue_fill_ratio: 0.8,
+            loss_ratio: 0.01,
+        }
+    }
+}
+
+impl AdvisorThresholds {
+    fn severity(ratio: f64, threshold: f64) -> Severity {
+        if ratio >= threshold * 4.0 {
+            Severity::Critical
+        } else {
+            Severity::Warning
+        }
+    }
+}
+
+type EndpointKey = (EndpointKind, String, Option<String>);
+
+/// Collects endpoint observations and derives recommendations from them.
+#[derive(Default)]
+pub struct QosAdvisor {
+    thresholds: AdvisorThresholds,
+    endpoints: Mutex<HashMap<EndpointKey, EndpointObservation>>,
+}
+
+impl QosAdvisor {
+    /// Advisor with the default thresholds.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Advisor with custom thresholds.
+    #[must_use]
+    pub fn with_thresholds(thresholds: AdvisorThresholds) -> Self {
+        Self {
+            thresholds,
+            endpoints: Mutex::default(),
+        }
+    }
+
+    fn endpoints(&self) -> MutexGuard<'_, HashMap<EndpointKey, EndpointObservation>> {
+        self.endpoints.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Record the latest counters of an endpoint (replaces the previous ones).
+    pub fn observe(&self, observation: EndpointObservation) {
+        let key = (
+            observation.kind,
+            observation.topic.clone(),
+            observation.name.clone(),
+        );
+        self.endpoints().insert(key, observation);
+    }
+
+    /// Stop tracking an endpoint (e.g. after it was deleted).
+    pub fn forget(&self, kind: EndpointKind, topic: &str, name: Option<&str>) {
+        self.endpoints()
+            .remove(&(kind, topic.to_string(), name.map(str::to_string)));
+    }
+
+    /// Number of endpoints observed.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.endpoints().len()
+    }
+
+    /// Whether no endpoint was observed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Recommendations for the observed endpoints and, when given, the
+    /// receive worker pool and remote locators; most severe first.
+    #[must_use]
+    pub fn recommend(
+        &self,
+        rx_workers: Option<&RxWorkersSnapshot>,
+        locators: Option<&LocatorStatsSnapshot>,
+    ) -> Vec<Recommendation> {
+        let mut advice = Vec::new();
+        {
+            let endpoints = self.endpoints();
+            let mut observations: Vec<_> = endpoints.values().collect();
+            observations.sort_by_key(|observation| observation.target());
+            for observation in observations {
+                self.advise_nacks(observation, &mut advice);
+                self.advise_deadline(observation, &mut advice);
+                self.advise_queue(observation, &mut advice);
+            }
+        }
+        if let Some(rx_workers) = rx_workers {
+            advise_rx_workers(rx_workers, &mut advice);
+        }
+        if let Some(locators) = locators {
+            self.advise_locators(locators, &mut advice);
+        }
+        advice.sort_by_key(|recommendation| std::cmp::Reverse(recommendation.severity));
+        advice
+    }
+
+    fn advise_nacks(&self, observation: &EndpointObservation, advice: &mut Vec<Recommendation>) {
+        if observation.kind != EndpointKind::Writer
+            || observation.qos.reliability != Reliability::Reliable
+            || observation.samples == 0
+        {
+            return;
+        }
+        let ratio = observation.nacks as f64 / observation.samples as f64;
+        if ratio <= self.thresholds.nack_ratio {
+            return;
+        }
+        let suggestion = match observation.qos.history {
+            History::KeepLast(depth) => format!(
+                "raise history to KEEP_LAST({}) so repairs are served from the writer cache, \
+                 and check the link for loss (locator stats)",
+                depth.saturating_mul(2)
+            ),
+            History::KeepAll => "check the link for loss (locator stats), or lower the \
+                                 publish rate / batch samples to reduce the repair traffic"
+                .to_string(),
+        };
+        advice.push(Recommendation {
+            severity: AdvisorThresholds::severity(ratio, self.thresholds.nack_ratio),
+            target: observation.target(),
+            issue: format!(
+                "{} NACKs for {} samples ({:.1}% repaired)",
+                observation.nacks,
+                observation.samples,
+                ratio * 100.0
+            ),
+            suggestion,
+        });
+    }
+
+    fn advise_deadline(&self, observation: &EndpointObservation, advice: &mut Vec<Recommendation>) {
+        let deadline = observation.qos.deadline;
+        if deadline.is_infinite() || observation.deadline_missed == 0 {
+            return;
+        }
+        let periods = observation.samples + observation.deadline_missed;
+        let ratio = observation.deadline_missed as f64 / periods as f64;
+        if ratio <= self.thresholds.deadline_miss_ratio {
+            return;
+        }
+        let period = format_duration(deadline.period);
+        let suggestion = match observation.kind {
+            EndpointKind::Reader => format!(
+                "raise the reader deadline to {} or make the writer publish at least every {}",
+                format_duration(deadline.period * 2),
+                period
+            ),
+            EndpointKind::Writer => format!(
+                "publish at least every {} or raise the offered deadline to {}",
+                period,
+                format_duration(deadline.period * 2)
+            ),
+        };
+        advice.push(Recommendation {
+            severity: AdvisorThresholds::severity(ratio, self.thresholds.deadline_miss_ratio),
+            target: observation.target(),
+            issue: format!(
+                "deadline of {} missed {} times ({:.1}% of periods)",
+                period,
+                observation.deadline_missed,
+                ratio * 100.0
+            ),
+            suggestion,
+        });
+    }
+
+    fn advise_queue(&self, observation: &EndpointObservation, advice: &mut Vec<Recommendation>) {
+        let capacity = observation.capacity();
+        if capacity == 0 || observation.queue_depth == 0 {
+            return;
+        }
+        let ratio = observation.queue_depth as f64 / capacity as f64;
+        if ratio < self.thresholds.queue_fill_ratio {
+            return;
+        }
+        let severity = if observation.queue_depth >= capacity {
+            Severity::Critical
+        } else {
+            Severity::Warning
+        };
+        let drain = match observation.kind {
+            EndpointKind::Reader => "take() more often",
+            EndpointKind::Writer => "wait for acknowledgments before bursts",
+        };
+        let suggestion = match observation.qos.history {
+            History::KeepLast(depth) => format!(
+                "raise history to KEEP_LAST({}) or {}",
+                depth.saturating_mul(2),
+                drain
+            ),
+            History::KeepAll => format!(
+                "raise resource_limits.max_samples to {} or {}",
+                capacity.saturating_mul(2),
+                drain
+            ),
+        };
+        advice.push(Recommendation {
+            severity,
+            target: observation.target(),
+            issue: format!(
+                "history cache holds {} of {} samples",
+                observation.queue_depth, capacity
+            ),
+            suggestion,
+        });
+    }
+
+    fn advise_locators(&self, locators: &LocatorStatsSnapshot, advice: &mut Vec<Recommendation>) {
+        for locator in &locators.locators {
+            if locator.loss_ratio <= self.thresholds.loss_ratio {
+                continue;
+            }
+            advice.push(Recommendation {
+                severity: AdvisorThresholds::severity(
+                    locator.loss_ratio,
+                    self.thresholds.loss_ratio,
+                ),
+                target: format!("locator {}", locator.locator),
+                issue: format!(
+                    "{} samples estimated lost ({:.1}%)",
+                    locator.estimated_lost,
+                    locator.loss_ratio * 100.0
+                ),
+                suggestion: "raise the socket receive buffer and check the network path; \
+                             use RELIABLE for topics that must not lose samples"
+                    .to_string(),
+            });
+        }
+    }
+}
+
+fn advise_rx_workers(rx_workers: &RxWorkersSnapshot, advice: &mut Vec<Recommendation>) {
+    if !rx_workers.enabled || rx_workers.dropped == 0 {
+        return;
+    }
+    advice.push(Recommendation {
+        severity: Severity::Critical,
+        target: "rx_workers".to_string(),
+        issue: format!(
+            "{} packets dropped by a full receive queue ({} submitted)",
+            rx_workers.dropped, rx_workers.submitted
+        ),
+        suggestion: format!(
+            "raise the receive worker pool to {} threads (ParticipantBuilder::with_rx_workers)",
+            rx_workers.threads.saturating_mul(2)
+        ),
+    });
+}
+
+fn format_duration(duration: Duration) -> String {
+    if duration.subsec_nanos() == 0 {
+        format!("{}s", duration.as_secs())
+    } else if duration.subsec_nanos().is_multiple_of(1_000_000) {
+        format!("{}ms", duration.as_millis())
+    } else {
+        format!("{}us", duration.as_micros())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::admin::snapshot::LocatorView;
+
+    fn writer(samples: u64, nacks: u64) -> EndpointObservation {
+        let mut observation = EndpointObservation::writer("sensors/imu", &QoS::reliable());
+        observation.samples = samples;
+        observation.nacks = nacks;
+        observation
+    }
+
+    #[test]
+    fn test_nack_rate() {
+        let advisor = QosAdvisor::new();
+        advisor.observe(writer(1_000, 10));
+        assert!(advisor.recommend(None, None).is_empty());
+
+        // Latest counters replace the previous ones
+        advisor.observe(writer(1_000, 100));
+        assert_eq!(advisor.len(), 1);
+        let advice = advisor.recommend(None, None);
+        assert_eq!(advice.len(), 1);
+        assert_eq!(advice[0].severity, Severity::Warning);
+        assert_eq!(advice[0].target, "writer on 'sensors/imu'");
+        assert_eq!(
+            advice[0].issue,
+            "100 NACKs for 1000 samples (10.0% repaired)"
+        );
+        assert!(advice[0]
+            .suggestion
+            .starts_with("raise history to KEEP_LAST(200)"));
+
+        // BestEffort writers are never NACKed
+        let mut best_effort = EndpointObservation::writer("video", &QoS::best_effort());
+        best_effort.samples = 10;
+        best_effort.nacks = 10;
+        advisor.observe(best_effort);
+        assert_eq!(advisor.recommend(None, None).len(), 1);
+    }
+
+    #[test]
+    fn test_deadline_misses() {
+        let advisor = QosAdvisor::new();
+        let qos = QoS::reliable().deadline_millis(100).entity_name("fusion");
+        let mut reader = EndpointObservation::reader("sensors/imu", &qos);
+        reader.samples = 80;
+        reader.deadline_missed = 20;
+        advisor.observe(reader);
+
+        let advice = advisor.recommend(None, None);
+        assert_eq!(advice.len(), 1);
+        assert_eq!(advice[0].severity, Severity::Critical);
+        assert_eq!(advice[0].target, "reader 'fusion' on 'sensors/imu'");
+        assert_eq!(
+            advice[0].issue,
+            "deadline of 100ms missed 20 times (20.0% of periods)"
+        );
+        assert!(advice[0].suggestion.contains("200ms"));
+
+        advisor.forget(EndpointKind::Reader, "sensors/imu", Some("fusion"));
+        assert!(advisor.is_empty());
+    }
+
+    #[test]
+    fn test_queue_depth() {
+        let advisor = QosAdvisor::new();
+        let mut reader = EndpointObservation::reader("map", &QoS::reliable().keep_last(10));
+        reader.queue_depth = 8;
+        advisor.observe(reader.clone());
+        let advice = advisor.recommend(None, None);
+        assert_eq!(advice[0].severity, Severity::Warning);
+        assert_eq!(
+            advice[0].suggestion,
+            "raise history to KEEP_LAST(20) or take() more often"
+        );
+
+        reader.queue_depth = 10;
+        advisor.observe(reader);
+        assert_eq!(
+            advisor.recommend(None, None)[0].severity,
+            Severity::Critical
+        );
+    }
+
+    #[test]
+    fn test_transport_advice_most_severe_first() {
+        let advisor = QosAdvisor::new();
+        advisor.observe(writer(100, 10));
+        let rx_workers = RxWorkersSnapshot {
+            epoch: 0,
+            enabled: true,
+            threads: 2,
+            submitted: 500,
+            completed: 490,
+            dropped: 10,
+            queued: 0,
+            busy_ns: 0,
+            max_job_ns: 0,
+        };
+        let locators = LocatorStatsSnapshot {
+            epoch: 0,
+            enabled: true,
+            untracked: 0,
+            locators: vec![LocatorView {
+                locator: "10.0.0.5:7411".to_string(),
+                writers: 1,
+                received: 98,
+                duplicates: 0,
+                gaps: 1,
+                estimated_lost: 2,
+                reordered: 0,
+                max_reorder_distance: 0,
+                loss_ratio: 0.02,
+                idle_ms: 0,
+            }],
+        };
+
+        let advice = advisor.recommend(Some(&rx_workers), Some(&locators));
+        let targets: Vec<_> = advice.iter().map(|a| a.target.as_str()).collect();
+        assert_eq!(
+            targets,
+            [
+                "rx_workers",
+                "writer on 'sensors/imu'",
+                "locator 10.0.0.5:7411"
+            ]
+        );
+        assert!(advice[0].suggestion.contains("4 threads"));
+    }
+}
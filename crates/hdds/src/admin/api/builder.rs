@@ -5,14 +5,17 @@
 //!
 //! Produces mesh, topics, endpoints, and metrics snapshots from internal state.
 
+use super::super::advisor::QosAdvisor;
 use super::super::snapshot::{
-    snapshot_participants, snapshot_with_epoch, EndpointView, EndpointsSnapshot, MeshSnapshot,
-    MetricsSnapshot, ParticipantDB, TopicView, TopicsSnapshot,
+    snapshot_participants, snapshot_with_epoch, AdviceSnapshot, AllocationsSnapshot, EndpointView,
+    EndpointsSnapshot, LocatorStatsSnapshot, LocatorView, MeshSnapshot, MetricsSnapshot,
+    ParticipantDB, RxWorkersSnapshot, TopicView, TopicsSnapshot,
 };
 use super::locks::recover_write;
 use crate::core::discovery::multicast::DiscoveryFsm;
 use crate::core::discovery::multicast::EndpointInfo;
 use crate::dds::qos::{Durability, History, Reliability};
+use crate::engine::{LocatorStats, RxWorkerStats};
 use crate::telemetry::{extract_metrics_from_collector, MetricsCollector};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex, RwLock};
@@ -108,6 +111,132 @@ pub(crate) fn metrics_snapshot(
     extract_metrics_from_collector(epoch_val, metrics)
 }
 
+/// Allocation snapshot from the per-subsystem tracking allocator.
+#[cfg(feature = "alloc-tracking")]
+pub(crate) fn allocations_snapshot(epoch: &Arc<AtomicU64>) -> AllocationsSnapshot {
+    use super::super::snapshot::AllocationView;
+
+    let stats = crate::telemetry::alloc::snapshot();
+    AllocationsSnapshot {
+        epoch: epoch.load(Ordering::SeqCst),
+        enabled: true,
+        installed: stats.installed,
+        subsystems: stats
+            .subsystems
+            .iter()
+            .map(|s| AllocationView {
+                subsystem: s.subsystem.as_str().to_string(),
+                live_bytes: s.live_bytes,
+                total_allocs: s.total_allocs,
+                total_frees: s.total_frees,
+            })
+            .collect(),
+    }
+}
+
+/// Allocation snapshot placeholder (alloc-tracking feature disabled).
+#[cfg(not(feature = "alloc-tracking"))]
+pub(crate) fn allocations_snapshot(epoch: &Arc<AtomicU64>) -> AllocationsSnapshot {
+    AllocationsSnapshot {
+        epoch: epoch.load(Ordering::SeqCst),
+        enabled: false,
+        installed: false,
+        subsystems: Vec::new(),
+    }
+}
+
+/// Receive worker pool counters, if a pool was attached.
+pub(crate) fn rx_workers_snapshot(
+    epoch: &Arc<AtomicU64>,
+    rx_workers: &Arc<Mutex<Option<Arc<RxWorkerStats>>>>,
+) -> RxWorkersSnapshot {
+    let stats = match rx_workers.lock() {
+        Ok(guard) => guard.as_ref().map(|stats| stats.snapshot()),
+        Err(e) => e.into_inner().as_ref().map(|stats| stats.snapshot()),
+    };
+    let stats = stats.unwrap_or_default();
+
+    RxWorkersSnapshot {
+        epoch: epoch.load(Ordering::SeqCst),
+        enabled: stats.threads > 0,
+        threads: stats.threads,
+        submitted: stats.submitted,
+        completed: stats.completed,
+        dropped: stats.dropped,
+        queued: stats.queued,
+        busy_ns: stats.busy_ns,
+        max_job_ns: stats.max_job_ns,
+    }
+}
+
+/// Per-locator receive statistics, if a table was attached.
+pub(crate) fn locator_stats_snapshot(
+    epoch: &Arc<AtomicU64>,
+    locator_stats: &Arc<Mutex<Option<Arc<LocatorStats>>>>,
+) -> LocatorStatsSnapshot {
+    let stats = match locator_stats.lock() {
+        Ok(guard) => guard.clone(),
+        Err(e) => e.into_inner().clone(),
+    };
+    let Some(stats) = stats else {
+        return LocatorStatsSnapshot {
+            epoch: epoch.load(Ordering::SeqCst),
+            enabled: false,
+            untracked: 0,
+            locators: Vec::new(),
+        };
+    };
+
+    let locators = stats
+        .snapshot()
+        .into_iter()
+        .map(|view| LocatorView {
+            locator: view.locator.to_string(),
+            writers: view.writers,
+            received: view.received,
+            duplicates: view.duplicates,
+            gaps: view.gaps,
+            estimated_lost: view.estimated_lost,
+            reordered: view.reordered,
+            max_reorder_distance: view.max_reorder_distance,
+            loss_ratio: view.loss_ratio,
+            idle_ms: u64::try_from(view.idle.as_millis()).unwrap_or(u64::MAX),
+        })
+        .collect();
+
+    LocatorStatsSnapshot {
+        epoch: epoch.load(Ordering::SeqCst),
+        enabled: true,
+        untracked: stats.untracked(),
+        locators,
+    }
+}
+
+/// Tuning advice from the attached advisor plus the receive worker and
+/// locator statistics; transport advice is given even without an advisor.
+pub(crate) fn advice_snapshot(
+    epoch: &Arc<AtomicU64>,
+    advisor: &Arc<Mutex<Option<Arc<QosAdvisor>>>>,
+    rx_workers: &Arc<Mutex<Option<Arc<RxWorkerStats>>>>,
+    locator_stats: &Arc<Mutex<Option<Arc<LocatorStats>>>>,
+) -> AdviceSnapshot {
+    let advisor = match advisor.lock() {
+        Ok(guard) => guard.clone(),
+        Err(e) => e.into_inner().clone(),
+    };
+    let enabled = advisor.is_some();
+    let advisor = advisor.unwrap_or_default();
+    let rx_workers = rx_workers_snapshot(epoch, rx_workers);
+    let locators = locator_stats_snapshot(epoch, locator_stats);
+
+    AdviceSnapshot {
+        epoch: epoch.load(Ordering::SeqCst),
+        enabled,
+        endpoints: advisor.len(),
+        recommendations: advisor.recommend(Some(&rx_workers), Some(&locators)),
+    }
+}
+
 fn endpoints_snapshot(
     epoch: &Arc<AtomicU64>,
     fsm: Option<&Arc<DiscoveryFsm>>,
@@ -157,5 +286,6 @@ fn endpoint_view(endpoint: &EndpointInfo) -> EndpointView {
         reliability: reliability.to_string(),
         durability: durability.to_string(),
         history,
+        name: endpoint.qos.entity_name.clone(),
     }
 }
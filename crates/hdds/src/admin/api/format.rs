@@ -5,7 +5,10 @@
 //!
 //! Manually renders snapshots as JSON for minimal dependencies.
 
-use super::super::snapshot::{EndpointsSnapshot, MeshSnapshot, MetricsSnapshot, TopicsSnapshot};
+use super::super::snapshot::{
+    AdviceSnapshot, AllocationsSnapshot, EndpointMetricsView, EndpointsSnapshot,
+    LocatorStatsSnapshot, MeshSnapshot, MetricsSnapshot, RxWorkersSnapshot, TopicsSnapshot,
+};
 use super::time::timestamp_iso8601;
 
 /// Render a mesh snapshot as JSON (serde-free for core crate minimalism).
@@ -71,7 +74,7 @@ pub(crate) fn format_json_topics(snapshot: TopicsSnapshot) -> String {
 /// Render the metrics snapshot as JSON payload.
 pub(crate) fn format_json_metrics(snapshot: MetricsSnapshot) -> String {
     format!(
-        r#"{{"schema_version":"1.0","timestamp":"{}","epoch":{},"messages_sent":{},"messages_received":{},"messages_dropped":{},"latency_min_ns":{},"latency_p50_ns":{},"latency_p99_ns":{},"latency_max_ns":{}}}"#,
+        r#"{{"schema_version":"1.0","timestamp":"{}","epoch":{},"messages_sent":{},"messages_received":{},"messages_dropped":{},"latency_min_ns":{},"latency_p50_ns":{},"latency_p99_ns":{},"latency_max_ns":{},"writers":[{}],"readers":[{}]}}"#,
         timestamp_iso8601(),
         snapshot.epoch,
         snapshot.messages_sent,
@@ -80,7 +83,140 @@ pub(crate) fn format_json_metrics(snapshot: MetricsSnapshot) -> String {
         snapshot.latency_min_ns,
         snapshot.latency_p50_ns,
         snapshot.latency_p99_ns,
-        snapshot.latency_max_ns
+        snapshot.latency_max_ns,
+        format_json_endpoint_metrics(&snapshot.writers),
+        format_json_endpoint_metrics(&snapshot.readers)
+    )
+}
+
+/// Render per-endpoint counters as the elements of a JSON array.
+fn format_json_endpoint_metrics(endpoints: &[EndpointMetricsView]) -> String {
+    let join = |values: &[u64]| {
+        values
+            .iter()
+            .map(u64::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+    endpoints
+        .iter()
+        .map(|e| {
+            format!(
+                r#"{{"guid":"{}","topic":"{}","samples":{},"bytes":{},"retransmissions":{},"nacks":{},"history_drops":{},"ack_rtt":{{"bounds_us":[{}],"counts":[{}],"count":{},"sum_ns":{}}}}}"#,
+                e.guid,
+                escape(&e.topic_name),
+                e.samples,
+                e.bytes,
+                e.retransmissions,
+                e.nacks,
+                e.history_drops,
+                join(&e.ack_rtt.bounds_us),
+                join(&e.ack_rtt.counts),
+                e.ack_rtt.count,
+                e.ack_rtt.sum_ns
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Render per-subsystem allocation counters as JSON payload.
+pub(crate) fn format_json_allocations(snapshot: AllocationsSnapshot) -> String {
+    let subsystems_json: Vec<String> = snapshot
+        .subsystems
+        .iter()
+        .map(|s| {
+            format!(
+                r#"{{"subsystem":"{}","live_bytes":{},"total_allocs":{},"total_frees":{}}}"#,
+                s.subsystem, s.live_bytes, s.total_allocs, s.total_frees
+            )
+        })
+        .collect();
+    let total_live: u64 = snapshot.subsystems.iter().map(|s| s.live_bytes).sum();
+
+    format!(
+        r#"{{"schema_version":"1.0","timestamp":"{}","epoch":{},"enabled":{},"installed":{},"total_live_bytes":{},"subsystems":[{}]}}"#,
+        timestamp_iso8601(),
+        snapshot.epoch,
+        snapshot.enabled,
+        snapshot.installed,
+        total_live,
+        subsystems_json.join(",")
+    )
+}
+
+/// Render receive worker pool counters as JSON payload.
+pub(crate) fn format_json_rx_workers(snapshot: RxWorkersSnapshot) -> String {
+    format!(
+        r#"{{"schema_version":"1.0","timestamp":"{}","epoch":{},"enabled":{},"threads":{},"submitted":{},"completed":{},"dropped":{},"queued":{},"busy_ns":{},"max_job_ns":{}}}"#,
+        timestamp_iso8601(),
+        snapshot.epoch,
+        snapshot.enabled,
+        snapshot.threads,
+        snapshot.submitted,
+        snapshot.completed,
+        snapshot.dropped,
+        snapshot.queued,
+        snapshot.busy_ns,
+        snapshot.max_job_ns
+    )
+}
+
+/// Render per-locator receive statistics as JSON payload.
+pub(crate) fn format_json_locator_stats(snapshot: LocatorStatsSnapshot) -> String {
+    let locators_json: Vec<String> = snapshot
+        .locators
+        .iter()
+        .map(|l| {
+            format!(
+                r#"{{"locator":"{}","writers":{},"received":{},"duplicates":{},"gaps":{},"estimated_lost":{},"reordered":{},"max_reorder_distance":{},"loss_ratio":{:.6},"idle_ms":{}}}"#,
+                l.locator,
+                l.writers,
+                l.received,
+                l.duplicates,
+                l.gaps,
+                l.estimated_lost,
+                l.reordered,
+                l.max_reorder_distance,
+                l.loss_ratio,
+                l.idle_ms
+            )
+        })
+        .collect();
+
+    format!(
+        r#"{{"schema_version":"1.0","timestamp":"{}","epoch":{},"enabled":{},"untracked":{},"locators":[{}]}}"#,
+        timestamp_iso8601(),
+        snapshot.epoch,
+        snapshot.enabled,
+        snapshot.untracked,
+        locators_json.join(",")
+    )
+}
+
+/// Render QoS tuning advice as JSON payload.
+pub(crate) fn format_json_advice(snapshot: AdviceSnapshot) -> String {
+    let recommendations_json: Vec<String> = snapshot
+        .recommendations
+        .iter()
+        .map(|r| {
+            format!(
+                r#"{{"severity":"{}","target":"{}","issue":"{}","suggestion":"{}"}}"#,
+                r.severity,
+                escape(&r.target),
+                escape(&r.issue),
+                escape(&r.suggestion)
+            )
+        })
+        .collect();
+
+    format!(
+        r#"{{"schema_version":"1.0","timestamp":"{}","epoch":{},"enabled":{},"endpoints":{},"recommendations":[{}]}}"#,
+        timestamp_iso8601(),
+        snapshot.epoch,
+        snapshot.enabled,
+        snapshot.endpoints,
+        recommendations_json.join(",")
     )
 }
 
@@ -106,15 +242,20 @@ fn format_endpoints(snapshot: EndpointsSnapshot, label: &str) -> String {
         .endpoints
         .iter()
         .map(|endpoint| {
+            let name = endpoint
+                .name
+                .as_ref()
+                .map_or(String::new(), |name| format!(r#","name":"{}""#, escape(name)));
             format!(
-                r#"{{"guid":"{}","participant_guid":"{}","topic":"{}","type":"{}","reliability":"{}","durability":"{}","history":"{}"}}"#,
+                r#"{{"guid":"{}","participant_guid":"{}","topic":"{}","type":"{}","reliability":"{}","durability":"{}","history":"{}"{}}}"#,
                 endpoint.guid,
                 endpoint.participant_guid,
                 endpoint.topic_name,
                 endpoint.type_name,
                 endpoint.reliability,
                 endpoint.durability,
-                endpoint.history
+                endpoint.history,
+                name
             )
         })
         .collect();
@@ -127,3 +268,8 @@ fn format_endpoints(snapshot: EndpointsSnapshot, label: &str) -> String {
         endpoints_json.join(",")
     )
 }
+
+/// Escape a free-form string (entity or topic names) for a JSON string value.
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -0,0 +1,118 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Plain HTTP endpoint for scrapers (feature `admin-http`).
+//!
+//! Serves `GET /metrics` in the Prometheus text format and `GET /mesh` as
+//! the JSON mesh snapshot, one request per connection. Requests are handled
+//! on the accept thread: scrapes are infrequent and each answer is a single
+//! snapshot.
+
+use super::builder;
+use super::format::format_json_mesh;
+use super::prometheus;
+use crate::admin::snapshot::ParticipantDB;
+use crate::core::discovery::multicast::DiscoveryFsm;
+use crate::telemetry::MetricsCollector;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+use std::time::Duration;
+
+/// Largest request head read before answering.
+const MAX_REQUEST_HEAD: usize = 8 * 1024;
+
+/// Time a client gets to send its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(2);
+
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// State shared with the admin API, read on every request.
+pub(super) struct HttpState {
+    pub(super) shutdown: Arc<AtomicBool>,
+    pub(super) epoch: Arc<AtomicU64>,
+    pub(super) participant_db: Arc<RwLock<ParticipantDB>>,
+    pub(super) metrics: Arc<Mutex<Option<Arc<MetricsCollector>>>>,
+    pub(super) fsm: Option<Arc<DiscoveryFsm>>,
+}
+
+/// Serve HTTP requests on `listener` (non-blocking) until shutdown.
+pub(super) fn spawn_http_thread(listener: TcpListener, state: HttpState) -> thread::JoinHandle<()> {
+    thread::spawn(move || loop {
+        if state.shutdown.load(Ordering::Relaxed) {
+            break;
+        }
+        match listener.accept() {
+            Ok((stream, _addr)) => handle_request(stream, &state),
+            Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                thread::sleep(Duration::from_millis(10));
+            }
+            Err(_) => {}
+        }
+    })
+}
+
+fn handle_request(mut stream: TcpStream, state: &HttpState) {
+    let _ = stream.set_nonblocking(false);
+    let _ = stream.set_read_timeout(Some(READ_TIMEOUT));
+
+    let Some(head) = read_request_head(&mut stream) else {
+        return;
+    };
+    let mut request_line = head.lines().next().unwrap_or("").split_whitespace();
+    let method = request_line.next().unwrap_or("");
+    let target = request_line.next().unwrap_or("");
+    let path = target.split('?').next().unwrap_or("");
+
+    let (status, content_type, body) = match (method, path) {
+        ("GET", "/metrics") => {
+            let snapshot = builder::metrics_snapshot(&state.epoch, &state.metrics);
+            (
+                "200 OK",
+                PROMETHEUS_CONTENT_TYPE,
+                prometheus::format_prometheus_metrics(&snapshot),
+            )
+        }
+        ("GET", "/mesh") => {
+            let snapshot =
+                builder::mesh_snapshot(&state.epoch, &state.participant_db, state.fsm.as_ref());
+            ("200 OK", "application/json", format_json_mesh(snapshot))
+        }
+        ("GET", _) => ("404 Not Found", "text/plain", "not found\n".to_string()),
+        _ => (
+            "405 Method Not Allowed",
+            "text/plain",
+            "method not allowed\n".to_string(),
+        ),
+    };
+
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    );
+    if let Err(e) = stream.write_all(response.as_bytes()) {
+        log::debug!("[AdminApi] HTTP response failed: {}", e);
+    }
+}
+
+/// Read up to the blank line ending the request head.
+fn read_request_head(stream: &mut TcpStream) -> Option<String> {
+    let mut head = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+        if head.len() >= MAX_REQUEST_HEAD {
+            break;
+        }
+        match stream.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => head.extend_from_slice(&buf[..n]),
+            Err(_) => return None,
+        }
+    }
+    String::from_utf8(head).ok()
+}
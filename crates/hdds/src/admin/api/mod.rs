@@ -8,7 +8,10 @@
 
 mod builder;
 mod format;
+#[cfg(feature = "admin-http")]
+mod http;
 mod locks;
+mod prometheus;
 mod protocol;
 mod server;
 mod time;
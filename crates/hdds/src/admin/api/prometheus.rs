@@ -0,0 +1,159 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Prometheus text exposition of the metrics snapshot.
+//!
+//! Global counters are unlabeled; writer and reader counters carry `guid`
+//! and `topic` labels, and the reliable ACK round trip is a histogram in
+//! seconds.
+
+use super::super::snapshot::{EndpointMetricsView, MetricsSnapshot};
+use std::fmt::Write;
+
+/// Per-endpoint counter: metric suffix, help text and value.
+type EndpointCounter = (&'static str, &'static str, fn(&EndpointMetricsView) -> u64);
+
+const WRITER_COUNTERS: &[EndpointCounter] = &[
+    ("samples_total", "Samples written", |e| e.samples),
+    ("bytes_total", "Payload bytes written", |e| e.bytes),
+    (
+        "retransmissions_total",
+        "Samples retransmitted after a NACK",
+        |e| e.retransmissions,
+    ),
+    ("nacks_received_total", "NACKs received from readers", |e| {
+        e.nacks
+    }),
+    (
+        "history_drops_total",
+        "Samples rejected because the history was full",
+        |e| e.history_drops,
+    ),
+];
+
+const READER_COUNTERS: &[EndpointCounter] = &[
+    ("samples_total", "Samples received", |e| e.samples),
+    ("bytes_total", "Payload bytes received", |e| e.bytes),
+    (
+        "retransmissions_total",
+        "Retransmitted samples received",
+        |e| e.retransmissions,
+    ),
+    ("nacks_sent_total", "NACKs sent to writers", |e| e.nacks),
+    (
+        "history_drops_total",
+        "Samples dropped because the history was full",
+        |e| e.history_drops,
+    ),
+];
+
+/// Render the metrics snapshot in the Prometheus text format.
+pub(crate) fn format_prometheus_metrics(snapshot: &MetricsSnapshot) -> String {
+    let mut out = String::new();
+    for (name, help, value) in [
+        (
+            "messages_sent_total",
+            "Messages sent",
+            snapshot.messages_sent,
+        ),
+        (
+            "messages_received_total",
+            "Messages received",
+            snapshot.messages_received,
+        ),
+        (
+            "messages_dropped_total",
+            "Messages dropped",
+            snapshot.messages_dropped,
+        ),
+    ] {
+        header(&mut out, name, help, "counter");
+        let _ = writeln!(out, "hdds_{} {}", name, value);
+    }
+
+    for (role, endpoints, counters) in [
+        ("writer", &snapshot.writers, WRITER_COUNTERS),
+        ("reader", &snapshot.readers, READER_COUNTERS),
+    ] {
+        if endpoints.is_empty() {
+            continue;
+        }
+        for (suffix, help, value) in counters {
+            let name = format!("{}_{}", role, suffix);
+            header(&mut out, &name, help, "counter");
+            for endpoint in endpoints {
+                let _ = writeln!(
+                    out,
+                    "hdds_{}{{{}}} {}",
+                    name,
+                    labels(endpoint),
+                    value(endpoint)
+                );
+            }
+        }
+    }
+
+    if !snapshot.writers.is_empty() {
+        header(
+            &mut out,
+            "writer_ack_rtt_seconds",
+            "Time from writing a sample to a reader acknowledging it",
+            "histogram",
+        );
+        for writer in &snapshot.writers {
+            write_histogram(&mut out, "writer_ack_rtt_seconds", writer);
+        }
+    }
+    out
+}
+
+fn header(out: &mut String, name: &str, help: &str, kind: &str) {
+    let _ = writeln!(out, "# HELP hdds_{} {}", name, help);
+    let _ = writeln!(out, "# TYPE hdds_{} {}", name, kind);
+}
+
+fn labels(endpoint: &EndpointMetricsView) -> String {
+    format!(
+        r#"guid="{}",topic="{}""#,
+        endpoint.guid,
+        escape_label(&endpoint.topic_name)
+    )
+}
+
+fn write_histogram(out: &mut String, name: &str, endpoint: &EndpointMetricsView) {
+    let labels = labels(endpoint);
+    let histogram = &endpoint.ack_rtt;
+    let mut cumulative = 0;
+    for (bound_us, count) in histogram.bounds_us.iter().zip(&histogram.counts) {
+        cumulative += count;
+        let _ = writeln!(
+            out,
+            r#"hdds_{}_bucket{{{},le="{}"}} {}"#,
+            name,
+            labels,
+            *bound_us as f64 / 1e6,
+            cumulative
+        );
+    }
+    let _ = writeln!(
+        out,
+        r#"hdds_{}_bucket{{{},le="+Inf"}} {}"#,
+        name, labels, histogram.count
+    );
+    let _ = writeln!(
+        out,
+        "hdds_{}_sum{{{}}} {}",
+        name,
+        labels,
+        histogram.sum_ns as f64 / 1e9
+    );
+    let _ = writeln!(out, "hdds_{}_count{{{}}} {}", name, labels, histogram.count);
+}
+
+/// Escape a label value (backslash, double quote and newline).
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', r"\\")
+        .replace('"', r#"\""#)
+        .replace('\n', r"\n")
+}
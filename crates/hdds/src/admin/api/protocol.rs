@@ -16,6 +16,12 @@ pub enum Command {
     GetHealth = 0x04,
     GetWriters = 0x05,
     GetReaders = 0x06,
+    GetAllocations = 0x07,
+    GetRxWorkers = 0x08,
+    GetLocatorStats = 0x09,
+    GetAdvice = 0x0A,
+    /// Metrics in the Prometheus text format instead of JSON
+    GetPrometheusMetrics = 0x0B,
 }
 
 impl Command {
@@ -27,6 +33,11 @@ impl Command {
             0x04 => Some(Command::GetHealth),
             0x05 => Some(Command::GetWriters),
             0x06 => Some(Command::GetReaders),
+            0x07 => Some(Command::GetAllocations),
+            0x08 => Some(Command::GetRxWorkers),
+            0x09 => Some(Command::GetLocatorStats),
+            0x0A => Some(Command::GetAdvice),
+            0x0B => Some(Command::GetPrometheusMetrics),
             _ => None,
         }
     }
@@ -4,17 +4,23 @@
 //! Admin API server binding and connection handling.
 //!
 //!
-//! Accepts TCP connections, parses commands, and returns JSON snapshots.
+//! Accepts TCP connections, parses commands, and returns JSON snapshots
+//! (or Prometheus text for `GetPrometheusMetrics`).
 
 use super::builder;
 use super::format::{
-    format_json_health, format_json_mesh, format_json_metrics, format_json_readers,
+    format_json_advice, format_json_allocations, format_json_health, format_json_locator_stats,
+    format_json_mesh, format_json_metrics, format_json_readers, format_json_rx_workers,
     format_json_topics, format_json_writers,
 };
+use super::prometheus;
 use super::protocol::{Command, Status};
+use crate::admin::advisor::QosAdvisor;
 use crate::admin::snapshot::{
-    EndpointsSnapshot, MeshSnapshot, MetricsSnapshot, ParticipantDB, TopicsSnapshot,
+    AdviceSnapshot, AllocationsSnapshot, EndpointsSnapshot, LocatorStatsSnapshot, MeshSnapshot,
+    MetricsSnapshot, ParticipantDB, RxWorkersSnapshot, TopicsSnapshot,
 };
+use crate::engine::{LocatorStats, RxWorkerStats};
 use crate::telemetry::MetricsCollector;
 use std::convert::TryFrom;
 use std::io::{Read, Write};
@@ -31,8 +37,13 @@ pub struct AdminApi {
     epoch: Arc<AtomicU64>,
     participant_db: Arc<RwLock<ParticipantDB>>,
     metrics: Arc<Mutex<Option<Arc<MetricsCollector>>>>,
+    rx_workers: Arc<Mutex<Option<Arc<RxWorkerStats>>>>,
+    locator_stats: Arc<Mutex<Option<Arc<LocatorStats>>>>,
+    advisor: Arc<Mutex<Option<Arc<QosAdvisor>>>>,
     fsm: Option<Arc<crate::core::discovery::multicast::DiscoveryFsm>>,
     accept_thread: Option<thread::JoinHandle<()>>,
+    #[cfg(feature = "admin-http")]
+    http_thread: Option<thread::JoinHandle<()>>,
     start_time: Instant,
 }
 
@@ -49,6 +60,9 @@ impl AdminApi {
         let epoch = Arc::new(AtomicU64::new(0));
         let participant_db = Arc::new(RwLock::new(ParticipantDB::new()));
         let metrics = Arc::new(Mutex::new(None));
+        let rx_workers = Arc::new(Mutex::new(None));
+        let locator_stats = Arc::new(Mutex::new(None));
+        let advisor = Arc::new(Mutex::new(None));
         let start_time = Instant::now();
 
         let accept_thread = spawn_accept_thread(
@@ -58,6 +72,9 @@ impl AdminApi {
             participant_db.clone(),
             start_time,
             metrics.clone(),
+            rx_workers.clone(),
+            locator_stats.clone(),
+            advisor.clone(),
             fsm.clone(),
         );
 
@@ -67,8 +84,13 @@ impl AdminApi {
             epoch,
             participant_db,
             metrics,
+            rx_workers,
+            locator_stats,
+            advisor,
             fsm,
             accept_thread: Some(accept_thread),
+            #[cfg(feature = "admin-http")]
+            http_thread: None,
             start_time,
         })
     }
@@ -85,6 +107,47 @@ impl AdminApi {
         *guard = Some(metrics_collector);
     }
 
+    /// Attach the participant's receive worker pool counters
+    /// (see [`crate::engine::Router::rx_worker_stats`]).
+    pub fn set_rx_workers(&mut self, stats: Arc<RxWorkerStats>) {
+        let mut guard = match self.rx_workers.lock() {
+            Ok(lock) => lock,
+            Err(e) => {
+                log::debug!("[AdminApi::set_rx_workers] rx_workers lock poisoned, recovering");
+                e.into_inner()
+            }
+        };
+        *guard = Some(stats);
+    }
+
+    /// Attach the router's per-locator receive statistics
+    /// (see [`crate::engine::Router::locator_stats`]).
+    pub fn set_locator_stats(&mut self, stats: Arc<LocatorStats>) {
+        let mut guard = match self.locator_stats.lock() {
+            Ok(lock) => lock,
+            Err(e) => {
+                log::debug!(
+                    "[AdminApi::set_locator_stats] locator_stats lock poisoned, recovering"
+                );
+                e.into_inner()
+            }
+        };
+        *guard = Some(stats);
+    }
+
+    /// Attach a QoS tuning advisor fed with endpoint observations
+    /// (see [`crate::admin::advisor`]).
+    pub fn set_advisor(&mut self, advisor: Arc<QosAdvisor>) {
+        let mut guard = match self.advisor.lock() {
+            Ok(lock) => lock,
+            Err(e) => {
+                log::debug!("[AdminApi::set_advisor] advisor lock poisoned, recovering");
+                e.into_inner()
+            }
+        };
+        *guard = Some(advisor);
+    }
+
     /// Mark the local participant entry and bump the epoch.
     pub fn set_local_participant(&self, name: String) {
         builder::set_local_participant(&self.participant_db, &self.epoch, name);
@@ -120,12 +183,77 @@ impl AdminApi {
         builder::metrics_snapshot(&self.epoch, &self.metrics)
     }
 
+    /// Metrics snapshot in the Prometheus text exposition format, with
+    /// per-writer and per-reader series labeled by GUID and topic.
+    #[must_use]
+    pub fn prometheus_metrics(&self) -> String {
+        prometheus::format_prometheus_metrics(&self.snapshot_metrics())
+    }
+
+    /// Snapshot live allocation bytes per subsystem (feature `alloc-tracking`).
+    #[must_use]
+    pub fn snapshot_allocations(&self) -> AllocationsSnapshot {
+        builder::allocations_snapshot(&self.epoch)
+    }
+
+    /// Snapshot receive worker pool counters (disabled if none attached).
+    #[must_use]
+    pub fn snapshot_rx_workers(&self) -> RxWorkersSnapshot {
+        builder::rx_workers_snapshot(&self.epoch, &self.rx_workers)
+    }
+
+    /// Snapshot per-locator receive statistics (disabled if none attached).
+    #[must_use]
+    pub fn snapshot_locator_stats(&self) -> LocatorStatsSnapshot {
+        builder::locator_stats_snapshot(&self.epoch, &self.locator_stats)
+    }
+
+    /// Snapshot QoS tuning advice (recommendations only, nothing is applied).
+    #[must_use]
+    pub fn snapshot_advice(&self) -> AdviceSnapshot {
+        builder::advice_snapshot(
+            &self.epoch,
+            &self.advisor,
+            &self.rx_workers,
+            &self.locator_stats,
+        )
+    }
+
     /// Return the current uptime in seconds.
     #[must_use]
     pub fn uptime_secs(&self) -> u64 {
         self.start_time.elapsed().as_secs()
     }
 
+    /// Serve `GET /metrics` (Prometheus text) and `GET /mesh` (JSON mesh
+    /// snapshot) over plain HTTP on `bind_addr:port`, until shutdown.
+    ///
+    /// Returns the bound address (useful with port 0). Fails if the HTTP
+    /// endpoint is already running.
+    #[cfg(feature = "admin-http")]
+    pub fn serve_http(&mut self, bind_addr: &str, port: u16) -> std::io::Result<SocketAddr> {
+        if self.http_thread.is_some() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::AlreadyExists,
+                "HTTP endpoint already running",
+            ));
+        }
+        let listener = create_tcp_listener(bind_addr, port)?;
+        let local_addr = listener.local_addr()?;
+        self.http_thread = Some(super::http::spawn_http_thread(
+            listener,
+            super::http::HttpState {
+                shutdown: self.shutdown.clone(),
+                epoch: self.epoch.clone(),
+                participant_db: self.participant_db.clone(),
+                metrics: self.metrics.clone(),
+                fsm: self.fsm.clone(),
+            },
+        ));
+        log::info!("[AdminApi] HTTP endpoint on http://{}", local_addr);
+        Ok(local_addr)
+    }
+
     /// Signal shutdown to the accept loop.
     pub fn shutdown(&self) {
         self.shutdown.store(true, Ordering::Release);
@@ -138,6 +266,10 @@ impl Drop for AdminApi {
         if let Some(handle) = self.accept_thread.take() {
             let _ = handle.join();
         }
+        #[cfg(feature = "admin-http")]
+        if let Some(handle) = self.http_thread.take() {
+            let _ = handle.join();
+        }
     }
 }
 
@@ -164,6 +296,7 @@ fn create_tcp_listener(bind_addr: &str, port: u16) -> std::io::Result<TcpListene
     Ok(listener)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn spawn_accept_thread(
     listener: TcpListener,
     shutdown: Arc<AtomicBool>,
@@ -171,13 +304,28 @@ fn spawn_accept_thread(
     db: Arc<RwLock<ParticipantDB>>,
     start_time: Instant,
     metrics: Arc<Mutex<Option<Arc<MetricsCollector>>>>,
+    rx_workers: Arc<Mutex<Option<Arc<RxWorkerStats>>>>,
+    locator_stats: Arc<Mutex<Option<Arc<LocatorStats>>>>,
+    advisor: Arc<Mutex<Option<Arc<QosAdvisor>>>>,
     fsm: Option<Arc<crate::core::discovery::multicast::DiscoveryFsm>>,
 ) -> thread::JoinHandle<()> {
     thread::spawn(move || {
-        accept_loop(listener, shutdown, epoch, db, start_time, metrics, fsm);
+        accept_loop(
+            listener,
+            shutdown,
+            epoch,
+            db,
+            start_time,
+            metrics,
+            rx_workers,
+            locator_stats,
+            advisor,
+            fsm,
+        );
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 fn accept_loop(
     listener: TcpListener,
     shutdown: Arc<AtomicBool>,
@@ -185,6 +333,9 @@ fn accept_loop(
     db: Arc<RwLock<ParticipantDB>>,
     start_time: Instant,
     metrics: Arc<Mutex<Option<Arc<MetricsCollector>>>>,
+    rx_workers: Arc<Mutex<Option<Arc<RxWorkerStats>>>>,
+    locator_stats: Arc<Mutex<Option<Arc<LocatorStats>>>>,
+    advisor: Arc<Mutex<Option<Arc<QosAdvisor>>>>,
     fsm: Option<Arc<crate::core::discovery::multicast::DiscoveryFsm>>,
 ) {
     loop {
@@ -201,6 +352,9 @@ fn accept_loop(
                 let epoch_clone = epoch.clone();
                 let db_clone = db.clone();
                 let metrics_clone = metrics.clone();
+                let rx_workers_clone = rx_workers.clone();
+                let locator_stats_clone = locator_stats.clone();
+                let advisor_clone = advisor.clone();
                 let fsm_clone = fsm.clone();
 
                 thread::spawn(move || {
@@ -211,6 +365,9 @@ fn accept_loop(
                         db_clone,
                         start_time,
                         metrics_clone,
+                        rx_workers_clone,
+                        locator_stats_clone,
+                        advisor_clone,
                         fsm_clone,
                     );
                 });
@@ -223,6 +380,7 @@ fn accept_loop(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn handle_client(
     mut stream: TcpStream,
     _shutdown: Arc<AtomicBool>,
@@ -230,6 +388,9 @@ fn handle_client(
     db: Arc<RwLock<ParticipantDB>>,
     start_time: Instant,
     metrics: Arc<Mutex<Option<Arc<MetricsCollector>>>>,
+    rx_workers: Arc<Mutex<Option<Arc<RxWorkerStats>>>>,
+    locator_stats: Arc<Mutex<Option<Arc<LocatorStats>>>>,
+    advisor: Arc<Mutex<Option<Arc<QosAdvisor>>>>,
     fsm: Option<Arc<crate::core::discovery::multicast::DiscoveryFsm>>,
 ) {
     let mut buf = [0u8; 1024];
@@ -275,6 +436,27 @@ fn handle_client(
                 let snapshot = builder::readers_snapshot(&epoch, fsm.as_ref());
                 format_json_readers(snapshot)
             }
+            Command::GetAllocations => {
+                let snapshot = builder::allocations_snapshot(&epoch);
+                format_json_allocations(snapshot)
+            }
+            Command::GetRxWorkers => {
+                let snapshot = builder::rx_workers_snapshot(&epoch, &rx_workers);
+                format_json_rx_workers(snapshot)
+            }
+            Command::GetLocatorStats => {
+                let snapshot = builder::locator_stats_snapshot(&epoch, &locator_stats);
+                format_json_locator_stats(snapshot)
+            }
+            Command::GetAdvice => {
+                let snapshot =
+                    builder::advice_snapshot(&epoch, &advisor, &rx_workers, &locator_stats);
+                format_json_advice(snapshot)
+            }
+            Command::GetPrometheusMetrics => {
+                let snapshot = builder::metrics_snapshot(&epoch, &metrics);
+                prometheus::format_prometheus_metrics(&snapshot)
+            }
         };
 
         let response_bytes = response.as_bytes();
@@ -2,11 +2,17 @@
 // Copyright (c) 2025-2026 naskel.com
 
 use super::format::{
-    format_json_health, format_json_mesh, format_json_metrics, format_json_topics,
+    format_json_advice, format_json_allocations, format_json_health, format_json_locator_stats,
+    format_json_mesh, format_json_metrics, format_json_rx_workers, format_json_topics,
+    format_json_writers,
 };
+use super::prometheus::format_prometheus_metrics;
 use super::time::timestamp_iso8601;
 use super::AdminApi;
-use crate::admin::snapshot::{MeshSnapshot, MetricsSnapshot, TopicsSnapshot};
+use crate::admin::snapshot::{
+    AllocationView, AllocationsSnapshot, EndpointMetricsView, EndpointView, EndpointsSnapshot,
+    LatencyHistogram, MeshSnapshot, MetricsSnapshot, TopicsSnapshot,
+};
 use crate::telemetry::parse_frame_fields;
 
 #[test]
@@ -78,6 +84,28 @@ fn test_format_json_topics() {
     assert!(json.contains(r#""topics":[]"#));
 }
 
+#[test]
+fn test_format_json_writers_entity_name() {
+    let view = |name: Option<&str>| EndpointView {
+        guid: "01.aa".to_string(),
+        participant_guid: "01.ab".to_string(),
+        topic_name: "sensors/temp".to_string(),
+        type_name: "Temperature".to_string(),
+        reliability: "RELIABLE".to_string(),
+        durability: "VOLATILE".to_string(),
+        history: "KEEP_LAST(10)".to_string(),
+        name: name.map(str::to_string),
+    };
+    let snapshot = EndpointsSnapshot {
+        epoch: 3,
+        endpoints: vec![view(Some(r#"imu "front""#)), view(None)],
+    };
+
+    let json = format_json_writers(snapshot);
+    assert!(json.contains(r#""history":"KEEP_LAST(10)","name":"imu \"front\""}"#));
+    assert!(json.contains(r#""history":"KEEP_LAST(10)"}"#));
+}
+
 #[test]
 fn test_format_json_metrics() {
     let snapshot = MetricsSnapshot {
@@ -89,6 +117,8 @@ fn test_format_json_metrics() {
         latency_p50_ns: 500,
         latency_p99_ns: 1000,
         latency_max_ns: 5000,
+        writers: Vec::new(),
+        readers: Vec::new(),
     };
 
     let json = format_json_metrics(snapshot);
@@ -96,6 +126,197 @@ fn test_format_json_metrics() {
     assert!(json.contains(r#""timestamp":""#));
     assert!(json.contains(r#""messages_sent":1000"#));
     assert!(json.contains(r#""latency_p99_ns":1000"#));
+    assert!(json.contains(r#""writers":[],"readers":[]"#));
+}
+
+fn endpoint_metrics_view(topic: &str) -> EndpointMetricsView {
+    EndpointMetricsView {
+        guid: "01.02.03.04.05.06.07.08.09.0a.0b.0c.00.00.01.03".to_string(),
+        topic_name: topic.to_string(),
+        samples: 12,
+        bytes: 480,
+        retransmissions: 2,
+        nacks: 1,
+        history_drops: 3,
+        ack_rtt: LatencyHistogram {
+            bounds_us: vec![100, 1_000],
+            counts: vec![4, 1, 1],
+            count: 6,
+            sum_ns: 2_500_000,
+        },
+    }
+}
+
+#[test]
+fn test_format_metrics_per_endpoint() {
+    let mut snapshot = MetricsSnapshot::empty(9);
+    snapshot.writers.push(endpoint_metrics_view("robot/pose"));
+    snapshot
+        .readers
+        .push(endpoint_metrics_view(r#"robot "cmd""#));
+
+    let json = format_json_metrics(snapshot.clone());
+    assert!(json.contains(
+        r#""topic":"robot/pose","samples":12,"bytes":480,"retransmissions":2,"nacks":1,"history_drops":3"#
+    ));
+    assert!(json.contains(r#""ack_rtt":{"bounds_us":[100,1000],"counts":[4,1,1],"count":6"#));
+    assert!(json.contains(r##""topic":"robot \"cmd\"""##));
+
+    let text = format_prometheus_metrics(&snapshot);
+    let guid = r#"guid="01.02.03.04.05.06.07.08.09.0a.0b.0c.00.00.01.03""#;
+    assert!(text.contains("# TYPE hdds_writer_samples_total counter"));
+    assert!(text.contains("# TYPE hdds_writer_ack_rtt_seconds histogram"));
+    for line in [
+        format!(
+            r#"hdds_writer_samples_total{{{},topic="robot/pose"}} 12"#,
+            guid
+        ),
+        format!(
+            r#"hdds_writer_nacks_received_total{{{},topic="robot/pose"}} 1"#,
+            guid
+        ),
+        format!(
+            r#"hdds_writer_ack_rtt_seconds_bucket{{{},topic="robot/pose",le="0.001"}} 5"#,
+            guid
+        ),
+        format!(
+            r#"hdds_writer_ack_rtt_seconds_bucket{{{},topic="robot/pose",le="+Inf"}} 6"#,
+            guid
+        ),
+        format!(
+            r#"hdds_writer_ack_rtt_seconds_sum{{{},topic="robot/pose"}} 0.0025"#,
+            guid
+        ),
+        format!(
+            r##"hdds_reader_history_drops_total{{{},topic="robot \"cmd\""}} 3"##,
+            guid
+        ),
+        "hdds_messages_sent_total 0".to_string(),
+    ] {
+        assert!(text.lines().any(|l| l == line), "missing: {}", line);
+    }
+}
+
+#[test]
+fn test_format_json_allocations() {
+    let snapshot = AllocationsSnapshot {
+        epoch: 7,
+        enabled: true,
+        installed: true,
+        subsystems: vec![
+            AllocationView {
+                subsystem: "discovery".to_string(),
+                live_bytes: 4096,
+                total_allocs: 12,
+                total_frees: 4,
+            },
+            AllocationView {
+                subsystem: "cache".to_string(),
+                live_bytes: 1024,
+                total_allocs: 3,
+                total_frees: 1,
+            },
+        ],
+    };
+
+    let json = format_json_allocations(snapshot);
+    assert!(json.contains(r#""schema_version":"1.0""#));
+    assert!(json.contains(r#""enabled":true"#));
+    assert!(json.contains(r#""total_live_bytes":5120"#));
+    assert!(json.contains(r#""subsystem":"discovery","live_bytes":4096"#));
+}
+
+#[test]
+fn test_snapshot_allocations_matches_feature() {
+    let api = AdminApi::bind("127.0.0.1", 0, None).expect("AdminApi bind should succeed");
+    let snapshot = api.snapshot_allocations();
+    assert_eq!(snapshot.enabled, cfg!(feature = "alloc-tracking"));
+    api.shutdown();
+}
+
+#[test]
+fn test_snapshot_rx_workers() {
+    use crate::engine::{RouterMetrics, RxWorkerConfig, RxWorkerPool, TopicRegistry};
+    use std::sync::Arc;
+
+    let mut api = AdminApi::bind("127.0.0.1", 0, None).expect("AdminApi bind should succeed");
+    assert!(!api.snapshot_rx_workers().enabled);
+
+    let pool = RxWorkerPool::start(
+        &RxWorkerConfig::new(3),
+        Arc::new(TopicRegistry::new()),
+        Arc::new(RouterMetrics::new()),
+    )
+    .expect("start pool");
+    api.set_rx_workers(pool.stats());
+
+    let snapshot = api.snapshot_rx_workers();
+    assert!(snapshot.enabled);
+    assert_eq!(snapshot.threads, 3);
+
+    let json = format_json_rx_workers(snapshot);
+    assert!(json.contains(r#""enabled":true,"threads":3,"submitted":0"#));
+    api.shutdown();
+}
+
+#[test]
+fn test_snapshot_locator_stats() {
+    use crate::core::discovery::GUID;
+    use crate::engine::LocatorStats;
+    use std::sync::Arc;
+
+    let mut api = AdminApi::bind("127.0.0.1", 0, None).expect("AdminApi bind should succeed");
+    assert!(!api.snapshot_locator_stats().enabled);
+
+    let stats = Arc::new(LocatorStats::new());
+    let writer = GUID::new([7; 12], [0, 0, 1, 2]);
+    for seq in [1, 2, 4] {
+        stats.record(([10, 0, 0, 2], 7411).into(), writer, seq);
+    }
+    api.set_locator_stats(stats);
+
+    let snapshot = api.snapshot_locator_stats();
+    assert!(snapshot.enabled);
+    assert_eq!(snapshot.locators.len(), 1);
+    assert_eq!(snapshot.locators[0].estimated_lost, 1);
+
+    let json = format_json_locator_stats(snapshot);
+    assert!(json.contains(
+        r#""locator":"10.0.0.2:7411","writers":1,"received":3,"duplicates":0,"gaps":1,"estimated_lost":1"#
+    ));
+    assert!(json.contains(r#""loss_ratio":0.250000"#));
+    api.shutdown();
+}
+
+#[test]
+fn test_snapshot_advice() {
+    use crate::admin::advisor::{EndpointObservation, QosAdvisor};
+    use crate::dds::QoS;
+    use std::sync::Arc;
+
+    let mut api = AdminApi::bind("127.0.0.1", 0, None).expect("AdminApi bind should succeed");
+    let snapshot = api.snapshot_advice();
+    assert!(!snapshot.enabled);
+    assert!(snapshot.recommendations.is_empty());
+
+    let advisor = Arc::new(QosAdvisor::new());
+    api.set_advisor(Arc::clone(&advisor));
+    let mut writer = EndpointObservation::writer("cmd \"vel\"", &QoS::reliable().keep_last(5));
+    writer.samples = 10;
+    writer.nacks = 5;
+    advisor.observe(writer);
+
+    let snapshot = api.snapshot_advice();
+    assert!(snapshot.enabled);
+    assert_eq!(snapshot.endpoints, 1);
+    assert_eq!(snapshot.recommendations.len(), 1);
+
+    let json = format_json_advice(snapshot);
+    assert!(json.contains(
+        r#""enabled":true,"endpoints":1,"recommendations":[{"severity":"critical","target":"writer on 'cmd \"vel\"'","issue":"5 NACKs for 10 samples (50.0% repaired)""#
+    ));
+    assert!(json.contains("KEEP_LAST(10)"));
+    api.shutdown();
 }
 
 #[test]
@@ -124,6 +345,40 @@ fn test_uptime_secs() {
     api.shutdown();
 }
 
+#[cfg(feature = "admin-http")]
+fn http_get(addr: std::net::SocketAddr, request: &str) -> String {
+    use std::io::{Read, Write};
+    let mut stream = std::net::TcpStream::connect(addr).expect("connect");
+    stream.write_all(request.as_bytes()).expect("send request");
+    let mut response = String::new();
+    stream.read_to_string(&mut response).expect("read response");
+    response
+}
+
+#[cfg(feature = "admin-http")]
+#[test]
+fn test_http_metrics_and_mesh() {
+    let mut api = AdminApi::bind("127.0.0.1", 0, None).expect("AdminApi bind should succeed");
+    api.set_local_participant("http_node".to_string());
+    let addr = api.serve_http("127.0.0.1", 0).expect("serve_http");
+    assert!(api.serve_http("127.0.0.1", 0).is_err());
+
+    let metrics = http_get(addr, "GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n");
+    assert!(metrics.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(metrics.contains("Content-Type: text/plain; version=0.0.4"));
+    assert!(metrics.contains("# TYPE hdds_messages_sent_total counter"));
+
+    let mesh = http_get(addr, "GET /mesh HTTP/1.1\r\n\r\n");
+    assert!(mesh.contains("Content-Type: application/json"));
+    assert!(mesh.contains("\"http_node\""));
+
+    let missing = http_get(addr, "GET /nope HTTP/1.1\r\n\r\n");
+    assert!(missing.starts_with("HTTP/1.1 404"));
+    let post = http_get(addr, "POST /metrics HTTP/1.1\r\n\r\n");
+    assert!(post.starts_with("HTTP/1.1 405"));
+    api.shutdown();
+}
+
 #[test]
 fn test_parse_frame_complete() {
     use crate::telemetry::metrics::{DType, Field, Frame};
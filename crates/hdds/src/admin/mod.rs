@@ -13,6 +13,8 @@
 //! - **Binary protocol**: Simple `[cmd_id][len][payload]` format
 //! - **Zero data-plane impact**: No locks held during write/read operations
 
+/// QoS tuning advisor (recommendations served by `Command::GetAdvice`).
+pub mod advisor;
 /// Admin API for Web Debugger
 ///
 /// Provides epoch-based snapshots of mesh state (participants, topics, metrics)
@@ -28,8 +30,10 @@ pub mod api;
 /// Snapshot helpers used by the admin API for mesh/metrics reporting.
 pub mod snapshot;
 
+pub use advisor::{EndpointObservation, QosAdvisor, Recommendation, Severity};
 pub use api::AdminApi;
 pub use snapshot::{
-    snapshot_participants, EndpointView, EndpointsSnapshot, MeshSnapshot, MetricsSnapshot,
-    ParticipantView, TopicsSnapshot,
+    snapshot_participants, AdviceSnapshot, AllocationView, AllocationsSnapshot,
+    EndpointMetricsView, EndpointView, EndpointsSnapshot, LatencyHistogram, LocatorStatsSnapshot,
+    LocatorView, MeshSnapshot, MetricsSnapshot, ParticipantView, RxWorkersSnapshot, TopicsSnapshot,
 };
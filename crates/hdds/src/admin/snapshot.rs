@@ -60,6 +60,8 @@ pub struct EndpointView {
     pub reliability: String,
     pub durability: String,
     pub history: String,
+    /// ENTITY_NAME announced by the endpoint, if any
+    pub name: Option<String>,
 }
 
 /// Snapshot of endpoints (writers or readers).
@@ -80,6 +82,41 @@ pub struct MetricsSnapshot {
     pub latency_p50_ns: u64,
     pub latency_p99_ns: u64,
     pub latency_max_ns: u64,
+    /// Counters of each live writer
+    pub writers: Vec<EndpointMetricsView>,
+    /// Counters of each live reader
+    pub readers: Vec<EndpointMetricsView>,
+}
+
+/// Traffic counters of one writer or reader.
+#[derive(Debug, Clone)]
+pub struct EndpointMetricsView {
+    pub guid: String,
+    pub topic_name: String,
+    /// Samples sent (writer) or received (reader)
+    pub samples: u64,
+    /// Payload bytes sent (writer) or received (reader)
+    pub bytes: u64,
+    /// Retransmissions sent (writer) or received (reader)
+    pub retransmissions: u64,
+    /// NACKs received (writer) or sent (reader)
+    pub nacks: u64,
+    /// Samples dropped because the history was full
+    pub history_drops: u64,
+    /// Write-to-acknowledgment round trip (reliable writers)
+    pub ack_rtt: LatencyHistogram,
+}
+
+/// Latency histogram with fixed buckets.
+#[derive(Debug, Clone, Default)]
+pub struct LatencyHistogram {
+    /// Upper bound of each bucket, in microseconds
+    pub bounds_us: Vec<u64>,
+    /// Observations per bucket; one more than `bounds_us`, the last one
+    /// unbounded
+    pub counts: Vec<u64>,
+    pub count: u64,
+    pub sum_ns: u64,
 }
 
 impl MetricsSnapshot {
@@ -94,10 +131,80 @@ impl MetricsSnapshot {
             latency_p50_ns: 0,
             latency_p99_ns: 0,
             latency_max_ns: 0,
+            writers: Vec::new(),
+            readers: Vec::new(),
         }
     }
 }
 
+/// Allocation snapshot: live bytes per subsystem tag
+///
+/// Populated only when built with the `alloc-tracking` feature and the
+/// tracking allocator is installed as the global allocator.
+#[derive(Debug, Clone)]
+pub struct AllocationsSnapshot {
+    pub epoch: u64,
+    pub enabled: bool,   // `alloc-tracking` feature compiled in
+    pub installed: bool, // TrackingAllocator is the global allocator
+    pub subsystems: Vec<AllocationView>,
+}
+
+/// Allocation counters for a single subsystem tag
+#[derive(Debug, Clone)]
+pub struct AllocationView {
+    pub subsystem: String, // "discovery", "reliability", "cache", "transport", "untagged"
+    pub live_bytes: u64,
+    pub total_allocs: u64,
+    pub total_frees: u64,
+}
+
+/// Receive worker pool snapshot (see `ParticipantBuilder::with_rx_workers`)
+#[derive(Debug, Clone)]
+pub struct RxWorkersSnapshot {
+    pub epoch: u64,
+    pub enabled: bool, // a pool is attached to the admin API
+    pub threads: usize,
+    pub submitted: u64,
+    pub completed: u64,
+    pub dropped: u64,
+    pub queued: u64,
+    pub busy_ns: u64,
+    pub max_job_ns: u64,
+}
+
+/// Per-locator receive statistics (see `Router::locator_stats`)
+#[derive(Debug, Clone)]
+pub struct LocatorStatsSnapshot {
+    pub epoch: u64,
+    pub enabled: bool, // locator stats are attached to the admin API
+    pub untracked: u64,
+    pub locators: Vec<LocatorView>,
+}
+
+/// Receive statistics of a single remote locator
+#[derive(Debug, Clone)]
+pub struct LocatorView {
+    pub locator: String, // "10.0.0.5:7411"
+    pub writers: usize,
+    pub received: u64,
+    pub duplicates: u64,
+    pub gaps: u64,
+    pub estimated_lost: u64,
+    pub reordered: u64,
+    pub max_reorder_distance: u64,
+    pub loss_ratio: f64,
+    pub idle_ms: u64,
+}
+
+/// QoS tuning advice (see [`crate::admin::advisor`])
+#[derive(Debug, Clone)]
+pub struct AdviceSnapshot {
+    pub epoch: u64,
+    pub enabled: bool,    // an advisor is attached to the admin API
+    pub endpoints: usize, // endpoints observed by the advisor
+    pub recommendations: Vec<crate::admin::advisor::Recommendation>,
+}
+
 /// Internal database of participants (placeholder for T1+)
 ///
 /// In Tier 0, there's no real discovery mesh, so this is minimal.
@@ -134,6 +134,15 @@ pub const MULTICAST_GROUP: &str = "239.255.0.1";
 /// String version of MULTICAST_IP_ALT (for fast parsing)
 pub const MULTICAST_GROUP_ALT: &str = "239.255.0.2";
 
+/// IPv6 counterpart of MULTICAST_IP (ff02::ffff:239.255.0.1)
+///
+/// Link-local scope: sends go out on a single interface, selected by its
+/// index (scope id).
+pub const MULTICAST_IP_V6: [u16; 8] = [0xff02, 0, 0, 0, 0, 0xffff, 0xefff, 0x0001];
+
+/// IPv6 counterpart of MULTICAST_IP_ALT (ff02::ffff:239.255.0.2)
+pub const MULTICAST_IP_V6_ALT: [u16; 8] = [0xff02, 0, 0, 0, 0, 0xffff, 0xefff, 0x0002];
+
 // =======================================================================
 // Timing & Lease (RTPS v2.5 defaults)
 // =======================================================================
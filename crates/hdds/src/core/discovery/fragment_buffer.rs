@@ -80,6 +80,9 @@ struct FragmentSet {
 
     /// Source address for NACK_FRAG responses
     source_addr: Option<SocketAddr>,
+
+    /// `PID_HDDS_TRACE_ID` of the sample, sent with its first fragment
+    trace_id: Option<u64>,
 }
 
 impl FragmentBuffer {
@@ -147,6 +150,40 @@ impl FragmentBuffer {
         data: Vec<u8>,
         source_addr: Option<SocketAddr>,
     ) -> Option<Vec<u8>> {
+        self.insert_traced_fragment(
+            writer_guid,
+            seq_num,
+            frag_num,
+            total_frags,
+            data,
+            source_addr,
+            None,
+        )
+        .map(|(payload, _)| payload)
+    }
+
+    /// Insert a fragment that may carry the sample's trace id.
+    ///
+    /// Same as [`insert_fragment_with_addr`](Self::insert_fragment_with_addr),
+    /// also keeping `trace_id` (sent with the first fragment, whichever
+    /// order the fragments arrive in) until the sample is complete.
+    ///
+    /// # Returns
+    ///
+    /// - `Some((payload, trace_id))`: Complete reassembled payload and the
+    ///   trace id any of its fragments carried
+    /// - `None`: Still waiting for more fragments
+    #[allow(clippy::too_many_arguments)]
+    pub fn insert_traced_fragment(
+        &mut self,
+        writer_guid: GUID,
+        seq_num: u64,
+        frag_num: u32,
+        total_frags: u16,
+        data: Vec<u8>,
+        source_addr: Option<SocketAddr>,
+        trace_id: Option<u64>,
+    ) -> Option<(Vec<u8>, Option<u64>)> {
         let key = (writer_guid, seq_num);
         let now = Instant::now();
 
@@ -157,12 +194,16 @@ impl FragmentBuffer {
             first_seen: now,
             last_updated: now,
             source_addr: None,
+            trace_id: None,
         });
 
         // Update source address if provided
         if source_addr.is_some() {
             frag_set.source_addr = source_addr;
         }
+        if trace_id.is_some() {
+            frag_set.trace_id = trace_id;
+        }
 
         // Update timestamp for LRU
         frag_set.last_updated = now;
@@ -206,7 +247,7 @@ impl FragmentBuffer {
             // SAFETY: We just checked that fragments.len() == total_frags, so the key must exist
             if let Some(frag_set) = self.pending.remove(&key) {
                 let complete = Self::reassemble_static(&frag_set);
-                Some(complete)
+                Some((complete, frag_set.trace_id))
             } else {
                 // This should never happen since we just verified len() == total_frags
                 log::debug!(
@@ -0,0 +1,271 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Initial peers for unicast SPDP announcements.
+//!
+//! In networks without multicast (Kubernetes overlays, cloud VPCs), SPDP
+//! announcements are sent via unicast to a configured peer list instead.
+//! A peer is an address or host name, with or without a port:
+//!
+//! - `192.168.1.10:7410`, `[fd00::2]:7410`, `node-a:7410`: that port only
+//! - `192.168.1.10`, `fd00::2`, `node-a`: the metatraffic unicast ports of
+//!   participant ids `0..INITIAL_PEER_PARTICIPANT_IDS` in the local domain
+//!
+//! Host names are resolved again every [`RESOLVE_INTERVAL`], so peers whose
+//! address changes (restarted pods) are picked up without a restart.
+
+use crate::transport::{IpMode, PortMapping};
+use std::fmt;
+use std::net::{IpAddr, SocketAddr, ToSocketAddrs};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// Participant ids reached on a peer given without a port.
+pub const INITIAL_PEER_PARTICIPANT_IDS: u8 = 4;
+
+/// Interval between host name resolutions.
+pub const RESOLVE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// A unicast SPDP destination: an address or host name, with an optional port.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InitialPeer {
+    host: String,
+    port: Option<u16>,
+}
+
+impl InitialPeer {
+    /// Host name or IP address of the peer.
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Explicit port, if any.
+    pub fn port(&self) -> Option<u16> {
+        self.port
+    }
+
+    /// Resolve the peer to SPDP destinations in `domain_id`.
+    ///
+    /// Only addresses of the families enabled by `ip_mode` are returned.
+    /// Resolution failures are logged and yield no destination.
+    pub fn resolve(&self, domain_id: u32, ip_mode: IpMode) -> Vec<SocketAddr> {
+        let ips: Vec<IpAddr> = match self.host.parse::<IpAddr>() {
+            Ok(ip) => vec![ip],
+            Err(_) => match (self.host.as_str(), 0).to_socket_addrs() {
+                Ok(addrs) => addrs.map(|addr| addr.ip()).collect(),
+                Err(err) => {
+                    log::debug!(
+                        "[spdp] Cannot resolve initial peer '{}': {}",
+                        self.host,
+                        err
+                    );
+                    Vec::new()
+                }
+            },
+        };
+
+        let ports: Vec<u16> = match self.port {
+            Some(port) => vec![port],
+            None => (0..INITIAL_PEER_PARTICIPANT_IDS)
+                .filter_map(|pid| PortMapping::calculate(domain_id, pid).ok())
+                .map(|mapping| mapping.metatraffic_unicast)
+                .collect(),
+        };
+
+        let mut targets = Vec::new();
+        for ip in ips {
+            let enabled = match ip {
+                IpAddr::V4(_) => ip_mode.uses_v4(),
+                IpAddr::V6(_) => ip_mode.uses_v6(),
+            };
+            if !enabled {
+                continue;
+            }
+            for &port in &ports {
+                let target = SocketAddr::new(ip, port);
+                if !targets.contains(&target) {
+                    targets.push(target);
+                }
+            }
+        }
+        targets
+    }
+}
+
+impl FromStr for InitialPeer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(addr) = s.parse::<SocketAddr>() {
+            return Ok(Self {
+                host: addr.ip().to_string(),
+                port: Some(addr.port()),
+            });
+        }
+        if let Ok(ip) = s.parse::<IpAddr>() {
+            return Ok(Self {
+                host: ip.to_string(),
+                port: None,
+            });
+        }
+
+        let (host, port) = match s.rsplit_once(':') {
+            Some((host, port)) => {
+                let port = port
+                    .parse::<u16>()
+                    .map_err(|_| format!("invalid port in initial peer '{}'", s))?;
+                (host, Some(port))
+            }
+            None => (s, None),
+        };
+        let valid_host = !host.is_empty()
+            && host
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.' || c == '_');
+        if !valid_host {
+            return Err(format!("invalid initial peer '{}'", s));
+        }
+        Ok(Self {
+            host: host.to_string(),
+            port,
+        })
+    }
+}
+
+impl fmt::Display for InitialPeer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.port, self.host.contains(':')) {
+            (Some(port), true) => write!(f, "[{}]:{}", self.host, port),
+            (Some(port), false) => write!(f, "{}:{}", self.host, port),
+            (None, _) => f.write_str(&self.host),
+        }
+    }
+}
+
+/// Initial peers with their resolved destinations, refreshed periodically.
+#[derive(Debug)]
+pub struct InitialPeerSet {
+    peers: Vec<InitialPeer>,
+    targets: Vec<SocketAddr>,
+    resolved_at: Option<Instant>,
+}
+
+impl InitialPeerSet {
+    /// Create a set from parsed peers (duplicates removed).
+    pub fn new(peers: Vec<InitialPeer>) -> Self {
+        let mut unique: Vec<InitialPeer> = Vec::with_capacity(peers.len());
+        for peer in peers {
+            if !unique.contains(&peer) {
+                unique.push(peer);
+            }
+        }
+        Self {
+            peers: unique,
+            targets: Vec::new(),
+            resolved_at: None,
+        }
+    }
+
+    /// Whether no peer is configured.
+    pub fn is_empty(&self) -> bool {
+        self.peers.is_empty()
+    }
+
+    /// Configured peers.
+    pub fn peers(&self) -> &[InitialPeer] {
+        &self.peers
+    }
+
+    /// SPDP destinations, resolving host names again when they are stale.
+    ///
+    /// The previous destinations are kept if a new resolution finds none
+    /// (DNS outage), so announcements keep going to the last known peers.
+    pub fn targets(&mut self, domain_id: u32, ip_mode: IpMode) -> &[SocketAddr] {
+        let stale = self
+            .resolved_at
+            .is_none_or(|at| at.elapsed() >= RESOLVE_INTERVAL);
+        if stale && !self.peers.is_empty() {
+            let mut targets = Vec::new();
+            for peer in &self.peers {
+                for target in peer.resolve(domain_id, ip_mode) {
+                    if !targets.contains(&target) {
+                        targets.push(target);
+                    }
+                }
+            }
+            if !targets.is_empty() || self.targets.is_empty() {
+                if targets != self.targets {
+                    log::debug!(
+                        "[spdp] Initial peers resolved to {} destination(s): {:?}",
+                        targets.len(),
+                        targets
+                    );
+                }
+                self.targets = targets;
+            }
+            self.resolved_at = Some(Instant::now());
+        }
+        &self.targets
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_initial_peers() {
+        let peer: InitialPeer = "192.168.1.10:7410".parse().expect("address with port");
+        assert_eq!((peer.host(), peer.port()), ("192.168.1.10", Some(7410)));
+
+        let peer: InitialPeer = "[fd00::2]:7410".parse().expect("IPv6 with port");
+        assert_eq!((peer.host(), peer.port()), ("fd00::2", Some(7410)));
+        assert_eq!(peer.to_string(), "[fd00::2]:7410");
+
+        let peer: InitialPeer = "fd00::2".parse().expect("bare IPv6");
+        assert_eq!((peer.host(), peer.port()), ("fd00::2", None));
+
+        let peer: InitialPeer = " node-a.svc.cluster.local ".parse().expect("host");
+        assert_eq!(
+            (peer.host(), peer.port()),
+            ("node-a.svc.cluster.local", None)
+        );
+
+        assert!("host2:70000".parse::<InitialPeer>().is_err());
+        assert!("".parse::<InitialPeer>().is_err());
+        assert!("bad host".parse::<InitialPeer>().is_err());
+    }
+
+    #[test]
+    fn test_resolve_without_port_uses_metatraffic_ports() {
+        let peer: InitialPeer = "10.0.0.5".parse().expect("address");
+        let targets = peer.resolve(1, IpMode::V4);
+        assert_eq!(targets.len(), usize::from(INITIAL_PEER_PARTICIPANT_IDS));
+        let expected = PortMapping::calculate(1, 0)
+            .expect("mapping")
+            .metatraffic_unicast;
+        assert_eq!(
+            targets[0],
+            SocketAddr::new("10.0.0.5".parse().expect("ip"), expected)
+        );
+
+        // Families disabled by the IP mode are skipped
+        assert!(peer.resolve(1, IpMode::V6).is_empty());
+    }
+
+    #[test]
+    fn test_peer_set_resolves_localhost_and_dedups() {
+        let mut set = InitialPeerSet::new(vec![
+            "localhost:7410".parse().expect("host"),
+            "localhost:7410".parse().expect("host"),
+            "127.0.0.1:7410".parse().expect("address"),
+        ]);
+        assert_eq!(set.peers().len(), 2);
+        let targets = set.targets(0, IpMode::V4);
+        assert_eq!(
+            targets,
+            ["127.0.0.1:7410".parse::<SocketAddr>().expect("addr")]
+        );
+    }
+}
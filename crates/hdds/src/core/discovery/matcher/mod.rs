@@ -4,7 +4,7 @@
 //! Lazy-binding matcher utilities orchestrating QoS, topic, and type compatibility checks.
 //!
 
-use crate::dds::qos::QoS;
+use crate::dds::qos::{QoS, QosPolicyId};
 use crate::xtypes::CompleteTypeObject;
 
 mod qos;
@@ -14,6 +14,8 @@ mod types;
 #[cfg(test)]
 mod tests;
 
+pub use types::TypeMismatch;
+
 /// Lazy-binding matcher: topic/type/QoS compatibility.
 ///
 /// Phase 4 (T0) delivers basic QoS compatibility. Phase 9 adds XTypes structural equivalence,
@@ -61,6 +63,15 @@ impl Matcher {
         qos::is_compatible(reader_qos, writer_qos)
     }
 
+    /// First Request vs Offered QoS policy the writer does not satisfy.
+    ///
+    /// `None` when the offered QoS meets every RxO policy the reader
+    /// requests (Reliability, Durability, Deadline, Ownership, Liveliness).
+    pub fn incompatible_policy(reader_qos: &QoS, writer_qos: &QoS) -> Option<QosPolicyId> {
+        crate::trace_fn!("Matcher::incompatible_policy");
+        qos::incompatible_policy(reader_qos, writer_qos)
+    }
+
     /// Check topic name compatibility (exact string match)
     ///
     /// # Returns
@@ -81,31 +92,14 @@ impl Matcher {
         topic::is_type_match(reader_type_id, writer_type_id)
     }
 
-    /// Check type compatibility using XTypes v1.3 TypeObject (Phase 9).
-    ///
-    /// Implements structural type equivalence checking via EquivalenceHash. Falls back to
-    /// type_name matching for legacy interoperability when TypeObjects are unavailable.
-    ///
-    /// # XTypes v1.3 Mode (both TypeObjects present)
-    ///
-    /// Compares EquivalenceHash (MD5-based 14-byte hash) of TypeObjects:
-    /// - Same hash -> structurally equivalent types -> **compatible**
-    /// - Different hash -> incompatible structure -> **incompatible**
+    /// Check type name compatibility, using TypeObject names when an
+    /// endpoint announces no type name.
     ///
-    /// Benefits:
-    /// - **Multi-vendor interop**: Works with FastDDS, RTI, etc.
-    /// - **Type evolution**: Compatible changes allowed (e.g., add optional fields)
-    /// - **Structural equivalence**: Match by structure, not name
-    ///
-    /// # Legacy Mode (at least one TypeObject missing)
-    ///
-    /// Falls back to simple type_name string comparison:
-    /// - Same name -> **compatible**
-    /// - Different name -> **incompatible**
-    ///
-    /// Used when:
-    /// - Remote endpoint doesn't announce TypeObject (old HDDS version)
-    /// - Local type doesn't provide TypeObject (manual DDS impl without proc-macro)
+    /// Names are compared after normalization (`IDL:` prefix and version,
+    /// `/` vs `::` separators, ROS 2 `::msg::` namespaces), so the check is
+    /// symmetric and does not look at the structure of the types; see
+    /// [`type_assignability`](Self::type_assignability) for the structural
+    /// XTypes check between a writer and a reader.
     ///
     /// # Arguments
     ///
@@ -114,11 +108,6 @@ impl Matcher {
     /// - `local_type_name`: Local type name (from TypeDescriptor)
     /// - `remote_type_name`: Remote type name (from SEDP)
     ///
-    /// # Returns
-    ///
-    /// - `true`: Types are compatible (same EquivalenceHash or same name)
-    /// - `false`: Types are incompatible
-    ///
     /// # Examples
     ///
     /// ```
@@ -152,15 +141,6 @@ impl Matcher {
     ///     "Temperature"
     /// ));
     /// ```
-    ///
-    /// # Performance
-    ///
-    /// - XTypes mode: ~100-200 ns (MD5 hash computation + comparison)
-    /// - Legacy mode: < 10 ns (string comparison)
-    ///
-    /// # Future Enhancements
-    ///
-    /// - Phase 11: Type assignability checking (implemented below)
     pub fn is_type_compatible(
         local_type_object: Option<&CompleteTypeObject>,
         remote_type_object: Option<&CompleteTypeObject>,
@@ -175,6 +155,43 @@ impl Matcher {
         )
     }
 
+    /// Check if a writer can match a reader by type (XTypes v1.3).
+    ///
+    /// Type names must be compatible (see [`is_type_compatible`](Self::is_type_compatible)).
+    /// When both TypeObjects are known, the writer type must also be
+    /// assignable to the reader type (see [`type_mismatch`](Self::type_mismatch)),
+    /// so a publisher can append members to an APPENDABLE type or add
+    /// members to a MUTABLE one without losing its existing subscribers.
+    ///
+    /// # Errors
+    /// The first mismatch found, for diagnostics.
+    pub fn type_assignability(
+        writer_type_object: Option<&CompleteTypeObject>,
+        reader_type_object: Option<&CompleteTypeObject>,
+        writer_type_name: &str,
+        reader_type_name: &str,
+    ) -> Result<(), TypeMismatch> {
+        types::type_assignability(
+            writer_type_object,
+            reader_type_object,
+            writer_type_name,
+            reader_type_name,
+        )
+    }
+
+    /// Why the writer type is not assignable to the reader type, or `None`
+    /// if it is.
+    ///
+    /// Implements the XTypes v1.3 assignability rules for structs of each
+    /// extensibility kind; other types must be structurally equivalent.
+    /// Members are compared by ID, name and TypeIdentifier.
+    pub fn type_mismatch(
+        writer_type: &CompleteTypeObject,
+        reader_type: &CompleteTypeObject,
+    ) -> Option<TypeMismatch> {
+        types::type_mismatch(writer_type, reader_type)
+    }
+
     /// Check if writer type is assignable to reader type (Phase 11).
     ///
     /// Implements XTypes v1.3 Type Assignability rules with extensibility support. Determines if
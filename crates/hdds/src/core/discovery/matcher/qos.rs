@@ -17,9 +17,10 @@
 //! | Deadline    | Writer period <= Reader period                     |
 //! | Ownership   | Must match exactly                                |
 //! | Liveliness  | Kind must match, writer lease <= reader lease      |
-//! | Partition   | Must have intersection                            |
+//! | DestinationOrder | Writer >= Reader (BySource > ByReception)    |
+//! | Partition   | Must have intersection (wildcards allowed)        |
 
-use crate::dds::qos::{Durability, History, QoS, Reliability};
+use crate::dds::qos::{Durability, History, QoS, QosPolicyId, Reliability};
 use log;
 
 /// Check QoS compatibility between offered (writer) and requested (reader)
@@ -55,9 +56,14 @@ use log;
 ///    - Kind must be identical
 ///    - Writer lease duration must be <= reader lease duration
 ///
+/// 6b. **DestinationOrder** - Writer kind >= Reader kind
+///    - BY_SOURCE_TIMESTAMP writer can match both kinds of readers
+///    - BY_RECEPTION_TIMESTAMP writer cannot match a BY_SOURCE_TIMESTAMP reader
+///
 /// 7. **Partition** - Must have at least one common partition
 ///    - Empty partitions match each other [OK]
 ///    - Non-empty partitions must intersect [OK]
+///    - fnmatch-style wildcards match names of the other side [OK]
 ///
 /// 8. **TimeBasedFilter** - Reader-side only, no compatibility check needed
 ///
@@ -73,6 +79,55 @@ use log;
 /// `true` if all policies are compatible
 pub(super) fn is_compatible(reader_qos: &QoS, writer_qos: &QoS) -> bool {
     crate::trace_fn!("qos::is_compatible");
+    // 1, 2, 4, 5, 6: Request vs Offered policies
+    if incompatible_policy(reader_qos, writer_qos).is_some() {
+        return false;
+    }
+
+    // 3. History compatibility
+    let history_ok = match (reader_qos.history, writer_qos.history) {
+        (History::KeepLast(r_keep), History::KeepLast(w_keep)) => w_keep >= r_keep,
+        (History::KeepLast(_), History::KeepAll) => true,
+        (History::KeepAll, History::KeepAll) => true,
+        (History::KeepAll, History::KeepLast(_)) => false,
+    };
+
+    if !history_ok {
+        log::debug!(
+            "[MATCH-QOS] History mismatch (writer={:?}, reader={:?})",
+            writer_qos.history,
+            reader_qos.history
+        );
+        return false;
+    }
+
+    // 7. Partition compatibility (must intersect, wildcards allowed)
+    if !writer_qos
+        .partition
+        .is_compatible_with(&reader_qos.partition)
+    {
+        log::debug!(
+            "[MATCH-QOS] Partition mismatch (writer={:?}, reader={:?})",
+            writer_qos.partition,
+            reader_qos.partition
+        );
+        return false;
+    }
+
+    // 8. TimeBasedFilter - reader-side filtering only, no compatibility check
+    // 9. ResourceLimits - local configuration, no compatibility check
+
+    true
+}
+
+/// First Request vs Offered policy the writer fails to satisfy.
+///
+/// Covers the policies reported through the incompatible QoS statuses:
+/// Reliability, Durability, Deadline, Ownership, Liveliness and
+/// DestinationOrder. History and
+/// Partition also gate [`is_compatible`], but a mismatch there is not an RxO
+/// incompatibility.
+pub(super) fn incompatible_policy(reader_qos: &QoS, writer_qos: &QoS) -> Option<QosPolicyId> {
     // 1. Reliability compatibility
     let reliability_ok = match (&writer_qos.reliability, &reader_qos.reliability) {
         (Reliability::BestEffort, Reliability::BestEffort) => true,
@@ -87,7 +142,7 @@ pub(super) fn is_compatible(reader_qos: &QoS, writer_qos: &QoS) -> bool {
             writer_qos.reliability,
             reader_qos.reliability
         );
-        return false;
+        return Some(QosPolicyId::Reliability);
     }
 
     // 2. Durability compatibility
@@ -105,24 +160,7 @@ pub(super) fn is_compatible(reader_qos: &QoS, writer_qos: &QoS) -> bool {
             writer_qos.durability,
             reader_qos.durability
         );
-        return false;
-    }
-
-    // 3. History compatibility
-    let history_ok = match (reader_qos.history, writer_qos.history) {
-        (History::KeepLast(r_keep), History::KeepLast(w_keep)) => w_keep >= r_keep,
-        (History::KeepLast(_), History::KeepAll) => true,
-        (History::KeepAll, History::KeepAll) => true,
-        (History::KeepAll, History::KeepLast(_)) => false,
-    };
-
-    if !history_ok {
-        log::debug!(
-            "[MATCH-QOS] History mismatch (writer={:?}, reader={:?})",
-            writer_qos.history,
-            reader_qos.history
-        );
-        return false;
+        return Some(QosPolicyId::Durability);
     }
 
     // 4. Deadline compatibility
@@ -133,7 +171,7 @@ pub(super) fn is_compatible(reader_qos: &QoS, writer_qos: &QoS) -> bool {
             writer_qos.deadline,
             reader_qos.deadline
         );
-        return false;
+        return Some(QosPolicyId::Deadline);
     }
 
     // 5. Ownership compatibility (must match exactly)
@@ -143,7 +181,7 @@ pub(super) fn is_compatible(reader_qos: &QoS, writer_qos: &QoS) -> bool {
             writer_qos.ownership,
             reader_qos.ownership
         );
-        return false;
+        return Some(QosPolicyId::Ownership);
     }
 
     // 6. Liveliness compatibility (kind + lease duration)
@@ -154,7 +192,7 @@ pub(super) fn is_compatible(reader_qos: &QoS, writer_qos: &QoS) -> bool {
             writer_qos.liveliness.kind,
             reader_qos.liveliness.kind
         );
-        return false;
+        return Some(QosPolicyId::Liveliness);
     }
     if writer_qos.liveliness.lease_duration > reader_qos.liveliness.lease_duration {
         log::debug!(
@@ -162,44 +200,20 @@ pub(super) fn is_compatible(reader_qos: &QoS, writer_qos: &QoS) -> bool {
             writer_qos.liveliness.lease_duration,
             reader_qos.liveliness.lease_duration
         );
-        return false;
+        return Some(QosPolicyId::Liveliness);
     }
 
-    // 7. Partition compatibility (must intersect)
-    // Both default (empty) -> compatible
-    // Either default but not both -> incompatible
-    // Both non-empty -> must have at least one common partition
-    if writer_qos.partition.is_default() && reader_qos.partition.is_default() {
-        // Both default -> compatible
-    } else if writer_qos.partition.is_default() || reader_qos.partition.is_default() {
-        // Only one default -> incompatible
+    // 6b. DestinationOrder compatibility (BY_SOURCE > BY_RECEPTION)
+    if writer_qos.destination_order.kind < reader_qos.destination_order.kind {
         log::debug!(
-            "[MATCH-QOS] Partition mismatch (writer={:?}, reader={:?})",
-            writer_qos.partition,
-            reader_qos.partition
+            "[MATCH-QOS] DestinationOrder mismatch (writer={:?}, reader={:?})",
+            writer_qos.destination_order.kind,
+            reader_qos.destination_order.kind
         );
-        return false;
-    } else {
-        // Both non-empty -> check intersection
-        let has_intersection = writer_qos
-            .partition
-            .names
-            .iter()
-            .any(|w_name| reader_qos.partition.names.contains(w_name));
-        if !has_intersection {
-            log::debug!(
-                "[MATCH-QOS] Partition mismatch (no intersection) writer={:?}, reader={:?})",
-                writer_qos.partition,
-                reader_qos.partition
-            );
-            return false;
-        }
+        return Some(QosPolicyId::DestinationOrder);
     }
 
-    // 8. TimeBasedFilter - reader-side filtering only, no compatibility check
-    // 9. ResourceLimits - local configuration, no compatibility check
-
-    true
+    None
 }
 
 #[cfg(test)]
@@ -534,4 +548,55 @@ mod tests {
         };
         assert!(!is_compatible(&reader, &writer));
     }
+
+    #[test]
+    fn test_incompatible_policy_reports_first_rxo_failure() {
+        let reader = QoS {
+            reliability: Reliability::Reliable,
+            ownership: Ownership::exclusive(),
+            ..QoS::default()
+        };
+        let writer = QoS {
+            reliability: Reliability::BestEffort,
+            ..QoS::default()
+        };
+        assert_eq!(
+            incompatible_policy(&reader, &writer),
+            Some(QosPolicyId::Reliability)
+        );
+
+        let writer = QoS {
+            reliability: Reliability::Reliable,
+            ..QoS::default()
+        };
+        assert_eq!(
+            incompatible_policy(&reader, &writer),
+            Some(QosPolicyId::Ownership)
+        );
+
+        // Partition and History mismatches do not count as RxO failures
+        let reader = QoS {
+            history: History::KeepAll,
+            partition: Partition::single("a"),
+            ..QoS::default()
+        };
+        let writer = QoS {
+            partition: Partition::single("b"),
+            ..QoS::default()
+        };
+        assert_eq!(incompatible_policy(&reader, &writer), None);
+        assert!(!is_compatible(&reader, &writer));
+    }
+
+    #[test]
+    fn test_destination_order_by_source_requires_source_writer() {
+        let reader = QoS::default().destination_order_by_source();
+        assert_eq!(
+            incompatible_policy(&reader, &QoS::default()),
+            Some(QosPolicyId::DestinationOrder)
+        );
+        let writer = QoS::default().destination_order_by_source();
+        assert!(is_compatible(&reader, &writer));
+        assert!(is_compatible(&QoS::default(), &writer));
+    }
 }
@@ -104,6 +104,7 @@ fn test_assignable_mutable_writer_has_extra_field() {
 
 #[test]
 fn test_assignable_mutable_reader_missing_field() {
+    // The reader skips writer members it does not know
     let writer = build_struct(
         StructTypeFlag::IS_MUTABLE,
         "Point",
@@ -118,7 +119,54 @@ fn test_assignable_mutable_reader_missing_field() {
         &[member(0, "x", TypeIdentifier::TK_INT32)],
     );
 
-    assert!(!Matcher::is_assignable_to(&writer, &reader));
+    assert!(Matcher::is_assignable_to(&writer, &reader));
+}
+
+#[test]
+fn test_assignable_mutable_reader_missing_must_understand_field() {
+    let mut y = member(1, "y", TypeIdentifier::TK_INT32);
+    y.flags = MemberFlag::IS_MUST_UNDERSTAND;
+    let writer = build_struct(
+        StructTypeFlag::IS_MUTABLE,
+        "Point",
+        &[member(0, "x", TypeIdentifier::TK_INT32), y],
+    );
+    let reader = build_struct(
+        StructTypeFlag::IS_MUTABLE,
+        "Point",
+        &[member(0, "x", TypeIdentifier::TK_INT32)],
+    );
+
+    assert_eq!(
+        Matcher::type_mismatch(&writer, &reader),
+        Some(TypeMismatch::NotUnderstood {
+            id: 1,
+            name: "y".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_assignable_mutable_renamed_field() {
+    let writer = build_struct(
+        StructTypeFlag::IS_MUTABLE,
+        "Point",
+        &[member(0, "x", TypeIdentifier::TK_INT32)],
+    );
+    let reader = build_struct(
+        StructTypeFlag::IS_MUTABLE,
+        "Point",
+        &[member(0, "lon", TypeIdentifier::TK_INT32)],
+    );
+
+    assert_eq!(
+        Matcher::type_mismatch(&writer, &reader),
+        Some(TypeMismatch::MemberName {
+            id: 0,
+            writer: "x".to_string(),
+            reader: "lon".to_string()
+        })
+    );
 }
 
 #[test]
@@ -151,3 +151,30 @@ fn test_type_compatible_mixed_remote_has_typeobject() {
         "Point"
     ));
 }
+
+#[test]
+fn test_type_assignability_checks_structure_and_direction() {
+    let point = make_struct("Point", &["x", "y"]);
+    let point3d = make_struct("Point", &["x", "y", "z"]);
+
+    assert_eq!(
+        Matcher::type_assignability(Some(&point), Some(&point), "Point", "Point"),
+        Ok(())
+    );
+    assert_eq!(
+        Matcher::type_assignability(Some(&point3d), Some(&point), "Point", "Point"),
+        Err(TypeMismatch::Structure)
+    );
+    // Without both TypeObjects, names decide
+    assert_eq!(
+        Matcher::type_assignability(Some(&point3d), None, "Point", "IDL:Point:1.0"),
+        Ok(())
+    );
+    assert_eq!(
+        Matcher::type_assignability(None, None, "Point", "Line"),
+        Err(TypeMismatch::TypeName {
+            writer: "Point".to_string(),
+            reader: "Line".to_string()
+        })
+    );
+}
@@ -12,11 +12,113 @@
 //! # Type Assignability Rules
 //!
 //! - **FINAL**: Requires exact structural equivalence (EquivalenceHash match)
-//! - **APPENDABLE**: Reader fields must prefix-match writer fields
-//! - **MUTABLE**: Member ID-based matching with optional field handling
+//! - **APPENDABLE**: Reader members must prefix-match writer members; the
+//!   writer may append trailing members, the reader only optional ones
+//! - **MUTABLE**: Members matched by ID in any order; the reader ignores
+//!   writer members it lacks unless they are `@must_understand`, and
+//!   defaults its own optional members the writer lacks
+//!
+//! Members matched by ID must have the same name and type, and both sides
+//! the same key members.
 
-use crate::xtypes::{CompleteStructType, CompleteTypeObject, MemberFlag, StructTypeFlag};
+use crate::xtypes::{
+    CompleteStructMember, CompleteStructType, CompleteTypeObject, MemberFlag, StructTypeFlag,
+};
 use std::borrow::Cow;
+use std::fmt;
+
+/// Why a writer type is not assignable to a reader type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeMismatch {
+    /// Type names differ.
+    TypeName { writer: String, reader: String },
+    /// FINAL / APPENDABLE / MUTABLE differ.
+    Extensibility {
+        writer: &'static str,
+        reader: &'static str,
+    },
+    /// Key members differ in ID, type or number.
+    Keys,
+    /// FINAL types (or non-struct types) are not structurally equivalent.
+    Structure,
+    /// A member has different types on both sides.
+    MemberType { id: u32, name: String },
+    /// A member ID names different members on both sides.
+    MemberName {
+        id: u32,
+        writer: String,
+        reader: String,
+    },
+    /// An APPENDABLE member is not at the same position on both sides.
+    MemberOrder { id: u32, name: String },
+    /// A non-optional reader member is missing from the writer type.
+    MissingMember { id: u32, name: String },
+    /// A `@must_understand` writer member is missing from the reader type.
+    NotUnderstood { id: u32, name: String },
+}
+
+impl fmt::Display for TypeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TypeName { writer, reader } => {
+                write!(f, "type name '{}' differs from '{}'", writer, reader)
+            }
+            Self::Extensibility { writer, reader } => write!(
+                f,
+                "extensibility differs (writer {}, reader {})",
+                writer, reader
+            ),
+            Self::Keys => write!(f, "key members differ"),
+            Self::Structure => write!(f, "structures differ"),
+            Self::MemberType { id, name } => {
+                write!(f, "member '{}' (id {}) has another type", name, id)
+            }
+            Self::MemberName { id, writer, reader } => write!(
+                f,
+                "member id {} is '{}' in the writer type but '{}' in the reader type",
+                id, writer, reader
+            ),
+            Self::MemberOrder { id, name } => {
+                write!(f, "member '{}' (id {}) moved", name, id)
+            }
+            Self::MissingMember { id, name } => write!(
+                f,
+                "non-optional reader member '{}' (id {}) is missing from the writer type",
+                name, id
+            ),
+            Self::NotUnderstood { id, name } => write!(
+                f,
+                "must-understand writer member '{}' (id {}) is missing from the reader type",
+                name, id
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TypeMismatch {}
+
+pub(super) fn type_assignability(
+    writer_type_object: Option<&CompleteTypeObject>,
+    reader_type_object: Option<&CompleteTypeObject>,
+    writer_type_name: &str,
+    reader_type_name: &str,
+) -> Result<(), TypeMismatch> {
+    if !is_type_compatible(
+        writer_type_object,
+        reader_type_object,
+        writer_type_name,
+        reader_type_name,
+    ) {
+        return Err(TypeMismatch::TypeName {
+            writer: writer_type_name.to_string(),
+            reader: reader_type_name.to_string(),
+        });
+    }
+    match (writer_type_object, reader_type_object) {
+        (Some(writer), Some(reader)) => type_mismatch(writer, reader).map_or(Ok(()), Err),
+        _ => Ok(()),
+    }
+}
 
 pub(super) fn is_type_compatible(
     local_type_object: Option<&CompleteTypeObject>,
@@ -25,12 +127,12 @@ pub(super) fn is_type_compatible(
     remote_type_name: &str,
 ) -> bool {
     let local_name = match local_type_object {
-        Some(local) if !local_type_name.is_empty() => local_type_name,
+        Some(_) if !local_type_name.is_empty() => local_type_name,
         Some(local) => type_name_from_object(local).unwrap_or(local_type_name),
         None => local_type_name,
     };
     let remote_name = match remote_type_object {
-        Some(remote) if !remote_type_name.is_empty() => remote_type_name,
+        Some(_) if !remote_type_name.is_empty() => remote_type_name,
         Some(remote) => type_name_from_object(remote).unwrap_or(remote_type_name),
         None => remote_type_name,
     };
@@ -78,46 +180,58 @@ fn normalize_type_name(name: &str) -> Cow<'_, str> {
     }
 }
 
-pub(super) fn is_assignable_to(
+pub(super) fn type_mismatch(
     writer_type: &CompleteTypeObject,
     reader_type: &CompleteTypeObject,
-) -> bool {
+) -> Option<TypeMismatch> {
     let (writer_struct, reader_struct) = match (writer_type, reader_type) {
         (CompleteTypeObject::Struct(w), CompleteTypeObject::Struct(r)) => (w, r),
-        _ => return false,
+        _ => {
+            return (writer_type.compute_equivalence_hash()
+                != reader_type.compute_equivalence_hash())
+            .then_some(TypeMismatch::Structure)
+        }
     };
 
     let writer_flags = writer_struct.struct_flags;
     let reader_flags = reader_struct.struct_flags;
 
-    if !compatible_extensibility(writer_flags, reader_flags) {
-        return false;
+    if extensibility(writer_flags) != extensibility(reader_flags) {
+        return Some(TypeMismatch::Extensibility {
+            writer: extensibility(writer_flags),
+            reader: extensibility(reader_flags),
+        });
     }
 
     if !compatible_keys(writer_struct, reader_struct) {
-        return false;
+        return Some(TypeMismatch::Keys);
     }
 
-    if writer_flags.contains(StructTypeFlag::IS_FINAL) {
-        is_assignable_final(writer_struct, reader_struct)
-    } else if writer_flags.contains(StructTypeFlag::IS_APPENDABLE) {
-        is_assignable_appendable(writer_struct, reader_struct)
+    if writer_flags.contains(StructTypeFlag::IS_APPENDABLE) {
+        appendable_mismatch(writer_struct, reader_struct)
     } else if writer_flags.contains(StructTypeFlag::IS_MUTABLE) {
-        is_assignable_mutable(writer_struct, reader_struct)
+        mutable_mismatch(writer_struct, reader_struct)
     } else {
-        is_assignable_final(writer_struct, reader_struct)
+        final_mismatch(writer_struct, reader_struct)
     }
 }
 
-fn compatible_extensibility(writer_flags: StructTypeFlag, reader_flags: StructTypeFlag) -> bool {
-    let final_match = writer_flags.contains(StructTypeFlag::IS_FINAL)
-        == reader_flags.contains(StructTypeFlag::IS_FINAL);
-    let appendable_match = writer_flags.contains(StructTypeFlag::IS_APPENDABLE)
-        == reader_flags.contains(StructTypeFlag::IS_APPENDABLE);
-    let mutable_match = writer_flags.contains(StructTypeFlag::IS_MUTABLE)
-        == reader_flags.contains(StructTypeFlag::IS_MUTABLE);
+pub(super) fn is_assignable_to(
+    writer_type: &CompleteTypeObject,
+    reader_type: &CompleteTypeObject,
+) -> bool {
+    type_mismatch(writer_type, reader_type).is_none()
+}
 
-    final_match && appendable_match && mutable_match
+/// Extensibility kind; structs without a kind flag are FINAL.
+fn extensibility(flags: StructTypeFlag) -> &'static str {
+    if flags.contains(StructTypeFlag::IS_MUTABLE) {
+        "MUTABLE"
+    } else if flags.contains(StructTypeFlag::IS_APPENDABLE) {
+        "APPENDABLE"
+    } else {
+        "FINAL"
+    }
 }
 
 fn compatible_keys(writer: &CompleteStructType, reader: &CompleteStructType) -> bool {
@@ -157,44 +271,80 @@ fn compatible_keys(writer: &CompleteStructType, reader: &CompleteStructType) ->
     true
 }
 
-fn is_assignable_final(writer: &CompleteStructType, reader: &CompleteStructType) -> bool {
+fn final_mismatch(
+    writer: &CompleteStructType,
+    reader: &CompleteStructType,
+) -> Option<TypeMismatch> {
     let writer_obj = CompleteTypeObject::Struct(writer.clone());
     let reader_obj = CompleteTypeObject::Struct(reader.clone());
 
-    writer_obj.compute_equivalence_hash() == reader_obj.compute_equivalence_hash()
+    (writer_obj.compute_equivalence_hash() != reader_obj.compute_equivalence_hash())
+        .then_some(TypeMismatch::Structure)
+}
+
+/// Mismatch between two members with the same ID.
+fn member_mismatch(
+    writer: &CompleteStructMember,
+    reader: &CompleteStructMember,
+) -> Option<TypeMismatch> {
+    let id = reader.common.member_id;
+    if writer.detail.name != reader.detail.name {
+        return Some(TypeMismatch::MemberName {
+            id,
+            writer: writer.detail.name.clone(),
+            reader: reader.detail.name.clone(),
+        });
+    }
+    (writer.common.member_type_id != reader.common.member_type_id).then(|| {
+        TypeMismatch::MemberType {
+            id,
+            name: reader.detail.name.clone(),
+        }
+    })
+}
+
+fn is_optional(member: &CompleteStructMember) -> bool {
+    member.common.member_flags.contains(MemberFlag::IS_OPTIONAL)
 }
 
-fn is_assignable_appendable(writer: &CompleteStructType, reader: &CompleteStructType) -> bool {
+fn missing_member(member: &CompleteStructMember) -> TypeMismatch {
+    TypeMismatch::MissingMember {
+        id: member.common.member_id,
+        name: member.detail.name.clone(),
+    }
+}
+
+fn appendable_mismatch(
+    writer: &CompleteStructType,
+    reader: &CompleteStructType,
+) -> Option<TypeMismatch> {
     for (i, reader_member) in reader.member_seq.iter().enumerate() {
         match writer.member_seq.get(i) {
             Some(writer_member) => {
                 if reader_member.common.member_id != writer_member.common.member_id {
-                    return false;
+                    return Some(TypeMismatch::MemberOrder {
+                        id: reader_member.common.member_id,
+                        name: reader_member.detail.name.clone(),
+                    });
                 }
-                if reader_member.common.member_type_id != writer_member.common.member_type_id {
-                    return false;
-                }
-            }
-            None => {
-                if !reader_member
-                    .common
-                    .member_flags
-                    .contains(MemberFlag::IS_OPTIONAL)
-                {
-                    return false;
+                if let Some(mismatch) = member_mismatch(writer_member, reader_member) {
+                    return Some(mismatch);
                 }
             }
+            None if is_optional(reader_member) => {}
+            None => return Some(missing_member(reader_member)),
         }
     }
 
-    true
+    None
 }
 
-fn is_assignable_mutable(writer: &CompleteStructType, reader: &CompleteStructType) -> bool {
+fn mutable_mismatch(
+    writer: &CompleteStructType,
+    reader: &CompleteStructType,
+) -> Option<TypeMismatch> {
     for reader_member in &reader.member_seq {
         let reader_id = reader_member.common.member_id;
-        let reader_type = &reader_member.common.member_type_id;
-
         let writer_match = writer
             .member_seq
             .iter()
@@ -202,41 +352,32 @@ fn is_assignable_mutable(writer: &CompleteStructType, reader: &CompleteStructTyp
 
         match writer_match {
             Some(writer_member) => {
-                if &writer_member.common.member_type_id != reader_type {
-                    return false;
+                if let Some(mismatch) = member_mismatch(writer_member, reader_member) {
+                    return Some(mismatch);
                 }
             }
-            None => {
-                if !reader_member
-                    .common
-                    .member_flags
-                    .contains(MemberFlag::IS_OPTIONAL)
-                {
-                    return false;
-                }
-            }
-        }
-    }
-
-    for writer_member in &writer.member_seq {
-        if writer_member
-            .common
-            .member_flags
-            .contains(MemberFlag::IS_OPTIONAL)
-        {
-            continue;
-        }
-
-        let writer_id = writer_member.common.member_id;
-        let reader_match = reader
-            .member_seq
-            .iter()
-            .find(|r| r.common.member_id == writer_id);
-
-        if reader_match.is_none() {
-            return false;
+            None if is_optional(reader_member) => {}
+            None => return Some(missing_member(reader_member)),
         }
     }
 
-    true
+    // Other writer members are skipped by the reader, unless it must understand them
+    writer
+        .member_seq
+        .iter()
+        .filter(|w| {
+            w.common
+                .member_flags
+                .contains(MemberFlag::IS_MUST_UNDERSTAND)
+        })
+        .find(|w| {
+            !reader
+                .member_seq
+                .iter()
+                .any(|r| r.common.member_id == w.common.member_id)
+        })
+        .map(|w| TypeMismatch::NotUnderstood {
+            id: w.common.member_id,
+            name: w.detail.name.clone(),
+        })
 }
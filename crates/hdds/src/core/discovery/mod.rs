@@ -8,9 +8,11 @@
 pub mod endpoints;
 pub mod fragment_buffer;
 pub mod guid;
+pub mod initial_peers;
 pub mod matcher;
 pub mod multicast;
 pub mod participant;
+pub mod peer_keepalive;
 pub mod replay;
 pub mod seen_table;
 // v61: Service-request builtin endpoints - MIGRATED to protocol/dialect/rti/handshake.rs
@@ -21,8 +23,10 @@ use std::fmt;
 pub use endpoints::EndpointRegistry;
 pub use fragment_buffer::FragmentBuffer;
 pub use guid::GUID;
-pub use matcher::Matcher;
+pub use initial_peers::{InitialPeer, InitialPeerSet};
+pub use matcher::{Matcher, TypeMismatch};
 pub use participant::{Discovery, NetPeer};
+pub use peer_keepalive::PeerKeepalive;
 pub use replay::{ReplayRegistry, ReplayToken};
 pub use seen_table::SeenTable;
 pub use spdp_announcer::SpdpAnnouncer;
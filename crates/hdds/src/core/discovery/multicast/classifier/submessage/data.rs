@@ -359,14 +359,16 @@ pub(in crate::core::discovery::multicast::classifier) fn calculate_payload_offse
             // writerSeqNum (8 bytes) ends at offset +24
             // Encapsulation header (4 bytes: 00 03 00 00 for CDR_LE) starts at +24
             // PID parameters start at +28
-            if matches!(kind, PacketKind::DataFrag) {
-                // DATA_FRAG: Payload (with encapsulation) starts at +36
-                // (4 submessage header + 20 standard headers + 12 fragment metadata)
-                // Fragment metadata: fragmentStartingNum(4) + fragmentsInSubmessage(2)
-                //                  + fragmentSize(2) + sampleSize(4) = 12 bytes
-                offset + 36
-            } else if octets_to_inline_qos > 0 && has_inline_qos {
+            let is_frag = matches!(kind, PacketKind::DataFrag);
+            // DATA_FRAG: Payload (with encapsulation) starts at +36
+            // (4 submessage header + 20 standard headers + 12 fragment metadata)
+            // Fragment metadata: fragmentStartingNum(4) + fragmentsInSubmessage(2)
+            //                  + fragmentSize(2) + sampleSize(4) = 12 bytes
+            let default_offset = if is_frag { offset + 36 } else { offset + 24 };
+            if octets_to_inline_qos > 0 && has_inline_qos {
                 // DATA with inline QoS: scan for PID_SENTINEL to find SerializedData start
+                // (the first DATA_FRAG of an HDDS sample carries one too, for
+                // PID_HDDS_TRACE_ID)
                 // v137: Only scan when Q flag is set - without inline QoS, data starts at offset+24
                 // Accept all RTI sentinel variants (0x0001, 0x3F01, 0x3F02, 0x3F03, 0x3F41)
                 const PID_SENTINEL: u16 = 0x0001;
@@ -405,10 +407,10 @@ pub(in crate::core::discovery::multicast::classifier) fn calculate_payload_offse
                     }
                     p += (len + 3) & !3;
                 }
-                payload_off.unwrap_or(offset + 24)
+                payload_off.unwrap_or(default_offset)
             } else {
-                // Default: encapsulation starts after standard 24-byte header
-                offset + 24
+                // Default: encapsulation starts after the standard header
+                default_offset
             }
         } else {
             offset + 24 // Fallback: assume standard header size
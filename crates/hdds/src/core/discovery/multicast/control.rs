@@ -345,6 +345,7 @@ impl ControlHandler {
         discovery_fsm: Arc<DiscoveryFsm>,
         sedp_cache: SedpCache,
     ) {
+        crate::alloc_scope!(Discovery);
         log::debug!("[CONTROL] Control handler thread started");
 
         let mut acknack_count: u32 = 1;
@@ -514,13 +515,9 @@ impl ControlHandler {
                                 );
                             }
                         } else if let Some(ref registry) = topic_registry {
-                            if !an.missing_ranges.is_empty() {
-                                let nack_msg = crate::reliability::NackMsg::from_ranges(
-                                    an.missing_ranges.clone(),
-                                );
-                                let nack_bytes = nack_msg.encode_cdr2_le();
-                                let _ = registry.deliver_nack(&nack_bytes);
-                            }
+                            // Writers parse the RTPS ACKNACK themselves (and record
+                            // the ack), so hand them the packet as received.
+                            let _ = registry.deliver_nack(msg.data());
                         }
                     } else if let Some(ref nf) = msg.nack_frag {
                         // Handle NACK_FRAG: Request for fragment retransmission
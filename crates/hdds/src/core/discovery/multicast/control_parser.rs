@@ -326,6 +326,7 @@ pub fn parse_acknack_submessage(packet: &[u8]) -> Option<AckNackInfo> {
             return Some(AckNackInfo {
                 reader_entity_id,
                 writer_entity_id,
+                bitmap_base: bitmap_base.max(0) as u64,
                 missing_ranges,
             });
         }
@@ -46,6 +46,8 @@ pub struct AckNackInfo {
     pub reader_entity_id: [u8; 4],
     /// Writer entity ID (4 bytes) - what they want us to write TO
     pub writer_entity_id: [u8; 4],
+    /// readerSNState bitmapBase: the reader received every sample before it.
+    pub bitmap_base: u64,
     /// v202: Missing sequence ranges extracted from RTPS ACKNACK bitmap.
     /// Format: Vec of [start..end) ranges where end is exclusive.
     /// Empty if reader has received all sequences (pure ACK).
@@ -12,17 +12,36 @@ use super::metrics::DiscoveryMetrics;
 use super::registry::TopicRegistry;
 use crate::core::discovery::multicast::ParticipantInfo;
 use crate::core::discovery::{EndpointRegistry, ReplayRegistry, GUID};
+use crate::core::rt::clock::{Clock, SystemClock};
 use crate::dds::qos::Durability;
+use crate::protocol::constants::RTPS_ENTITYID_PARTICIPANT;
 use crate::protocol::dialect::Dialect;
 use crate::protocol::discovery::{SedpData, SpdpData};
-use std::collections::HashMap;
+use crate::qos::metadata::UserData;
+use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicU8, Ordering};
 use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
-/// Listener for discovery events (endpoints only).
+/// Listener for discovery events.
 pub trait DiscoveryListener: Send + Sync {
     /// Called when a new endpoint is discovered.
     fn on_endpoint_discovered(&self, endpoint: EndpointInfo);
+
+    /// Called when a new remote participant is discovered through SPDP.
+    fn on_participant_discovered(&self, participant: GUID) {
+        let _ = participant;
+    }
+
+    /// Called when a remote participant is removed (lease expired or
+    /// participant disposed).
+    fn on_participant_lost(&self, participant: GUID) {
+        let _ = participant;
+    }
+
+    /// Called when a remote endpoint is disposed through SEDP.
+    fn on_endpoint_lost(&self, endpoint: EndpointInfo) {
+        let _ = endpoint;
+    }
 }
 
 /// Security validator for participant authentication (DDS Security v1.1).
@@ -57,7 +76,7 @@ pub type ParticipantDB = HashMap<GUID, ParticipantInfo>;
 ///
 /// Preference order:
 /// 1. Addresses on common DDS subnets (192.168.x.x, 10.x.x.x)
-/// 2. Any non-Docker, non-localhost address
+/// 2. Any non-Docker, non-localhost address (IPv6 outside link-local included)
 /// 3. Any non-unspecified address
 ///
 /// This handles FastDDS which announces multiple locators including Docker bridge (172.17.x.x).
@@ -97,7 +116,8 @@ fn select_best_locator(locators: &[std::net::SocketAddr]) -> Option<&std::net::S
                 // Skip Docker bridge (172.17.x.x) and localhost
                 !(octets[0] == 172 && octets[1] == 17) && octets[0] != 127
             }
-            IpAddr::V6(_) => false,
+            // Link-local (fe80::/10) needs a scope id, leave it to the last resort
+            IpAddr::V6(ipv6) => (ipv6.segments()[0] & 0xffc0) != 0xfe80,
         }
     });
     if fallback.is_some() {
@@ -108,6 +128,15 @@ fn select_best_locator(locators: &[std::net::SocketAddr]) -> Option<&std::net::S
     locators.iter().find(|addr| !addr.ip().is_unspecified())
 }
 
+/// Participants and endpoints admitted after [`DiscoveryFsm::freeze`].
+#[derive(Debug, Default)]
+struct FrozenGraph {
+    /// GUID prefixes of the participants known when discovery was frozen.
+    participants: HashSet<[u8; 12]>,
+    /// Endpoints known when discovery was frozen.
+    endpoints: HashSet<GUID>,
+}
+
 /// Discovery Finite State Machine.
 ///
 /// Manages participant database and handles SPDP/SEDP discovery packets.
@@ -151,6 +180,14 @@ pub struct DiscoveryFsm {
     security_validator: Option<Arc<dyn SecurityValidator>>,
     /// Whether to require security (reject participants without identity_token).
     require_authentication: bool,
+    /// Set once discovery is frozen: only these participants/endpoints are admitted.
+    frozen: RwLock<Option<FrozenGraph>>,
+    /// USER_DATA of the local participant, sent in SPDP replies.
+    local_user_data: UserData,
+    /// Time source of participant leases.
+    clock: Arc<dyn Clock>,
+    /// Reach peers behind a NAT at the source address of their SPDP.
+    follow_reflexive_addresses: bool,
 }
 
 impl DiscoveryFsm {
@@ -184,9 +221,30 @@ impl DiscoveryFsm {
             listeners: Arc::new(RwLock::new(Vec::new())),
             security_validator: None,
             require_authentication: false,
+            frozen: RwLock::new(None),
+            local_user_data: UserData::default(),
+            clock: SystemClock::shared(),
+            follow_reflexive_addresses: false,
         }
     }
 
+    /// Time participant leases on `clock` instead of the system clock.
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Time source of participant leases.
+    #[must_use]
+    pub fn clock(&self) -> Arc<dyn Clock> {
+        Arc::clone(&self.clock)
+    }
+
+    /// Reach peers behind a NAT at the address their SPDP comes from (see
+    /// [`resolve_reflexive_address`](Self::resolve_reflexive_address)).
+    pub fn set_follow_reflexive_addresses(&mut self, follow: bool) {
+        self.follow_reflexive_addresses = follow;
+    }
+
     /// Set security validator for participant authentication.
     ///
     /// When set, incoming SPDP participants will have their identity_token
@@ -204,6 +262,17 @@ impl DiscoveryFsm {
         self.require_authentication = require_authentication;
     }
 
+    /// Set the USER_DATA of the local participant.
+    pub fn set_local_user_data(&mut self, user_data: UserData) {
+        self.local_user_data = user_data;
+    }
+
+    /// USER_DATA of the local participant.
+    #[must_use]
+    pub fn local_user_data(&self) -> &UserData {
+        &self.local_user_data
+    }
+
     /// Get reference to participant database (for LeaseTracker).
     #[must_use]
     pub fn db(&self) -> Arc<RwLock<ParticipantDB>> {
@@ -308,6 +377,16 @@ impl DiscoveryFsm {
         }
     }
 
+    fn notify_participant(&self, event: impl Fn(&dyn DiscoveryListener)) {
+        let listeners = recover_read(
+            Arc::as_ref(&self.listeners),
+            "DiscoveryFsm::notify_participant",
+        );
+        for listener in listeners.iter() {
+            event(listener.as_ref());
+        }
+    }
+
     /// Update endpoints that match a type name with a newly received TypeObject.
     ///
     /// Returns the number of endpoints updated.
@@ -323,6 +402,62 @@ impl DiscoveryFsm {
         registry.update_type_object_for_type(type_name, &type_object)
     }
 
+    /// Freeze the discovery graph.
+    ///
+    /// Participants and endpoints known at this point keep being refreshed
+    /// (and are re-admitted if their lease expires and they come back);
+    /// any other participant or remote endpoint is ignored from now on.
+    /// Used by static deployments once their initial discovery completed.
+    ///
+    /// Returns the number of (participants, endpoints) admitted.
+    pub fn freeze(&self) -> (usize, usize) {
+        crate::trace_fn!("DiscoveryFsm::freeze");
+        let participants: HashSet<[u8; 12]> = {
+            let db = recover_read(Arc::as_ref(&self.db), "DiscoveryFsm::freeze db");
+            db.keys().map(|guid| guid.prefix).collect()
+        };
+        let endpoints: HashSet<GUID> = {
+            let registry = recover_read(
+                Arc::as_ref(&self.topic_registry),
+                "DiscoveryFsm::freeze registry",
+            );
+            registry.endpoint_guids().into_iter().collect()
+        };
+        let admitted = (participants.len(), endpoints.len());
+
+        let mut frozen = recover_write(&self.frozen, "DiscoveryFsm::freeze");
+        *frozen = Some(FrozenGraph {
+            participants,
+            endpoints,
+        });
+        log::info!(
+            "[discovery] Frozen with {} participant(s) and {} endpoint(s)",
+            admitted.0,
+            admitted.1
+        );
+        admitted
+    }
+
+    /// Whether [`freeze`](Self::freeze) has been called.
+    #[must_use]
+    pub fn is_frozen(&self) -> bool {
+        recover_read(&self.frozen, "DiscoveryFsm::is_frozen").is_some()
+    }
+
+    fn admits_participant(&self, guid: &GUID) -> bool {
+        match &*recover_read(&self.frozen, "DiscoveryFsm::admits_participant") {
+            Some(graph) => graph.participants.contains(&guid.prefix),
+            None => true,
+        }
+    }
+
+    fn admits_endpoint(&self, guid: &GUID) -> bool {
+        match &*recover_read(&self.frozen, "DiscoveryFsm::admits_endpoint") {
+            Some(graph) => graph.endpoints.contains(guid),
+            None => true,
+        }
+    }
+
     /// Handle SPDP (participant discovery) packet.
     ///
     /// Inserts new participant or refreshes existing one.
@@ -333,6 +468,7 @@ impl DiscoveryFsm {
     /// - `false` if this was a new participant (or self-discovery)
     pub fn handle_spdp(&self, data: SpdpData) -> bool {
         crate::trace_fn!("DiscoveryFsm::handle_spdp");
+        crate::alloc_scope!(Discovery);
         self.metrics.spdp_received.fetch_add(1, Ordering::Relaxed);
 
         // Ignore self-discovery.
@@ -340,6 +476,17 @@ impl DiscoveryFsm {
             return false;
         }
 
+        if !self.admits_participant(&data.participant_guid) {
+            log::debug!(
+                "[discovery] Frozen: ignoring participant {:?}",
+                data.participant_guid
+            );
+            self.metrics
+                .frozen_rejections
+                .fetch_add(1, Ordering::Relaxed);
+            return false;
+        }
+
         // DDS Security v1.1: Validate identity token if security is enabled
         if let Some(ref validator) = self.security_validator {
             match &data.identity_token {
@@ -388,19 +535,30 @@ impl DiscoveryFsm {
             // Refresh existing participant (write lock).
             let mut db = recover_write(Arc::as_ref(&self.db), "DiscoveryFsm::handle_spdp refresh");
             if let Some(info) = db.get_mut(&data.participant_guid) {
-                info.refresh();
+                info.refresh_at(self.clock.instant());
+                // Announcements parsed from fragments carry no USER_DATA: keep the last one
+                if !data.user_data.is_empty() {
+                    info.user_data = data.user_data;
+                }
+                if data.identity_token.is_some() {
+                    info.identity_token = data.identity_token;
+                }
             }
             return true; // v182: Signal this was a refresh
         } else {
             // Insert new participant (write lock).
-            let info = ParticipantInfo::new(
+            let mut info = ParticipantInfo::new(
                 data.participant_guid,
                 data.metatraffic_unicast_locators.clone(), // v79: use metatraffic for SEDP
                 data.lease_duration_ms,
             );
+            info.refresh_at(self.clock.instant());
+            info.user_data = data.user_data.clone();
+            info.identity_token = data.identity_token.clone();
 
             let mut db = recover_write(Arc::as_ref(&self.db), "DiscoveryFsm::handle_spdp insert");
             db.insert(data.participant_guid, info);
+            drop(db);
 
             // v99: FIX - Register USER DATA endpoint (port 7411) not metatraffic (port 7410)!
             // User data must be sent to default_unicast_locators per RTPS v2.3 Sec.8.5.3.1
@@ -436,10 +594,81 @@ impl DiscoveryFsm {
             self.metrics
                 .participants_discovered
                 .fetch_add(1, Ordering::Relaxed);
+            self.notify_participant(|listener| {
+                listener.on_participant_discovered(data.participant_guid)
+            });
         }
         false // v182: New participant (not a refresh)
     }
 
+    /// Point the unicast locators of a peer behind a NAT at the address its
+    /// SPDP announcement came from.
+    ///
+    /// A peer behind a NAT announces its private addresses; when `source`
+    /// is none of them, the IP of every unicast locator of `data` is
+    /// replaced by the IP of `source` (the reflexive address of the peer).
+    /// The announced ports are kept, so the NAT must preserve or forward
+    /// them. If the peer is known and its reflexive address changed (NAT
+    /// rebinding, new uplink), its locators are updated as well.
+    ///
+    /// Only applies after
+    /// [`set_follow_reflexive_addresses`](Self::set_follow_reflexive_addresses).
+    /// Returns true if a known peer moved to a new address.
+    pub fn resolve_reflexive_address(
+        &self,
+        data: &mut SpdpData,
+        source: std::net::SocketAddr,
+    ) -> bool {
+        crate::trace_fn!("DiscoveryFsm::resolve_reflexive_address");
+        if !self.follow_reflexive_addresses || data.participant_guid == self.local_guid {
+            return false;
+        }
+        let ip = source.ip();
+        let announced: Vec<_> = data
+            .metatraffic_unicast_locators
+            .iter()
+            .chain(&data.default_unicast_locators)
+            .map(|locator| locator.ip())
+            .collect();
+        if announced.is_empty() || announced.contains(&ip) {
+            return false;
+        }
+        for locator in data
+            .metatraffic_unicast_locators
+            .iter_mut()
+            .chain(&mut data.default_unicast_locators)
+        {
+            locator.set_ip(ip);
+        }
+
+        let mut db = recover_write(
+            Arc::as_ref(&self.db),
+            "DiscoveryFsm::resolve_reflexive_address",
+        );
+        let Some(info) = db.get_mut(&data.participant_guid) else {
+            return false;
+        };
+        if info.endpoints == data.metatraffic_unicast_locators {
+            return false;
+        }
+        log::info!(
+            "[discovery] Participant {:?} now reachable at {} (was {:?})",
+            data.participant_guid,
+            ip,
+            info.endpoints
+        );
+        info.endpoints = data.metatraffic_unicast_locators.clone();
+        drop(db);
+
+        let user_endpoint = select_best_locator(&data.default_unicast_locators)
+            .or_else(|| select_best_locator(&data.metatraffic_unicast_locators));
+        if let Some(&endpoint) = user_endpoint {
+            self.endpoint_registry
+                .register(data.participant_guid, endpoint);
+        }
+        true
+    }
+
     /// Handle SEDP (endpoint discovery) packet.
     ///
     /// Inserts or updates endpoint in topic registry.
@@ -447,6 +676,7 @@ impl DiscoveryFsm {
     /// Performs automatic endpoint matching (Phase 1.4).
     pub fn handle_sedp(&self, data: SedpData) {
         crate::trace_fn!("DiscoveryFsm::handle_sedp");
+        crate::alloc_scope!(Discovery);
         self.metrics.sedp_received.fetch_add(1, Ordering::Relaxed);
 
         // Extract participant prefix from endpoint GUID (first 12 bytes).
@@ -476,6 +706,18 @@ impl DiscoveryFsm {
             return;
         }
 
+        if !is_local_endpoint && !self.admits_endpoint(&data.endpoint_guid) {
+            log::debug!(
+                "[SEDP] Frozen: ignoring endpoint {:?} on '{}'",
+                data.endpoint_guid,
+                data.topic_name
+            );
+            self.metrics
+                .frozen_rejections
+                .fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+
         // Create endpoint info (auto-detects Writer vs Reader from GUID).
         // Uses locked dialect for vendor-specific QoS defaults when no PIDs present.
         let dialect = self.get_locked_dialect();
@@ -534,13 +776,20 @@ impl DiscoveryFsm {
                         type_name,
                         compatible_writers.len()
                     );
+                    // Local readers get the history from the writer's merger.
                     if is_new
+                        && !is_local_endpoint
                         && matches!(
                             endpoint_durability,
                             Durability::TransientLocal | Durability::Persistent
                         )
                     {
-                        if let Some(dest) = self.endpoint_registry.get(&endpoint_participant) {
+                        // SPDP registers the participant under ENTITYID_PARTICIPANT,
+                        // while SEDP endpoints carry a zeroed entity id.
+                        let mut prefix = [0u8; 12];
+                        prefix.copy_from_slice(&endpoint_participant.as_bytes()[..12]);
+                        let participant = GUID::new(prefix, RTPS_ENTITYID_PARTICIPANT);
+                        if let Some(dest) = self.endpoint_registry.get(&participant) {
                             self.replay_registry
                                 .replay_for(&topic_name, &type_name, dest);
                         } else {
@@ -593,6 +842,48 @@ impl DiscoveryFsm {
         db.values().cloned().collect()
     }
 
+    /// Restart the lease of every known participant.
+    ///
+    /// Called when a suspended participant resumes, so peers get a full
+    /// lease duration to be heard from again before they expire.
+    pub fn refresh_leases(&self) {
+        crate::trace_fn!("DiscoveryFsm::refresh_leases");
+        let now = self.clock.instant();
+        let mut db = recover_write(Arc::as_ref(&self.db), "DiscoveryFsm::refresh_leases");
+        for info in db.values_mut() {
+            info.refresh_at(now);
+        }
+    }
+
+    /// Remove the participants whose lease expired on the FSM clock.
+    ///
+    /// The `LeaseTracker` does this every second; a replay or test driving
+    /// a [`VirtualClock`](crate::VirtualClock) can call it right after
+    /// advancing the clock. Returns the GUIDs of the removed participants.
+    pub fn expire_leases(&self) -> Vec<GUID> {
+        crate::trace_fn!("DiscoveryFsm::expire_leases");
+        let now = self.clock.instant();
+        let expired: Vec<GUID> = {
+            let db = recover_read(Arc::as_ref(&self.db), "DiscoveryFsm::expire_leases");
+            db.values()
+                .filter(|info| info.is_expired_at(now))
+                .map(|info| info.guid)
+                .collect()
+        };
+        expired
+            .into_iter()
+            .filter(|&guid| {
+                let removed = self.drop_participant(guid);
+                if removed {
+                    self.metrics
+                        .participants_expired
+                        .fetch_add(1, Ordering::Relaxed);
+                }
+                removed
+            })
+            .collect()
+    }
+
     /// Get count of discovered participants.
     ///
     /// More efficient than `get_participants().len()` as it doesn't clone.
@@ -633,11 +924,109 @@ impl DiscoveryFsm {
         result
     }
 
+    /// Re-validate the identity token of every known participant.
+    ///
+    /// Called when the security documents change (CA certificates,
+    /// revocation list). Participants whose identity no longer validates are
+    /// removed with their endpoints, as on lease expiry; their further SPDP
+    /// announcements are rejected by [`handle_spdp`](Self::handle_spdp).
+    ///
+    /// Returns the GUIDs of the removed participants.
+    pub fn revalidate_participants(&self) -> Vec<GUID> {
+        crate::trace_fn!("DiscoveryFsm::revalidate_participants");
+        let Some(ref validator) = self.security_validator else {
+            return Vec::new();
+        };
+
+        let tokens: Vec<(GUID, Vec<u8>)> = {
+            let db = recover_read(
+                Arc::as_ref(&self.db),
+                "DiscoveryFsm::revalidate_participants",
+            );
+            db.values()
+                .filter_map(|info| Some((info.guid, info.identity_token.clone()?)))
+                .collect()
+        };
+
+        let mut revoked = Vec::new();
+        for (guid, token) in tokens {
+            if let Err(reason) = validator.validate_identity(guid, &token) {
+                log::warn!(
+                    "[discovery] Security: Revoked participant {:?} - {}",
+                    guid,
+                    reason
+                );
+                self.metrics.security_errors.fetch_add(1, Ordering::Relaxed);
+                if self.drop_participant(guid) {
+                    revoked.push(guid);
+                }
+            }
+        }
+        revoked
+    }
+
     /// Remove participant from database.
     ///
     /// Used by LeaseTracker to remove expired participants.
     pub fn remove_participant(&self, guid: GUID) {
         crate::trace_fn!("DiscoveryFsm::remove_participant");
+        if self.drop_participant(guid) {
+            self.metrics
+                .participants_expired
+                .fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Remove a participant that disposed itself through SPDP (graceful
+    /// shutdown), with its endpoints.
+    ///
+    /// Returns false if the participant was unknown.
+    pub fn dispose_participant(&self, guid: GUID) -> bool {
+        crate::trace_fn!("DiscoveryFsm::dispose_participant");
+        // Match on the prefix: the key hash may carry another entity id
+        let known = recover_read(Arc::as_ref(&self.db), "DiscoveryFsm::dispose_participant")
+            .keys()
+            .find(|known| known.prefix == guid.prefix)
+            .copied();
+        let removed = known.is_some_and(|guid| self.drop_participant(guid));
+        if removed {
+            log::debug!("[discovery] Participant {:?} disposed", guid);
+        }
+        removed
+    }
+
+    /// Remove a remote endpoint disposed through SEDP and notify listeners.
+    ///
+    /// Returns false if the endpoint was unknown.
+    pub fn dispose_endpoint(&self, guid: GUID) -> bool {
+        crate::trace_fn!("DiscoveryFsm::dispose_endpoint");
+        let removed = recover_write(
+            Arc::as_ref(&self.topic_registry),
+            "DiscoveryFsm::dispose_endpoint",
+        )
+        .remove_endpoint(&guid);
+        let Some(endpoint) = removed else {
+            return false;
+        };
+        log::debug!(
+            "[discovery] {:?} {:?} on '{}' disposed",
+            endpoint.kind,
+            guid,
+            endpoint.topic_name
+        );
+        let listeners = recover_read(
+            Arc::as_ref(&self.listeners),
+            "DiscoveryFsm::dispose_endpoint listeners",
+        );
+        for listener in listeners.iter() {
+            listener.on_endpoint_lost(endpoint.clone());
+        }
+        true
+    }
+
+    /// Remove a participant, its endpoints and locators, and notify
+    /// listeners. Returns false if the participant was unknown.
+    fn drop_participant(&self, guid: GUID) -> bool {
         // Remove from participant DB.
         let mut db = recover_write(
             Arc::as_ref(&self.db),
@@ -653,14 +1042,14 @@ impl DiscoveryFsm {
                 "DiscoveryFsm::remove_participant registry.write()",
             );
             registry.remove_participant(&guid);
+            drop(registry);
 
             // Remove from endpoint registry (v0.5.1+).
             self.endpoint_registry.remove(&guid);
 
-            self.metrics
-                .participants_expired
-                .fetch_add(1, Ordering::Relaxed);
+            self.notify_participant(|listener| listener.on_participant_lost(guid));
         }
+        removed
     }
 }
 
@@ -729,6 +1118,7 @@ mod tests {
             default_multicast_locators: vec![],
             metatraffic_multicast_locators: vec![],
             identity_token: None,
+            user_data: Default::default(),
         };
 
         fsm.handle_spdp(spdp_data);
@@ -754,6 +1144,7 @@ mod tests {
             default_multicast_locators: vec![],
             metatraffic_multicast_locators: vec![],
             identity_token: None,
+            user_data: Default::default(),
         };
 
         fsm.handle_spdp(spdp_data.clone());
@@ -778,6 +1169,63 @@ mod tests {
         assert_eq!(fsm.get_participants().len(), 1);
     }
 
+    #[test]
+    fn test_resolve_reflexive_address_follows_nat() {
+        let mut fsm = DiscoveryFsm::new(GUID::zero(), 100_000);
+        let remote_guid = sample_remote_guid(5);
+        let announce = || SpdpData {
+            participant_guid: remote_guid,
+            lease_duration_ms: 100_000,
+            domain_id: 0,
+            metatraffic_unicast_locators: vec!["192.168.1.20:7410".parse().expect("addr")],
+            default_unicast_locators: vec!["192.168.1.20:7411".parse().expect("addr")],
+            default_multicast_locators: vec![],
+            metatraffic_multicast_locators: vec![],
+            identity_token: None,
+            user_data: Default::default(),
+        };
+        let first_nat = "203.0.113.7:7410".parse().expect("addr");
+        let second_nat = "198.51.100.9:41000".parse().expect("addr");
+
+        // Disabled by default
+        let mut data = announce();
+        assert!(!fsm.resolve_reflexive_address(&mut data, first_nat));
+        assert_eq!(data, announce());
+
+        fsm.set_follow_reflexive_addresses(true);
+        let mut data = announce();
+        assert!(!fsm.resolve_reflexive_address(&mut data, first_nat));
+        assert_eq!(
+            data.metatraffic_unicast_locators,
+            ["203.0.113.7:7410".parse().expect("addr")]
+        );
+        fsm.handle_spdp(data);
+
+        // Same mapping on the next announcement
+        let mut data = announce();
+        assert!(!fsm.resolve_reflexive_address(&mut data, first_nat));
+
+        // The NAT rebound the peer to a new public address
+        let mut data = announce();
+        assert!(fsm.resolve_reflexive_address(&mut data, second_nat));
+        let participants = fsm.get_participants();
+        assert_eq!(
+            participants[0].endpoints,
+            ["198.51.100.9:7410".parse().expect("addr")]
+        );
+        assert_eq!(
+            fsm.endpoint_registry().get(&remote_guid),
+            Some("198.51.100.9:7411".parse().expect("addr"))
+        );
+
+        // Announced addresses are kept for peers reached directly
+        let mut data = announce();
+        assert!(
+            !fsm.resolve_reflexive_address(&mut data, "192.168.1.20:7410".parse().expect("addr"))
+        );
+        assert_eq!(data, announce());
+    }
+
     #[test]
     fn test_handle_spdp_ignore_self() {
         let local_guid = sample_remote_guid(4);
@@ -792,6 +1240,7 @@ mod tests {
             default_multicast_locators: vec![],
             metatraffic_multicast_locators: vec![],
             identity_token: None,
+            user_data: Default::default(),
         };
 
         fsm.handle_spdp(spdp_data);
@@ -813,6 +1262,7 @@ mod tests {
             default_multicast_locators: vec![],
             metatraffic_multicast_locators: vec![],
             identity_token: None,
+            user_data: Default::default(),
         };
 
         fsm.handle_spdp(spdp_data);
@@ -822,6 +1272,138 @@ mod tests {
         assert!(fsm.get_participants().is_empty());
     }
 
+    #[test]
+    fn test_listener_participant_events() {
+        #[derive(Default)]
+        struct Events(std::sync::Mutex<Vec<(bool, GUID)>>);
+
+        impl DiscoveryListener for Events {
+            fn on_endpoint_discovered(&self, _endpoint: EndpointInfo) {}
+
+            fn on_participant_discovered(&self, participant: GUID) {
+                self.0.lock().expect("events").push((true, participant));
+            }
+
+            fn on_participant_lost(&self, participant: GUID) {
+                self.0.lock().expect("events").push((false, participant));
+            }
+        }
+
+        let fsm = DiscoveryFsm::new(GUID::zero(), 100_000);
+        let events = Arc::new(Events::default());
+        fsm.register_listener(events.clone());
+
+        let remote_guid = sample_remote_guid(6);
+        let spdp_data = SpdpData {
+            participant_guid: remote_guid,
+            lease_duration_ms: 100_000,
+            domain_id: 0,
+            metatraffic_unicast_locators: vec![],
+            default_unicast_locators: vec![],
+            default_multicast_locators: vec![],
+            metatraffic_multicast_locators: vec![],
+            identity_token: None,
+            user_data: Default::default(),
+        };
+        fsm.handle_spdp(spdp_data.clone());
+        // Refreshes are not reported again
+        fsm.handle_spdp(spdp_data);
+        fsm.remove_participant(remote_guid);
+        fsm.remove_participant(remote_guid);
+
+        assert_eq!(
+            *events.0.lock().expect("events"),
+            vec![(true, remote_guid), (false, remote_guid)]
+        );
+    }
+
+    #[test]
+    fn test_revalidate_participants_removes_revoked() {
+        /// Accepts every token except those listed as revoked
+        #[derive(Default)]
+        struct Revocations(std::sync::Mutex<Vec<Vec<u8>>>);
+
+        impl SecurityValidator for Revocations {
+            fn validate_identity(&self, _guid: GUID, token: &[u8]) -> Result<(), String> {
+                if self.0.lock().expect("revoked").iter().any(|t| t == token) {
+                    return Err("revoked".to_string());
+                }
+                Ok(())
+            }
+        }
+
+        let revocations = Arc::new(Revocations::default());
+        let mut fsm = DiscoveryFsm::new(GUID::zero(), 100_000);
+        fsm.set_security_validator(revocations.clone(), true);
+
+        let announce = |guid: GUID, token: &[u8]| SpdpData {
+            participant_guid: guid,
+            lease_duration_ms: 100_000,
+            domain_id: 0,
+            metatraffic_unicast_locators: vec![],
+            default_unicast_locators: vec![],
+            default_multicast_locators: vec![],
+            metatraffic_multicast_locators: vec![],
+            identity_token: Some(token.to_vec()),
+            user_data: Default::default(),
+        };
+        let alice = sample_remote_guid(10);
+        let mallory = sample_remote_guid(40);
+        fsm.handle_spdp(announce(alice, b"alice"));
+        fsm.handle_spdp(announce(mallory, b"mallory"));
+        assert_eq!(fsm.participant_count(), 2);
+        assert!(fsm.revalidate_participants().is_empty());
+
+        revocations
+            .0
+            .lock()
+            .expect("revoked")
+            .push(b"mallory".to_vec());
+        assert_eq!(fsm.revalidate_participants(), vec![mallory]);
+        assert_eq!(fsm.participant_count(), 1);
+        assert_eq!(fsm.metrics.security_errors(), 1);
+
+        // The revoked participant cannot come back
+        assert!(!fsm.handle_spdp(announce(mallory, b"mallory")));
+        assert_eq!(fsm.participant_count(), 1);
+    }
+
+    #[test]
+    fn test_expire_leases_follows_clock() {
+        let clock = Arc::new(crate::VirtualClock::new());
+        let mut fsm = DiscoveryFsm::new(GUID::zero(), 100_000);
+        fsm.set_clock(clock.clone());
+
+        let announce = |guid: GUID, lease_duration_ms: u64| SpdpData {
+            participant_guid: guid,
+            lease_duration_ms,
+            domain_id: 0,
+            metatraffic_unicast_locators: vec![],
+            default_unicast_locators: vec![],
+            default_multicast_locators: vec![],
+            metatraffic_multicast_locators: vec![],
+            identity_token: None,
+            user_data: Default::default(),
+        };
+        let short = sample_remote_guid(10);
+        let long = sample_remote_guid(40);
+        fsm.handle_spdp(announce(short, 10_000));
+        fsm.handle_spdp(announce(long, 30_000));
+
+        clock.advance(std::time::Duration::from_secs(10));
+        assert!(fsm.expire_leases().is_empty());
+
+        // A refresh restarts the lease at the current virtual time
+        fsm.handle_spdp(announce(long, 30_000));
+        clock.advance(std::time::Duration::from_secs(25));
+        assert_eq!(fsm.expire_leases(), vec![short]);
+        assert_eq!(fsm.metrics.snapshot().3, 1);
+
+        clock.advance(std::time::Duration::from_secs(6));
+        assert_eq!(fsm.expire_leases(), vec![long]);
+        assert_eq!(fsm.participant_count(), 0);
+    }
+
     #[test]
     fn test_handle_sedp_ignores_unknown_participant() {
         let local_guid = GUID::zero();
@@ -837,6 +1419,8 @@ mod tests {
             type_object: None,
             unicast_locators: vec![],
             user_data: None,
+            type_versions: None,
+            metadata: Default::default(),
         };
 
         fsm.handle_sedp(sedp_data);
@@ -858,6 +1442,7 @@ mod tests {
             default_multicast_locators: vec![],
             metatraffic_multicast_locators: vec![],
             identity_token: None,
+            user_data: Default::default(),
         };
         fsm.handle_spdp(spdp_data);
 
@@ -873,6 +1458,8 @@ mod tests {
             type_object: None,
             unicast_locators: vec![],
             user_data: None,
+            type_versions: None,
+            metadata: Default::default(),
         };
 
         fsm.handle_sedp(sedp_data);
@@ -880,6 +1467,124 @@ mod tests {
         let writers = fsm.find_writers_for_topic("sensor/temp");
         assert_eq!(writers.len(), 1);
         assert_eq!(writers[0].type_name, "Temperature");
+
+        // Disposed through SEDP: removed at once
+        assert!(fsm.dispose_endpoint(GUID::from_bytes(endpoint_guid_bytes)));
+        assert!(!fsm.dispose_endpoint(GUID::from_bytes(endpoint_guid_bytes)));
+        assert!(fsm.find_writers_for_topic("sensor/temp").is_empty());
+        assert!(fsm.dispose_participant(remote_guid));
+        assert_eq!(fsm.participant_count(), 0);
+    }
+
+    #[test]
+    fn test_freeze_admits_only_known_graph() {
+        let fsm = DiscoveryFsm::new(GUID::zero(), 100_000);
+        let spdp = |guid: GUID| SpdpData {
+            participant_guid: guid,
+            lease_duration_ms: 100_000,
+            domain_id: 0,
+            metatraffic_unicast_locators: vec![],
+            default_unicast_locators: vec![],
+            default_multicast_locators: vec![],
+            metatraffic_multicast_locators: vec![],
+            identity_token: None,
+            user_data: Default::default(),
+        };
+        let writer = |participant: GUID, key: u8| {
+            let mut bytes = participant.as_bytes();
+            bytes[14] = key;
+            bytes[15] = 0x02; // writer
+            SedpData {
+                topic_name: "sensor/temp".to_string(),
+                type_name: "Temperature".to_string(),
+                participant_guid: GUID::zero(),
+                endpoint_guid: GUID::from_bytes(bytes),
+                qos_hash: 0,
+                qos: None,
+                type_object: None,
+                unicast_locators: vec![],
+                user_data: None,
+                type_versions: None,
+                metadata: Default::default(),
+            }
+        };
+
+        let known = sample_remote_guid(20);
+        fsm.handle_spdp(spdp(known));
+        fsm.handle_sedp(writer(known, 1));
+        assert_eq!(fsm.freeze(), (1, 1));
+        assert!(fsm.is_frozen());
+
+        // New participants and new endpoints of known ones are ignored
+        fsm.handle_spdp(spdp(sample_remote_guid(40)));
+        fsm.handle_sedp(writer(known, 2));
+        assert_eq!(fsm.participant_count(), 1);
+        assert_eq!(fsm.find_writers_for_topic("sensor/temp").len(), 1);
+        assert_eq!(fsm.metrics.frozen_rejections(), 2);
+
+        // A known participant coming back after lease expiry is re-admitted
+        fsm.remove_participant(known);
+        fsm.handle_spdp(spdp(known));
+        fsm.handle_sedp(writer(known, 1));
+        assert_eq!(fsm.participant_count(), 1);
+        assert_eq!(fsm.find_writers_for_topic("sensor/temp").len(), 1);
+    }
+
+    #[test]
+    fn test_handle_sedp_transient_local_reader_triggers_replay() {
+        let fsm = DiscoveryFsm::new(GUID::zero(), 100_000);
+
+        let mut prefix = [0u8; 12];
+        prefix.copy_from_slice(&sample_remote_guid(9).as_bytes()[..12]);
+        let user_locator: std::net::SocketAddr = "192.168.1.50:7411"
+            .parse()
+            .expect("Socket address parsing should succeed");
+        fsm.handle_spdp(SpdpData {
+            participant_guid: GUID::new(prefix, RTPS_ENTITYID_PARTICIPANT),
+            lease_duration_ms: 100_000,
+            domain_id: 0,
+            metatraffic_unicast_locators: vec![],
+            default_unicast_locators: vec![user_locator],
+            default_multicast_locators: vec![],
+            metatraffic_multicast_locators: vec![],
+            identity_token: None,
+            user_data: Default::default(),
+        });
+
+        let replayed = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = Arc::clone(&replayed);
+        let _token = fsm.replay_registry().register(
+            "map",
+            "OccupancyGrid",
+            Arc::new(move |dest: std::net::SocketAddr| {
+                sink.lock().expect("Mutex lock should succeed").push(dest);
+            }),
+        );
+
+        let sedp_reader = |entity: u8, qos: crate::dds::QoS| SedpData {
+            topic_name: "map".to_string(),
+            type_name: "OccupancyGrid".to_string(),
+            participant_guid: GUID::zero(), // Test data
+            endpoint_guid: GUID::new(prefix, [0x00, 0x00, entity, 0x04]),
+            qos_hash: 0,
+            qos: Some(qos),
+            type_object: None,
+            unicast_locators: vec![],
+            user_data: None,
+            type_versions: None,
+            metadata: Default::default(),
+        };
+
+        fsm.handle_sedp(sedp_reader(1, crate::dds::QoS::reliable()));
+        fsm.handle_sedp(sedp_reader(
+            2,
+            crate::dds::QoS::reliable().transient_local(),
+        ));
+
+        assert_eq!(
+            *replayed.lock().expect("Mutex lock should succeed"),
+            vec![user_locator]
+        );
     }
 
     #[test]
@@ -897,6 +1602,7 @@ mod tests {
             default_multicast_locators: vec![],
             metatraffic_multicast_locators: vec![],
             identity_token: None,
+            user_data: Default::default(),
         };
         fsm.handle_spdp(spdp_data.clone());
         fsm.handle_spdp(spdp_data);
@@ -6,10 +6,12 @@
 //! Defines `EndpointInfo` for storing discovered DataWriter/DataReader metadata
 //! and `EndpointKind` for distinguishing writers from readers via RTPS entity ID.
 
-use crate::core::discovery::GUID;
+use crate::core::discovery::{Matcher, TypeMismatch, GUID};
 use crate::protocol::dialect::{get_encoder, Dialect};
 use crate::protocol::discovery::SedpData;
+use crate::protocol::type_version::TypeVersionAnnouncement;
 use crate::xtypes::CompleteTypeObject;
+use std::net::SocketAddr;
 
 /// Endpoint kind (Writer or Reader).
 ///
@@ -116,6 +118,14 @@ pub struct EndpointInfo {
     /// - Primitive types (no complex structure)
     /// - Endpoints that opt out of type discovery
     pub type_object: Option<CompleteTypeObject>,
+    /// Side-by-side type versions announced by an HDDS endpoint.
+    ///
+    /// A remote endpoint matches if the local type is compatible with its
+    /// native type or with any of the announced versions.
+    pub type_versions: Option<TypeVersionAnnouncement>,
+    /// Unicast locators announced by the endpoint (empty when it is reached
+    /// through the locators of its participant).
+    pub unicast_locators: Vec<SocketAddr>,
 }
 
 impl EndpointInfo {
@@ -152,6 +162,8 @@ impl EndpointInfo {
     ///     type_object: None,
     ///     unicast_locators: vec![],
     ///     user_data: None,
+    ///     type_versions: None,
+    ///     metadata: Default::default(),
     /// };
     ///
     /// let endpoint = EndpointInfo::from_sedp(sedp_data, Some(Dialect::Rti));
@@ -169,7 +181,7 @@ impl EndpointInfo {
         let participant_guid = GUID::from_bytes(participant_bytes);
 
         // Apply QoS: use explicit PIDs if present, otherwise get dialect-specific defaults
-        let qos = if let Some(qos) = sedp_data.qos {
+        let mut qos = if let Some(qos) = sedp_data.qos {
             log::debug!("[ENDPOINT] Using QoS from SEDP PIDs: reliability={:?}, durability={:?}, history={:?}",
                       qos.reliability, qos.durability, qos.history);
             qos
@@ -182,6 +194,8 @@ impl EndpointInfo {
                       encoder.name(), default_qos.reliability, default_qos.durability, default_qos.history);
             default_qos
         };
+        // USER_DATA / TOPIC_DATA / GROUP_DATA are announced apart from the policy PIDs
+        sedp_data.metadata.apply_to(&mut qos);
 
         Self {
             endpoint_guid,
@@ -191,8 +205,59 @@ impl EndpointInfo {
             qos,
             kind,
             type_object: sedp_data.type_object,
+            type_versions: sedp_data.type_versions,
+            unicast_locators: sedp_data.unicast_locators,
         }
     }
+
+    /// Whether this endpoint matches the local type directly or through one
+    /// of its announced side-by-side type versions.
+    #[must_use]
+    pub(crate) fn matches_type(
+        &self,
+        local_type_object: Option<&CompleteTypeObject>,
+        local_type_name: &str,
+    ) -> bool {
+        self.type_mismatch(local_type_object, local_type_name)
+            .is_none()
+    }
+
+    /// Why this endpoint matches the local type neither directly nor
+    /// through one of its type versions (the mismatch of its main type),
+    /// or `None` when it matches.
+    ///
+    /// The local endpoint is of the other kind: a remote writer's type must
+    /// be assignable to the local reader's, the local writer's type to a
+    /// remote reader's.
+    #[must_use]
+    pub(crate) fn type_mismatch(
+        &self,
+        local_type_object: Option<&CompleteTypeObject>,
+        local_type_name: &str,
+    ) -> Option<TypeMismatch> {
+        let check = |type_object: Option<&CompleteTypeObject>, type_name: &str| match self.kind {
+            EndpointKind::Writer => Matcher::type_assignability(
+                type_object,
+                local_type_object,
+                type_name,
+                local_type_name,
+            ),
+            EndpointKind::Reader => Matcher::type_assignability(
+                local_type_object,
+                type_object,
+                local_type_name,
+                type_name,
+            ),
+        };
+        let mismatch = check(self.type_object.as_ref(), &self.type_name).err()?;
+        let version_matches = self.type_versions.as_ref().is_some_and(|versions| {
+            versions
+                .versions
+                .iter()
+                .any(|v| check(v.type_object.as_ref(), &v.type_name).is_ok())
+        });
+        (!version_matches).then_some(mismatch)
+    }
 }
 
 #[cfg(test)]
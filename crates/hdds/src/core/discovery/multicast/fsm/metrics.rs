@@ -25,6 +25,8 @@ pub struct DiscoveryMetrics {
     pub parse_errors: AtomicU64,
     /// Security validation errors (rejected participants).
     pub security_errors: AtomicU64,
+    /// Participants/endpoints ignored because discovery is frozen.
+    pub frozen_rejections: AtomicU64,
 }
 
 impl DiscoveryMetrics {
@@ -38,6 +40,7 @@ impl DiscoveryMetrics {
             participants_expired: AtomicU64::new(0),
             parse_errors: AtomicU64::new(0),
             security_errors: AtomicU64::new(0),
+            frozen_rejections: AtomicU64::new(0),
         }
     }
 
@@ -62,6 +65,12 @@ impl DiscoveryMetrics {
     pub fn security_errors(&self) -> u64 {
         self.security_errors.load(Ordering::Relaxed)
     }
+
+    /// Get count of participants/endpoints ignored after discovery was frozen.
+    #[must_use]
+    pub fn frozen_rejections(&self) -> u64 {
+        self.frozen_rejections.load(Ordering::Relaxed)
+    }
 }
 
 impl Default for DiscoveryMetrics {
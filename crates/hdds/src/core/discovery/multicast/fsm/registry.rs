@@ -8,7 +8,7 @@
 //! to find compatible local/remote endpoint pairs for data delivery.
 
 use super::endpoint::{EndpointInfo, EndpointKind};
-use crate::core::discovery::{Matcher, GUID};
+use crate::core::discovery::GUID;
 use crate::xtypes::CompleteTypeObject;
 use std::collections::HashMap;
 
@@ -147,13 +147,7 @@ impl TopicRegistry {
                     .iter()
                     .filter(|e| e.kind == EndpointKind::Writer)
                     .filter(|e| {
-                        // Existing matching logic (unchanged)
-                        let compatible = Matcher::is_type_compatible(
-                            local_type_object,
-                            e.type_object.as_ref(),
-                            local_type_name,
-                            &e.type_name,
-                        );
+                        let compatible = e.matches_type(local_type_object, local_type_name);
 
                         // Optional diagnostics
                         if let Some(matcher) = diag_matcher.as_mut() {
@@ -236,12 +230,7 @@ impl TopicRegistry {
                     .iter()
                     .filter(|e| e.kind == EndpointKind::Reader)
                     .filter(|e| {
-                        let compatible = Matcher::is_type_compatible(
-                            local_type_object,
-                            e.type_object.as_ref(),
-                            local_type_name,
-                            &e.type_name,
-                        );
+                        let compatible = e.matches_type(local_type_object, local_type_name);
 
                         if let Some(matcher) = diag_matcher.as_mut() {
                             use crate::interop::matching::{
@@ -314,6 +303,32 @@ impl TopicRegistry {
         removed
     }
 
+    /// Remove the endpoint `endpoint_guid`, disposed by its participant.
+    ///
+    /// Returns the removed endpoint, or `None` if it was unknown.
+    pub fn remove_endpoint(&mut self, endpoint_guid: &GUID) -> Option<EndpointInfo> {
+        crate::trace_fn!("TopicRegistry::remove_endpoint");
+        for endpoints in self.topics.values_mut() {
+            if let Some(index) = endpoints
+                .iter()
+                .position(|endpoint| &endpoint.endpoint_guid == endpoint_guid)
+            {
+                return Some(endpoints.swap_remove(index));
+            }
+        }
+        None
+    }
+
+    /// GUIDs of all endpoints in the registry.
+    #[must_use]
+    pub fn endpoint_guids(&self) -> Vec<GUID> {
+        self.topics
+            .values()
+            .flatten()
+            .map(|endpoint| endpoint.endpoint_guid)
+            .collect()
+    }
+
     /// Get all topic names currently in the registry.
     ///
     /// Returns a vector of topic names for iteration.
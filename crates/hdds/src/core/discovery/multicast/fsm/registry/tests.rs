@@ -61,6 +61,8 @@ fn make_endpoint(
         qos: crate::dds::qos::QoS::rti_defaults(), // v61: Use actual QoS object
         kind,
         type_object: type_obj,
+        type_versions: None,
+        unicast_locators: Vec::new(),
     }
 }
 
@@ -183,6 +185,8 @@ fn test_topic_registry_remove_participant() {
         qos: crate::dds::qos::QoS::rti_defaults(), // v61: Use actual QoS object
         kind: EndpointKind::Writer,
         type_object: None,
+        type_versions: None,
+        unicast_locators: Vec::new(),
     });
     registry.insert(EndpointInfo {
         endpoint_guid: participant2,
@@ -192,6 +196,8 @@ fn test_topic_registry_remove_participant() {
         qos: crate::dds::qos::QoS::rti_defaults(), // v61: Use actual QoS object
         kind: EndpointKind::Writer,
         type_object: None,
+        type_versions: None,
+        unicast_locators: Vec::new(),
     });
 
     let removed = registry.remove_participant(&participant1);
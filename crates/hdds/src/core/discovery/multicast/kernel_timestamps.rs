@@ -0,0 +1,161 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Kernel receive timestamps for UDP listeners.
+//!
+//! # Linux
+//!
+//! `SO_TIMESTAMPNS` makes the kernel attach the time it received each
+//! datagram (CLOCK_REALTIME, taken in the network stack before the listener
+//! thread is scheduled) as a control message, read with `recvmsg()`.
+//!
+//! # Other platforms
+//!
+//! Not implemented: packets are received with `recv_from()` and carry no
+//! kernel timestamp.
+
+use std::io;
+use std::net::SocketAddr;
+
+/// Ask the kernel to timestamp received datagrams.
+#[cfg(target_os = "linux")]
+pub fn enable(socket: &mio::net::UdpSocket) -> io::Result<()> {
+    use std::os::unix::io::AsRawFd;
+
+    let val: libc::c_int = 1;
+    // SAFETY:
+    // - the fd belongs to `socket`, which outlives the call
+    // - val is a properly initialized c_int on the stack
+    // - SO_TIMESTAMPNS is a valid SOL_SOCKET option taking an int flag
+    let ret = unsafe {
+        libc::setsockopt(
+            socket.as_raw_fd(),
+            libc::SOL_SOCKET,
+            libc::SO_TIMESTAMPNS,
+            &val as *const _ as *const libc::c_void,
+            std::mem::size_of::<libc::c_int>() as libc::socklen_t,
+        )
+    };
+
+    if ret < 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(())
+    }
+}
+
+/// Ask the kernel to timestamp received datagrams (no-op on this platform).
+#[cfg(not(target_os = "linux"))]
+pub fn enable(_socket: &mio::net::UdpSocket) -> io::Result<()> {
+    Ok(())
+}
+
+/// Control message buffer, aligned for `cmsghdr`.
+#[cfg(target_os = "linux")]
+#[repr(C, align(8))]
+struct CmsgBuf([u8; 64]);
+
+/// Receive one datagram with its kernel receive timestamp (nanoseconds
+/// since the UNIX epoch), if the socket provides one.
+#[cfg(target_os = "linux")]
+pub fn recv_from(
+    socket: &mio::net::UdpSocket,
+    buf: &mut [u8],
+) -> io::Result<(usize, SocketAddr, Option<u64>)> {
+    use std::os::unix::io::AsRawFd;
+
+    let fd = socket.as_raw_fd();
+    let mut cmsg = CmsgBuf([0u8; 64]);
+    let mut iov = libc::iovec {
+        iov_base: buf.as_mut_ptr().cast(),
+        iov_len: buf.len(),
+    };
+    let mut kernel_rx_ns = None;
+
+    // SAFETY:
+    // - try_init hands us a zeroed sockaddr_storage and its length, and only
+    //   reads the address back once we return Ok with the length set
+    // - iov points to `buf`, and msg_control to `cmsg`, both live for the call
+    // - cmsg traversal uses the libc CMSG_* macros bounded by msg_controllen,
+    //   and the timespec is read unaligned
+    let (len, addr) = unsafe {
+        socket2::SockAddr::try_init(|storage, addr_len| {
+            let mut msg: libc::msghdr = std::mem::zeroed();
+            msg.msg_name = storage.cast();
+            msg.msg_namelen = *addr_len;
+            msg.msg_iov = &mut iov;
+            msg.msg_iovlen = 1;
+            msg.msg_control = cmsg.0.as_mut_ptr().cast();
+            msg.msg_controllen = cmsg.0.len() as _;
+
+            let received = libc::recvmsg(fd, &mut msg, 0);
+            if received < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            *addr_len = msg.msg_namelen;
+
+            let mut header = libc::CMSG_FIRSTHDR(&msg);
+            while !header.is_null() {
+                if (*header).cmsg_level == libc::SOL_SOCKET
+                    && (*header).cmsg_type == libc::SCM_TIMESTAMPNS
+                {
+                    let ts =
+                        std::ptr::read_unaligned(libc::CMSG_DATA(header) as *const libc::timespec);
+                    kernel_rx_ns = timespec_to_ns(&ts);
+                }
+                header = libc::CMSG_NXTHDR(&msg, header);
+            }
+
+            Ok(received as usize)
+        })?
+    };
+
+    let addr = addr
+        .as_socket()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "non-IP source address"))?;
+    Ok((len, addr, kernel_rx_ns))
+}
+
+/// Receive one datagram (no kernel timestamp on this platform).
+#[cfg(not(target_os = "linux"))]
+pub fn recv_from(
+    socket: &mio::net::UdpSocket,
+    buf: &mut [u8],
+) -> io::Result<(usize, SocketAddr, Option<u64>)> {
+    socket.recv_from(buf).map(|(len, addr)| (len, addr, None))
+}
+
+#[cfg(target_os = "linux")]
+fn timespec_to_ns(ts: &libc::timespec) -> Option<u64> {
+    let sec = u64::try_from(ts.tv_sec).ok()?;
+    let nsec = u64::try_from(ts.tv_nsec).ok()?;
+    Some(sec * 1_000_000_000 + nsec)
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn test_recv_from_reports_kernel_timestamp() {
+        let std_rx = std::net::UdpSocket::bind("127.0.0.1:0").expect("bind rx");
+        let rx = mio::net::UdpSocket::from_std(std_rx);
+        enable(&rx).expect("SO_TIMESTAMPNS");
+
+        let tx = std::net::UdpSocket::bind("127.0.0.1:0").expect("bind tx");
+        let before = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock")
+            .as_nanos() as u64;
+        tx.send_to(b"stamp", rx.local_addr().expect("addr"))
+            .expect("send");
+
+        let mut buf = [0u8; 16];
+        let (len, src, kernel_rx_ns) = recv_from(&rx, &mut buf).expect("recv");
+        assert_eq!(&buf[..len], b"stamp");
+        assert_eq!(src, tx.local_addr().expect("addr"));
+        let kernel_rx_ns = kernel_rx_ns.expect("kernel timestamp");
+        assert!(kernel_rx_ns >= before);
+    }
+}
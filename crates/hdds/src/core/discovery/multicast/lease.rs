@@ -8,6 +8,7 @@
 
 use crate::core::discovery::multicast::ParticipantDB;
 use crate::core::discovery::GUID;
+use crate::core::rt::clock::{Clock, SystemClock};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
 use std::thread::{self, JoinHandle};
@@ -29,6 +30,8 @@ use std::time::Duration;
 pub struct LeaseTracker {
     /// Stop flag for graceful shutdown
     stop_flag: Arc<AtomicBool>,
+    /// Expiry checks are skipped while set
+    paused: Arc<AtomicBool>,
     /// Background thread handle
     handle: Option<JoinHandle<()>>,
 }
@@ -58,40 +61,96 @@ impl LeaseTracker {
     /// tracker.stop(); // Graceful shutdown
     /// ```
     pub fn start(db: Arc<RwLock<ParticipantDB>>) -> std::io::Result<Self> {
+        Self::spawn(db, SystemClock::shared(), None)
+    }
+
+    /// Start lease tracker with a custom expiry handler
+    ///
+    /// `on_expired` is called for each expired participant instead of removing
+    /// it from the database, so the caller can also drop its endpoints and
+    /// notify listeners (see `DiscoveryFsm::remove_participant`).
+    pub fn start_with_handler(
+        db: Arc<RwLock<ParticipantDB>>,
+        on_expired: Box<dyn Fn(GUID) + Send>,
+    ) -> std::io::Result<Self> {
+        Self::spawn(db, SystemClock::shared(), Some(on_expired))
+    }
+
+    /// Start lease tracker with a custom expiry handler, timing leases on
+    /// `clock` (see `DiscoveryFsm::clock`)
+    ///
+    /// The check still runs every second of wall time.
+    pub fn start_with_clock(
+        db: Arc<RwLock<ParticipantDB>>,
+        clock: Arc<dyn Clock>,
+        on_expired: Box<dyn Fn(GUID) + Send>,
+    ) -> std::io::Result<Self> {
+        Self::spawn(db, clock, Some(on_expired))
+    }
+
+    fn spawn(
+        db: Arc<RwLock<ParticipantDB>>,
+        clock: Arc<dyn Clock>,
+        on_expired: Option<Box<dyn Fn(GUID) + Send>>,
+    ) -> std::io::Result<Self> {
         let stop_flag = Arc::new(AtomicBool::new(false));
         let stop_flag_clone = Arc::clone(&stop_flag);
+        let paused = Arc::new(AtomicBool::new(false));
+        let paused_clone = Arc::clone(&paused);
 
         let handle = thread::Builder::new()
             .name("hdds-lease-tracker".to_string())
             .spawn(move || {
-                Self::run_loop(db, stop_flag_clone);
+                Self::run_loop(
+                    db,
+                    clock.as_ref(),
+                    stop_flag_clone,
+                    paused_clone,
+                    on_expired,
+                );
             })?;
 
         Ok(Self {
             stop_flag,
+            paused,
             handle: Some(handle),
         })
     }
 
     /// Main loop (runs in background thread)
-    fn run_loop(db: Arc<RwLock<ParticipantDB>>, stop_flag: Arc<AtomicBool>) {
+    fn run_loop(
+        db: Arc<RwLock<ParticipantDB>>,
+        clock: &dyn Clock,
+        stop_flag: Arc<AtomicBool>,
+        paused: Arc<AtomicBool>,
+        on_expired: Option<Box<dyn Fn(GUID) + Send>>,
+    ) {
+        crate::alloc_scope!(Discovery);
         while !stop_flag.load(Ordering::Relaxed) {
             // Sleep 1 second (1 Hz check rate)
             thread::sleep(Duration::from_secs(1));
+            if paused.load(Ordering::Relaxed) {
+                continue;
+            }
 
             // Check for expired participants
+            let now = clock.instant();
             let expired_guids: Vec<GUID> = {
                 let db_guard =
                     recover_read(Arc::as_ref(&db), "LeaseTracker::run_loop collect expired");
                 db_guard
                     .iter()
-                    .filter(|(_, info)| info.is_expired())
+                    .filter(|(_, info)| info.is_expired_at(now))
                     .map(|(guid, _)| *guid)
                     .collect()
             };
 
             // Remove expired participants
-            if !expired_guids.is_empty() {
+            if let Some(ref on_expired) = on_expired {
+                for guid in expired_guids {
+                    on_expired(guid);
+                }
+            } else if !expired_guids.is_empty() {
                 let mut db_guard =
                     recover_write(Arc::as_ref(&db), "LeaseTracker::run_loop remove expired");
                 for guid in expired_guids {
@@ -101,6 +160,15 @@ impl LeaseTracker {
         }
     }
 
+    /// Pause (`true`) or resume (`false`) expiry checks.
+    ///
+    /// Used while the participant is suspended: peers cannot be heard from,
+    /// so their leases must not run out. Refresh the leases before resuming
+    /// (see `DiscoveryFsm::refresh_leases`).
+    pub fn set_paused(&self, paused: bool) {
+        self.paused.store(paused, Ordering::Relaxed);
+    }
+
     /// Stop lease tracker gracefully
     ///
     /// Signals background thread to exit and waits for join.
@@ -221,4 +289,30 @@ mod tests {
 
         tracker.stop();
     }
+
+    #[test]
+    fn test_paused_lease_tracker_keeps_expired() {
+        let db = Arc::new(RwLock::new(HashMap::new()));
+        let guid = GUID::from_bytes([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+        let info = ParticipantInfo::new(guid, vec![], 100); // 100ms lease
+
+        {
+            let mut db_lock = db.write().expect("RwLock write should succeed");
+            db_lock.insert(guid, info);
+        }
+
+        let tracker =
+            LeaseTracker::start(Arc::clone(&db)).expect("LeaseTracker start should succeed");
+        tracker.set_paused(true);
+
+        // Lease expired, but the tracker is paused
+        thread::sleep(Duration::from_millis(1200));
+        assert!(db.read().expect("RwLock read").contains_key(&guid));
+
+        tracker.set_paused(false);
+        thread::sleep(Duration::from_millis(1200));
+        assert!(!db.read().expect("RwLock read").contains_key(&guid));
+
+        tracker.stop();
+    }
 }
@@ -23,17 +23,19 @@ use super::control_parser::{
     parse_acknack_submessage, parse_all_heartbeat_submessages, parse_nack_frag_submessage,
 };
 use super::control_types::ControlMessage;
-use super::{classify_rtps, PacketKind, RxMeta, RxPool};
+use super::{classify_rtps, kernel_timestamps, PacketKind, RxMeta, RxPool};
 use crate::engine::wake::WakeNotifier;
-use crossbeam::channel::Sender;
+use crate::transport::loopback::LoopbackPort;
+use crate::transport::tap::{CapturedPacket, PacketDirection, PacketTap};
+use crossbeam::channel::{RecvTimeoutError, Sender};
 use crossbeam::queue::ArrayQueue;
 use mio::{Events, Interest, Poll, Token};
 use std::io;
-use std::net::UdpSocket;
+use std::net::{SocketAddr, UdpSocket};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::thread::JoinHandle;
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
 // Fragment metadata moved to meta.rs to avoid circular dependencies
 pub use super::meta::FragmentMetadata;
@@ -160,7 +162,7 @@ impl MulticastListener {
     /// let ring = Arc::new(ArrayQueue::new(256));
     ///
     /// let listener = MulticastListener::spawn(
-    ///     transport.socket(),
+    ///     transport.socket().expect("UDP socket"),
     ///     pool,
     ///     ring,
     ///     None, // No discovery callback
@@ -218,7 +220,32 @@ impl MulticastListener {
         control_tx: Option<Sender<ControlMessage>>,
         notifier: Option<Arc<WakeNotifier>>,
     ) -> io::Result<Self> {
-        crate::trace_fn!("MulticastListener::spawn_with_notifier");
+        Self::spawn_with_tap(
+            socket,
+            pool,
+            ring,
+            discovery_callback,
+            control_tx,
+            notifier,
+            None,
+        )
+    }
+
+    /// Spawn multicast listener that also hands every received datagram to
+    /// `tap` (see [`ParticipantBuilder::with_packet_tap`](crate::dds::ParticipantBuilder::with_packet_tap)).
+    ///
+    /// Otherwise identical to [`spawn_with_notifier`](Self::spawn_with_notifier).
+    pub fn spawn_with_tap(
+        socket: Arc<UdpSocket>,
+        pool: Arc<RxPool>,
+        ring: Arc<ArrayQueue<(RxMeta, u8)>>,
+        discovery_callback: Option<DiscoveryCallback>,
+        control_tx: Option<Sender<ControlMessage>>,
+        notifier: Option<Arc<WakeNotifier>>,
+        tap: Option<PacketTap>,
+    ) -> io::Result<Self> {
+        crate::trace_fn!("MulticastListener::spawn_with_tap");
+        let local_addr = socket.local_addr().ok();
         // v212: Set socket to non-blocking for mio epoll
         socket.set_nonblocking(true)?;
 
@@ -226,26 +253,22 @@ impl MulticastListener {
         let running_clone = Arc::clone(&running);
 
         let metrics = ListenerMetrics::new();
-        let metrics_clone = Arc::clone(&metrics);
-
-        let callback_clone = discovery_callback.clone();
-        let control_tx_clone = control_tx.clone();
-        let notifier_clone = notifier.clone();
+        let sink = RxSink {
+            pool,
+            ring,
+            metrics: Arc::clone(&metrics),
+            discovery_callback: discovery_callback.clone(),
+            control_tx: control_tx.clone(),
+            notifier: notifier.clone(),
+            tap,
+            local_addr,
+        };
 
         // Spawn IO thread
         let handle = std::thread::Builder::new()
             .name("hdds-mcast-rx".to_string())
             .spawn(move || {
-                Self::run_loop(
-                    socket,
-                    pool,
-                    ring,
-                    running_clone,
-                    metrics_clone,
-                    callback_clone,
-                    control_tx_clone,
-                    notifier_clone,
-                );
+                Self::run_loop(socket, running_clone, sink);
             })?;
 
         Ok(Self {
@@ -258,20 +281,64 @@ impl MulticastListener {
         })
     }
 
-    /// Main IO loop (runs in dedicated thread)
+    /// Spawn a listener on a loopback bus port (`TransportMode::LoopbackBus`).
     ///
-    /// v212: Uses mio/epoll for event-driven I/O with minimal latency.
-    #[allow(clippy::too_many_arguments)]
-    fn run_loop(
-        socket: Arc<UdpSocket>,
+    /// Same dispatch as [`spawn_with_notifier`](Self::spawn_with_notifier),
+    /// fed by the port's in-memory channel instead of a socket.
+    pub fn spawn_loopback(
+        port: &LoopbackPort,
         pool: Arc<RxPool>,
         ring: Arc<ArrayQueue<(RxMeta, u8)>>,
-        running: Arc<AtomicBool>,
-        metrics: Arc<ListenerMetrics>,
         discovery_callback: Option<DiscoveryCallback>,
         control_tx: Option<Sender<ControlMessage>>,
         notifier: Option<Arc<WakeNotifier>>,
-    ) {
+        tap: Option<PacketTap>,
+    ) -> io::Result<Self> {
+        crate::trace_fn!("MulticastListener::spawn_loopback");
+        let running = Arc::new(AtomicBool::new(true));
+        let running_clone = Arc::clone(&running);
+        let receiver = port.receiver();
+
+        let metrics = ListenerMetrics::new();
+        let sink = RxSink {
+            pool,
+            ring,
+            metrics: Arc::clone(&metrics),
+            discovery_callback: discovery_callback.clone(),
+            control_tx: control_tx.clone(),
+            notifier: notifier.clone(),
+            tap,
+            local_addr: Some(port.metatraffic_addr()),
+        };
+
+        let handle = std::thread::Builder::new()
+            .name("hdds-loopback-rx".to_string())
+            .spawn(move || {
+                crate::alloc_scope!(Transport);
+                while running_clone.load(Ordering::Relaxed) {
+                    match receiver.recv_timeout(Duration::from_millis(1)) {
+                        Ok(datagram) => sink.handle(&datagram.data, datagram.src, None),
+                        Err(RecvTimeoutError::Timeout) => {}
+                        Err(RecvTimeoutError::Disconnected) => break,
+                    }
+                }
+            })?;
+
+        Ok(Self {
+            handle: Some(handle),
+            running,
+            metrics,
+            discovery_callback,
+            control_tx,
+            notifier,
+        })
+    }
+
+    /// Main IO loop (runs in dedicated thread)
+    ///
+    /// v212: Uses mio/epoll for event-driven I/O with minimal latency.
+    fn run_loop(socket: Arc<UdpSocket>, running: Arc<AtomicBool>, sink: RxSink) {
+        crate::alloc_scope!(Transport);
         let local_addr = socket
             .local_addr()
             .map(|addr| addr.to_string())
@@ -313,6 +380,12 @@ impl MulticastListener {
             return;
         }
 
+        // Kernel receive timestamps separate network time from scheduling
+        // time in SampleInfo; packets are still received without them
+        if let Err(e) = kernel_timestamps::enable(&mio_socket) {
+            log::debug!("[MCAST-THREAD] kernel RX timestamps unavailable: {}", e);
+        }
+
         // Temporary buffer for recv_from (reused across iterations)
         let mut temp_buf = vec![0u8; crate::config::MAX_PACKET_SIZE];
 
@@ -334,407 +407,428 @@ impl MulticastListener {
 
                 // Drain all available packets (edge-triggered style)
                 loop {
-                    let (len, src_addr) = match mio_socket.recv_from(&mut temp_buf) {
-                        Ok(result) => result,
-                        Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
-                        Err(e) => {
-                            log::debug!("[hdds-mcast-rx] recv_from error: {:?}", e);
-                            break;
-                        }
-                    };
+                    let (len, src_addr, kernel_rx_ns) =
+                        match kernel_timestamps::recv_from(&mio_socket, &mut temp_buf) {
+                            Ok(result) => result,
+                            Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                            Err(e) => {
+                                log::debug!("[hdds-mcast-rx] recv_from error: {:?}", e);
+                                break;
+                            }
+                        };
+                    sink.handle(&temp_buf[..len], src_addr, kernel_rx_ns);
+                } // end inner drain loop
+            } // end for event
+        } // end while running
+    }
+
+    /// Shutdown listener gracefully
+    ///
+    /// Signals thread to exit and waits for join.
+    pub fn shutdown(mut self) {
+        crate::trace_fn!("MulticastListener::shutdown");
+        self.running.store(false, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Receive-side state shared by the socket and loopback bus loops.
+struct RxSink {
+    pool: Arc<RxPool>,
+    ring: Arc<ArrayQueue<(RxMeta, u8)>>,
+    metrics: Arc<ListenerMetrics>,
+    discovery_callback: Option<DiscoveryCallback>,
+    control_tx: Option<Sender<ControlMessage>>,
+    notifier: Option<Arc<WakeNotifier>>,
+    tap: Option<PacketTap>,
+    /// Address the datagrams arrive on, reported to the tap
+    local_addr: Option<SocketAddr>,
+}
+
+impl RxSink {
+    /// Classify one received RTPS message and dispatch it.
+    fn handle(&self, packet: &[u8], src_addr: SocketAddr, kernel_rx_ns: Option<u64>) {
+        let len = packet.len();
+        if let Some(tap) = &self.tap {
+            tap(&CapturedPacket {
+                direction: PacketDirection::Received,
+                timestamp: SystemTime::now(),
+                source: Some(src_addr),
+                destination: self.local_addr,
+                data: packet,
+            });
+        }
+        log::debug!(
+            "[MCAST] recv len={} src={} thread={:?}",
+            len,
+            src_addr,
+            std::thread::current().id()
+        );
+
+        // Update metrics
+        self.metrics
+            .packets_received
+            .fetch_add(1, Ordering::Relaxed);
+        self.metrics
+            .bytes_received
+            .fetch_add(len as u64, Ordering::Relaxed);
+
+        // Classify packet (returns kind, optional DATA payload offset, fragment metadata, and RTPS context)
+        // v61 Blocker #1: Now captures INFO_DST/INFO_TS context for stateful RTPS parsing
+        let (kind, payload_offset, fragment_metadata, rtps_context) = classify_rtps(packet);
+
+        let kind_label = match kind {
+            PacketKind::Data => "DATA",
+            PacketKind::Heartbeat => "HEARTBEAT",
+            PacketKind::AckNack => "ACKNACK",
+            PacketKind::DataFrag => "DATA_FRAG",
+            PacketKind::Gap => "GAP",
+            PacketKind::NackFrag => "NACK_FRAG",
+            PacketKind::HeartbeatFrag => "HEARTBEAT_FRAG",
+            PacketKind::InfoTs => "INFO_TS",
+            PacketKind::InfoSrc => "INFO_SRC",
+            PacketKind::InfoDst => "INFO_DST",
+            PacketKind::InfoReply => "INFO_REPLY",
+            PacketKind::Pad => "PAD",
+            PacketKind::SPDP => "SPDP",
+            PacketKind::SEDP => "SEDP",
+            PacketKind::TypeLookup => "TYPE_LOOKUP",
+            PacketKind::Invalid => "INVALID",
+            PacketKind::Unknown => "UNKNOWN",
+        };
+        log::debug!(
+            "[MCAST] recv kind={} len={} src={} thread={:?}",
+            kind_label,
+            len,
+            src_addr,
+            std::thread::current().id()
+        );
+
+        // Drop invalid packets
+        if matches!(kind, PacketKind::Invalid) {
+            self.metrics.packets_invalid.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+
+        // v202/v203: Two-Ring Architecture - dispatch HEARTBEAT/ACKNACK to control channel
+        // When control_tx is available, these control packets bypass the pool and go
+        // to a dedicated ControlHandler thread. This prevents pool exhaustion under
+        // high HEARTBEAT/ACKNACK load (RELIABLE QoS).
+        //
+        // v203: Extended to also bypass AckNack packets which were saturating the pool
+        // (623 AckNack drops observed in EVENT test causing 0 samples received)
+        if kind == PacketKind::Heartbeat {
+            if let Some(ref tx) = self.control_tx {
+                // v210: Parse ALL heartbeat submessages from the packet.
+                // FastDDS bundles HBs for multiple writers (03C2, 04C2, 0200C2)
+                // in one RTPS packet. Previous code only extracted the first one,
+                // silently dropping SEDP pub/sub HBs.
+                let all_hbs = parse_all_heartbeat_submessages(packet);
+
+                if all_hbs.is_empty() {
                     log::debug!(
-                        "[MCAST] recv len={} src={} thread={:?}",
-                        len,
+                        "[MCAST-RX] v189: Failed to parse HEARTBEAT from {}, len={}",
                         src_addr,
-                        std::thread::current().id()
+                        len
                     );
+                } else {
+                    // Extract peer GUID prefix from RTPS header
+                    let mut peer_guid_prefix = [0u8; 12];
+                    if len >= 20 {
+                        peer_guid_prefix.copy_from_slice(&packet[8..20]);
+                    }
 
-                    // Update metrics
-                    metrics.packets_received.fetch_add(1, Ordering::Relaxed);
-                    metrics
-                        .bytes_received
-                        .fetch_add(len as u64, Ordering::Relaxed);
-
-                    // Classify packet (returns kind, optional DATA payload offset, fragment metadata, and RTPS context)
-                    // v61 Blocker #1: Now captures INFO_DST/INFO_TS context for stateful RTPS parsing
-                    let (kind, payload_offset, fragment_metadata, rtps_context) =
-                        classify_rtps(&temp_buf[..len]);
-
-                    let kind_label = match kind {
-                        PacketKind::Data => "DATA",
-                        PacketKind::Heartbeat => "HEARTBEAT",
-                        PacketKind::AckNack => "ACKNACK",
-                        PacketKind::DataFrag => "DATA_FRAG",
-                        PacketKind::Gap => "GAP",
-                        PacketKind::NackFrag => "NACK_FRAG",
-                        PacketKind::HeartbeatFrag => "HEARTBEAT_FRAG",
-                        PacketKind::InfoTs => "INFO_TS",
-                        PacketKind::InfoSrc => "INFO_SRC",
-                        PacketKind::InfoDst => "INFO_DST",
-                        PacketKind::InfoReply => "INFO_REPLY",
-                        PacketKind::Pad => "PAD",
-                        PacketKind::SPDP => "SPDP",
-                        PacketKind::SEDP => "SEDP",
-                        PacketKind::TypeLookup => "TYPE_LOOKUP",
-                        PacketKind::Invalid => "INVALID",
-                        PacketKind::Unknown => "UNKNOWN",
-                    };
                     log::debug!(
-                        "[MCAST] recv kind={} len={} src={} thread={:?}",
-                        kind_label,
-                        len,
+                        "[MCAST-RX] v210: Parsed {} HEARTBEAT(s) from {} writers={:?}",
+                        all_hbs.len(),
                         src_addr,
-                        std::thread::current().id()
+                        all_hbs
+                            .iter()
+                            .map(|h| h.writer_entity_id)
+                            .collect::<Vec<_>>()
                     );
 
-                    // Drop invalid packets
-                    if matches!(kind, PacketKind::Invalid) {
-                        metrics.packets_invalid.fetch_add(1, Ordering::Relaxed);
-                        continue;
-                    }
-
-                    // v202/v203: Two-Ring Architecture - dispatch HEARTBEAT/ACKNACK to control channel
-                    // When control_tx is available, these control packets bypass the pool and go
-                    // to a dedicated ControlHandler thread. This prevents pool exhaustion under
-                    // high HEARTBEAT/ACKNACK load (RELIABLE QoS).
-                    //
-                    // v203: Extended to also bypass AckNack packets which were saturating the pool
-                    // (623 AckNack drops observed in EVENT test causing 0 samples received)
-                    if kind == PacketKind::Heartbeat {
-                        if let Some(ref tx) = control_tx {
-                            // v210: Parse ALL heartbeat submessages from the packet.
-                            // FastDDS bundles HBs for multiple writers (03C2, 04C2, 0200C2)
-                            // in one RTPS packet. Previous code only extracted the first one,
-                            // silently dropping SEDP pub/sub HBs.
-                            let all_hbs = parse_all_heartbeat_submessages(&temp_buf[..len]);
-
-                            if all_hbs.is_empty() {
-                                log::debug!(
-                                    "[MCAST-RX] v189: Failed to parse HEARTBEAT from {}, len={}",
-                                    src_addr,
-                                    len
-                                );
-                            } else {
-                                // Extract peer GUID prefix from RTPS header
-                                let mut peer_guid_prefix = [0u8; 12];
-                                if len >= 20 {
-                                    peer_guid_prefix.copy_from_slice(&temp_buf[8..20]);
-                                }
-
+                    for hb_info in all_hbs {
+                        if let Some(msg) =
+                            ControlMessage::heartbeat(src_addr, peer_guid_prefix, hb_info, packet)
+                        {
+                            // Non-blocking send - drop if channel full (HBs are idempotent)
+                            if tx.try_send(msg).is_err() {
                                 log::debug!(
-                                    "[MCAST-RX] v210: Parsed {} HEARTBEAT(s) from {} writers={:?}",
-                                    all_hbs.len(),
-                                    src_addr,
-                                    all_hbs
-                                        .iter()
-                                        .map(|h| h.writer_entity_id)
-                                        .collect::<Vec<_>>()
+                                    "[MCAST-RX] Control channel full, dropping HEARTBEAT from {}",
+                                    src_addr
                                 );
-
-                                for hb_info in all_hbs {
-                                    if let Some(msg) = ControlMessage::heartbeat(
-                                        src_addr,
-                                        peer_guid_prefix,
-                                        hb_info,
-                                        &temp_buf[..len],
-                                    ) {
-                                        // Non-blocking send - drop if channel full (HBs are idempotent)
-                                        if tx.try_send(msg).is_err() {
-                                            log::debug!(
-                                        "[MCAST-RX] Control channel full, dropping HEARTBEAT from {}",
-                                        src_addr
-                                    );
-                                        }
-                                    }
-                                }
                             }
-                            // Skip synchronous callback and pool for this HEARTBEAT
-                            continue;
                         }
-                        // No control_tx: fall through to legacy synchronous callback
                     }
+                }
+                // Skip synchronous callback and pool for this HEARTBEAT
+                return;
+            }
+            // No control_tx: fall through to legacy synchronous callback
+        }
 
-                    // v137: Parse and send ACKNACKs to control channel for SEDP response.
-                    //
-                    // RTI sends ACKNACKs asking for our publications (0x03c2).
-                    // We must respond with a HEARTBEAT indicating "empty writer" (lastSeq=0).
-                    // Without this, RTI never sends its SEDP Publications DATA.
-                    //
-                    // v203: Extended to also bypass non-SEDP AckNack packets which don't need processing.
-                    if kind == PacketKind::AckNack {
-                        if let Some(ref tx) = control_tx {
-                            // Parse ACKNACK and send to control channel
-                            if let Some(an_info) = parse_acknack_submessage(&temp_buf[..len]) {
-                                // Extract peer GUID prefix from RTPS header
-                                let mut peer_guid_prefix = [0u8; 12];
-                                if len >= 20 {
-                                    peer_guid_prefix.copy_from_slice(&temp_buf[8..20]);
-                                }
-
-                                if let Some(msg) = ControlMessage::acknack(
-                                    src_addr,
-                                    peer_guid_prefix,
-                                    an_info.clone(),
-                                    &temp_buf[..len],
-                                ) {
-                                    // Non-blocking send - drop if channel full (ACKNACKs can be retried)
-                                    log::debug!(
-                                "[MCAST-RX] v204: Sending ACKNACK to control channel: writer={:02x?} from {} ranges={}",
-                                an_info.writer_entity_id,
-                                src_addr,
-                                an_info.missing_ranges.len()
-                            );
-                                    if tx.try_send(msg).is_err() {
-                                        log::debug!(
-                                    "[MCAST-RX] Control channel full, dropping ACKNACK from {}",
-                                    src_addr
-                                );
-                                    }
-                                } else {
-                                    log::debug!(
-                                "[MCAST-RX] v204: ControlMessage::acknack returned None for ACKNACK from {}",
-                                src_addr
-                            );
-                                }
-                            } else {
-                                log::debug!(
-                            "[MCAST-RX] v204: parse_acknack_submessage returned None for {} bytes from {}",
-                            len,
-                            src_addr
-                        );
-                            }
-                            // Skip synchronous callback and pool for this ACKNACK
-                            continue;
-                        }
-                        // No control_tx: fall through to pool-based processing
+        // v137: Parse and send ACKNACKs to control channel for SEDP response.
+        //
+        // RTI sends ACKNACKs asking for our publications (0x03c2).
+        // We must respond with a HEARTBEAT indicating "empty writer" (lastSeq=0).
+        // Without this, RTI never sends its SEDP Publications DATA.
+        //
+        // v203: Extended to also bypass non-SEDP AckNack packets which don't need processing.
+        if kind == PacketKind::AckNack {
+            if let Some(ref tx) = self.control_tx {
+                // Parse ACKNACK and send to control channel
+                if let Some(an_info) = parse_acknack_submessage(packet) {
+                    // Extract peer GUID prefix from RTPS header
+                    let mut peer_guid_prefix = [0u8; 12];
+                    if len >= 20 {
+                        peer_guid_prefix.copy_from_slice(&packet[8..20]);
                     }
 
-                    // Handle NACK_FRAG packets (fragment retransmission requests)
-                    if kind == PacketKind::NackFrag {
-                        if let Some(ref tx) = control_tx {
-                            // Parse NACK_FRAG and send to control channel
-                            if let Some(nf_info) = parse_nack_frag_submessage(&temp_buf[..len]) {
-                                // Extract peer GUID prefix from RTPS header
-                                let mut peer_guid_prefix = [0u8; 12];
-                                if len >= 20 {
-                                    peer_guid_prefix.copy_from_slice(&temp_buf[8..20]);
-                                }
-
-                                if let Some(msg) = ControlMessage::nack_frag(
-                                    src_addr,
-                                    peer_guid_prefix,
-                                    nf_info.clone(),
-                                    &temp_buf[..len],
-                                ) {
-                                    log::debug!(
-                                "[MCAST-RX] Sending NACK_FRAG to control channel: writer={:02x?} sn={} frags={:?} from {}",
-                                nf_info.writer_entity_id,
-                                nf_info.writer_sn,
-                                nf_info.missing_fragments,
+                    if let Some(msg) =
+                        ControlMessage::acknack(src_addr, peer_guid_prefix, an_info.clone(), packet)
+                    {
+                        // Non-blocking send - drop if channel full (ACKNACKs can be retried)
+                        log::debug!(
+                    "[MCAST-RX] v204: Sending ACKNACK to control channel: writer={:02x?} from {} ranges={}",
+                    an_info.writer_entity_id,
+                    src_addr,
+                    an_info.missing_ranges.len()
+                );
+                        if tx.try_send(msg).is_err() {
+                            log::debug!(
+                                "[MCAST-RX] Control channel full, dropping ACKNACK from {}",
                                 src_addr
                             );
-                                    if tx.try_send(msg).is_err() {
-                                        log::debug!(
-                                    "[MCAST-RX] Control channel full, dropping NACK_FRAG from {}",
-                                    src_addr
-                                );
-                                    }
-                                }
-                            }
-                            // Skip synchronous callback and pool for this NACK_FRAG
-                            continue;
                         }
-                        // No control_tx: fall through to pool-based processing
+                    } else {
+                        log::debug!(
+                    "[MCAST-RX] v204: ControlMessage::acknack returned None for ACKNACK from {}",
+                    src_addr
+                );
                     }
+                } else {
+                    log::debug!(
+                "[MCAST-RX] v204: parse_acknack_submessage returned None for {} bytes from {}",
+                len,
+                src_addr
+            );
+                }
+                // Skip synchronous callback and pool for this ACKNACK
+                return;
+            }
+            // No control_tx: fall through to pool-based processing
+        }
 
-                    // Invoke discovery callback for discovery packets (DATA/DATA_FRAG/SPDP/SEDP/TYPE_LOOKUP/Heartbeat)
-                    // RTI uses DATA_FRAG for SPDP announcements and builtin writers for SEDP.
-                    // v104: Also invoke for HEARTBEAT to enable SEDP NACK responses
-                    // Callback processes discovery synchronously before ring push
-                    // v0.4.0+: Panic boundary to prevent callback failures from killing listener thread
-                    // v202: HEARTBEATs with control_tx are handled above, skip here
-                    if matches!(
-                        kind,
-                        PacketKind::Data
-                            | PacketKind::DataFrag
-                            | PacketKind::SPDP
-                            | PacketKind::SEDP
-                            | PacketKind::TypeLookup
-                            | PacketKind::Heartbeat
-                    ) {
-                        if let Some(ref callback) = discovery_callback {
-                            if std::env::var("HDDS_INTEROP_DIAGNOSTICS").is_ok() {
-                                let head_len = len.min(16);
-                                log::debug!(
-                                    "[MCAST-RX] kind={} len={} src={} head={:02x?}",
-                                    kind_label,
-                                    len,
-                                    src_addr,
-                                    &temp_buf[..head_len]
-                                );
-                            }
-                            if std::env::var("HDDS_INTEROP_DIAGNOSTICS").is_ok() {
-                                let head_len = len.min(16);
-                                log::debug!(
-                                    "[MCAST-RX] kind={} len={} src={} head={:02x?}",
-                                    kind_label,
-                                    len,
-                                    src_addr,
-                                    &temp_buf[..head_len]
-                                );
-                            }
-                            // Extract CDR payload from RTPS packet using classifier-provided offset
-                            // For standard HDDS packets: offset = 20 (16-byte header + 4-byte submessage header)
-                            // For RTI packets after recovery: offset = variable (e.g., 24 if DATA at offset 20)
-                            //
-                            // v62: Fix fallback offset: 20 (RTPS header) + 24 (DATA submessage header) = 44
-                            // Previous value of 40 was 4 bytes short, reading tail of writerSeqNum as encapsulation
-                            const LEGACY_RTPS_DATA_PAYLOAD_OFFSET: usize = 44;
-                            let offset = payload_offset.unwrap_or(LEGACY_RTPS_DATA_PAYLOAD_OFFSET);
-
-                            if len >= offset {
-                                // v124: Pass full RTPS packet + offset to callback
-                                // Callback receives full packet (for DialectDetector) + offset (for CDR extraction)
-                                let full_packet = &temp_buf[..len];
-
-                                log::debug!(
-                            "[callback] v124: Received DATA packet, len={}, cdr_offset={}, passing full packet",
-                            len,
-                            offset
-                        );
-
-                                let callback_result =
-                                    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                                        callback(
-                                            kind,
-                                            full_packet,
-                                            offset,
-                                            fragment_metadata,
-                                            src_addr,
-                                        );
-                                    }));
-
-                                if let Err(e) = callback_result {
-                                    metrics.callback_errors.fetch_add(1, Ordering::Relaxed);
-                                    log::debug!(
-                                        "[hdds-mcast-rx:{}] Discovery callback panicked: {:?}",
-                                        std::process::id(),
-                                        e
-                                    );
-                                    // Continue processing, don't crash listener thread
-                                }
-                            } else {
-                                log::debug!(
-                            "[hdds-mcast-rx] DATA packet too short for payload: {} bytes (need >= 44)",
-                            len
-                        );
-                            }
-                        }
+        // Handle NACK_FRAG packets (fragment retransmission requests)
+        if kind == PacketKind::NackFrag {
+            if let Some(ref tx) = self.control_tx {
+                // Parse NACK_FRAG and send to control channel
+                if let Some(nf_info) = parse_nack_frag_submessage(packet) {
+                    // Extract peer GUID prefix from RTPS header
+                    let mut peer_guid_prefix = [0u8; 12];
+                    if len >= 20 {
+                        peer_guid_prefix.copy_from_slice(&packet[8..20]);
                     }
 
-                    // Only process relevant packet types for ring buffer
-                    // SPDP/SEDP are handled by discovery callback above, not sent to ring
-                    if !matches!(
-                        kind,
-                        PacketKind::Data
-                            | PacketKind::DataFrag
-                            | PacketKind::Heartbeat
-                            | PacketKind::HeartbeatFrag
-                            | PacketKind::AckNack
-                            | PacketKind::SPDP
-                            | PacketKind::SEDP
+                    if let Some(msg) = ControlMessage::nack_frag(
+                        src_addr,
+                        peer_guid_prefix,
+                        nf_info.clone(),
+                        packet,
                     ) {
-                        continue;
-                    }
-
-                    // Skip SPDP/SEDP packets - they were already handled by discovery callback
-                    if matches!(kind, PacketKind::SPDP | PacketKind::SEDP) {
-                        continue;
-                    }
-
-                    // Acquire buffer from pool
-                    let buffer_id = match pool.acquire_for_listener() {
-                        Some(id) => id,
-                        None => {
-                            // Pool exhausted
-                            metrics.packets_dropped.fetch_add(1, Ordering::Relaxed);
+                        log::debug!(
+                    "[MCAST-RX] Sending NACK_FRAG to control channel: writer={:02x?} sn={} frags={:?} from {}",
+                    nf_info.writer_entity_id,
+                    nf_info.writer_sn,
+                    nf_info.missing_fragments,
+                    src_addr
+                );
+                        if tx.try_send(msg).is_err() {
                             log::debug!(
-                                "[hdds-mcast-rx] Pool exhausted, dropping {:?} packet ({} bytes)",
-                                kind,
-                                len
+                                "[MCAST-RX] Control channel full, dropping NACK_FRAG from {}",
+                                src_addr
                             );
-                            continue;
                         }
-                    };
-
-                    // Copy packet to pool buffer
-                    // SAFETY: buffer_id is valid (just acquired), no concurrent access
-                    unsafe {
-                        let pool_ptr = Arc::as_ptr(&pool);
-                        let buf = (*pool_ptr.cast_mut()).get_buffer_mut(buffer_id);
-                        buf[..len].copy_from_slice(&temp_buf[..len]);
                     }
+                }
+                // Skip synchronous callback and pool for this NACK_FRAG
+                return;
+            }
+            // No control_tx: fall through to pool-based processing
+        }
 
-                    // Build metadata (including payload offset / fragment info when available)
-                    let mut meta = if let Some(offset) = payload_offset {
-                        if let Some(frag_meta) = fragment_metadata {
-                            RxMeta::new_with_fragment(src_addr, len, kind, offset, frag_meta)
-                        } else {
-                            RxMeta::new_with_offset(src_addr, len, kind, offset)
-                        }
-                    } else {
-                        RxMeta::new(src_addr, len, kind)
-                    };
-                    // v61 Blocker #1: Apply accumulated RTPS context from INFO_DST/INFO_TS submessages
-                    meta.rtps_context = rtps_context;
+        // Invoke discovery callback for discovery packets (DATA/DATA_FRAG/SPDP/SEDP/TYPE_LOOKUP/Heartbeat)
+        // RTI uses DATA_FRAG for SPDP announcements and builtin writers for SEDP.
+        // v104: Also invoke for HEARTBEAT to enable SEDP NACK responses
+        // Callback processes discovery synchronously before ring push
+        // v0.4.0+: Panic boundary to prevent callback failures from killing listener thread
+        // v202: HEARTBEATs with control_tx are handled above, skip here
+        if matches!(
+            kind,
+            PacketKind::Data
+                | PacketKind::DataFrag
+                | PacketKind::SPDP
+                | PacketKind::SEDP
+                | PacketKind::TypeLookup
+                | PacketKind::Heartbeat
+        ) {
+            if let Some(ref callback) = self.discovery_callback {
+                if std::env::var("HDDS_INTEROP_DIAGNOSTICS").is_ok() {
+                    let head_len = len.min(16);
+                    log::debug!(
+                        "[MCAST-RX] kind={} len={} src={} head={:02x?}",
+                        kind_label,
+                        len,
+                        src_addr,
+                        &packet[..head_len]
+                    );
+                }
+                if std::env::var("HDDS_INTEROP_DIAGNOSTICS").is_ok() {
+                    let head_len = len.min(16);
+                    log::debug!(
+                        "[MCAST-RX] kind={} len={} src={} head={:02x?}",
+                        kind_label,
+                        len,
+                        src_addr,
+                        &packet[..head_len]
+                    );
+                }
+                // Extract CDR payload from RTPS packet using classifier-provided offset
+                // For standard HDDS packets: offset = 20 (16-byte header + 4-byte submessage header)
+                // For RTI packets after recovery: offset = variable (e.g., 24 if DATA at offset 20)
+                //
+                // v62: Fix fallback offset: 20 (RTPS header) + 24 (DATA submessage header) = 44
+                // Previous value of 40 was 4 bytes short, reading tail of writerSeqNum as encapsulation
+                const LEGACY_RTPS_DATA_PAYLOAD_OFFSET: usize = 44;
+                let offset = payload_offset.unwrap_or(LEGACY_RTPS_DATA_PAYLOAD_OFFSET);
+
+                if len >= offset {
+                    // v124: Pass full RTPS packet + offset to callback
+                    // Callback receives full packet (for DialectDetector) + offset (for CDR extraction)
+                    let full_packet = packet;
 
-                    // v62: Log RTPS context propagation when non-empty (avoid unwrap in hot path)
-                    if let Some(dest_prefix) = rtps_context.destination_guid_prefix {
-                        log::debug!(
-                            "[RTPS-CONTEXT] Applied INFO_DST: dest_prefix={:02x?}",
-                            dest_prefix
-                        );
-                    }
-                    if let Some((sec, frac)) = rtps_context.source_timestamp {
+                    log::debug!(
+                "[callback] v124: Received DATA packet, len={}, cdr_offset={}, passing full packet",
+                len,
+                offset
+            );
+
+                    let callback_result =
+                        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                            callback(kind, full_packet, offset, fragment_metadata, src_addr);
+                        }));
+
+                    if let Err(e) = callback_result {
+                        self.metrics.callback_errors.fetch_add(1, Ordering::Relaxed);
                         log::debug!(
-                            "[RTPS-CONTEXT] Applied INFO_TS: timestamp=({}, {})",
-                            sec,
-                            frac
+                            "[hdds-mcast-rx:{}] Discovery callback panicked: {:?}",
+                            std::process::id(),
+                            e
                         );
+                        // Continue processing, don't crash listener thread
                     }
+                } else {
+                    log::debug!(
+                        "[hdds-mcast-rx] DATA packet too short for payload: {} bytes (need >= 44)",
+                        len
+                    );
+                }
+            }
+        }
 
-                    // Push to ring (non-blocking)
-                    if ring.push((meta, buffer_id)).is_err() {
-                        // Ring full, release buffer to avoid leak
-                        if let Err(e) = pool.release(buffer_id) {
-                            log::debug!(
-                                "[hdds-mcast-rx] CRITICAL: Failed to release buffer {}: {}",
-                                buffer_id,
-                                e
-                            );
-                        }
-                        metrics.packets_dropped.fetch_add(1, Ordering::Relaxed);
-                        log::debug!("[hdds-mcast-rx] Ring full, dropping {:?} packet", kind);
-                    } else {
-                        // v211: Always notify to ensure router wakes from idle
-                        // The spin loop in router catches most hot traffic before condvar
-                        if let Some(ref n) = notifier {
-                            n.notify();
-                        }
-                    }
-                } // end inner drain loop
-            } // end for event
-        } // end while running
-    }
+        // Only process relevant packet types for ring buffer
+        // SPDP/SEDP are handled by discovery callback above, not sent to ring
+        if !matches!(
+            kind,
+            PacketKind::Data
+                | PacketKind::DataFrag
+                | PacketKind::Heartbeat
+                | PacketKind::HeartbeatFrag
+                | PacketKind::AckNack
+                | PacketKind::SPDP
+                | PacketKind::SEDP
+        ) {
+            return;
+        }
 
-    /// Shutdown listener gracefully
-    ///
-    /// Signals thread to exit and waits for join.
-    pub fn shutdown(mut self) {
-        crate::trace_fn!("MulticastListener::shutdown");
-        self.running.store(false, Ordering::Relaxed);
-        if let Some(handle) = self.handle.take() {
-            let _ = handle.join();
+        // Skip SPDP/SEDP packets - they were already handled by discovery callback
+        if matches!(kind, PacketKind::SPDP | PacketKind::SEDP) {
+            return;
+        }
+
+        // Acquire buffer from pool
+        let buffer_id = match self.pool.acquire_for_listener() {
+            Some(id) => id,
+            None => {
+                // Pool exhausted
+                self.metrics.packets_dropped.fetch_add(1, Ordering::Relaxed);
+                log::debug!(
+                    "[hdds-mcast-rx] Pool exhausted, dropping {:?} packet ({} bytes)",
+                    kind,
+                    len
+                );
+                return;
+            }
+        };
+
+        // Copy packet to pool buffer
+        // SAFETY: buffer_id is valid (just acquired), no concurrent access
+        unsafe {
+            let pool_ptr = Arc::as_ptr(&self.pool);
+            let buf = (*pool_ptr.cast_mut()).get_buffer_mut(buffer_id);
+            buf[..len].copy_from_slice(packet);
+        }
+
+        // Build metadata (including payload offset / fragment info when available)
+        let mut meta = if let Some(offset) = payload_offset {
+            if let Some(frag_meta) = fragment_metadata {
+                RxMeta::new_with_fragment(src_addr, len, kind, offset, frag_meta)
+            } else {
+                RxMeta::new_with_offset(src_addr, len, kind, offset)
+            }
+        } else {
+            RxMeta::new(src_addr, len, kind)
+        };
+        // v61 Blocker #1: Apply accumulated RTPS context from INFO_DST/INFO_TS submessages
+        meta.rtps_context = rtps_context;
+        meta.kernel_rx_ns = kernel_rx_ns;
+
+        // v62: Log RTPS context propagation when non-empty (avoid unwrap in hot path)
+        if let Some(dest_prefix) = rtps_context.destination_guid_prefix {
+            log::debug!(
+                "[RTPS-CONTEXT] Applied INFO_DST: dest_prefix={:02x?}",
+                dest_prefix
+            );
+        }
+        if let Some((sec, frac)) = rtps_context.source_timestamp {
+            log::debug!(
+                "[RTPS-CONTEXT] Applied INFO_TS: timestamp=({}, {})",
+                sec,
+                frac
+            );
+        }
+
+        // Push to ring (non-blocking)
+        if self.ring.push((meta, buffer_id)).is_err() {
+            // Ring full, release buffer to avoid leak
+            if let Err(e) = self.pool.release(buffer_id) {
+                log::debug!(
+                    "[hdds-mcast-rx] CRITICAL: Failed to release buffer {}: {}",
+                    buffer_id,
+                    e
+                );
+            }
+            self.metrics.packets_dropped.fetch_add(1, Ordering::Relaxed);
+            log::debug!("[hdds-mcast-rx] Ring full, dropping {:?} packet", kind);
+        } else {
+            // v211: Always notify to ensure router wakes from idle
+            // The spin loop in router catches most hot traffic before condvar
+            if let Some(ref n) = self.notifier {
+                n.notify();
+            }
         }
     }
 }
@@ -772,7 +866,7 @@ mod tests {
 
         // Spawn listener with shared socket
         let listener = MulticastListener::spawn(
-            transport.socket(),
+            transport.socket().expect("UDP socket"),
             Arc::clone(&pool),
             Arc::clone(&ring),
             None, // No discovery callback for this test
@@ -848,7 +942,7 @@ mod tests {
         })?;
 
         let listener = MulticastListener::spawn(
-            transport.socket(),
+            transport.socket().expect("UDP socket"),
             Arc::clone(&pool),
             Arc::clone(&ring),
             None, // No callback for this test
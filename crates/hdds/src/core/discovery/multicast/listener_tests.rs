@@ -21,7 +21,7 @@
 
         // Spawn listener with shared socket
         let listener = MulticastListener::spawn(
-            transport.socket(),
+            transport.socket().expect("UDP socket"),
             Arc::clone(&pool),
             Arc::clone(&ring),
             None, // No discovery callback for this test
@@ -97,7 +97,7 @@
         })?;
 
         let listener = MulticastListener::spawn(
-            transport.socket(),
+            transport.socket().expect("UDP socket"),
             Arc::clone(&pool),
             Arc::clone(&ring),
             None, // No callback for this test
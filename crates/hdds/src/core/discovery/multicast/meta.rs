@@ -102,6 +102,9 @@ pub struct RxMeta {
     pub len: u16,
     /// Timestamp when packet was received (for latency tracking)
     pub ts: Instant,
+    /// Kernel receive timestamp in nanoseconds since the UNIX epoch
+    /// (`SO_TIMESTAMPNS`), when the socket provides one
+    pub kernel_rx_ns: Option<u64>,
     /// Classified packet type
     pub kind: PacketKind,
     /// Offset to DATA submessage payload (for RTI vendor-specific headers)
@@ -142,6 +145,7 @@ impl RxMeta {
             sock,
             len,
             ts: Instant::now(),
+            kernel_rx_ns: None,
             kind,
             data_payload_offset: None,
             frag_meta: None,
@@ -162,6 +166,7 @@ impl RxMeta {
             sock,
             len,
             ts: Instant::now(),
+            kernel_rx_ns: None,
             kind,
             data_payload_offset: offset,
             frag_meta: None,
@@ -185,6 +190,7 @@ impl RxMeta {
             sock,
             len,
             ts: Instant::now(),
+            kernel_rx_ns: None,
             kind,
             data_payload_offset: offset,
             frag_meta: Some(frag_meta),
@@ -246,9 +252,10 @@ mod tests {
     fn test_rx_meta_size() {
         // Ensure RxMeta fits in expected memory budget
         // SocketAddr (28 bytes max) + u16 (2) + Instant (12) + PacketKind (1) +
-        // Option<u16> (4) + Option<FragmentMetadata> (48) + RtpsContext (28) + padding
+        // Option<u16> (4) + Option<FragmentMetadata> (48) + RtpsContext (28) +
+        // Option<u64> kernel timestamp (16) + padding
         let size = std::mem::size_of::<RxMeta>();
         println!("RxMeta size: {} bytes", size);
-        assert!(size <= 140); // v61: Increased to accommodate RtpsContext (128 bytes actual)
+        assert!(size <= 152); // 144 bytes actual with the kernel RX timestamp
     }
 }
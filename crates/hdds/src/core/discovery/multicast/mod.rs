@@ -23,6 +23,7 @@
 //! # Modules
 //!
 //! - [`meta`]: Packet metadata and classification types
+//! - [`kernel_timestamps`]: Kernel receive timestamps (`SO_TIMESTAMPNS`)
 //! - [`pool`]: Lock-free buffer pool for zero-allocation RX
 //! - [`tiny_vec`]: Small-vector optimization for topic index
 //! - [`dialect_detector`]: Auto-detect vendor implementation
@@ -42,6 +43,7 @@ pub mod control_parser;
 pub mod control_types;
 pub mod dialect_detector;
 pub mod fsm;
+pub mod kernel_timestamps;
 pub mod lease;
 pub mod listener;
 pub mod meta;
@@ -71,9 +73,10 @@ pub use meta::{FragmentMetadata, PacketKind, RtpsContext, RxMeta};
 pub use dialect_detector::should_skip_spdp_barrier_for_packet;
 pub use pool::RxPool;
 pub use rtps_packet::{
-    build_heartbeat_submessage, build_heartbeat_submessage_final, build_sedp_rtps_packet,
+    build_heartbeat_submessage, build_heartbeat_submessage_final, build_keepalive_packet,
+    build_sedp_dispose_packet, build_sedp_rtps_packet, build_spdp_dispose_packet,
     build_spdp_rtps_packet, get_publications_last_seq, get_subscriptions_last_seq,
-    next_publications_seq, next_subscriptions_seq, SedpEndpointKind,
+    next_publications_seq, next_subscriptions_seq, parse_dispose_rtps_packet, SedpEndpointKind,
 };
 pub use spdp::{FsmState, ParticipantInfo};
 pub use tiny_vec::TinyVec;
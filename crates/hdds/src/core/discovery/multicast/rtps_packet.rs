@@ -8,6 +8,7 @@
 //! proper headers and submessages, ensuring interoperability with
 //! RTI Connext, FastDDS, and other DDS implementations.
 
+use crate::core::discovery::GUID;
 use crate::core::rtps_constants::*;
 use crate::protocol::dialect::{
     build_sedp_for_dialect, get_encoder, Dialect, Guid, QosProfile, SedpEndpointData,
@@ -330,6 +331,12 @@ pub fn build_sedp_rtps_packet(
     #[cfg(not(feature = "xtypes"))]
     let type_object_bytes: Option<Vec<u8>> = None;
 
+    // Vendor PID: only peers speaking an HDDS dialect understand it.
+    let type_versions_bytes = match dialect {
+        Dialect::Hdds | Dialect::Hybrid => sedp_data.type_versions.as_ref().map(|a| a.to_bytes()),
+        _ => None,
+    };
+
     // Step 1: Build SEDP CDR payload using dialect encoder
     // Convert legacy SedpData to new SedpEndpointData format
     let endpoint_guid = Guid {
@@ -354,6 +361,7 @@ pub fn build_sedp_rtps_packet(
             History::KeepLast(depth) => (0, depth), // KEEP_LAST
             History::KeepAll => (1, 0),             // KEEP_ALL
         };
+        let separation = q.time_based_filter.minimum_separation;
 
         QosProfile {
             reliability_kind: match q.reliability {
@@ -367,6 +375,10 @@ pub fn build_sedp_rtps_packet(
             },
             history_kind,
             history_depth,
+            time_based_filter_sec: u32::try_from(separation.as_secs()).unwrap_or(u32::MAX),
+            time_based_filter_nsec: separation.subsec_nanos(),
+            partition: q.partition.names.clone(),
+            metadata: sedp_data.metadata.clone(),
             ..Default::default()
         }
     });
@@ -380,6 +392,7 @@ pub fn build_sedp_rtps_packet(
         multicast_locators: &[],
         qos: qos_profile.as_ref(),
         type_object: type_object_bytes.as_deref(),
+        type_versions: type_versions_bytes.as_deref(),
     };
 
     // Use dialect-specific encoder
@@ -470,6 +483,199 @@ pub fn build_sedp_rtps_packet(
     Ok(packet)
 }
 
+/// Build the RTPS packet refreshing the NAT mapping toward a peer.
+///
+/// Only an RTPS header and an INFO_DST for `destination_prefix`: the peer
+/// has nothing to process, but the datagram keeps the mapping of the
+/// sending socket alive on the NATs along the path.
+pub fn build_keepalive_packet(
+    participant_guid_prefix: &[u8; 12],
+    destination_prefix: &[u8; 12],
+) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(20 + 16);
+    packet.extend_from_slice(RTPS_MAGIC);
+    packet.extend_from_slice(&[RTPS_VERSION_MAJOR, RTPS_VERSION_MINOR]);
+    packet.extend_from_slice(&HDDS_VENDOR_ID);
+    packet.extend_from_slice(participant_guid_prefix);
+    packet.extend_from_slice(&build_info_dst_submessage(destination_prefix));
+    packet
+}
+
+/// `PID_PARTICIPANT_GUID`: serialized key of an SPDP sample.
+const PID_PARTICIPANT_GUID: u16 = 0x0050;
+
+/// `PID_ENDPOINT_GUID`: serialized key of an SEDP sample.
+const PID_ENDPOINT_GUID: u16 = 0x005a;
+
+/// Build RTPS packet disposing a participant through SPDP.
+///
+/// Sent on shutdown so that peers remove the participant and its endpoints
+/// at once instead of waiting for its lease to expire. `sequence_number`
+/// must follow the last SPDP announcement of the participant.
+pub fn build_spdp_dispose_packet(participant_guid: &GUID, sequence_number: u64) -> Vec<u8> {
+    build_dispose_packet(
+        &RTPS_ENTITYID_SPDP_READER,
+        &RTPS_ENTITYID_SPDP_WRITER,
+        PID_PARTICIPANT_GUID,
+        participant_guid,
+        participant_guid.prefix,
+        None,
+        sequence_number,
+    )
+}
+
+/// Build RTPS packet disposing a local writer or reader through SEDP.
+///
+/// `seq_num` comes from the SEDP writer of `endpoint_kind`, as for
+/// [`build_sedp_rtps_packet`].
+pub fn build_sedp_dispose_packet(
+    endpoint_guid: &GUID,
+    endpoint_kind: SedpEndpointKind,
+    destination_prefix: Option<&[u8; 12]>,
+    seq_num: u64,
+) -> Vec<u8> {
+    let (reader_id, writer_id) = match endpoint_kind {
+        SedpEndpointKind::Writer => (
+            RTPS_ENTITYID_SEDP_PUBLICATIONS_READER,
+            RTPS_ENTITYID_SEDP_PUBLICATIONS_WRITER,
+        ),
+        SedpEndpointKind::Reader => (
+            RTPS_ENTITYID_SEDP_SUBSCRIPTIONS_READER,
+            RTPS_ENTITYID_SEDP_SUBSCRIPTIONS_WRITER,
+        ),
+    };
+    build_dispose_packet(
+        &reader_id,
+        &writer_id,
+        PID_ENDPOINT_GUID,
+        endpoint_guid,
+        endpoint_guid.prefix,
+        destination_prefix,
+        seq_num,
+    )
+}
+
+/// Build a discovery DATA disposing and unregistering the instance `guid`
+/// (RTPS v2.3 Sec.8.5.3.3, 9.6.3.9).
+///
+/// The inline QoS carries `PID_KEY_HASH` (the GUID) and `PID_STATUS_INFO`;
+/// the payload is the serialized key, `key_pid` holding the GUID.
+fn build_dispose_packet(
+    reader_id: &[u8; 4],
+    writer_id: &[u8; 4],
+    key_pid: u16,
+    guid: &GUID,
+    participant_guid_prefix: [u8; 12],
+    destination_prefix: Option<&[u8; 12]>,
+    sequence_number: u64,
+) -> Vec<u8> {
+    use crate::protocol::constants::{
+        PID_KEY_HASH, PID_STATUS_INFO, STATUS_INFO_DISPOSED, STATUS_INFO_UNREGISTERED,
+    };
+
+    let mut inline_qos = Vec::with_capacity(32);
+    inline_qos.extend_from_slice(&PID_KEY_HASH.to_le_bytes());
+    inline_qos.extend_from_slice(&16u16.to_le_bytes());
+    inline_qos.extend_from_slice(&guid.as_bytes());
+    inline_qos.extend_from_slice(&PID_STATUS_INFO.to_le_bytes());
+    inline_qos.extend_from_slice(&4u16.to_le_bytes());
+    inline_qos.extend_from_slice(&[0, 0, 0, STATUS_INFO_DISPOSED | STATUS_INFO_UNREGISTERED]);
+    inline_qos.extend_from_slice(&[0x01, 0x00, 0x00, 0x00]); // PID_SENTINEL
+
+    // PL_CDR_LE serialized key
+    let mut key = Vec::with_capacity(28);
+    key.extend_from_slice(&[0x00, 0x03, 0x00, 0x00]);
+    key.extend_from_slice(&key_pid.to_le_bytes());
+    key.extend_from_slice(&16u16.to_le_bytes());
+    key.extend_from_slice(&guid.as_bytes());
+    key.extend_from_slice(&[0x01, 0x00, 0x00, 0x00]); // PID_SENTINEL
+
+    // Flags: E (little-endian) | Q (inline QoS) | K (serialized key)
+    let data_len = 20 + inline_qos.len() + key.len();
+    let mut packet = Vec::with_capacity(20 + 16 + 12 + 4 + data_len);
+    packet.extend_from_slice(RTPS_MAGIC);
+    packet.extend_from_slice(&[RTPS_VERSION_MAJOR, RTPS_VERSION_MINOR]);
+    packet.extend_from_slice(&HDDS_VENDOR_ID);
+    packet.extend_from_slice(&participant_guid_prefix);
+    if let Some(prefix) = destination_prefix {
+        packet.extend_from_slice(&build_info_dst_submessage(prefix));
+    }
+    packet.extend_from_slice(&build_info_ts_submessage());
+    packet.extend_from_slice(&[RTPS_SUBMSG_DATA, 0x01 | 0x02 | 0x08]);
+    packet.extend_from_slice(&(data_len as u16).to_le_bytes());
+    packet.extend_from_slice(&[0x00, 0x00]); // extraFlags
+    packet.extend_from_slice(&16u16.to_le_bytes()); // octetsToInlineQos
+    packet.extend_from_slice(reader_id);
+    packet.extend_from_slice(writer_id);
+    packet.extend_from_slice(&((sequence_number >> 32) as u32).to_le_bytes());
+    packet.extend_from_slice(&(sequence_number as u32).to_le_bytes());
+    packet.extend_from_slice(&inline_qos);
+    packet.extend_from_slice(&key);
+    packet
+}
+
+/// GUID of the participant or endpoint disposed or unregistered by a
+/// discovery DATA, if `packet` carries one.
+///
+/// Looks for a DATA submessage whose inline QoS holds a `PID_STATUS_INFO`
+/// with the disposed or unregistered flag, and returns its `PID_KEY_HASH`
+/// (the GUID of the instance for the built-in topics).
+pub fn parse_dispose_rtps_packet(packet: &[u8]) -> Option<GUID> {
+    use crate::protocol::constants::{
+        PID_KEY_HASH, PID_STATUS_INFO, STATUS_INFO_DISPOSED, STATUS_INFO_UNREGISTERED,
+    };
+
+    if packet.len() < 20 || &packet[..4] != RTPS_MAGIC {
+        return None;
+    }
+
+    let mut offset = 20;
+    while offset + 4 <= packet.len() {
+        let id = packet[offset];
+        let flags = packet[offset + 1];
+        let little_endian = flags & 0x01 != 0;
+        let read_u16 = |at: usize| -> Option<u16> {
+            let bytes = [*packet.get(at)?, *packet.get(at + 1)?];
+            Some(match little_endian {
+                true => u16::from_le_bytes(bytes),
+                false => u16::from_be_bytes(bytes),
+            })
+        };
+        let len = read_u16(offset + 2)? as usize;
+        let body = offset + 4;
+        let end = if len == 0 { packet.len() } else { body + len };
+
+        if id == RTPS_SUBMSG_DATA && flags & 0x02 != 0 {
+            let mut param = body + 4 + read_u16(body + 2)? as usize;
+            let mut key_hash = None;
+            let mut status_info = 0;
+            while param + 4 <= end.min(packet.len()) {
+                let pid = read_u16(param)?;
+                let param_len = read_u16(param + 2)? as usize;
+                let value = packet.get(param + 4..param + 4 + param_len)?;
+                match pid {
+                    0x0001 => break, // PID_SENTINEL
+                    PID_KEY_HASH if param_len >= 16 => {
+                        key_hash = <[u8; 16]>::try_from(&value[..16]).ok();
+                    }
+                    PID_STATUS_INFO if param_len >= 4 => status_info = value[3],
+                    _ => {}
+                }
+                param += 4 + param_len;
+            }
+            if status_info & (STATUS_INFO_DISPOSED | STATUS_INFO_UNREGISTERED) != 0 {
+                return key_hash.map(GUID::from_bytes);
+            }
+        }
+
+        if len == 0 {
+            break;
+        }
+        offset = end;
+    }
+    None
+}
+
 /// Build RTPS packet for TypeLookup request/response (HDDS-only).
 ///
 /// Uses the built-in TypeLookup entity IDs and CDR2 payload.
@@ -545,11 +751,6 @@ fn build_type_object_for_dialect(sedp_data: &SedpData, dialect: Dialect) -> Opti
     }
 }
 
-#[cfg(not(feature = "xtypes"))]
-fn build_type_object_for_dialect(_sedp_data: &SedpData, _dialect: Dialect) -> Option<Vec<u8>> {
-    None
-}
-
 /// Build HEARTBEAT submessage using DialectEncoder (RTPS v2.5 Sec.8.3.7.5)
 ///
 /// Delegates to the dialect encoder for vendor-compatible HEARTBEAT encoding.
@@ -673,6 +874,7 @@ mod tests {
             default_multicast_locators: vec![],
             metatraffic_multicast_locators: vec![],
             identity_token: None,
+            user_data: Default::default(),
         };
 
         let packet = build_spdp_rtps_packet(&spdp_data, 1, None).expect("Failed to build");
@@ -702,6 +904,7 @@ mod tests {
             default_multicast_locators: vec![],
             metatraffic_multicast_locators: vec![],
             identity_token: None,
+            user_data: Default::default(),
         };
 
         let packet = build_spdp_rtps_packet(&spdp_data, 42, None).expect("Failed to build packet");
@@ -763,4 +966,35 @@ mod tests {
             "PID_METATRAFFIC_UNICAST_LOCATOR not found in SPDP packet"
         );
     }
+
+    #[test]
+    fn test_dispose_packets_roundtrip() {
+        let participant = GUID::from_bytes([7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 0, 0, 1, 0xC1]);
+        let packet = build_spdp_dispose_packet(&participant, 12);
+        assert_eq!(&packet[8..20], &participant.prefix);
+        assert_eq!(parse_dispose_rtps_packet(&packet), Some(participant));
+
+        let writer = GUID::new(participant.prefix, [0, 0, 0x12, 0x02]);
+        let packet =
+            build_sedp_dispose_packet(&writer, SedpEndpointKind::Writer, Some(&[9; 12]), 3);
+        assert_eq!(parse_dispose_rtps_packet(&packet), Some(writer));
+        // Header, INFO_DST and INFO_TS, then the DATA
+        assert_eq!(packet[48], RTPS_SUBMSG_DATA);
+        assert_eq!(&packet[60..64], &RTPS_ENTITYID_SEDP_PUBLICATIONS_WRITER);
+
+        // Plain announcements are not disposals
+        let spdp_data = SpdpData {
+            participant_guid: participant,
+            lease_duration_ms: 30_000,
+            domain_id: 0,
+            metatraffic_unicast_locators: vec![],
+            default_unicast_locators: vec![],
+            default_multicast_locators: vec![],
+            metatraffic_multicast_locators: vec![],
+            identity_token: None,
+            user_data: Default::default(),
+        };
+        let packet = build_spdp_rtps_packet(&spdp_data, 1, None).expect("spdp");
+        assert_eq!(parse_dispose_rtps_packet(&packet), None);
+    }
 }
@@ -7,6 +7,7 @@
 //! Each participant transitions through: Idle -> Announced -> Discovered -> Active.
 
 use crate::core::discovery::GUID;
+use crate::qos::metadata::UserData;
 use std::net::SocketAddr;
 use std::time::Instant;
 
@@ -47,6 +48,11 @@ pub struct ParticipantInfo {
     pub last_seen: Instant,
     /// Current FSM state
     pub state: FsmState,
+    /// USER_DATA announced by the participant (empty if none)
+    pub user_data: UserData,
+    /// Identity token the participant authenticated with (DDS Security),
+    /// kept to re-validate it when the security documents change
+    pub identity_token: Option<Vec<u8>>,
 }
 
 impl ParticipantInfo {
@@ -75,6 +81,8 @@ impl ParticipantInfo {
             lease_duration_ms,
             last_seen: Instant::now(),
             state: FsmState::Discovered,
+            user_data: UserData::default(),
+            identity_token: None,
         }
     }
 
@@ -93,7 +101,12 @@ impl ParticipantInfo {
     /// }
     /// ```
     pub fn is_expired(&self) -> bool {
-        let elapsed = self.last_seen.elapsed();
+        self.is_expired_at(Instant::now())
+    }
+
+    /// Check if the lease has expired at `now` (see `Clock::instant`).
+    pub fn is_expired_at(&self, now: Instant) -> bool {
+        let elapsed = now.saturating_duration_since(self.last_seen);
         elapsed.as_millis() as u64 > self.lease_duration_ms
     }
 
@@ -111,7 +124,12 @@ impl ParticipantInfo {
     /// info.refresh();
     /// ```
     pub fn refresh(&mut self) {
-        self.last_seen = Instant::now();
+        self.refresh_at(Instant::now());
+    }
+
+    /// Reset the lease timer to `now` (see `Clock::instant`).
+    pub fn refresh_at(&mut self, now: Instant) {
+        self.last_seen = now;
     }
 }
 
@@ -73,7 +73,7 @@ impl Discovery {
     /// Discovery instance with parsed seed peers
     ///
     /// # Errors
-    /// Returns Error::Config if seed string is malformed
+    /// Returns Error::InvalidConfig if seed string is malformed
     ///
     /// # Examples
     ///
@@ -145,6 +145,7 @@ impl Discovery {
             default_multicast_locators: Vec::new(),
             metatraffic_multicast_locators: Vec::new(),
             identity_token: None,
+            user_data: Default::default(),
         };
 
         // v110: Use centralized builder with DialectEncoder support
@@ -207,7 +208,7 @@ fn send_to_seeds(packet: &[u8], seeds: &[NetPeer]) -> crate::dds::Result<()> {
 /// Vec<NetPeer> on success
 ///
 /// # Errors
-/// - Error::Config: Malformed host:port format
+/// - Error::InvalidConfig: Malformed host:port format
 fn parse_seed_peers(seed_str: &str) -> Result<Vec<NetPeer>> {
     if seed_str.trim().is_empty() {
         return Ok(Vec::new());
@@ -220,15 +221,18 @@ fn parse_seed_peers(seed_str: &str) -> Result<Vec<NetPeer>> {
             let parts: Vec<&str> = s.split(':').collect();
 
             if parts.len() != 2 {
-                return Err(crate::dds::Error::Config);
+                return Err(crate::dds::Error::config(format!(
+                    "seed peer '{}' is not host:port",
+                    s
+                )));
             }
 
-            let addr = parts[0]
-                .parse::<Ipv4Addr>()
-                .map_err(|_| crate::dds::Error::Config)?;
-            let port = parts[1]
-                .parse::<u16>()
-                .map_err(|_| crate::dds::Error::Config)?;
+            let addr = parts[0].parse::<Ipv4Addr>().map_err(|e| {
+                crate::dds::Error::config(format!("seed peer '{}': bad IPv4 address: {}", s, e))
+            })?;
+            let port = parts[1].parse::<u16>().map_err(|e| {
+                crate::dds::Error::config(format!("seed peer '{}': bad port: {}", s, e))
+            })?;
 
             Ok(NetPeer::from_ipv4(addr, port))
         })
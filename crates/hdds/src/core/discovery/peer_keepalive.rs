@@ -0,0 +1,182 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Keepalive traffic toward unicast peers, for links crossing NATs.
+//!
+//! A NAT drops the mapping of a UDP flow that stays idle for its timeout
+//! (often 30 s to a few minutes), without telling either end. A field
+//! device whose topics are quiet between bursts then stops receiving until
+//! its next SPDP announcement happens to reopen the path. The keepalive
+//! sends a tiny RTPS message (header + INFO_DST) to every unicast locator
+//! of every discovered peer, from the metatraffic and user data sockets,
+//! at a low fixed rate so these mappings never go idle.
+//!
+//! Peers whose NAT hands them a new public address are followed by
+//! [`DiscoveryFsm::resolve_reflexive_address`].
+
+use crate::core::discovery::multicast::{build_keepalive_packet, DiscoveryFsm};
+use crate::transport::UdpTransport;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Background thread sending keepalives to the discovered peers.
+///
+/// Stops on drop.
+pub struct PeerKeepalive {
+    /// Dropping the sender wakes the thread up and stops it
+    stop_tx: Option<mpsc::Sender<()>>,
+    /// Background thread handle
+    handle: Option<JoinHandle<()>>,
+    /// Keepalive datagrams sent so far
+    sent: Arc<AtomicU64>,
+}
+
+impl PeerKeepalive {
+    /// Send a keepalive to each unicast locator of the peers known to `fsm`
+    /// every `interval`.
+    ///
+    /// `interval` should stay below the shortest NAT UDP timeout on the
+    /// path; 15-25 s suits most gateways.
+    pub fn start(
+        local_prefix: [u8; 12],
+        transport: Arc<UdpTransport>,
+        fsm: Arc<DiscoveryFsm>,
+        interval: Duration,
+    ) -> std::io::Result<Self> {
+        let (stop_tx, stop_rx) = mpsc::channel::<()>();
+        let sent = Arc::new(AtomicU64::new(0));
+        let sent_clone = Arc::clone(&sent);
+
+        let handle = thread::Builder::new()
+            .name("hdds-peer-keepalive".to_string())
+            .spawn(move || {
+                while let Err(RecvTimeoutError::Timeout) = stop_rx.recv_timeout(interval) {
+                    let count = send_keepalives(&local_prefix, &transport, &fsm);
+                    sent_clone.fetch_add(count, Ordering::Relaxed);
+                }
+            })?;
+
+        Ok(Self {
+            stop_tx: Some(stop_tx),
+            handle: Some(handle),
+            sent,
+        })
+    }
+
+    /// Number of keepalive datagrams sent.
+    pub fn sent(&self) -> u64 {
+        self.sent.load(Ordering::Relaxed)
+    }
+
+    /// Stop the keepalive thread and wait for it to exit.
+    pub fn stop(&mut self) {
+        drop(self.stop_tx.take());
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for PeerKeepalive {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Send one keepalive round; returns the number of datagrams sent.
+fn send_keepalives(local_prefix: &[u8; 12], transport: &UdpTransport, fsm: &DiscoveryFsm) -> u64 {
+    // Nothing leaves a suspended transport
+    if transport.is_suspended() {
+        return 0;
+    }
+    let user_endpoints = fsm.endpoint_registry();
+    let mut sent = 0;
+    for peer in fsm.get_participants() {
+        let packet = build_keepalive_packet(local_prefix, &peer.guid.prefix);
+        for locator in &peer.endpoints {
+            match transport.send_to_endpoint(&packet, locator) {
+                Ok(_) => sent += 1,
+                Err(e) => log::debug!("[keepalive] send to {} failed: {}", locator, e),
+            }
+        }
+        if let Some(locator) = user_endpoints.get(&peer.guid) {
+            match transport.send_user_data_unicast(&packet, &locator) {
+                Ok(_) => sent += 1,
+                Err(e) => log::debug!("[keepalive] send to {} failed: {}", locator, e),
+            }
+        }
+    }
+    log::trace!("[keepalive] {} datagram(s) sent", sent);
+    sent
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::discovery::GUID;
+    use crate::protocol::discovery::SpdpData;
+    use crate::transport::loopback::LoopbackBus;
+
+    #[test]
+    fn test_keepalive_reaches_peer_locators() {
+        let bus = Arc::new(LoopbackBus::new());
+        let local = bus.attach(0, None).expect("attach");
+        let peer = bus.attach(0, None).expect("attach");
+        let inbox = peer.receiver();
+
+        let local_prefix = [1u8; 12];
+        let peer_guid = GUID::new([2u8; 12], [0, 0, 1, 0xc1]);
+        let fsm = Arc::new(DiscoveryFsm::new(
+            GUID::new(local_prefix, [0, 0, 1, 0xc1]),
+            100_000,
+        ));
+        fsm.handle_spdp(SpdpData {
+            participant_guid: peer_guid,
+            lease_duration_ms: 100_000,
+            domain_id: 0,
+            metatraffic_unicast_locators: vec![peer.metatraffic_addr()],
+            default_unicast_locators: vec![peer.user_addr()],
+            default_multicast_locators: vec![],
+            metatraffic_multicast_locators: vec![],
+            identity_token: None,
+            user_data: Default::default(),
+        });
+
+        let transport = Arc::new(UdpTransport::loopback(0, local));
+        let keepalive = PeerKeepalive::start(
+            local_prefix,
+            Arc::clone(&transport),
+            fsm,
+            Duration::from_millis(20),
+        )
+        .expect("start");
+
+        let expected = build_keepalive_packet(&local_prefix, &peer_guid.prefix);
+        assert_eq!(&expected[..4], b"RTPS");
+        assert_eq!(&expected[8..20], &local_prefix);
+        assert_eq!(expected[20], 0x0e, "INFO_DST");
+        assert_eq!(&expected[24..36], &peer_guid.prefix);
+
+        let mut sources = Vec::new();
+        while sources.len() < 2 {
+            let datagram = inbox
+                .recv_timeout(Duration::from_secs(2))
+                .expect("keepalive");
+            assert_eq!(datagram.data, expected);
+            if !sources.contains(&datagram.src) {
+                sources.push(datagram.src);
+            }
+        }
+        assert!(keepalive.sent() >= 2);
+
+        // Silent while the transport is suspended
+        transport.set_suspended(true);
+        thread::sleep(Duration::from_millis(50));
+        let sent = keepalive.sent();
+        thread::sleep(Duration::from_millis(100));
+        assert_eq!(keepalive.sent(), sent);
+    }
+}
@@ -12,12 +12,13 @@
 //! themselves and discovery remains empty.
 
 use crate::config::{
-    RuntimeConfig, DATA_MULTICAST_OFFSET, DOMAIN_ID_GAIN, MULTICAST_IP, PORT_BASE,
-    SPDP_MULTICAST_PORT_DOMAIN0, USER_UNICAST_PORT_DOMAIN0_P0,
+    RuntimeConfig, DATA_MULTICAST_OFFSET, DOMAIN_ID_GAIN, PORT_BASE, SPDP_MULTICAST_PORT_DOMAIN0,
+    USER_UNICAST_PORT_DOMAIN0_P0,
 };
-use crate::core::discovery::multicast::build_spdp_rtps_packet;
-use crate::core::discovery::GUID;
+use crate::core::discovery::multicast::{build_spdp_rtps_packet, DiscoveryFsm};
+use crate::core::discovery::{InitialPeer, InitialPeerSet, GUID};
 use crate::protocol::discovery::SpdpData;
+use crate::qos::metadata::UserData;
 use crate::transport::UdpTransport;
 use std::net::SocketAddr;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
@@ -51,6 +52,8 @@ pub struct SpdpAnnouncer {
     handle: Option<JoinHandle<()>>,
     /// Shutdown signal (set to true to stop announcer)
     shutdown: Arc<AtomicBool>,
+    /// Sequence number of the next announcement
+    sequence_number: Arc<AtomicU64>,
 }
 
 impl SpdpAnnouncer {
@@ -86,6 +89,38 @@ impl SpdpAnnouncer {
         Self::spawn_with_security(participant_guid, transport, lease_duration_ms, config, None)
     }
 
+    /// Spawn SPDP announcer thread that also announces via unicast to initial peers.
+    ///
+    /// Each announcement goes to the multicast group and to every destination
+    /// of `initial_peers` (see [`InitialPeer`]). Participants known to
+    /// `discovery_fsm` are announced to via their metatraffic unicast
+    /// locators as well, so the peer list does not have to name every
+    /// participant of the deployment. Announcements carry `user_data` as
+    /// the participant USER_DATA.
+    #[must_use]
+    pub fn spawn_with_initial_peers(
+        participant_guid: GUID,
+        transport: Arc<UdpTransport>,
+        lease_duration_ms: u64,
+        config: Arc<RuntimeConfig>,
+        initial_peers: Vec<InitialPeer>,
+        discovery_fsm: Option<Arc<DiscoveryFsm>>,
+        user_data: UserData,
+    ) -> Self {
+        Self::spawn_inner(
+            participant_guid,
+            transport,
+            lease_duration_ms,
+            config,
+            None,
+            user_data,
+            UnicastAnnounce {
+                initial_peers,
+                discovery_fsm,
+            },
+        )
+    }
+
     /// Spawn SPDP announcer thread with DDS Security identity token.
     ///
     /// # Arguments
@@ -108,8 +143,31 @@ impl SpdpAnnouncer {
         identity_token: Option<Vec<u8>>,
     ) -> Self {
         crate::trace_fn!("SpdpAnnouncer::spawn_with_security");
+        Self::spawn_inner(
+            participant_guid,
+            transport,
+            lease_duration_ms,
+            config,
+            identity_token,
+            UserData::default(),
+            UnicastAnnounce::default(),
+        )
+    }
+
+    fn spawn_inner(
+        participant_guid: GUID,
+        transport: Arc<UdpTransport>,
+        lease_duration_ms: u64,
+        config: Arc<RuntimeConfig>,
+        identity_token: Option<Vec<u8>>,
+        user_data: UserData,
+        unicast: UnicastAnnounce,
+    ) -> Self {
         let shutdown = Arc::new(AtomicBool::new(false));
         let shutdown_clone = Arc::clone(&shutdown);
+        // Sequence number counter (starts at 1 per RTPS spec)
+        let sequence_number = Arc::new(AtomicU64::new(1));
+        let sequence_clone = Arc::clone(&sequence_number);
 
         let handle = thread::spawn(move || {
             announcer_loop(
@@ -118,13 +176,17 @@ impl SpdpAnnouncer {
                 lease_duration_ms,
                 config,
                 shutdown_clone,
+                sequence_clone,
                 identity_token,
+                user_data,
+                unicast,
             );
         });
 
         Self {
             handle: Some(handle),
             shutdown,
+            sequence_number,
         }
     }
 
@@ -133,34 +195,60 @@ impl SpdpAnnouncer {
     /// This is automatically called on Drop, but can be explicitly invoked
     /// if synchronous shutdown is required.
     pub fn shutdown(mut self) {
+        self.stop();
+    }
+
+    /// Stop the announcer thread, keeping the announcer around (see
+    /// [`next_sequence_number`](Self::next_sequence_number)).
+    pub fn stop(&mut self) {
         self.shutdown.store(true, Ordering::Relaxed);
         if let Some(handle) = self.handle.take() {
             let _ = handle.join();
         }
     }
+
+    /// Sequence number the next SPDP announcement would use.
+    ///
+    /// A final SPDP DATA sent after [`stop`](Self::stop) (the participant
+    /// dispose) takes it, so that peers do not discard it as a duplicate.
+    #[must_use]
+    pub fn next_sequence_number(&self) -> u64 {
+        self.sequence_number.load(Ordering::Relaxed)
+    }
 }
 
 impl Drop for SpdpAnnouncer {
     fn drop(&mut self) {
-        self.shutdown.store(true, Ordering::Relaxed);
-        if let Some(handle) = self.handle.take() {
-            let _ = handle.join();
-        }
+        self.stop();
     }
 }
 
+/// Unicast SPDP destinations beyond the multicast group.
+#[derive(Default)]
+struct UnicastAnnounce {
+    /// Peers from `ParticipantBuilder::with_initial_peers`
+    initial_peers: Vec<InitialPeer>,
+    /// Discovered participants are announced to when initial peers are set
+    discovery_fsm: Option<Arc<DiscoveryFsm>>,
+}
+
 /// Main announcer loop (runs in background thread).
 ///
 /// Sends SPDP RTPS packets every 3 seconds until shutdown signal is received.
 /// Each packet includes a complete RTPS header with incrementing sequence numbers.
+#[allow(clippy::too_many_arguments)]
 fn announcer_loop(
     participant_guid: GUID,
     transport: Arc<UdpTransport>,
     lease_duration_ms: u64,
     config: Arc<RuntimeConfig>,
     shutdown: Arc<AtomicBool>,
+    sequence_number: Arc<AtomicU64>,
     identity_token: Option<Vec<u8>>,
+    user_data: UserData,
+    unicast: UnicastAnnounce,
 ) {
+    crate::alloc_scope!(Discovery);
     // RTPS v2.3 default periodic announcement interval.
     const ANNOUNCEMENT_INTERVAL_SECS: u64 = 3;
     // Aggressive burst phase at startup to reduce SPDP->SEDP races with
@@ -172,10 +260,8 @@ fn announcer_loop(
 
     let normal_interval = Duration::from_secs(ANNOUNCEMENT_INTERVAL_SECS);
     let aggressive_interval = Duration::from_millis(AGGRESSIVE_INTERVAL_MS);
-    let start_instant = std::time::Instant::now();
-
-    // Sequence number counter (starts at 1 per RTPS spec)
-    let sequence_number = AtomicU64::new(1);
+    let mut start_instant = std::time::Instant::now();
+    let mut was_suspended = false;
 
     // v79: Get all locator types needed for RTI interop (RTPS v2.3 Sec.8.5.3.1)
     // RTI needs to know WHERE to send different types of traffic:
@@ -212,13 +298,9 @@ fn announcer_loop(
         })
         .collect();
 
-    // Build multicast locators (ports from config)
-    let default_multicast_locators = vec![
-        std::net::SocketAddr::from((MULTICAST_IP, data_multicast_port)), // [OK] From config
-    ];
-    let metatraffic_multicast_locators = vec![
-        std::net::SocketAddr::from((MULTICAST_IP, spdp_multicast_port)), // [OK] From config
-    ];
+    // Build multicast locators (ports from config, one group per address family)
+    let default_multicast_locators = transport.multicast_locators(data_multicast_port);
+    let metatraffic_multicast_locators = transport.multicast_locators(spdp_multicast_port);
 
     log::debug!("[spdp_announcer] v79: Announcing locators (RTI interop fix):");
     log::debug!(
@@ -253,36 +335,38 @@ fn announcer_loop(
     // we will send each SPDP announce both to multicast AND to these unicast endpoints.
     // This is particularly useful when remote stacks (FastDDS/RTI) do not see our
     // multicast SPDP due to IGMP/interface quirks but do listen on metatraffic unicast.
-    let spdp_unicast_peers: Vec<SocketAddr> = std::env::var("HDDS_SPDP_UNICAST_PEERS")
-        .ok()
-        .map(|raw| {
-            raw.split(',')
-                .filter_map(|s| {
-                    let trimmed = s.trim();
-                    if trimmed.is_empty() {
-                        None
-                    } else {
-                        match trimmed.parse::<SocketAddr>() {
-                            Ok(addr) => Some(addr),
-                            Err(err) => {
-                                log::debug!(
-                                    "[spdp_announcer] Ignoring invalid HDDS_SPDP_UNICAST_PEERS entry '{}': {}",
-                                    trimmed,
-                                    err
-                                );
-                                None
-                            }
-                        }
-                    }
-                })
-                .collect()
-        })
-        .unwrap_or_default();
+    // Entries follow the initial peer syntax (host names and port-less peers accepted).
+    let mut initial_peers = unicast.initial_peers;
+    if let Ok(raw) = std::env::var("HDDS_SPDP_UNICAST_PEERS") {
+        for entry in raw.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            match entry.parse::<InitialPeer>() {
+                Ok(peer) => initial_peers.push(peer),
+                Err(err) => log::debug!(
+                    "[spdp_announcer] Ignoring invalid HDDS_SPDP_UNICAST_PEERS entry: {}",
+                    err
+                ),
+            }
+        }
+    }
+    let mut initial_peers = InitialPeerSet::new(initial_peers);
+    let discovery_fsm = unicast.discovery_fsm.filter(|_| !initial_peers.is_empty());
+    if !initial_peers.is_empty() {
+        log::info!(
+            "[spdp_announcer] Unicast SPDP to {} initial peer(s): {}",
+            initial_peers.peers().len(),
+            initial_peers
+                .peers()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
 
     // v242: When HDDS_REUSEPORT=1, automatically add localhost unicast peers
     // to work around Linux SO_REUSEPORT multicast load-balancing issue.
     // Without this, each process only receives SOME multicast packets, breaking discovery.
-    let mut spdp_unicast_peers = spdp_unicast_peers;
+    let mut spdp_unicast_peers: Vec<SocketAddr> = Vec::new();
     let reuseport_enabled = std::env::var("HDDS_REUSEPORT")
         .map(|v| v == "1")
         .unwrap_or(false);
@@ -330,6 +414,18 @@ fn announcer_loop(
             break;
         }
 
+        // Suspended participant: stay silent, then burst again on resume so
+        // peers re-ingest us before their lease on us runs out
+        if transport.is_suspended() {
+            was_suspended = true;
+            thread::sleep(Duration::from_millis(50));
+            continue;
+        }
+        if was_suspended {
+            was_suspended = false;
+            start_instant = std::time::Instant::now();
+        }
+
         // v79: Build SPDP participant data with ALL locator types
         // v208: derive domain_id from metatraffic multicast port
         // Formula: metatraffic_multicast = PORT_BASE + DOMAIN_ID_GAIN * domain_id
@@ -346,6 +442,7 @@ fn announcer_loop(
             default_multicast_locators: default_multicast_locators.clone(),
             metatraffic_multicast_locators: metatraffic_multicast_locators.clone(),
             identity_token: identity_token.clone(), // DDS Security identity certificate (if security enabled)
+            user_data: user_data.clone(),
         };
 
         // Get current sequence number and increment for next announcement
@@ -376,8 +473,25 @@ fn announcer_loop(
                 // Track SPDP announcements for interop timing helpers.
                 SPDP_SENT_COUNT.fetch_add(1, Ordering::Relaxed);
 
-                // 2) Optional unicast SPDP to explicitly configured peers
-                for peer in &spdp_unicast_peers {
+                // 2) Optional unicast SPDP to explicitly configured peers,
+                //    plus participants discovered in unicast-only deployments
+                let mut unicast_targets = spdp_unicast_peers.clone();
+                for target in initial_peers.targets(domain_id, transport.ip_mode()) {
+                    if !unicast_targets.contains(target) {
+                        unicast_targets.push(*target);
+                    }
+                }
+                if let Some(fsm) = &discovery_fsm {
+                    for info in fsm.get_participants() {
+                        for locator in info.endpoints {
+                            if !locator.ip().is_unspecified() && !unicast_targets.contains(&locator)
+                            {
+                                unicast_targets.push(locator);
+                            }
+                        }
+                    }
+                }
+                for peer in &unicast_targets {
                     match transport.send_to_endpoint(&packet, peer) {
                         Ok(sent) => {
                             // Always log SPDP unicast for FastDDS interop
@@ -423,7 +537,7 @@ fn announcer_loop(
         };
         let sleep_end = std::time::Instant::now() + sleep_dur;
         while std::time::Instant::now() < sleep_end {
-            if shutdown.load(Ordering::Relaxed) {
+            if shutdown.load(Ordering::Relaxed) || transport.is_suspended() {
                 break;
             }
             thread::sleep(Duration::from_millis(50));
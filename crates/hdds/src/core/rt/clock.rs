@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Time source of a participant.
+//!
+//! Lease timers (participant leases, writer liveliness) and the deadline and
+//! lifespan checkers read the time from a [`Clock`] instead of the wall
+//! clock. Participants use [`SystemClock`] unless one is injected with
+//! `ParticipantBuilder::clock`; a [`VirtualClock`] lets a replay or a
+//! deterministic test drive those timers from recorded time.
+//!
+//! # Example
+//!
+//! ```
+//! use hdds::{Clock, VirtualClock};
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//!
+//! let clock = Arc::new(VirtualClock::new());
+//! let start = clock.now();
+//! clock.advance(Duration::from_secs(30));
+//! assert_eq!(clock.now() - start, Duration::from_secs(30));
+//! ```
+
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+/// Process-wide origin of [`SystemClock`] and of [`Instant`]s derived from clocks.
+fn process_epoch() -> Instant {
+    static EPOCH: OnceLock<Instant> = OnceLock::new();
+    *EPOCH.get_or_init(Instant::now)
+}
+
+fn as_nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+/// Monotonic time source.
+pub trait Clock: Send + Sync + fmt::Debug {
+    /// Time elapsed since the clock's origin. Never goes backwards.
+    fn now(&self) -> Duration;
+
+    /// [`now`](Clock::now) in nanoseconds.
+    fn now_ns(&self) -> u64 {
+        as_nanos(self.now())
+    }
+
+    /// [`now`](Clock::now) as an [`Instant`], for timers kept as instants.
+    ///
+    /// Equals `Instant::now()` for [`SystemClock`]. Instants of other clocks
+    /// may only be compared with instants of the same clock.
+    fn instant(&self) -> Instant {
+        process_epoch() + self.now()
+    }
+}
+
+/// Wall (monotonic) time, the default clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl SystemClock {
+    /// Shared instance, used where no clock is injected.
+    #[must_use]
+    pub fn shared() -> Arc<dyn Clock> {
+        static SHARED: OnceLock<Arc<dyn Clock>> = OnceLock::new();
+        Arc::clone(SHARED.get_or_init(|| Arc::new(SystemClock)))
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        process_epoch().elapsed()
+    }
+}
+
+/// Clock that only moves when told to.
+///
+/// Starts at zero (or [`starting_at`](Self::starting_at) a recorded time)
+/// and follows [`advance`](Self::advance) and [`set`](Self::set). Threads
+/// still wake up on wall time: a timer due after a jump fires on its next
+/// periodic check.
+#[derive(Debug, Default)]
+pub struct VirtualClock {
+    now_ns: AtomicU64,
+}
+
+impl VirtualClock {
+    /// Virtual clock at zero.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Virtual clock at `now`, e.g. the first timestamp of a recording.
+    #[must_use]
+    pub fn starting_at(now: Duration) -> Self {
+        Self {
+            now_ns: AtomicU64::new(as_nanos(now)),
+        }
+    }
+
+    /// Move the clock forward by `delta`.
+    pub fn advance(&self, delta: Duration) {
+        let delta = as_nanos(delta);
+        let _ = self
+            .now_ns
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |now| {
+                Some(now.saturating_add(delta))
+            });
+    }
+
+    /// Move the clock to `now`. Earlier times are ignored: a clock never
+    /// goes backwards.
+    pub fn set(&self, now: Duration) {
+        self.now_ns.fetch_max(as_nanos(now), Ordering::AcqRel);
+    }
+}
+
+impl Clock for VirtualClock {
+    fn now(&self) -> Duration {
+        Duration::from_nanos(self.now_ns.load(Ordering::Acquire))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_system_clock_is_monotonic() {
+        let clock = SystemClock::shared();
+        let first = clock.now();
+        let instant = clock.instant();
+        assert!(clock.now() >= first);
+        assert!(instant <= Instant::now());
+    }
+
+    #[test]
+    fn test_virtual_clock_moves_only_forward() {
+        let clock = VirtualClock::starting_at(Duration::from_secs(100));
+        assert_eq!(clock.now(), Duration::from_secs(100));
+
+        clock.advance(Duration::from_millis(1500));
+        assert_eq!(clock.now(), Duration::from_millis(101_500));
+
+        clock.set(Duration::from_secs(50));
+        assert_eq!(clock.now(), Duration::from_millis(101_500));
+        clock.set(Duration::from_secs(200));
+        assert_eq!(clock.now(), Duration::from_secs(200));
+
+        let clock: Arc<dyn Clock> = Arc::new(clock);
+        assert_eq!(clock.now_ns(), 200_000_000_000);
+        assert_eq!(clock.instant() - process_epoch(), Duration::from_secs(200));
+    }
+}
@@ -12,19 +12,45 @@
 //! - pop: < 5 ns (p99)
 
 use super::slabpool::SlabHandle;
+use crate::engine::RxTimestamps;
+use crate::protocol::coherent::CoherentSetTag;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 /// COMMITTED flag (bit 0): entry is fully written and ready to pop
 const COMMITTED_FLAG: u8 = 0x01;
 
+/// DISPOSED flag (bit 1): the writer disposed the entry's instance
+pub const DISPOSED_FLAG: u8 = 0x02;
+
+/// UNREGISTERED flag (bit 2): the writer unregistered the entry's instance
+pub const UNREGISTERED_FLAG: u8 = 0x04;
+
+/// Map `PID_STATUS_INFO` flags to the entry's DISPOSED/UNREGISTERED bits.
+pub fn instance_state_flags(status_info: u8) -> u8 {
+    use crate::protocol::constants::{STATUS_INFO_DISPOSED, STATUS_INFO_UNREGISTERED};
+
+    let mut flags = 0;
+    if status_info & STATUS_INFO_DISPOSED != 0 {
+        flags |= DISPOSED_FLAG;
+    }
+    if status_info & STATUS_INFO_UNREGISTERED != 0 {
+        flags |= UNREGISTERED_FLAG;
+    }
+    flags
+}
+
 /// Entry in a SPSC ring (sequence + slab handle + length + flags + timestamp)
 #[derive(Debug, Clone, Copy)]
 pub struct IndexEntry {
-    pub seq: u32,           // sequence number
-    pub handle: SlabHandle, // slab handle for payload
-    pub len: u32,           // bytes written
-    pub flags: u8,          // COMMITTED flag (bit 0)
-    pub timestamp_ns: u64,  // write timestamp for latency measurement
+    pub seq: u32,                         // sequence number
+    pub handle: SlabHandle,               // slab handle for payload
+    pub len: u32,                         // bytes written
+    pub flags: u8,         // COMMITTED (bit 0), DISPOSED (bit 1), UNREGISTERED (bit 2)
+    pub timestamp_ns: u64, // write timestamp for latency measurement
+    pub trace_id: u64,     // per-sample trace id (0 = untraced)
+    pub coherent: Option<CoherentSetTag>, // group coherent set (see dds::coherent)
+    pub rx_timestamps: RxTimestamps, // source/kernel RX timestamps (network samples)
+    pub key_hash: Option<[u8; 16]>, // instance of DISPOSED/UNREGISTERED entries (PID_KEY_HASH)
 }
 
 impl IndexEntry {
@@ -36,6 +62,10 @@ impl IndexEntry {
             len,
             flags: 0, // Not committed yet
             timestamp_ns: 0,
+            trace_id: 0,
+            coherent: None,
+            rx_timestamps: RxTimestamps::NONE,
+            key_hash: None,
         }
     }
 
@@ -47,6 +77,10 @@ impl IndexEntry {
             len,
             flags: 0,
             timestamp_ns,
+            trace_id: 0,
+            coherent: None,
+            rx_timestamps: RxTimestamps::NONE,
+            key_hash: None,
         }
     }
 
@@ -55,6 +89,11 @@ impl IndexEntry {
         (self.flags & COMMITTED_FLAG) != 0
     }
 
+    /// Check if entry carries an instance dispose/unregister instead of data
+    pub fn is_instance_state(self) -> bool {
+        (self.flags & (DISPOSED_FLAG | UNREGISTERED_FLAG)) != 0
+    }
+
     /// Mark entry as committed
     fn mark_committed(&mut self) {
         self.flags |= COMMITTED_FLAG;
@@ -69,6 +108,10 @@ impl Default for IndexEntry {
             len: 0,
             flags: 0,
             timestamp_ns: 0,
+            trace_id: 0,
+            coherent: None,
+            rx_timestamps: RxTimestamps::NONE,
+            key_hash: None,
         }
     }
 }
@@ -11,9 +11,13 @@
 //!
 //! - push: < 100 ns per reader (RwLock read-only in hot path)
 
-use super::indexring::{IndexEntry, IndexRing};
+use super::indexring::{instance_state_flags, IndexEntry, IndexRing};
 use super::slabpool::SlabPool;
+use crate::core::discovery::GUID;
+use crate::engine::RxTimestamps;
+use crate::protocol::original_writer::OriginalWriterInfo;
 use crate::reliability::HistoryCache;
+use crate::telemetry::EndpointMetrics;
 use std::convert::TryFrom;
 use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
@@ -42,6 +46,7 @@ pub struct MergerReader {
     id: ReaderId,
     ring: Arc<IndexRing>,
     on_data: Arc<dyn Fn() + Send + Sync>,
+    metrics: Option<Arc<EndpointMetrics>>,
 }
 
 impl MergerReader {
@@ -49,7 +54,20 @@ impl MergerReader {
     #[must_use]
     pub fn new(ring: Arc<IndexRing>, on_data: Arc<dyn Fn() + Send + Sync>) -> Self {
         let id = ReaderId::from_ring(&ring);
-        Self { id, ring, on_data }
+        Self {
+            id,
+            ring,
+            on_data,
+            metrics: None,
+        }
+    }
+
+    /// Count the samples pushed to the reader, and those dropped because
+    /// its ring was full, in `metrics`.
+    #[must_use]
+    pub fn with_metrics(mut self, metrics: Arc<EndpointMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
     }
 
     /// Get the reader's unique identifier
@@ -86,6 +104,8 @@ pub struct TopicMerger {
     readers: RwLock<Vec<MergerReader>>,
     /// Optional history cache + slab pool for TRANSIENT_LOCAL/PERSISTENT durability
     durability_state: Option<(Arc<HistoryCache>, Arc<SlabPool>)>,
+    /// Writer whose historical samples are replayed, if it has a GUID
+    writer_guid: Option<GUID>,
 }
 
 impl TopicMerger {
@@ -94,6 +114,7 @@ impl TopicMerger {
         Self {
             readers: RwLock::new(Vec::new()),
             durability_state: None,
+            writer_guid: None,
         }
     }
 
@@ -105,9 +126,18 @@ impl TopicMerger {
         Self {
             readers: RwLock::new(Vec::new()),
             durability_state: Some((cache, slab_pool)),
+            writer_guid: None,
         }
     }
 
+    /// Tag replayed historical samples with `writer_guid` and their original
+    /// writer, so PERSISTENT readers recognize copies received otherwise.
+    #[must_use]
+    pub fn with_writer(mut self, writer_guid: Option<GUID>) -> Self {
+        self.writer_guid = writer_guid;
+        self
+    }
+
     /// Register reader ring (called during matching)
     ///
     /// Takes write lock briefly (cold path).
@@ -136,12 +166,13 @@ impl TopicMerger {
 
         // Push historical samples if TRANSIENT_LOCAL durability is enabled
         if let Some((cache, _slab_pool)) = &self.durability_state {
-            let all_samples = cache.get_all_samples();
+            let all_samples = cache.entries();
             log::debug!(
                 "[DEBUG] TopicMerger::add_reader(): Found {} historical samples in cache",
                 all_samples.len()
             );
-            for (seq, handle, size) in all_samples {
+            for cached in all_samples {
+                let (seq, size) = (cached.seq, cached.len);
                 let seq_u32 = match u32::try_from(seq) {
                     Ok(value) => value,
                     Err(_) => {
@@ -162,7 +193,18 @@ impl TopicMerger {
                         continue;
                     }
                 };
-                let entry = IndexEntry::new(seq_u32, handle, len_u32);
+                let original = cached.original_writer.or_else(|| {
+                    self.writer_guid
+                        .map(|guid| OriginalWriterInfo::new(guid, seq))
+                });
+                let mut entry = IndexEntry::new(seq_u32, cached.slab, len_u32);
+                if let Some(change) = cached.instance_state {
+                    entry.flags |= instance_state_flags(change.status_info);
+                    entry.key_hash = Some(change.key_hash);
+                }
+                entry.rx_timestamps = RxTimestamps::NONE
+                    .with_writer(self.writer_guid)
+                    .with_original_writer(original);
                 if reader.ring().push(entry) {
                     reader.notify();
                 } else {
@@ -213,8 +255,13 @@ impl TopicMerger {
         let mut pushed_any = false;
         for reader in readers.iter() {
             if reader.ring().push(entry) {
+                if let Some(metrics) = &reader.metrics {
+                    metrics.record_sample(entry.len as usize);
+                }
                 reader.notify();
                 pushed_any = true;
+            } else if let Some(metrics) = &reader.metrics {
+                metrics.record_history_drop();
             }
         }
 
@@ -3,12 +3,15 @@
 
 //! Runtime primitives for lock-free data structures and event handling.
 
+pub mod clock;
 pub mod indexring;
 pub mod merger;
 pub mod slabpool;
 pub mod waitset;
 
-pub use indexring::{IndexEntry, IndexRing};
+pub use indexring::{
+    instance_state_flags, IndexEntry, IndexRing, DISPOSED_FLAG, UNREGISTERED_FLAG,
+};
 pub use merger::{MergerReader, TopicMerger};
 pub use slabpool::{SlabHandle, SlabPool};
 pub use waitset::{
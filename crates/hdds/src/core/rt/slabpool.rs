@@ -11,9 +11,11 @@
 //! - reserve: < 30 ns (p99)
 //! - release: < 30 ns (p99)
 
+use bytes::Bytes;
 use std::cell::UnsafeCell;
 use std::convert::TryFrom;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 
 /// Handle to a reserved slab region
 ///
@@ -307,6 +309,39 @@ impl SlabPool {
 
         self.pools[pool_id].release_slot(slot_id);
     }
+
+    /// Hand a committed slab over to a refcounted `Bytes` (zero-copy)
+    ///
+    /// The first `len` bytes of the slab are exposed; the slot is released
+    /// when the last clone is dropped, so long-lived clones keep pool
+    /// capacity reserved.
+    pub fn into_bytes(self: &Arc<Self>, handle: SlabHandle, len: usize) -> Bytes {
+        Bytes::from_owner(SlabOwner {
+            pool: Arc::clone(self),
+            handle,
+            len,
+        })
+    }
+}
+
+/// Owner of a slab lent to `Bytes` (see `SlabPool::into_bytes`)
+struct SlabOwner {
+    pool: Arc<SlabPool>,
+    handle: SlabHandle,
+    len: usize,
+}
+
+impl AsRef<[u8]> for SlabOwner {
+    fn as_ref(&self) -> &[u8] {
+        let buf = self.pool.get_buffer(self.handle);
+        &buf[..self.len.min(buf.len())]
+    }
+}
+
+impl Drop for SlabOwner {
+    fn drop(&mut self) {
+        self.pool.release(self.handle);
+    }
 }
 
 impl Default for SlabPool {
@@ -356,6 +391,33 @@ mod tests {
         assert_eq!(h, h2); // Same slot reused
     }
 
+    #[test]
+    fn test_into_bytes_releases_on_last_drop() {
+        let pool = Arc::new(SlabPool::new());
+        let (h, buf) = pool
+            .reserve(100)
+            .expect("SlabPool reservation should succeed");
+        buf[..3].copy_from_slice(b"abc");
+
+        let bytes = pool.into_bytes(h, 3);
+        let clone = bytes.slice(1..);
+        drop(bytes);
+        assert_eq!(&clone[..], b"bc");
+
+        // Slot still held by the clone
+        let (h2, _) = pool
+            .reserve(100)
+            .expect("SlabPool reservation should succeed");
+        assert_ne!(h2, h);
+        pool.release(h2);
+
+        drop(clone);
+        let (h3, _) = pool
+            .reserve(100)
+            .expect("SlabPool reservation should succeed");
+        assert_eq!(h3, h); // Same slot reused
+    }
+
     #[test]
     fn test_reserve_size_classes() {
         let pool = SlabPool::new();
@@ -89,6 +89,12 @@ impl WaitsetDriver {
     pub fn manual_notify(&self) {
         self.inner.write_event();
     }
+
+    /// Descriptor that polls readable while the event is signalled.
+    #[cfg(unix)]
+    pub fn raw_fd(&self) -> std::os::fd::RawFd {
+        platform::poll_fd(&self.inner.event_handle)
+    }
 }
 
 struct WaitsetDriverInner {
@@ -176,6 +182,10 @@ mod platform {
 
     pub type EventHandle = RawFd;
 
+    pub fn poll_fd(handle: &EventHandle) -> RawFd {
+        *handle
+    }
+
     pub fn create_event() -> io::Result<EventHandle> {
         // SAFETY: eventfd is invoked with valid flags and no shared state.
         let fd = unsafe { libc::eventfd(0, EVENTFD_FLAGS) };
@@ -293,6 +303,10 @@ mod platform {
         write_fd: RawFd,
     }
 
+    pub fn poll_fd(handle: &EventHandle) -> RawFd {
+        handle.read_fd
+    }
+
     pub fn create_event() -> io::Result<EventHandle> {
         let mut fds = [0 as RawFd; 2];
         // SAFETY: fds is a valid 2-element array for pipe().
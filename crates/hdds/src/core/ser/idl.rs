@@ -0,0 +1,381 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! XCDR1 codec for types generated from IDL by `hdds-gen rust`.
+//!
+//! Generated structs, unions and enums implement [`IdlCdr`] member by
+//! member, and so do `#[derive(DDS)]` types, which can therefore nest in
+//! each other. The impls below cover the IDL primitives, strings, sequences
+//! (`Vec<T>`), arrays (`[T; N]`) and optional members (`Option<T>`).
+//! Samples use the PLAIN_CDR little endian layout: padding is relative to
+//! the start of the sample, and appendable types are encoded like final
+//! ones.
+//!
+//! Keys are written as the big-endian stream hashed into the instance
+//! handle, with alignment capped at 4.
+//!
+//! [`sequence_type_id`] and [`array_type_id`] build the anonymous collection
+//! identifiers of generated TypeObjects.
+
+use super::cursor::{Cursor, CursorMut};
+use super::{SerError, SerResult};
+use crate::dds::{Error, InstanceHandle, Result};
+use crate::xtypes::{
+    CollectionElementFlag, CompleteArrayType, CompleteCollectionElement, CompleteCollectionHeader,
+    CompleteSequenceType, CompleteTypeDetail, CompleteTypeObject, TypeIdentifier,
+};
+
+/// XCDR1 encoding of an IDL type.
+pub trait IdlCdr: Sized {
+    /// Append the encoding of `self`, padded relative to the sample start.
+    fn encode_cdr(&self, cursor: &mut CursorMut<'_>) -> SerResult<()>;
+
+    /// Decode a value written by [`IdlCdr::encode_cdr`].
+    fn decode_cdr(cursor: &mut Cursor<'_>) -> SerResult<Self>;
+
+    /// Append `self` to a big-endian key stream.
+    fn encode_key(&self, key: &mut Vec<u8>);
+}
+
+/// Zero-pad `cursor` to `alignment`.
+pub fn pad(cursor: &mut CursorMut<'_>, alignment: usize) -> SerResult<()> {
+    while !cursor.offset().is_multiple_of(alignment) {
+        cursor.write_u8(0)?;
+    }
+    Ok(())
+}
+
+/// Zero-pad a key stream to `alignment` (capped at 4).
+pub fn pad_key(key: &mut Vec<u8>, alignment: usize) {
+    while !key.len().is_multiple_of(alignment.min(4)) {
+        key.push(0);
+    }
+}
+
+/// Fail when a string or sequence is longer than its IDL bound.
+pub fn check_bound(len: usize, bound: usize) -> SerResult<()> {
+    if len > bound {
+        return Err(SerError::InvalidData {
+            reason: format!("length {} exceeds bound {}", len, bound),
+        });
+    }
+    Ok(())
+}
+
+/// Encode `value` into a sample buffer (generated `DDS::encode_cdr2`).
+pub fn encode_sample<T: IdlCdr>(value: &T, buf: &mut [u8]) -> Result<usize> {
+    let mut cursor = CursorMut::new(buf);
+    value.encode_cdr(&mut cursor).map_err(|e| match e {
+        SerError::WriteFailed { .. } => Error::BufferTooSmall,
+        e => {
+            log::debug!("[idl] encode {}: {}", std::any::type_name::<T>(), e);
+            Error::SerializationError
+        }
+    })?;
+    Ok(cursor.offset())
+}
+
+/// Decode a sample buffer (generated `DDS::decode_cdr2`).
+pub fn decode_sample<T: IdlCdr>(buf: &[u8]) -> Result<T> {
+    T::decode_cdr(&mut Cursor::new(buf)).map_err(|e| {
+        log::debug!("[idl] decode {}: {}", std::any::type_name::<T>(), e);
+        Error::SerializationError
+    })
+}
+
+/// Instance key hash of `value` (generated `DDS::compute_key`).
+pub fn key_hash<T: IdlCdr>(value: &T) -> [u8; 16] {
+    let mut key = Vec::new();
+    value.encode_key(&mut key);
+    InstanceHandle::from_serialized_key(&key).0
+}
+
+/// Identifier of `sequence<element, bound>` (`bound` 0: unbounded).
+pub fn sequence_type_id(element: TypeIdentifier, bound: u32) -> TypeIdentifier {
+    TypeIdentifier::Inline(Box::new(CompleteTypeObject::Sequence(
+        CompleteSequenceType {
+            header: CompleteCollectionHeader {
+                bound,
+                detail: CompleteTypeDetail::new(""),
+            },
+            element: CompleteCollectionElement {
+                flags: CollectionElementFlag::empty(),
+                type_id: element,
+            },
+        },
+    )))
+}
+
+/// Identifier of an array of `element` with dimensions `dims` (outermost
+/// first).
+pub fn array_type_id(element: TypeIdentifier, dims: Vec<u32>) -> TypeIdentifier {
+    TypeIdentifier::Inline(Box::new(CompleteTypeObject::Array(CompleteArrayType {
+        header: CompleteCollectionHeader {
+            bound: 0,
+            detail: CompleteTypeDetail::new(""),
+        },
+        element: CompleteCollectionElement {
+            flags: CollectionElementFlag::empty(),
+            type_id: element,
+        },
+        bound_seq: dims,
+    })))
+}
+
+fn length_u32(len: usize) -> SerResult<u32> {
+    u32::try_from(len).map_err(|_| SerError::InvalidData {
+        reason: format!("length {} does not fit in u32", len),
+    })
+}
+
+macro_rules! impl_idl_number {
+    ($($ty:ty),*) => {
+        $(
+            impl IdlCdr for $ty {
+                fn encode_cdr(&self, cursor: &mut CursorMut<'_>) -> SerResult<()> {
+                    pad(cursor, std::mem::size_of::<$ty>())?;
+                    cursor.write_bytes(&self.to_le_bytes())
+                }
+
+                fn decode_cdr(cursor: &mut Cursor<'_>) -> SerResult<Self> {
+                    const SIZE: usize = std::mem::size_of::<$ty>();
+                    cursor.align(SIZE as u8)?;
+                    let mut bytes = [0u8; SIZE];
+                    bytes.copy_from_slice(cursor.read_bytes(SIZE)?);
+                    Ok(<$ty>::from_le_bytes(bytes))
+                }
+
+                fn encode_key(&self, key: &mut Vec<u8>) {
+                    pad_key(key, std::mem::size_of::<$ty>());
+                    key.extend_from_slice(&self.to_be_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_idl_number!(i8, u8, i16, u16, i32, u32, i64, u64, f32, f64);
+
+impl IdlCdr for bool {
+    fn encode_cdr(&self, cursor: &mut CursorMut<'_>) -> SerResult<()> {
+        cursor.write_u8(u8::from(*self))
+    }
+
+    fn decode_cdr(cursor: &mut Cursor<'_>) -> SerResult<Self> {
+        Ok(cursor.read_u8()? != 0)
+    }
+
+    fn encode_key(&self, key: &mut Vec<u8>) {
+        key.push(u8::from(*self));
+    }
+}
+
+/// IDL `char`: one byte, Latin-1.
+impl IdlCdr for char {
+    fn encode_cdr(&self, cursor: &mut CursorMut<'_>) -> SerResult<()> {
+        let byte = u8::try_from(*self).map_err(|_| SerError::InvalidData {
+            reason: format!("char {:?} is not Latin-1", self),
+        })?;
+        cursor.write_u8(byte)
+    }
+
+    fn decode_cdr(cursor: &mut Cursor<'_>) -> SerResult<Self> {
+        Ok(char::from(cursor.read_u8()?))
+    }
+
+    fn encode_key(&self, key: &mut Vec<u8>) {
+        key.push(u8::try_from(*self).unwrap_or(b'?'));
+    }
+}
+
+/// IDL `string`: length (including the NUL terminator), bytes, NUL.
+impl IdlCdr for String {
+    fn encode_cdr(&self, cursor: &mut CursorMut<'_>) -> SerResult<()> {
+        pad(cursor, 4)?;
+        cursor.write_u32_le(length_u32(self.len() + 1)?)?;
+        cursor.write_bytes(self.as_bytes())?;
+        cursor.write_u8(0)
+    }
+
+    fn decode_cdr(cursor: &mut Cursor<'_>) -> SerResult<Self> {
+        cursor.align(4)?;
+        let len = cursor.read_u32_le()? as usize;
+        if len == 0 {
+            return Ok(String::new());
+        }
+        let bytes = cursor.read_bytes(len)?;
+        let text = bytes.strip_suffix(&[0]).unwrap_or(bytes);
+        String::from_utf8(text.to_vec()).map_err(|_| SerError::InvalidData {
+            reason: "string is not valid UTF-8".into(),
+        })
+    }
+
+    fn encode_key(&self, key: &mut Vec<u8>) {
+        pad_key(key, 4);
+        key.extend_from_slice(&((self.len() + 1) as u32).to_be_bytes());
+        key.extend_from_slice(self.as_bytes());
+        key.push(0);
+    }
+}
+
+/// IDL `sequence<T>`: element count, elements.
+impl<T: IdlCdr> IdlCdr for Vec<T> {
+    fn encode_cdr(&self, cursor: &mut CursorMut<'_>) -> SerResult<()> {
+        pad(cursor, 4)?;
+        cursor.write_u32_le(length_u32(self.len())?)?;
+        self.iter().try_for_each(|item| item.encode_cdr(cursor))
+    }
+
+    fn decode_cdr(cursor: &mut Cursor<'_>) -> SerResult<Self> {
+        cursor.align(4)?;
+        let offset = cursor.offset();
+        let len = cursor.read_u32_le()? as usize;
+        // Every element takes at least one byte: reject bogus lengths early
+        if len > cursor.remaining() {
+            return Err(SerError::ReadFailed {
+                offset,
+                reason: format!("sequence length {} exceeds payload", len),
+            });
+        }
+        (0..len).map(|_| T::decode_cdr(cursor)).collect()
+    }
+
+    fn encode_key(&self, key: &mut Vec<u8>) {
+        pad_key(key, 4);
+        key.extend_from_slice(&(self.len() as u32).to_be_bytes());
+        self.iter().for_each(|item| item.encode_key(key));
+    }
+}
+
+/// IDL arrays: elements only.
+impl<T: IdlCdr, const N: usize> IdlCdr for [T; N] {
+    fn encode_cdr(&self, cursor: &mut CursorMut<'_>) -> SerResult<()> {
+        self.iter().try_for_each(|item| item.encode_cdr(cursor))
+    }
+
+    fn decode_cdr(cursor: &mut Cursor<'_>) -> SerResult<Self> {
+        let items = (0..N)
+            .map(|_| T::decode_cdr(cursor))
+            .collect::<SerResult<Vec<T>>>()?;
+        items.try_into().map_err(|_| SerError::InvalidData {
+            reason: "array length mismatch".into(),
+        })
+    }
+
+    fn encode_key(&self, key: &mut Vec<u8>) {
+        self.iter().for_each(|item| item.encode_key(key));
+    }
+}
+
+/// XTypes optional member: presence flag (0 or 1), then the value.
+impl<T: IdlCdr> IdlCdr for Option<T> {
+    fn encode_cdr(&self, cursor: &mut CursorMut<'_>) -> SerResult<()> {
+        self.is_some().encode_cdr(cursor)?;
+        self.as_ref()
+            .map_or(Ok(()), |value| value.encode_cdr(cursor))
+    }
+
+    fn decode_cdr(cursor: &mut Cursor<'_>) -> SerResult<Self> {
+        match cursor.read_u8()? {
+            0 => Ok(None),
+            1 => T::decode_cdr(cursor).map(Some),
+            flag => Err(SerError::InvalidData {
+                reason: format!("invalid optional flag {}", flag),
+            }),
+        }
+    }
+
+    fn encode_key(&self, key: &mut Vec<u8>) {
+        self.is_some().encode_key(key);
+        if let Some(value) = self {
+            value.encode_key(key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode<T: IdlCdr>(value: &T) -> Vec<u8> {
+        let mut buf = [0u8; 64];
+        let len = encode_sample(value, &mut buf).expect("encode");
+        buf[..len].to_vec()
+    }
+
+    #[test]
+    fn test_primitives_are_aligned_to_their_size() {
+        let bytes = encode(&(1u8, 2u16, 3u64));
+        assert_eq!(
+            bytes,
+            [1, 0, 2, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0],
+            "XCDR1 aligns u64 to 8"
+        );
+    }
+
+    #[test]
+    fn test_string_sequence_array_roundtrip() {
+        let value = (
+            String::from("hi"),
+            vec![1.5f64, -2.0],
+            [['a', 'b'], ['c', 'd']],
+        );
+        let bytes = encode(&value);
+        assert_eq!(&bytes[..7], &[3, 0, 0, 0, b'h', b'i', 0]);
+        let decoded: (String, Vec<f64>, [[char; 2]; 2]) = decode_sample(&bytes).expect("decode");
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn test_bogus_sequence_length_is_rejected() {
+        let bytes = [0xFF, 0xFF, 0xFF, 0x0F, 1, 2];
+        assert!(decode_sample::<Vec<u8>>(&bytes).is_err());
+        assert!(check_bound(5, 4).is_err());
+        assert!(check_bound(4, 4).is_ok());
+    }
+
+    #[test]
+    fn test_optional_presence_flag() {
+        let value = (Some(7u32), None::<u16>, 1u8);
+        let bytes = encode(&value);
+        assert_eq!(bytes, [1, 0, 0, 0, 7, 0, 0, 0, 0, 1]);
+        let decoded: (Option<u32>, Option<u16>, u8) = decode_sample(&bytes).expect("decode");
+        assert_eq!(decoded, value);
+        assert!(decode_sample::<Option<u8>>(&[2, 0]).is_err());
+    }
+
+    #[test]
+    fn test_key_stream_is_big_endian_capped_at_4() {
+        let mut key = Vec::new();
+        1u8.encode_key(&mut key);
+        2u64.encode_key(&mut key);
+        String::from("a").encode_key(&mut key);
+        assert_eq!(
+            key,
+            [1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 2, b'a', 0]
+        );
+    }
+
+    /// Tuples stand in for generated structs in these tests.
+    impl<A: IdlCdr, B: IdlCdr, C: IdlCdr> IdlCdr for (A, B, C) {
+        fn encode_cdr(&self, cursor: &mut CursorMut<'_>) -> SerResult<()> {
+            self.0.encode_cdr(cursor)?;
+            self.1.encode_cdr(cursor)?;
+            self.2.encode_cdr(cursor)
+        }
+
+        fn decode_cdr(cursor: &mut Cursor<'_>) -> SerResult<Self> {
+            Ok((
+                A::decode_cdr(cursor)?,
+                B::decode_cdr(cursor)?,
+                C::decode_cdr(cursor)?,
+            ))
+        }
+
+        fn encode_key(&self, key: &mut Vec<u8>) {
+            self.0.encode_key(key);
+            self.1.encode_key(key);
+            self.2.encode_key(key);
+        }
+    }
+}
@@ -4,7 +4,10 @@
 //! CDR2 serialization helpers for RTPS message encoding/decoding.
 
 pub mod cursor;
+pub mod idl;
 pub mod pl_cdr2;
+#[cfg(feature = "serde")]
+pub mod serde_cdr;
 pub mod traits;
 
 // Re-export from protocol module for backwards compatibility
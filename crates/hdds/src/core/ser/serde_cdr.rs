@@ -0,0 +1,769 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Generic CDR2 codec for serde types.
+//!
+//! Encodes any `Serialize` type as plain little-endian CDR2 with the same
+//! layout `#[derive(hdds::DDS)]` produces: primitives aligned to their size,
+//! strings as `[u32 len incl. NUL][bytes][NUL]`, sequences as
+//! `[u32 count][elements]`. Beyond what IDL can express:
+//!
+//! - `Option<T>`: `bool` presence flag followed by the value
+//! - enums: `u32` variant index followed by the variant fields
+//! - maps: `u32` entry count followed by key/value pairs
+//! - `char`: `u32` code point
+//!
+//! The format is not self-describing: `deserialize_any` (untagged enums,
+//! `#[serde(flatten)]`, `serde_json::Value`, ...) is not supported, and
+//! 128-bit integers are rejected.
+
+use super::{SerError, SerResult};
+use serde::de::{self, DeserializeSeed, IntoDeserializer, Visitor};
+use serde::ser::{self, Serialize};
+use std::fmt::Display;
+
+impl ser::Error for SerError {
+    fn custom<T: Display>(msg: T) -> Self {
+        SerError::EncoderFailed {
+            reason: msg.to_string(),
+        }
+    }
+}
+
+impl de::Error for SerError {
+    fn custom<T: Display>(msg: T) -> Self {
+        SerError::DecoderFailed {
+            reason: msg.to_string(),
+        }
+    }
+}
+
+/// Serialize `value` into `buf`, returning the number of bytes written.
+///
+/// # Errors
+///
+/// `SerError::WriteFailed` if `buf` is too small, `SerError::EncoderFailed`
+/// for values the format cannot represent.
+pub fn to_cdr2<T: Serialize + ?Sized>(value: &T, buf: &mut [u8]) -> SerResult<usize> {
+    let mut encoder = CdrSerializer { buf, offset: 0 };
+    value.serialize(&mut encoder)?;
+    Ok(encoder.offset)
+}
+
+/// Deserialize a `T` from the start of `buf`.
+///
+/// # Errors
+///
+/// `SerError::ReadFailed` if `buf` is truncated, `SerError::DecoderFailed`
+/// or `SerError::InvalidData` for malformed input.
+pub fn from_cdr2<'de, T: de::Deserialize<'de>>(buf: &'de [u8]) -> SerResult<T> {
+    let mut decoder = CdrDeserializer { buf, offset: 0 };
+    T::deserialize(&mut decoder)
+}
+
+// ============================================================================
+// Encoder
+// ============================================================================
+
+struct CdrSerializer<'a> {
+    buf: &'a mut [u8],
+    offset: usize,
+}
+
+impl<'a> CdrSerializer<'a> {
+    fn write_bytes(&mut self, data: &[u8]) -> SerResult<()> {
+        let end = self.offset + data.len();
+        if end > self.buf.len() {
+            return Err(SerError::WriteFailed {
+                offset: self.offset,
+                reason: "buffer too small".into(),
+            });
+        }
+        self.buf[self.offset..end].copy_from_slice(data);
+        self.offset = end;
+        Ok(())
+    }
+
+    fn align(&mut self, alignment: usize) -> SerResult<()> {
+        while !self.offset.is_multiple_of(alignment) {
+            self.write_bytes(&[0])?;
+        }
+        Ok(())
+    }
+
+    fn write_aligned(&mut self, data: &[u8]) -> SerResult<()> {
+        self.align(data.len())?;
+        self.write_bytes(data)
+    }
+
+    fn write_len(&mut self, len: usize) -> SerResult<()> {
+        let len = u32::try_from(len).map_err(|_| SerError::EncoderFailed {
+            reason: "length exceeds u32".into(),
+        })?;
+        self.write_aligned(&len.to_le_bytes())
+    }
+
+    /// Reserve a `u32` count to be patched by [`SeqEncoder::end`].
+    fn begin_count<'b>(&'b mut self, len: Option<usize>) -> SerResult<SeqEncoder<'b, 'a>> {
+        self.write_len(len.unwrap_or(0))?;
+        Ok(SeqEncoder {
+            count_offset: self.offset - 4,
+            count: 0,
+            ser: self,
+        })
+    }
+}
+
+impl<'b, 'a> ser::Serializer for &'b mut CdrSerializer<'a> {
+    type Ok = ();
+    type Error = SerError;
+    type SerializeSeq = SeqEncoder<'b, 'a>;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = SeqEncoder<'b, 'a>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    fn serialize_bool(self, v: bool) -> SerResult<()> {
+        self.write_bytes(&[u8::from(v)])
+    }
+
+    fn serialize_i8(self, v: i8) -> SerResult<()> {
+        self.write_bytes(&v.to_le_bytes())
+    }
+
+    fn serialize_i16(self, v: i16) -> SerResult<()> {
+        self.write_aligned(&v.to_le_bytes())
+    }
+
+    fn serialize_i32(self, v: i32) -> SerResult<()> {
+        self.write_aligned(&v.to_le_bytes())
+    }
+
+    fn serialize_i64(self, v: i64) -> SerResult<()> {
+        self.write_aligned(&v.to_le_bytes())
+    }
+
+    fn serialize_u8(self, v: u8) -> SerResult<()> {
+        self.write_bytes(&[v])
+    }
+
+    fn serialize_u16(self, v: u16) -> SerResult<()> {
+        self.write_aligned(&v.to_le_bytes())
+    }
+
+    fn serialize_u32(self, v: u32) -> SerResult<()> {
+        self.write_aligned(&v.to_le_bytes())
+    }
+
+    fn serialize_u64(self, v: u64) -> SerResult<()> {
+        self.write_aligned(&v.to_le_bytes())
+    }
+
+    fn serialize_f32(self, v: f32) -> SerResult<()> {
+        self.write_aligned(&v.to_le_bytes())
+    }
+
+    fn serialize_f64(self, v: f64) -> SerResult<()> {
+        self.write_aligned(&v.to_le_bytes())
+    }
+
+    fn serialize_char(self, v: char) -> SerResult<()> {
+        self.serialize_u32(u32::from(v))
+    }
+
+    fn serialize_str(self, v: &str) -> SerResult<()> {
+        self.write_len(v.len() + 1)?;
+        self.write_bytes(v.as_bytes())?;
+        self.write_bytes(&[0])
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> SerResult<()> {
+        self.write_len(v.len())?;
+        self.write_bytes(v)
+    }
+
+    fn serialize_none(self) -> SerResult<()> {
+        self.serialize_bool(false)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> SerResult<()> {
+        self.serialize_bool(true)?;
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> SerResult<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> SerResult<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+    ) -> SerResult<()> {
+        self.serialize_u32(variant_index)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> SerResult<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        value: &T,
+    ) -> SerResult<()> {
+        self.serialize_u32(variant_index)?;
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> SerResult<Self::SerializeSeq> {
+        self.begin_count(len)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> SerResult<Self> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> SerResult<Self> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> SerResult<Self> {
+        self.serialize_u32(variant_index)?;
+        Ok(self)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> SerResult<Self::SerializeMap> {
+        self.begin_count(len)
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> SerResult<Self> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> SerResult<Self> {
+        self.serialize_u32(variant_index)?;
+        Ok(self)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+/// Sequence or map encoder; patches the element count on `end`, so
+/// iterators without a size hint still encode correctly.
+struct SeqEncoder<'b, 'a> {
+    ser: &'b mut CdrSerializer<'a>,
+    count_offset: usize,
+    count: u32,
+}
+
+impl SeqEncoder<'_, '_> {
+    fn end(self) -> SerResult<()> {
+        let at = self.count_offset;
+        self.ser.buf[at..at + 4].copy_from_slice(&self.count.to_le_bytes());
+        Ok(())
+    }
+}
+
+impl ser::SerializeSeq for SeqEncoder<'_, '_> {
+    type Ok = ();
+    type Error = SerError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> SerResult<()> {
+        self.count += 1;
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> SerResult<()> {
+        SeqEncoder::end(self)
+    }
+}
+
+impl ser::SerializeMap for SeqEncoder<'_, '_> {
+    type Ok = ();
+    type Error = SerError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> SerResult<()> {
+        self.count += 1;
+        key.serialize(&mut *self.ser)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> SerResult<()> {
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> SerResult<()> {
+        SeqEncoder::end(self)
+    }
+}
+
+/// Fixed-arity compounds (tuples, structs, variants) carry no prefix.
+macro_rules! impl_serialize_fields {
+    ($($trait:ident :: $method:ident),*) => {
+        $(
+            impl ser::$trait for &mut CdrSerializer<'_> {
+                type Ok = ();
+                type Error = SerError;
+
+                fn $method<T: Serialize + ?Sized>(&mut self, value: &T) -> SerResult<()> {
+                    value.serialize(&mut **self)
+                }
+
+                fn end(self) -> SerResult<()> {
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_serialize_fields!(
+    SerializeTuple::serialize_element,
+    SerializeTupleStruct::serialize_field,
+    SerializeTupleVariant::serialize_field
+);
+
+macro_rules! impl_serialize_named_fields {
+    ($($trait:ident),*) => {
+        $(
+            impl ser::$trait for &mut CdrSerializer<'_> {
+                type Ok = ();
+                type Error = SerError;
+
+                fn serialize_field<T: Serialize + ?Sized>(
+                    &mut self,
+                    _key: &'static str,
+                    value: &T,
+                ) -> SerResult<()> {
+                    value.serialize(&mut **self)
+                }
+
+                fn end(self) -> SerResult<()> {
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_serialize_named_fields!(SerializeStruct, SerializeStructVariant);
+
+// ============================================================================
+// Decoder
+// ============================================================================
+
+struct CdrDeserializer<'de> {
+    buf: &'de [u8],
+    offset: usize,
+}
+
+impl<'de> CdrDeserializer<'de> {
+    fn read_bytes(&mut self, len: usize) -> SerResult<&'de [u8]> {
+        if len > self.buf.len() - self.offset {
+            return Err(SerError::ReadFailed {
+                offset: self.offset,
+                reason: "unexpected end of buffer".into(),
+            });
+        }
+        let slice = &self.buf[self.offset..self.offset + len];
+        self.offset += len;
+        Ok(slice)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> SerResult<[u8; N]> {
+        while !self.offset.is_multiple_of(N) {
+            self.read_bytes(1)?;
+        }
+        let mut bytes = [0u8; N];
+        bytes.copy_from_slice(self.read_bytes(N)?);
+        Ok(bytes)
+    }
+
+    fn read_u32(&mut self) -> SerResult<u32> {
+        self.read_array::<4>().map(u32::from_le_bytes)
+    }
+
+    fn read_bool(&mut self) -> SerResult<bool> {
+        match self.read_bytes(1)?[0] {
+            0 => Ok(false),
+            1 => Ok(true),
+            v => Err(SerError::InvalidData {
+                reason: format!("invalid bool {}", v),
+            }),
+        }
+    }
+
+    /// Read a sequence/map count, rejecting counts larger than the
+    /// remaining input so a corrupt prefix cannot spin the decoder.
+    fn read_count(&mut self) -> SerResult<usize> {
+        let count = self.read_u32()? as usize;
+        if count > self.buf.len() - self.offset {
+            return Err(SerError::InvalidData {
+                reason: format!("count {} exceeds remaining input", count),
+            });
+        }
+        Ok(count)
+    }
+
+    fn read_str(&mut self) -> SerResult<&'de str> {
+        let len = self.read_u32()? as usize;
+        if len == 0 {
+            return Ok("");
+        }
+        let bytes = self.read_bytes(len)?;
+        std::str::from_utf8(&bytes[..len - 1]).map_err(|_| SerError::InvalidData {
+            reason: "string is not UTF-8".into(),
+        })
+    }
+}
+
+macro_rules! impl_deserialize_primitive {
+    ($($method:ident => $visit:ident($ty:ty, $size:expr)),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> SerResult<V::Value> {
+                visitor.$visit(<$ty>::from_le_bytes(self.read_array::<$size>()?))
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for &mut CdrDeserializer<'de> {
+    type Error = SerError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> SerResult<V::Value> {
+        Err(SerError::DecoderFailed {
+            reason: "CDR is not self-describing".into(),
+        })
+    }
+
+    impl_deserialize_primitive!(
+        deserialize_i8 => visit_i8(i8, 1),
+        deserialize_i16 => visit_i16(i16, 2),
+        deserialize_i32 => visit_i32(i32, 4),
+        deserialize_i64 => visit_i64(i64, 8),
+        deserialize_u8 => visit_u8(u8, 1),
+        deserialize_u16 => visit_u16(u16, 2),
+        deserialize_u32 => visit_u32(u32, 4),
+        deserialize_u64 => visit_u64(u64, 8),
+        deserialize_f32 => visit_f32(f32, 4),
+        deserialize_f64 => visit_f64(f64, 8),
+    );
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> SerResult<V::Value> {
+        visitor.visit_bool(self.read_bool()?)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> SerResult<V::Value> {
+        let code = self.read_u32()?;
+        let c = char::from_u32(code).ok_or_else(|| SerError::InvalidData {
+            reason: format!("invalid char {:#x}", code),
+        })?;
+        visitor.visit_char(c)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> SerResult<V::Value> {
+        visitor.visit_borrowed_str(self.read_str()?)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> SerResult<V::Value> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> SerResult<V::Value> {
+        let len = self.read_u32()? as usize;
+        visitor.visit_borrowed_bytes(self.read_bytes(len)?)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> SerResult<V::Value> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> SerResult<V::Value> {
+        if self.read_bool()? {
+            visitor.visit_some(self)
+        } else {
+            visitor.visit_none()
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> SerResult<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> SerResult<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> SerResult<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> SerResult<V::Value> {
+        let len = self.read_count()?;
+        visitor.visit_seq(Fields { de: self, len })
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> SerResult<V::Value> {
+        visitor.visit_seq(Fields { de: self, len })
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> SerResult<V::Value> {
+        visitor.visit_seq(Fields { de: self, len })
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> SerResult<V::Value> {
+        let len = self.read_count()?;
+        visitor.visit_map(Fields { de: self, len })
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> SerResult<V::Value> {
+        visitor.visit_seq(Fields {
+            de: self,
+            len: fields.len(),
+        })
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> SerResult<V::Value> {
+        visitor.visit_enum(self)
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, _visitor: V) -> SerResult<V::Value> {
+        Err(SerError::DecoderFailed {
+            reason: "CDR does not encode identifiers".into(),
+        })
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> SerResult<V::Value> {
+        self.deserialize_any(visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+/// Access to a known number of consecutive elements (or map entries).
+struct Fields<'a, 'de> {
+    de: &'a mut CdrDeserializer<'de>,
+    len: usize,
+}
+
+impl<'de> de::SeqAccess<'de> for Fields<'_, 'de> {
+    type Error = SerError;
+
+    fn next_element_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> SerResult<Option<S::Value>> {
+        if self.len == 0 {
+            return Ok(None);
+        }
+        self.len -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+impl<'de> de::MapAccess<'de> for Fields<'_, 'de> {
+    type Error = SerError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> SerResult<Option<K::Value>> {
+        if self.len == 0 {
+            return Ok(None);
+        }
+        self.len -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn next_value_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> SerResult<S::Value> {
+        seed.deserialize(&mut *self.de)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+impl<'de> de::EnumAccess<'de> for &mut CdrDeserializer<'de> {
+    type Error = SerError;
+    type Variant = Self;
+
+    fn variant_seed<S: DeserializeSeed<'de>>(self, seed: S) -> SerResult<(S::Value, Self)> {
+        let index = self.read_u32()?;
+        let variant = seed.deserialize(IntoDeserializer::<SerError>::into_deserializer(index))?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for &mut CdrDeserializer<'de> {
+    type Error = SerError;
+
+    fn unit_variant(self) -> SerResult<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<S: DeserializeSeed<'de>>(self, seed: S) -> SerResult<S::Value> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> SerResult<V::Value> {
+        visitor.visit_seq(Fields { de: self, len })
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> SerResult<V::Value> {
+        visitor.visit_seq(Fields {
+            de: self,
+            len: fields.len(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    enum Mode {
+        Idle,
+        Manual(u8),
+        Auto { target: f64, retries: u16 },
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Status {
+        id: u8,
+        name: String,
+        position: (f32, f32),
+        label: Option<String>,
+        modes: Vec<Mode>,
+        params: BTreeMap<String, i64>,
+    }
+
+    #[test]
+    fn test_roundtrip_nested_types() {
+        let status = Status {
+            id: 7,
+            name: "arm".into(),
+            position: (1.5, -2.0),
+            label: None,
+            modes: vec![
+                Mode::Idle,
+                Mode::Manual(3),
+                Mode::Auto {
+                    target: 0.25,
+                    retries: 2,
+                },
+            ],
+            params: [("gain".to_string(), -4), ("rate".to_string(), 100)]
+                .into_iter()
+                .collect(),
+        };
+
+        let mut buf = [0u8; 256];
+        let len = to_cdr2(&status, &mut buf).expect("encode");
+        let decoded: Status = from_cdr2(&buf[..len]).expect("decode");
+        assert_eq!(decoded, status);
+    }
+
+    #[test]
+    fn test_layout_matches_plain_cdr2() {
+        #[derive(Serialize)]
+        struct Sample {
+            flag: u8,
+            value: u64,
+            name: String,
+        }
+
+        let mut buf = [0u8; 64];
+        let len = to_cdr2(
+            &Sample {
+                flag: 1,
+                value: 2,
+                name: "ab".into(),
+            },
+            &mut buf,
+        )
+        .expect("encode");
+
+        let mut expected = vec![1u8, 0, 0, 0, 0, 0, 0, 0];
+        expected.extend_from_slice(&2u64.to_le_bytes());
+        expected.extend_from_slice(&3u32.to_le_bytes());
+        expected.extend_from_slice(b"ab\0");
+        assert_eq!(&buf[..len], &expected[..]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut small = [0u8; 4];
+        assert!(matches!(
+            to_cdr2(&String::from("too long"), &mut small),
+            Err(SerError::WriteFailed { .. })
+        ));
+
+        // Truncated input and an oversized sequence count
+        assert!(from_cdr2::<u64>(&[1, 2, 3]).is_err());
+        assert!(matches!(
+            from_cdr2::<Vec<u8>>(&u32::MAX.to_le_bytes()),
+            Err(SerError::InvalidData { .. })
+        ));
+    }
+}
@@ -68,6 +68,7 @@ impl MatchedReadersRegistry {
     /// # Returns
     /// Sequences needing retransmission (if any)
     pub fn on_acknack(&self, reader_guid: &[u8; 16], acked_seq: i64, has_gaps: bool) -> Vec<i64> {
+        crate::alloc_scope!(Reliability);
         if let Some(proxy) = self.proxies.get(reader_guid) {
             proxy.on_acknack(acked_seq, has_gaps)
         } else {
@@ -75,6 +75,7 @@ impl ReliableWriterProxy {
     /// # Returns
     /// List of sequence numbers that need retransmission (if any)
     pub fn on_acknack(&self, acked_seq: i64, has_gaps: bool) -> Vec<i64> {
+        crate::alloc_scope!(Reliability);
         // Update last seen time
         // Note: We can't update last_seen directly as it's not atomic
         // This is a limitation - consider using AtomicU64 for timestamp
@@ -0,0 +1,916 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Coherent sets across the writers of a Publisher (PRESENTATION
+//! `coherent_access` with TOPIC or GROUP scope), and ordering across them
+//! (`ordered_access` with GROUP scope).
+//!
+//! Publisher side ([`CoherentGroup`]): samples written between
+//! `begin_coherent_changes()` and `end_coherent_changes()` carry a
+//! `PID_HDDS_COHERENT_SET` tag (see `protocol::coherent`). Ending the set
+//! publishes a [`CoherentSetEnd`] marker on [`SET_END_TOPIC`] with the
+//! number of samples written per topic.
+//!
+//! Subscriber side ([`CoherentGate`]): the readers of a coherent subscriber
+//! read through a gate that holds tagged samples back until their set is
+//! complete, then makes the whole set visible to all readers at once. A set
+//! is complete when its marker arrived and every reader that has already
+//! received samples from that publisher got the announced count for its
+//! topic; a reader's first set from a publisher is not waited for.
+//!
+//! Sets favour liveness: a complete set releases the earlier, still
+//! incomplete sets of the same publisher as they are, and at most
+//! `MAX_PENDING_SETS` sets are held per publisher.
+//!
+//! Group ordering: an ordered publisher tags every sample with its write
+//! order, and an ordered gate hands samples to its readers in that order,
+//! numbering them so `Subscriber::ordered_topics()` can tell which reader
+//! holds the next one. Each pump drains the readers' rings twice and holds
+//! back samples newer than the newest one seen in the first pass, so a
+//! sample still on its way to a ring drained earlier is not overtaken.
+//! Within a coherent set the order is strict; across the network, a sample
+//! arriving after a later sample of another topic was read is late.
+
+use super::{
+    Condition, DataReader, DataWriter, Error, PresentationAccessScope, QoS, Result,
+    StatusCondition, StatusMask, DDS,
+};
+use crate::core::rt::{self, IndexEntry, IndexRing, WaitsetSignal};
+use crate::core::types::TypeDescriptor;
+use crate::protocol::coherent::CoherentSetTag;
+use crate::Participant;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+
+/// Topic carrying [`CoherentSetEnd`] markers.
+pub(crate) const SET_END_TOPIC: &str = "__hdds_coherent_set_end";
+
+/// Sets held per publisher before the oldest is released incomplete.
+const MAX_PENDING_SETS: usize = 64;
+
+/// Whether `qos` asks for coherent sets.
+pub(crate) fn coherent_access(qos: &QoS) -> bool {
+    qos.presentation.coherent_access
+        && qos.presentation.access_scope != PresentationAccessScope::Instance
+}
+
+/// Whether `qos` asks for ordering across the topics of a group.
+pub(crate) fn group_ordered_access(qos: &QoS) -> bool {
+    qos.presentation.ordered_access
+        && qos.presentation.access_scope == PresentationAccessScope::Group
+}
+
+/// Markers are replayed to late-matching readers, which may receive a
+/// set's samples before discovering the marker writer.
+fn marker_qos() -> QoS {
+    QoS::reliable()
+        .transient_local()
+        .keep_last(MAX_PENDING_SETS as u32)
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+// ============================================================================
+// Set end marker
+// ============================================================================
+
+/// Marker closing a coherent set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CoherentSetEnd {
+    pub(crate) group_id: u64,
+    pub(crate) set_seq: u64,
+    /// Samples written in the set, per topic.
+    pub(crate) topics: Vec<(String, u32)>,
+}
+
+impl DDS for CoherentSetEnd {
+    fn type_descriptor() -> &'static TypeDescriptor {
+        static DESC: TypeDescriptor = TypeDescriptor {
+            type_id: 0xa164_cbab,
+            type_name: "hdds::CoherentSetEnd",
+            size_bytes: 0,
+            alignment: 8,
+            is_variable_size: true,
+            fields: &[],
+        };
+        &DESC
+    }
+
+    /// Group id (u64), set number (u64), topic count (u32), then per topic
+    /// the sample count (u32) and name (CDR string, padded to 4).
+    fn encode_cdr2(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut out = Vec::with_capacity(20 + 16 * self.topics.len());
+        out.extend_from_slice(&self.group_id.to_le_bytes());
+        out.extend_from_slice(&self.set_seq.to_le_bytes());
+        out.extend_from_slice(&(self.topics.len() as u32).to_le_bytes());
+        for (topic, count) in &self.topics {
+            out.extend_from_slice(&count.to_le_bytes());
+            out.extend_from_slice(&(topic.len() as u32 + 1).to_le_bytes());
+            out.extend_from_slice(topic.as_bytes());
+            out.push(0);
+            out.resize(out.len().next_multiple_of(4), 0);
+        }
+
+        let dst = buf.get_mut(..out.len()).ok_or(Error::BufferTooSmall)?;
+        dst.copy_from_slice(&out);
+        Ok(out.len())
+    }
+
+    fn decode_cdr2(buf: &[u8]) -> Result<Self> {
+        let mut reader = MarkerReader { buf, pos: 0 };
+        let group_id = reader.u64()?;
+        let set_seq = reader.u64()?;
+        let count = reader.u32()? as usize;
+        if count > buf.len() / 8 {
+            return Err(Error::SerializationError);
+        }
+        let mut topics = Vec::with_capacity(count);
+        for _ in 0..count {
+            let samples = reader.u32()?;
+            topics.push((reader.string()?, samples));
+        }
+        Ok(Self {
+            group_id,
+            set_seq,
+            topics,
+        })
+    }
+}
+
+struct MarkerReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl MarkerReader<'_> {
+    fn take(&mut self, len: usize) -> Result<&[u8]> {
+        let end = self.pos.checked_add(len).ok_or(Error::SerializationError)?;
+        let bytes = self
+            .buf
+            .get(self.pos..end)
+            .ok_or(Error::SerializationError)?;
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        let mut bytes = [0u8; 4];
+        bytes.copy_from_slice(self.take(4)?);
+        Ok(u32::from_le_bytes(bytes))
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(self.take(8)?);
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    fn string(&mut self) -> Result<String> {
+        let len = self.u32()? as usize;
+        let name = match self.take(len)?.split_last() {
+            Some((0, name)) => std::str::from_utf8(name)
+                .map_err(|_| Error::SerializationError)?
+                .to_string(),
+            _ => return Err(Error::SerializationError),
+        };
+        self.pos = self.pos.next_multiple_of(4);
+        Ok(name)
+    }
+}
+
+// ============================================================================
+// Publisher side
+// ============================================================================
+
+/// Coherent set and write order state of one Publisher, shared with its
+/// writers.
+pub(crate) struct CoherentGroup {
+    group_id: u64,
+    ordered: bool,
+    state: Mutex<GroupState>,
+}
+
+struct GroupState {
+    open: Option<OpenSet>,
+    next_set_seq: u64,
+    last_order: u64,
+    markers: DataWriter<CoherentSetEnd>,
+}
+
+struct OpenSet {
+    set_seq: u64,
+    counts: BTreeMap<String, u32>,
+}
+
+impl CoherentGroup {
+    pub(crate) fn new(participant: &Arc<Participant>, ordered: bool) -> Result<Self> {
+        static NEXT_GROUP: AtomicU64 = AtomicU64::new(1);
+
+        let mut hasher = DefaultHasher::new();
+        participant.guid().prefix.hash(&mut hasher);
+        NEXT_GROUP.fetch_add(1, Ordering::Relaxed).hash(&mut hasher);
+
+        let markers = participant
+            .topic::<CoherentSetEnd>(SET_END_TOPIC)?
+            .writer()
+            .qos(marker_qos())
+            .build()?;
+        Ok(Self {
+            group_id: hasher.finish(),
+            ordered,
+            state: Mutex::new(GroupState {
+                open: None,
+                next_set_seq: 1,
+                last_order: 0,
+                markers,
+            }),
+        })
+    }
+
+    /// Open a new set.
+    pub(crate) fn begin(&self) {
+        let mut state = lock(&self.state);
+        let set_seq = state.next_set_seq;
+        state.next_set_seq += 1;
+        state.open = Some(OpenSet {
+            set_seq,
+            counts: BTreeMap::new(),
+        });
+    }
+
+    /// Close the open set and publish its marker.
+    pub(crate) fn end(&self) -> Result<()> {
+        let mut state = lock(&self.state);
+        let Some(set) = state.open.take() else {
+            return Ok(());
+        };
+        if set.counts.is_empty() {
+            return Ok(());
+        }
+
+        let marker = CoherentSetEnd {
+            group_id: self.group_id,
+            set_seq: set.set_seq,
+            topics: set.counts.into_iter().collect(),
+        };
+        log::debug!(
+            "[coherent] end set group={:016x} seq={} topics={:?}",
+            marker.group_id,
+            marker.set_seq,
+            marker.topics
+        );
+        state.markers.write(&marker)
+    }
+
+    /// Tag for the sample being written, if a set is open or the group is
+    /// ordered. Call once per sample: each call takes the next write order.
+    pub(crate) fn current(&self) -> Option<CoherentSetTag> {
+        let mut state = lock(&self.state);
+        let set_seq = state.open.as_ref().map(|set| set.set_seq);
+        if !self.ordered && set_seq.is_none() {
+            return None;
+        }
+        let order = if self.ordered {
+            state.last_order += 1;
+            state.last_order
+        } else {
+            0
+        };
+        Some(CoherentSetTag {
+            group_id: self.group_id,
+            set_seq: set_seq.unwrap_or(0),
+            order,
+        })
+    }
+
+    /// Count a sample written with `tag` on `topic`. Ignored if the set has
+    /// been closed meanwhile: readers pass such late samples through.
+    pub(crate) fn record(&self, tag: CoherentSetTag, topic: &str) {
+        let mut state = lock(&self.state);
+        if let Some(set) = state.open.as_mut().filter(|set| set.set_seq == tag.set_seq) {
+            *set.counts.entry(topic.to_string()).or_default() += 1;
+        }
+    }
+}
+
+// ============================================================================
+// Subscriber side
+// ============================================================================
+
+/// Holds the tagged samples of a Subscriber's readers until their set is
+/// complete, and puts them in write order for ordered groups.
+pub(crate) struct CoherentGate {
+    state: Mutex<GateState>,
+    waker: Arc<GateWaker>,
+}
+
+struct GateState {
+    markers: DataReader<CoherentSetEnd>,
+    /// Indexed by member id; `None` once the reader is dropped.
+    members: Vec<Option<Member>>,
+    groups: HashMap<u64, PendingGroup>,
+    access_locked: bool,
+    ordered: bool,
+    /// Samples leaving the gate on the next delivery.
+    staged: Vec<(usize, IndexEntry)>,
+    /// Newest write order drained, per ordered publisher.
+    newest: HashMap<u64, u64>,
+    /// Number given to the next sample delivered to a member.
+    next_delivery: u64,
+}
+
+struct Member {
+    topic: String,
+    ring: Arc<IndexRing>,
+    status: Arc<StatusCondition>,
+    /// Released samples with their delivery number.
+    ready: VecDeque<(u64, IndexEntry)>,
+    /// Publishers this reader received coherent samples from.
+    seen_groups: HashSet<u64>,
+}
+
+#[derive(Default)]
+struct PendingGroup {
+    released: Option<u64>,
+    sets: BTreeMap<u64, PendingSet>,
+}
+
+#[derive(Default)]
+struct PendingSet {
+    marker: Option<Vec<(String, u32)>>,
+    held: Vec<(usize, IndexEntry)>,
+    received: HashMap<usize, u32>,
+}
+
+impl CoherentGate {
+    pub(crate) fn new(participant: &Arc<Participant>, ordered: bool) -> Result<Arc<Self>> {
+        let markers = participant
+            .topic::<CoherentSetEnd>(SET_END_TOPIC)?
+            .reader()
+            .qos(marker_qos())
+            .build()?;
+        let waker = Arc::new(GateWaker::default());
+        markers
+            .get_status_condition()
+            .add_waitset_signal(Arc::clone(&waker) as Arc<dyn WaitsetSignal>);
+
+        Ok(Arc::new(Self {
+            state: Mutex::new(GateState {
+                markers,
+                members: Vec::new(),
+                groups: HashMap::new(),
+                access_locked: false,
+                ordered,
+                staged: Vec::new(),
+                newest: HashMap::new(),
+                next_delivery: 0,
+            }),
+            waker,
+        }))
+    }
+
+    /// Route a reader's samples through the gate.
+    pub(crate) fn register(
+        self: &Arc<Self>,
+        topic: &str,
+        ring: Arc<IndexRing>,
+        status: Arc<StatusCondition>,
+    ) -> CoherentMembership {
+        let mut state = lock(&self.state);
+        let id = state.members.len();
+        lock(&self.waker.statuses).push((id, Arc::downgrade(&status)));
+        state.members.push(Some(Member {
+            topic: topic.to_string(),
+            ring,
+            status,
+            ready: VecDeque::new(),
+            seen_groups: HashSet::new(),
+        }));
+        CoherentMembership(CoherentMember {
+            gate: Arc::clone(self),
+            id,
+        })
+    }
+
+    /// Hold back (or release) complete sets, for `Subscriber::begin_access`.
+    pub(crate) fn set_access_locked(&self, locked: bool) {
+        lock(&self.state).access_locked = locked;
+        if !locked {
+            self.pump();
+        }
+    }
+
+    /// Topics of the samples released to the members and not read yet, in
+    /// delivery order.
+    pub(crate) fn ready_topics(&self) -> Vec<String> {
+        self.pump();
+        let state = lock(&self.state);
+        let mut ready: Vec<(u64, &str)> = state
+            .members
+            .iter()
+            .flatten()
+            .flat_map(|m| m.ready.iter().map(|(n, _)| (*n, m.topic.as_str())))
+            .collect();
+        ready.sort_unstable_by_key(|(n, _)| *n);
+        ready.into_iter().map(|(_, t)| t.to_string()).collect()
+    }
+
+    /// Drain markers and member rings, release complete sets and wake the
+    /// readers that got samples.
+    fn pump(&self) {
+        let notify = {
+            let mut guard = lock(&self.state);
+            let state = &mut *guard;
+
+            // Markers first: intra-process, everything they announce was
+            // pushed to the member rings before them.
+            while let Ok(Some(marker)) = state.markers.take() {
+                state.on_marker(marker);
+            }
+
+            state.drain_rings();
+            let watermarks = state.ordered.then(|| {
+                // Anything written before the newest sample drained so far
+                // is in a ring by now
+                let newest = state.newest.clone();
+                state.drain_rings();
+                newest
+            });
+            if !state.access_locked {
+                state.release_sets();
+            }
+
+            let mut notify = state.deliver(watermarks.as_ref());
+            notify.sort_unstable();
+            notify.dedup();
+            notify
+                .into_iter()
+                .filter_map(|id| state.members[id].as_ref().map(|m| Arc::clone(&m.status)))
+                .collect::<Vec<_>>()
+        };
+
+        for status in notify {
+            status.set_active_statuses(StatusMask::DATA_AVAILABLE);
+        }
+    }
+
+    fn unregister(&self, id: usize) {
+        let mut state = lock(&self.state);
+        lock(&self.waker.statuses).retain(|(member, _)| *member != id);
+        let slab_pool = rt::get_slab_pool();
+        if let Some(member) = state.members.get_mut(id).and_then(Option::take) {
+            for (_, entry) in member.ready {
+                slab_pool.release(entry.handle);
+            }
+        }
+        state.staged.retain(|(member, entry)| {
+            if *member == id {
+                slab_pool.release(entry.handle);
+            }
+            *member != id
+        });
+        for set in state.groups.values_mut().flat_map(|g| g.sets.values_mut()) {
+            set.held.retain(|(member, entry)| {
+                if *member == id {
+                    slab_pool.release(entry.handle);
+                }
+                *member != id
+            });
+        }
+    }
+}
+
+impl GateState {
+    fn on_marker(&mut self, marker: CoherentSetEnd) {
+        let group = self.groups.entry(marker.group_id).or_default();
+        if group.released.is_some_and(|r| marker.set_seq <= r) {
+            return;
+        }
+        group.sets.entry(marker.set_seq).or_default().marker = Some(marker.topics);
+    }
+
+    fn drain_rings(&mut self) {
+        for id in 0..self.members.len() {
+            while let Some(entry) = self.members[id].as_ref().and_then(|m| m.ring.pop()) {
+                self.route(id, entry);
+            }
+        }
+    }
+
+    fn route(&mut self, id: usize, entry: IndexEntry) {
+        let Some(member) = self.members[id].as_mut() else {
+            rt::get_slab_pool().release(entry.handle);
+            return;
+        };
+        let Some(tag) = entry.coherent else {
+            self.staged.push((id, entry));
+            return;
+        };
+
+        if tag.order != 0 {
+            let newest = self.newest.entry(tag.group_id).or_default();
+            *newest = (*newest).max(tag.order);
+        }
+        if tag.set_seq == 0 {
+            self.staged.push((id, entry));
+            return;
+        }
+
+        member.seen_groups.insert(tag.group_id);
+        let group = self.groups.entry(tag.group_id).or_default();
+        if group.released.is_some_and(|r| tag.set_seq <= r) {
+            // Written while the set was being closed
+            self.staged.push((id, entry));
+            return;
+        }
+        let set = group.sets.entry(tag.set_seq).or_default();
+        set.held.push((id, entry));
+        *set.received.entry(id).or_default() += 1;
+    }
+
+    /// Count a tagged sample the member's reader dropped (e.g. filtered).
+    fn dropped(&mut self, id: usize, tag: CoherentSetTag) {
+        let Some(member) = self.members[id].as_mut() else {
+            return;
+        };
+        if tag.set_seq == 0 {
+            return;
+        }
+        member.seen_groups.insert(tag.group_id);
+        let group = self.groups.entry(tag.group_id).or_default();
+        if group.released.is_some_and(|r| tag.set_seq <= r) {
+            return;
+        }
+        *group
+            .sets
+            .entry(tag.set_seq)
+            .or_default()
+            .received
+            .entry(id)
+            .or_default() += 1;
+    }
+
+    fn release_sets(&mut self) {
+        let members = &self.members;
+        let staged = &mut self.staged;
+        for (&group_id, group) in &mut self.groups {
+            let complete = group
+                .sets
+                .iter()
+                .rev()
+                .find(|(_, set)| set.is_complete(group_id, members))
+                .map(|(&set_seq, _)| set_seq);
+            if let Some(set_seq) = complete {
+                group.release_through(group_id, set_seq, staged);
+            }
+            while group.sets.len() > MAX_PENDING_SETS {
+                if let Some(&oldest) = group.sets.keys().next() {
+                    group.release_through(group_id, oldest, staged);
+                }
+            }
+        }
+    }
+
+    /// Hand staged samples to their members and return the members to wake.
+    /// With `watermarks` (ordered gates), samples are delivered in write
+    /// order and those newer than their publisher's watermark stay staged.
+    fn deliver(&mut self, watermarks: Option<&HashMap<u64, u64>>) -> Vec<usize> {
+        let mut staged = std::mem::take(&mut self.staged);
+        if let Some(watermarks) = watermarks {
+            // Stable: unordered samples keep their arrival order
+            staged.sort_by_key(|(_, entry)| {
+                entry
+                    .coherent
+                    .filter(|tag| tag.order != 0)
+                    .map(|tag| (tag.group_id, tag.order))
+            });
+            staged.retain(|(id, entry)| {
+                let late = entry.coherent.is_some_and(|tag| {
+                    tag.order > watermarks.get(&tag.group_id).copied().unwrap_or(0)
+                });
+                if late {
+                    self.staged.push((*id, *entry));
+                }
+                !late
+            });
+        }
+
+        // Members holding back samples are woken too, so they pump again
+        let mut notify: Vec<usize> = self.staged.iter().map(|(id, _)| *id).collect();
+        for (id, entry) in staged {
+            match self.members.get_mut(id).and_then(Option::as_mut) {
+                Some(member) => {
+                    member.ready.push_back((self.next_delivery, entry));
+                    self.next_delivery += 1;
+                    notify.push(id);
+                }
+                None => rt::get_slab_pool().release(entry.handle),
+            }
+        }
+        notify
+    }
+}
+
+impl PendingGroup {
+    /// Release every held set up to and including `set_seq`, in order.
+    fn release_through(
+        &mut self,
+        group_id: u64,
+        set_seq: u64,
+        staged: &mut Vec<(usize, IndexEntry)>,
+    ) {
+        let later = self.sets.split_off(&set_seq.saturating_add(1));
+        for (seq, set) in std::mem::replace(&mut self.sets, later) {
+            log::debug!(
+                "[coherent] release set group={:016x} seq={} samples={} complete={}",
+                group_id,
+                seq,
+                set.held.len(),
+                seq == set_seq && set.marker.is_some()
+            );
+            staged.extend(set.held);
+        }
+        self.released = Some(set_seq);
+    }
+}
+
+impl PendingSet {
+    fn is_complete(&self, group_id: u64, members: &[Option<Member>]) -> bool {
+        let Some(topics) = &self.marker else {
+            return false;
+        };
+        members.iter().enumerate().all(|(id, member)| {
+            let Some(member) = member
+                .as_ref()
+                .filter(|m| m.seen_groups.contains(&group_id))
+            else {
+                return true;
+            };
+            let expected: u32 = topics
+                .iter()
+                .filter(|(topic, _)| *topic == member.topic)
+                .map(|(_, count)| count)
+                .sum();
+            self.received.get(&id).copied().unwrap_or(0) >= expected
+        })
+    }
+}
+
+/// Wakes the gate's readers when a marker arrives, so a reader blocked on
+/// its status condition pumps the gate.
+#[derive(Default)]
+struct GateWaker {
+    statuses: Mutex<Vec<(usize, Weak<StatusCondition>)>>,
+}
+
+impl WaitsetSignal for GateWaker {
+    fn signal(&self) {
+        let statuses: Vec<_> = lock(&self.statuses)
+            .iter()
+            .filter_map(|(_, status)| status.upgrade())
+            .collect();
+        for status in statuses {
+            status.set_active_statuses(StatusMask::DATA_AVAILABLE);
+        }
+    }
+
+    fn id(&self) -> u64 {
+        // Only signal attached to the gate's private marker reader
+        1
+    }
+}
+
+/// Handle a reader (and its engine subscriber) uses to read through the gate.
+#[derive(Clone)]
+pub(crate) struct CoherentMember {
+    gate: Arc<CoherentGate>,
+    id: usize,
+}
+
+impl CoherentMember {
+    /// Move arrived samples through the gate.
+    pub(crate) fn pump(&self) {
+        self.gate.pump();
+    }
+
+    /// Next sample released to this reader.
+    pub(crate) fn pop_ready(&self) -> Option<IndexEntry> {
+        lock(&self.gate.state)
+            .members
+            .get_mut(self.id)?
+            .as_mut()?
+            .ready
+            .pop_front()
+            .map(|(_, entry)| entry)
+    }
+
+    /// Samples released to this reader and not read yet.
+    pub(crate) fn ready_len(&self) -> usize {
+        lock(&self.gate.state)
+            .members
+            .get(self.id)
+            .and_then(Option::as_ref)
+            .map_or(0, |m| m.ready.len())
+    }
+
+    /// Count a tagged sample that never reached the reader's ring, so its
+    /// set does not wait for it.
+    pub(crate) fn dropped(&self, tag: CoherentSetTag) {
+        lock(&self.gate.state).dropped(self.id, tag);
+    }
+}
+
+/// Registration owned by the reader; leaves the gate on drop.
+pub(crate) struct CoherentMembership(CoherentMember);
+
+impl Deref for CoherentMembership {
+    type Target = CoherentMember;
+
+    fn deref(&self) -> &CoherentMember {
+        &self.0
+    }
+}
+
+impl Drop for CoherentMembership {
+    fn drop(&mut self) {
+        self.0.gate.unregister(self.0.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dds::qos::Presentation;
+    use crate::dds::{DataReader, DataWriter};
+    use crate::generated::temperature::Temperature;
+    use std::time::{Duration, Instant};
+
+    const TOPICS: [&str; 3] = ["coherent/pose", "coherent/twist", "coherent/cov"];
+
+    type Endpoints = (
+        crate::dds::Publisher,
+        crate::dds::Subscriber,
+        Vec<DataWriter<Temperature>>,
+        Vec<DataReader<Temperature>>,
+    );
+
+    fn group_endpoints(name: &str) -> Endpoints {
+        group_endpoints_with(name, Presentation::group_coherent())
+    }
+
+    fn group_endpoints_with(name: &str, presentation: Presentation) -> Endpoints {
+        let participant = Participant::builder(name).build().expect("participant");
+        let qos = QoS::reliable().presentation(presentation);
+        let publisher = participant
+            .create_publisher(qos.clone())
+            .expect("publisher");
+        let subscriber = participant.create_subscriber(qos).expect("subscriber");
+
+        let mut writers = Vec::new();
+        let mut readers = Vec::new();
+        for name in TOPICS {
+            let topic = participant.topic::<Temperature>(name).expect("topic");
+            readers.push(
+                topic
+                    .reader()
+                    .qos(QoS::reliable())
+                    .subscriber(&subscriber)
+                    .build()
+                    .expect("reader"),
+            );
+            writers.push(
+                topic
+                    .writer()
+                    .qos(QoS::reliable())
+                    .publisher(&publisher)
+                    .build()
+                    .expect("writer"),
+            );
+        }
+        (publisher, subscriber, writers, readers)
+    }
+
+    fn write_all(writers: &[DataWriter<Temperature>], timestamp: i32) {
+        for writer in writers {
+            writer
+                .write(&Temperature {
+                    value: 1.0,
+                    timestamp,
+                })
+                .expect("write");
+        }
+    }
+
+    fn assert_none_visible(readers: &[DataReader<Temperature>]) {
+        for reader in readers {
+            assert!(reader.take().expect("take").is_none(), "torn set visible");
+        }
+    }
+
+    fn take_all(readers: &[DataReader<Temperature>]) -> Vec<Temperature> {
+        let deadline = Instant::now() + Duration::from_secs(1);
+        readers
+            .iter()
+            .map(|reader| loop {
+                if let Some(sample) = reader.take().expect("take") {
+                    break sample;
+                }
+                assert!(Instant::now() < deadline, "coherent set not released");
+                std::thread::sleep(Duration::from_millis(5));
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_set_released_across_topics_on_end() {
+        let (publisher, _subscriber, writers, readers) = group_endpoints("coherent_end");
+
+        publisher.begin_coherent_changes().expect("begin");
+        write_all(&writers, 1);
+        std::thread::sleep(Duration::from_millis(20));
+        assert_none_visible(&readers);
+
+        publisher.end_coherent_changes().expect("end");
+        let samples = take_all(&readers);
+        assert!(samples.iter().all(|sample| sample.timestamp == 1));
+    }
+
+    #[test]
+    fn test_access_lock_defers_complete_sets() {
+        let (publisher, subscriber, writers, readers) = group_endpoints("coherent_access");
+
+        subscriber.begin_access().expect("begin_access");
+        publisher.begin_coherent_changes().expect("begin");
+        write_all(&writers, 2);
+        publisher.end_coherent_changes().expect("end");
+        std::thread::sleep(Duration::from_millis(20));
+        assert_none_visible(&readers);
+
+        subscriber.end_access().expect("end_access");
+        let samples = take_all(&readers);
+        assert!(samples.iter().all(|sample| sample.timestamp == 2));
+    }
+
+    #[test]
+    fn test_group_order_across_topics() {
+        let (_publisher, subscriber, writers, readers) =
+            group_endpoints_with("ordered_group", Presentation::group_ordered());
+
+        let writes = [2, 0, 1, 1, 0, 2];
+        for (timestamp, &topic) in writes.iter().enumerate() {
+            writers[topic]
+                .write(&Temperature {
+                    value: 1.0,
+                    timestamp: timestamp as i32,
+                })
+                .expect("write");
+        }
+
+        let deadline = Instant::now() + Duration::from_secs(1);
+        let order = loop {
+            let order = subscriber.ordered_topics();
+            if order.len() == writes.len() {
+                break order;
+            }
+            assert!(Instant::now() < deadline, "samples not released");
+            std::thread::sleep(Duration::from_millis(5));
+        };
+
+        let expected: Vec<_> = writes.iter().map(|&topic| TOPICS[topic]).collect();
+        assert_eq!(order, expected);
+        for (timestamp, topic) in order.iter().enumerate() {
+            let reader = &readers[TOPICS.iter().position(|t| t == topic).expect("topic")];
+            let sample = reader.take().expect("take").expect("sample");
+            assert_eq!(sample.timestamp, timestamp as i32);
+        }
+        assert!(subscriber.ordered_topics().is_empty());
+    }
+
+    #[test]
+    fn test_set_end_marker_roundtrip() {
+        let marker = CoherentSetEnd {
+            group_id: 0x0123_4567_89ab_cdef,
+            set_seq: 42,
+            topics: vec![("robot/pose".into(), 1), ("robot/twist_cov".into(), 3)],
+        };
+        let mut buf = [0u8; 256];
+        let len = marker.encode_cdr2(&mut buf).expect("encode");
+        assert_eq!(len % 4, 0);
+        assert_eq!(
+            CoherentSetEnd::decode_cdr2(&buf[..len]).expect("decode"),
+            marker
+        );
+
+        assert!(CoherentSetEnd::decode_cdr2(&buf[..len - 4]).is_err());
+        assert!(matches!(
+            marker.encode_cdr2(&mut buf[..16]),
+            Err(Error::BufferTooSmall)
+        ));
+    }
+}
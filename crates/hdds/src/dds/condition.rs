@@ -7,6 +7,7 @@
 //! to WaitSets to enable event-driven blocking wait patterns.
 //!
 
+use super::read_condition::ReadCondition;
 use crate::core::rt::waitset::WaitsetSignal;
 use std::any::Any;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -81,6 +82,12 @@ impl StatusMask {
     /// Publication matched (DataWriter)
     pub const PUBLICATION_MATCHED: StatusMask = StatusMask(1 << 10);
 
+    /// Staleness watchdog changed (DataReader, HDDS extension)
+    pub const SAMPLE_STALENESS_CHANGED: StatusMask = StatusMask(1 << 11);
+
+    /// Endpoint with another type discovered on the topic (DataReader)
+    pub const INCONSISTENT_TOPIC: StatusMask = StatusMask(1 << 12);
+
     /// Create a new StatusMask from raw bits
     #[must_use]
     pub const fn from_bits(bits: u32) -> Self {
@@ -143,6 +150,9 @@ pub struct StatusCondition {
 
     /// Waitset hooks to notify when trigger value changes
     waitset_signals: Mutex<Vec<WaitsetHook>>,
+
+    /// Read conditions of the entity, woken when data arrives
+    read_conditions: Mutex<Vec<Weak<ReadCondition>>>,
 }
 
 impl StatusCondition {
@@ -159,6 +169,7 @@ impl StatusCondition {
             enabled_statuses: Arc::new(Mutex::new(StatusMask::NONE)),
             active_statuses: Arc::new(Mutex::new(StatusMask::NONE)),
             waitset_signals: Mutex::new(Vec::new()),
+            read_conditions: Mutex::new(Vec::new()),
         }
     }
 
@@ -203,6 +214,27 @@ impl StatusCondition {
         if enabled.and(mask).bits() != 0 {
             self.notify_waitsets();
         }
+        if mask.contains(StatusMask::DATA_AVAILABLE) {
+            self.notify_read_conditions();
+        }
+    }
+
+    /// Forward data arrivals to `condition` (see `DataReader::create_readcondition`).
+    pub(crate) fn add_read_condition(&self, condition: &Arc<ReadCondition>) {
+        if let Ok(mut conditions) = self.read_conditions.lock() {
+            conditions.retain(|c| c.strong_count() > 0);
+            conditions.push(Arc::downgrade(condition));
+        }
+    }
+
+    fn notify_read_conditions(&self) {
+        let live: Vec<Arc<ReadCondition>> = match self.read_conditions.lock() {
+            Ok(conditions) => conditions.iter().filter_map(Weak::upgrade).collect(),
+            Err(_) => return,
+        };
+        for condition in live {
+            condition.on_data_available();
+        }
     }
 
     /// Get the currently active statuses
@@ -219,6 +251,13 @@ impl StatusCondition {
             *active = StatusMask::NONE;
         }
     }
+
+    /// Clear the statuses in `mask` only (called after reading one status)
+    pub(crate) fn clear_active_status(&self, mask: StatusMask) {
+        if let Ok(mut active) = self.active_statuses.lock() {
+            *active = StatusMask::from_bits(active.bits() & !mask.bits());
+        }
+    }
 }
 
 impl Condition for StatusCondition {
@@ -42,7 +42,8 @@ use std::sync::{Arc, Mutex, RwLock, Weak};
 
 use crate::core::discovery::GUID;
 use crate::core::rt::{IndexRing, TopicMerger};
-use crate::dds::qos::Reliability;
+use crate::dds::liveliness::WriterLiveliness;
+use crate::dds::qos::{Partition, Reliability};
 
 /// Domain ID type (0-232 per DDS spec)
 pub type DomainId = u32;
@@ -157,6 +158,8 @@ pub struct LocalEndpointEntry {
     pub kind: EndpointKind,
     /// QoS Reliability policy
     pub reliability: Reliability,
+    /// QoS Partition policy
+    pub partition: Partition,
     /// TopicMerger (writers only) - used by readers to bind
     pub merger: Option<Arc<TopicMerger>>,
     /// IndexRing (readers only) - receives data from merger
@@ -166,6 +169,8 @@ pub struct LocalEndpointEntry {
     /// For readers: called when a matching writer is found
     /// For writers: None
     pub bind_callback: Option<Box<dyn Fn(Arc<TopicMerger>) + Send + Sync>>,
+    /// Liveliness assertions (writers only)
+    pub(crate) liveliness: Option<Arc<WriterLiveliness>>,
 }
 
 /// Check QoS compatibility between writer and reader
@@ -188,9 +193,11 @@ impl std::fmt::Debug for LocalEndpointEntry {
             .field("guid", &self.guid)
             .field("kind", &self.kind)
             .field("reliability", &self.reliability)
+            .field("partition", &self.partition)
             .field("has_merger", &self.merger.is_some())
             .field("has_ring", &self.ring.is_some())
             .field("has_bind_callback", &self.bind_callback.is_some())
+            .field("has_liveliness", &self.liveliness.is_some())
             .finish()
     }
 }
@@ -250,7 +257,7 @@ impl DomainState {
         }
     }
 
-    /// Register a writer endpoint
+    /// Register a writer endpoint in the default partition
     ///
     /// Returns a BindToken that unregisters on drop.
     /// Also triggers auto-binding with any existing matching readers (QoS compatible only).
@@ -260,6 +267,47 @@ impl DomainState {
         guid: GUID,
         merger: Arc<TopicMerger>,
         reliability: Reliability,
+    ) -> BindToken {
+        self.register_writer_with_partition(key, guid, merger, reliability, Partition::default())
+    }
+
+    /// Register a writer endpoint in `partition`
+    ///
+    /// Like [`register_writer`](Self::register_writer), but only binds
+    /// readers whose PARTITION QoS matches.
+    pub fn register_writer_with_partition(
+        self: &Arc<Self>,
+        key: MatchKey,
+        guid: GUID,
+        merger: Arc<TopicMerger>,
+        reliability: Reliability,
+        partition: Partition,
+    ) -> BindToken {
+        self.register_writer_entry(key, guid, merger, reliability, partition, None)
+    }
+
+    /// Register a writer endpoint in `partition` whose liveliness readers
+    /// of this process track (see [`find_writer_liveliness`](Self::find_writer_liveliness))
+    pub(crate) fn register_writer_with_liveliness(
+        self: &Arc<Self>,
+        key: MatchKey,
+        guid: GUID,
+        merger: Arc<TopicMerger>,
+        reliability: Reliability,
+        partition: Partition,
+        liveliness: Arc<WriterLiveliness>,
+    ) -> BindToken {
+        self.register_writer_entry(key, guid, merger, reliability, partition, Some(liveliness))
+    }
+
+    fn register_writer_entry(
+        self: &Arc<Self>,
+        key: MatchKey,
+        guid: GUID,
+        merger: Arc<TopicMerger>,
+        reliability: Reliability,
+        partition: Partition,
+        liveliness: Option<Arc<WriterLiveliness>>,
     ) -> BindToken {
         let mut endpoints = self.endpoints.write().unwrap_or_else(|e| e.into_inner());
 
@@ -267,9 +315,11 @@ impl DomainState {
             guid,
             kind: EndpointKind::Writer,
             reliability,
+            partition,
             merger: Some(merger.clone()),
             ring: None,
             bind_callback: None,
+            liveliness,
         };
 
         // Get or create endpoint list for this key
@@ -287,6 +337,14 @@ impl DomainState {
                     );
                     continue;
                 }
+                if !entry.partition.is_compatible_with(&existing.partition) {
+                    log::debug!(
+                        "[DomainRegistry] Skipping bind: writer partition {:?} does not match reader {:?}",
+                        entry.partition.names,
+                        existing.partition.names
+                    );
+                    continue;
+                }
 
                 if let Some(ref callback) = existing.bind_callback {
                     log::debug!(
@@ -304,7 +362,7 @@ impl DomainState {
         BindToken::new(self, key, guid)
     }
 
-    /// Register a reader endpoint
+    /// Register a reader endpoint in the default partition
     ///
     /// Returns a BindToken that unregisters on drop.
     /// Also triggers auto-binding with any existing matching writers (QoS compatible only).
@@ -316,6 +374,32 @@ impl DomainState {
         reliability: Reliability,
         bind_callback: F,
     ) -> BindToken
+    where
+        F: Fn(Arc<TopicMerger>) + Send + Sync + 'static,
+    {
+        self.register_reader_with_partition(
+            key,
+            guid,
+            ring,
+            reliability,
+            Partition::default(),
+            bind_callback,
+        )
+    }
+
+    /// Register a reader endpoint in `partition`
+    ///
+    /// Like [`register_reader`](Self::register_reader), but only binds to
+    /// writers whose PARTITION QoS matches.
+    pub fn register_reader_with_partition<F>(
+        self: &Arc<Self>,
+        key: MatchKey,
+        guid: GUID,
+        ring: Arc<IndexRing>,
+        reliability: Reliability,
+        partition: Partition,
+        bind_callback: F,
+    ) -> BindToken
     where
         F: Fn(Arc<TopicMerger>) + Send + Sync + 'static,
     {
@@ -336,6 +420,14 @@ impl DomainState {
                     );
                     continue;
                 }
+                if !existing.partition.is_compatible_with(&partition) {
+                    log::debug!(
+                        "[DomainRegistry] Skipping bind: writer partition {:?} does not match reader {:?}",
+                        existing.partition.names,
+                        partition.names
+                    );
+                    continue;
+                }
 
                 if let Some(ref merger) = existing.merger {
                     log::debug!(
@@ -352,9 +444,11 @@ impl DomainState {
             guid,
             kind: EndpointKind::Reader,
             reliability,
+            partition,
             merger: None,
             ring: Some(ring),
             bind_callback: Some(Box::new(bind_callback)),
+            liveliness: None,
         };
 
         entries.push(entry);
@@ -382,6 +476,29 @@ impl DomainState {
         );
     }
 
+    /// Unregister every endpoint of the participant with GUID prefix `prefix`
+    ///
+    /// Used for participants inherited through `fork()`, whose endpoints are
+    /// never dropped in the child. Returns the number of endpoints removed.
+    pub fn unregister_participant(&self, prefix: &[u8; 12]) -> usize {
+        let mut endpoints = self.endpoints.write().unwrap_or_else(|e| e.into_inner());
+
+        let mut removed = 0;
+        endpoints.retain(|_, entries| {
+            let before = entries.len();
+            entries.retain(|e| &e.guid.prefix != prefix);
+            removed += before - entries.len();
+            !entries.is_empty()
+        });
+
+        log::debug!(
+            "[DomainRegistry] Unregistered {} endpoint(s) of participant {:02x?}",
+            removed,
+            prefix
+        );
+        removed
+    }
+
     /// Find all writers matching a key
     pub fn find_writers(&self, key: &MatchKey) -> Vec<Arc<TopicMerger>> {
         let endpoints = self.endpoints.read().unwrap_or_else(|e| e.into_inner());
@@ -398,6 +515,84 @@ impl DomainState {
             .unwrap_or_default()
     }
 
+    /// GUIDs of all writers matching a key
+    pub fn find_writer_guids(&self, key: &MatchKey) -> Vec<GUID> {
+        let endpoints = self.endpoints.read().unwrap_or_else(|e| e.into_inner());
+
+        endpoints
+            .get(key)
+            .map(|entries| {
+                entries
+                    .iter()
+                    .filter(|e| e.kind == EndpointKind::Writer)
+                    .map(|e| e.guid)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// GUIDs of all writers on a topic, whatever their type
+    pub fn find_writer_guids_for_topic(&self, topic_name: &str) -> Vec<GUID> {
+        let endpoints = self.endpoints.read().unwrap_or_else(|e| e.into_inner());
+
+        endpoints
+            .iter()
+            .filter(|(key, _)| &*key.topic_name == topic_name)
+            .flat_map(|(_, entries)| entries.iter())
+            .filter(|e| e.kind == EndpointKind::Writer)
+            .map(|e| e.guid)
+            .collect()
+    }
+
+    /// Find the GUIDs of readers matching a key that a writer with
+    /// `writer_reliability` in `writer_partition` binds to
+    pub fn find_reader_guids(
+        &self,
+        key: &MatchKey,
+        writer_reliability: Reliability,
+        writer_partition: &Partition,
+    ) -> Vec<GUID> {
+        let endpoints = self.endpoints.read().unwrap_or_else(|e| e.into_inner());
+
+        endpoints
+            .get(key)
+            .map(|entries| {
+                entries
+                    .iter()
+                    .filter(|e| e.kind == EndpointKind::Reader)
+                    .filter(|e| qos_compatible(writer_reliability, e.reliability))
+                    .filter(|e| writer_partition.is_compatible_with(&e.partition))
+                    .map(|e| e.guid)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Writers matching a key that a reader with `reader_reliability` in
+    /// `reader_partition` binds to, with their liveliness when registered
+    /// through [`register_writer_with_liveliness`](Self::register_writer_with_liveliness)
+    pub(crate) fn find_writer_liveliness(
+        &self,
+        key: &MatchKey,
+        reader_reliability: Reliability,
+        reader_partition: &Partition,
+    ) -> Vec<(GUID, Option<Arc<WriterLiveliness>>)> {
+        let endpoints = self.endpoints.read().unwrap_or_else(|e| e.into_inner());
+
+        endpoints
+            .get(key)
+            .map(|entries| {
+                entries
+                    .iter()
+                    .filter(|e| e.kind == EndpointKind::Writer)
+                    .filter(|e| qos_compatible(e.reliability, reader_reliability))
+                    .filter(|e| e.partition.is_compatible_with(reader_partition))
+                    .map(|e| (e.guid, e.liveliness.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Find all readers matching a key
     pub fn find_readers(&self, key: &MatchKey) -> Vec<Arc<IndexRing>> {
         let endpoints = self.endpoints.read().unwrap_or_else(|e| e.into_inner());
@@ -758,6 +953,65 @@ mod tests {
         );
     }
 
+    /// Test: readers bind only to writers with a matching PARTITION
+    #[test]
+    fn test_partition_mismatch_no_bind() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let domain = Arc::new(DomainState::new(0));
+        let key = MatchKey::from_names("test/topic", "TestType");
+
+        let bound = Arc::new(AtomicUsize::new(0));
+        let bound_clone = bound.clone();
+        let reader_guid = GUID::new([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1], [0, 0, 0, 1]);
+        let ring = Arc::new(IndexRing::with_capacity(1024));
+        let _reader_token = domain.register_reader_with_partition(
+            key.clone(),
+            reader_guid,
+            ring,
+            Reliability::Reliable,
+            Partition::single("bench/*"),
+            move |_| {
+                bound_clone.fetch_add(1, Ordering::SeqCst);
+            },
+        );
+
+        let production = GUID::new([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2], [0, 0, 0, 2]);
+        let _production_token = domain.register_writer_with_partition(
+            key.clone(),
+            production,
+            Arc::new(TopicMerger::new()),
+            Reliability::Reliable,
+            Partition::single("production"),
+        );
+        assert_eq!(bound.load(Ordering::SeqCst), 0);
+        assert!(domain
+            .find_reader_guids(
+                &key,
+                Reliability::Reliable,
+                &Partition::single("production")
+            )
+            .is_empty());
+
+        let bench = GUID::new([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3], [0, 0, 0, 2]);
+        let _bench_token = domain.register_writer_with_partition(
+            key.clone(),
+            bench,
+            Arc::new(TopicMerger::new()),
+            Reliability::Reliable,
+            Partition::single("bench/cell1"),
+        );
+        assert_eq!(bound.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            domain.find_reader_guids(
+                &key,
+                Reliability::Reliable,
+                &Partition::single("bench/cell1")
+            ),
+            vec![reader_guid]
+        );
+    }
+
     #[test]
     fn test_domain_registry_get_or_create() {
         let registry = DomainRegistry::global();
@@ -800,6 +1054,30 @@ mod tests {
         assert_eq!(domain.endpoint_count(), 0);
     }
 
+    #[test]
+    fn test_unregister_participant_keeps_other_participants() {
+        let domain = Arc::new(DomainState::new(0));
+        let key = MatchKey::from_names("test/topic", "TestType");
+        let inherited = GUID::new([7; 12], [0, 0, 0, 2]);
+        let other = GUID::new([8; 12], [0, 0, 0, 2]);
+
+        let _t1 = domain.register_writer(
+            key.clone(),
+            inherited,
+            Arc::new(TopicMerger::new()),
+            Reliability::BestEffort,
+        );
+        let _t2 = domain.register_writer(
+            key.clone(),
+            other,
+            Arc::new(TopicMerger::new()),
+            Reliability::BestEffort,
+        );
+
+        assert_eq!(domain.unregister_participant(&inherited.prefix), 1);
+        assert_eq!(domain.find_writer_guids(&key), vec![other]);
+    }
+
     #[test]
     fn test_multiple_writers_same_topic() {
         let domain = Arc::new(DomainState::new(0));
@@ -900,6 +1178,10 @@ mod tests {
             len: data.len() as u32,
             flags: 0x01,
             timestamp_ns: 0,
+            trace_id: 0,
+            coherent: None,
+            rx_timestamps: crate::engine::RxTimestamps::NONE,
+            key_hash: None,
         };
 
         let push_ok = writer_merger.push(entry);
@@ -396,6 +396,10 @@
             len: data.len() as u32,
             flags: 0x01,
             timestamp_ns: 0,
+            trace_id: 0,
+            coherent: None,
+            rx_timestamps: crate::engine::RxTimestamps::NONE,
+            key_hash: None,
         };
 
         let push_ok = writer_merger.push(entry);
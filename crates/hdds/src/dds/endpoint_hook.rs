@@ -0,0 +1,208 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Hooks run on the creation of every writer and reader.
+//!
+//! A hook sees the endpoint about to be created (kind, topic, type,
+//! participant) and its final QoS, which it may change. Returning an error
+//! rejects the creation: `build()` fails with that error. This lets a
+//! platform team enforce naming conventions and QoS policies across every
+//! application linking hdds:
+//!
+//! - [`register_endpoint_hook`] installs a process-wide hook, run for the
+//!   endpoints of every participant;
+//! - [`ParticipantBuilder::with_endpoint_hook`] installs a hook for the
+//!   endpoints of one participant, run after the process-wide ones.
+//!
+//! Hooks run in registration order, before access control and static
+//! deployment checks, so these see the QoS the hooks settled on.
+//!
+//! [`ParticipantBuilder::with_endpoint_hook`]: crate::dds::ParticipantBuilder::with_endpoint_hook
+
+use crate::dds::{EndpointKind, Error, QoS, Result};
+use std::sync::{Arc, RwLock};
+
+/// Hook run on the creation of a writer or reader.
+///
+/// Implemented for closures taking `&mut EndpointCreation`.
+pub trait EndpointHook: Send + Sync {
+    /// Inspect or adjust the endpoint; an error rejects its creation.
+    fn on_create(&self, endpoint: &mut EndpointCreation<'_>) -> Result<()>;
+}
+
+impl<F> EndpointHook for F
+where
+    F: Fn(&mut EndpointCreation<'_>) -> Result<()> + Send + Sync,
+{
+    fn on_create(&self, endpoint: &mut EndpointCreation<'_>) -> Result<()> {
+        self(endpoint)
+    }
+}
+
+/// Writer or reader being created, as seen by an [`EndpointHook`].
+#[derive(Debug)]
+pub struct EndpointCreation<'a> {
+    kind: EndpointKind,
+    topic: &'a str,
+    type_name: &'a str,
+    participant: &'a str,
+    domain_id: u32,
+    qos: &'a mut QoS,
+}
+
+impl<'a> EndpointCreation<'a> {
+    pub(crate) fn new(
+        kind: EndpointKind,
+        topic: &'a str,
+        type_name: &'a str,
+        participant: &'a str,
+        domain_id: u32,
+        qos: &'a mut QoS,
+    ) -> Self {
+        Self {
+            kind,
+            topic,
+            type_name,
+            participant,
+            domain_id,
+            qos,
+        }
+    }
+
+    /// Writer or reader.
+    pub fn kind(&self) -> EndpointKind {
+        self.kind
+    }
+
+    /// Topic name.
+    pub fn topic(&self) -> &str {
+        self.topic
+    }
+
+    /// Type name announced for the topic.
+    pub fn type_name(&self) -> &str {
+        self.type_name
+    }
+
+    /// Name of the participant creating the endpoint.
+    pub fn participant_name(&self) -> &str {
+        self.participant
+    }
+
+    /// Domain of the participant.
+    pub fn domain_id(&self) -> u32 {
+        self.domain_id
+    }
+
+    /// QoS the endpoint will be created with.
+    pub fn qos(&self) -> &QoS {
+        self.qos
+    }
+
+    /// QoS to adjust before the endpoint is created.
+    pub fn qos_mut(&mut self) -> &mut QoS {
+        self.qos
+    }
+
+    /// Error rejecting this endpoint for `reason`, naming the endpoint.
+    pub fn reject(&self, reason: impl std::fmt::Display) -> Error {
+        let kind = match self.kind {
+            EndpointKind::Writer => "writer",
+            EndpointKind::Reader => "reader",
+        };
+        Error::EndpointRejected(format!("{} on '{}': {}", kind, self.topic, reason))
+    }
+}
+
+/// Hooks run for the endpoints of every participant of the process.
+static PROCESS_HOOKS: RwLock<Vec<Arc<dyn EndpointHook>>> = RwLock::new(Vec::new());
+
+/// Run `hook` on the creation of every writer and reader of the process,
+/// including those of participants built earlier.
+///
+/// # Example
+/// ```no_run
+/// use hdds::dds::{register_endpoint_hook, EndpointCreation};
+///
+/// register_endpoint_hook(|endpoint: &mut EndpointCreation<'_>| {
+///     if !endpoint.topic().starts_with("fleet/") {
+///         return Err(endpoint.reject("topics must live under fleet/"));
+///     }
+///     endpoint.qos_mut().transport_priority.value = 10;
+///     Ok(())
+/// });
+/// ```
+pub fn register_endpoint_hook(hook: impl EndpointHook + 'static) {
+    PROCESS_HOOKS
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .push(Arc::new(hook));
+}
+
+/// Run the process-wide hooks, then `participant_hooks`, stopping at the
+/// first rejection.
+pub(crate) fn run_endpoint_hooks(
+    participant_hooks: &[Arc<dyn EndpointHook>],
+    endpoint: &mut EndpointCreation<'_>,
+) -> Result<()> {
+    let process_hooks = PROCESS_HOOKS
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+    for hook in process_hooks.iter().chain(participant_hooks) {
+        hook.on_create(endpoint)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hooks_adjust_qos_in_order_and_reject() {
+        let hooks: Vec<Arc<dyn EndpointHook>> = vec![
+            Arc::new(|endpoint: &mut EndpointCreation<'_>| {
+                endpoint.qos_mut().transport_priority.value = 5;
+                Ok(())
+            }),
+            Arc::new(|endpoint: &mut EndpointCreation<'_>| {
+                if endpoint.qos().transport_priority.value != 5 {
+                    return Err(Error::config("transport priority not set"));
+                }
+                match endpoint.topic().contains(char::is_uppercase) {
+                    true => Err(endpoint.reject("topic names are lowercase")),
+                    false => Ok(()),
+                }
+            }),
+        ];
+
+        let mut qos = QoS::default();
+        let mut endpoint = EndpointCreation::new(
+            EndpointKind::Writer,
+            "robot/pose",
+            "Pose",
+            "app",
+            0,
+            &mut qos,
+        );
+        run_endpoint_hooks(&hooks, &mut endpoint).expect("admitted");
+        assert_eq!(qos.transport_priority.value, 5);
+
+        let mut qos = QoS::default();
+        let mut endpoint = EndpointCreation::new(
+            EndpointKind::Reader,
+            "Robot/Pose",
+            "Pose",
+            "app",
+            0,
+            &mut qos,
+        );
+        match run_endpoint_hooks(&hooks, &mut endpoint) {
+            Err(Error::EndpointRejected(msg)) => {
+                assert_eq!(msg, "reader on 'Robot/Pose': topic names are lowercase")
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
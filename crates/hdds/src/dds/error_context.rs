@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Context attached to [`Error`](super::Error) values.
+//!
+//! An [`ErrorContext`] names what an error relates to: the entity, topic,
+//! QoS policy and file involved. It travels inside `Error::InvalidConfig` and
+//! `Error::UnsupportedOperation`, and [`Error::context`](super::Error::context) wraps
+//! any other error with one, keeping the original as its
+//! [`source`](std::error::Error::source).
+
+use std::fmt;
+
+/// Entity, topic, QoS policy and file an error relates to.
+///
+/// Every field is optional; unset fields are left out of the message.
+///
+/// ```
+/// use hdds::ErrorContext;
+///
+/// let context = ErrorContext::new().entity("DataWriter").topic("sensors/temp");
+/// assert_eq!(context.to_string(), "DataWriter on topic 'sensors/temp'");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ErrorContext {
+    /// Entity involved (e.g. `"DataWriter"`, `"participant 'robot'"`).
+    pub entity: Option<String>,
+    /// Topic name.
+    pub topic: Option<String>,
+    /// QoS or security policy (e.g. `"HISTORY"`).
+    pub policy: Option<String>,
+    /// File being read or written.
+    pub path: Option<String>,
+}
+
+impl ErrorContext {
+    /// Empty context.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the entity involved.
+    #[must_use]
+    pub fn entity(mut self, entity: impl Into<String>) -> Self {
+        self.entity = Some(entity.into());
+        self
+    }
+
+    /// Set the topic name.
+    #[must_use]
+    pub fn topic(mut self, topic: impl Into<String>) -> Self {
+        self.topic = Some(topic.into());
+        self
+    }
+
+    /// Set the QoS or security policy.
+    #[must_use]
+    pub fn policy(mut self, policy: impl Into<String>) -> Self {
+        self.policy = Some(policy.into());
+        self
+    }
+
+    /// Set the file path.
+    #[must_use]
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Whether no field is set.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entity.is_none()
+            && self.topic.is_none()
+            && self.policy.is_none()
+            && self.path.is_none()
+    }
+
+    /// Fill the fields unset here from `other`.
+    pub(crate) fn merge(&mut self, other: ErrorContext) {
+        self.entity = self.entity.take().or(other.entity);
+        self.topic = self.topic.take().or(other.topic);
+        self.policy = self.policy.take().or(other.policy);
+        self.path = self.path.take().or(other.path);
+    }
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts: Vec<String> = Vec::new();
+        match (&self.entity, &self.topic) {
+            (Some(entity), Some(topic)) => parts.push(format!("{} on topic '{}'", entity, topic)),
+            (Some(entity), None) => parts.push(entity.clone()),
+            (None, Some(topic)) => parts.push(format!("topic '{}'", topic)),
+            (None, None) => {}
+        }
+        if let Some(policy) = &self.policy {
+            parts.push(format!("policy {}", policy));
+        }
+        if let Some(path) = &self.path {
+            parts.push(format!("file {}", path));
+        }
+        f.write_str(&parts.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dds::Error;
+    use std::error::Error as _;
+
+    #[test]
+    fn test_config_error_message_and_context() {
+        let err = Error::config("history depth is 0").with_context(
+            ErrorContext::new()
+                .entity("DataWriter")
+                .topic("cmd")
+                .policy("HISTORY"),
+        );
+        assert_eq!(
+            err.to_string(),
+            "Configuration error: history depth is 0 (DataWriter on topic 'cmd', policy HISTORY)"
+        );
+        assert_eq!(err.context().and_then(|c| c.topic.as_deref()), Some("cmd"));
+        assert!(err.source().is_none());
+    }
+
+    #[test]
+    fn test_context_wraps_other_errors_as_source() {
+        let io = std::io::Error::new(std::io::ErrorKind::NotFound, "gone");
+        let err = Error::IoError(io)
+            .with_context(ErrorContext::new().path("/etc/hdds.xml"))
+            .with_context(ErrorContext::new().entity("participant 'p'"));
+        assert_eq!(
+            err.to_string(),
+            "participant 'p', file /etc/hdds.xml: I/O error: gone"
+        );
+        assert!(matches!(err.root(), Error::IoError(_)));
+
+        let source = err.source().expect("wrapped error");
+        assert_eq!(source.to_string(), "I/O error: gone");
+        assert_eq!(
+            source.source().map(ToString::to_string).as_deref(),
+            Some("gone")
+        );
+    }
+}
@@ -16,6 +16,8 @@
 //!
 //! condition  ::= field_name operator value
 //!
+//! field_name ::= identifier ('.' identifier)*
+//!
 //! operator   ::= '>' | '<' | '>=' | '<=' | '=' | '<>' | '!='
 //!
 //! value      ::= parameter | literal
@@ -109,10 +109,11 @@ impl<'a> Lexer<'a> {
         }
     }
 
+    /// Field name, dots separating nested members (`pose.position.x`).
     fn read_identifier(&mut self) -> String {
         let start = self.pos;
         while let Some(ch) = self.peek_char() {
-            if ch.is_alphanumeric() || ch == '_' {
+            if ch.is_alphanumeric() || ch == '_' || ch == '.' {
                 self.next_char();
             } else {
                 break;
@@ -450,6 +451,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_nested_field() {
+        let expr = parse_expression("pose.position.x < 10").unwrap();
+        match expr {
+            Expression::Comparison { left, .. } => {
+                assert_eq!(left, Value::Field("pose.position.x".to_string()));
+            }
+            _ => panic!("Expected comparison"),
+        }
+    }
+
     #[test]
     fn test_parse_float_comparison() {
         let pi = std::f64::consts::PI;
@@ -0,0 +1,363 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Idle-endpoint hibernation for participants with many mostly idle topics.
+//!
+//! With [`ParticipantBuilder::with_endpoint_hibernation`], a sweep thread of
+//! the participant watches the traffic of its writers and readers. An
+//! endpoint without traffic for the configured idle time hibernates:
+//!
+//! - a writer keeping a history (RELIABLE or durable) parks its periodic
+//!   HEARTBEAT thread and releases the unused capacity of its history cache,
+//!   once every matched reliable reader acknowledged its samples;
+//! - a reader releases the unused capacity of its sample cache.
+//!
+//! Discovery state is kept: the endpoint stays announced and matched, and
+//! cached samples stay available to `read()`, `take()` and late joiners.
+//! The next write, the discovery of a reader on a writer's topic, or the
+//! next sample reaching a reader reactivates the endpoint; buffers grow back
+//! on demand.
+//!
+//! [`ParticipantBuilder::with_endpoint_hibernation`]: crate::dds::ParticipantBuilder::with_endpoint_hibernation
+
+use crate::dds::EndpointKind;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, Weak};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Idle times after which endpoints hibernate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HibernationConfig {
+    /// Time without writes after which a writer hibernates.
+    pub writer_idle: Duration,
+    /// Time without received samples after which a reader hibernates.
+    pub reader_idle: Duration,
+    /// Period of the sweep checking endpoint activity.
+    pub check_interval: Duration,
+}
+
+impl HibernationConfig {
+    /// Hibernate writers and readers idle for `idle`, checked every quarter
+    /// of it (between 100 ms and 5 s).
+    pub fn new(idle: Duration) -> Self {
+        Self {
+            writer_idle: idle,
+            reader_idle: idle,
+            check_interval: (idle / 4).clamp(Duration::from_millis(100), Duration::from_secs(5)),
+        }
+    }
+
+    /// Set the writer idle time.
+    pub fn writer_idle(mut self, idle: Duration) -> Self {
+        self.writer_idle = idle;
+        self
+    }
+
+    /// Set the reader idle time.
+    pub fn reader_idle(mut self, idle: Duration) -> Self {
+        self.reader_idle = idle;
+        self
+    }
+
+    /// Set the sweep period.
+    pub fn check_interval(mut self, interval: Duration) -> Self {
+        self.check_interval = interval;
+        self
+    }
+}
+
+impl Default for HibernationConfig {
+    /// Endpoints idle for a minute hibernate.
+    fn default() -> Self {
+        Self::new(Duration::from_secs(60))
+    }
+}
+
+/// Hibernation counters of a participant (see `Participant::hibernation_stats`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HibernationStats {
+    /// Writers watched for hibernation.
+    pub writers: usize,
+    /// Readers watched for hibernation.
+    pub readers: usize,
+    /// Writers currently hibernating.
+    pub hibernating_writers: usize,
+    /// Readers currently hibernating.
+    pub hibernating_readers: usize,
+    /// Hibernations since the participant was built.
+    pub hibernations: u64,
+    /// Reactivations since the participant was built.
+    pub reactivations: u64,
+}
+
+/// Endpoint able to release its buffers while idle.
+pub(crate) trait Hibernate: Send + Sync {
+    /// Counter advancing with the endpoint's traffic.
+    fn activity(&self) -> u64;
+
+    /// Release buffers and park timers; `false` if the endpoint cannot
+    /// hibernate yet.
+    fn hibernate(&self) -> bool;
+
+    /// Undo [`hibernate`](Self::hibernate) after traffic resumed.
+    fn reactivate(&self);
+
+    /// Whether the endpoint is hibernating; an endpoint may reactivate
+    /// itself on traffic before the next sweep notices.
+    fn is_hibernating(&self) -> bool;
+}
+
+struct Watched {
+    kind: EndpointKind,
+    endpoint: Weak<dyn Hibernate>,
+    activity: u64,
+    since: Instant,
+    hibernating: bool,
+}
+
+struct Shared {
+    config: HibernationConfig,
+    endpoints: Mutex<Vec<Watched>>,
+    stop: Mutex<bool>,
+    wake: Condvar,
+    hibernations: AtomicU64,
+    reactivations: AtomicU64,
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+impl Shared {
+    fn idle_time(&self, kind: EndpointKind) -> Duration {
+        match kind {
+            EndpointKind::Writer => self.config.writer_idle,
+            EndpointKind::Reader => self.config.reader_idle,
+        }
+    }
+
+    /// Hibernate idle endpoints and note the ones that resumed.
+    fn sweep(&self, now: Instant) {
+        let mut endpoints = lock(&self.endpoints);
+        endpoints.retain_mut(|watched| {
+            let Some(endpoint) = watched.endpoint.upgrade() else {
+                return false;
+            };
+            let activity = endpoint.activity();
+            let resumed = activity != watched.activity;
+            if watched.hibernating && (resumed || !endpoint.is_hibernating()) {
+                endpoint.reactivate();
+                watched.hibernating = false;
+                watched.since = now;
+                self.reactivations.fetch_add(1, Ordering::Relaxed);
+            } else if resumed {
+                watched.since = now;
+            } else if !watched.hibernating
+                && now.saturating_duration_since(watched.since) >= self.idle_time(watched.kind)
+                && endpoint.hibernate()
+            {
+                watched.hibernating = true;
+                self.hibernations.fetch_add(1, Ordering::Relaxed);
+            }
+            watched.activity = activity;
+            true
+        });
+    }
+}
+
+/// Sweep thread hibernating the idle endpoints of one participant.
+pub(crate) struct Hibernator {
+    shared: Arc<Shared>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Hibernator {
+    pub(crate) fn spawn(config: HibernationConfig) -> Self {
+        let shared = Arc::new(Shared {
+            config,
+            endpoints: Mutex::new(Vec::new()),
+            stop: Mutex::new(false),
+            wake: Condvar::new(),
+            hibernations: AtomicU64::new(0),
+            reactivations: AtomicU64::new(0),
+        });
+        let sweeper = Arc::clone(&shared);
+        let thread = thread::Builder::new()
+            .name("hdds-hibernate".into())
+            .spawn(move || {
+                let mut stop = lock(&sweeper.stop);
+                while !*stop {
+                    stop = sweeper
+                        .wake
+                        .wait_timeout(stop, sweeper.config.check_interval)
+                        .unwrap_or_else(|e| e.into_inner())
+                        .0;
+                    if !*stop {
+                        drop(stop);
+                        sweeper.sweep(Instant::now());
+                        stop = lock(&sweeper.stop);
+                    }
+                }
+            })
+            .map_err(|e| log::warn!("[hibernation] failed to spawn sweep thread: {}", e))
+            .ok();
+        Self { shared, thread }
+    }
+
+    pub(crate) fn config(&self) -> HibernationConfig {
+        self.shared.config
+    }
+
+    /// Watch `endpoint`; it is dropped from the sweep once it is dropped.
+    pub(crate) fn register(&self, kind: EndpointKind, endpoint: &Arc<dyn Hibernate>) {
+        lock(&self.shared.endpoints).push(Watched {
+            kind,
+            endpoint: Arc::downgrade(endpoint),
+            activity: endpoint.activity(),
+            since: Instant::now(),
+            hibernating: false,
+        });
+    }
+
+    pub(crate) fn stats(&self) -> HibernationStats {
+        let mut stats = HibernationStats {
+            hibernations: self.shared.hibernations.load(Ordering::Relaxed),
+            reactivations: self.shared.reactivations.load(Ordering::Relaxed),
+            ..HibernationStats::default()
+        };
+        for watched in lock(&self.shared.endpoints)
+            .iter()
+            .filter(|w| w.endpoint.strong_count() > 0)
+        {
+            let hibernating = watched.hibernating as usize;
+            match watched.kind {
+                EndpointKind::Writer => {
+                    stats.writers += 1;
+                    stats.hibernating_writers += hibernating;
+                }
+                EndpointKind::Reader => {
+                    stats.readers += 1;
+                    stats.hibernating_readers += hibernating;
+                }
+            }
+        }
+        stats
+    }
+
+    #[cfg(test)]
+    fn sweep_at(&self, now: Instant) {
+        self.shared.sweep(now);
+    }
+}
+
+impl Drop for Hibernator {
+    fn drop(&mut self) {
+        *lock(&self.shared.stop) = true;
+        self.shared.wake.notify_all();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicBool;
+
+    #[derive(Default)]
+    struct Endpoint {
+        activity: AtomicU64,
+        hibernating: AtomicBool,
+        refuse: AtomicBool,
+    }
+
+    impl Hibernate for Endpoint {
+        fn activity(&self) -> u64 {
+            self.activity.load(Ordering::Relaxed)
+        }
+
+        fn hibernate(&self) -> bool {
+            if self.refuse.load(Ordering::Relaxed) {
+                return false;
+            }
+            self.hibernating.store(true, Ordering::Relaxed);
+            true
+        }
+
+        fn reactivate(&self) {
+            self.hibernating.store(false, Ordering::Relaxed);
+        }
+
+        fn is_hibernating(&self) -> bool {
+            self.hibernating.load(Ordering::Relaxed)
+        }
+    }
+
+    fn hibernator() -> Hibernator {
+        Hibernator::spawn(
+            HibernationConfig::new(Duration::from_secs(10))
+                .reader_idle(Duration::from_secs(20))
+                .check_interval(Duration::from_secs(3600)),
+        )
+    }
+
+    #[test]
+    fn test_idle_endpoint_hibernates_and_reactivates() {
+        let hibernator = hibernator();
+        let endpoint = Arc::new(Endpoint::default());
+        let dyn_endpoint: Arc<dyn Hibernate> = endpoint.clone();
+        hibernator.register(EndpointKind::Writer, &dyn_endpoint);
+        let start = Instant::now();
+
+        hibernator.sweep_at(start + Duration::from_secs(5));
+        assert!(!endpoint.is_hibernating());
+        hibernator.sweep_at(start + Duration::from_secs(11));
+        assert!(endpoint.is_hibernating());
+        assert_eq!(hibernator.stats().hibernating_writers, 1);
+
+        endpoint.activity.store(1, Ordering::Relaxed);
+        hibernator.sweep_at(start + Duration::from_secs(12));
+        assert!(!endpoint.is_hibernating());
+        let stats = hibernator.stats();
+        assert_eq!((stats.hibernations, stats.reactivations), (1, 1));
+        assert_eq!(stats.hibernating_writers, 0);
+
+        // The idle time restarts from the last traffic
+        hibernator.sweep_at(start + Duration::from_secs(21));
+        assert!(!endpoint.is_hibernating());
+        hibernator.sweep_at(start + Duration::from_secs(23));
+        assert!(endpoint.is_hibernating());
+    }
+
+    #[test]
+    fn test_self_reactivation_and_refusal() {
+        let hibernator = hibernator();
+        let reader = Arc::new(Endpoint::default());
+        let dyn_reader: Arc<dyn Hibernate> = reader.clone();
+        hibernator.register(EndpointKind::Reader, &dyn_reader);
+        let start = Instant::now();
+
+        // Readers use their own idle time
+        hibernator.sweep_at(start + Duration::from_secs(11));
+        assert!(!reader.is_hibernating());
+        hibernator.sweep_at(start + Duration::from_secs(21));
+        assert!(reader.is_hibernating());
+
+        // Woken by traffic before the sweep noticed
+        reader.reactivate();
+        hibernator.sweep_at(start + Duration::from_secs(22));
+        assert_eq!(hibernator.stats().reactivations, 1);
+
+        reader.refuse.store(true, Ordering::Relaxed);
+        hibernator.sweep_at(start + Duration::from_secs(60));
+        assert!(!reader.is_hibernating());
+        assert_eq!(hibernator.stats().hibernating_readers, 0);
+
+        drop(dyn_reader);
+        drop(reader);
+        hibernator.sweep_at(start + Duration::from_secs(61));
+        assert_eq!(hibernator.stats().readers, 0);
+    }
+}
@@ -0,0 +1,557 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! REQUESTED/OFFERED_INCOMPATIBLE_QOS and INCONSISTENT_TOPIC statuses
+//! (DDS v1.4 Sec.2.2.4.1).
+//!
+//! [`IncompatibleQosWatch`] checks the endpoints SEDP discovers on the topic
+//! of a local writer or reader against its type and QoS. An endpoint of the
+//! opposite kind announcing another type (or, with TypeObjects on both
+//! sides, a type not assignable per XTypes), or whose partitions overlap but
+//! whose QoS fails a Request vs Offered policy, is counted once, with the
+//! first failing policy.
+//!
+//! Both cases leave the endpoints unmatched, which users otherwise only see
+//! as missing data, so each is also logged as a warning naming both types
+//! and the first mismatch, or the failing policy with both values. Warnings are limited to one per
+//! [`WARN_INTERVAL`] per local endpoint.
+
+use crate::core::discovery::multicast::{DiscoveryListener, EndpointInfo, EndpointKind};
+use crate::core::discovery::{Matcher, TypeMismatch, GUID};
+use crate::dds::listener::{InconsistentTopicStatus, RequestedIncompatibleQosStatus};
+use crate::dds::qos::{QoS, QosPolicyId};
+use crate::dds::{StatusCondition, StatusMask};
+use crate::xtypes::CompleteTypeObject;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+use std::time::{Duration, Instant};
+
+/// Shortest time between two mismatch warnings of one local endpoint.
+const WARN_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Listener callback: updated status and the policy that failed.
+pub(crate) type IncompatibleQosCallback =
+    Box<dyn Fn(RequestedIncompatibleQosStatus, QosPolicyId) + Send + Sync>;
+
+/// Listener callback: updated INCONSISTENT_TOPIC status.
+pub(crate) type InconsistentTopicCallback = Box<dyn Fn(InconsistentTopicStatus) + Send + Sync>;
+
+/// Rate limit for the warnings of one local endpoint.
+#[derive(Default)]
+struct WarnLimit {
+    last: Option<Instant>,
+    suppressed: u32,
+}
+
+impl WarnLimit {
+    /// Whether a warning may be logged at `now`; if so, the number of
+    /// warnings suppressed since the previous one.
+    fn admit(&mut self, now: Instant) -> Option<u32> {
+        if self
+            .last
+            .is_some_and(|last| now.duration_since(last) < WARN_INTERVAL)
+        {
+            self.suppressed += 1;
+            return None;
+        }
+        self.last = Some(now);
+        Some(std::mem::take(&mut self.suppressed))
+    }
+}
+
+#[derive(Default)]
+struct IncompatibleState {
+    reported: HashSet<GUID>,
+    status: RequestedIncompatibleQosStatus,
+    inconsistent: InconsistentTopicStatus,
+    warn_limit: WarnLimit,
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn kind_name(kind: EndpointKind) -> &'static str {
+    match kind {
+        EndpointKind::Reader => "reader",
+        EndpointKind::Writer => "writer",
+    }
+}
+
+fn suppressed_note(suppressed: u32) -> String {
+    if suppressed == 0 {
+        String::new()
+    } else {
+        format!(" ({} similar warnings suppressed)", suppressed)
+    }
+}
+
+/// Writer and reader values of a failing policy, for warnings.
+fn policy_values(policy: QosPolicyId, reader_qos: &QoS, writer_qos: &QoS) -> String {
+    match policy {
+        QosPolicyId::Reliability => format!(
+            "writer={:?}, reader={:?}",
+            writer_qos.reliability, reader_qos.reliability
+        ),
+        QosPolicyId::Durability => format!(
+            "writer={:?}, reader={:?}",
+            writer_qos.durability, reader_qos.durability
+        ),
+        QosPolicyId::Deadline => format!(
+            "writer={:?}, reader={:?}",
+            writer_qos.deadline.period, reader_qos.deadline.period
+        ),
+        QosPolicyId::Ownership => format!(
+            "writer={:?}, reader={:?}",
+            writer_qos.ownership.kind, reader_qos.ownership.kind
+        ),
+        QosPolicyId::Liveliness => format!(
+            "writer={:?}/{:?}, reader={:?}/{:?}",
+            writer_qos.liveliness.kind,
+            writer_qos.liveliness.lease_duration,
+            reader_qos.liveliness.kind,
+            reader_qos.liveliness.lease_duration
+        ),
+        _ => "see QoS".to_string(),
+    }
+}
+
+/// Incompatible endpoints seen by one local writer or reader.
+pub(crate) struct IncompatibleQosWatch {
+    topic: String,
+    /// Kind of the local endpoint; remote endpoints of the other kind are checked.
+    kind: EndpointKind,
+    qos: QoS,
+    /// Local type and its side-by-side versions; empty skips the type check.
+    types: Vec<(String, Option<CompleteTypeObject>)>,
+    on_incompatible: Option<IncompatibleQosCallback>,
+    on_inconsistent: Option<InconsistentTopicCallback>,
+    /// Readers only; writers have no status condition
+    status_condition: Option<Arc<StatusCondition>>,
+    state: Mutex<IncompatibleState>,
+}
+
+impl IncompatibleQosWatch {
+    pub(crate) fn new(
+        topic: String,
+        kind: EndpointKind,
+        qos: QoS,
+        on_incompatible: Option<IncompatibleQosCallback>,
+        status_condition: Option<Arc<StatusCondition>>,
+    ) -> Self {
+        Self {
+            topic,
+            kind,
+            qos,
+            types: Vec::new(),
+            on_incompatible,
+            on_inconsistent: None,
+            status_condition,
+            state: Mutex::new(IncompatibleState::default()),
+        }
+    }
+
+    /// Also report endpoints whose type matches none of `types` (the local
+    /// type first, then its side-by-side versions).
+    pub(crate) fn with_types(
+        mut self,
+        types: Vec<(String, Option<CompleteTypeObject>)>,
+        on_inconsistent: Option<InconsistentTopicCallback>,
+    ) -> Self {
+        self.types = types;
+        self.on_inconsistent = on_inconsistent;
+        self
+    }
+
+    fn mask(&self) -> StatusMask {
+        match self.kind {
+            EndpointKind::Reader => StatusMask::REQUESTED_INCOMPATIBLE_QOS,
+            EndpointKind::Writer => StatusMask::OFFERED_INCOMPATIBLE_QOS,
+        }
+    }
+
+    fn local_type_name(&self) -> &str {
+        self.types.first().map_or("?", |(name, _)| name.as_str())
+    }
+
+    /// Current status; resets the change counter.
+    pub(crate) fn status(&self) -> RequestedIncompatibleQosStatus {
+        let mut state = lock(&self.state);
+        let status = state.status.clone();
+        state.status.total_count_change = 0;
+        if let Some(ref condition) = self.status_condition {
+            condition.clear_active_status(self.mask());
+        }
+        status
+    }
+
+    /// Current INCONSISTENT_TOPIC status; resets the change counter.
+    pub(crate) fn inconsistent_status(&self) -> InconsistentTopicStatus {
+        let mut state = lock(&self.state);
+        let status = state.inconsistent.clone();
+        state.inconsistent.total_count_change = 0;
+        if let Some(ref condition) = self.status_condition {
+            condition.clear_active_status(StatusMask::INCONSISTENT_TOPIC);
+        }
+        status
+    }
+
+    fn raise(&self, mask: StatusMask) {
+        if let Some(ref condition) = self.status_condition {
+            condition.set_active_statuses(condition.get_active_statuses() | mask);
+        }
+    }
+
+    /// Check a discovered endpoint against the local type and QoS.
+    pub(crate) fn check(&self, endpoint: &EndpointInfo) {
+        if endpoint.kind == self.kind || endpoint.topic_name != self.topic {
+            return;
+        }
+        if !self.types.is_empty()
+            && !self
+                .types
+                .iter()
+                .any(|(name, object)| endpoint.matches_type(object.as_ref(), name))
+        {
+            let (name, object) = &self.types[0];
+            let mismatch = endpoint.type_mismatch(object.as_ref(), name);
+            self.report_inconsistent(endpoint, mismatch);
+            return;
+        }
+
+        let (reader_qos, writer_qos) = match self.kind {
+            EndpointKind::Reader => (&self.qos, &endpoint.qos),
+            EndpointKind::Writer => (&endpoint.qos, &self.qos),
+        };
+        // Endpoints in disjoint partitions never match, compatible or not
+        if !writer_qos
+            .partition
+            .is_compatible_with(&reader_qos.partition)
+        {
+            return;
+        }
+        let Some(policy) = Matcher::incompatible_policy(reader_qos, writer_qos) else {
+            return;
+        };
+
+        let changed = {
+            let mut state = lock(&self.state);
+            if !state.reported.insert(endpoint.endpoint_guid) {
+                return;
+            }
+            state.status.total_count += 1;
+            state.status.total_count_change += 1;
+            state.status.last_policy_id = policy.id();
+            if let Some(suppressed) = state.warn_limit.admit(Instant::now()) {
+                log::warn!(
+                    "{} on '{}' (type '{}') cannot match {} {}: incompatible {} QoS ({}){}",
+                    kind_name(self.kind),
+                    self.topic,
+                    self.local_type_name(),
+                    kind_name(endpoint.kind),
+                    endpoint.endpoint_guid,
+                    policy,
+                    policy_values(policy, reader_qos, writer_qos),
+                    suppressed_note(suppressed)
+                );
+            }
+
+            // The listener consumes the changes, like a status read
+            self.on_incompatible.is_some().then(|| {
+                let status = state.status.clone();
+                state.status.total_count_change = 0;
+                status
+            })
+        };
+
+        self.raise(self.mask());
+        if let (Some(callback), Some(status)) = (&self.on_incompatible, changed) {
+            callback(status, policy);
+        }
+    }
+
+    fn report_inconsistent(&self, endpoint: &EndpointInfo, mismatch: Option<TypeMismatch>) {
+        let reason =
+            mismatch.map_or_else(|| "no type version matches".to_string(), |m| m.to_string());
+        let changed = {
+            let mut state = lock(&self.state);
+            if !state.reported.insert(endpoint.endpoint_guid) {
+                return;
+            }
+            state.inconsistent.total_count += 1;
+            state.inconsistent.total_count_change += 1;
+            state
+                .inconsistent
+                .last_type_name
+                .clone_from(&endpoint.type_name);
+            state.inconsistent.last_reason.clone_from(&reason);
+            if let Some(suppressed) = state.warn_limit.admit(Instant::now()) {
+                log::warn!(
+                    "{} on '{}' cannot match {} {}: type '{}' is inconsistent with local type '{}' ({}){}",
+                    kind_name(self.kind),
+                    self.topic,
+                    kind_name(endpoint.kind),
+                    endpoint.endpoint_guid,
+                    endpoint.type_name,
+                    self.local_type_name(),
+                    reason,
+                    suppressed_note(suppressed)
+                );
+            }
+
+            self.on_inconsistent.is_some().then(|| {
+                let status = state.inconsistent.clone();
+                state.inconsistent.total_count_change = 0;
+                status
+            })
+        };
+
+        self.raise(StatusMask::INCONSISTENT_TOPIC);
+        if let (Some(callback), Some(status)) = (&self.on_inconsistent, changed) {
+            callback(status);
+        }
+    }
+}
+
+/// Feeds SEDP discoveries to an [`IncompatibleQosWatch`].
+pub(crate) struct IncompatibleQosListener {
+    watch: Weak<IncompatibleQosWatch>,
+}
+
+impl IncompatibleQosListener {
+    pub(crate) fn new(watch: &Arc<IncompatibleQosWatch>) -> Self {
+        Self {
+            watch: Arc::downgrade(watch),
+        }
+    }
+}
+
+impl DiscoveryListener for IncompatibleQosListener {
+    fn on_endpoint_discovered(&self, endpoint: EndpointInfo) {
+        if let Some(watch) = self.watch.upgrade() {
+            watch.check(&endpoint);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dds::qos::{Partition, Reliability};
+
+    fn endpoint(n: u8, kind: EndpointKind, qos: QoS) -> EndpointInfo {
+        EndpointInfo {
+            endpoint_guid: GUID::new([n; 12], [0, 0, 1, 0x02]),
+            participant_guid: GUID::new([n; 12], [0, 0, 1, 0xc1]),
+            topic_name: "incompatible/topic".to_string(),
+            type_name: "Temperature".to_string(),
+            qos,
+            kind,
+            type_object: None,
+            type_versions: None,
+            unicast_locators: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_reader_counts_each_incompatible_writer_once() {
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&reported);
+        let watch = IncompatibleQosWatch::new(
+            "incompatible/topic".to_string(),
+            EndpointKind::Reader,
+            QoS::reliable(),
+            Some(Box::new(move |status, policy| {
+                lock(&sink).push((status.total_count, policy));
+            })),
+            None,
+        );
+
+        let best_effort = endpoint(1, EndpointKind::Writer, QoS::best_effort());
+        watch.check(&best_effort);
+        watch.check(&best_effort);
+        // Compatible writers, other readers and other partitions are ignored
+        watch.check(&endpoint(2, EndpointKind::Writer, QoS::reliable()));
+        watch.check(&endpoint(3, EndpointKind::Reader, QoS::best_effort()));
+        watch.check(&endpoint(
+            4,
+            EndpointKind::Writer,
+            QoS::best_effort().partition(Partition::single("elsewhere")),
+        ));
+
+        assert_eq!(*lock(&reported), vec![(1, QosPolicyId::Reliability)]);
+        let status = watch.status();
+        assert_eq!(status.total_count, 1);
+        assert_eq!(status.total_count_change, 0);
+        assert_eq!(status.last_policy_id, QosPolicyId::Reliability.id());
+    }
+
+    #[test]
+    fn test_writer_checks_discovered_readers() {
+        let status_condition = Arc::new(StatusCondition::new());
+        let watch = IncompatibleQosWatch::new(
+            "incompatible/topic".to_string(),
+            EndpointKind::Writer,
+            QoS::best_effort(),
+            None,
+            Some(Arc::clone(&status_condition)),
+        );
+        let mut reader_qos = QoS::best_effort();
+        reader_qos.reliability = Reliability::Reliable;
+        watch.check(&endpoint(5, EndpointKind::Reader, reader_qos));
+
+        assert!(status_condition
+            .get_active_statuses()
+            .contains(StatusMask::OFFERED_INCOMPATIBLE_QOS));
+        let status = watch.status();
+        assert_eq!(status.total_count, 1);
+        assert_eq!(status.total_count_change, 1);
+        assert!(!status_condition
+            .get_active_statuses()
+            .contains(StatusMask::OFFERED_INCOMPATIBLE_QOS));
+    }
+
+    #[test]
+    fn test_inconsistent_type_is_reported_instead_of_qos() {
+        let status_condition = Arc::new(StatusCondition::new());
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&reported);
+        let watch = IncompatibleQosWatch::new(
+            "incompatible/topic".to_string(),
+            EndpointKind::Reader,
+            QoS::reliable(),
+            None,
+            Some(Arc::clone(&status_condition)),
+        )
+        .with_types(
+            vec![("Humidity".to_string(), None)],
+            Some(Box::new(move |status: InconsistentTopicStatus| {
+                lock(&sink).push(status.last_type_name);
+            })),
+        );
+
+        // Wrong type and wrong QoS: the type is what keeps them apart
+        let writer = endpoint(1, EndpointKind::Writer, QoS::best_effort());
+        watch.check(&writer);
+        watch.check(&writer);
+        let mut same_type = endpoint(2, EndpointKind::Writer, QoS::reliable());
+        same_type.type_name = "Humidity".to_string();
+        watch.check(&same_type);
+
+        assert_eq!(*lock(&reported), vec!["Temperature".to_string()]);
+        assert!(status_condition
+            .get_active_statuses()
+            .contains(StatusMask::INCONSISTENT_TOPIC));
+        let status = watch.inconsistent_status();
+        assert_eq!(status.total_count, 1);
+        assert_eq!(status.total_count_change, 0);
+        assert!(!status_condition
+            .get_active_statuses()
+            .contains(StatusMask::INCONSISTENT_TOPIC));
+        assert_eq!(watch.status().total_count, 0);
+    }
+
+    #[test]
+    fn test_side_by_side_versions_are_consistent() {
+        let watch = IncompatibleQosWatch::new(
+            "incompatible/topic".to_string(),
+            EndpointKind::Writer,
+            QoS::reliable(),
+            None,
+            None,
+        )
+        .with_types(
+            vec![
+                ("TemperatureV2".to_string(), None),
+                ("Temperature".to_string(), None),
+            ],
+            None,
+        );
+        watch.check(&endpoint(1, EndpointKind::Reader, QoS::reliable()));
+        assert_eq!(watch.inconsistent_status().total_count, 0);
+    }
+
+    fn appendable(members: &[(&str, crate::xtypes::TypeIdentifier)]) -> CompleteTypeObject {
+        use crate::xtypes::{
+            CommonStructMember, CompleteMemberDetail, CompleteStructHeader, CompleteStructMember,
+            CompleteStructType, CompleteTypeDetail, MemberFlag, StructTypeFlag,
+        };
+        CompleteTypeObject::Struct(CompleteStructType {
+            struct_flags: StructTypeFlag::IS_APPENDABLE,
+            header: CompleteStructHeader {
+                base_type: None,
+                detail: CompleteTypeDetail::new("Temperature"),
+            },
+            member_seq: members
+                .iter()
+                .zip(0_u32..)
+                .map(|((name, type_id), member_id)| CompleteStructMember {
+                    common: CommonStructMember {
+                        member_id,
+                        member_flags: MemberFlag::empty(),
+                        member_type_id: type_id.clone(),
+                    },
+                    detail: CompleteMemberDetail::new(*name),
+                })
+                .collect(),
+        })
+    }
+
+    #[test]
+    fn test_appended_writer_members_are_consistent() {
+        use crate::xtypes::TypeIdentifier;
+
+        let local = appendable(&[("celsius", TypeIdentifier::TK_FLOAT32)]);
+        let watch = IncompatibleQosWatch::new(
+            "incompatible/topic".to_string(),
+            EndpointKind::Reader,
+            QoS::reliable(),
+            None,
+            None,
+        )
+        .with_types(vec![("Temperature".to_string(), Some(local))], None);
+
+        // Upgraded publisher: one more trailing member
+        let mut upgraded = endpoint(1, EndpointKind::Writer, QoS::reliable());
+        upgraded.type_object = Some(appendable(&[
+            ("celsius", TypeIdentifier::TK_FLOAT32),
+            ("sensor_id", TypeIdentifier::TK_UINT32),
+        ]));
+        watch.check(&upgraded);
+        assert_eq!(watch.inconsistent_status().total_count, 0);
+
+        // Changed member type: reported with the member
+        let mut changed = endpoint(2, EndpointKind::Writer, QoS::reliable());
+        changed.type_object = Some(appendable(&[("celsius", TypeIdentifier::TK_FLOAT64)]));
+        watch.check(&changed);
+        let status = watch.inconsistent_status();
+        assert_eq!(status.total_count, 1);
+        assert_eq!(
+            status.last_reason,
+            "member 'celsius' (id 0) has another type"
+        );
+    }
+
+    #[test]
+    fn test_warnings_are_rate_limited() {
+        let mut limit = WarnLimit::default();
+        let start = Instant::now();
+        assert_eq!(limit.admit(start), Some(0));
+        assert_eq!(limit.admit(start + Duration::from_secs(1)), None);
+        assert_eq!(limit.admit(start + Duration::from_secs(2)), None);
+        assert_eq!(limit.admit(start + WARN_INTERVAL), Some(2));
+        assert_eq!(limit.admit(start + WARN_INTERVAL), None);
+    }
+
+    #[test]
+    fn test_policy_values_name_both_sides() {
+        assert_eq!(
+            policy_values(
+                QosPolicyId::Reliability,
+                &QoS::reliable(),
+                &QoS::best_effort()
+            ),
+            "writer=BestEffort, reader=Reliable"
+        );
+    }
+}
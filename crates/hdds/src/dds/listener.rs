@@ -35,8 +35,11 @@
 //!
 //! See DDS v1.4 Section 2.2.4 - Listeners, Conditions, and Wait-sets.
 
+use crate::core::discovery::multicast::{DiscoveryListener, EndpointInfo, EndpointKind};
 use crate::core::discovery::GUID;
 use crate::dds::DDS;
+use std::sync::Arc;
+use std::time::Duration;
 
 /// Status information for subscription matching events.
 #[derive(Debug, Clone, Default)]
@@ -138,6 +141,23 @@ pub struct RequestedDeadlineMissedStatus {
     pub last_instance_handle: Option<u64>,
 }
 
+/// Status information for a staleness watchdog (HDDS extension).
+///
+/// See `ReaderBuilder::with_staleness_watchdog`.
+#[derive(Debug, Clone, Default)]
+pub struct SampleStalenessStatus {
+    /// Window without samples after which the reader is stale.
+    pub window: Duration,
+    /// Whether no sample arrived within the window.
+    pub stale: bool,
+    /// Total cumulative count of times the reader went stale.
+    pub total_count: u32,
+    /// Change in total_count since last callback.
+    pub total_count_change: i32,
+    /// Time since the last sample (`None` if none arrived yet).
+    pub last_sample_age: Option<Duration>,
+}
+
 /// Status information for incompatible QoS events.
 #[derive(Debug, Clone, Default)]
 pub struct RequestedIncompatibleQosStatus {
@@ -149,6 +169,25 @@ pub struct RequestedIncompatibleQosStatus {
     pub last_policy_id: u32,
 }
 
+/// Status information for readers a writer cannot match (same fields).
+pub type OfferedIncompatibleQosStatus = RequestedIncompatibleQosStatus;
+
+/// Status information for endpoints on the same topic with another data type.
+///
+/// Such endpoints never match: no data flows between them.
+#[derive(Debug, Clone, Default)]
+pub struct InconsistentTopicStatus {
+    /// Total cumulative count of endpoints with an inconsistent type.
+    pub total_count: u32,
+    /// Change in total_count since last callback.
+    pub total_count_change: i32,
+    /// Type name announced by the last inconsistent endpoint.
+    pub last_type_name: String,
+    /// Why the last inconsistent endpoint's type does not match, e.g. a
+    /// member whose type changed.
+    pub last_reason: String,
+}
+
 /// Listener for DataReader events.
 ///
 /// Implement this trait to receive callbacks when events occur on a DataReader.
@@ -247,6 +286,24 @@ pub trait DataReaderListener<T: DDS>: Send + Sync {
     fn on_requested_incompatible_qos(&self, status: RequestedIncompatibleQosStatus) {
         let _ = status;
     }
+
+    /// Called when a writer on the topic announces an incompatible type.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - Inconsistent topic status
+    fn on_inconsistent_topic(&self, status: InconsistentTopicStatus) {
+        let _ = status;
+    }
+
+    /// Called when a staleness watchdog goes stale or samples resume.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - Status of the watchdog that changed
+    fn on_sample_staleness_changed(&self, status: SampleStalenessStatus) {
+        let _ = status;
+    }
 }
 
 /// Listener for DataWriter events.
@@ -304,12 +361,81 @@ pub trait DataWriterListener<T: DDS>: Send + Sync {
         let _ = (policy_id, policy_name);
     }
 
+    /// Called when a reader on the topic announces an incompatible type.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - Inconsistent topic status
+    fn on_inconsistent_topic(&self, status: InconsistentTopicStatus) {
+        let _ = status;
+    }
+
     /// Called when liveliness is lost (MANUAL_BY_* only).
     /// Default: no-op (user overrides if needed)
     fn on_liveliness_lost(&self) { /* @audit-ok: intentional no-op default */
     }
 }
 
+/// Listener for DomainParticipant discovery events.
+///
+/// Register it with [`ParticipantBuilder::with_listener`](crate::dds::ParticipantBuilder::with_listener).
+/// Remote participants and endpoints are reported once, when first
+/// discovered; a participant is lost when its SPDP lease expires.
+/// All methods have default no-op implementations.
+///
+/// # Example
+///
+/// ```ignore
+/// struct GraphLogger;
+///
+/// impl ParticipantListener for GraphLogger {
+///     fn on_participant_discovered(&self, participant: GUID) {
+///         println!("joined: {}", participant);
+///     }
+///
+///     fn on_publication_discovered(&self, publication: &EndpointInfo) {
+///         println!("writer on '{}'", publication.topic_name);
+///     }
+/// }
+/// ```
+pub trait ParticipantListener: Send + Sync {
+    /// Called when a remote participant is discovered.
+    ///
+    /// # Arguments
+    ///
+    /// * `participant` - GUID of the remote participant
+    fn on_participant_discovered(&self, participant: GUID) {
+        let _ = participant;
+    }
+
+    /// Called when a remote participant's lease expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `participant` - GUID of the lost participant
+    fn on_participant_lost(&self, participant: GUID) {
+        let _ = participant;
+    }
+
+    /// Called when a remote DataWriter is discovered through SEDP.
+    ///
+    /// # Arguments
+    ///
+    /// * `publication` - Topic, type and QoS of the writer
+    fn on_publication_discovered(&self, publication: &EndpointInfo) {
+        let _ = publication;
+    }
+
+    /// Called when a remote DataReader is discovered through SEDP.
+    ///
+    /// # Arguments
+    ///
+    /// * `subscription` - Topic, type and QoS of the reader
+    fn on_subscription_discovered(&self, subscription: &EndpointInfo) {
+        let _ = subscription;
+    }
+}
+
 /// Closure-based listener for simple data callbacks.
 ///
 /// Use this when you only need `on_data_available` and want a simple closure.
@@ -347,6 +473,42 @@ impl<T: DDS, F: Fn(&T) + Send + Sync> DataReaderListener<T> for ClosureListener<
     }
 }
 
+/// Forwards discovery FSM events to a [`ParticipantListener`].
+pub(crate) struct ParticipantDiscoveryListener {
+    listener: Arc<dyn ParticipantListener>,
+    /// GUID prefix of the local participant, whose own endpoints are skipped.
+    local_prefix: [u8; 12],
+}
+
+impl ParticipantDiscoveryListener {
+    pub(crate) fn new(listener: Arc<dyn ParticipantListener>, local: GUID) -> Self {
+        Self {
+            listener,
+            local_prefix: local.prefix,
+        }
+    }
+}
+
+impl DiscoveryListener for ParticipantDiscoveryListener {
+    fn on_endpoint_discovered(&self, endpoint: EndpointInfo) {
+        if endpoint.endpoint_guid.prefix == self.local_prefix {
+            return;
+        }
+        match endpoint.kind {
+            EndpointKind::Writer => self.listener.on_publication_discovered(&endpoint),
+            EndpointKind::Reader => self.listener.on_subscription_discovered(&endpoint),
+        }
+    }
+
+    fn on_participant_discovered(&self, participant: GUID) {
+        self.listener.on_participant_discovered(participant);
+    }
+
+    fn on_participant_lost(&self, participant: GUID) {
+        self.listener.on_participant_lost(participant);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -434,6 +596,7 @@ mod tests {
 
     impl DataReaderListener<TestData> for NoOpListener {}
     impl DataWriterListener<TestData> for NoOpListener {}
+    impl ParticipantListener for NoOpListener {}
 
     #[test]
     fn test_noop_reader_listener() {
@@ -448,6 +611,10 @@ mod tests {
         listener.on_sample_rejected(SampleRejectedStatus::default());
         listener.on_requested_deadline_missed(RequestedDeadlineMissedStatus::default());
         listener.on_requested_incompatible_qos(RequestedIncompatibleQosStatus::default());
+        DataReaderListener::<TestData>::on_inconsistent_topic(
+            &listener,
+            InconsistentTopicStatus::default(),
+        );
     }
 
     #[test]
@@ -460,6 +627,17 @@ mod tests {
         listener.on_publication_matched(PublicationMatchedStatus::default());
         listener.on_offered_deadline_missed(None);
         listener.on_offered_incompatible_qos(0, "RELIABILITY");
+        DataWriterListener::<TestData>::on_inconsistent_topic(
+            &listener,
+            InconsistentTopicStatus::default(),
+        );
         listener.on_liveliness_lost();
     }
+
+    #[test]
+    fn test_noop_participant_listener() {
+        let listener = NoOpListener;
+        listener.on_participant_discovered(GUID::zero());
+        listener.on_participant_lost(GUID::zero());
+    }
 }
@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! LIVELINESS QoS runtime state (DDS v1.4 Sec.2.2.3.11).
+//!
+//! Each writer owns a [`WriterLiveliness`] recording when it last asserted
+//! liveliness. Writes and `DataWriter::assert_liveliness()` assert it; for
+//! AUTOMATIC writers with a finite lease the writer's liveliness thread does
+//! it every third of the lease. The record is shared with the domain
+//! registry so readers in the same process check it directly, and remote
+//! readers learn about assertions from HEARTBEATs with the LivelinessFlag.
+
+use super::qos::{Liveliness, LivelinessKind};
+use crate::core::rt::clock::Clock;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Lease duration in nanoseconds, or `None` for an infinite lease.
+pub(crate) fn lease_ns(policy: &Liveliness) -> Option<u64> {
+    (!policy.is_infinite())
+        .then(|| u64::try_from(policy.lease_duration.as_nanos()).unwrap_or(u64::MAX))
+}
+
+/// Liveliness assertions of one writer.
+#[derive(Debug)]
+pub(crate) struct WriterLiveliness {
+    kind: LivelinessKind,
+    lease_ns: Option<u64>,
+    /// Clock of the writer's participant.
+    clock: Arc<dyn Clock>,
+    /// `clock.now_ns()` of the last assertion.
+    last_asserted_ns: AtomicU64,
+    /// Asserted since the liveliness thread last announced it.
+    pending: AtomicBool,
+}
+
+impl WriterLiveliness {
+    /// Writer on the system clock.
+    #[cfg(test)]
+    pub(crate) fn new(policy: &Liveliness) -> Self {
+        Self::with_clock(policy, crate::core::rt::clock::SystemClock::shared())
+    }
+
+    /// Writer created now on `clock`, which counts as its first assertion.
+    pub(crate) fn with_clock(policy: &Liveliness, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            kind: policy.kind,
+            lease_ns: lease_ns(policy),
+            last_asserted_ns: AtomicU64::new(clock.now_ns()),
+            clock,
+            pending: AtomicBool::new(false),
+        }
+    }
+
+    pub(crate) fn kind(&self) -> LivelinessKind {
+        self.kind
+    }
+
+    /// Lease duration, or `None` when infinite.
+    pub(crate) fn lease(&self) -> Option<Duration> {
+        self.lease_ns.map(Duration::from_nanos)
+    }
+
+    /// Record an assertion.
+    pub(crate) fn assert(&self) {
+        self.last_asserted_ns
+            .store(self.clock.now_ns(), Ordering::Release);
+        self.pending.store(true, Ordering::Release);
+    }
+
+    pub(crate) fn last_asserted_ns(&self) -> u64 {
+        self.last_asserted_ns.load(Ordering::Acquire)
+    }
+
+    /// Whether the writer asserted since the previous call.
+    pub(crate) fn take_pending(&self) -> bool {
+        self.pending.swap(false, Ordering::AcqRel)
+    }
+
+    /// Whether the lease has not expired now.
+    pub(crate) fn is_alive(&self) -> bool {
+        self.is_alive_at(self.clock.now_ns())
+    }
+
+    /// Whether the lease has not expired at `now_ns`.
+    pub(crate) fn is_alive_at(&self, now_ns: u64) -> bool {
+        self.lease_ns
+            .is_none_or(|lease| now_ns.saturating_sub(self.last_asserted_ns()) <= lease)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_writer_liveliness_lease() {
+        let writer =
+            WriterLiveliness::new(&Liveliness::manual_by_topic(Duration::from_millis(100)));
+        let asserted = writer.last_asserted_ns();
+        assert!(writer.is_alive_at(asserted + 100_000_000));
+        assert!(!writer.is_alive_at(asserted + 100_000_001));
+
+        assert!(!writer.take_pending());
+        writer.assert();
+        assert!(writer.take_pending());
+        assert!(!writer.take_pending());
+        assert!(writer.last_asserted_ns() >= asserted);
+    }
+
+    #[test]
+    fn test_infinite_lease_never_expires() {
+        let writer = WriterLiveliness::new(&Liveliness::infinite());
+        assert_eq!(writer.lease(), None);
+        assert!(writer.is_alive_at(u64::MAX));
+    }
+
+    #[test]
+    fn test_lease_follows_virtual_clock() {
+        let clock = Arc::new(crate::core::rt::clock::VirtualClock::new());
+        let writer = WriterLiveliness::with_clock(
+            &Liveliness::manual_by_participant(Duration::from_secs(2)),
+            clock.clone(),
+        );
+        clock.advance(Duration::from_secs(2));
+        assert!(writer.is_alive());
+        clock.advance(Duration::from_millis(1));
+        assert!(!writer.is_alive());
+
+        writer.assert();
+        assert!(writer.is_alive());
+        assert_eq!(writer.last_asserted_ns(), 2_001_000_000);
+    }
+}
@@ -59,13 +59,19 @@
 //! - [`DDS`] - Trait for serializable types
 //! - [DDS Specification](https://www.omg.org/spec/DDS/1.4/)
 
+mod coherent;
 mod condition;
 mod content_filtered_topic;
 mod domain_registry;
+mod endpoint_hook;
+mod error_context;
 /// Content filter expression parser and evaluator.
 pub mod filter;
+mod hibernation;
+mod incompatible_qos;
 /// Listener traits for callback-based notifications.
 pub mod listener;
+mod liveliness;
 mod participant;
 /// Prelude module for convenient imports.
 pub mod prelude;
@@ -74,40 +80,72 @@ mod publisher;
 pub mod qos;
 mod read_condition;
 mod reader;
+#[cfg(feature = "serde")]
+mod serde_sample;
+mod static_deployment;
 mod subscriber;
 mod topic;
+mod type_versions;
 mod waitset;
+#[cfg(feature = "wasmtime")]
+mod wasm;
 mod writer;
 
 pub use condition::{Condition, GuardCondition, HasStatusCondition, StatusCondition, StatusMask};
 pub use content_filtered_topic::ContentFilteredTopic;
+pub(crate) use endpoint_hook::run_endpoint_hooks;
+pub use endpoint_hook::{register_endpoint_hook, EndpointCreation, EndpointHook};
+pub use error_context::ErrorContext;
 pub use filter::{ContentFilter, FieldValue, FilterError, FilterEvaluator};
+pub(crate) use hibernation::{Hibernate, Hibernator};
+pub use hibernation::{HibernationConfig, HibernationStats};
 pub use participant::{
-    DiscoveredTopicInfo, Participant, ParticipantBuilder, RawDataReader, RawDataWriter, RawSample,
+    global_participant, global_participant_with, DiscoveredTopicInfo, DiscoveryEvent,
+    DiscoveryEvents, Participant, ParticipantBuilder, RawDataReader, RawDataWriter, RawSample,
     TransportMode,
 };
+#[cfg(feature = "dynamic")]
+pub use participant::{DynamicDataReader, DynamicDataWriter};
 pub use publisher::Publisher;
 pub use qos::{
-    Deadline, DestinationOrder, DestinationOrderKind, Durability, DurabilityService, EntityFactory,
-    GroupData, History, LatencyBudget, Lifespan, Liveliness, LivelinessKind, Ownership,
-    OwnershipKind, OwnershipStrength, Partition, Presentation, PresentationAccessScope, QoS,
-    ReaderDataLifecycle, Reliability, TimeBasedFilter, TopicData, TransportPriority, UserData,
-    WriterDataLifecycle,
+    Batching, Deadline, DestinationOrder, DestinationOrderKind, Durability, DurabilityService,
+    EntityFactory, GroupData, History, LatencyBudget, Lifespan, Liveliness, LivelinessKind,
+    Ownership, OwnershipKind, OwnershipStrength, Partition, Presentation, PresentationAccessScope,
+    QoS, QosPolicyId, ReaderDataLifecycle, Reliability, TimeBasedFilter, TopicData,
+    TransportPriority, UserData, WriterDataLifecycle,
 };
 pub use read_condition::{
-    InstanceStateMask, QueryCondition, ReadCondition, SampleStateMask, ViewStateMask,
+    InstanceStateMask, QueryCondition, ReadCondition, SampleCondition, SampleStateMask,
+    ViewStateMask,
 };
-pub use reader::DataReader;
+#[cfg(target_os = "linux")]
+pub use reader::LoanedSample;
+#[cfg(feature = "async")]
+pub use reader::SampleStream;
+pub use reader::{
+    DataReader, InstanceHandle, InstanceState, SampleInfo, SampleIter, SampleRateMetrics,
+    SampleState, ViewState,
+};
+#[cfg(feature = "serde")]
+pub use serde_sample::Serde;
+pub(crate) use static_deployment::StaticPlan;
+pub use static_deployment::{StaticDeployment, StaticTopic, DEFAULT_STATIC_DISCOVERY_TIMEOUT};
 pub use subscriber::Subscriber;
 pub use topic::Topic;
 pub use waitset::WaitSet;
+#[cfg(feature = "wasmtime")]
+pub use wasm::{WasmLimits, WasmPlugin};
 pub use writer::DataWriter;
+#[cfg(target_os = "linux")]
+pub use writer::WriteLoan;
 
 // Listener traits and status types
 pub use listener::{
-    ClosureListener, DataReaderListener, DataWriterListener, LivelinessChangedStatus,
+    ClosureListener, DataReaderListener, DataWriterListener, InconsistentTopicStatus,
+    LivelinessChangedStatus, OfferedIncompatibleQosStatus, ParticipantListener,
     PublicationMatchedStatus, RequestedDeadlineMissedStatus, RequestedIncompatibleQosStatus,
-    SampleLostStatus, SampleRejectedReason, SampleRejectedStatus, SubscriptionMatchedStatus,
+    SampleLostStatus, SampleRejectedReason, SampleRejectedStatus, SampleStalenessStatus,
+    SubscriptionMatchedStatus,
 };
 
 // Intra-process auto-binding
@@ -138,8 +176,19 @@ pub enum Error {
     // ========================================================================
     // Configuration Errors
     // ========================================================================
-    /// Generic configuration error (prefer specific variants below).
+    /// Generic configuration error, without details.
+    #[deprecated(
+        since = "1.0.11",
+        note = "configuration errors are reported as `Error::InvalidConfig`, with a reason and context"
+    )]
     Config,
+    /// Invalid configuration (malformed document, missing field, bad value).
+    InvalidConfig {
+        /// What is wrong.
+        reason: String,
+        /// Entity, topic, policy or file involved.
+        context: Box<ErrorContext>,
+    },
     /// QoS policy is invalid (e.g., negative depth, conflicting policies).
     InvalidQos(String),
     /// Configuration file not found at specified path.
@@ -160,6 +209,8 @@ pub enum Error {
     RegistrationFailed,
     /// Invalid state for the requested operation.
     InvalidState(String),
+    /// Writer or reader creation rejected by an endpoint hook.
+    EndpointRejected(String),
 
     // ========================================================================
     // Transport Errors
@@ -208,11 +259,33 @@ pub enum Error {
     // ========================================================================
     // Other Errors
     // ========================================================================
-    /// Requested feature or operation is not supported.
+    /// Requested feature or operation is not supported, without details.
+    #[deprecated(
+        since = "1.0.11",
+        note = "unsupported operations are reported as `Error::UnsupportedOperation`, naming the operation"
+    )]
     Unsupported,
+    /// Requested feature or operation is not supported.
+    UnsupportedOperation {
+        /// Operation that was refused.
+        operation: String,
+        /// Entity, topic, policy or file involved.
+        context: Box<ErrorContext>,
+    },
+    /// Error raised while operating on an entity, topic, policy or file
+    /// (see [`Error::with_context`]); the original is the `source()`.
+    Context {
+        /// What the failed operation involved.
+        context: Box<ErrorContext>,
+        /// Original error.
+        source: Box<Error>,
+    },
     /// Permission denied by access control (DDS Security).
     #[cfg(feature = "security")]
     PermissionDenied(String),
+    /// WASM plugin failed to load (compilation, sandbox limits, module ABI).
+    #[cfg(feature = "wasmtime")]
+    WasmPlugin(String),
 }
 
 impl std::fmt::Display for Error {
@@ -220,7 +293,12 @@ impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             // Configuration
+            #[allow(deprecated)]
             Error::Config => write!(f, "Configuration error"),
+            Error::InvalidConfig { reason, context } => {
+                write!(f, "Configuration error: {}", reason)?;
+                write_context(f, context)
+            }
             Error::InvalidQos(msg) => write!(f, "Invalid QoS: {}", msg),
             Error::ConfigFileNotFound(path) => write!(f, "Config file not found: {}", path),
             // Entity
@@ -235,6 +313,7 @@ impl std::fmt::Display for Error {
             Error::ParticipantNotFound => write!(f, "Participant not found"),
             Error::RegistrationFailed => write!(f, "Topic registration failed"),
             Error::InvalidState(msg) => write!(f, "Invalid state: {}", msg),
+            Error::EndpointRejected(msg) => write!(f, "Endpoint rejected: {}", msg),
             // Transport
             Error::Io => write!(f, "I/O error"),
             Error::IoError(e) => write!(f, "I/O error: {}", e),
@@ -255,9 +334,17 @@ impl std::fmt::Display for Error {
             Error::WriteTimeout => write!(f, "Write timeout"),
             Error::DiscoveryTimeout => write!(f, "Discovery timeout"),
             // Other
+            #[allow(deprecated)]
             Error::Unsupported => write!(f, "Unsupported operation"),
+            Error::UnsupportedOperation { operation, context } => {
+                write!(f, "Unsupported operation: {}", operation)?;
+                write_context(f, context)
+            }
+            Error::Context { context, source } => write!(f, "{}: {}", context, source),
             #[cfg(feature = "security")]
             Error::PermissionDenied(msg) => write!(f, "Permission denied: {}", msg),
+            #[cfg(feature = "wasmtime")]
+            Error::WasmPlugin(msg) => write!(f, "WASM plugin error: {}", msg),
         }
     }
 }
@@ -266,9 +353,107 @@ impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Error::IoError(e) => Some(e),
+            Error::Context { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Append ` (context)` when the context is not empty.
+fn write_context(f: &mut std::fmt::Formatter<'_>, context: &ErrorContext) -> std::fmt::Result {
+    if context.is_empty() {
+        Ok(())
+    } else {
+        write!(f, " ({})", context)
+    }
+}
+
+impl Error {
+    /// Configuration error with no context yet.
+    pub fn config(reason: impl Into<String>) -> Self {
+        Error::InvalidConfig {
+            reason: reason.into(),
+            context: Box::default(),
+        }
+    }
+
+    /// Unsupported operation error with no context yet.
+    pub fn unsupported(operation: impl Into<String>) -> Self {
+        Error::UnsupportedOperation {
+            operation: operation.into(),
+            context: Box::default(),
+        }
+    }
+
+    /// Attach `context`: `InvalidConfig` and `UnsupportedOperation` errors fill their
+    /// unset context fields, other errors are wrapped in
+    /// [`Error::Context`] (keeping them as `source()`).
+    #[must_use]
+    pub fn with_context(self, context: ErrorContext) -> Self {
+        match self {
+            Error::InvalidConfig {
+                reason,
+                context: mut own,
+            } => {
+                own.merge(context);
+                Error::InvalidConfig {
+                    reason,
+                    context: own,
+                }
+            }
+            Error::UnsupportedOperation {
+                operation,
+                context: mut own,
+            } => {
+                own.merge(context);
+                Error::UnsupportedOperation {
+                    operation,
+                    context: own,
+                }
+            }
+            Error::Context {
+                context: mut own,
+                source,
+            } => {
+                own.merge(context);
+                Error::Context {
+                    context: own,
+                    source,
+                }
+            }
+            other => Error::Context {
+                context: Box::new(context),
+                source: Box::new(other),
+            },
+        }
+    }
+
+    /// Context of the error, if any was attached.
+    #[must_use]
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            Error::InvalidConfig { context, .. }
+            | Error::UnsupportedOperation { context, .. }
+            | Error::Context { context, .. } => Some(&**context).filter(|c| !c.is_empty()),
             _ => None,
         }
     }
+
+    /// The original error, under any [`Error::Context`] wrapper.
+    ///
+    /// ```
+    /// use hdds::{Error, ErrorContext};
+    ///
+    /// let err = Error::WouldBlock.with_context(ErrorContext::new().topic("cmd"));
+    /// assert!(matches!(err.root(), Error::WouldBlock));
+    /// ```
+    #[must_use]
+    pub fn root(&self) -> &Error {
+        match self {
+            Error::Context { source, .. } => source.root(),
+            other => other,
+        }
+    }
 }
 
 /// Convenient alias for API results using the public `Error` type.
@@ -383,6 +568,24 @@ pub trait DDS: Sized + Send + Sync + 'static {
     fn has_key() -> bool {
         false
     }
+
+    /// Default topic name for this type (`#[dds(topic = "...")]`)
+    ///
+    /// Used by [`Participant::create_writer_for`] and
+    /// [`Participant::create_reader_for`] so call sites don't repeat it.
+    #[must_use]
+    fn default_topic() -> Option<&'static str> {
+        None
+    }
+
+    /// Default QoS for this type (`#[dds(qos = ...)]`)
+    ///
+    /// Applied by [`Participant::create_writer_for`] and
+    /// [`Participant::create_reader_for`]; `None` keeps the builder default.
+    #[must_use]
+    fn default_qos() -> Option<QoS> {
+        None
+    }
 }
 
 // All generated types (via build.rs or #[derive(DDS)]) provide real type_descriptor().
@@ -398,5 +601,5 @@ pub trait DDS: Sized + Send + Sync + 'static {
 //   - No more stubs -> 100% real metadata
 //
 // If you're getting "trait DDS is not implemented" errors after this change:
-//   -> Use #[derive(hdds::DDS)] for simple types (primitives only)
+//   -> Use #[derive(hdds::DDS)] for Rust-defined types (nested structs, enums, arrays, sequences)
 //   -> Use hdds-gen codegen for complex types (generates full impl in build.rs)
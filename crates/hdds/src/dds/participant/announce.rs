@@ -14,7 +14,8 @@ use crate::core::rtps_constants::{ENTITY_KIND_USER_READER, ENTITY_KIND_USER_WRIT
 #[cfg(target_os = "linux")]
 use crate::dds::qos::Reliability;
 use crate::dds::Result;
-use crate::protocol::discovery::SedpData;
+use crate::protocol::discovery::{EndpointMetadata, SedpData};
+use crate::protocol::type_version::TypeVersionAnnouncement;
 use crate::xtypes::CompleteTypeObject;
 
 use crate::core::discovery::multicast::rtps_packet::{
@@ -30,9 +31,16 @@ impl Participant {
         &self,
         topic: &str,
         qos: &crate::dds::QoS,
+        type_versions: Option<TypeVersionAnnouncement>,
     ) -> Result<[u8; 4]> {
         let (type_name, type_object) = self.resolve_type_info::<T>(topic, None, None);
-        self.announce_writer_endpoint_with_resolved(topic, qos, type_name, type_object)
+        self.announce_writer_endpoint_with_resolved(
+            topic,
+            qos,
+            type_name,
+            type_object,
+            type_versions,
+        )
     }
 
     pub(crate) fn announce_writer_endpoint_with_type<T: crate::dds::DDS>(
@@ -41,10 +49,17 @@ impl Participant {
         qos: &crate::dds::QoS,
         type_name: &str,
         type_object: Option<CompleteTypeObject>,
+        type_versions: Option<TypeVersionAnnouncement>,
     ) -> Result<[u8; 4]> {
         let (type_name, type_object) =
             self.resolve_type_info::<T>(topic, Some(type_name), type_object);
-        self.announce_writer_endpoint_with_resolved(topic, qos, type_name, type_object)
+        self.announce_writer_endpoint_with_resolved(
+            topic,
+            qos,
+            type_name,
+            type_object,
+            type_versions,
+        )
     }
 
     fn announce_writer_endpoint_with_resolved(
@@ -53,6 +68,7 @@ impl Participant {
         qos: &crate::dds::QoS,
         type_name: String,
         type_object: Option<CompleteTypeObject>,
+        type_versions: Option<TypeVersionAnnouncement>,
     ) -> Result<[u8; 4]> {
         // NOTE: TypeObject handling is now delegated to the dialect encoder.
         // FastDdsEncoder ignores type_object (requires_type_object() = false)
@@ -96,6 +112,11 @@ impl Participant {
         #[cfg(not(target_os = "linux"))]
         let user_data: Option<String> = None;
 
+        let type_versions = type_versions.map(|mut versions| {
+            versions.set_native_type(&type_name, type_object.clone());
+            versions
+        });
+
         let sedp_data = SedpData {
             topic_name: topic.to_string(),
             type_name,
@@ -106,6 +127,8 @@ impl Participant {
             type_object,
             unicast_locators,
             user_data,
+            type_versions,
+            metadata: EndpointMetadata::from_qos(qos),
         };
 
         // Cache announcement for unicast replay / re-announces driven by SPDP
@@ -136,6 +159,14 @@ impl Participant {
         // so we need this mapping even for local writers.
         if let Some(ref registry) = self.registry {
             registry.register_writer_guid(endpoint_guid_bytes, topic.to_string());
+            registry.set_writer_partition(
+                endpoint_guid_bytes,
+                sedp_data
+                    .qos
+                    .as_ref()
+                    .map(|q| q.partition.clone())
+                    .unwrap_or_default(),
+            );
             log::debug!(
                 "[SEDP-ANNOUNCE] Registered writer GUID {:02x?} -> topic '{}'",
                 &endpoint_guid_bytes[..],
@@ -143,7 +174,7 @@ impl Participant {
             );
         }
 
-        self.graph_guard.set_trigger_value(true);
+        self.graph_events.notify();
 
         Ok(entity_id)
     }
@@ -152,13 +183,20 @@ impl Participant {
         &self,
         topic: &str,
         qos: &crate::dds::QoS,
-    ) -> Result<()> {
+        type_versions: Option<TypeVersionAnnouncement>,
+    ) -> Result<GUID> {
         log::debug!(
             "[SEDP-ANNOUNCE] announce_reader_endpoint called for topic '{}'",
             topic
         );
         let (type_name, type_object) = self.resolve_type_info::<T>(topic, None, None);
-        self.announce_reader_endpoint_with_resolved(topic, qos, type_name, type_object)
+        self.announce_reader_endpoint_with_resolved(
+            topic,
+            qos,
+            type_name,
+            type_object,
+            type_versions,
+        )
     }
 
     pub(crate) fn announce_reader_endpoint_with_type<T: crate::dds::DDS>(
@@ -167,10 +205,17 @@ impl Participant {
         qos: &crate::dds::QoS,
         type_name: &str,
         type_object: Option<CompleteTypeObject>,
-    ) -> Result<()> {
+        type_versions: Option<TypeVersionAnnouncement>,
+    ) -> Result<GUID> {
         let (type_name, type_object) =
             self.resolve_type_info::<T>(topic, Some(type_name), type_object);
-        self.announce_reader_endpoint_with_resolved(topic, qos, type_name, type_object)
+        self.announce_reader_endpoint_with_resolved(
+            topic,
+            qos,
+            type_name,
+            type_object,
+            type_versions,
+        )
     }
 
     fn announce_reader_endpoint_with_resolved(
@@ -179,7 +224,8 @@ impl Participant {
         qos: &crate::dds::QoS,
         type_name: String,
         type_object: Option<CompleteTypeObject>,
-    ) -> Result<()> {
+        type_versions: Option<TypeVersionAnnouncement>,
+    ) -> Result<GUID> {
         // NOTE: TypeObject handling is now delegated to the dialect encoder.
         // FastDdsEncoder ignores type_object (requires_type_object() = false)
         // RtiEncoder would encode it if present (requires_type_object() = true)
@@ -221,6 +267,11 @@ impl Participant {
         #[cfg(not(target_os = "linux"))]
         let user_data: Option<String> = None;
 
+        let type_versions = type_versions.map(|mut versions| {
+            versions.set_native_type(&type_name, type_object.clone());
+            versions
+        });
+
         let sedp_data = SedpData {
             topic_name: topic.to_string(),
             type_name,
@@ -231,6 +282,8 @@ impl Participant {
             type_object,
             unicast_locators,
             user_data,
+            type_versions,
+            metadata: EndpointMetadata::from_qos(qos),
         };
 
         // Cache announcement for unicast replay to discovered peers
@@ -250,14 +303,40 @@ impl Participant {
         // v234: Immediately announce this endpoint to all known peers.
         self.flush_sedp_to_known_peers(&sedp_data, SedpEndpointKind::Reader);
 
-        self.graph_guard.set_trigger_value(true);
+        self.graph_events.notify();
 
-        Ok(())
+        Ok(endpoint_guid)
     }
 }
 
 impl Participant {
-    fn resolve_type_info<T: crate::dds::DDS>(
+    /// Type names and TypeObjects announced by the writers and readers of
+    /// this participant, e.g. to publish them to a schema registry.
+    ///
+    /// One entry per distinct pair, in announcement order. Endpoints
+    /// announced without a TypeObject are skipped.
+    pub fn local_type_objects(&self) -> Vec<(String, CompleteTypeObject)> {
+        let announcements = self
+            .sedp_announcements
+            .read()
+            .unwrap_or_else(|e| e.into_inner());
+        let mut types: Vec<(String, CompleteTypeObject)> = Vec::new();
+        for (data, _) in announcements.iter() {
+            let Some(type_object) = &data.type_object else {
+                continue;
+            };
+            if !types
+                .iter()
+                .any(|(name, known)| *name == data.type_name && known == type_object)
+            {
+                types.push((data.type_name.clone(), type_object.clone()));
+            }
+        }
+        types
+    }
+
+    /// Type name and TypeObject announced for an endpoint of `T` on `topic`.
+    pub(crate) fn resolve_type_info<T: crate::dds::DDS>(
         &self,
         topic: &str,
         type_name_override: Option<&str>,
@@ -303,6 +382,8 @@ impl Participant {
 
         #[cfg(not(feature = "xtypes"))]
         {
+            // Topic-bound TypeObjects are only registered with XTypes
+            let _ = topic;
             let type_name = type_name_override
                 .map(|name| name.to_string())
                 .unwrap_or_else(|| type_descriptor.type_name.to_string());
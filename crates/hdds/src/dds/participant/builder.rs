@@ -11,19 +11,30 @@
 //! - Seed peers and configuration files
 
 mod bootstrap; // build() implementation (orchestration)
-mod discovery_setup; // Discovery FSM, listeners, demux router (now a module with handlers)
+pub(in crate::dds::participant) mod discovery_setup; // Discovery FSM, listeners, demux router (now a module with handlers)
 mod entity_registry; // SEDP cache and GUID generation
 mod sockets; // Socket creation utilities
+pub(in crate::dds::participant) mod tcp_relay; // TransportMode::Tcp loopback relay
 mod telemetry_setup; // Telemetry and metrics initialization
 mod threads; // Background thread spawning (SPDP, lease tracker)
 pub(in crate::dds::participant) mod unicast_routing; // Sprint 7: TCP/QUIC → TopicRegistry routing thread
 
 use super::runtime::{Participant, TransportMode};
-use crate::discovery_server::DiscoveryServerConfig;
+use crate::core::rt::clock::{Clock, SystemClock};
+use crate::dds::{
+    EndpointHook, HibernationConfig, ParticipantListener, StaticDeployment, UserData,
+};
+#[cfg(feature = "discovery-server")]
+use crate::discovery_server::{DiscoveryServerConfig, EmbeddedServerConfig};
+use crate::engine::RxWorkerConfig;
 use crate::transport::lowbw::LowBwConfig;
 use crate::transport::shm::ShmPolicy;
+use crate::transport::tap::{CapturedPacket, PacketTap};
 use crate::transport::tcp::{TcpConfig, TransportPreference};
-use crate::transport::CustomPortMapping;
+use crate::transport::uds::UdsConfig;
+use crate::transport::{CustomPortMapping, InterfaceFilter, IpMode};
+use std::sync::Arc;
+use std::time::Duration;
 
 #[cfg(feature = "xtypes")]
 use crate::core::types::{Distro, TypeObjectHandle};
@@ -33,14 +44,17 @@ use crate::discovery::k8s::K8sDiscoveryConfig;
 use crate::security::SecurityConfig;
 #[cfg(feature = "quic")]
 use crate::transport::quic::QuicConfig;
+#[cfg(feature = "rdma")]
+use crate::transport::rdma::RdmaConfig;
+#[cfg(all(feature = "af-xdp", target_os = "linux"))]
+use crate::transport::xdp::XdpConfig;
 #[cfg(feature = "xtypes")]
 use parking_lot::RwLock;
 #[cfg(feature = "xtypes")]
 use std::collections::HashMap;
-#[cfg(feature = "xtypes")]
-use std::sync::Arc;
 
 /// Builder for configuring and creating a [`Participant`].
+#[derive(Clone)]
 pub struct ParticipantBuilder {
     pub(super) name: String,
     pub(super) transport_mode: TransportMode,
@@ -48,9 +62,26 @@ pub struct ParticipantBuilder {
     pub(super) participant_id: Option<u8>,
     pub(super) seed_peers: Option<String>,
     pub(super) config_path: Option<String>,
+    /// QoS profile files loaded into the global profile registry at build
+    #[cfg(feature = "qos-loaders")]
+    pub(super) qos_profile_files: Vec<std::path::PathBuf>,
     pub(super) custom_ports: Option<CustomPortMapping>,
+    /// UDP address families (None = HDDS_IP_MODE, IPv4 by default)
+    pub(super) ip_mode: Option<IpMode>,
+    /// Network interfaces used by the UDP transport (None = HDDS_INTERFACE*, all by default)
+    pub(super) interface_filter: Option<InterfaceFilter>,
     /// Static peers for unicast communication without discovery
     pub(super) static_peers: Vec<std::net::SocketAddr>,
+    /// Unicast SPDP destinations (multicast-less discovery)
+    pub(super) initial_peers: Vec<crate::core::discovery::InitialPeer>,
+    /// USER_DATA announced in SPDP
+    pub(super) user_data: UserData,
+    /// Keepalive interval toward unicast peers behind NATs (None = disabled)
+    pub(super) nat_keepalive: Option<Duration>,
+    /// Time source of leases, deadlines and lifespans
+    pub(super) clock: Arc<dyn Clock>,
+    /// Discovery event listener
+    pub(super) listener: Option<Arc<dyn ParticipantListener>>,
     #[cfg(feature = "xtypes")]
     pub(super) type_cache_capacity: usize,
     #[cfg(feature = "xtypes")]
@@ -69,13 +100,25 @@ pub struct ParticipantBuilder {
     pub(super) tcp_config: Option<TcpConfig>,
     /// Transport preference (UDP only, TCP only, hybrid)
     pub(super) transport_preference: TransportPreference,
+    /// Unix domain socket transport configuration (same-host peers)
+    pub(super) uds_config: Option<UdsConfig>,
+    /// RDMA transport configuration (HPC clusters, experimental)
+    #[cfg(feature = "rdma")]
+    pub(super) rdma_config: Option<RdmaConfig>,
+    /// AF_XDP receive path configuration (user traffic, experimental)
+    #[cfg(all(feature = "af-xdp", target_os = "linux"))]
+    pub(super) xdp_config: Option<XdpConfig>,
     /// QUIC transport configuration for NAT traversal
     #[cfg(feature = "quic")]
     pub(super) quic_config: Option<QuicConfig>,
     /// Low Bandwidth transport configuration (for constrained links like HC-12)
     pub(super) lowbw_config: Option<LowBwConfig>,
     /// Discovery Server configuration (for environments without multicast)
+    #[cfg(feature = "discovery-server")]
     pub(super) discovery_server_config: Option<DiscoveryServerConfig>,
+    /// Discovery Server role embedded in this participant
+    #[cfg(feature = "discovery-server")]
+    pub(super) embedded_discovery_server: Option<EmbeddedServerConfig>,
     /// Cloud discovery provider name (consul, aws, azure)
     #[cfg(feature = "cloud-discovery")]
     pub(super) cloud_discovery_provider: Option<String>,
@@ -84,6 +127,16 @@ pub struct ParticipantBuilder {
     pub(super) consul_addr: Option<String>,
     /// Shared Memory transport policy (Prefer, Require, Disable)
     pub(super) shm_policy: ShmPolicy,
+    /// Receive worker pool for large samples (None = route on the router thread)
+    pub(super) rx_worker_config: Option<RxWorkerConfig>,
+    /// Topics and endpoints declared upfront (None = dynamic participant)
+    pub(super) static_deployment: Option<StaticDeployment>,
+    /// Idle-endpoint hibernation (None = endpoints never hibernate)
+    pub(super) hibernation: Option<HibernationConfig>,
+    /// Hooks run on every writer and reader creation
+    pub(super) endpoint_hooks: Vec<Arc<dyn EndpointHook>>,
+    /// Taps run on every sent and received datagram
+    pub(super) packet_taps: Vec<PacketTap>,
 }
 
 impl Participant {
@@ -131,8 +184,17 @@ impl ParticipantBuilder {
             participant_id: None,
             seed_peers: None,
             config_path: None,
+            #[cfg(feature = "qos-loaders")]
+            qos_profile_files: Vec::new(),
             custom_ports: None,
+            ip_mode: None,
+            interface_filter: None,
             static_peers: Vec::new(),
+            initial_peers: Vec::new(),
+            user_data: UserData::default(),
+            nat_keepalive: None,
+            clock: SystemClock::shared(),
+            listener: None,
             #[cfg(feature = "xtypes")]
             type_cache_capacity: 256,
             #[cfg(feature = "xtypes")]
@@ -147,21 +209,127 @@ impl ParticipantBuilder {
             k8s_discovery_config: None,
             tcp_config: None,
             transport_preference: TransportPreference::UdpOnly,
+            uds_config: None,
+            #[cfg(feature = "rdma")]
+            rdma_config: None,
+            #[cfg(all(feature = "af-xdp", target_os = "linux"))]
+            xdp_config: None,
             #[cfg(feature = "quic")]
             quic_config: None,
             lowbw_config: None,
+            #[cfg(feature = "discovery-server")]
             discovery_server_config: None,
+            #[cfg(feature = "discovery-server")]
+            embedded_discovery_server: None,
             #[cfg(feature = "cloud-discovery")]
             cloud_discovery_provider: None,
             #[cfg(feature = "cloud-discovery")]
             consul_addr: None,
             shm_policy: ShmPolicy::Prefer,
+            rx_worker_config: None,
+            static_deployment: None,
+            hibernation: None,
+            endpoint_hooks: Vec::new(),
+            packet_taps: Vec::new(),
         }
     }
 
-    /// Set the transport mode (intra-process or UDP multicast).
+    /// Set the transport mode (intra-process, UDP multicast, TCP or loopback bus).
+    ///
+    /// [`TransportMode::Tcp`] also selects [`TransportPreference::TcpOnly`];
+    /// configure peers, role and framing with [`tcp_config()`](Self::tcp_config).
     pub fn with_transport(mut self, mode: TransportMode) -> Self {
         self.transport_mode = mode;
+        if mode == TransportMode::Tcp {
+            self.transport_preference = TransportPreference::TcpOnly;
+        }
+        self
+    }
+
+    /// Select the IP address families of the UDP transport.
+    ///
+    /// [`IpMode::V6`] runs on IPv6 only (ff02::ffff:239.255.0.1 multicast,
+    /// UDPv6 locators), for IPv6-only networks. [`IpMode::Dual`] runs IPv4
+    /// and IPv6 side by side and announces locators of both families.
+    /// Defaults to `HDDS_IP_MODE` (`v4`, `v6` or `dual`), else IPv4.
+    /// Ignored by [`TransportMode::Tcp`], whose traffic stays on loopback,
+    /// and by [`TransportMode::LoopbackBus`], which has no socket.
+    pub fn with_ip_mode(mut self, mode: IpMode) -> Self {
+        self.ip_mode = Some(mode);
+        self
+    }
+
+    /// Restrict the UDP transport to a network interface.
+    ///
+    /// `interface` is an interface name, optionally with `*` wildcards
+    /// (`eth0`, `enp*`), or an IPv4 address or CIDR (`192.168.1.0/24`).
+    /// SPDP multicast is joined and sent on the matching interfaces only and
+    /// unicast locators are announced from them, so VPN or container bridge
+    /// addresses are never advertised. Can be called several times.
+    ///
+    /// Builder settings replace `HDDS_INTERFACE`, `HDDS_INTERFACE_ALLOW` and
+    /// `HDDS_INTERFACE_DENY`. Building fails if no interface matches.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use hdds::Participant;
+    ///
+    /// let participant = Participant::builder("app")
+    ///     .with_interface("eth0")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_interface(mut self, interface: &str) -> Self {
+        let filter = self.interface_filter.take().unwrap_or_default();
+        self.interface_filter = Some(filter.allow(interface));
+        self
+    }
+
+    /// Allow several interfaces (see [`with_interface()`](Self::with_interface)).
+    pub fn allow_interfaces(self, interfaces: &[&str]) -> Self {
+        interfaces
+            .iter()
+            .fold(self, |builder, interface| builder.with_interface(interface))
+    }
+
+    /// Exclude interfaces by name pattern or CIDR, e.g. `&["docker*", "tun*"]`.
+    ///
+    /// Denied interfaces are skipped even when allowed; without an allow list
+    /// every other interface stays in use.
+    pub fn deny_interfaces(mut self, interfaces: &[&str]) -> Self {
+        let filter = self.interface_filter.take().unwrap_or_default();
+        self.interface_filter = Some(
+            interfaces
+                .iter()
+                .fold(filter, |filter, interface| filter.deny(interface)),
+        );
+        self
+    }
+
+    /// Register a listener for participant and endpoint discovery events.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use hdds::core::discovery::GUID;
+    /// use hdds::dds::ParticipantListener;
+    /// use hdds::Participant;
+    /// use std::sync::Arc;
+    ///
+    /// struct Joins;
+    ///
+    /// impl ParticipantListener for Joins {
+    ///     fn on_participant_discovered(&self, participant: GUID) {
+    ///         println!("discovered {}", participant);
+    ///     }
+    /// }
+    ///
+    /// let participant = Participant::builder("app")
+    ///     .with_listener(Arc::new(Joins))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_listener(mut self, listener: Arc<dyn ParticipantListener>) -> Self {
+        self.listener = Some(listener);
         self
     }
 
@@ -219,6 +387,34 @@ impl ParticipantBuilder {
         self
     }
 
+    /// Load named QoS profiles from a YAML or XML file.
+    ///
+    /// The file is loaded into the process-wide profile registry when the
+    /// participant is built, so its profiles are available through
+    /// [`QoS::from_profile`](crate::QoS::from_profile). XML files can be
+    /// OMG DDS-XML (`<qos_library>`, as written for RTI Connext or Cyclone
+    /// DDS) or FastDDS `<profiles>`. Can be called several times; later
+    /// files override profiles of the same name.
+    ///
+    /// `build()` fails if a file is missing or cannot be parsed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use hdds::{Participant, QoS};
+    ///
+    /// let participant = Participant::builder("app")
+    ///     .with_qos_profile_file("qos_profiles.xml")
+    ///     .build()?;
+    /// let qos = QoS::from_profile("telemetry_profile")
+    ///     .map_err(hdds::Error::InvalidQos)?;
+    /// # Ok::<(), hdds::Error>(())
+    /// ```
+    #[cfg(feature = "qos-loaders")]
+    pub fn with_qos_profile_file(mut self, path: impl AsRef<std::path::Path>) -> Self {
+        self.qos_profile_files.push(path.as_ref().to_path_buf());
+        self
+    }
+
     /// Set custom discovery ports (override RTPS v2.5 formula).
     ///
     /// Use this when you need non-standard ports for:
@@ -322,6 +518,126 @@ impl ParticipantBuilder {
         self
     }
 
+    /// Announce this participant via unicast SPDP to a list of initial peers.
+    ///
+    /// Use this where multicast is disabled (Kubernetes, cloud VPCs): every
+    /// SPDP announcement is also sent to each peer, and participants found
+    /// this way are announced to in turn, so no Discovery Server is needed.
+    /// Requires `TransportMode::UdpMulticast`.
+    ///
+    /// A peer is an IP address or host name with an optional port:
+    /// - `"192.168.1.10:7410"`, `"[fd00::2]:7410"`, `"node-a:7410"`: that
+    ///   metatraffic unicast port only
+    /// - `"192.168.1.10"`, `"node-a"`: the metatraffic unicast ports of the
+    ///   first participant ids (0-3) in this participant's domain
+    ///
+    /// Host names are resolved again periodically, so changing pod addresses
+    /// are followed. `HDDS_SPDP_UNICAST_PEERS` accepts the same syntax.
+    /// Calls accumulate; invalid entries are logged and ignored (no panic).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use hdds::{Participant, TransportMode};
+    ///
+    /// let participant = Participant::builder("my_app")
+    ///     .with_transport(TransportMode::UdpMulticast)
+    ///     .with_initial_peers(&["192.168.1.10:7410", "host2"])
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_initial_peers(mut self, peers: &[&str]) -> Self {
+        for peer in peers {
+            match peer.parse::<crate::core::discovery::InitialPeer>() {
+                Ok(peer) => self.initial_peers.push(peer),
+                Err(e) => log::error!("Invalid initial peer: {}", e),
+            }
+        }
+        self
+    }
+
+    /// Keep unicast paths through NATs open (WAN, cellular field links).
+    ///
+    /// NATs silently drop the mapping of a UDP flow idle for longer than
+    /// their timeout, cutting off a quiet peer. Every `interval`, a tiny
+    /// RTPS message (header + INFO_DST) is sent to each unicast locator of
+    /// each discovered peer, so the mappings never go idle. Keep `interval`
+    /// below the shortest NAT timeout on the path (15-25 s usually).
+    ///
+    /// Also follows peers behind a NAT: a peer whose SPDP comes from an
+    /// address it did not announce is reached at that (reflexive) address
+    /// instead, keeping the announced ports, and is re-resolved, with its
+    /// endpoints announced again, when its public address changes.
+    /// Requires `TransportMode::UdpMulticast`; a zero `interval` disables
+    /// both (default).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use hdds::{Participant, TransportMode};
+    /// use std::time::Duration;
+    ///
+    /// let participant = Participant::builder("field_unit")
+    ///     .with_transport(TransportMode::UdpMulticast)
+    ///     .with_initial_peers(&["control.example.com:7410"])
+    ///     .nat_keepalive(Duration::from_secs(20))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn nat_keepalive(mut self, interval: Duration) -> Self {
+        self.nat_keepalive = (!interval.is_zero()).then_some(interval);
+        self
+    }
+
+    /// Set the participant USER_DATA (default: empty).
+    ///
+    /// The bytes are announced in SPDP; remote participants read them from
+    /// [`ParticipantInfo::user_data`](crate::core::discovery::multicast::ParticipantInfo).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use hdds::dds::UserData;
+    /// use hdds::Participant;
+    ///
+    /// let participant = Participant::builder("my_app")
+    ///     .user_data(UserData::new(b"token=abc;site=lab".to_vec()))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn user_data(mut self, user_data: UserData) -> Self {
+        self.user_data = user_data;
+        self
+    }
+
+    /// Set the time source of the participant (default: [`SystemClock`]).
+    ///
+    /// Participant leases and writer liveliness leases of the participant's
+    /// entities are timed on `clock`; [`Participant::clock`] hands it to
+    /// deadline and lifespan checkers. With a
+    /// [`VirtualClock`](crate::VirtualClock), a replay or a test advances
+    /// these timers from recorded time instead of the wall clock.
+    ///
+    /// Network timers (SPDP announcements, heartbeats, retransmissions)
+    /// keep running on the wall clock.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use hdds::{Participant, VirtualClock};
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    ///
+    /// let clock = Arc::new(VirtualClock::new());
+    /// let participant = Participant::builder("replay")
+    ///     .clock(clock.clone())
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Play back 5 s of recording
+    /// clock.advance(Duration::from_secs(5));
+    /// ```
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Set type cache capacity (XTypes feature only).
     #[cfg(feature = "xtypes")]
     pub fn with_type_cache_capacity(mut self, capacity: usize) -> Self {
@@ -524,9 +840,8 @@ impl ParticipantBuilder {
     /// ]);
     ///
     /// let participant = Participant::builder("client")
-    ///     .with_transport(TransportMode::UdpMulticast)
+    ///     .with_transport(TransportMode::Tcp)  // Disable UDP, TCP-only mode
     ///     .tcp_config(tcp)
-    ///     .tcp_only()  // Disable UDP, TCP-only mode
     ///     .build()
     ///     .unwrap();
     /// ```
@@ -541,9 +856,10 @@ impl ParticipantBuilder {
 
     /// Set transport to TCP-only mode (no UDP).
     ///
-    /// In this mode, UDP is completely disabled. Requires either:
+    /// Shorthand for `with_transport(TransportMode::Tcp)`: discovery and
+    /// user data both travel over TCP. Requires either:
     /// - Initial peers configured in [`TcpConfig::initial_peers`]
-    /// - A discovery server (planned feature)
+    /// - Remote peers connecting to this participant's listen port
     ///
     /// This is useful for:
     /// - Environments where UDP is blocked
@@ -566,9 +882,8 @@ impl ParticipantBuilder {
     ///     .build()
     ///     .unwrap();
     /// ```
-    pub fn tcp_only(mut self) -> Self {
-        self.transport_preference = TransportPreference::TcpOnly;
-        self
+    pub fn tcp_only(self) -> Self {
+        self.with_transport(TransportMode::Tcp)
     }
 
     /// Set transport preference (UDP only, TCP only, hybrid, etc.).
@@ -576,6 +891,10 @@ impl ParticipantBuilder {
     /// For most cases, use the convenience methods:
     /// - [`with_tcp()`](Self::with_tcp) - Enables hybrid mode (UDP discovery + TCP data)
     /// - [`tcp_only()`](Self::tcp_only) - TCP-only, no UDP
+    /// - [`with_uds()`](Self::with_uds) - UDS for same-host peers, UDP for remote
+    ///
+    /// Selecting [`TransportPreference::UdsPreferred`] without a UDS config
+    /// enables UDS with its default settings.
     ///
     /// This method provides full control over transport selection.
     pub fn with_transport_preference(mut self, preference: TransportPreference) -> Self {
@@ -583,6 +902,118 @@ impl ParticipantBuilder {
         self
     }
 
+    // =========================================================================
+    // Unix Domain Socket Transport (same-host containers without SHM)
+    // =========================================================================
+
+    /// Enable Unix domain socket transport with the default socket directory.
+    ///
+    /// Sets the transport preference to [`TransportPreference::UdsPreferred`]:
+    /// UDP keeps handling discovery and remote peers, while peers with a
+    /// socket in the same directory exchange RTPS messages over UDS.
+    ///
+    /// The directory defaults to `$HDDS_UDS_DIR` (or `<tmp>/hdds`); mount it
+    /// into every container that should talk over UDS.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use hdds::Participant;
+    ///
+    /// let participant = Participant::builder("sidecar")
+    ///     .with_uds()
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_uds(self) -> Self {
+        self.uds_config(UdsConfig::enabled())
+    }
+
+    /// Set full Unix domain socket transport configuration.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use hdds::Participant;
+    /// use hdds::transport::uds::UdsConfig;
+    ///
+    /// let uds = UdsConfig::enabled().with_socket_dir("/var/run/hdds");
+    ///
+    /// let participant = Participant::builder("sidecar")
+    ///     .uds_config(uds)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn uds_config(mut self, config: UdsConfig) -> Self {
+        self.uds_config = Some(config);
+        if self.transport_preference == TransportPreference::UdpOnly {
+            self.transport_preference = TransportPreference::UdsPreferred;
+        }
+        self
+    }
+
+    // =========================================================================
+    // RDMA Transport (InfiniBand / RoCE clusters, experimental)
+    // =========================================================================
+
+    /// Enable the experimental RDMA verbs transport.
+    ///
+    /// The participant opens the device and registers the buffer pool at
+    /// build time; connections are set up with
+    /// [`RdmaTransport::prepare`](crate::transport::rdma::RdmaTransport::prepare)
+    /// / [`connect`](crate::transport::rdma::RdmaTransport::connect) once
+    /// queue pair info has been exchanged. If libibverbs or the device is
+    /// missing, the error is logged and the participant runs without RDMA.
+    ///
+    /// # Feature Flag
+    /// Requires the `rdma` feature (Linux only at runtime).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use hdds::Participant;
+    /// use hdds::transport::rdma::RdmaConfig;
+    ///
+    /// let participant = Participant::builder("solver")
+    ///     .with_rdma(RdmaConfig::enabled().with_device("mlx5_0").with_gid_index(3))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "rdma")]
+    pub fn with_rdma(mut self, config: RdmaConfig) -> Self {
+        self.rdma_config = Some(config);
+        self
+    }
+
+    // =========================================================================
+    // AF_XDP Receive Path (Linux, experimental)
+    // =========================================================================
+
+    /// Receive user traffic through an AF_XDP socket instead of the kernel
+    /// UDP stack.
+    ///
+    /// HDDS attaches a small XDP program that redirects UDP packets for the
+    /// participant's user unicast port (or `XdpConfig::ports`) on the bound
+    /// queue; the routing thread parses RTPS straight out of UMEM frames.
+    /// Needs `CAP_NET_RAW` + `CAP_BPF`. If setup fails, the error is logged
+    /// and the kernel UDP path keeps working.
+    ///
+    /// # Feature Flag
+    /// Requires the `af-xdp` feature (Linux only).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use hdds::Participant;
+    /// use hdds::transport::xdp::XdpConfig;
+    ///
+    /// let participant = Participant::builder("md_ingest")
+    ///     .with_af_xdp(XdpConfig::enabled("ens1f0").with_queue(0))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[cfg(all(feature = "af-xdp", target_os = "linux"))]
+    pub fn with_af_xdp(mut self, config: XdpConfig) -> Self {
+        self.xdp_config = Some(config);
+        self
+    }
+
     // =========================================================================
     // QUIC Transport (for NAT traversal and connection migration)
     // =========================================================================
@@ -724,6 +1155,7 @@ impl ParticipantBuilder {
     ///     .with_discovery_server(config)
     ///     .build()?;
     /// ```
+    #[cfg(feature = "discovery-server")]
     pub fn with_discovery_server(mut self, config: DiscoveryServerConfig) -> Self {
         self.discovery_server_config = Some(config);
         self
@@ -739,11 +1171,48 @@ impl ParticipantBuilder {
     ///     .discovery_server_addr("discovery.example.com:7400".parse()?)
     ///     .build()?;
     /// ```
+    #[cfg(feature = "discovery-server")]
     pub fn discovery_server_addr(mut self, addr: std::net::SocketAddr) -> Self {
         self.discovery_server_config = Some(DiscoveryServerConfig::new(addr));
         self
     }
 
+    /// Serve discovery from this participant (embedded Discovery Server).
+    ///
+    /// Small deployments then need no separate `hdds-discovery-server`: the
+    /// participant listens on `port` (all interfaces) and the others connect
+    /// with [`discovery_server_addr`](Self::discovery_server_addr).
+    ///
+    /// If another participant on this host already serves `port`, this one
+    /// becomes its client and runs a standby server on an ephemeral port.
+    /// When the serving participant shuts down it redirects its clients to
+    /// the standby, so discovery survives it.
+    ///
+    /// Unless set with [`with_discovery_server`](Self::with_discovery_server),
+    /// the participant's own client config points at `127.0.0.1:<port>`.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let participant = Participant::builder("gateway")
+    ///     .as_discovery_server([0x44, 0x53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1], 7400)
+    ///     .build()?;
+    /// assert!(participant.is_discovery_server());
+    /// ```
+    #[cfg(feature = "discovery-server")]
+    pub fn as_discovery_server(self, guid_prefix: [u8; 12], port: u16) -> Self {
+        self.with_embedded_discovery_server(EmbeddedServerConfig::new(guid_prefix, port))
+    }
+
+    /// Serve discovery from this participant with a custom configuration
+    /// (fallback server, lease duration, peer servers).
+    ///
+    /// See [`as_discovery_server`](Self::as_discovery_server).
+    #[cfg(feature = "discovery-server")]
+    pub fn with_embedded_discovery_server(mut self, config: EmbeddedServerConfig) -> Self {
+        self.embedded_discovery_server = Some(config);
+        self
+    }
+
     // =========================================================================
     // Cloud Discovery (AWS, Azure, Consul)
     // =========================================================================
@@ -899,5 +1368,179 @@ impl ParticipantBuilder {
         self
     }
 
+    // =========================================================================
+    // Receive Processing
+    // =========================================================================
+
+    /// Route large incoming samples on a dedicated worker pool.
+    ///
+    /// Checksum verification and subscriber decode of large samples then run
+    /// off the router thread, so HEARTBEAT/ACKNACK handling and fragment
+    /// timers keep their pace during a burst. Each writer's samples stay in
+    /// order. Counters are available from [`crate::engine::Router::rx_worker_stats`]
+    /// and the admin API.
+    ///
+    /// Only applies to the UDP receive path.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use hdds::Participant;
+    /// use hdds::engine::RxWorkerConfig;
+    ///
+    /// let participant = Participant::builder("camera_ingest")
+    ///     .with_rx_workers(RxWorkerConfig::new(4).with_offload_threshold(16 * 1024))
+    ///     .build()?;
+    /// # Ok::<(), hdds::Error>(())
+    /// ```
+    pub fn with_rx_workers(mut self, config: RxWorkerConfig) -> Self {
+        self.rx_worker_config = Some(config);
+        self
+    }
+
+    // =========================================================================
+    // Static Deployment
+    // =========================================================================
+
+    /// Restrict this participant to the topics and endpoints of `deployment`.
+    ///
+    /// Creating a writer or reader the deployment does not declare fails.
+    /// After creating all declared endpoints, call
+    /// [`Participant::complete_static_discovery`] to wait for the declared
+    /// remote endpoints and seal the participant: discovery is then frozen
+    /// and no endpoint can be created or matched anymore.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use hdds::{Participant, QoS, StaticDeployment, StaticTopic};
+    ///
+    /// #[derive(hdds::DDS)]
+    /// struct Attitude {
+    ///     roll: f32,
+    /// }
+    ///
+    /// fn main() -> hdds::Result<()> {
+    ///     let participant = Participant::builder("flight_ctrl")
+    ///         .with_static_deployment(
+    ///             StaticDeployment::new()
+    ///                 .topic(StaticTopic::new("attitude").writers(1).remote_readers(2)),
+    ///         )
+    ///         .build()?;
+    ///     let _writer = participant
+    ///         .topic::<Attitude>("attitude")?
+    ///         .writer()
+    ///         .qos(QoS::reliable())
+    ///         .build()?;
+    ///     participant.complete_static_discovery()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_static_deployment(mut self, deployment: StaticDeployment) -> Self {
+        self.static_deployment = Some(deployment);
+        self
+    }
+
+    // =========================================================================
+    // Endpoint Hibernation
+    // =========================================================================
+
+    /// Hibernate writers and readers that stay idle longer than `config`
+    /// allows.
+    ///
+    /// Meant for domains with thousands of mostly idle topics: an idle
+    /// writer parks its HEARTBEAT thread and an idle endpoint releases the
+    /// unused capacity of its caches, while staying announced and matched.
+    /// Traffic reactivates it. Counters are available from
+    /// [`Participant::hibernation_stats`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use hdds::{HibernationConfig, Participant};
+    /// use std::time::Duration;
+    ///
+    /// let participant = Participant::builder("fleet_gateway")
+    ///     .with_endpoint_hibernation(
+    ///         HibernationConfig::new(Duration::from_secs(30))
+    ///             .reader_idle(Duration::from_secs(120)),
+    ///     )
+    ///     .build()?;
+    /// # Ok::<(), hdds::Error>(())
+    /// ```
+    pub fn with_endpoint_hibernation(mut self, config: HibernationConfig) -> Self {
+        self.hibernation = Some(config);
+        self
+    }
+
+    // =========================================================================
+    // Endpoint Creation Hooks
+    // =========================================================================
+
+    /// Run `hook` on the creation of every writer and reader of this
+    /// participant, after the process-wide hooks of
+    /// [`register_endpoint_hook`](crate::dds::register_endpoint_hook).
+    ///
+    /// The hook may adjust the endpoint QoS, or reject the creation by
+    /// returning an error, which `build()` then returns. Hooks run in the
+    /// order they were added.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use hdds::dds::Reliability;
+    /// use hdds::{EndpointCreation, Participant};
+    ///
+    /// let participant = Participant::builder("app")
+    ///     .with_endpoint_hook(|endpoint: &mut EndpointCreation<'_>| {
+    ///         if endpoint.topic().contains(char::is_uppercase) {
+    ///             return Err(endpoint.reject("topic names are lowercase"));
+    ///         }
+    ///         if endpoint.topic().starts_with("cmd/") {
+    ///             endpoint.qos_mut().reliability = Reliability::Reliable;
+    ///         }
+    ///         Ok(())
+    ///     })
+    ///     .build()?;
+    /// # Ok::<(), hdds::Error>(())
+    /// ```
+    pub fn with_endpoint_hook(mut self, hook: impl EndpointHook + 'static) -> Self {
+        self.endpoint_hooks.push(Arc::new(hook));
+        self
+    }
+
+    // =========================================================================
+    // Packet Capture
+    // =========================================================================
+
+    /// Call `tap` with every RTPS datagram this participant sends or
+    /// receives over UDP (or the loopback bus).
+    ///
+    /// The tap runs inline on the sending thread and on the listener
+    /// threads, so it should return quickly. Taps run in the order they were
+    /// added. Setting `HDDS_PCAP=<file>` adds a tap writing the traffic to a
+    /// pcapng capture readable by Wireshark's RTPS dissector.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use hdds::{PacketDirection, Participant};
+    /// use std::sync::atomic::{AtomicU64, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// let received = Arc::new(AtomicU64::new(0));
+    /// let counter = received.clone();
+    /// let participant = Participant::builder("app")
+    ///     .with_packet_tap(move |packet| {
+    ///         if packet.direction == PacketDirection::Received {
+    ///             counter.fetch_add(packet.data.len() as u64, Ordering::Relaxed);
+    ///         }
+    ///     })
+    ///     .build()?;
+    /// # Ok::<(), hdds::Error>(())
+    /// ```
+    pub fn with_packet_tap(
+        mut self,
+        tap: impl Fn(&CapturedPacket<'_>) + Send + Sync + 'static,
+    ) -> Self {
+        self.packet_taps.push(Arc::new(tap));
+        self
+    }
+
     // build() is implemented in bootstrap.rs
 }
@@ -11,10 +11,21 @@
 
 use super::{discovery_setup, entity_registry, telemetry_setup, threads, ParticipantBuilder};
 use crate::config::RuntimeConfig;
+use crate::dds::listener::ParticipantDiscoveryListener;
+use crate::dds::participant::graph_wait::{GraphDiscoveryListener, GraphEvents};
 use crate::dds::participant::runtime::{Participant, TransportMode, RTPS_ENTITYID_PARTICIPANT};
-use crate::dds::{DomainRegistry, GuardCondition, Result};
-use crate::transport::tcp::{TcpTransport, TransportPreference};
-use crate::transport::UdpTransport;
+use crate::dds::{DomainRegistry, GuardCondition, Result, StaticPlan};
+#[cfg(feature = "discovery-server")]
+use crate::discovery_server::{
+    DiscoveryServerConfig, EmbeddedDiscoveryServer, EmbeddedServerConfig,
+};
+use crate::transport::tcp::{TcpConfig, TcpTransport, TransportPreference};
+#[cfg(unix)]
+use crate::transport::uds::{UdsConfig, UdsTransport};
+use crate::transport::{LoopbackBus, UdpTransport};
+use std::net::Ipv4Addr;
+#[cfg(feature = "discovery-server")]
+use std::net::SocketAddr;
 use std::sync::atomic::{AtomicBool, AtomicU32};
 use std::sync::Arc;
 
@@ -44,6 +55,32 @@ impl ParticipantBuilder {
     /// `Result<Arc<Participant>>` or Error if initialization fails
     pub fn build(self) -> Result<Arc<Participant>> {
         log::debug!("[hdds] ParticipantBuilder::build name={}", self.name);
+        let build_config = self.clone();
+
+        let static_plan = match &self.static_deployment {
+            Some(deployment) => {
+                deployment.validate()?;
+                Some(Arc::new(StaticPlan::new(deployment.clone())))
+            }
+            None => None,
+        };
+
+        #[cfg(feature = "qos-loaders")]
+        for path in &self.qos_profile_files {
+            if !path.exists() {
+                return Err(crate::dds::Error::ConfigFileNotFound(
+                    path.display().to_string(),
+                ));
+            }
+            let count = crate::dds::qos::profiles::QosProfileRegistry::global()
+                .load_file(path)
+                .map_err(|e| crate::dds::Error::InvalidQos(format!("{}: {}", path.display(), e)))?;
+            log::debug!(
+                "[hdds] Loaded {} QoS profiles from {}",
+                count,
+                path.display()
+            );
+        }
 
         // Step 1: Initialize slab pool and telemetry
         let _slab_pool = crate::core::rt::init_slab_pool();
@@ -67,10 +104,23 @@ impl ParticipantBuilder {
         // until transport creation to prevent race conditions where two processes on
         // the same machine both get the same participant_id.
         let mut _port_reservation: Vec<std::net::UdpSocket> = Vec::new();
+        let ip_mode = self
+            .ip_mode
+            .unwrap_or_else(crate::transport::IpMode::from_env);
 
+        let mut loopback_port = None;
         let (port_mapping, actual_participant_id) = match self.transport_mode {
             TransportMode::IntraProcess => (None, 0),
-            TransportMode::UdpMulticast => {
+            TransportMode::LoopbackBus => {
+                // The bus hands out participant IDs; no port is probed
+                let port = LoopbackBus::global()
+                    .attach(self.domain_id, self.participant_id)
+                    .map_err(crate::dds::Error::IoError)?;
+                let assigned = (Some(port.mapping()), port.participant_id());
+                loopback_port = Some(port);
+                assigned
+            }
+            TransportMode::UdpMulticast | TransportMode::Tcp => {
                 // Priority 1: Custom ports (if specified via with_discovery_ports())
                 let (mapping, pid) = if let Some(custom) = self.custom_ports {
                     let mapping = crate::transport::PortMapping::from_custom(custom);
@@ -96,7 +146,10 @@ impl ParticipantBuilder {
                             "[hdds] Invalid HDDS_PARTICIPANT_ID='{}' (must be 0-119)",
                             pid_str
                         );
-                        crate::dds::Error::Config
+                        crate::dds::Error::config(format!(
+                            "HDDS_PARTICIPANT_ID='{}' is not a participant ID (0-119)",
+                            pid_str
+                        ))
                     })?;
                     let mapping = crate::transport::PortMapping::calculate(self.domain_id, pid)?;
                     log::info!(
@@ -126,9 +179,11 @@ impl ParticipantBuilder {
                         last_mapping = Some(mapping);
 
                         // Try to bind to BOTH unicast ports to check availability
-                        // This prevents race conditions on same-machine deployments
-                        let meta_bind_addr = format!("0.0.0.0:{}", mapping.metatraffic_unicast);
-                        let user_bind_addr = format!("0.0.0.0:{}", mapping.user_unicast);
+                        // This prevents race conditions on same-machine deployments.
+                        // With IPv6 in use, [::] (dual-stack by default) covers both families.
+                        let any_ip = if ip_mode.uses_v6() { "[::]" } else { "0.0.0.0" };
+                        let meta_bind_addr = format!("{}:{}", any_ip, mapping.metatraffic_unicast);
+                        let user_bind_addr = format!("{}:{}", any_ip, mapping.user_unicast);
 
                         match (
                             std::net::UdpSocket::bind(&meta_bind_addr),
@@ -158,7 +213,9 @@ impl ParticipantBuilder {
                         log::debug!(
                             "[hdds] ERROR: All participant IDs (0-255) failed to calculate valid PortMapping"
                         );
-                        crate::dds::Error::Config
+                        crate::dds::Error::config(
+                            "no participant ID (0-255) gives a valid port mapping",
+                        )
                     })?;
 
                     (mapping, found_pid)
@@ -195,7 +252,54 @@ impl ParticipantBuilder {
             None => None,
         };
 
-        // Step 4-5: Setup transport and discovery (if UdpMulticast mode)
+        // Step 3.7: Create TCP transport (if configured, always in TransportMode::Tcp)
+        // Note: the length-prefix framing is HDDS-to-HDDS only; TcpFraming::Rti
+        // speaks the RTI Connext RTPS-over-TCP wire format
+        let tcp_required = self.transport_mode == TransportMode::Tcp
+            || self.transport_preference == TransportPreference::TcpOnly;
+        let tcp_config = match self.tcp_config {
+            Some(config) if self.transport_mode == TransportMode::Tcp => Some(TcpConfig {
+                enabled: true,
+                ..config
+            }),
+            None if self.transport_mode == TransportMode::Tcp => Some(TcpConfig::enabled()),
+            other => other,
+        };
+        let tcp_transport = if let Some(tcp_config) = tcp_config {
+            if tcp_config.enabled {
+                log::info!(
+                    "[hdds] Creating TCP transport (port={}, role={:?}, framing={:?})",
+                    tcp_config.listen_port,
+                    tcp_config.role,
+                    tcp_config.framing
+                );
+
+                match TcpTransport::new(guid.prefix, tcp_config) {
+                    Ok(tcp) => {
+                        log::info!("[hdds] TCP transport ready");
+                        Some(Arc::new(tcp))
+                    }
+                    Err(e) => {
+                        log::error!("[hdds] Failed to create TCP transport: {}", e);
+                        // TCP is optional unless it carries all traffic
+                        if tcp_required {
+                            return Err(crate::dds::Error::IoError(e));
+                        }
+                        None
+                    }
+                }
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        // Step 4-5: Setup transport and discovery (UdpMulticast, Tcp or LoopbackBus mode)
+        // In Tcp mode the UDP transport is relayed: every send lands on a
+        // loopback relay socket that the TCP relay thread (Step 9.6) drains.
+        // In LoopbackBus mode it is socket-free: sends go through the bus port.
+        let mut tcp_relay_socket: Option<std::net::UdpSocket> = None;
         let (transport, discovery_components) = match self.transport_mode {
             TransportMode::IntraProcess => {
                 // No transport or discovery for intra-process mode
@@ -210,8 +314,10 @@ impl ParticipantBuilder {
                     },
                 )
             }
-            TransportMode::UdpMulticast => {
-                let mapping = port_mapping.ok_or(crate::dds::Error::Config)?;
+            TransportMode::UdpMulticast | TransportMode::Tcp | TransportMode::LoopbackBus => {
+                let mapping = port_mapping.ok_or_else(|| {
+                    crate::dds::Error::config("network transport without a port mapping")
+                })?;
 
                 log::debug!(
                     "[hdds] Creating UDP transport domain={} pid={}",
@@ -225,13 +331,60 @@ impl ParticipantBuilder {
                 // Race window is minimal since we're in single-threaded init.
                 drop(_port_reservation);
 
-                let transport = Arc::new(
-                    UdpTransport::new(self.domain_id, actual_participant_id, mapping)
+                let transport = if let Some(port) = loopback_port.take() {
+                    Arc::new(UdpTransport::loopback(self.domain_id, port))
+                } else if self.transport_mode == TransportMode::Tcp {
+                    let relay_socket = std::net::UdpSocket::bind((Ipv4Addr::LOCALHOST, 0))
+                        .map_err(crate::dds::Error::IoError)?;
+                    let relay_addr = relay_socket
+                        .local_addr()
+                        .map_err(crate::dds::Error::IoError)?;
+                    tcp_relay_socket = Some(relay_socket);
+                    Arc::new(
+                        UdpTransport::relayed(
+                            self.domain_id,
+                            actual_participant_id,
+                            mapping,
+                            relay_addr,
+                        )
                         .map_err(crate::dds::Error::IoError)?,
-                );
+                    )
+                } else {
+                    Arc::new(
+                        UdpTransport::with_interface_filter(
+                            self.domain_id,
+                            actual_participant_id,
+                            mapping,
+                            ip_mode,
+                            self.interface_filter
+                                .clone()
+                                .unwrap_or_else(crate::transport::InterfaceFilter::from_env),
+                        )
+                        .map_err(crate::dds::Error::IoError)?,
+                    )
+                };
 
                 log::debug!("[hdds] UDP transport ready");
 
+                // Packet taps (and the HDDS_PCAP capture) must be installed
+                // before the listeners start
+                let mut packet_taps = self.packet_taps.clone();
+                if let Some(path) = std::env::var_os(crate::transport::tap::PCAP_ENV) {
+                    let tap = crate::transport::tap::pcapng_file_tap(&path).map_err(|e| {
+                        crate::dds::Error::IoError(e).with_context(
+                            crate::dds::ErrorContext::new().path(path.to_string_lossy()),
+                        )
+                    })?;
+                    log::info!(
+                        "[hdds] Capturing RTPS traffic to {}",
+                        path.to_string_lossy()
+                    );
+                    packet_taps.push(tap);
+                }
+                if let Some(tap) = crate::transport::tap::combine(packet_taps) {
+                    transport.set_packet_tap(tap);
+                }
+
                 #[cfg(feature = "type-lookup")]
                 let type_lookup_config = discovery_setup::TypeLookupConfig {
                     registered_types: self.registered_types.clone(),
@@ -250,6 +403,10 @@ impl ParticipantBuilder {
                     mapping,
                     dialect_detector.clone(),
                     type_lookup_config,
+                    self.rx_worker_config.clone(),
+                    self.user_data.clone(),
+                    Arc::clone(&self.clock),
+                    self.nat_keepalive.is_some(),
                     #[cfg(feature = "security")]
                     security_suite.clone(),
                 )
@@ -259,6 +416,14 @@ impl ParticipantBuilder {
             }
         };
 
+        // Step 5.4: Forward discovery events to the application listener
+        if let (Some(listener), Some(fsm)) = (&self.listener, &discovery_components.discovery_fsm) {
+            fsm.register_listener(Arc::new(ParticipantDiscoveryListener::new(
+                Arc::clone(listener),
+                guid,
+            )));
+        }
+
         // Step 5.5: Register static peers (for unicast without discovery)
         // Note: add_static_peer() is for UDP SPDP discovery. For TCP-only mode,
         // use TcpConfig::initial_peers instead.
@@ -270,13 +435,13 @@ impl ParticipantBuilder {
                 }
             } else {
                 // Provide helpful guidance based on configuration
-                let has_tcp = self.tcp_config.as_ref().is_some_and(|c| c.enabled);
-                if has_tcp {
+                if tcp_transport.is_some() {
                     eprintln!(
-                        "[hdds] Warning: add_static_peer() requires UdpMulticast transport mode.\n\
+                        "[hdds] Warning: add_static_peer() requires a UDP or TCP transport mode.\n\
                          For TCP-only mode, use TcpConfig::initial_peers instead:\n\
                          \n\
-                             .with_tcp(TcpConfig::tcp_only(vec![\"addr:port\".parse().unwrap()]))\n\
+                             .with_transport(TransportMode::Tcp)\n\
+                             .tcp_config(TcpConfig::tcp_only(vec![\"addr:port\".parse().unwrap()]))\n\
                          \n\
                          Current transport mode: {:?}",
                         self.transport_mode
@@ -292,36 +457,83 @@ impl ParticipantBuilder {
             }
         }
 
-        // Step 5.6: Create TCP transport (if configured)
-        // Note: TCP transport is HDDS-to-HDDS only, NOT interoperable with other DDS vendors
-        let tcp_transport = if let Some(tcp_config) = self.tcp_config {
-            if tcp_config.enabled {
-                log::info!(
-                    "[hdds] Creating TCP transport (port={}, role={:?})",
-                    tcp_config.listen_port,
-                    tcp_config.role
-                );
-
-                match TcpTransport::new(guid.prefix, tcp_config) {
-                    Ok(tcp) => {
-                        log::info!("[hdds] TCP transport ready");
-                        Some(Arc::new(tcp))
+        // Step 5.65: Create UDS transport (if configured or preferred)
+        // Note: like TCP, UDS is HDDS-to-HDDS only
+        #[cfg(unix)]
+        let uds_transport = {
+            let uds_config = match self.uds_config {
+                Some(config) if config.enabled => Some(config),
+                Some(_) => None,
+                None if self.transport_preference.prefers_uds() => Some(UdsConfig::enabled()),
+                None => None,
+            };
+            match uds_config {
+                Some(config) if self.transport_mode == TransportMode::UdpMulticast => {
+                    log::info!(
+                        "[hdds] Creating UDS transport (dir={})",
+                        config.socket_dir.display()
+                    );
+                    match UdsTransport::new(self.domain_id, guid.prefix, config) {
+                        Ok(uds) => {
+                            log::info!(
+                                "[hdds] UDS transport ready at {}",
+                                uds.local_path().display()
+                            );
+                            Some(Arc::new(uds))
+                        }
+                        Err(e) => {
+                            // UDS is an optimization - fall back to UDP
+                            log::error!("[hdds] Failed to create UDS transport: {}", e);
+                            None
+                        }
                     }
+                }
+                Some(_) => {
+                    log::warn!(
+                        "[hdds] UDS configured but transport mode is {:?}, skipping",
+                        self.transport_mode
+                    );
+                    None
+                }
+                None => None,
+            }
+        };
+        #[cfg(not(unix))]
+        if self.uds_config.as_ref().is_some_and(|c| c.enabled)
+            || self.transport_preference.prefers_uds()
+        {
+            log::warn!("[hdds] UDS transport is only available on Unix, using UDP");
+        }
+
+        // Step 5.66: Create RDMA transport (if configured, experimental)
+        // Note: like TCP, RDMA is HDDS-to-HDDS only
+        #[cfg(feature = "rdma")]
+        let rdma_transport = match self.rdma_config {
+            Some(config) if config.enabled => open_rdma_transport(config),
+            _ => None,
+        };
+
+        // Step 5.67: Bind AF_XDP receive socket (if configured, experimental)
+        #[cfg(all(feature = "af-xdp", target_os = "linux"))]
+        let xdp_socket = match (self.xdp_config, port_mapping) {
+            (Some(config), Some(mapping)) if config.enabled => {
+                match crate::transport::xdp::XdpSocket::open(config, mapping.user_unicast) {
+                    Ok(xdp) => Some(Arc::new(xdp)),
                     Err(e) => {
-                        log::error!("[hdds] Failed to create TCP transport: {}", e);
-                        // TCP is optional - continue without it
-                        // For tcp_only mode, this would be an error, but we handle that gracefully
-                        if self.transport_preference == TransportPreference::TcpOnly {
-                            return Err(crate::dds::Error::IoError(e));
-                        }
+                        // AF_XDP is an optimization - the kernel UDP path still works
+                        log::error!("[hdds] Failed to set up AF_XDP receive path: {}", e);
                         None
                     }
                 }
-            } else {
+            }
+            (Some(config), None) if config.enabled => {
+                log::warn!(
+                    "[hdds] AF_XDP configured but transport mode is {:?}, skipping",
+                    self.transport_mode
+                );
                 None
             }
-        } else {
-            None
+            _ => None,
         };
 
         // Store transport preference
@@ -351,6 +563,20 @@ impl ParticipantBuilder {
         };
 
         // Step 6: Spawn background threads (SPDP announcer, lease tracker)
+        // Initial peers are unicast SPDP destinations of the UDP announcer
+        let initial_peers = if self.transport_mode == TransportMode::UdpMulticast {
+            self.initial_peers
+        } else {
+            if !self.initial_peers.is_empty() {
+                log::warn!(
+                    "[hdds] with_initial_peers() requires TransportMode::UdpMulticast (current: {:?}); \
+                     ignoring {} peer(s). For TCP, use TcpConfig::initial_peers instead.",
+                    self.transport_mode,
+                    self.initial_peers.len()
+                );
+            }
+            Vec::new()
+        };
         let participant_threads = threads::spawn_participant_threads(
             guid,
             metrics.clone(),
@@ -358,8 +584,20 @@ impl ParticipantBuilder {
             discovery_components.discovery_fsm.clone(),
             telemetry_thread,
             config.clone(),
+            initial_peers,
+            self.user_data.clone(),
+            self.nat_keepalive,
         );
 
+        // Step 6.5: Watch the security documents for hot reload (if configured)
+        #[cfg(feature = "security")]
+        let security_watcher = security_suite.as_ref().and_then(|suite| {
+            super::super::security_reload::start_watcher(
+                suite,
+                discovery_components.discovery_fsm.as_ref(),
+            )
+        });
+
         // Step 7: Create type cache (if xtypes feature enabled)
         #[cfg(feature = "xtypes")]
         let type_cache = Arc::new(TypeCache::new(self.type_cache_capacity));
@@ -378,15 +616,27 @@ impl ParticipantBuilder {
             None
         };
 
-        // Step 9.5: Sprint 7 - Spawn unicast routing thread if TCP or QUIC configured
+        // Step 9.5: Sprint 7 - Spawn unicast routing thread if TCP, UDS, QUIC, RDMA or AF_XDP configured
         let unicast_routing_thread = {
-            let has_tcp = tcp_transport.is_some();
+            let has_tcp = tcp_transport.is_some() && tcp_relay_socket.is_none();
+            #[cfg(unix)]
+            let has_uds = uds_transport.is_some();
+            #[cfg(not(unix))]
+            let has_uds = false;
             #[cfg(feature = "quic")]
             let has_quic = quic_io_thread.is_some();
             #[cfg(not(feature = "quic"))]
             let has_quic = false;
-
-            if has_tcp || has_quic {
+            #[cfg(feature = "rdma")]
+            let has_rdma = rdma_transport.is_some();
+            #[cfg(not(feature = "rdma"))]
+            let has_rdma = false;
+            #[cfg(all(feature = "af-xdp", target_os = "linux"))]
+            let has_xdp = xdp_socket.is_some();
+            #[cfg(not(all(feature = "af-xdp", target_os = "linux")))]
+            let has_xdp = false;
+
+            if has_tcp || has_uds || has_quic || has_rdma || has_xdp {
                 #[cfg(feature = "quic")]
                 let quic_h = quic_io_thread.as_ref().map(|q| q.handle());
 
@@ -394,9 +644,16 @@ impl ParticipantBuilder {
                     (&discovery_components.registry, &discovery_components.router)
                 {
                     Some(super::unicast_routing::spawn(
-                        tcp_transport.clone(),
+                        // In Tcp mode the relay thread owns the TCP receive path
+                        tcp_transport.clone().filter(|_| has_tcp),
+                        #[cfg(unix)]
+                        uds_transport.clone(),
                         #[cfg(feature = "quic")]
                         quic_h,
+                        #[cfg(feature = "rdma")]
+                        rdma_transport.clone(),
+                        #[cfg(all(feature = "af-xdp", target_os = "linux"))]
+                        xdp_socket.clone(),
                         Arc::clone(reg),
                         Arc::clone(&rtr.metrics),
                         Arc::new(AtomicBool::new(false)),
@@ -409,6 +666,29 @@ impl ParticipantBuilder {
             }
         };
 
+        // Step 9.6: Spawn the TCP relay thread (TransportMode::Tcp)
+        let tcp_relay_thread = match (tcp_relay_socket, &tcp_transport, &transport) {
+            (Some(relay_socket), Some(tcp), Some(udp)) => {
+                let deliver_to = udp
+                    .metatraffic_unicast_socket()
+                    .ok_or_else(|| {
+                        crate::dds::Error::config("TCP relay needs a metatraffic unicast socket")
+                    })?
+                    .local_addr()
+                    .map_err(crate::dds::Error::IoError)?;
+                Some(
+                    super::tcp_relay::spawn(Arc::clone(tcp), relay_socket, deliver_to)
+                        .map_err(crate::dds::Error::IoError)?,
+                )
+            }
+            (Some(_), None, _) => {
+                return Err(crate::dds::Error::config(
+                    "TCP relay socket without a TCP transport",
+                ))
+            }
+            _ => None,
+        };
+
         // Step 10: v233 - Spawn cloud discovery poller if configured
         #[cfg(feature = "cloud-discovery")]
         let cloud_discovery_poller = if let Some(ref provider) = self.cloud_discovery_provider {
@@ -439,8 +719,26 @@ impl ParticipantBuilder {
             None
         };
 
+        // Step 10b: Embedded Discovery Server (active, or standby if the port is taken)
+        #[cfg(feature = "discovery-server")]
+        let (embedded_discovery_server, discovery_server_config) = match self
+            .embedded_discovery_server
+            .clone()
+        {
+            Some(config) => {
+                let (server, client_config) =
+                    start_embedded_discovery_server(config, self.discovery_server_config.clone())?;
+                (Some(server), Some(client_config))
+            }
+            None => (None, self.discovery_server_config.clone()),
+        };
+
         // Step 11: Construct and return Participant (wrapped in Arc)
         let graph_guard = Arc::new(GuardCondition::new());
+        let graph_events = Arc::new(GraphEvents::new(Arc::clone(&graph_guard)));
+        if let Some(fsm) = &discovery_components.discovery_fsm {
+            fsm.register_listener(Arc::new(GraphDiscoveryListener::new(&graph_events)));
+        }
 
         Ok(Arc::new(Participant {
             name: self.name,
@@ -451,11 +749,20 @@ impl ParticipantBuilder {
             port_mapping,
             transport,
             tcp_transport,
+            #[cfg(unix)]
+            uds_transport,
+            #[cfg(feature = "rdma")]
+            rdma_transport,
+            #[cfg(all(feature = "af-xdp", target_os = "linux"))]
+            xdp_socket,
             transport_preference,
             #[cfg(feature = "quic")]
             quic_config: self.quic_config,
             lowbw_config: self.lowbw_config,
-            discovery_server_config: self.discovery_server_config,
+            #[cfg(feature = "discovery-server")]
+            discovery_server_config,
+            #[cfg(feature = "discovery-server")]
+            embedded_discovery_server,
             #[cfg(feature = "cloud-discovery")]
             cloud_discovery_provider: self.cloud_discovery_provider,
             #[cfg(feature = "cloud-discovery")]
@@ -464,14 +771,17 @@ impl ParticipantBuilder {
             registry: discovery_components.registry,
             router: discovery_components.router,
             discovery_fsm: discovery_components.discovery_fsm,
-            _spdp_announcer: participant_threads.spdp_announcer,
+            spdp_announcer: parking_lot::Mutex::new(participant_threads.spdp_announcer),
             lease_tracker: participant_threads.lease_tracker,
+            peer_keepalive: participant_threads.peer_keepalive,
             _control_handler: discovery_components.control_handler, // v230: prevent Drop
             _listeners: discovery_components.listeners,             // v230: prevent Drop
             sedp_announcements: sedp_cache,
             telemetry_shutdown: participant_threads.telemetry_shutdown,
             telemetry_handle: participant_threads.telemetry_handle,
             graph_guard,
+            graph_events,
+            discovery_events: std::sync::OnceLock::new(),
             dialect_detector,
             next_entity_key: AtomicU32::new(0),
             domain_state,
@@ -484,6 +794,8 @@ impl ParticipantBuilder {
             #[cfg(feature = "xtypes")]
             distro: self.distro,
             #[cfg(feature = "security")]
+            security_watcher,
+            #[cfg(feature = "security")]
             security: security_suite,
             #[cfg(feature = "k8s")]
             k8s_discovery_handle,
@@ -492,6 +804,84 @@ impl ParticipantBuilder {
             #[cfg(feature = "cloud-discovery")]
             cloud_discovery_poller,
             _unicast_routing_thread: unicast_routing_thread,
+            _tcp_relay_thread: tcp_relay_thread,
+            static_plan,
+            hibernator: self.hibernation.map(crate::dds::Hibernator::spawn),
+            endpoint_hooks: self.endpoint_hooks,
+            suspended: AtomicBool::new(false),
+            shut_down: AtomicBool::new(false),
+            build_pid: std::process::id(),
+            build_config,
         }))
     }
 }
+
+/// Start the embedded Discovery Server and derive this participant's client
+/// config.
+///
+/// When another server already holds the port on this host, a standby server
+/// is started on an ephemeral port and announced to the active one, which
+/// redirects its clients there when it shuts down.
+#[cfg(feature = "discovery-server")]
+fn start_embedded_discovery_server(
+    config: EmbeddedServerConfig,
+    client_config: Option<DiscoveryServerConfig>,
+) -> Result<(EmbeddedDiscoveryServer, DiscoveryServerConfig)> {
+    let bind_failed = |port: u16, e: std::io::Error| {
+        crate::dds::Error::BindFailed(format!("discovery server port {}: {}", port, e))
+    };
+
+    match EmbeddedDiscoveryServer::bind(config.clone()) {
+        Ok(server) => {
+            let active = SocketAddr::from((Ipv4Addr::LOCALHOST, server.local_addr().port()));
+            let client_config = client_config.unwrap_or_else(|| DiscoveryServerConfig::new(active));
+            Ok((server, client_config))
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::AddrInUse => {
+            log::info!(
+                "[hdds] Discovery server port {} already served on this host, starting standby",
+                config.port
+            );
+            let active = SocketAddr::from((Ipv4Addr::LOCALHOST, config.port));
+            let standby = EmbeddedDiscoveryServer::bind(EmbeddedServerConfig { port: 0, ..config })
+                .map_err(|e| bind_failed(0, e))?;
+            let client_config = client_config
+                .unwrap_or_else(|| DiscoveryServerConfig::new(active))
+                .with_standby_port(standby.local_addr().port());
+            Ok((standby, client_config))
+        }
+        Err(e) => Err(bind_failed(config.port, e)),
+    }
+}
+
+/// Open the RDMA transport; RDMA is an optimization, so failures are logged
+/// and the participant continues on UDP.
+#[cfg(feature = "rdma")]
+fn open_rdma_transport(
+    config: crate::transport::rdma::RdmaConfig,
+) -> Option<Arc<crate::transport::rdma::RdmaTransport>> {
+    #[cfg(target_os = "linux")]
+    {
+        match crate::transport::rdma::RdmaTransport::open(config) {
+            Ok(rdma) => {
+                log::info!(
+                    "[hdds] RDMA transport ready on {} ({} slots x {} bytes)",
+                    rdma.device_name(),
+                    rdma.pool().capacity(),
+                    rdma.pool().slot_size()
+                );
+                Some(Arc::new(rdma))
+            }
+            Err(e) => {
+                log::error!("[hdds] Failed to create RDMA transport: {}", e);
+                None
+            }
+        }
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = config;
+        log::warn!("[hdds] RDMA transport is only available on Linux, using UDP");
+        None
+    }
+}
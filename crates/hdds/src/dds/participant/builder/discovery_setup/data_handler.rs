@@ -115,6 +115,14 @@ pub(super) fn handle_data_packet(
                     sedp_data.topic_name
                 );
                 registry.register_writer_guid(endpoint_guid_bytes, sedp_data.topic_name.clone());
+                registry.set_writer_partition(
+                    endpoint_guid_bytes,
+                    sedp_data
+                        .qos
+                        .as_ref()
+                        .map(|q| q.partition.clone())
+                        .unwrap_or_default(),
+                );
             } else {
                 log::debug!("[callback] >>  Reader endpoint - not registering writer mapping");
             }
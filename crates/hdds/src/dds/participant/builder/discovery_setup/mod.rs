@@ -30,13 +30,16 @@ use fragment_handler::handle_fragment;
 use heartbeat_handler::handle_heartbeat_packet;
 use sedp_handler::handle_sedp_packet;
 use spdp_handler::handle_spdp_packet;
+pub(in crate::dds::participant) use spdp_handler::rearm_sedp_reannounce;
 pub(super) use type_lookup_handler::TypeLookupConfig;
 #[cfg(feature = "type-lookup")]
 use type_lookup_handler::TypeLookupService;
 use type_lookup_handler::{handle_type_lookup_packet, TypeLookupHandle};
 
 use super::entity_registry::SedpAnnouncementsCache;
-use super::sockets::{create_data_multicast_socket, create_unicast_socket};
+use super::sockets::{
+    create_data_multicast_socket, create_unicast_socket, create_unicast_socket_v6,
+};
 use crate::config::{
     FRAGMENT_BUFFER_SIZE, FRAGMENT_TIMEOUT_MS, MAX_PACKET_SIZE, PARTICIPANT_LEASE_DURATION_MS,
     RX_POOL_SIZE, RX_RING_SIZE,
@@ -82,11 +85,16 @@ pub(super) struct DiscoveryComponents {
 /// - `sedp_cache`: SEDP announcements cache
 /// - `mapping`: Port mapping for multicast/unicast ports
 /// - `dialect_detector`: Dialect detector for SPDP packet monitoring (Phase 1.6)
+/// - `rx_workers`: Optional receive worker pool for large samples
+/// - `user_data`: USER_DATA of the participant, sent in SPDP replies
+/// - `clock`: Time source of participant leases
+/// - `follow_reflexive_addresses`: Reach peers behind a NAT at their SPDP source address
 /// - `security_suite`: Optional security plugin suite for participant authentication (DDS Security v1.1)
 ///
 /// # Returns
 /// DiscoveryComponents with FSM, registry, and router
 #[allow(unused_variables)] // security_suite unused when feature disabled
+#[allow(clippy::too_many_arguments)]
 pub(super) fn setup_discovery(
     guid: GUID,
     transport: Arc<UdpTransport>,
@@ -96,6 +104,10 @@ pub(super) fn setup_discovery(
         std::sync::Mutex<crate::core::discovery::multicast::dialect_detector::DialectDetector>,
     >,
     type_lookup_config: TypeLookupConfig,
+    rx_workers: Option<crate::engine::RxWorkerConfig>,
+    user_data: crate::qos::metadata::UserData,
+    clock: Arc<dyn crate::core::rt::clock::Clock>,
+    follow_reflexive_addresses: bool,
     #[cfg(feature = "security")] security_suite: Option<Arc<SecurityPluginSuite>>,
 ) -> std::io::Result<DiscoveryComponents> {
     log::debug!("[hdds] Setting up discovery subsystem");
@@ -107,8 +119,10 @@ pub(super) fn setup_discovery(
             .map_err(|_| std::io::Error::other("Failed to create RxPool"))?,
     );
 
-    #[allow(unused_mut)] // mut needed when security feature is enabled
     let mut discovery_fsm = DiscoveryFsm::new(guid, PARTICIPANT_LEASE_DURATION_MS);
+    discovery_fsm.set_local_user_data(user_data);
+    discovery_fsm.set_clock(clock);
+    discovery_fsm.set_follow_reflexive_addresses(follow_reflexive_addresses);
 
     // DDS Security v1.1: Connect AuthenticationPlugin to DiscoveryFsm
     // When security is enabled, participants with invalid identity_tokens are rejected.
@@ -321,6 +335,8 @@ pub(super) fn setup_discovery(
     // v210: Create shared WakeNotifier for low-latency router wake
     // All listeners share this notifier to immediately wake the router when data arrives
     let wake_notifier = Arc::new(WakeNotifier::new());
+    // Listeners hand every received datagram to the participant's packet tap
+    let packet_tap = transport.packet_tap();
     log::debug!("[hdds] v210: WakeNotifier created for low-latency routing");
 
     // v230: Collect all listeners to store in DiscoveryComponents (prevent Drop)
@@ -332,16 +348,35 @@ pub(super) fn setup_discovery(
     // This avoids port conflicts and ensures we receive all metatraffic
     // v203: Now uses Two-Ring to bypass HEARTBEAT/ACKNACK pool allocation
     // v210: Now uses WakeNotifier for low-latency router wake
-    let metatraffic_listener =
-        crate::core::discovery::multicast::MulticastListener::spawn_with_notifier(
-            transport.socket(),
-            rx_pool.clone(),
-            rx_ring.clone(),
-            Some(discovery_callback.clone()),
-            control_tx.clone(),          // v203: All listeners use Two-Ring
-            Some(wake_notifier.clone()), // v210: WakeNotifier for low-latency
-        )?;
-    listeners.push(metatraffic_listener);
+    if let Some(socket) = transport.socket() {
+        let metatraffic_listener =
+            crate::core::discovery::multicast::MulticastListener::spawn_with_tap(
+                socket,
+                rx_pool.clone(),
+                rx_ring.clone(),
+                Some(discovery_callback.clone()),
+                control_tx.clone(),          // v203: All listeners use Two-Ring
+                Some(wake_notifier.clone()), // v210: WakeNotifier for low-latency
+                packet_tap.clone(),
+            )?;
+        listeners.push(metatraffic_listener);
+    }
+
+    // A loopback bus transport has no socket: a single listener drains its
+    // in-memory port (multicast and unicast alike)
+    if let Some(port) = transport.loopback_port() {
+        listeners.push(
+            crate::core::discovery::multicast::MulticastListener::spawn_loopback(
+                port,
+                rx_pool.clone(),
+                rx_ring.clone(),
+                Some(discovery_callback.clone()),
+                control_tx.clone(),
+                Some(wake_notifier.clone()),
+                packet_tap.clone(),
+            )?,
+        );
+    }
 
     // Phase 1.6: UNICAST listener (port 7410 for domain 0)
     // RTI/HDDS sends SEDP and Temperature data to this unicast address
@@ -359,94 +394,133 @@ pub(super) fn setup_discovery(
         "[hdds] v133: Reusing transport metatraffic_unicast_socket for SEDP reception (port {})",
         mapping.metatraffic_unicast
     );
-    let unicast_socket = transport.metatraffic_unicast_socket();
+    if let Some(unicast_socket) = transport.metatraffic_unicast_socket() {
+        // v203: Now uses Two-Ring to bypass HEARTBEAT/ACKNACK pool allocation
+        // v210: Now uses WakeNotifier for low-latency router wake
+        let unicast_listener =
+            crate::core::discovery::multicast::MulticastListener::spawn_with_tap(
+                unicast_socket,  // v133: Use transport's socket directly for both send AND recv
+                rx_pool.clone(), // Reuse same pool
+                rx_ring.clone(), // Reuse same ring -> goes to DemuxRouter!
+                Some(discovery_callback.clone()),
+                control_tx.clone(),          // v203: All listeners use Two-Ring
+                Some(wake_notifier.clone()), // v210: WakeNotifier for low-latency
+                packet_tap.clone(),
+            )?;
+        listeners.push(unicast_listener);
+    }
 
-    // v203: Now uses Two-Ring to bypass HEARTBEAT/ACKNACK pool allocation
-    // v210: Now uses WakeNotifier for low-latency router wake
-    let unicast_listener =
-        crate::core::discovery::multicast::MulticastListener::spawn_with_notifier(
-            unicast_socket,  // v133: Use transport's socket directly for both send AND recv
-            rx_pool.clone(), // Reuse same pool
-            rx_ring.clone(), // Reuse same ring -> goes to DemuxRouter!
-            Some(discovery_callback.clone()),
-            control_tx.clone(),          // v203: All listeners use Two-Ring
-            Some(wake_notifier.clone()), // v210: WakeNotifier for low-latency
-        )?;
-    listeners.push(unicast_listener);
+    // Dual-stack: the IPv6 metatraffic sockets (multicast + unicast) need their own listeners
+    for socket in transport.dual_stack_sockets() {
+        listeners.push(
+            crate::core::discovery::multicast::MulticastListener::spawn_with_tap(
+                socket,
+                rx_pool.clone(),
+                rx_ring.clone(),
+                Some(discovery_callback.clone()),
+                control_tx.clone(),
+                Some(wake_notifier.clone()),
+                packet_tap.clone(),
+            )?,
+        );
+    }
 
     log::debug!("[hdds] [OK] Unicast listener ready - RTI/HDDS can now send us SEDP/data!");
 
-    // Phase v58: USER DATA listener (port 7411 for domain 0)
-    // RTI sends user data (Temperature, etc.) to this port per RTPS v2.5
-    // CRITICAL for RTI interop: must listen where SEDP announced
-    log::debug!(
-        "[hdds] Creating user data listener on port {} for RTI interop",
-        mapping.user_unicast
-    );
-    let user_socket = create_unicast_socket(mapping.user_unicast)?;
-
-    // v201/v202/v203: Pass discovery callback AND control channel to user data listener
-    // With Two-Ring: HEARTBEATs/ACKNACKs bypass pool, DATA goes to ring
-    // Without Two-Ring: Legacy synchronous callback handles HEARTBEATs
-    // v210: WakeNotifier for low-latency router wake
-    let user_data_listener =
-        crate::core::discovery::multicast::MulticastListener::spawn_with_notifier(
-            Arc::new(user_socket),
-            rx_pool.clone(),                  // Reuse same pool
-            rx_ring.clone(),                  // Reuse same ring -> goes to DemuxRouter!
-            Some(discovery_callback.clone()), // For SPDP/SEDP on this port (rare but possible)
-            control_tx,                       // v203: Control channel for HEARTBEATs/ACKNACKs
-            Some(wake_notifier.clone()),      // v210: WakeNotifier for low-latency
-        )?;
-    listeners.push(user_data_listener);
+    // A relayed (TCP mode) transport receives everything from its relay on
+    // the metatraffic unicast socket, a loopback bus one from its port;
+    // nothing may be bound on the network.
+    if transport.relay_addr().is_none() && transport.loopback_port().is_none() {
+        // Phase v58: USER DATA listener (port 7411 for domain 0)
+        // RTI sends user data (Temperature, etc.) to this port per RTPS v2.5
+        // CRITICAL for RTI interop: must listen where SEDP announced
+        log::debug!(
+            "[hdds] Creating user data listener on port {} for RTI interop",
+            mapping.user_unicast
+        );
+        let ip_mode = transport.ip_mode();
+        let mut user_sockets = Vec::with_capacity(2);
+        if ip_mode.uses_v4() {
+            user_sockets.push(create_unicast_socket(mapping.user_unicast)?);
+        }
+        if ip_mode.uses_v6() {
+            user_sockets.push(create_unicast_socket_v6(mapping.user_unicast)?);
+        }
 
-    log::debug!(
-        "[hdds] [OK] User data listener ready on port {}{}!",
-        mapping.user_unicast,
-        if ENABLE_TWO_RING_CONTROL {
-            " (Two-Ring enabled on ALL listeners)"
-        } else {
-            ""
+        // v201/v202/v203: Pass discovery callback AND control channel to user data listener
+        // With Two-Ring: HEARTBEATs/ACKNACKs bypass pool, DATA goes to ring
+        // Without Two-Ring: Legacy synchronous callback handles HEARTBEATs
+        // v210: WakeNotifier for low-latency router wake
+        for user_socket in user_sockets {
+            let user_data_listener =
+                crate::core::discovery::multicast::MulticastListener::spawn_with_tap(
+                    Arc::new(user_socket),
+                    rx_pool.clone(),                  // Reuse same pool
+                    rx_ring.clone(),                  // Reuse same ring -> goes to DemuxRouter!
+                    Some(discovery_callback.clone()), // For SPDP/SEDP on this port (rare but possible)
+                    control_tx.clone(), // v203: Control channel for HEARTBEATs/ACKNACKs
+                    Some(wake_notifier.clone()), // v210: WakeNotifier for low-latency
+                    packet_tap.clone(),
+                )?;
+            listeners.push(user_data_listener);
         }
-    );
 
-    // v240: DATA MULTICAST listener (port 7401 for domain 0)
-    // CycloneDDS and FastDDS send user data to 239.255.0.1:7401 when unicast readers
-    // are not yet discovered. This is a non-standard but widely-used extension.
-    // Without this listener, HDDS cannot receive user data from CycloneDDS until
-    // both sides have exchanged SEDP endpoint info.
-    let data_multicast_port = mapping.metatraffic_multicast + crate::config::DATA_MULTICAST_OFFSET;
-    log::debug!(
-        "[hdds] v240: Creating data multicast listener on port {} for CycloneDDS/FastDDS interop",
-        data_multicast_port
-    );
-    let data_multicast_socket = create_data_multicast_socket(mapping.metatraffic_multicast)?;
-
-    let data_multicast_listener =
-        crate::core::discovery::multicast::MulticastListener::spawn_with_notifier(
-            Arc::new(data_multicast_socket),
-            rx_pool.clone(),                  // Reuse same pool
-            rx_ring.clone(),                  // Reuse same ring -> goes to DemuxRouter!
-            Some(discovery_callback.clone()), // For any discovery packets on this port
-            None,                             // v240: No control channel needed for data multicast
-            Some(wake_notifier.clone()),      // v210: WakeNotifier for low-latency
-        )?;
-    listeners.push(data_multicast_listener);
+        log::debug!(
+            "[hdds] [OK] User data listener ready on port {}{}!",
+            mapping.user_unicast,
+            if ENABLE_TWO_RING_CONTROL {
+                " (Two-Ring enabled on ALL listeners)"
+            } else {
+                ""
+            }
+        );
 
-    log::debug!(
-        "[hdds] [OK] Data multicast listener ready on port {} (CycloneDDS interop)!",
-        data_multicast_port
-    );
+        // v240: DATA MULTICAST listener (port 7401 for domain 0)
+        // CycloneDDS and FastDDS send user data to 239.255.0.1:7401 when unicast readers
+        // are not yet discovered. This is a non-standard but widely-used extension.
+        // Without this listener, HDDS cannot receive user data from CycloneDDS until
+        // both sides have exchanged SEDP endpoint info. IPv4 only.
+        if ip_mode.uses_v4() {
+            let data_multicast_port =
+                mapping.metatraffic_multicast + crate::config::DATA_MULTICAST_OFFSET;
+            log::debug!(
+                "[hdds] v240: Creating data multicast listener on port {} for CycloneDDS/FastDDS interop",
+                data_multicast_port
+            );
+            let data_multicast_socket = create_data_multicast_socket(
+                mapping.metatraffic_multicast,
+                transport.interface_filter(),
+            )?;
+
+            let data_multicast_listener =
+                crate::core::discovery::multicast::MulticastListener::spawn_with_tap(
+                    Arc::new(data_multicast_socket),
+                    rx_pool.clone(),                  // Reuse same pool
+                    rx_ring.clone(),                  // Reuse same ring -> goes to DemuxRouter!
+                    Some(discovery_callback.clone()), // For any discovery packets on this port
+                    None, // v240: No control channel needed for data multicast
+                    Some(wake_notifier.clone()), // v210: WakeNotifier for low-latency
+                    packet_tap.clone(),
+                )?;
+            listeners.push(data_multicast_listener);
+
+            log::debug!(
+                "[hdds] [OK] Data multicast listener ready on port {} (CycloneDDS interop)!",
+                data_multicast_port
+            );
+        }
+    }
 
     // Start router with transport for NACK_FRAG support
     // v210: Use WakeNotifier for low-latency packet routing
-    let router = DemuxRouter::start_with_notifier(
+    let router = DemuxRouter::start_with_rx_workers(
         rx_ring,
         rx_pool,
         registry.clone(),
         Some(transport.clone()),
         guid.prefix,
         Some(wake_notifier), // v210: WakeNotifier for low-latency
+        rx_workers,
     )?;
 
     log::debug!("[hdds] Discovery subsystem setup complete");
@@ -14,7 +14,8 @@
 use super::type_lookup_handler::{maybe_request_type_object, TypeLookupHandle};
 use crate::core::discovery::multicast::{
     build_heartbeat_submessage_final, build_sedp_rtps_packet, next_subscriptions_seq,
-    rtps_packet::SEDP_HEARTBEAT_COUNT, DiscoveryFsm, EndpointKind, SedpEndpointKind,
+    parse_dispose_rtps_packet, rtps_packet::SEDP_HEARTBEAT_COUNT, DiscoveryFsm, EndpointKind,
+    SedpEndpointKind,
 };
 use crate::core::reader::ReaderProxyRegistry;
 use crate::engine::TopicRegistry;
@@ -109,6 +110,14 @@ pub(super) fn handle_sedp_packet(
         }
     }
 
+    // Endpoint deleted by a peer (or its shutdown): drop it now
+    if let Some(guid) = parse_dispose_rtps_packet(payload) {
+        if guid.prefix != our_guid_prefix {
+            fsm.dispose_endpoint(guid);
+        }
+        return;
+    }
+
     // v125: Extract CDR payload using offset from classifier
     let cdr_payload = if cdr_offset < payload.len() {
         log::debug!(
@@ -153,6 +162,14 @@ pub(super) fn handle_sedp_packet(
                     sedp_data.topic_name
                 );
                 registry.register_writer_guid(endpoint_guid_bytes, sedp_data.topic_name.clone());
+                registry.set_writer_partition(
+                    endpoint_guid_bytes,
+                    sedp_data
+                        .qos
+                        .as_ref()
+                        .map(|q| q.partition.clone())
+                        .unwrap_or_default(),
+                );
 
                 // v187: Re-announce our Reader endpoints for the same topic if dialect requires it.
                 // OpenDDS has a peculiar state machine where it won't trigger PUBLICATION_MATCHED
@@ -355,6 +372,14 @@ pub(super) fn handle_sedp_from_single_fragment(
                     sedp_data.topic_name
                 );
                 registry.register_writer_guid(endpoint_guid_bytes, sedp_data.topic_name.clone());
+                registry.set_writer_partition(
+                    endpoint_guid_bytes,
+                    sedp_data
+                        .qos
+                        .as_ref()
+                        .map(|q| q.partition.clone())
+                        .unwrap_or_default(),
+                );
             } else {
                 log::debug!("[callback] >>  Reader endpoint - not registering writer mapping");
             }
@@ -399,6 +424,14 @@ pub(super) fn handle_sedp_from_fragments(
                     sedp_data.topic_name
                 );
                 registry.register_writer_guid(endpoint_guid_bytes, sedp_data.topic_name.clone());
+                registry.set_writer_partition(
+                    endpoint_guid_bytes,
+                    sedp_data
+                        .qos
+                        .as_ref()
+                        .map(|q| q.partition.clone())
+                        .unwrap_or_default(),
+                );
             } else {
                 log::debug!("[callback] >>  Reader endpoint - not registering writer mapping");
             }
@@ -18,16 +18,18 @@
 use crate::core::discovery::{
     multicast::{
         build_heartbeat_submessage, build_heartbeat_submessage_final, build_sedp_rtps_packet,
-        build_spdp_rtps_packet, rtps_packet::SEDP_HEARTBEAT_COUNT, DiscoveryFsm, SedpEndpointKind,
+        build_spdp_rtps_packet, parse_dispose_rtps_packet, rtps_packet::SEDP_HEARTBEAT_COUNT,
+        DiscoveryFsm, SedpEndpointKind,
     },
     spdp_announcer::SPDP_SENT_COUNT,
 };
 use crate::protocol::dialect::{get_encoder, Dialect};
 use crate::protocol::discovery::constants::{CDR2_LE, CDR_LE, PID_METATRAFFIC_UNICAST_LOCATOR};
+use crate::protocol::discovery::spdp::parse::decode_locator_ip;
 use crate::protocol::discovery::{parse_spdp, parse_spdp_partial, ParseError, SedpData, SpdpData};
+use crate::qos::metadata::UserData;
 use crate::transport::UdpTransport;
 use std::collections::HashSet;
-use std::net::Ipv4Addr;
 use std::net::SocketAddr;
 use std::sync::atomic::Ordering;
 use std::sync::{Arc, Mutex, OnceLock};
@@ -51,24 +53,44 @@ use super::super::entity_registry::SedpAnnouncementsCache;
 
 /// Tracks active retry threads by peer GUID prefix.
 /// Prevents multiple threads from retrying SEDP to the same peer.
-fn active_retries() -> &'static Mutex<HashSet<[u8; 12]>> {
-    static ACTIVE_RETRIES: OnceLock<Mutex<HashSet<[u8; 12]>>> = OnceLock::new();
+fn active_retries() -> &'static Mutex<HashSet<RetryKey>> {
+    static ACTIVE_RETRIES: OnceLock<Mutex<HashSet<RetryKey>>> = OnceLock::new();
     ACTIVE_RETRIES.get_or_init(|| Mutex::new(HashSet::new()))
 }
 
 /// v210: Tracks peers that completed at least one SEDP reannounce cycle.
 /// Peers in this set will NOT be re-announced on subsequent SPDPs.
-fn completed_peers() -> &'static Mutex<HashSet<[u8; 12]>> {
-    static COMPLETED_PEERS: OnceLock<Mutex<HashSet<[u8; 12]>>> = OnceLock::new();
+fn completed_peers() -> &'static Mutex<HashSet<RetryKey>> {
+    static COMPLETED_PEERS: OnceLock<Mutex<HashSet<RetryKey>>> = OnceLock::new();
     COMPLETED_PEERS.get_or_init(|| Mutex::new(HashSet::new()))
 }
 
+/// Let the next SPDP of each peer trigger a SEDP re-announce cycle again.
+///
+/// Used on resume from suspension: cycles that ran while the transport was
+/// suspended completed without a single datagram leaving the participant.
+pub(in crate::dds::participant) fn rearm_sedp_reannounce(
+    our_guid_prefix: [u8; 12],
+    peers: &[[u8; 12]],
+) {
+    if let Ok(mut completed) = completed_peers().lock() {
+        for peer in peers {
+            completed.remove(&(our_guid_prefix, *peer));
+        }
+    }
+}
+
+/// (local participant, peer) GUID prefixes of a re-announce cycle: several
+/// participants of one process each announce their own endpoints to a peer.
+type RetryKey = ([u8; 12], [u8; 12]);
+
 /// RAII guard for SEDP retry thread deduplication.
 ///
-/// Ensures only one retry thread runs per peer. When dropped, removes the peer
-/// from ACTIVE_RETRIES and adds it to COMPLETED_PEERS to suppress future re-announce.
+/// Ensures only one retry thread runs per (local participant, peer). When
+/// dropped, removes the pair from ACTIVE_RETRIES and adds it to
+/// COMPLETED_PEERS to suppress future re-announce.
 struct RetryGuard {
-    peer_prefix: [u8; 12],
+    key: RetryKey,
 }
 
 impl RetryGuard {
@@ -76,30 +98,31 @@ impl RetryGuard {
     ///
     /// Returns `Some(RetryGuard)` if no other thread is retrying to this peer,
     /// `None` if a retry is already in progress OR already completed.
-    fn try_acquire(peer_prefix: [u8; 12]) -> Option<Self> {
+    fn try_acquire(our_prefix: [u8; 12], peer_prefix: [u8; 12]) -> Option<Self> {
+        let key = (our_prefix, peer_prefix);
         // v210: Check completed peers first — skip if already announced
         if let Ok(completed) = completed_peers().lock() {
-            if completed.contains(&peer_prefix) {
+            if completed.contains(&key) {
                 return None;
             }
         }
         let mut set = active_retries().lock().ok()?;
-        if set.contains(&peer_prefix) {
+        if set.contains(&key) {
             return None;
         }
-        set.insert(peer_prefix);
-        Some(Self { peer_prefix })
+        set.insert(key);
+        Some(Self { key })
     }
 }
 
 impl Drop for RetryGuard {
     fn drop(&mut self) {
         if let Ok(mut set) = active_retries().lock() {
-            set.remove(&self.peer_prefix);
+            set.remove(&self.key);
         }
         // v210: Mark peer as completed to suppress future re-announce
         if let Ok(mut completed) = completed_peers().lock() {
-            completed.insert(self.peer_prefix);
+            completed.insert(self.key);
         }
     }
 }
@@ -149,6 +172,14 @@ pub(super) fn handle_spdp_packet(
 
     let is_self_packet = packet_guid_prefix == Some(our_guid_prefix);
 
+    // Peer shutting down: drop it now rather than on lease expiry
+    if let Some(guid) = parse_dispose_rtps_packet(payload) {
+        if guid.prefix != our_guid_prefix {
+            fsm.dispose_participant(guid);
+        }
+        return;
+    }
+
     // Phase 1.6: Feed full RTPS packet to dialect detector (monitoring passif)
     // and remember detected dialect for SEDP encoding.
     //
@@ -248,6 +279,11 @@ pub(super) fn handle_spdp_packet(
                 );
             }
 
+            // Peer behind a NAT: reach it where its announcements come from
+            if fsm.resolve_reflexive_address(&mut spdp_data, src_addr) {
+                rearm_sedp_reannounce(our_guid_prefix, &[spdp_data.participant_guid.prefix]);
+            }
+
             fsm.handle_spdp(spdp_data.clone());
 
             // v61 Blocker #2: Send service-request ACKNACK to RTI participants
@@ -290,6 +326,7 @@ pub(super) fn handle_spdp_packet(
                     &transport,
                     port_mapping,
                     encoder.name(),
+                    fsm.local_user_data(),
                 );
             }
 
@@ -525,6 +562,11 @@ pub(super) fn handle_spdp_from_fragments(
                 );
             }
 
+            // Peer behind a NAT: reach it where its announcements come from
+            if fsm.resolve_reflexive_address(&mut spdp_data, src_addr) {
+                rearm_sedp_reannounce(our_guid_prefix, &[spdp_data.participant_guid.prefix]);
+            }
+
             fsm.handle_spdp(spdp_data.clone());
 
             // Service-request ACKNACK (same as non-fragment path)
@@ -558,6 +600,7 @@ pub(super) fn handle_spdp_from_fragments(
                     &transport,
                     port_mapping,
                     encoder.name(),
+                    fsm.local_user_data(),
                 );
             }
 
@@ -737,7 +780,7 @@ fn send_sedp_reannouncements_with_prealloc(
         use std::time::Duration;
 
         // v125: Acquire RetryGuard - skip if already retrying to this peer
-        let _guard = match RetryGuard::try_acquire(peer_prefix) {
+        let _guard = match RetryGuard::try_acquire(our_prefix, peer_prefix) {
             Some(g) => g,
             None => {
                 log::debug!(
@@ -927,7 +970,7 @@ fn send_sedp_reannouncements(
         use std::time::Duration;
 
         // v125: Acquire RetryGuard - skip if already retrying to this peer
-        let _guard = match RetryGuard::try_acquire(peer_prefix) {
+        let _guard = match RetryGuard::try_acquire(our_prefix, peer_prefix) {
             Some(g) => g,
             None => {
                 log::debug!(
@@ -1162,13 +1205,8 @@ fn extract_metatraffic_unicast_from_cdr(buf: &[u8]) -> Option<SocketAddr> {
             };
 
             if let Ok(port_u16) = u16::try_from(port_u32) {
-                let addr = Ipv4Addr::new(
-                    buf[offset + 20],
-                    buf[offset + 21],
-                    buf[offset + 22],
-                    buf[offset + 23],
-                );
-                return Some(SocketAddr::new(addr.into(), port_u16));
+                let addr = decode_locator_ip(buf, offset);
+                return Some(SocketAddr::new(addr, port_u16));
             }
         }
 
@@ -1192,6 +1230,7 @@ fn extract_metatraffic_unicast_from_cdr(buf: &[u8]) -> Option<SocketAddr> {
 /// - `transport`: UDP transport for sending
 /// - `port_mapping`: Port mapping for calculating locator ports
 /// - `dialect_name`: Dialect name for logging
+/// - `user_data`: USER_DATA of our participant
 fn send_immediate_spdp_unicast(
     our_guid_prefix: &[u8; 12],
     peer_guid_prefix: &[u8; 12],
@@ -1199,8 +1238,9 @@ fn send_immediate_spdp_unicast(
     transport: &Arc<UdpTransport>,
     port_mapping: crate::transport::PortMapping,
     dialect_name: &str,
+    user_data: &UserData,
 ) {
-    use crate::config::{DATA_MULTICAST_OFFSET, MULTICAST_IP};
+    use crate::config::DATA_MULTICAST_OFFSET;
     use crate::core::discovery::GUID;
 
     // Build our SPDP data using transport locators
@@ -1220,9 +1260,8 @@ fn send_immediate_spdp_unicast(
         .collect();
 
     // Build multicast locators
-    let default_multicast_locators = vec![SocketAddr::from((MULTICAST_IP, data_multicast_port))];
-    let metatraffic_multicast_locators =
-        vec![SocketAddr::from((MULTICAST_IP, spdp_multicast_port))];
+    let default_multicast_locators = transport.multicast_locators(data_multicast_port);
+    let metatraffic_multicast_locators = transport.multicast_locators(spdp_multicast_port);
 
     // Reconstruct participant GUID from prefix
     let mut guid_bytes = [0u8; 16];
@@ -1247,6 +1286,7 @@ fn send_immediate_spdp_unicast(
         default_multicast_locators,
         metatraffic_multicast_locators,
         identity_token: None,
+        user_data: user_data.clone(),
     };
 
     // Get a sequence number (use current SPDP count + 1)
@@ -8,10 +8,11 @@
 //! options (SO_REUSEADDR) and bound to specific ports for RTPS communication.
 
 use crate::config::{DATA_MULTICAST_OFFSET, MULTICAST_IP};
-use crate::transport::multicast::get_multicast_interfaces;
+use crate::transport::multicast::get_filtered_multicast_interfaces;
+use crate::transport::InterfaceFilter;
 use socket2::{Domain, Protocol, Socket, Type};
 use std::io;
-use std::net::{Ipv4Addr, UdpSocket};
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, UdpSocket};
 
 /// Create unicast socket for receiving SEDP and user data from RTI.
 ///
@@ -47,6 +48,25 @@ pub(super) fn create_unicast_socket(unicast_port: u16) -> io::Result<UdpSocket>
     Ok(socket)
 }
 
+/// Create IPv6 unicast socket for receiving user data (IPv6 / dual-stack modes).
+///
+/// IPv6-only (`IPV6_V6ONLY`), so it coexists with the IPv4 socket on the same port.
+pub(super) fn create_unicast_socket_v6(unicast_port: u16) -> io::Result<UdpSocket> {
+    let socket2 = Socket::new(Domain::IPV6, Type::DGRAM, Some(Protocol::UDP))?;
+    socket2.set_only_v6(true)?;
+    socket2.set_reuse_address(true)?;
+    socket2.bind(&SocketAddr::from((Ipv6Addr::UNSPECIFIED, unicast_port)).into())?;
+
+    let socket: UdpSocket = socket2.into();
+    socket.set_read_timeout(Some(std::time::Duration::from_millis(100)))?;
+    log::debug!(
+        "[Unicast Socket] Bound to [::]:{} for IPv6 data reception",
+        unicast_port
+    );
+
+    Ok(socket)
+}
+
 /// Create multicast socket for receiving user data from CycloneDDS/FastDDS.
 ///
 /// CycloneDDS and FastDDS send user data to 239.255.0.1:7401 (non-standard, but common).
@@ -54,10 +74,14 @@ pub(super) fn create_unicast_socket(unicast_port: u16) -> io::Result<UdpSocket>
 ///
 /// # Arguments
 /// * `metatraffic_multicast` - The SPDP multicast port (e.g., 7400 for domain 0)
+/// * `filter` - Interfaces to join the group on (the transport's filter)
 ///
 /// # Returns
 /// A UDP socket bound to the data multicast port and joined to the multicast group.
-pub(super) fn create_data_multicast_socket(metatraffic_multicast: u16) -> io::Result<UdpSocket> {
+pub(super) fn create_data_multicast_socket(
+    metatraffic_multicast: u16,
+    filter: &InterfaceFilter,
+) -> io::Result<UdpSocket> {
     let data_multicast_port = metatraffic_multicast + DATA_MULTICAST_OFFSET;
 
     // Create socket with SO_REUSEADDR
@@ -80,7 +104,7 @@ pub(super) fn create_data_multicast_socket(metatraffic_multicast: u16) -> io::Re
     // Resilient: track successes, fallback to UNSPECIFIED if all per-interface joins fail
     // (common on Windows with Hyper-V/WSL/Docker virtual adapters)
     let multicast_group = Ipv4Addr::from(MULTICAST_IP);
-    let interfaces = get_multicast_interfaces()?;
+    let interfaces = get_filtered_multicast_interfaces(filter)?;
     let mut any_joined = false;
 
     if interfaces.is_empty() {
@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Loopback relay between the relayed UDP transport and TCP (`TransportMode::Tcp`).
+//!
+//! In TCP mode every UDP send of the participant (SPDP, SEDP, user data,
+//! heartbeats, ACKNACKs) lands on a local relay socket. This thread forwards
+//! those datagrams to all connected TCP peers, and hands messages received
+//! over TCP to the participant's metatraffic unicast socket so the regular
+//! RX pipeline processes them.
+
+use std::io::ErrorKind;
+use std::net::{SocketAddr, UdpSocket};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use crate::core::discovery::multicast::{classify_rtps, PacketKind};
+use crate::transport::tcp::{TcpTransport, TcpTransportEvent};
+
+const RELAY_READ_TIMEOUT: Duration = Duration::from_millis(1);
+/// Largest datagram the loopback hop can carry.
+const MAX_DATAGRAM: usize = 65_507;
+
+/// TCP relay thread handle.
+///
+/// Automatically shuts down and joins the thread on Drop.
+pub(in crate::dds::participant) struct TcpRelayThread {
+    shutdown: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Drop for TcpRelayThread {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Spawn the TCP relay thread.
+///
+/// `relay_socket` is the socket the relayed `UdpTransport` sends to;
+/// `deliver_to` is the participant's metatraffic unicast address.
+pub(super) fn spawn(
+    tcp: Arc<TcpTransport>,
+    relay_socket: UdpSocket,
+    deliver_to: SocketAddr,
+) -> std::io::Result<TcpRelayThread> {
+    relay_socket.set_read_timeout(Some(RELAY_READ_TIMEOUT))?;
+    let shutdown = Arc::new(AtomicBool::new(false));
+    let shutdown_clone = Arc::clone(&shutdown);
+
+    let handle = thread::Builder::new()
+        .name("hdds-tcp-relay".into())
+        .spawn(move || {
+            log::info!(
+                "[hdds] TCP relay started (relay={:?}, deliver_to={})",
+                relay_socket.local_addr().ok(),
+                deliver_to
+            );
+            let mut buf = vec![0u8; MAX_DATAGRAM];
+            // Last SPDP announcement, replayed to peers as soon as they connect
+            // so discovery does not wait for the next announcement period.
+            let mut last_spdp: Option<Vec<u8>> = None;
+
+            while !shutdown_clone.load(Ordering::Relaxed) {
+                loop {
+                    match relay_socket.recv_from(&mut buf) {
+                        Ok((len, _)) => {
+                            let datagram = &buf[..len];
+                            if classify_rtps(datagram).0 == PacketKind::SPDP {
+                                last_spdp = Some(datagram.to_vec());
+                            }
+                            if let Err(e) = tcp.broadcast(datagram) {
+                                log::debug!("[TCP] relay broadcast failed: {}", e);
+                            }
+                        }
+                        Err(e)
+                            if e.kind() == ErrorKind::WouldBlock
+                                || e.kind() == ErrorKind::TimedOut =>
+                        {
+                            break;
+                        }
+                        Err(e) => {
+                            log::debug!("[TCP] relay socket error: {}", e);
+                            break;
+                        }
+                    }
+                }
+
+                for event in tcp.poll() {
+                    match event {
+                        TcpTransportEvent::MessageReceived { from, payload } => {
+                            if payload.len() > MAX_DATAGRAM {
+                                log::debug!(
+                                    "[TCP] dropping {} byte message from {:?}: exceeds loopback datagram size",
+                                    payload.len(),
+                                    from
+                                );
+                                continue;
+                            }
+                            if let Err(e) = relay_socket.send_to(&payload, deliver_to) {
+                                log::debug!("[TCP] relay delivery failed: {}", e);
+                            }
+                        }
+                        TcpTransportEvent::Connected {
+                            remote_guid,
+                            remote_addr,
+                        } => {
+                            log::debug!(
+                                "[TCP] peer connected: GUID {:?} at {}",
+                                remote_guid,
+                                remote_addr
+                            );
+                            if let Some(ref spdp) = last_spdp {
+                                let _ = tcp.send(&remote_guid, spdp);
+                            }
+                        }
+                        TcpTransportEvent::Disconnected {
+                            remote_guid,
+                            reason,
+                        } => {
+                            log::debug!(
+                                "[TCP] peer disconnected: GUID {:?} ({})",
+                                remote_guid,
+                                reason.as_deref().unwrap_or("unknown")
+                            );
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            log::info!("[hdds] TCP relay stopped");
+        })?;
+
+    Ok(TcpRelayThread {
+        shutdown,
+        handle: Some(handle),
+    })
+}
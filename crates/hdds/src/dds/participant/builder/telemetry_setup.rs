@@ -7,7 +7,10 @@
 //! - MetricsCollector initialization
 //! - Telemetry exporter setup (optional, can be disabled via env var)
 //! - Telemetry push thread spawning
+//!
+//! Without the `telemetry` feature only the metrics collector is set up.
 
+#[cfg(feature = "telemetry")]
 use crate::dds::participant::telemetry::telemetry_push_loop;
 use crate::telemetry;
 use std::sync::atomic::AtomicBool;
@@ -27,6 +30,16 @@ pub(super) fn init_telemetry() -> Arc<telemetry::MetricsCollector> {
     let metrics = telemetry::init_metrics();
     log::debug!("[hdds] metrics initialized");
 
+    #[cfg(feature = "telemetry")]
+    start_exporter();
+
+    log::debug!("[hdds] telemetry setup complete");
+    metrics
+}
+
+/// Start the global telemetry exporter unless disabled via env var.
+#[cfg(feature = "telemetry")]
+fn start_exporter() {
     // Check if telemetry exporter should be disabled
     let exporter_raw = std::env::var("HDDS_EXPORTER_DISABLE").ok();
     if let Some(ref raw) = exporter_raw {
@@ -59,21 +72,19 @@ pub(super) fn init_telemetry() -> Arc<telemetry::MetricsCollector> {
             }
         }
     }
-
-    log::debug!("[hdds] telemetry setup complete");
-    metrics
 }
 
 /// Telemetry thread components.
 pub(super) struct TelemetryThread {
     pub shutdown: Arc<AtomicBool>,
-    pub handle: thread::JoinHandle<()>,
+    pub handle: Option<thread::JoinHandle<()>>,
 }
 
 /// Spawn telemetry push thread.
 ///
 /// Starts background thread that periodically pushes metrics to exporter.
-/// Thread can be stopped by setting `shutdown` flag to `true`.
+/// Thread can be stopped by setting `shutdown` flag to `true`. Without the
+/// `telemetry` feature there is no exporter and no thread is spawned.
 ///
 /// # Arguments
 /// - `metrics`: MetricsCollector to push from
@@ -82,10 +93,19 @@ pub(super) struct TelemetryThread {
 /// TelemetryThread with shutdown flag and thread handle
 pub(super) fn spawn_telemetry_thread(metrics: Arc<telemetry::MetricsCollector>) -> TelemetryThread {
     let shutdown = Arc::new(AtomicBool::new(false));
-    let shutdown_clone = shutdown.clone();
-    let metrics_clone = metrics.clone();
 
-    let handle = thread::spawn(move || telemetry_push_loop(metrics_clone, shutdown_clone));
+    #[cfg(feature = "telemetry")]
+    let handle = {
+        let shutdown_clone = shutdown.clone();
+        Some(thread::spawn(move || {
+            telemetry_push_loop(metrics, shutdown_clone)
+        }))
+    };
+    #[cfg(not(feature = "telemetry"))]
+    let handle = {
+        let _ = metrics;
+        None
+    };
 
     TelemetryThread { shutdown, handle }
 }
@@ -108,6 +128,8 @@ mod tests {
 
         // Immediately shut down
         telemetry.shutdown.store(true, Ordering::SeqCst);
-        let _ = telemetry.handle.join();
+        if let Some(handle) = telemetry.handle {
+            let _ = handle.join();
+        }
     }
 }
@@ -7,14 +7,17 @@
 //! participant operation:
 //! - SPDP announcer (participant discovery)
 //! - Lease tracker (participant liveliness monitoring)
+//! - Peer keepalive (NAT mappings toward unicast peers)
 
 use super::telemetry_setup::TelemetryThread;
 use crate::config::{RuntimeConfig, PARTICIPANT_LEASE_DURATION_MS};
-use crate::core::discovery::{multicast::DiscoveryFsm, GUID};
+use crate::core::discovery::{multicast::DiscoveryFsm, InitialPeer, GUID};
+use crate::qos::metadata::UserData;
 use crate::telemetry::MetricsCollector;
 use crate::transport::UdpTransport;
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
+use std::time::Duration;
 
 /// Spawned threads and shutdown coordination for a participant.
 pub(super) struct ParticipantThreads {
@@ -22,6 +25,7 @@ pub(super) struct ParticipantThreads {
     pub telemetry_handle: Option<std::thread::JoinHandle<()>>,
     pub spdp_announcer: Option<crate::core::discovery::SpdpAnnouncer>,
     pub lease_tracker: Option<crate::core::discovery::multicast::LeaseTracker>,
+    pub peer_keepalive: Option<crate::core::discovery::PeerKeepalive>,
 }
 
 /// Spawn background threads for participant operation (excluding telemetry).
@@ -35,9 +39,13 @@ pub(super) struct ParticipantThreads {
 /// - `discovery_fsm`: Discovery FSM for lease tracking
 /// - `telemetry`: Telemetry thread components from telemetry_setup
 /// - `config`: Runtime configuration (for custom port mapping)
+/// - `initial_peers`: Unicast SPDP destinations (empty = multicast only)
+/// - `user_data`: USER_DATA announced in SPDP
+/// - `nat_keepalive`: Keepalive interval toward unicast peers (None = disabled)
 ///
 /// # Returns
 /// Struct containing thread handles and shutdown coordination
+#[allow(clippy::too_many_arguments)]
 pub(super) fn spawn_participant_threads(
     guid: GUID,
     _metrics: Arc<MetricsCollector>,
@@ -45,16 +53,24 @@ pub(super) fn spawn_participant_threads(
     discovery_fsm: Option<Arc<DiscoveryFsm>>,
     telemetry: TelemetryThread,
     config: Arc<RuntimeConfig>,
+    initial_peers: Vec<InitialPeer>,
+    user_data: UserData,
+    nat_keepalive: Option<Duration>,
 ) -> ParticipantThreads {
     // Spawn SPDP announcer (periodic participant discovery)
     let spdp_announcer = if let Some(ref transport_arc) = transport {
         log::debug!("[hdds] Spawning SPDP announcer (GUID={:?})", guid);
-        Some(crate::core::discovery::SpdpAnnouncer::spawn(
-            guid,
-            transport_arc.clone(),
-            PARTICIPANT_LEASE_DURATION_MS,
-            config.clone(),
-        ))
+        Some(
+            crate::core::discovery::SpdpAnnouncer::spawn_with_initial_peers(
+                guid,
+                transport_arc.clone(),
+                PARTICIPANT_LEASE_DURATION_MS,
+                config.clone(),
+                initial_peers,
+                discovery_fsm.clone(),
+                user_data,
+            ),
+        )
     } else {
         None
     };
@@ -62,7 +78,17 @@ pub(super) fn spawn_participant_threads(
     // Phase 1.4: Start LeaseTracker to remove expired participants
     let lease_tracker = if let Some(ref fsm) = discovery_fsm {
         log::debug!("[hdds] Starting LeaseTracker (1 Hz check rate)");
-        match crate::core::discovery::multicast::LeaseTracker::start(fsm.db()) {
+        // Expiry goes through the FSM so endpoints are dropped and listeners notified
+        let weak_fsm = Arc::downgrade(fsm);
+        match crate::core::discovery::multicast::LeaseTracker::start_with_clock(
+            fsm.db(),
+            fsm.clock(),
+            Box::new(move |guid| {
+                if let Some(fsm) = weak_fsm.upgrade() {
+                    fsm.remove_participant(guid);
+                }
+            }),
+        ) {
             Ok(tracker) => Some(tracker),
             Err(e) => {
                 log::debug!("[hdds] WARNING: LeaseTracker failed to start: {}", e);
@@ -73,10 +99,30 @@ pub(super) fn spawn_participant_threads(
         None
     };
 
+    let peer_keepalive = match (nat_keepalive, &transport, &discovery_fsm) {
+        (Some(interval), Some(transport), Some(fsm)) => {
+            log::debug!("[hdds] Starting peer keepalive (every {:?})", interval);
+            match crate::core::discovery::PeerKeepalive::start(
+                guid.prefix,
+                Arc::clone(transport),
+                Arc::clone(fsm),
+                interval,
+            ) {
+                Ok(keepalive) => Some(keepalive),
+                Err(e) => {
+                    log::debug!("[hdds] WARNING: peer keepalive failed to start: {}", e);
+                    None
+                }
+            }
+        }
+        _ => None,
+    };
+
     ParticipantThreads {
         telemetry_shutdown: telemetry.shutdown,
-        telemetry_handle: Some(telemetry.handle),
+        telemetry_handle: telemetry.handle,
         spdp_announcer,
         lease_tracker,
+        peer_keepalive,
     }
 }
@@ -1,9 +1,9 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 // Copyright (c) 2025-2026 naskel.com
 
-//! Sprint 7: Unicast routing thread for TCP and QUIC transports.
+//! Sprint 7: Unicast routing thread for TCP, UDS, QUIC, RDMA and AF_XDP.
 //!
-//! Polls TCP and QUIC transports for incoming RTPS messages and routes them
+//! Polls TCP, UDS, QUIC, RDMA and AF_XDP receive paths for incoming RTPS messages and routes them
 //! through `route_raw_rtps_message()` to the `TopicRegistry`.
 
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -14,12 +14,26 @@ use std::time::Duration;
 use crate::core::discovery::fragment_buffer::FragmentBuffer;
 use crate::engine::{route_raw_rtps_message, RouterMetrics, TopicRegistry};
 use crate::transport::tcp::{TcpTransport, TcpTransportEvent};
+#[cfg(unix)]
+use crate::transport::uds::{UdsTransport, UdsTransportEvent};
 
 #[cfg(feature = "quic")]
 use crate::transport::quic::{QuicEvent, QuicIoThreadHandle};
+#[cfg(feature = "rdma")]
+use crate::transport::rdma::{RdmaTransport, RdmaTransportEvent};
+#[cfg(all(feature = "af-xdp", target_os = "linux"))]
+use crate::transport::xdp::XdpSocket;
 
 const TCP_POLL_TIMEOUT: Duration = Duration::from_millis(50);
+#[cfg(unix)]
+const UDS_POLL_TIMEOUT: Duration = Duration::from_millis(50);
 const NO_TRANSPORT_SLEEP: Duration = Duration::from_millis(50);
+/// Completion queues cannot be waited on here; back off briefly when RDMA
+/// is the only transport and had nothing to deliver.
+#[cfg(all(feature = "af-xdp", target_os = "linux"))]
+const XDP_POLL_TIMEOUT: Duration = Duration::from_millis(50);
+#[cfg(feature = "rdma")]
+const RDMA_IDLE_SLEEP: Duration = Duration::from_micros(200);
 const FRAG_MAX_PENDING: usize = 64;
 const FRAG_TIMEOUT_MS: u64 = 5000;
 
@@ -42,13 +56,16 @@ impl Drop for UnicastRoutingThread {
 
 /// Spawn the unicast routing thread.
 ///
-/// The thread polls TCP and QUIC transports, routing received RTPS messages
-/// through `route_raw_rtps_message()` to the `TopicRegistry`.
+/// The thread polls TCP, UDS, QUIC, RDMA and AF_XDP, routing received RTPS
+/// messages through `route_raw_rtps_message()` to the `TopicRegistry`.
 ///
-/// Auto-starts only when at least one of TCP or QUIC is configured.
+/// Auto-starts only when at least one of them is configured.
 pub(super) fn spawn(
     tcp: Option<Arc<TcpTransport>>,
+    #[cfg(unix)] uds: Option<Arc<UdsTransport>>,
     #[cfg(feature = "quic")] quic_handle: Option<QuicIoThreadHandle>,
+    #[cfg(feature = "rdma")] rdma: Option<Arc<RdmaTransport>>,
+    #[cfg(all(feature = "af-xdp", target_os = "linux"))] xdp: Option<Arc<XdpSocket>>,
     registry: Arc<TopicRegistry>,
     metrics: Arc<RouterMetrics>,
     shutdown: Arc<AtomicBool>,
@@ -60,8 +77,18 @@ pub(super) fn spawn(
         .name("hdds-unicast-router".into())
         .spawn(move || {
             log::info!(
-                "[hdds] Unicast routing thread started (TCP={}, QUIC={})",
+                "[hdds] Unicast routing thread started (TCP={}, UDS={}, QUIC={}, RDMA={}, XDP={})",
                 tcp.is_some(),
+                {
+                    #[cfg(unix)]
+                    {
+                        uds.is_some()
+                    }
+                    #[cfg(not(unix))]
+                    {
+                        false
+                    }
+                },
                 {
                     #[cfg(feature = "quic")]
                     {
@@ -72,6 +99,26 @@ pub(super) fn spawn(
                         false
                     }
                 },
+                {
+                    #[cfg(feature = "rdma")]
+                    {
+                        rdma.is_some()
+                    }
+                    #[cfg(not(feature = "rdma"))]
+                    {
+                        false
+                    }
+                },
+                {
+                    #[cfg(all(feature = "af-xdp", target_os = "linux"))]
+                    {
+                        xdp.is_some()
+                    }
+                    #[cfg(not(all(feature = "af-xdp", target_os = "linux")))]
+                    {
+                        false
+                    }
+                },
             );
 
             let frag_buf = Mutex::new(FragmentBuffer::new(FRAG_MAX_PENDING, FRAG_TIMEOUT_MS));
@@ -118,6 +165,30 @@ pub(super) fn spawn(
                     }
                 }
 
+                // --- UDS ---
+                // Don't block on UDS when TCP already waited this iteration.
+                #[cfg(unix)]
+                {
+                    if let Some(ref uds) = uds {
+                        let timeout = if had_transport {
+                            Duration::ZERO
+                        } else {
+                            UDS_POLL_TIMEOUT
+                        };
+                        had_transport = true;
+                        for event in uds.poll_timeout(timeout) {
+                            let UdsTransportEvent::MessageReceived { payload, from } = event;
+                            let outcome = route_raw_rtps_message(
+                                &payload,
+                                &registry,
+                                &metrics,
+                                Some(&frag_buf),
+                            );
+                            log::trace!("[unicast-router] UDS from {:?}: {:?}", from, outcome);
+                        }
+                    }
+                }
+
                 // --- QUIC ---
                 #[cfg(feature = "quic")]
                 {
@@ -163,6 +234,68 @@ pub(super) fn spawn(
                     }
                 }
 
+                // --- AF_XDP ---
+                // RTPS is routed straight out of the UMEM frame, no copy.
+                #[cfg(all(feature = "af-xdp", target_os = "linux"))]
+                {
+                    if let Some(ref xdp) = xdp {
+                        let timeout = if had_transport {
+                            Duration::ZERO
+                        } else {
+                            XDP_POLL_TIMEOUT
+                        };
+                        had_transport = true;
+                        xdp.poll_timeout(timeout, |pkt| {
+                            let outcome = route_raw_rtps_message(
+                                pkt.payload,
+                                &registry,
+                                &metrics,
+                                Some(&frag_buf),
+                            );
+                            log::trace!("[unicast-router] XDP from {}: {:?}", pkt.src, outcome);
+                        });
+                    }
+                }
+
+                // --- RDMA ---
+                #[cfg(feature = "rdma")]
+                {
+                    if let Some(ref rdma) = rdma {
+                        let events = rdma.poll();
+                        if events.is_empty() && !had_transport {
+                            thread::sleep(RDMA_IDLE_SLEEP);
+                        }
+                        had_transport = true;
+                        for event in events {
+                            match event {
+                                RdmaTransportEvent::MessageReceived { payload, from } => {
+                                    let outcome = route_raw_rtps_message(
+                                        &payload,
+                                        &registry,
+                                        &metrics,
+                                        Some(&frag_buf),
+                                    );
+                                    log::trace!(
+                                        "[unicast-router] RDMA from {:?}: {:?}",
+                                        from,
+                                        outcome
+                                    );
+                                }
+                                RdmaTransportEvent::Disconnected {
+                                    remote_guid,
+                                    reason,
+                                } => {
+                                    log::debug!(
+                                        "[unicast-router] RDMA peer disconnected: GUID {:?} ({})",
+                                        remote_guid,
+                                        reason
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
+
                 // Avoid busy-loop if no transport is active
                 if !had_transport {
                     thread::sleep(NO_TRANSPORT_SLEEP);
@@ -0,0 +1,325 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Typed stream of discovery events.
+//!
+//! [`Participant::discovery_events`] subscribes to the participants, writers
+//! and readers discovered and lost by the participant. A subscription starts
+//! with a `*Discovered` event for everything already known, then follows
+//! the changes, so a consumer can build and maintain its view of the domain
+//! without diffing snapshots.
+//!
+//! Events of all subscriptions come from one [`DiscoveryEventHub`],
+//! registered with the discovery FSM on the first subscription. The hub
+//! keeps the last known state of every remote participant and endpoint:
+//! losing a participant reports its endpoints lost first, and a
+//! `ParticipantLost` event still carries the participant's locators.
+
+use super::runtime::Participant;
+use crate::core::discovery::multicast::{
+    DiscoveryFsm, DiscoveryListener, EndpointInfo, EndpointKind, ParticipantInfo,
+};
+use crate::core::discovery::GUID;
+use crate::dds::{Error, Result};
+use crossbeam::channel::{self, Receiver, Sender};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+use std::time::Duration;
+
+/// A change of the discovery graph seen by a participant.
+///
+/// Only remote entities are reported; the participant's own writers and
+/// readers are not.
+#[derive(Debug, Clone)]
+pub enum DiscoveryEvent {
+    /// A remote participant announced itself through SPDP.
+    ParticipantDiscovered(ParticipantInfo),
+    /// A remote participant's lease expired or it was disposed.
+    ParticipantLost(ParticipantInfo),
+    /// A remote DataWriter was announced through SEDP.
+    WriterDiscovered(EndpointInfo),
+    /// A remote DataWriter was disposed, or its participant lost.
+    WriterLost(EndpointInfo),
+    /// A remote DataReader was announced through SEDP.
+    ReaderDiscovered(EndpointInfo),
+    /// A remote DataReader was disposed, or its participant lost.
+    ReaderLost(EndpointInfo),
+}
+
+impl DiscoveryEvent {
+    fn endpoint_discovered(endpoint: EndpointInfo) -> Self {
+        match endpoint.kind {
+            EndpointKind::Writer => DiscoveryEvent::WriterDiscovered(endpoint),
+            EndpointKind::Reader => DiscoveryEvent::ReaderDiscovered(endpoint),
+        }
+    }
+
+    fn endpoint_lost(endpoint: EndpointInfo) -> Self {
+        match endpoint.kind {
+            EndpointKind::Writer => DiscoveryEvent::WriterLost(endpoint),
+            EndpointKind::Reader => DiscoveryEvent::ReaderLost(endpoint),
+        }
+    }
+
+    /// GUID of the participant or endpoint the event is about.
+    #[must_use]
+    pub fn guid(&self) -> GUID {
+        match self {
+            DiscoveryEvent::ParticipantDiscovered(p) | DiscoveryEvent::ParticipantLost(p) => p.guid,
+            DiscoveryEvent::WriterDiscovered(e)
+            | DiscoveryEvent::WriterLost(e)
+            | DiscoveryEvent::ReaderDiscovered(e)
+            | DiscoveryEvent::ReaderLost(e) => e.endpoint_guid,
+        }
+    }
+
+    /// Whether the event reports something going away.
+    #[must_use]
+    pub fn is_lost(&self) -> bool {
+        matches!(
+            self,
+            DiscoveryEvent::ParticipantLost(_)
+                | DiscoveryEvent::WriterLost(_)
+                | DiscoveryEvent::ReaderLost(_)
+        )
+    }
+}
+
+/// Subscription to the discovery events of a participant.
+///
+/// Created by [`Participant::discovery_events`]. Events are queued until
+/// taken, without bound; dropping the subscription unsubscribes. Iterating
+/// blocks for the next event and ends when the participant is dropped.
+pub struct DiscoveryEvents {
+    rx: Receiver<DiscoveryEvent>,
+}
+
+impl DiscoveryEvents {
+    /// Next queued event, without blocking.
+    pub fn try_next(&self) -> Option<DiscoveryEvent> {
+        self.rx.try_recv().ok()
+    }
+
+    /// Next event, waiting up to `timeout` for one.
+    pub fn next_timeout(&self, timeout: Duration) -> Option<DiscoveryEvent> {
+        self.rx.recv_timeout(timeout).ok()
+    }
+
+    /// Number of queued events.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.rx.len()
+    }
+
+    /// Whether no event is queued.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rx.is_empty()
+    }
+}
+
+impl Iterator for DiscoveryEvents {
+    type Item = DiscoveryEvent;
+
+    fn next(&mut self) -> Option<DiscoveryEvent> {
+        self.rx.recv().ok()
+    }
+}
+
+impl std::fmt::Debug for DiscoveryEvents {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DiscoveryEvents")
+            .field("queued", &self.rx.len())
+            .finish()
+    }
+}
+
+/// Known remote entities and the subscriptions to notify.
+#[derive(Default)]
+struct HubState {
+    participants: HashMap<GUID, ParticipantInfo>,
+    endpoints: HashMap<GUID, EndpointInfo>,
+    subscribers: Vec<Sender<DiscoveryEvent>>,
+}
+
+impl HubState {
+    /// Send `event` to every subscription, dropping the closed ones.
+    fn publish(&mut self, event: DiscoveryEvent) {
+        self.subscribers
+            .retain(|subscriber| subscriber.send(event.clone()).is_ok());
+    }
+}
+
+/// Fans discovery FSM callbacks out to [`DiscoveryEvents`] subscriptions.
+pub(crate) struct DiscoveryEventHub {
+    /// Weak: the FSM owns the hub's listener
+    fsm: Weak<DiscoveryFsm>,
+    local_prefix: [u8; 12],
+    state: Mutex<HubState>,
+}
+
+fn lock(state: &Mutex<HubState>) -> MutexGuard<'_, HubState> {
+    state.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+impl DiscoveryEventHub {
+    /// Register a hub with `fsm` and load what it already knows.
+    pub(super) fn start(fsm: &Arc<DiscoveryFsm>, local: GUID) -> Arc<Self> {
+        let hub = Arc::new(Self {
+            fsm: Arc::downgrade(fsm),
+            local_prefix: local.prefix,
+            state: Mutex::new(HubState::default()),
+        });
+        // Registered before the snapshot so nothing falls in between;
+        // callbacks racing the snapshot are deduplicated against it.
+        fsm.register_listener(Arc::new(HubListener(Arc::clone(&hub))));
+
+        let mut state = lock(&hub.state);
+        for participant in fsm.get_participants() {
+            state
+                .participants
+                .entry(participant.guid)
+                .or_insert(participant);
+        }
+        for (writers, readers) in fsm.get_all_topics().into_values() {
+            for endpoint in writers.into_iter().chain(readers) {
+                if hub.is_remote(&endpoint) {
+                    state
+                        .endpoints
+                        .entry(endpoint.endpoint_guid)
+                        .or_insert(endpoint);
+                }
+            }
+        }
+        drop(state);
+        hub
+    }
+
+    /// New subscription, primed with the current state.
+    pub(super) fn subscribe(&self) -> DiscoveryEvents {
+        let (tx, rx) = channel::unbounded();
+        let mut state = lock(&self.state);
+        for participant in state.participants.values() {
+            let _ = tx.send(DiscoveryEvent::ParticipantDiscovered(participant.clone()));
+        }
+        for endpoint in state.endpoints.values() {
+            let _ = tx.send(DiscoveryEvent::endpoint_discovered(endpoint.clone()));
+        }
+        state.subscribers.push(tx);
+        DiscoveryEvents { rx }
+    }
+
+    fn is_remote(&self, endpoint: &EndpointInfo) -> bool {
+        endpoint.endpoint_guid.prefix != self.local_prefix
+    }
+}
+
+/// The hub's discovery FSM listener.
+struct HubListener(Arc<DiscoveryEventHub>);
+
+impl DiscoveryListener for HubListener {
+    fn on_endpoint_discovered(&self, endpoint: EndpointInfo) {
+        let hub = &self.0;
+        if !hub.is_remote(&endpoint) {
+            return;
+        }
+        let mut state = lock(&hub.state);
+        if state.endpoints.contains_key(&endpoint.endpoint_guid) {
+            return;
+        }
+        state
+            .endpoints
+            .insert(endpoint.endpoint_guid, endpoint.clone());
+        state.publish(DiscoveryEvent::endpoint_discovered(endpoint));
+    }
+
+    fn on_endpoint_lost(&self, endpoint: EndpointInfo) {
+        let mut state = lock(&self.0.state);
+        if let Some(endpoint) = state.endpoints.remove(&endpoint.endpoint_guid) {
+            state.publish(DiscoveryEvent::endpoint_lost(endpoint));
+        }
+    }
+
+    fn on_participant_discovered(&self, participant: GUID) {
+        let hub = &self.0;
+        // The FSM reports the GUID once the participant is in its database
+        let Some(info) = hub.fsm.upgrade().and_then(|fsm| {
+            fsm.get_participants()
+                .into_iter()
+                .find(|p| p.guid == participant)
+        }) else {
+            return;
+        };
+        let mut state = lock(&hub.state);
+        if state.participants.contains_key(&participant) {
+            return;
+        }
+        state.participants.insert(participant, info.clone());
+        state.publish(DiscoveryEvent::ParticipantDiscovered(info));
+    }
+
+    fn on_participant_lost(&self, participant: GUID) {
+        let mut state = lock(&self.0.state);
+        let Some(info) = state.participants.remove(&participant) else {
+            return;
+        };
+        // The FSM drops the endpoints of a lost participant silently
+        let lost: Vec<GUID> = state
+            .endpoints
+            .keys()
+            .filter(|guid| guid.prefix == participant.prefix)
+            .copied()
+            .collect();
+        for guid in lost {
+            if let Some(endpoint) = state.endpoints.remove(&guid) {
+                state.publish(DiscoveryEvent::endpoint_lost(endpoint));
+            }
+        }
+        state.publish(DiscoveryEvent::ParticipantLost(info));
+    }
+}
+
+impl Participant {
+    /// Subscribe to the participants, writers and readers this participant
+    /// discovers and loses.
+    ///
+    /// The subscription first yields a `*Discovered` event for every remote
+    /// entity already known, then one event per change. Events carry the
+    /// full discovery data: QoS, type and locators of endpoints, locators,
+    /// lease and USER_DATA of participants. When a participant is lost,
+    /// its writers and readers are reported lost before it.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::InvalidState`] if discovery is not initialized
+    /// (intra-process participants).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use hdds::{DiscoveryEvent, Participant};
+    ///
+    /// let participant = Participant::builder("orchestrator").build()?;
+    /// for event in participant.discovery_events()? {
+    ///     match event {
+    ///         DiscoveryEvent::WriterDiscovered(writer) => {
+    ///             println!("+ writer {} ({:?})", writer.topic_name, writer.qos.reliability)
+    ///         }
+    ///         DiscoveryEvent::ParticipantLost(peer) => println!("- {:?}", peer.guid),
+    ///         _ => {}
+    ///     }
+    /// }
+    /// # Ok::<(), hdds::Error>(())
+    /// ```
+    pub fn discovery_events(&self) -> Result<DiscoveryEvents> {
+        let Some(fsm) = &self.discovery_fsm else {
+            return Err(Error::InvalidState(
+                "Discovery not initialized (participant may be in IntraProcess mode)".to_string(),
+            ));
+        };
+        Ok(self
+            .discovery_events
+            .get_or_init(|| DiscoveryEventHub::start(fsm, self.guid))
+            .subscribe())
+    }
+}
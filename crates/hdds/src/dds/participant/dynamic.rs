@@ -0,0 +1,185 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Writers and readers of runtime-defined types.
+//!
+//! A [`DynamicDataWriter`] publishes [`DynamicData`] samples of a type built
+//! at runtime (with [`TypeDescriptorBuilder`](crate::dynamic::TypeDescriptorBuilder)
+//! or converted from a discovered TypeObject), and a [`DynamicDataReader`]
+//! decodes received samples against such a type. Samples are serialized
+//! like those of generated types, in the `PLAIN_CDR_LE` encapsulation the
+//! DATA path announces (XCDR1 little-endian), so dynamic and typed
+//! endpoints of the same type match and interoperate.
+//!
+//! The descriptor's name is announced as the type name via SEDP.
+
+use super::live_capture::{RawDataReader, RawDataWriter};
+use super::runtime::Participant;
+use crate::dds::{Error, QoS, Result};
+use crate::dynamic::{
+    decode_dynamic_as, encode_dynamic_as, CdrEncoding, DynamicData, TypeDescriptor,
+};
+use std::sync::Arc;
+
+/// Wire encoding of dynamic samples, matching the DATA encapsulation header.
+const ENCODING: CdrEncoding = CdrEncoding::Xcdr1Le;
+
+/// A DataWriter publishing [`DynamicData`] samples.
+///
+/// Created by [`Participant::create_dynamic_writer()`].
+pub struct DynamicDataWriter {
+    inner: RawDataWriter,
+    descriptor: Arc<TypeDescriptor>,
+}
+
+/// A DataReader taking [`DynamicData`] samples.
+///
+/// Created by [`Participant::create_dynamic_reader()`].
+pub struct DynamicDataReader {
+    inner: RawDataReader,
+    descriptor: Arc<TypeDescriptor>,
+}
+
+impl DynamicDataWriter {
+    /// Serialize and publish a sample.
+    ///
+    /// # Errors
+    /// `Error::TypeMismatch` if `data` is not of the writer's type,
+    /// `Error::SerializationError` if it cannot be encoded (e.g. a bounded
+    /// string or sequence is too long), or the error of the write.
+    pub fn write(&self, data: &DynamicData) -> Result<()> {
+        if data.descriptor().name != self.descriptor.name {
+            return Err(Error::TypeMismatch);
+        }
+        let payload = encode_dynamic_as(data, ENCODING).map_err(|e| {
+            log::debug!(
+                "[dynamic] failed to encode {} sample: {}",
+                self.descriptor.name,
+                e
+            );
+            Error::SerializationError
+        })?;
+        self.inner.write_raw(&payload)
+    }
+
+    /// Type of the published samples.
+    #[must_use]
+    pub fn descriptor(&self) -> &Arc<TypeDescriptor> {
+        &self.descriptor
+    }
+
+    /// Access the configured QoS.
+    #[must_use]
+    pub fn qos(&self) -> &QoS {
+        self.inner.qos()
+    }
+
+    /// Return the topic name for this writer.
+    #[must_use]
+    pub fn topic_name(&self) -> &str {
+        self.inner.topic_name()
+    }
+}
+
+impl DynamicDataReader {
+    /// Take the next sample without blocking.
+    ///
+    /// Returns `Ok(None)` when no sample is available.
+    ///
+    /// # Errors
+    /// `Error::SerializationError` if the sample does not decode as the
+    /// reader's type; the sample is dropped and the next call moves on.
+    pub fn take(&self) -> Result<Option<DynamicData>> {
+        let Some(payload) = self.inner.take_payload() else {
+            return Ok(None);
+        };
+        decode_dynamic_as(&payload, &self.descriptor, ENCODING)
+            .map(Some)
+            .map_err(|e| {
+                log::debug!(
+                    "[dynamic] failed to decode {} sample: {}",
+                    self.descriptor.name,
+                    e
+                );
+                Error::SerializationError
+            })
+    }
+
+    /// Type of the taken samples.
+    #[must_use]
+    pub fn descriptor(&self) -> &Arc<TypeDescriptor> {
+        &self.descriptor
+    }
+}
+
+impl Participant {
+    /// Create a DataWriter for a type defined at runtime.
+    ///
+    /// # Arguments
+    /// * `topic_name` - Name of the topic to publish to
+    /// * `descriptor` - Type of the samples, announced by its name
+    /// * `qos` - Optional QoS (uses default if None)
+    ///
+    /// # Errors
+    /// Returns error if the transport is not initialized (IntraProcess
+    /// mode) or if the writer cannot be created.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use hdds::dynamic::{DynamicData, PrimitiveKind, TypeDescriptorBuilder};
+    /// use hdds::Participant;
+    /// use std::sync::Arc;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let participant = Participant::builder("gateway").domain_id(0).build()?;
+    /// let sensor = Arc::new(
+    ///     TypeDescriptorBuilder::new("SensorReading")
+    ///         .field("sensor_id", PrimitiveKind::U32)
+    ///         .field("temperature", PrimitiveKind::F64)
+    ///         .build(),
+    /// );
+    /// let writer = participant.create_dynamic_writer("sensors", &sensor, None)?;
+    ///
+    /// let mut data = DynamicData::new(&sensor);
+    /// data.set("sensor_id", 42u32)?;
+    /// data.set("temperature", 23.5f64)?;
+    /// writer.write(&data)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_dynamic_writer(
+        self: &Arc<Self>,
+        topic_name: &str,
+        descriptor: &Arc<TypeDescriptor>,
+        qos: Option<QoS>,
+    ) -> Result<DynamicDataWriter> {
+        let inner = self.create_raw_writer_with_type(topic_name, &descriptor.name, qos, None)?;
+        Ok(DynamicDataWriter {
+            inner,
+            descriptor: Arc::clone(descriptor),
+        })
+    }
+
+    /// Create a DataReader for a type defined at runtime.
+    ///
+    /// # Arguments
+    /// * `topic_name` - Name of the topic to subscribe to
+    /// * `descriptor` - Type of the samples, announced by its name
+    /// * `qos` - Optional QoS (uses default if None)
+    ///
+    /// # Errors
+    /// Returns error if the transport is not initialized (IntraProcess
+    /// mode) or if the reader cannot be created.
+    pub fn create_dynamic_reader(
+        self: &Arc<Self>,
+        topic_name: &str,
+        descriptor: &Arc<TypeDescriptor>,
+        qos: Option<QoS>,
+    ) -> Result<DynamicDataReader> {
+        let inner = self.create_raw_reader_with_type(topic_name, &descriptor.name, qos, None)?;
+        Ok(DynamicDataReader {
+            inner,
+            descriptor: Arc::clone(descriptor),
+        })
+    }
+}
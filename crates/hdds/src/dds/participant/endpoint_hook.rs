@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Endpoint creation hooks of a participant.
+//!
+//! Endpoint builders call [`Participant::run_endpoint_hooks`] with their
+//! final QoS; see [`crate::dds::EndpointHook`].
+
+use super::runtime::Participant;
+use crate::dds::{EndpointCreation, EndpointKind, QoS, Result};
+
+impl Participant {
+    /// Run the process-wide and participant hooks on a new endpoint, which
+    /// may adjust `qos` or reject the creation.
+    pub(crate) fn run_endpoint_hooks(
+        &self,
+        kind: EndpointKind,
+        topic: &str,
+        type_name: &str,
+        qos: &mut QoS,
+    ) -> Result<()> {
+        let mut endpoint =
+            EndpointCreation::new(kind, topic, type_name, &self.name, self.domain_id, qos);
+        crate::dds::run_endpoint_hooks(&self.endpoint_hooks, &mut endpoint)
+    }
+}
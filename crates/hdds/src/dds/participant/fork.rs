@@ -0,0 +1,168 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Fork handling for Participant.
+//!
+//! A child created by `fork()` inherits the participant's memory and socket
+//! descriptors but none of its background threads. Using the inherited
+//! participant would send with the parent's GUID from sockets the parent
+//! still reads, so the child builds a fresh participant with
+//! [`Participant::reinit_after_fork`] instead.
+//!
+//! Dropping the inherited participant in the child must not stop anything
+//! the parent still uses: joining the parent's threads would block forever,
+//! shutting down TCP connections would cut the parent's, and removing the
+//! UDS socket file would unlink the parent's endpoint. Those parts are
+//! leaked instead.
+
+use super::runtime::Participant;
+use crate::dds::{Error, Result};
+use std::sync::Arc;
+
+impl Participant {
+    /// Whether this participant was built by another process and inherited
+    /// through `fork()`.
+    pub fn is_forked(&self) -> bool {
+        self.build_pid != std::process::id()
+    }
+
+    /// Build a fresh participant in a child process forked after `self` was
+    /// built.
+    ///
+    /// The new participant uses the same configuration with a new GUID (the
+    /// GUID embeds the process ID), its own sockets and its own background
+    /// threads. The participant ID is auto-assigned because the parent keeps
+    /// the ports of the inherited one; unset `HDDS_PARTICIPANT_ID` in the
+    /// child if the parent used it. Custom ports from `with_discovery_ports()`
+    /// are kept and need `HDDS_REUSEPORT=1` to be shared with the parent.
+    ///
+    /// Entities created from the inherited participant belong to the parent:
+    /// recreate them from the returned participant, and do not drop the
+    /// inherited ones in the child (`std::mem::forget` them) since their
+    /// background threads only exist in the parent.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::InvalidState`] when called in the process that built the
+    /// participant, or any error of [`ParticipantBuilder::build`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use hdds::Participant;
+    ///
+    /// let participant = Participant::builder("worker").build()?;
+    /// // ... launcher forks ...
+    /// let participant = if participant.is_forked() {
+    ///     participant.reinit_after_fork()?
+    /// } else {
+    ///     participant
+    /// };
+    /// # Ok::<(), hdds::Error>(())
+    /// ```
+    ///
+    /// [`ParticipantBuilder::build`]: super::ParticipantBuilder::build
+    pub fn reinit_after_fork(&self) -> Result<Arc<Participant>> {
+        if !self.is_forked() {
+            return Err(Error::InvalidState(
+                "reinit_after_fork() called in the process that built the participant".to_string(),
+            ));
+        }
+
+        log::debug!(
+            "[participant] reinit after fork: '{}' built by pid {}, now pid {}",
+            self.name,
+            self.build_pid,
+            std::process::id()
+        );
+
+        // Same-process endpoints of the inherited participant are dead in the
+        // child; keep them from binding to the new participant's endpoints
+        self.domain_state.unregister_participant(&self.guid.prefix);
+
+        let mut builder = self.build_config.clone();
+        builder.participant_id = None;
+        builder.build()
+    }
+
+    /// Leak everything the parent process still uses (see module docs).
+    pub(super) fn abandon_after_fork(&mut self) {
+        log::debug!(
+            "[participant] dropping '{}' inherited from pid {}: leaking parent resources",
+            self.name,
+            self.build_pid
+        );
+        self.domain_state.unregister_participant(&self.guid.prefix);
+
+        std::mem::forget(self.lease_tracker.take());
+        std::mem::forget(self.peer_keepalive.take());
+        std::mem::forget(self.telemetry_handle.take());
+        std::mem::forget(self.spdp_announcer.get_mut().take());
+        std::mem::forget(self._control_handler.take());
+        std::mem::forget(std::mem::take(&mut self._listeners));
+        std::mem::forget(self._unicast_routing_thread.take());
+        std::mem::forget(self._tcp_relay_thread.take());
+        std::mem::forget(self.hibernator.take());
+        #[cfg(feature = "discovery-server")]
+        std::mem::forget(self.embedded_discovery_server.take());
+        std::mem::forget(self.router.take());
+        std::mem::forget(self.tcp_transport.take());
+        #[cfg(unix)]
+        std::mem::forget(self.uds_transport.take());
+        #[cfg(feature = "rdma")]
+        std::mem::forget(self.rdma_transport.take());
+        #[cfg(all(feature = "af-xdp", target_os = "linux"))]
+        std::mem::forget(self.xdp_socket.take());
+        #[cfg(feature = "k8s")]
+        std::mem::forget(self.k8s_discovery_handle.take());
+        #[cfg(feature = "quic")]
+        std::mem::forget(self.quic_io_thread.take());
+        #[cfg(feature = "cloud-discovery")]
+        std::mem::forget(self.cloud_discovery_poller.take());
+        #[cfg(feature = "security")]
+        std::mem::forget(self.security_watcher.take());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dds::participant::TransportMode;
+
+    #[test]
+    fn test_reinit_requires_forked_child() {
+        let participant = Participant::builder("fork_test")
+            .with_transport(TransportMode::IntraProcess)
+            .build()
+            .expect("participant");
+
+        assert!(!participant.is_forked());
+        assert!(matches!(
+            participant.reinit_after_fork(),
+            Err(Error::InvalidState(_))
+        ));
+    }
+
+    #[test]
+    fn test_forked_drop_leaks_hibernator() {
+        let participant = Participant::builder("fork_hibernation")
+            .with_transport(TransportMode::IntraProcess)
+            .with_endpoint_hibernation(crate::dds::HibernationConfig::new(
+                std::time::Duration::from_secs(30),
+            ))
+            .build()
+            .expect("participant");
+        let Ok(mut participant) = Arc::try_unwrap(participant) else {
+            panic!("participant is shared");
+        };
+        assert!(participant.hibernator.is_some());
+
+        // Pretend the participant was inherited through fork()
+        participant.build_pid = participant.build_pid.wrapping_add(1);
+        assert!(participant.is_forked());
+        participant.abandon_after_fork();
+        // Its sweep thread only exists in the parent: joining it would hang
+        assert!(participant.hibernator.is_none());
+        drop(participant);
+    }
+}
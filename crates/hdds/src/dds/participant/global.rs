@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Process-wide participants, one per domain.
+//!
+//! Every participant runs its own discovery and opens its own sockets, so
+//! libraries embedded in one process that each build a participant multiply
+//! the discovery traffic and ports in use. [`global_participant`] hands out
+//! one shared participant per domain instead.
+//!
+//! The registry only holds weak references: a global participant lives as
+//! long as one of its handles does, and the next call after the last handle
+//! is dropped builds a new one.
+
+use super::builder::ParticipantBuilder;
+use super::runtime::Participant;
+use crate::dds::Result;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, Weak};
+
+fn registry() -> MutexGuard<'static, HashMap<u32, Weak<Participant>>> {
+    static REGISTRY: OnceLock<Mutex<HashMap<u32, Weak<Participant>>>> = OnceLock::new();
+    REGISTRY
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+}
+
+/// Shared participant of this process on `domain_id`.
+///
+/// The first call builds a participant with the default configuration;
+/// later calls return the same participant while any handle to it is alive.
+///
+/// # Example
+/// ```no_run
+/// let a = hdds::global_participant(0)?;
+/// let b = hdds::global_participant(0)?;
+/// assert!(std::sync::Arc::ptr_eq(&a, &b));
+/// # Ok::<(), hdds::Error>(())
+/// ```
+pub fn global_participant(domain_id: u32) -> Result<Arc<Participant>> {
+    global_participant_with(domain_id, |builder| builder)
+}
+
+/// Like [`global_participant`], with `configure` applied to the builder if
+/// the participant has to be built.
+///
+/// `configure` is ignored when the domain already has a live global
+/// participant; its domain ID is always `domain_id`.
+pub fn global_participant_with(
+    domain_id: u32,
+    configure: impl FnOnce(ParticipantBuilder) -> ParticipantBuilder,
+) -> Result<Arc<Participant>> {
+    // Held while building so concurrent first calls share one participant
+    let mut registry = registry();
+    if let Some(participant) = registry.get(&domain_id).and_then(Weak::upgrade) {
+        // A forked child must not reuse the parent's participant
+        if !participant.is_forked() {
+            return Ok(participant);
+        }
+    }
+
+    let participant = configure(Participant::builder("hdds_global"))
+        .domain_id(domain_id)
+        .build()?;
+    registry.retain(|_, weak| weak.strong_count() > 0);
+    registry.insert(domain_id, Arc::downgrade(&participant));
+    log::debug!(
+        "[GLOBAL] built global participant {} on domain {}",
+        participant.guid(),
+        domain_id
+    );
+    Ok(participant)
+}
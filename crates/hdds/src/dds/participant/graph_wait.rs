@@ -0,0 +1,268 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Blocking waits on the discovery graph.
+//!
+//! [`GraphEvents`] is signalled whenever discovery reports a new endpoint,
+//! local or remote, and triggers the participant's graph guard condition.
+//! [`Participant::wait_for_topic`] and [`Participant::wait_for_publisher`]
+//! re-evaluate their condition on each event instead of polling with sleeps.
+//! Writers of other participants in the same process are registered in the
+//! domain registry without a graph event, so waits also re-check
+//! periodically.
+
+use super::live_capture::DiscoveredTopicInfo;
+use super::runtime::Participant;
+use crate::core::discovery::multicast::{DiscoveryListener, EndpointInfo};
+use crate::core::discovery::{Matcher, GUID};
+use crate::dds::{Error, GuardCondition, Result};
+use std::collections::HashSet;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, Weak};
+use std::time::{Duration, Instant};
+
+/// Longest wait between two checks of a graph condition.
+const GRAPH_RECHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Discovery graph change notifications of one participant.
+pub(crate) struct GraphEvents {
+    /// Bumped on every change.
+    epoch: Mutex<u64>,
+    changed: Condvar,
+    guard: Arc<GuardCondition>,
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+impl GraphEvents {
+    pub(super) fn new(guard: Arc<GuardCondition>) -> Self {
+        Self {
+            epoch: Mutex::new(0),
+            changed: Condvar::new(),
+            guard,
+        }
+    }
+
+    /// Record a graph change: wake waiters and trigger the graph guard.
+    pub(super) fn notify(&self) {
+        *lock(&self.epoch) += 1;
+        self.changed.notify_all();
+        self.guard.set_trigger_value(true);
+    }
+
+    /// Evaluate `check` on every graph change until it returns `Some` or
+    /// `timeout` elapses.
+    pub(super) fn wait_until<T>(
+        &self,
+        timeout: Duration,
+        mut check: impl FnMut() -> Option<T>,
+    ) -> Option<T> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let seen = *lock(&self.epoch);
+            if let Some(value) = check() {
+                return Some(value);
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                return None;
+            }
+            let epoch = lock(&self.epoch);
+            // A change that landed during `check` is evaluated right away
+            if *epoch == seen {
+                let wait = (deadline - now).min(GRAPH_RECHECK_INTERVAL);
+                drop(
+                    self.changed
+                        .wait_timeout(epoch, wait)
+                        .unwrap_or_else(|e| e.into_inner()),
+                );
+            }
+        }
+    }
+}
+
+/// Forwards discovery events to [`GraphEvents`].
+pub(super) struct GraphDiscoveryListener {
+    events: Weak<GraphEvents>,
+}
+
+impl GraphDiscoveryListener {
+    pub(super) fn new(events: &Arc<GraphEvents>) -> Self {
+        Self {
+            events: Arc::downgrade(events),
+        }
+    }
+}
+
+impl DiscoveryListener for GraphDiscoveryListener {
+    fn on_endpoint_discovered(&self, _endpoint: EndpointInfo) {
+        if let Some(events) = self.events.upgrade() {
+            events.notify();
+        }
+    }
+
+    fn on_endpoint_lost(&self, endpoint: EndpointInfo) {
+        self.on_endpoint_discovered(endpoint);
+    }
+}
+
+impl Participant {
+    /// Block until a topic whose name matches `pattern` is discovered.
+    ///
+    /// `pattern` is a topic name or an MQTT-style pattern: `+` matches one
+    /// `/`-separated level, `#` (last level only) any number of levels.
+    /// Topics known through discovery count, including this participant's
+    /// own; DCPS built-in topics are ignored.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::DiscoveryTimeout`] if no matching topic shows up in time
+    /// - [`Error::InvalidState`] if discovery is not initialized
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use hdds::Participant;
+    /// use std::time::Duration;
+    ///
+    /// let participant = Participant::builder("viewer").build()?;
+    /// let topic = participant.wait_for_topic("sensors/+/temperature", Duration::from_secs(5))?;
+    /// println!("{} ({})", topic.name, topic.type_name);
+    /// # Ok::<(), hdds::Error>(())
+    /// ```
+    pub fn wait_for_topic(&self, pattern: &str, timeout: Duration) -> Result<DiscoveredTopicInfo> {
+        // Fail fast without discovery
+        self.discover_topics()?;
+
+        self.graph_events
+            .wait_until(timeout, || {
+                self.discover_topics()
+                    .ok()?
+                    .into_iter()
+                    .find(|topic| Matcher::is_topic_match(pattern, &topic.name))
+            })
+            .ok_or_else(|| {
+                log::debug!(
+                    "[participant] no topic matching '{}' within {:?}",
+                    pattern,
+                    timeout
+                );
+                Error::DiscoveryTimeout
+            })
+    }
+
+    /// Block until at least `min_count` writers publish on `topic`.
+    ///
+    /// Counts writers known through discovery (remote and this
+    /// participant's) and writers of any participant in this process.
+    /// Returns the number of writers seen.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::DiscoveryTimeout`] if fewer than `min_count` writers are
+    /// present when `timeout` elapses.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use hdds::Participant;
+    /// use std::time::Duration;
+    ///
+    /// let participant = Participant::builder("listener").build()?;
+    /// participant.wait_for_publisher("chatter", 1, Duration::from_secs(5))?;
+    /// # Ok::<(), hdds::Error>(())
+    /// ```
+    pub fn wait_for_publisher(
+        &self,
+        topic: &str,
+        min_count: usize,
+        timeout: Duration,
+    ) -> Result<usize> {
+        self.graph_events
+            .wait_until(timeout, || {
+                let count = self.publisher_count(topic);
+                (count >= min_count).then_some(count)
+            })
+            .ok_or_else(|| {
+                log::debug!(
+                    "[participant] fewer than {} writer(s) on '{}' within {:?}",
+                    min_count,
+                    topic,
+                    timeout
+                );
+                Error::DiscoveryTimeout
+            })
+    }
+
+    /// Writers on `topic` known through discovery or the domain registry.
+    fn publisher_count(&self, topic: &str) -> usize {
+        let mut writers: HashSet<GUID> = self
+            .domain_state
+            .find_writer_guids_for_topic(topic)
+            .into_iter()
+            .collect();
+        if let Some(fsm) = &self.discovery_fsm {
+            writers.extend(
+                fsm.find_writers_for_topic(topic)
+                    .into_iter()
+                    .map(|w| w.endpoint_guid),
+            );
+        }
+        writers.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dds::participant::TransportMode;
+    use crate::dds::{Error, Participant};
+    use crate::generated::temperature::Temperature;
+    use std::time::{Duration, Instant};
+
+    fn participant(name: &str) -> std::sync::Arc<Participant> {
+        Participant::builder(name)
+            .with_transport(TransportMode::IntraProcess)
+            .build()
+            .expect("participant")
+    }
+
+    #[test]
+    fn test_wait_for_publisher_wakes_on_new_writer() {
+        let subscriber = participant("graph_wait_sub");
+        let publisher = participant("graph_wait_pub");
+
+        assert!(matches!(
+            subscriber.wait_for_publisher("graph_wait/topic", 1, Duration::from_millis(20)),
+            Err(Error::DiscoveryTimeout)
+        ));
+
+        let handle = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            publisher
+                .topic::<Temperature>("graph_wait/topic")
+                .expect("topic")
+                .writer()
+                .build()
+                .expect("writer")
+        });
+
+        let start = Instant::now();
+        let count = subscriber
+            .wait_for_publisher("graph_wait/topic", 1, Duration::from_secs(5))
+            .expect("publisher");
+        assert_eq!(count, 1);
+        assert!(start.elapsed() < Duration::from_secs(5));
+        drop(handle.join().expect("writer thread"));
+    }
+
+    #[test]
+    fn test_wait_for_topic_requires_discovery() {
+        let participant = participant("graph_wait_topic");
+        assert!(matches!(
+            participant.wait_for_topic("graph_wait/#", Duration::from_millis(20)),
+            Err(Error::InvalidState(_))
+        ));
+    }
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Idle-endpoint hibernation of a participant's writers and readers.
+//!
+//! Endpoint builders hand their hibernation hooks to
+//! [`Participant::hibernator`]; see [`crate::dds::HibernationConfig`].
+
+use super::runtime::Participant;
+use crate::dds::{HibernationConfig, HibernationStats, Hibernator};
+
+impl Participant {
+    /// Hibernation settings, or `None` if endpoints never hibernate.
+    pub fn hibernation_config(&self) -> Option<HibernationConfig> {
+        self.hibernator.as_ref().map(Hibernator::config)
+    }
+
+    /// Counters of idle-endpoint hibernation, or `None` if the participant
+    /// was built without
+    /// [`with_endpoint_hibernation`](super::ParticipantBuilder::with_endpoint_hibernation).
+    pub fn hibernation_stats(&self) -> Option<HibernationStats> {
+        self.hibernator.as_ref().map(Hibernator::stats)
+    }
+
+    /// Sweep watching the participant's endpoints, if hibernation is on.
+    pub(crate) fn hibernator(&self) -> Option<&Hibernator> {
+        self.hibernator.as_ref()
+    }
+}
@@ -7,27 +7,33 @@
 //! This module provides APIs for discovering topics and reading raw CDR payloads
 //! without compile-time type knowledge, enabling tools like `hdds_viewer` to
 //! capture and analyze live DDS traffic (similar to Wireshark for DDS).
+//!
+//! Payloads are `bytes::Bytes`: a sample taken from a [`RawDataReader`] still
+//! lives in the receive slab pool, and passing it to
+//! [`RawDataWriter::write_bytes`] forwards it without an intermediate copy.
 
 use crate::core::discovery::GUID;
 use crate::core::ser::{Cdr2Decode, Cdr2Encode, CdrError};
 use crate::core::types::TypeDescriptor;
-use crate::dds::{Error, Result, DDS as DdsTrait};
+use crate::dds::{DataReaderListener, Error, Result, DDS as DdsTrait};
+use crate::protocol::original_writer::OriginalWriterInfo;
 use crate::xtypes::CompleteTypeObject;
+use bytes::Bytes;
 use std::sync::Arc;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// Raw CDR payload wrapper for type-agnostic reading.
 ///
 /// This type bypasses CDR deserialization and stores raw bytes directly.
 /// Used by `RawDataReader` to capture DDS traffic without compile-time type knowledge.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct RawBytes(pub Vec<u8>);
+pub struct RawBytes(pub Bytes);
 
 impl Cdr2Decode for RawBytes {
     fn decode_cdr2_le(src: &[u8]) -> std::result::Result<(Self, usize), CdrError> {
         // No deserialization - just copy raw bytes
         let len = src.len();
-        Ok((RawBytes(src.to_vec()), len))
+        Ok((RawBytes(Bytes::copy_from_slice(src)), len))
     }
 }
 
@@ -113,9 +119,13 @@ pub struct DiscoveredTopicInfo {
 #[derive(Debug, Clone)]
 pub struct RawSample {
     /// Raw CDR2 payload (unparsed bytes)
-    pub payload: Vec<u8>,
+    ///
+    /// Backed by the receive slab pool: the slab is recycled once every
+    /// clone is dropped, so copy it out (`to_vec()`) to keep it long-term.
+    pub payload: Bytes,
 
-    /// Source timestamp (from DataWriter)
+    /// Source timestamp (from DataWriter), or the reception time if the
+    /// writer sent none
     pub source_timestamp: SystemTime,
 
     /// Reception timestamp (local time)
@@ -124,8 +134,25 @@ pub struct RawSample {
     /// Sample sequence number (if available)
     pub sequence_number: Option<u64>,
 
-    /// GUID of the source DataWriter
+    /// GUID of the source DataWriter (zero if unknown)
     pub writer_guid: GUID,
+
+    /// Writer and sequence number the sample was first published with;
+    /// differs from `writer_guid` for samples replayed by a persistence
+    /// service (see [`RawDataWriter::write_relayed`])
+    pub original_writer: Option<OriginalWriterInfo>,
+}
+
+/// Callback receiving the raw CDR payload of each sample.
+pub(crate) type RawDataCallback = Arc<dyn Fn(&[u8]) + Send + Sync>;
+
+/// Forwards the payloads of a raw reader to a [`RawDataCallback`].
+struct RawCallbackListener(RawDataCallback);
+
+impl DataReaderListener<RawBytes> for RawCallbackListener {
+    fn on_data_available(&self, sample: &RawBytes) {
+        (self.0)(&sample.0);
+    }
 }
 
 /// A DataReader that returns raw CDR payloads instead of typed data.
@@ -163,23 +190,37 @@ impl RawDataReader {
         let mut samples = Vec::new();
         let reception_timestamp = SystemTime::now();
 
-        // Drain all available samples from the inner reader
-        while let Some(raw_bytes) = self.inner.take()? {
+        // Drain all available samples from the inner reader, without
+        // copying them out of their receive slabs
+        while let Some((payload, rx)) = self.inner.take_serialized() {
             // CDR encapsulation header is now stripped in the router (route_data_packet
             // and route_reassembled_data), so the payload is raw serialized data.
-            let payload = raw_bytes.0;
+            let source_timestamp = rx.source_ns.map_or(reception_timestamp, |ns| {
+                UNIX_EPOCH + Duration::from_nanos(ns)
+            });
+            // The writer's own sequence number, unless the sample is relayed
+            let sequence_number = rx
+                .original_writer
+                .filter(|original| Some(original.writer_guid) == rx.writer_guid)
+                .map(|original| original.sequence);
             samples.push(RawSample {
                 payload,
-                // Phase 7c: RTPS metadata extraction tracked in #GITEA_ISSUE_TBD
-                source_timestamp: reception_timestamp,
+                source_timestamp,
                 reception_timestamp,
-                sequence_number: None,
-                writer_guid: GUID::zero(),
+                sequence_number,
+                writer_guid: rx.writer_guid.unwrap_or_else(GUID::zero),
+                original_writer: rx.original_writer,
             });
         }
 
         Ok(samples)
     }
+
+    /// Take the next payload, if any.
+    #[cfg(feature = "dynamic")]
+    pub(super) fn take_payload(&self) -> Option<Bytes> {
+        self.inner.take_serialized().map(|(payload, _)| payload)
+    }
 }
 
 impl RawDataWriter {
@@ -193,8 +234,49 @@ impl RawDataWriter {
     /// # Errors
     /// Returns error if the write fails.
     pub fn write_raw(&self, payload: &[u8]) -> Result<()> {
-        let msg = RawBytes(payload.to_vec());
-        self.inner.write(&msg)
+        self.write_bytes(Bytes::copy_from_slice(payload))
+    }
+
+    /// Write a raw CDR payload without copying it first.
+    ///
+    /// Accepts [`RawSample::payload`] as-is, for pass-through gateways.
+    ///
+    /// # Errors
+    /// Returns error if the write fails.
+    pub fn write_bytes(&self, payload: Bytes) -> Result<()> {
+        self.inner.write(&RawBytes(payload))
+    }
+
+    /// Write a raw CDR payload with an explicit source timestamp, in
+    /// nanoseconds since the UNIX epoch.
+    ///
+    /// # Errors
+    /// Returns error if the write fails.
+    pub fn write_raw_with_timestamp(&self, payload: &[u8], source_timestamp_ns: u64) -> Result<()> {
+        self.inner.write_with_timestamp(
+            &RawBytes(Bytes::copy_from_slice(payload)),
+            source_timestamp_ns,
+        )
+    }
+
+    /// Write a raw CDR payload relayed on behalf of `original`, the writer
+    /// and sequence number it was first published with.
+    ///
+    /// See [`DataWriter::write_relayed`](crate::DataWriter::write_relayed).
+    ///
+    /// # Errors
+    /// Returns error if the write fails.
+    pub fn write_relayed(
+        &self,
+        payload: &[u8],
+        source_timestamp_ns: u64,
+        original: OriginalWriterInfo,
+    ) -> Result<()> {
+        self.inner.write_relayed(
+            &RawBytes(Bytes::copy_from_slice(payload)),
+            source_timestamp_ns,
+            original,
+        )
     }
 
     /// Access the configured QoS.
@@ -351,6 +433,21 @@ impl super::Participant {
         type_name: &str,
         qos: Option<crate::dds::QoS>,
         type_object: Option<CompleteTypeObject>,
+    ) -> Result<RawDataReader> {
+        self.create_raw_reader_with_callback(topic_name, type_name, qos, type_object, None)
+    }
+
+    /// Create a raw DataReader that also hands each payload to `on_data`.
+    ///
+    /// `on_data` runs on the router thread as samples arrive; they are
+    /// still cached for [`RawDataReader::try_take_raw`].
+    pub(crate) fn create_raw_reader_with_callback(
+        self: &Arc<Self>,
+        topic_name: &str,
+        type_name: &str,
+        qos: Option<crate::dds::QoS>,
+        type_object: Option<CompleteTypeObject>,
+        on_data: Option<RawDataCallback>,
     ) -> Result<RawDataReader> {
         // Check if transport is available
         if self.transport.is_none() {
@@ -373,6 +470,10 @@ impl super::Participant {
             builder = builder.with_type_object_override(type_object);
         }
 
+        if let Some(on_data) = on_data {
+            builder = builder.with_listener(Arc::new(RawCallbackListener(on_data)));
+        }
+
         if let Some(ref registry) = self.registry {
             builder = builder.with_registry(registry.clone());
         }
@@ -476,11 +577,12 @@ mod tests {
     #[test]
     fn test_raw_sample_construction() {
         let sample = RawSample {
-            payload: vec![0xCA, 0xFE, 0xBA, 0xBE],
+            payload: Bytes::from_static(&[0xCA, 0xFE, 0xBA, 0xBE]),
             source_timestamp: SystemTime::now(),
             reception_timestamp: SystemTime::now(),
             sequence_number: Some(42),
             writer_guid: GUID::zero(),
+            original_writer: None,
         };
 
         assert_eq!(sample.payload.len(), 4);
@@ -44,12 +44,24 @@
 //! # #[derive(hdds::DDS)] struct Command { id: u32 }
 //! ```
 //!
+//! ## Sharing a Participant
+//!
+//! Libraries in the same process can share one participant per domain, and
+//! with it discovery and sockets, through [`global_participant`]:
+//!
+//! ```rust,no_run
+//! let participant = hdds::global_participant(0)?;
+//! # Ok::<(), hdds::Error>(())
+//! ```
+//!
 //! ## Transport Modes
 //!
 //! | Mode | Use Case |
 //! |------|----------|
 //! | [`TransportMode::IntraProcess`] | Same process, zero-copy (default) |
 //! | [`TransportMode::UdpMulticast`] | Network communication with auto-discovery |
+//! | [`TransportMode::Tcp`] | RTPS over TCP where UDP/multicast is unavailable |
+//! | [`TransportMode::LoopbackBus`] | RTPS between participants of one process, no sockets |
 //!
 //! ## Architecture
 //!
@@ -76,12 +88,32 @@
 
 mod announce;
 mod builder;
+mod discovery_events;
+#[cfg(feature = "dynamic")]
+mod dynamic;
+mod endpoint_hook;
+mod fork;
+mod global;
+mod graph_wait;
+mod hibernation;
 mod live_capture;
 mod runtime;
+#[cfg(feature = "security")]
+mod security_reload;
+mod shutdown;
+mod static_discovery;
+mod suspend;
+#[cfg(feature = "telemetry")]
 mod telemetry;
+#[cfg(feature = "wasmtime")]
+mod wasm;
 #[cfg(feature = "xtypes")]
 mod xtypes;
 
 pub use builder::ParticipantBuilder;
+pub use discovery_events::{DiscoveryEvent, DiscoveryEvents};
+#[cfg(feature = "dynamic")]
+pub use dynamic::{DynamicDataReader, DynamicDataWriter};
+pub use global::{global_participant, global_participant_with};
 pub use live_capture::{DiscoveredTopicInfo, RawDataReader, RawDataWriter, RawSample};
 pub use runtime::{Participant, TransportMode};
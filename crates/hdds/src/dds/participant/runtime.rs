@@ -7,12 +7,13 @@ use crate::core::discovery::GUID;
 use crate::dds::Error;
 use crate::dds::{
     ContentFilteredTopic, DataReader, DataWriter, DomainState, FilterError, GuardCondition,
-    Publisher, Result, Subscriber, Topic,
+    Publisher, Result, Subscriber, Topic, UserData,
 };
 #[cfg(feature = "cloud-discovery")]
 use crate::discovery::cloud::{CloudDiscoveryPoller, CloudDiscoveryPollerHandle};
 #[cfg(feature = "k8s")]
 use crate::discovery::k8s::K8sDiscoveryHandle;
+#[cfg(feature = "discovery-server")]
 use crate::discovery_server::DiscoveryServerConfig;
 use crate::engine::{Router as DemuxRouter, TopicRegistry};
 use crate::transport::lowbw::LowBwConfig;
@@ -20,6 +21,8 @@ use crate::transport::lowbw::LowBwConfig;
 use crate::transport::quic::{QuicConfig, QuicIoThread, QuicIoThreadHandle, QuicTransportHandle};
 use crate::transport::shm::ShmPolicy;
 use crate::transport::tcp::{TcpTransport, TransportPreference};
+#[cfg(unix)]
+use crate::transport::uds::UdsTransport;
 use crate::transport::UdpTransport;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Arc;
@@ -47,6 +50,8 @@ pub(super) const RTPS_ENTITYID_PARTICIPANT: [u8; 4] = [0, 0, 1, 0xC1];
 /// |------|---------|----------|
 /// | `IntraProcess` | ~257ns | Same process, testing, single-node apps |
 /// | `UdpMulticast` | ~10us+ | Network communication, distributed systems |
+/// | `Tcp` | ~50us+ | Networks without multicast/UDP, firewalls, WAN |
+/// | `LoopbackBus` | ~10us | Full RTPS stack in one process, no sockets (examples, CI) |
 ///
 /// # Example
 ///
@@ -78,6 +83,22 @@ pub enum TransportMode {
     /// Best for: distributed systems, multi-host deployments, ROS 2 interop.
     /// Requires: network access, multicast-enabled network (or static peers).
     UdpMulticast,
+
+    /// RTPS over TCP for discovery and communication.
+    ///
+    /// Best for: networks that block UDP or multicast, NAT/firewall traversal.
+    /// Requires: reachable peers listed in `TcpConfig::initial_peers` (or a
+    /// listening server); a default server+client config is used when none
+    /// is given via `with_tcp_config`.
+    Tcp,
+
+    /// Full RTPS stack over an in-memory bus shared by the process.
+    ///
+    /// Best for: examples and CI in sandboxes that forbid networking, tests
+    /// of discovery and reliability without sockets.
+    /// Limitation: only reaches `LoopbackBus` participants of the same
+    /// process; participant IDs are assigned by the bus.
+    LoopbackBus,
 }
 
 /// DDS Domain Participant - the entry point to the HDDS middleware.
@@ -112,6 +133,10 @@ pub enum TransportMode {
 /// 2. Stops telemetry collection
 /// 3. Closes all network sockets
 ///
+/// In a child process forked after the participant was built, dropping
+/// leaves the parent's threads and sockets alone; see
+/// [`Participant::reinit_after_fork`].
+///
 /// All child entities (readers, writers) hold an `Arc` to the participant,
 /// so the participant remains alive until all children are dropped.
 ///
@@ -130,6 +155,15 @@ pub struct Participant {
     pub(crate) transport: Option<Arc<UdpTransport>>,
     /// TCP transport for WAN/Internet communication (HDDS-to-HDDS only)
     pub(crate) tcp_transport: Option<Arc<TcpTransport>>,
+    /// Unix domain socket transport for same-host peers (HDDS-to-HDDS only)
+    #[cfg(unix)]
+    pub(crate) uds_transport: Option<Arc<UdsTransport>>,
+    /// RDMA verbs transport for HPC clusters (HDDS-to-HDDS only, experimental)
+    #[cfg(feature = "rdma")]
+    pub(crate) rdma_transport: Option<Arc<crate::transport::rdma::RdmaTransport>>,
+    /// AF_XDP socket receiving user traffic (experimental)
+    #[cfg(all(feature = "af-xdp", target_os = "linux"))]
+    pub(crate) xdp_socket: Option<Arc<crate::transport::xdp::XdpSocket>>,
     /// Transport preference (UDP only, TCP only, hybrid)
     pub(crate) transport_preference: TransportPreference,
     /// QUIC configuration for async transport creation
@@ -138,7 +172,11 @@ pub struct Participant {
     /// Low Bandwidth transport configuration (for constrained links)
     pub(crate) lowbw_config: Option<LowBwConfig>,
     /// Discovery Server configuration (for environments without multicast)
+    #[cfg(feature = "discovery-server")]
     pub(crate) discovery_server_config: Option<DiscoveryServerConfig>,
+    /// Embedded Discovery Server (active or standby), stopped on Drop
+    #[cfg(feature = "discovery-server")]
+    pub(super) embedded_discovery_server: Option<crate::discovery_server::EmbeddedDiscoveryServer>,
     /// Cloud discovery provider (consul, aws, azure)
     #[cfg(feature = "cloud-discovery")]
     pub(crate) cloud_discovery_provider: Option<String>,
@@ -160,10 +198,12 @@ pub struct Participant {
     pub(super) topic_types: Arc<RwLock<HashMap<String, Arc<TypeObjectHandle>>>>,
     pub(super) telemetry_shutdown: Arc<AtomicBool>,
     pub(super) telemetry_handle: Option<JoinHandle<()>>,
-    /// SPDP announcer thread (kept alive for automatic cleanup on Drop)
-    pub(super) _spdp_announcer: Option<crate::core::discovery::SpdpAnnouncer>,
+    /// SPDP announcer thread (stopped by [`Participant::shutdown`] and on Drop)
+    pub(super) spdp_announcer: parking_lot::Mutex<Option<crate::core::discovery::SpdpAnnouncer>>,
     /// Lease tracker thread (removes expired participants)
     pub(super) lease_tracker: Option<crate::core::discovery::multicast::LeaseTracker>,
+    /// NAT keepalive toward unicast peers (see `ParticipantBuilder::nat_keepalive`)
+    pub(super) peer_keepalive: Option<crate::core::discovery::PeerKeepalive>,
     /// v230: ControlHandler for Two-Ring HEARTBEAT/ACKNACK processing.
     /// Must be stored in Participant to prevent immediate Drop (which stops the thread).
     pub(super) _control_handler: Option<ControlHandler>,
@@ -171,6 +211,11 @@ pub struct Participant {
     /// If dropped, listener threads exit (running flag set to false).
     pub(super) _listeners: Vec<crate::core::discovery::multicast::MulticastListener>,
     pub(super) graph_guard: Arc<GuardCondition>,
+    /// Wakes graph waits (`wait_for_topic`, `wait_for_publisher`)
+    pub(super) graph_events: Arc<super::graph_wait::GraphEvents>,
+    /// Source of `discovery_events()` subscriptions, started by the first one
+    pub(super) discovery_events:
+        std::sync::OnceLock<Arc<super::discovery_events::DiscoveryEventHub>>,
     /// Cache of local SEDP announcements (Reader/Writer) for unicast replay to newly discovered peers
     /// Used by discovery callback to re-announce endpoints via unicast (RTI interop)
     pub(super) sedp_announcements: Arc<
@@ -196,6 +241,9 @@ pub struct Participant {
     /// When set, security checks are performed on writer/reader creation and data operations.
     #[cfg(feature = "security")]
     pub(crate) security: Option<Arc<crate::security::SecurityPluginSuite>>,
+    /// Security document watcher (`SecurityConfig::reload_interval`), stopped on Drop
+    #[cfg(feature = "security")]
+    pub(super) security_watcher: Option<crate::security::SecurityWatcher>,
     /// Kubernetes DNS-based discovery handle (for cleanup on drop)
     ///
     /// When dropped, stops the background DNS polling thread.
@@ -218,6 +266,24 @@ pub struct Participant {
     /// Stored to prevent Drop until Participant drops.
     pub(super) _unicast_routing_thread:
         Option<super::builder::unicast_routing::UnicastRoutingThread>,
+    /// TCP mode relay between the relayed UDP transport and TCP peers.
+    pub(super) _tcp_relay_thread: Option<super::builder::tcp_relay::TcpRelayThread>,
+    /// Static deployment enforced on endpoint creation (None = dynamic participant).
+    pub(super) static_plan: Option<Arc<crate::dds::StaticPlan>>,
+    /// Sweep hibernating idle endpoints (see `ParticipantBuilder::with_endpoint_hibernation`).
+    pub(super) hibernator: Option<crate::dds::Hibernator>,
+    /// Hooks run on writer and reader creation (see `ParticipantBuilder::with_endpoint_hook`).
+    pub(super) endpoint_hooks: Vec<Arc<dyn crate::dds::EndpointHook>>,
+    /// Network activity suspended by [`Participant::suspend`].
+    pub(super) suspended: AtomicBool,
+    /// Set by [`Participant::shutdown`].
+    pub(super) shut_down: AtomicBool,
+    /// Process that built the participant; differs from the current one in
+    /// a child forked afterwards.
+    pub(super) build_pid: u32,
+    /// Configuration the participant was built with, replayed by
+    /// [`Participant::reinit_after_fork`].
+    pub(super) build_config: super::ParticipantBuilder,
 }
 
 impl Participant {
@@ -262,6 +328,17 @@ impl Participant {
         self.guid
     }
 
+    /// USER_DATA announced by this participant.
+    pub fn user_data(&self) -> &UserData {
+        &self.build_config.user_data
+    }
+
+    /// Time source of the participant (see
+    /// [`ParticipantBuilder::clock`](super::ParticipantBuilder::clock)).
+    pub fn clock(&self) -> Arc<dyn crate::Clock> {
+        Arc::clone(&self.build_config.clock)
+    }
+
     pub fn discovery(&self) -> Option<Arc<DiscoveryFsm>> {
         self.discovery_fsm.clone()
     }
@@ -362,6 +439,30 @@ impl Participant {
         Ok(ciphertext.to_vec())
     }
 
+    /// Payload protection of `topic` when governance protects its data,
+    /// `None` for topics sent in the clear.
+    #[cfg(feature = "security")]
+    pub(crate) fn topic_protection(
+        &self,
+        topic: &str,
+    ) -> Result<Option<Box<dyn crate::security::PayloadProtection>>> {
+        let Some(ref security) = self.security else {
+            return Ok(None);
+        };
+        let protection = security
+            .topic_protection(self.domain_id, topic)
+            .map_err(|e| Error::InvalidState(format!("Topic key derivation failed: {}", e)))?;
+        if let Some(ref protection) = protection {
+            log::debug!(
+                "[security] topic '{}' data protection {:?} ({:?})",
+                topic,
+                protection.kind(),
+                protection
+            );
+        }
+        Ok(protection)
+    }
+
     /// Check if encryption is enabled for this participant.
     #[cfg(feature = "security")]
     pub fn is_encryption_enabled(&self) -> bool {
@@ -419,6 +520,58 @@ impl Participant {
         Ok(Topic::new(name.to_string(), Arc::clone(self)))
     }
 
+    /// Create a writer on the type's default topic with its default QoS.
+    ///
+    /// Both come from the type annotation, so call sites don't repeat them:
+    ///
+    /// ```no_run
+    /// use hdds::{Participant, TransportMode};
+    ///
+    /// #[derive(hdds::DDS)]
+    /// #[dds(topic = "camera/image_meta", qos = "reliable")]
+    /// struct ImageMeta {
+    ///     width: u16,
+    ///     height: u16,
+    /// }
+    ///
+    /// fn main() -> hdds::Result<()> {
+    ///     let participant = Participant::builder("camera")
+    ///         .with_transport(TransportMode::UdpMulticast)
+    ///         .build()?;
+    ///     let writer = participant.create_writer_for::<ImageMeta>()?;
+    ///     writer.write(&ImageMeta { width: 640, height: 480 })?;
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`Error::InvalidState`](crate::dds::Error::InvalidState) if `T` has no
+    /// default topic, or any writer construction error.
+    pub fn create_writer_for<T: crate::dds::DDS>(self: &Arc<Self>) -> Result<DataWriter<T>> {
+        let builder = self.topic::<T>(default_topic_of::<T>()?)?.writer();
+        match T::default_qos() {
+            Some(qos) => builder.qos(qos).build(),
+            None => builder.build(),
+        }
+    }
+
+    /// Create a reader on the type's default topic with its default QoS.
+    ///
+    /// Counterpart of [`create_writer_for`](Self::create_writer_for).
+    ///
+    /// # Errors
+    ///
+    /// [`Error::InvalidState`](crate::dds::Error::InvalidState) if `T` has no
+    /// default topic, or any reader construction error.
+    pub fn create_reader_for<T: crate::dds::DDS>(self: &Arc<Self>) -> Result<DataReader<T>> {
+        let builder = self.topic::<T>(default_topic_of::<T>()?)?.reader();
+        match T::default_qos() {
+            Some(qos) => builder.qos(qos).build(),
+            None => builder.build(),
+        }
+    }
+
     /// Create a ContentFilteredTopic with SQL-like filtering.
     ///
     /// A ContentFilteredTopic is a specialized topic that filters incoming samples
@@ -500,12 +653,13 @@ impl Participant {
     }
 
     pub fn create_publisher(self: &Arc<Self>, qos: crate::dds::QoS) -> Result<Publisher> {
-        Ok(Publisher::new(
+        Publisher::new(
             qos,
             self.transport.clone(),
             self.registry.clone(),
             Some(Arc::clone(self)),
-        ))
+        )
+        .with_presentation()
     }
 
     pub fn router(&self) -> Option<Arc<DemuxRouter>> {
@@ -513,12 +667,13 @@ impl Participant {
     }
 
     pub fn create_subscriber(self: &Arc<Self>, qos: crate::dds::QoS) -> Result<Subscriber> {
-        Ok(Subscriber::new(
+        Subscriber::new(
             qos,
             self.transport.clone(),
             self.registry.clone(),
             Some(Arc::clone(self)),
-        ))
+        )
+        .with_presentation()
     }
 
     /// Access the participant-level discovery guard condition.
@@ -984,6 +1139,93 @@ impl Participant {
             .unwrap_or_default()
     }
 
+    // =========================================================================
+    // UDS Transport Methods
+    // =========================================================================
+
+    /// Get UDS transport (if configured).
+    ///
+    /// Returns `Some(Arc<UdsTransport>)` if UDS was enabled via
+    /// `ParticipantBuilder::with_uds`, `ParticipantBuilder::uds_config` or
+    /// `TransportPreference::UdsPreferred`.
+    #[cfg(unix)]
+    pub fn uds_transport(&self) -> Option<Arc<UdsTransport>> {
+        self.uds_transport.clone()
+    }
+
+    /// Check if UDS transport is configured and bound.
+    #[cfg(unix)]
+    pub fn uds_enabled(&self) -> bool {
+        self.uds_transport.is_some()
+    }
+
+    /// Get the local UDS socket path (if UDS is configured).
+    #[cfg(unix)]
+    pub fn uds_socket_path(&self) -> Option<std::path::PathBuf> {
+        self.uds_transport
+            .as_ref()
+            .map(|uds| uds.local_path().to_path_buf())
+    }
+
+    /// Check if a remote participant is reachable over UDS.
+    ///
+    /// True when the peer has a socket in our UDS directory, i.e. it runs on
+    /// this host or in a container sharing the directory.
+    #[cfg(unix)]
+    pub fn uds_has_peer(&self, remote_guid_prefix: &[u8; 12]) -> bool {
+        self.uds_transport
+            .as_ref()
+            .is_some_and(|uds| uds.has_peer(remote_guid_prefix))
+    }
+
+    /// Send an RTPS message to a remote participant over UDS.
+    #[cfg(unix)]
+    pub fn uds_send(&self, remote_guid_prefix: &[u8; 12], payload: &[u8]) -> std::io::Result<()> {
+        if let Some(ref uds) = self.uds_transport {
+            uds.send(remote_guid_prefix, payload)
+        } else {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::NotConnected,
+                "UDS transport not configured - use .with_uds() in ParticipantBuilder",
+            ))
+        }
+    }
+
+    // =========================================================================
+    // RDMA Transport Methods (experimental)
+    // =========================================================================
+
+    /// Get RDMA transport (if configured and the device opened).
+    ///
+    /// Use it to exchange [`QpInfo`](crate::transport::rdma::QpInfo) with
+    /// peers and send; received messages are routed automatically.
+    #[cfg(feature = "rdma")]
+    pub fn rdma_transport(&self) -> Option<Arc<crate::transport::rdma::RdmaTransport>> {
+        self.rdma_transport.clone()
+    }
+
+    /// Check if RDMA transport is configured and its device is open.
+    #[cfg(feature = "rdma")]
+    pub fn rdma_enabled(&self) -> bool {
+        self.rdma_transport.is_some()
+    }
+
+    // =========================================================================
+    // AF_XDP Receive Path Methods (experimental)
+    // =========================================================================
+
+    /// Get the AF_XDP socket (if configured and bound).
+    #[cfg(all(feature = "af-xdp", target_os = "linux"))]
+    pub fn xdp_socket(&self) -> Option<Arc<crate::transport::xdp::XdpSocket>> {
+        self.xdp_socket.clone()
+    }
+
+    /// Get AF_XDP receive counters (if the socket is bound).
+    #[cfg(all(feature = "af-xdp", target_os = "linux"))]
+    pub fn xdp_stats(&self) -> Option<crate::transport::xdp::XdpStats> {
+        self.xdp_socket.as_ref().map(|xdp| xdp.stats())
+    }
+
     // =========================================================================
     // QUIC Transport Methods (async, requires tokio runtime)
     // =========================================================================
@@ -1145,15 +1387,43 @@ impl Participant {
     // ========================================================================
 
     /// Check if a Discovery Server is configured.
+    #[cfg(feature = "discovery-server")]
     pub fn discovery_server_configured(&self) -> bool {
         self.discovery_server_config.is_some()
     }
 
     /// Get the Discovery Server configuration (if configured).
+    #[cfg(feature = "discovery-server")]
     pub fn discovery_server_config(&self) -> Option<&DiscoveryServerConfig> {
         self.discovery_server_config.as_ref()
     }
 
+    /// Check if this participant serves discovery (see
+    /// [`ParticipantBuilder::as_discovery_server`](super::ParticipantBuilder::as_discovery_server)).
+    ///
+    /// A standby participant returns `false` until its clients are
+    /// redirected to it.
+    #[cfg(feature = "discovery-server")]
+    pub fn is_discovery_server(&self) -> bool {
+        match (
+            &self.embedded_discovery_server,
+            &self.build_config.embedded_discovery_server,
+        ) {
+            (Some(server), Some(config)) => {
+                config.port == 0 || server.local_addr().port() == config.port
+            }
+            _ => false,
+        }
+    }
+
+    /// Get the embedded Discovery Server (active or standby), if any.
+    #[cfg(feature = "discovery-server")]
+    pub fn embedded_discovery_server(
+        &self,
+    ) -> Option<&crate::discovery_server::EmbeddedDiscoveryServer> {
+        self.embedded_discovery_server.as_ref()
+    }
+
     /// Create a Discovery Server client.
     ///
     /// Returns a client that can connect to a Discovery Server for environments
@@ -1172,6 +1442,7 @@ impl Participant {
     /// let mut client = participant.create_discovery_server_client()?;
     /// client.connect()?;
     /// ```
+    #[cfg(feature = "discovery-server")]
     pub fn create_discovery_server_client(
         &self,
     ) -> std::result::Result<
@@ -1229,10 +1500,12 @@ impl Participant {
         let addr = self
             .cloud_discovery_config
             .as_ref()
-            .ok_or(crate::dds::Error::Config)?;
+            .ok_or_else(|| cloud_discovery_error("no cloud discovery configured"))?;
 
         if self.cloud_discovery_provider.as_deref() != Some("consul") {
-            return Err(crate::dds::Error::Config);
+            return Err(cloud_discovery_error(
+                "cloud discovery provider is not consul",
+            ));
         }
 
         crate::discovery::cloud::ConsulDiscovery::new(addr)
@@ -1248,20 +1521,21 @@ impl Participant {
         let config = self
             .cloud_discovery_config
             .as_ref()
-            .ok_or(crate::dds::Error::Config)?;
+            .ok_or_else(|| cloud_discovery_error("no cloud discovery configured"))?;
 
         if self.cloud_discovery_provider.as_deref() != Some("aws") {
-            return Err(crate::dds::Error::Config);
+            return Err(cloud_discovery_error("cloud discovery provider is not aws"));
         }
 
         // Parse JSON config: {"namespace":"...", "service":"...", "region":"..."}
-        let parsed: serde_json::Value =
-            serde_json::from_str(config).map_err(|_| crate::dds::Error::Config)?;
+        let parsed: serde_json::Value = serde_json::from_str(config).map_err(|e| {
+            cloud_discovery_error(format!("invalid AWS Cloud Map config JSON: {}", e))
+        })?;
 
         let namespace = parsed
             .get("namespace")
             .and_then(|v| v.as_str())
-            .ok_or(crate::dds::Error::Config)?;
+            .ok_or_else(|| cloud_discovery_error("AWS Cloud Map config has no namespace"))?;
         let service = parsed
             .get("service")
             .and_then(|v| v.as_str())
@@ -1269,7 +1543,7 @@ impl Participant {
         let region = parsed
             .get("region")
             .and_then(|v| v.as_str())
-            .ok_or(crate::dds::Error::Config)?;
+            .ok_or_else(|| cloud_discovery_error("AWS Cloud Map config has no region"))?;
 
         crate::discovery::cloud::AwsCloudMap::new(namespace, service, region)
     }
@@ -1282,10 +1556,12 @@ impl Participant {
         let config = self
             .cloud_discovery_config
             .as_ref()
-            .ok_or(crate::dds::Error::Config)?;
+            .ok_or_else(|| cloud_discovery_error("no cloud discovery configured"))?;
 
         if self.cloud_discovery_provider.as_deref() != Some("azure") {
-            return Err(crate::dds::Error::Config);
+            return Err(cloud_discovery_error(
+                "cloud discovery provider is not azure",
+            ));
         }
 
         crate::discovery::cloud::AzureDiscovery::new(config)
@@ -1436,8 +1712,32 @@ impl Participant {
     }
 }
 
+/// Default topic of `T`, or an error naming the type.
+fn default_topic_of<T: crate::dds::DDS>() -> Result<&'static str> {
+    T::default_topic().ok_or_else(|| {
+        crate::dds::Error::InvalidState(format!(
+            "type '{}' has no default topic (annotate it with #[dds(topic = \"...\")])",
+            T::type_descriptor().type_name
+        ))
+    })
+}
+
+/// Configuration error on the participant's cloud discovery setup.
+#[cfg(feature = "cloud-discovery")]
+fn cloud_discovery_error(reason: impl Into<String>) -> Error {
+    Error::config(reason).with_context(crate::dds::ErrorContext::new().entity("cloud discovery"))
+}
+
 impl Drop for Participant {
     fn drop(&mut self) {
+        if self.is_forked() {
+            self.abandon_after_fork();
+            return;
+        }
+
+        // Dispose our endpoints and ourselves on peers
+        self.shutdown();
+
         // Stop lease tracker
         if let Some(tracker) = self.lease_tracker.take() {
             tracker.stop();
@@ -1450,3 +1750,71 @@ impl Drop for Participant {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dds::{QoS, DDS};
+    use crate::generated::temperature::Temperature;
+
+    #[derive(Debug, Clone, PartialEq, crate::DDS)]
+    #[dds(
+        topic = "camera/image_meta",
+        qos = "reliable",
+        ros_package = "vision_msgs"
+    )]
+    struct ImageMeta {
+        width: u16,
+        height: u16,
+    }
+
+    #[derive(Debug, Clone, PartialEq, crate::DDS)]
+    #[dds(topic = "camera/frame_rate", qos = QoS::best_effort().keep_last(3))]
+    struct FrameRate {
+        fps: u32,
+    }
+
+    #[test]
+    fn test_type_level_defaults() {
+        assert_eq!(ImageMeta::DDS_TOPIC, "camera/image_meta");
+        assert_eq!(
+            ImageMeta::ROS_TYPE_NAME,
+            "vision_msgs::msg::dds_::ImageMeta_"
+        );
+        assert_eq!(FrameRate::ROS_TYPE_NAME, "hdds::msg::dds_::FrameRate_");
+        let qos = ImageMeta::default_qos().expect("ImageMeta QoS");
+        assert_eq!(qos.reliability, QoS::reliable().reliability);
+        let qos = FrameRate::default_qos().expect("FrameRate QoS");
+        assert_eq!(qos.reliability, QoS::best_effort().reliability);
+        assert_eq!(qos.history, QoS::best_effort().keep_last(3).history);
+        assert_eq!(Temperature::default_topic(), None);
+    }
+
+    #[test]
+    fn test_create_endpoints_for_type_defaults() {
+        let participant = Participant::builder("type_defaults")
+            .with_transport(TransportMode::IntraProcess)
+            .build()
+            .expect("participant");
+
+        let writer = participant
+            .create_writer_for::<ImageMeta>()
+            .expect("writer");
+        let reader = participant
+            .create_reader_for::<ImageMeta>()
+            .expect("reader");
+        assert_eq!(writer.topic_name(), "camera/image_meta");
+
+        let sample = ImageMeta {
+            width: 640,
+            height: 480,
+        };
+        writer.write(&sample).expect("write");
+        assert_eq!(reader.take().expect("take"), Some(sample));
+
+        assert!(matches!(
+            participant.create_writer_for::<Temperature>(),
+            Err(crate::dds::Error::InvalidState(_))
+        ));
+    }
+}
@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Hot reload of the security documents.
+//!
+//! Certificates, the revocation list, governance and permissions can change
+//! while the participant runs. A reload rebuilds the authentication and
+//! access control plugins from the files, then re-validates every
+//! discovered participant: those whose identity no longer validates (CA
+//! removed from the trust store, certificate revoked or expired) are
+//! removed with their endpoints, and their announcements are rejected from
+//! then on.
+//!
+//! Reloads run on [`Participant::reload_security`], or automatically when
+//! the files change if `SecurityConfigBuilder::reload_interval` is set.
+
+use super::runtime::Participant;
+use crate::core::discovery::multicast::DiscoveryFsm;
+use crate::dds::{Error, Result};
+use crate::security::{SecurityPluginSuite, SecurityWatcher};
+use std::sync::Arc;
+
+impl Participant {
+    /// Reload the security documents and drop revoked participants.
+    ///
+    /// Returns the number of remote participants removed. On error (a
+    /// document is missing or invalid) the previous documents stay in force.
+    ///
+    /// # Errors
+    ///
+    /// `Error::InvalidState` if the participant has no security configured,
+    /// `Error::InvalidConfig` if a document is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # fn demo(participant: &hdds::Participant) -> hdds::Result<()> {
+    /// // After installing a new revocation list
+    /// let revoked = participant.reload_security()?;
+    /// println!("{} participants revoked", revoked);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reload_security(&self) -> Result<usize> {
+        let security = self
+            .security
+            .as_ref()
+            .ok_or_else(|| Error::InvalidState("security is not configured".to_string()))?;
+        reload_and_revalidate(security, self.discovery_fsm.as_deref())
+    }
+}
+
+fn reload_and_revalidate(
+    security: &SecurityPluginSuite,
+    discovery_fsm: Option<&DiscoveryFsm>,
+) -> Result<usize> {
+    security.reload()?;
+    let revoked = discovery_fsm.map_or_else(Vec::new, DiscoveryFsm::revalidate_participants);
+    if !revoked.is_empty() {
+        log::info!(
+            "[security] removed {} participants after reload: {:?}",
+            revoked.len(),
+            revoked
+        );
+    }
+    Ok(revoked.len())
+}
+
+/// Start polling the security documents if a reload interval is configured.
+pub(super) fn start_watcher(
+    security: &Arc<SecurityPluginSuite>,
+    discovery_fsm: Option<&Arc<DiscoveryFsm>>,
+) -> Option<SecurityWatcher> {
+    let interval = security.config().reload_interval?;
+    let suite = Arc::downgrade(security);
+    let fsm = discovery_fsm.map(Arc::downgrade);
+    let on_change = Box::new(move || {
+        let Some(suite) = suite.upgrade() else {
+            return;
+        };
+        let fsm = fsm.as_ref().and_then(std::sync::Weak::upgrade);
+        if let Err(e) = reload_and_revalidate(&suite, fsm.as_deref()) {
+            log::warn!(
+                "[security] reload failed, keeping previous documents: {}",
+                e
+            );
+        }
+    });
+
+    match SecurityWatcher::start(security.watched_files(), interval, on_change) {
+        Ok(watcher) => Some(watcher),
+        Err(e) => {
+            log::warn!("[security] failed to start document watcher: {}", e);
+            None
+        }
+    }
+}
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Graceful shutdown of a Participant.
+//!
+//! Peers otherwise keep a participant that went away, and list its
+//! endpoints, until its lease expires (100 s by default). On shutdown the
+//! participant stops its SPDP announcer, disposes every endpoint it
+//! announced through SEDP, then itself through SPDP, so that peers of any
+//! vendor remove them at once. The UDP transport is silenced afterwards.
+//!
+//! Dropping the participant shuts it down; an explicit call announces the
+//! departure while writers and readers still hold the participant.
+
+use super::runtime::Participant;
+use crate::core::discovery::multicast::{
+    build_sedp_dispose_packet, build_spdp_dispose_packet, next_publications_seq,
+    next_subscriptions_seq, SedpEndpointKind,
+};
+use crate::core::discovery::GUID;
+use crate::transport::UdpTransport;
+use std::net::SocketAddr;
+use std::sync::atomic::Ordering;
+
+impl Participant {
+    /// Announce the departure of the participant and stop its network
+    /// activity.
+    ///
+    /// Sends an SEDP dispose for each local writer and reader and an SPDP
+    /// dispose for the participant, so that remote participants remove them
+    /// right away instead of on lease expiry. Nothing is sent afterwards:
+    /// the writers and readers of the participant should not be used
+    /// anymore. Intra-process delivery is not affected.
+    ///
+    /// Called on drop; calling it again has no effect. A
+    /// [suspended](Self::suspend) participant sends nothing, so its peers
+    /// drop it on lease expiry.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use hdds::{Participant, TransportMode};
+    ///
+    /// let participant = Participant::builder("node")
+    ///     .with_transport(TransportMode::UdpMulticast)
+    ///     .build()?;
+    /// // ...
+    /// participant.shutdown(); // peers forget the node now
+    /// # Ok::<(), hdds::Error>(())
+    /// ```
+    pub fn shutdown(&self) {
+        if self.shut_down.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        // A late announcement would bring the participant back on peers
+        let spdp_seq = self.spdp_announcer.lock().as_mut().map(|announcer| {
+            announcer.stop();
+            announcer.next_sequence_number()
+        });
+
+        if let Some(transport) = &self.transport {
+            self.send_disposals(transport, spdp_seq.unwrap_or(1));
+            transport.set_suspended(true);
+        }
+        log::debug!("[participant] '{}' shut down", self.name);
+    }
+
+    /// Whether [`shutdown`](Self::shutdown) was called.
+    pub fn is_shut_down(&self) -> bool {
+        self.shut_down.load(Ordering::Acquire)
+    }
+
+    /// Send the SEDP disposes of the local endpoints to the known peers,
+    /// then the SPDP dispose of the participant to the multicast group and
+    /// the peers.
+    fn send_disposals(&self, transport: &UdpTransport, spdp_seq: u64) {
+        let peers: Vec<(GUID, Vec<SocketAddr>)> = self
+            .discovery_fsm
+            .as_ref()
+            .map(|fsm| fsm.get_participants())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|info| (info.guid, info.endpoints))
+            .collect();
+        let endpoints: Vec<(GUID, SedpEndpointKind)> = self
+            .sedp_announcements
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|(data, kind)| (data.endpoint_guid, *kind))
+            .collect();
+
+        let send = |packet: &[u8], locator: &SocketAddr| {
+            if let Err(e) = transport.send_to_endpoint(packet, locator) {
+                log::debug!("[participant] dispose to {} failed: {}", locator, e);
+            }
+        };
+
+        for (endpoint, kind) in &endpoints {
+            let seq = match kind {
+                SedpEndpointKind::Writer => next_publications_seq(),
+                SedpEndpointKind::Reader => next_subscriptions_seq(),
+            };
+            for (peer, locators) in &peers {
+                let packet = build_sedp_dispose_packet(endpoint, *kind, Some(&peer.prefix), seq);
+                locators.iter().for_each(|locator| send(&packet, locator));
+            }
+        }
+
+        let packet = build_spdp_dispose_packet(&self.guid, spdp_seq);
+        if let Err(e) = transport.send(&packet) {
+            log::debug!("[participant] SPDP dispose multicast failed: {}", e);
+        }
+        for (_, locators) in &peers {
+            locators.iter().for_each(|locator| send(&packet, locator));
+        }
+
+        log::debug!(
+            "[participant] '{}' disposed itself and {} endpoint(s) on {} peer(s)",
+            self.name,
+            endpoints.len(),
+            peers.len()
+        );
+    }
+}
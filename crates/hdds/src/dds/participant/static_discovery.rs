@@ -0,0 +1,226 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Static deployment enforcement and one-shot discovery.
+//!
+//! Endpoint builders call [`Participant::admit_static_endpoint`] so only the
+//! declared writers and readers get created. [`Participant::complete_static_discovery`]
+//! then waits on the graph events for the declared remote endpoints and seals
+//! the participant by freezing the discovery FSM.
+
+use super::runtime::Participant;
+use crate::dds::{EndpointKind, Error, Result, StaticDeployment, StaticTopic};
+
+impl Participant {
+    /// Static deployment this participant was built with, if any.
+    pub fn static_deployment(&self) -> Option<&StaticDeployment> {
+        self.static_plan.as_ref().map(|plan| plan.deployment())
+    }
+
+    /// Whether [`complete_static_discovery`](Self::complete_static_discovery) succeeded.
+    pub fn is_static_sealed(&self) -> bool {
+        self.static_plan
+            .as_ref()
+            .is_some_and(|plan| plan.is_sealed())
+    }
+
+    /// Run the initial discovery of a static deployment and seal the participant.
+    ///
+    /// Waits up to the deployment's discovery timeout until every topic has
+    /// at least its declared number of remote writers and readers. Discovery
+    /// is then frozen: only the participants and endpoints found so far stay
+    /// admitted (refreshes and lease re-acquisition included), and creating
+    /// any further writer or reader fails. Calling it again once sealed is a
+    /// no-op.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidState`] if the participant has no static deployment,
+    ///   some declared local endpoints were not created yet, or remote
+    ///   endpoints are expected without a discovery subsystem
+    /// - [`Error::DiscoveryTimeout`] if the declared remote endpoints are not
+    ///   all discovered in time (the participant stays unsealed)
+    pub fn complete_static_discovery(&self) -> Result<()> {
+        let plan = self.static_plan.as_ref().ok_or_else(|| {
+            Error::InvalidState("participant has no static deployment".to_string())
+        })?;
+        if plan.is_sealed() {
+            return Ok(());
+        }
+
+        let missing = plan.missing_endpoints();
+        if !missing.is_empty() {
+            return Err(Error::InvalidState(format!(
+                "static deployment endpoints not created: {}",
+                missing.join(", ")
+            )));
+        }
+
+        let deployment = plan.deployment();
+        let expects_remote = deployment
+            .topics()
+            .iter()
+            .any(|t| t.remote_writers > 0 || t.remote_readers > 0);
+        if expects_remote && self.discovery_fsm.is_none() {
+            return Err(Error::InvalidState(
+                "static deployment expects remote endpoints but discovery is not initialized"
+                    .to_string(),
+            ));
+        }
+
+        let timeout = deployment.timeout();
+        let complete = self
+            .graph_events
+            .wait_until(timeout, || {
+                deployment
+                    .topics()
+                    .iter()
+                    .all(|topic| self.static_shortfall(topic).is_none())
+                    .then_some(())
+            })
+            .is_some();
+        if !complete {
+            let shortfall: Vec<String> = deployment
+                .topics()
+                .iter()
+                .filter_map(|topic| self.static_shortfall(topic))
+                .collect();
+            log::warn!(
+                "[participant] static discovery incomplete after {:?}: {}",
+                timeout,
+                shortfall.join(", ")
+            );
+            return Err(Error::DiscoveryTimeout);
+        }
+
+        plan.seal();
+        if let Some(fsm) = &self.discovery_fsm {
+            fsm.freeze();
+        }
+        log::info!(
+            "[participant] '{}' sealed static deployment ({} topic(s))",
+            self.name,
+            deployment.topics().len()
+        );
+        Ok(())
+    }
+
+    /// Check a new endpoint against the static deployment (if any).
+    pub(crate) fn admit_static_endpoint(
+        &self,
+        kind: EndpointKind,
+        topic: &str,
+        type_name: &str,
+    ) -> Result<()> {
+        match &self.static_plan {
+            Some(plan) => plan.admit(kind, topic, type_name),
+            None => Ok(()),
+        }
+    }
+
+    /// Remote endpoints of `topic` still missing, as a readable description.
+    fn static_shortfall(&self, topic: &StaticTopic) -> Option<String> {
+        let (writers, readers) = match &self.discovery_fsm {
+            Some(fsm) => {
+                let remote = |guid: &crate::core::discovery::GUID| guid.prefix != self.guid.prefix;
+                (
+                    fsm.find_writers_for_topic(&topic.name)
+                        .iter()
+                        .filter(|w| remote(&w.endpoint_guid))
+                        .count(),
+                    fsm.find_readers_for_topic(&topic.name)
+                        .iter()
+                        .filter(|r| remote(&r.endpoint_guid))
+                        .count(),
+                )
+            }
+            None => (0, 0),
+        };
+        let missing_writers = (topic.remote_writers as usize).saturating_sub(writers);
+        let missing_readers = (topic.remote_readers as usize).saturating_sub(readers);
+        (missing_writers > 0 || missing_readers > 0).then(|| {
+            format!(
+                "'{}' missing {} remote writer(s) and {} remote reader(s)",
+                topic.name, missing_writers, missing_readers
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dds::participant::TransportMode;
+    use crate::dds::{
+        DataReader, DataWriter, Error, Participant, QoS, Result, StaticDeployment, StaticTopic,
+    };
+    use crate::generated::temperature::Temperature;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    fn make_writer(participant: &Arc<Participant>, topic: &str) -> Result<DataWriter<Temperature>> {
+        participant
+            .topic::<Temperature>(topic)?
+            .writer()
+            .qos(QoS::reliable())
+            .build()
+    }
+
+    fn make_reader(participant: &Arc<Participant>, topic: &str) -> Result<DataReader<Temperature>> {
+        participant
+            .topic::<Temperature>(topic)?
+            .reader()
+            .qos(QoS::reliable())
+            .build()
+    }
+
+    fn participant(deployment: StaticDeployment) -> Arc<Participant> {
+        Participant::builder("static_deployment")
+            .with_transport(TransportMode::IntraProcess)
+            .with_static_deployment(deployment)
+            .build()
+            .expect("participant")
+    }
+
+    #[test]
+    fn test_static_participant_seals_after_declared_endpoints() {
+        let participant = participant(
+            StaticDeployment::new().topic(StaticTopic::new("static/temp").writers(1).readers(1)),
+        );
+
+        let writer = make_writer(&participant, "static/temp").expect("declared writer");
+        assert!(matches!(
+            make_writer(&participant, "static/other"),
+            Err(Error::InvalidState(_))
+        ));
+        // The reader is still missing
+        assert!(matches!(
+            participant.complete_static_discovery(),
+            Err(Error::InvalidState(_))
+        ));
+
+        let reader = make_reader(&participant, "static/temp").expect("declared reader");
+        participant
+            .complete_static_discovery()
+            .expect("static discovery");
+        assert!(participant.is_static_sealed());
+        assert!(matches!(
+            make_reader(&participant, "static/temp"),
+            Err(Error::InvalidState(_))
+        ));
+        drop((writer, reader));
+    }
+
+    #[test]
+    fn test_static_discovery_requires_discovery_for_remote_endpoints() {
+        let participant = participant(
+            StaticDeployment::new()
+                .topic(StaticTopic::new("static/remote").remote_writers(1))
+                .discovery_timeout(Duration::from_millis(20)),
+        );
+        assert!(matches!(
+            participant.complete_static_discovery(),
+            Err(Error::InvalidState(_))
+        ));
+        assert!(!participant.is_static_sealed());
+    }
+}
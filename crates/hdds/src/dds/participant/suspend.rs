@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Suspend mode for Participant.
+//!
+//! A suspended participant keeps everything it knows (discovered
+//! participants and endpoints, matches, writer histories) but stops using
+//! the network: SPDP announcements, SEDP, user data, HEARTBEAT and ACKNACK
+//! sends are all dropped by the UDP transport. Leases of remote
+//! participants are frozen, so nothing expires while the participant
+//! cannot hear from its peers.
+//!
+//! Resuming restarts those leases, re-arms the SEDP re-announce towards
+//! known peers and sends SPDP at the startup burst rate again, so peers
+//! that dropped the participant in the meantime pick it up within a few
+//! hundred milliseconds instead of going through a full re-discovery.
+//! Reliable writers repair what their history still holds once readers
+//! answer the next HEARTBEAT.
+//!
+//! Only the UDP transport (which also carries [`TransportMode::Tcp`]) is
+//! suspended; intra-process and shared-memory delivery keep running.
+//!
+//! [`TransportMode::Tcp`]: super::TransportMode::Tcp
+
+use super::builder::discovery_setup::rearm_sedp_reannounce;
+use super::runtime::Participant;
+use std::sync::atomic::Ordering;
+
+impl Participant {
+    /// Suspend all network activity of the participant.
+    ///
+    /// Writes still succeed and land in the writers' histories, and
+    /// incoming traffic is still processed, but nothing is sent until
+    /// [`resume`](Self::resume). Calling it on a suspended participant has
+    /// no effect.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use hdds::{Participant, TransportMode};
+    ///
+    /// let participant = Participant::builder("rover")
+    ///     .with_transport(TransportMode::UdpMulticast)
+    ///     .build()?;
+    /// participant.suspend(); // before the radio sleeps
+    /// // ...
+    /// participant.resume(); // after it wakes up
+    /// # Ok::<(), hdds::Error>(())
+    /// ```
+    pub fn suspend(&self) {
+        if self.suspended.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        if let Some(transport) = &self.transport {
+            transport.set_suspended(true);
+        }
+        if let Some(tracker) = &self.lease_tracker {
+            tracker.set_paused(true);
+        }
+        log::info!("[participant] '{}' suspended", self.name);
+    }
+
+    /// Resume network activity after [`suspend`](Self::suspend).
+    ///
+    /// Calling it on a participant that is not suspended, or that was
+    /// [`shut down`](Self::shutdown), has no effect.
+    pub fn resume(&self) {
+        // A shut down participant stays silent
+        if self.is_shut_down() || !self.suspended.swap(false, Ordering::AcqRel) {
+            return;
+        }
+        if let Some(fsm) = &self.discovery_fsm {
+            fsm.refresh_leases();
+            let peers: Vec<[u8; 12]> = fsm
+                .get_participants()
+                .iter()
+                .map(|info| info.guid.prefix)
+                .collect();
+            rearm_sedp_reannounce(self.guid().prefix, &peers);
+        }
+        if let Some(tracker) = &self.lease_tracker {
+            tracker.set_paused(false);
+        }
+        if let Some(transport) = &self.transport {
+            transport.set_suspended(false);
+        }
+        log::info!("[participant] '{}' resumed", self.name);
+    }
+
+    /// Whether network activity is suspended.
+    pub fn is_suspended(&self) -> bool {
+        self.suspended.load(Ordering::Acquire)
+    }
+}
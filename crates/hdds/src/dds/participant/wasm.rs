@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Loading WASM filter/transform plugins (feature `wasmtime`).
+
+use super::runtime::Participant;
+use crate::dds::{Result, WasmLimits, WasmPlugin};
+use std::path::Path;
+use std::sync::Arc;
+
+impl Participant {
+    /// Load a sandboxed WASM filter/transform plugin from a `.wasm` file.
+    ///
+    /// Attach it to readers and writers with `ReaderBuilder::wasm_plugin`
+    /// and `WriterBuilder::wasm_plugin`; [`WasmPlugin::reload_file`] deploys
+    /// a new version to all of them without recreating the entities. See
+    /// [`WasmPlugin`] for the module ABI.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::IoError` if the file cannot be read, and
+    /// `Error::WasmPlugin` if the module is invalid or exceeds `limits`.
+    pub fn load_wasm_plugin(
+        &self,
+        path: impl AsRef<Path>,
+        limits: WasmLimits,
+    ) -> Result<Arc<WasmPlugin>> {
+        let plugin = WasmPlugin::from_file(path, limits)?;
+        log::debug!(
+            "[participant] '{}' loaded WASM plugin '{}'",
+            self.name,
+            plugin.name()
+        );
+        Ok(Arc::new(plugin))
+    }
+}
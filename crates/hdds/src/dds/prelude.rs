@@ -4,6 +4,7 @@
 //! Prelude module for convenient imports.
 //!
 //! This module re-exports the most commonly used types from the DDS API.
+//! For imports covered by the stability policy, use [`crate::prelude::v1`].
 //!
 //! # Example
 //!
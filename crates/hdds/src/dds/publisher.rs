@@ -25,6 +25,7 @@
 //! This duplication is **approved** for ANSSI/IGI-1300 compliance.
 //! jscpd: ignore (intentional API symmetry per DDS v1.4 spec)
 
+use super::coherent::{self, CoherentGroup};
 use super::{DataWriter, QoS, Result, Topic};
 use crate::engine::TopicRegistry;
 use crate::transport::UdpTransport;
@@ -74,6 +75,10 @@ pub struct Publisher {
     /// Whether we're currently in a coherent change set
     /// Used by begin_coherent_changes() / end_coherent_changes()
     in_coherent_set: AtomicBool,
+
+    /// Coherent set and write order tagging, when PRESENTATION enables
+    /// coherent access or group ordered access
+    coherent: Option<Arc<CoherentGroup>>,
 }
 
 impl Publisher {
@@ -99,7 +104,29 @@ impl Publisher {
             registry,
             participant,
             in_coherent_set: AtomicBool::new(false),
+            coherent: None,
+        }
+    }
+
+    /// Set up coherent sets and group ordering if the PRESENTATION QoS
+    /// enables coherent access with TOPIC or GROUP scope, or ordered access
+    /// with GROUP scope.
+    pub(crate) fn with_presentation(mut self) -> Result<Self> {
+        let ordered = coherent::group_ordered_access(&self.qos);
+        if ordered || coherent::coherent_access(&self.qos) {
+            let participant = self.participant.as_ref().ok_or_else(|| {
+                crate::dds::Error::InvalidState(
+                    "Coherent or ordered access requires a Participant reference".to_string(),
+                )
+            })?;
+            self.coherent = Some(Arc::new(CoherentGroup::new(participant, ordered)?));
         }
+        Ok(self)
+    }
+
+    /// Coherent set state shared with this Publisher's writers.
+    pub(crate) fn coherent_group(&self) -> Option<&Arc<CoherentGroup>> {
+        self.coherent.as_ref()
     }
 
     /// Get the QoS policies for this Publisher
@@ -150,13 +177,8 @@ impl Publisher {
     pub fn create_writer<T: crate::dds::DDS>(
         &self,
         topic_name: &str,
-        mut qos: QoS,
+        qos: QoS,
     ) -> Result<DataWriter<T>> {
-        // Inherit PARTITION from publisher if not explicitly set in writer QoS
-        if qos.partition.is_default() && !self.qos.partition.is_default() {
-            qos.partition = self.qos.partition.clone();
-        }
-
         // Create topic with participant reference (required for SEDP announcements)
         let participant = self.participant.as_ref().ok_or_else(|| {
             crate::dds::Error::InvalidState(
@@ -164,7 +186,8 @@ impl Publisher {
             )
         })?;
         let topic = Topic::<T>::new(topic_name.to_string(), Arc::clone(participant));
-        let mut builder = topic.writer().qos(qos);
+        // Inherits PARTITION (if not set in writer QoS) and coherent sets
+        let mut builder = topic.writer().qos(qos).publisher(self);
 
         // Attach registry for NACK RX (Reliable QoS retransmission)
         if let Some(ref registry) = self.registry {
@@ -194,11 +217,17 @@ impl Publisher {
     ///
     /// # DDS v1.4 Specification
     ///
-    /// Coherent changes require `Presentation` QoS with `coherent_access = true`.
-    /// The access scope determines the granularity:
-    /// - `Instance`: Coherent per instance (same key)
-    /// - `Topic`: Coherent per topic
-    /// - `Group`: Coherent across all topics in this Publisher
+    /// Coherent changes require `Presentation` QoS with `coherent_access = true`
+    /// on both the Publisher and the Subscriber. With `Topic` or `Group` scope,
+    /// a set spans every writer of this Publisher (created with
+    /// `topic.writer().publisher(&publisher)`) and is made visible at once to every reader of a coherent Subscriber.
+    /// With `Instance` scope (or no coherent access) writes are delivered
+    /// immediately and these calls only track the set boundaries.
+    ///
+    /// Not part of a set: dispose/unregister, loaned samples, and samples
+    /// written while no set is open. Samples over 8 KB cannot be sent to
+    /// remote readers inside a set (fragments carry no inline QoS): their
+    /// write fails with `Error::UnsupportedOperation`.
     ///
     /// # Errors
     ///
@@ -207,9 +236,15 @@ impl Publisher {
     /// # Example
     ///
     /// ```ignore
+    /// let publisher = participant.create_publisher(
+    ///     QoS::reliable().presentation(Presentation::group_coherent()),
+    /// )?;
+    /// let writer_pos = participant.topic::<Position>("pos")?.writer().publisher(&publisher).build()?;
+    /// let writer_vel = participant.topic::<Velocity>("vel")?.writer().publisher(&publisher).build()?;
+    ///
     /// publisher.begin_coherent_changes()?;
-    /// writer_pos.write(Position { x: 10.0, y: 20.0 })?;
-    /// writer_vel.write(Velocity { vx: 1.0, vy: 2.0 })?;
+    /// writer_pos.write(&Position { x: 10.0, y: 20.0 })?;
+    /// writer_vel.write(&Velocity { vx: 1.0, vy: 2.0 })?;
     /// publisher.end_coherent_changes()?;
     /// ```
     pub fn begin_coherent_changes(&self) -> Result<()> {
@@ -219,6 +254,9 @@ impl Publisher {
                 "Already in a coherent change set (nested calls not supported)".to_string(),
             ));
         }
+        if let Some(group) = &self.coherent {
+            group.begin();
+        }
         log::debug!("[Publisher] Begin coherent changes");
         Ok(())
     }
@@ -230,7 +268,8 @@ impl Publisher {
     ///
     /// # Errors
     ///
-    /// Returns an error if not currently in a coherent set.
+    /// Returns an error if not currently in a coherent set, or if the
+    /// end-of-set marker cannot be written (the set is closed regardless).
     ///
     /// # Example
     ///
@@ -246,6 +285,9 @@ impl Publisher {
                 "Not in a coherent change set".to_string(),
             ));
         }
+        if let Some(group) = &self.coherent {
+            group.end()?;
+        }
         log::debug!("[Publisher] End coherent changes (committed)");
         Ok(())
     }
@@ -7,16 +7,31 @@ use std::time::Duration;
 
 /// Parse duration from XML `<sec>` and `<nanosec>` elements.
 ///
-/// Handles special values like "DURATION_INFINITY".
+/// Handles special values like "DURATION_INFINITY" (FastDDS) and
+/// "DURATION_INFINITE_SEC"/"DURATION_ZERO_SEC" (OMG DDS-XML).
 pub fn parse_duration(sec_text: Option<&str>, nanosec_text: Option<&str>) -> Duration {
-    match (sec_text, nanosec_text) {
-        (Some("DURATION_INFINITY"), _) | (_, Some("DURATION_INFINITY")) => Duration::MAX,
+    let is_infinite = |text: &str| {
+        matches!(
+            text,
+            "DURATION_INFINITY" | "DURATION_INFINITE_SEC" | "DURATION_INFINITE_NSEC"
+        )
+    };
+    let parse_secs = |s: &str| match s {
+        "DURATION_ZERO_SEC" => 0,
+        s => s.parse::<u64>().unwrap_or(0),
+    };
+    match (sec_text.map(str::trim), nanosec_text.map(str::trim)) {
+        (Some(s), _) if is_infinite(s) => Duration::MAX,
+        (_, Some(ns)) if is_infinite(ns) => Duration::MAX,
         (Some(s), Some(ns)) => {
-            let secs = s.parse::<u64>().unwrap_or(0);
-            let nanos = ns.parse::<u32>().unwrap_or(0);
+            let secs = parse_secs(s);
+            let nanos = match ns {
+                "DURATION_ZERO_NSEC" => 0,
+                ns => ns.parse::<u32>().unwrap_or(0),
+            };
             Duration::new(secs, nanos)
         }
-        (Some(s), None) => Duration::from_secs(s.parse::<u64>().unwrap_or(0)),
+        (Some(s), None) => Duration::from_secs(parse_secs(s)),
         _ => Duration::ZERO,
     }
 }
@@ -0,0 +1,499 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! OMG DDS-XML QoS profile loader (RTI Connext, Cyclone DDS, OpenDDS).
+//!
+//! Parses `<qos_library>`/`<qos_profile>` documents and converts each
+//! profile to HDDS QoS:
+//!
+//! ```xml
+//! <dds>
+//!   <qos_library name="SensorLib">
+//!     <qos_profile name="telemetry_profile" base_name="Base">
+//!       <datawriter_qos>
+//!         <reliability><kind>RELIABLE_RELIABILITY_QOS</kind></reliability>
+//!       </datawriter_qos>
+//!     </qos_profile>
+//!   </qos_library>
+//! </dds>
+//! ```
+//!
+//! Profiles are named `library::profile`. A profile starts from its
+//! `base_name` profile (if any), then applies `<topic_qos>`,
+//! `<datawriter_qos>` and `<datareader_qos>` in that order, so reader
+//! settings win when both entities set the same policy. Entity QoS with a
+//! `topic_filter` attribute only apply to some topics and are skipped.
+
+use crate::dds::qos::*;
+use crate::qos::ResourceLimits;
+use roxmltree::{Document, Node};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use super::common::parse_duration;
+
+/// Entity QoS elements merged into a profile, in application order.
+const ENTITY_QOS: [&str; 3] = ["topic_qos", "datawriter_qos", "datareader_qos"];
+
+/// Profiles in document order, and the name of the default profile.
+type ParsedDocument = (Vec<(String, QoS)>, Option<String>);
+
+/// A `<qos_profile>` before inheritance is resolved.
+struct RawProfile<'a, 'input> {
+    /// Qualified name (`library::profile`)
+    name: String,
+    library: String,
+    base_name: Option<String>,
+    is_default: bool,
+    node: Node<'a, 'input>,
+}
+
+pub struct DdsXmlLoader;
+
+impl DdsXmlLoader {
+    /// Whether the XML content is an OMG DDS-XML document (has a `<qos_library>`).
+    pub fn is_dds_xml(xml_content: &str) -> bool {
+        Document::parse(xml_content)
+            .map(|doc| {
+                doc.descendants()
+                    .any(|n| n.tag_name().name() == "qos_library")
+            })
+            .unwrap_or(false)
+    }
+
+    /// Load one QoS profile from a DDS-XML file.
+    ///
+    /// See [`DdsXmlLoader::parse_profile`] for the profile selection.
+    pub fn load_from_file<P: AsRef<Path>>(
+        path: P,
+        profile_name: Option<&str>,
+    ) -> Result<QoS, String> {
+        crate::trace_fn!("DdsXmlLoader::load_from_file");
+        let xml_content =
+            fs::read_to_string(path).map_err(|e| format!("Failed to read XML file: {}", e))?;
+        Self::parse_profile(&xml_content, profile_name)
+    }
+
+    /// Parse DDS-XML content and extract one QoS profile.
+    ///
+    /// `profile_name` is either qualified (`library::profile`) or a bare
+    /// profile name that only one library defines. Without a name, the
+    /// profile marked `is_default_qos="true"` is used, else the first one.
+    pub fn parse_profile(xml_content: &str, profile_name: Option<&str>) -> Result<QoS, String> {
+        crate::trace_fn!("DdsXmlLoader::parse_profile");
+        let (profiles, default_name) = Self::parse_document(xml_content)?;
+
+        let name = match profile_name {
+            Some(name) => resolve_name(profiles.iter().map(|(n, _)| n.as_str()), name)?,
+            None => default_name.ok_or("No qos_profile found")?,
+        };
+        profiles
+            .into_iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, qos)| qos)
+            .ok_or_else(|| format!("Profile '{}' not found", name))
+    }
+
+    /// Parse every profile of a DDS-XML document, in document order.
+    pub fn parse_profiles(xml_content: &str) -> Result<Vec<(String, QoS)>, String> {
+        crate::trace_fn!("DdsXmlLoader::parse_profiles");
+        Self::parse_document(xml_content).map(|(profiles, _)| profiles)
+    }
+
+    /// All profiles and the name of the default one.
+    fn parse_document(xml_content: &str) -> Result<ParsedDocument, String> {
+        let doc =
+            Document::parse(xml_content).map_err(|e| format!("Failed to parse XML: {}", e))?;
+        let raw = Self::raw_profiles(&doc)?;
+
+        let mut resolved = HashMap::new();
+        let mut profiles = Vec::with_capacity(raw.len());
+        for profile in &raw {
+            let qos = Self::resolve(profile, &raw, &mut resolved, &mut Vec::new())?;
+            profiles.push((profile.name.clone(), qos));
+        }
+        let default_name = raw
+            .iter()
+            .find(|p| p.is_default)
+            .or_else(|| raw.first())
+            .map(|p| p.name.clone());
+        Ok((profiles, default_name))
+    }
+
+    fn raw_profiles<'a, 'input>(
+        doc: &'a Document<'input>,
+    ) -> Result<Vec<RawProfile<'a, 'input>>, String> {
+        let mut profiles = Vec::new();
+        for library in doc
+            .descendants()
+            .filter(|n| n.tag_name().name() == "qos_library")
+        {
+            let library_name = library
+                .attribute("name")
+                .ok_or("<qos_library> without a name")?;
+            for profile in library
+                .children()
+                .filter(|n| n.tag_name().name() == "qos_profile")
+            {
+                let name = profile
+                    .attribute("name")
+                    .ok_or_else(|| format!("<qos_profile> without a name in '{}'", library_name))?;
+                profiles.push(RawProfile {
+                    name: format!("{}::{}", library_name, name),
+                    library: library_name.to_string(),
+                    base_name: profile.attribute("base_name").map(str::to_string),
+                    is_default: profile.attribute("is_default_qos") == Some("true"),
+                    node: profile,
+                });
+            }
+        }
+        Ok(profiles)
+    }
+
+    /// QoS of a profile with its base profiles applied first.
+    fn resolve(
+        profile: &RawProfile,
+        raw: &[RawProfile],
+        resolved: &mut HashMap<String, QoS>,
+        stack: &mut Vec<String>,
+    ) -> Result<QoS, String> {
+        if let Some(qos) = resolved.get(&profile.name) {
+            return Ok(qos.clone());
+        }
+        if stack.contains(&profile.name) {
+            return Err(format!("Circular base_name chain at '{}'", profile.name));
+        }
+        stack.push(profile.name.clone());
+
+        let mut qos = match profile.base_name {
+            Some(ref base) => {
+                // Unqualified base names refer to the same library
+                let qualified = if base.contains("::") {
+                    base.clone()
+                } else {
+                    format!("{}::{}", profile.library, base)
+                };
+                match raw.iter().find(|p| p.name == qualified) {
+                    Some(parent) => Self::resolve(parent, raw, resolved, stack)?,
+                    // Vendor built-in libraries are not available here
+                    None if qualified.starts_with("BuiltinQosLib") => {
+                        log::warn!(
+                            "[qos] '{}' inherits built-in profile '{}', using defaults",
+                            profile.name,
+                            qualified
+                        );
+                        QoS::default()
+                    }
+                    None => {
+                        return Err(format!(
+                            "Unknown base profile '{}' for '{}'",
+                            base, profile.name
+                        ))
+                    }
+                }
+            }
+            None => QoS::default(),
+        };
+
+        for entity in ENTITY_QOS {
+            for node in profile
+                .node
+                .children()
+                .filter(|n| n.tag_name().name() == entity && n.attribute("topic_filter").is_none())
+            {
+                Self::apply_policies(&node, &mut qos)?;
+            }
+        }
+
+        stack.pop();
+        resolved.insert(profile.name.clone(), qos.clone());
+        Ok(qos)
+    }
+
+    /// Apply the policies of one entity QoS element.
+    fn apply_policies(entity: &Node, qos: &mut QoS) -> Result<(), String> {
+        for policy in entity.children().filter(Node::is_element) {
+            match policy.tag_name().name() {
+                "reliability" => {
+                    if let Some(kind) = kind(&policy, "_RELIABILITY_QOS") {
+                        qos.reliability = match kind.as_str() {
+                            "RELIABLE" => Reliability::Reliable,
+                            "BEST_EFFORT" => Reliability::BestEffort,
+                            other => return Err(format!("Invalid reliability kind: {}", other)),
+                        };
+                    }
+                }
+                "durability" => {
+                    if let Some(kind) = kind(&policy, "_DURABILITY_QOS") {
+                        qos.durability = match kind.as_str() {
+                            "VOLATILE" => Durability::Volatile,
+                            // HDDS has no TRANSIENT service, like the FastDDS loader
+                            "TRANSIENT_LOCAL" | "TRANSIENT" => Durability::TransientLocal,
+                            "PERSISTENT" => Durability::Persistent,
+                            other => return Err(format!("Invalid durability kind: {}", other)),
+                        };
+                    }
+                }
+                "history" => {
+                    let depth = child_text(&policy, "depth")
+                        .map(|d| {
+                            d.parse::<u32>()
+                                .map_err(|_| format!("Invalid history depth: {}", d))
+                        })
+                        .transpose()?;
+                    qos.history = match kind(&policy, "_HISTORY_QOS").as_deref() {
+                        Some("KEEP_ALL") => History::KeepAll,
+                        Some("KEEP_LAST") | None => History::KeepLast(depth.unwrap_or(1)),
+                        Some(other) => return Err(format!("Invalid history kind: {}", other)),
+                    };
+                }
+                "deadline" => {
+                    if let Some(period) = duration(&policy, "period") {
+                        qos.deadline = Deadline::new(period);
+                    }
+                }
+                "latency_budget" => {
+                    if let Some(duration) = duration(&policy, "duration") {
+                        qos.latency_budget = LatencyBudget::new(duration);
+                    }
+                }
+                "lifespan" => {
+                    if let Some(duration) = duration(&policy, "duration") {
+                        qos.lifespan = Lifespan::new(duration);
+                    }
+                }
+                "time_based_filter" => {
+                    if let Some(separation) = duration(&policy, "minimum_separation") {
+                        qos.time_based_filter = TimeBasedFilter::new(separation);
+                    }
+                }
+                "liveliness" => {
+                    let liveliness_kind = match kind(&policy, "_LIVELINESS_QOS").as_deref() {
+                        Some("AUTOMATIC") | None => LivelinessKind::Automatic,
+                        Some("MANUAL_BY_PARTICIPANT") => LivelinessKind::ManualByParticipant,
+                        Some("MANUAL_BY_TOPIC") => LivelinessKind::ManualByTopic,
+                        Some(other) => return Err(format!("Invalid liveliness kind: {}", other)),
+                    };
+                    let lease = duration(&policy, "lease_duration").unwrap_or(Duration::MAX);
+                    qos.liveliness = Liveliness::new(liveliness_kind, lease);
+                }
+                "ownership" => {
+                    if let Some(kind) = kind(&policy, "_OWNERSHIP_QOS") {
+                        qos.ownership = match kind.as_str() {
+                            "SHARED" => Ownership::shared(),
+                            "EXCLUSIVE" => Ownership::exclusive(),
+                            other => return Err(format!("Invalid ownership kind: {}", other)),
+                        };
+                    }
+                }
+                "ownership_strength" => {
+                    if let Some(value) = child_text(&policy, "value") {
+                        let value = value
+                            .parse::<i32>()
+                            .map_err(|_| format!("Invalid ownership strength: {}", value))?;
+                        qos.ownership_strength = OwnershipStrength::new(value);
+                    }
+                }
+                "destination_order" => {
+                    if let Some(kind) = kind(&policy, "_DESTINATIONORDER_QOS") {
+                        qos.destination_order = match kind.as_str() {
+                            "BY_RECEPTION_TIMESTAMP" => DestinationOrder::by_reception_timestamp(),
+                            "BY_SOURCE_TIMESTAMP" => DestinationOrder::by_source_timestamp(),
+                            other => {
+                                return Err(format!("Invalid destination order kind: {}", other))
+                            }
+                        };
+                    }
+                }
+                "partition" => {
+                    let names: Vec<String> = policy
+                        .descendants()
+                        .filter(|n| n.tag_name().name() == "element")
+                        .filter_map(|n| n.text())
+                        .map(|s| s.trim().to_string())
+                        .collect();
+                    if !names.is_empty() {
+                        qos.partition = Partition::new(names);
+                    }
+                }
+                "transport_priority" => {
+                    if let Some(value) = child_text(&policy, "value") {
+                        let value = value
+                            .parse::<i32>()
+                            .map_err(|_| format!("Invalid transport priority: {}", value))?;
+                        qos.transport_priority = TransportPriority { value };
+                    }
+                }
+                "resource_limits" => {
+                    let limit = |name: &str| -> Result<usize, String> {
+                        match child_text(&policy, name) {
+                            None | Some("LENGTH_UNLIMITED") | Some("-1") => Ok(usize::MAX),
+                            Some(value) => value
+                                .parse::<usize>()
+                                .map_err(|_| format!("Invalid {}: {}", name, value)),
+                        }
+                    };
+                    qos.resource_limits = ResourceLimits {
+                        max_samples: limit("max_samples")?,
+                        max_instances: limit("max_instances")?,
+                        max_samples_per_instance: limit("max_samples_per_instance")?,
+                        ..qos.resource_limits
+                    };
+                }
+                other => {
+                    log::debug!("[qos] Ignoring unsupported DDS-XML policy <{}>", other);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Resolve a qualified or bare profile name against the loaded names.
+pub(crate) fn resolve_name<'a>(
+    names: impl Iterator<Item = &'a str>,
+    name: &str,
+) -> Result<String, String> {
+    let names: Vec<&str> = names.collect();
+    if names.contains(&name) {
+        return Ok(name.to_string());
+    }
+    let suffix = format!("::{}", name);
+    let mut candidates = names.into_iter().filter(|n| n.ends_with(&suffix));
+    match (candidates.next(), candidates.next()) {
+        (Some(found), None) => Ok(found.to_string()),
+        (Some(_), Some(_)) => Err(format!(
+            "Profile '{}' is ambiguous, use 'library::{}'",
+            name, name
+        )),
+        (None, _) => Err(format!("Profile '{}' not found", name)),
+    }
+}
+
+/// Trimmed text of a direct child element.
+fn child_text<'a>(node: &Node<'a, '_>, name: &str) -> Option<&'a str> {
+    node.children()
+        .find(|n| n.tag_name().name() == name)
+        .and_then(|n| n.text())
+        .map(str::trim)
+}
+
+/// `<kind>` of a policy, without its `_<POLICY>_QOS` suffix.
+fn kind(policy: &Node, suffix: &str) -> Option<String> {
+    child_text(policy, "kind").map(|k| k.strip_suffix(suffix).unwrap_or(k).to_string())
+}
+
+/// Duration from a `<sec>`/`<nanosec>` child element.
+fn duration(policy: &Node, name: &str) -> Option<Duration> {
+    policy
+        .children()
+        .find(|n| n.tag_name().name() == name)
+        .map(|n| parse_duration(child_text(&n, "sec"), child_text(&n, "nanosec")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROFILES: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<dds xmlns="http://www.omg.org/dds">
+  <qos_library name="SensorLib">
+    <qos_profile name="Base">
+      <datawriter_qos>
+        <reliability><kind>RELIABLE_RELIABILITY_QOS</kind></reliability>
+        <history><kind>KEEP_LAST_HISTORY_QOS</kind><depth>10</depth></history>
+      </datawriter_qos>
+    </qos_profile>
+    <qos_profile name="telemetry_profile" base_name="Base" is_default_qos="true">
+      <datawriter_qos>
+        <durability><kind>TRANSIENT_LOCAL_DURABILITY_QOS</kind></durability>
+        <deadline><period><sec>0</sec><nanosec>100000000</nanosec></period></deadline>
+        <liveliness>
+          <kind>MANUAL_BY_TOPIC_LIVELINESS_QOS</kind>
+          <lease_duration><sec>DURATION_INFINITE_SEC</sec><nanosec>DURATION_INFINITE_NSEC</nanosec></lease_duration>
+        </liveliness>
+      </datawriter_qos>
+      <datawriter_qos topic_filter="Debug*">
+        <reliability><kind>BEST_EFFORT_RELIABILITY_QOS</kind></reliability>
+      </datawriter_qos>
+      <datareader_qos>
+        <partition><name><element>sensors</element><element>fleet</element></name></partition>
+        <resource_limits><max_samples>LENGTH_UNLIMITED</max_samples><max_instances>4</max_instances></resource_limits>
+      </datareader_qos>
+    </qos_profile>
+  </qos_library>
+  <qos_library name="OtherLib">
+    <qos_profile name="Base">
+      <datareader_qos>
+        <history><kind>KEEP_ALL_HISTORY_QOS</kind></history>
+      </datareader_qos>
+    </qos_profile>
+  </qos_library>
+</dds>"#;
+
+    #[test]
+    fn test_parse_profiles_with_inheritance() {
+        assert!(DdsXmlLoader::is_dds_xml(PROFILES));
+        let profiles = DdsXmlLoader::parse_profiles(PROFILES).expect("profiles");
+        let names: Vec<&str> = profiles.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "SensorLib::Base",
+                "SensorLib::telemetry_profile",
+                "OtherLib::Base"
+            ]
+        );
+
+        let qos = &profiles[1].1;
+        // Inherited from Base, not overridden by the filtered writer QoS
+        assert!(matches!(qos.reliability, Reliability::Reliable));
+        assert!(matches!(qos.history, History::KeepLast(10)));
+        assert!(matches!(qos.durability, Durability::TransientLocal));
+        assert_eq!(qos.deadline.period, Duration::from_millis(100));
+        assert_eq!(qos.liveliness.lease_duration, Duration::MAX);
+        assert_eq!(qos.partition.names, vec!["sensors", "fleet"]);
+        assert_eq!(qos.resource_limits.max_samples, usize::MAX);
+        assert_eq!(qos.resource_limits.max_instances, 4);
+        assert!(matches!(profiles[2].1.history, History::KeepAll));
+    }
+
+    #[test]
+    fn test_profile_selection() {
+        let qos = DdsXmlLoader::parse_profile(PROFILES, None).expect("default");
+        assert!(matches!(qos.durability, Durability::TransientLocal));
+
+        let qos = DdsXmlLoader::parse_profile(PROFILES, Some("telemetry_profile")).expect("bare");
+        assert!(matches!(qos.reliability, Reliability::Reliable));
+
+        let qos = DdsXmlLoader::parse_profile(PROFILES, Some("OtherLib::Base")).expect("qualified");
+        assert!(matches!(qos.history, History::KeepAll));
+
+        let err = DdsXmlLoader::parse_profile(PROFILES, Some("Base")).unwrap_err();
+        assert!(err.contains("ambiguous"), "{}", err);
+        assert!(DdsXmlLoader::parse_profile(PROFILES, Some("missing")).is_err());
+    }
+
+    #[test]
+    fn test_invalid_base_and_kind() {
+        let cyclic = r#"<dds><qos_library name="L">
+            <qos_profile name="A" base_name="B"/>
+            <qos_profile name="B" base_name="A"/>
+        </qos_library></dds>"#;
+        assert!(DdsXmlLoader::parse_profiles(cyclic)
+            .unwrap_err()
+            .contains("Circular"));
+
+        let builtin = r#"<dds><qos_library name="L">
+            <qos_profile name="A" base_name="BuiltinQosLib::Generic.StrictReliable"/>
+        </qos_library></dds>"#;
+        assert!(DdsXmlLoader::parse_profiles(builtin).is_ok());
+
+        let bad_kind = r#"<dds><qos_library name="L"><qos_profile name="A">
+            <datawriter_qos><reliability><kind>SOMETIMES</kind></reliability></datawriter_qos>
+        </qos_profile></qos_library></dds>"#;
+        assert!(DdsXmlLoader::parse_profiles(bad_kind).is_err());
+    }
+}
@@ -67,6 +67,40 @@ impl FastDdsLoader {
         Self::extract_qos(&profile)
     }
 
+    /// Parse FastDDS XML content and extract the profile named `profile_name`.
+    pub fn parse_xml_profile(xml_content: &str, profile_name: &str) -> Result<QoS, String> {
+        crate::trace_fn!("FastDdsLoader::parse_xml_profile");
+        Self::parse_profiles(xml_content)?
+            .into_iter()
+            .find(|(name, _)| name == profile_name)
+            .map(|(_, qos)| qos)
+            .ok_or_else(|| format!("Profile '{}' not found", profile_name))
+    }
+
+    /// Parse every named `<data_writer>`/`<data_reader>` profile, in document order.
+    pub fn parse_profiles(xml_content: &str) -> Result<Vec<(String, QoS)>, String> {
+        crate::trace_fn!("FastDdsLoader::parse_profiles");
+        let doc =
+            Document::parse(xml_content).map_err(|e| format!("Failed to parse XML: {}", e))?;
+
+        let profiles = doc
+            .root_element()
+            .descendants()
+            .find(|n| n.tag_name().name() == "profiles")
+            .ok_or("No <profiles> element found")?;
+
+        profiles
+            .children()
+            .filter(|n| {
+                n.is_element()
+                    && (n.tag_name().name() == "data_writer"
+                        || n.tag_name().name() == "data_reader")
+            })
+            .filter_map(|n| n.attribute("profile_name").map(|name| (name, n)))
+            .map(|(name, n)| Ok((name.to_string(), Self::extract_qos(&n)?)))
+            .collect()
+    }
+
     fn extract_qos(profile: &roxmltree::Node) -> Result<QoS, String> {
         let mut qos = QoS::default();
 
@@ -480,4 +514,28 @@ mod tests {
         assert!(matches!(qos.reliability, Reliability::BestEffort));
         assert!(matches!(qos.history, History::KeepAll));
     }
+
+    #[test]
+    fn test_parse_named_profiles() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<dds xmlns="http://www.eprosima.com/XMLSchemas/fastRTPS_Profiles">
+  <profiles>
+    <participant profile_name="participant"/>
+    <data_writer profile_name="telemetry_profile">
+      <qos><reliability><kind>RELIABLE</kind></reliability></qos>
+    </data_writer>
+    <data_reader profile_name="logs_profile" is_default_profile="true">
+      <qos><reliability><kind>BEST_EFFORT</kind></reliability></qos>
+    </data_reader>
+  </profiles>
+</dds>"#;
+
+        let profiles = FastDdsLoader::parse_profiles(xml).expect("profiles");
+        let names: Vec<&str> = profiles.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, ["telemetry_profile", "logs_profile"]);
+
+        let qos = FastDdsLoader::parse_xml_profile(xml, "telemetry_profile").expect("profile");
+        assert!(matches!(qos.reliability, Reliability::Reliable));
+        assert!(FastDdsLoader::parse_xml_profile(xml, "participant").is_err());
+    }
 }
@@ -4,7 +4,7 @@
 //! QoS profile loaders for XML and YAML formats.
 //!
 //! This module provides runtime loading of QoS policies from:
-//! - Vendor XML files (FastDDS, RTI, Cyclone)
+//! - Vendor XML files (OMG DDS-XML as used by RTI and Cyclone, FastDDS)
 //! - YAML configuration files (HDDS native format)
 //!
 //! # Example
@@ -19,6 +19,9 @@
 //! let qos = ProfileLoader::load("config.yaml", Some("my_profile"))?;
 //! ```
 
+#[cfg(feature = "qos-loaders")]
+pub mod dds_xml;
+
 #[cfg(feature = "qos-loaders")]
 pub mod fastdds;
 
@@ -31,6 +34,9 @@ pub mod common;
 #[cfg(feature = "qos-loaders")]
 mod profile_loader;
 
+#[cfg(feature = "qos-loaders")]
+pub use dds_xml::DdsXmlLoader;
+
 #[cfg(feature = "qos-loaders")]
 pub use fastdds::FastDdsLoader;
 
@@ -38,4 +44,4 @@ pub use fastdds::FastDdsLoader;
 pub use yaml::{YamlLoader, YamlQosDocument, YamlQosProfile};
 
 #[cfg(feature = "qos-loaders")]
-pub use profile_loader::ProfileLoader;
+pub use profile_loader::{ConfigFormat, ProfileLoader};
@@ -4,10 +4,13 @@
 //! Unified profile loader with format auto-detection.
 //!
 //! Automatically detects file format (XML or YAML) and loads QoS profiles.
+//! XML files are either OMG DDS-XML (`<qos_library>`, RTI/Cyclone) or
+//! FastDDS (`<profiles>`) documents.
 
 use crate::dds::qos::QoS;
 use std::path::Path;
 
+use super::dds_xml::DdsXmlLoader;
 use super::fastdds::FastDdsLoader;
 use super::yaml::YamlLoader;
 
@@ -44,6 +47,11 @@ impl ConfigFormat {
             None
         }
     }
+
+    /// Detect format from file extension, then from file content.
+    pub fn detect(path: &Path, content: &str) -> Option<Self> {
+        Self::from_extension(path).or_else(|| Self::from_content(content))
+    }
 }
 
 /// Unified profile loader with auto-detection.
@@ -75,29 +83,16 @@ impl ProfileLoader {
         crate::trace_fn!("ProfileLoader::load");
         let path = path.as_ref();
 
-        // Try extension-based detection first
-        let format = ConfigFormat::from_extension(path)
-            .or_else(|| {
-                // Fall back to content-based detection
-                std::fs::read_to_string(path)
-                    .ok()
-                    .and_then(|content| ConfigFormat::from_content(&content))
-            })
-            .ok_or_else(|| {
-                format!(
-                    "Unable to detect config format for '{}'. Use .xml, .yaml, or .yml extension.",
-                    path.display()
-                )
-            })?;
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read config file: {}", e))?;
+        let format = ConfigFormat::detect(path, &content).ok_or_else(|| {
+            format!(
+                "Unable to detect config format for '{}'. Use .xml, .yaml, or .yml extension.",
+                path.display()
+            )
+        })?;
 
-        match format {
-            ConfigFormat::Xml => {
-                // For XML, we currently only support FastDDS format
-                // profile_name is ignored (uses default profile in XML)
-                FastDdsLoader::load_from_file(path)
-            }
-            ConfigFormat::Yaml => YamlLoader::load_qos(path, profile_name),
-        }
+        Self::load_from_str(&content, format, profile_name)
     }
 
     /// Load QoS from string content with explicit format.
@@ -107,7 +102,7 @@ impl ProfileLoader {
         profile_name: Option<&str>,
     ) -> Result<QoS, String> {
         match format {
-            ConfigFormat::Xml => FastDdsLoader::parse_xml(content),
+            ConfigFormat::Xml => Self::xml_profile(content, profile_name),
             ConfigFormat::Yaml => {
                 let doc = YamlLoader::parse_yaml(content)?;
                 match profile_name {
@@ -118,6 +113,27 @@ impl ProfileLoader {
         }
     }
 
+    /// Parse every named profile of XML content, in document order.
+    ///
+    /// DDS-XML profiles are named `library::profile`, FastDDS profiles by
+    /// their `profile_name`.
+    pub fn xml_profiles(content: &str) -> Result<Vec<(String, QoS)>, String> {
+        if DdsXmlLoader::is_dds_xml(content) {
+            DdsXmlLoader::parse_profiles(content)
+        } else {
+            FastDdsLoader::parse_profiles(content)
+        }
+    }
+
+    /// One profile of XML content (the default one if `profile_name` is None).
+    fn xml_profile(content: &str, profile_name: Option<&str>) -> Result<QoS, String> {
+        match (DdsXmlLoader::is_dds_xml(content), profile_name) {
+            (true, name) => DdsXmlLoader::parse_profile(content, name),
+            (false, Some(name)) => FastDdsLoader::parse_xml_profile(content, name),
+            (false, None) => FastDdsLoader::parse_xml(content),
+        }
+    }
+
     /// Load QoS from string content with format auto-detection.
     pub fn load_from_str_auto(content: &str, profile_name: Option<&str>) -> Result<QoS, String> {
         let format = ConfigFormat::from_content(content)
@@ -249,6 +265,40 @@ profiles:
         assert!(matches!(qos.reliability, Reliability::Reliable));
     }
 
+    #[test]
+    fn test_load_named_xml_profiles() {
+        let dds_xml = r#"<dds>
+  <qos_library name="Lib">
+    <qos_profile name="first"/>
+    <qos_profile name="telemetry_profile">
+      <datawriter_qos><reliability><kind>RELIABLE_RELIABILITY_QOS</kind></reliability></datawriter_qos>
+    </qos_profile>
+  </qos_library>
+</dds>"#;
+        let qos =
+            ProfileLoader::load_from_str(dds_xml, ConfigFormat::Xml, Some("telemetry_profile"))
+                .expect("DDS-XML profile");
+        assert!(matches!(qos.reliability, Reliability::Reliable));
+        let names: Vec<String> = ProfileLoader::xml_profiles(dds_xml)
+            .expect("profiles")
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, ["Lib::first", "Lib::telemetry_profile"]);
+
+        let fastdds = r#"<dds><profiles>
+  <data_writer profile_name="default" is_default_profile="true"/>
+  <data_writer profile_name="telemetry_profile">
+    <qos><durability><kind>TRANSIENT_LOCAL</kind></durability></qos>
+  </data_writer>
+</profiles></dds>"#;
+        let qos =
+            ProfileLoader::load_from_str(fastdds, ConfigFormat::Xml, Some("telemetry_profile"))
+                .expect("FastDDS profile");
+        assert!(matches!(qos.durability, Durability::TransientLocal));
+        assert!(ProfileLoader::load_from_str(fastdds, ConfigFormat::Xml, Some("missing")).is_err());
+    }
+
     #[test]
     fn test_is_config_file() {
         assert!(ProfileLoader::is_config_file("test.xml"));
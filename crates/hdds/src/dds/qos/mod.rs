@@ -12,6 +12,7 @@ mod liveliness;
 mod ordering;
 mod ownership;
 mod partition;
+mod policy_id;
 mod profile;
 mod reliability;
 mod transport;
@@ -33,9 +34,10 @@ pub use liveliness::{Liveliness, LivelinessKind};
 pub use ordering::{DestinationOrder, DestinationOrderKind, Presentation, PresentationAccessScope};
 pub use ownership::{Ownership, OwnershipKind, OwnershipStrength};
 pub use partition::Partition;
+pub use policy_id::QosPolicyId;
 pub use profile::QoS;
 pub use reliability::{Durability, DurabilityService, History, Reliability};
-pub use transport::TransportPriority;
+pub use transport::{Batching, TransportPriority};
 
 // Timing policies re-exported from core qos/ module (uses Duration-based types)
 pub use crate::qos::deadline::Deadline;
@@ -119,6 +119,15 @@ impl Presentation {
         }
     }
 
+    /// Create GROUP-level presentation with ordered access.
+    pub fn group_ordered() -> Self {
+        Self {
+            access_scope: PresentationAccessScope::Group,
+            coherent_access: false,
+            ordered_access: true,
+        }
+    }
+
     /// Create GROUP-level presentation with coherent and ordered access.
     pub fn group_coherent_ordered() -> Self {
         Self {
@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! QoS policy identifiers (DDS v1.4 Sec.2.3.3, `QosPolicyId_t`).
+//!
+//! Reported by the incompatible QoS statuses to name the first policy that
+//! prevented a writer and a reader from matching.
+
+use std::fmt;
+
+/// Identifier of a QoS policy, as used by `last_policy_id` in statuses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum QosPolicyId {
+    Durability = 2,
+    Presentation = 3,
+    Deadline = 4,
+    LatencyBudget = 5,
+    Ownership = 6,
+    Liveliness = 8,
+    Partition = 10,
+    Reliability = 11,
+    DestinationOrder = 12,
+    History = 13,
+}
+
+impl QosPolicyId {
+    /// Numeric policy id.
+    pub fn id(self) -> u32 {
+        self as u32
+    }
+
+    /// Policy name as spelled by the DDS specification (e.g. `"RELIABILITY"`).
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Durability => "DURABILITY",
+            Self::Presentation => "PRESENTATION",
+            Self::Deadline => "DEADLINE",
+            Self::LatencyBudget => "LATENCY_BUDGET",
+            Self::Ownership => "OWNERSHIP",
+            Self::Liveliness => "LIVELINESS",
+            Self::Partition => "PARTITION",
+            Self::Reliability => "RELIABILITY",
+            Self::DestinationOrder => "DESTINATION_ORDER",
+            Self::History => "HISTORY",
+        }
+    }
+}
+
+impl fmt::Display for QosPolicyId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
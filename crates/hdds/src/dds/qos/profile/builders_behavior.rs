@@ -48,6 +48,18 @@ impl QoS {
         self
     }
 
+    /// Set manual-by-topic liveliness from milliseconds.
+    pub fn liveliness_manual_topic_millis(mut self, ms: u64) -> Self {
+        self.liveliness = Liveliness::manual_topic_millis(ms);
+        self
+    }
+
+    /// Set manual-by-topic liveliness from seconds.
+    pub fn liveliness_manual_topic_secs(mut self, secs: u64) -> Self {
+        self.liveliness = Liveliness::manual_topic_secs(secs);
+        self
+    }
+
     /// Set ownership policy (v0.6.0+).
     ///
     /// # Examples
@@ -202,4 +214,21 @@ impl QoS {
         self.topic_data = TopicData::new(value.to_vec());
         self
     }
+
+    /// Set the ENTITY_NAME announced in discovery (`PID_ENTITY_NAME`).
+    ///
+    /// Lets tools such as admin consoles or spies show a readable name
+    /// instead of the endpoint GUID.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use hdds::api::QoS;
+    ///
+    /// let qos = QoS::reliable().entity_name("imu_publisher");
+    /// ```
+    pub fn entity_name(mut self, name: impl Into<String>) -> Self {
+        self.entity_name = Some(name.into());
+        self
+    }
 }
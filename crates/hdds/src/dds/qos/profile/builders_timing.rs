@@ -181,6 +181,12 @@ impl QoS {
         self
     }
 
+    /// Set PRESENTATION to GROUP scope with ordered access.
+    pub fn presentation_group_ordered(mut self) -> Self {
+        self.presentation = Presentation::group_ordered();
+        self
+    }
+
     /// Set PRESENTATION to GROUP scope with coherent and ordered access.
     pub fn presentation_group_coherent_ordered(mut self) -> Self {
         self.presentation = Presentation::group_coherent_ordered();
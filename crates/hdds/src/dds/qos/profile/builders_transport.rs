@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! QoS builder methods for transport policies (batching).
+
+use super::super::Batching;
+use super::structs::QoS;
+use std::time::Duration;
+
+impl QoS {
+    /// Batch small samples into RTPS messages of up to `max_bytes`, each
+    /// sample waiting at most `max_delay` before it is sent.
+    ///
+    /// Trades up to `max_delay` of latency for far fewer datagrams on topics
+    /// with tiny samples at high rates. `DataWriter::flush()` sends the
+    /// pending batch early. Only the writer's QoS matters: readers handle
+    /// batched messages whatever their own setting.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use hdds::api::QoS;
+    /// use std::time::Duration;
+    ///
+    /// // Up to 8 KB per datagram, no sample delayed more than 1 ms
+    /// let qos = QoS::best_effort().batching(8192, Duration::from_millis(1));
+    /// ```
+    pub fn batching(mut self, max_bytes: usize, max_delay: Duration) -> Self {
+        self.batching = Batching::new(max_bytes, max_delay);
+        self
+    }
+}
@@ -10,6 +10,7 @@ mod builders_behavior;
 mod builders_factory;
 mod builders_reliability;
 mod builders_timing;
+mod builders_transport;
 mod structs;
 
 pub use structs::QoS;
@@ -5,10 +5,10 @@
 //!
 
 use super::super::{
-    Deadline, DestinationOrder, Durability, DurabilityService, EntityFactory, GroupData, History,
-    LatencyBudget, Lifespan, Liveliness, Ownership, OwnershipStrength, Partition, Presentation,
-    ReaderDataLifecycle, Reliability, TimeBasedFilter, TopicData, TransportPriority, UserData,
-    WriterDataLifecycle,
+    Batching, Deadline, DestinationOrder, Durability, DurabilityService, EntityFactory, GroupData,
+    History, LatencyBudget, Lifespan, Liveliness, Ownership, OwnershipStrength, Partition,
+    Presentation, ReaderDataLifecycle, Reliability, TimeBasedFilter, TopicData, TransportPriority,
+    UserData, WriterDataLifecycle,
 };
 use crate::qos::ResourceLimits;
 
@@ -38,6 +38,10 @@ pub struct QoS {
     pub writer_data_lifecycle: WriterDataLifecycle,
     pub reader_data_lifecycle: ReaderDataLifecycle,
     pub durability_service: DurabilityService,
+    /// ENTITY_NAME announced in discovery (`PID_ENTITY_NAME`), not a DDS 1.4 policy
+    pub entity_name: Option<String>,
+    /// Writer-side batching of small samples, not a DDS 1.4 policy
+    pub batching: Batching,
 }
 
 impl QoS {
@@ -66,6 +70,8 @@ impl QoS {
             writer_data_lifecycle: WriterDataLifecycle::default(),
             reader_data_lifecycle: ReaderDataLifecycle::default(),
             durability_service: DurabilityService::default(),
+            entity_name: None,
+            batching: Batching::disabled(),
         }
     }
 
@@ -94,6 +100,8 @@ impl QoS {
             writer_data_lifecycle: WriterDataLifecycle::default(),
             reader_data_lifecycle: ReaderDataLifecycle::default(),
             durability_service: DurabilityService::default(),
+            entity_name: None,
+            batching: Batching::disabled(),
         }
     }
 
@@ -129,6 +137,8 @@ impl QoS {
             writer_data_lifecycle: WriterDataLifecycle::default(),
             reader_data_lifecycle: ReaderDataLifecycle::default(),
             durability_service: DurabilityService::default(),
+            entity_name: None,
+            batching: Batching::disabled(),
         }
     }
 
@@ -158,6 +168,7 @@ impl QoS {
     /// structure and loads the appropriate QoS profile.
     ///
     /// Currently supported vendors:
+    /// - OMG DDS-XML (`<qos_library>`: RTI Connext, Cyclone DDS)
     /// - FastDDS (eProsima)
     ///
     /// # Example
@@ -170,8 +181,35 @@ impl QoS {
     #[cfg(feature = "qos-loaders")]
     pub fn from_xml<P: AsRef<std::path::Path>>(path: P) -> Result<Self, String> {
         crate::trace_fn!("QoS::from_xml");
-        // For now, try FastDDS (can add auto-detection later)
-        Self::load_fastdds(path)
+        use crate::dds::qos::loaders::{ConfigFormat, ProfileLoader};
+        let content =
+            std::fs::read_to_string(path).map_err(|e| format!("Failed to read XML file: {}", e))?;
+        ProfileLoader::load_from_str(&content, ConfigFormat::Xml, None)
+    }
+
+    /// Look up a named profile in the process-wide profile registry.
+    ///
+    /// Profiles are registered with `ParticipantBuilder::with_qos_profile_file`
+    /// or `QosProfileRegistry::global().load_file(..)`. DDS-XML profiles can
+    /// be named `library::profile` or, when unambiguous, just `profile`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use hdds::{Participant, QoS};
+    ///
+    /// let participant = Participant::builder("app")
+    ///     .with_qos_profile_file("qos_profiles.xml")
+    ///     .build()?;
+    /// let qos = QoS::from_profile("telemetry_profile")?;
+    /// ```
+    #[cfg(feature = "qos-loaders")]
+    pub fn from_profile(name: &str) -> Result<Self, String> {
+        crate::trace_fn!("QoS::from_profile");
+        use crate::dds::qos::profiles::QosProfileRegistry;
+        QosProfileRegistry::global()
+            .get(name)
+            .ok_or_else(|| format!("QoS profile '{}' is not loaded", name))
     }
 }
 
@@ -1,11 +1,17 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 // Copyright (c) 2025-2026 naskel.com
 
-//! QoS profile registry with named profiles loaded from YAML files.
+//! QoS profile registry with named profiles loaded from YAML or XML files.
 //!
 //! Provides a registry of named QoS profiles that can be loaded from YAML files
 //! and hot-reloaded at runtime. Supports the simplified YAML format with
 //! shorthand keys like `reliability`, `history_depth`, `deadline_ms`, etc.
+//! [`QosProfileRegistry::load_file`] also accepts vendor XML: OMG DDS-XML
+//! profiles (RTI, Cyclone) are registered as `library::profile`, FastDDS
+//! profiles by `profile_name`.
+//!
+//! The process-wide registry ([`QosProfileRegistry::global`]) backs
+//! [`QoS::from_profile`] and `ParticipantBuilder::with_qos_profile_file`.
 //!
 //! # DDS Spec Mutability Constraints
 //!
@@ -31,12 +37,14 @@
 //! let qos = registry.get("high_reliability").unwrap();
 //! ```
 
+use crate::dds::qos::loaders::dds_xml::resolve_name;
 use crate::dds::qos::loaders::yaml::{YamlLoader, YamlQosDocument};
+use crate::dds::qos::loaders::{ConfigFormat, ProfileLoader};
 use crate::dds::qos::QoS;
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, OnceLock, RwLock};
 use std::time::Duration;
 
 use crate::dds::qos::{
@@ -171,7 +179,7 @@ struct SimplifiedDocument {
     profiles: HashMap<String, SimplifiedProfile>,
 }
 
-/// QoS profile registry -- stores named profiles loaded from YAML files.
+/// QoS profile registry -- stores named profiles loaded from YAML or XML files.
 ///
 /// Thread-safe via `RwLock`. Multiple readers can access profiles concurrently,
 /// while writes (load, reload) are serialized.
@@ -213,25 +221,51 @@ impl QosProfileRegistry {
             }
         }
 
-        // Track the loaded file path for reload
+        self.track_file(path)?;
+
+        Ok(count)
+    }
+
+    /// The process-wide registry used by [`QoS::from_profile`].
+    pub fn global() -> &'static QosProfileRegistry {
+        static GLOBAL: OnceLock<QosProfileRegistry> = OnceLock::new();
+        GLOBAL.get_or_init(QosProfileRegistry::new)
+    }
+
+    /// Load QoS profiles from a YAML or XML file.
+    ///
+    /// The format is detected from the extension, then from the content;
+    /// files that match neither are parsed as YAML. Profiles with the same
+    /// name as a loaded one replace it.
+    ///
+    /// Returns the number of profiles loaded.
+    pub fn load_file(&self, path: &Path) -> Result<usize, String> {
+        crate::trace_fn!("QosProfileRegistry::load_file");
+        let new_profiles = Self::parse_file(path)?;
+        let count = new_profiles.len();
+
         {
-            let mut files = self
-                .loaded_files
+            let mut profiles = self
+                .profiles
                 .write()
                 .map_err(|e| format!("Lock poisoned: {}", e))?;
-            let canonical = path.to_path_buf();
-            if !files.contains(&canonical) {
-                files.push(canonical);
-            }
+            profiles.extend(new_profiles);
         }
-
+        self.track_file(path)?;
         Ok(count)
     }
 
     /// Get a QoS profile by name. Returns `None` if not found.
+    ///
+    /// A bare name also finds a `library::name` XML profile, as long as only
+    /// one library defines it.
     pub fn get(&self, name: &str) -> Option<QoS> {
         let profiles = self.profiles.read().ok()?;
-        profiles.get(name).cloned()
+        if let Some(qos) = profiles.get(name) {
+            return Some(qos.clone());
+        }
+        let qualified = resolve_name(profiles.keys().map(String::as_str), name).ok()?;
+        profiles.get(&qualified).cloned()
     }
 
     /// List all profile names in the registry.
@@ -248,7 +282,7 @@ impl QosProfileRegistry {
 
     /// Reload all previously loaded files and compute a diff.
     ///
-    /// This re-reads every YAML file that was loaded via `load_from_yaml`
+    /// This re-reads every file loaded via `load_from_yaml` or `load_file`
     /// and returns a `ReloadResult` describing what changed.
     pub fn reload(&self) -> Result<ReloadResult, String> {
         let files: Vec<PathBuf> = {
@@ -282,20 +316,9 @@ impl QosProfileRegistry {
         let mut errors = Vec::new();
 
         for file in &files {
-            match fs::read_to_string(file) {
-                Ok(yaml_content) => match Self::parse_profiles(&yaml_content) {
-                    Ok(parsed) => {
-                        for (name, qos) in parsed {
-                            new_profiles.insert(name, qos);
-                        }
-                    }
-                    Err(e) => {
-                        errors.push(format!("Parse error in {}: {}", file.display(), e));
-                    }
-                },
-                Err(e) => {
-                    errors.push(format!("Read error for {}: {}", file.display(), e));
-                }
+            match Self::parse_file(file) {
+                Ok(parsed) => new_profiles.extend(parsed),
+                Err(e) => errors.push(format!("{}: {}", file.display(), e)),
             }
         }
 
@@ -346,6 +369,31 @@ impl QosProfileRegistry {
         })
     }
 
+    /// Remember a loaded file for reload.
+    fn track_file(&self, path: &Path) -> Result<(), String> {
+        let mut files = self
+            .loaded_files
+            .write()
+            .map_err(|e| format!("Lock poisoned: {}", e))?;
+        if !files.iter().any(|f| f == path) {
+            files.push(path.to_path_buf());
+        }
+        Ok(())
+    }
+
+    /// Read a YAML or XML profile file.
+    fn parse_file(path: &Path) -> Result<HashMap<String, QoS>, String> {
+        let content = fs::read_to_string(path).map_err(|e| format!("Read error: {}", e))?;
+        match ConfigFormat::detect(path, &content).unwrap_or(ConfigFormat::Yaml) {
+            ConfigFormat::Xml => ProfileLoader::xml_profiles(&content)
+                .map(|profiles| profiles.into_iter().collect())
+                .map_err(|e| format!("Parse error: {}", e)),
+            ConfigFormat::Yaml => {
+                Self::parse_profiles(&content).map_err(|e| format!("Parse error: {}", e))
+            }
+        }
+    }
+
     /// Parse profiles from YAML content.
     ///
     /// Tries the simplified format first, then falls back to the full
@@ -3,7 +3,10 @@
 
 //! Transport-related QoS policies.
 //!
-//! Defines policies for network priority (DSCP/ToS) mapping.
+//! Defines policies for network priority (DSCP/ToS) mapping and writer-side
+//! batching of small samples.
+
+use std::time::Duration;
 
 /// Transport priority policy used for DSCP/ToS mapping.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -53,3 +56,45 @@ impl Default for TransportPriority {
         Self::normal()
     }
 }
+
+/// Writer-side batching of small samples (vendor extension, not a DDS 1.4
+/// policy).
+///
+/// A batching writer aggregates the DATA submessages of consecutive writes
+/// into one RTPS message, sent once it would grow past `max_bytes` or once
+/// its oldest sample waited `max_delay`, instead of sending one datagram per
+/// sample. Samples large enough to be fragmented are never batched.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Batching {
+    /// Maximum size of a batched RTPS message, header included; 0 disables
+    /// batching.
+    pub max_bytes: usize,
+    /// Maximum time a sample waits in the batch before it is sent.
+    pub max_delay: Duration,
+}
+
+impl Batching {
+    /// Create BATCHING flushing at `max_bytes` or after `max_delay`.
+    pub fn new(max_bytes: usize, max_delay: Duration) -> Self {
+        Self {
+            max_bytes,
+            max_delay,
+        }
+    }
+
+    /// Create BATCHING disabled (one message per sample, the default).
+    pub fn disabled() -> Self {
+        Self::new(0, Duration::ZERO)
+    }
+
+    /// Check if writes are batched.
+    pub fn is_enabled(&self) -> bool {
+        self.max_bytes > 0
+    }
+}
+
+impl Default for Batching {
+    fn default() -> Self {
+        Self::disabled()
+    }
+}
@@ -3,12 +3,19 @@
 
 //! ReadCondition and QueryCondition for DataReader event filtering
 //!
+//! Conditions created with `DataReader::create_readcondition()` or
+//! `DataReader::create_querycondition()` are bound to their reader: their
+//! trigger value reflects the samples it holds, and they wake attached
+//! WaitSets when data arrives. Conditions built with `new()` are standalone
+//! and only change when their trigger value is set explicitly.
 
 use super::condition::Condition;
+use super::filter::{parse_expression, FilterEvaluator};
+use super::reader::{InstanceState, SampleState, ViewState};
 use crate::core::rt::waitset::WaitsetSignal;
 use std::any::Any;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, Mutex, Weak};
+use std::sync::{Arc, Mutex, OnceLock, RwLock, Weak};
 
 /// Sample state mask for ReadCondition
 ///
@@ -150,13 +157,25 @@ pub struct ReadCondition {
     /// Instance state mask
     instance_state_mask: InstanceStateMask,
 
-    /// Trigger value (updated by DataReader)
+    /// Trigger value of a standalone condition
     trigger_value: AtomicBool,
 
+    /// Samples of the DataReader the condition was created on
+    source: OnceLock<ConditionSource>,
+
     /// Waitset hooks to notify on trigger change
     waitset_signals: Mutex<Vec<ReadConditionHook>>,
 }
 
+/// Evaluates a condition against the samples of its DataReader.
+pub(crate) type HasMatching = Box<dyn Fn(&ReadCondition) -> bool + Send + Sync>;
+
+struct ConditionSource {
+    /// Identity of the owning DataReader
+    reader: usize,
+    has_matching: HasMatching,
+}
+
 impl ReadCondition {
     /// Create a new ReadCondition
     ///
@@ -191,10 +210,54 @@ impl ReadCondition {
             view_state_mask,
             instance_state_mask,
             trigger_value: AtomicBool::new(false),
+            source: OnceLock::new(),
             waitset_signals: Mutex::new(Vec::new()),
         }
     }
 
+    /// Bind the condition to the samples of a DataReader.
+    ///
+    /// The trigger value is then computed by `has_matching` on every query.
+    pub(crate) fn bind(&self, reader: usize, has_matching: HasMatching) {
+        let _ = self.source.set(ConditionSource {
+            reader,
+            has_matching,
+        });
+    }
+
+    /// Identity of the DataReader the condition is bound to, if any.
+    pub(crate) fn reader(&self) -> Option<usize> {
+        self.source.get().map(|source| source.reader)
+    }
+
+    /// Whether a sample in the given states is selected by the masks.
+    pub fn matches(&self, sample: SampleState, view: ViewState, instance: InstanceState) -> bool {
+        let sample = match sample {
+            SampleState::Read => SampleStateMask::READ,
+            SampleState::NotRead => SampleStateMask::NOT_READ,
+        };
+        let view = match view {
+            ViewState::New => ViewStateMask::NEW,
+            ViewState::NotNew => ViewStateMask::NOT_NEW,
+        };
+        let instance = match instance {
+            InstanceState::Alive => InstanceStateMask::ALIVE,
+            InstanceState::NotAliveDisposed => InstanceStateMask::NOT_ALIVE_DISPOSED,
+            InstanceState::NotAliveNoWriters => InstanceStateMask::NOT_ALIVE_NO_WRITERS,
+        };
+        self.sample_state_mask.contains(sample)
+            && self.view_state_mask.contains(view)
+            && self.instance_state_mask.contains(instance)
+    }
+
+    /// Wake attached waitsets if samples of the reader match (called when
+    /// data arrives).
+    pub(crate) fn on_data_available(&self) {
+        if self.get_trigger_value() {
+            self.notify_waitsets();
+        }
+    }
+
     /// Get the sample state mask
     pub fn get_sample_state_mask(&self) -> SampleStateMask {
         self.sample_state_mask
@@ -246,7 +309,10 @@ struct ReadConditionHook {
 
 impl Condition for ReadCondition {
     fn get_trigger_value(&self) -> bool {
-        self.trigger_value.load(Ordering::Acquire)
+        match self.source.get() {
+            Some(source) => (source.has_matching)(self),
+            None => self.trigger_value.load(Ordering::Acquire),
+        }
     }
 
     fn condition_id(&self) -> u64 {
@@ -284,6 +350,25 @@ impl Condition for ReadCondition {
     }
 }
 
+/// Condition selecting samples for `DataReader::take_w_condition()` and
+/// `DataReader::read_w_condition()`: a [`ReadCondition`] or a
+/// [`QueryCondition`].
+pub trait SampleCondition: Condition {
+    /// The state masks of the condition.
+    fn read_condition(&self) -> &ReadCondition;
+
+    /// The query samples must also satisfy, if any.
+    fn query(&self) -> Option<&FilterEvaluator> {
+        None
+    }
+}
+
+impl SampleCondition for ReadCondition {
+    fn read_condition(&self) -> &ReadCondition {
+        self
+    }
+}
+
 /// QueryCondition - ReadCondition with SQL-like query expression
 ///
 /// Per DDS v1.4 spec section 2.2.4.1.7:
@@ -291,13 +376,16 @@ impl Condition for ReadCondition {
 /// a filter on the content of the data."
 pub struct QueryCondition {
     /// Base ReadCondition
-    base: ReadCondition,
+    base: Arc<ReadCondition>,
 
     /// Query expression (SQL-like subset)
     query_expression: String,
 
     /// Query parameters
-    query_parameters: Arc<Mutex<Vec<String>>>,
+    query_parameters: Arc<RwLock<Vec<String>>>,
+
+    /// Evaluator of the parsed expression (`None` if it does not parse)
+    filter: Option<FilterEvaluator>,
 }
 
 impl QueryCondition {
@@ -329,13 +417,26 @@ impl QueryCondition {
         query_expression: String,
         query_parameters: Vec<String>,
     ) -> Self {
+        let query_parameters = Arc::new(RwLock::new(query_parameters));
+        let filter = parse_expression(&query_expression).ok().map(|expression| {
+            FilterEvaluator::new(Arc::new(expression), Arc::clone(&query_parameters))
+        });
         Self {
-            base: ReadCondition::new(sample_state_mask, view_state_mask, instance_state_mask),
+            base: Arc::new(ReadCondition::new(
+                sample_state_mask,
+                view_state_mask,
+                instance_state_mask,
+            )),
             query_expression,
-            query_parameters: Arc::new(Mutex::new(query_parameters)),
+            query_parameters,
+            filter,
         }
     }
 
+    pub(crate) fn base(&self) -> &Arc<ReadCondition> {
+        &self.base
+    }
+
     /// Get the query expression
     pub fn get_query_expression(&self) -> &str {
         &self.query_expression
@@ -344,14 +445,14 @@ impl QueryCondition {
     /// Get the query parameters
     pub fn get_query_parameters(&self) -> Vec<String> {
         self.query_parameters
-            .lock()
+            .read()
             .map(|p| p.clone())
             .unwrap_or_default()
     }
 
     /// Set new query parameters
     pub fn set_query_parameters(&self, parameters: Vec<String>) {
-        if let Ok(mut params) = self.query_parameters.lock() {
+        if let Ok(mut params) = self.query_parameters.write() {
             *params = parameters;
         }
     }
@@ -363,6 +464,17 @@ impl QueryCondition {
     }
 }
 
+impl SampleCondition for QueryCondition {
+    fn read_condition(&self) -> &ReadCondition {
+        &self.base
+    }
+
+    /// Evaluator of the query expression, using the current parameters.
+    fn query(&self) -> Option<&FilterEvaluator> {
+        self.filter.as_ref()
+    }
+}
+
 impl Condition for QueryCondition {
     fn get_trigger_value(&self) -> bool {
         self.base.get_trigger_value()
@@ -8,25 +8,45 @@
 //! before constructing a DataReader instance.
 
 use super::heartbeat::ReaderHeartbeatHandler;
+use super::hibernation::ReaderHibernation;
+use super::liveliness::{
+    spawn_liveliness_watch, LivelinessCallback, LivelinessHeartbeatHandler, LivelinessWatch,
+    SubscriptionMatchedCallback, WatchDiscoveryListener, WatchedWriter,
+};
+use super::ordered_merge::OrderedMerge;
 use super::runtime::DataReader;
+use super::sample_lost::{SampleLost, SampleLostCallback};
+use super::staleness::{spawn_staleness_watch, SampleArrivals, StalenessCallback};
 use super::subscriber::ReaderSubscriber;
 use crate::config::READER_HISTORY_RING_SIZE;
-use crate::core::discovery::GUID;
+use crate::core::discovery::{Matcher, GUID};
 use crate::core::rt;
+use crate::core::rt::clock::SystemClock;
+use crate::dds::coherent::CoherentGate;
 use crate::dds::filter::FilterEvaluator;
+use crate::dds::incompatible_qos::{
+    IncompatibleQosCallback, IncompatibleQosListener, IncompatibleQosWatch,
+    InconsistentTopicCallback,
+};
 use crate::dds::listener::DataReaderListener;
-use crate::dds::qos::{History, Reliability};
+use crate::dds::liveliness::lease_ns;
+use crate::dds::qos::{GroupData, History, LivelinessKind, Partition, Reliability};
+use crate::dds::type_versions::{self, DecodeFrom, TypeVersions, VersionCodec};
+#[cfg(feature = "wasmtime")]
+use crate::dds::WasmPlugin;
 use crate::dds::{
-    DomainState, Error, GuardCondition, MatchKey, QoS, Result, StatusCondition, StatusMask, TypeId,
-    DDS,
+    DomainState, EndpointKind, Error, GuardCondition, MatchKey, QoS, Result, StatusCondition,
+    StatusMask, Subscriber, TypeId, DDS,
 };
 use crate::engine::TopicRegistry;
 use crate::reliability::{NackScheduler, ReliableMetrics};
+use crate::telemetry::{EndpointMetrics, EndpointRole};
 #[cfg(target_os = "linux")]
 use crate::transport::shm::ShmPolicy;
 use crate::transport::UdpTransport;
 use crate::xtypes::CompleteTypeObject;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 pub struct ReaderBuilder<T: DDS> {
     pub(super) topic: String,
@@ -45,6 +65,25 @@ pub struct ReaderBuilder<T: DDS> {
     pub(super) content_filter: Option<FilterEvaluator>,
     /// Listener for data callbacks
     pub(super) listener: Option<Arc<dyn DataReaderListener<T>>>,
+    /// Schema version of `T` (side-by-side type versions)
+    pub(super) type_version: Option<u32>,
+    /// Extra versions accepted and converted into `T`
+    pub(super) accepted_versions: Vec<VersionCodec<DecodeFrom<T>>>,
+    /// PARTITION inherited from the Subscriber
+    pub(super) subscriber_partition: Option<Partition>,
+    /// GROUP_DATA inherited from the Subscriber
+    pub(super) subscriber_group_data: Option<GroupData>,
+    /// ENTITY_NAME set with `ReaderBuilder::name`
+    pub(super) entity_name: Option<String>,
+    /// Coherent set gate of the Subscriber
+    pub(super) coherent_gate: Option<Arc<CoherentGate>>,
+    /// Staleness watchdog windows
+    pub(super) staleness_windows: Vec<Duration>,
+    /// Reordering window of the ordered merge across writers
+    pub(super) ordered_merge_window: Option<Duration>,
+    /// WASM filter/transform run on received payloads
+    #[cfg(feature = "wasmtime")]
+    pub(super) wasm_plugin: Option<Arc<WasmPlugin>>,
     pub(super) _phantom: core::marker::PhantomData<T>,
 }
 
@@ -64,6 +103,16 @@ impl<T: DDS> ReaderBuilder<T> {
             shm_policy: ShmPolicy::default(),
             content_filter: None,
             listener: None,
+            type_version: None,
+            accepted_versions: Vec::new(),
+            subscriber_partition: None,
+            subscriber_group_data: None,
+            entity_name: None,
+            coherent_gate: None,
+            staleness_windows: Vec::new(),
+            ordered_merge_window: None,
+            #[cfg(feature = "wasmtime")]
+            wasm_plugin: None,
             _phantom: core::marker::PhantomData,
         }
     }
@@ -78,6 +127,14 @@ impl<T: DDS> ReaderBuilder<T> {
         self
     }
 
+    /// Name the reader in discovery (ENTITY_NAME, see [`QoS::entity_name`]).
+    ///
+    /// Takes precedence over the name set in the QoS.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.entity_name = Some(name.into());
+        self
+    }
+
     /// Set SHM transport policy (Linux only).
     ///
     /// - `Prefer` (default): Use SHM if same-host + BestEffort, fallback to UDP
@@ -159,7 +216,111 @@ impl<T: DDS> ReaderBuilder<T> {
         self
     }
 
+    /// Report when no sample arrives within `window`.
+    ///
+    /// The reader goes stale once `window` elapses without a sample (or
+    /// since creation, before the first one) and fresh again on the next
+    /// sample. Both changes raise `StatusMask::SAMPLE_STALENESS_CHANGED`
+    /// and call the listener's `on_sample_staleness_changed`. Call it once
+    /// per window to watch several.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let reader = participant
+    ///     .create_reader::<Pose>("robot/pose", QoS::best_effort())
+    ///     .with_staleness_watchdog(Duration::from_millis(500)) // UI warning
+    ///     .with_staleness_watchdog(Duration::from_secs(5)) // link lost
+    ///     .build()?;
+    /// ```
+    pub fn with_staleness_watchdog(mut self, window: Duration) -> Self {
+        self.staleness_windows.push(window);
+        self
+    }
+
+    /// Deliver samples ordered by source timestamp across all matched
+    /// writers.
+    ///
+    /// Each sample is held back for `window` after it arrives, then
+    /// released after every older sample, so redundant publishers of a
+    /// topic read as a single time-ordered stream. Samples without a
+    /// source timestamp are ordered by reception time. A sample arriving
+    /// after a newer one was delivered is dropped: choose a `window` above
+    /// the skew between writers plus network jitter. The reader stays
+    /// DATA_AVAILABLE while samples are held.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let reader = participant
+    ///     .create_reader::<Imu>("robot/imu", QoS::reliable())
+    ///     .with_ordered_merge(Duration::from_millis(20))
+    ///     .build()?;
+    /// ```
+    pub fn with_ordered_merge(mut self, window: Duration) -> Self {
+        self.ordered_merge_window = Some(window);
+        self
+    }
+
+    /// Declare the schema version of `T`.
+    ///
+    /// When a writer publishes several versions of the same sample, this
+    /// reader keeps only the copy in the highest version both sides
+    /// support. Untagged samples from unversioned writers are always kept.
+    pub fn type_version(mut self, version: u32) -> Self {
+        self.type_version = Some(version);
+        self
+    }
+
+    /// Also accept samples published as `version` of type `V`, converted
+    /// into `T` with `convert`. Requires [`type_version`](Self::type_version).
+    pub fn accept_type_version<V: DDS>(
+        mut self,
+        version: u32,
+        convert: impl Fn(V) -> T + Send + Sync + 'static,
+    ) -> Self {
+        self.accepted_versions
+            .push(type_versions::decode_from(version, convert));
+        self
+    }
+
+    /// Run `plugin` on every received sample before it is decoded.
+    ///
+    /// The plugin sees the serialized payload and may drop or rewrite it
+    /// (see [`WasmPlugin`]). Zero-copy loans (`take_loaned`) bypass it.
+    #[cfg(feature = "wasmtime")]
+    pub fn wasm_plugin(mut self, plugin: Arc<WasmPlugin>) -> Self {
+        self.wasm_plugin = Some(plugin);
+        self
+    }
+
+    /// Create the reader as part of `subscriber`.
+    ///
+    /// The reader inherits the subscriber's PARTITION and GROUP_DATA unless
+    /// its own QoS sets them, and coherent sets are held back until complete (see
+    /// [`Subscriber::begin_access`]).
+    pub fn subscriber(mut self, subscriber: &Subscriber) -> Self {
+        self.subscriber_partition = Some(subscriber.qos().partition.clone());
+        self.subscriber_group_data = Some(subscriber.qos().group_data.clone());
+        self.coherent_gate = subscriber.coherent_gate().cloned();
+        self
+    }
+
     pub fn build(mut self) -> Result<DataReader<T>> {
+        if let Some(partition) = self.subscriber_partition.take() {
+            if self.qos.partition.is_default() {
+                self.qos.partition = partition;
+            }
+        }
+        if let Some(group_data) = self.subscriber_group_data.take() {
+            if self.qos.group_data.is_empty() {
+                self.qos.group_data = group_data;
+            }
+        }
+        if let Some(name) = self.entity_name.take() {
+            self.qos.entity_name = Some(name);
+        }
+
         // Extract configs from participant if not explicitly provided
         // (mirrors WriterBuilder behavior for API consistency)
         if let Some(ref participant) = self.participant {
@@ -181,6 +342,20 @@ impl<T: DDS> ReaderBuilder<T> {
             }
         }
 
+        // Endpoint hooks: may adjust the QoS or reject the reader
+        if let Some(ref participant) = self.participant {
+            let type_name = self
+                .type_name_override
+                .as_deref()
+                .unwrap_or(T::type_descriptor().type_name);
+            participant.run_endpoint_hooks(
+                EndpointKind::Reader,
+                &self.topic,
+                type_name,
+                &mut self.qos,
+            )?;
+        }
+
         // DDS Security: Check access control permissions for reader creation
         #[cfg(feature = "security")]
         if let Some(ref participant) = self.participant {
@@ -188,6 +363,15 @@ impl<T: DDS> ReaderBuilder<T> {
             participant.check_create_reader(&self.topic, partition)?;
         }
 
+        // Static deployment: only declared readers, and none once sealed
+        if let Some(ref participant) = self.participant {
+            let type_name = self
+                .type_name_override
+                .as_deref()
+                .unwrap_or(T::type_descriptor().type_name);
+            participant.admit_static_endpoint(EndpointKind::Reader, &self.topic, type_name)?;
+        }
+
         let ReaderBuilder {
             topic,
             qos,
@@ -200,9 +384,21 @@ impl<T: DDS> ReaderBuilder<T> {
             type_object_override,
             content_filter,
             listener,
+            type_version,
+            accepted_versions,
+            coherent_gate,
+            staleness_windows,
+            ordered_merge_window,
+            #[cfg(target_os = "linux")]
+            shm_policy,
+            #[cfg(feature = "wasmtime")]
+            wasm_plugin,
             ..
         } = self;
 
+        let type_versions =
+            TypeVersions::from_builder(&topic, type_version, accepted_versions)?.map(Arc::new);
+
         if matches!(qos.history, History::KeepLast(0)) {
             return Err(Error::InvalidState(
                 "History::KeepLast requires depth > 0".to_string(),
@@ -227,25 +423,62 @@ impl<T: DDS> ReaderBuilder<T> {
         let status_condition = Arc::new(StatusCondition::new());
         status_condition.set_enabled_statuses(StatusMask::DATA_AVAILABLE);
 
+        let coherent = coherent_gate
+            .map(|gate| gate.register(&topic, Arc::clone(&ring), Arc::clone(&status_condition)));
+
+        // Arrival metrics and staleness watchdogs
+        if staleness_windows.iter().any(Duration::is_zero) {
+            return Err(Error::InvalidState(
+                "Staleness watchdog window must be > 0".to_string(),
+            ));
+        }
+        let on_staleness = listener.clone().map(|listener| {
+            Box::new(move |status| listener.on_sample_staleness_changed(status))
+                as StalenessCallback
+        });
+        let arrivals = Arc::new(SampleArrivals::new(
+            &staleness_windows,
+            on_staleness,
+            Arc::clone(&status_condition),
+        ));
+
+        let is_reliable = matches!(qos.reliability, Reliability::Reliable);
+        let reliable_metrics = is_reliable.then(|| Arc::new(ReliableMetrics::new()));
+        // Traffic counters for the admin API, listed once the reader has a GUID
+        let endpoint_metrics = Arc::new(EndpointMetrics::new(
+            EndpointRole::Reader,
+            &topic,
+            reliable_metrics.clone(),
+        ));
+
         if let Some(ref registry) = registry {
-            let subscriber: Arc<dyn crate::engine::Subscriber> =
-                Arc::new(ReaderSubscriber::<T>::new(
-                    topic.clone(),
-                    Arc::clone(&ring),
-                    Arc::clone(&status_condition),
-                    participant_guard.as_ref().map(Arc::clone),
-                    content_filter.clone(),
-                    listener.clone(),
-                ));
+            let mut reader_subscriber = ReaderSubscriber::<T>::new(
+                topic.clone(),
+                Arc::clone(&ring),
+                Arc::clone(&status_condition),
+                participant_guard.as_ref().map(Arc::clone),
+                content_filter.clone(),
+                listener.clone(),
+                type_versions.clone(),
+            );
+            if let Some(ref membership) = coherent {
+                reader_subscriber = reader_subscriber.with_coherent((**membership).clone());
+            }
+            reader_subscriber = reader_subscriber.with_arrivals(Arc::clone(&arrivals));
+            reader_subscriber = reader_subscriber.with_partition(qos.partition.clone());
+            reader_subscriber = reader_subscriber.with_metrics(Arc::clone(&endpoint_metrics));
+            #[cfg(feature = "security")]
+            if let Some(ref participant) = participant {
+                reader_subscriber =
+                    reader_subscriber.with_protection(participant.topic_protection(&topic)?);
+            }
+            let subscriber: Arc<dyn crate::engine::Subscriber> = Arc::new(reader_subscriber);
 
             if let Err(err) = registry.register_subscriber(subscriber) {
                 log::debug!("Failed to register subscriber: {}", err);
             }
         }
 
-        let is_reliable = matches!(qos.reliability, Reliability::Reliable);
-        let reliable_metrics = is_reliable.then(|| Arc::new(ReliableMetrics::new()));
-
         let nack_scheduler = if is_reliable {
             let scheduler = Arc::new(Mutex::new(NackScheduler::new()));
 
@@ -304,18 +537,25 @@ impl<T: DDS> ReaderBuilder<T> {
         };
 
         // v108: Announce Reader endpoint via SEDP (if participant available)
-        if let Some(ref p) = participant {
-            if let Some(ref type_name) = type_name_override {
+        let endpoint_guid = match participant {
+            Some(ref p) => Some(if let Some(ref type_name) = type_name_override {
                 p.announce_reader_endpoint_with_type::<T>(
                     &topic,
                     &qos,
                     type_name,
                     type_object_override.clone(),
-                )?;
+                    type_versions.as_deref().map(TypeVersions::announcement),
+                )?
             } else {
-                p.announce_reader_endpoint::<T>(&topic, &qos)?;
-            }
-        }
+                p.announce_reader_endpoint::<T>(
+                    &topic,
+                    &qos,
+                    type_versions.as_deref().map(TypeVersions::announcement),
+                )?
+            }),
+            None => None,
+        };
+        let metrics_registration = endpoint_guid.map(|guid| endpoint_metrics.register(guid));
 
         // Register reader in domain state for intra-process auto-binding
         let bind_token = if let Some(ref domain_state) = domain_state {
@@ -325,8 +565,8 @@ impl<T: DDS> ReaderBuilder<T> {
             let type_id = TypeId::from_type_name(type_name);
             let key = MatchKey::new(topic.as_str(), type_id);
 
-            // Generate a GUID for this reader endpoint (use zero if no participant)
-            let guid = GUID::zero();
+            // SEDP endpoint GUID (zero if no participant)
+            let guid = endpoint_guid.unwrap_or_else(GUID::zero);
 
             log::debug!(
                 "[ReaderBuilder] Registering reader in domain {} for topic='{}' type='{}'",
@@ -338,21 +578,27 @@ impl<T: DDS> ReaderBuilder<T> {
             // Create bind callback that will be called for each matching writer
             let ring_clone = Arc::clone(&ring);
             let status_condition_clone = Arc::clone(&status_condition);
+            let arrivals_clone = Arc::clone(&arrivals);
+            let metrics_clone = Arc::clone(&endpoint_metrics);
 
-            Some(domain_state.register_reader(
+            Some(domain_state.register_reader_with_partition(
                 key,
                 guid,
                 ring.clone(),
                 qos.reliability,
+                qos.partition.clone(),
                 move |writer_merger| {
                     // Create notification callback for status condition
                     let status_condition_for_notify = Arc::clone(&status_condition_clone);
+                    let arrivals_for_notify = Arc::clone(&arrivals_clone);
                     let notify: Arc<dyn Fn() + Send + Sync> = Arc::new(move || {
+                        arrivals_for_notify.record();
                         status_condition_for_notify.set_active_statuses(StatusMask::DATA_AVAILABLE);
                     });
 
                     // Register this reader with the writer's merger
-                    let registration = rt::MergerReader::new(Arc::clone(&ring_clone), notify);
+                    let registration = rt::MergerReader::new(Arc::clone(&ring_clone), notify)
+                        .with_metrics(Arc::clone(&metrics_clone));
                     writer_merger.add_reader(registration);
                 },
             ))
@@ -360,11 +606,196 @@ impl<T: DDS> ReaderBuilder<T> {
             None
         };
 
+        // LIVELINESS: track local writers through their shared assertions,
+        // discovered ones through their HEARTBEATs
+        let liveliness = match (&domain_state, &participant) {
+            (None, None) => None,
+            _ => {
+                let type_name = type_name_override
+                    .as_deref()
+                    .unwrap_or(T::type_descriptor().type_name);
+                let key = MatchKey::new(topic.as_str(), TypeId::from_type_name(type_name));
+                let local = domain_state.clone();
+                let fsm = participant.as_ref().and_then(|p| p.discovery_fsm.clone());
+                let scan_topic = topic.clone();
+                let reliability = qos.reliability;
+                let partition = qos.partition.clone();
+                let reader_qos = qos.clone();
+                let on_change = listener.clone().map(|listener| {
+                    Box::new(move |status| listener.on_liveliness_changed(status))
+                        as LivelinessCallback
+                });
+                let on_matched = listener.clone().map(|listener| {
+                    Box::new(move |status| listener.on_subscription_matched(status))
+                        as SubscriptionMatchedCallback
+                });
+                let watch = Arc::new(
+                    LivelinessWatch::new(
+                        Box::new(move || {
+                            let mut writers: Vec<WatchedWriter> = local
+                                .as_ref()
+                                .map(|local| {
+                                    local
+                                        .find_writer_liveliness(&key, reliability, &partition)
+                                        .into_iter()
+                                        .filter(|(guid, _)| *guid != GUID::zero())
+                                        .map(|(guid, liveliness)| match liveliness {
+                                            Some(liveliness) => {
+                                                WatchedWriter::local(guid, liveliness)
+                                            }
+                                            None => WatchedWriter {
+                                                guid,
+                                                kind: LivelinessKind::Automatic,
+                                                lease_ns: None,
+                                                local: None,
+                                            },
+                                        })
+                                        .collect()
+                                })
+                                .unwrap_or_default();
+                            if let Some(ref fsm) = fsm {
+                                for w in fsm.find_writers_for_topic(&scan_topic) {
+                                    let compatible =
+                                        Matcher::incompatible_policy(&reader_qos, &w.qos).is_none();
+                                    if !compatible
+                                        || !w.qos.partition.is_compatible_with(&partition)
+                                        || writers.iter().any(|l| l.guid == w.endpoint_guid)
+                                    {
+                                        continue;
+                                    }
+                                    writers.push(WatchedWriter {
+                                        guid: w.endpoint_guid,
+                                        kind: w.qos.liveliness.kind,
+                                        lease_ns: lease_ns(&w.qos.liveliness),
+                                        local: None,
+                                    });
+                                }
+                            }
+                            writers
+                        }),
+                        on_change,
+                        Arc::clone(&status_condition),
+                    )
+                    .with_matched_callback(on_matched)
+                    .with_clock(
+                        participant
+                            .as_ref()
+                            .map_or_else(SystemClock::shared, |p| p.clock()),
+                    ),
+                );
+                if let Some(ref registry) = registry {
+                    registry.register_heartbeat_handler(Arc::new(LivelinessHeartbeatHandler::new(
+                        &watch,
+                    )));
+                }
+                if let Some(fsm) = participant.as_ref().and_then(|p| p.discovery_fsm.as_ref()) {
+                    fsm.register_listener(Arc::new(WatchDiscoveryListener::new(
+                        topic.clone(),
+                        &watch,
+                    )));
+                }
+                Some(watch)
+            }
+        };
+        let liveliness_watch = liveliness
+            .as_ref()
+            .filter(|_| !qos.liveliness.is_infinite() || listener.is_some())
+            .map(|watch| spawn_liveliness_watch(Arc::clone(watch)));
+
+        // REQUESTED_INCOMPATIBLE_QOS and INCONSISTENT_TOPIC: check writers
+        // found so far and later ones
+        let incompatible_qos = participant
+            .as_ref()
+            .and_then(|p| p.discovery_fsm.clone().map(|fsm| (p, fsm)))
+            .map(|(p, fsm)| {
+                let on_incompatible = listener.clone().map(|listener| {
+                    Box::new(move |status, _policy| listener.on_requested_incompatible_qos(status))
+                        as IncompatibleQosCallback
+                });
+                let on_inconsistent = listener.clone().map(|listener| {
+                    Box::new(move |status| listener.on_inconsistent_topic(status))
+                        as InconsistentTopicCallback
+                });
+                let mut types = vec![p.resolve_type_info::<T>(
+                    &topic,
+                    type_name_override.as_deref(),
+                    type_object_override.clone(),
+                )];
+                if let Some(ref versions) = type_versions {
+                    types.extend(
+                        versions
+                            .announcement()
+                            .versions
+                            .into_iter()
+                            .map(|v| (v.type_name, v.type_object)),
+                    );
+                }
+                let watch = Arc::new(
+                    IncompatibleQosWatch::new(
+                        topic.clone(),
+                        crate::core::discovery::multicast::EndpointKind::Reader,
+                        qos.clone(),
+                        on_incompatible,
+                        Some(Arc::clone(&status_condition)),
+                    )
+                    .with_types(types, on_inconsistent),
+                );
+                fsm.register_listener(Arc::new(IncompatibleQosListener::new(&watch)));
+                for writer in fsm.find_writers_for_topic(&topic) {
+                    watch.check(&writer);
+                }
+                watch
+            });
+
+        // SAMPLE_LOST: sequences the NACK scheduler gives up on
+        let sample_lost = nack_scheduler.as_ref().map(|_| {
+            let on_lost = listener.clone().map(|listener| {
+                Box::new(move |status| listener.on_sample_lost(status)) as SampleLostCallback
+            });
+            SampleLost::new(on_lost, Arc::clone(&status_condition))
+        });
+
+        // Zero-copy loans: attach to SHM rings of local and discovered
+        // writers. BestEffort only, like the SHM transport itself.
+        #[cfg(target_os = "linux")]
+        let shm_loans = match &domain_state {
+            Some(domain_state)
+                if shm_policy != ShmPolicy::Disable
+                    && participant.as_ref().is_none_or(|p| p.shm_enabled())
+                    && matches!(qos.reliability, Reliability::BestEffort) =>
+            {
+                let type_name = type_name_override
+                    .as_deref()
+                    .unwrap_or(T::type_descriptor().type_name);
+                let key = MatchKey::new(topic.as_str(), TypeId::from_type_name(type_name));
+                let local = Arc::clone(domain_state);
+                let fsm = participant.as_ref().and_then(|p| p.discovery_fsm.clone());
+                let scan_topic = topic.clone();
+                Some(super::loan::ShmLoanReader::new(
+                    domain_state.domain_id,
+                    &topic,
+                    Box::new(move || {
+                        let mut guids = local.find_writer_guids(&key);
+                        if let Some(ref fsm) = fsm {
+                            guids.extend(
+                                fsm.find_writers_for_topic(&scan_topic)
+                                    .into_iter()
+                                    .map(|w| w.endpoint_guid),
+                            );
+                        }
+                        guids.retain(|guid| *guid != GUID::zero());
+                        guids
+                    }),
+                ))
+            }
+            _ => None,
+        };
+
         // Get security plugin from participant if available
         #[cfg(feature = "security")]
         let security = participant.as_ref().and_then(|p| p.security());
 
-        Ok(DataReader::new(
+        let mut reader = DataReader::new(
             topic,
             qos,
             ring,
@@ -376,6 +807,33 @@ impl<T: DDS> ReaderBuilder<T> {
             bind_token,
             #[cfg(feature = "security")]
             security,
-        ))
+        );
+        reader.coherent = coherent;
+        reader.ordered_merge = ordered_merge_window.map(OrderedMerge::new);
+        reader.liveliness = liveliness;
+        reader._liveliness_watch = liveliness_watch;
+        reader.incompatible_qos = incompatible_qos;
+        reader.sample_lost = sample_lost;
+        if arrivals.has_watchdogs() {
+            reader._staleness_watch = Some(spawn_staleness_watch(Arc::clone(&arrivals)));
+        }
+        if let Some(hibernator) = participant.as_ref().and_then(|p| p.hibernator()) {
+            let hibernation: Arc<dyn crate::dds::Hibernate> =
+                Arc::new(ReaderHibernation::new(&reader.cache, Arc::clone(&arrivals)));
+            hibernator.register(crate::dds::EndpointKind::Reader, &hibernation);
+            reader._hibernation = Some(hibernation);
+        }
+        reader.arrivals = arrivals;
+        reader.endpoint_metrics = endpoint_metrics;
+        reader._metrics_registration = metrics_registration;
+        #[cfg(feature = "wasmtime")]
+        {
+            reader.wasm_plugin = wasm_plugin;
+        }
+        #[cfg(target_os = "linux")]
+        {
+            reader.shm_loans = shm_loans;
+        }
+        Ok(reader)
     }
 }
@@ -18,8 +18,10 @@
 //! take()  -> removes sample, advances take_cursor
 //! ```
 
+use crate::core::discovery::GUID;
+use crate::engine::RxTimestamps;
 use parking_lot::Mutex;
-use std::collections::VecDeque;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
 /// Sample state per DDS spec (NOT_READ vs READ).
@@ -31,6 +33,38 @@ pub enum SampleState {
     Read,
 }
 
+/// View state per DDS spec (NEW vs NOT_NEW).
+///
+/// An instance is NEW until a sample of it has been read or taken, and
+/// becomes NEW again when it comes back to life after a dispose or
+/// unregister.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViewState {
+    /// No sample of the current incarnation of the instance was accessed yet.
+    New,
+    /// The instance has been read or taken since it (re)appeared.
+    NotNew,
+}
+
+/// Instance state per DDS spec (ALIVE, NOT_ALIVE_DISPOSED, NOT_ALIVE_NO_WRITERS).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InstanceState {
+    /// The instance has been written and not disposed or unregistered since.
+    #[default]
+    Alive,
+    /// A writer disposed the instance.
+    NotAliveDisposed,
+    /// Every writer of the instance unregistered it without disposing it.
+    NotAliveNoWriters,
+}
+
+impl InstanceState {
+    /// True for [`InstanceState::Alive`].
+    pub fn is_alive(self) -> bool {
+        self == Self::Alive
+    }
+}
+
 /// Instance handle for keyed topics (16-byte key hash).
 ///
 /// This is the DDS-standard instance identifier computed from @key fields.
@@ -58,6 +92,56 @@ impl InstanceHandle {
     pub fn as_bytes(&self) -> &[u8; 16] {
         &self.0
     }
+
+    /// Compute the handle from the big-endian CDR serialization of the key
+    /// fields (DDS-RTPS Sec.9.6.3.8 KeyHash).
+    ///
+    /// Keys that serialize to 16 bytes or less are used as-is, zero-padded;
+    /// longer keys are hashed with MD5.
+    pub fn from_serialized_key(key: &[u8]) -> Self {
+        let mut hash = [0u8; 16];
+        if key.len() <= 16 {
+            hash[..key.len()].copy_from_slice(key);
+        } else {
+            use md5::{Digest, Md5};
+            hash.copy_from_slice(&Md5::digest(key));
+        }
+        Self(hash)
+    }
+}
+
+/// Per-sample metadata returned alongside data by `take_with_info()`,
+/// `take_n()` and `read_n()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SampleInfo {
+    /// Sequence number from writer.
+    pub seq: u64,
+    /// Reception timestamp (nanoseconds since epoch), taken when the sample
+    /// was queued for this reader.
+    pub timestamp_ns: u64,
+    /// Writer-side timestamp (nanoseconds since epoch) from the sample's
+    /// INFO_TS submessage, if the writer sent one; the write time for
+    /// same-process writers.
+    pub source_timestamp_ns: Option<u64>,
+    /// Time the kernel received the packet (nanoseconds since epoch), for
+    /// samples received over UDP on Linux.
+    ///
+    /// `kernel_rx_timestamp_ns - source_timestamp_ns` is the time spent on the
+    /// network, `timestamp_ns - kernel_rx_timestamp_ns` the time spent in the
+    /// receive path before the sample reached this reader.
+    pub kernel_rx_timestamp_ns: Option<u64>,
+    /// Instance handle (key hash for keyed topics).
+    pub instance_handle: InstanceHandle,
+    /// Trace id attached by the writer via `write_with_trace_id()`, if any.
+    pub trace_id: Option<u64>,
+    /// State of the sample's instance when the sample was taken.
+    pub instance_state: InstanceState,
+    /// Whether the sample had been read before this access.
+    pub sample_state: SampleState,
+    /// Whether a sample of the instance had been accessed before this one.
+    pub view_state: ViewState,
+    /// GUID of the writer that sent the sample, if known.
+    pub writer_guid: Option<GUID>,
 }
 
 /// Cached sample with metadata.
@@ -71,6 +155,10 @@ pub struct CachedSample<T> {
     pub timestamp_ns: u64,
     /// Instance handle (key hash for keyed topics).
     pub instance_handle: InstanceHandle,
+    /// Per-sample trace id propagated from the writer.
+    pub trace_id: Option<u64>,
+    /// Source and kernel receive timestamps of network samples.
+    pub rx_timestamps: RxTimestamps,
     /// Sample state (NOT_READ vs READ).
     state: AtomicBool, // false = NotRead, true = Read
 }
@@ -82,6 +170,8 @@ impl<T: Clone> Clone for CachedSample<T> {
             seq: self.seq,
             timestamp_ns: self.timestamp_ns,
             instance_handle: self.instance_handle,
+            trace_id: self.trace_id,
+            rx_timestamps: self.rx_timestamps,
             state: AtomicBool::new(self.state.load(Ordering::Relaxed)),
         }
     }
@@ -96,6 +186,8 @@ impl<T> CachedSample<T> {
             seq,
             timestamp_ns,
             instance_handle: InstanceHandle::nil(),
+            trace_id: None,
+            rx_timestamps: RxTimestamps::NONE,
             state: AtomicBool::new(false), // NotRead
         }
     }
@@ -112,10 +204,62 @@ impl<T> CachedSample<T> {
             seq,
             timestamp_ns,
             instance_handle,
+            trace_id: None,
+            rx_timestamps: RxTimestamps::NONE,
             state: AtomicBool::new(false), // NotRead
         }
     }
 
+    /// Attach the writer's trace id (if any).
+    pub fn with_trace_id(mut self, trace_id: Option<u64>) -> Self {
+        self.trace_id = trace_id;
+        self
+    }
+
+    /// Attach the receive timestamps recorded by the transport.
+    pub fn with_rx_timestamps(mut self, rx_timestamps: RxTimestamps) -> Self {
+        self.rx_timestamps = rx_timestamps;
+        self
+    }
+
+    /// Whether both samples may come from the same write: equal source
+    /// timestamps, or at least one without.
+    fn same_source_time(&self, other: &Self) -> bool {
+        match (self.rx_timestamps.source_ns, other.rx_timestamps.source_ns) {
+            (Some(a), Some(b)) => a == b,
+            _ => true,
+        }
+    }
+
+    /// Metadata view of this sample.
+    pub fn info(&self) -> SampleInfo {
+        SampleInfo {
+            seq: self.seq,
+            timestamp_ns: self.timestamp_ns,
+            source_timestamp_ns: self.rx_timestamps.source_ns,
+            kernel_rx_timestamp_ns: self.rx_timestamps.kernel_rx_ns,
+            instance_handle: self.instance_handle,
+            trace_id: self.trace_id,
+            instance_state: InstanceState::Alive,
+            sample_state: self.sample_state(),
+            view_state: ViewState::New,
+            writer_guid: self.rx_timestamps.writer_guid,
+        }
+    }
+
+    /// Metadata view of this sample with the states it had in its cache.
+    fn info_with_states(
+        &self,
+        (sample_state, view_state, instance_state): (SampleState, ViewState, InstanceState),
+    ) -> SampleInfo {
+        SampleInfo {
+            sample_state,
+            view_state,
+            instance_state,
+            ..self.info()
+        }
+    }
+
     /// Get sample state.
     pub fn sample_state(&self) -> SampleState {
         if self.state.load(Ordering::Relaxed) {
@@ -142,6 +286,17 @@ pub struct SampleCache<T> {
     read_cursor: AtomicUsize,
     /// Maximum number of samples to keep (history depth).
     max_samples: usize,
+    /// Maximum number of samples kept per instance (keyed KEEP_LAST).
+    per_instance_depth: Option<usize>,
+    /// Lifecycle state of every instance seen so far.
+    instances: Mutex<HashMap<InstanceHandle, InstanceState>>,
+    /// Instances accessed since they (re)appeared (view state NOT_NEW).
+    viewed: Mutex<HashSet<InstanceHandle>>,
+    /// Writers that wrote each instance and did not unregister it since
+    /// (`None` for writers of unknown GUID).
+    writers: Mutex<HashMap<InstanceHandle, HashSet<Option<GUID>>>>,
+    /// Autopurge deadline (ns) of NOT_ALIVE instances.
+    purges: Mutex<HashMap<InstanceHandle, u64>>,
     /// Total samples received (for stats).
     total_received: AtomicUsize,
 }
@@ -153,6 +308,27 @@ impl<T> SampleCache<T> {
             buffer: Mutex::new(VecDeque::with_capacity(max_samples)),
             read_cursor: AtomicUsize::new(0),
             max_samples,
+            per_instance_depth: None,
+            instances: Mutex::new(HashMap::new()),
+            viewed: Mutex::new(HashSet::new()),
+            writers: Mutex::new(HashMap::new()),
+            purges: Mutex::new(HashMap::new()),
+            total_received: AtomicUsize::new(0),
+        }
+    }
+
+    /// Create a cache for a keyed topic: KEEP_LAST `depth` applies to each
+    /// instance, `max_samples` bounds the cache as a whole.
+    pub fn with_instance_depth(max_samples: usize, depth: usize) -> Self {
+        Self {
+            buffer: Mutex::new(VecDeque::new()),
+            read_cursor: AtomicUsize::new(0),
+            max_samples,
+            per_instance_depth: Some(depth),
+            instances: Mutex::new(HashMap::new()),
+            viewed: Mutex::new(HashSet::new()),
+            writers: Mutex::new(HashMap::new()),
+            purges: Mutex::new(HashMap::new()),
             total_received: AtomicUsize::new(0),
         }
     }
@@ -163,12 +339,34 @@ impl<T> SampleCache<T> {
     pub fn push(&self, sample: CachedSample<T>) {
         let mut buffer = self.buffer.lock();
 
-        // Dedup: reject if a sample with same seq already in buffer
-        if buffer.iter().any(|s| s.seq == sample.seq) {
+        // Dedup: reject if a sample with same seq already in buffer. Samples
+        // of different writers may share a seq; their source timestamps
+        // tell them apart when both have one.
+        if buffer
+            .iter()
+            .any(|s| s.seq == sample.seq && s.same_source_time(&sample))
+        {
             log::warn!("[CACHE] dedup: dropping duplicate seq={}", sample.seq);
             return;
         }
 
+        // Enforce per-instance history depth (keyed topics)
+        if let Some(depth) = self.per_instance_depth {
+            let handle = sample.instance_handle;
+            while buffer
+                .iter()
+                .filter(|s| s.instance_handle == handle)
+                .count()
+                >= depth
+            {
+                let Some(pos) = buffer.iter().position(|s| s.instance_handle == handle) else {
+                    break;
+                };
+                buffer.remove(pos);
+                self.adjust_cursor_after_remove(pos);
+            }
+        }
+
         // Enforce history depth
         while buffer.len() >= self.max_samples {
             buffer.pop_front();
@@ -179,12 +377,164 @@ impl<T> SampleCache<T> {
             }
         }
 
+        // A new sample (re)activates its instance, which is NEW again if it
+        // was not alive before
+        let previous = self
+            .instances
+            .lock()
+            .insert(sample.instance_handle, InstanceState::Alive);
+        if previous != Some(InstanceState::Alive) {
+            self.viewed.lock().remove(&sample.instance_handle);
+            self.purges.lock().remove(&sample.instance_handle);
+        }
+        self.writers
+            .lock()
+            .entry(sample.instance_handle)
+            .or_default()
+            .insert(sample.rx_timestamps.writer_guid);
+
         buffer.push_back(sample);
         self.total_received.fetch_add(1, Ordering::Relaxed);
     }
 
-    /// Number of samples currently in cache.
+    fn adjust_cursor_after_remove(&self, pos: usize) {
+        let cursor = self.read_cursor.load(Ordering::Relaxed);
+        if pos < cursor {
+            self.read_cursor.store(cursor - 1, Ordering::Relaxed);
+        }
+    }
+
+    /// Record a dispose or unregister of `handle` received from a writer.
+    ///
+    /// The instance's cached samples are dropped by
+    /// [`purge_expired`](Self::purge_expired) once `purge_at` (ns) is
+    /// reached (READER_DATA_LIFECYCLE autopurge delays); `None` keeps them.
+    pub fn set_instance_state(
+        &self,
+        handle: InstanceHandle,
+        state: InstanceState,
+        purge_at: Option<u64>,
+    ) {
+        self.instances.lock().insert(handle, state);
+        let mut purges = self.purges.lock();
+        match purge_at {
+            Some(deadline) => purges.insert(handle, deadline),
+            None => purges.remove(&handle),
+        };
+    }
+
+    /// Record that `writer` unregistered `handle`.
+    ///
+    /// Returns true while other writers still have the instance registered,
+    /// in which case it stays in its current state.
+    pub fn unregister_writer(&self, handle: InstanceHandle, writer: Option<GUID>) -> bool {
+        let mut writers = self.writers.lock();
+        let Some(live) = writers.get_mut(&handle) else {
+            return false;
+        };
+        live.remove(&writer);
+        if live.is_empty() {
+            writers.remove(&handle);
+            return false;
+        }
+        true
+    }
+
+    /// Drop the cached samples of NOT_ALIVE instances whose autopurge
+    /// deadline is at or before `now_ns`.
+    pub fn purge_expired(&self, now_ns: u64) {
+        let expired: Vec<InstanceHandle> = {
+            let mut purges = self.purges.lock();
+            if purges.is_empty() {
+                return;
+            }
+            let expired = purges
+                .iter()
+                .filter(|(_, &deadline)| deadline <= now_ns)
+                .map(|(&handle, _)| handle)
+                .collect();
+            purges.retain(|_, deadline| *deadline > now_ns);
+            expired
+        };
+        let mut buffer = self.buffer.lock();
+        for handle in expired {
+            while let Some(pos) = buffer.iter().position(|s| s.instance_handle == handle) {
+                buffer.remove(pos);
+                self.adjust_cursor_after_remove(pos);
+            }
+        }
+    }
+
+    /// Current state of an instance, or `None` if it was never seen.
+    pub fn instance_state(&self, handle: InstanceHandle) -> Option<InstanceState> {
+        self.instances.lock().get(&handle).copied()
+    }
+
+    /// View state of an instance, or `None` if it was never seen.
     #[allow(dead_code)] // DDS API - diagnostics
+    pub fn view_state(&self, handle: InstanceHandle) -> Option<ViewState> {
+        if !self.instances.lock().contains_key(&handle) {
+            return None;
+        }
+        if self.viewed.lock().contains(&handle) {
+            Some(ViewState::NotNew)
+        } else {
+            Some(ViewState::New)
+        }
+    }
+
+    /// Mark the instances of accessed samples NOT_NEW.
+    fn mark_viewed(&self, handles: impl IntoIterator<Item = InstanceHandle>) {
+        self.viewed.lock().extend(handles);
+    }
+
+    /// Sample, view and instance state of `samples`, in order.
+    fn states<'a>(
+        &self,
+        samples: impl IntoIterator<Item = &'a CachedSample<T>>,
+    ) -> Vec<(SampleState, ViewState, InstanceState)>
+    where
+        T: 'a,
+    {
+        let instances = self.instances.lock();
+        let viewed = self.viewed.lock();
+        samples
+            .into_iter()
+            .map(|s| {
+                let view = if viewed.contains(&s.instance_handle) {
+                    ViewState::NotNew
+                } else {
+                    ViewState::New
+                };
+                let instance = instances
+                    .get(&s.instance_handle)
+                    .copied()
+                    .unwrap_or_default();
+                (s.sample_state(), view, instance)
+            })
+            .collect()
+    }
+
+    /// Whether any cached sample satisfies `filter`, which is given the
+    /// sample with its sample, view and instance state.
+    pub fn any_matching<F>(&self, mut filter: F) -> bool
+    where
+        F: FnMut(&T, SampleState, ViewState, InstanceState) -> bool,
+    {
+        let buffer = self.buffer.lock();
+        let states = self.states(buffer.iter());
+        buffer
+            .iter()
+            .zip(states)
+            .any(|(s, (sample, view, instance))| filter(&s.data, sample, view, instance))
+    }
+
+    /// Handles of all instances seen so far.
+    pub fn instance_handles(&self) -> Vec<InstanceHandle> {
+        self.instances.lock().keys().copied().collect()
+    }
+
+    /// Number of samples currently in cache.
     pub fn len(&self) -> usize {
         self.buffer.lock().len()
     }
@@ -199,6 +549,21 @@ impl<T> SampleCache<T> {
     pub fn total_received(&self) -> usize {
         self.total_received.load(Ordering::Relaxed)
     }
+
+    /// Samples the cache can hold without reallocating.
+    #[allow(dead_code)] // DDS API - diagnostics
+    pub fn capacity(&self) -> usize {
+        self.buffer.lock().capacity()
+    }
+
+    /// Release unused capacity; cached samples and instance states are kept.
+    ///
+    /// The buffer grows back on demand (see endpoint hibernation).
+    pub fn shrink_to_fit(&self) {
+        self.buffer.lock().shrink_to_fit();
+        self.instances.lock().shrink_to_fit();
+        self.viewed.lock().shrink_to_fit();
+    }
 }
 
 impl<T> SampleCache<T> {
@@ -221,11 +586,51 @@ impl<T> SampleCache<T> {
             self.read_cursor.store(cursor - 1, Ordering::Relaxed);
         }
 
+        self.mark_viewed([sample.instance_handle]);
         Some(sample.data)
     }
 
+    /// Take a single sample together with its [`SampleInfo`].
+    ///
+    /// Same semantics as [`take`](Self::take).
+    pub fn take_with_info(&self) -> Option<(T, SampleInfo)> {
+        let mut buffer = self.buffer.lock();
+        let sample = buffer.pop_front()?;
+
+        let cursor = self.read_cursor.load(Ordering::Relaxed);
+        if cursor > 0 {
+            self.read_cursor.store(cursor - 1, Ordering::Relaxed);
+        }
+
+        let states = self.states([&sample]);
+        let info = sample.info_with_states(states[0]);
+        self.mark_viewed([info.instance_handle]);
+        Some((sample.data, info))
+    }
+
+    /// Take up to `max` samples with their [`SampleInfo`], oldest first,
+    /// under a single lock.
+    pub fn take_batch_with_info(&self, max: usize) -> Vec<(T, SampleInfo)> {
+        let mut buffer = self.buffer.lock();
+        let count = max.min(buffer.len());
+        let states = self.states(buffer.range(..count));
+        let result: Vec<(T, SampleInfo)> = buffer
+            .drain(..count)
+            .zip(states)
+            .map(|(sample, states)| {
+                let info = sample.info_with_states(states);
+                (sample.data, info)
+            })
+            .collect();
+
+        let cursor = self.read_cursor.load(Ordering::Relaxed);
+        self.read_cursor
+            .store(cursor.saturating_sub(count), Ordering::Relaxed);
+        self.mark_viewed(result.iter().map(|(_, info)| info.instance_handle));
+        result
+    }
+
     /// Take up to `max` samples, removing them from cache.
-    #[allow(dead_code)] // DDS API - batch operations
     pub fn take_batch(&self, max: usize) -> Vec<T> {
         let mut buffer = self.buffer.lock();
         let count = max.min(buffer.len());
@@ -233,6 +638,7 @@ impl<T> SampleCache<T> {
 
         for _ in 0..count {
             if let Some(sample) = buffer.pop_front() {
+                self.mark_viewed([sample.instance_handle]);
                 result.push(sample.data);
             }
         }
@@ -284,6 +690,7 @@ impl<T> SampleCache<T> {
             self.read_cursor.store(cursor - 1, Ordering::Relaxed);
         }
 
+        self.mark_viewed([handle]);
         Some(sample.data)
     }
 
@@ -326,10 +733,48 @@ impl<T> SampleCache<T> {
             self.read_cursor.store(new_cursor, Ordering::Relaxed);
         }
 
+        if !result.is_empty() {
+            self.mark_viewed([handle]);
+        }
+
         // Results are in reverse order, fix that
         result.reverse();
         result
     }
+
+    /// Take up to `max` samples for which `filter` returns true, oldest
+    /// first (DDS take_w_condition).
+    ///
+    /// `filter` is given each sample with its sample, view and instance
+    /// state; states are those before the call.
+    pub fn take_matching<F>(&self, max: usize, mut filter: F) -> Vec<(T, SampleInfo)>
+    where
+        F: FnMut(&T, SampleState, ViewState, InstanceState) -> bool,
+    {
+        let mut buffer = self.buffer.lock();
+        let states = self.states(buffer.iter());
+        let selected: Vec<(usize, (SampleState, ViewState, InstanceState))> = buffer
+            .iter()
+            .zip(states)
+            .enumerate()
+            .filter(|(_, (s, (sample, view, instance)))| filter(&s.data, *sample, *view, *instance))
+            .take(max)
+            .map(|(i, (_, states))| (i, states))
+            .collect();
+
+        let mut result = Vec::with_capacity(selected.len());
+        for &(idx, states) in selected.iter().rev() {
+            if let Some(sample) = buffer.remove(idx) {
+                self.adjust_cursor_after_remove(idx);
+                let info = sample.info_with_states(states);
+                result.push((sample.data, info));
+            }
+        }
+        result.reverse();
+
+        self.mark_viewed(result.iter().map(|(_, info)| info.instance_handle));
+        result
+    }
 }
 
 // Read operations require T: Clone (samples are copied, not moved)
@@ -348,6 +793,7 @@ impl<T: Clone> SampleCache<T> {
 
         let sample = &buffer[cursor];
         sample.mark_read();
+        self.mark_viewed([sample.instance_handle]);
 
         // Advance read cursor
         self.read_cursor.store(cursor + 1, Ordering::Relaxed);
@@ -370,6 +816,7 @@ impl<T: Clone> SampleCache<T> {
 
             let sample = &buffer[cursor];
             sample.mark_read();
+            self.mark_viewed([sample.instance_handle]);
             result.push(sample.data.clone());
             cursor += 1;
         }
@@ -378,6 +825,30 @@ impl<T: Clone> SampleCache<T> {
         result
     }
 
+    /// Read up to `max` unread samples with their [`SampleInfo`] without
+    /// removing them, under a single lock.
+    ///
+    /// Same selection as [`read_batch`](Self::read_batch); the info carries
+    /// the states before the call.
+    pub fn read_batch_with_info(&self, max: usize) -> Vec<(T, SampleInfo)> {
+        let buffer = self.buffer.lock();
+        let cursor = self.read_cursor.load(Ordering::Relaxed).min(buffer.len());
+        let end = cursor.saturating_add(max).min(buffer.len());
+        let states = self.states(buffer.range(cursor..end));
+        let result: Vec<(T, SampleInfo)> = buffer
+            .range(cursor..end)
+            .zip(states)
+            .map(|(sample, states)| {
+                sample.mark_read();
+                (sample.data.clone(), sample.info_with_states(states))
+            })
+            .collect();
+
+        self.read_cursor.store(end, Ordering::Relaxed);
+        self.mark_viewed(result.iter().map(|(_, info)| info.instance_handle));
+        result
+    }
+
     /// Read a single sample for a specific instance (DDS read_instance).
     ///
     /// Returns the first unread sample matching the given instance handle.
@@ -399,6 +870,7 @@ impl<T: Clone> SampleCache<T> {
         for sample in buffer.iter() {
             if sample.instance_handle == handle && sample.sample_state() == SampleState::NotRead {
                 sample.mark_read();
+                self.mark_viewed([handle]);
                 return Some(sample.data.clone());
             }
         }
@@ -430,6 +902,35 @@ impl<T: Clone> SampleCache<T> {
             }
         }
 
+        if !result.is_empty() {
+            self.mark_viewed([handle]);
+        }
+        result
+    }
+
+    /// Read up to `max` samples for which `filter` returns true without
+    /// removing them, oldest first (DDS read_w_condition).
+    ///
+    /// Same filter semantics as [`take_matching`](Self::take_matching); the
+    /// returned samples are marked READ.
+    pub fn read_matching<F>(&self, max: usize, mut filter: F) -> Vec<(T, SampleInfo)>
+    where
+        F: FnMut(&T, SampleState, ViewState, InstanceState) -> bool,
+    {
+        let buffer = self.buffer.lock();
+        let states = self.states(buffer.iter());
+        let result: Vec<(T, SampleInfo)> = buffer
+            .iter()
+            .zip(states)
+            .filter(|(s, (sample, view, instance))| filter(&s.data, *sample, *view, *instance))
+            .take(max)
+            .map(|(s, states)| {
+                s.mark_read();
+                (s.data.clone(), s.info_with_states(states))
+            })
+            .collect();
+
+        self.mark_viewed(result.iter().map(|(_, info)| info.instance_handle));
         result
     }
 }
@@ -568,6 +1069,59 @@ mod tests {
         assert_eq!(cache.len(), 2);
     }
 
+    #[test]
+    fn test_batches_with_info_carry_states() {
+        let cache: SampleCache<i32> = SampleCache::with_instance_depth(10, 10);
+        let a = make_handle(1);
+        let b = make_handle(2);
+        cache.push(CachedSample::with_instance(1, 1, 0, a));
+        cache.push(CachedSample::with_instance(2, 2, 0, a));
+        cache.push(CachedSample::with_instance(3, 3, 0, b));
+
+        let read = cache.read_batch_with_info(1);
+        assert_eq!(read[0].0, 1);
+        assert_eq!(read[0].1.sample_state, SampleState::NotRead);
+        assert_eq!(read[0].1.view_state, ViewState::New);
+
+        // The first read moved instance `a` to NOT_NEW
+        let read = cache.read_batch_with_info(10);
+        assert_eq!(read.len(), 2);
+        assert_eq!(read[0].1.view_state, ViewState::NotNew);
+        assert_eq!(read[1].1.view_state, ViewState::New);
+        assert!(cache.read_batch_with_info(10).is_empty());
+
+        let taken = cache.take_batch_with_info(2);
+        assert_eq!(
+            taken.iter().map(|(v, _)| *v).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert!(taken
+            .iter()
+            .all(|(_, info)| info.sample_state == SampleState::Read));
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.read(), None, "read cursor follows removed samples");
+    }
+
+    #[test]
+    fn test_shrink_keeps_samples() {
+        let cache: SampleCache<i32> = SampleCache::new(1000);
+        assert!(cache.capacity() >= 1000);
+
+        cache.push(CachedSample::new(1, 1, 0));
+        cache.push(CachedSample::new(2, 2, 0));
+        cache.shrink_to_fit();
+
+        assert!(cache.capacity() < 1000);
+        assert_eq!(cache.read(), Some(1));
+        assert_eq!(cache.take_batch(10), vec![1, 2]);
+
+        // Grows back on demand
+        for seq in 3..503 {
+            cache.push(CachedSample::new(seq as i32, seq, 0));
+        }
+        assert_eq!(cache.len(), 500);
+    }
+
     // =========================================================================
     // Instance filtering tests (Phase 2)
     // =========================================================================
@@ -763,4 +1317,112 @@ mod tests {
         assert_eq!(cache.take_instance(nil), Some(2));
         assert_eq!(cache.take_instance(nil), None);
     }
+
+    #[test]
+    fn test_take_with_info_carries_trace_id() {
+        let cache: SampleCache<i32> = SampleCache::new(10);
+        let h1 = make_handle(1);
+
+        cache.push(CachedSample::with_instance(10, 1, 100, h1).with_trace_id(Some(0xfeed)));
+        cache.push(CachedSample::new(20, 2, 200));
+
+        let (data, info) = cache.take_with_info().expect("sample");
+        assert_eq!(data, 10);
+        assert_eq!(info.seq, 1);
+        assert_eq!(info.timestamp_ns, 100);
+        assert_eq!(info.instance_handle, h1);
+        assert_eq!(info.trace_id, Some(0xfeed));
+
+        let (data, info) = cache.take_with_info().expect("sample");
+        assert_eq!(data, 20);
+        assert_eq!(info.trace_id, None);
+        assert!(cache.take_with_info().is_none());
+    }
+
+    #[test]
+    fn test_per_instance_history_depth() {
+        let cache: SampleCache<i32> = SampleCache::with_instance_depth(100, 2);
+        let h1 = make_handle(1);
+        let h2 = make_handle(2);
+
+        cache.push(CachedSample::with_instance(10, 1, 0, h1));
+        cache.push(CachedSample::with_instance(20, 2, 0, h2));
+        cache.push(CachedSample::with_instance(11, 3, 0, h1));
+        cache.push(CachedSample::with_instance(12, 4, 0, h1)); // Evicts 10, not 20
+
+        assert_eq!(cache.take_instance_batch(h1, 10), vec![11, 12]);
+        assert_eq!(cache.take_instance_batch(h2, 10), vec![20]);
+    }
+
+    #[test]
+    fn test_instance_state_lifecycle() {
+        let cache: SampleCache<i32> = SampleCache::new(10);
+        let h1 = make_handle(1);
+        let h2 = make_handle(2);
+        assert_eq!(cache.instance_state(h1), None);
+
+        cache.push(CachedSample::with_instance(10, 1, 0, h1));
+        cache.push(CachedSample::with_instance(20, 2, 0, h2));
+        assert_eq!(cache.instance_state(h1), Some(InstanceState::Alive));
+
+        // Dispose without purge keeps the samples, with purge drops them
+        // once the deadline is reached
+        cache.set_instance_state(h1, InstanceState::NotAliveDisposed, None);
+        cache.set_instance_state(h2, InstanceState::NotAliveDisposed, Some(100));
+        cache.purge_expired(99);
+        assert_eq!(cache.len(), 2);
+        cache.purge_expired(100);
+        assert_eq!(cache.len(), 1);
+
+        let (data, info) = cache.take_with_info().expect("sample");
+        assert_eq!(data, 10);
+        assert_eq!(info.instance_state, InstanceState::NotAliveDisposed);
+
+        // Writing again revives the instance
+        cache.push(CachedSample::with_instance(21, 3, 0, h2));
+        assert_eq!(cache.instance_state(h2), Some(InstanceState::Alive));
+        assert_eq!(cache.instance_handles().len(), 2);
+    }
+
+    #[test]
+    fn test_view_state_and_matching() {
+        let cache: SampleCache<i32> = SampleCache::new(10);
+        let h1 = make_handle(1);
+        let h2 = make_handle(2);
+        cache.push(CachedSample::with_instance(10, 1, 0, h1));
+        cache.push(CachedSample::with_instance(20, 2, 0, h2));
+        assert_eq!(cache.view_state(h1), Some(ViewState::New));
+
+        // Reading one instance makes it NOT_NEW, the other stays NEW
+        let read = cache.read_matching(10, |data, _, _, _| *data == 10);
+        assert_eq!(read.len(), 1);
+        assert_eq!(cache.view_state(h1), Some(ViewState::NotNew));
+        assert!(cache.any_matching(|data, sample, view, _| {
+            *data == 20 && sample == SampleState::NotRead && view == ViewState::New
+        }));
+
+        let new = cache.take_matching(10, |_, _, view, _| view == ViewState::New);
+        assert_eq!(new.len(), 1);
+        assert_eq!(new[0].0, 20);
+        assert_eq!(cache.len(), 1);
+
+        // An instance written again after a dispose is NEW again
+        cache.set_instance_state(h1, InstanceState::NotAliveDisposed, None);
+        assert!(cache.any_matching(|_, sample, _, instance| {
+            sample == SampleState::Read && instance == InstanceState::NotAliveDisposed
+        }));
+        cache.push(CachedSample::with_instance(11, 3, 0, h1));
+        assert_eq!(cache.view_state(h1), Some(ViewState::New));
+    }
+
+    #[test]
+    fn test_instance_handle_from_serialized_key() {
+        let short = InstanceHandle::from_serialized_key(&[0, 0, 0, 7]);
+        assert_eq!(&short.as_bytes()[..4], &[0, 0, 0, 7]);
+        assert_eq!(&short.as_bytes()[4..], &[0u8; 12]);
+
+        let long = InstanceHandle::from_serialized_key(&[1u8; 20]);
+        assert_ne!(long, InstanceHandle::from_serialized_key(&[2u8; 20]));
+        assert!(!long.is_nil());
+    }
 }
@@ -0,0 +1,207 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! ReadConditions and QueryConditions created on a DataReader.
+//!
+//! A condition created here is bound to the reader's sample cache: its
+//! trigger value is true while the reader holds a sample whose sample, view
+//! and instance states match the condition's masks (and, for a
+//! QueryCondition, whose fields satisfy the query). Every data arrival is
+//! forwarded to the reader's conditions, which wake their WaitSets only when
+//! they trigger.
+//!
+//! Samples still queued in the reader's ring are not decoded yet. They count
+//! as NOT_READ samples of ALIVE instances of either view state, and as
+//! satisfying any query, until the next read or take moves them to the
+//! cache. A WaitSet may therefore wake for a sample that
+//! `take_w_condition()` then leaves out.
+
+use super::cache::SampleCache;
+use super::{DataReader, SampleInfo};
+use crate::core::rt::IndexRing;
+use crate::dds::filter::{ContentFilter, FilterEvaluator};
+use crate::dds::{
+    Error, InstanceStateMask, QueryCondition, ReadCondition, Result, SampleCondition,
+    SampleStateMask, ViewStateMask, DDS,
+};
+use std::sync::{Arc, Weak};
+
+/// Whether a sample satisfies `query` (no query matches every sample).
+///
+/// A sample whose fields the query cannot be evaluated on does not match.
+fn query_matches<T: DDS>(sample: &T, query: Option<&FilterEvaluator>) -> bool {
+    query.is_none_or(|query| query.matches(&sample.get_fields()).unwrap_or(false))
+}
+
+/// Trigger value of `condition` over a reader's pending and cached samples.
+fn has_matching<T: DDS>(
+    cache: &SampleCache<T>,
+    ring: &IndexRing,
+    condition: &ReadCondition,
+    query: Option<&FilterEvaluator>,
+) -> bool {
+    let pending_match = condition
+        .get_sample_state_mask()
+        .contains(SampleStateMask::NOT_READ)
+        && condition
+            .get_instance_state_mask()
+            .contains(InstanceStateMask::ALIVE)
+        && condition.get_view_state_mask().bits() & ViewStateMask::ANY.bits() != 0;
+    if pending_match && !ring.is_empty() {
+        return true;
+    }
+    cache.any_matching(|sample, state, view, instance| {
+        condition.matches(state, view, instance) && query_matches(sample, query)
+    })
+}
+
+impl<T: DDS> DataReader<T> {
+    /// Identity of the reader, recorded in the conditions created on it.
+    fn condition_owner(&self) -> usize {
+        Arc::as_ptr(&self.cache) as *const () as usize
+    }
+
+    /// Bind `condition` to this reader's samples and forward data arrivals
+    /// to it.
+    fn bind_condition(&self, condition: &Arc<ReadCondition>, query: Option<FilterEvaluator>) {
+        let cache: Weak<SampleCache<T>> = Arc::downgrade(&self.cache);
+        let ring: Weak<IndexRing> = Arc::downgrade(&self.ring);
+        condition.bind(
+            self.condition_owner(),
+            Box::new(move |condition| match (cache.upgrade(), ring.upgrade()) {
+                (Some(cache), Some(ring)) => has_matching(&cache, &ring, condition, query.as_ref()),
+                _ => false,
+            }),
+        );
+        self.status_condition.add_read_condition(condition);
+    }
+
+    /// Create a ReadCondition on this reader (DDS create_readcondition).
+    ///
+    /// The condition triggers while the reader holds a sample matching the
+    /// three masks, e.g. NOT_READ samples of NEW, ALIVE instances. Attach it
+    /// to a [`WaitSet`](crate::dds::WaitSet) to wake only for such samples,
+    /// then fetch them with [`take_w_condition()`](Self::take_w_condition).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let cond = reader.create_readcondition(
+    ///     SampleStateMask::NOT_READ,
+    ///     ViewStateMask::NEW,
+    ///     InstanceStateMask::ALIVE,
+    /// );
+    /// waitset.attach_condition(cond.clone())?;
+    /// waitset.wait(None)?;
+    /// let new_instances = reader.take_w_condition(&*cond, 16)?;
+    /// ```
+    pub fn create_readcondition(
+        &self,
+        sample_state_mask: SampleStateMask,
+        view_state_mask: ViewStateMask,
+        instance_state_mask: InstanceStateMask,
+    ) -> Arc<ReadCondition> {
+        let condition = Arc::new(ReadCondition::new(
+            sample_state_mask,
+            view_state_mask,
+            instance_state_mask,
+        ));
+        self.bind_condition(&condition, None);
+        condition
+    }
+
+    /// Create a QueryCondition on this reader (DDS create_querycondition).
+    ///
+    /// Like [`create_readcondition()`](Self::create_readcondition), with the
+    /// samples also filtered by `query_expression`, written in the content
+    /// filter syntax (see [`ContentFilter`]) over the fields returned by
+    /// [`DDS::get_fields()`]. Parameters can be changed later with
+    /// [`QueryCondition::set_query_parameters()`].
+    ///
+    /// # Errors
+    ///
+    /// [`Error::InvalidState`] if the expression does not parse.
+    pub fn create_querycondition(
+        &self,
+        sample_state_mask: SampleStateMask,
+        view_state_mask: ViewStateMask,
+        instance_state_mask: InstanceStateMask,
+        query_expression: &str,
+        query_parameters: Vec<String>,
+    ) -> Result<Arc<QueryCondition>> {
+        ContentFilter::new(query_expression).map_err(|e| {
+            Error::InvalidState(format!("invalid query '{}': {}", query_expression, e))
+        })?;
+        let condition = Arc::new(QueryCondition::new(
+            sample_state_mask,
+            view_state_mask,
+            instance_state_mask,
+            query_expression.to_string(),
+            query_parameters,
+        ));
+        self.bind_condition(condition.base(), condition.query().cloned());
+        Ok(condition)
+    }
+
+    fn check_condition(&self, condition: &ReadCondition) -> Result<()> {
+        if condition.reader() == Some(self.condition_owner()) {
+            Ok(())
+        } else {
+            Err(Error::InvalidState(
+                "condition was not created on this reader".to_string(),
+            ))
+        }
+    }
+
+    /// Take up to `max` samples matching `condition`, oldest first (DDS
+    /// take_w_condition).
+    ///
+    /// Only samples whose states match the condition's masks, and that
+    /// satisfy its query if it is a QueryCondition, are removed.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::InvalidState`] if `condition` was not created on this reader.
+    pub fn take_w_condition<C: SampleCondition + ?Sized>(
+        &self,
+        condition: &C,
+        max: usize,
+    ) -> Result<Vec<(T, SampleInfo)>> {
+        let states = condition.read_condition();
+        self.check_condition(states)?;
+        self.pump_ring_to_cache()?;
+        let query = condition.query();
+        Ok(self
+            .cache
+            .take_matching(max, |sample, state, view, instance| {
+                states.matches(state, view, instance) && query_matches(sample, query)
+            }))
+    }
+}
+
+impl<T: DDS + Clone> DataReader<T> {
+    /// Read up to `max` samples matching `condition` without removing them
+    /// (DDS read_w_condition).
+    ///
+    /// Same selection as [`take_w_condition()`](Self::take_w_condition); the
+    /// returned samples are marked READ and their instances NOT_NEW.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::InvalidState`] if `condition` was not created on this reader.
+    pub fn read_w_condition<C: SampleCondition + ?Sized>(
+        &self,
+        condition: &C,
+        max: usize,
+    ) -> Result<Vec<(T, SampleInfo)>> {
+        let states = condition.read_condition();
+        self.check_condition(states)?;
+        self.pump_ring_to_cache()?;
+        let query = condition.query();
+        Ok(self
+            .cache
+            .read_matching(max, |sample, state, view, instance| {
+                states.matches(state, view, instance) && query_matches(sample, query)
+            }))
+    }
+}
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Hibernation of an idle reader (see [`crate::dds::HibernationConfig`]).
+//!
+//! A hibernating reader releases the unused capacity of its sample cache;
+//! cached samples and instance states are kept. The reader's receive ring is
+//! shared with the transport without locking and stays allocated. The next
+//! sample received wakes the reader and the cache grows back on demand.
+
+use super::cache::SampleCache;
+use super::staleness::SampleArrivals;
+use crate::dds::Hibernate;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Weak};
+
+/// Hibernation hooks of one reader.
+pub(super) struct ReaderHibernation<T> {
+    cache: Weak<SampleCache<T>>,
+    arrivals: Arc<SampleArrivals>,
+    hibernating: AtomicBool,
+    /// Arrivals when the reader hibernated
+    asleep_at: AtomicU64,
+}
+
+impl<T> ReaderHibernation<T> {
+    pub(super) fn new(cache: &Arc<SampleCache<T>>, arrivals: Arc<SampleArrivals>) -> Self {
+        Self {
+            cache: Arc::downgrade(cache),
+            arrivals,
+            hibernating: AtomicBool::new(false),
+            asleep_at: AtomicU64::new(0),
+        }
+    }
+}
+
+impl<T: Send + Sync> Hibernate for ReaderHibernation<T> {
+    fn activity(&self) -> u64 {
+        self.arrivals.metrics().samples_received
+    }
+
+    fn hibernate(&self) -> bool {
+        let Some(cache) = self.cache.upgrade() else {
+            return false;
+        };
+        self.asleep_at.store(self.activity(), Ordering::Release);
+        self.hibernating.store(true, Ordering::Release);
+        cache.shrink_to_fit();
+        true
+    }
+
+    fn reactivate(&self) {
+        self.hibernating.store(false, Ordering::Release);
+    }
+
+    fn is_hibernating(&self) -> bool {
+        // Nothing to undo eagerly: a sample reaching the cache wakes the reader
+        self.hibernating.load(Ordering::Acquire)
+            && self.activity() == self.asleep_at.load(Ordering::Acquire)
+    }
+}
@@ -0,0 +1,204 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Iterator and `Stream` adapters over [`DataReader::take`].
+//!
+//! Both wait on the reader's DATA_AVAILABLE status instead of polling, so a
+//! simple consumer loop needs no WaitSet plumbing.
+
+use super::DataReader;
+use crate::dds::{Result, WaitSet, DDS};
+use std::time::{Duration, Instant};
+
+impl<T: DDS> DataReader<T> {
+    /// Blocking iterator over incoming samples.
+    ///
+    /// Each call to `next()` takes one sample, waiting up to `timeout` for
+    /// one to arrive. The iterator ends when the timeout elapses with no
+    /// data; `None` waits indefinitely.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use hdds::{Participant, QoS, Result};
+    /// use std::time::Duration;
+    ///
+    /// # #[derive(hdds::DDS, Debug)] struct SensorData { value: f64 }
+    /// fn main() -> Result<()> {
+    ///     let participant = Participant::builder("subscriber").build()?;
+    ///     let reader = participant.create_reader::<SensorData>("sensors", QoS::reliable())?;
+    ///
+    ///     for sample in reader.iter(Some(Duration::from_secs(5))) {
+    ///         println!("Received: {:?}", sample?);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn iter(&self, timeout: Option<Duration>) -> SampleIter<'_, T> {
+        let waitset = WaitSet::new();
+        let waitset = match waitset.attach_condition(self.get_status_condition()) {
+            Ok(()) => Some(waitset),
+            Err(e) => {
+                log::debug!(
+                    "[READER] iter topic='{}': waitset attach failed ({:?}), polling",
+                    self.topic_name(),
+                    e
+                );
+                None
+            }
+        };
+        SampleIter {
+            reader: self,
+            waitset,
+            timeout,
+        }
+    }
+
+    /// Asynchronous stream of incoming samples.
+    ///
+    /// The stream never ends on its own; wrap it in a timeout from your
+    /// runtime to bound the wait. Works with any executor.
+    ///
+    /// # Feature Flag
+    /// Requires the `async` feature.
+    #[cfg(feature = "async")]
+    pub fn stream(&self) -> SampleStream<'_, T> {
+        use crate::dds::Condition;
+
+        let signal = std::sync::Arc::new(stream::WakerSignal::new());
+        self.get_status_condition().add_waitset_signal(
+            signal.clone() as std::sync::Arc<dyn crate::core::rt::WaitsetSignal>
+        );
+        SampleStream {
+            reader: self,
+            signal,
+        }
+    }
+}
+
+/// Blocking iterator returned by [`DataReader::iter`].
+pub struct SampleIter<'a, T: DDS> {
+    reader: &'a DataReader<T>,
+    waitset: Option<WaitSet>,
+    timeout: Option<Duration>,
+}
+
+impl<T: DDS> Iterator for SampleIter<'_, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let deadline = self.timeout.map(|t| Instant::now() + t);
+        loop {
+            match self.reader.take() {
+                Ok(Some(sample)) => return Some(Ok(sample)),
+                Ok(None) => {}
+                Err(e) => return Some(Err(e)),
+            }
+
+            let remaining = match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return None;
+                    }
+                    Some(deadline - now)
+                }
+                None => None,
+            };
+
+            match &self.waitset {
+                // WouldBlock = timed out; the deadline check above ends the loop.
+                Some(waitset) => match waitset.wait(remaining) {
+                    Ok(_) | Err(crate::dds::Error::WouldBlock) => {}
+                    Err(e) => return Some(Err(e)),
+                },
+                None => {
+                    std::thread::sleep(remaining.map_or(POLL_INTERVAL, |r| r.min(POLL_INTERVAL)))
+                }
+            }
+        }
+    }
+}
+
+/// Fallback poll period when no WaitSet could be attached.
+const POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+#[cfg(feature = "async")]
+pub use stream::SampleStream;
+
+#[cfg(feature = "async")]
+mod stream {
+    use super::DataReader;
+    use crate::core::rt::WaitsetSignal;
+    use crate::dds::{Condition, Result, DDS};
+    use std::pin::Pin;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll, Waker};
+
+    /// Wakes the stream's task when the reader signals DATA_AVAILABLE.
+    pub(super) struct WakerSignal {
+        id: u64,
+        waker: Mutex<Option<Waker>>,
+    }
+
+    impl WakerSignal {
+        pub(super) fn new() -> Self {
+            static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+            Self {
+                // High bit keeps ids clear of WaitSet slot ids.
+                id: (1 << 63) | NEXT_ID.fetch_add(1, Ordering::Relaxed),
+                waker: Mutex::new(None),
+            }
+        }
+
+        fn register(&self, waker: &Waker) {
+            let mut slot = self.waker.lock().unwrap_or_else(|e| e.into_inner());
+            match slot.as_ref() {
+                Some(current) if current.will_wake(waker) => {}
+                _ => *slot = Some(waker.clone()),
+            }
+        }
+    }
+
+    impl WaitsetSignal for WakerSignal {
+        fn signal(&self) {
+            let waker = self.waker.lock().unwrap_or_else(|e| e.into_inner()).take();
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }
+
+        fn id(&self) -> u64 {
+            self.id
+        }
+    }
+
+    /// Stream returned by [`DataReader::stream`].
+    pub struct SampleStream<'a, T: DDS> {
+        pub(super) reader: &'a DataReader<T>,
+        pub(super) signal: Arc<WakerSignal>,
+    }
+
+    impl<T: DDS> futures_core::Stream for SampleStream<'_, T> {
+        type Item = Result<T>;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            // Register before taking so a sample landing in between still wakes us.
+            self.signal.register(cx.waker());
+            match self.reader.take() {
+                Ok(Some(sample)) => Poll::Ready(Some(Ok(sample))),
+                Ok(None) => Poll::Pending,
+                Err(e) => Poll::Ready(Some(Err(e))),
+            }
+        }
+    }
+
+    impl<T: DDS> Drop for SampleStream<'_, T> {
+        fn drop(&mut self) {
+            self.reader
+                .get_status_condition()
+                .remove_waitset_signal(self.signal.id);
+        }
+    }
+}
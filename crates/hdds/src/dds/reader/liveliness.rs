@@ -0,0 +1,631 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! LIVELINESS_CHANGED status of a reader (DDS v1.4 Sec.2.2.4.2.14).
+//!
+//! [`LivelinessWatch`] tracks the liveliness of the writers matched with a
+//! reader: same-process writers from the domain registry, remote writers
+//! from SEDP. A same-process writer is alive while the lease of its shared
+//! `WriterLiveliness` runs. A remote writer is alive while less than its
+//! announced lease elapsed since its last assertion: any HEARTBEAT from an
+//! AUTOMATIC writer, a liveliness HEARTBEAT (LivelinessFlag) from a MANUAL
+//! one. DATA does not count, the routing hot path does not report the
+//! writer. Writers with an infinite lease are alive while matched.
+//!
+//! The watch is refreshed whenever the status is read, on every liveliness
+//! HEARTBEAT that revives a writer, when SEDP discovers a writer on the
+//! topic, and periodically by a background thread for readers with a finite
+//! lease or a listener. The same refresh maintains the SUBSCRIPTION_MATCHED
+//! status from the set of matched writers.
+
+use crate::core::discovery::multicast::{DiscoveryListener, EndpointInfo, EndpointKind};
+use crate::core::discovery::GUID;
+use crate::core::rt::clock::{Clock, SystemClock};
+use crate::dds::listener::{LivelinessChangedStatus, SubscriptionMatchedStatus};
+use crate::dds::liveliness::WriterLiveliness;
+use crate::dds::qos::LivelinessKind;
+use crate::dds::{StatusCondition, StatusMask};
+use crate::engine::HeartbeatHandler;
+use crate::reliability::HeartbeatMsg;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, Weak};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Check period when no matched writer has a finite lease.
+const IDLE_CHECK_PERIOD: Duration = Duration::from_millis(100);
+
+/// Shortest period between two checks.
+const MIN_CHECK_PERIOD: Duration = Duration::from_millis(1);
+
+/// Writer matched with a reader.
+pub(super) struct WatchedWriter {
+    pub(super) guid: GUID,
+    pub(super) kind: LivelinessKind,
+    /// Lease in nanoseconds, `None` when infinite.
+    pub(super) lease_ns: Option<u64>,
+    /// Assertions of a same-process writer.
+    pub(super) local: Option<Arc<WriterLiveliness>>,
+}
+
+impl WatchedWriter {
+    /// Same-process writer.
+    pub(super) fn local(guid: GUID, liveliness: Arc<WriterLiveliness>) -> Self {
+        Self {
+            guid,
+            kind: liveliness.kind(),
+            lease_ns: liveliness
+                .lease()
+                .map(|lease| u64::try_from(lease.as_nanos()).unwrap_or(u64::MAX)),
+            local: Some(liveliness),
+        }
+    }
+}
+
+/// Writers currently matched with the reader.
+pub(super) type WatchedWriters = Box<dyn Fn() -> Vec<WatchedWriter> + Send + Sync>;
+
+/// Listener callback for liveliness changes.
+pub(super) type LivelinessCallback = Box<dyn Fn(LivelinessChangedStatus) + Send + Sync>;
+
+/// Listener callback for matched status changes.
+pub(super) type SubscriptionMatchedCallback = Box<dyn Fn(SubscriptionMatchedStatus) + Send + Sync>;
+
+struct TrackedWriter {
+    alive: bool,
+    kind: LivelinessKind,
+    lease_ns: Option<u64>,
+    local: Option<Arc<WriterLiveliness>>,
+    /// Reader clock time of the last assertion heard from a remote writer.
+    heard_ns: u64,
+}
+
+impl TrackedWriter {
+    fn is_alive_at(&self, now: u64) -> bool {
+        match self.local {
+            // On the writer's clock, which asserted it
+            Some(ref local) => local.is_alive(),
+            None => self
+                .lease_ns
+                .is_none_or(|lease| now.saturating_sub(self.heard_ns) <= lease),
+        }
+    }
+}
+
+#[derive(Default)]
+struct WatchState {
+    writers: HashMap<GUID, TrackedWriter>,
+    status: LivelinessChangedStatus,
+    matched: SubscriptionMatchedStatus,
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Liveliness of the writers matched with one reader.
+pub(super) struct LivelinessWatch {
+    writers: WatchedWriters,
+    on_change: Option<LivelinessCallback>,
+    on_matched: Option<SubscriptionMatchedCallback>,
+    status_condition: Arc<StatusCondition>,
+    clock: Arc<dyn Clock>,
+    state: Mutex<WatchState>,
+}
+
+impl LivelinessWatch {
+    pub(super) fn new(
+        writers: WatchedWriters,
+        on_change: Option<LivelinessCallback>,
+        status_condition: Arc<StatusCondition>,
+    ) -> Self {
+        Self {
+            writers,
+            on_change,
+            on_matched: None,
+            status_condition,
+            clock: SystemClock::shared(),
+            state: Mutex::new(WatchState::default()),
+        }
+    }
+
+    /// Time remote writer leases on `clock`.
+    pub(super) fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Report matched status changes to `on_matched`.
+    pub(super) fn with_matched_callback(
+        mut self,
+        on_matched: Option<SubscriptionMatchedCallback>,
+    ) -> Self {
+        self.on_matched = on_matched;
+        self
+    }
+
+    /// Current SUBSCRIPTION_MATCHED status; resets the change counters.
+    pub(super) fn matched_status(&self) -> SubscriptionMatchedStatus {
+        self.refresh();
+        let mut state = lock(&self.state);
+        let status = state.matched.clone();
+        state.matched.total_count_change = 0;
+        state.matched.current_count_change = 0;
+        self.status_condition
+            .clear_active_status(StatusMask::SUBSCRIPTION_MATCHED);
+        status
+    }
+
+    /// Current status; resets the change counters.
+    pub(super) fn status(&self) -> LivelinessChangedStatus {
+        self.refresh();
+        let mut state = lock(&self.state);
+        let status = state.status.clone();
+        state.status.alive_count_change = 0;
+        state.status.not_alive_count_change = 0;
+        self.status_condition
+            .clear_active_status(StatusMask::LIVELINESS_CHANGED);
+        status
+    }
+
+    /// Record a HEARTBEAT from `writer`.
+    pub(super) fn on_heartbeat(&self, writer: GUID, liveliness_flag: bool) {
+        let revived = {
+            let mut state = lock(&self.state);
+            let Some(tracked) = state.writers.get_mut(&writer) else {
+                return;
+            };
+            if !liveliness_flag && tracked.kind != LivelinessKind::Automatic {
+                return;
+            }
+            tracked.heard_ns = self.clock.now_ns();
+            !tracked.alive
+        };
+        if revived {
+            self.refresh();
+        }
+    }
+
+    /// Time until the next check: a quarter of the shortest lease.
+    fn check_period(&self) -> Duration {
+        lock(&self.state)
+            .writers
+            .values()
+            .filter_map(|w| w.lease_ns)
+            .min()
+            .map_or(IDLE_CHECK_PERIOD, |lease| {
+                Duration::from_nanos(lease / 4).clamp(MIN_CHECK_PERIOD, IDLE_CHECK_PERIOD)
+            })
+    }
+
+    /// Recompute matched writers and their liveliness, and report changes.
+    fn refresh(&self) {
+        let current = (self.writers)();
+        let now = self.clock.now_ns();
+        let (liveliness, matched) = {
+            let mut state = lock(&self.state);
+            let state = &mut *state;
+            let mut last_changed = None;
+
+            let current_guids: HashSet<GUID> = current.iter().map(|w| w.guid).collect();
+            let mut removed = Vec::new();
+            let status = &mut state.status;
+            state.writers.retain(|guid, tracked| {
+                if current_guids.contains(guid) {
+                    return true;
+                }
+                if tracked.alive {
+                    status.alive_count = status.alive_count.saturating_sub(1);
+                    status.alive_count_change -= 1;
+                } else {
+                    status.not_alive_count = status.not_alive_count.saturating_sub(1);
+                    status.not_alive_count_change -= 1;
+                }
+                last_changed = Some(*guid);
+                removed.push(*guid);
+                false
+            });
+
+            let mut added = Vec::new();
+            for writer in current {
+                let tracked = state.writers.entry(writer.guid).or_insert_with(|| {
+                    // Matching counts as hearing from the writer
+                    let tracked = TrackedWriter {
+                        alive: false,
+                        kind: writer.kind,
+                        lease_ns: writer.lease_ns,
+                        local: writer.local.clone(),
+                        heard_ns: now,
+                    };
+                    status.not_alive_count += 1;
+                    status.not_alive_count_change += 1;
+                    added.push(writer.guid);
+                    tracked
+                });
+                tracked.kind = writer.kind;
+                tracked.lease_ns = writer.lease_ns;
+                tracked.local = writer.local;
+
+                let alive = tracked.is_alive_at(now);
+                if alive == tracked.alive {
+                    continue;
+                }
+                tracked.alive = alive;
+                if alive {
+                    status.alive_count += 1;
+                    status.alive_count_change += 1;
+                    status.not_alive_count = status.not_alive_count.saturating_sub(1);
+                    status.not_alive_count_change -= 1;
+                } else {
+                    status.alive_count = status.alive_count.saturating_sub(1);
+                    status.alive_count_change -= 1;
+                    status.not_alive_count += 1;
+                    status.not_alive_count_change += 1;
+                }
+                last_changed = Some(writer.guid);
+            }
+
+            let matched =
+                self.record_matches(&mut state.matched, &added, &removed, state.writers.len());
+
+            let liveliness = last_changed.map(|guid| {
+                status.last_publication_handle = Some(guid);
+                log::debug!(
+                    "[reader] writer liveliness: {} alive, {} not alive (last {})",
+                    status.alive_count,
+                    status.not_alive_count,
+                    guid
+                );
+
+                // The listener consumes the changes, like a status read
+                self.on_change.is_some().then(|| {
+                    let snapshot = status.clone();
+                    status.alive_count_change = 0;
+                    status.not_alive_count_change = 0;
+                    snapshot
+                })
+            });
+            (liveliness, matched)
+        };
+
+        if let Some(changed) = liveliness {
+            self.status_condition.set_active_statuses(
+                self.status_condition.get_active_statuses() | StatusMask::LIVELINESS_CHANGED,
+            );
+            if let (Some(callback), Some(status)) = (&self.on_change, changed) {
+                callback(status);
+            }
+        }
+        if let Some(changed) = matched {
+            self.status_condition.set_active_statuses(
+                self.status_condition.get_active_statuses() | StatusMask::SUBSCRIPTION_MATCHED,
+            );
+            if let (Some(callback), Some(status)) = (&self.on_matched, changed) {
+                callback(status);
+            }
+        }
+    }
+
+    /// Update the SUBSCRIPTION_MATCHED counters. `None` when nothing
+    /// changed, otherwise the snapshot for the listener (if any).
+    fn record_matches(
+        &self,
+        status: &mut SubscriptionMatchedStatus,
+        added: &[GUID],
+        removed: &[GUID],
+        current: usize,
+    ) -> Option<Option<SubscriptionMatchedStatus>> {
+        if added.is_empty() && removed.is_empty() {
+            return None;
+        }
+        status.total_count += added.len() as u32;
+        status.total_count_change += added.len() as i32;
+        status.current_count = current as u32;
+        status.current_count_change += added.len() as i32 - removed.len() as i32;
+        status.last_publication_handle = added.last().or(removed.last()).copied();
+        log::debug!(
+            "[reader] matched writers: {} (+{} -{})",
+            current,
+            added.len(),
+            removed.len()
+        );
+
+        // The listener consumes the changes, like a status read
+        Some(self.on_matched.is_some().then(|| {
+            let snapshot = status.clone();
+            status.total_count_change = 0;
+            status.current_count_change = 0;
+            snapshot
+        }))
+    }
+}
+
+/// Whether the first HEARTBEAT submessage of `packet` has the
+/// LivelinessFlag set (RTPS v2.5 Sec.8.3.7.5).
+fn has_liveliness_flag(packet: &[u8]) -> bool {
+    let mut offset = 20; // RTPS header
+    while offset + 4 <= packet.len() {
+        let id = packet[offset];
+        let flags = packet[offset + 1];
+        let len = if flags & 0x01 != 0 {
+            u16::from_le_bytes([packet[offset + 2], packet[offset + 3]])
+        } else {
+            u16::from_be_bytes([packet[offset + 2], packet[offset + 3]])
+        } as usize;
+        if id == 0x07 {
+            return flags & 0x04 != 0;
+        }
+        offset += 4 + len;
+    }
+    false
+}
+
+/// Feeds writer HEARTBEATs to a reader's [`LivelinessWatch`].
+pub(super) struct LivelinessHeartbeatHandler {
+    watch: Weak<LivelinessWatch>,
+}
+
+impl LivelinessHeartbeatHandler {
+    pub(super) fn new(watch: &Arc<LivelinessWatch>) -> Self {
+        Self {
+            watch: Arc::downgrade(watch),
+        }
+    }
+}
+
+impl HeartbeatHandler for LivelinessHeartbeatHandler {
+    fn on_heartbeat(&self, heartbeat_bytes: &[u8]) {
+        let Some(watch) = self.watch.upgrade() else {
+            return;
+        };
+        if let Some(hb) = HeartbeatMsg::decode_from_packet(heartbeat_bytes) {
+            watch.on_heartbeat(
+                GUID::new(hb.writer_guid_prefix, hb.writer_entity_id),
+                has_liveliness_flag(heartbeat_bytes),
+            );
+        }
+    }
+}
+
+/// Refreshes a reader's [`LivelinessWatch`] when SEDP discovers a writer on
+/// its topic, so the match is reported without waiting for the next check.
+pub(super) struct WatchDiscoveryListener {
+    topic: String,
+    watch: Weak<LivelinessWatch>,
+}
+
+impl WatchDiscoveryListener {
+    pub(super) fn new(topic: String, watch: &Arc<LivelinessWatch>) -> Self {
+        Self {
+            topic,
+            watch: Arc::downgrade(watch),
+        }
+    }
+}
+
+impl DiscoveryListener for WatchDiscoveryListener {
+    fn on_endpoint_discovered(&self, endpoint: EndpointInfo) {
+        if endpoint.kind != EndpointKind::Writer || endpoint.topic_name != self.topic {
+            return;
+        }
+        if let Some(watch) = self.watch.upgrade() {
+            watch.refresh();
+        }
+    }
+
+    fn on_participant_lost(&self, _participant: GUID) {
+        if let Some(watch) = self.watch.upgrade() {
+            watch.refresh();
+        }
+    }
+
+    fn on_endpoint_lost(&self, endpoint: EndpointInfo) {
+        self.on_endpoint_discovered(endpoint);
+    }
+}
+
+/// Handle to a reader's liveliness thread; stops it when dropped.
+pub(super) struct LivelinessWatchHandle {
+    stop: Arc<(Mutex<bool>, Condvar)>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for LivelinessWatchHandle {
+    fn drop(&mut self) {
+        let (stopped, wake) = &*self.stop;
+        *lock(stopped) = true;
+        wake.notify_all();
+        if let Some(handle) = self.thread.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Spawn a thread refreshing `watch` so lease expirations are reported
+/// without the application reading the status.
+pub(super) fn spawn_liveliness_watch(watch: Arc<LivelinessWatch>) -> LivelinessWatchHandle {
+    let stop = Arc::new((Mutex::new(false), Condvar::new()));
+    let thread_stop = Arc::clone(&stop);
+
+    #[allow(clippy::expect_used)] // thread spawn failure is unrecoverable
+    let thread = thread::Builder::new()
+        .name("hdds-liveliness-watch".into())
+        .spawn(move || {
+            let (stopped, wake) = &*thread_stop;
+            loop {
+                watch.refresh();
+                let period = watch.check_period();
+                let (guard, _) = wake
+                    .wait_timeout_while(lock(stopped), period, |stopped| !*stopped)
+                    .unwrap_or_else(|e| e.into_inner());
+                if *guard {
+                    break;
+                }
+            }
+        })
+        .expect("failed to spawn liveliness watch thread");
+
+    LivelinessWatchHandle {
+        stop,
+        thread: Some(thread),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dds::qos::Liveliness;
+
+    fn guid(n: u8) -> GUID {
+        GUID::new([n; 12], [0, 0, 1, 0x02])
+    }
+
+    type LocalWriters = Arc<Mutex<Vec<(GUID, Arc<WriterLiveliness>)>>>;
+
+    fn watch_of(writers: LocalWriters) -> LivelinessWatch {
+        LivelinessWatch::new(
+            Box::new(move || {
+                lock(&writers)
+                    .iter()
+                    .map(|(guid, liveliness)| WatchedWriter::local(*guid, Arc::clone(liveliness)))
+                    .collect()
+            }),
+            None,
+            Arc::new(StatusCondition::new()),
+        )
+    }
+
+    #[test]
+    fn test_local_writer_lease_expiry() {
+        let writer = Arc::new(WriterLiveliness::new(&Liveliness::manual_by_topic(
+            Duration::from_millis(30),
+        )));
+        let writers = Arc::new(Mutex::new(vec![(guid(1), Arc::clone(&writer))]));
+        let watch = watch_of(Arc::clone(&writers));
+
+        let status = watch.status();
+        assert_eq!(status.alive_count, 1);
+        assert_eq!(status.alive_count_change, 1);
+        assert_eq!(status.not_alive_count, 0);
+        assert_eq!(status.last_publication_handle, Some(guid(1)));
+
+        thread::sleep(Duration::from_millis(60));
+        let status = watch.status();
+        assert_eq!(status.alive_count, 0);
+        assert_eq!(status.alive_count_change, -1);
+        assert_eq!(status.not_alive_count, 1);
+        assert_eq!(status.not_alive_count_change, 1);
+
+        writer.assert();
+        let status = watch.status();
+        assert_eq!(status.alive_count, 1);
+        assert_eq!(status.not_alive_count, 0);
+
+        // Unmatched writers leave the counts
+        lock(&writers).clear();
+        let status = watch.status();
+        assert_eq!(status.alive_count, 0);
+        assert_eq!(status.alive_count_change, -1);
+        assert_eq!(status.not_alive_count, 0);
+    }
+
+    #[test]
+    fn test_remote_manual_writer_needs_liveliness_flag() {
+        let remote = guid(2);
+        let watch = LivelinessWatch::new(
+            Box::new(move || {
+                vec![WatchedWriter {
+                    guid: remote,
+                    kind: LivelinessKind::ManualByTopic,
+                    lease_ns: Some(30_000_000),
+                    local: None,
+                }]
+            }),
+            None,
+            Arc::new(StatusCondition::new()),
+        );
+        assert_eq!(watch.status().alive_count, 1);
+
+        thread::sleep(Duration::from_millis(60));
+        // A plain HEARTBEAT does not assert a MANUAL writer
+        watch.on_heartbeat(remote, false);
+        assert_eq!(watch.status().not_alive_count, 1);
+
+        watch.on_heartbeat(remote, true);
+        let status = watch.status();
+        assert_eq!(status.alive_count, 1);
+        assert_eq!(status.not_alive_count, 0);
+    }
+
+    #[test]
+    fn test_remote_lease_on_virtual_clock() {
+        let remote = guid(3);
+        let clock = Arc::new(crate::core::rt::clock::VirtualClock::new());
+        let watch = LivelinessWatch::new(
+            Box::new(move || {
+                vec![WatchedWriter {
+                    guid: remote,
+                    kind: LivelinessKind::Automatic,
+                    lease_ns: Some(1_000_000_000),
+                    local: None,
+                }]
+            }),
+            None,
+            Arc::new(StatusCondition::new()),
+        )
+        .with_clock(clock.clone());
+        assert_eq!(watch.status().alive_count, 1);
+
+        // Wall time does not expire the lease, recorded time does
+        thread::sleep(Duration::from_millis(10));
+        clock.advance(Duration::from_millis(999));
+        assert_eq!(watch.status().alive_count, 1);
+        clock.advance(Duration::from_millis(2));
+        assert_eq!(watch.status().not_alive_count, 1);
+
+        watch.on_heartbeat(remote, false);
+        assert_eq!(watch.status().alive_count, 1);
+    }
+
+    #[test]
+    fn test_matched_status_follows_writers() {
+        let writers: LocalWriters = Arc::new(Mutex::new(Vec::new()));
+        let liveliness = Arc::new(WriterLiveliness::new(&Liveliness::default()));
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        let watch = watch_of(Arc::clone(&writers)).with_matched_callback(Some(Box::new(
+            move |status: SubscriptionMatchedStatus| lock(&sink).push(status),
+        )));
+
+        lock(&writers).push((guid(1), Arc::clone(&liveliness)));
+        lock(&writers).push((guid(2), Arc::clone(&liveliness)));
+        watch.refresh();
+        lock(&writers).retain(|(g, _)| *g != guid(1));
+        watch.refresh();
+        watch.refresh();
+
+        let events = lock(&events);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].current_count, 2);
+        assert_eq!(events[0].current_count_change, 2);
+        assert_eq!(events[1].total_count, 2);
+        assert_eq!(events[1].current_count, 1);
+        assert_eq!(events[1].current_count_change, -1);
+        assert_eq!(events[1].last_publication_handle, Some(guid(1)));
+        // The callback consumed the changes
+        assert_eq!(watch.matched_status().current_count_change, 0);
+    }
+
+    #[test]
+    fn test_liveliness_flag_parsing() {
+        let ctx = crate::protocol::builder::RtpsEndpointContext {
+            guid_prefix: [1; 12],
+            reader_entity_id: [0; 4],
+            writer_entity_id: [0, 0, 1, 0x02],
+        };
+        let liveliness = crate::protocol::builder::build_liveliness_heartbeat_packet(&ctx, 1, 0, 1);
+        assert!(has_liveliness_flag(&liveliness));
+        let plain = crate::protocol::builder::build_heartbeat_packet_with_context(&ctx, 1, 5, 1);
+        assert!(!has_liveliness_flag(&plain));
+    }
+}
@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Zero-copy loaned samples over the shared-memory transport.
+//!
+//! [`DataReader::take_loaned`] borrows samples published with
+//! [`DataWriter::loan_sample`](crate::DataWriter::loan_sample) straight from
+//! the writer's SHM ring. The returned guard pins the slot; dropping it
+//! hands the slot back to the writer.
+//!
+//! Writer rings are found by GUID (local writers and writers discovered via
+//! SEDP); a writer whose ring cannot be opened, e.g. on another host, is
+//! retried at most every `WRITER_SCAN_INTERVAL`.
+
+use super::DataReader;
+use crate::core::discovery::GUID;
+use crate::dds::{Error, ErrorContext, Result, DDS};
+use crate::transport::shm::{ShmReadLoan, ShmReaderTransport};
+use std::collections::HashSet;
+use std::ops::Deref;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Minimum delay between scans for new writer rings.
+const WRITER_SCAN_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Matched writer GUIDs for the reader's topic.
+pub(crate) type WriterGuids = Box<dyn Fn() -> Vec<GUID> + Send + Sync>;
+
+/// Reader-side attachments to writer SHM rings.
+pub(crate) struct ShmLoanReader {
+    domain_id: u32,
+    writers: WriterGuids,
+    state: Mutex<LoanReaderState>,
+}
+
+#[derive(Default)]
+struct LoanReaderState {
+    transport: Option<ShmReaderTransport>,
+    attached: HashSet<GUID>,
+    /// Writers seen before their ring existed: attach from the start so
+    /// the first loans are not missed.
+    pending: HashSet<GUID>,
+    last_scan: Option<Instant>,
+}
+
+impl ShmLoanReader {
+    pub(crate) fn new(domain_id: u32, topic: &str, writers: WriterGuids) -> Self {
+        let reader = Self {
+            domain_id,
+            writers,
+            state: Mutex::new(LoanReaderState::default()),
+        };
+        // Record writers already matched now, so rings they create later
+        // are read from their first sample.
+        if let Ok(mut state) = reader.state.lock() {
+            reader.scan(&mut state, topic);
+        }
+        reader
+    }
+
+    fn try_take(&self, topic: &str) -> Result<Option<ShmReadLoan>> {
+        let mut state = self.state.lock().map_err(|_| Error::WouldBlock)?;
+        if let Some(loan) = state.transport.as_mut().and_then(|t| t.try_read_loaned()) {
+            return Ok(Some(loan));
+        }
+        if state
+            .last_scan
+            .is_some_and(|t| t.elapsed() < WRITER_SCAN_INTERVAL)
+        {
+            return Ok(None);
+        }
+        self.scan(&mut state, topic);
+        Ok(state.transport.as_mut().and_then(|t| t.try_read_loaned()))
+    }
+
+    /// Attach to rings of writers not attached yet.
+    fn scan(&self, state: &mut LoanReaderState, topic: &str) {
+        state.last_scan = Some(Instant::now());
+        let guids: Vec<GUID> = (self.writers)()
+            .into_iter()
+            .filter(|guid| !state.attached.contains(guid))
+            .collect();
+        if guids.is_empty() {
+            return;
+        }
+
+        if state.transport.is_none() {
+            match ShmReaderTransport::new(self.domain_id, topic) {
+                Ok(transport) => state.transport = Some(transport),
+                Err(e) => {
+                    log::debug!("[reader] SHM loan transport topic='{}': {}", topic, e);
+                    return;
+                }
+            }
+        }
+        let Some(transport) = state.transport.as_mut() else {
+            return;
+        };
+
+        for guid in guids {
+            let start_seq = state.pending.contains(&guid).then_some(0);
+            match transport.attach_writer_from(guid, start_seq) {
+                Ok(()) => {
+                    log::debug!(
+                        "[reader] SHM loan ring attached topic='{}' writer={}",
+                        topic,
+                        guid
+                    );
+                    state.pending.remove(&guid);
+                    state.attached.insert(guid);
+                }
+                Err(_) => {
+                    state.pending.insert(guid);
+                }
+            }
+        }
+    }
+}
+
+impl<T: DDS> DataReader<T> {
+    /// Take the next loaned sample without copying it out of shared memory.
+    ///
+    /// Receives samples published with
+    /// [`DataWriter::loan_sample`](crate::DataWriter::loan_sample) by writers
+    /// on the same host. The writer's slot is released when the returned
+    /// guard is dropped, so drop it promptly: a writer that laps the ring
+    /// onto a held slot gets `Error::WouldBlock`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::UnsupportedOperation` if the reader has no SHM path (SHM
+    /// disabled, Reliable QoS, or no participant).
+    pub fn take_loaned(&self) -> Result<Option<LoanedSample<T>>> {
+        let shm = self.shm_loans.as_ref().ok_or_else(|| {
+            Error::unsupported("loaned take without a shared-memory path").with_context(
+                ErrorContext::new()
+                    .entity("DataReader")
+                    .topic(self.topic_name()),
+            )
+        })?;
+        Ok(shm.try_take(self.topic_name())?.map(|loan| LoanedSample {
+            loan,
+            _phantom: core::marker::PhantomData,
+        }))
+    }
+}
+
+/// Sample borrowed from a writer's shared-memory ring.
+///
+/// Dereferences to the serialized (CDR2) bytes; [`decode`](Self::decode)
+/// deserializes them. Dropping it releases the slot back to the writer.
+pub struct LoanedSample<T: DDS> {
+    loan: ShmReadLoan,
+    _phantom: core::marker::PhantomData<T>,
+}
+
+impl<T: DDS> LoanedSample<T> {
+    /// Deserialize the sample.
+    pub fn decode(&self) -> Result<T> {
+        T::decode_cdr2(&self.loan)
+    }
+}
+
+impl<T: DDS> Deref for LoanedSample<T> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.loan
+    }
+}
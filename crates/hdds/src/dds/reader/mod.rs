@@ -37,6 +37,11 @@
 //!     let samples = reader.take_batch(10)?;
 //!     println!("Got {} samples", samples.len());
 //!
+//!     // Blocking iteration, ends after 1s without data
+//!     for sample in reader.iter(Some(std::time::Duration::from_secs(1))) {
+//!         println!("Received: {:?}", sample?);
+//!     }
+//!
 //!     Ok(())
 //! }
 //! # #[derive(hdds::DDS, Debug)] struct SensorData { value: f64 }
@@ -57,15 +62,34 @@
 
 mod builder;
 mod cache;
+mod conditions;
 mod heartbeat;
+mod hibernation;
+mod iter;
+mod liveliness;
+#[cfg(target_os = "linux")]
+mod loan;
+mod ordered_merge;
+mod replay_dedup;
 mod runtime;
+mod sample_lost;
+mod source_order;
+mod staleness;
 mod subscriber;
 #[cfg(test)]
 mod tests;
+mod time_filter;
 
 pub use builder::ReaderBuilder;
+pub use cache::{InstanceHandle, InstanceState, SampleInfo, SampleState, ViewState};
+pub use iter::SampleIter;
+#[cfg(feature = "async")]
+pub use iter::SampleStream;
+#[cfg(target_os = "linux")]
+pub use loan::LoanedSample;
 #[allow(unused_imports)]
 pub use runtime::{DataReader, ReaderStats};
+pub use staleness::SampleRateMetrics;
 
 use super::condition::HasStatusCondition;
 use super::DDS;
@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Ordered merge of the samples of all matched writers.
+//!
+//! Samples are held back for a reordering window after they arrive, and
+//! released in source timestamp order across writers, so two redundant
+//! publishers of a topic read as one time-ordered stream. The order key is
+//! the writer's INFO_TS timestamp when the sample carried one, the ring
+//! entry's timestamp otherwise (reception time, or write time for
+//! intra-process writers); ties keep arrival order.
+//!
+//! A sample arriving after a newer one was released is late: it is dropped
+//! so the delivered stream never goes back in time. Widen the window to
+//! cover the skew between writers plus their network jitter.
+
+use crate::core::rt::IndexEntry;
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Reordering buffer placed between the ring and the sample cache.
+pub(super) struct OrderedMerge {
+    window_ns: u64,
+    state: Mutex<MergeState>,
+}
+
+#[derive(Default)]
+struct MergeState {
+    held: BinaryHeap<Reverse<Held>>,
+    /// Arrival counter, breaks ties between equal order keys.
+    next_arrival: u64,
+    /// Order key of the last released sample.
+    last_released_ns: Option<u64>,
+}
+
+struct Held {
+    order_ns: u64,
+    arrival: u64,
+    release_at_ns: u64,
+    entry: IndexEntry,
+}
+
+impl Held {
+    fn key(&self) -> (u64, u64) {
+        (self.order_ns, self.arrival)
+    }
+}
+
+impl PartialEq for Held {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for Held {}
+
+impl PartialOrd for Held {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Held {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+/// Order key of `entry`: source timestamp, else ring entry timestamp.
+fn order_ns(entry: &IndexEntry) -> u64 {
+    entry.rx_timestamps.source_ns.unwrap_or(entry.timestamp_ns)
+}
+
+impl OrderedMerge {
+    pub(super) fn new(window: Duration) -> Self {
+        Self {
+            window_ns: u64::try_from(window.as_nanos()).unwrap_or(u64::MAX),
+            state: Mutex::new(MergeState::default()),
+        }
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, MergeState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Hold `entry`, which arrived at `now_ns`.
+    ///
+    /// Returns the entry back if it is late (older than the last released
+    /// sample); the caller drops it.
+    pub(super) fn push(&self, entry: IndexEntry, now_ns: u64) -> Option<IndexEntry> {
+        let order_ns = order_ns(&entry);
+        let mut state = self.state();
+        if state.last_released_ns.is_some_and(|last| order_ns < last) {
+            return Some(entry);
+        }
+        let arrival = state.next_arrival;
+        state.next_arrival += 1;
+        state.held.push(Reverse(Held {
+            order_ns,
+            arrival,
+            release_at_ns: now_ns.saturating_add(self.window_ns),
+            entry,
+        }));
+        None
+    }
+
+    /// Oldest held sample, once it has been held for the whole window.
+    ///
+    /// A sample is only released after every sample older than it, so a
+    /// recent sample can hold back newer ones that arrived earlier.
+    pub(super) fn pop_ready(&self, now_ns: u64) -> Option<IndexEntry> {
+        let mut state = self.state();
+        let ready = state
+            .held
+            .peek()
+            .is_some_and(|Reverse(held)| held.release_at_ns <= now_ns);
+        if !ready {
+            return None;
+        }
+        let Reverse(held) = state.held.pop()?;
+        state.last_released_ns = Some(held.order_ns);
+        Some(held.entry)
+    }
+
+    /// Number of samples held back.
+    pub(super) fn len(&self) -> usize {
+        self.state().held.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::rt::SlabHandle;
+    use crate::engine::RxTimestamps;
+
+    const MS: u64 = 1_000_000;
+
+    fn entry(seq: u32, source_ns: Option<u64>, timestamp_ns: u64) -> IndexEntry {
+        let mut entry = IndexEntry::with_timestamp(seq, SlabHandle(0), 0, timestamp_ns);
+        entry.rx_timestamps = RxTimestamps {
+            source_ns,
+            ..RxTimestamps::NONE
+        };
+        entry
+    }
+
+    fn drain(merge: &OrderedMerge, now_ns: u64) -> Vec<u32> {
+        std::iter::from_fn(|| merge.pop_ready(now_ns))
+            .map(|entry| entry.seq)
+            .collect()
+    }
+
+    #[test]
+    fn test_releases_in_source_order_after_window() {
+        let merge = OrderedMerge::new(Duration::from_millis(50));
+
+        // Two writers, interleaved out of source order on arrival
+        assert!(merge.push(entry(1, Some(30 * MS), 0), 100 * MS).is_none());
+        assert!(merge.push(entry(2, Some(10 * MS), 0), 110 * MS).is_none());
+        assert!(merge.push(entry(3, Some(20 * MS), 0), 120 * MS).is_none());
+
+        // Nothing before the oldest sample's window has elapsed
+        assert!(drain(&merge, 140 * MS).is_empty());
+        assert_eq!(drain(&merge, 170 * MS), vec![2, 3, 1]);
+        assert_eq!(merge.len(), 0);
+    }
+
+    #[test]
+    fn test_late_sample_is_returned() {
+        let merge = OrderedMerge::new(Duration::ZERO);
+
+        assert!(merge.push(entry(1, Some(20 * MS), 0), 0).is_none());
+        assert_eq!(drain(&merge, 0), vec![1]);
+
+        let late = merge.push(entry(2, Some(10 * MS), 0), 0);
+        assert_eq!(late.map(|entry| entry.seq), Some(2));
+        assert!(merge.push(entry(3, Some(20 * MS), 0), 0).is_none());
+        assert_eq!(drain(&merge, 0), vec![3]);
+    }
+
+    #[test]
+    fn test_falls_back_to_entry_timestamp() {
+        let merge = OrderedMerge::new(Duration::ZERO);
+
+        assert!(merge.push(entry(1, None, 5 * MS), 0).is_none());
+        assert!(merge.push(entry(2, Some(2 * MS), 9 * MS), 0).is_none());
+        assert!(merge.push(entry(3, None, 5 * MS), 0).is_none());
+        assert_eq!(drain(&merge, 0), vec![2, 1, 3]);
+    }
+}
@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Duplicate suppression for PERSISTENT readers.
+//!
+//! A late-joining PERSISTENT reader can receive a sample twice: from the
+//! history of the writer that published it and from a persistence service
+//! replaying it (or from two replicas of the service). Every sample is
+//! identified by the writer and sequence number it was first published
+//! with, which relayed samples carry in `PID_ORIGINAL_WRITER_INFO`, so the
+//! reader delivers each one once, whichever copy arrives first. Like
+//! TIME_BASED_FILTER, dropped copies never reach the sample cache.
+
+use crate::core::discovery::GUID;
+use crate::dds::qos::Durability;
+use crate::protocol::original_writer::OriginalWriterInfo;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+
+/// Sequence numbers delivered per original writer.
+pub(super) struct ReplayDedup {
+    delivered: Mutex<HashMap<GUID, DeliveredRanges>>,
+}
+
+impl ReplayDedup {
+    /// Deduplication for `durability`, or `None` below PERSISTENT.
+    pub(super) fn new(durability: Durability) -> Option<Self> {
+        (durability == Durability::Persistent).then(|| Self {
+            delivered: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Whether the sample first published as `original` is delivered,
+    /// i.e. no copy of it was delivered before.
+    pub(super) fn accept(&self, original: OriginalWriterInfo) -> bool {
+        let mut delivered = self.delivered.lock().unwrap_or_else(|e| e.into_inner());
+        delivered
+            .entry(original.writer_guid)
+            .or_default()
+            .insert(original.sequence)
+    }
+}
+
+/// Disjoint inclusive ranges of sequence numbers, keyed by their start.
+///
+/// Live samples and replays each arrive mostly in order, so a writer takes
+/// a handful of ranges whatever the number of samples.
+#[derive(Default)]
+struct DeliveredRanges(BTreeMap<u64, u64>);
+
+impl DeliveredRanges {
+    /// Add `seq`; `false` if it was already there.
+    fn insert(&mut self, seq: u64) -> bool {
+        let below = self.0.range(..=seq).next_back().map(|(&s, &e)| (s, e));
+        let mut start = seq;
+        if let Some((below_start, below_end)) = below {
+            if seq <= below_end {
+                return false;
+            }
+            if below_end + 1 == seq {
+                start = below_start;
+            }
+        }
+        let end = match seq.checked_add(1).and_then(|next| self.0.remove(&next)) {
+            Some(above_end) => above_end,
+            None => seq,
+        };
+        self.0.insert(start, end);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_persistent_readers_deduplicate() {
+        assert!(ReplayDedup::new(Durability::Volatile).is_none());
+        assert!(ReplayDedup::new(Durability::TransientLocal).is_none());
+        assert!(ReplayDedup::new(Durability::Persistent).is_some());
+    }
+
+    #[test]
+    fn test_live_then_replay() {
+        let dedup = ReplayDedup::new(Durability::Persistent).expect("dedup");
+        let writer = GUID::new([1; 12], [0, 0, 1, 2]);
+        let other = GUID::new([2; 12], [0, 0, 1, 2]);
+
+        // Joined live at 5, then the replay brings 1..=6
+        let live: Vec<bool> = (5..=6)
+            .map(|seq| dedup.accept(OriginalWriterInfo::new(writer, seq)))
+            .collect();
+        assert_eq!(live, [true, true]);
+        let replayed: Vec<u64> = (1..=6)
+            .filter(|&seq| dedup.accept(OriginalWriterInfo::new(writer, seq)))
+            .collect();
+        assert_eq!(replayed, [1, 2, 3, 4]);
+
+        // Sequence numbers are per writer
+        assert!(dedup.accept(OriginalWriterInfo::new(other, 5)));
+        assert!(!dedup.accept(OriginalWriterInfo::new(other, 5)));
+    }
+
+    #[test]
+    fn test_ranges_merge() {
+        let mut ranges = DeliveredRanges::default();
+        for seq in [1, 3, 5, 2, 4, u64::MAX] {
+            assert!(ranges.insert(seq));
+        }
+        assert_eq!(
+            ranges.0.into_iter().collect::<Vec<_>>(),
+            [(1, 5), (u64::MAX, u64::MAX)]
+        );
+    }
+}
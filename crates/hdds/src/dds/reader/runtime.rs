@@ -1,15 +1,30 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 // Copyright (c) 2025-2026 naskel.com
 
-use super::cache::{CachedSample, InstanceHandle, SampleCache};
+use super::cache::{CachedSample, InstanceHandle, InstanceState, SampleCache, SampleInfo};
+use super::liveliness::{LivelinessWatch, LivelinessWatchHandle};
+use super::ordered_merge::OrderedMerge;
+use super::replay_dedup::ReplayDedup;
+use super::sample_lost::SampleLost;
+use super::source_order::SourceOrder;
+use super::staleness::{SampleArrivals, SampleRateMetrics, StalenessWatchHandle};
+use super::time_filter::TimeFilter;
 use crate::core::rt;
+use crate::dds::coherent::CoherentMembership;
+use crate::dds::incompatible_qos::IncompatibleQosWatch;
+use crate::dds::listener::{
+    InconsistentTopicStatus, LivelinessChangedStatus, RequestedIncompatibleQosStatus,
+    SampleLostStatus, SampleStalenessStatus, SubscriptionMatchedStatus,
+};
 use crate::dds::{qos::History, BindToken, QoS, Result, StatusCondition, StatusMask, DDS};
-use crate::engine::TopicRegistry;
+use crate::engine::{RxTimestamps, TopicRegistry};
 use crate::protocol::builder;
 use crate::reliability::{NackScheduler, ReliableMetrics};
 use crate::telemetry;
 use crate::telemetry::metrics::current_time_ns;
+use crate::telemetry::{EndpointMetrics, EndpointRole, MetricsRegistration};
 use crate::transport::UdpTransport;
+use bytes::Bytes;
 use std::sync::{Arc, Mutex};
 
 /// A typed DDS DataReader that subscribes to samples on a topic.
@@ -61,18 +76,53 @@ use std::sync::{Arc, Mutex};
 pub struct DataReader<T: DDS> {
     topic: String,
     qos: QoS,
-    ring: Arc<rt::IndexRing>,
-    /// Sample cache for read/take operations (DDS standard API).
-    cache: SampleCache<T>,
+    pub(super) ring: Arc<rt::IndexRing>,
+    /// Sample cache for read/take operations (DDS standard API), shared with
+    /// the reader's read conditions.
+    pub(super) cache: Arc<SampleCache<T>>,
     #[allow(dead_code)]
     registry: Option<Arc<TopicRegistry>>,
     nack_scheduler: Option<Arc<Mutex<NackScheduler>>>,
     transport: Option<Arc<UdpTransport>>,
     #[allow(dead_code)]
     reliable_metrics: Option<Arc<ReliableMetrics>>,
-    status_condition: Arc<StatusCondition>,
+    /// Per-reader traffic counters reported by the admin API
+    pub(super) endpoint_metrics: Arc<EndpointMetrics>,
+    /// Listing of `endpoint_metrics` in the admin API (removed on drop)
+    pub(super) _metrics_registration: Option<MetricsRegistration>,
+    pub(super) status_condition: Arc<StatusCondition>,
     /// BindToken for intra-process auto-binding (unregisters on drop)
     _bind_token: Option<BindToken>,
+    /// Coherent set gate of the reader's Subscriber (see `ReaderBuilder::subscriber`)
+    pub(super) coherent: Option<CoherentMembership>,
+    /// TIME_BASED_FILTER QoS (`None` when disabled)
+    time_filter: Option<TimeFilter>,
+    /// DESTINATION_ORDER BY_SOURCE_TIMESTAMP (`None` when by reception)
+    source_order: Option<SourceOrder>,
+    /// Relayed copies of delivered samples dropped (`None` below PERSISTENT)
+    replay_dedup: Option<ReplayDedup>,
+    /// Ordered merge across writers (see `ReaderBuilder::with_ordered_merge`)
+    pub(super) ordered_merge: Option<OrderedMerge>,
+    /// Liveliness of matched writers (see `DataReader::liveliness_changed_status`)
+    pub(super) liveliness: Option<Arc<LivelinessWatch>>,
+    /// Thread reporting writer lease expirations (stopped on drop)
+    pub(super) _liveliness_watch: Option<LivelinessWatchHandle>,
+    /// Writers discovered with incompatible QoS (see `DataReader::requested_incompatible_qos_status`)
+    pub(super) incompatible_qos: Option<Arc<IncompatibleQosWatch>>,
+    /// Samples given up on by the NACK scheduler (see `DataReader::sample_lost_status`)
+    pub(super) sample_lost: Option<SampleLost>,
+    /// Sample arrivals and staleness watchdogs (see `DataReader::rate_metrics`)
+    pub(super) arrivals: Arc<SampleArrivals>,
+    /// Thread reporting stale watchdogs (stopped on drop)
+    pub(super) _staleness_watch: Option<StalenessWatchHandle>,
+    /// Idle-reader hibernation hooks, watched by the participant's sweep
+    pub(super) _hibernation: Option<Arc<dyn crate::dds::Hibernate>>,
+    /// Attachments to writer SHM rings (see `DataReader::take_loaned`)
+    #[cfg(target_os = "linux")]
+    pub(super) shm_loans: Option<super::loan::ShmLoanReader>,
+    /// WASM filter/transform (see `ReaderBuilder::wasm_plugin`)
+    #[cfg(feature = "wasmtime")]
+    pub(super) wasm_plugin: Option<Arc<crate::dds::WasmPlugin>>,
     /// Security plugin suite for decryption (DDS Security v1.1)
     #[cfg(feature = "security")]
     #[allow(dead_code)]
@@ -94,23 +144,61 @@ impl<T: DDS> DataReader<T> {
         bind_token: Option<BindToken>,
         #[cfg(feature = "security")] security: Option<Arc<crate::security::SecurityPluginSuite>>,
     ) -> Self {
-        // Determine cache size from history QoS
-        let cache_size = match qos.history {
-            History::KeepLast(depth) => depth as usize,
-            History::KeepAll => 1024, // Default for KeepAll
+        // Determine cache size from history QoS. KEEP_LAST depth applies per
+        // instance on keyed topics, bounded by ResourceLimits.max_samples.
+        let cache = match qos.history {
+            History::KeepLast(depth) if T::has_key() => SampleCache::with_instance_depth(
+                qos.resource_limits.max_samples.max(depth as usize),
+                depth as usize,
+            ),
+            History::KeepLast(depth) => SampleCache::new(depth as usize),
+            History::KeepAll => SampleCache::new(1024), // Default for KeepAll
         };
 
+        let time_filter = TimeFilter::new(qos.time_based_filter);
+        let source_order = SourceOrder::new(qos.destination_order);
+        let replay_dedup = ReplayDedup::new(qos.durability);
+        let arrivals = Arc::new(SampleArrivals::new(
+            &[],
+            None,
+            Arc::clone(&status_condition),
+        ));
+
+        let endpoint_metrics = Arc::new(EndpointMetrics::new(
+            EndpointRole::Reader,
+            &topic,
+            reliable_metrics.clone(),
+        ));
+
         Self {
             topic,
             qos,
             ring,
-            cache: SampleCache::new(cache_size),
+            cache: Arc::new(cache),
             registry,
             nack_scheduler,
             transport,
             reliable_metrics,
+            endpoint_metrics,
+            _metrics_registration: None,
             status_condition,
             _bind_token: bind_token,
+            coherent: None,
+            time_filter,
+            source_order,
+            replay_dedup,
+            ordered_merge: None,
+            liveliness: None,
+            _liveliness_watch: None,
+            incompatible_qos: None,
+            sample_lost: None,
+            arrivals,
+            _staleness_watch: None,
+            _hibernation: None,
+            #[cfg(target_os = "linux")]
+            shm_loans: None,
+            #[cfg(feature = "wasmtime")]
+            wasm_plugin: None,
             #[cfg(feature = "security")]
             security,
             _phantom: core::marker::PhantomData,
@@ -132,10 +220,106 @@ impl<T: DDS> DataReader<T> {
         Arc::clone(&self.status_condition)
     }
 
+    /// Liveliness of the writers matched with this reader (DDS
+    /// LIVELINESS_CHANGED status).
+    ///
+    /// A writer is alive while it keeps asserting liveliness within its
+    /// lease duration (see [`DataWriter::assert_liveliness`]). The `*_change`
+    /// fields count changes since the previous call, or since the last
+    /// [`DataReaderListener::on_liveliness_changed`] callback when a listener
+    /// is set.
+    ///
+    /// Lease expirations are reported in the background when the reader has
+    /// a listener or a finite LIVELINESS lease of its own; otherwise they are
+    /// detected when this method is called.
+    ///
+    /// [`DataWriter::assert_liveliness`]: crate::DataWriter::assert_liveliness
+    /// [`DataReaderListener::on_liveliness_changed`]: crate::dds::DataReaderListener::on_liveliness_changed
+    pub fn liveliness_changed_status(&self) -> LivelinessChangedStatus {
+        self.liveliness
+            .as_ref()
+            .map(|watch| watch.status())
+            .unwrap_or_default()
+    }
+
+    /// Writers matched with this reader (DDS SUBSCRIPTION_MATCHED status).
+    ///
+    /// Same-process writers and writers discovered through SEDP count when
+    /// their reliability, QoS and partitions are compatible with the reader.
+    /// The `*_change` fields count changes since the previous call, or since
+    /// the last [`DataReaderListener::on_subscription_matched`] callback when
+    /// a listener is set.
+    ///
+    /// [`DataReaderListener::on_subscription_matched`]: crate::dds::DataReaderListener::on_subscription_matched
+    pub fn subscription_matched_status(&self) -> SubscriptionMatchedStatus {
+        self.liveliness
+            .as_ref()
+            .map(|watch| watch.matched_status())
+            .unwrap_or_default()
+    }
+
+    /// Discovered writers whose QoS does not satisfy this reader (DDS
+    /// REQUESTED_INCOMPATIBLE_QOS status).
+    ///
+    /// `last_policy_id` is a [`QosPolicyId`](crate::dds::qos::QosPolicyId)
+    /// value. Each incompatible writer is counted once.
+    pub fn requested_incompatible_qos_status(&self) -> RequestedIncompatibleQosStatus {
+        self.incompatible_qos
+            .as_ref()
+            .map(|watch| watch.status())
+            .unwrap_or_default()
+    }
+
+    /// Discovered writers on this topic whose type does not match this
+    /// reader's (DDS INCONSISTENT_TOPIC status).
+    ///
+    /// Such writers never match; each is counted once and logged as a
+    /// warning naming both types.
+    pub fn inconsistent_topic_status(&self) -> InconsistentTopicStatus {
+        self.incompatible_qos
+            .as_ref()
+            .map(|watch| watch.inconsistent_status())
+            .unwrap_or_default()
+    }
+
+    /// Samples this reader will never receive (DDS SAMPLE_LOST status).
+    ///
+    /// Only Reliable readers detect losses: sequences still missing after
+    /// the NACK retries are exhausted. Losses are found while the reader
+    /// is read or taken from.
+    pub fn sample_lost_status(&self) -> SampleLostStatus {
+        self.sample_lost
+            .as_ref()
+            .map(SampleLost::status)
+            .unwrap_or_default()
+    }
+
+    /// Time since the last sample arrived (`None` before the first one).
+    ///
+    /// Counts arrivals, not reads: a sample waiting in the reader is fresh.
+    pub fn last_sample_age(&self) -> Option<std::time::Duration> {
+        self.arrivals.last_sample_age()
+    }
+
+    /// Sample arrival count and rate.
+    pub fn rate_metrics(&self) -> SampleRateMetrics {
+        self.arrivals.metrics()
+    }
+
+    /// Status of each staleness watchdog, in the order they were added
+    /// (see `ReaderBuilder::with_staleness_watchdog`).
+    ///
+    /// Resets the change counters.
+    pub fn staleness_status(&self) -> Vec<SampleStalenessStatus> {
+        self.arrivals.staleness_status()
+    }
+
     pub fn bind_to_writer(&self, writer_merger: Arc<rt::TopicMerger>) {
         let ring = Arc::clone(&self.ring);
         let status_condition = Arc::clone(&self.status_condition);
+        let arrivals = Arc::clone(&self.arrivals);
         let notify: Arc<dyn Fn() + Send + Sync> = Arc::new(move || {
+            arrivals.record();
             status_condition.set_active_statuses(StatusMask::DATA_AVAILABLE);
         });
 
@@ -159,54 +343,73 @@ impl<T: DDS> DataReader<T> {
 
         self.enforce_history();
 
-        let entry = match self.ring.pop() {
-            Some(entry) => entry,
-            None => return Ok(None),
-        };
-        log::debug!(
-            "[READER] pop topic='{}' seq={} len={} handle={:?}",
-            self.topic,
-            entry.seq,
-            entry.len,
-            entry.handle
-        );
-
-        if let Some(scheduler) = &self.nack_scheduler {
-            let mut sched = match scheduler.lock() {
-                Ok(lock) => lock,
-                Err(err) => {
-                    log::debug!(
-                        "[Reader::try_take] nack_scheduler lock poisoned; recovering. {:?}",
-                        err
-                    );
-                    err.into_inner()
+        // Instance lifecycle entries carry no sample; only take() tracks them.
+        let (entry, msg) = loop {
+            let entry = match self.pop_entry() {
+                Some(entry) if entry.is_instance_state() => {
+                    rt::get_slab_pool().release(entry.handle);
+                    continue;
+                }
+                Some(entry) if !T::has_key() && self.filtered(&entry, InstanceHandle::nil()) => {
+                    rt::get_slab_pool().release(entry.handle);
+                    continue;
                 }
+                Some(entry) => entry,
+                None => return Ok(None),
             };
-            sched.on_receive(u64::from(entry.seq));
-        }
+            log::debug!(
+                "[READER] pop topic='{}' seq={} len={} handle={:?}",
+                self.topic,
+                entry.seq,
+                entry.len,
+                entry.handle
+            );
 
-        let slab_pool = rt::get_slab_pool();
-        let buf = slab_pool.get_buffer(entry.handle);
-        let data_len = entry.len as usize;
-        let slice = &buf[..data_len];
-        let decode_result = T::decode_cdr2(slice);
-        slab_pool.release(entry.handle);
-
-        let msg = match decode_result {
-            Ok(msg) => {
-                log::debug!("[READER] decoded topic='{}' len={}", self.topic, data_len);
-                msg
+            if let Some(scheduler) = &self.nack_scheduler {
+                let mut sched = match scheduler.lock() {
+                    Ok(lock) => lock,
+                    Err(err) => {
+                        log::debug!(
+                            "[Reader::try_take] nack_scheduler lock poisoned; recovering. {:?}",
+                            err
+                        );
+                        err.into_inner()
+                    }
+                };
+                sched.on_receive(u64::from(entry.seq));
             }
-            Err(e) => {
-                let preview_len = if data_len > 32 { 32 } else { data_len };
-                log::error!(
-                    "[READER-DECODE] failed topic='{}' len={} error={} first_bytes={:02x?}",
-                    self.topic,
-                    data_len,
-                    e,
-                    &slice[..preview_len]
-                );
-                return Err(e);
+
+            let slab_pool = rt::get_slab_pool();
+            let buf = slab_pool.get_buffer(entry.handle);
+            let data_len = entry.len as usize;
+            let slice = &buf[..data_len];
+            let decode_result = self.decode_entry(&entry, slice);
+            slab_pool.release(entry.handle);
+
+            match decode_result {
+                // Dropped by the WASM plugin
+                None => continue,
+                Some(Ok(msg))
+                    if T::has_key()
+                        && self.out_of_order(&entry, InstanceHandle::new(msg.compute_key())) =>
+                {
+                    continue
+                }
+                Some(Ok(msg)) => {
+                    log::debug!("[READER] decoded topic='{}' len={}", self.topic, data_len);
+                    break (entry, msg);
+                }
+                Some(Err(e)) => {
+                    let preview_len = if data_len > 32 { 32 } else { data_len };
+                    log::error!(
+                        "[READER-DECODE] failed topic='{}' len={} error={} first_bytes={:02x?}",
+                        self.topic,
+                        data_len,
+                        e,
+                        &slice[..preview_len]
+                    );
+                    return Err(e);
+                }
             }
         };
 
@@ -217,7 +420,7 @@ impl<T: DDS> DataReader<T> {
 
         self.maybe_send_nack();
 
-        if self.ring.is_empty() {
+        if self.pending_len() == 0 {
             self.status_condition.clear_active_statuses();
         }
 
@@ -225,14 +428,8 @@ impl<T: DDS> DataReader<T> {
     }
 
     pub fn take_batch(&self, max: usize) -> Result<Vec<T>> {
-        let mut messages = Vec::with_capacity(max);
-        for _ in 0..max {
-            match self.take()? {
-                Some(sample) => messages.push(sample),
-                None => break,
-            }
-        }
-        Ok(messages)
+        self.pump_ring_to_cache()?;
+        Ok(self.cache.take_batch(max))
     }
 
     // =========================================================================
@@ -272,6 +469,70 @@ impl<T: DDS> DataReader<T> {
         Ok(result)
     }
 
+    /// Take a single sample together with its [`SampleInfo`].
+    ///
+    /// Same semantics as [`take()`](Self::take); the info carries the
+    /// writer sequence number, reception timestamp, instance handle and the
+    /// trace id set via [`DataWriter::write_with_trace_id`](crate::DataWriter::write_with_trace_id).
+    pub fn take_with_info(&self) -> Result<Option<(T, SampleInfo)>> {
+        self.pump_ring_to_cache()?;
+
+        let result = self.cache.take_with_info();
+        if let Some((_, info)) = &result {
+            if let Some(trace_id) = info.trace_id {
+                log::debug!(
+                    "[trace] take topic='{}' seq={} trace_id={:016x} queued_ns={}",
+                    self.topic,
+                    info.seq,
+                    trace_id,
+                    current_time_ns().saturating_sub(info.timestamp_ns)
+                );
+            }
+        }
+        Ok(result)
+    }
+
+    /// Take up to `max` samples with their [`SampleInfo`], oldest first.
+    ///
+    /// Pending samples are moved to the cache and the batch is removed under
+    /// a single lock, which makes this the cheapest way to drain a
+    /// high-rate topic. The info carries the sample, view and instance
+    /// states before the call and the writer's GUID when known.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use hdds::{Participant, QoS, Result};
+    /// # fn main() -> Result<()> {
+    /// # let participant = Participant::builder("test").build()?;
+    /// # let reader = participant.create_reader::<MyType>("topic", QoS::default())?;
+    /// for (sample, info) in reader.take_n(256)? {
+    ///     println!("seq={} from {:?}: {:?}", info.seq, info.writer_guid, sample);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # #[derive(hdds::DDS, Debug)] struct MyType { x: i32 }
+    /// ```
+    pub fn take_n(&self, max: usize) -> Result<Vec<(T, SampleInfo)>> {
+        self.pump_ring_to_cache()?;
+        Ok(self.cache.take_batch_with_info(max))
+    }
+
+    /// Number of samples available to [`take()`](Self::take) or
+    /// [`read()`](Self::read), including received samples not decoded yet.
+    ///
+    /// Samples not decoded yet may still be dropped by the time-based
+    /// filter, so this is an upper bound.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cache.len() + self.pending_len()
+    }
+
+    /// Whether the reader holds no sample (see [`len()`](Self::len)).
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.pending_len() == 0 && self.cache.is_empty()
+    }
+
     /// Take the next sample (DDS standard alias).
     ///
     /// Equivalent to [`take()`](Self::take).
@@ -297,6 +558,26 @@ impl<T: DDS> DataReader<T> {
         Ok(self.cache.take_instance(handle))
     }
 
+    /// Current lifecycle state of an instance, or `None` if no sample or
+    /// lifecycle change for it was received yet.
+    pub fn instance_state(&self, handle: InstanceHandle) -> Result<Option<InstanceState>> {
+        self.pump_ring_to_cache()?;
+        Ok(self.cache.instance_state(handle))
+    }
+
+    /// Handle of the instance identified by the key fields of `instance`,
+    /// or `None` if this reader has not seen it (DDS `lookup_instance`).
+    pub fn lookup_instance(&self, instance: &T) -> Result<Option<InstanceHandle>> {
+        let handle = InstanceHandle::new(instance.compute_key());
+        Ok(self.instance_state(handle)?.map(|_| handle))
+    }
+
+    /// Handles of all instances seen so far, in no particular order.
+    pub fn instance_handles(&self) -> Result<Vec<InstanceHandle>> {
+        self.pump_ring_to_cache()?;
+        Ok(self.cache.instance_handles())
+    }
+
     /// Take up to `max` samples for a specific instance, removing them.
     ///
     /// Returns and removes samples matching the given instance handle.
@@ -360,6 +641,16 @@ impl<T: DDS + Clone> DataReader<T> {
         Ok(self.cache.read_batch(max))
     }
 
+    /// Read up to `max` unread samples with their [`SampleInfo`] without
+    /// removing them.
+    ///
+    /// Same selection as [`read_batch()`](Self::read_batch), under a single
+    /// lock; see [`take_n()`](Self::take_n) for the info.
+    pub fn read_n(&self, max: usize) -> Result<Vec<(T, SampleInfo)>> {
+        self.pump_ring_to_cache()?;
+        Ok(self.cache.read_batch_with_info(max))
+    }
+
     /// Read the next sample (DDS standard alias).
     ///
     /// Equivalent to [`read()`](Self::read).
@@ -402,27 +693,69 @@ impl<T: DDS> DataReader<T> {
     /// Pump samples from network ring to cache.
     ///
     /// Decodes all pending samples and stores them in the cache.
-    fn pump_ring_to_cache(&self) -> Result<()> {
+    pub(super) fn pump_ring_to_cache(&self) -> Result<()> {
         let slab_pool = rt::get_slab_pool();
 
-        while let Some(entry) = self.ring.pop() {
+        if let Some(member) = &self.coherent {
+            member.pump();
+        }
+        while let Some(entry) = self.pop_entry() {
+            // Keyless topics have a single instance: filter before decoding
+            if !T::has_key()
+                && !entry.is_instance_state()
+                && self.filtered(&entry, InstanceHandle::nil())
+            {
+                slab_pool.release(entry.handle);
+                continue;
+            }
+            // Lifecycle changes name their instance by PID_KEY_HASH, their
+            // payload may be the serialized key only (raw publishers)
+            if let Some(key_hash) = entry.key_hash.filter(|_| entry.is_instance_state()) {
+                slab_pool.release(entry.handle);
+                self.apply_instance_state(InstanceHandle::new(key_hash), &entry);
+                continue;
+            }
+
             let buf = slab_pool.get_buffer(entry.handle);
             let data_len = entry.len as usize;
             let slice = &buf[..data_len];
 
-            let decode_result = T::decode_cdr2(slice);
+            let decode_result = self.decode_entry(&entry, slice);
             slab_pool.release(entry.handle);
+            let Some(decode_result) = decode_result else {
+                continue;
+            };
 
             match decode_result {
+                Ok(data) if entry.is_instance_state() => {
+                    let handle = InstanceHandle::new(data.compute_key());
+                    self.apply_instance_state(handle, &entry);
+                }
+                Err(e) if entry.is_instance_state() => {
+                    // Without PID_KEY_HASH nothing else names the instance
+                    log::debug!(
+                        "[READER] undecodable instance state topic='{}' seq={}: {}",
+                        self.topic,
+                        entry.seq,
+                        e
+                    );
+                }
                 Ok(data) => {
                     // Compute instance handle from @key fields
                     let instance_handle = InstanceHandle::new(data.compute_key());
+                    if T::has_key() && self.filtered(&entry, instance_handle) {
+                        continue;
+                    }
+                    // `write_with_trace_id` rejects 0, the untraced marker
+                    let trace_id = (entry.trace_id != 0).then_some(entry.trace_id);
                     let cached = CachedSample::with_instance(
                         data,
                         entry.seq as u64,
                         entry.timestamp_ns,
                         instance_handle,
-                    );
+                    )
+                    .with_trace_id(trace_id)
+                    .with_rx_timestamps(entry.rx_timestamps);
                     self.cache.push(cached);
 
                     // Update NACK scheduler if reliable
@@ -445,17 +778,166 @@ impl<T: DDS> DataReader<T> {
             }
         }
 
-        // Update status condition
-        if self.cache.is_empty() {
+        self.cache.purge_expired(current_time_ns());
+
+        // Update status condition (held samples keep DATA_AVAILABLE raised)
+        if self.cache.is_empty() && self.held_len() == 0 {
             self.status_condition.clear_active_statuses();
         }
 
         Ok(())
     }
 
+    /// Decode the payload of `entry`, after the WASM plugin when one is
+    /// attached. `None` when the plugin drops the sample, which still counts
+    /// as received for reliability.
+    fn decode_entry(&self, entry: &rt::IndexEntry, payload: &[u8]) -> Option<Result<T>> {
+        #[cfg(feature = "wasmtime")]
+        if let Some(plugin) = self
+            .wasm_plugin
+            .as_ref()
+            .filter(|_| !entry.is_instance_state())
+        {
+            let Some(payload) = plugin.apply(payload) else {
+                if let Some(scheduler) = &self.nack_scheduler {
+                    if let Ok(mut sched) = scheduler.lock() {
+                        sched.on_receive(u64::from(entry.seq));
+                    }
+                }
+                log::trace!(
+                    "[READER] WASM plugin dropped topic='{}' seq={}",
+                    self.topic,
+                    entry.seq
+                );
+                return None;
+            };
+            return Some(T::decode_cdr2(&payload));
+        }
+        #[cfg(not(feature = "wasmtime"))]
+        let _ = entry;
+        Some(T::decode_cdr2(payload))
+    }
+
+    /// Whether `entry` of `instance` is a copy of a delivered sample, or
+    /// DESTINATION_ORDER or the TIME_BASED_FILTER drops it.
+    fn filtered(&self, entry: &rt::IndexEntry, instance: InstanceHandle) -> bool {
+        self.duplicate(entry)
+            || self.out_of_order(entry, instance)
+            || self.time_filtered(entry, instance)
+    }
+
+    /// Whether a PERSISTENT reader already delivered the sample `entry`
+    /// was first published as, received from its writer or a replay.
+    fn duplicate(&self, entry: &rt::IndexEntry) -> bool {
+        let (Some(dedup), Some(original)) =
+            (&self.replay_dedup, entry.rx_timestamps.original_writer)
+        else {
+            return false;
+        };
+        if dedup.accept(original) {
+            return false;
+        }
+        self.mark_dropped(entry, "replay deduplication");
+        true
+    }
+
+    /// Whether the TIME_BASED_FILTER drops `entry` of `instance`.
+    ///
+    /// A dropped sample still counts as received for reliability, so it is
+    /// not NACKed again.
+    fn time_filtered(&self, entry: &rt::IndexEntry, instance: InstanceHandle) -> bool {
+        let Some(filter) = &self.time_filter else {
+            return false;
+        };
+        if filter.accept(instance, entry.timestamp_ns) {
+            return false;
+        }
+        self.mark_dropped(entry, "time-based filter");
+        true
+    }
+
+    /// Whether DESTINATION_ORDER BY_SOURCE_TIMESTAMP drops `entry` of
+    /// `instance`, older than the last sample delivered for it.
+    fn out_of_order(&self, entry: &rt::IndexEntry, instance: InstanceHandle) -> bool {
+        let Some(order) = &self.source_order else {
+            return false;
+        };
+        let source_ns = entry.rx_timestamps.source_ns.unwrap_or(entry.timestamp_ns);
+        if order.accept(instance, source_ns) {
+            return false;
+        }
+        self.mark_dropped(entry, "destination order");
+        true
+    }
+
+    /// Count a sample dropped by a reader-side policy as received, so it is
+    /// not NACKed again.
+    fn mark_dropped(&self, entry: &rt::IndexEntry, policy: &str) {
+        if let Some(scheduler) = &self.nack_scheduler {
+            if let Ok(mut sched) = scheduler.lock() {
+                sched.on_receive(u64::from(entry.seq));
+            }
+        }
+        log::trace!(
+            "[READER] {} dropped topic='{}' seq={}",
+            policy,
+            self.topic,
+            entry.seq
+        );
+    }
+
+    /// Record a dispose/unregister of `handle` carried by `entry`.
+    ///
+    /// An unregister leaves the instance alive while other writers still
+    /// have it registered. NOT_ALIVE instances are purged after the
+    /// READER_DATA_LIFECYCLE autopurge delay of their state.
+    fn apply_instance_state(&self, handle: InstanceHandle, entry: &rt::IndexEntry) {
+        let lifecycle = &self.qos.reader_data_lifecycle;
+        let writer = entry.rx_timestamps.writer_guid;
+        let others_registered = entry.flags & rt::UNREGISTERED_FLAG != 0
+            && self.cache.unregister_writer(handle, writer);
+        let (state, delay_us) = if entry.flags & rt::DISPOSED_FLAG != 0 {
+            (
+                InstanceState::NotAliveDisposed,
+                lifecycle.autopurge_disposed_samples_delay_us,
+            )
+        } else if others_registered {
+            log::debug!(
+                "[READER] instance {:02x?} still registered topic='{}'",
+                &handle.as_bytes()[..4],
+                self.topic
+            );
+            return;
+        } else {
+            (
+                InstanceState::NotAliveNoWriters,
+                lifecycle.autopurge_nowriter_samples_delay_us,
+            )
+        };
+        // i64::MAX is INFINITE: never purge
+        let purge_at = (delay_us != i64::MAX).then(|| {
+            let delay_ns = u64::try_from(delay_us).unwrap_or(0).saturating_mul(1_000);
+            current_time_ns().saturating_add(delay_ns)
+        });
+        log::debug!(
+            "[READER] instance {:02x?} -> {:?} topic='{}' purge_at={:?}",
+            &handle.as_bytes()[..4],
+            state,
+            self.topic,
+            purge_at
+        );
+        self.cache.set_instance_state(handle, state, purge_at);
+    }
+
+    /// Samples and payload bytes received, and samples dropped because the
+    /// history was full.
     #[must_use]
     pub fn stats(&self) -> ReaderStats {
-        ReaderStats::default()
+        ReaderStats {
+            messages_received: self.endpoint_metrics.samples(),
+            bytes_received: self.endpoint_metrics.bytes(),
+            drops: self.endpoint_metrics.history_drops(),
+        }
     }
 
     fn enforce_history(&self) {
@@ -470,9 +952,13 @@ impl<T: DDS> DataReader<T> {
 
         let slab_pool = rt::get_slab_pool();
 
-        while self.ring.len() > max_samples {
-            if let Some(entry) = self.ring.pop() {
+        if let Some(member) = &self.coherent {
+            member.pump();
+        }
+        while self.pending_len() > max_samples {
+            if let Some(entry) = self.pop_entry() {
                 slab_pool.release(entry.handle);
+                self.endpoint_metrics.record_history_drop();
 
                 if let Some(metrics) = telemetry::get_metrics_opt() {
                     metrics.increment_dropped(1);
@@ -483,6 +969,105 @@ impl<T: DDS> DataReader<T> {
         }
     }
 
+    /// Take the next sample as serialized bytes, left in its receive slab.
+    ///
+    /// Skips decoding and the sample cache (used by `RawDataReader`); the
+    /// slab is released when the last `Bytes` clone is dropped.
+    pub(crate) fn take_serialized(&self) -> Option<(Bytes, RxTimestamps)> {
+        let read_start_ns = current_time_ns();
+        self.enforce_history();
+
+        let slab_pool = rt::get_slab_pool();
+        let entry = loop {
+            match self.pop_entry() {
+                Some(entry) if entry.is_instance_state() => slab_pool.release(entry.handle),
+                Some(entry) if self.filtered(&entry, InstanceHandle::nil()) => {
+                    slab_pool.release(entry.handle);
+                }
+                Some(entry) => break entry,
+                None => return None,
+            }
+        };
+
+        if let Some(scheduler) = &self.nack_scheduler {
+            if let Ok(mut sched) = scheduler.lock() {
+                sched.on_receive(u64::from(entry.seq));
+            }
+        }
+        if let Some(metrics) = telemetry::get_metrics_opt() {
+            metrics.increment_received(1);
+            metrics.add_latency_sample(entry.timestamp_ns, read_start_ns);
+        }
+
+        self.maybe_send_nack();
+
+        if self.pending_len() == 0 && self.cache.is_empty() {
+            self.status_condition.clear_active_statuses();
+        }
+
+        Some((
+            slab_pool.into_bytes(entry.handle, entry.len as usize),
+            entry.rx_timestamps,
+        ))
+    }
+
+    /// Next entry to decode, through the ordered merge when enabled.
+    fn pop_entry(&self) -> Option<rt::IndexEntry> {
+        let Some(merge) = &self.ordered_merge else {
+            return self.pop_arrived();
+        };
+        let now_ns = current_time_ns();
+        while let Some(entry) = self.pop_arrived() {
+            if let Some(late) = merge.push(entry, now_ns) {
+                self.drop_late(late);
+            }
+        }
+        merge.pop_ready(now_ns)
+    }
+
+    /// Next arrived entry: released coherent sets when the reader belongs
+    /// to a coherent Subscriber, the ring otherwise.
+    fn pop_arrived(&self) -> Option<rt::IndexEntry> {
+        match &self.coherent {
+            Some(member) => member.pop_ready(),
+            None => self.ring.pop(),
+        }
+    }
+
+    /// Drop a sample older than one the ordered merge already released.
+    ///
+    /// It still counts as received for reliability, so it is not NACKed
+    /// again.
+    fn drop_late(&self, entry: rt::IndexEntry) {
+        rt::get_slab_pool().release(entry.handle);
+        if let Some(scheduler) = &self.nack_scheduler {
+            if let Ok(mut sched) = scheduler.lock() {
+                sched.on_receive(u64::from(entry.seq));
+            }
+        }
+        if let Some(metrics) = telemetry::get_metrics_opt() {
+            metrics.increment_dropped(1);
+        }
+        log::debug!(
+            "[READER] ordered merge dropped late sample topic='{}' seq={}",
+            self.topic,
+            entry.seq
+        );
+    }
+
+    fn pending_len(&self) -> usize {
+        let arrived = match &self.coherent {
+            Some(member) => member.ready_len(),
+            None => self.ring.len(),
+        };
+        arrived + self.held_len()
+    }
+
+    /// Samples held back by the ordered merge.
+    fn held_len(&self) -> usize {
+        self.ordered_merge.as_ref().map_or(0, OrderedMerge::len)
+    }
+
     fn maybe_send_nack(&self) {
         let (transport, scheduler) = match (&self.transport, &self.nack_scheduler) {
             (Some(transport), Some(scheduler)) => (transport, scheduler),
@@ -510,6 +1095,11 @@ impl<T: DDS> DataReader<T> {
         }
 
         sched.on_nack_sent();
+        let lost = sched.take_lost();
+        drop(sched);
+        if let Some(ref sample_lost) = self.sample_lost {
+            sample_lost.record(lost);
+        }
     }
 
     #[cfg(test)]
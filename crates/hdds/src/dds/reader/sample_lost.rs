@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! SAMPLE_LOST status of a reader (DDS v1.4 Sec.2.2.4.1).
+//!
+//! A Reliable reader counts as lost the sequences its NACK scheduler gives
+//! up on: still missing after `MAX_RETRIES` ACKNACKs. BestEffort readers do
+//! not request repairs and report no loss.
+
+use crate::dds::listener::SampleLostStatus;
+use crate::dds::{StatusCondition, StatusMask};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Listener callback for lost samples.
+pub(super) type SampleLostCallback = Box<dyn Fn(SampleLostStatus) + Send + Sync>;
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Lost sample counters of one reader.
+pub(super) struct SampleLost {
+    status: Mutex<SampleLostStatus>,
+    on_lost: Option<SampleLostCallback>,
+    status_condition: Arc<StatusCondition>,
+}
+
+impl SampleLost {
+    pub(super) fn new(
+        on_lost: Option<SampleLostCallback>,
+        status_condition: Arc<StatusCondition>,
+    ) -> Self {
+        Self {
+            status: Mutex::new(SampleLostStatus::default()),
+            on_lost,
+            status_condition,
+        }
+    }
+
+    /// Current status; resets the change counter.
+    pub(super) fn status(&self) -> SampleLostStatus {
+        let mut status = lock(&self.status);
+        let snapshot = status.clone();
+        status.total_count_change = 0;
+        self.status_condition
+            .clear_active_status(StatusMask::SAMPLE_LOST);
+        snapshot
+    }
+
+    /// Record `count` samples that will never be received.
+    pub(super) fn record(&self, count: u64) {
+        if count == 0 {
+            return;
+        }
+        let count = u32::try_from(count).unwrap_or(u32::MAX);
+        let changed = {
+            let mut status = lock(&self.status);
+            status.total_count = status.total_count.saturating_add(count);
+            status.total_count_change = status
+                .total_count_change
+                .saturating_add(i32::try_from(count).unwrap_or(i32::MAX));
+
+            // The listener consumes the changes, like a status read
+            self.on_lost.is_some().then(|| {
+                let snapshot = status.clone();
+                status.total_count_change = 0;
+                snapshot
+            })
+        };
+
+        self.status_condition.set_active_statuses(
+            self.status_condition.get_active_statuses() | StatusMask::SAMPLE_LOST,
+        );
+        if let (Some(callback), Some(status)) = (&self.on_lost, changed) {
+            callback(status);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_lost_counts_and_resets() {
+        let status_condition = Arc::new(StatusCondition::new());
+        let lost = SampleLost::new(None, Arc::clone(&status_condition));
+        lost.record(0);
+        assert!(!status_condition
+            .get_active_statuses()
+            .contains(StatusMask::SAMPLE_LOST));
+
+        lost.record(2);
+        lost.record(3);
+        assert!(status_condition
+            .get_active_statuses()
+            .contains(StatusMask::SAMPLE_LOST));
+        let status = lost.status();
+        assert_eq!((status.total_count, status.total_count_change), (5, 5));
+        let status = lost.status();
+        assert_eq!((status.total_count, status.total_count_change), (5, 0));
+    }
+}
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! DESTINATION_ORDER BY_SOURCE_TIMESTAMP enforcement (DDS v1.4 Sec.2.2.3.17).
+//!
+//! Samples of an instance are delivered in the order of their source
+//! timestamps: a sample older than the last one delivered for its instance
+//! is dropped, so that redundant writers of the same instance converge on
+//! the newest value whatever order their samples arrive in. Samples without
+//! a source timestamp (writers not sending INFO_TS) are ordered by their
+//! reception time. Like TIME_BASED_FILTER, dropped samples never reach the
+//! sample cache.
+
+use super::cache::InstanceHandle;
+use crate::dds::qos::DestinationOrder;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Per-instance source timestamp of the newest delivered sample.
+pub(super) struct SourceOrder {
+    /// Source timestamp of the last delivered sample, per instance.
+    last_accepted: Mutex<HashMap<InstanceHandle, u64>>,
+}
+
+impl SourceOrder {
+    /// Ordering for `policy`, or `None` for BY_RECEPTION_TIMESTAMP.
+    pub(super) fn new(policy: DestinationOrder) -> Option<Self> {
+        policy.uses_source_timestamp().then(|| Self {
+            last_accepted: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Whether the sample of `instance` stamped `source_ns` is delivered.
+    /// Samples as recent as the last delivered one are.
+    pub(super) fn accept(&self, instance: InstanceHandle, source_ns: u64) -> bool {
+        let mut last_accepted = self.last_accepted.lock().unwrap_or_else(|e| e.into_inner());
+        match last_accepted.get(&instance) {
+            Some(&last) if source_ns < last => false,
+            _ => {
+                last_accepted.insert(instance, source_ns);
+                true
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reception_order_has_no_ordering() {
+        assert!(SourceOrder::new(DestinationOrder::by_reception_timestamp()).is_none());
+    }
+
+    #[test]
+    fn test_older_samples_dropped_per_instance() {
+        let order = SourceOrder::new(DestinationOrder::by_source_timestamp()).expect("order");
+        let a = InstanceHandle::new([1; 16]);
+        let b = InstanceHandle::new([2; 16]);
+
+        // Two redundant publishers, the second one's samples arriving late
+        let accepted: Vec<u64> = [10, 10, 20, 15, 30, 20, 30]
+            .into_iter()
+            .filter(|&ts| order.accept(a, ts))
+            .collect();
+        assert_eq!(accepted, [10, 10, 20, 30, 30]);
+
+        // Instance b has its own order
+        assert!(order.accept(b, 5));
+        assert!(!order.accept(b, 4));
+    }
+}
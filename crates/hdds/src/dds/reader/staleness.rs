@@ -0,0 +1,361 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Sample arrival metrics and staleness watchdogs of a reader.
+//!
+//! Every sample reaching the reader's ring is recorded: the arrival count,
+//! the time of the last arrival and the arrival rate over the last second.
+//!
+//! A staleness watchdog (`ReaderBuilder::with_staleness_watchdog`) reports
+//! when no sample arrived within its window, and again when samples resume.
+//! Before the first sample the window runs from the reader's creation, so a
+//! topic that never publishes goes stale too. Unlike DEADLINE, the window is
+//! per reader rather than per instance and is not part of QoS matching.
+
+use crate::dds::listener::SampleStalenessStatus;
+use crate::dds::{StatusCondition, StatusMask};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Period over which the arrival rate is measured.
+const RATE_WINDOW: Duration = Duration::from_secs(1);
+
+/// Listener callback for staleness changes.
+pub(super) type StalenessCallback = Box<dyn Fn(SampleStalenessStatus) + Send + Sync>;
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Arrival metrics of a reader.
+///
+/// See `DataReader::rate_metrics`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SampleRateMetrics {
+    /// Samples received since the reader was created.
+    pub samples_received: u64,
+    /// Samples per second over the last second.
+    pub samples_per_sec: f64,
+    /// Time since the last sample arrived (`None` before the first one).
+    pub last_sample_age: Option<Duration>,
+}
+
+/// One staleness window.
+struct Watchdog {
+    window: Duration,
+    stale: bool,
+    total_count: u32,
+    total_count_change: i32,
+}
+
+impl Watchdog {
+    fn status(&self, last_sample_age: Option<Duration>) -> SampleStalenessStatus {
+        SampleStalenessStatus {
+            window: self.window,
+            stale: self.stale,
+            total_count: self.total_count,
+            total_count_change: self.total_count_change,
+            last_sample_age,
+        }
+    }
+}
+
+struct State {
+    created: Instant,
+    last: Option<Instant>,
+    total: u64,
+    window_start: Instant,
+    window_count: u64,
+    /// Rate of the last completed window.
+    rate: f64,
+    watchdogs: Vec<Watchdog>,
+}
+
+impl State {
+    fn rate_at(&self, now: Instant) -> f64 {
+        let elapsed = now.saturating_duration_since(self.window_start);
+        if elapsed >= RATE_WINDOW * 2 {
+            // Not a single sample over the last full window
+            0.0
+        } else if elapsed >= RATE_WINDOW {
+            self.window_count as f64 / elapsed.as_secs_f64()
+        } else {
+            self.rate
+        }
+    }
+
+    fn age_at(&self, now: Instant) -> Option<Duration> {
+        self.last.map(|last| now.saturating_duration_since(last))
+    }
+}
+
+/// Arrival tracking and staleness watchdogs of one reader.
+pub(super) struct SampleArrivals {
+    state: Mutex<State>,
+    on_change: Option<StalenessCallback>,
+    status_condition: Arc<StatusCondition>,
+}
+
+impl SampleArrivals {
+    pub(super) fn new(
+        windows: &[Duration],
+        on_change: Option<StalenessCallback>,
+        status_condition: Arc<StatusCondition>,
+    ) -> Self {
+        let now = Instant::now();
+        Self {
+            state: Mutex::new(State {
+                created: now,
+                last: None,
+                total: 0,
+                window_start: now,
+                window_count: 0,
+                rate: 0.0,
+                watchdogs: windows
+                    .iter()
+                    .map(|&window| Watchdog {
+                        window,
+                        stale: false,
+                        total_count: 0,
+                        total_count_change: 0,
+                    })
+                    .collect(),
+            }),
+            on_change,
+            status_condition,
+        }
+    }
+
+    /// Whether any staleness window is configured.
+    pub(super) fn has_watchdogs(&self) -> bool {
+        !lock(&self.state).watchdogs.is_empty()
+    }
+
+    /// Record a sample arrival; reports the watchdogs it makes fresh.
+    pub(super) fn record(&self) {
+        let now = Instant::now();
+        let fresh = {
+            let mut state = lock(&self.state);
+            state.last = Some(now);
+            state.total += 1;
+            let elapsed = now.saturating_duration_since(state.window_start);
+            if elapsed >= RATE_WINDOW {
+                state.rate = state.rate_at(now);
+                state.window_start = now;
+                state.window_count = 0;
+            }
+            state.window_count += 1;
+
+            let mut fresh = Vec::new();
+            for watchdog in state.watchdogs.iter_mut().filter(|w| w.stale) {
+                watchdog.stale = false;
+                fresh.push(watchdog.status(Some(Duration::ZERO)));
+            }
+            fresh
+        };
+        self.report(fresh);
+    }
+
+    /// Mark the watchdogs whose window elapsed without a sample as stale.
+    ///
+    /// Returns the time until the next window may elapse.
+    pub(super) fn check(&self) -> Duration {
+        let now = Instant::now();
+        let (stale, next) = {
+            let mut state = lock(&self.state);
+            let since = state.last.unwrap_or(state.created);
+            let quiet = now.saturating_duration_since(since);
+            let age = state.age_at(now);
+
+            let mut stale = Vec::new();
+            let mut next = RATE_WINDOW;
+            for watchdog in state.watchdogs.iter_mut().filter(|w| !w.stale) {
+                if quiet >= watchdog.window {
+                    watchdog.stale = true;
+                    watchdog.total_count = watchdog.total_count.saturating_add(1);
+                    watchdog.total_count_change = watchdog.total_count_change.saturating_add(1);
+                    stale.push(watchdog.status(age));
+                } else {
+                    next = next.min(watchdog.window - quiet);
+                }
+            }
+            (stale, next)
+        };
+        self.report(stale);
+        next
+    }
+
+    /// Set the status bit and call the listener for changed watchdogs.
+    fn report(&self, changed: Vec<SampleStalenessStatus>) {
+        if changed.is_empty() {
+            return;
+        }
+        self.status_condition.set_active_statuses(
+            self.status_condition.get_active_statuses() | StatusMask::SAMPLE_STALENESS_CHANGED,
+        );
+        if let Some(callback) = &self.on_change {
+            for status in changed {
+                callback(status);
+            }
+        }
+    }
+
+    pub(super) fn metrics(&self) -> SampleRateMetrics {
+        let now = Instant::now();
+        let state = lock(&self.state);
+        SampleRateMetrics {
+            samples_received: state.total,
+            samples_per_sec: state.rate_at(now),
+            last_sample_age: state.age_at(now),
+        }
+    }
+
+    pub(super) fn last_sample_age(&self) -> Option<Duration> {
+        lock(&self.state).age_at(Instant::now())
+    }
+
+    /// Current watchdog statuses; resets the change counters.
+    pub(super) fn staleness_status(&self) -> Vec<SampleStalenessStatus> {
+        let mut state = lock(&self.state);
+        let age = state.age_at(Instant::now());
+        let statuses = state
+            .watchdogs
+            .iter_mut()
+            .map(|watchdog| {
+                let status = watchdog.status(age);
+                watchdog.total_count_change = 0;
+                status
+            })
+            .collect();
+        self.status_condition
+            .clear_active_status(StatusMask::SAMPLE_STALENESS_CHANGED);
+        statuses
+    }
+}
+
+/// Handle to a reader's staleness thread; stops it when dropped.
+pub(super) struct StalenessWatchHandle {
+    stop: Arc<(Mutex<bool>, Condvar)>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for StalenessWatchHandle {
+    fn drop(&mut self) {
+        let (stopped, wake) = &*self.stop;
+        *lock(stopped) = true;
+        wake.notify_all();
+        if let Some(handle) = self.thread.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Spawn a thread checking the watchdogs of `arrivals` as their windows
+/// elapse.
+pub(super) fn spawn_staleness_watch(arrivals: Arc<SampleArrivals>) -> StalenessWatchHandle {
+    let stop = Arc::new((Mutex::new(false), Condvar::new()));
+    let thread_stop = Arc::clone(&stop);
+
+    #[allow(clippy::expect_used)] // thread spawn failure is unrecoverable
+    let thread = thread::Builder::new()
+        .name("hdds-staleness-watch".into())
+        .spawn(move || {
+            let (stopped, wake) = &*thread_stop;
+            loop {
+                let period = arrivals.check();
+                let (guard, _) = wake
+                    .wait_timeout_while(lock(stopped), period, |stopped| !*stopped)
+                    .unwrap_or_else(|e| e.into_inner());
+                if *guard {
+                    break;
+                }
+            }
+        })
+        .expect("failed to spawn staleness watch thread");
+
+    StalenessWatchHandle {
+        stop,
+        thread: Some(thread),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arrivals(windows: &[Duration]) -> (SampleArrivals, Arc<Mutex<Vec<SampleStalenessStatus>>>) {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        let arrivals = SampleArrivals::new(
+            windows,
+            Some(Box::new(move |status| lock(&sink).push(status))),
+            Arc::new(StatusCondition::new()),
+        );
+        (arrivals, seen)
+    }
+
+    #[test]
+    fn test_watchdog_goes_stale_and_recovers() {
+        let (arrivals, seen) = arrivals(&[Duration::from_millis(20), Duration::from_secs(60)]);
+        arrivals.record();
+        assert!(arrivals.check() <= Duration::from_millis(20));
+
+        thread::sleep(Duration::from_millis(30));
+        arrivals.check();
+        {
+            let seen = lock(&seen);
+            assert_eq!(seen.len(), 1);
+            assert!(seen[0].stale);
+            assert_eq!(seen[0].window, Duration::from_millis(20));
+            assert_eq!(seen[0].total_count, 1);
+        }
+        // Already stale: not reported twice
+        arrivals.check();
+        assert_eq!(lock(&seen).len(), 1);
+
+        arrivals.record();
+        {
+            let seen = lock(&seen);
+            assert_eq!(seen.len(), 2);
+            assert!(!seen[1].stale);
+        }
+
+        let statuses = arrivals.staleness_status();
+        assert_eq!(statuses.len(), 2);
+        assert_eq!(statuses[0].total_count_change, 1);
+        assert!(!statuses[1].stale);
+        assert_eq!(arrivals.staleness_status()[0].total_count_change, 0);
+    }
+
+    #[test]
+    fn test_never_published_topic_goes_stale() {
+        let (arrivals, seen) = arrivals(&[Duration::from_millis(10)]);
+        assert_eq!(arrivals.last_sample_age(), None);
+        thread::sleep(Duration::from_millis(20));
+        arrivals.check();
+        let seen = lock(&seen);
+        assert_eq!(seen.len(), 1);
+        assert_eq!(seen[0].last_sample_age, None);
+    }
+
+    #[test]
+    fn test_rate_metrics() {
+        let (arrivals, _) = arrivals(&[]);
+        for _ in 0..5 {
+            arrivals.record();
+        }
+        let metrics = arrivals.metrics();
+        assert_eq!(metrics.samples_received, 5);
+        assert!(metrics.last_sample_age.is_some());
+
+        let mut state = lock(&arrivals.state);
+        let start = state.window_start;
+        assert_eq!(state.rate_at(start + Duration::from_millis(500)), 0.0);
+        let rate = state.rate_at(start + RATE_WINDOW);
+        assert!((rate - 5.0).abs() < 1e-9);
+        assert_eq!(state.rate_at(start + RATE_WINDOW * 2), 0.0);
+        state.rate = 3.0;
+        assert_eq!(state.rate_at(start + Duration::from_millis(10)), 3.0);
+    }
+}
@@ -7,12 +7,20 @@
 //! Bridges the engine's subscriber trait to the typed DataReader,
 //! handling sample deserialization and duplicate detection.
 
+use super::staleness::SampleArrivals;
 use crate::core::rt;
+use crate::dds::coherent::CoherentMember;
 use crate::dds::filter::FilterEvaluator;
 use crate::dds::listener::DataReaderListener;
+use crate::dds::qos::Partition;
+use crate::dds::type_versions::{DecodeFrom, TypeVersions};
 use crate::dds::{GuardCondition, StatusCondition, StatusMask, DDS};
+use crate::protocol::coherent::CoherentSetTag;
+use crate::protocol::type_version::TypeVersionTag;
 use crate::telemetry;
 use crate::telemetry::metrics::current_time_ns;
+use crate::telemetry::EndpointMetrics;
+use std::borrow::Cow;
 use std::convert::TryFrom;
 use std::sync::{Arc, Mutex};
 
@@ -144,6 +152,19 @@ pub(super) struct ReaderSubscriber<T: DDS> {
     pub(super) content_filter: Option<FilterEvaluator>,
     /// Optional listener for data callbacks
     pub(super) listener: Option<Arc<dyn DataReaderListener<T>>>,
+    /// Side-by-side type versions (see `ReaderBuilder::type_version`)
+    type_versions: Option<Arc<TypeVersions<DecodeFrom<T>>>>,
+    /// Coherent set gate of the reader's Subscriber (see `ReaderBuilder::subscriber`)
+    coherent: Option<CoherentMember>,
+    /// PARTITION QoS, checked by the router against the sending writer
+    partition: Option<Partition>,
+    /// Arrival metrics and staleness watchdogs of the reader
+    arrivals: Option<Arc<SampleArrivals>>,
+    /// Traffic counters of the reader
+    metrics: Option<Arc<EndpointMetrics>>,
+    /// Payload protection of the topic when governance protects it (DDS Security v1.1)
+    #[cfg(feature = "security")]
+    protection: Option<Box<dyn crate::security::PayloadProtection>>,
     pub(super) _phantom: core::marker::PhantomData<T>,
 }
 
@@ -155,6 +176,7 @@ impl<T: DDS> ReaderSubscriber<T> {
         participant_guard: Option<Arc<GuardCondition>>,
         content_filter: Option<FilterEvaluator>,
         listener: Option<Arc<dyn DataReaderListener<T>>>,
+        type_versions: Option<Arc<TypeVersions<DecodeFrom<T>>>>,
     ) -> Self {
         if participant_guard.is_some() {
             log::debug!(
@@ -175,25 +197,231 @@ impl<T: DDS> ReaderSubscriber<T> {
             seq_window: Mutex::new(SeqWindow::new()),
             content_filter,
             listener,
+            type_versions,
+            coherent: None,
+            partition: None,
+            arrivals: None,
+            metrics: None,
+            #[cfg(feature = "security")]
+            protection: None,
             _phantom: core::marker::PhantomData,
         }
     }
+
+    /// Keep coherent set tags and report dropped tagged samples to `member`.
+    pub fn with_coherent(mut self, member: CoherentMember) -> Self {
+        self.coherent = Some(member);
+        self
+    }
+
+    /// Only receive from remote writers whose partitions match `partition`.
+    pub fn with_partition(mut self, partition: Partition) -> Self {
+        self.partition = Some(partition);
+        self
+    }
+
+    /// Record the samples reaching the ring in `arrivals`.
+    pub fn with_arrivals(mut self, arrivals: Arc<SampleArrivals>) -> Self {
+        self.arrivals = Some(arrivals);
+        self
+    }
+
+    /// Count the samples reaching the ring, and those dropped because it
+    /// was full, in `metrics`.
+    pub fn with_metrics(mut self, metrics: Arc<EndpointMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Only accept payloads signed or encrypted with `protection`.
+    #[cfg(feature = "security")]
+    pub fn with_protection(
+        mut self,
+        protection: Option<Box<dyn crate::security::PayloadProtection>>,
+    ) -> Self {
+        self.protection = protection;
+        self
+    }
+
+    /// Verify and decrypt `data` when governance protects the topic.
+    /// `None` drops a payload that fails authentication.
+    fn unprotect<'a>(&self, data: &'a [u8], remote_seq: u64) -> Option<Cow<'a, [u8]>> {
+        #[cfg(feature = "security")]
+        if let Some(ref protection) = self.protection {
+            return match protection.unprotect(data) {
+                Ok(plain) => Some(Cow::Owned(plain)),
+                Err(_e) => {
+                    log::debug!(
+                        "[READER-SUB] dropped unverified sample topic='{}' seq={}: {}",
+                        self.topic,
+                        remote_seq,
+                        _e
+                    );
+                    None
+                }
+            };
+        }
+        #[cfg(not(feature = "security"))]
+        let _ = remote_seq;
+        Some(Cow::Borrowed(data))
+    }
 }
 
 impl<T: DDS> crate::engine::Subscriber for ReaderSubscriber<T> {
-    fn on_data(&self, _topic: &str, remote_seq: u64, data: &[u8]) {
-        let msg = match T::decode_cdr2(data) {
-            Ok(m) => m,
+    fn on_data(&self, topic: &str, remote_seq: u64, data: &[u8]) {
+        self.on_data_traced(topic, remote_seq, data, None);
+    }
+
+    fn on_data_traced(&self, _topic: &str, remote_seq: u64, data: &[u8], trace_id: Option<u64>) {
+        if let Some(data) = self.unprotect(data, remote_seq) {
+            self.decode_and_deliver(&data, remote_seq, trace_id, None);
+        }
+    }
+
+    fn on_data_versioned(
+        &self,
+        _topic: &str,
+        remote_seq: u64,
+        data: &[u8],
+        trace_id: Option<u64>,
+        type_version: Option<&TypeVersionTag>,
+    ) {
+        if let Some(data) = self.unprotect(data, remote_seq) {
+            self.receive(remote_seq, &data, trace_id, type_version, None);
+        }
+    }
+
+    fn on_data_coherent(
+        &self,
+        _topic: &str,
+        remote_seq: u64,
+        data: &[u8],
+        trace_id: Option<u64>,
+        type_version: Option<&TypeVersionTag>,
+        coherent_set: CoherentSetTag,
+    ) {
+        // Readers outside a coherent subscriber see sets as plain samples
+        let coherent = self.coherent.as_ref().map(|_| coherent_set);
+        match self.unprotect(data, remote_seq) {
+            Some(data) => self.receive(remote_seq, &data, trace_id, type_version, coherent),
+            None => self.coherent_dropped(coherent),
+        }
+    }
+
+    fn on_instance_state(
+        &self,
+        _topic: &str,
+        remote_seq: u64,
+        data: &[u8],
+        status_info: u8,
+        key_hash: Option<[u8; 16]>,
+    ) {
+        let flags = rt::instance_state_flags(status_info);
+        if flags == 0 {
+            return;
+        }
+        let Some(data) = self.unprotect(data, remote_seq) else {
+            return;
+        };
+        log::debug!(
+            "[READER-SUB] instance state topic='{}' seq={} status_info={:#04x}",
+            self.topic,
+            remote_seq,
+            status_info
+        );
+        self.enqueue(&data, remote_seq, None, flags, key_hash, None);
+    }
+
+    fn partition(&self) -> Option<&Partition> {
+        self.partition.as_ref()
+    }
+
+    fn topic_name(&self) -> &str {
+        &self.topic
+    }
+}
+
+impl<T: DDS> ReaderSubscriber<T> {
+    /// Select the type version copy to keep, then decode and deliver it.
+    fn receive(
+        &self,
+        remote_seq: u64,
+        data: &[u8],
+        trace_id: Option<u64>,
+        type_version: Option<&TypeVersionTag>,
+        coherent: Option<CoherentSetTag>,
+    ) {
+        let (Some(versions), Some(tag)) = (&self.type_versions, type_version) else {
+            // Unversioned reader, or untagged sample from an unversioned writer
+            if type_version.is_none_or(|tag| tag.accepted_by(None)) {
+                self.decode_and_deliver(data, remote_seq, trace_id, coherent);
+            }
+            return;
+        };
+
+        if !tag.accepted_by(Some(versions.all())) {
+            log::trace!(
+                "[READER-SUB] skip type version {} for topic='{}' seq={}",
+                tag.sample,
+                self.topic,
+                remote_seq
+            );
+            return;
+        }
+
+        let decoded = match versions.codec(tag.sample) {
+            Some(decode) => decode(data),
+            None => T::decode_cdr2(data),
+        };
+        match decoded {
+            Ok(msg) => self.deliver(msg, remote_seq, trace_id, coherent),
+            Err(_e) => {
+                log::debug!(
+                    "[READER-SUB] decode of type version {} failed for topic '{}': {:?}",
+                    tag.sample,
+                    self.topic,
+                    _e
+                );
+                self.coherent_dropped(coherent);
+            }
+        }
+    }
+
+    fn decode_and_deliver(
+        &self,
+        data: &[u8],
+        remote_seq: u64,
+        trace_id: Option<u64>,
+        coherent: Option<CoherentSetTag>,
+    ) {
+        match T::decode_cdr2(data) {
+            Ok(msg) => self.deliver(msg, remote_seq, trace_id, coherent),
             Err(_e) => {
                 log::debug!(
                     "[READER-SUB] CDR2 decode failed for topic '{}': {:?}",
                     self.topic,
                     _e
                 );
-                return;
+                self.coherent_dropped(coherent);
             }
-        };
+        }
+    }
+
+    /// Tell the coherent gate a tagged sample will never reach the ring.
+    fn coherent_dropped(&self, coherent: Option<CoherentSetTag>) {
+        if let (Some(member), Some(tag)) = (&self.coherent, coherent) {
+            member.dropped(tag);
+        }
+    }
 
+    /// Filter, notify and enqueue a decoded sample.
+    fn deliver(
+        &self,
+        msg: T,
+        remote_seq: u64,
+        trace_id: Option<u64>,
+        coherent: Option<CoherentSetTag>,
+    ) {
         // Apply content filter if present
         if let Some(ref filter) = self.content_filter {
             // Extract fields from the message for filter evaluation
@@ -212,6 +440,7 @@ impl<T: DDS> crate::engine::Subscriber for ReaderSubscriber<T> {
                         "[READER-SUB] Sample rejected by content filter for topic='{}'",
                         self.topic
                     );
+                    self.coherent_dropped(coherent);
                     return;
                 }
                 Err(e) => {
@@ -221,6 +450,7 @@ impl<T: DDS> crate::engine::Subscriber for ReaderSubscriber<T> {
                         e
                     );
                     // On error, reject the sample (fail-safe)
+                    self.coherent_dropped(coherent);
                     return;
                 }
             }
@@ -237,20 +467,48 @@ impl<T: DDS> crate::engine::Subscriber for ReaderSubscriber<T> {
             Ok(len) => len,
             Err(_e) => {
                 log::debug!("[READER-SUB] re-encode failed: {:?}", _e);
+                self.coherent_dropped(coherent);
                 return;
             }
         };
 
+        if !self.enqueue(
+            &tmp_buf[..serialized_len],
+            remote_seq,
+            trace_id,
+            0,
+            None,
+            coherent,
+        ) {
+            self.coherent_dropped(coherent);
+        }
+    }
+
+    /// Copy a serialized sample into the slab pool and push it to the ring.
+    /// Returns false if the sample was dropped.
+    ///
+    /// `flags` carries `DISPOSED_FLAG`/`UNREGISTERED_FLAG` and `key_hash`
+    /// the instance of lifecycle entries, 0 and `None` for data.
+    fn enqueue(
+        &self,
+        payload: &[u8],
+        remote_seq: u64,
+        trace_id: Option<u64>,
+        flags: u8,
+        key_hash: Option<[u8; 16]>,
+        coherent: Option<CoherentSetTag>,
+    ) -> bool {
+        let serialized_len = payload.len();
         let slab_pool = rt::get_slab_pool();
         let (handle, slab_buf) = match slab_pool.reserve(serialized_len) {
             Some((h, b)) => (h, b),
             None => {
                 log::debug!("[READER-SUB] slab_pool exhausted");
-                return;
+                return false;
             }
         };
 
-        slab_buf[..serialized_len].copy_from_slice(&tmp_buf[..serialized_len]);
+        slab_buf[..serialized_len].copy_from_slice(payload);
         slab_pool.commit(handle, serialized_len);
 
         let seq = {
@@ -272,7 +530,7 @@ impl<T: DDS> crate::engine::Subscriber for ReaderSubscriber<T> {
                     if let Some(m) = telemetry::get_metrics_opt() {
                         m.increment_dropped(1);
                     }
-                    return;
+                    return false;
                 }
             }
         };
@@ -288,7 +546,7 @@ impl<T: DDS> crate::engine::Subscriber for ReaderSubscriber<T> {
                     "[reader] Serialized payload too large ({} bytes); dropping UDP packet",
                     serialized_len
                 );
-                return;
+                return false;
             }
         };
 
@@ -296,8 +554,12 @@ impl<T: DDS> crate::engine::Subscriber for ReaderSubscriber<T> {
             seq,
             handle,
             len,
-            flags: 0x01,
+            flags: 0x01 | flags,
             timestamp_ns: current_time_ns(),
+            trace_id: trace_id.unwrap_or(0),
+            coherent,
+            rx_timestamps: crate::engine::rx_timestamps::current(),
+            key_hash,
         };
 
         if self.ring.push(entry) {
@@ -307,6 +569,22 @@ impl<T: DDS> crate::engine::Subscriber for ReaderSubscriber<T> {
                 seq,
                 len
             );
+            if let Some(trace_id) = trace_id {
+                log::debug!(
+                    "[trace] enqueue topic='{}' seq={} trace_id={:016x}",
+                    self.topic,
+                    seq,
+                    trace_id
+                );
+            }
+            if flags == 0 {
+                if let Some(arrivals) = &self.arrivals {
+                    arrivals.record();
+                }
+                if let Some(metrics) = &self.metrics {
+                    metrics.record_sample(serialized_len);
+                }
+            }
             self.status_condition
                 .set_active_statuses(StatusMask::DATA_AVAILABLE);
             if let Some(guard) = &self.participant_guard {
@@ -316,15 +594,16 @@ impl<T: DDS> crate::engine::Subscriber for ReaderSubscriber<T> {
                 );
                 guard.set_trigger_value(true);
             }
+            true
         } else {
             slab_pool.release(handle);
+            if let Some(metrics) = &self.metrics {
+                metrics.record_history_drop();
+            }
             log::debug!("Reader ring full - dropping UDP packet");
+            false
         }
     }
-
-    fn topic_name(&self) -> &str {
-        &self.topic
-    }
 }
 
 #[cfg(test)]
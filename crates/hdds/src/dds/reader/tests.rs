@@ -3,8 +3,8 @@
 
 use super::ReaderBuilder;
 use crate::core::rt::{self, IndexEntry};
-use crate::dds::QoS;
 use crate::dds::DDS;
+use crate::dds::{Error, QoS};
 
 #[derive(Debug, Clone, Copy, PartialEq, crate::DDS)]
 struct Point {
@@ -50,6 +50,108 @@ fn reader_reads_written_sample() {
     assert_eq!(received.y, 123);
 }
 
+#[test]
+fn take_with_info_returns_writer_trace_id() {
+    let _ = rt::init_slab_pool();
+
+    let writer = crate::dds::writer::WriterBuilder::<Point>::new("trace".to_string())
+        .qos(QoS::best_effort())
+        .build()
+        .expect("writer build should succeed");
+
+    let reader = ReaderBuilder::<Point>::new("trace".to_string())
+        .qos(QoS::best_effort())
+        .build()
+        .expect("reader build should succeed");
+
+    reader.bind_to_writer(writer.merger());
+
+    writer
+        .write_with_trace_id(&Point { x: 1, y: 2 }, 0xdead_beef)
+        .expect("write should succeed");
+    writer
+        .write(&Point { x: 3, y: 4 })
+        .expect("write should succeed");
+
+    let (sample, info) = reader
+        .take_with_info()
+        .expect("take should not error")
+        .expect("should receive message");
+    assert_eq!(sample, Point { x: 1, y: 2 });
+    assert_eq!(info.trace_id, Some(0xdead_beef));
+
+    let (_, info) = reader
+        .take_with_info()
+        .expect("take should not error")
+        .expect("should receive message");
+    assert_eq!(info.trace_id, None);
+
+    // 0 is the untraced marker, so it cannot be sent as an id
+    assert!(matches!(
+        writer.write_with_trace_id(&Point { x: 5, y: 6 }, 0),
+        Err(Error::InvalidConfig { .. })
+    ));
+    assert!(reader
+        .take_with_info()
+        .expect("take should not error")
+        .is_none());
+}
+
+#[test]
+fn take_n_and_read_n_return_sample_info() {
+    use super::SampleState;
+    use crate::core::discovery::GUID;
+    use crate::engine::RxTimestamps;
+
+    let _ = rt::init_slab_pool();
+
+    let reader = ReaderBuilder::<Point>::new("batch_info".to_string())
+        .qos(QoS::best_effort().keep_last(16))
+        .build()
+        .expect("reader build should succeed");
+
+    let writer_guid = GUID::new([7; 12], [0, 0, 1, 0x02]);
+    let slab_pool = rt::get_slab_pool();
+    let ring = reader.ring_for_test();
+    for seq in 1..=5u32 {
+        let mut buf = vec![0u8; 64];
+        let len = Point {
+            x: seq as i32,
+            y: 0,
+        }
+        .encode_cdr2(&mut buf)
+        .expect("encode should succeed");
+        let (handle, slab_buf) = slab_pool.reserve(len).expect("slab reserve");
+        slab_buf[..len].copy_from_slice(&buf[..len]);
+        slab_pool.commit(handle, len);
+        let mut entry = IndexEntry::new(seq, handle, len as u32);
+        entry.rx_timestamps =
+            RxTimestamps::at_source(u64::from(seq)).with_writer(Some(writer_guid));
+        assert!(ring.push(entry));
+    }
+    assert_eq!(reader.len(), 5);
+    assert!(!reader.is_empty());
+
+    let read = reader.read_n(2).expect("read_n should not error");
+    assert_eq!(read.len(), 2);
+    assert_eq!(read[0].0.x, 1);
+    assert_eq!(read[0].1.sample_state, SampleState::NotRead);
+    assert_eq!(read[0].1.writer_guid, Some(writer_guid));
+    assert_eq!(read[1].1.source_timestamp_ns, Some(2));
+    assert_eq!(reader.len(), 5, "read_n leaves samples in the reader");
+
+    let taken = reader.take_n(10).expect("take_n should not error");
+    let seqs: Vec<u64> = taken.iter().map(|(_, info)| info.seq).collect();
+    assert_eq!(seqs, vec![1, 2, 3, 4, 5]);
+    assert_eq!(taken[0].1.sample_state, SampleState::Read);
+    assert_eq!(taken[2].1.sample_state, SampleState::NotRead);
+    assert!(reader.is_empty());
+    assert!(reader
+        .take_n(10)
+        .expect("take_n should not error")
+        .is_empty());
+}
+
 #[test]
 fn keep_last_drops_oldest_samples() {
     let _ = rt::init_slab_pool();
@@ -211,3 +313,453 @@ fn take_deduplicates_replay_scenario() {
     assert_eq!(received[1], Point { x: 2, y: 20 });
     assert_eq!(received[2], Point { x: 3, y: 30 });
 }
+
+#[test]
+fn ordered_merge_interleaves_writers_by_source_timestamp() {
+    use crate::engine::RxTimestamps;
+    use std::time::Duration;
+
+    let _ = rt::init_slab_pool();
+
+    let window = Duration::from_millis(30);
+    let reader = ReaderBuilder::<Point>::new("merge".to_string())
+        .qos(QoS::best_effort().keep_last(16))
+        .with_ordered_merge(window)
+        .build()
+        .expect("reader build should succeed");
+
+    // Two redundant writers, both at seq 1..=3; writer x=2 lags behind
+    let slab_pool = rt::get_slab_pool();
+    let ring = reader.ring_for_test();
+    let push = |x: i32, seq: u32, source_ns: u64| {
+        let mut buf = vec![0u8; 64];
+        let len = Point { x, y: seq as i32 }
+            .encode_cdr2(&mut buf)
+            .expect("encode should succeed");
+        let (handle, slab_buf) = slab_pool.reserve(len).expect("slab reserve");
+        slab_buf[..len].copy_from_slice(&buf[..len]);
+        slab_pool.commit(handle, len);
+        let mut entry = IndexEntry::new(seq, handle, len as u32);
+        entry.rx_timestamps = RxTimestamps::at_source(source_ns);
+        assert!(ring.push(entry));
+    };
+    for seq in 1..=3 {
+        push(1, seq, u64::from(seq) * 10);
+    }
+    for seq in 1..=3 {
+        push(2, seq, u64::from(seq) * 10 + 5);
+    }
+
+    // Held back for the window
+    assert!(reader.take().expect("take should not error").is_none());
+    std::thread::sleep(window);
+
+    let received: Vec<(i32, i32)> = std::iter::from_fn(|| reader.take().expect("take"))
+        .map(|p| (p.x, p.y))
+        .collect();
+    assert_eq!(
+        received,
+        vec![(1, 1), (2, 1), (1, 2), (2, 2), (1, 3), (2, 3)]
+    );
+
+    // Older than the last delivered sample: dropped
+    push(1, 4, 20);
+    std::thread::sleep(window);
+    assert!(reader.take().expect("take should not error").is_none());
+}
+
+#[derive(Debug, Clone, PartialEq, crate::DDS)]
+struct Track {
+    #[key]
+    id: u32,
+    range: i32,
+}
+
+#[test]
+fn keyed_reader_tracks_instance_lifecycle() {
+    use crate::dds::{InstanceHandle, InstanceState};
+
+    let _ = rt::init_slab_pool();
+
+    let writer = crate::dds::writer::WriterBuilder::<Track>::new("tracks".to_string())
+        .qos(QoS::best_effort().keep_last(2))
+        .build()
+        .expect("writer build should succeed");
+
+    let reader = ReaderBuilder::<Track>::new("tracks".to_string())
+        .qos(QoS::best_effort().keep_last(2))
+        .build()
+        .expect("reader build should succeed");
+
+    reader.bind_to_writer(writer.merger());
+
+    let a = Track { id: 1, range: 0 };
+    let b = Track { id: 2, range: 0 };
+    let handle_a = writer.register_instance(&a).expect("register");
+    assert_eq!(writer.lookup_instance(&a), Some(handle_a));
+    assert_eq!(writer.lookup_instance(&b), None);
+
+    // Depth applies per instance: three writes of `a` keep the last two.
+    for range in 1..=3 {
+        writer
+            .write(&Track { id: 1, range })
+            .expect("write should succeed");
+    }
+    writer.write(&b).expect("write should succeed");
+
+    let handle_b = reader
+        .lookup_instance(&b)
+        .expect("lookup")
+        .expect("instance b seen");
+    assert_ne!(handle_a, handle_b);
+    assert_eq!(
+        handle_a,
+        InstanceHandle::new(Track { id: 1, range: 99 }.compute_key())
+    );
+
+    let taken = reader.take_instance_batch(handle_a, 10).expect("take");
+    let ranges: Vec<i32> = taken.iter().map(|t| t.range).collect();
+    assert_eq!(ranges, vec![2, 3]);
+
+    writer.dispose(&b).expect("dispose");
+    assert_eq!(
+        reader.instance_state(handle_b).expect("state"),
+        Some(InstanceState::NotAliveDisposed)
+    );
+    assert_eq!(
+        reader.instance_state(handle_a).expect("state"),
+        Some(InstanceState::Alive)
+    );
+
+    // autodispose_unregistered_instances is on by default.
+    writer.unregister_instance(&a).expect("unregister");
+    assert!(writer.unregister_instance(&a).is_err());
+    assert_eq!(
+        reader.instance_state(handle_a).expect("state"),
+        Some(InstanceState::NotAliveDisposed)
+    );
+}
+
+#[test]
+fn transient_local_late_joiner_sees_disposed_instance() {
+    use crate::dds::InstanceState;
+
+    let _ = rt::init_slab_pool();
+
+    let qos = QoS::reliable().transient_local().keep_last(4);
+    let writer = crate::dds::writer::WriterBuilder::<Track>::new("tracks_late".to_string())
+        .qos(qos.clone())
+        .build()
+        .expect("writer build should succeed");
+    let track = Track { id: 3, range: 1 };
+    writer.write(&track).expect("write should succeed");
+    writer.dispose(&track).expect("dispose");
+
+    let reader = ReaderBuilder::<Track>::new("tracks_late".to_string())
+        .qos(qos)
+        .build()
+        .expect("reader build should succeed");
+    reader.bind_to_writer(writer.merger());
+
+    let handle = reader
+        .lookup_instance(&track)
+        .expect("lookup")
+        .expect("instance replayed");
+    assert_eq!(
+        reader.instance_state(handle).expect("state"),
+        Some(InstanceState::NotAliveDisposed)
+    );
+}
+
+#[test]
+fn keyed_reader_applies_lifecycle_by_serialized_key() {
+    use crate::dds::{InstanceHandle, InstanceState};
+
+    let _ = rt::init_slab_pool();
+
+    let writer = crate::dds::writer::WriterBuilder::<Track>::new("tracks_by_key".to_string())
+        .qos(QoS::best_effort().keep_last(2))
+        .build()
+        .expect("writer build should succeed");
+
+    let reader = ReaderBuilder::<Track>::new("tracks_by_key".to_string())
+        .qos(QoS::best_effort().keep_last(2))
+        .build()
+        .expect("reader build should succeed");
+
+    reader.bind_to_writer(writer.merger());
+
+    writer
+        .write(&Track { id: 7, range: 1 })
+        .expect("write should succeed");
+    let handle = reader
+        .lookup_instance(&Track { id: 7, range: 0 })
+        .expect("lookup")
+        .expect("instance seen");
+
+    // Big-endian CDR of the key field, as sent by raw publishers
+    let key = 7u32.to_be_bytes();
+    assert_eq!(InstanceHandle::from_serialized_key(&key), handle);
+    writer.dispose_serialized_key(&key).expect("dispose");
+    assert_eq!(
+        reader.instance_state(handle).expect("state"),
+        Some(InstanceState::NotAliveDisposed)
+    );
+
+    writer
+        .write(&Track { id: 7, range: 2 })
+        .expect("write should succeed");
+    writer
+        .unregister_serialized_key(&key)
+        .expect("unregister without registration");
+    assert_eq!(
+        reader.instance_state(handle).expect("state"),
+        Some(InstanceState::NotAliveDisposed)
+    );
+}
+
+#[test]
+fn keyed_reader_applies_lifecycle_by_key_hash() {
+    use crate::dds::{InstanceHandle, InstanceState};
+
+    let _ = rt::init_slab_pool();
+
+    let reader = ReaderBuilder::<Track>::new("tracks_by_hash".to_string())
+        .qos(QoS::best_effort().keep_last(2))
+        .build()
+        .expect("reader build should succeed");
+    let handle = InstanceHandle::new(Track { id: 9, range: 0 }.compute_key());
+
+    // A dispose from a remote stack, whose payload this reader cannot
+    // decode: PID_KEY_HASH alone names the instance.
+    let payload = [0xff, 0xff];
+    let slab_pool = rt::get_slab_pool();
+    let push = |seq: u32, key_hash: Option<[u8; 16]>| {
+        let (slab, buf) = slab_pool.reserve(payload.len()).expect("slab reserve");
+        buf[..payload.len()].copy_from_slice(&payload);
+        slab_pool.commit(slab, payload.len());
+        let mut entry = IndexEntry::new(seq, slab, payload.len() as u32);
+        entry.flags |= rt::DISPOSED_FLAG;
+        entry.key_hash = key_hash;
+        assert!(reader.ring_for_test().push(entry));
+    };
+
+    push(1, Some(handle.0));
+    assert!(reader.take().expect("take should not error").is_none());
+    assert_eq!(
+        reader.instance_state(handle).expect("state"),
+        Some(InstanceState::NotAliveDisposed)
+    );
+
+    // Without the key hash the change cannot be matched: dropped
+    push(2, None);
+    assert!(reader.take().expect("take should not error").is_none());
+    assert_eq!(reader.instance_handles().expect("handles"), vec![handle]);
+}
+
+#[test]
+fn instance_stays_alive_until_every_writer_unregisters() {
+    use crate::core::discovery::GUID;
+    use crate::dds::qos::ReaderDataLifecycle;
+    use crate::dds::{InstanceHandle, InstanceState};
+    use crate::engine::RxTimestamps;
+    use std::time::Duration;
+
+    let _ = rt::init_slab_pool();
+
+    let reader = ReaderBuilder::<Track>::new("tracks_shared".to_string())
+        .qos(
+            QoS::best_effort()
+                .keep_last(4)
+                .reader_data_lifecycle(ReaderDataLifecycle {
+                    autopurge_nowriter_samples_delay_us: 200_000,
+                    autopurge_disposed_samples_delay_us: i64::MAX,
+                }),
+        )
+        .build()
+        .expect("reader build should succeed");
+    let track = Track { id: 5, range: 0 };
+    let handle = InstanceHandle::new(track.compute_key());
+
+    let slab_pool = rt::get_slab_pool();
+    let ring = reader.ring_for_test();
+    let push = |writer: u8, seq: u32, flags: u8| {
+        let mut buf = vec![0u8; 64];
+        let len = track.encode_cdr2(&mut buf).expect("encode should succeed");
+        let (slab, slab_buf) = slab_pool.reserve(len).expect("slab reserve");
+        slab_buf[..len].copy_from_slice(&buf[..len]);
+        slab_pool.commit(slab, len);
+        let mut entry = IndexEntry::new(seq, slab, len as u32);
+        entry.flags |= flags;
+        entry.rx_timestamps = RxTimestamps::NONE.with_writer(Some(GUID::from_bytes([writer; 16])));
+        assert!(ring.push(entry));
+    };
+
+    // Two writers share the instance; the first one goes away
+    push(1, 1, 0);
+    push(2, 2, 0);
+    push(1, 3, rt::UNREGISTERED_FLAG);
+    assert_eq!(
+        reader.instance_state(handle).expect("state"),
+        Some(InstanceState::Alive)
+    );
+
+    push(2, 4, rt::UNREGISTERED_FLAG);
+    assert_eq!(
+        reader.instance_state(handle).expect("state"),
+        Some(InstanceState::NotAliveNoWriters)
+    );
+    assert_eq!(
+        reader.read_instance_batch(handle, 10).expect("read").len(),
+        2
+    );
+
+    // Samples are purged once autopurge_nowriter_samples_delay elapsed
+    std::thread::sleep(Duration::from_millis(250));
+    assert!(reader.take().expect("take should not error").is_none());
+}
+
+#[test]
+fn iter_yields_samples_until_timeout() {
+    use std::time::{Duration, Instant};
+
+    let _ = rt::init_slab_pool();
+
+    let writer = crate::dds::writer::WriterBuilder::<Point>::new("iter".to_string())
+        .qos(QoS::best_effort().keep_last(8))
+        .build()
+        .expect("writer build should succeed");
+
+    let reader = ReaderBuilder::<Point>::new("iter".to_string())
+        .qos(QoS::best_effort().keep_last(8))
+        .build()
+        .expect("reader build should succeed");
+
+    reader.bind_to_writer(writer.merger());
+
+    for x in 0..3 {
+        writer
+            .write(&Point { x, y: 0 })
+            .expect("write should succeed");
+    }
+
+    let start = Instant::now();
+    let xs: Vec<i32> = reader
+        .iter(Some(Duration::from_millis(20)))
+        .map(|s| s.expect("take should not error").x)
+        .collect();
+    assert_eq!(xs, vec![0, 1, 2]);
+    assert!(start.elapsed() >= Duration::from_millis(20));
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn stream_wakes_on_new_sample() {
+    use futures_core::Stream;
+    use std::pin::pin;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+
+    struct Flag(AtomicBool);
+    impl Wake for Flag {
+        fn wake(self: Arc<Self>) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    let _ = rt::init_slab_pool();
+
+    let writer = crate::dds::writer::WriterBuilder::<Point>::new("stream".to_string())
+        .qos(QoS::best_effort())
+        .build()
+        .expect("writer build should succeed");
+
+    let reader = ReaderBuilder::<Point>::new("stream".to_string())
+        .qos(QoS::best_effort())
+        .build()
+        .expect("reader build should succeed");
+
+    reader.bind_to_writer(writer.merger());
+
+    let flag = Arc::new(Flag(AtomicBool::new(false)));
+    let waker = Waker::from(flag.clone());
+    let mut cx = Context::from_waker(&waker);
+    let mut stream = pin!(reader.stream());
+
+    assert!(stream.as_mut().poll_next(&mut cx).is_pending());
+
+    writer
+        .write(&Point { x: 7, y: 8 })
+        .expect("write should succeed");
+    assert!(flag.0.load(Ordering::SeqCst), "write should wake the task");
+
+    match stream.as_mut().poll_next(&mut cx) {
+        Poll::Ready(Some(Ok(p))) => assert_eq!(p, Point { x: 7, y: 8 }),
+        other => panic!(
+            "expected a sample, got {:?}",
+            other.map(|o| o.map(|r| r.is_ok()))
+        ),
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn loaned_samples_roundtrip_over_shm() {
+    use crate::dds::{Error, Participant};
+    use std::time::{Duration, Instant};
+
+    let participant = Participant::builder("reader_loan_test")
+        .build()
+        .expect("participant");
+    let writer = participant
+        .topic::<Point>("loans")
+        .expect("topic")
+        .writer()
+        .qos(QoS::best_effort())
+        .build()
+        .expect("writer");
+    let reader = participant
+        .topic::<Point>("loans")
+        .expect("topic")
+        .reader()
+        .qos(QoS::best_effort())
+        .build()
+        .expect("reader");
+
+    // Dropped loans are discarded.
+    drop(writer.loan_sample(64).expect("loan"));
+
+    let mut loan = writer.loan_sample(64).expect("loan");
+    loan.encode(&Point { x: 1, y: 2 }).expect("encode");
+    assert!(loan.len() < 64);
+    loan.commit().expect("commit");
+
+    // The reader picks up the writer's ring on its next scan.
+    let deadline = Instant::now() + Duration::from_secs(1);
+    let sample = loop {
+        if let Some(sample) = reader.take_loaned().expect("take_loaned") {
+            break sample;
+        }
+        assert!(Instant::now() < deadline, "loaned sample not received");
+        std::thread::sleep(Duration::from_millis(10));
+    };
+    assert_eq!(sample.decode().expect("decode"), Point { x: 1, y: 2 });
+    drop(sample);
+
+    // Loans bypass the regular take() path.
+    assert!(reader.take().expect("take").is_none());
+    assert!(reader.take_loaned().expect("take_loaned").is_none());
+
+    let reliable = participant
+        .topic::<Point>("loans_reliable")
+        .expect("topic")
+        .writer()
+        .qos(QoS::reliable())
+        .build()
+        .expect("writer");
+    assert!(matches!(
+        reliable.loan_sample(8),
+        Err(Error::UnsupportedOperation { .. })
+    ));
+}
@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! TIME_BASED_FILTER enforcement (DDS v1.4 Sec.2.2.3.14).
+//!
+//! A sample is delivered only if at least `minimum_separation` elapsed since
+//! the last sample delivered for the same instance. Separation is measured
+//! between the ring entries' timestamps (reception time, or write time for
+//! intra-process writers), so it does not depend on how often the
+//! application takes samples. Filtered samples are dropped before they reach
+//! the sample cache (before decoding on keyless topics).
+
+use super::cache::InstanceHandle;
+use crate::dds::qos::TimeBasedFilter;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Per-instance minimum separation between delivered samples.
+pub(super) struct TimeFilter {
+    min_separation_ns: u64,
+    /// Reception timestamp of the last delivered sample, per instance.
+    last_accepted: Mutex<HashMap<InstanceHandle, u64>>,
+}
+
+impl TimeFilter {
+    /// Filter for `policy`, or `None` when the policy is disabled.
+    pub(super) fn new(policy: TimeBasedFilter) -> Option<Self> {
+        if policy.is_disabled() {
+            return None;
+        }
+        Some(Self {
+            min_separation_ns: u64::try_from(policy.minimum_separation.as_nanos())
+                .unwrap_or(u64::MAX),
+            last_accepted: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Whether the sample of `instance` received at `reception_ns` is
+    /// delivered. Accepted samples restart the separation window.
+    pub(super) fn accept(&self, instance: InstanceHandle, reception_ns: u64) -> bool {
+        let mut last_accepted = self.last_accepted.lock().unwrap_or_else(|e| e.into_inner());
+        match last_accepted.get(&instance) {
+            Some(&last) if reception_ns.saturating_sub(last) < self.min_separation_ns => false,
+            _ => {
+                last_accepted.insert(instance, reception_ns);
+                true
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    const MS: u64 = 1_000_000;
+
+    #[test]
+    fn test_disabled_policy_has_no_filter() {
+        assert!(TimeFilter::new(TimeBasedFilter::zero()).is_none());
+    }
+
+    #[test]
+    fn test_minimum_separation_per_instance() {
+        let filter =
+            TimeFilter::new(TimeBasedFilter::new(Duration::from_millis(100))).expect("filter");
+        let a = InstanceHandle::new([1; 16]);
+        let b = InstanceHandle::new([2; 16]);
+
+        // 1 kHz stream on instance a: one sample per 100 ms gets through
+        let accepted = (0..1000)
+            .filter(|i| filter.accept(a, 1_000 * MS + i * MS))
+            .count();
+        assert_eq!(accepted, 10);
+
+        // Instance b has its own window
+        assert!(filter.accept(b, 1_050 * MS));
+        assert!(!filter.accept(b, 1_100 * MS));
+        assert!(filter.accept(b, 1_150 * MS));
+    }
+}
@@ -0,0 +1,216 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Publish serde types without IDL or `#[derive(hdds::DDS)]`.
+//!
+//! [`Serde<T>`] implements [`DDS`] for any `T: Serialize + DeserializeOwned`
+//! using the generic CDR2 codec in [`crate::core::ser::serde_cdr`].
+//!
+//! # Caveats
+//!
+//! - No XTypes: no TypeObject is announced, so remote type checking and
+//!   DynamicData decoding are unavailable
+//! - The topic type name is the Rust type name without module paths
+//!   (e.g. `Pose` or `Wrapper<Pose>`); peers must use the same name
+//! - Only structs of primitives, strings and sequences share their wire
+//!   layout with IDL types; enums, `Option` and maps are HDDS-specific
+//! - Keyless: every sample belongs to the same instance
+//! - Samples are limited to 64 KB, like other `DDS` types
+
+use super::{Error, Result, DDS};
+use crate::core::ser::{serde_cdr, SerError};
+use crate::core::types::TypeDescriptor;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Mutex, OnceLock};
+
+/// Sample wrapper publishing any serde type over DDS.
+///
+/// # Feature Flag
+/// Requires the `serde` feature.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use hdds::{Participant, QoS, Result, Serde};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Pose { x: f64, y: f64, frame: Option<String> }
+///
+/// fn main() -> Result<()> {
+///     let participant = Participant::builder("tool").build()?;
+///     let writer = participant
+///         .topic::<Serde<Pose>>("pose")?
+///         .writer()
+///         .qos(QoS::reliable())
+///         .build()?;
+///     writer.write(&Serde(Pose { x: 1.0, y: 2.0, frame: None }))
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Serde<T>(pub T);
+
+impl<T> Serde<T> {
+    /// Unwrap the inner value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Serde<T> {
+    fn from(value: T) -> Self {
+        Serde(value)
+    }
+}
+
+impl<T> Deref for Serde<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Serde<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T> DDS for Serde<T>
+where
+    T: Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    fn type_descriptor() -> &'static TypeDescriptor {
+        descriptor_for::<T>()
+    }
+
+    fn encode_cdr2(&self, buf: &mut [u8]) -> Result<usize> {
+        serde_cdr::to_cdr2(&self.0, buf).map_err(|e| match e {
+            SerError::WriteFailed { .. } => Error::BufferTooSmall,
+            e => {
+                log::debug!("[serde] encode {}: {}", short_type_name::<T>(), e);
+                Error::SerializationError
+            }
+        })
+    }
+
+    fn decode_cdr2(buf: &[u8]) -> Result<Self> {
+        serde_cdr::from_cdr2(buf).map(Serde).map_err(|e| {
+            log::debug!("[serde] decode {}: {}", short_type_name::<T>(), e);
+            Error::SerializationError
+        })
+    }
+}
+
+/// Descriptors are built once per type and leaked, since generic functions
+/// cannot own a `static`.
+fn descriptor_for<T: 'static>() -> &'static TypeDescriptor {
+    static DESCRIPTORS: OnceLock<Mutex<HashMap<TypeId, &'static TypeDescriptor>>> = OnceLock::new();
+
+    let mut descriptors = DESCRIPTORS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    descriptors.entry(TypeId::of::<T>()).or_insert_with(|| {
+        let type_name: &'static str = Box::leak(short_type_name::<T>().into_boxed_str());
+        Box::leak(Box::new(TypeDescriptor::new(
+            fnv1a(type_name),
+            type_name,
+            0,
+            1,
+            true,
+            &[],
+        )))
+    })
+}
+
+/// `std::any::type_name` with module paths stripped, so the same struct
+/// defined in two tools maps to the same topic type.
+fn short_type_name<T>() -> String {
+    let full = std::any::type_name::<T>();
+    let mut name = String::with_capacity(full.len());
+    let mut segment_start = 0;
+    let mut chars = full.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            name.truncate(segment_start);
+            continue;
+        }
+        name.push(c);
+        if matches!(c, '<' | '>' | ',' | ' ' | '(' | ')' | '[' | ']' | ';' | '&') {
+            segment_start = name.len();
+        }
+    }
+    name
+}
+
+/// FNV-1a (32-bit), as used for `#[derive(DDS)]` type ids.
+fn fnv1a(s: &str) -> u32 {
+    s.bytes().fold(2_166_136_261_u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(16_777_619)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Pose {
+        x: f64,
+        frame: Option<String>,
+    }
+
+    #[test]
+    fn test_type_name_strips_module_paths() {
+        assert_eq!(Serde::<Pose>::type_descriptor().type_name, "Pose");
+        assert_eq!(Serde::<Vec<Pose>>::type_descriptor().type_name, "Vec<Pose>");
+        assert!(std::ptr::eq(
+            Serde::<Pose>::type_descriptor(),
+            Serde::<Pose>::type_descriptor()
+        ));
+    }
+
+    #[test]
+    fn test_serde_sample_over_intra_process() {
+        let participant = crate::Participant::builder("serde_test")
+            .build()
+            .expect("participant");
+        let topic = participant
+            .topic::<Serde<Pose>>("serde/pose")
+            .expect("topic");
+        let reader = topic
+            .reader()
+            .qos(crate::QoS::reliable())
+            .build()
+            .expect("reader");
+        let writer = topic
+            .writer()
+            .qos(crate::QoS::reliable())
+            .build()
+            .expect("writer");
+
+        let pose = Pose {
+            x: 1.5,
+            frame: Some("map".into()),
+        };
+        writer.write(&Serde(pose.clone())).expect("write");
+
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(1);
+        let received = loop {
+            if let Some(sample) = reader.take().expect("take") {
+                break sample;
+            }
+            assert!(std::time::Instant::now() < deadline, "no sample received");
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        };
+        assert_eq!(received.into_inner(), pose);
+    }
+}
@@ -0,0 +1,482 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Static deployment: every topic and endpoint declared upfront.
+//!
+//! A participant built with [`ParticipantBuilder::with_static_deployment`]
+//! only creates the writers and readers its [`StaticDeployment`] declares.
+//! Once they exist, [`Participant::complete_static_discovery`] waits for the
+//! declared remote endpoints and seals the participant: discovery is frozen
+//! on the graph found so far and further endpoint creation fails. All
+//! history caches, matches and unicast routes are therefore set up during
+//! startup, and nothing is allocated or matched in the steady state.
+//!
+//! # Example YAML
+//!
+//! ```yaml
+//! discovery_timeout_ms: 5000
+//! topics:
+//!   - name: sensors/imu
+//!     type: Imu
+//!     writers: 1
+//!     remote_readers: 2
+//!   - name: commands
+//!     readers: 1
+//!     remote_writers: 1
+//! ```
+//!
+//! [`ParticipantBuilder::with_static_deployment`]: crate::dds::ParticipantBuilder::with_static_deployment
+//! [`Participant::complete_static_discovery`]: crate::dds::Participant::complete_static_discovery
+
+use crate::dds::{EndpointKind, Error, ErrorContext, Result};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Default time [`Participant::complete_static_discovery`] waits for remote endpoints.
+///
+/// [`Participant::complete_static_discovery`]: crate::dds::Participant::complete_static_discovery
+pub const DEFAULT_STATIC_DISCOVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A topic of a static deployment with its endpoint counts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaticTopic {
+    /// Topic name.
+    pub name: String,
+    /// Type name the endpoints must use (`None` accepts any type).
+    pub type_name: Option<String>,
+    /// Writers this participant creates on the topic.
+    pub writers: u32,
+    /// Readers this participant creates on the topic.
+    pub readers: u32,
+    /// Writers of other participants to discover before sealing.
+    pub remote_writers: u32,
+    /// Readers of other participants to discover before sealing.
+    pub remote_readers: u32,
+}
+
+impl StaticTopic {
+    /// Declare a topic without endpoints.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            type_name: None,
+            writers: 0,
+            readers: 0,
+            remote_writers: 0,
+            remote_readers: 0,
+        }
+    }
+
+    /// Require endpoints on this topic to use `type_name`.
+    pub fn type_name(mut self, type_name: impl Into<String>) -> Self {
+        self.type_name = Some(type_name.into());
+        self
+    }
+
+    /// Number of local writers.
+    pub fn writers(mut self, count: u32) -> Self {
+        self.writers = count;
+        self
+    }
+
+    /// Number of local readers.
+    pub fn readers(mut self, count: u32) -> Self {
+        self.readers = count;
+        self
+    }
+
+    /// Number of remote writers expected during initial discovery.
+    pub fn remote_writers(mut self, count: u32) -> Self {
+        self.remote_writers = count;
+        self
+    }
+
+    /// Number of remote readers expected during initial discovery.
+    pub fn remote_readers(mut self, count: u32) -> Self {
+        self.remote_readers = count;
+        self
+    }
+}
+
+/// Topics and endpoints of a static deployment.
+///
+/// # Example
+///
+/// ```
+/// use hdds::dds::{StaticDeployment, StaticTopic};
+/// use std::time::Duration;
+///
+/// let deployment = StaticDeployment::new()
+///     .topic(StaticTopic::new("sensors/imu").type_name("Imu").writers(1).remote_readers(2))
+///     .topic(StaticTopic::new("commands").readers(1).remote_writers(1))
+///     .discovery_timeout(Duration::from_secs(5));
+/// assert_eq!(deployment.topics().len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaticDeployment {
+    topics: Vec<StaticTopic>,
+    discovery_timeout: Duration,
+}
+
+impl Default for StaticDeployment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StaticDeployment {
+    /// Create an empty deployment.
+    pub fn new() -> Self {
+        Self {
+            topics: Vec::new(),
+            discovery_timeout: DEFAULT_STATIC_DISCOVERY_TIMEOUT,
+        }
+    }
+
+    /// Declare a topic.
+    pub fn topic(mut self, topic: StaticTopic) -> Self {
+        self.topics.push(topic);
+        self
+    }
+
+    /// Time the initial discovery may take before it fails.
+    pub fn discovery_timeout(mut self, timeout: Duration) -> Self {
+        self.discovery_timeout = timeout;
+        self
+    }
+
+    /// Declared topics.
+    pub fn topics(&self) -> &[StaticTopic] {
+        &self.topics
+    }
+
+    /// Declared topic named `name`.
+    pub fn get(&self, name: &str) -> Option<&StaticTopic> {
+        self.topics.iter().find(|topic| topic.name == name)
+    }
+
+    /// Initial discovery timeout.
+    pub fn timeout(&self) -> Duration {
+        self.discovery_timeout
+    }
+
+    /// Check that the deployment is well formed (unique, non-empty topic names).
+    ///
+    /// # Errors
+    ///
+    /// [`Error::InvalidConfig`] on an empty or duplicate topic name.
+    pub fn validate(&self) -> Result<()> {
+        for (index, topic) in self.topics.iter().enumerate() {
+            if topic.name.is_empty() {
+                log::error!("[hdds] Static deployment: topic #{} has no name", index);
+                return Err(Error::config(format!(
+                    "static deployment topic #{} has no name",
+                    index
+                )));
+            }
+            if self.topics[..index].iter().any(|t| t.name == topic.name) {
+                log::error!(
+                    "[hdds] Static deployment: topic '{}' declared twice",
+                    topic.name
+                );
+                return Err(Error::config("static deployment declares the topic twice")
+                    .with_context(ErrorContext::new().topic(&topic.name)));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "qos-loaders")]
+mod yaml {
+    use super::{StaticDeployment, StaticTopic, DEFAULT_STATIC_DISCOVERY_TIMEOUT};
+    use crate::dds::{Error, Result};
+    use serde::Deserialize;
+    use std::path::Path;
+    use std::time::Duration;
+
+    #[derive(Debug, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct YamlStaticDeployment {
+        #[serde(default)]
+        discovery_timeout_ms: Option<u64>,
+        #[serde(default)]
+        topics: Vec<YamlStaticTopic>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct YamlStaticTopic {
+        name: String,
+        #[serde(default, rename = "type")]
+        type_name: Option<String>,
+        #[serde(default)]
+        writers: u32,
+        #[serde(default)]
+        readers: u32,
+        #[serde(default)]
+        remote_writers: u32,
+        #[serde(default)]
+        remote_readers: u32,
+    }
+
+    impl StaticDeployment {
+        /// Parse a deployment from YAML.
+        ///
+        /// The document holds an optional `discovery_timeout_ms` and a
+        /// `topics` list; each topic has a `name`, an optional `type` and
+        /// optional `writers`, `readers`, `remote_writers` and
+        /// `remote_readers` counts (default 0). Unknown keys are rejected.
+        ///
+        /// # Errors
+        ///
+        /// [`Error::InvalidConfig`] if the document is malformed or invalid.
+        pub fn from_yaml_str(yaml: &str) -> Result<Self> {
+            let doc: YamlStaticDeployment = serde_yaml::from_str(yaml).map_err(|e| {
+                log::error!("[hdds] Invalid static deployment YAML: {}", e);
+                Error::config(format!("invalid static deployment YAML: {}", e))
+            })?;
+            let deployment = Self {
+                topics: doc
+                    .topics
+                    .into_iter()
+                    .map(|t| StaticTopic {
+                        name: t.name,
+                        type_name: t.type_name,
+                        writers: t.writers,
+                        readers: t.readers,
+                        remote_writers: t.remote_writers,
+                        remote_readers: t.remote_readers,
+                    })
+                    .collect(),
+                discovery_timeout: doc
+                    .discovery_timeout_ms
+                    .map_or(DEFAULT_STATIC_DISCOVERY_TIMEOUT, Duration::from_millis),
+            };
+            deployment.validate()?;
+            Ok(deployment)
+        }
+
+        /// Load a deployment from a YAML file.
+        ///
+        /// # Errors
+        ///
+        /// [`Error::ConfigFileNotFound`] if the file cannot be read,
+        /// [`Error::InvalidConfig`] if it is malformed or invalid.
+        pub fn from_yaml_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+            let path = path.as_ref();
+            let yaml = std::fs::read_to_string(path)
+                .map_err(|_| Error::ConfigFileNotFound(path.display().to_string()))?;
+            Self::from_yaml_str(&yaml).map_err(|e| {
+                e.with_context(crate::dds::ErrorContext::new().path(path.display().to_string()))
+            })
+        }
+    }
+}
+
+/// Endpoint creation bookkeeping of a static participant.
+#[derive(Debug, Default)]
+struct PlanState {
+    /// Topic name -> (writers, readers) created so far.
+    created: HashMap<String, (u32, u32)>,
+    sealed: bool,
+}
+
+/// Enforces a [`StaticDeployment`] on endpoint creation.
+#[derive(Debug)]
+pub(crate) struct StaticPlan {
+    deployment: StaticDeployment,
+    state: Mutex<PlanState>,
+}
+
+impl StaticPlan {
+    pub(crate) fn new(deployment: StaticDeployment) -> Self {
+        Self {
+            deployment,
+            state: Mutex::new(PlanState::default()),
+        }
+    }
+
+    pub(crate) fn deployment(&self) -> &StaticDeployment {
+        &self.deployment
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, PlanState> {
+        match self.state.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    /// Account for a new endpoint, or refuse it if the deployment does not
+    /// declare it.
+    pub(crate) fn admit(&self, kind: EndpointKind, topic: &str, type_name: &str) -> Result<()> {
+        let what = match kind {
+            EndpointKind::Writer => "writer",
+            EndpointKind::Reader => "reader",
+        };
+        let mut state = self.state();
+        if state.sealed {
+            return Err(Error::InvalidState(format!(
+                "static deployment is sealed: cannot create {} on '{}'",
+                what, topic
+            )));
+        }
+        let declared = self.deployment.get(topic).ok_or_else(|| {
+            Error::InvalidState(format!(
+                "topic '{}' is not declared in the static deployment",
+                topic
+            ))
+        })?;
+        if let Some(expected) = declared.type_name.as_deref() {
+            if expected != type_name {
+                return Err(Error::InvalidState(format!(
+                    "topic '{}' is declared with type '{}', not '{}'",
+                    topic, expected, type_name
+                )));
+            }
+        }
+
+        let (writers, readers) = state.created.entry(topic.to_string()).or_default();
+        let (count, limit) = match kind {
+            EndpointKind::Writer => (writers, declared.writers),
+            EndpointKind::Reader => (readers, declared.readers),
+        };
+        if *count >= limit {
+            return Err(Error::ResourceLimitExceeded(format!(
+                "static deployment declares {} {}(s) on '{}'",
+                limit, what, topic
+            )));
+        }
+        *count += 1;
+        Ok(())
+    }
+
+    /// Declared local endpoints not created yet, as readable descriptions.
+    pub(crate) fn missing_endpoints(&self) -> Vec<String> {
+        let state = self.state();
+        let mut missing = Vec::new();
+        for topic in &self.deployment.topics {
+            let (writers, readers) = state.created.get(&topic.name).copied().unwrap_or_default();
+            if writers < topic.writers {
+                missing.push(format!(
+                    "{} writer(s) on '{}'",
+                    topic.writers - writers,
+                    topic.name
+                ));
+            }
+            if readers < topic.readers {
+                missing.push(format!(
+                    "{} reader(s) on '{}'",
+                    topic.readers - readers,
+                    topic.name
+                ));
+            }
+        }
+        missing
+    }
+
+    pub(crate) fn seal(&self) {
+        self.state().sealed = true;
+    }
+
+    pub(crate) fn is_sealed(&self) -> bool {
+        self.state().sealed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plan() -> StaticPlan {
+        StaticPlan::new(
+            StaticDeployment::new().topic(
+                StaticTopic::new("imu")
+                    .type_name("Imu")
+                    .writers(1)
+                    .readers(2),
+            ),
+        )
+    }
+
+    #[test]
+    fn test_plan_admits_declared_endpoints_only() {
+        let plan = plan();
+        assert_eq!(plan.missing_endpoints().len(), 2);
+
+        plan.admit(EndpointKind::Writer, "imu", "Imu")
+            .expect("writer");
+        assert!(matches!(
+            plan.admit(EndpointKind::Writer, "imu", "Imu"),
+            Err(Error::ResourceLimitExceeded(_))
+        ));
+        assert!(matches!(
+            plan.admit(EndpointKind::Reader, "imu", "Other"),
+            Err(Error::InvalidState(_))
+        ));
+        assert!(matches!(
+            plan.admit(EndpointKind::Reader, "gps", "Imu"),
+            Err(Error::InvalidState(_))
+        ));
+        plan.admit(EndpointKind::Reader, "imu", "Imu")
+            .expect("reader");
+        assert_eq!(plan.missing_endpoints(), vec!["1 reader(s) on 'imu'"]);
+
+        plan.seal();
+        assert!(plan.is_sealed());
+        assert!(matches!(
+            plan.admit(EndpointKind::Reader, "imu", "Imu"),
+            Err(Error::InvalidState(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_rejects_duplicate_topics() {
+        let deployment = StaticDeployment::new()
+            .topic(StaticTopic::new("imu"))
+            .topic(StaticTopic::new("imu"));
+        assert!(matches!(
+            deployment.validate(),
+            Err(Error::InvalidConfig { .. })
+        ));
+        assert!(StaticDeployment::new()
+            .topic(StaticTopic::new(""))
+            .validate()
+            .is_err());
+    }
+
+    #[cfg(feature = "qos-loaders")]
+    #[test]
+    fn test_from_yaml_str() {
+        let deployment = StaticDeployment::from_yaml_str(
+            r#"
+discovery_timeout_ms: 2500
+topics:
+  - name: sensors/imu
+    type: Imu
+    writers: 1
+    remote_readers: 2
+  - name: commands
+    readers: 1
+"#,
+        )
+        .expect("deployment");
+
+        assert_eq!(deployment.timeout(), Duration::from_millis(2500));
+        assert_eq!(
+            deployment.get("sensors/imu"),
+            Some(
+                &StaticTopic::new("sensors/imu")
+                    .type_name("Imu")
+                    .writers(1)
+                    .remote_readers(2)
+            )
+        );
+        assert_eq!(deployment.get("commands").map(|t| t.readers), Some(1));
+
+        // Typos must not silently drop a declaration
+        assert!(StaticDeployment::from_yaml_str("topics:\n  - name: a\n    writer: 1\n").is_err());
+    }
+}
@@ -25,6 +25,7 @@
 //! This duplication is **approved** for ANSSI/IGI-1300 compliance.
 //! jscpd: ignore (intentional API symmetry per DDS v1.4 spec)
 
+use super::coherent::{self, CoherentGate};
 use super::{DataReader, QoS, Result, Topic};
 use crate::engine::TopicRegistry;
 use crate::transport::UdpTransport;
@@ -74,6 +75,10 @@ pub struct Subscriber {
     /// Whether access is currently locked for coherent reading
     /// Used by begin_access() / end_access()
     access_locked: AtomicBool,
+
+    /// Holds coherent sets back until complete and orders samples across
+    /// topics, when PRESENTATION enables coherent or group ordered access
+    coherent: Option<Arc<CoherentGate>>,
 }
 
 impl Subscriber {
@@ -99,7 +104,29 @@ impl Subscriber {
             registry,
             participant,
             access_locked: AtomicBool::new(false),
+            coherent: None,
+        }
+    }
+
+    /// Set up coherent set and ordered delivery if the PRESENTATION QoS
+    /// enables coherent access with TOPIC or GROUP scope, or ordered access
+    /// with GROUP scope.
+    pub(crate) fn with_presentation(mut self) -> Result<Self> {
+        let ordered = coherent::group_ordered_access(&self.qos);
+        if ordered || coherent::coherent_access(&self.qos) {
+            let participant = self.participant.as_ref().ok_or_else(|| {
+                crate::dds::Error::InvalidState(
+                    "Coherent or ordered access requires a Participant reference".to_string(),
+                )
+            })?;
+            self.coherent = Some(CoherentGate::new(participant, ordered)?);
         }
+        Ok(self)
+    }
+
+    /// Coherent set gate shared with this Subscriber's readers.
+    pub(crate) fn coherent_gate(&self) -> Option<&Arc<CoherentGate>> {
+        self.coherent.as_ref()
     }
 
     /// Get the QoS policies for this Subscriber
@@ -150,13 +177,8 @@ impl Subscriber {
     pub fn create_reader<T: crate::dds::DDS>(
         &self,
         topic_name: &str,
-        mut qos: QoS,
+        qos: QoS,
     ) -> Result<DataReader<T>> {
-        // Inherit PARTITION from subscriber if not explicitly set in reader QoS
-        if qos.partition.is_default() && !self.qos.partition.is_default() {
-            qos.partition = self.qos.partition.clone();
-        }
-
         // Create topic with participant reference (required for SEDP announcements)
         let participant = self.participant.as_ref().ok_or_else(|| {
             crate::dds::Error::InvalidState(
@@ -164,7 +186,8 @@ impl Subscriber {
             )
         })?;
         let topic = Topic::<T>::new(topic_name.to_string(), Arc::clone(participant));
-        let mut builder = topic.reader().qos(qos);
+        // Inherits PARTITION (if not set in reader QoS) and coherent sets
+        let mut builder = topic.reader().qos(qos).subscriber(self);
 
         // Attach registry for UDP RX (data reception)
         if let Some(ref registry) = self.registry {
@@ -197,6 +220,12 @@ impl Subscriber {
     ///
     /// Coherent access ensures that when a Publisher uses coherent changes,
     /// the Subscriber sees all samples from a coherent set atomically.
+    /// With coherent access enabled, sets completed while access is locked
+    /// stay hidden until `end_access()`.
+    ///
+    /// Readers take part when created with `topic.reader().subscriber(&subscriber)`.
+    /// Their listeners are still called as samples arrive, before the set
+    /// is complete.
     ///
     /// # Errors
     ///
@@ -217,6 +246,9 @@ impl Subscriber {
                 "Access already locked (nested calls not supported)".to_string(),
             ));
         }
+        if let Some(gate) = &self.coherent {
+            gate.set_access_locked(true);
+        }
         log::debug!("[Subscriber] Begin access (locked)");
         Ok(())
     }
@@ -244,6 +276,9 @@ impl Subscriber {
                 "Access not locked".to_string(),
             ));
         }
+        if let Some(gate) = &self.coherent {
+            gate.set_access_locked(false);
+        }
         log::debug!("[Subscriber] End access (unlocked)");
         Ok(())
     }
@@ -253,6 +288,35 @@ impl Subscriber {
     pub fn is_access_locked(&self) -> bool {
         self.access_locked.load(Ordering::SeqCst)
     }
+
+    /// Topics of the samples waiting in this Subscriber's readers, in the
+    /// order the publishers wrote them.
+    ///
+    /// With GROUP ordered access (`Presentation::group_ordered()`), each
+    /// entry stands for one sample: taking one sample from the reader of
+    /// each listed topic, in turn, reads samples in write order across
+    /// topics. This is the DDS `get_datareaders()` of ordered access.
+    ///
+    /// Readers take part when created with `topic.reader().subscriber(&subscriber)`.
+    /// Without coherent or ordered access the list is empty. Samples
+    /// already moved to a reader's cache by `read()` are not listed.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// for topic in subscriber.ordered_topics() {
+    ///     match topic.as_str() {
+    ///         "log/append" => apply(reader_append.take()?),
+    ///         "log/truncate" => apply(reader_truncate.take()?),
+    ///         _ => {}
+    ///     }
+    /// }
+    /// ```
+    pub fn ordered_topics(&self) -> Vec<String> {
+        self.coherent
+            .as_ref()
+            .map_or_else(Vec::new, |gate| gate.ready_topics())
+    }
 }
 
 #[cfg(test)]
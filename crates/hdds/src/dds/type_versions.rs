@@ -0,0 +1,194 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Side-by-side type versions for DataWriter and DataReader.
+//!
+//! A writer publishes its native type plus converted copies in older (or
+//! newer) versions; a reader decodes its native type plus converted copies
+//! from the versions it accepts. Wire format and selection rules live in
+//! [`crate::protocol::type_version`].
+
+use crate::dds::{Error, Result, DDS};
+use crate::protocol::type_version::{
+    AnnouncedTypeVersion, TypeVersionAnnouncement, TypeVersionTag, MAX_TYPE_VERSIONS,
+};
+use std::sync::Arc;
+
+/// Writer-side conversion: encode `T` as another version into `buf`.
+pub(crate) type EncodeAs<T> = Arc<dyn Fn(&T, &mut [u8]) -> Result<usize> + Send + Sync>;
+
+/// Reader-side conversion: decode another version from `buf` into `T`.
+pub(crate) type DecodeFrom<T> = Arc<dyn Fn(&[u8]) -> Result<T> + Send + Sync>;
+
+/// A non-native version with its conversion.
+pub(crate) struct VersionCodec<F> {
+    pub(crate) version: u32,
+    pub(crate) type_name: String,
+    pub(crate) codec: F,
+    type_object: Option<crate::xtypes::CompleteTypeObject>,
+}
+
+impl<F> VersionCodec<F> {
+    fn new<V: DDS>(version: u32, codec: F) -> Self {
+        Self {
+            version,
+            type_name: V::type_descriptor().type_name.to_string(),
+            codec,
+            type_object: V::get_type_object(),
+        }
+    }
+}
+
+/// Writer codec publishing version `version` of type `V`, converted from `T`.
+pub(crate) fn encode_as<T, V, C>(version: u32, convert: C) -> VersionCodec<EncodeAs<T>>
+where
+    V: DDS,
+    C: Fn(&T) -> V + Send + Sync + 'static,
+{
+    VersionCodec::new::<V>(
+        version,
+        Arc::new(move |msg: &T, buf: &mut [u8]| convert(msg).encode_cdr2(buf)),
+    )
+}
+
+/// Reader codec accepting version `version` of type `V`, converted into `T`.
+pub(crate) fn decode_from<T, V, C>(version: u32, convert: C) -> VersionCodec<DecodeFrom<T>>
+where
+    V: DDS,
+    C: Fn(V) -> T + Send + Sync + 'static,
+{
+    VersionCodec::new::<V>(
+        version,
+        Arc::new(move |buf: &[u8]| V::decode_cdr2(buf).map(&convert)),
+    )
+}
+
+/// Native version plus the extra versions an endpoint handles.
+pub(crate) struct TypeVersions<F> {
+    native: u32,
+    others: Vec<VersionCodec<F>>,
+    /// All versions, including `native`, ascending.
+    all: Vec<u32>,
+}
+
+impl<F> TypeVersions<F> {
+    /// Validate builder input. Returns `Ok(None)` when versioning is off.
+    pub(crate) fn from_builder(
+        topic: &str,
+        native: Option<u32>,
+        others: Vec<VersionCodec<F>>,
+    ) -> Result<Option<Self>> {
+        let native = match native {
+            Some(native) => native,
+            None if others.is_empty() => return Ok(None),
+            None => {
+                return Err(Error::InvalidState(format!(
+                    "topic '{}': extra type versions require type_version() for the native type",
+                    topic
+                )))
+            }
+        };
+
+        let mut all: Vec<u32> = others.iter().map(|c| c.version).collect();
+        all.push(native);
+        all.sort_unstable();
+        if all.windows(2).any(|w| w[0] == w[1]) {
+            return Err(Error::InvalidState(format!(
+                "topic '{}': type version declared more than once: {:?}",
+                topic, all
+            )));
+        }
+        if all.len() > MAX_TYPE_VERSIONS {
+            return Err(Error::InvalidState(format!(
+                "topic '{}': {} type versions exceed the limit of {}",
+                topic,
+                all.len(),
+                MAX_TYPE_VERSIONS
+            )));
+        }
+
+        Ok(Some(Self {
+            native,
+            others,
+            all,
+        }))
+    }
+
+    pub(crate) fn native(&self) -> u32 {
+        self.native
+    }
+
+    /// All versions, including the native one, ascending.
+    pub(crate) fn all(&self) -> &[u32] {
+        &self.all
+    }
+
+    /// Non-native versions with their conversion.
+    pub(crate) fn others(&self) -> &[VersionCodec<F>] {
+        &self.others
+    }
+
+    /// Conversion for a non-native version.
+    pub(crate) fn codec(&self, version: u32) -> Option<&F> {
+        self.others
+            .iter()
+            .find(|c| c.version == version)
+            .map(|c| &c.codec)
+    }
+
+    /// DATA tag for a copy encoded in `sample`.
+    pub(crate) fn tag(&self, sample: u32) -> TypeVersionTag {
+        TypeVersionTag {
+            sample,
+            native: self.native,
+            offered: self.all.clone(),
+        }
+    }
+
+    /// SEDP announcement. The native entry is filled in by the participant
+    /// once the endpoint's type name and TypeObject are resolved.
+    pub(crate) fn announcement(&self) -> TypeVersionAnnouncement {
+        TypeVersionAnnouncement {
+            native: self.native,
+            versions: self
+                .others
+                .iter()
+                .map(|c| AnnouncedTypeVersion {
+                    version: c.version,
+                    type_name: c.type_name.clone(),
+                    type_object: c.type_object.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn codec(version: u32) -> VersionCodec<()> {
+        VersionCodec {
+            version,
+            type_name: format!("V{}", version),
+            codec: (),
+            type_object: None,
+        }
+    }
+
+    #[test]
+    fn test_from_builder_validation() {
+        assert!(TypeVersions::<()>::from_builder("t", None, Vec::new())
+            .expect("valid")
+            .is_none());
+        assert!(TypeVersions::from_builder("t", None, vec![codec(1)]).is_err());
+        assert!(TypeVersions::from_builder("t", Some(1), vec![codec(1)]).is_err());
+
+        let versions = TypeVersions::from_builder("t", Some(3), vec![codec(1), codec(2)])
+            .expect("valid")
+            .expect("enabled");
+        assert_eq!(versions.all(), &[1, 2, 3]);
+        assert_eq!(versions.tag(1).offered, vec![1, 2, 3]);
+        assert_eq!(versions.announcement().version_numbers(), vec![1, 2]);
+    }
+}
@@ -7,9 +7,16 @@
 //! coalesced `eventfd` notifications. Conditions register a waitset signal when
 //! attached so they can wake blocked waiters immediately when their trigger
 //! value flips to `true`.
+//!
+//! On Unix the driver's descriptor is exported through [`AsRawFd`], so that
+//! event loops built on `poll`/`epoll` can watch a WaitSet without a
+//! dedicated waiting thread.
 
 use super::condition::{Condition, HasStatusCondition};
 use crate::core::rt::{WaitsetDriver, WaitsetSignal, WaitsetWaitError, WAITSET_DEFAULT_MAX_SLOTS};
+#[cfg(unix)]
+use std::os::fd::{AsRawFd, RawFd};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
@@ -21,6 +28,9 @@ use std::time::{Duration, Instant};
 pub struct WaitSet {
     driver: Arc<WaitsetDriver>,
     entries: Mutex<Vec<Option<ConditionEntry>>>,
+    /// Descriptor handed out: `wait()` keeps it readable while it returns
+    /// triggered conditions
+    fd_exported: AtomicBool,
 }
 
 struct ConditionEntry {
@@ -42,6 +52,7 @@ impl WaitSet {
         Self {
             driver: Arc::new(driver),
             entries: Mutex::new(Vec::new()),
+            fd_exported: AtomicBool::new(false),
         }
     }
 
@@ -57,7 +68,7 @@ impl WaitSet {
                 .flatten()
                 .any(|entry| entry.condition.condition_id() == condition_id)
             {
-                return Err(super::Error::Config);
+                return Err(waitset_error("condition already attached"));
             }
         }
 
@@ -123,7 +134,7 @@ impl WaitSet {
                     break;
                 }
             }
-            result.ok_or(super::Error::Config)?
+            result.ok_or_else(|| waitset_error("condition not attached"))?
         };
 
         if let Some(entry) = entries.get_mut(slot_index) {
@@ -164,14 +175,10 @@ impl WaitSet {
         let start = timeout.map(|_| Instant::now());
 
         loop {
+            // Past the deadline the driver still polls once without
+            // blocking, consuming a pending event
             let remaining = match (timeout, start) {
-                (Some(total), Some(begin)) => {
-                    let elapsed = begin.elapsed();
-                    if elapsed >= total {
-                        return Err(super::Error::WouldBlock);
-                    }
-                    Some(total.saturating_sub(elapsed))
-                }
+                (Some(total), Some(begin)) => Some(total.saturating_sub(begin.elapsed())),
                 _ => timeout,
             };
 
@@ -186,6 +193,11 @@ impl WaitSet {
                     if let Some(mut triggered) = candidates {
                         triggered.retain(|cond| cond.get_trigger_value());
                         if !triggered.is_empty() {
+                            // The event was consumed: re-arm it so that the
+                            // descriptor reports conditions still triggered
+                            if self.fd_exported.load(Ordering::Relaxed) {
+                                self.driver.manual_notify();
+                            }
                             log::debug!(
                                 "[RUST-WAITSET] wait returning triggered_len={}",
                                 triggered.len()
@@ -238,6 +250,23 @@ impl WaitSet {
     }
 }
 
+/// Pollable descriptor of the WaitSet (eventfd on Linux, pipe elsewhere).
+///
+/// It becomes readable when an attached condition triggers or on
+/// [`WaitSet::notify`]. It is never read directly: once it polls readable,
+/// call [`WaitSet::wait`] with a zero timeout to get the triggered
+/// conditions. The descriptor stays readable while `wait()` returns
+/// conditions, and is cleared by a `wait()` finding none triggered, so a
+/// wakeup may be spurious. It is owned by the WaitSet and closed when the
+/// WaitSet is dropped.
+#[cfg(unix)]
+impl AsRawFd for WaitSet {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd_exported.store(true, Ordering::Relaxed);
+        self.driver.raw_fd()
+    }
+}
+
 impl Default for WaitSet {
     fn default() -> Self {
         Self::new()
@@ -261,6 +290,11 @@ impl Drop for WaitSet {
     }
 }
 
+/// Error for a condition attached twice or detached while not attached.
+fn waitset_error(reason: &str) -> super::Error {
+    super::Error::config(reason).with_context(super::ErrorContext::new().entity("WaitSet"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -397,4 +431,41 @@ mod tests {
             .expect("wait should succeed");
         assert_eq!(triggered[0].condition_id(), guard1.condition_id());
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_waitset_fd_readable_while_triggered() {
+        let readable = |fd: RawFd| {
+            let mut pollfd = libc::pollfd {
+                fd,
+                events: libc::POLLIN,
+                revents: 0,
+            };
+            // SAFETY: pollfd is a valid stack-allocated structure.
+            unsafe { libc::poll(&mut pollfd, 1, 0) == 1 }
+        };
+
+        let ws = WaitSet::new();
+        let guard = Arc::new(GuardCondition::new());
+        ws.attach_condition(guard.clone())
+            .expect("condition attachment should succeed");
+        let fd = ws.as_raw_fd();
+        assert!(!readable(fd));
+
+        guard.set_trigger_value(true);
+        assert!(readable(fd));
+        assert_eq!(ws.wait(Some(Duration::ZERO)).expect("triggered").len(), 1);
+        // Still triggered: still readable
+        assert!(readable(fd));
+        assert_eq!(ws.wait(Some(Duration::ZERO)).expect("triggered").len(), 1);
+
+        guard.set_trigger_value(false);
+        assert!(ws.wait(Some(Duration::ZERO)).is_err());
+        assert!(!readable(fd));
+
+        ws.notify();
+        assert!(readable(fd));
+        assert!(ws.wait(Some(Duration::ZERO)).is_err());
+        assert!(!readable(fd));
+    }
 }
@@ -0,0 +1,436 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! WASM filter/transform plugins (feature `wasmtime`).
+//!
+//! A plugin is a WebAssembly module run on the serialized (CDR) payload of
+//! every sample a writer publishes or a reader receives. Plugins are
+//! sandboxed: they get no host imports, each call runs with a fuel budget
+//! (CPU limit) and the instance memory is capped (memory limit). A sample
+//! whose plugin call traps or runs out of fuel or memory is dropped, and the
+//! instance is recreated for the next sample.
+//!
+//! # Module ABI
+//!
+//! The module exports:
+//!
+//! - `memory`: its linear memory
+//! - `hdds_alloc(len: i32) -> i32`: returns the address where HDDS copies a
+//!   `len`-byte payload. Called once per sample, so a bump allocator that
+//!   resets on each call is enough.
+//! - `hdds_filter(ptr: i32, len: i32) -> i32` (optional): 0 drops the sample
+//! - `hdds_transform(ptr: i32, len: i32) -> i64` (optional): a negative
+//!   value drops the sample, otherwise `(out_ptr << 32) | out_len` locates
+//!   the replacement payload in `memory`
+//!
+//! At least one of `hdds_filter` and `hdds_transform` must be exported; when
+//! both are, the filter runs first.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use hdds::{Participant, QoS};
+//! use hdds::dds::WasmLimits;
+//! use hdds::generated::temperature::Temperature;
+//!
+//! let participant = Participant::builder("app").build()?;
+//! let plugin = participant.load_wasm_plugin("filters/over_threshold.wasm", WasmLimits::default())?;
+//! let reader = participant
+//!     .topic::<Temperature>("sensor/temp")?
+//!     .reader()
+//!     .qos(QoS::reliable())
+//!     .wasm_plugin(plugin.clone())
+//!     .build()?;
+//!
+//! // Later: deploy a new version to the running reader
+//! plugin.reload_file("filters/over_threshold_v2.wasm")?;
+//! # Ok::<(), hdds::Error>(())
+//! ```
+
+use crate::dds::{Error, Result};
+use std::borrow::Cow;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard, OnceLock};
+use wasmtime::TypedFunc;
+use wasmtime::{Config, Engine, Instance, Memory, Module, Store, StoreLimits, StoreLimitsBuilder};
+
+/// CPU and memory limits of a WASM plugin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WasmLimits {
+    /// Fuel available to one plugin call (roughly one unit per instruction).
+    pub fuel_per_sample: u64,
+    /// Maximum size of the plugin's linear memory, in bytes.
+    pub max_memory_bytes: usize,
+}
+
+impl Default for WasmLimits {
+    /// 10 million fuel units per sample, 16 MiB of memory.
+    fn default() -> Self {
+        Self {
+            fuel_per_sample: 10_000_000,
+            max_memory_bytes: 16 * 1024 * 1024,
+        }
+    }
+}
+
+impl WasmLimits {
+    pub fn new(fuel_per_sample: u64, max_memory_bytes: usize) -> Self {
+        Self {
+            fuel_per_sample,
+            max_memory_bytes,
+        }
+    }
+}
+
+/// Engine shared by all plugins of the process.
+fn engine() -> Result<&'static Engine> {
+    static ENGINE: OnceLock<std::result::Result<Engine, String>> = OnceLock::new();
+    ENGINE
+        .get_or_init(|| {
+            let mut config = Config::new();
+            config.consume_fuel(true);
+            Engine::new(&config).map_err(|e| format!("{:#}", e))
+        })
+        .as_ref()
+        .map_err(|e| Error::WasmPlugin(e.clone()))
+}
+
+fn plugin_error(name: &str, e: &wasmtime::Error) -> Error {
+    Error::WasmPlugin(format!("{}: {:#}", name, e))
+}
+
+/// One instance of a plugin module.
+struct PluginInstance {
+    module: Module,
+    store: Store<StoreLimits>,
+    memory: Memory,
+    alloc: TypedFunc<i32, i32>,
+    filter: Option<TypedFunc<(i32, i32), i32>>,
+    transform: Option<TypedFunc<(i32, i32), i64>>,
+}
+
+impl PluginInstance {
+    fn new(module: Module, limits: WasmLimits) -> wasmtime::Result<Self> {
+        let store_limits = StoreLimitsBuilder::new()
+            .memory_size(limits.max_memory_bytes)
+            .instances(1)
+            .memories(1)
+            .tables(1)
+            .table_elements(10_000)
+            .build();
+        let mut store = Store::new(module.engine(), store_limits);
+        store.limiter(|limits| limits);
+        store.set_fuel(limits.fuel_per_sample)?;
+
+        // No imports: a module needing host functions fails here
+        let instance = Instance::new(&mut store, &module, &[])?;
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .ok_or_else(|| wasmtime::Error::msg("module does not export `memory`"))?;
+        let alloc = instance.get_typed_func(&mut store, "hdds_alloc")?;
+        let filter = instance
+            .get_export(&mut store, "hdds_filter")
+            .is_some()
+            .then(|| instance.get_typed_func(&mut store, "hdds_filter"))
+            .transpose()?;
+        let transform = instance
+            .get_export(&mut store, "hdds_transform")
+            .is_some()
+            .then(|| instance.get_typed_func(&mut store, "hdds_transform"))
+            .transpose()?;
+        if filter.is_none() && transform.is_none() {
+            return Err(wasmtime::Error::msg(
+                "module exports neither `hdds_filter` nor `hdds_transform`",
+            ));
+        }
+
+        Ok(Self {
+            module,
+            store,
+            memory,
+            alloc,
+            filter,
+            transform,
+        })
+    }
+
+    /// Run the plugin on `payload`; `Ok(None)` when it drops the sample.
+    fn call<'a>(
+        &mut self,
+        payload: &'a [u8],
+        limits: WasmLimits,
+    ) -> wasmtime::Result<Option<Cow<'a, [u8]>>> {
+        self.store.set_fuel(limits.fuel_per_sample)?;
+        let len = i32::try_from(payload.len())?;
+        let ptr = self.alloc.call(&mut self.store, len)?;
+        self.memory
+            .write(&mut self.store, usize::try_from(ptr)?, payload)?;
+
+        if let Some(ref filter) = self.filter {
+            if filter.call(&mut self.store, (ptr, len))? == 0 {
+                return Ok(None);
+            }
+        }
+
+        let Some(ref transform) = self.transform else {
+            return Ok(Some(Cow::Borrowed(payload)));
+        };
+        let packed = transform.call(&mut self.store, (ptr, len))?;
+        if packed < 0 {
+            return Ok(None);
+        }
+        let out_ptr = (packed >> 32) as usize;
+        let out_len = (packed & 0xffff_ffff) as usize;
+        if out_len > self.memory.data_size(&self.store) {
+            return Err(wasmtime::Error::msg(format!(
+                "transform output of {} bytes is out of bounds",
+                out_len
+            )));
+        }
+        let mut out = vec![0u8; out_len];
+        self.memory.read(&self.store, out_ptr, &mut out)?;
+        Ok(Some(Cow::Owned(out)))
+    }
+}
+
+/// A sandboxed WASM filter/transform plugin.
+///
+/// Shared between the readers and writers it is attached to (see
+/// `ReaderBuilder::wasm_plugin` and `WriterBuilder::wasm_plugin`); calls are
+/// serialized. [`reload`](Self::reload) swaps the module for all of them.
+pub struct WasmPlugin {
+    name: String,
+    limits: WasmLimits,
+    instance: Mutex<PluginInstance>,
+    /// Samples dropped because a call failed.
+    failures: AtomicU64,
+}
+
+impl std::fmt::Debug for WasmPlugin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WasmPlugin")
+            .field("name", &self.name)
+            .field("limits", &self.limits)
+            .field("failures", &self.failures())
+            .finish_non_exhaustive()
+    }
+}
+
+impl WasmPlugin {
+    /// Compile and instantiate a plugin from WASM binary (or WAT text).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::WasmPlugin` if the module does not compile, imports
+    /// host functions, exceeds `limits` at instantiation, or does not follow
+    /// the module ABI.
+    pub fn from_bytes(name: impl Into<String>, bytes: &[u8], limits: WasmLimits) -> Result<Self> {
+        let name = name.into();
+        let instance = Self::instantiate(&name, bytes, limits)?;
+        log::debug!("[wasm] loaded plugin '{}' limits={:?}", name, limits);
+        Ok(Self {
+            name,
+            limits,
+            instance: Mutex::new(instance),
+            failures: AtomicU64::new(0),
+        })
+    }
+
+    /// Load a plugin from a `.wasm` (or `.wat`) file.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::IoError` if the file cannot be read, otherwise as
+    /// [`from_bytes`](Self::from_bytes).
+    pub fn from_file(path: impl AsRef<Path>, limits: WasmLimits) -> Result<Self> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path).map_err(Error::IoError)?;
+        Self::from_bytes(path.display().to_string(), &bytes, limits)
+    }
+
+    fn instantiate(name: &str, bytes: &[u8], limits: WasmLimits) -> Result<PluginInstance> {
+        let module = Module::new(engine()?, bytes).map_err(|e| plugin_error(name, &e))?;
+        PluginInstance::new(module, limits).map_err(|e| plugin_error(name, &e))
+    }
+
+    /// Replace the module of this plugin, keeping its limits.
+    ///
+    /// The new module is validated first: on error the current one stays in
+    /// place.
+    ///
+    /// # Errors
+    ///
+    /// As [`from_bytes`](Self::from_bytes).
+    pub fn reload(&self, bytes: &[u8]) -> Result<()> {
+        let instance = Self::instantiate(&self.name, bytes, self.limits)?;
+        *self.lock() = instance;
+        log::debug!("[wasm] reloaded plugin '{}'", self.name);
+        Ok(())
+    }
+
+    /// Replace the module of this plugin with the one in `path`.
+    ///
+    /// # Errors
+    ///
+    /// As [`from_file`](Self::from_file).
+    pub fn reload_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        let bytes = std::fs::read(path).map_err(Error::IoError)?;
+        self.reload(&bytes)
+    }
+
+    /// Name of the plugin (its path when loaded from a file).
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn limits(&self) -> WasmLimits {
+        self.limits
+    }
+
+    /// Number of samples dropped because a plugin call trapped or exceeded
+    /// its limits.
+    #[must_use]
+    pub fn failures(&self) -> u64 {
+        self.failures.load(Ordering::Relaxed)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, PluginInstance> {
+        self.instance.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Run the plugin on a serialized sample; `None` when the sample is
+    /// dropped.
+    pub(crate) fn apply<'a>(&self, payload: &'a [u8]) -> Option<Cow<'a, [u8]>> {
+        let mut instance = self.lock();
+        match instance.call(payload, self.limits) {
+            Ok(result) => result,
+            Err(e) => {
+                self.failures.fetch_add(1, Ordering::Relaxed);
+                log::debug!(
+                    "[wasm] plugin '{}' failed, sample dropped: {:#}",
+                    self.name,
+                    e
+                );
+                // The instance may be left in any state: start afresh
+                let module = instance.module.clone();
+                match PluginInstance::new(module, self.limits) {
+                    Ok(fresh) => *instance = fresh,
+                    Err(e) => log::debug!(
+                        "[wasm] plugin '{}' re-instantiation failed: {:#}",
+                        self.name,
+                        e
+                    ),
+                }
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Keeps payloads whose first byte is non-zero.
+    const FILTER: &str = r#"(module
+        (memory (export "memory") 1)
+        (func (export "hdds_alloc") (param i32) (result i32) i32.const 1024)
+        (func (export "hdds_filter") (param $ptr i32) (param $len i32) (result i32)
+            (i32.load8_u (local.get $ptr))))"#;
+
+    /// Increments every byte; drops empty payloads.
+    const TRANSFORM: &str = r#"(module
+        (memory (export "memory") 1)
+        (func (export "hdds_alloc") (param i32) (result i32) i32.const 1024)
+        (func (export "hdds_transform") (param $ptr i32) (param $len i32) (result i64)
+            (local $i i32)
+            (if (i32.eqz (local.get $len)) (then (return (i64.const -1))))
+            (loop $next
+                (i32.store8
+                    (i32.add (local.get $ptr) (local.get $i))
+                    (i32.add (i32.load8_u (i32.add (local.get $ptr) (local.get $i))) (i32.const 1)))
+                (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                (br_if $next (i32.lt_u (local.get $i) (local.get $len))))
+            (i64.or
+                (i64.shl (i64.extend_i32_u (local.get $ptr)) (i64.const 32))
+                (i64.extend_i32_u (local.get $len)))))"#;
+
+    /// Never returns.
+    const SPIN: &str = r#"(module
+        (memory (export "memory") 1)
+        (func (export "hdds_alloc") (param i32) (result i32) i32.const 0)
+        (func (export "hdds_filter") (param i32 i32) (result i32)
+            (loop $forever (br $forever))
+            i32.const 1))"#;
+
+    #[test]
+    fn test_filter_plugin() {
+        let plugin = WasmPlugin::from_bytes("filter", FILTER.as_bytes(), WasmLimits::default())
+            .expect("plugin");
+        assert!(matches!(
+            plugin.apply(&[1, 2, 3]),
+            Some(Cow::Borrowed(&[1, 2, 3]))
+        ));
+        assert!(plugin.apply(&[0, 2, 3]).is_none());
+        assert_eq!(plugin.failures(), 0);
+    }
+
+    #[test]
+    fn test_transform_plugin_and_reload() {
+        let plugin =
+            WasmPlugin::from_bytes("transform", TRANSFORM.as_bytes(), WasmLimits::default())
+                .expect("plugin");
+        assert_eq!(plugin.apply(&[1, 2, 3]).as_deref(), Some(&[2u8, 3, 4][..]));
+        assert!(plugin.apply(&[]).is_none());
+
+        plugin.reload(FILTER.as_bytes()).expect("plugin");
+        assert!(plugin.apply(&[0]).is_none());
+
+        // A broken module leaves the current one in place
+        assert!(matches!(
+            plugin.reload(b"(module)"),
+            Err(Error::WasmPlugin(_))
+        ));
+        assert!(plugin.apply(&[1]).is_some());
+    }
+
+    #[test]
+    fn test_fuel_limit_drops_sample() {
+        let plugin =
+            WasmPlugin::from_bytes("spin", SPIN.as_bytes(), WasmLimits::new(100_000, 1 << 20))
+                .expect("plugin");
+        assert!(plugin.apply(&[1]).is_none());
+        assert!(plugin.apply(&[1]).is_none());
+        assert_eq!(plugin.failures(), 2);
+    }
+
+    #[test]
+    fn test_memory_limit_and_imports_rejected() {
+        // 2 pages (128 KiB) of initial memory over a 64 KiB limit
+        let big = r#"(module
+            (memory (export "memory") 2)
+            (func (export "hdds_alloc") (param i32) (result i32) i32.const 0)
+            (func (export "hdds_filter") (param i32 i32) (result i32) i32.const 1))"#;
+        assert!(
+            WasmPlugin::from_bytes("big", big.as_bytes(), WasmLimits::new(1000, 65536)).is_err()
+        );
+
+        let imports = r#"(module
+            (import "env" "now" (func (result i64)))
+            (memory (export "memory") 1)
+            (func (export "hdds_alloc") (param i32) (result i32) i32.const 0)
+            (func (export "hdds_filter") (param i32 i32) (result i32) i32.const 1))"#;
+        assert!(
+            WasmPlugin::from_bytes("imports", imports.as_bytes(), WasmLimits::default()).is_err()
+        );
+
+        // Writes past the memory limit fail at call time
+        let plugin = WasmPlugin::from_bytes("filter", FILTER.as_bytes(), WasmLimits::default())
+            .expect("plugin");
+        assert!(plugin.apply(&vec![1u8; 70_000]).is_none());
+        assert_eq!(plugin.failures(), 1);
+    }
+}
@@ -0,0 +1,260 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Writer-side batching of small samples (see [`QoS::batching`]).
+//!
+//! The DATA packets of consecutive writes are aggregated into one RTPS
+//! message: the first packet keeps its RTPS header, the next ones only add
+//! their submessages (INFO_TS and DATA). The message is sent when adding a
+//! packet would grow it past `max_bytes`, when its oldest sample waited
+//! `max_delay` (checked by a flush thread), on `DataWriter::flush()` and
+//! when the writer is dropped.
+//!
+//! [`QoS::batching`]: crate::dds::QoS::batching
+
+use crate::dds::qos::Batching;
+use std::io;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::Instant;
+
+/// Length of the RTPS header starting every packet.
+const RTPS_HEADER_LEN: usize = 20;
+
+/// Largest RTPS message a UDP datagram carries.
+const MAX_MESSAGE_LEN: usize = 65_507;
+
+/// Sends a (batched) RTPS message to the writer's readers.
+pub(super) type SendMessage = Box<dyn Fn(&[u8]) -> io::Result<()> + Send + Sync>;
+
+struct Pending {
+    /// RTPS message being built; empty when nothing is batched
+    message: Vec<u8>,
+    /// DATA packets in `message`
+    samples: usize,
+    /// When the first packet of `message` was batched
+    opened: Option<Instant>,
+    stop: bool,
+}
+
+struct Shared {
+    config: Batching,
+    pending: Mutex<Pending>,
+    wake: Condvar,
+    send: SendMessage,
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, Pending> {
+        self.pending.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Send the batched message, if any. Runs under the lock so that
+    /// batches leave in write order.
+    fn flush_locked(&self, pending: &mut Pending) -> io::Result<()> {
+        pending.opened = None;
+        if pending.message.is_empty() {
+            return Ok(());
+        }
+        log::trace!(
+            "[writer] sending batch of {} samples ({} bytes)",
+            pending.samples,
+            pending.message.len()
+        );
+        let result = (self.send)(&pending.message);
+        pending.message.clear();
+        pending.samples = 0;
+        result
+    }
+
+    /// Flush thread: sends each batch once its oldest sample waited
+    /// `max_delay`, and what is left on stop.
+    fn run(&self) {
+        let mut pending = self.lock();
+        loop {
+            if pending.stop {
+                if let Err(e) = self.flush_locked(&mut pending) {
+                    log::debug!("[writer] final batch send failed: {}", e);
+                }
+                return;
+            }
+            let Some(opened) = pending.opened else {
+                pending = self.wake.wait(pending).unwrap_or_else(|e| e.into_inner());
+                continue;
+            };
+            let deadline = opened + self.config.max_delay;
+            let now = Instant::now();
+            if now >= deadline {
+                if let Err(e) = self.flush_locked(&mut pending) {
+                    log::debug!("[writer] batch send failed: {}", e);
+                }
+            } else {
+                pending = self
+                    .wake
+                    .wait_timeout(pending, deadline - now)
+                    .unwrap_or_else(|e| e.into_inner())
+                    .0;
+            }
+        }
+    }
+}
+
+/// Batch of DATA packets waiting to be sent as one RTPS message.
+pub(super) struct WriterBatch {
+    shared: Arc<Shared>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl WriterBatch {
+    /// Batch according to `config`, sending messages with `send`.
+    ///
+    /// `max_bytes` is capped to the size of a UDP datagram.
+    pub(super) fn spawn(mut config: Batching, send: SendMessage) -> Self {
+        config.max_bytes = config.max_bytes.min(MAX_MESSAGE_LEN);
+        let shared = Arc::new(Shared {
+            config,
+            pending: Mutex::new(Pending {
+                message: Vec::with_capacity(config.max_bytes),
+                samples: 0,
+                opened: None,
+                stop: false,
+            }),
+            wake: Condvar::new(),
+            send,
+        });
+        let flusher = Arc::clone(&shared);
+        let thread = thread::Builder::new()
+            .name("hdds-batch".into())
+            .spawn(move || flusher.run())
+            .map_err(|e| log::warn!("[writer] failed to spawn batch flush thread: {}", e))
+            .ok();
+        Self { shared, thread }
+    }
+
+    /// Add a DATA packet (RTPS header, INFO_TS, DATA) to the batch.
+    ///
+    /// Sends the batch first if the packet does not fit, and after adding
+    /// it if the batch is full. Errors are those of these sends.
+    pub(super) fn push(&self, packet: &[u8]) -> io::Result<()> {
+        let config = self.shared.config;
+        let mut pending = self.shared.lock();
+        let mut result = Ok(());
+
+        let batched_len = pending.message.len() + packet.len().saturating_sub(RTPS_HEADER_LEN);
+        if !pending.message.is_empty() && batched_len > config.max_bytes {
+            result = self.shared.flush_locked(&mut pending);
+        }
+
+        if pending.message.is_empty() {
+            pending.message.extend_from_slice(packet);
+            pending.opened = Some(Instant::now());
+            self.shared.wake.notify_one();
+        } else {
+            pending
+                .message
+                .extend_from_slice(packet.get(RTPS_HEADER_LEN..).unwrap_or_default());
+        }
+        pending.samples += 1;
+
+        // Without a flush thread, nothing would send a batch left behind
+        if pending.message.len() >= config.max_bytes
+            || config.max_delay.is_zero()
+            || self.thread.is_none()
+        {
+            result = result.and(self.shared.flush_locked(&mut pending));
+        }
+        result
+    }
+
+    /// Send the batched samples now.
+    pub(super) fn flush(&self) -> io::Result<()> {
+        let mut pending = self.shared.lock();
+        self.shared.flush_locked(&mut pending)
+    }
+}
+
+impl Drop for WriterBatch {
+    fn drop(&mut self) {
+        self.shared.lock().stop = true;
+        self.shared.wake.notify_all();
+        match self.thread.take() {
+            Some(thread) => {
+                let _ = thread.join();
+            }
+            None => {
+                let _ = self.flush();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    type Sent = Arc<Mutex<Vec<Vec<u8>>>>;
+
+    fn batch(max_bytes: usize, max_delay: Duration) -> (WriterBatch, Sent) {
+        let sent: Sent = Arc::default();
+        let sink = Arc::clone(&sent);
+        let batch = WriterBatch::spawn(
+            Batching::new(max_bytes, max_delay),
+            Box::new(move |message| {
+                sink.lock().expect("sent").push(message.to_vec());
+                Ok(())
+            }),
+        );
+        (batch, sent)
+    }
+
+    /// Header of 20 `h` bytes, then a 12-byte submessage of `body` bytes.
+    fn packet(body: u8) -> Vec<u8> {
+        let mut packet = vec![b'h'; RTPS_HEADER_LEN];
+        packet.extend_from_slice(&[body; 12]);
+        packet
+    }
+
+    #[test]
+    fn test_aggregates_until_max_bytes() {
+        let (batch, sent) = batch(64, Duration::from_secs(3600));
+
+        // 32 + 12 + 12 bytes fit, the fourth packet does not
+        for body in 1..=4 {
+            batch.push(&packet(body)).expect("push");
+        }
+        {
+            let sent = sent.lock().expect("sent");
+            assert_eq!(sent.len(), 1);
+            let mut expected = packet(1);
+            expected.extend_from_slice(&[2; 12]);
+            expected.extend_from_slice(&[3; 12]);
+            assert_eq!(sent[0], expected);
+        }
+
+        batch.flush().expect("flush");
+        assert_eq!(sent.lock().expect("sent")[1], packet(4));
+        batch.flush().expect("empty flush");
+        assert_eq!(sent.lock().expect("sent").len(), 2);
+    }
+
+    #[test]
+    fn test_flushes_after_max_delay_and_on_drop() {
+        let (batch, sent) = batch(1024, Duration::from_millis(20));
+        batch.push(&packet(1)).expect("push");
+        batch.push(&packet(2)).expect("push");
+        assert!(sent.lock().expect("sent").is_empty());
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while sent.lock().expect("sent").is_empty() && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(5));
+        }
+        assert_eq!(sent.lock().expect("sent")[0].len(), 44);
+
+        batch.push(&packet(3)).expect("push");
+        drop(batch);
+        let sent = sent.lock().expect("sent");
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[1], packet(3));
+    }
+}
@@ -10,16 +10,36 @@
 use super::heartbeat_scheduler::{
     spawn_heartbeat_scheduler, HeartbeatSchedulerHandle, DEFAULT_HEARTBEAT_PERIOD_MS,
 };
+use super::hibernation::{HeartbeatWakeListener, WriterHibernation};
+use super::liveliness::{spawn_liveliness_scheduler, LivelinessAnnouncer};
+use super::matched::PublicationMatches;
+use super::matched::{self, MatchedCallback, MatchedDiscoveryListener, MatchedReader};
 use super::nack::{WriterNackFragHandler, WriterNackHandler};
 use super::runtime::DataWriter;
 use super::runtime::WriterReplayState;
 use crate::core::discovery::ReplayRegistry;
-use crate::core::discovery::GUID;
+use crate::core::discovery::{Matcher, GUID};
 use crate::core::rt;
+use crate::core::rt::clock::SystemClock;
+use crate::dds::coherent::CoherentGroup;
+use crate::dds::incompatible_qos::{
+    IncompatibleQosCallback, IncompatibleQosListener, IncompatibleQosWatch,
+    InconsistentTopicCallback,
+};
 use crate::dds::listener::DataWriterListener;
-use crate::dds::{DomainState, Error, MatchKey, QoS, Result, TypeId, DDS};
+use crate::dds::liveliness::WriterLiveliness;
+use crate::dds::qos::QosPolicyId;
+use crate::dds::type_versions::{self, EncodeAs, TypeVersions, VersionCodec};
+#[cfg(feature = "wasmtime")]
+use crate::dds::WasmPlugin;
+use crate::dds::{
+    DomainState, EndpointKind, Error, GroupData, MatchKey, Partition, Publisher, QoS, Result,
+    TypeId, DDS,
+};
 use crate::protocol::builder::RtpsEndpointContext;
+use crate::protocol::checksum::ChecksumKind;
 use crate::reliability::{HeartbeatTx, HistoryCache, ReliableMetrics};
+use crate::telemetry::{EndpointMetrics, EndpointRole};
 #[cfg(target_os = "linux")]
 use crate::transport::shm::ShmPolicy;
 use crate::transport::UdpTransport;
@@ -207,6 +227,23 @@ pub struct WriterBuilder<T: DDS> {
     pub(super) shm_policy: ShmPolicy,
     /// Listener for writer callbacks
     pub(super) listener: Option<Arc<dyn DataWriterListener<T>>>,
+    /// Per-sample checksum added to DATA inline QoS
+    pub(super) sample_checksum: Option<ChecksumKind>,
+    /// Schema version of `T` (side-by-side type versions)
+    pub(super) type_version: Option<u32>,
+    /// Extra versions published alongside `T`
+    pub(super) published_versions: Vec<VersionCodec<EncodeAs<T>>>,
+    /// PARTITION inherited from the Publisher (see `WriterBuilder::publisher`)
+    pub(super) publisher_partition: Option<Partition>,
+    /// GROUP_DATA inherited from the Publisher
+    pub(super) publisher_group_data: Option<GroupData>,
+    /// ENTITY_NAME set with `WriterBuilder::name`
+    pub(super) entity_name: Option<String>,
+    /// Coherent sets of the Publisher
+    pub(super) coherent_group: Option<Arc<CoherentGroup>>,
+    /// WASM filter/transform run on written samples
+    #[cfg(feature = "wasmtime")]
+    pub(super) wasm_plugin: Option<Arc<WasmPlugin>>,
     pub(super) _phantom: core::marker::PhantomData<T>,
 }
 
@@ -226,6 +263,15 @@ impl<T: DDS> WriterBuilder<T> {
             #[cfg(target_os = "linux")]
             shm_policy: ShmPolicy::default(),
             listener: None,
+            sample_checksum: None,
+            type_version: None,
+            published_versions: Vec::new(),
+            publisher_partition: None,
+            publisher_group_data: None,
+            entity_name: None,
+            coherent_group: None,
+            #[cfg(feature = "wasmtime")]
+            wasm_plugin: None,
             _phantom: core::marker::PhantomData,
         }
     }
@@ -240,6 +286,14 @@ impl<T: DDS> WriterBuilder<T> {
         self
     }
 
+    /// Name the writer in discovery (ENTITY_NAME, see [`QoS::entity_name`]).
+    ///
+    /// Takes precedence over the name set in the QoS.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.entity_name = Some(name.into());
+        self
+    }
+
     /// Set SHM transport policy (Linux only).
     ///
     /// - `Prefer` (default): Use SHM if same-host + BestEffort, fallback to UDP
@@ -251,6 +305,67 @@ impl<T: DDS> WriterBuilder<T> {
         self
     }
 
+    /// Create the writer as part of `publisher`.
+    ///
+    /// The writer inherits the publisher's PARTITION and GROUP_DATA unless its
+    /// own QoS sets them, and its samples join the publisher's coherent sets (see
+    /// [`Publisher::begin_coherent_changes`]).
+    pub fn publisher(mut self, publisher: &Publisher) -> Self {
+        self.publisher_partition = Some(publisher.qos().partition.clone());
+        self.publisher_group_data = Some(publisher.qos().group_data.clone());
+        self.coherent_group = publisher.coherent_group().cloned();
+        self
+    }
+
+    /// Attach a checksum of each serialized sample to outgoing DATA.
+    ///
+    /// HDDS readers verify it and drop corrupted samples, counting them in
+    /// `RouterMetrics::checksum_failures` and the telemetry
+    /// `TAG_CHECKSUM_FAILURES` counter. Guards against corruption that slips
+    /// past UDP/NIC checksums. Fragmented samples (> 8 KB) are not covered.
+    pub fn sample_checksum(mut self, kind: ChecksumKind) -> Self {
+        self.sample_checksum = Some(kind);
+        self
+    }
+
+    /// Declare the schema version of `T`.
+    ///
+    /// Samples are tagged with it on the wire and the writer's SEDP
+    /// announcement lists every published version, so versioned readers
+    /// pick the highest version both sides support. Use the schema
+    /// registry's subject version numbers.
+    pub fn type_version(mut self, version: u32) -> Self {
+        self.type_version = Some(version);
+        self
+    }
+
+    /// Also publish each sample as `version` of type `V`, converted with
+    /// `convert`, so readers still on `V` keep working during a rolling
+    /// upgrade. Requires [`type_version`](Self::type_version).
+    ///
+    /// Each extra version costs one more DATA per write. Fragmented
+    /// samples (> 8 KB) and retransmissions carry the native version only.
+    pub fn publish_type_version<V: DDS>(
+        mut self,
+        version: u32,
+        convert: impl Fn(&T) -> V + Send + Sync + 'static,
+    ) -> Self {
+        self.published_versions
+            .push(type_versions::encode_as(version, convert));
+        self
+    }
+
+    /// Run `plugin` on every sample before it is published.
+    ///
+    /// The plugin sees the serialized payload and may drop or rewrite it
+    /// (see [`WasmPlugin`]); a dropped sample uses no sequence number.
+    /// Dispose/unregister and zero-copy loans bypass it.
+    #[cfg(feature = "wasmtime")]
+    pub fn wasm_plugin(mut self, plugin: Arc<WasmPlugin>) -> Self {
+        self.wasm_plugin = Some(plugin);
+        self
+    }
+
     pub fn with_transport(mut self, transport: Arc<UdpTransport>) -> Self {
         self.transport = Some(transport);
         self
@@ -319,6 +434,20 @@ impl<T: DDS> WriterBuilder<T> {
     }
 
     pub fn build(mut self) -> Result<DataWriter<T>> {
+        if let Some(partition) = self.publisher_partition.take() {
+            if self.qos.partition.is_default() {
+                self.qos.partition = partition;
+            }
+        }
+        if let Some(group_data) = self.publisher_group_data.take() {
+            if self.qos.group_data.is_empty() {
+                self.qos.group_data = group_data;
+            }
+        }
+        if let Some(name) = self.entity_name.take() {
+            self.qos.entity_name = Some(name);
+        }
+
         // Extract configs from participant if not explicitly provided
         if let Some(ref participant) = self.participant {
             if self.transport.is_none() {
@@ -346,6 +475,20 @@ impl<T: DDS> WriterBuilder<T> {
             }
         }
 
+        // Endpoint hooks: may adjust the QoS or reject the writer
+        if let Some(ref participant) = self.participant {
+            let type_name = self
+                .type_name_override
+                .as_deref()
+                .unwrap_or(T::type_descriptor().type_name);
+            participant.run_endpoint_hooks(
+                EndpointKind::Writer,
+                &self.topic,
+                type_name,
+                &mut self.qos,
+            )?;
+        }
+
         // DDS Security: Check access control permissions for writer creation
         #[cfg(feature = "security")]
         if let Some(ref participant) = self.participant {
@@ -353,7 +496,22 @@ impl<T: DDS> WriterBuilder<T> {
             participant.check_create_writer(&self.topic, partition)?;
         }
 
+        // Static deployment: only declared writers, and none once sealed
+        if let Some(ref participant) = self.participant {
+            let type_name = self
+                .type_name_override
+                .as_deref()
+                .unwrap_or(T::type_descriptor().type_name);
+            participant.admit_static_endpoint(EndpointKind::Writer, &self.topic, type_name)?;
+        }
+
         let (history_policy, resource_limits) = derive_history_and_limits(&self.qos)?;
+        let type_versions = TypeVersions::from_builder(
+            &self.topic,
+            self.type_version,
+            std::mem::take(&mut self.published_versions),
+        )?;
+        let type_versions_announcement = type_versions.as_ref().map(TypeVersions::announcement);
 
         let history_cache = match (self.qos.reliability, self.qos.durability) {
             (super::super::qos::Reliability::Reliable, _) => {
@@ -427,9 +585,14 @@ impl<T: DDS> WriterBuilder<T> {
                     &self.qos,
                     type_name,
                     self.type_object_override.clone(),
+                    type_versions_announcement,
                 )?
             } else {
-                participant.announce_writer_endpoint::<T>(&self.topic, &self.qos)?
+                participant.announce_writer_endpoint::<T>(
+                    &self.topic,
+                    &self.qos,
+                    type_versions_announcement,
+                )?
             };
             let guid = participant.guid();
             // Default: use ENTITYID_UNKNOWN so that DATA is not tied to a
@@ -446,6 +609,131 @@ impl<T: DDS> WriterBuilder<T> {
             None
         };
 
+        // Traffic counters for the admin API, listed once the writer has a GUID
+        let endpoint_metrics = Arc::new(EndpointMetrics::new(
+            EndpointRole::Writer,
+            &self.topic,
+            reliable_metrics.clone(),
+        ));
+        let metrics_registration = rtps_endpoint
+            .map(|ctx| endpoint_metrics.register(GUID::new(ctx.guid_prefix, ctx.writer_entity_id)));
+
+        // Matched readers: same-process ones from the domain registry, remote
+        // ones from SEDP (see `DataWriter::publication_matched_status`)
+        let matches = {
+            let local = self.domain_state.as_ref().map(|domain_state| {
+                let type_name = self
+                    .type_name_override
+                    .as_deref()
+                    .unwrap_or(T::type_descriptor().type_name);
+                let key = MatchKey::new(self.topic.as_str(), TypeId::from_type_name(type_name));
+                (Arc::clone(domain_state), key)
+            });
+            let fsm = self
+                .participant
+                .as_ref()
+                .and_then(|p| p.discovery_fsm.clone());
+            // Our own readers are in SEDP too; the domain registry is
+            // authoritative for them since it drops them with the reader
+            let own_prefix = self.participant.as_ref().map(|p| p.guid().prefix);
+            let topic = self.topic.clone();
+            let reliability = self.qos.reliability;
+            let reliable = matches!(reliability, super::super::qos::Reliability::Reliable);
+            let partition = self.qos.partition.clone();
+            let writer_qos = self.qos.clone();
+            let remote_fsm = fsm.clone();
+            let readers = Box::new(move || {
+                let local_guids = local
+                    .as_ref()
+                    .map(|(domain_state, key)| {
+                        domain_state.find_reader_guids(key, reliability, &partition)
+                    })
+                    .unwrap_or_default();
+                let remote = remote_fsm
+                    .as_ref()
+                    .map(|fsm| {
+                        fsm.find_readers_for_topic(&topic)
+                            .into_iter()
+                            .filter(|reader| Some(reader.endpoint_guid.prefix) != own_prefix)
+                            .filter(|reader| partition.is_compatible_with(&reader.qos.partition))
+                            .filter(|reader| {
+                                Matcher::incompatible_policy(&reader.qos, &writer_qos).is_none()
+                            })
+                            .map(|reader| {
+                                let reader_reliable = matches!(
+                                    reader.qos.reliability,
+                                    super::super::qos::Reliability::Reliable
+                                );
+                                MatchedReader {
+                                    guid: reader.endpoint_guid,
+                                    needs_ack: reliable && reader_reliable,
+                                }
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                matched::merge_readers(local_guids, remote)
+            });
+            let on_change = self.listener.clone().map(|listener| {
+                Box::new(move |status| listener.on_publication_matched(status)) as MatchedCallback
+            });
+            let matches = Arc::new(PublicationMatches::new(readers, on_change));
+            if let Some(ref fsm) = fsm {
+                fsm.register_listener(Arc::new(MatchedDiscoveryListener::new(
+                    self.topic.clone(),
+                    &matches,
+                )));
+            }
+            matches
+        };
+
+        // OFFERED_INCOMPATIBLE_QOS and INCONSISTENT_TOPIC: check readers
+        // found so far and later ones
+        let incompatible_qos = self
+            .participant
+            .as_ref()
+            .and_then(|p| p.discovery_fsm.clone().map(|fsm| (p, fsm)))
+            .map(|(p, fsm)| {
+                let on_incompatible = self.listener.clone().map(|listener| {
+                    Box::new(move |_status, policy: QosPolicyId| {
+                        listener.on_offered_incompatible_qos(policy.id(), policy.name());
+                    }) as IncompatibleQosCallback
+                });
+                let on_inconsistent = self.listener.clone().map(|listener| {
+                    Box::new(move |status| listener.on_inconsistent_topic(status))
+                        as InconsistentTopicCallback
+                });
+                let mut types = vec![p.resolve_type_info::<T>(
+                    &self.topic,
+                    self.type_name_override.as_deref(),
+                    self.type_object_override.clone(),
+                )];
+                if let Some(ref versions) = type_versions {
+                    types.extend(
+                        versions
+                            .announcement()
+                            .versions
+                            .into_iter()
+                            .map(|v| (v.type_name, v.type_object)),
+                    );
+                }
+                let watch = Arc::new(
+                    IncompatibleQosWatch::new(
+                        self.topic.clone(),
+                        crate::core::discovery::multicast::EndpointKind::Writer,
+                        self.qos.clone(),
+                        on_incompatible,
+                        None,
+                    )
+                    .with_types(types, on_inconsistent),
+                );
+                fsm.register_listener(Arc::new(IncompatibleQosListener::new(&watch)));
+                for reader in fsm.find_readers_for_topic(&self.topic) {
+                    watch.check(&reader);
+                }
+                watch
+            });
+
         let next_seq = 1u64;
         if let (Some(ref cache), Some(ref transport), Some(ref registry), Some(ref metrics)) = (
             &history_cache,
@@ -459,7 +747,9 @@ impl<T: DDS> WriterBuilder<T> {
                 cache.clone(),
                 transport.clone(),
                 metrics.clone(),
+                Arc::clone(&endpoint_metrics),
                 rtps_endpoint,
+                Arc::clone(&matches),
             ));
             registry.register_nack_handler(handler);
 
@@ -486,7 +776,13 @@ impl<T: DDS> WriterBuilder<T> {
 
             if needs_late_joiner {
                 let slab_pool = rt::get_slab_pool();
-                Arc::new(rt::TopicMerger::with_history(cache.clone(), slab_pool))
+                let writer_guid = rtps_endpoint
+                    .as_ref()
+                    .map(|ctx| GUID::new(ctx.guid_prefix, ctx.writer_entity_id));
+                Arc::new(
+                    rt::TopicMerger::with_history(cache.clone(), slab_pool)
+                        .with_writer(writer_guid),
+                )
             } else {
                 Arc::new(rt::TopicMerger::new())
             }
@@ -494,6 +790,12 @@ impl<T: DDS> WriterBuilder<T> {
             Arc::new(rt::TopicMerger::new())
         };
 
+        let clock = self
+            .participant
+            .as_ref()
+            .map_or_else(SystemClock::shared, |p| p.clock());
+        let liveliness = Arc::new(WriterLiveliness::with_clock(&self.qos.liveliness, clock));
+
         // Register writer in domain state for intra-process auto-binding
         let bind_token = if let Some(ref domain_state) = self.domain_state {
             let type_name = self
@@ -517,7 +819,14 @@ impl<T: DDS> WriterBuilder<T> {
                 type_name
             );
 
-            Some(domain_state.register_writer(key, guid, merger.clone(), self.qos.reliability))
+            Some(domain_state.register_writer_with_liveliness(
+                key,
+                guid,
+                merger.clone(),
+                self.qos.reliability,
+                self.qos.partition.clone(),
+                Arc::clone(&liveliness),
+            ))
         } else {
             None
         };
@@ -556,9 +865,12 @@ impl<T: DDS> WriterBuilder<T> {
             None
         };
 
-        // Get security plugin from participant if available
+        // Data key of the topic if governance protects it
         #[cfg(feature = "security")]
-        let security = self.participant.as_ref().and_then(|p| p.security());
+        let protection = match self.participant {
+            Some(ref participant) => participant.topic_protection(&self.topic)?,
+            None => None,
+        };
 
         // Spawn periodic heartbeat scheduler thread for RELIABLE writers
         // This ensures HEARTBEAT messages are sent even when the writer is idle,
@@ -585,23 +897,129 @@ impl<T: DDS> WriterBuilder<T> {
             _ => None,
         };
 
+        // Idle-writer hibernation: the participant's sweep parks the
+        // heartbeat thread and trims the history of writers left idle
+        let next_seq = Arc::new(AtomicU64::new(next_seq));
+        let hibernator = self.participant.as_ref().and_then(|p| p.hibernator());
+        let hibernation = match (hibernator, &history_cache) {
+            (Some(hibernator), Some(cache)) => {
+                let heartbeat = heartbeat_scheduler.as_ref().map(|h| Arc::clone(h.state()));
+                let fsm = self
+                    .participant
+                    .as_ref()
+                    .and_then(|p| p.discovery_fsm.clone());
+                if let (Some(heartbeat), Some(fsm)) = (&heartbeat, fsm) {
+                    fsm.register_listener(Arc::new(HeartbeatWakeListener::new(
+                        self.topic.clone(),
+                        heartbeat,
+                    )));
+                }
+                let hibernation: Arc<dyn crate::dds::Hibernate> = Arc::new(WriterHibernation::new(
+                    Arc::clone(&next_seq),
+                    Arc::clone(cache),
+                    heartbeat,
+                    Arc::clone(&matches),
+                ));
+                hibernator.register(crate::dds::EndpointKind::Writer, &hibernation);
+                Some(hibernation)
+            }
+            _ => None,
+        };
+
+        // LIVELINESS: announce assertions to remote readers, and assert
+        // automatically or watch the lease when it is finite
+        let liveliness_announcer = match (&self.transport, rtps_endpoint) {
+            (Some(transport), Some(ctx)) => Some(Arc::new(LivelinessAnnouncer::new(
+                transport.clone(),
+                ctx,
+                history_cache.clone(),
+            ))),
+            _ => None,
+        };
+        let on_liveliness_lost = self.listener.clone().map(|listener| {
+            Box::new(move || listener.on_liveliness_lost()) as Box<dyn Fn() + Send>
+        });
+        let liveliness_scheduler = spawn_liveliness_scheduler(
+            self.topic.clone(),
+            Arc::clone(&liveliness),
+            liveliness_announcer.clone(),
+            on_liveliness_lost,
+        );
+
+        // Zero-copy loans need a writer GUID (SHM segment name) and
+        // BestEffort QoS: SHM has no retransmission.
+        #[cfg(target_os = "linux")]
+        let shm_loans = match (&self.domain_state, rtps_endpoint) {
+            (Some(domain_state), Some(ctx))
+                if self.shm_policy != ShmPolicy::Disable
+                    && self.participant.as_ref().is_none_or(|p| p.shm_enabled())
+                    && matches!(
+                        self.qos.reliability,
+                        super::super::qos::Reliability::BestEffort
+                    ) =>
+            {
+                Some(super::loan::ShmLoanWriter::new(
+                    domain_state.domain_id,
+                    GUID::new(ctx.guid_prefix, ctx.writer_entity_id),
+                ))
+            }
+            _ => None,
+        };
+
+        // Batching: small samples share RTPS messages (see `QoS::batching`)
+        let batch = match (&self.transport, rtps_endpoint) {
+            (Some(transport), Some(ctx)) if self.qos.batching.is_enabled() => {
+                let transport = Arc::clone(transport);
+                let registry = self.endpoint_registry.clone();
+                Some(super::batch::WriterBatch::spawn(
+                    self.qos.batching,
+                    Box::new(move |message| {
+                        super::runtime::send_to_endpoints(
+                            &transport,
+                            registry.as_ref(),
+                            Some(ctx.guid_prefix),
+                            message,
+                        )
+                    }),
+                ))
+            }
+            _ => None,
+        };
+
         Ok(DataWriter {
             topic: self.topic,
             qos: self.qos,
             rtps_endpoint,
             merger,
             transport: self.transport,
-            next_seq: AtomicU64::new(next_seq),
+            next_seq,
             history_cache,
             reliable_metrics,
+            endpoint_metrics,
+            _metrics_registration: metrics_registration,
             heartbeat_tx,
             _heartbeat_scheduler: heartbeat_scheduler,
+            _hibernation: hibernation,
             endpoint_registry: self.endpoint_registry,
             _bind_token: bind_token,
             _replay_token: replay_token,
             listener: self.listener,
+            sample_checksum: self.sample_checksum,
+            type_versions,
+            batch,
+            instances: parking_lot::Mutex::new(std::collections::HashSet::new()),
+            coherent: self.coherent_group,
+            matches,
+            incompatible_qos,
+            liveliness,
+            liveliness_announcer,
+            _liveliness_scheduler: liveliness_scheduler,
+            #[cfg(target_os = "linux")]
+            shm_loans,
+            #[cfg(feature = "wasmtime")]
+            wasm_plugin: self.wasm_plugin,
             #[cfg(feature = "security")]
-            security,
+            protection,
             _phantom: core::marker::PhantomData,
         })
     }
@@ -31,7 +31,7 @@ use crate::protocol::builder::{self, RtpsEndpointContext};
 use crate::reliability::HistoryCache;
 use crate::transport::UdpTransport;
 use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
 use std::thread::{self, JoinHandle};
 use std::time::Duration;
 
@@ -47,6 +47,11 @@ pub struct HeartbeatSchedulerState {
     pub stop: AtomicBool,
     /// Heartbeat counter (monotonically increasing per RTPS spec)
     pub count: AtomicU32,
+    /// Parked while the writer hibernates (see `crate::dds::HibernationConfig`)
+    hibernating: AtomicBool,
+    /// Wakes the thread on stop or reactivation
+    wake_lock: Mutex<()>,
+    wake: Condvar,
 }
 
 impl HeartbeatSchedulerState {
@@ -56,17 +61,62 @@ impl HeartbeatSchedulerState {
             last_seq: AtomicU64::new(0),
             stop: AtomicBool::new(false),
             count: AtomicU32::new(1),
+            hibernating: AtomicBool::new(false),
+            wake_lock: Mutex::new(()),
+            wake: Condvar::new(),
         }
     }
 
+    fn lock_wake(&self) -> MutexGuard<'_, ()> {
+        self.wake_lock.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
     /// Update the last sequence number (called by writer on each write).
+    ///
+    /// Reactivates a hibernating scheduler.
     pub fn update_seq(&self, seq: u64) {
         self.last_seq.store(seq, Ordering::Release);
+        if self.is_hibernating() {
+            self.set_hibernating(false);
+        }
     }
 
     /// Signal the thread to stop.
     pub fn signal_stop(&self) {
+        let _guard = self.lock_wake();
         self.stop.store(true, Ordering::Release);
+        self.wake.notify_all();
+    }
+
+    /// Park the thread until reactivated (`true`), or wake it (`false`).
+    ///
+    /// A woken thread sends a HEARTBEAT right away.
+    pub fn set_hibernating(&self, hibernating: bool) {
+        let _guard = self.lock_wake();
+        self.hibernating.store(hibernating, Ordering::Release);
+        if !hibernating {
+            self.wake.notify_all();
+        }
+    }
+
+    /// Whether the thread is parked by hibernation.
+    pub fn is_hibernating(&self) -> bool {
+        self.hibernating.load(Ordering::Acquire)
+    }
+
+    /// Sleep for `period`, or until reactivated while hibernating.
+    fn sleep(&self, period: Duration) {
+        let mut guard = self.lock_wake();
+        if self.should_stop() {
+            return;
+        }
+        if self.is_hibernating() {
+            while self.is_hibernating() && !self.should_stop() {
+                guard = self.wake.wait(guard).unwrap_or_else(|e| e.into_inner());
+            }
+        } else {
+            let _ = self.wake.wait_timeout(guard, period);
+        }
     }
 
     /// Check if stop was signaled.
@@ -161,13 +211,14 @@ fn heartbeat_loop(
     state: Arc<HeartbeatSchedulerState>,
     period: Duration,
 ) {
+    crate::alloc_scope!(Reliability);
     log::debug!(
         "[heartbeat] Starting periodic heartbeat thread (period={:?})",
         period
     );
 
     while !state.should_stop() {
-        thread::sleep(period);
+        state.sleep(period);
 
         if state.should_stop() {
             break;
@@ -226,6 +277,20 @@ mod tests {
         assert!(state.should_stop());
     }
 
+    #[test]
+    fn test_hibernation_parks_until_write() {
+        let state = Arc::new(HeartbeatSchedulerState::new());
+        state.set_hibernating(true);
+        let sleeper = Arc::clone(&state);
+        let thread = thread::spawn(move || sleeper.sleep(Duration::from_millis(1)));
+
+        thread::sleep(Duration::from_millis(50));
+        assert!(!thread.is_finished());
+        state.update_seq(7);
+        assert!(!state.is_hibernating());
+        thread.join().expect("join");
+    }
+
     #[test]
     fn test_state_count_increment() {
         let state = HeartbeatSchedulerState::new();
@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Hibernation of an idle writer (see [`crate::dds::HibernationConfig`]).
+//!
+//! A writer hibernates once every matched reliable reader acknowledged its
+//! last sample: the periodic HEARTBEAT thread parks and the history cache
+//! drops its unused capacity. `write()` wakes the HEARTBEAT thread right
+//! away, and so does the discovery of a reader on the writer's topic, so a
+//! late joiner learns the history it can request.
+
+use super::heartbeat_scheduler::HeartbeatSchedulerState;
+use super::matched::PublicationMatches;
+use crate::core::discovery::multicast::{DiscoveryListener, EndpointInfo, EndpointKind};
+use crate::dds::Hibernate;
+use crate::reliability::HistoryCache;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Weak};
+
+/// Hibernation hooks of one writer.
+pub(super) struct WriterHibernation {
+    /// The writer's next sequence number, advanced by every write
+    next_seq: Arc<AtomicU64>,
+    history: Arc<HistoryCache>,
+    heartbeat: Option<Arc<HeartbeatSchedulerState>>,
+    matches: Arc<PublicationMatches>,
+    hibernating: AtomicBool,
+}
+
+impl WriterHibernation {
+    pub(super) fn new(
+        next_seq: Arc<AtomicU64>,
+        history: Arc<HistoryCache>,
+        heartbeat: Option<Arc<HeartbeatSchedulerState>>,
+        matches: Arc<PublicationMatches>,
+    ) -> Self {
+        Self {
+            next_seq,
+            history,
+            heartbeat,
+            matches,
+            hibernating: AtomicBool::new(false),
+        }
+    }
+}
+
+impl Hibernate for WriterHibernation {
+    fn activity(&self) -> u64 {
+        self.next_seq.load(Ordering::Relaxed)
+    }
+
+    fn hibernate(&self) -> bool {
+        let last_seq = self.activity().saturating_sub(1);
+        if !self.matches.all_acked(last_seq) {
+            return false;
+        }
+        if let Some(heartbeat) = &self.heartbeat {
+            heartbeat.set_hibernating(true);
+        }
+        self.hibernating.store(true, Ordering::Release);
+        self.history.shrink_to_fit();
+        true
+    }
+
+    fn reactivate(&self) {
+        self.hibernating.store(false, Ordering::Release);
+        if let Some(heartbeat) = &self.heartbeat {
+            heartbeat.set_hibernating(false);
+        }
+    }
+
+    fn is_hibernating(&self) -> bool {
+        self.hibernating.load(Ordering::Acquire)
+            && self
+                .heartbeat
+                .as_ref()
+                .is_none_or(|heartbeat| heartbeat.is_hibernating())
+    }
+}
+
+/// Wakes a hibernating writer's HEARTBEAT thread when SEDP discovers a
+/// reader on its topic.
+pub(super) struct HeartbeatWakeListener {
+    topic: String,
+    heartbeat: Weak<HeartbeatSchedulerState>,
+}
+
+impl HeartbeatWakeListener {
+    pub(super) fn new(topic: String, heartbeat: &Arc<HeartbeatSchedulerState>) -> Self {
+        Self {
+            topic,
+            heartbeat: Arc::downgrade(heartbeat),
+        }
+    }
+}
+
+impl DiscoveryListener for HeartbeatWakeListener {
+    fn on_endpoint_discovered(&self, endpoint: EndpointInfo) {
+        if endpoint.kind != EndpointKind::Reader || endpoint.topic_name != self.topic {
+            return;
+        }
+        if let Some(heartbeat) = self.heartbeat.upgrade() {
+            if heartbeat.is_hibernating() {
+                heartbeat.set_hibernating(false);
+            }
+        }
+    }
+}
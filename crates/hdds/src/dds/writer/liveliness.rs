@@ -0,0 +1,220 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! LIVELINESS assertions of a writer (DDS v1.4 Sec.2.2.3.11).
+//!
+//! Writers with a finite lease run a thread that wakes every third of the
+//! lease. AUTOMATIC writers assert liveliness on each wake-up; MANUAL
+//! writers only announce the assertions made by `write()` and
+//! `assert_liveliness()` since the previous wake-up, and report
+//! LIVELINESS_LOST to their listener when the lease expires without one.
+//!
+//! Remote readers learn about assertions from liveliness HEARTBEATs
+//! (LivelinessFlag set); readers in the same process check the shared
+//! [`WriterLiveliness`] directly.
+
+use crate::dds::liveliness::WriterLiveliness;
+use crate::dds::qos::LivelinessKind;
+use crate::protocol::builder::{self, RtpsEndpointContext};
+use crate::reliability::HistoryCache;
+use crate::transport::UdpTransport;
+use std::io;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Shortest period between two liveliness checks.
+const MIN_LIVELINESS_PERIOD: Duration = Duration::from_millis(1);
+
+/// Sends liveliness HEARTBEATs for one writer.
+pub(super) struct LivelinessAnnouncer {
+    transport: Arc<UdpTransport>,
+    ctx: RtpsEndpointContext,
+    history_cache: Option<Arc<HistoryCache>>,
+    count: AtomicU32,
+}
+
+impl LivelinessAnnouncer {
+    pub(super) fn new(
+        transport: Arc<UdpTransport>,
+        ctx: RtpsEndpointContext,
+        history_cache: Option<Arc<HistoryCache>>,
+    ) -> Self {
+        Self {
+            transport,
+            ctx,
+            history_cache,
+            count: AtomicU32::new(1),
+        }
+    }
+
+    /// Send one liveliness HEARTBEAT.
+    pub(super) fn announce(&self) -> io::Result<()> {
+        let (first_seq, last_seq) = self.history_cache.as_ref().map_or((1, 0), |cache| {
+            (
+                cache.oldest_seq().unwrap_or(1),
+                cache.newest_seq().unwrap_or(0),
+            )
+        });
+        let count = self.count.fetch_add(1, Ordering::Relaxed);
+        let packet =
+            builder::build_liveliness_heartbeat_packet(&self.ctx, first_seq, last_seq, count);
+        self.transport.send(&packet)
+    }
+}
+
+/// Handle to a writer's liveliness thread; stops it when dropped.
+pub(crate) struct LivelinessSchedulerHandle {
+    stop: Arc<(Mutex<bool>, Condvar)>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for LivelinessSchedulerHandle {
+    fn drop(&mut self) {
+        let (stopped, wake) = &*self.stop;
+        *stopped.lock().unwrap_or_else(|e| e.into_inner()) = true;
+        wake.notify_all();
+        if let Some(handle) = self.thread.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Spawn the liveliness thread of a writer, or `None` when its lease is
+/// infinite.
+///
+/// `on_lost` is called when a MANUAL writer lets its lease expire.
+pub(super) fn spawn_liveliness_scheduler(
+    topic: String,
+    liveliness: Arc<WriterLiveliness>,
+    announcer: Option<Arc<LivelinessAnnouncer>>,
+    on_lost: Option<Box<dyn Fn() + Send>>,
+) -> Option<LivelinessSchedulerHandle> {
+    let period = (liveliness.lease()? / 3).max(MIN_LIVELINESS_PERIOD);
+    let stop = Arc::new((Mutex::new(false), Condvar::new()));
+    let thread_stop = Arc::clone(&stop);
+
+    #[allow(clippy::expect_used)] // thread spawn failure is unrecoverable
+    let thread = thread::Builder::new()
+        .name("hdds-liveliness".into())
+        .spawn(move || {
+            liveliness_loop(
+                &topic,
+                &liveliness,
+                announcer.as_deref(),
+                on_lost,
+                &thread_stop,
+                period,
+            );
+        })
+        .expect("failed to spawn liveliness thread");
+
+    Some(LivelinessSchedulerHandle {
+        stop,
+        thread: Some(thread),
+    })
+}
+
+fn liveliness_loop(
+    topic: &str,
+    liveliness: &WriterLiveliness,
+    announcer: Option<&LivelinessAnnouncer>,
+    on_lost: Option<Box<dyn Fn() + Send>>,
+    stop: &(Mutex<bool>, Condvar),
+    period: Duration,
+) {
+    log::debug!(
+        "[liveliness] Starting writer liveliness thread topic='{}' kind={:?} period={:?}",
+        topic,
+        liveliness.kind(),
+        period
+    );
+
+    let (stopped, wake) = stop;
+    let mut lost = false;
+    loop {
+        let guard = stopped.lock().unwrap_or_else(|e| e.into_inner());
+        let (guard, _) = wake
+            .wait_timeout_while(guard, period, |stopped| !*stopped)
+            .unwrap_or_else(|e| e.into_inner());
+        if *guard {
+            break;
+        }
+        drop(guard);
+
+        if liveliness.kind() == LivelinessKind::Automatic {
+            liveliness.assert();
+        }
+        if liveliness.take_pending() {
+            if let Some(announcer) = announcer {
+                if let Err(e) = announcer.announce() {
+                    log::debug!("[liveliness] Failed to send liveliness HEARTBEAT: {}", e);
+                }
+            }
+        }
+
+        let alive = liveliness.is_alive();
+        if !alive && !lost {
+            log::debug!("[liveliness] Writer on topic '{}' lost liveliness", topic);
+            if let Some(ref on_lost) = on_lost {
+                on_lost();
+            }
+        }
+        lost = !alive;
+    }
+
+    log::debug!("[liveliness] Writer liveliness thread stopped");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dds::qos::Liveliness;
+    use std::sync::atomic::AtomicUsize;
+
+    #[test]
+    fn test_infinite_lease_has_no_thread() {
+        let liveliness = Arc::new(WriterLiveliness::new(&Liveliness::infinite()));
+        assert!(spawn_liveliness_scheduler("t".into(), liveliness, None, None).is_none());
+    }
+
+    #[test]
+    fn test_manual_writer_reports_lost_once() {
+        let liveliness = Arc::new(WriterLiveliness::new(&Liveliness::manual_by_topic(
+            Duration::from_millis(30),
+        )));
+        let lost = Arc::new(AtomicUsize::new(0));
+        let lost_count = Arc::clone(&lost);
+        let handle = spawn_liveliness_scheduler(
+            "t".into(),
+            Arc::clone(&liveliness),
+            None,
+            Some(Box::new(move || {
+                lost_count.fetch_add(1, Ordering::Relaxed);
+            })),
+        )
+        .expect("finite lease");
+
+        thread::sleep(Duration::from_millis(150));
+        assert_eq!(lost.load(Ordering::Relaxed), 1);
+
+        // Asserting again makes the writer alive until the lease expires anew
+        liveliness.assert();
+        thread::sleep(Duration::from_millis(150));
+        drop(handle);
+        assert_eq!(lost.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn test_automatic_writer_stays_alive() {
+        let liveliness = Arc::new(WriterLiveliness::new(&Liveliness::automatic(
+            Duration::from_millis(150),
+        )));
+        let handle = spawn_liveliness_scheduler("t".into(), Arc::clone(&liveliness), None, None)
+            .expect("finite lease");
+        thread::sleep(Duration::from_millis(400));
+        assert!(liveliness.is_alive());
+        drop(handle);
+    }
+}
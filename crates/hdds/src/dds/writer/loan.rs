@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Zero-copy loaned samples over the shared-memory transport.
+//!
+//! [`DataWriter::loan_sample`] hands out the next slot of the writer's SHM
+//! ring so a sample is serialized (or filled) in place, with no
+//! intermediate `Vec`. Same-host readers borrow it back with
+//! [`DataReader::take_loaned`](crate::DataReader::take_loaned).
+//!
+//! Loaned samples travel over SHM only: readers using `take()` and remote
+//! (UDP) readers do not receive them. The ring is created on the first loan.
+
+use super::DataWriter;
+use crate::core::discovery::GUID;
+use crate::dds::{Error, Result, DDS};
+use crate::transport::shm::ShmWriterTransport;
+use parking_lot::{MappedMutexGuard, Mutex, MutexGuard};
+use std::ops::{Deref, DerefMut};
+
+/// Writer-side SHM ring used for loans, created on first use.
+pub(crate) struct ShmLoanWriter {
+    domain_id: u32,
+    guid: GUID,
+    transport: Mutex<Option<ShmWriterTransport>>,
+}
+
+impl ShmLoanWriter {
+    pub(crate) fn new(domain_id: u32, guid: GUID) -> Self {
+        Self {
+            domain_id,
+            guid,
+            transport: Mutex::new(None),
+        }
+    }
+
+    fn transport(&self, topic: &str) -> Result<MappedMutexGuard<'_, ShmWriterTransport>> {
+        let mut guard = self.transport.lock();
+        if guard.is_none() {
+            let transport = ShmWriterTransport::new(self.domain_id, self.guid, topic)?;
+            log::debug!(
+                "[writer] SHM loan ring created topic='{}' segment='{}'",
+                topic,
+                transport.segment_name()
+            );
+            *guard = Some(transport);
+        }
+        MutexGuard::try_map(guard, Option::as_mut)
+            .map_err(|_| Error::InvalidState("SHM loan ring unavailable".to_string()))
+    }
+}
+
+impl<T: DDS> DataWriter<T> {
+    /// Loan a `len`-byte sample buffer located directly in shared memory.
+    ///
+    /// Serialize into it with [`WriteLoan::encode`] (or fill the bytes
+    /// directly) and publish with [`WriteLoan::commit`]; dropping the loan
+    /// discards it. Only one loan per writer can be outstanding.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::UnsupportedOperation` if the writer has no SHM path (SHM disabled,
+    ///   Reliable QoS, or no participant)
+    /// - `Error::BufferTooSmall` if `len` exceeds the SHM slot size
+    /// - `Error::WouldBlock` if a reader still holds the next slot
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use hdds::{Participant, QoS, Result};
+    ///
+    /// # #[derive(hdds::DDS)] struct Frame { pixels: Vec<u8> }
+    /// fn main() -> Result<()> {
+    ///     let participant = Participant::builder("camera").build()?;
+    ///     let writer = participant.create_writer::<Frame>("frames", QoS::best_effort())?;
+    ///
+    ///     let frame = Frame { pixels: vec![0; 3000] };
+    ///     let mut loan = writer.loan_sample(4000)?;
+    ///     loan.encode(&frame)?;
+    ///     loan.commit()
+    /// }
+    /// ```
+    pub fn loan_sample(&self, len: usize) -> Result<WriteLoan<'_, T>> {
+        let shm = self.shm_loans.as_ref().ok_or_else(|| {
+            Error::unsupported("loaned write without a shared-memory path")
+                .with_context(self.error_context())
+        })?;
+        let mut transport = shm.transport(&self.topic)?;
+        transport.loan(len)?;
+        Ok(WriteLoan {
+            transport,
+            len,
+            _phantom: core::marker::PhantomData,
+        })
+    }
+}
+
+/// Sample buffer loaned from the writer's shared-memory ring.
+///
+/// Dereferences to the loaned bytes. Nothing is visible to readers until
+/// [`commit`](Self::commit); dropping the loan discards it.
+pub struct WriteLoan<'a, T: DDS> {
+    transport: MappedMutexGuard<'a, ShmWriterTransport>,
+    len: usize,
+    _phantom: core::marker::PhantomData<T>,
+}
+
+impl<T: DDS> WriteLoan<'_, T> {
+    /// Serialize `msg` (CDR2) into the loan and shrink it to the encoded size.
+    pub fn encode(&mut self, msg: &T) -> Result<()> {
+        let len = msg.encode_cdr2(self)?;
+        self.truncate(len);
+        Ok(())
+    }
+
+    /// Publish only the first `len` bytes.
+    pub fn truncate(&mut self, len: usize) {
+        self.len = self.len.min(len);
+    }
+
+    /// Publish the sample to attached readers.
+    pub fn commit(mut self) -> Result<()> {
+        if self.transport.commit_loan(self.len) {
+            Ok(())
+        } else {
+            Err(Error::InvalidState("SHM loan already released".to_string()))
+        }
+    }
+}
+
+impl<T: DDS> Deref for WriteLoan<'_, T> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        let buf = self.transport.loaned().unwrap_or_default();
+        &buf[..self.len.min(buf.len())]
+    }
+}
+
+impl<T: DDS> DerefMut for WriteLoan<'_, T> {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        let len = self.len;
+        let buf = self.transport.loaned_mut().unwrap_or_default();
+        let len = len.min(buf.len());
+        &mut buf[..len]
+    }
+}
+
+impl<T: DDS> Drop for WriteLoan<'_, T> {
+    fn drop(&mut self) {
+        // No-op after commit()
+        self.transport.abort_loan();
+    }
+}
@@ -0,0 +1,355 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Publication matched status and acknowledgment tracking.
+//!
+//! [`PublicationMatches`] keeps the set of readers a writer is matched with
+//! (same-process readers from the domain registry, remote readers from
+//! SEDP) and the highest sequence number each remote reliable reader has
+//! acknowledged. Same-process readers receive samples synchronously during
+//! `write()`, so they never need to acknowledge.
+//!
+//! The matched set is refreshed when SEDP discovers a reader on the topic,
+//! when the number of bound same-process readers changes (checked on
+//! `write()`), and whenever the status is queried.
+
+use crate::core::discovery::multicast::{DiscoveryListener, EndpointInfo, EndpointKind};
+use crate::core::discovery::GUID;
+use crate::dds::listener::PublicationMatchedStatus;
+use crate::dds::{Error, Result};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, Weak};
+use std::time::{Duration, Instant};
+
+/// Longest wait between two refreshes of the matched set while waiting for
+/// acknowledgments, so readers that went away stop being waited for.
+const ACK_RECHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Reader matched with a writer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct MatchedReader {
+    pub(crate) guid: GUID,
+    /// Remote reliable reader: samples count as delivered once it ACKs them.
+    pub(crate) needs_ack: bool,
+}
+
+/// Readers currently matched with the writer.
+pub(crate) type MatchedReaders = Box<dyn Fn() -> Vec<MatchedReader> + Send + Sync>;
+
+/// Listener callback for matched status changes.
+pub(crate) type MatchedCallback = Box<dyn Fn(PublicationMatchedStatus) + Send + Sync>;
+
+/// Matched readers and acknowledgments of one writer.
+pub(crate) struct PublicationMatches {
+    readers: MatchedReaders,
+    on_change: Option<MatchedCallback>,
+    state: Mutex<MatchedState>,
+    acked: Condvar,
+    /// Same-process reader count at the last refresh triggered by `write()`.
+    local_readers: AtomicUsize,
+}
+
+#[derive(Default)]
+struct MatchedState {
+    matched: HashMap<GUID, bool>,
+    status: PublicationMatchedStatus,
+    /// Highest sequence number acknowledged, per remote reader.
+    acks: HashMap<GUID, u64>,
+}
+
+impl MatchedState {
+    /// Matched remote reliable readers that did not acknowledge `last_seq`.
+    fn pending_acks(&self, last_seq: u64) -> usize {
+        self.matched
+            .iter()
+            .filter(|(guid, needs_ack)| {
+                **needs_ack && self.acks.get(guid).copied().unwrap_or(0) < last_seq
+            })
+            .count()
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+impl PublicationMatches {
+    pub(crate) fn new(readers: MatchedReaders, on_change: Option<MatchedCallback>) -> Self {
+        Self {
+            readers,
+            on_change,
+            state: Mutex::new(MatchedState::default()),
+            acked: Condvar::new(),
+            local_readers: AtomicUsize::new(0),
+        }
+    }
+
+    /// Current status; resets the change counters.
+    pub(crate) fn status(&self) -> PublicationMatchedStatus {
+        self.refresh();
+        let mut state = lock(&self.state);
+        let status = state.status.clone();
+        state.status.total_count_change = 0;
+        state.status.current_count_change = 0;
+        status
+    }
+
+    /// Number of readers currently matched.
+    pub(crate) fn matched_count(&self) -> usize {
+        self.refresh();
+        lock(&self.state).matched.len()
+    }
+
+    /// Refresh if the number of bound same-process readers changed.
+    pub(crate) fn on_write(&self, local_readers: usize) {
+        if self.local_readers.swap(local_readers, Ordering::Relaxed) != local_readers {
+            self.refresh();
+        }
+    }
+
+    /// Record an ACKNACK: `reader` received every sample before `base`.
+    ///
+    /// Returns whether the reader acknowledged new samples.
+    pub(crate) fn on_acknack(&self, reader: GUID, base: u64) -> bool {
+        let acked = base.saturating_sub(1);
+        let mut state = lock(&self.state);
+        let entry = state.acks.entry(reader).or_default();
+        if acked > *entry {
+            *entry = acked;
+            self.acked.notify_all();
+            return true;
+        }
+        false
+    }
+
+    /// Block until every matched remote reliable reader acknowledged
+    /// `last_seq`, or `timeout` elapses.
+    pub(crate) fn wait_for_acks(&self, last_seq: u64, timeout: Duration) -> Result<()> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            self.refresh();
+            let state = lock(&self.state);
+            let pending = state.pending_acks(last_seq);
+            if pending == 0 {
+                return Ok(());
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                log::debug!(
+                    "[writer] {} reader(s) did not acknowledge seq {} in {:?}",
+                    pending,
+                    last_seq,
+                    timeout
+                );
+                return Err(Error::WriteTimeout);
+            }
+            let wait = (deadline - now).min(ACK_RECHECK_INTERVAL);
+            drop(
+                self.acked
+                    .wait_timeout(state, wait)
+                    .unwrap_or_else(|e| e.into_inner()),
+            );
+        }
+    }
+
+    /// Whether every matched remote reliable reader acknowledged `last_seq`.
+    pub(crate) fn all_acked(&self, last_seq: u64) -> bool {
+        self.refresh();
+        lock(&self.state).pending_acks(last_seq) == 0
+    }
+
+    /// Recompute the matched set and report changes to the listener.
+    fn refresh(&self) {
+        let readers = (self.readers)();
+        let changed = {
+            let mut state = lock(&self.state);
+            let current: HashMap<GUID, bool> =
+                readers.iter().map(|r| (r.guid, r.needs_ack)).collect();
+            let added: Vec<GUID> = current
+                .keys()
+                .filter(|guid| !state.matched.contains_key(guid))
+                .copied()
+                .collect();
+            let removed: Vec<GUID> = state
+                .matched
+                .keys()
+                .filter(|guid| !current.contains_key(guid))
+                .copied()
+                .collect();
+            if added.is_empty() && removed.is_empty() {
+                state.matched = current;
+                return;
+            }
+
+            let status = &mut state.status;
+            status.total_count += added.len() as u32;
+            status.total_count_change += added.len() as i32;
+            status.current_count = current.len() as u32;
+            status.current_count_change += added.len() as i32 - removed.len() as i32;
+            status.last_subscription_handle = added.last().or(removed.last()).copied();
+            for guid in &removed {
+                state.acks.remove(guid);
+            }
+            state.matched = current;
+            log::debug!(
+                "[writer] matched readers: {} (+{} -{})",
+                state.matched.len(),
+                added.len(),
+                removed.len()
+            );
+
+            // The listener consumes the changes, like a status read
+            self.on_change.is_some().then(|| {
+                let status = state.status.clone();
+                state.status.total_count_change = 0;
+                state.status.current_count_change = 0;
+                status
+            })
+        };
+
+        if let (Some(callback), Some(status)) = (&self.on_change, changed) {
+            callback(status);
+        }
+        // Readers that went away no longer hold up waiters
+        self.acked.notify_all();
+    }
+}
+
+/// Refreshes the matched set when SEDP discovers or disposes a reader on
+/// the topic.
+pub(crate) struct MatchedDiscoveryListener {
+    topic: String,
+    matches: Weak<PublicationMatches>,
+}
+
+impl MatchedDiscoveryListener {
+    pub(crate) fn new(topic: String, matches: &Arc<PublicationMatches>) -> Self {
+        Self {
+            topic,
+            matches: Arc::downgrade(matches),
+        }
+    }
+}
+
+impl MatchedDiscoveryListener {
+    fn refresh_for(&self, endpoint: &EndpointInfo) {
+        if endpoint.kind != EndpointKind::Reader || endpoint.topic_name != self.topic {
+            return;
+        }
+        if let Some(matches) = self.matches.upgrade() {
+            matches.refresh();
+        }
+    }
+}
+
+impl DiscoveryListener for MatchedDiscoveryListener {
+    fn on_endpoint_discovered(&self, endpoint: EndpointInfo) {
+        self.refresh_for(&endpoint);
+    }
+
+    fn on_endpoint_lost(&self, endpoint: EndpointInfo) {
+        self.refresh_for(&endpoint);
+    }
+}
+
+/// Union of same-process and remote readers; a reader known both ways is
+/// served in-process and needs no acknowledgment.
+pub(crate) fn merge_readers(local: Vec<GUID>, remote: Vec<MatchedReader>) -> Vec<MatchedReader> {
+    let local_set: HashSet<GUID> = local.iter().copied().collect();
+    local
+        .into_iter()
+        .map(|guid| MatchedReader {
+            guid,
+            needs_ack: false,
+        })
+        .chain(remote.into_iter().filter(|r| !local_set.contains(&r.guid)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guid(n: u8) -> GUID {
+        GUID::new([n; 12], [0, 0, 0, 0x07])
+    }
+
+    fn matches(readers: Arc<Mutex<Vec<MatchedReader>>>) -> PublicationMatches {
+        PublicationMatches::new(Box::new(move || lock(&readers).clone()), None)
+    }
+
+    #[test]
+    fn test_status_counts_changes_since_last_read() {
+        let readers = Arc::new(Mutex::new(vec![MatchedReader {
+            guid: guid(1),
+            needs_ack: false,
+        }]));
+        let matches = matches(Arc::clone(&readers));
+
+        let status = matches.status();
+        assert_eq!(status.current_count, 1);
+        assert_eq!(status.current_count_change, 1);
+        assert_eq!(status.last_subscription_handle, Some(guid(1)));
+
+        lock(&readers).clear();
+        let status = matches.status();
+        assert_eq!(status.current_count, 0);
+        assert_eq!(status.current_count_change, -1);
+        assert_eq!(status.total_count, 1);
+        assert_eq!(status.total_count_change, 0);
+    }
+
+    #[test]
+    fn test_wait_for_acks_from_remote_reliable_readers() {
+        let readers = Arc::new(Mutex::new(vec![
+            MatchedReader {
+                guid: guid(1),
+                needs_ack: true,
+            },
+            MatchedReader {
+                guid: guid(2),
+                needs_ack: false,
+            },
+        ]));
+        let matches = Arc::new(matches(readers));
+
+        assert!(matches!(
+            matches.wait_for_acks(3, Duration::from_millis(20)),
+            Err(Error::WriteTimeout)
+        ));
+
+        let acker = Arc::clone(&matches);
+        let handle = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            acker.on_acknack(guid(1), 4);
+        });
+        matches
+            .wait_for_acks(3, Duration::from_secs(2))
+            .expect("acknowledged");
+        handle.join().expect("acker");
+    }
+
+    #[test]
+    fn test_local_reader_known_remotely_needs_no_ack() {
+        let merged = merge_readers(
+            vec![guid(1)],
+            vec![
+                MatchedReader {
+                    guid: guid(1),
+                    needs_ack: true,
+                },
+                MatchedReader {
+                    guid: guid(2),
+                    needs_ack: true,
+                },
+            ],
+        );
+        assert_eq!(merged.len(), 2);
+        assert!(merged.contains(&MatchedReader {
+            guid: guid(1),
+            needs_ack: false
+        }));
+    }
+}
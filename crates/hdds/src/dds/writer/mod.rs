@@ -59,13 +59,21 @@
 //! - [`QoS`](crate::QoS) - Quality of Service configuration
 //! - [DDS Spec Sec.2.2.2.4](https://www.omg.org/spec/DDS/1.4/) - DataWriter
 
+mod batch;
 mod builder;
 mod heartbeat_scheduler;
+mod hibernation;
+mod liveliness;
+#[cfg(target_os = "linux")]
+mod loan;
+mod matched;
 mod nack;
 mod runtime;
 #[cfg(test)]
 mod tests;
 
 pub use builder::WriterBuilder;
+#[cfg(target_os = "linux")]
+pub use loan::WriteLoan;
 #[allow(unused_imports)]
 pub use runtime::{DataWriter, WriterStats};
@@ -20,19 +20,25 @@
 
 use crate::core::discovery::multicast::control_parser::parse_acknack_submessage;
 use crate::core::discovery::multicast::control_types::AckNackInfo;
+use crate::core::discovery::GUID;
 use crate::engine::{NackFragHandler, NackHandler};
 use crate::protocol::builder;
 use crate::reliability::{GapTx, HistoryCache, NackMsg, ReliableMetrics, WriterRetransmitHandler};
+use crate::telemetry::EndpointMetrics;
 use crate::transport::UdpTransport;
 use std::sync::{Arc, Mutex};
 
+use super::matched::PublicationMatches;
+
 pub(super) struct WriterNackHandler {
     topic: String,
     cache: Arc<HistoryCache>,
     transport: Arc<UdpTransport>,
     metrics: Arc<ReliableMetrics>,
+    endpoint_metrics: Arc<EndpointMetrics>,
     gap_tx: Mutex<GapTx>,
     rtps_endpoint: Option<crate::protocol::builder::RtpsEndpointContext>,
+    matches: Arc<PublicationMatches>,
 }
 
 impl WriterNackHandler {
@@ -41,15 +47,41 @@ impl WriterNackHandler {
         cache: Arc<HistoryCache>,
         transport: Arc<UdpTransport>,
         metrics: Arc<ReliableMetrics>,
+        endpoint_metrics: Arc<EndpointMetrics>,
         rtps_endpoint: Option<crate::protocol::builder::RtpsEndpointContext>,
+        matches: Arc<PublicationMatches>,
     ) -> Self {
         Self {
             topic,
             cache,
             transport,
             metrics,
+            endpoint_metrics,
             gap_tx: Mutex::new(GapTx::new()),
             rtps_endpoint,
+            matches,
+        }
+    }
+
+    /// Record the acknowledgment if the ACKNACK is addressed to this writer.
+    fn record_ack(&self, packet: &[u8], info: &AckNackInfo) {
+        let Some(ctx) = self.rtps_endpoint else {
+            return;
+        };
+        if info.writer_entity_id != ctx.writer_entity_id {
+            return;
+        }
+        if !info.missing_ranges.is_empty() {
+            self.metrics.increment_nacks_received(1);
+        }
+        // parse_acknack_submessage checked the RTPS header is present
+        let Some(prefix) = packet.get(8..20).and_then(|p| <[u8; 12]>::try_from(p).ok()) else {
+            return;
+        };
+        let reader = GUID::new(prefix, info.reader_entity_id);
+        if self.matches.on_acknack(reader, info.bitmap_base) {
+            self.endpoint_metrics
+                .record_ack(info.bitmap_base.saturating_sub(1));
         }
     }
 }
@@ -82,6 +114,8 @@ impl NackHandler for WriterNackHandler {
             }
         };
 
+        self.record_ack(nack_bytes, &ack_nack_info);
+
         // Convert AckNackInfo to NackMsg format for WriterRetransmitHandler
         let nack = NackMsg::new(ack_nack_info.missing_ranges);
 
@@ -116,11 +150,12 @@ impl NackHandler for WriterNackHandler {
             if builder::should_fragment(payload.len()) {
                 // Large payload: retransmit as DATA_FRAG packets
                 if let Some(ctx) = self.rtps_endpoint {
-                    let frag_packets = builder::build_data_frag_packets(
+                    let frag_packets = builder::build_data_frag_packets_stamped(
                         &ctx,
                         seq,
                         &payload,
                         builder::DEFAULT_FRAGMENT_SIZE,
+                        self.cache.timestamp(seq),
                     );
                     log::debug!(
                         "[writer] Retransmitting seq {} as {} DATA_FRAG packets ({} bytes)",
@@ -145,8 +180,21 @@ impl NackHandler for WriterNackHandler {
                 }
             } else {
                 // Small payload: retransmit as single DATA packet
+                let change = self.cache.instance_state(seq);
                 let rtps_packet = if let Some(ctx) = self.rtps_endpoint {
-                    builder::build_data_packet_with_context(&ctx, &self.topic, seq, &payload)
+                    builder::build_data_packet_with_options(
+                        &ctx,
+                        &self.topic,
+                        seq,
+                        &payload,
+                        &builder::DataPacketOptions {
+                            key_hash: change.map(|change| change.key_hash),
+                            status_info: change.map_or(0, |change| change.status_info),
+                            source_timestamp_ns: self.cache.timestamp(seq),
+                            original_writer: self.cache.original_writer(seq),
+                            ..Default::default()
+                        },
+                    )
                 } else {
                     builder::build_data_packet(&self.topic, seq, &payload)
                 };
@@ -255,8 +303,15 @@ impl NackFragHandler for WriterNackFragHandler {
 
         // Re-fragment the payload using default fragment size
         let fragment_size = builder::DEFAULT_FRAGMENT_SIZE;
+        let source_ns = self.cache.timestamp(writer_sn);
         let frag_packets = if let Some(ctx) = self.rtps_endpoint {
-            builder::build_data_frag_packets(&ctx, writer_sn, &payload, fragment_size)
+            builder::build_data_frag_packets_stamped(
+                &ctx,
+                writer_sn,
+                &payload,
+                fragment_size,
+                source_ns,
+            )
         } else {
             // Build without endpoint context (fallback)
             let ctx = builder::RtpsEndpointContext {
@@ -2,23 +2,39 @@
 // Copyright (c) 2025-2026 naskel.com
 
 use super::heartbeat_scheduler::HeartbeatSchedulerHandle;
+use super::liveliness::{LivelinessAnnouncer, LivelinessSchedulerHandle};
 use crate::core::discovery::ReplayToken;
 use crate::core::discovery::GUID;
 use crate::core::rt;
 use crate::core::rtps_constants::RTPS_ENTITYID_PARTICIPANT;
-use crate::dds::listener::DataWriterListener;
-use crate::dds::{BindToken, Error, QoS, Result, DDS};
+use crate::dds::coherent::CoherentGroup;
+use crate::dds::listener::{
+    DataWriterListener, InconsistentTopicStatus, OfferedIncompatibleQosStatus,
+    PublicationMatchedStatus,
+};
+use crate::dds::liveliness::WriterLiveliness;
+use crate::dds::type_versions::{EncodeAs, TypeVersions};
+use crate::dds::{BindToken, Error, ErrorContext, InstanceHandle, QoS, Result, DDS};
 use crate::protocol::builder;
-use crate::reliability::{HeartbeatTx, HistoryCache, ReliableMetrics};
+use crate::protocol::checksum::ChecksumKind;
+use crate::protocol::coherent::CoherentSetTag;
+use crate::protocol::constants::{STATUS_INFO_DISPOSED, STATUS_INFO_UNREGISTERED};
+use crate::protocol::original_writer::OriginalWriterInfo;
+use crate::reliability::{HeartbeatTx, HistoryCache, InstanceStateChange, ReliableMetrics};
 use crate::telemetry;
 use crate::telemetry::metrics::current_time_ns;
+use crate::telemetry::{EndpointMetrics, MetricsRegistration};
 use crate::transport::UdpTransport;
+use parking_lot::Mutex;
+#[cfg(feature = "wasmtime")]
+use std::borrow::Cow;
 use std::cell::RefCell;
+use std::collections::HashSet;
 use std::convert::TryFrom;
 use std::net::SocketAddr;
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 /// Global counter for HEARTBEAT_FRAG messages (RTPS v2.3 Sec.8.3.7.6)
 static HEARTBEAT_FRAG_COUNT: AtomicU32 = AtomicU32::new(1);
@@ -83,13 +99,19 @@ pub struct DataWriter<T: DDS> {
     pub(super) rtps_endpoint: Option<crate::protocol::builder::RtpsEndpointContext>,
     pub(super) merger: Arc<rt::TopicMerger>,
     pub(super) transport: Option<Arc<UdpTransport>>,
-    pub(super) next_seq: AtomicU64,
+    pub(super) next_seq: Arc<AtomicU64>,
     pub(super) history_cache: Option<Arc<HistoryCache>>,
     pub(super) reliable_metrics: Option<Arc<ReliableMetrics>>,
+    /// Per-writer traffic counters reported by the admin API
+    pub(super) endpoint_metrics: Arc<EndpointMetrics>,
+    /// Listing of `endpoint_metrics` in the admin API (removed on drop)
+    pub(super) _metrics_registration: Option<MetricsRegistration>,
     pub(super) heartbeat_tx: Option<RefCell<HeartbeatTx>>,
     /// Periodic heartbeat scheduler thread handle (RTPS 2.5 Section 8.4.7.2)
     /// Sends HEARTBEAT messages independently of write() calls for reliable recovery.
     pub(super) _heartbeat_scheduler: Option<HeartbeatSchedulerHandle>,
+    /// Idle-writer hibernation hooks, watched by the participant's sweep
+    pub(super) _hibernation: Option<Arc<dyn crate::dds::Hibernate>>,
     pub(super) endpoint_registry: Option<crate::core::discovery::EndpointRegistry>,
     /// BindToken for intra-process auto-binding (unregisters on drop)
     pub(super) _bind_token: Option<BindToken>,
@@ -97,9 +119,35 @@ pub struct DataWriter<T: DDS> {
     pub(super) _replay_token: Option<ReplayToken>,
     /// Optional listener for writer callbacks
     pub(super) listener: Option<Arc<dyn DataWriterListener<T>>>,
-    /// Security plugin suite for encryption (DDS Security v1.1)
+    /// Per-sample checksum added to DATA inline QoS (see `WriterBuilder::sample_checksum`)
+    pub(super) sample_checksum: Option<ChecksumKind>,
+    /// Side-by-side type versions (see `WriterBuilder::type_version`)
+    pub(super) type_versions: Option<TypeVersions<EncodeAs<T>>>,
+    /// Pending batch of small samples (see `QoS::batching`)
+    pub(super) batch: Option<super::batch::WriterBatch>,
+    /// Instances registered explicitly or by writing them
+    pub(super) instances: Mutex<HashSet<InstanceHandle>>,
+    /// Coherent sets of the writer's Publisher (see `WriterBuilder::publisher`)
+    pub(super) coherent: Option<Arc<CoherentGroup>>,
+    /// Matched readers and their acknowledgments
+    pub(super) matches: Arc<super::matched::PublicationMatches>,
+    /// Discovered readers whose QoS this writer cannot offer
+    pub(super) incompatible_qos: Option<Arc<crate::dds::incompatible_qos::IncompatibleQosWatch>>,
+    /// LIVELINESS assertions, shared with same-process readers
+    pub(super) liveliness: Arc<WriterLiveliness>,
+    /// Sends liveliness HEARTBEATs to remote readers
+    pub(super) liveliness_announcer: Option<Arc<LivelinessAnnouncer>>,
+    /// Liveliness thread (finite lease only)
+    pub(super) _liveliness_scheduler: Option<LivelinessSchedulerHandle>,
+    /// SHM ring for zero-copy loans (see `DataWriter::loan_sample`)
+    #[cfg(target_os = "linux")]
+    pub(super) shm_loans: Option<super::loan::ShmLoanWriter>,
+    /// WASM filter/transform (see `WriterBuilder::wasm_plugin`)
+    #[cfg(feature = "wasmtime")]
+    pub(super) wasm_plugin: Option<Arc<crate::dds::WasmPlugin>>,
+    /// Payload protection of the topic when governance protects it (DDS Security v1.1)
     #[cfg(feature = "security")]
-    pub(super) security: Option<Arc<crate::security::SecurityPluginSuite>>,
+    pub(super) protection: Option<Box<dyn crate::security::PayloadProtection>>,
     pub(super) _phantom: core::marker::PhantomData<T>,
 }
 
@@ -142,11 +190,12 @@ impl WriterReplayState {
             // Check if payload needs fragmentation
             if builder::should_fragment(payload.len()) {
                 if let Some(ctx) = self.rtps_endpoint {
-                    let frag_packets = builder::build_data_frag_packets(
+                    let frag_packets = builder::build_data_frag_packets_stamped(
                         &ctx,
                         seq,
                         &payload,
                         builder::DEFAULT_FRAGMENT_SIZE,
+                        self.history_cache.timestamp(seq),
                     );
                     log::debug!(
                         "[writer] Replaying {} DATA_FRAG packets for seq {} ({} bytes)",
@@ -172,8 +221,21 @@ impl WriterReplayState {
                 }
             } else {
                 // Small payload: single DATA packet
+                let change = self.history_cache.instance_state(seq);
                 let packet = if let Some(ctx) = self.rtps_endpoint {
-                    builder::build_data_packet_with_context(&ctx, &self.topic, seq, &payload)
+                    builder::build_data_packet_with_options(
+                        &ctx,
+                        &self.topic,
+                        seq,
+                        &payload,
+                        &builder::DataPacketOptions {
+                            key_hash: change.map(|change| change.key_hash),
+                            status_info: change.map_or(0, |change| change.status_info),
+                            source_timestamp_ns: self.history_cache.timestamp(seq),
+                            original_writer: self.history_cache.original_writer(seq),
+                            ..Default::default()
+                        },
+                    )
                 } else {
                     builder::build_data_packet(&self.topic, seq, &payload)
                 };
@@ -216,35 +278,353 @@ impl<T: DDS> DataWriter<T> {
         Arc::clone(&self.merger)
     }
 
-    /// Encrypt payload if security is enabled and a session key is available.
-    ///
-    /// Returns the encrypted payload, or the original payload if encryption is not available.
+    /// Context naming this writer and its topic, for errors.
+    pub(super) fn error_context(&self) -> ErrorContext {
+        ErrorContext::new().entity("DataWriter").topic(&self.topic)
+    }
+
+    /// Sign or encrypt a payload bound for the network when governance
+    /// protects the topic. `None` leaves the payload in the clear.
     #[cfg(feature = "security")]
-    fn maybe_encrypt_payload(&self, plaintext: &[u8]) -> Vec<u8> {
-        if let Some(ref security) = self.security {
-            if let Some(crypto) = security.cryptographic() {
-                // Use session key ID 0 (local key)
-                // Full per-participant keys require ECDH handshake during discovery
-                if let Ok(encrypted) = crypto.encrypt_data(plaintext, 0) {
+    fn protect_payload(&self, payload: &[u8]) -> Result<Option<Vec<u8>>> {
+        let Some(ref protection) = self.protection else {
+            return Ok(None);
+        };
+        let protected = protection.protect(payload).map_err(|e| {
+            Error::InvalidState(format!(
+                "Payload protection failed for topic '{}': {}",
+                self.topic, e
+            ))
+        })?;
+        log::trace!(
+            "[writer] {:?} payload: {} -> {} bytes",
+            protection.kind(),
+            payload.len(),
+            protected.len()
+        );
+        Ok(Some(protected))
+    }
+
+    pub fn write(&self, msg: &T) -> Result<()> {
+        self.write_sample(msg, 0, None, None)
+    }
+
+    /// Write a sample with an explicit source timestamp, in nanoseconds
+    /// since the UNIX epoch (DDS `write_w_timestamp`).
+    ///
+    /// [`write()`](Self::write) stamps samples with the current time. The
+    /// timestamp travels in the INFO_TS submessage preceding the DATA and is
+    /// exposed to readers via
+    /// [`SampleInfo::source_timestamp_ns`](crate::SampleInfo); readers with
+    /// DESTINATION_ORDER BY_SOURCE_TIMESTAMP order samples by it. Redundant
+    /// publishers of the same measurement should pass the time it was taken.
+    pub fn write_with_timestamp(&self, msg: &T, source_timestamp_ns: u64) -> Result<()> {
+        self.write_sample(msg, 0, Some(source_timestamp_ns), None)
+    }
+
+    /// Write a sample relayed on behalf of another writer: `original` is
+    /// the writer and sequence number it was first published with, and
+    /// `source_timestamp_ns` its source timestamp.
+    ///
+    /// Used by persistence services replaying stored samples. The original
+    /// writer travels in the DATA inline QoS (`PID_ORIGINAL_WRITER_INFO`),
+    /// history replays and repairs included, and PERSISTENT readers deliver
+    /// a sample once even when they receive it both from its writer and
+    /// from a replay. Fragmented samples (> 8 KB) carry no inline QoS, so
+    /// their copies are not recognized.
+    pub fn write_relayed(
+        &self,
+        msg: &T,
+        source_timestamp_ns: u64,
+        original: OriginalWriterInfo,
+    ) -> Result<()> {
+        self.write_sample(msg, 0, Some(source_timestamp_ns), Some(original))
+    }
+
+    /// Write a sample tagged with a 64-bit trace id.
+    ///
+    /// The id travels in the DATA inline QoS (`PID_HDDS_TRACE_ID`), or in
+    /// the first DATA_FRAG's for fragmented samples (> 8 KB), and is exposed
+    /// to readers via [`SampleInfo::trace_id`](crate::SampleInfo), so a
+    /// telemetry backend can attribute end-to-end latency across processes.
+    ///
+    /// Writers without an RTPS endpoint context keep the id for
+    /// intra-process readers only, since their DATA submessages carry no
+    /// inline QoS.
+    ///
+    /// # Errors
+    /// `Error::InvalidConfig` if `trace_id` is 0, which is reserved for untraced
+    /// samples (use [`write()`](Self::write) instead).
+    pub fn write_with_trace_id(&self, msg: &T, trace_id: u64) -> Result<()> {
+        if trace_id == 0 {
+            return Err(Error::config("trace id 0 is reserved for untraced samples")
+                .with_context(self.error_context()));
+        }
+        self.write_sample(msg, trace_id, None, None)
+    }
+
+    /// Register an instance ahead of writing it (DDS `register_instance`).
+    ///
+    /// Returns the handle computed from the sample's key fields; readers
+    /// report the same handle in [`SampleInfo`](crate::SampleInfo). Writing
+    /// an instance registers it implicitly. Keyless types have a single
+    /// instance with the nil handle.
+    pub fn register_instance(&self, instance: &T) -> Result<InstanceHandle> {
+        let handle = InstanceHandle::new(instance.compute_key());
+        self.instances.lock().insert(handle);
+        Ok(handle)
+    }
+
+    /// Handle of the instance identified by the key fields of `instance`,
+    /// or `None` if this writer has not registered it.
+    #[must_use]
+    pub fn lookup_instance(&self, instance: &T) -> Option<InstanceHandle> {
+        let handle = InstanceHandle::new(instance.compute_key());
+        self.instances.lock().contains(&handle).then_some(handle)
+    }
+
+    /// Unregister an instance (DDS `unregister_instance`).
+    ///
+    /// Readers see the instance as NOT_ALIVE_NO_WRITERS, or as
+    /// NOT_ALIVE_DISPOSED when WRITER_DATA_LIFECYCLE autodispose is enabled
+    /// (the default).
+    ///
+    /// # Errors
+    /// `Error::InvalidState` if the instance is not registered.
+    pub fn unregister_instance(&self, instance: &T) -> Result<()> {
+        let handle = InstanceHandle::new(instance.compute_key());
+        if !self.instances.lock().remove(&handle) {
+            return Err(Error::InvalidState(format!(
+                "topic '{}': unregister_instance on an unregistered instance",
+                self.topic
+            )));
+        }
+
+        self.write_instance_state(instance, self.unregister_status_info())
+    }
+
+    /// Dispose an instance (DDS `dispose`).
+    ///
+    /// Readers see the instance as NOT_ALIVE_DISPOSED until it is written
+    /// again. The instance stays registered with this writer.
+    pub fn dispose(&self, instance: &T) -> Result<()> {
+        self.instances
+            .lock()
+            .insert(InstanceHandle::new(instance.compute_key()));
+        self.write_instance_state(instance, STATUS_INFO_DISPOSED)
+    }
+
+    /// Dispose the instance whose serialized key is `key`.
+    ///
+    /// `key` is the big-endian CDR of the key fields, as hashed by
+    /// `compute_key()` (DDS-RTPS Sec.9.6.3.8). The change carries `key` as
+    /// its payload, so publishers that only hold raw bytes (the C API) can
+    /// manage instances; keyed readers match it by key.
+    pub fn dispose_serialized_key(&self, key: &[u8]) -> Result<()> {
+        let handle = InstanceHandle::from_serialized_key(key);
+        self.instances.lock().insert(handle);
+        self.send_instance_state(handle, key, STATUS_INFO_DISPOSED)
+    }
+
+    /// Unregister the instance whose serialized key is `key`.
+    ///
+    /// See [`dispose_serialized_key`](Self::dispose_serialized_key) for the
+    /// key format. Unlike [`unregister_instance`](Self::unregister_instance)
+    /// the instance need not be registered: raw writes do not register
+    /// instances.
+    pub fn unregister_serialized_key(&self, key: &[u8]) -> Result<()> {
+        let handle = InstanceHandle::from_serialized_key(key);
+        self.instances.lock().remove(&handle);
+        self.send_instance_state(handle, key, self.unregister_status_info())
+    }
+
+    /// `PID_STATUS_INFO` of an unregister, disposed too with autodispose
+    fn unregister_status_info(&self) -> u8 {
+        let mut status_info = STATUS_INFO_UNREGISTERED;
+        if self
+            .qos
+            .writer_data_lifecycle
+            .autodispose_unregistered_instances
+        {
+            status_info |= STATUS_INFO_DISPOSED;
+        }
+        status_info
+    }
+
+    fn write_instance_state(&self, instance: &T, status_info: u8) -> Result<()> {
+        let mut tmp_buf = vec![0u8; 65536];
+        let serialized_len = instance.encode_cdr2(&mut tmp_buf)?;
+        self.send_instance_state(
+            InstanceHandle::new(instance.compute_key()),
+            &tmp_buf[..serialized_len],
+            status_info,
+        )
+    }
+
+    /// Send an instance lifecycle change to local and remote readers.
+    ///
+    /// The change travels as a DATA carrying `PID_KEY_HASH` and
+    /// `PID_STATUS_INFO`. It is kept in the history cache like any sample,
+    /// so NACK repairs and TRANSIENT_LOCAL replays carry it too.
+    fn send_instance_state(
+        &self,
+        handle: InstanceHandle,
+        payload: &[u8],
+        status_info: u8,
+    ) -> Result<()> {
+        let seq = self.next_seq.fetch_add(1, Ordering::Relaxed);
+        self.liveliness.assert();
+        let serialized_len = payload.len();
+        let now = current_time_ns();
+
+        log::debug!(
+            "[writer] instance state topic='{}' seq={} status_info={:#04x}",
+            self.topic,
+            seq,
+            status_info
+        );
+
+        if self.merger.reader_count() > 0 {
+            match self.prepare_intra_process_entry(payload, serialized_len, seq, now, 0) {
+                Ok((mut entry, slab)) => {
+                    entry.flags |= rt::instance_state_flags(status_info);
+                    entry.key_hash = Some(handle.0);
+                    if !self.merger.push(entry) {
+                        rt::get_slab_pool().release(slab);
+                    }
+                }
+                Err(Error::WouldBlock) => {
                     log::debug!(
-                        "[writer] Encrypted payload: {} -> {} bytes",
-                        plaintext.len(),
-                        encrypted.len()
+                        "[writer] slab pool full seq={}, skipping intra-process",
+                        seq
                     );
-                    return encrypted;
                 }
-                // Session key not available - fall through to plaintext
+                Err(e) => return Err(e),
+            }
+        }
+
+        #[cfg(feature = "security")]
+        let protected = self.protect_payload(payload)?;
+        #[cfg(feature = "security")]
+        let payload: &[u8] = protected.as_deref().unwrap_or(payload);
+        let change = InstanceStateChange {
+            key_hash: handle.0,
+            status_info,
+        };
+
+        if let (Some(transport), Some(ctx)) = (&self.transport, self.rtps_endpoint) {
+            let packet = builder::build_data_packet_with_options(
+                &ctx,
+                &self.topic,
+                seq,
+                payload,
+                &builder::DataPacketOptions {
+                    key_hash: Some(change.key_hash),
+                    status_info,
+                    checksum: self.sample_checksum,
+                    source_timestamp_ns: Some(now),
+                    aligned: self.batch.is_some(),
+                    ..Default::default()
+                },
+            );
+            if packet.is_empty() {
+                return Err(Error::BufferTooSmall);
+            }
+            if let Err(e) = self.send_data_packet(transport, &packet) {
                 log::debug!(
-                    "[writer] Encryption skipped: no session key available (key exchange not complete)"
+                    "UDP send of instance state failed for topic '{}': {}",
+                    self.topic,
+                    e
                 );
+                if let Some(m) = telemetry::get_metrics_opt() {
+                    m.increment_transport_errors(1);
+                }
             }
         }
-        plaintext.to_vec()
+
+        if let Some(ref cache) = self.history_cache {
+            if let Err(e) = cache.insert_instance_state(seq, payload, now, change) {
+                log::debug!(
+                    "[writer] History cache insert failed for seq {}: {}",
+                    seq,
+                    e
+                );
+                self.endpoint_metrics.record_history_drop();
+            }
+            self.endpoint_metrics.record_sent(seq);
+        }
+        self.maybe_send_heartbeat(seq);
+
+        Ok(())
     }
 
-    pub fn write(&self, msg: &T) -> Result<()> {
+    /// Publish `msg`, after the WASM plugin when one is attached.
+    ///
+    /// Without `source_ns`, the sample is stamped with the write time.
+    /// `original` is set for relayed samples.
+    fn write_sample(
+        &self,
+        msg: &T,
+        trace_id: u64,
+        source_ns: Option<u64>,
+        original: Option<OriginalWriterInfo>,
+    ) -> Result<()> {
+        #[cfg(feature = "wasmtime")]
+        if let Some(ref plugin) = self.wasm_plugin {
+            let mut tmp_buf = vec![0u8; 65536];
+            let serialized_len = msg.encode_cdr2(&mut tmp_buf)?;
+            return match plugin.apply(&tmp_buf[..serialized_len]) {
+                Some(Cow::Borrowed(_)) => self.publish_sample(msg, trace_id, source_ns, original),
+                Some(Cow::Owned(payload)) => {
+                    self.publish_sample(&T::decode_cdr2(&payload)?, trace_id, source_ns, original)
+                }
+                None => {
+                    // The application is still alive
+                    self.liveliness.assert();
+                    log::trace!("[writer] WASM plugin dropped sample topic='{}'", self.topic);
+                    Ok(())
+                }
+            };
+        }
+        self.publish_sample(msg, trace_id, source_ns, original)
+    }
+
+    fn publish_sample(
+        &self,
+        msg: &T,
+        trace_id: u64,
+        source_ns: Option<u64>,
+        original: Option<OriginalWriterInfo>,
+    ) -> Result<()> {
         let write_start_ns = current_time_ns();
+        let source_ns = source_ns.unwrap_or(write_start_ns);
         let seq = self.next_seq.fetch_add(1, Ordering::Relaxed);
+        self.liveliness.assert();
+
+        // What same-process readers report and deduplicate the sample by
+        let writer_guid = self.writer_guid();
+        let rx_timestamps = crate::engine::RxTimestamps::at_source(source_ns)
+            .with_writer(writer_guid)
+            .with_original_writer(
+                original.or_else(|| writer_guid.map(|guid| OriginalWriterInfo::new(guid, seq))),
+            );
+
+        if T::has_key() {
+            self.instances
+                .lock()
+                .insert(InstanceHandle::new(msg.compute_key()));
+        }
+
+        if trace_id != 0 {
+            log::debug!(
+                "[trace] write topic='{}' seq={} trace_id={:016x}",
+                self.topic,
+                seq,
+                trace_id
+            );
+        }
+
+        let coherent = self.coherent.as_ref().and_then(|group| group.current());
 
         // Check if we have local readers - only allocate slab pool if needed
         let has_local_readers = self.merger.reader_count() > 0;
@@ -253,7 +633,16 @@ impl<T: DDS> DataWriter<T> {
         // Skip RTPS framing, UDP send, history cache, and heartbeats entirely.
         let has_remote_peers = self.has_remote_peers();
         if has_local_readers && !has_remote_peers {
-            return self.write_intra_process_fast(msg, seq, write_start_ns);
+            self.write_intra_process_fast(
+                msg,
+                seq,
+                write_start_ns,
+                rx_timestamps,
+                trace_id,
+                coherent,
+            )?;
+            self.record_coherent(coherent);
+            return Ok(());
         }
 
         // Buffer sized to fit max RTPS DATA submessage payload (~64KB)
@@ -273,13 +662,18 @@ impl<T: DDS> DataWriter<T> {
         // If slab pool is full (WouldBlock), gracefully skip intra-process
         // delivery but still proceed with UDP - never fail the whole write.
         let intra_process = if has_local_readers {
-            match Self::prepare_intra_process_entry(
+            match self.prepare_intra_process_entry(
                 &tmp_buf[..serialized_len],
                 serialized_len,
                 seq,
                 write_start_ns,
+                trace_id,
             ) {
-                Ok(entry) => Some(entry),
+                Ok((mut entry, handle)) => {
+                    entry.coherent = coherent;
+                    entry.rx_timestamps = rx_timestamps;
+                    Some((entry, handle))
+                }
                 Err(Error::WouldBlock) => {
                     log::debug!(
                         "[writer] slab pool full seq={}, skipping intra-process",
@@ -293,16 +687,17 @@ impl<T: DDS> DataWriter<T> {
             None
         };
 
+        // DDS Security: sign or encrypt what leaves the process (sends and
+        // history cache repairs), local readers get the plain payload
+        #[cfg(feature = "security")]
+        let protected = self.protect_payload(&tmp_buf[..serialized_len])?;
+        #[cfg(feature = "security")]
+        let payload_for_network: &[u8] = protected.as_deref().unwrap_or(&tmp_buf[..serialized_len]);
+        #[cfg(not(feature = "security"))]
+        let payload_for_network: &[u8] = &tmp_buf[..serialized_len];
+
         // Send on UDP
         if let Some(ref transport) = self.transport {
-            // DDS Security: Encrypt payload if enabled
-            #[cfg(feature = "security")]
-            let encrypted_buf = self.maybe_encrypt_payload(&tmp_buf[..serialized_len]);
-            #[cfg(feature = "security")]
-            let payload_for_network: &[u8] = &encrypted_buf;
-            #[cfg(not(feature = "security"))]
-            let payload_for_network: &[u8] = &tmp_buf[..serialized_len];
-
             // Check if payload needs fragmentation (>8KB)
             let use_fragmentation =
                 builder::should_fragment(payload_for_network.len()) && self.rtps_endpoint.is_some();
@@ -316,15 +711,36 @@ impl<T: DDS> DataWriter<T> {
             );
 
             let sent_result = if use_fragmentation {
+                // DATA_FRAG carries no inline QoS, so no coherent set tag
+                // (an untagged sample only loses its group write order)
+                if coherent.is_some_and(|tag| tag.set_seq != 0) {
+                    log::debug!(
+                        "[writer] {} bytes too large for a coherent set topic='{}' seq={}",
+                        payload_for_network.len(),
+                        self.topic,
+                        seq
+                    );
+                    if let Some((_, handle)) = intra_process {
+                        rt::get_slab_pool().release(handle);
+                    }
+                    return Err(Error::unsupported(format!(
+                        "{}-byte sample needs fragmenting inside a coherent set",
+                        payload_for_network.len()
+                    ))
+                    .with_context(self.error_context()));
+                }
+
                 // Large payload: send as DATA_FRAG packets
                 #[allow(clippy::unwrap_used)]
                 // Safe: use_fragmentation requires rtps_endpoint.is_some()
                 let ctx = self.rtps_endpoint.unwrap();
-                let frag_packets = builder::build_data_frag_packets(
+                let frag_packets = builder::build_data_frag_packets_traced(
                     &ctx,
                     seq,
                     payload_for_network,
                     builder::DEFAULT_FRAGMENT_SIZE,
+                    Some(source_ns),
+                    (trace_id != 0).then_some(trace_id),
                 );
 
                 if frag_packets.is_empty() {
@@ -348,8 +764,10 @@ impl<T: DDS> DataWriter<T> {
                     payload_for_network.len()
                 );
 
-                // Send all fragments
-                let result = self.send_packets_to_endpoints(transport, &frag_packets);
+                // Send all fragments, after the samples batched before them
+                let result = self
+                    .flush_batch()
+                    .and_then(|()| self.send_packets_to_endpoints(transport, &frag_packets));
 
                 // v208: Send multiple HEARTBEAT_FRAGs to improve reliability
                 // RTPS v2.3 Sec.8.3.7.6 recommends periodic heartbeats for fragment recovery.
@@ -390,12 +808,27 @@ impl<T: DDS> DataWriter<T> {
                 result
             } else {
                 // Small payload: send as single DATA packet (existing path)
+                let native_tag = self
+                    .type_versions
+                    .as_ref()
+                    .map(|versions| versions.tag(versions.native()));
                 let rtps_packet = if let Some(ctx) = self.rtps_endpoint {
-                    builder::build_data_packet_with_context(
+                    builder::build_data_packet_with_options(
                         &ctx,
                         &self.topic,
                         seq,
                         payload_for_network,
+                        &builder::DataPacketOptions {
+                            key_hash: T::has_key().then(|| msg.compute_key()),
+                            trace_id: (trace_id != 0).then_some(trace_id),
+                            checksum: self.sample_checksum,
+                            type_version: native_tag.as_ref(),
+                            coherent_set: coherent,
+                            source_timestamp_ns: Some(source_ns),
+                            original_writer: original,
+                            aligned: self.batch.is_some(),
+                            ..Default::default()
+                        },
                     )
                 } else {
                     builder::build_data_packet(&self.topic, seq, payload_for_network)
@@ -412,7 +845,13 @@ impl<T: DDS> DataWriter<T> {
                     return Err(Error::BufferTooSmall);
                 }
 
-                self.send_packet_to_endpoints(transport, &rtps_packet)
+                let result = self.send_data_packet(transport, &rtps_packet);
+                if result.is_ok() {
+                    self.send_type_version_copies(
+                        transport, msg, seq, source_ns, trace_id, coherent,
+                    );
+                }
+                result
             };
 
             if let Err(e) = sent_result {
@@ -426,6 +865,15 @@ impl<T: DDS> DataWriter<T> {
                     self.topic,
                     seq
                 );
+                if trace_id != 0 {
+                    log::debug!(
+                        "[trace] wire-out topic='{}' seq={} trace_id={:016x} fragmented={}",
+                        self.topic,
+                        seq,
+                        trace_id,
+                        use_fragmentation
+                    );
+                }
             }
         }
 
@@ -450,22 +898,34 @@ impl<T: DDS> DataWriter<T> {
         }
 
         if let Some(ref cache) = self.history_cache {
-            if let Err(e) = cache.insert(seq, &tmp_buf[..serialized_len]) {
+            let inserted = match original {
+                Some(original) => {
+                    cache.insert_relayed(seq, payload_for_network, source_ns, original)
+                }
+                None => cache.insert_stamped(seq, payload_for_network, source_ns),
+            };
+            if let Err(e) = inserted {
                 log::debug!(
                     "[writer] History cache insert failed for seq {}: {}",
                     seq,
                     e
                 );
+                self.endpoint_metrics.record_history_drop();
             }
+            self.endpoint_metrics.record_sent(seq);
         }
 
         self.maybe_send_heartbeat(seq);
+        self.record_coherent(coherent);
 
+        self.endpoint_metrics.record_sample(serialized_len);
         if let Some(m) = telemetry::get_metrics_opt() {
             m.increment_sent(1);
             m.add_latency_sample(write_start_ns, current_time_ns());
         }
 
+        self.matches.on_write(self.merger.reader_count());
+
         // Invoke listener callback if present
         if let Some(ref listener) = self.listener {
             listener.on_sample_written(msg, seq);
@@ -474,6 +934,13 @@ impl<T: DDS> DataWriter<T> {
         Ok(())
     }
 
+    /// Count a completed write towards the publisher's open coherent set.
+    fn record_coherent(&self, tag: Option<CoherentSetTag>) {
+        if let (Some(group), Some(tag)) = (&self.coherent, tag) {
+            group.record(tag, &self.topic);
+        }
+    }
+
     /// Returns true if there are remote peers discovered for this writer.
     #[inline]
     fn has_remote_peers(&self) -> bool {
@@ -494,7 +961,15 @@ impl<T: DDS> DataWriter<T> {
 
     /// Ultra-fast intra-process write path.
     /// Bypasses RTPS framing, UDP transport, history cache, and heartbeats.
-    fn write_intra_process_fast(&self, msg: &T, seq: u64, write_start_ns: u64) -> Result<()> {
+    fn write_intra_process_fast(
+        &self,
+        msg: &T,
+        seq: u64,
+        write_start_ns: u64,
+        rx_timestamps: crate::engine::RxTimestamps,
+        trace_id: u64,
+        coherent: Option<CoherentSetTag>,
+    ) -> Result<()> {
         // Reserve max-sized slab slot, encode directly into it, then commit
         // the actual serialized length. Single copy, no intermediate buffer.
         let slab_pool = rt::get_slab_pool();
@@ -522,7 +997,11 @@ impl<T: DDS> DataWriter<T> {
             Ok(v) => v,
             Err(_) => {
                 slab_pool.release(handle);
-                return Err(Error::Unsupported);
+                return Err(Error::unsupported(format!(
+                    "sequence number {} beyond 32 bits for intra-process delivery",
+                    seq
+                ))
+                .with_context(self.error_context()));
             }
         };
         let len_u32 = match u32::try_from(serialized_len) {
@@ -539,6 +1018,10 @@ impl<T: DDS> DataWriter<T> {
             len: len_u32,
             flags: 0x01, // COMMITTED
             timestamp_ns: write_start_ns,
+            trace_id,
+            coherent,
+            rx_timestamps,
+            key_hash: None,
         };
 
         let merger_success = self.merger.push(entry);
@@ -546,11 +1029,14 @@ impl<T: DDS> DataWriter<T> {
             slab_pool.release(handle);
         }
 
+        self.endpoint_metrics.record_sample(serialized_len);
         if let Some(m) = telemetry::get_metrics_opt() {
             m.increment_sent(1);
             m.add_latency_sample(write_start_ns, current_time_ns());
         }
 
+        self.matches.on_write(self.merger.reader_count());
+
         if let Some(ref listener) = self.listener {
             listener.on_sample_written(msg, seq);
         }
@@ -558,12 +1044,20 @@ impl<T: DDS> DataWriter<T> {
         Ok(())
     }
 
+    /// GUID of this writer, reported to same-process readers in SampleInfo.
+    fn writer_guid(&self) -> Option<GUID> {
+        self.rtps_endpoint
+            .map(|ctx| GUID::new(ctx.guid_prefix, ctx.writer_entity_id))
+    }
+
     #[allow(clippy::missing_panics_doc)]
     fn prepare_intra_process_entry(
+        &self,
         payload: &[u8],
         serialized_len: usize,
         seq: u64,
         write_start_ns: u64,
+        trace_id: u64,
     ) -> Result<(rt::IndexEntry, rt::SlabHandle)> {
         let slab_pool = rt::get_slab_pool();
         let (handle, slab_buf) = match slab_pool.reserve(serialized_len) {
@@ -590,7 +1084,11 @@ impl<T: DDS> DataWriter<T> {
                     "[writer] Sequence {} exceeds 32-bit limit; dropping intra-process delivery",
                     seq
                 );
-                return Err(Error::Unsupported);
+                return Err(Error::unsupported(format!(
+                    "sequence number {} beyond 32 bits for intra-process delivery",
+                    seq
+                ))
+                .with_context(self.error_context()));
             }
         };
 
@@ -615,14 +1113,153 @@ impl<T: DDS> DataWriter<T> {
             len: len_u32,
             flags: 0x01,
             timestamp_ns: write_start_ns,
+            trace_id,
+            coherent: None,
+            rx_timestamps: crate::engine::RxTimestamps::at_source(write_start_ns)
+                .with_writer(self.writer_guid()),
+            key_hash: None,
         };
 
         Ok((entry, handle))
     }
 
+    /// Samples and payload bytes written, and samples the history rejected
+    /// because it was full (KEEP_ALL).
     #[must_use]
     pub fn stats(&self) -> WriterStats {
-        WriterStats::default()
+        WriterStats {
+            messages_sent: self.endpoint_metrics.samples(),
+            bytes_sent: self.endpoint_metrics.bytes(),
+            drops: self.endpoint_metrics.history_drops(),
+        }
+    }
+
+    /// Send the samples waiting in the writer's batch now.
+    ///
+    /// With [`QoS::batching`], small samples wait up to the batch's
+    /// `max_delay` to share an RTPS message with the next ones; call this
+    /// after the last write of a burst to send them without that delay.
+    /// Does nothing when the writer does not batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::IoError` if the batch could not be sent.
+    pub fn flush(&self) -> Result<()> {
+        self.flush_batch().map_err(Error::IoError)
+    }
+
+    /// Block until every matched reader received the samples written so far.
+    ///
+    /// Remote Reliable readers must acknowledge the last sample (ACKNACK);
+    /// same-process readers receive samples during `write()` and BestEffort
+    /// readers are never waited for, so a BestEffort writer returns at once.
+    /// Call it before exiting a short-lived process so the last samples are
+    /// not lost with it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::WriteTimeout` if some reader has not acknowledged
+    /// within `timeout`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use hdds::{Participant, QoS, Result};
+    /// use std::time::Duration;
+    ///
+    /// # #[derive(hdds::DDS)] struct Command { id: u32 }
+    /// fn main() -> Result<()> {
+    ///     let participant = Participant::builder("cli").build()?;
+    ///     let writer = participant.create_writer::<Command>("commands", QoS::reliable())?;
+    ///     writer.write(&Command { id: 1 })?;
+    ///     writer.wait_for_acknowledgments(Duration::from_secs(2))
+    /// }
+    /// ```
+    pub fn wait_for_acknowledgments(&self, timeout: Duration) -> Result<()> {
+        let last_seq = self.next_seq.load(Ordering::Relaxed).saturating_sub(1);
+        if last_seq == 0 || self.qos.reliability != crate::dds::Reliability::Reliable {
+            return Ok(());
+        }
+        self.flush()?;
+        self.matches.wait_for_acks(last_seq, timeout)
+    }
+
+    /// Readers matched with this writer (DDS PUBLICATION_MATCHED status).
+    ///
+    /// The `*_change` fields count changes since the previous call, or since
+    /// the last [`DataWriterListener::on_publication_matched`] callback when
+    /// a listener is set. Same-process readers created after the writer are
+    /// reported on the next call or `write()`.
+    pub fn publication_matched_status(&self) -> PublicationMatchedStatus {
+        self.matches.status()
+    }
+
+    /// Discovered readers this writer cannot match because of their QoS
+    /// (DDS OFFERED_INCOMPATIBLE_QOS status).
+    ///
+    /// `last_policy_id` is the [`QosPolicyId`](crate::dds::QosPolicyId) of
+    /// the first incompatible policy. Same-process readers are not checked.
+    pub fn offered_incompatible_qos_status(&self) -> OfferedIncompatibleQosStatus {
+        self.incompatible_qos
+            .as_ref()
+            .map(|watch| watch.status())
+            .unwrap_or_default()
+    }
+
+    /// Discovered readers on this topic whose type does not match this
+    /// writer's (DDS INCONSISTENT_TOPIC status).
+    ///
+    /// Such readers never match; each is counted once and logged as a
+    /// warning naming both types.
+    pub fn inconsistent_topic_status(&self) -> InconsistentTopicStatus {
+        self.incompatible_qos
+            .as_ref()
+            .map(|watch| watch.inconsistent_status())
+            .unwrap_or_default()
+    }
+
+    /// Number of readers currently matched with this writer.
+    #[must_use]
+    pub fn matched_readers_count(&self) -> usize {
+        self.matches.matched_count()
+    }
+
+    /// Assert the liveliness of this writer (DDS `assert_liveliness`).
+    ///
+    /// MANUAL_BY_TOPIC and MANUAL_BY_PARTICIPANT writers must assert
+    /// liveliness at least once per lease duration, or their readers
+    /// declare them not alive; every `write()`, `dispose()` and
+    /// `unregister_instance()` also counts. Remote readers are notified
+    /// right away with a liveliness HEARTBEAT. AUTOMATIC writers assert
+    /// liveliness on their own.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::IoError` if the liveliness HEARTBEAT could not be
+    /// sent.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use hdds::{Participant, QoS, Result};
+    ///
+    /// # #[derive(hdds::DDS)] struct Heartbeat { seq: u32 }
+    /// fn main() -> Result<()> {
+    ///     let participant = Participant::builder("controller").build()?;
+    ///     let qos = QoS::reliable().liveliness_manual_topic_millis(500);
+    ///     let writer = participant.create_writer::<Heartbeat>("controller/state", qos)?;
+    ///     // Nothing to publish, but still alive
+    ///     writer.assert_liveliness()
+    /// }
+    /// ```
+    pub fn assert_liveliness(&self) -> Result<()> {
+        self.liveliness.assert();
+        if let Some(ref announcer) = self.liveliness_announcer {
+            // Already announced, the liveliness thread need not repeat it
+            self.liveliness.take_pending();
+            announcer.announce().map_err(Error::IoError)?;
+        }
+        Ok(())
     }
 
     fn maybe_send_heartbeat(&self, last_seq: u64) {
@@ -652,6 +1289,12 @@ impl<T: DDS> DataWriter<T> {
 
         let hb = hb_tx_borrow.build_heartbeat(first_seq, last_seq);
 
+        // Announce only samples that left: a HEARTBEAT ahead of its batch
+        // would have readers NACK samples still waiting in it
+        if let Err(e) = self.flush_batch() {
+            log::debug!("[writer] batch send failed: {}", e);
+        }
+
         // v200: Use context-aware HEARTBEAT if available (for RELIABLE user data).
         // This ensures HEARTBEATs are sent with the correct writer entity ID so
         // readers can properly match and respond with ACKNACKs for retransmission.
@@ -667,50 +1310,128 @@ impl<T: DDS> DataWriter<T> {
         }
     }
 
-    /// Send a single RTPS packet to discovered endpoints or multicast fallback.
-    fn send_packet_to_endpoints(
+    /// Send one extra DATA per non-native type version, sharing `seq` with
+    /// the native copy. A failed conversion or send only drops that copy.
+    ///
+    /// Copies carry the native copy's coherent set tag: each reader keeps
+    /// exactly one copy, so per-topic set counts still match.
+    fn send_type_version_copies(
         &self,
         transport: &UdpTransport,
-        packet: &[u8],
-    ) -> std::result::Result<(), std::io::Error> {
-        if let Some(ref registry) = self.endpoint_registry {
-            let endpoints = registry.entries();
-            if endpoints.is_empty() {
-                log::debug!("[writer] No endpoints in registry, falling back to multicast");
-                return transport.send(packet);
-            }
+        msg: &T,
+        seq: u64,
+        source_ns: u64,
+        trace_id: u64,
+        coherent: Option<CoherentSetTag>,
+    ) {
+        let (Some(versions), Some(ctx)) = (&self.type_versions, self.rtps_endpoint) else {
+            return;
+        };
 
-            let local_guid = self
-                .rtps_endpoint
-                .map(|ctx| GUID::new(ctx.guid_prefix, RTPS_ENTITYID_PARTICIPANT));
-            let mut delivered = false;
+        let mut buf = vec![0u8; 65536];
+        for other in versions.others() {
+            let len = match (other.codec)(msg, &mut buf) {
+                Ok(len) => len,
+                Err(e) => {
+                    log::debug!(
+                        "[writer] type version {} ('{}') encode failed topic='{}' seq={}: {:?}",
+                        other.version,
+                        other.type_name,
+                        self.topic,
+                        seq,
+                        e
+                    );
+                    continue;
+                }
+            };
 
-            for (guid, endpoint) in endpoints {
-                if Some(guid) == local_guid {
+            #[cfg(feature = "security")]
+            let protected = match self.protect_payload(&buf[..len]) {
+                Ok(protected) => protected,
+                Err(e) => {
+                    log::debug!("[writer] type version {} skipped: {}", other.version, e);
                     continue;
                 }
+            };
+            #[cfg(feature = "security")]
+            let payload: &[u8] = protected.as_deref().unwrap_or(&buf[..len]);
+            #[cfg(not(feature = "security"))]
+            let payload: &[u8] = &buf[..len];
+
+            if builder::should_fragment(payload.len()) {
                 log::debug!(
-                    "[writer] Sending unicast USER DATA to endpoint={} guid={}",
-                    endpoint,
-                    guid
+                    "[writer] type version {} copy too large ({} bytes) topic='{}' seq={}; skipped",
+                    other.version,
+                    payload.len(),
+                    self.topic,
+                    seq
                 );
-                if transport.send_user_data_unicast(packet, &endpoint).is_ok() {
-                    delivered = true;
-                }
+                continue;
             }
 
-            if delivered {
-                Ok(())
-            } else {
-                log::debug!("[writer] No remote endpoints (self-only); falling back to multicast");
-                transport.send(packet)
+            let tag = versions.tag(other.version);
+            let packet = builder::build_data_packet_with_options(
+                &ctx,
+                &self.topic,
+                seq,
+                payload,
+                &builder::DataPacketOptions {
+                    key_hash: T::has_key().then(|| msg.compute_key()),
+                    trace_id: (trace_id != 0).then_some(trace_id),
+                    checksum: self.sample_checksum,
+                    type_version: Some(&tag),
+                    coherent_set: coherent,
+                    source_timestamp_ns: Some(source_ns),
+                    aligned: self.batch.is_some(),
+                    ..Default::default()
+                },
+            );
+            if packet.is_empty() {
+                continue;
+            }
+            if let Err(e) = self.send_data_packet(transport, &packet) {
+                log::debug!(
+                    "[writer] type version {} send failed topic='{}' seq={}: {}",
+                    other.version,
+                    self.topic,
+                    seq,
+                    e
+                );
             }
-        } else {
-            log::debug!("[writer] No endpoint_registry, falling back to multicast");
-            transport.send(packet)
         }
     }
 
+    /// Send a DATA packet, through the batch when the writer batches.
+    fn send_data_packet(
+        &self,
+        transport: &UdpTransport,
+        packet: &[u8],
+    ) -> std::result::Result<(), std::io::Error> {
+        match self.batch {
+            Some(ref batch) => batch.push(packet),
+            None => self.send_packet_to_endpoints(transport, packet),
+        }
+    }
+
+    /// Send the batched samples, if any.
+    fn flush_batch(&self) -> std::result::Result<(), std::io::Error> {
+        self.batch.as_ref().map_or(Ok(()), |batch| batch.flush())
+    }
+
+    /// Send a single RTPS packet to discovered endpoints or multicast fallback.
+    fn send_packet_to_endpoints(
+        &self,
+        transport: &UdpTransport,
+        packet: &[u8],
+    ) -> std::result::Result<(), std::io::Error> {
+        send_to_endpoints(
+            transport,
+            self.endpoint_registry.as_ref(),
+            self.rtps_endpoint.map(|ctx| ctx.guid_prefix),
+            packet,
+        )
+    }
+
     /// Send multiple RTPS packets (DATA_FRAG) to discovered endpoints or multicast fallback.
     fn send_packets_to_endpoints(
         &self,
@@ -780,3 +1501,49 @@ impl<T: DDS> DataWriter<T> {
         }
     }
 }
+
+/// Send a single RTPS packet to the endpoints of `registry`, or multicast it
+/// when no remote endpoint is known.
+///
+/// The participant `local_prefix` of the writer is skipped.
+pub(super) fn send_to_endpoints(
+    transport: &UdpTransport,
+    registry: Option<&crate::core::discovery::EndpointRegistry>,
+    local_prefix: Option<[u8; 12]>,
+    packet: &[u8],
+) -> std::result::Result<(), std::io::Error> {
+    if let Some(registry) = registry {
+        let endpoints = registry.entries();
+        if endpoints.is_empty() {
+            log::debug!("[writer] No endpoints in registry, falling back to multicast");
+            return transport.send(packet);
+        }
+
+        let local_guid = local_prefix.map(|prefix| GUID::new(prefix, RTPS_ENTITYID_PARTICIPANT));
+        let mut delivered = false;
+
+        for (guid, endpoint) in endpoints {
+            if Some(guid) == local_guid {
+                continue;
+            }
+            log::debug!(
+                "[writer] Sending unicast USER DATA to endpoint={} guid={}",
+                endpoint,
+                guid
+            );
+            if transport.send_user_data_unicast(packet, &endpoint).is_ok() {
+                delivered = true;
+            }
+        }
+
+        if delivered {
+            Ok(())
+        } else {
+            log::debug!("[writer] No remote endpoints (self-only); falling back to multicast");
+            transport.send(packet)
+        }
+    } else {
+        log::debug!("[writer] No endpoint_registry, falling back to multicast");
+        transport.send(packet)
+    }
+}
@@ -82,3 +82,61 @@ fn test_writer_without_transport_backward_compat() {
     let result = writer.write(&msg);
     assert!(result.is_ok() || matches!(result, Err(Error::WouldBlock)));
 }
+
+#[test]
+fn test_publication_matched_with_local_reader() {
+    use crate::dds::listener::{DataWriterListener, PublicationMatchedStatus};
+    use crate::generated::temperature::Temperature;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    #[derive(Default)]
+    struct Matched(Mutex<Vec<PublicationMatchedStatus>>);
+
+    impl DataWriterListener<Temperature> for Matched {
+        fn on_publication_matched(&self, status: PublicationMatchedStatus) {
+            self.0.lock().expect("lock").push(status);
+        }
+    }
+
+    let participant = crate::Participant::builder("writer_matched")
+        .build()
+        .expect("participant");
+    let topic = participant
+        .topic::<Temperature>("writer/matched")
+        .expect("topic");
+    let listener = Arc::new(Matched::default());
+    let writer = topic
+        .writer()
+        .qos(QoS::reliable())
+        .with_listener(listener.clone())
+        .build()
+        .expect("writer");
+    assert_eq!(writer.matched_readers_count(), 0);
+
+    let reader = topic.reader().qos(QoS::reliable()).build().expect("reader");
+    writer
+        .write(&Temperature {
+            value: 1.0,
+            timestamp: 1,
+        })
+        .expect("write");
+
+    let calls = listener.0.lock().expect("lock").clone();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].current_count, 1);
+    assert_eq!(calls[0].current_count_change, 1);
+    assert_eq!(writer.matched_readers_count(), 1);
+    writer
+        .wait_for_acknowledgments(Duration::from_millis(100))
+        .expect("same-process readers need no ACK");
+
+    // The listener consumes the change counters
+    drop(reader);
+    let status = writer.publication_matched_status();
+    assert_eq!(status.current_count, 0);
+    assert_eq!(status.current_count_change, 0);
+    assert_eq!(status.total_count, 1);
+    let last = listener.0.lock().expect("lock").last().cloned();
+    assert_eq!(last.map(|s| s.current_count_change), Some(-1));
+}
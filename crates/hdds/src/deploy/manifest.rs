@@ -0,0 +1,281 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! YAML schema of a deployment manifest and its validation.
+
+use crate::dds::qos::loaders::{YamlLoader, YamlQosProfile};
+use crate::dds::qos::profiles::QosProfileRegistry;
+use crate::dds::{Error, QoS, Result, TransportMode};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+
+/// Root of a manifest.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct Manifest {
+    /// Named QoS profiles, in the YAML QoS loader format.
+    #[serde(default)]
+    pub profiles: HashMap<String, YamlQosProfile>,
+    #[serde(default)]
+    pub topics: Vec<TopicEntry>,
+    pub participants: Vec<ParticipantEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct TopicEntry {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    #[serde(default)]
+    pub qos: Option<QosRef>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct ParticipantEntry {
+    pub name: String,
+    #[serde(default)]
+    pub domain_id: u32,
+    #[serde(default)]
+    pub transport: TransportEntry,
+    #[serde(default)]
+    pub writers: Vec<WriterEntry>,
+    #[serde(default)]
+    pub readers: Vec<ReaderEntry>,
+}
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum TransportEntry {
+    #[default]
+    UdpMulticast,
+    Tcp,
+    LoopbackBus,
+}
+
+impl From<TransportEntry> for TransportMode {
+    fn from(entry: TransportEntry) -> Self {
+        match entry {
+            TransportEntry::UdpMulticast => TransportMode::UdpMulticast,
+            TransportEntry::Tcp => TransportMode::Tcp,
+            TransportEntry::LoopbackBus => TransportMode::LoopbackBus,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct WriterEntry {
+    pub name: String,
+    pub topic: String,
+    #[serde(default)]
+    pub qos: Option<QosRef>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct ReaderEntry {
+    pub name: String,
+    pub topic: String,
+    #[serde(default)]
+    pub qos: Option<QosRef>,
+    /// Name of the callback receiving each sample.
+    #[serde(default)]
+    pub on_data: Option<String>,
+}
+
+/// QoS of a topic or endpoint: a profile name or an inline profile.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(super) enum QosRef {
+    Profile(String),
+    Inline(Box<YamlQosProfile>),
+}
+
+impl Manifest {
+    /// Parse a manifest from YAML.
+    pub(super) fn parse(yaml: &str) -> Result<Self> {
+        serde_yaml::from_str(yaml).map_err(|e| {
+            log::error!("[hdds] Invalid deployment manifest: {}", e);
+            Error::config(format!("invalid deployment manifest: {}", e))
+        })
+    }
+
+    /// Declared topic named `name`.
+    pub(super) fn topic(&self, name: &str) -> Option<&TopicEntry> {
+        self.topics.iter().find(|topic| topic.name == name)
+    }
+
+    /// Check names and references before anything is created: unique
+    /// participant, topic and endpoint names, declared topics, resolvable
+    /// QoS and registered callbacks.
+    pub(super) fn validate(&self, callbacks: &HashSet<&str>) -> Result<()> {
+        let mut topics = HashSet::new();
+        for topic in &self.topics {
+            if topic.name.is_empty() || !topics.insert(topic.name.as_str()) {
+                return invalid(format!("topic '{}' is empty or declared twice", topic.name));
+            }
+            self.resolve_qos(topic.qos.as_ref(), None)?;
+        }
+
+        let mut participants = HashSet::new();
+        let mut endpoints = HashSet::new();
+        for participant in &self.participants {
+            if participant.name.is_empty() || !participants.insert(participant.name.as_str()) {
+                return invalid(format!(
+                    "participant '{}' is empty or declared twice",
+                    participant.name
+                ));
+            }
+            let writers = participant
+                .writers
+                .iter()
+                .map(|w| (w.name.as_str(), w.topic.as_str(), w.qos.as_ref()));
+            let readers = participant
+                .readers
+                .iter()
+                .map(|r| (r.name.as_str(), r.topic.as_str(), r.qos.as_ref()));
+            for (name, topic, qos) in writers.chain(readers) {
+                if name.is_empty() || !endpoints.insert(name) {
+                    return invalid(format!("endpoint '{}' is empty or declared twice", name));
+                }
+                let Some(topic) = self.topic(topic) else {
+                    return invalid(format!(
+                        "endpoint '{}' uses undeclared topic '{}'",
+                        name, topic
+                    ));
+                };
+                self.resolve_qos(qos, Some(topic))?;
+            }
+            for reader in &participant.readers {
+                if let Some(callback) = reader.on_data.as_deref() {
+                    if !callbacks.contains(callback) {
+                        return invalid(format!(
+                            "reader '{}' uses unregistered callback '{}'",
+                            reader.name, callback
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// QoS of an endpoint: its own, else its topic's, else the default.
+    ///
+    /// Profile names are looked up in the manifest, then in the global
+    /// [`QosProfileRegistry`].
+    pub(super) fn resolve_qos(
+        &self,
+        qos: Option<&QosRef>,
+        topic: Option<&TopicEntry>,
+    ) -> Result<QoS> {
+        let qos = match (qos, topic) {
+            (Some(qos), _) => qos,
+            (None, Some(topic)) => return self.resolve_qos(topic.qos.as_ref(), None),
+            (None, None) => return Ok(QoS::default()),
+        };
+        let profile = match qos {
+            QosRef::Inline(profile) => profile,
+            QosRef::Profile(name) => match self.profiles.get(name) {
+                Some(profile) => profile,
+                None => {
+                    return QosProfileRegistry::global()
+                        .get(name)
+                        .map_or_else(|| invalid(format!("unknown QoS profile '{}'", name)), Ok)
+                }
+            },
+        };
+        YamlLoader::profile_to_qos(profile).or_else(|e| invalid(format!("invalid QoS: {}", e)))
+    }
+}
+
+fn invalid<T>(reason: String) -> Result<T> {
+    log::error!("[hdds] Invalid deployment manifest: {}", reason);
+    Err(Error::config(format!(
+        "invalid deployment manifest: {}",
+        reason
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dds::qos::{History, Reliability};
+
+    const MANIFEST: &str = r#"
+profiles:
+  sensor:
+    reliability: RELIABLE
+    history: { kind: KEEP_LAST, depth: 7 }
+topics:
+  - name: robot/pose
+    type: Pose
+    qos: sensor
+participants:
+  - name: robot
+    domain_id: 3
+    transport: loopback_bus
+    writers:
+      - name: pose_out
+        topic: robot/pose
+  - name: monitor
+    readers:
+      - name: pose_in
+        topic: robot/pose
+        qos: { reliability: BEST_EFFORT }
+        on_data: log_pose
+"#;
+
+    #[test]
+    fn test_manifest_parse_and_resolve_qos() {
+        let manifest = Manifest::parse(MANIFEST).expect("parse");
+        manifest
+            .validate(&HashSet::from(["log_pose"]))
+            .expect("valid");
+        assert_eq!(manifest.participants[0].domain_id, 3);
+        assert!(matches!(
+            TransportMode::from(manifest.participants[1].transport),
+            TransportMode::UdpMulticast
+        ));
+
+        let topic = manifest.topic("robot/pose");
+        let writer = &manifest.participants[0].writers[0];
+        let qos = manifest
+            .resolve_qos(writer.qos.as_ref(), topic)
+            .expect("writer qos");
+        assert!(matches!(qos.reliability, Reliability::Reliable));
+        assert!(matches!(qos.history, History::KeepLast(7)));
+        let reader = &manifest.participants[1].readers[0];
+        let qos = manifest
+            .resolve_qos(reader.qos.as_ref(), topic)
+            .expect("reader qos");
+        assert!(matches!(qos.reliability, Reliability::BestEffort));
+    }
+
+    #[test]
+    fn test_manifest_rejects_bad_references() {
+        let manifest = Manifest::parse(MANIFEST).expect("parse");
+        assert!(manifest.validate(&HashSet::new()).is_err(), "no callback");
+
+        for (from, to) in [
+            (
+                "topic: robot/pose\n        qos",
+                "topic: robot/twist\n        qos",
+            ),
+            ("qos: sensor", "qos: missing"),
+            ("name: pose_in", "name: pose_out"),
+            ("name: monitor", "name: robot"),
+        ] {
+            let manifest = Manifest::parse(&MANIFEST.replacen(from, to, 1)).expect("parse");
+            assert!(
+                manifest.validate(&HashSet::from(["log_pose"])).is_err(),
+                "{} -> {}",
+                from,
+                to
+            );
+        }
+        assert!(Manifest::parse(&MANIFEST.replace("domain_id", "domain")).is_err());
+    }
+}
@@ -0,0 +1,260 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Declarative deployments: participants, topics and endpoints from YAML.
+//!
+//! A manifest describes the data-plane layout of an application: its
+//! participants, the topics they use with their type names and QoS, and
+//! the writers and readers each participant creates. [`from_manifest`]
+//! creates all of it and returns the handles in a [`Deployment`], looked up
+//! by name. Endpoints are raw ([`RawDataWriter`], [`RawDataReader`]): they
+//! announce the declared type name and carry CDR payloads, so no Rust type
+//! is needed per variation of the layout.
+//!
+//! Readers can hand each payload to a callback named in the manifest and
+//! registered with [`Deployer::on_data`]. QoS are profile names or inline
+//! profiles in the [YAML QoS loader] format; names are looked up in the
+//! manifest's `profiles`, then in the global [`QosProfileRegistry`]. An
+//! endpoint without QoS uses its topic's.
+//!
+//! # Example YAML
+//!
+//! ```yaml
+//! profiles:
+//!   sensor:
+//!     reliability: RELIABLE
+//!     history: { kind: KEEP_LAST, depth: 10 }
+//! topics:
+//!   - name: robot/pose
+//!     type: geometry_msgs::msg::Pose
+//!     qos: sensor
+//! participants:
+//!   - name: robot
+//!     domain_id: 0
+//!     transport: udp_multicast   # or tcp, loopback_bus
+//!     writers:
+//!       - name: pose_out
+//!         topic: robot/pose
+//!   - name: monitor
+//!     readers:
+//!       - name: pose_in
+//!         topic: robot/pose
+//!         qos: { reliability: BEST_EFFORT }
+//!         on_data: log_pose
+//! ```
+//!
+//! The whole manifest is validated before the first entity is created:
+//! names must be unique, endpoints must use declared topics, and QoS and
+//! callbacks must resolve. Unknown keys are rejected.
+//!
+//! [YAML QoS loader]: crate::dds::qos::loaders::YamlLoader
+//! [`QosProfileRegistry`]: crate::dds::qos::profiles::QosProfileRegistry
+
+mod manifest;
+
+use crate::dds::{Error, ErrorContext, Participant, RawDataReader, RawDataWriter, Result};
+use manifest::Manifest;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+
+/// Callback receiving the raw CDR payload of each sample of a reader.
+type DataCallback = Arc<dyn Fn(&[u8]) + Send + Sync>;
+
+/// Create every entity declared by the manifest at `path`.
+///
+/// Manifests wiring reader callbacks need a [`Deployer`] holding them.
+///
+/// # Errors
+///
+/// [`Error::ConfigFileNotFound`] if the file cannot be read,
+/// [`Error::InvalidConfig`] if it is malformed or invalid, or the error of the
+/// first entity that cannot be created.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let deployment = hdds::deploy::from_manifest("app.yaml")?;
+/// if let Some(writer) = deployment.writer("pose_out") {
+///     writer.write_raw(&[0u8; 24])?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn from_manifest<P: AsRef<Path>>(path: P) -> Result<Deployment> {
+    Deployer::new().deploy_file(path)
+}
+
+/// Creates deployments, with the callbacks their manifests refer to.
+///
+/// # Example
+///
+/// ```no_run
+/// use hdds::deploy::Deployer;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let deployment = Deployer::new()
+///     .on_data("log_pose", |payload| println!("pose: {} bytes", payload.len()))
+///     .deploy_file("app.yaml")?;
+/// assert!(deployment.reader("pose_in").is_some());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct Deployer {
+    callbacks: HashMap<String, DataCallback>,
+}
+
+impl Deployer {
+    /// Create a deployer without callbacks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `callback` under `name`, for readers declaring
+    /// `on_data: <name>`.
+    ///
+    /// The callback receives the CDR payload of each sample, without its
+    /// encapsulation header, on the router thread: it should return
+    /// quickly. Samples are also kept for [`RawDataReader::try_take_raw`].
+    pub fn on_data<F>(mut self, name: impl Into<String>, callback: F) -> Self
+    where
+        F: Fn(&[u8]) + Send + Sync + 'static,
+    {
+        self.callbacks.insert(name.into(), Arc::new(callback));
+        self
+    }
+
+    /// Create every entity declared by the manifest at `path`.
+    ///
+    /// # Errors
+    ///
+    /// See [`from_manifest`].
+    pub fn deploy_file<P: AsRef<Path>>(&self, path: P) -> Result<Deployment> {
+        let path = path.as_ref();
+        let yaml = std::fs::read_to_string(path)
+            .map_err(|_| Error::ConfigFileNotFound(path.display().to_string()))?;
+        self.deploy_str(&yaml)
+    }
+
+    /// Create every entity declared by a YAML manifest.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::InvalidConfig`] if the manifest is malformed or invalid, or the
+    /// error of the first entity that cannot be created.
+    pub fn deploy_str(&self, yaml: &str) -> Result<Deployment> {
+        let manifest = Manifest::parse(yaml)?;
+        let callbacks: HashSet<&str> = self.callbacks.keys().map(String::as_str).collect();
+        manifest.validate(&callbacks)?;
+
+        let mut deployment = Deployment::default();
+        for entry in &manifest.participants {
+            let participant = Participant::builder(&entry.name)
+                .domain_id(entry.domain_id)
+                .with_transport(entry.transport.into())
+                .build()
+                .map_err(|e| {
+                    e.with_context(
+                        ErrorContext::new().entity(format!("participant '{}'", entry.name)),
+                    )
+                })?;
+
+            for writer in &entry.writers {
+                let context = ErrorContext::new()
+                    .entity(format!("writer '{}'", writer.name))
+                    .topic(&writer.topic);
+                let topic = manifest
+                    .topic(&writer.topic)
+                    .ok_or_else(|| Error::config("unknown topic").with_context(context.clone()))?;
+                let qos = manifest
+                    .resolve_qos(writer.qos.as_ref(), Some(topic))
+                    .map_err(|e| e.with_context(context.clone()))?;
+                let handle = participant
+                    .create_raw_writer_with_type(&topic.name, &topic.type_name, Some(qos), None)
+                    .map_err(|e| e.with_context(context))?;
+                deployment.writers.insert(writer.name.clone(), handle);
+            }
+            for reader in &entry.readers {
+                let context = ErrorContext::new()
+                    .entity(format!("reader '{}'", reader.name))
+                    .topic(&reader.topic);
+                let topic = manifest
+                    .topic(&reader.topic)
+                    .ok_or_else(|| Error::config("unknown topic").with_context(context.clone()))?;
+                let qos = manifest
+                    .resolve_qos(reader.qos.as_ref(), Some(topic))
+                    .map_err(|e| e.with_context(context.clone()))?;
+                let on_data = reader
+                    .on_data
+                    .as_ref()
+                    .and_then(|name| self.callbacks.get(name))
+                    .cloned();
+                let handle = participant
+                    .create_raw_reader_with_callback(
+                        &topic.name,
+                        &topic.type_name,
+                        Some(qos),
+                        None,
+                        on_data,
+                    )
+                    .map_err(|e| e.with_context(context))?;
+                deployment.readers.insert(reader.name.clone(), handle);
+            }
+
+            log::info!(
+                "[hdds] Deployed participant '{}' ({} writer(s), {} reader(s))",
+                entry.name,
+                entry.writers.len(),
+                entry.readers.len()
+            );
+            deployment
+                .participants
+                .insert(entry.name.clone(), participant);
+        }
+        Ok(deployment)
+    }
+}
+
+/// Entities created from a manifest, by name.
+///
+/// Dropping the deployment deletes its endpoints, then its participants.
+#[derive(Default)]
+pub struct Deployment {
+    writers: HashMap<String, RawDataWriter>,
+    readers: HashMap<String, RawDataReader>,
+    participants: HashMap<String, Arc<Participant>>,
+}
+
+impl Deployment {
+    /// Participant named `name`.
+    pub fn participant(&self, name: &str) -> Option<&Arc<Participant>> {
+        self.participants.get(name)
+    }
+
+    /// Writer named `name`.
+    pub fn writer(&self, name: &str) -> Option<&RawDataWriter> {
+        self.writers.get(name)
+    }
+
+    /// Reader named `name`.
+    pub fn reader(&self, name: &str) -> Option<&RawDataReader> {
+        self.readers.get(name)
+    }
+
+    /// Names of the participants, in no particular order.
+    pub fn participant_names(&self) -> impl Iterator<Item = &str> {
+        self.participants.keys().map(String::as_str)
+    }
+
+    /// Names of the writers, in no particular order.
+    pub fn writer_names(&self) -> impl Iterator<Item = &str> {
+        self.writers.keys().map(String::as_str)
+    }
+
+    /// Names of the readers, in no particular order.
+    pub fn reader_names(&self) -> impl Iterator<Item = &str> {
+        self.readers.keys().map(String::as_str)
+    }
+}
@@ -236,7 +236,10 @@ impl AwsCloudMap {
         let client = Client::builder()
             .timeout(std::time::Duration::from_secs(30))
             .build()
-            .map_err(|_| Error::Config)?;
+            .map_err(|e| {
+                Error::config(format!("HTTP client setup failed: {}", e))
+                    .with_context(crate::dds::ErrorContext::new().entity("AWS Cloud Map"))
+            })?;
 
         Ok(Self {
             namespace: namespace.into(),
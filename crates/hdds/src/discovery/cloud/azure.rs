@@ -225,7 +225,10 @@ impl AzureDiscovery {
         let client = Client::builder()
             .timeout(std::time::Duration::from_secs(30))
             .build()
-            .map_err(|_| Error::Config)?;
+            .map_err(|e| {
+                Error::config(format!("HTTP client setup failed: {}", e))
+                    .with_context(crate::dds::ErrorContext::new().entity("Azure DNS"))
+            })?;
 
         Ok(Self {
             dns_zone: dns_zone.into(),
@@ -33,6 +33,8 @@ use super::Locator;
 use super::{CloudDiscovery, ParticipantInfo};
 use crate::dds::Error;
 #[cfg(feature = "cloud-discovery")]
+use crate::dds::ErrorContext;
+#[cfg(feature = "cloud-discovery")]
 use std::collections::HashMap;
 #[cfg(feature = "cloud-discovery")]
 use std::sync::Arc;
@@ -218,6 +220,12 @@ fn extract_ipv4(locators: &[Locator]) -> Option<String> {
 // Implementation
 // ============================================================================
 
+/// Failed Consul API call.
+#[cfg(feature = "cloud-discovery")]
+fn consul_error(reason: String) -> Error {
+    Error::SendFailed(reason).with_context(ErrorContext::new().entity("Consul"))
+}
+
 impl ConsulDiscovery {
     /// Create a new Consul Service Discovery backend
     ///
@@ -229,7 +237,10 @@ impl ConsulDiscovery {
         let client = Client::builder()
             .timeout(std::time::Duration::from_secs(30))
             .build()
-            .map_err(|_e| Error::Config)?;
+            .map_err(|e| {
+                Error::config(format!("HTTP client setup failed: {}", e))
+                    .with_context(ErrorContext::new().entity("Consul"))
+            })?;
 
         Ok(Self {
             consul_addr: consul_addr.into(),
@@ -345,13 +356,16 @@ impl CloudDiscovery for ConsulDiscovery {
             .json(&registration)
             .send()
             .await
-            .map_err(|_| Error::Config)?;
+            .map_err(|e| consul_error(format!("registration request failed: {}", e)))?;
 
         if !response.status().is_success() {
             let status = response.status();
             let body = response.text().await.unwrap_or_default();
             log::error!("Consul registration failed: {} - {}", status, body);
-            return Err(Error::Config);
+            return Err(consul_error(format!(
+                "registration failed: {} - {}",
+                status, body
+            )));
         }
 
         // Store participant metadata in KV store for richer queries
@@ -400,15 +414,17 @@ impl CloudDiscovery for ConsulDiscovery {
             .get(&url)
             .send()
             .await
-            .map_err(|_| Error::Config)?;
+            .map_err(|e| consul_error(format!("discovery query failed: {}", e)))?;
 
         if !response.status().is_success() {
             log::warn!("Consul discovery query failed: {}", response.status());
             return Ok(vec![]);
         }
 
-        let services: Vec<ConsulCatalogService> =
-            response.json().await.map_err(|_| Error::Config)?;
+        let services: Vec<ConsulCatalogService> = response
+            .json()
+            .await
+            .map_err(|e| consul_error(format!("invalid discovery query response: {}", e)))?;
 
         let mut participants = Vec::with_capacity(services.len());
 
@@ -495,7 +511,7 @@ impl CloudDiscovery for ConsulDiscovery {
             .put(&url)
             .send()
             .await
-            .map_err(|_| Error::Config)?;
+            .map_err(|e| consul_error(format!("deregistration request failed: {}", e)))?;
 
         if !response.status().is_success() {
             log::warn!(
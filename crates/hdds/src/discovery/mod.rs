@@ -16,6 +16,7 @@
 //! | **Discovery Server** | Non-multicast networks | `hdds-discovery-server` crate |
 //! | **Cloud Discovery** | AWS, Azure, Consul | `cloud` |
 //! | **Static Peers** | Embedded, known endpoints | [`Participant::add_static_peer`](crate::Participant) |
+//! | **Initial Peers** | Unicast SPDP without multicast | [`ParticipantBuilder::with_initial_peers`](crate::dds::ParticipantBuilder::with_initial_peers) |
 //!
 //! ## How Discovery Works
 //!
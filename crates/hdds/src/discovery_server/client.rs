@@ -4,6 +4,11 @@
 //! Discovery Server client implementation.
 //!
 //! Provides async TCP client for connecting to a discovery server.
+//!
+//! The client remembers what it announced, so when a server shuts down and
+//! redirects it to another one it reconnects and announces again. The same
+//! replay runs when the connection is lost and the client fails over to one
+//! of the configured backup servers.
 
 use super::config::DiscoveryServerConfig;
 use super::protocol::{ClientMessage, EntityId, GuidPrefix, ServerMessage};
@@ -19,7 +24,13 @@ pub struct DiscoveryServerClient {
     config: DiscoveryServerConfig,
     state: ClientState,
     guid_prefix: GuidPrefix,
+    /// Server currently used (configured, backup or redirect target).
+    server_address: SocketAddr,
     last_heartbeat: Option<Instant>,
+    /// Last participant announcement (replayed after a redirect).
+    announced_participant: Option<ClientMessage>,
+    /// Endpoint announcements (replayed after a redirect).
+    announced_endpoints: Vec<ClientMessage>,
 }
 
 /// Internal client state.
@@ -66,6 +77,16 @@ pub enum ClientEvent {
         unicast_locators: Vec<SocketAddr>,
     },
 
+    /// Server shut down and redirected us to `server_address`.
+    ///
+    /// With auto-reconnect the client is already connected to the new
+    /// server and has announced itself again.
+    Redirected { server_address: SocketAddr },
+
+    /// Connection lost; the client reconnected to `server_address`, the
+    /// next reachable configured server, and announced itself again.
+    FailedOver { server_address: SocketAddr },
+
     /// Error from server.
     Error { code: u32, message: String },
 }
@@ -130,31 +151,83 @@ impl DiscoveryServerClient {
             .map_err(|e| ClientError::Config(e.to_string()))?;
 
         Ok(Self {
+            server_address: config.server_address,
             config,
             state: ClientState::Disconnected,
             guid_prefix,
             last_heartbeat: None,
+            announced_participant: None,
+            announced_endpoints: Vec::new(),
         })
     }
 
     /// Connect to the discovery server.
+    ///
+    /// Tries the current server first, then the other configured servers in
+    /// order.
     pub fn connect(&mut self) -> Result<(), ClientError> {
+        let candidates = self.candidates();
+        self.connect_any(&candidates)
+    }
+
+    /// Connect to the first reachable server of `candidates`.
+    fn connect_any(&mut self, candidates: &[SocketAddr]) -> Result<(), ClientError> {
         use std::net::TcpStream;
 
-        let stream =
-            TcpStream::connect_timeout(&self.config.server_address, self.config.connect_timeout)
-                .map_err(|e| ClientError::ConnectionFailed(e.to_string()))?;
+        let mut errors = Vec::new();
+        for &server_address in candidates {
+            let stream =
+                match TcpStream::connect_timeout(&server_address, self.config.connect_timeout) {
+                    Ok(stream) => stream,
+                    Err(e) => {
+                        errors.push(format!("{}: {}", server_address, e));
+                        continue;
+                    }
+                };
+
+            // Set TCP options
+            stream.set_nodelay(true).ok();
+            stream
+                .set_read_timeout(Some(self.config.connect_timeout))
+                .ok();
+
+            self.state = ClientState::Connected { stream };
+            self.server_address = server_address;
+            self.last_heartbeat = Some(Instant::now());
+            return Ok(());
+        }
 
-        // Set TCP options
-        stream.set_nodelay(true).ok();
-        stream
-            .set_read_timeout(Some(self.config.connect_timeout))
-            .ok();
+        Err(ClientError::ConnectionFailed(errors.join(", ")))
+    }
 
-        self.state = ClientState::Connected { stream };
-        self.last_heartbeat = Some(Instant::now());
+    /// Configured servers, starting with the current one.
+    fn candidates(&self) -> Vec<SocketAddr> {
+        let mut candidates = vec![self.server_address];
+        for server in
+            std::iter::once(&self.config.server_address).chain(&self.config.backup_servers)
+        {
+            if !candidates.contains(server) {
+                candidates.push(*server);
+            }
+        }
+        candidates
+    }
 
-        Ok(())
+    /// Move to the next reachable configured server and announce again.
+    ///
+    /// The current server is tried last. Returns the new server address.
+    pub fn failover(&mut self) -> Result<SocketAddr, ClientError> {
+        let mut candidates = self.candidates();
+        candidates.rotate_left(1);
+        log::info!(
+            "[discovery-server] lost {}, failing over ({} candidates)",
+            self.server_address,
+            candidates.len()
+        );
+        self.disconnect();
+        self.connect_any(&candidates)?;
+        self.replay_announcements()?;
+        Ok(self.server_address)
     }
 
     /// Check if connected to the server.
@@ -181,9 +254,12 @@ impl DiscoveryServerClient {
             name,
             unicast_locators,
             builtin_endpoints,
+            server_port: self.config.standby_port,
         };
 
-        self.send_message(&msg)
+        self.send_message(&msg)?;
+        self.announced_participant = Some(msg);
+        Ok(())
     }
 
     /// Announce an endpoint (writer or reader) to the server.
@@ -209,7 +285,12 @@ impl DiscoveryServerClient {
             unicast_locators,
         };
 
-        self.send_message(&msg)
+        self.send_message(&msg)?;
+        self.announced_endpoints.retain(|announced| {
+            !matches!(announced, ClientMessage::EndpointAnnounce { entity_id: id, .. } if *id == entity_id)
+        });
+        self.announced_endpoints.push(msg);
+        Ok(())
     }
 
     /// Send a heartbeat to keep the lease alive.
@@ -231,6 +312,8 @@ impl DiscoveryServerClient {
 
         let result = self.send_message(&msg);
         self.disconnect();
+        self.announced_participant = None;
+        self.announced_endpoints.clear();
         result
     }
 
@@ -255,15 +338,18 @@ impl DiscoveryServerClient {
         let mut len_buf = [0u8; 4];
         match stream.read_exact(&mut len_buf) {
             Ok(_) => {}
-            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {
-                self.state = ClientState::Disconnected;
-                return Ok(None);
-            }
             Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
                 return Ok(None);
             }
             Err(e) => {
                 self.state = ClientState::Disconnected;
+                if self.config.auto_reconnect && !self.config.backup_servers.is_empty() {
+                    let server_address = self.failover()?;
+                    return Ok(Some(ClientEvent::FailedOver { server_address }));
+                }
+                if e.kind() == io::ErrorKind::UnexpectedEof {
+                    return Ok(None);
+                }
                 return Err(ClientError::Io(e));
             }
         }
@@ -285,10 +371,51 @@ impl DiscoveryServerClient {
         // Parse message
         let msg = ServerMessage::decode(&buf).map_err(|e| ClientError::Protocol(e.to_string()))?;
 
+        if let ServerMessage::Redirect { server_address } = msg {
+            self.follow_redirect(server_address)?;
+        }
+
         // Convert to event
         Ok(Some(self.message_to_event(msg)))
     }
 
+    /// Switch to the server at `server_address`.
+    ///
+    /// With auto-reconnect, connects right away and replays the participant
+    /// and endpoint announcements; otherwise the next [`connect`] uses the
+    /// new address.
+    ///
+    /// [`connect`]: Self::connect
+    pub fn follow_redirect(&mut self, server_address: SocketAddr) -> Result<(), ClientError> {
+        log::info!(
+            "[discovery-server] redirected from {} to {}",
+            self.server_address,
+            server_address
+        );
+        self.disconnect();
+        self.server_address = server_address;
+        if !self.config.auto_reconnect {
+            return Ok(());
+        }
+
+        self.connect()?;
+        self.replay_announcements()
+    }
+
+    /// Send the participant and endpoint announcements again.
+    fn replay_announcements(&mut self) -> Result<(), ClientError> {
+        let announcements: Vec<ClientMessage> = self
+            .announced_participant
+            .iter()
+            .chain(&self.announced_endpoints)
+            .cloned()
+            .collect();
+        for msg in &announcements {
+            self.send_message(msg)?;
+        }
+        Ok(())
+    }
+
     /// Send a message to the server.
     fn send_message(&mut self, msg: &ClientMessage) -> Result<(), ClientError> {
         use std::io::Write;
@@ -351,6 +478,10 @@ impl DiscoveryServerClient {
                 unicast_locators,
             },
 
+            ServerMessage::Redirect { server_address } => {
+                ClientEvent::Redirected { server_address }
+            }
+
             ServerMessage::Error { code, message } => ClientEvent::Error { code, message },
         }
     }
@@ -364,9 +495,9 @@ impl DiscoveryServerClient {
         }
     }
 
-    /// Get the server address.
+    /// Get the address of the server in use.
     pub fn server_address(&self) -> SocketAddr {
-        self.config.server_address
+        self.server_address
     }
 
     /// Get the configuration.
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 // Copyright (c) 2025-2026 naskel.com
 
-//! Discovery Server client configuration.
+//! Discovery Server client and embedded server configuration.
 
 use std::net::SocketAddr;
 use std::time::Duration;
@@ -12,6 +12,10 @@ pub struct DiscoveryServerConfig {
     /// Discovery server address (host:port).
     pub server_address: SocketAddr,
 
+    /// Redundant servers, tried in order when `server_address` is
+    /// unreachable or the connection to it is lost.
+    pub backup_servers: Vec<SocketAddr>,
+
     /// Connection timeout.
     pub connect_timeout: Duration,
 
@@ -32,6 +36,10 @@ pub struct DiscoveryServerConfig {
 
     /// Disable multicast discovery when using server.
     pub disable_multicast: bool,
+
+    /// Port of a standby embedded server run by this participant, announced
+    /// so the current server can redirect clients to it when it shuts down.
+    pub standby_port: Option<u16>,
 }
 
 impl Default for DiscoveryServerConfig {
@@ -39,6 +47,7 @@ impl Default for DiscoveryServerConfig {
         use std::net::{IpAddr, Ipv4Addr, SocketAddr};
         Self {
             server_address: SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 7400),
+            backup_servers: Vec::new(),
             connect_timeout: Duration::from_secs(5),
             reconnect_delay: Duration::from_secs(1),
             max_reconnect_attempts: 10,
@@ -46,6 +55,7 @@ impl Default for DiscoveryServerConfig {
             auto_reconnect: true,
             max_message_size: 16 * 1024 * 1024,
             disable_multicast: true,
+            standby_port: None,
         }
     }
 }
@@ -59,6 +69,12 @@ impl DiscoveryServerConfig {
         }
     }
 
+    /// Builder: add a redundant server to fail over to.
+    pub fn with_backup_server(mut self, server_address: SocketAddr) -> Self {
+        self.backup_servers.push(server_address);
+        self
+    }
+
     /// Builder: set connection timeout.
     pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
         self.connect_timeout = timeout;
@@ -95,6 +111,12 @@ impl DiscoveryServerConfig {
         self
     }
 
+    /// Builder: announce a standby embedded server on `port`.
+    pub fn with_standby_port(mut self, port: u16) -> Self {
+        self.standby_port = Some(port);
+        self
+    }
+
     /// Validate configuration.
     pub fn validate(&self) -> Result<(), &'static str> {
         if self.connect_timeout.is_zero() {
@@ -110,6 +132,69 @@ impl DiscoveryServerConfig {
     }
 }
 
+/// Configuration of a Discovery Server embedded in a participant.
+///
+/// See [`ParticipantBuilder::as_discovery_server`](crate::ParticipantBuilder::as_discovery_server).
+#[derive(Debug, Clone)]
+pub struct EmbeddedServerConfig {
+    /// GUID prefix identifying the server.
+    pub guid_prefix: [u8; 12],
+
+    /// TCP port to listen on (all interfaces).
+    pub port: u16,
+
+    /// Server to redirect clients to on shutdown (default: a standby
+    /// participant's server, if one announced itself).
+    pub fallback: Option<SocketAddr>,
+
+    /// Participant lease duration (clients must heartbeat within it).
+    pub lease_duration: Duration,
+
+    /// Redundant servers to replicate registrations with. Each server
+    /// forwards only its own clients, so every server of the group must
+    /// list all the others.
+    pub peers: Vec<SocketAddr>,
+}
+
+impl EmbeddedServerConfig {
+    /// Create a configuration for a server listening on `port`.
+    pub fn new(guid_prefix: [u8; 12], port: u16) -> Self {
+        Self {
+            guid_prefix,
+            port,
+            fallback: None,
+            lease_duration: Duration::from_secs(30),
+            peers: Vec::new(),
+        }
+    }
+
+    /// Builder: redirect clients to `fallback` on shutdown.
+    pub fn with_fallback(mut self, fallback: SocketAddr) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+
+    /// Builder: set the participant lease duration.
+    pub fn with_lease_duration(mut self, lease_duration: Duration) -> Self {
+        self.lease_duration = lease_duration;
+        self
+    }
+
+    /// Builder: replicate registrations with the server at `peer`.
+    pub fn with_peer(mut self, peer: SocketAddr) -> Self {
+        self.peers.push(peer);
+        self
+    }
+
+    /// Validate configuration.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if self.lease_duration.is_zero() {
+            return Err("lease_duration must be > 0");
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -137,13 +222,20 @@ mod tests {
             .with_heartbeat_interval(Duration::from_secs(5))
             .with_max_reconnect_attempts(20)
             .without_auto_reconnect()
-            .with_multicast_enabled();
+            .with_multicast_enabled()
+            .with_standby_port(41234)
+            .with_backup_server("10.0.0.2:7400".parse().unwrap());
 
         assert_eq!(config.connect_timeout, Duration::from_secs(10));
         assert_eq!(config.heartbeat_interval, Duration::from_secs(5));
         assert_eq!(config.max_reconnect_attempts, 20);
         assert!(!config.auto_reconnect);
         assert!(!config.disable_multicast);
+        assert_eq!(config.standby_port, Some(41234));
+        assert_eq!(
+            config.backup_servers,
+            vec!["10.0.0.2:7400".parse().unwrap()]
+        );
     }
 
     #[test]
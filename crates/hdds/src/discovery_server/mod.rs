@@ -4,7 +4,9 @@
 //! Discovery Server client support.
 //!
 //! This module provides client-side support for connecting to a Discovery Server
-//! instead of using multicast-based discovery.
+//! instead of using multicast-based discovery, and an embedded server so a
+//! participant can serve discovery itself (see
+//! [`ParticipantBuilder::as_discovery_server`](crate::ParticipantBuilder::as_discovery_server)).
 //!
 //! # Use Cases
 //!
@@ -28,7 +30,9 @@
 mod client;
 mod config;
 mod protocol;
+mod server;
 
 pub use client::{ClientError, ClientEvent, DiscoveryServerClient};
-pub use config::DiscoveryServerConfig;
+pub use config::{DiscoveryServerConfig, EmbeddedServerConfig};
 pub use protocol::{ClientMessage, ServerMessage};
+pub use server::EmbeddedDiscoveryServer;
@@ -1,9 +1,11 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 // Copyright (c) 2025-2026 naskel.com
 
-//! Discovery Server wire protocol (client-side).
+//! Discovery Server wire protocol.
 //!
-//! Compatible with hdds-discovery-server protocol.
+//! Compatible with hdds-discovery-server protocol. The server-side codecs
+//! ([`ClientMessage::decode`], [`ServerMessage::encode`]) serve the embedded
+//! server.
 
 use std::io;
 use std::net::SocketAddr;
@@ -24,6 +26,8 @@ pub enum ClientMessage {
         name: Option<String>,
         unicast_locators: Vec<SocketAddr>,
         builtin_endpoints: u32,
+        /// Port of a standby embedded server this participant runs.
+        server_port: Option<u16>,
     },
 
     /// Announce an endpoint (writer/reader).
@@ -43,6 +47,11 @@ pub enum ClientMessage {
 
     /// Participant is leaving.
     ParticipantLeave { guid_prefix: GuidPrefix },
+
+    /// Opens a link from a peer server: the announcements that follow on
+    /// the connection replicate the participants registered with that
+    /// server.
+    ServerHello { guid_prefix: GuidPrefix },
 }
 
 /// Messages received from the server.
@@ -75,6 +84,9 @@ pub enum ServerMessage {
         unicast_locators: Vec<SocketAddr>,
     },
 
+    /// Server is shutting down: reconnect to `server_address`.
+    Redirect { server_address: SocketAddr },
+
     /// Error from server.
     Error { code: u32, message: String },
 }
@@ -86,13 +98,15 @@ pub enum ServerMessage {
 impl ClientMessage {
     /// Encode message to wire format.
     pub fn encode(&self) -> io::Result<Vec<u8>> {
-        let json = self.to_json()?;
-        let len = json.len() as u32;
+        Ok(frame(&self.to_json()?))
+    }
 
-        let mut buf = Vec::with_capacity(4 + json.len());
-        buf.extend_from_slice(&len.to_be_bytes());
-        buf.extend_from_slice(json.as_bytes());
-        Ok(buf)
+    /// Decode message from wire format (without length prefix).
+    pub fn decode(data: &[u8]) -> io::Result<Self> {
+        let s =
+            std::str::from_utf8(data).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        Self::from_json(s)
     }
 
     fn to_json(&self) -> io::Result<String> {
@@ -103,21 +117,19 @@ impl ClientMessage {
                 name,
                 unicast_locators,
                 builtin_endpoints,
+                server_port,
             } => {
-                let json = format!(
-                    r#"{{"type":"participant_announce","guid_prefix":"{}","domain_id":{},"name":{},"unicast_locators":[{}],"vendor_id":[1,16],"protocol_version":[2,4],"builtin_endpoints":{}}}"#,
-                    hex_encode(guid_prefix),
-                    domain_id,
-                    name.as_ref()
-                        .map(|n| format!("\"{}\"", n))
-                        .unwrap_or_else(|| "null".to_string()),
-                    unicast_locators
-                        .iter()
-                        .map(|a| format!("\"{}\"", a))
-                        .collect::<Vec<_>>()
-                        .join(","),
-                    builtin_endpoints,
+                let mut json = participant_announce_json(
+                    guid_prefix,
+                    *domain_id,
+                    name.as_deref(),
+                    unicast_locators,
+                    *builtin_endpoints,
                 );
+                if let Some(port) = server_port {
+                    json.pop();
+                    json.push_str(&format!(r#","server_port":{}}}"#, port));
+                }
                 Ok(json)
             }
 
@@ -130,45 +142,152 @@ impl ClientMessage {
                 reliable,
                 durability,
                 unicast_locators,
-            } => {
+            } => Ok(endpoint_announce_json(
+                guid_prefix,
+                entity_id,
+                topic_name,
+                type_name,
+                *is_writer,
+                *reliable,
+                *durability,
+                unicast_locators,
+            )),
+
+            Self::Heartbeat { guid_prefix } => {
                 let json = format!(
-                    r#"{{"type":"endpoint_announce","guid_prefix":"{}","entity_id":"{}","topic_name":"{}","type_name":"{}","is_writer":{},"reliable":{},"durability":{},"unicast_locators":[{}]}}"#,
+                    r#"{{"type":"heartbeat","guid_prefix":"{}"}}"#,
                     hex_encode(guid_prefix),
-                    hex_encode(entity_id),
-                    topic_name,
-                    type_name,
-                    is_writer,
-                    reliable,
-                    durability,
-                    unicast_locators
-                        .iter()
-                        .map(|a| format!("\"{}\"", a))
-                        .collect::<Vec<_>>()
-                        .join(","),
                 );
                 Ok(json)
             }
 
-            Self::Heartbeat { guid_prefix } => {
+            Self::ParticipantLeave { guid_prefix } => {
                 let json = format!(
-                    r#"{{"type":"heartbeat","guid_prefix":"{}"}}"#,
+                    r#"{{"type":"participant_leave","guid_prefix":"{}"}}"#,
                     hex_encode(guid_prefix),
                 );
                 Ok(json)
             }
 
-            Self::ParticipantLeave { guid_prefix } => {
+            Self::ServerHello { guid_prefix } => {
                 let json = format!(
-                    r#"{{"type":"participant_leave","guid_prefix":"{}"}}"#,
+                    r#"{{"type":"server_hello","guid_prefix":"{}"}}"#,
                     hex_encode(guid_prefix),
                 );
                 Ok(json)
             }
         }
     }
+
+    fn from_json(s: &str) -> io::Result<Self> {
+        let msg_type = extract_string_field(s, "type")?;
+
+        match msg_type.as_str() {
+            "participant_announce" => Ok(Self::ParticipantAnnounce {
+                guid_prefix: extract_guid_prefix(s, "guid_prefix")?,
+                domain_id: extract_u32_field(s, "domain_id").unwrap_or(0),
+                name: extract_string_field(s, "name").ok(),
+                unicast_locators: extract_locators(s, "unicast_locators"),
+                builtin_endpoints: extract_u32_field(s, "builtin_endpoints").unwrap_or(0),
+                server_port: extract_u32_field(s, "server_port")
+                    .and_then(|port| u16::try_from(port).ok()),
+            }),
+
+            "endpoint_announce" => Ok(Self::EndpointAnnounce {
+                guid_prefix: extract_guid_prefix(s, "guid_prefix")?,
+                entity_id: extract_entity_id(s, "entity_id")?,
+                topic_name: extract_string_field(s, "topic_name")?,
+                type_name: extract_string_field(s, "type_name")?,
+                is_writer: extract_bool_field(s, "is_writer").unwrap_or(false),
+                reliable: extract_bool_field(s, "reliable").unwrap_or(false),
+                durability: extract_u32_field(s, "durability").unwrap_or(0) as u8,
+                unicast_locators: extract_locators(s, "unicast_locators"),
+            }),
+
+            "heartbeat" => Ok(Self::Heartbeat {
+                guid_prefix: extract_guid_prefix(s, "guid_prefix")?,
+            }),
+
+            "participant_leave" => Ok(Self::ParticipantLeave {
+                guid_prefix: extract_guid_prefix(s, "guid_prefix")?,
+            }),
+
+            "server_hello" => Ok(Self::ServerHello {
+                guid_prefix: extract_guid_prefix(s, "guid_prefix")?,
+            }),
+
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Unknown message type: {}", msg_type),
+            )),
+        }
+    }
 }
 
 impl ServerMessage {
+    /// Encode message to wire format.
+    pub fn encode(&self) -> io::Result<Vec<u8>> {
+        Ok(frame(&self.to_json()))
+    }
+
+    fn to_json(&self) -> String {
+        match self {
+            Self::ParticipantAck { guid_prefix } => format!(
+                r#"{{"type":"participant_ack","guid_prefix":"{}"}}"#,
+                hex_encode(guid_prefix),
+            ),
+
+            Self::ParticipantAnnounce {
+                guid_prefix,
+                domain_id,
+                name,
+                unicast_locators,
+                builtin_endpoints,
+            } => participant_announce_json(
+                guid_prefix,
+                *domain_id,
+                name.as_deref(),
+                unicast_locators,
+                *builtin_endpoints,
+            ),
+
+            Self::ParticipantLeave { guid_prefix } => format!(
+                r#"{{"type":"participant_leave","guid_prefix":"{}"}}"#,
+                hex_encode(guid_prefix),
+            ),
+
+            Self::EndpointAnnounce {
+                guid_prefix,
+                entity_id,
+                topic_name,
+                type_name,
+                is_writer,
+                reliable,
+                durability,
+                unicast_locators,
+            } => endpoint_announce_json(
+                guid_prefix,
+                entity_id,
+                topic_name,
+                type_name,
+                *is_writer,
+                *reliable,
+                *durability,
+                unicast_locators,
+            ),
+
+            Self::Redirect { server_address } => format!(
+                r#"{{"type":"redirect","server_address":"{}"}}"#,
+                server_address,
+            ),
+
+            Self::Error { code, message } => format!(
+                r#"{{"type":"error","code":{},"message":"{}"}}"#,
+                code, message,
+            ),
+        }
+    }
+
     /// Decode message from wire format (without length prefix).
     pub fn decode(data: &[u8]) -> io::Result<Self> {
         // Simple JSON parsing (minimal, no serde dependency in core)
@@ -232,6 +351,17 @@ impl ServerMessage {
                 })
             }
 
+            "redirect" => {
+                let address = extract_string_field(s, "server_address")?;
+                let server_address = address.parse().map_err(|_| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("Invalid redirect address: {}", address),
+                    )
+                })?;
+                Ok(Self::Redirect { server_address })
+            }
+
             "error" => {
                 let code = extract_u32_field(s, "code").unwrap_or(0);
                 let message = extract_string_field(s, "message").unwrap_or_default();
@@ -246,6 +376,70 @@ impl ServerMessage {
     }
 }
 
+// ============================================================================
+// JSON encoding helpers
+// ============================================================================
+
+/// Prefix a JSON payload with its length (4 bytes, big-endian).
+fn frame(json: &str) -> Vec<u8> {
+    let len = json.len() as u32;
+
+    let mut buf = Vec::with_capacity(4 + json.len());
+    buf.extend_from_slice(&len.to_be_bytes());
+    buf.extend_from_slice(json.as_bytes());
+    buf
+}
+
+fn locators_json(locators: &[SocketAddr]) -> String {
+    locators
+        .iter()
+        .map(|a| format!("\"{}\"", a))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn participant_announce_json(
+    guid_prefix: &GuidPrefix,
+    domain_id: u32,
+    name: Option<&str>,
+    unicast_locators: &[SocketAddr],
+    builtin_endpoints: u32,
+) -> String {
+    format!(
+        r#"{{"type":"participant_announce","guid_prefix":"{}","domain_id":{},"name":{},"unicast_locators":[{}],"vendor_id":[1,16],"protocol_version":[2,4],"builtin_endpoints":{}}}"#,
+        hex_encode(guid_prefix),
+        domain_id,
+        name.map(|n| format!("\"{}\"", n))
+            .unwrap_or_else(|| "null".to_string()),
+        locators_json(unicast_locators),
+        builtin_endpoints,
+    )
+}
+
+#[allow(clippy::too_many_arguments)] // Discovery protocol fields
+fn endpoint_announce_json(
+    guid_prefix: &GuidPrefix,
+    entity_id: &EntityId,
+    topic_name: &str,
+    type_name: &str,
+    is_writer: bool,
+    reliable: bool,
+    durability: u8,
+    unicast_locators: &[SocketAddr],
+) -> String {
+    format!(
+        r#"{{"type":"endpoint_announce","guid_prefix":"{}","entity_id":"{}","topic_name":"{}","type_name":"{}","is_writer":{},"reliable":{},"durability":{},"unicast_locators":[{}]}}"#,
+        hex_encode(guid_prefix),
+        hex_encode(entity_id),
+        topic_name,
+        type_name,
+        is_writer,
+        reliable,
+        durability,
+        locators_json(unicast_locators),
+    )
+}
+
 // ============================================================================
 // JSON parsing helpers (minimal, no serde)
 // ============================================================================
@@ -374,6 +568,7 @@ mod tests {
             name: Some("TestParticipant".into()),
             unicast_locators: vec!["192.168.1.1:7400".parse().unwrap()],
             builtin_endpoints: 0x3f,
+            server_port: None,
         };
 
         let encoded = msg.encode().unwrap();
@@ -462,6 +657,73 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_client_message_roundtrip_with_server_port() {
+        let msg = ClientMessage::ParticipantAnnounce {
+            guid_prefix: [7; 12],
+            domain_id: 3,
+            name: Some("Standby".into()),
+            unicast_locators: vec!["10.0.0.2:7411".parse().unwrap()],
+            builtin_endpoints: 0x3f,
+            server_port: Some(41234),
+        };
+
+        let encoded = msg.encode().unwrap();
+        match ClientMessage::decode(&encoded[4..]).unwrap() {
+            ClientMessage::ParticipantAnnounce {
+                guid_prefix,
+                domain_id,
+                name,
+                unicast_locators,
+                server_port,
+                ..
+            } => {
+                assert_eq!(guid_prefix, [7; 12]);
+                assert_eq!(domain_id, 3);
+                assert_eq!(name, Some("Standby".into()));
+                assert_eq!(unicast_locators.len(), 1);
+                assert_eq!(server_port, Some(41234));
+            }
+            other => assert!(
+                matches!(other, ClientMessage::ParticipantAnnounce { .. }),
+                "Expected ParticipantAnnounce, got {:?}",
+                other
+            ),
+        }
+    }
+
+    #[test]
+    fn test_server_hello_roundtrip() {
+        let msg = ClientMessage::ServerHello {
+            guid_prefix: [0xd5; 12],
+        };
+
+        let encoded = msg.encode().unwrap();
+        assert!(matches!(
+            ClientMessage::decode(&encoded[4..]).unwrap(),
+            ClientMessage::ServerHello { guid_prefix } if guid_prefix == [0xd5; 12]
+        ));
+    }
+
+    #[test]
+    fn test_server_message_redirect_roundtrip() {
+        let msg = ServerMessage::Redirect {
+            server_address: "10.0.0.2:41234".parse().unwrap(),
+        };
+
+        let encoded = msg.encode().unwrap();
+        match ServerMessage::decode(&encoded[4..]).unwrap() {
+            ServerMessage::Redirect { server_address } => {
+                assert_eq!(server_address, "10.0.0.2:41234".parse().unwrap());
+            }
+            other => assert!(
+                matches!(other, ServerMessage::Redirect { .. }),
+                "Expected Redirect, got {:?}",
+                other
+            ),
+        }
+    }
+
     #[test]
     fn test_hex_roundtrip() {
         let data = [0xde, 0xad, 0xbe, 0xef];
@@ -0,0 +1,1050 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Discovery Server embedded in a participant.
+//!
+//! A blocking TCP server speaking the hdds-discovery-server protocol, so a
+//! small deployment can let one participant serve discovery instead of
+//! running the standalone binary. Announcements are relayed to every other
+//! client, and a client joining late receives the participants and endpoints
+//! already registered.
+//!
+//! On shutdown the server sends each client a `redirect` to its successor --
+//! the configured fallback, else the standby server announced by a
+//! registered participant (lowest GUID prefix first), else the first peer --
+//! and the clients reconnect there.
+//!
+//! For redundancy, servers listed as peers link to each other: each server
+//! opens a connection to every peer, says `server_hello` and forwards the
+//! registrations of its own clients. Participants registered on one server
+//! are thus discovered by the clients of all the others, and a client
+//! failing over to another server of the group finds the same database.
+//! Replicated registrations are not forwarded again, so the peers must form
+//! a full mesh.
+
+use super::config::EmbeddedServerConfig;
+use super::protocol::{ClientMessage, GuidPrefix, ServerMessage};
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use std::net::{Ipv4Addr, Shutdown, SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Accept loop polling interval (also the lease check period).
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Write timeout, so a stalled client cannot block the others.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Maximum accepted message size.
+const MAX_MESSAGE_SIZE: usize = 16 * 1024 * 1024;
+
+/// Timeout when linking to a peer server.
+const PEER_CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Delay before linking again to an unreachable peer server.
+const PEER_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Discovery Server running inside a participant.
+///
+/// Stops (and redirects its clients) when dropped.
+pub struct EmbeddedDiscoveryServer {
+    config: EmbeddedServerConfig,
+    local_addr: SocketAddr,
+    shared: Arc<Shared>,
+    accept_thread: Option<JoinHandle<()>>,
+    peer_threads: Vec<JoinHandle<()>>,
+}
+
+struct Shared {
+    guid_prefix: GuidPrefix,
+    running: AtomicBool,
+    lease_duration: Duration,
+    clients: Mutex<HashMap<u64, Client>>,
+    /// Write halves of the links to peer servers.
+    ///
+    /// Lock order: `clients` before `peer_links`.
+    peer_links: Mutex<HashMap<SocketAddr, TcpStream>>,
+}
+
+/// A connected client.
+struct Client {
+    /// Write half of the connection.
+    stream: TcpStream,
+    peer_addr: SocketAddr,
+    /// Our address as seen by the client.
+    local_addr: SocketAddr,
+    registration: Option<Registration>,
+    /// Registrations replicated by a peer server (`Some` once the
+    /// connection said `server_hello`).
+    replicated: Option<HashMap<GuidPrefix, Registration>>,
+    last_seen: Instant,
+}
+
+/// Participant registered over a connection.
+struct Registration {
+    guid_prefix: GuidPrefix,
+    announce: ServerMessage,
+    endpoints: Vec<ServerMessage>,
+    server_port: Option<u16>,
+}
+
+impl EmbeddedDiscoveryServer {
+    /// Bind `0.0.0.0:<port>` and start serving.
+    ///
+    /// Fails with [`io::ErrorKind::AddrInUse`] when another server (usually
+    /// another participant on this host) already holds the port.
+    pub fn bind(config: EmbeddedServerConfig) -> io::Result<Self> {
+        config
+            .validate()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let listener = TcpListener::bind((Ipv4Addr::UNSPECIFIED, config.port))?;
+        listener.set_nonblocking(true)?;
+        let local_addr = listener.local_addr()?;
+
+        let shared = Arc::new(Shared {
+            guid_prefix: config.guid_prefix,
+            running: AtomicBool::new(true),
+            lease_duration: config.lease_duration,
+            clients: Mutex::new(HashMap::new()),
+            peer_links: Mutex::new(HashMap::new()),
+        });
+        let accept_shared = Arc::clone(&shared);
+        let accept_thread = thread::Builder::new()
+            .name("hdds-ds-accept".into())
+            .spawn(move || accept_loop(listener, accept_shared))?;
+
+        let mut peer_threads = Vec::with_capacity(config.peers.len());
+        for &peer in &config.peers {
+            let peer_shared = Arc::clone(&shared);
+            let spawned = thread::Builder::new()
+                .name("hdds-ds-peer".into())
+                .spawn(move || peer_loop(peer, &peer_shared));
+            match spawned {
+                Ok(handle) => peer_threads.push(handle),
+                Err(e) => {
+                    shared.running.store(false, Ordering::Release);
+                    let _ = accept_thread.join();
+                    for handle in peer_threads {
+                        let _ = handle.join();
+                    }
+                    return Err(e);
+                }
+            }
+        }
+
+        log::info!(
+            "[discovery-server] embedded server {} listening on {}",
+            hex(&config.guid_prefix),
+            local_addr
+        );
+
+        Ok(Self {
+            config,
+            local_addr,
+            shared,
+            accept_thread: Some(accept_thread),
+            peer_threads,
+        })
+    }
+
+    /// GUID prefix identifying the server.
+    pub fn guid_prefix(&self) -> &[u8; 12] {
+        &self.config.guid_prefix
+    }
+
+    /// Address the server listens on.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Number of peer servers currently linked to.
+    pub fn linked_peer_count(&self) -> usize {
+        self.shared.lock_peer_links().len()
+    }
+
+    /// Number of participants registered directly with this server.
+    pub fn participant_count(&self) -> usize {
+        self.shared
+            .lock_clients()
+            .values()
+            .filter(|client| client.registration.is_some())
+            .count()
+    }
+
+    /// Check if the server is running.
+    pub fn is_running(&self) -> bool {
+        self.shared.running.load(Ordering::Acquire)
+    }
+
+    /// Stop the server, redirecting its clients to the successor.
+    pub fn shutdown(&mut self) {
+        if !self.shared.running.swap(false, Ordering::AcqRel) {
+            return;
+        }
+
+        {
+            let mut clients = self.shared.lock_clients();
+            let successor = self.successor(&clients);
+            for client in clients.values_mut() {
+                if let Some(successor) = successor {
+                    let server_address =
+                        if successor.ip().is_loopback() && !client.peer_addr.ip().is_loopback() {
+                            // Standby on this host: reachable where the client reached us
+                            SocketAddr::new(client.local_addr.ip(), successor.port())
+                        } else {
+                            successor
+                        };
+                    let _ = send(
+                        &mut client.stream,
+                        &ServerMessage::Redirect { server_address },
+                    );
+                }
+                let _ = client.stream.shutdown(Shutdown::Both);
+            }
+            log::info!(
+                "[discovery-server] embedded server {} stopped ({} clients, successor {:?})",
+                hex(&self.config.guid_prefix),
+                clients.len(),
+                successor
+            );
+            clients.clear();
+        }
+
+        for (_, link) in self.shared.lock_peer_links().drain() {
+            let _ = link.shutdown(Shutdown::Both);
+        }
+        if let Some(handle) = self.accept_thread.take() {
+            let _ = handle.join();
+        }
+        for handle in self.peer_threads.drain(..) {
+            let _ = handle.join();
+        }
+    }
+
+    /// Server the clients move to when this one shuts down.
+    fn successor(&self, clients: &HashMap<u64, Client>) -> Option<SocketAddr> {
+        if self.config.fallback.is_some() {
+            return self.config.fallback;
+        }
+        clients
+            .values()
+            .filter_map(|client| {
+                let registration = client.registration.as_ref()?;
+                let port = registration.server_port?;
+                Some((
+                    registration.guid_prefix,
+                    SocketAddr::new(client.peer_addr.ip(), port),
+                ))
+            })
+            .min_by_key(|(guid_prefix, _)| *guid_prefix)
+            .map(|(_, addr)| addr)
+            .or_else(|| self.config.peers.first().copied())
+    }
+}
+
+impl Drop for EmbeddedDiscoveryServer {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+impl Shared {
+    fn lock_clients(&self) -> MutexGuard<'_, HashMap<u64, Client>> {
+        match self.clients.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    fn lock_peer_links(&self) -> MutexGuard<'_, HashMap<SocketAddr, TcpStream>> {
+        match self.peer_links.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    /// Forward a change of a local registration to the peer servers.
+    fn replicate(&self, msg: &ClientMessage) {
+        let mut links = self.lock_peer_links();
+        links.retain(|peer, link| match send_client(link, msg) {
+            Ok(()) => true,
+            Err(e) => {
+                // The link thread notices the closed socket and relinks
+                log::debug!("[discovery-server] peer {} unreachable: {}", peer, e);
+                let _ = link.shutdown(Shutdown::Both);
+                false
+            }
+        });
+    }
+
+    /// Handle a message from client `id`; returns false to close the connection.
+    fn handle(&self, id: u64, msg: ClientMessage) -> bool {
+        let mut clients = self.lock_clients();
+        let Some(client) = clients.get_mut(&id) else {
+            return false;
+        };
+        client.last_seen = Instant::now();
+        if client.replicated.is_some() || matches!(msg, ClientMessage::ServerHello { .. }) {
+            return handle_replicated(&mut clients, id, msg);
+        }
+
+        match msg {
+            ClientMessage::ParticipantAnnounce {
+                guid_prefix,
+                domain_id,
+                name,
+                unicast_locators,
+                builtin_endpoints,
+                server_port,
+            } => {
+                let announce = ServerMessage::ParticipantAnnounce {
+                    guid_prefix,
+                    domain_id,
+                    name,
+                    unicast_locators,
+                    builtin_endpoints,
+                };
+                if let Some(replica) = replica(&announce) {
+                    self.replicate(&replica);
+                }
+                let endpoints = match client.registration.take() {
+                    Some(previous) if previous.guid_prefix == guid_prefix => previous.endpoints,
+                    _ => Vec::new(),
+                };
+                client.registration = Some(Registration {
+                    guid_prefix,
+                    announce: announce.clone(),
+                    endpoints,
+                    server_port,
+                });
+                let _ = send(
+                    &mut client.stream,
+                    &ServerMessage::ParticipantAck { guid_prefix },
+                );
+
+                // Catch the newcomer up, then tell the others about it
+                let known: Vec<ServerMessage> = clients
+                    .iter()
+                    .filter(|(other, _)| **other != id)
+                    .flat_map(|(_, other)| other.registrations())
+                    .filter(|reg| reg.guid_prefix != guid_prefix)
+                    .flat_map(|reg| std::iter::once(&reg.announce).chain(&reg.endpoints))
+                    .cloned()
+                    .collect();
+                if let Some(client) = clients.get_mut(&id) {
+                    for msg in &known {
+                        let _ = send(&mut client.stream, msg);
+                    }
+                }
+                broadcast(&mut clients, id, &announce);
+                log::debug!(
+                    "[discovery-server] registered participant {}",
+                    hex(&guid_prefix)
+                );
+            }
+
+            ClientMessage::EndpointAnnounce {
+                guid_prefix,
+                entity_id,
+                topic_name,
+                type_name,
+                is_writer,
+                reliable,
+                durability,
+                unicast_locators,
+            } => {
+                let Some(registration) = client.registration.as_mut() else {
+                    let _ = send(
+                        &mut client.stream,
+                        &ServerMessage::Error {
+                            code: 2,
+                            message: "Register participant first".into(),
+                        },
+                    );
+                    return true;
+                };
+                let announce = ServerMessage::EndpointAnnounce {
+                    guid_prefix,
+                    entity_id,
+                    topic_name,
+                    type_name,
+                    is_writer,
+                    reliable,
+                    durability,
+                    unicast_locators,
+                };
+                registration.endpoints.retain(|known| {
+                    !matches!(known, ServerMessage::EndpointAnnounce { entity_id: id, .. } if *id == entity_id)
+                });
+                registration.endpoints.push(announce.clone());
+                if let Some(replica) = replica(&announce) {
+                    self.replicate(&replica);
+                }
+                broadcast(&mut clients, id, &announce);
+            }
+
+            ClientMessage::Heartbeat { .. } => {}
+
+            ClientMessage::ParticipantLeave { .. } => {
+                if let Some(registration) = client.registration.take() {
+                    let guid_prefix = registration.guid_prefix;
+                    self.replicate(&ClientMessage::ParticipantLeave { guid_prefix });
+                    broadcast(
+                        &mut clients,
+                        id,
+                        &ServerMessage::ParticipantLeave { guid_prefix },
+                    );
+                }
+                return false;
+            }
+
+            // Routed to `handle_replicated` above
+            ClientMessage::ServerHello { .. } => {}
+        }
+        true
+    }
+
+    /// Forget client `id`, telling the others its participant left.
+    fn remove(&self, id: u64) {
+        let mut clients = self.lock_clients();
+        if let Some(client) = clients.remove(&id) {
+            let _ = client.stream.shutdown(Shutdown::Both);
+            for guid_prefix in client.replicated.into_iter().flat_map(|r| r.into_keys()) {
+                if !is_registered(&clients, &guid_prefix) {
+                    broadcast(
+                        &mut clients,
+                        id,
+                        &ServerMessage::ParticipantLeave { guid_prefix },
+                    );
+                }
+            }
+            if let Some(registration) = client.registration {
+                self.replicate(&ClientMessage::ParticipantLeave {
+                    guid_prefix: registration.guid_prefix,
+                });
+                broadcast(
+                    &mut clients,
+                    id,
+                    &ServerMessage::ParticipantLeave {
+                        guid_prefix: registration.guid_prefix,
+                    },
+                );
+                log::debug!(
+                    "[discovery-server] participant {} left",
+                    hex(&registration.guid_prefix)
+                );
+            }
+        }
+    }
+
+    /// Drop the clients that missed their lease.
+    fn expire_leases(&self) {
+        let expired: Vec<u64> = self
+            .lock_clients()
+            .iter()
+            .filter(|(_, client)| client.last_seen.elapsed() > self.lease_duration)
+            .map(|(id, _)| *id)
+            .collect();
+        for id in expired {
+            log::debug!("[discovery-server] client {} lease expired", id);
+            self.remove(id);
+        }
+    }
+}
+
+impl Client {
+    /// Registrations known through this connection, direct or replicated.
+    fn registrations(&self) -> impl Iterator<Item = &Registration> {
+        self.registration.iter().chain(
+            self.replicated
+                .iter()
+                .flat_map(|replicated| replicated.values()),
+        )
+    }
+}
+
+/// Handle a message on a peer server link (or the `server_hello` opening
+/// one); returns false to close the connection.
+fn handle_replicated(clients: &mut HashMap<u64, Client>, id: u64, msg: ClientMessage) -> bool {
+    let Some(client) = clients.get_mut(&id) else {
+        return false;
+    };
+    if let ClientMessage::ServerHello { guid_prefix } = msg {
+        if client.registration.is_some() {
+            log::warn!(
+                "[discovery-server] {} sent server_hello after registering",
+                client.peer_addr
+            );
+            return false;
+        }
+        client.replicated.get_or_insert_with(HashMap::new);
+        log::info!(
+            "[discovery-server] peer server {} linked from {}",
+            hex(&guid_prefix),
+            client.peer_addr
+        );
+        return true;
+    }
+    let Some(replicated) = client.replicated.as_mut() else {
+        return false;
+    };
+
+    match msg {
+        ClientMessage::ParticipantAnnounce {
+            guid_prefix,
+            domain_id,
+            name,
+            unicast_locators,
+            builtin_endpoints,
+            ..
+        } => {
+            let announce = ServerMessage::ParticipantAnnounce {
+                guid_prefix,
+                domain_id,
+                name,
+                unicast_locators,
+                builtin_endpoints,
+            };
+            let endpoints = replicated
+                .remove(&guid_prefix)
+                .map(|previous| previous.endpoints)
+                .unwrap_or_default();
+            replicated.insert(
+                guid_prefix,
+                Registration {
+                    guid_prefix,
+                    announce: announce.clone(),
+                    endpoints,
+                    server_port: None,
+                },
+            );
+            broadcast(clients, id, &announce);
+        }
+
+        ClientMessage::EndpointAnnounce {
+            guid_prefix,
+            entity_id,
+            topic_name,
+            type_name,
+            is_writer,
+            reliable,
+            durability,
+            unicast_locators,
+        } => {
+            let Some(registration) = replicated.get_mut(&guid_prefix) else {
+                log::debug!(
+                    "[discovery-server] replicated endpoint of unknown participant {}",
+                    hex(&guid_prefix)
+                );
+                return true;
+            };
+            let announce = ServerMessage::EndpointAnnounce {
+                guid_prefix,
+                entity_id,
+                topic_name,
+                type_name,
+                is_writer,
+                reliable,
+                durability,
+                unicast_locators,
+            };
+            registration.endpoints.retain(|known| {
+                !matches!(known, ServerMessage::EndpointAnnounce { entity_id: id, .. } if *id == entity_id)
+            });
+            registration.endpoints.push(announce.clone());
+            broadcast(clients, id, &announce);
+        }
+
+        ClientMessage::ParticipantLeave { guid_prefix } => {
+            // A participant that failed over here stays registered
+            if replicated.remove(&guid_prefix).is_some() && !is_registered(clients, &guid_prefix) {
+                broadcast(
+                    clients,
+                    id,
+                    &ServerMessage::ParticipantLeave { guid_prefix },
+                );
+            }
+        }
+
+        ClientMessage::Heartbeat { .. } | ClientMessage::ServerHello { .. } => {}
+    }
+    true
+}
+
+/// Whether `guid_prefix` is registered directly or by another peer.
+fn is_registered(clients: &HashMap<u64, Client>, guid_prefix: &GuidPrefix) -> bool {
+    clients
+        .values()
+        .flat_map(Client::registrations)
+        .any(|registration| registration.guid_prefix == *guid_prefix)
+}
+
+/// The announcement forwarded to peer servers for a registration entry.
+fn replica(msg: &ServerMessage) -> Option<ClientMessage> {
+    match msg.clone() {
+        ServerMessage::ParticipantAnnounce {
+            guid_prefix,
+            domain_id,
+            name,
+            unicast_locators,
+            builtin_endpoints,
+        } => Some(ClientMessage::ParticipantAnnounce {
+            guid_prefix,
+            domain_id,
+            name,
+            unicast_locators,
+            builtin_endpoints,
+            // Standby servers are only meaningful to the local server
+            server_port: None,
+        }),
+        ServerMessage::EndpointAnnounce {
+            guid_prefix,
+            entity_id,
+            topic_name,
+            type_name,
+            is_writer,
+            reliable,
+            durability,
+            unicast_locators,
+        } => Some(ClientMessage::EndpointAnnounce {
+            guid_prefix,
+            entity_id,
+            topic_name,
+            type_name,
+            is_writer,
+            reliable,
+            durability,
+            unicast_locators,
+        }),
+        _ => None,
+    }
+}
+
+/// Keep a link to `peer` up while the server runs.
+fn peer_loop(peer: SocketAddr, shared: &Shared) {
+    while shared.running.load(Ordering::Acquire) {
+        if let Err(e) = link_peer(peer, shared) {
+            log::debug!("[discovery-server] link to peer {} down: {}", peer, e);
+        }
+        if let Some(link) = shared.lock_peer_links().remove(&peer) {
+            let _ = link.shutdown(Shutdown::Both);
+        }
+
+        let retry_at = Instant::now() + PEER_RETRY_DELAY;
+        while shared.running.load(Ordering::Acquire) && Instant::now() < retry_at {
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+/// Link to `peer`, send it the local registrations and keep the link alive
+/// until it breaks or the server stops.
+fn link_peer(peer: SocketAddr, shared: &Shared) -> io::Result<()> {
+    let mut stream = TcpStream::connect_timeout(&peer, PEER_CONNECT_TIMEOUT)?;
+    stream.set_nodelay(true)?;
+    stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+    stream.set_read_timeout(Some(POLL_INTERVAL))?;
+
+    {
+        // Snapshot and registration under the clients lock, so no change
+        // slips between them
+        let clients = shared.lock_clients();
+        let mut link = stream.try_clone()?;
+        send_client(
+            &mut link,
+            &ClientMessage::ServerHello {
+                guid_prefix: shared.guid_prefix,
+            },
+        )?;
+        let local = clients
+            .values()
+            .filter_map(|client| client.registration.as_ref())
+            .flat_map(|reg| std::iter::once(&reg.announce).chain(&reg.endpoints));
+        for msg in local.filter_map(replica) {
+            send_client(&mut link, &msg)?;
+        }
+        shared.lock_peer_links().insert(peer, link);
+    }
+    log::info!("[discovery-server] linked to peer server {}", peer);
+
+    let heartbeat_interval = shared.lease_duration / 3;
+    let mut last_heartbeat = Instant::now();
+    let mut buf = [0u8; 512];
+    while shared.running.load(Ordering::Acquire) {
+        // The peer sends nothing on a link; reading only detects its close
+        match stream.read(&mut buf) {
+            Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+            Ok(_) => {}
+            Err(e)
+                if matches!(
+                    e.kind(),
+                    io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                ) => {}
+            Err(e) => return Err(e),
+        }
+
+        if last_heartbeat.elapsed() >= heartbeat_interval {
+            let mut links = shared.lock_peer_links();
+            let link = links
+                .get_mut(&peer)
+                .ok_or_else(|| io::Error::from(io::ErrorKind::NotConnected))?;
+            send_client(
+                link,
+                &ClientMessage::Heartbeat {
+                    guid_prefix: shared.guid_prefix,
+                },
+            )?;
+            last_heartbeat = Instant::now();
+        }
+    }
+    Ok(())
+}
+
+fn accept_loop(listener: TcpListener, shared: Arc<Shared>) {
+    let mut next_id = 0u64;
+    while shared.running.load(Ordering::Acquire) {
+        match listener.accept() {
+            Ok((stream, peer_addr)) => {
+                next_id += 1;
+                if let Err(e) = start_connection(next_id, stream, peer_addr, &shared) {
+                    log::warn!("[discovery-server] failed to serve {}: {}", peer_addr, e);
+                }
+            }
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                shared.expire_leases();
+                thread::sleep(POLL_INTERVAL);
+            }
+            Err(e) => {
+                log::warn!("[discovery-server] accept error: {}", e);
+                thread::sleep(POLL_INTERVAL);
+            }
+        }
+    }
+}
+
+fn start_connection(
+    id: u64,
+    stream: TcpStream,
+    peer_addr: SocketAddr,
+    shared: &Arc<Shared>,
+) -> io::Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_nodelay(true)?;
+    stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+    let client = Client {
+        stream: stream.try_clone()?,
+        peer_addr,
+        local_addr: stream.local_addr()?,
+        registration: None,
+        replicated: None,
+        last_seen: Instant::now(),
+    };
+    shared.lock_clients().insert(id, client);
+
+    let connection_shared = Arc::clone(shared);
+    let spawned = thread::Builder::new()
+        .name("hdds-ds-client".into())
+        .spawn(move || {
+            serve_connection(id, stream, &connection_shared);
+            connection_shared.remove(id);
+        });
+    if let Err(e) = spawned {
+        shared.remove(id);
+        return Err(e);
+    }
+    log::debug!(
+        "[discovery-server] client {} connected from {}",
+        id,
+        peer_addr
+    );
+    Ok(())
+}
+
+fn serve_connection(id: u64, mut stream: TcpStream, shared: &Shared) {
+    while shared.running.load(Ordering::Acquire) {
+        let msg = match read_message(&mut stream) {
+            Ok(msg) => msg,
+            Err(e) => {
+                if e.kind() != io::ErrorKind::UnexpectedEof {
+                    log::debug!("[discovery-server] client {} read error: {}", id, e);
+                }
+                return;
+            }
+        };
+        if !shared.handle(id, msg) {
+            return;
+        }
+    }
+}
+
+/// Read one length-prefixed message.
+fn read_message(stream: &mut TcpStream) -> io::Result<ClientMessage> {
+    let mut len_buf = [0u8; 4];
+    stream.read_exact(&mut len_buf)?;
+    let len = u32::from_be_bytes(len_buf) as usize;
+    if len == 0 || len > MAX_MESSAGE_SIZE {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Invalid message length: {}", len),
+        ));
+    }
+
+    let mut buf = vec![0u8; len];
+    stream.read_exact(&mut buf)?;
+    ClientMessage::decode(&buf)
+}
+
+fn send(stream: &mut TcpStream, msg: &ServerMessage) -> io::Result<()> {
+    stream.write_all(&msg.encode()?)
+}
+
+fn send_client(stream: &mut TcpStream, msg: &ClientMessage) -> io::Result<()> {
+    stream.write_all(&msg.encode()?)
+}
+
+/// Send `msg` to every registered client but `from`.
+fn broadcast(clients: &mut HashMap<u64, Client>, from: u64, msg: &ServerMessage) {
+    for (id, client) in clients.iter_mut() {
+        if *id != from && client.registration.is_some() {
+            let _ = send(&mut client.stream, msg);
+        }
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::discovery_server::{ClientEvent, DiscoveryServerClient, DiscoveryServerConfig};
+
+    fn client(server: SocketAddr, guid: u8) -> DiscoveryServerClient {
+        let config = DiscoveryServerConfig::new(server);
+        let mut client = DiscoveryServerClient::new(config, [guid; 12]).expect("client");
+        client.connect().expect("connect");
+        client
+    }
+
+    fn loopback(server: &EmbeddedDiscoveryServer) -> SocketAddr {
+        SocketAddr::new(Ipv4Addr::LOCALHOST.into(), server.local_addr().port())
+    }
+
+    /// Read events until one matches `pred`.
+    fn wait_for(
+        client: &mut DiscoveryServerClient,
+        pred: impl Fn(&ClientEvent) -> bool,
+    ) -> ClientEvent {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while Instant::now() < deadline {
+            match client.read_message() {
+                Ok(Some(event)) if pred(&event) => return event,
+                Ok(_) => {}
+                Err(e) => panic!("no matching event: {}", e),
+            }
+        }
+        panic!("timed out waiting for event");
+    }
+
+    #[test]
+    fn test_late_joiner_receives_registered_endpoints() {
+        let server =
+            EmbeddedDiscoveryServer::bind(EmbeddedServerConfig::new([0xd5; 12], 0)).expect("bind");
+
+        let mut first = client(loopback(&server), 1);
+        first
+            .announce_participant(0, Some("first".into()), vec![], 0x3f)
+            .unwrap();
+        wait_for(&mut first, |e| {
+            matches!(e, ClientEvent::ParticipantAcknowledged)
+        });
+        first
+            .announce_endpoint(
+                [0, 0, 1, 2],
+                "Temperature".into(),
+                "sensor::Temperature".into(),
+                true,
+                true,
+                1,
+                vec![],
+            )
+            .unwrap();
+
+        let mut second = client(loopback(&server), 2);
+        second.announce_participant(0, None, vec![], 0x3f).unwrap();
+        let event = wait_for(&mut second, |e| {
+            matches!(e, ClientEvent::EndpointDiscovered { .. })
+        });
+        match event {
+            ClientEvent::EndpointDiscovered {
+                guid_prefix,
+                topic_name,
+                is_writer,
+                ..
+            } => {
+                assert_eq!(guid_prefix, [1; 12]);
+                assert_eq!(topic_name, "Temperature");
+                assert!(is_writer);
+            }
+            other => assert!(
+                matches!(other, ClientEvent::EndpointDiscovered { .. }),
+                "Expected EndpointDiscovered, got {:?}",
+                other
+            ),
+        }
+
+        // The first client hears about the second one
+        wait_for(
+            &mut first,
+            |e| matches!(e, ClientEvent::ParticipantDiscovered { guid_prefix, .. } if *guid_prefix == [2; 12]),
+        );
+        assert_eq!(server.participant_count(), 2);
+
+        second.leave().unwrap();
+        wait_for(
+            &mut first,
+            |e| matches!(e, ClientEvent::ParticipantLeft { guid_prefix } if *guid_prefix == [2; 12]),
+        );
+    }
+
+    #[test]
+    fn test_shutdown_redirects_to_standby() {
+        let mut primary = EmbeddedDiscoveryServer::bind(EmbeddedServerConfig::new([0xd5; 12], 0))
+            .expect("bind primary");
+        let standby = EmbeddedDiscoveryServer::bind(EmbeddedServerConfig::new([0xd6; 12], 0))
+            .expect("bind standby");
+
+        // The standby participant announces its server port
+        let standby_config = DiscoveryServerConfig::new(loopback(&primary))
+            .with_standby_port(standby.local_addr().port());
+        let mut standby_client =
+            DiscoveryServerClient::new(standby_config, [3; 12]).expect("client");
+        standby_client.connect().unwrap();
+        standby_client
+            .announce_participant(0, None, vec![], 0x3f)
+            .unwrap();
+        wait_for(&mut standby_client, |e| {
+            matches!(e, ClientEvent::ParticipantAcknowledged)
+        });
+
+        let mut app = client(loopback(&primary), 4);
+        app.announce_participant(0, Some("app".into()), vec![], 0x3f)
+            .unwrap();
+        wait_for(&mut app, |e| {
+            matches!(e, ClientEvent::ParticipantAcknowledged)
+        });
+
+        primary.shutdown();
+        assert!(!primary.is_running());
+
+        let event = wait_for(&mut app, |e| matches!(e, ClientEvent::Redirected { .. }));
+        match event {
+            ClientEvent::Redirected { server_address } => {
+                assert_eq!(server_address.port(), standby.local_addr().port());
+            }
+            other => assert!(
+                matches!(other, ClientEvent::Redirected { .. }),
+                "Expected Redirected, got {:?}",
+                other
+            ),
+        }
+        assert!(app.is_connected());
+        wait_for(&mut app, |e| {
+            matches!(e, ClientEvent::ParticipantAcknowledged)
+        });
+        assert_eq!(standby.participant_count(), 1);
+    }
+
+    #[test]
+    fn test_peer_replicates_registrations() {
+        let hub =
+            EmbeddedDiscoveryServer::bind(EmbeddedServerConfig::new([0xd5; 12], 0)).expect("bind");
+        let edge = EmbeddedDiscoveryServer::bind(
+            EmbeddedServerConfig::new([0xd6; 12], 0).with_peer(loopback(&hub)),
+        )
+        .expect("bind edge");
+
+        // Registered on the edge, possibly before the link is up: the hub
+        // learns about it either way
+        let mut remote = client(loopback(&edge), 5);
+        remote.announce_participant(0, None, vec![], 0x3f).unwrap();
+        wait_for(&mut remote, |e| {
+            matches!(e, ClientEvent::ParticipantAcknowledged)
+        });
+        remote
+            .announce_endpoint(
+                [0, 0, 3, 2],
+                "Pose".into(),
+                "nav::Pose".into(),
+                true,
+                false,
+                0,
+                vec![],
+            )
+            .unwrap();
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while edge.linked_peer_count() == 0 && Instant::now() < deadline {
+            thread::sleep(POLL_INTERVAL);
+        }
+        assert_eq!(edge.linked_peer_count(), 1);
+
+        let mut local = client(loopback(&hub), 6);
+        local.announce_participant(0, None, vec![], 0x3f).unwrap();
+        wait_for(
+            &mut local,
+            |e| matches!(e, ClientEvent::EndpointDiscovered { guid_prefix, topic_name, .. } if *guid_prefix == [5; 12] && topic_name == "Pose"),
+        );
+        assert_eq!(hub.participant_count(), 1);
+
+        remote.leave().unwrap();
+        wait_for(
+            &mut local,
+            |e| matches!(e, ClientEvent::ParticipantLeft { guid_prefix } if *guid_prefix == [5; 12]),
+        );
+    }
+
+    #[test]
+    fn test_client_fails_over_to_backup_server() {
+        let backup =
+            EmbeddedDiscoveryServer::bind(EmbeddedServerConfig::new([0xd7; 12], 0)).expect("bind");
+        let primary = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).expect("bind primary");
+        let config = DiscoveryServerConfig::new(primary.local_addr().unwrap())
+            .with_backup_server(loopback(&backup));
+        let mut app = DiscoveryServerClient::new(config, [7; 12]).expect("client");
+        app.connect().unwrap();
+        assert_eq!(app.server_address(), primary.local_addr().unwrap());
+        app.announce_participant(0, Some("app".into()), vec![], 0x3f)
+            .unwrap();
+
+        // The primary dies without redirecting
+        let (connection, _) = primary.accept().unwrap();
+        drop(connection);
+        drop(primary);
+
+        let event = wait_for(&mut app, |e| matches!(e, ClientEvent::FailedOver { .. }));
+        assert!(matches!(
+            event,
+            ClientEvent::FailedOver { server_address } if server_address == loopback(&backup)
+        ));
+        wait_for(&mut app, |e| {
+            matches!(e, ClientEvent::ParticipantAcknowledged)
+        });
+        assert_eq!(backup.participant_count(), 1);
+
+        // An unreachable primary is skipped on connect
+        let mut late = DiscoveryServerClient::new(
+            DiscoveryServerConfig::new(SocketAddr::from((Ipv4Addr::LOCALHOST, 1)))
+                .with_backup_server(loopback(&backup)),
+            [8; 12],
+        )
+        .expect("client");
+        late.connect().unwrap();
+        assert_eq!(late.server_address(), loopback(&backup));
+    }
+}
@@ -4,10 +4,9 @@
 //! Fluent builder API for TypeDescriptor.
 
 use crate::dynamic::{
-    ArrayDescriptor, FieldDescriptor, PrimitiveKind, SequenceDescriptor, TypeDescriptor, TypeKind,
+    ArrayDescriptor, EnumDescriptor, EnumVariant, FieldDescriptor, PrimitiveKind,
+    SequenceDescriptor, TypeDescriptor, TypeKind, UnionCase, UnionDescriptor,
 };
-#[cfg(feature = "dynamic-types")]
-use crate::dynamic::{EnumDescriptor, EnumVariant, UnionCase, UnionDescriptor};
 use std::sync::Arc;
 
 /// Builder for creating TypeDescriptor instances.
@@ -124,9 +123,7 @@ impl TypeDescriptorBuilder {
 }
 
 /// Builder for enum types.
-#[cfg(feature = "dynamic-types")]
 #[derive(Debug)]
-#[allow(dead_code)]
 pub struct EnumBuilder {
     name: String,
     variants: Vec<EnumVariant>,
@@ -134,8 +131,6 @@ pub struct EnumBuilder {
     underlying: PrimitiveKind,
 }
 
-#[cfg(feature = "dynamic-types")]
-#[allow(dead_code)]
 impl EnumBuilder {
     /// Create a new enum builder.
     pub fn new(name: impl Into<String>) -> Self {
@@ -175,9 +170,7 @@ impl EnumBuilder {
 }
 
 /// Builder for union types.
-#[cfg(feature = "dynamic-types")]
 #[derive(Debug)]
-#[allow(dead_code)]
 pub struct UnionBuilder {
     name: String,
     discriminator: Arc<TypeDescriptor>,
@@ -185,8 +178,6 @@ pub struct UnionBuilder {
     default_case: Option<UnionCase>,
 }
 
-#[cfg(feature = "dynamic-types")]
-#[allow(dead_code)]
 impl UnionBuilder {
     /// Create a new union builder with discriminator type.
     pub fn new(name: impl Into<String>, discriminator: Arc<TypeDescriptor>) -> Self {
@@ -249,16 +240,13 @@ impl UnionBuilder {
 }
 
 /// Builder for sequence types.
-#[cfg(feature = "dynamic-types")]
-#[allow(dead_code)]
+#[derive(Debug)]
 pub struct SequenceBuilder {
     name: String,
     element_type: Arc<TypeDescriptor>,
     max_length: Option<usize>,
 }
 
-#[cfg(feature = "dynamic-types")]
-#[allow(dead_code)]
 impl SequenceBuilder {
     /// Create unbounded sequence of primitives.
     pub fn of_primitive(name: impl Into<String>, kind: PrimitiveKind) -> Self {
@@ -295,16 +283,13 @@ impl SequenceBuilder {
 }
 
 /// Builder for array types.
-#[cfg(feature = "dynamic-types")]
-#[allow(dead_code)]
+#[derive(Debug)]
 pub struct ArrayBuilder {
     name: String,
     element_type: Arc<TypeDescriptor>,
     length: usize,
 }
 
-#[cfg(feature = "dynamic-types")]
-#[allow(dead_code)]
 impl ArrayBuilder {
     /// Create array of primitives.
     pub fn of_primitive(name: impl Into<String>, kind: PrimitiveKind, length: usize) -> Self {
@@ -377,7 +362,6 @@ mod tests {
     }
 
     #[test]
-    #[cfg(feature = "dynamic-types")]
     fn test_enum_builder() {
         let desc = EnumBuilder::new("Color")
             .variant("RED")
@@ -395,7 +379,6 @@ mod tests {
     }
 
     #[test]
-    #[cfg(feature = "dynamic-types")]
     fn test_enum_explicit_values() {
         let desc = EnumBuilder::new("HttpStatus")
             .variant_value("OK", 200)
@@ -412,7 +395,6 @@ mod tests {
     }
 
     #[test]
-    #[cfg(feature = "dynamic-types")]
     fn test_union_builder() {
         let desc = UnionBuilder::with_u32_discriminator("Value")
             .primitive_case("int_val", 0, PrimitiveKind::I32)
@@ -447,7 +429,6 @@ mod tests {
     }
 
     #[test]
-    #[cfg(feature = "dynamic-types")]
     fn test_sequence_builder() {
         let desc = SequenceBuilder::of_primitive("ByteArray", PrimitiveKind::U8)
             .max_length(1024)
@@ -462,7 +443,6 @@ mod tests {
     }
 
     #[test]
-    #[cfg(feature = "dynamic-types")]
     fn test_array_builder() {
         let desc = ArrayBuilder::of_primitive("Vector3", PrimitiveKind::F32, 3).build();
 
@@ -41,19 +41,101 @@ impl From<std::string::FromUtf8Error> for DynamicCdrError {
     }
 }
 
-/// Encode DynamicData to CDR bytes.
+/// CDR encoding version and byte order (XTypes v1.3 Sec.7.4).
+///
+/// Dynamic types are FINAL, so XCDR2 uses the plain `CDR2` encapsulation
+/// (no DHEADER). XCDR2 caps alignment at 4 bytes and encodes `wstring`
+/// lengths in bytes without a terminator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CdrEncoding {
+    /// XCDR1 (classic CDR), little-endian
+    Xcdr1Le,
+    /// XCDR1 (classic CDR), big-endian
+    Xcdr1Be,
+    /// XCDR2, little-endian
+    Xcdr2Le,
+    /// XCDR2, big-endian
+    Xcdr2Be,
+}
+
+impl CdrEncoding {
+    /// Every encoding, XCDR1 first.
+    pub const ALL: [CdrEncoding; 4] = [Self::Xcdr1Le, Self::Xcdr1Be, Self::Xcdr2Le, Self::Xcdr2Be];
+
+    /// Encapsulation identifier of the serialized payload header.
+    pub fn encapsulation_id(self) -> u16 {
+        match self {
+            Self::Xcdr1Be => 0x0000,
+            Self::Xcdr1Le => 0x0001,
+            Self::Xcdr2Be => 0x0006,
+            Self::Xcdr2Le => 0x0007,
+        }
+    }
+
+    /// Encoding for an encapsulation identifier, if it is a plain CDR one.
+    pub fn from_encapsulation_id(id: u16) -> Option<Self> {
+        Self::ALL.into_iter().find(|e| e.encapsulation_id() == id)
+    }
+
+    /// Short lowercase name (e.g. `"xcdr2_le"`), used in file names.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Xcdr1Le => "xcdr1_le",
+            Self::Xcdr1Be => "xcdr1_be",
+            Self::Xcdr2Le => "xcdr2_le",
+            Self::Xcdr2Be => "xcdr2_be",
+        }
+    }
+
+    fn is_big_endian(self) -> bool {
+        matches!(self, Self::Xcdr1Be | Self::Xcdr2Be)
+    }
+
+    fn is_xcdr2(self) -> bool {
+        matches!(self, Self::Xcdr2Le | Self::Xcdr2Be)
+    }
+
+    /// Alignment of a primitive of `size` bytes.
+    fn alignment(self, size: usize) -> usize {
+        if self.is_xcdr2() {
+            size.min(4)
+        } else {
+            size
+        }
+    }
+}
+
+/// Encode DynamicData to CDR bytes (XCDR1 little-endian).
 pub fn encode_dynamic(data: &DynamicData) -> Result<Vec<u8>, DynamicCdrError> {
-    let mut encoder = CdrEncoder::new();
+    encode_dynamic_as(data, CdrEncoding::Xcdr1Le)
+}
+
+/// Decode CDR bytes (XCDR1 little-endian) to DynamicData.
+pub fn decode_dynamic(
+    bytes: &[u8],
+    descriptor: &Arc<TypeDescriptor>,
+) -> Result<DynamicData, DynamicCdrError> {
+    decode_dynamic_as(bytes, descriptor, CdrEncoding::Xcdr1Le)
+}
+
+/// Encode DynamicData with the given encoding (payload only, no
+/// encapsulation header).
+pub fn encode_dynamic_as(
+    data: &DynamicData,
+    encoding: CdrEncoding,
+) -> Result<Vec<u8>, DynamicCdrError> {
+    let mut encoder = CdrEncoder::new(encoding);
     encoder.encode_value(data.value(), &data.descriptor().kind)?;
     Ok(encoder.into_bytes())
 }
 
-/// Decode CDR bytes to DynamicData.
-pub fn decode_dynamic(
+/// Decode a payload (no encapsulation header) with the given encoding.
+pub fn decode_dynamic_as(
     bytes: &[u8],
     descriptor: &Arc<TypeDescriptor>,
+    encoding: CdrEncoding,
 ) -> Result<DynamicData, DynamicCdrError> {
-    let mut decoder = CdrDecoder::new(bytes);
+    let mut decoder = CdrDecoder::new(bytes, encoding);
     let value = decoder.decode_value(&descriptor.kind)?;
     DynamicData::from_value(descriptor, value)
         .map_err(|e| DynamicCdrError::InvalidData(e.to_string()))
@@ -62,11 +144,15 @@ pub fn decode_dynamic(
 /// CDR Encoder for dynamic types.
 struct CdrEncoder {
     buffer: Vec<u8>,
+    encoding: CdrEncoding,
 }
 
 impl CdrEncoder {
-    fn new() -> Self {
-        Self { buffer: Vec::new() }
+    fn new(encoding: CdrEncoding) -> Self {
+        Self {
+            buffer: Vec::new(),
+            encoding,
+        }
     }
 
     fn into_bytes(self) -> Vec<u8> {
@@ -78,6 +164,20 @@ impl CdrEncoder {
         self.buffer.extend(std::iter::repeat_n(0, padding));
     }
 
+    /// Write a primitive given its little-endian bytes.
+    fn put(&mut self, le_bytes: &[u8]) {
+        self.align(self.encoding.alignment(le_bytes.len()));
+        if self.encoding.is_big_endian() {
+            self.buffer.extend(le_bytes.iter().rev());
+        } else {
+            self.buffer.extend(le_bytes);
+        }
+    }
+
+    fn put_u32(&mut self, value: u32) {
+        self.put(&value.to_le_bytes());
+    }
+
     fn encode_value(
         &mut self,
         value: &DynamicValue,
@@ -104,8 +204,7 @@ impl CdrEncoder {
             TypeKind::Sequence(seq) => {
                 if let DynamicValue::Sequence(vec) = value {
                     // Write length
-                    self.align(4);
-                    self.buffer.extend(&(vec.len() as u32).to_le_bytes());
+                    self.put_u32(vec.len() as u32);
                     // Write elements
                     for elem in vec {
                         self.encode_value(elem, &seq.element_type.kind)?;
@@ -140,8 +239,7 @@ impl CdrEncoder {
             }
             TypeKind::Enum(_e) => {
                 if let DynamicValue::Enum(val, _) = value {
-                    self.align(4);
-                    self.buffer.extend(&(*val as u32).to_le_bytes());
+                    self.put_u32(*val as u32);
                     Ok(())
                 } else {
                     Err(DynamicCdrError::TypeMismatch {
@@ -182,44 +280,24 @@ impl CdrEncoder {
             (DynamicValue::U8(v), PrimitiveKind::U8) => {
                 self.buffer.push(*v);
             }
-            (DynamicValue::U16(v), PrimitiveKind::U16) => {
-                self.align(2);
-                self.buffer.extend(&v.to_le_bytes());
-            }
-            (DynamicValue::U32(v), PrimitiveKind::U32) => {
-                self.align(4);
-                self.buffer.extend(&v.to_le_bytes());
-            }
-            (DynamicValue::U64(v), PrimitiveKind::U64) => {
-                self.align(8);
-                self.buffer.extend(&v.to_le_bytes());
-            }
+            (DynamicValue::U16(v), PrimitiveKind::U16) => self.put(&v.to_le_bytes()),
+            (DynamicValue::U32(v), PrimitiveKind::U32) => self.put(&v.to_le_bytes()),
+            (DynamicValue::U64(v), PrimitiveKind::U64) => self.put(&v.to_le_bytes()),
             (DynamicValue::I8(v), PrimitiveKind::I8) => {
                 self.buffer.push(*v as u8);
             }
-            (DynamicValue::I16(v), PrimitiveKind::I16) => {
-                self.align(2);
-                self.buffer.extend(&v.to_le_bytes());
-            }
-            (DynamicValue::I32(v), PrimitiveKind::I32) => {
-                self.align(4);
-                self.buffer.extend(&v.to_le_bytes());
-            }
-            (DynamicValue::I64(v), PrimitiveKind::I64) => {
-                self.align(8);
-                self.buffer.extend(&v.to_le_bytes());
-            }
-            (DynamicValue::F32(v), PrimitiveKind::F32) => {
-                self.align(4);
-                self.buffer.extend(&v.to_le_bytes());
-            }
-            (DynamicValue::F64(v), PrimitiveKind::F64) => {
-                self.align(8);
-                self.buffer.extend(&v.to_le_bytes());
-            }
+            (DynamicValue::I16(v), PrimitiveKind::I16) => self.put(&v.to_le_bytes()),
+            (DynamicValue::I32(v), PrimitiveKind::I32) => self.put(&v.to_le_bytes()),
+            (DynamicValue::I64(v), PrimitiveKind::I64) => self.put(&v.to_le_bytes()),
+            (DynamicValue::F32(v), PrimitiveKind::F32) => self.put(&v.to_le_bytes()),
+            (DynamicValue::F64(v), PrimitiveKind::F64) => self.put(&v.to_le_bytes()),
             (DynamicValue::LongDouble(v), PrimitiveKind::LongDouble) => {
-                self.align(crate::dynamic::LONG_DOUBLE_ALIGN);
-                self.buffer.extend(v);
+                self.align(self.encoding.alignment(crate::dynamic::LONG_DOUBLE_ALIGN));
+                if self.encoding.is_big_endian() {
+                    self.buffer.extend(v.iter().rev());
+                } else {
+                    self.buffer.extend(v);
+                }
             }
             (DynamicValue::Char(v), PrimitiveKind::Char) => {
                 self.buffer.push(*v as u8);
@@ -230,11 +308,9 @@ impl CdrEncoder {
                         return Err(DynamicCdrError::InvalidData("string exceeds bound".into()));
                     }
                 }
-                self.align(4);
                 let bytes = s.as_bytes();
                 // Length includes null terminator
-                self.buffer
-                    .extend(&((bytes.len() + 1) as u32).to_le_bytes());
+                self.put_u32((bytes.len() + 1) as u32);
                 self.buffer.extend(bytes);
                 self.buffer.push(0); // Null terminator
             }
@@ -244,14 +320,19 @@ impl CdrEncoder {
                         return Err(DynamicCdrError::InvalidData("wstring exceeds bound".into()));
                     }
                 }
-                self.align(4);
                 let chars: Vec<u16> = s.encode_utf16().collect();
-                self.buffer
-                    .extend(&((chars.len() + 1) as u32).to_le_bytes());
+                if self.encoding.is_xcdr2() {
+                    // XCDR2: length in bytes, no terminator
+                    self.put_u32((chars.len() * 2) as u32);
+                } else {
+                    self.put_u32((chars.len() + 1) as u32);
+                }
                 for c in chars {
-                    self.buffer.extend(&c.to_le_bytes());
+                    self.put(&c.to_le_bytes());
+                }
+                if !self.encoding.is_xcdr2() {
+                    self.put(&0u16.to_le_bytes()); // Null terminator
                 }
-                self.buffer.extend(&0u16.to_le_bytes()); // Null terminator
             }
             _ => {
                 return Err(DynamicCdrError::TypeMismatch {
@@ -268,11 +349,16 @@ impl CdrEncoder {
 struct CdrDecoder<'a> {
     buffer: &'a [u8],
     offset: usize,
+    encoding: CdrEncoding,
 }
 
 impl<'a> CdrDecoder<'a> {
-    fn new(buffer: &'a [u8]) -> Self {
-        Self { buffer, offset: 0 }
+    fn new(buffer: &'a [u8], encoding: CdrEncoding) -> Self {
+        Self {
+            buffer,
+            offset: 0,
+            encoding,
+        }
     }
 
     fn remaining(&self) -> usize {
@@ -295,6 +381,21 @@ impl<'a> CdrDecoder<'a> {
         Ok(slice)
     }
 
+    /// Read a primitive, returning its bytes in little-endian order.
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], DynamicCdrError> {
+        self.align(self.encoding.alignment(N));
+        let mut bytes = [0u8; N];
+        bytes.copy_from_slice(self.read_bytes(N)?);
+        if self.encoding.is_big_endian() {
+            bytes.reverse();
+        }
+        Ok(bytes)
+    }
+
+    fn take_u32(&mut self) -> Result<u32, DynamicCdrError> {
+        Ok(u32::from_le_bytes(self.take()?))
+    }
+
     fn decode_value(&mut self, kind: &TypeKind) -> Result<DynamicValue, DynamicCdrError> {
         match kind {
             TypeKind::Primitive(p) => self.decode_primitive(*p),
@@ -307,10 +408,8 @@ impl<'a> CdrDecoder<'a> {
                 Ok(DynamicValue::Struct(map))
             }
             TypeKind::Sequence(seq) => {
-                self.align(4);
-                let bytes = self.read_bytes(4)?;
-                let len = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize;
-                let mut vec = Vec::with_capacity(len);
+                let len = self.take_u32()? as usize;
+                let mut vec = Vec::with_capacity(len.min(self.remaining()));
                 for _ in 0..len {
                     vec.push(self.decode_value(&seq.element_type.kind)?);
                 }
@@ -324,9 +423,7 @@ impl<'a> CdrDecoder<'a> {
                 Ok(DynamicValue::Array(vec))
             }
             TypeKind::Enum(e) => {
-                self.align(4);
-                let bytes = self.read_bytes(4)?;
-                let val = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as i64;
+                let val = self.take_u32()? as i64;
                 let name = e
                     .variant_by_value(val)
                     .map(|v| v.name.clone())
@@ -374,67 +471,26 @@ impl<'a> CdrDecoder<'a> {
                 let bytes = self.read_bytes(1)?;
                 Ok(DynamicValue::U8(bytes[0]))
             }
-            PrimitiveKind::U16 => {
-                self.align(2);
-                let bytes = self.read_bytes(2)?;
-                Ok(DynamicValue::U16(u16::from_le_bytes([bytes[0], bytes[1]])))
-            }
-            PrimitiveKind::U32 => {
-                self.align(4);
-                let bytes = self.read_bytes(4)?;
-                Ok(DynamicValue::U32(u32::from_le_bytes([
-                    bytes[0], bytes[1], bytes[2], bytes[3],
-                ])))
-            }
-            PrimitiveKind::U64 => {
-                self.align(8);
-                let bytes = self.read_bytes(8)?;
-                Ok(DynamicValue::U64(u64::from_le_bytes([
-                    bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
-                ])))
-            }
+            PrimitiveKind::U16 => Ok(DynamicValue::U16(u16::from_le_bytes(self.take()?))),
+            PrimitiveKind::U32 => Ok(DynamicValue::U32(self.take_u32()?)),
+            PrimitiveKind::U64 => Ok(DynamicValue::U64(u64::from_le_bytes(self.take()?))),
             PrimitiveKind::I8 => {
                 let bytes = self.read_bytes(1)?;
                 Ok(DynamicValue::I8(bytes[0] as i8))
             }
-            PrimitiveKind::I16 => {
-                self.align(2);
-                let bytes = self.read_bytes(2)?;
-                Ok(DynamicValue::I16(i16::from_le_bytes([bytes[0], bytes[1]])))
-            }
-            PrimitiveKind::I32 => {
-                self.align(4);
-                let bytes = self.read_bytes(4)?;
-                Ok(DynamicValue::I32(i32::from_le_bytes([
-                    bytes[0], bytes[1], bytes[2], bytes[3],
-                ])))
-            }
-            PrimitiveKind::I64 => {
-                self.align(8);
-                let bytes = self.read_bytes(8)?;
-                Ok(DynamicValue::I64(i64::from_le_bytes([
-                    bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
-                ])))
-            }
-            PrimitiveKind::F32 => {
-                self.align(4);
-                let bytes = self.read_bytes(4)?;
-                Ok(DynamicValue::F32(f32::from_le_bytes([
-                    bytes[0], bytes[1], bytes[2], bytes[3],
-                ])))
-            }
-            PrimitiveKind::F64 => {
-                self.align(8);
-                let bytes = self.read_bytes(8)?;
-                Ok(DynamicValue::F64(f64::from_le_bytes([
-                    bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
-                ])))
-            }
+            PrimitiveKind::I16 => Ok(DynamicValue::I16(i16::from_le_bytes(self.take()?))),
+            PrimitiveKind::I32 => Ok(DynamicValue::I32(i32::from_le_bytes(self.take()?))),
+            PrimitiveKind::I64 => Ok(DynamicValue::I64(i64::from_le_bytes(self.take()?))),
+            PrimitiveKind::F32 => Ok(DynamicValue::F32(f32::from_le_bytes(self.take()?))),
+            PrimitiveKind::F64 => Ok(DynamicValue::F64(f64::from_le_bytes(self.take()?))),
             PrimitiveKind::LongDouble => {
-                self.align(crate::dynamic::LONG_DOUBLE_ALIGN);
+                self.align(self.encoding.alignment(crate::dynamic::LONG_DOUBLE_ALIGN));
                 let bytes = self.read_bytes(crate::dynamic::LONG_DOUBLE_SIZE)?;
                 let mut storage = [0u8; crate::dynamic::LONG_DOUBLE_SIZE];
                 storage.copy_from_slice(bytes);
+                if self.encoding.is_big_endian() {
+                    storage.reverse();
+                }
                 Ok(DynamicValue::LongDouble(storage))
             }
             PrimitiveKind::Char => {
@@ -442,11 +498,7 @@ impl<'a> CdrDecoder<'a> {
                 Ok(DynamicValue::Char(bytes[0] as char))
             }
             PrimitiveKind::String { max_length } => {
-                self.align(4);
-                let len_bytes = self.read_bytes(4)?;
-                let len =
-                    u32::from_le_bytes([len_bytes[0], len_bytes[1], len_bytes[2], len_bytes[3]])
-                        as usize;
+                let len = self.take_u32()? as usize;
                 if let Some(max) = max_length {
                     if len > max + 1 {
                         return Err(DynamicCdrError::InvalidData("string exceeds bound".into()));
@@ -466,11 +518,13 @@ impl<'a> CdrDecoder<'a> {
                 Ok(DynamicValue::String(s))
             }
             PrimitiveKind::WString { max_length } => {
-                self.align(4);
-                let len_bytes = self.read_bytes(4)?;
-                let len =
-                    u32::from_le_bytes([len_bytes[0], len_bytes[1], len_bytes[2], len_bytes[3]])
-                        as usize;
+                let len = self.take_u32()? as usize;
+                // XCDR2 counts bytes, XCDR1 counts characters
+                let len = if self.encoding.is_xcdr2() {
+                    len / 2
+                } else {
+                    len
+                };
                 if let Some(max) = max_length {
                     if len > max + 1 {
                         return Err(DynamicCdrError::InvalidData("wstring exceeds bound".into()));
@@ -481,8 +535,7 @@ impl<'a> CdrDecoder<'a> {
                 }
                 let mut chars = Vec::with_capacity(len);
                 for _ in 0..len {
-                    let char_bytes = self.read_bytes(2)?;
-                    chars.push(u16::from_le_bytes([char_bytes[0], char_bytes[1]]));
+                    chars.push(u16::from_le_bytes(self.take()?));
                 }
                 // Remove null terminator
                 if chars.last() == Some(&0) {
@@ -616,4 +669,49 @@ mod tests {
         assert_eq!(origin.get_field("x").and_then(|v| v.as_i32()), Some(10));
         assert_eq!(origin.get_field("y").and_then(|v| v.as_i32()), Some(20));
     }
+
+    fn sample() -> DynamicData {
+        let desc = Arc::new(
+            TypeDescriptorBuilder::new("Sample")
+                .field("flag", PrimitiveKind::Bool)
+                .field("stamp", PrimitiveKind::U64)
+                .field("label", PrimitiveKind::WString { max_length: None })
+                .build(),
+        );
+        let mut data = DynamicData::new(&desc);
+        data.set("flag", true).unwrap();
+        data.set("stamp", 0x0102_0304_0506_0708u64).unwrap();
+        if let DynamicValue::Struct(ref mut fields) = data.value_mut() {
+            fields.insert("label".to_string(), DynamicValue::WString("hi".into()));
+        }
+        data
+    }
+
+    #[test]
+    fn test_encodings_layout() {
+        let data = sample();
+        let xcdr1_le = encode_dynamic_as(&data, CdrEncoding::Xcdr1Le).expect("encode");
+        assert_eq!(xcdr1_le, encode_dynamic(&data).expect("encode"));
+        // XCDR1 aligns the u64 to 8, XCDR2 to 4
+        assert_eq!(&xcdr1_le[8..16], &0x0102_0304_0506_0708u64.to_le_bytes());
+        let xcdr2_be = encode_dynamic_as(&data, CdrEncoding::Xcdr2Be).expect("encode");
+        assert_eq!(&xcdr2_be[4..12], &0x0102_0304_0506_0708u64.to_be_bytes());
+        // XCDR2 wstring: byte length, no terminator
+        assert_eq!(&xcdr2_be[12..], &[0, 0, 0, 4, 0, b'h', 0, b'i']);
+        assert_eq!(&xcdr1_le[16..20], &3u32.to_le_bytes());
+    }
+
+    #[test]
+    fn test_encodings_roundtrip() {
+        let data = sample();
+        for encoding in CdrEncoding::ALL {
+            let bytes = encode_dynamic_as(&data, encoding).expect("encode");
+            let decoded = decode_dynamic_as(&bytes, data.descriptor(), encoding).expect("decode");
+            assert_eq!(decoded.value(), data.value(), "{}", encoding.name());
+            assert_eq!(
+                CdrEncoding::from_encapsulation_id(encoding.encapsulation_id()),
+                Some(encoding)
+            );
+        }
+    }
 }
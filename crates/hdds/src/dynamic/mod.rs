@@ -12,6 +12,8 @@
 //! - **DynamicData**: Type-erased data container with field access
 //! - **Builder API**: Fluent interface for building type descriptors
 //! - **CDR Support**: Encode/decode DynamicData to/from CDR wire format
+//! - **Endpoints**: Publish and take DynamicData with
+//!   `Participant::create_dynamic_writer` / `create_dynamic_reader`
 //!
 //! # Example
 //!
@@ -44,9 +46,15 @@ mod type_descriptor;
 mod value;
 mod xtypes_bridge;
 
-pub use builder::TypeDescriptorBuilder;
-pub use cdr_dynamic::{decode_dynamic, encode_dynamic, DynamicCdrError};
-pub use dynamic_data::DynamicData;
+pub use crate::dds::{DynamicDataReader, DynamicDataWriter};
+pub use builder::{
+    ArrayBuilder, EnumBuilder, SequenceBuilder, TypeDescriptorBuilder, UnionBuilder,
+};
+pub use cdr_dynamic::{
+    decode_dynamic, decode_dynamic_as, encode_dynamic, encode_dynamic_as, CdrEncoding,
+    DynamicCdrError,
+};
+pub use dynamic_data::{DynamicData, FromDynamicValue, IntoDynamicValue};
 pub use type_descriptor::{
     ArrayDescriptor, EnumDescriptor, EnumVariant, FieldDescriptor, PrimitiveKind,
     SequenceDescriptor, TypeDescriptor, TypeKind, UnionCase, UnionDescriptor,
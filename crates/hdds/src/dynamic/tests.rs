@@ -92,7 +92,6 @@ fn test_complex_nested_types() {
 }
 
 #[test]
-#[cfg(feature = "dynamic-types")]
 fn test_enum_type() {
     use crate::dynamic::builder::EnumBuilder;
 
@@ -6,7 +6,10 @@
 //! Manages topic registration, subscriber lists, and data delivery.
 //! Provides GUID->topic mapping for RTI/Cyclone/FastDDS interoperability.
 
+use crate::dds::qos::Partition;
 use crate::engine::subscriber::Subscriber;
+use crate::protocol::coherent::CoherentSetTag;
+use crate::protocol::type_version::TypeVersionTag;
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
@@ -126,11 +129,35 @@ impl Topic {
     /// HOT PATH: Called for every DATA packet delivery.
     #[inline]
     pub fn deliver(&self, seq: u64, data: &[u8]) -> usize {
+        self.deliver_traced(seq, data, None)
+    }
+
+    /// Deliver data carrying an optional per-sample trace id to all subscribers.
+    ///
+    /// Same as [`deliver`](Self::deliver); subscribers receive the trace id via
+    /// [`Subscriber::on_data_traced`].
+    #[inline]
+    pub fn deliver_traced(&self, seq: u64, data: &[u8], trace_id: Option<u64>) -> usize {
+        self.deliver_versioned(seq, data, trace_id, None)
+    }
+
+    /// Deliver data carrying an optional trace id and type version tag.
+    ///
+    /// Subscribers receive both via [`Subscriber::on_data_versioned`] and
+    /// decide themselves whether to keep the tagged copy.
+    #[inline]
+    pub fn deliver_versioned(
+        &self,
+        seq: u64,
+        data: &[u8],
+        trace_id: Option<u64>,
+        type_version: Option<&TypeVersionTag>,
+    ) -> usize {
         let mut errors = 0;
 
         for sub in &self.subscribers {
             let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                sub.on_data(&self.name, seq, data);
+                sub.on_data_versioned(&self.name, seq, data, trace_id, type_version);
             }));
 
             if result.is_err() {
@@ -144,6 +171,65 @@ impl Topic {
 
         errors
     }
+
+    /// Deliver data belonging to a group coherent set.
+    ///
+    /// Subscribers receive it via [`Subscriber::on_data_coherent`].
+    pub fn deliver_coherent(
+        &self,
+        seq: u64,
+        data: &[u8],
+        trace_id: Option<u64>,
+        type_version: Option<&TypeVersionTag>,
+        coherent_set: CoherentSetTag,
+    ) -> usize {
+        let mut errors = 0;
+
+        for sub in &self.subscribers {
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                sub.on_data_coherent(&self.name, seq, data, trace_id, type_version, coherent_set);
+            }));
+
+            if result.is_err() {
+                errors += 1;
+                log::debug!(
+                    "[demux] Subscriber '{}' panicked during delivery",
+                    sub.topic_name()
+                );
+            }
+        }
+
+        errors
+    }
+
+    /// Deliver an instance dispose/unregister to all subscribers.
+    ///
+    /// Subscribers receive it via [`Subscriber::on_instance_state`].
+    pub fn deliver_instance_state(
+        &self,
+        seq: u64,
+        data: &[u8],
+        status_info: u8,
+        key_hash: Option<[u8; 16]>,
+    ) -> usize {
+        let mut errors = 0;
+
+        for sub in &self.subscribers {
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                sub.on_instance_state(&self.name, seq, data, status_info, key_hash);
+            }));
+
+            if result.is_err() {
+                errors += 1;
+                log::debug!(
+                    "[demux] Subscriber '{}' panicked during instance state delivery",
+                    sub.topic_name()
+                );
+            }
+        }
+
+        errors
+    }
 }
 
 // ============================================================================
@@ -168,8 +254,13 @@ pub struct TopicRegistry {
     pub(crate) nack_frag_handlers: RwLock<Vec<Arc<dyn NackFragHandler>>>,
     /// Writer GUID -> topic name mapping for DATA routing (RTI interop)
     writer_guid_to_topic: RwLock<HashMap<[u8; 16], String>>,
+    /// Writer GUID -> PARTITION QoS, for writers outside the default partition
+    writer_partitions: RwLock<HashMap<[u8; 16], Partition>>,
 }
 
+/// Partition of writers without a `writer_partitions` entry.
+static DEFAULT_PARTITION: Partition = Partition { names: Vec::new() };
+
 #[inline]
 fn recover_write<'a, T>(lock: &'a RwLock<T>, context: &str) -> RwLockWriteGuard<'a, T> {
     match lock.write() {
@@ -200,6 +291,7 @@ impl TopicRegistry {
             nack_handlers: RwLock::new(Vec::new()),
             nack_frag_handlers: RwLock::new(Vec::new()),
             writer_guid_to_topic: RwLock::new(HashMap::new()),
+            writer_partitions: RwLock::new(HashMap::new()),
         }
     }
 
@@ -270,6 +362,32 @@ impl TopicRegistry {
         topics.get(name).cloned()
     }
 
+    /// Get a topic with only the subscribers whose PARTITION QoS matches the
+    /// writer with GUID `writer_guid` (see [`Subscriber::partition`]).
+    ///
+    /// # Performance
+    /// HOT PATH: Called for every DATA packet delivery.
+    #[must_use]
+    pub fn get_topic_for_writer(
+        &self,
+        name: &str,
+        writer_guid: Option<&[u8; 16]>,
+    ) -> Option<Topic> {
+        let mut topic = self.get_topic(name)?;
+        let partitions = recover_read(
+            &self.writer_partitions,
+            "TopicRegistry::writer_partitions.read()",
+        );
+        let offered = writer_guid
+            .and_then(|guid| partitions.get(guid))
+            .unwrap_or(&DEFAULT_PARTITION);
+        topic.subscribers.retain(|sub| {
+            sub.partition()
+                .is_none_or(|requested| offered.is_compatible_with(requested))
+        });
+        Some(topic)
+    }
+
     #[must_use]
     pub fn topic_count(&self) -> usize {
         let topics = recover_read(&self.topics, "TopicRegistry::topics.read()");
@@ -315,6 +433,27 @@ impl TopicRegistry {
         );
     }
 
+    /// Record the PARTITION QoS a writer announced via SEDP.
+    ///
+    /// Samples of the writer are only delivered to subscribers whose
+    /// partitions match (see [`get_topic_for_writer`](Self::get_topic_for_writer)).
+    pub fn set_writer_partition(&self, guid: [u8; 16], partition: Partition) {
+        let mut partitions = recover_write(
+            &self.writer_partitions,
+            "TopicRegistry::writer_partitions.write()",
+        );
+        if partition.is_default() {
+            partitions.remove(&guid);
+        } else {
+            log::debug!(
+                "[REGISTRY] writer guid={:02x?} partition={:?}",
+                &guid[..],
+                partition.names
+            );
+            partitions.insert(guid, partition);
+        }
+    }
+
     /// Lookup topic name by writer GUID for DATA packet routing.
     ///
     /// Returns `None` if the GUID is unknown (writer not announced via SEDP yet).
@@ -0,0 +1,360 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Per-locator receive statistics (sequence gaps, reordering, estimated loss).
+//!
+//! The router records the sequence number of every DATA it pops, keyed by
+//! the source address it arrived from and the writer that sent it. Each
+//! locator therefore reflects the health of one network path: a segment that
+//! drops packets shows gaps there while the other paths stay clean.
+//!
+//! Counting happens before deduplication, so a sample reaching us over both
+//! multicast and unicast is accounted on each path. Per writer, a 64-sample
+//! window tells late arrivals (holes being filled) from duplicates. Samples
+//! older than the window are taken as a writer restart. Reliable repairs
+//! also fill holes, so on reliable topics `estimated_lost` converges to what
+//! the repair did not recover and `reordered` includes retransmissions.
+
+use crate::core::discovery::GUID;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Width of the per-writer reorder window, in sequence numbers.
+const REORDER_WINDOW: u64 = 64;
+/// Locators tracked at once; packets from further sources are only counted.
+const MAX_LOCATORS: usize = 256;
+/// Writers tracked per locator; the least recently seen one is evicted.
+const MAX_WRITERS_PER_LOCATOR: usize = 1024;
+
+/// Sequence tracking for one writer as seen from one locator.
+#[derive(Debug, Clone, Copy)]
+struct WriterStream {
+    highest: u64,
+    /// Bit `i` set: `highest - i` has been received.
+    window: u64,
+    last_seen: Instant,
+}
+
+/// Outcome of recording one sequence number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SeqEvent {
+    /// Next in order (or first sample of the writer).
+    InOrder,
+    /// Ahead of the next expected one; `missing` samples were skipped.
+    Gap { missing: u64 },
+    /// Fills a hole `distance` samples behind the highest one.
+    Late { distance: u64 },
+    /// Already received.
+    Duplicate,
+    /// Too far behind the window: the writer restarted.
+    Restart,
+}
+
+impl WriterStream {
+    fn new(seq: u64, now: Instant) -> Self {
+        Self {
+            highest: seq,
+            window: 1,
+            last_seen: now,
+        }
+    }
+
+    fn record(&mut self, seq: u64, now: Instant) -> SeqEvent {
+        self.last_seen = now;
+        if seq > self.highest {
+            let advance = seq - self.highest;
+            self.window = if advance >= REORDER_WINDOW {
+                1
+            } else {
+                (self.window << advance) | 1
+            };
+            self.highest = seq;
+            return match advance {
+                1 => SeqEvent::InOrder,
+                _ => SeqEvent::Gap {
+                    missing: advance - 1,
+                },
+            };
+        }
+
+        let distance = self.highest - seq;
+        if distance >= REORDER_WINDOW {
+            *self = Self::new(seq, now);
+            return SeqEvent::Restart;
+        }
+        let bit = 1u64 << distance;
+        if self.window & bit != 0 {
+            return SeqEvent::Duplicate;
+        }
+        self.window |= bit;
+        SeqEvent::Late { distance }
+    }
+}
+
+#[derive(Debug)]
+struct LocatorEntry {
+    writers: HashMap<GUID, WriterStream>,
+    received: u64,
+    duplicates: u64,
+    gaps: u64,
+    missing: u64,
+    reordered: u64,
+    max_reorder_distance: u64,
+    last_seen: Instant,
+}
+
+impl LocatorEntry {
+    fn new(now: Instant) -> Self {
+        Self {
+            writers: HashMap::new(),
+            received: 0,
+            duplicates: 0,
+            gaps: 0,
+            missing: 0,
+            reordered: 0,
+            max_reorder_distance: 0,
+            last_seen: now,
+        }
+    }
+
+    fn record(&mut self, writer: GUID, seq: u64, now: Instant) {
+        self.last_seen = now;
+        let event = match self.writers.get_mut(&writer) {
+            Some(stream) => stream.record(seq, now),
+            None => {
+                if self.writers.len() >= MAX_WRITERS_PER_LOCATOR {
+                    self.evict_stalest_writer();
+                }
+                self.writers.insert(writer, WriterStream::new(seq, now));
+                SeqEvent::InOrder
+            }
+        };
+
+        match event {
+            SeqEvent::InOrder | SeqEvent::Restart => self.received += 1,
+            SeqEvent::Gap { missing } => {
+                self.received += 1;
+                self.gaps += 1;
+                self.missing += missing;
+            }
+            SeqEvent::Late { distance } => {
+                self.received += 1;
+                self.reordered += 1;
+                self.missing = self.missing.saturating_sub(1);
+                self.max_reorder_distance = self.max_reorder_distance.max(distance);
+            }
+            SeqEvent::Duplicate => self.duplicates += 1,
+        }
+    }
+
+    fn evict_stalest_writer(&mut self) {
+        let stalest = self
+            .writers
+            .iter()
+            .min_by_key(|(_, stream)| stream.last_seen)
+            .map(|(guid, _)| *guid);
+        if let Some(guid) = stalest {
+            self.writers.remove(&guid);
+        }
+    }
+
+    fn view(&self, locator: SocketAddr, now: Instant) -> LocatorStatsView {
+        let expected = self.received + self.missing;
+        LocatorStatsView {
+            locator,
+            writers: self.writers.len(),
+            received: self.received,
+            duplicates: self.duplicates,
+            gaps: self.gaps,
+            estimated_lost: self.missing,
+            reordered: self.reordered,
+            max_reorder_distance: self.max_reorder_distance,
+            loss_ratio: if expected == 0 {
+                0.0
+            } else {
+                self.missing as f64 / expected as f64
+            },
+            idle: now.saturating_duration_since(self.last_seen),
+        }
+    }
+}
+
+/// Receive statistics of one remote locator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocatorStatsView {
+    /// Source address the packets arrived from.
+    pub locator: SocketAddr,
+    /// Writers currently tracked on this locator.
+    pub writers: usize,
+    /// Distinct samples received.
+    pub received: u64,
+    /// Samples received more than once on this locator.
+    pub duplicates: u64,
+    /// Times the sequence jumped ahead of the next expected number.
+    pub gaps: u64,
+    /// Skipped sequence numbers that have not arrived (yet).
+    pub estimated_lost: u64,
+    /// Samples that arrived after a higher sequence number.
+    pub reordered: u64,
+    /// Largest distance (in samples) a late sample arrived behind.
+    pub max_reorder_distance: u64,
+    /// `estimated_lost / (received + estimated_lost)`.
+    pub loss_ratio: f64,
+    /// Time since the last sample from this locator.
+    pub idle: Duration,
+}
+
+/// Per-locator receive statistics shared between the router and its readers.
+#[derive(Debug)]
+pub struct LocatorStats {
+    locators: Mutex<HashMap<SocketAddr, LocatorEntry>>,
+    untracked: AtomicU64,
+}
+
+impl Default for LocatorStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LocatorStats {
+    /// Create an empty table.
+    pub fn new() -> Self {
+        Self {
+            locators: Mutex::new(HashMap::new()),
+            untracked: AtomicU64::new(0),
+        }
+    }
+
+    /// Record sequence number `seq` of `writer` received from `locator`.
+    pub fn record(&self, locator: SocketAddr, writer: GUID, seq: u64) {
+        let now = Instant::now();
+        let mut locators = match self.locators.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if !locators.contains_key(&locator) && locators.len() >= MAX_LOCATORS {
+            self.untracked.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+        locators
+            .entry(locator)
+            .or_insert_with(|| LocatorEntry::new(now))
+            .record(writer, seq, now);
+    }
+
+    /// Samples not tracked because `MAX_LOCATORS` sources were already known.
+    pub fn untracked(&self) -> u64 {
+        self.untracked.load(Ordering::Relaxed)
+    }
+
+    /// Statistics of every tracked locator, worst loss ratio first.
+    pub fn snapshot(&self) -> Vec<LocatorStatsView> {
+        let now = Instant::now();
+        let locators = match self.locators.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let mut views: Vec<LocatorStatsView> = locators
+            .iter()
+            .map(|(locator, entry)| entry.view(*locator, now))
+            .collect();
+        views.sort_by(|a, b| {
+            b.loss_ratio
+                .total_cmp(&a.loss_ratio)
+                .then_with(|| a.locator.cmp(&b.locator))
+        });
+        views
+    }
+
+    /// Statistics of a single locator.
+    pub fn get(&self, locator: &SocketAddr) -> Option<LocatorStatsView> {
+        let locators = match self.locators.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        locators
+            .get(locator)
+            .map(|entry| entry.view(*locator, Instant::now()))
+    }
+
+    /// Forget all locators.
+    pub fn reset(&self) {
+        match self.locators.lock() {
+            Ok(mut guard) => guard.clear(),
+            Err(poisoned) => poisoned.into_inner().clear(),
+        }
+        self.untracked.store(0, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn writer(id: u8) -> GUID {
+        GUID::new([id; 12], [0, 0, 1, 2])
+    }
+
+    fn addr(port: u16) -> SocketAddr {
+        ([10, 0, 0, 1], port).into()
+    }
+
+    #[test]
+    fn test_gap_then_late_arrival() {
+        let stats = LocatorStats::new();
+        for seq in [1, 2, 5, 3, 6] {
+            stats.record(addr(7411), writer(1), seq);
+        }
+
+        let view = stats.get(&addr(7411)).expect("locator");
+        assert_eq!(view.received, 5);
+        assert_eq!(view.gaps, 1);
+        assert_eq!(view.reordered, 1);
+        assert_eq!(view.max_reorder_distance, 2);
+        // 4 never arrived
+        assert_eq!(view.estimated_lost, 1);
+        assert!((view.loss_ratio - 1.0 / 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_duplicates_and_writer_restart() {
+        let stats = LocatorStats::new();
+        for seq in [1, 2, 2, 1, 200, 1, 2] {
+            stats.record(addr(7411), writer(1), seq);
+        }
+
+        let view = stats.get(&addr(7411)).expect("locator");
+        assert_eq!(view.duplicates, 2);
+        assert_eq!(view.received, 5);
+        assert_eq!(view.gaps, 1);
+        assert_eq!(view.estimated_lost, 197);
+    }
+
+    #[test]
+    fn test_locators_tracked_separately() {
+        let stats = LocatorStats::new();
+        for seq in 1..=10 {
+            stats.record(addr(1), writer(1), seq);
+            if seq % 2 == 0 {
+                stats.record(addr(2), writer(1), seq);
+            }
+        }
+        stats.record(addr(2), writer(2), 1);
+
+        let views = stats.snapshot();
+        assert_eq!(views.len(), 2);
+        // Worst path first
+        assert_eq!(views[0].locator, addr(2));
+        assert_eq!(views[0].writers, 2);
+        assert_eq!(views[0].estimated_lost, 4);
+        assert_eq!(views[1].estimated_lost, 0);
+        assert_eq!(views[1].loss_ratio, 0.0);
+
+        stats.reset();
+        assert!(stats.snapshot().is_empty());
+    }
+}
@@ -26,6 +26,8 @@
 //! - **Router**: Background thread routing RTPS packets from RxRing to subscribers
 //! - **RouterMetrics**: Telemetry counters (packets routed, orphaned, errors, bytes)
 //! - **route_data_packet()**: HOT PATH function for DATA packet routing
+//! - **RxWorkerPool**: Optional threads for large samples, keeping the router responsive
+//! - **LocatorStats**: Sequence gaps, reordering and estimated loss per source locator
 //!
 //! ## Demux
 //! - **TopicRegistry**: Thread-safe topic -> subscribers mapping with GUID routing
@@ -71,8 +73,14 @@
 pub mod demux;
 /// Event hub for routing notifications.
 pub mod hub;
+/// Per-locator receive statistics (gaps, reordering, loss).
+pub mod locator_stats;
 /// Core multicast router loop.
 pub mod router;
+/// Receive timestamps of the packet being routed.
+pub mod rx_timestamps;
+/// Worker pool for CPU-heavy receive processing.
+pub mod rx_workers;
 /// Subscriber trait and callback adapter.
 pub mod subscriber;
 /// Unicast RTPS router entry points.
@@ -85,7 +93,10 @@ pub use demux::{
     HeartbeatHandler, NackFragHandler, NackHandler, RegistryError, Topic, TopicRegistry,
 };
 pub use hub::{Event, Hub};
+pub use locator_stats::{LocatorStats, LocatorStatsView};
 pub use router::{route_data_packet, RouteStatus, Router, RouterMetrics};
+pub use rx_timestamps::RxTimestamps;
+pub use rx_workers::{RxWorkerConfig, RxWorkerPool, RxWorkerSnapshot, RxWorkerStats};
 pub use subscriber::{CallbackSubscriber, Subscriber};
 pub use unicast_router::{route_raw_rtps_message, UnicastRouteOutcome};
 pub use wake::WakeNotifier;
@@ -164,6 +175,385 @@ mod tests {
         assert_eq!(metrics.delivery_errors.load(Ordering::Relaxed), 1);
     }
 
+    struct TraceCapture {
+        topic: String,
+        seen: std::sync::Mutex<Vec<Option<u64>>>,
+    }
+
+    impl Subscriber for TraceCapture {
+        fn on_data(&self, _topic: &str, _seq: u64, _data: &[u8]) {
+            panic!("router should deliver through on_data_traced");
+        }
+
+        fn on_data_traced(&self, _topic: &str, _seq: u64, _data: &[u8], trace_id: Option<u64>) {
+            self.seen.lock().expect("lock").push(trace_id);
+        }
+
+        fn topic_name(&self) -> &str {
+            &self.topic
+        }
+    }
+
+    #[test]
+    fn test_route_data_packet_propagates_trace_id() {
+        let registry = TopicRegistry::new();
+        let metrics = RouterMetrics::new();
+        let capture = Arc::new(TraceCapture {
+            topic: "trace/topic".to_string(),
+            seen: std::sync::Mutex::new(Vec::new()),
+        });
+        registry
+            .register_topic("trace/topic".to_string(), None)
+            .expect("register topic");
+        registry
+            .register_subscriber(capture.clone())
+            .expect("register subscriber");
+
+        let ctx = builder::RtpsEndpointContext {
+            guid_prefix: [0x22; 12],
+            reader_entity_id: [0, 0, 0, 0x04],
+            writer_entity_id: [0, 0, 0x01, 0x03],
+        };
+        let traced = builder::build_data_packet_with_context_traced(
+            &ctx,
+            "trace/topic",
+            1,
+            &[1, 2, 3, 4],
+            Some(0xabcd),
+        );
+        let plain = builder::build_data_packet_with_context(&ctx, "trace/topic", 2, &[5, 6, 7, 8]);
+
+        for packet in [&traced, &plain] {
+            // Serialized payload = 4-byte encapsulation header + 4 data bytes.
+            let offset = Some(packet.len() - 8);
+            let status = route_data_packet(packet, packet.len(), offset, &registry, &metrics);
+            assert_eq!(status, RouteStatus::Delivered);
+        }
+        assert_eq!(
+            *capture.seen.lock().expect("lock"),
+            vec![Some(0xabcd), None]
+        );
+    }
+
+    #[test]
+    fn test_route_data_packet_drops_checksum_mismatch() {
+        let registry = TopicRegistry::new();
+        let metrics = RouterMetrics::new();
+        let capture = Arc::new(TraceCapture {
+            topic: "crc/topic".to_string(),
+            seen: std::sync::Mutex::new(Vec::new()),
+        });
+        registry
+            .register_topic("crc/topic".to_string(), None)
+            .expect("register topic");
+        registry
+            .register_subscriber(capture.clone())
+            .expect("register subscriber");
+
+        let ctx = builder::RtpsEndpointContext {
+            guid_prefix: [0x33; 12],
+            reader_entity_id: [0, 0, 0, 0x04],
+            writer_entity_id: [0, 0, 0x01, 0x03],
+        };
+        for kind in [crate::ChecksumKind::Crc32c, crate::ChecksumKind::XxHash64] {
+            let packet = builder::build_data_packet_with_context_checked(
+                &ctx,
+                "crc/topic",
+                1,
+                &[1, 2, 3, 4],
+                None,
+                Some(kind),
+            );
+            let offset = Some(packet.len() - 8);
+
+            let status = route_data_packet(&packet, packet.len(), offset, &registry, &metrics);
+            assert_eq!(status, RouteStatus::Delivered);
+
+            // Flip one payload bit after the checksum was computed.
+            let mut corrupted = packet.clone();
+            let last = corrupted.len() - 1;
+            corrupted[last] ^= 0x10;
+            let status =
+                route_data_packet(&corrupted, corrupted.len(), offset, &registry, &metrics);
+            assert_eq!(status, RouteStatus::Dropped);
+        }
+
+        assert_eq!(capture.seen.lock().expect("lock").len(), 2);
+        assert_eq!(metrics.checksum_failures.load(Ordering::Relaxed), 2);
+        assert_eq!(metrics.delivery_errors.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn test_route_data_packet_ignores_foreign_vendor_checksum_pid() {
+        use crate::protocol::constants::RTI_VENDOR_ID_U16;
+
+        let registry = TopicRegistry::new();
+        let metrics = RouterMetrics::new();
+        let capture = Arc::new(TraceCapture {
+            topic: "foreign/topic".to_string(),
+            seen: std::sync::Mutex::new(Vec::new()),
+        });
+        registry
+            .register_topic("foreign/topic".to_string(), None)
+            .expect("register topic");
+        registry
+            .register_subscriber(capture.clone())
+            .expect("register subscriber");
+
+        let ctx = builder::RtpsEndpointContext {
+            guid_prefix: [0x35; 12],
+            reader_entity_id: [0, 0, 0, 0x04],
+            writer_entity_id: [0, 0, 0x01, 0x03],
+        };
+        // 0x8004 from another vendor is that vendor's parameter, not a
+        // checksum: a value that does not verify must not drop the sample.
+        let mut packet = builder::build_data_packet_with_context_checked(
+            &ctx,
+            "foreign/topic",
+            1,
+            &[1, 2, 3, 4],
+            None,
+            Some(crate::ChecksumKind::Crc32c),
+        );
+        packet[6..8].copy_from_slice(&RTI_VENDOR_ID_U16.to_be_bytes());
+        let last = packet.len() - 1;
+        packet[last] ^= 0x10;
+        let offset = Some(packet.len() - 8);
+
+        let status = route_data_packet(&packet, packet.len(), offset, &registry, &metrics);
+        assert_eq!(status, RouteStatus::Delivered);
+        assert_eq!(capture.seen.lock().expect("lock").len(), 1);
+        assert_eq!(metrics.checksum_failures.load(Ordering::Relaxed), 0);
+    }
+
+    /// Records the HDDS tags each sample was delivered with.
+    struct TagCapture {
+        topic: String,
+        seen: std::sync::Mutex<Vec<(Option<u64>, bool, bool)>>,
+    }
+
+    impl Subscriber for TagCapture {
+        fn on_data(&self, _topic: &str, _seq: u64, _data: &[u8]) {
+            panic!("router should deliver through on_data_versioned");
+        }
+
+        fn on_data_versioned(
+            &self,
+            _topic: &str,
+            _seq: u64,
+            _data: &[u8],
+            trace_id: Option<u64>,
+            type_version: Option<&crate::protocol::type_version::TypeVersionTag>,
+        ) {
+            let entry = (trace_id, type_version.is_some(), false);
+            self.seen.lock().expect("lock").push(entry);
+        }
+
+        fn on_data_coherent(
+            &self,
+            _topic: &str,
+            _seq: u64,
+            _data: &[u8],
+            trace_id: Option<u64>,
+            type_version: Option<&crate::protocol::type_version::TypeVersionTag>,
+            _coherent_set: crate::protocol::coherent::CoherentSetTag,
+        ) {
+            let entry = (trace_id, type_version.is_some(), true);
+            self.seen.lock().expect("lock").push(entry);
+        }
+
+        fn topic_name(&self) -> &str {
+            &self.topic
+        }
+    }
+
+    #[test]
+    fn test_route_data_packet_ignores_foreign_vendor_hdds_tags() {
+        use crate::protocol::coherent::CoherentSetTag;
+        use crate::protocol::constants::RTI_VENDOR_ID_U16;
+        use crate::protocol::type_version::TypeVersionTag;
+
+        let registry = TopicRegistry::new();
+        let metrics = RouterMetrics::new();
+        let capture = Arc::new(TagCapture {
+            topic: "tags/topic".to_string(),
+            seen: std::sync::Mutex::new(Vec::new()),
+        });
+        registry
+            .register_topic("tags/topic".to_string(), None)
+            .expect("register topic");
+        registry
+            .register_subscriber(capture.clone())
+            .expect("register subscriber");
+
+        let ctx = builder::RtpsEndpointContext {
+            guid_prefix: [0x36; 12],
+            reader_entity_id: [0, 0, 0, 0x04],
+            writer_entity_id: [0, 0, 0x01, 0x03],
+        };
+        let tag = TypeVersionTag {
+            sample: 1,
+            native: 2,
+            offered: vec![1, 2],
+        };
+        let packet = builder::build_data_packet_with_options(
+            &ctx,
+            "tags/topic",
+            1,
+            &[1, 2, 3, 4],
+            &builder::DataPacketOptions {
+                trace_id: Some(0x77),
+                type_version: Some(&tag),
+                coherent_set: Some(CoherentSetTag {
+                    group_id: 9,
+                    set_seq: 1,
+                    order: 0,
+                }),
+                ..Default::default()
+            },
+        );
+        let mut foreign = packet.clone();
+        foreign[6..8].copy_from_slice(&RTI_VENDOR_ID_U16.to_be_bytes());
+
+        for packet in [&packet, &foreign] {
+            let offset = Some(packet.len() - 8);
+            let status = route_data_packet(packet, packet.len(), offset, &registry, &metrics);
+            assert_eq!(status, RouteStatus::Delivered);
+        }
+
+        // The same PIDs from another vendor are delivered as a plain sample.
+        assert_eq!(
+            *capture.seen.lock().expect("lock"),
+            vec![(Some(0x77), true, true), (None, false, false)]
+        );
+    }
+
+    #[test]
+    fn test_route_data_packet_keeps_native_type_version_by_default() {
+        use crate::protocol::type_version::TypeVersionTag;
+
+        let registry = TopicRegistry::new();
+        let metrics = RouterMetrics::new();
+        let capture = Arc::new(TraceCapture {
+            topic: "versioned/topic".to_string(),
+            seen: std::sync::Mutex::new(Vec::new()),
+        });
+        registry
+            .register_topic("versioned/topic".to_string(), None)
+            .expect("register topic");
+        registry
+            .register_subscriber(capture.clone())
+            .expect("register subscriber");
+
+        let ctx = builder::RtpsEndpointContext {
+            guid_prefix: [0x44; 12],
+            reader_entity_id: [0, 0, 0, 0x04],
+            writer_entity_id: [0, 0, 0x01, 0x03],
+        };
+        // Writer on v2 also publishing v1: same seq, one copy per version.
+        for (sample, trace_id) in [(1, 0x1), (2, 0x2)] {
+            let tag = TypeVersionTag {
+                sample,
+                native: 2,
+                offered: vec![1, 2],
+            };
+            let packet = builder::build_data_packet_with_options(
+                &ctx,
+                "versioned/topic",
+                1,
+                &[1, 2, 3, 4],
+                &builder::DataPacketOptions {
+                    trace_id: Some(trace_id),
+                    type_version: Some(&tag),
+                    ..Default::default()
+                },
+            );
+            let offset = Some(packet.len() - 8);
+            let status = route_data_packet(&packet, packet.len(), offset, &registry, &metrics);
+            assert_eq!(status, RouteStatus::Delivered);
+        }
+
+        // Unversioned subscriber keeps only the writer's native copy.
+        assert_eq!(*capture.seen.lock().expect("lock"), vec![Some(0x2)]);
+    }
+
+    struct LifecycleCapture {
+        topic: String,
+        data: AtomicUsize,
+        states: std::sync::Mutex<Vec<(u8, Option<[u8; 16]>)>>,
+    }
+
+    impl Subscriber for LifecycleCapture {
+        fn on_data(&self, _topic: &str, _seq: u64, _data: &[u8]) {
+            self.data.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn on_instance_state(
+            &self,
+            _topic: &str,
+            _seq: u64,
+            _data: &[u8],
+            status_info: u8,
+            key_hash: Option<[u8; 16]>,
+        ) {
+            self.states
+                .lock()
+                .expect("lock")
+                .push((status_info, key_hash));
+        }
+
+        fn topic_name(&self) -> &str {
+            &self.topic
+        }
+    }
+
+    #[test]
+    fn test_route_data_packet_dispatches_instance_state() {
+        use crate::protocol::constants::STATUS_INFO_DISPOSED;
+
+        let registry = TopicRegistry::new();
+        let metrics = RouterMetrics::new();
+        let capture = Arc::new(LifecycleCapture {
+            topic: "keyed/topic".to_string(),
+            data: AtomicUsize::new(0),
+            states: std::sync::Mutex::new(Vec::new()),
+        });
+        registry
+            .register_topic("keyed/topic".to_string(), None)
+            .expect("register topic");
+        registry
+            .register_subscriber(capture.clone())
+            .expect("register subscriber");
+
+        let ctx = builder::RtpsEndpointContext {
+            guid_prefix: [0x55; 12],
+            reader_entity_id: [0, 0, 0, 0x04],
+            writer_entity_id: [0, 0, 0x01, 0x03],
+        };
+        for (seq, status_info) in [(1, 0), (2, STATUS_INFO_DISPOSED)] {
+            let packet = builder::build_data_packet_with_options(
+                &ctx,
+                "keyed/topic",
+                seq,
+                &[1, 2, 3, 4],
+                &builder::DataPacketOptions {
+                    key_hash: Some([1; 16]),
+                    status_info,
+                    ..Default::default()
+                },
+            );
+            let offset = Some(packet.len() - 8);
+            let status = route_data_packet(&packet, packet.len(), offset, &registry, &metrics);
+            assert_eq!(status, RouteStatus::Delivered);
+        }
+
+        assert_eq!(capture.data.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            *capture.states.lock().expect("lock"),
+            vec![(STATUS_INFO_DISPOSED, Some([1; 16]))]
+        );
+    }
+
     struct CountingHeartbeatHandler(Arc<AtomicUsize>);
 
     impl HeartbeatHandler for CountingHeartbeatHandler {
@@ -388,6 +778,64 @@ mod tests {
         assert!(!removed_again);
     }
 
+    struct PartitionCapture {
+        partition: crate::dds::qos::Partition,
+        received: AtomicUsize,
+    }
+
+    impl Subscriber for PartitionCapture {
+        fn on_data(&self, _topic: &str, _seq: u64, _data: &[u8]) {
+            self.received.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn topic_name(&self) -> &str {
+            "partition/topic"
+        }
+
+        fn partition(&self) -> Option<&crate::dds::qos::Partition> {
+            Some(&self.partition)
+        }
+    }
+
+    #[test]
+    fn test_route_data_packet_filters_by_writer_partition() {
+        use crate::dds::qos::Partition;
+
+        let registry = TopicRegistry::new();
+        let metrics = RouterMetrics::new();
+        let bench = Arc::new(PartitionCapture {
+            partition: Partition::single("bench/*"),
+            received: AtomicUsize::new(0),
+        });
+        let production = Arc::new(PartitionCapture {
+            partition: Partition::single("production"),
+            received: AtomicUsize::new(0),
+        });
+        registry
+            .register_subscriber(bench.clone())
+            .expect("register subscriber");
+        registry
+            .register_subscriber(production.clone())
+            .expect("register subscriber");
+
+        let ctx = builder::RtpsEndpointContext {
+            guid_prefix: [0x33; 12],
+            reader_entity_id: [0, 0, 0, 0x04],
+            writer_entity_id: [0, 0, 0x01, 0x03],
+        };
+        let mut writer_guid = [0u8; 16];
+        writer_guid[..12].copy_from_slice(&ctx.guid_prefix);
+        writer_guid[12..].copy_from_slice(&ctx.writer_entity_id);
+        registry.set_writer_partition(writer_guid, Partition::single("bench/cell1"));
+
+        let packet = builder::build_data_packet_with_context(&ctx, "partition/topic", 1, &[1, 2]);
+        let offset = Some(packet.len() - 6);
+        let status = route_data_packet(&packet, packet.len(), offset, &registry, &metrics);
+        assert_eq!(status, RouteStatus::Delivered);
+        assert_eq!(bench.received.load(Ordering::SeqCst), 1);
+        assert_eq!(production.received.load(Ordering::SeqCst), 0);
+    }
+
     #[test]
     fn test_registry_handles_poisoned_locks() {
         let registry = TopicRegistry::new();
@@ -7,19 +7,28 @@
 //! to registered topic subscribers. Provides background thread orchestration and
 //! telemetry for packet processing.
 
-use crate::core::discovery::multicast::{FragmentMetadata, PacketKind, RxMeta, RxPool};
+use crate::core::discovery::multicast::{
+    classify_rtps, FragmentMetadata, PacketKind, RxMeta, RxPool,
+};
 use crate::core::discovery::{FragmentBuffer, GUID};
 use crate::engine::demux::TopicRegistry;
+use crate::engine::locator_stats::LocatorStats;
+use crate::engine::rx_timestamps::{self, RxTimestamps};
+use crate::engine::rx_workers::{RxJob, RxWorkerConfig, RxWorkerPool, RxWorkerStats};
 use crate::engine::wake::WakeNotifier;
 use crate::protocol::builder;
+use crate::protocol::constants::{STATUS_INFO_DISPOSED, STATUS_INFO_UNREGISTERED};
 use crate::protocol::discovery::parse_topic_name;
+use crate::protocol::original_writer::OriginalWriterInfo;
 use crossbeam::queue::ArrayQueue;
+use std::collections::HashMap;
 use std::io;
+use std::net::SocketAddr;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::thread::JoinHandle;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 /// Default configuration for user DATA_FRAG fragment buffer
 const USER_FRAG_MAX_PENDING: usize = 256;
@@ -45,6 +54,8 @@ pub struct RouterMetrics {
     pub fragment_timeouts: AtomicU64,
     /// v241: Packets skipped due to deduplication (same writer_guid+seq seen before)
     pub packets_deduplicated: AtomicU64,
+    /// Samples dropped because their `PID_HDDS_SAMPLE_CHECKSUM` did not match
+    pub checksum_failures: AtomicU64,
 }
 
 impl RouterMetrics {
@@ -59,6 +70,7 @@ impl RouterMetrics {
             nack_frag_requests: AtomicU64::new(0),
             fragment_timeouts: AtomicU64::new(0),
             packets_deduplicated: AtomicU64::new(0),
+            checksum_failures: AtomicU64::new(0),
         }
     }
 
@@ -122,17 +134,25 @@ pub fn route_data_packet(
     metrics: &RouterMetrics,
 ) -> RouteStatus {
     // Try inline QoS first (HDDS<->HDDS compat)
-    let topic_name = match builder::extract_inline_qos(payload) {
-        Some(qos) => parse_topic_name(qos),
-        None => None,
-    };
+    let inline_qos = builder::extract_inline_qos(payload);
+    let topic_name = inline_qos.and_then(parse_topic_name);
+    // Vendor-range PIDs only mean PID_HDDS_* in messages sent by HDDS
+    let hdds_qos = inline_qos.filter(|_| builder::is_hdds_vendor(payload));
+    let trace_id = hdds_qos.and_then(builder::parse_trace_id);
+    let checksum = hdds_qos.and_then(builder::parse_sample_checksum);
+    let type_version = hdds_qos.and_then(builder::parse_type_version);
+    let coherent_set = hdds_qos.and_then(builder::parse_coherent_set);
+    let original_writer = inline_qos.and_then(builder::parse_original_writer);
+    let status_info = inline_qos.and_then(builder::parse_status_info).unwrap_or(0);
+    let key_hash = inline_qos.and_then(builder::parse_key_hash);
+    // Extract writer GUID from RTPS header + DATA submessage
+    let writer_guid = builder::extract_writer_guid(payload);
 
     // Fallback to GUID-based routing if no inline QoS (RTI/Cyclone/FastDDS)
     let topic_name = match topic_name {
         Some(name) => name,
         None => {
-            // Extract writer GUID from RTPS header + DATA submessage
-            let guid = match builder::extract_writer_guid(payload) {
+            let guid = match writer_guid {
                 Some(g) => g,
                 None => {
                     log::debug!("[ROUTER] DROP: Cannot extract writer GUID from DATA packet");
@@ -176,7 +196,7 @@ pub fn route_data_packet(
         }
     };
 
-    let topic = match registry.get_topic(&topic_name) {
+    let topic = match registry.get_topic_for_writer(&topic_name, writer_guid.as_ref()) {
         Some(topic) => topic,
         None => {
             log::debug!(
@@ -215,9 +235,14 @@ pub fn route_data_packet(
         }
 
         let mut start = offset;
+        let mut end = payload.len();
         if start + 4 <= payload.len() {
             let enc = u16::from_be_bytes([payload[start], payload[start + 1]]);
-            let padding = u16::from_be_bytes([payload[start + 2], payload[start + 3]]);
+            let options = u16::from_be_bytes([payload[start + 2], payload[start + 3]]);
+            // The two low bits of the options count the padding bytes ending
+            // the payload (XTypes 7.6.3.1.2), e.g. in a batched message
+            let plain = options & !0x3 == 0;
+            let trailing = usize::from(options & 0x3);
 
             // XCDR1 encapsulations (CDR v1)
             let is_xcdr1 = matches!(enc, 0x0001 | 0x0003 | 0x8001 | 0x8003);
@@ -226,10 +251,14 @@ pub fn route_data_packet(
             // D_CDR2 encapsulations (Delimited CDR v2) - has DHEADER
             let is_d_cdr2 = matches!(enc, 0x0008 | 0x0009);
 
-            if (is_xcdr1 || is_xcdr2) && padding == 0 {
+            if (is_xcdr1 || is_xcdr2 || is_d_cdr2) && plain {
+                end = end.saturating_sub(trailing);
+            }
+
+            if (is_xcdr1 || is_xcdr2) && plain {
                 // Skip 4-byte encapsulation header
                 start += 4;
-            } else if is_d_cdr2 && padding == 0 {
+            } else if is_d_cdr2 && plain {
                 // D_CDR2: Skip 4-byte encapsulation header + 4-byte DHEADER (size field)
                 // DHEADER format: 4 bytes little-endian size of serialized data
                 if start + 8 <= payload.len() {
@@ -244,7 +273,7 @@ pub fn route_data_packet(
             }
         }
 
-        if start >= payload.len() {
+        if start >= end {
             log::debug!(
                 "[ROUTER] drop DATA topic='{}' reason=missing_payload_after_header",
                 topic_name
@@ -254,16 +283,16 @@ pub fn route_data_packet(
         }
 
         if std::env::var("HDDS_INTEROP_DIAGNOSTICS").is_ok() {
-            let head_len = (payload.len() - start).min(16);
+            let head_len = (end - start).min(16);
             log::debug!(
                 "[ROUTER] DATA payload(len={}, offset={}) head={:02x?}",
-                payload.len() - start,
+                end - start,
                 start,
                 &payload[start..start + head_len]
             );
         }
 
-        &payload[start..]
+        &payload[start..end]
     } else {
         match builder::extract_data_payload(payload) {
             Some(p) => {
@@ -288,6 +317,22 @@ pub fn route_data_packet(
         }
     };
 
+    if let Some(checksum) = checksum {
+        if !checksum.verify(cdr2_payload) {
+            log::debug!(
+                "[ROUTER] drop DATA topic='{}' seq={} reason=checksum_mismatch kind={:?}",
+                topic_name,
+                seq,
+                checksum.kind
+            );
+            metrics.checksum_failures.fetch_add(1, Ordering::Relaxed);
+            if let Some(m) = crate::telemetry::get_metrics_opt() {
+                m.increment_checksum_failures(1);
+            }
+            return RouteStatus::Dropped;
+        }
+    }
+
     let subscriber_count = topic.subscriber_count();
     log::debug!(
         "[ROUTER] deliver topic='{}' seq={} subscriber_count={}",
@@ -296,7 +341,44 @@ pub fn route_data_packet(
         subscriber_count
     );
 
-    let errors = topic.deliver(seq, cdr2_payload);
+    if let Some(trace_id) = trace_id {
+        log::debug!(
+            "[trace] wire-in topic='{}' seq={} trace_id={:016x}",
+            topic.name(),
+            seq,
+            trace_id
+        );
+    }
+
+    // Readers report the writer in SampleInfo; PERSISTENT readers drop
+    // relayed copies of samples by their original writer
+    let writer_guid = writer_guid.map(GUID::from_bytes);
+    let original_writer =
+        original_writer.or_else(|| writer_guid.map(|guid| OriginalWriterInfo::new(guid, seq)));
+    let _rx_timestamps = rx_timestamps::enter(
+        rx_timestamps::current()
+            .with_writer(writer_guid)
+            .with_original_writer(original_writer),
+    );
+    let errors = if status_info & (STATUS_INFO_DISPOSED | STATUS_INFO_UNREGISTERED) != 0 {
+        log::debug!(
+            "[ROUTER] instance state topic='{}' seq={} status_info={:#04x}",
+            topic.name(),
+            seq,
+            status_info
+        );
+        topic.deliver_instance_state(seq, cdr2_payload, status_info, key_hash)
+    } else if let Some(coherent_set) = coherent_set {
+        topic.deliver_coherent(
+            seq,
+            cdr2_payload,
+            trace_id,
+            type_version.as_ref(),
+            coherent_set,
+        )
+    } else {
+        topic.deliver_versioned(seq, cdr2_payload, trace_id, type_version.as_ref())
+    };
 
     metrics.packets_routed.fetch_add(1, Ordering::Relaxed);
     metrics
@@ -390,17 +472,19 @@ pub fn route_data_frag_packet(
             }
         };
 
-        buffer.insert_fragment(
+        buffer.insert_traced_fragment(
             meta.writer_guid,
             meta.seq_num,
             meta.frag_num,
             meta.total_frags,
             frag_data,
+            None,
+            frag_trace_id(payload),
         )
     };
 
     // If complete, route the reassembled sample
-    if let Some(reassembled) = complete_payload {
+    if let Some((reassembled, trace_id)) = complete_payload {
         log::debug!(
             "[ROUTER] DATA_FRAG COMPLETE: seq={} reassembled_len={}",
             meta.seq_num,
@@ -413,6 +497,7 @@ pub fn route_data_frag_packet(
             &meta.writer_guid,
             meta.seq_num,
             &reassembled,
+            trace_id,
             registry,
             metrics,
         );
@@ -435,7 +520,6 @@ pub fn route_data_frag_packet_with_addr(
     registry: &TopicRegistry,
     metrics: &RouterMetrics,
 ) -> RouteStatus {
-    // Extract fragment metadata
     let meta = match frag_meta {
         Some(m) => m,
         None => {
@@ -445,6 +529,47 @@ pub fn route_data_frag_packet_with_addr(
         }
     };
 
+    match reassemble_data_frag(
+        payload,
+        payload_offset,
+        meta,
+        src_addr,
+        fragment_buffer,
+        metrics,
+    ) {
+        Ok(Some((reassembled, trace_id))) => route_reassembled_data(
+            &meta.writer_guid,
+            meta.seq_num,
+            &reassembled,
+            trace_id,
+            registry,
+            metrics,
+        ),
+        Ok(None) => RouteStatus::Delivered,
+        Err(status) => status,
+    }
+}
+
+/// Trace id carried by the inline QoS of a sample's first DATA_FRAG.
+fn frag_trace_id(packet: &[u8]) -> Option<u64> {
+    builder::extract_data_frag_inline_qos(packet).and_then(builder::parse_trace_id)
+}
+
+/// A reassembled sample and the trace id carried by its first fragment.
+type TracedSample = (Vec<u8>, Option<u64>);
+
+/// Insert a DATA_FRAG into the fragment buffer.
+///
+/// Returns the reassembled sample and its trace id once its last fragment
+/// arrives, `Ok(None)` while fragments are still missing.
+fn reassemble_data_frag(
+    payload: &[u8],
+    payload_offset: Option<usize>,
+    meta: &FragmentMetadata,
+    src_addr: std::net::SocketAddr,
+    fragment_buffer: &Mutex<FragmentBuffer>,
+    metrics: &RouterMetrics,
+) -> Result<Option<TracedSample>, RouteStatus> {
     // Extract fragment payload data
     let frag_data = if let Some(offset) = payload_offset {
         if offset >= payload.len() {
@@ -454,7 +579,7 @@ pub fn route_data_frag_packet_with_addr(
                 payload.len()
             );
             metrics.delivery_errors.fetch_add(1, Ordering::Relaxed);
-            return RouteStatus::Dropped;
+            return Err(RouteStatus::Dropped);
         }
         payload[offset..].to_vec()
     } else {
@@ -463,7 +588,7 @@ pub fn route_data_frag_packet_with_addr(
             None => {
                 log::debug!("[ROUTER] DROP DATA_FRAG: cannot extract fragment payload");
                 metrics.delivery_errors.fetch_add(1, Ordering::Relaxed);
-                return RouteStatus::Dropped;
+                return Err(RouteStatus::Dropped);
             }
         }
     };
@@ -488,44 +613,37 @@ pub fn route_data_frag_packet_with_addr(
             }
         };
 
-        buffer.insert_fragment_with_addr(
+        buffer.insert_traced_fragment(
             meta.writer_guid,
             meta.seq_num,
             meta.frag_num,
             meta.total_frags,
             frag_data,
             Some(src_addr),
+            frag_trace_id(payload),
         )
     };
 
-    // If complete, route the reassembled sample
-    if let Some(reassembled) = complete_payload {
+    if let Some((ref reassembled, _)) = complete_payload {
         log::debug!(
             "[ROUTER] DATA_FRAG COMPLETE: seq={} reassembled_len={}",
             meta.seq_num,
             reassembled.len()
         );
-
-        return route_reassembled_data(
-            &meta.writer_guid,
-            meta.seq_num,
-            &reassembled,
-            registry,
-            metrics,
-        );
     }
 
-    RouteStatus::Delivered
+    Ok(complete_payload)
 }
 
 /// Route a reassembled DATA_FRAG payload to topic subscribers.
 ///
 /// This is similar to route_data_packet but uses pre-extracted GUID and sequence.
 #[inline]
-fn route_reassembled_data(
+pub(crate) fn route_reassembled_data(
     writer_guid: &GUID,
     seq: u64,
     payload: &[u8],
+    trace_id: Option<u64>,
     registry: &TopicRegistry,
     metrics: &RouterMetrics,
 ) -> RouteStatus {
@@ -558,7 +676,7 @@ fn route_reassembled_data(
         }
     };
 
-    let topic = match registry.get_topic(&topic_name) {
+    let topic = match registry.get_topic_for_writer(&topic_name, Some(&guid_bytes)) {
         Some(topic) => topic,
         None => {
             log::debug!(
@@ -596,7 +714,20 @@ fn route_reassembled_data(
         payload
     };
 
-    let errors = topic.deliver(seq, payload_to_deliver);
+    let _rx_timestamps = rx_timestamps::enter(
+        rx_timestamps::current()
+            .with_writer(Some(*writer_guid))
+            .with_original_writer(Some(OriginalWriterInfo::new(*writer_guid, seq))),
+    );
+    if let Some(trace_id) = trace_id {
+        log::debug!(
+            "[trace] wire-in topic='{}' seq={} trace_id={:016x}",
+            topic_name,
+            seq,
+            trace_id
+        );
+    }
+    let errors = topic.deliver_traced(seq, payload_to_deliver, trace_id);
 
     metrics.packets_routed.fetch_add(1, Ordering::Relaxed);
     metrics
@@ -622,6 +753,8 @@ pub struct Router {
     stop_flag: Arc<AtomicBool>,
     handle: Option<JoinHandle<()>>,
     pub metrics: Arc<RouterMetrics>,
+    rx_workers: Option<Arc<RxWorkerPool>>,
+    locator_stats: Arc<LocatorStats>,
 }
 
 impl Router {
@@ -672,12 +805,50 @@ impl Router {
         transport: Option<Arc<crate::transport::UdpTransport>>,
         our_guid_prefix: [u8; 12],
         notifier: Option<Arc<WakeNotifier>>,
+    ) -> io::Result<Self> {
+        Self::start_with_rx_workers(
+            ring,
+            pool,
+            registry,
+            transport,
+            our_guid_prefix,
+            notifier,
+            None,
+        )
+    }
+
+    /// Start router with an optional receive worker pool.
+    ///
+    /// With `rx_workers`, large DATA packets and reassembled DATA_FRAG
+    /// samples are routed on dedicated threads so that checksum checks and
+    /// subscriber decode never hold up the router's protocol work.
+    pub fn start_with_rx_workers(
+        ring: Arc<ArrayQueue<(RxMeta, u8)>>,
+        pool: Arc<RxPool>,
+        registry: Arc<TopicRegistry>,
+        transport: Option<Arc<crate::transport::UdpTransport>>,
+        our_guid_prefix: [u8; 12],
+        notifier: Option<Arc<WakeNotifier>>,
+        rx_workers: Option<RxWorkerConfig>,
     ) -> io::Result<Self> {
         let stop_flag = Arc::new(AtomicBool::new(false));
         let metrics = Arc::new(RouterMetrics::new());
 
+        let rx_workers = match rx_workers {
+            Some(config) => Some(Arc::new(RxWorkerPool::start(
+                &config,
+                Arc::clone(&registry),
+                Arc::clone(&metrics),
+            )?)),
+            None => None,
+        };
+
+        let locator_stats = Arc::new(LocatorStats::new());
+
         let stop_flag_clone = Arc::clone(&stop_flag);
         let metrics_clone = Arc::clone(&metrics);
+        let rx_workers_clone = rx_workers.clone();
+        let locator_stats_clone = Arc::clone(&locator_stats);
 
         let handle = thread::spawn(move || {
             router_loop_with_transport(
@@ -689,6 +860,8 @@ impl Router {
                 transport,
                 our_guid_prefix,
                 notifier,
+                rx_workers_clone,
+                locator_stats_clone,
             );
         });
 
@@ -696,9 +869,24 @@ impl Router {
             stop_flag,
             handle: Some(handle),
             metrics,
+            rx_workers,
+            locator_stats,
         })
     }
 
+    /// Receive worker pool counters, if a pool is configured.
+    #[must_use]
+    pub fn rx_worker_stats(&self) -> Option<Arc<RxWorkerStats>> {
+        self.rx_workers.as_ref().map(|pool| pool.stats())
+    }
+
+    /// Per-source-locator sequence gap, reordering and loss estimates of
+    /// the DATA routed by this router.
+    #[must_use]
+    pub fn locator_stats(&self) -> Arc<LocatorStats> {
+        Arc::clone(&self.locator_stats)
+    }
+
     pub fn stop(mut self) -> io::Result<()> {
         self.stop_flag.store(true, Ordering::Relaxed);
         if let Some(handle) = self.handle.take() {
@@ -972,6 +1160,81 @@ fn parse_heartbeat_frag(payload: &[u8]) -> Option<([u8; 12], [u8; 4], u64, u32)>
     None
 }
 
+/// Route a user DATA message received by the router thread.
+///
+/// DATA from our own writers (self-loopback) and samples already routed via
+/// another path are dropped; the others are routed inline, or offloaded to
+/// the RX workers when the message is large.
+#[allow(clippy::too_many_arguments)]
+fn route_user_data(
+    payload: &[u8],
+    payload_offset: Option<usize>,
+    source: SocketAddr,
+    our_guid_prefix: [u8; 12],
+    dedup_cache: &mut HashMap<([u8; 16], u64), Instant>,
+    locator_stats: &LocatorStats,
+    rx_workers: Option<&RxWorkerPool>,
+    registry: &TopicRegistry,
+    metrics: &RouterMetrics,
+) -> RouteStatus {
+    // Extract writer GUID once for both self-loopback and dedup checks
+    let writer_guid = builder::extract_writer_guid(payload);
+
+    // Self-loopback suppression: skip DATA from our own writers.
+    // When both intra-process (TopicMerger) and UDP transport are active,
+    // the writer delivers locally via merger AND sends via UDP. The UDP
+    // packet loops back to us; delivering it again would cause duplicates.
+    if let Some(guid) = writer_guid {
+        if guid[..12] == our_guid_prefix {
+            log::trace!(
+                "[ROUTER] self-loopback: dropping DATA from own writer {:02x?}",
+                &guid[..8]
+            );
+            return RouteStatus::Dropped;
+        }
+    }
+
+    // Per-path statistics count every copy, so they run before dedup
+    let seq_num = builder::extract_sequence_number(payload);
+    if let (Some(guid), Some(seq)) = (writer_guid, seq_num) {
+        locator_stats.record(source, GUID::from_bytes(guid), seq);
+    }
+
+    // v241: Deduplication check - skip if we've seen this (writer_guid, seq) recently
+    // This prevents double delivery when same packet arrives via multicast + unicast
+    if let (Some(guid), Some(seq)) = (writer_guid, seq_num) {
+        use std::collections::hash_map::Entry;
+        match dedup_cache.entry((guid, seq)) {
+            Entry::Occupied(_) => {
+                metrics.packets_deduplicated.fetch_add(1, Ordering::Relaxed);
+                log::trace!(
+                    "[ROUTER] DEDUP: skipping duplicate seq={} guid={:02x?}",
+                    seq,
+                    &guid[..8]
+                );
+                return RouteStatus::Dropped;
+            }
+            Entry::Vacant(e) => {
+                e.insert(Instant::now());
+            }
+        }
+    }
+
+    match rx_workers {
+        Some(workers) if workers.should_offload(payload.len()) => {
+            workers.submit(
+                writer_guid.as_ref(),
+                RxJob::Data {
+                    packet: payload.to_vec(),
+                    payload_offset,
+                },
+            );
+            RouteStatus::Delivered
+        }
+        _ => route_data_packet(payload, payload.len(), payload_offset, registry, metrics),
+    }
+}
+
 /// Router loop with transport for NACK_FRAG sending
 #[allow(clippy::too_many_arguments)]
 fn router_loop_with_transport(
@@ -983,9 +1246,10 @@ fn router_loop_with_transport(
     transport: Option<Arc<crate::transport::UdpTransport>>,
     our_guid_prefix: [u8; 12],
     notifier: Option<Arc<WakeNotifier>>,
+    rx_workers: Option<Arc<RxWorkerPool>>,
+    locator_stats: Arc<LocatorStats>,
 ) {
-    use std::collections::HashMap;
-    use std::time::Instant;
+    crate::alloc_scope!(Transport);
 
     // Create fragment buffer for user DATA_FRAG reassembly
     let user_fragment_buffer = Mutex::new(FragmentBuffer::new(
@@ -1005,6 +1269,9 @@ fn router_loop_with_transport(
     const DEDUP_EVICT_INTERVAL_MS: u128 = 500;
     const DEDUP_ENTRY_TTL_MS: u128 = 2000;
 
+    // Per-sample messages split from batched DATA messages
+    let mut batch_scratch = Vec::new();
+
     // v210: Log whether using notifier or polling
     if notifier.is_some() {
         log::debug!("[ROUTER] v210: Using WakeNotifier for low-latency wake");
@@ -1058,78 +1325,47 @@ fn router_loop_with_transport(
         };
 
         let (meta, buffer_id) = item;
+        let _rx_timestamps = rx_timestamps::enter(RxTimestamps::from_meta(&meta));
         let buffer = pool.get_buffer(buffer_id);
         let payload = &buffer[..meta.len as usize];
 
         match meta.kind {
             PacketKind::Data => {
-                // Extract writer GUID once for both self-loopback and dedup checks
-                let writer_guid = builder::extract_writer_guid(payload);
-
-                // Self-loopback suppression: skip DATA from our own writers.
-                // When both intra-process (TopicMerger) and UDP transport are active,
-                // the writer delivers locally via merger AND sends via UDP. The UDP
-                // packet loops back to us; delivering it again would cause duplicates.
-                if let Some(guid) = writer_guid {
-                    if guid[..12] == our_guid_prefix {
-                        log::trace!(
-                            "[ROUTER] self-loopback: dropping DATA from own writer {:02x?}",
-                            &guid[..8]
+                // Batched messages are routed one DATA at a time, each with
+                // the source timestamp of the INFO_TS preceding it
+                let status = if builder::is_batched_data(payload) {
+                    builder::for_each_batched_data(payload, &mut batch_scratch, |sample| {
+                        let (_, payload_offset, _, context) = classify_rtps(sample);
+                        let _rx_timestamps = rx_timestamps::enter(RxTimestamps::from_context(
+                            &context,
+                            meta.kernel_rx_ns,
+                        ));
+                        route_user_data(
+                            sample,
+                            payload_offset,
+                            meta.sock,
+                            our_guid_prefix,
+                            &mut dedup_cache,
+                            &locator_stats,
+                            rx_workers.as_deref(),
+                            &registry,
+                            &metrics,
                         );
-                        if let Err(e) = pool.release(buffer_id) {
-                            log::debug!(
-                                "[hdds-router] Failed to release self-loopback buffer {}: {}",
-                                buffer_id,
-                                e
-                            );
-                        }
-                        continue;
-                    }
-                }
-
-                // v241: Deduplication check - skip if we've seen this (writer_guid, seq) recently
-                // This prevents double delivery when same packet arrives via multicast + unicast
-                let is_duplicate = match (writer_guid, builder::extract_sequence_number(payload)) {
-                    (Some(guid), Some(seq)) => {
-                        use std::collections::hash_map::Entry;
-                        let key = (guid, seq);
-                        match dedup_cache.entry(key) {
-                            Entry::Occupied(_) => {
-                                metrics.packets_deduplicated.fetch_add(1, Ordering::Relaxed);
-                                log::trace!(
-                                    "[ROUTER] DEDUP: skipping duplicate seq={} guid={:02x?}",
-                                    seq,
-                                    &guid[..8]
-                                );
-                                true
-                            }
-                            Entry::Vacant(e) => {
-                                e.insert(Instant::now());
-                                false
-                            }
-                        }
-                    }
-                    _ => false, // Can't extract guid/seq, let route_data_packet handle it
+                    });
+                    RouteStatus::Delivered
+                } else {
+                    route_user_data(
+                        payload,
+                        meta.data_payload_offset.map(|off| off as usize),
+                        meta.sock,
+                        our_guid_prefix,
+                        &mut dedup_cache,
+                        &locator_stats,
+                        rx_workers.as_deref(),
+                        &registry,
+                        &metrics,
+                    )
                 };
-
-                if is_duplicate {
-                    if let Err(e) = pool.release(buffer_id) {
-                        log::debug!(
-                            "[hdds-router] Failed to release deduplicated buffer {}: {}",
-                            buffer_id,
-                            e
-                        );
-                    }
-                    continue;
-                }
-
-                let status = route_data_packet(
-                    payload,
-                    meta.len as usize,
-                    meta.data_payload_offset.map(|off| off as usize),
-                    Arc::as_ref(&registry),
-                    Arc::as_ref(&metrics),
-                );
                 if matches!(status, RouteStatus::Dropped) {
                     if let Err(e) = pool.release(buffer_id) {
                         log::debug!(
@@ -1158,6 +1394,11 @@ fn router_loop_with_transport(
                     }
                 }
 
+                // A fragmented sample counts once, on its first fragment
+                if let Some(frag) = meta.frag_meta.filter(|frag| frag.frag_num == 1) {
+                    locator_stats.record(meta.sock, frag.writer_guid, frag.seq_num);
+                }
+
                 // Dedup: skip if same (writer_guid, seq) already seen via another path
                 if let (Some(guid), Some(seq)) =
                     (writer_guid, builder::extract_sequence_number(payload))
@@ -1181,16 +1422,41 @@ fn router_loop_with_transport(
                 }
 
                 // Route fragmented data with source address tracking for NACK_FRAG
-                let status = route_data_frag_packet_with_addr(
-                    payload,
-                    meta.len as usize,
-                    meta.data_payload_offset.map(|off| off as usize),
-                    meta.frag_meta.as_ref(),
-                    meta.sock, // Source socket address for NACK_FRAG responses
-                    &user_fragment_buffer,
-                    Arc::as_ref(&registry),
-                    Arc::as_ref(&metrics),
-                );
+                let status = match (rx_workers.as_deref(), meta.frag_meta.as_ref()) {
+                    (Some(workers), Some(frag_meta)) => match reassemble_data_frag(
+                        payload,
+                        meta.data_payload_offset.map(|off| off as usize),
+                        frag_meta,
+                        meta.sock,
+                        &user_fragment_buffer,
+                        &metrics,
+                    ) {
+                        Ok(Some((reassembled, trace_id))) => {
+                            workers.submit(
+                                writer_guid.as_ref(),
+                                RxJob::Reassembled {
+                                    writer_guid: frag_meta.writer_guid,
+                                    seq: frag_meta.seq_num,
+                                    payload: reassembled,
+                                    trace_id,
+                                },
+                            );
+                            RouteStatus::Delivered
+                        }
+                        Ok(None) => RouteStatus::Delivered,
+                        Err(status) => status,
+                    },
+                    _ => route_data_frag_packet_with_addr(
+                        payload,
+                        meta.len as usize,
+                        meta.data_payload_offset.map(|off| off as usize),
+                        meta.frag_meta.as_ref(),
+                        meta.sock, // Source socket address for NACK_FRAG responses
+                        &user_fragment_buffer,
+                        Arc::as_ref(&registry),
+                        Arc::as_ref(&metrics),
+                    ),
+                };
                 if matches!(status, RouteStatus::Dropped) {
                     if let Err(e) = pool.release(buffer_id) {
                         log::debug!(
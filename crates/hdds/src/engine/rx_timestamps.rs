@@ -0,0 +1,145 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Receive timestamps and writer of the packet being routed.
+//!
+//! Routing delivers a packet to its subscribers synchronously on the thread
+//! that routes it. Instead of adding timestamp arguments to every
+//! [`Subscriber`](super::Subscriber) callback, the router records the
+//! packet's timestamps (and, once the DATA is parsed, its writer and the
+//! sample's original writer) in a
+//! thread-local for the duration of the delivery and readers pick them up
+//! with [`current`] when they enqueue the sample.
+
+use crate::core::discovery::multicast::{RtpsContext, RxMeta};
+use crate::core::discovery::GUID;
+use crate::protocol::original_writer::OriginalWriterInfo;
+use std::cell::Cell;
+
+/// Timestamps of a received sample, in nanoseconds since the UNIX epoch.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RxTimestamps {
+    /// Writer-side timestamp from the INFO_TS submessage preceding the DATA,
+    /// or the write time of same-process writers.
+    pub source_ns: Option<u64>,
+    /// Time the kernel received the packet (`SO_TIMESTAMPNS`, Linux UDP).
+    pub kernel_rx_ns: Option<u64>,
+    /// GUID of the writer that sent the sample, if known.
+    pub writer_guid: Option<GUID>,
+    /// Writer and sequence number the sample was first published with:
+    /// `PID_ORIGINAL_WRITER_INFO` for samples relayed by a persistence
+    /// service, the sending writer and its sequence number otherwise.
+    pub original_writer: Option<OriginalWriterInfo>,
+}
+
+impl RxTimestamps {
+    /// No timestamp available.
+    pub const NONE: Self = Self {
+        source_ns: None,
+        kernel_rx_ns: None,
+        writer_guid: None,
+        original_writer: None,
+    };
+
+    /// Source timestamp of a sample handed over by a same-process writer.
+    pub const fn at_source(source_ns: u64) -> Self {
+        Self {
+            source_ns: Some(source_ns),
+            kernel_rx_ns: None,
+            writer_guid: None,
+            original_writer: None,
+        }
+    }
+
+    /// The same timestamps, for a sample sent by `writer_guid`.
+    pub const fn with_writer(mut self, writer_guid: Option<GUID>) -> Self {
+        self.writer_guid = writer_guid;
+        self
+    }
+
+    /// The same timestamps, for a sample first published as `original`.
+    pub const fn with_original_writer(mut self, original: Option<OriginalWriterInfo>) -> Self {
+        self.original_writer = original;
+        self
+    }
+
+    /// Timestamps carried by a packet's RTPS context.
+    pub fn from_context(context: &RtpsContext, kernel_rx_ns: Option<u64>) -> Self {
+        Self {
+            source_ns: context
+                .source_timestamp
+                .and_then(|(sec, frac)| rtps_time_to_ns(sec, frac)),
+            kernel_rx_ns,
+            writer_guid: None,
+            original_writer: None,
+        }
+    }
+
+    /// Timestamps recorded by the listener for a packet.
+    pub fn from_meta(meta: &RxMeta) -> Self {
+        Self::from_context(&meta.rtps_context, meta.kernel_rx_ns)
+    }
+}
+
+/// Convert an RTPS `Time_t` (seconds, 2^-32 s fraction) to nanoseconds.
+///
+/// Returns `None` for negative times (invalid or infinite per the spec).
+pub fn rtps_time_to_ns(sec: i32, frac: u32) -> Option<u64> {
+    let sec = u64::try_from(sec).ok()?;
+    Some(sec * 1_000_000_000 + ((u64::from(frac) * 1_000_000_000) >> 32))
+}
+
+thread_local! {
+    static CURRENT: Cell<RxTimestamps> = const { Cell::new(RxTimestamps::NONE) };
+}
+
+/// Restores the previous timestamps when dropped.
+pub(crate) struct RxTimestampsGuard {
+    previous: RxTimestamps,
+}
+
+impl Drop for RxTimestampsGuard {
+    fn drop(&mut self) {
+        CURRENT.with(|current| current.set(self.previous));
+    }
+}
+
+/// Make `timestamps` current on this thread until the guard is dropped.
+pub(crate) fn enter(timestamps: RxTimestamps) -> RxTimestampsGuard {
+    RxTimestampsGuard {
+        previous: CURRENT.with(|current| current.replace(timestamps)),
+    }
+}
+
+/// Timestamps of the packet being delivered on this thread.
+pub(crate) fn current() -> RxTimestamps {
+    CURRENT.with(Cell::get)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rtps_time_conversion() {
+        assert_eq!(rtps_time_to_ns(0, 0), Some(0));
+        assert_eq!(rtps_time_to_ns(1, 0x8000_0000), Some(1_500_000_000));
+        assert_eq!(rtps_time_to_ns(-1, 0), None);
+    }
+
+    #[test]
+    fn test_enter_restores_previous() {
+        let outer = RxTimestamps {
+            source_ns: Some(1),
+            kernel_rx_ns: Some(2),
+            writer_guid: Some(GUID::new([1; 12], [0, 0, 1, 2])),
+            original_writer: None,
+        };
+        let _outer = enter(outer);
+        {
+            let _inner = enter(RxTimestamps::NONE);
+            assert_eq!(current(), RxTimestamps::NONE);
+        }
+        assert_eq!(current(), outer);
+    }
+}
@@ -0,0 +1,398 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Receive worker pool for CPU-heavy DATA processing.
+//!
+//! The router thread also drives protocol timers (NACK_FRAG, dedup eviction)
+//! and HEARTBEAT/ACKNACK dispatch. Checksum verification and subscriber
+//! decode of large samples can take long enough that a burst of them delays
+//! that work. With a pool configured, the router hands large DATA packets and
+//! reassembled DATA_FRAG samples to dedicated worker threads and goes back
+//! to the ring immediately.
+//!
+//! Samples are sharded by writer GUID, so each writer's samples are still
+//! delivered in order by a single worker. When a worker queue is full the
+//! sample is dropped and counted; reliable writers repair it through the
+//! normal HEARTBEAT/ACKNACK exchange.
+
+use crate::core::discovery::GUID;
+use crate::engine::demux::TopicRegistry;
+use crate::engine::router::{route_data_packet, route_reassembled_data, RouterMetrics};
+use crate::engine::rx_timestamps::{self, RxTimestamps};
+use crossbeam::channel::{self, Sender, TrySendError};
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Instant;
+
+/// Receive worker pool configuration.
+#[derive(Clone, Debug)]
+pub struct RxWorkerConfig {
+    /// Number of worker threads (at least 1).
+    pub threads: usize,
+
+    /// Pending samples per worker before new ones are dropped.
+    pub queue_depth: usize,
+
+    /// DATA packets at least this large (bytes) are offloaded; smaller ones
+    /// are cheaper to route inline than to copy. Reassembled DATA_FRAG
+    /// samples are always offloaded.
+    pub offload_threshold: usize,
+}
+
+impl Default for RxWorkerConfig {
+    fn default() -> Self {
+        Self {
+            threads: 2,
+            queue_depth: 256,
+            offload_threshold: 4096,
+        }
+    }
+}
+
+impl RxWorkerConfig {
+    /// Pool with `threads` workers and default limits.
+    pub fn new(threads: usize) -> Self {
+        Self {
+            threads,
+            ..Self::default()
+        }
+    }
+
+    /// Set the per-worker queue depth.
+    pub fn with_queue_depth(mut self, depth: usize) -> Self {
+        self.queue_depth = depth;
+        self
+    }
+
+    /// Set the minimum DATA packet size routed through the pool.
+    pub fn with_offload_threshold(mut self, bytes: usize) -> Self {
+        self.offload_threshold = bytes;
+        self
+    }
+}
+
+/// Counters shared between the router, the workers and the admin API.
+#[derive(Debug)]
+pub struct RxWorkerStats {
+    threads: usize,
+    /// Samples handed to the pool.
+    pub submitted: AtomicU64,
+    /// Samples routed by a worker.
+    pub completed: AtomicU64,
+    /// Samples dropped because the worker queue was full.
+    pub dropped: AtomicU64,
+    /// Total time workers spent routing samples (ns).
+    pub busy_ns: AtomicU64,
+    /// Longest single sample routing time (ns).
+    pub max_job_ns: AtomicU64,
+}
+
+/// Point-in-time copy of [`RxWorkerStats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RxWorkerSnapshot {
+    pub threads: usize,
+    pub submitted: u64,
+    pub completed: u64,
+    pub dropped: u64,
+    /// Samples accepted but not yet routed.
+    pub queued: u64,
+    pub busy_ns: u64,
+    pub max_job_ns: u64,
+}
+
+impl RxWorkerStats {
+    fn new(threads: usize) -> Self {
+        Self {
+            threads,
+            submitted: AtomicU64::new(0),
+            completed: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+            busy_ns: AtomicU64::new(0),
+            max_job_ns: AtomicU64::new(0),
+        }
+    }
+
+    /// Return the current counters.
+    #[must_use]
+    pub fn snapshot(&self) -> RxWorkerSnapshot {
+        let submitted = self.submitted.load(Ordering::Relaxed);
+        let completed = self.completed.load(Ordering::Relaxed);
+        RxWorkerSnapshot {
+            threads: self.threads,
+            submitted,
+            completed,
+            dropped: self.dropped.load(Ordering::Relaxed),
+            queued: submitted.saturating_sub(completed),
+            busy_ns: self.busy_ns.load(Ordering::Relaxed),
+            max_job_ns: self.max_job_ns.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Work item owned by a worker.
+#[derive(Debug)]
+pub(crate) enum RxJob {
+    /// Complete RTPS DATA packet (copied out of the RxPool buffer).
+    Data {
+        packet: Vec<u8>,
+        payload_offset: Option<usize>,
+    },
+    /// Sample reassembled from DATA_FRAG.
+    Reassembled {
+        writer_guid: GUID,
+        seq: u64,
+        payload: Vec<u8>,
+        trace_id: Option<u64>,
+    },
+}
+
+/// Dedicated threads routing offloaded samples to the topic registry.
+pub struct RxWorkerPool {
+    senders: Vec<Sender<(RxJob, RxTimestamps)>>,
+    handles: Vec<JoinHandle<()>>,
+    stats: Arc<RxWorkerStats>,
+    offload_threshold: usize,
+}
+
+impl RxWorkerPool {
+    /// Spawn the worker threads.
+    pub(crate) fn start(
+        config: &RxWorkerConfig,
+        registry: Arc<TopicRegistry>,
+        metrics: Arc<RouterMetrics>,
+    ) -> io::Result<Self> {
+        let threads = config.threads.max(1);
+        let stats = Arc::new(RxWorkerStats::new(threads));
+        let mut senders = Vec::with_capacity(threads);
+        let mut handles = Vec::with_capacity(threads);
+
+        for index in 0..threads {
+            let (tx, rx) = channel::bounded::<(RxJob, RxTimestamps)>(config.queue_depth.max(1));
+            let registry = Arc::clone(&registry);
+            let metrics = Arc::clone(&metrics);
+            let stats = Arc::clone(&stats);
+            let handle = thread::Builder::new()
+                .name(format!("hdds-rx-worker-{}", index))
+                .spawn(move || {
+                    // Exits once the pool drops its sender.
+                    for (job, timestamps) in rx {
+                        let start = Instant::now();
+                        let _rx_timestamps = rx_timestamps::enter(timestamps);
+                        run_job(job, &registry, &metrics);
+                        let elapsed = start.elapsed().as_nanos() as u64;
+                        stats.busy_ns.fetch_add(elapsed, Ordering::Relaxed);
+                        stats.max_job_ns.fetch_max(elapsed, Ordering::Relaxed);
+                        stats.completed.fetch_add(1, Ordering::Relaxed);
+                    }
+                })?;
+            senders.push(tx);
+            handles.push(handle);
+        }
+
+        log::debug!(
+            "[RX-WORKERS] started threads={} queue_depth={} offload_threshold={}",
+            threads,
+            config.queue_depth,
+            config.offload_threshold
+        );
+
+        Ok(Self {
+            senders,
+            handles,
+            stats,
+            offload_threshold: config.offload_threshold,
+        })
+    }
+
+    /// Whether a DATA packet of `len` bytes should go through the pool.
+    #[inline]
+    pub(crate) fn should_offload(&self, len: usize) -> bool {
+        len >= self.offload_threshold
+    }
+
+    /// Queue a job on the worker owning `writer_guid`. Returns false if the
+    /// sample was dropped.
+    pub(crate) fn submit(&self, writer_guid: Option<&[u8; 16]>, job: RxJob) -> bool {
+        let index = writer_guid.map_or(0, |guid| {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            guid.hash(&mut hasher);
+            (hasher.finish() % self.senders.len() as u64) as usize
+        });
+
+        match self.senders[index].try_send((job, rx_timestamps::current())) {
+            Ok(()) => {
+                self.stats.submitted.fetch_add(1, Ordering::Relaxed);
+                true
+            }
+            Err(TrySendError::Full(_)) | Err(TrySendError::Disconnected(_)) => {
+                self.stats.dropped.fetch_add(1, Ordering::Relaxed);
+                log::debug!("[RX-WORKERS] worker {} queue full, sample dropped", index);
+                false
+            }
+        }
+    }
+
+    /// Shared counters (for the admin API).
+    #[must_use]
+    pub fn stats(&self) -> Arc<RxWorkerStats> {
+        Arc::clone(&self.stats)
+    }
+}
+
+impl Drop for RxWorkerPool {
+    fn drop(&mut self) {
+        self.senders.clear();
+        for handle in self.handles.drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn run_job(job: RxJob, registry: &TopicRegistry, metrics: &RouterMetrics) {
+    match job {
+        RxJob::Data {
+            packet,
+            payload_offset,
+        } => {
+            route_data_packet(&packet, packet.len(), payload_offset, registry, metrics);
+        }
+        RxJob::Reassembled {
+            writer_guid,
+            seq,
+            payload,
+            trace_id,
+        } => {
+            route_reassembled_data(&writer_guid, seq, &payload, trace_id, registry, metrics);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::subscriber::CallbackSubscriber;
+    use crate::protocol::builder::{build_data_packet_with_context, RtpsEndpointContext};
+    use std::sync::atomic::AtomicUsize;
+    use std::time::Duration;
+
+    fn wait_for(cond: impl Fn() -> bool) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(2);
+        while Instant::now() < deadline {
+            if cond() {
+                return true;
+            }
+            thread::sleep(Duration::from_millis(1));
+        }
+        false
+    }
+
+    #[test]
+    fn test_pool_routes_offloaded_data_in_writer_order() {
+        let registry = Arc::new(TopicRegistry::new());
+        registry
+            .register_topic("rx/large".to_string(), None)
+            .expect("register topic");
+        let received = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let sink = Arc::clone(&received);
+        registry
+            .register_subscriber(Arc::new(CallbackSubscriber::new(
+                "rx/large".to_string(),
+                move |_, seq, _| sink.lock().push(seq),
+            )))
+            .expect("register");
+
+        let pool = RxWorkerPool::start(
+            &RxWorkerConfig::new(3).with_offload_threshold(64),
+            Arc::clone(&registry),
+            Arc::new(RouterMetrics::new()),
+        )
+        .expect("start pool");
+        assert!(!pool.should_offload(63));
+        assert!(pool.should_offload(64));
+
+        let ctx = RtpsEndpointContext {
+            guid_prefix: [0x22; 12],
+            reader_entity_id: [0, 0, 0, 0x04],
+            writer_entity_id: [0, 0, 0x01, 0x03],
+        };
+        let guid = [0x22; 16];
+        for seq in 1..=20 {
+            let packet = build_data_packet_with_context(&ctx, "rx/large", seq, &[7u8; 128]);
+            // Serialized payload = 4-byte encapsulation header + 128 data bytes.
+            let payload_offset = Some(packet.len() - 132);
+            assert!(pool.submit(
+                Some(&guid),
+                RxJob::Data {
+                    packet,
+                    payload_offset,
+                }
+            ));
+        }
+
+        let stats = pool.stats();
+        assert!(wait_for(|| stats.snapshot().completed == 20));
+        assert_eq!(*received.lock(), (1..=20).collect::<Vec<u64>>());
+
+        let snapshot = stats.snapshot();
+        assert_eq!(snapshot.threads, 3);
+        assert_eq!(snapshot.submitted, 20);
+        assert_eq!(snapshot.queued, 0);
+        assert_eq!(snapshot.dropped, 0);
+    }
+
+    #[test]
+    fn test_pool_drops_when_queue_full() {
+        let registry = Arc::new(TopicRegistry::new());
+        registry
+            .register_topic("rx/slow".to_string(), None)
+            .expect("register topic");
+        let gate = Arc::new(AtomicUsize::new(0));
+        let gate_sub = Arc::clone(&gate);
+        registry
+            .register_subscriber(Arc::new(CallbackSubscriber::new(
+                "rx/slow".to_string(),
+                move |_, _, _| {
+                    while gate_sub.load(Ordering::Acquire) == 0 {
+                        thread::sleep(Duration::from_millis(1));
+                    }
+                },
+            )))
+            .expect("register");
+
+        let pool = RxWorkerPool::start(
+            &RxWorkerConfig::new(1).with_queue_depth(1),
+            Arc::clone(&registry),
+            Arc::new(RouterMetrics::new()),
+        )
+        .expect("start pool");
+
+        let ctx = RtpsEndpointContext {
+            guid_prefix: [0x33; 12],
+            reader_entity_id: [0, 0, 0, 0x04],
+            writer_entity_id: [0, 0, 0x01, 0x03],
+        };
+        let job = |seq| {
+            let packet = build_data_packet_with_context(&ctx, "rx/slow", seq, &[1, 2, 3, 4]);
+            RxJob::Data {
+                payload_offset: Some(packet.len() - 8),
+                packet,
+            }
+        };
+
+        // First job blocks the worker, second fills the queue, third is dropped.
+        assert!(pool.submit(None, job(1)));
+        let stats = pool.stats();
+        assert!(wait_for(
+            || stats.snapshot().queued == 1 && pool.senders[0].is_empty()
+        ));
+        assert!(pool.submit(None, job(2)));
+        assert!(!pool.submit(None, job(3)));
+        assert_eq!(stats.snapshot().dropped, 1);
+
+        gate.store(1, Ordering::Release);
+        assert!(wait_for(|| stats.snapshot().completed == 2));
+    }
+}
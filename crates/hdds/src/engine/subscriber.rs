@@ -3,6 +3,10 @@
 
 //! Subscriber trait and implementations for receiving topic data
 
+use crate::dds::qos::Partition;
+use crate::protocol::coherent::CoherentSetTag;
+use crate::protocol::type_version::TypeVersionTag;
+
 /// Subscriber trait for receiving topic data
 ///
 /// # Thread Safety
@@ -40,6 +44,84 @@ pub trait Subscriber: Send + Sync {
     /// delivery to other subscribers (logged as delivery_error metric).
     fn on_data(&self, topic: &str, seq: u64, data: &[u8]);
 
+    /// Called instead of [`on_data`](Self::on_data) when the sender may have
+    /// attached a per-sample trace id (`PID_HDDS_TRACE_ID` inline QoS).
+    ///
+    /// The default implementation drops the trace id and forwards to `on_data`.
+    fn on_data_traced(&self, topic: &str, seq: u64, data: &[u8], trace_id: Option<u64>) {
+        let _ = trace_id;
+        self.on_data(topic, seq, data);
+    }
+
+    /// Called instead of [`on_data_traced`](Self::on_data_traced) when the
+    /// sender may have tagged the sample with a type version
+    /// (`PID_HDDS_TYPE_VERSION` inline QoS).
+    ///
+    /// A versioned writer sends one copy per offered version, all with the
+    /// same `seq`. The default implementation behaves like a reader without
+    /// a declared version: it keeps untagged samples and the writer's native
+    /// copy, and ignores the others.
+    fn on_data_versioned(
+        &self,
+        topic: &str,
+        seq: u64,
+        data: &[u8],
+        trace_id: Option<u64>,
+        type_version: Option<&TypeVersionTag>,
+    ) {
+        if type_version.is_none_or(|tag| tag.accepted_by(None)) {
+            self.on_data_traced(topic, seq, data, trace_id);
+        }
+    }
+
+    /// Called instead of [`on_data_versioned`](Self::on_data_versioned) when
+    /// the sample belongs to a group coherent set (`PID_HDDS_COHERENT_SET`
+    /// inline QoS).
+    ///
+    /// The default implementation ignores the set and delivers the sample
+    /// immediately, like a subscriber without PRESENTATION `coherent_access`.
+    fn on_data_coherent(
+        &self,
+        topic: &str,
+        seq: u64,
+        data: &[u8],
+        trace_id: Option<u64>,
+        type_version: Option<&TypeVersionTag>,
+        coherent_set: CoherentSetTag,
+    ) {
+        let _ = coherent_set;
+        self.on_data_versioned(topic, seq, data, trace_id, type_version);
+    }
+
+    /// Called when the sender disposed or unregistered an instance
+    /// (`PID_STATUS_INFO` inline QoS) instead of writing a sample.
+    ///
+    /// `status_info` holds the `STATUS_INFO_*` flags and `data` the sample
+    /// identifying the instance, or its serialized key. `key_hash` is the
+    /// instance's `PID_KEY_HASH`, when the sender included it. The default
+    /// implementation ignores lifecycle changes, so keyless subscribers
+    /// never see them as data.
+    fn on_instance_state(
+        &self,
+        topic: &str,
+        seq: u64,
+        data: &[u8],
+        status_info: u8,
+        key_hash: Option<[u8; 16]>,
+    ) {
+        let _ = (topic, seq, data, status_info, key_hash);
+    }
+
+    /// PARTITION QoS of this subscriber.
+    ///
+    /// When `Some`, the router only delivers samples of writers whose
+    /// partitions (announced via SEDP) match. Writers not announced yet count
+    /// as the default partition. The default implementation returns `None`
+    /// and receives from every writer.
+    fn partition(&self) -> Option<&Partition> {
+        None
+    }
+
     /// Returns the topic name this subscriber is registered for
     fn topic_name(&self) -> &str;
 }
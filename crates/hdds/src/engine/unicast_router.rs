@@ -17,6 +17,7 @@
 //! QUIC event  ──→ MessageReceived { payload } ──→ route_raw_rtps_message()
 //!                                                   ├── classify_rtps(payload)
 //!                                                   ├── Data      → route_data_packet()
+//!                                                   │               (per DATA of a batch)
 //!                                                   ├── DataFrag  → route_data_frag_packet()
 //!                                                   ├── Heartbeat → registry.deliver_heartbeat()
 //!                                                   ├── AckNack   → registry.deliver_nack()
@@ -32,6 +33,8 @@ use crate::engine::demux::TopicRegistry;
 use crate::engine::router::{
     route_data_frag_packet, route_data_packet, RouteStatus, RouterMetrics,
 };
+use crate::engine::rx_timestamps::{self, RxTimestamps};
+use crate::protocol::builder;
 
 // ============================================================================
 // Result type
@@ -78,12 +81,36 @@ pub fn route_raw_rtps_message(
     fragment_buffer: Option<&Mutex<FragmentBuffer>>,
 ) -> UnicastRouteOutcome {
     // Step 1: Classify the RTPS message
-    let (kind, data_offset, frag_meta, _rtps_ctx) = classify_rtps(payload);
+    let (kind, data_offset, frag_meta, rtps_ctx) = classify_rtps(payload);
+    let _rx_timestamps = rx_timestamps::enter(RxTimestamps::from_context(&rtps_ctx, None));
 
     let len = payload.len();
 
     // Step 2: Dispatch based on submessage type
     match kind {
+        PacketKind::Data if builder::is_batched_data(payload) => {
+            // One DATA at a time, each with its own INFO_TS; the batch counts
+            // as delivered if any of its samples was
+            let mut best = RouteStatus::Dropped;
+            builder::for_each_batched_data(payload, &mut Vec::new(), |sample| {
+                let (_, offset, _, context) = classify_rtps(sample);
+                let _rx_timestamps =
+                    rx_timestamps::enter(RxTimestamps::from_context(&context, None));
+                match route_data_packet(sample, sample.len(), offset, registry, metrics) {
+                    RouteStatus::Delivered => best = RouteStatus::Delivered,
+                    RouteStatus::Orphaned if best == RouteStatus::Dropped => {
+                        best = RouteStatus::Orphaned
+                    }
+                    _ => {}
+                }
+            });
+            match best {
+                RouteStatus::Delivered => UnicastRouteOutcome::Delivered,
+                RouteStatus::Orphaned => UnicastRouteOutcome::Orphaned,
+                RouteStatus::Dropped => UnicastRouteOutcome::Dropped,
+            }
+        }
+
         PacketKind::Data => {
             let status = route_data_packet(payload, len, data_offset, registry, metrics);
             match status {
@@ -65,8 +65,14 @@
 //! - **Discovery Server** mode for non-multicast networks
 //! - **ROS 2 compatible** via `rmw_hdds` middleware layer
 //!
+//! The Discovery Server, `XTypes` `TypeObject` support, dynamic types and the
+//! telemetry exporter are default Cargo features (`discovery-server`,
+//! `xtypes`, `dynamic`, `telemetry`) and can be compiled out with
+//! `default-features = false` for size-constrained targets.
+//!
 //! ## Modules Overview
 //!
+//! - [`prelude`] - Versioned stable API surface (`use hdds::prelude::v1::*`)
 //! - [`dds`] - Core DDS API (start here)
 //! - [`qos`] - Quality of Service policies
 //! - [`transport`] - Network transport implementations
@@ -98,14 +104,20 @@ pub mod congestion;
 pub mod core;
 /// Core DDS API (Participant, DataReader, DataWriter, Publisher, Subscriber).
 pub mod dds;
+/// Declarative deployments: participants, topics and endpoints from a YAML manifest.
+#[cfg(feature = "qos-loaders")]
+pub mod deploy;
 /// Discovery mechanisms (multicast SPDP/SEDP, Discovery Server, Cloud Discovery).
 pub mod discovery;
 /// Dynamic Types for runtime type manipulation without compile-time type knowledge.
+#[cfg(feature = "dynamic")]
 pub mod dynamic;
 /// Consolidated data routing and event distribution engine.
 pub mod engine;
 /// Compile-time configurable logging system (zero-cost when disabled).
 pub mod logging;
+/// Versioned preludes: the stable public API surface (`hdds::prelude::v1`).
+pub mod prelude;
 /// RTPS protocol implementation (constants, builders, discovery parsers).
 pub mod protocol;
 /// Reliability QoS implementation (Reliable protocol, RTPS messages, history cache).
@@ -115,6 +127,7 @@ pub mod demux {
     pub use crate::engine::*;
 }
 /// Discovery Server client support (for non-multicast environments).
+#[cfg(feature = "discovery-server")]
 pub mod discovery_server;
 /// Interop V2 (wire profiles, matching diagnostics).
 mod interop;
@@ -144,19 +157,47 @@ pub mod generated {
 
 pub use admin::{AdminApi, EndpointsSnapshot, MeshSnapshot, MetricsSnapshot, TopicsSnapshot};
 pub use dds::{
-    ContentFilteredTopic, DataReader, DataWriter, DiscoveredTopicInfo, Error, FieldValue,
-    FilterError, GuardCondition, HasStatusCondition, Participant, QoS, RawDataReader,
-    RawDataWriter, RawSample, Result, Topic, TransportMode, WaitSet,
+    global_participant, global_participant_with, ContentFilteredTopic, DataReader, DataWriter,
+    DiscoveredTopicInfo, DiscoveryEvent, DiscoveryEvents, Error, ErrorContext, FieldValue,
+    FilterError, GuardCondition, HasStatusCondition, InstanceHandle, InstanceState, Participant,
+    QoS, RawDataReader, RawDataWriter, RawSample, Result, SampleInfo, Topic, TransportMode,
+    WaitSet,
 };
 
+// Re-export serde sample wrapper when feature is enabled
+#[cfg(feature = "serde")]
+pub use dds::Serde;
+
+// Re-export per-sample checksum kind for WriterBuilder::sample_checksum()
+pub use protocol::checksum::ChecksumKind;
+pub use protocol::original_writer::OriginalWriterInfo;
+
 // Re-export transport configs for ParticipantBuilder
 pub use transport::lowbw::LowBwConfig;
 pub use transport::shm::ShmPolicy;
-pub use transport::tcp::{TcpConfig, TcpRole, TransportPreference};
+pub use transport::tcp::{TcpConfig, TcpFraming, TcpRole, TransportPreference};
+pub use transport::uds::UdsConfig;
+pub use transport::IpMode;
 
 // Re-export Discovery Server config
+#[cfg(feature = "discovery-server")]
 pub use discovery_server::DiscoveryServerConfig;
 
+// Re-export time sources for ParticipantBuilder::clock()
+pub use core::rt::clock::{Clock, SystemClock, VirtualClock};
+
+// Re-export static deployment manifest for ParticipantBuilder::with_static_deployment()
+pub use dds::{StaticDeployment, StaticTopic};
+
+// Re-export endpoint creation hooks for ParticipantBuilder::with_endpoint_hook()
+pub use dds::{register_endpoint_hook, EndpointCreation, EndpointHook};
+
+// Re-export idle-endpoint hibernation for ParticipantBuilder::with_endpoint_hibernation()
+pub use dds::{HibernationConfig, HibernationStats};
+
+// Re-export packet capture for ParticipantBuilder::with_packet_tap()
+pub use transport::{CapturedPacket, PacketDirection};
+
 // Re-export QUIC config when feature is enabled
 #[cfg(feature = "quic")]
 pub use transport::quic::QuicConfig;
@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Versioned preludes: the stable public API surface.
+//!
+//! Import a versioned prelude to pin the names your code depends on:
+//!
+//! ```rust,no_run
+//! use hdds::prelude::v1::*;
+//!
+//! #[derive(Debug, Clone, DDS)]
+//! struct Temperature {
+//!     sensor_id: u32,
+//!     celsius: f32,
+//! }
+//!
+//! let participant = Participant::builder("sensor").build()?;
+//! let writer = participant
+//!     .topic::<Temperature>("Temperature")?
+//!     .writer()
+//!     .qos(QoS::reliable())
+//!     .build()?;
+//! writer.write(&Temperature { sensor_id: 1, celsius: 21.5 })?;
+//! # Ok::<(), hdds::Error>(())
+//! ```
+//!
+//! `hdds::prelude::*` re-exports the latest version.
+//!
+//! # Stability Policy
+//!
+//! - A name exported by `prelude::vN` keeps its meaning for the whole major
+//!   release. It is never removed or renamed in a minor or patch release.
+//! - A breaking change to the prelude surface (removal, rename, changed
+//!   semantics) ships as a new `prelude::vN+1`; `vN` keeps compiling.
+//! - A renamed item stays available under its old name as a deprecated
+//!   alias (`#[deprecated] pub type Old = New;`), so callers get a
+//!   compile-time warning naming the replacement instead of an error.
+//! - Every `#[deprecated]` attribute carries `since` (the release that
+//!   deprecated it) and a `note` pointing at the replacement. Deprecated
+//!   items are removed in a major release at the earliest.
+//!
+//! `tests/api_stability.rs` enforces the policy: it names every `v1` item
+//! and rejects `#[deprecated]` attributes without `since` and `note`.
+//!
+//! Items outside the preludes (transport internals, `core`, `protocol`,
+//! `engine`, ...) are public for tooling but carry no such guarantee.
+
+/// Stable API surface of HDDS 1.x.
+pub mod v1;
+
+pub use v1::*;
@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! HDDS 1.x prelude.
+//!
+//! Frozen for the 1.x releases: items are only ever added, never removed or
+//! renamed (see the [stability policy](super#stability-policy)).
+
+// Entities
+pub use crate::dds::{
+    ContentFilteredTopic, DataReader, DataWriter, Participant, ParticipantBuilder, Publisher,
+    Subscriber, Topic, TransportMode,
+};
+
+// Data types: the DDS trait and its derive macro share the name
+pub use crate::dds::{InstanceHandle, InstanceState, SampleInfo, DDS};
+pub use hdds_codegen::DDS;
+
+// QoS
+pub use crate::dds::{
+    Deadline, Durability, History, Liveliness, LivelinessKind, Ownership, OwnershipKind, Partition,
+    QoS, Reliability,
+};
+
+// Conditions and WaitSet
+pub use crate::dds::{
+    Condition, GuardCondition, HasStatusCondition, ReadCondition, StatusCondition, StatusMask,
+    WaitSet,
+};
+
+// Listeners
+pub use crate::dds::{
+    DataReaderListener, DataWriterListener, ParticipantListener, PublicationMatchedStatus,
+    SubscriptionMatchedStatus,
+};
+
+// Errors
+pub use crate::dds::{Error, Result};
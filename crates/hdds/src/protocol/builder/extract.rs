@@ -1,40 +1,46 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 // Copyright (c) 2025-2026 naskel.com
 
-use super::helpers::validate_rtps_data_packet;
-use crate::protocol::constants::{RTPS_MAGIC, RTPS_SUBMSG_DATA};
+use super::helpers::{data_frag_submessage_offset, data_submessage_offset};
+use crate::protocol::checksum::SampleChecksum;
+use crate::protocol::coherent::CoherentSetTag;
+use crate::protocol::constants::{
+    CDR_LE, HDDS_VENDOR_ID, PID_HDDS_COHERENT_SET, PID_HDDS_SAMPLE_CHECKSUM, PID_HDDS_TRACE_ID,
+    PID_HDDS_TYPE_VERSION, PID_KEY_HASH, PID_ORIGINAL_WRITER_INFO, PID_STATUS_INFO, RTPS_MAGIC,
+    RTPS_SUBMSG_DATA, RTPS_SUBMSG_INFO_TS,
+};
+use crate::protocol::original_writer::OriginalWriterInfo;
+use crate::protocol::type_version::TypeVersionTag;
 
 /// Extract CDR2 payload from RTPS DATA packet.
+///
+/// The payload starts `octetsToInlineQos` bytes after that field, past the
+/// inline QoS parameter list when the InlineQos flag is set.
 pub fn extract_data_payload(rtps_packet: &[u8]) -> Option<&[u8]> {
-    if !validate_rtps_data_packet(rtps_packet, 40) {
-        return None;
-    }
+    let data = data_submessage_offset(rtps_packet, 40)?;
 
-    let _submessage_len = u16::from_le_bytes([rtps_packet[18], rtps_packet[19]]) as usize;
-    let octets_to_qos = u16::from_le_bytes([rtps_packet[22], rtps_packet[23]]) as usize;
-    let qos_offset = 24 + octets_to_qos;
+    let octets_to_inline_qos =
+        u16::from_le_bytes([rtps_packet[data + 6], rtps_packet[data + 7]]) as usize;
+    let mut offset = data + 8 + octets_to_inline_qos;
 
-    if rtps_packet.len() < qos_offset + 4 {
-        return None;
-    }
+    if rtps_packet[data + 1] & 0x02 != 0 {
+        loop {
+            if offset + 4 > rtps_packet.len() {
+                return None;
+            }
 
-    let mut offset = qos_offset + 4;
+            let pid = u16::from_le_bytes([rtps_packet[offset], rtps_packet[offset + 1]]);
+            let len =
+                u16::from_le_bytes([rtps_packet[offset + 2], rtps_packet[offset + 3]]) as usize;
 
-    loop {
-        if offset + 4 > rtps_packet.len() {
-            return None;
-        }
-
-        let pid = u16::from_le_bytes([rtps_packet[offset], rtps_packet[offset + 1]]);
-        let len = u16::from_le_bytes([rtps_packet[offset + 2], rtps_packet[offset + 3]]) as usize;
+            if pid == 0x0001 {
+                offset += 4;
+                break;
+            }
 
-        if pid == 0x0001 {
-            offset += 4;
-            break;
+            offset += 4 + len;
+            offset = (offset + 3) & !3;
         }
-
-        offset += 4 + len;
-        offset = (offset + 3) & !3;
     }
 
     if offset >= rtps_packet.len() {
@@ -46,7 +52,8 @@ pub fn extract_data_payload(rtps_packet: &[u8]) -> Option<&[u8]> {
 
 /// Extract inline QoS from RTPS DATA packet for topic name parsing.
 ///
-/// RTPS DATA layout (offsets in full RTPS packet):
+/// RTPS DATA layout (offsets in full RTPS packet, shifted by 12 when an
+/// INFO_TS precedes the DATA):
 /// - \[20\]: submessageId (0x15)
 /// - \[21\]: flags (bit 1 = InlineQos present)
 /// - \[22-23\]: submessageLength
@@ -57,19 +64,33 @@ pub fn extract_data_payload(rtps_packet: &[u8]) -> Option<&[u8]> {
 /// - \[36-43\]: sequenceNumber
 /// - \[44+\]: inline QoS (if flag set), then payload
 pub fn extract_inline_qos(rtps_packet: &[u8]) -> Option<&[u8]> {
-    if !validate_rtps_data_packet(rtps_packet, 44) {
-        return None;
-    }
+    let data = data_submessage_offset(rtps_packet, 44)?;
+    inline_qos_at(rtps_packet, data)
+}
 
+/// Extract inline QoS from an RTPS DATA_FRAG packet.
+///
+/// Only the first fragment of an HDDS sample carries one (its
+/// `PID_HDDS_TRACE_ID`); the other fragments return `None`. Same layout as
+/// for [`extract_inline_qos`], with `octetsToInlineQos` skipping the 12
+/// extra bytes of fragment information.
+pub fn extract_data_frag_inline_qos(rtps_packet: &[u8]) -> Option<&[u8]> {
+    let data_frag = data_frag_submessage_offset(rtps_packet, 56)?;
+    inline_qos_at(rtps_packet, data_frag)
+}
+
+/// Inline QoS of the DATA or DATA_FRAG submessage starting at `data`.
+fn inline_qos_at(rtps_packet: &[u8], data: usize) -> Option<&[u8]> {
     // Check InlineQos flag (bit 1 of submessage flags)
-    let flags = rtps_packet[21];
+    let flags = rtps_packet[data + 1];
     if flags & 0x02 == 0 {
         return None; // No inline QoS present
     }
 
     // octetsToInlineQos at offset 26-27 (after RTPS header + submsg header + extraFlags)
-    let octets_to_inline_qos = u16::from_le_bytes([rtps_packet[26], rtps_packet[27]]) as usize;
-    let qos_offset = 28 + octets_to_inline_qos;
+    let octets_to_inline_qos =
+        u16::from_le_bytes([rtps_packet[data + 6], rtps_packet[data + 7]]) as usize;
+    let qos_offset = data + 8 + octets_to_inline_qos;
 
     if qos_offset >= rtps_packet.len() {
         return None;
@@ -94,6 +115,100 @@ pub fn extract_inline_qos(rtps_packet: &[u8]) -> Option<&[u8]> {
     }
 }
 
+/// Find the value of parameter `wanted` in an inline QoS parameter list.
+///
+/// `inline_qos` is the slice returned by [`extract_inline_qos`] (CDR
+/// encapsulation header followed by parameters).
+fn find_inline_param(inline_qos: &[u8], wanted: u16) -> Option<&[u8]> {
+    if inline_qos.len() < 8 || u16::from_be_bytes([inline_qos[0], inline_qos[1]]) != CDR_LE {
+        return None;
+    }
+
+    let mut offset = 4;
+    while offset + 4 <= inline_qos.len() {
+        let pid = u16::from_le_bytes([inline_qos[offset], inline_qos[offset + 1]]);
+        let len = u16::from_le_bytes([inline_qos[offset + 2], inline_qos[offset + 3]]) as usize;
+        offset += 4;
+
+        if pid == 0x0001 || offset + len > inline_qos.len() {
+            return None;
+        }
+        if pid == wanted {
+            return Some(&inline_qos[offset..offset + len]);
+        }
+
+        offset += (len + 3) & !3;
+    }
+
+    None
+}
+
+/// Whether the RTPS header of `rtps_packet` carries the HDDS vendor id.
+///
+/// `PID_HDDS_*` parameters live in the vendor-specific PID range, so other
+/// stacks may reuse the same ids; only parse them from HDDS messages.
+pub fn is_hdds_vendor(rtps_packet: &[u8]) -> bool {
+    rtps_packet.get(6..8) == Some(&HDDS_VENDOR_ID[..])
+}
+
+/// Parse the per-sample trace id from an inline QoS parameter list.
+///
+/// Returns `None` when no `PID_HDDS_TRACE_ID` is present or the list is
+/// truncated.
+pub fn parse_trace_id(inline_qos: &[u8]) -> Option<u64> {
+    let value = find_inline_param(inline_qos, PID_HDDS_TRACE_ID)?;
+    let bytes: [u8; 8] = value.get(..8)?.try_into().ok()?;
+    Some(u64::from_le_bytes(bytes))
+}
+
+/// Parse the per-sample checksum from an inline QoS parameter list.
+///
+/// Returns `None` when no `PID_HDDS_SAMPLE_CHECKSUM` is present, the list is
+/// truncated, or the algorithm is unknown.
+pub fn parse_sample_checksum(inline_qos: &[u8]) -> Option<SampleChecksum> {
+    SampleChecksum::from_bytes(find_inline_param(inline_qos, PID_HDDS_SAMPLE_CHECKSUM)?)
+}
+
+/// Parse the per-sample type version tag from an inline QoS parameter list.
+///
+/// Returns `None` when no valid `PID_HDDS_TYPE_VERSION` is present.
+pub fn parse_type_version(inline_qos: &[u8]) -> Option<TypeVersionTag> {
+    TypeVersionTag::from_bytes(find_inline_param(inline_qos, PID_HDDS_TYPE_VERSION)?)
+}
+
+/// Parse the group coherent set tag from an inline QoS parameter list.
+///
+/// Returns `None` when no valid `PID_HDDS_COHERENT_SET` is present.
+pub fn parse_coherent_set(inline_qos: &[u8]) -> Option<CoherentSetTag> {
+    CoherentSetTag::from_bytes(find_inline_param(inline_qos, PID_HDDS_COHERENT_SET)?)
+}
+
+/// Parse the original writer of a relayed sample from an inline QoS
+/// parameter list.
+///
+/// Returns `None` when no valid `PID_ORIGINAL_WRITER_INFO` is present.
+pub fn parse_original_writer(inline_qos: &[u8]) -> Option<OriginalWriterInfo> {
+    OriginalWriterInfo::from_bytes(find_inline_param(inline_qos, PID_ORIGINAL_WRITER_INFO)?)
+}
+
+/// Parse the instance key hash from an inline QoS parameter list.
+pub fn parse_key_hash(inline_qos: &[u8]) -> Option<[u8; 16]> {
+    find_inline_param(inline_qos, PID_KEY_HASH)?
+        .get(..16)?
+        .try_into()
+        .ok()
+}
+
+/// Parse the `PID_STATUS_INFO` flags (`STATUS_INFO_*`) from an inline QoS
+/// parameter list.
+///
+/// Returns `None` when the parameter is absent or truncated.
+pub fn parse_status_info(inline_qos: &[u8]) -> Option<u8> {
+    find_inline_param(inline_qos, PID_STATUS_INFO)?
+        .get(3)
+        .copied()
+}
+
 /// Extract sequence number from RTPS DATA packet.
 ///
 /// RTPS DATA submessage layout (per RTPS v2.3 Sec.8.3.7.2):
@@ -105,23 +220,23 @@ pub fn extract_inline_qos(rtps_packet: &[u8]) -> Option<&[u8]> {
 /// - Offset 36: writerSN (8 bytes) <- SequenceNumber_t
 ///
 /// SequenceNumber_t is encoded as two 32-bit values (high, low) in little-endian.
+/// Offsets shift by 12 when an INFO_TS precedes the DATA.
 pub fn extract_sequence_number(rtps_packet: &[u8]) -> Option<u64> {
-    if !validate_rtps_data_packet(rtps_packet, 44) {
-        return None;
-    }
+    let data = data_submessage_offset(rtps_packet, 44)?;
 
     // writerSN starts at offset 36 (after writerEntityId)
+    let sn = data + 16;
     let seq_high = u32::from_le_bytes([
-        rtps_packet[36],
-        rtps_packet[37],
-        rtps_packet[38],
-        rtps_packet[39],
+        rtps_packet[sn],
+        rtps_packet[sn + 1],
+        rtps_packet[sn + 2],
+        rtps_packet[sn + 3],
     ]);
     let seq_low = u32::from_le_bytes([
-        rtps_packet[40],
-        rtps_packet[41],
-        rtps_packet[42],
-        rtps_packet[43],
+        rtps_packet[sn + 4],
+        rtps_packet[sn + 5],
+        rtps_packet[sn + 6],
+        rtps_packet[sn + 7],
     ]);
 
     // RTPS SequenceNumber_t: value = high * 2^32 + low
@@ -194,6 +309,84 @@ pub fn extract_writer_guid(rtps_packet: &[u8]) -> Option<[u8; 16]> {
     None // DATA submessage not found
 }
 
+/// Bounds `(start, end)` of the submessages of an RTPS message.
+///
+/// A submessage whose octetsToNextHeader is 0 extends to the end of the
+/// message (RTPS v2.5 Sec.9.4.5.1.3); a truncated one ends the walk.
+fn submessages(message: &[u8]) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let mut offset = 20;
+    std::iter::from_fn(move || {
+        if offset + 4 > message.len() {
+            return None;
+        }
+        let octets = if message[offset + 1] & 0x01 != 0 {
+            u16::from_le_bytes([message[offset + 2], message[offset + 3]])
+        } else {
+            u16::from_be_bytes([message[offset + 2], message[offset + 3]])
+        } as usize;
+        let start = offset;
+        let end = if octets == 0 {
+            message.len()
+        } else {
+            start + 4 + octets
+        };
+        if end > message.len() {
+            return None;
+        }
+        offset = end;
+        Some((start, end))
+    })
+}
+
+/// Whether an RTPS message batches several DATA submessages.
+///
+/// Extractors and the classifier only see the first DATA of a message, so
+/// batches go through [`for_each_batched_data`] first.
+pub fn is_batched_data(message: &[u8]) -> bool {
+    message.len() >= 20
+        && submessages(message)
+            .filter(|&(start, _)| message[start] == RTPS_SUBMSG_DATA)
+            .nth(1)
+            .is_some()
+}
+
+/// Split an RTPS message batching DATA submessages into one message per
+/// DATA, in order.
+///
+/// Each message is rebuilt in `scratch` from the batch's header, the INFO_TS
+/// in effect for the DATA (so that it keeps its source timestamp) and the
+/// DATA itself, then handed to `deliver`. Other submessages are skipped.
+/// Returns the number of DATA submessages delivered.
+pub fn for_each_batched_data(
+    message: &[u8],
+    scratch: &mut Vec<u8>,
+    mut deliver: impl FnMut(&[u8]),
+) -> usize {
+    if message.len() < 20 {
+        return 0;
+    }
+
+    let mut info_ts: Option<&[u8]> = None;
+    let mut delivered = 0;
+    for (start, end) in submessages(message) {
+        match message[start] {
+            RTPS_SUBMSG_INFO_TS => info_ts = Some(&message[start..end]),
+            RTPS_SUBMSG_DATA => {
+                scratch.clear();
+                scratch.extend_from_slice(&message[..20]);
+                if let Some(info_ts) = info_ts {
+                    scratch.extend_from_slice(info_ts);
+                }
+                scratch.extend_from_slice(&message[start..end]);
+                deliver(scratch);
+                delivered += 1;
+            }
+            _ => {}
+        }
+    }
+    delivered
+}
+
 #[cfg(test)]
 mod tests {
     use super::extract_writer_guid;
@@ -1,10 +1,15 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 // Copyright (c) 2025-2026 naskel.com
 
+use super::packet::DataPacketOptions;
+use crate::protocol::checksum::{SampleChecksum, SAMPLE_CHECKSUM_LEN};
+use crate::protocol::coherent::COHERENT_SET_TAG_LEN;
 use crate::protocol::constants::*;
+use crate::protocol::original_writer::ORIGINAL_WRITER_INFO_LEN;
 use std::convert::TryFrom;
 
-/// Validate RTPS DATA packet header (eliminates duplication across helpers).
+/// Locate the DATA submessage of an RTPS DATA packet (eliminates duplication
+/// across helpers).
 ///
 /// Accepts both RTPS (0x52545053) and RTPX (0x52545058) magic for RTI interop.
 ///
@@ -14,16 +19,38 @@ use std::convert::TryFrom;
 /// - Vendor ID: 2 bytes (offset 6-7)
 /// - GUID Prefix: 12 bytes (offset 8-19)
 /// - First submessage starts at offset 20
-pub(super) fn validate_rtps_data_packet(rtps_packet: &[u8], min_len: usize) -> bool {
-    if rtps_packet.len() < min_len {
-        return false;
+///
+/// The DATA submessage is the first one, or follows the INFO_TS carrying the
+/// sample's source timestamp. Returns its offset when the packet holds at
+/// least `min_len` bytes, not counting that INFO_TS.
+pub(super) fn data_submessage_offset(rtps_packet: &[u8], min_len: usize) -> Option<usize> {
+    submessage_offset(rtps_packet, min_len, RTPS_SUBMSG_DATA)
+}
+
+/// Locate the DATA_FRAG submessage of an RTPS DATA_FRAG packet, with the
+/// same layout rules as [`data_submessage_offset`].
+pub(super) fn data_frag_submessage_offset(rtps_packet: &[u8], min_len: usize) -> Option<usize> {
+    submessage_offset(rtps_packet, min_len, RTPS_SUBMSG_DATA_FRAG)
+}
+
+fn submessage_offset(rtps_packet: &[u8], min_len: usize, submessage_id: u8) -> Option<usize> {
+    if rtps_packet.len() < 20 {
+        return None;
     }
 
     // Accept both RTPS and RTPX magic (RTI vendor extension)
     let magic_valid = &rtps_packet[0..4] == RTPS_MAGIC || &rtps_packet[0..4] == b"RTPX";
+    if !magic_valid {
+        return None;
+    }
 
-    // First submessage ID is at offset 20 (after 20-byte RTPS header)
-    magic_valid && rtps_packet.len() > 20 && rtps_packet[20] == RTPS_SUBMSG_DATA
+    let offset = if rtps_packet.get(20) == Some(&RTPS_SUBMSG_INFO_TS) {
+        32
+    } else {
+        20
+    };
+    (rtps_packet.len() >= min_len.max(21) + offset - 20 && rtps_packet[offset] == submessage_id)
+        .then_some(offset)
 }
 
 /// Build standard RTPS header (16 bytes).
@@ -79,8 +106,18 @@ pub(super) fn try_u32_from_usize(value: usize, context: &str) -> Option<u32> {
     }
 }
 
-/// Build inline QoS parameter list with topic name.
-pub(super) fn build_inline_qos_with_topic(topic: &str) -> Vec<u8> {
+/// Build inline QoS parameter list with topic name and the optional
+/// parameters from `options`.
+///
+/// The instance parameters (`PID_KEY_HASH`, `PID_STATUS_INFO`),
+/// `PID_ORIGINAL_WRITER_INFO` and then the vendor parameters (`PID_HDDS_TRACE_ID`, `PID_HDDS_SAMPLE_CHECKSUM`,
+/// `PID_HDDS_TYPE_VERSION`, `PID_HDDS_COHERENT_SET`) are emitted right after the topic name.
+/// `checksum` is the value computed from `options.checksum`.
+pub(super) fn build_inline_qos(
+    topic: &str,
+    options: &DataPacketOptions<'_>,
+    checksum: Option<&SampleChecksum>,
+) -> Vec<u8> {
     let topic_bytes = topic.as_bytes();
     let string_len = topic_bytes.len() + 1;
     let param_len = 4 + string_len;
@@ -97,7 +134,7 @@ pub(super) fn build_inline_qos_with_topic(topic: &str) -> Vec<u8> {
     let aligned_size = (unaligned_size + 3) & !3;
     let padding = aligned_size - unaligned_size;
 
-    let mut qos = Vec::with_capacity(aligned_size + 4);
+    let mut qos = Vec::with_capacity(aligned_size + 4 + 12 + 4 + SAMPLE_CHECKSUM_LEN);
 
     // CDR encapsulation header (ALWAYS big-endian per CDR spec)
     qos.extend_from_slice(&CDR_LE.to_be_bytes());
@@ -111,8 +148,71 @@ pub(super) fn build_inline_qos_with_topic(topic: &str) -> Vec<u8> {
 
     qos.extend(std::iter::repeat_n(0, padding));
 
+    if let Some(key_hash) = options.key_hash {
+        qos.extend_from_slice(&PID_KEY_HASH.to_le_bytes());
+        qos.extend_from_slice(&16u16.to_le_bytes());
+        qos.extend_from_slice(&key_hash);
+    }
+
+    if options.status_info != 0 {
+        qos.extend_from_slice(&PID_STATUS_INFO.to_le_bytes());
+        qos.extend_from_slice(&4u16.to_le_bytes());
+        qos.extend_from_slice(&[0, 0, 0, options.status_info]);
+    }
+
+    if let Some(original) = options.original_writer {
+        qos.extend_from_slice(&PID_ORIGINAL_WRITER_INFO.to_le_bytes());
+        qos.extend_from_slice(&(ORIGINAL_WRITER_INFO_LEN as u16).to_le_bytes());
+        qos.extend_from_slice(&original.to_bytes());
+    }
+
+    if let Some(trace_id) = options.trace_id {
+        qos.extend_from_slice(&PID_HDDS_TRACE_ID.to_le_bytes());
+        qos.extend_from_slice(&8u16.to_le_bytes());
+        qos.extend_from_slice(&trace_id.to_le_bytes());
+    }
+
+    if let Some(checksum) = checksum {
+        qos.extend_from_slice(&PID_HDDS_SAMPLE_CHECKSUM.to_le_bytes());
+        qos.extend_from_slice(&(SAMPLE_CHECKSUM_LEN as u16).to_le_bytes());
+        qos.extend_from_slice(&checksum.to_bytes());
+    }
+
+    if let Some(tag) = options.type_version {
+        let len = match try_u16_from_usize(tag.encoded_len(), "type version tag length") {
+            Some(value) => value,
+            None => return Vec::new(),
+        };
+        qos.extend_from_slice(&PID_HDDS_TYPE_VERSION.to_le_bytes());
+        qos.extend_from_slice(&len.to_le_bytes());
+        tag.encode_into(&mut qos);
+    }
+
+    if let Some(tag) = options.coherent_set {
+        qos.extend_from_slice(&PID_HDDS_COHERENT_SET.to_le_bytes());
+        qos.extend_from_slice(&(COHERENT_SET_TAG_LEN as u16).to_le_bytes());
+        qos.extend_from_slice(&tag.to_bytes());
+    }
+
     qos.extend_from_slice(&0x0001u16.to_le_bytes());
     qos.extend_from_slice(&0x0000u16.to_le_bytes());
 
     qos
 }
+
+/// Build the inline QoS of the first DATA_FRAG of a sample.
+///
+/// Same layout as [`build_inline_qos`] (CDR header, parameters, sentinel),
+/// carrying only `PID_HDDS_TRACE_ID`: the reader routes fragments by writer
+/// GUID, so the topic name is not needed.
+pub(super) fn build_frag_inline_qos(trace_id: u64) -> Vec<u8> {
+    let mut qos = Vec::with_capacity(4 + 4 + 8 + 4);
+    qos.extend_from_slice(&CDR_LE.to_be_bytes());
+    qos.extend_from_slice(&[0x00, 0x00]);
+    qos.extend_from_slice(&PID_HDDS_TRACE_ID.to_le_bytes());
+    qos.extend_from_slice(&8u16.to_le_bytes());
+    qos.extend_from_slice(&trace_id.to_le_bytes());
+    qos.extend_from_slice(&0x0001u16.to_le_bytes());
+    qos.extend_from_slice(&0x0000u16.to_le_bytes());
+    qos
+}
@@ -14,15 +14,20 @@ pub use acknack::{
     build_acknack_packet, build_acknack_packet_with_final, build_acknack_submessage,
 };
 pub use extract::{
-    extract_data_payload, extract_inline_qos, extract_sequence_number, extract_writer_guid,
+    extract_data_frag_inline_qos, extract_data_payload, extract_inline_qos,
+    extract_sequence_number, extract_writer_guid, for_each_batched_data, is_batched_data,
+    is_hdds_vendor, parse_coherent_set, parse_key_hash, parse_original_writer,
+    parse_sample_checksum, parse_status_info, parse_trace_id, parse_type_version,
 };
 pub use heartbeat_frag::{build_heartbeat_frag_packet, build_heartbeat_frag_submessage};
 pub use nack_frag::{build_nack_frag_packet, build_nack_frag_submessage};
 pub use packet::{
-    build_acknack_packet_from_ranges, build_data_frag_packets, build_data_packet,
-    build_data_packet_with_context, build_gap_packet, build_heartbeat_packet,
-    build_heartbeat_packet_with_context, should_fragment, RtpsEndpointContext,
-    DEFAULT_FRAGMENT_SIZE, DEFAULT_MAX_UNFRAGMENTED_SIZE,
+    build_acknack_packet_from_ranges, build_data_frag_packets, build_data_frag_packets_stamped,
+    build_data_frag_packets_traced, build_data_packet, build_data_packet_with_context,
+    build_data_packet_with_context_checked, build_data_packet_with_context_traced,
+    build_data_packet_with_options, build_gap_packet, build_heartbeat_packet,
+    build_heartbeat_packet_with_context, build_liveliness_heartbeat_packet, should_fragment,
+    DataPacketOptions, RtpsEndpointContext, DEFAULT_FRAGMENT_SIZE, DEFAULT_MAX_UNFRAGMENTED_SIZE,
 };
 
 #[cfg(test)]
@@ -1,11 +1,16 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 // Copyright (c) 2025-2026 naskel.com
 
-use super::helpers::build_inline_qos_with_topic;
+use super::helpers::{build_frag_inline_qos, build_inline_qos};
 // v110: Removed unused imports (build_rtps_header, try_u16_from_usize)
 // - Now using DialectEncoder for DATA/GAP submessages
+use crate::protocol::checksum::{ChecksumKind, SampleChecksum};
+use crate::protocol::coherent::CoherentSetTag;
 use crate::protocol::constants::*;
 use crate::protocol::dialect::{get_encoder, Dialect};
+use crate::protocol::original_writer::OriginalWriterInfo;
+use crate::protocol::rtps;
+use crate::protocol::type_version::TypeVersionTag;
 use std::ops::Range;
 
 /// RTPS endpoint context used when building DATA packets.
@@ -29,7 +34,7 @@ pub struct RtpsEndpointContext {
 /// For interop with external stacks, use build_data_packet_with_context().
 pub fn build_data_packet(topic: &str, sequence: u64, payload: &[u8]) -> Vec<u8> {
     // Intra-HDDS mode: include inline QoS with topic for local routing
-    let inline_qos = build_inline_qos_with_topic(topic);
+    let inline_qos = build_inline_qos(topic, &DataPacketOptions::default(), None);
     if inline_qos.is_empty() {
         return Vec::new();
     }
@@ -129,6 +134,36 @@ pub fn build_heartbeat_packet_with_context(
     packet
 }
 
+/// Build a liveliness HEARTBEAT packet (Final and Liveliness flags set).
+///
+/// Asserts the liveliness of the writer in `ctx` to remote readers (RTPS
+/// v2.5 Sec.8.3.7.5). `first_seq`/`last_seq` still describe the writer's
+/// cache, so reliable readers see a consistent range.
+pub fn build_liveliness_heartbeat_packet(
+    ctx: &RtpsEndpointContext,
+    first_seq: u64,
+    last_seq: u64,
+    count: u32,
+) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(20 + 32);
+    packet.extend_from_slice(RTPS_MAGIC);
+    packet.extend_from_slice(&[RTPS_VERSION_MAJOR, RTPS_VERSION_MINOR]);
+    packet.extend_from_slice(&HDDS_VENDOR_ID);
+    packet.extend_from_slice(&ctx.guid_prefix);
+
+    let heartbeat = crate::protocol::rtps::encode_heartbeat_liveliness(
+        &ctx.reader_entity_id,
+        &ctx.writer_entity_id,
+        first_seq,
+        last_seq,
+        count,
+    )
+    .unwrap_or_else(|_| Vec::new());
+
+    packet.extend_from_slice(&heartbeat);
+    packet
+}
+
 /// Build RTPS ACKNACK packet from gap ranges using DialectEncoder.
 ///
 /// Converts ranges to RTPS-standard SequenceNumberSet (bitmap) format.
@@ -278,16 +313,98 @@ pub fn build_data_packet_with_context(
     topic: &str,
     sequence: u64,
     payload: &[u8],
+) -> Vec<u8> {
+    build_data_packet_with_context_traced(ctx, topic, sequence, payload, None)
+}
+
+/// Build RTPS DATA packet with endpoint context and an optional trace id.
+///
+/// Same as [`build_data_packet_with_context`], with `PID_HDDS_TRACE_ID` added
+/// to the inline QoS when `trace_id` is set.
+pub fn build_data_packet_with_context_traced(
+    ctx: &RtpsEndpointContext,
+    topic: &str,
+    sequence: u64,
+    payload: &[u8],
+    trace_id: Option<u64>,
+) -> Vec<u8> {
+    build_data_packet_with_context_checked(ctx, topic, sequence, payload, trace_id, None)
+}
+
+/// Build RTPS DATA packet with endpoint context, optional trace id and
+/// optional sample checksum.
+///
+/// When `checksum` is set, `payload` (without the encapsulation header) is
+/// checksummed and the result is added to the inline QoS as
+/// `PID_HDDS_SAMPLE_CHECKSUM`.
+pub fn build_data_packet_with_context_checked(
+    ctx: &RtpsEndpointContext,
+    topic: &str,
+    sequence: u64,
+    payload: &[u8],
+    trace_id: Option<u64>,
+    checksum: Option<ChecksumKind>,
+) -> Vec<u8> {
+    let options = DataPacketOptions {
+        trace_id,
+        checksum,
+        ..Default::default()
+    };
+    build_data_packet_with_options(ctx, topic, sequence, payload, &options)
+}
+
+/// Optional parameters added to a user DATA packet's inline QoS.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DataPacketOptions<'a> {
+    /// `PID_KEY_HASH` of the sample's instance (keyed topics)
+    pub key_hash: Option<[u8; 16]>,
+    /// `PID_STATUS_INFO` flags (`STATUS_INFO_*`); 0 omits the parameter
+    pub status_info: u8,
+    /// `PID_HDDS_TRACE_ID`
+    pub trace_id: Option<u64>,
+    /// `PID_HDDS_SAMPLE_CHECKSUM`, computed over the payload
+    pub checksum: Option<ChecksumKind>,
+    /// `PID_HDDS_TYPE_VERSION`
+    pub type_version: Option<&'a TypeVersionTag>,
+    /// `PID_HDDS_COHERENT_SET`
+    pub coherent_set: Option<CoherentSetTag>,
+    /// `PID_ORIGINAL_WRITER_INFO` of a relayed sample
+    pub original_writer: Option<OriginalWriterInfo>,
+    /// Source timestamp (ns since the UNIX epoch), sent in an INFO_TS
+    /// submessage ahead of the DATA
+    pub source_timestamp_ns: Option<u64>,
+    /// Pad the payload to a 4-byte boundary, recording the padding in the
+    /// encapsulation options (XTypes 7.6.3.1.2), so that further
+    /// submessages can follow the DATA in a batched message
+    pub aligned: bool,
+}
+
+/// Build RTPS DATA packet with endpoint context and any combination of
+/// instance and HDDS vendor inline parameters.
+pub fn build_data_packet_with_options(
+    ctx: &RtpsEndpointContext,
+    topic: &str,
+    sequence: u64,
+    payload: &[u8],
+    options: &DataPacketOptions<'_>,
 ) -> Vec<u8> {
     // v235: Prepend CDR encapsulation header (PLAIN_CDR_LE = 0x0001)
     let mut encapsulated_payload = Vec::with_capacity(4 + payload.len());
     encapsulated_payload.extend_from_slice(&[0x00, 0x01, 0x00, 0x00]); // PLAIN_CDR_LE
     encapsulated_payload.extend_from_slice(payload);
+    if options.aligned {
+        let padding = (4 - payload.len() % 4) % 4;
+        encapsulated_payload[3] = padding as u8;
+        encapsulated_payload.resize(4 + payload.len() + padding, 0);
+    }
 
     // v235: Build inline QoS with topic name for cross-process routing.
     // Without this, the router has to rely on GUID-based routing which requires
     // SEDP to have registered the writer first — a race condition.
-    let inline_qos = build_inline_qos_with_topic(topic);
+    let checksum = options
+        .checksum
+        .map(|kind| SampleChecksum::compute(kind, payload));
+    let inline_qos = build_inline_qos(topic, options, checksum.as_ref());
     if inline_qos.is_empty() {
         return Vec::new();
     }
@@ -296,12 +413,15 @@ pub fn build_data_packet_with_context(
     //                       + inline_qos + payload
     let submsg_body_len = 20 + inline_qos.len() + encapsulated_payload.len();
 
-    // Build RTPS header (20 bytes) + DATA submessage
-    let mut packet = Vec::with_capacity(20 + 4 + submsg_body_len);
+    // Build RTPS header (20 bytes) + [INFO_TS (12 bytes)] + DATA submessage
+    let mut packet = Vec::with_capacity(20 + 12 + 4 + submsg_body_len);
     packet.extend_from_slice(RTPS_MAGIC);
     packet.extend_from_slice(&[RTPS_VERSION_MAJOR, RTPS_VERSION_MINOR]);
     packet.extend_from_slice(&HDDS_VENDOR_ID);
     packet.extend_from_slice(&ctx.guid_prefix);
+    if let Some(timestamp_ns) = options.source_timestamp_ns {
+        packet.extend_from_slice(&rtps::encode_info_ts_ns(timestamp_ns));
+    }
 
     // DATA submessage header (4 bytes)
     packet.push(0x15); // DATA submessage ID
@@ -322,7 +442,7 @@ pub fn build_data_packet_with_context(
     packet.extend_from_slice(&sn_high.to_le_bytes());
     packet.extend_from_slice(&sn_low.to_le_bytes());
 
-    // Inline QoS (CDR header + PID_TOPIC_NAME + PID_SENTINEL)
+    // Inline QoS (CDR header + PID_TOPIC_NAME [+ vendor params] + PID_SENTINEL)
     packet.extend_from_slice(&inline_qos);
 
     // Serialized payload
@@ -380,6 +500,43 @@ pub fn build_data_frag_packets(
     sequence: u64,
     payload: &[u8],
     fragment_size: usize,
+) -> Vec<Vec<u8>> {
+    build_data_frag_packets_stamped(ctx, sequence, payload, fragment_size, None)
+}
+
+/// Build fragmented DATA_FRAG packets for large payloads, each preceded by
+/// an INFO_TS submessage when `source_timestamp_ns` is set.
+///
+/// Same as [`build_data_frag_packets`] otherwise.
+pub fn build_data_frag_packets_stamped(
+    ctx: &RtpsEndpointContext,
+    sequence: u64,
+    payload: &[u8],
+    fragment_size: usize,
+    source_timestamp_ns: Option<u64>,
+) -> Vec<Vec<u8>> {
+    build_data_frag_packets_traced(
+        ctx,
+        sequence,
+        payload,
+        fragment_size,
+        source_timestamp_ns,
+        None,
+    )
+}
+
+/// Build fragmented DATA_FRAG packets, the first one carrying `trace_id`
+/// as `PID_HDDS_TRACE_ID` in its inline QoS.
+///
+/// Same as [`build_data_frag_packets_stamped`] otherwise. The reader keeps
+/// the trace id until the sample is reassembled.
+pub fn build_data_frag_packets_traced(
+    ctx: &RtpsEndpointContext,
+    sequence: u64,
+    payload: &[u8],
+    fragment_size: usize,
+    source_timestamp_ns: Option<u64>,
+    trace_id: Option<u64>,
 ) -> Vec<Vec<u8>> {
     let total_size = payload.len();
 
@@ -394,6 +551,7 @@ pub fn build_data_frag_packets(
     // Clamp to RTPS limits: fragment_size fits in u16, total_size in u32
     let fragment_size_u16 = fragment_size.min(u16::MAX as usize) as u16;
     let total_size_u32 = total_size.min(u32::MAX as usize) as u32;
+    let inline_qos = trace_id.map(build_frag_inline_qos).unwrap_or_default();
 
     for frag_idx in 0..num_fragments {
         let start = frag_idx * fragment_size;
@@ -412,6 +570,8 @@ pub fn build_data_frag_packets(
             fragment_size_u16,
             total_size_u32,
             frag_data,
+            source_timestamp_ns,
+            if frag_idx == 0 { &inline_qos } else { &[] },
         );
         packets.push(packet);
     }
@@ -422,6 +582,7 @@ pub fn build_data_frag_packets(
 /// Build a single DATA_FRAG packet.
 ///
 /// Internal helper for `build_data_frag_packets`.
+#[allow(clippy::too_many_arguments)]
 fn build_single_data_frag_packet(
     ctx: &RtpsEndpointContext,
     sequence: u64,
@@ -430,13 +591,18 @@ fn build_single_data_frag_packet(
     fragment_size: u16,
     data_size: u32,
     fragment_data: &[u8],
+    source_timestamp_ns: Option<u64>,
+    inline_qos: &[u8],
 ) -> Vec<u8> {
-    // Build RTPS header (20 bytes)
-    let mut packet = Vec::with_capacity(20 + 40 + fragment_data.len());
+    // Build RTPS header (20 bytes) + [INFO_TS (12 bytes)]
+    let mut packet = Vec::with_capacity(20 + 12 + 40 + fragment_data.len());
     packet.extend_from_slice(RTPS_MAGIC);
     packet.extend_from_slice(&[RTPS_VERSION_MAJOR, RTPS_VERSION_MINOR]);
     packet.extend_from_slice(&HDDS_VENDOR_ID);
     packet.extend_from_slice(&ctx.guid_prefix);
+    if let Some(timestamp_ns) = source_timestamp_ns {
+        packet.extend_from_slice(&rtps::encode_info_ts_ns(timestamp_ns));
+    }
 
     // Use DialectEncoder for DATA_FRAG submessage
     let encoder = get_encoder(Dialect::Hybrid);
@@ -453,7 +619,26 @@ fn build_single_data_frag_packet(
         )
         .unwrap_or_else(|_| Vec::new());
 
-    packet.extend_from_slice(&data_frag_submsg);
+    if inline_qos.is_empty() || data_frag_submsg.len() < 36 {
+        packet.extend_from_slice(&data_frag_submsg);
+        return packet;
+    }
+
+    // The encoder has no inline QoS: splice it between the fragment header
+    // (submessage header + 32 bytes) and the fragment data, set the Q flag
+    // and point octetsToInlineQos past the 28 header bytes that follow it
+    let Ok(submsg_len) = u16::try_from(data_frag_submsg.len() - 4 + inline_qos.len()) else {
+        packet.extend_from_slice(&data_frag_submsg);
+        return packet;
+    };
+    packet.push(data_frag_submsg[0]);
+    packet.push(data_frag_submsg[1] | 0x02);
+    packet.extend_from_slice(&submsg_len.to_le_bytes());
+    packet.extend_from_slice(&data_frag_submsg[4..6]);
+    packet.extend_from_slice(&28u16.to_le_bytes());
+    packet.extend_from_slice(&data_frag_submsg[8..36]);
+    packet.extend_from_slice(inline_qos);
+    packet.extend_from_slice(&data_frag_submsg[36..]);
     packet
 }
 
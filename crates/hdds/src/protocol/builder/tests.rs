@@ -86,3 +86,317 @@ fn test_build_gap_packet() {
     assert_eq!(decoded.gap_start(), gap.gap_start());
     assert_eq!(decoded.lost_sequences(), gap.lost_sequences());
 }
+
+#[test]
+fn test_data_packet_trace_id_roundtrip() {
+    let ctx = RtpsEndpointContext {
+        guid_prefix: [0x11; 12],
+        reader_entity_id: [0, 0, 0, 0x04],
+        writer_entity_id: [0, 0, 0x01, 0x03],
+    };
+    let packet =
+        build_data_packet_with_context_traced(&ctx, "trace/topic", 7, &[1, 2, 3, 4], Some(42));
+
+    let qos = extract_inline_qos(&packet).expect("inline qos");
+    assert_eq!(parse_trace_id(qos), Some(42));
+    assert_eq!(
+        crate::protocol::discovery::parse_topic_name(qos).as_deref(),
+        Some("trace/topic")
+    );
+    assert_eq!(extract_sequence_number(&packet), Some(7));
+
+    let untraced = build_data_packet_with_context(&ctx, "trace/topic", 7, &[1, 2, 3, 4]);
+    assert_eq!(packet.len(), untraced.len() + 12);
+    let qos = extract_inline_qos(&untraced).expect("inline qos");
+    assert_eq!(parse_trace_id(qos), None);
+}
+
+#[test]
+fn test_data_packet_source_timestamp_roundtrip() {
+    use crate::core::discovery::multicast::{classify_rtps, PacketKind};
+    use crate::engine::RxTimestamps;
+
+    let ctx = RtpsEndpointContext {
+        guid_prefix: [0x11; 12],
+        reader_entity_id: [0, 0, 0, 0x04],
+        writer_entity_id: [0, 0, 0x01, 0x03],
+    };
+    let source_ns = 1_700_000_000_123_456_789;
+    let options = DataPacketOptions {
+        key_hash: Some([7; 16]),
+        source_timestamp_ns: Some(source_ns),
+        ..Default::default()
+    };
+    let packet = build_data_packet_with_options(&ctx, "ts/topic", 9, &[1, 2, 3, 4], &options);
+
+    // INFO_TS precedes the DATA
+    assert_eq!(packet[20], 0x09);
+    assert_eq!(packet[32], RTPS_SUBMSG_DATA);
+    let (kind, offset, _, context) = classify_rtps(&packet);
+    assert_eq!(kind, PacketKind::Data);
+    assert_eq!(
+        &packet[offset.expect("payload offset")..],
+        &[0, 1, 0, 0, 1, 2, 3, 4]
+    );
+    assert_eq!(
+        RxTimestamps::from_context(&context, None).source_ns,
+        Some(source_ns)
+    );
+
+    // The DATA extractors look past the INFO_TS
+    assert_eq!(extract_sequence_number(&packet), Some(9));
+    let writer_guid = extract_writer_guid(&packet).expect("writer guid");
+    assert_eq!(writer_guid[12..], [0, 0, 0x01, 0x03]);
+    let qos = extract_inline_qos(&packet).expect("inline qos");
+    assert_eq!(parse_key_hash(qos), Some([7; 16]));
+
+    let frags = build_data_frag_packets_stamped(
+        &ctx,
+        9,
+        &vec![0xAB; DEFAULT_MAX_UNFRAGMENTED_SIZE + 1],
+        DEFAULT_FRAGMENT_SIZE,
+        Some(source_ns),
+    );
+    for frag in &frags {
+        let (kind, _, _, context) = classify_rtps(frag);
+        assert_eq!(kind, PacketKind::DataFrag);
+        assert_eq!(
+            RxTimestamps::from_context(&context, None).source_ns,
+            Some(source_ns)
+        );
+    }
+}
+
+#[test]
+fn test_data_packet_sample_checksum_roundtrip() {
+    use crate::protocol::checksum::{ChecksumKind, SampleChecksum};
+
+    let ctx = RtpsEndpointContext {
+        guid_prefix: [0x11; 12],
+        reader_entity_id: [0, 0, 0, 0x04],
+        writer_entity_id: [0, 0, 0x01, 0x03],
+    };
+    let payload = [1, 2, 3, 4];
+    let packet = build_data_packet_with_context_checked(
+        &ctx,
+        "crc/topic",
+        7,
+        &payload,
+        Some(42),
+        Some(ChecksumKind::Crc32c),
+    );
+
+    let qos = extract_inline_qos(&packet).expect("inline qos");
+    assert_eq!(parse_trace_id(qos), Some(42));
+    assert_eq!(
+        parse_sample_checksum(qos),
+        Some(SampleChecksum::compute(ChecksumKind::Crc32c, &payload))
+    );
+    assert_eq!(
+        crate::protocol::discovery::parse_topic_name(qos).as_deref(),
+        Some("crc/topic")
+    );
+
+    let plain = build_data_packet_with_context(&ctx, "crc/topic", 7, &payload);
+    assert_eq!(packet.len(), plain.len() + 12 + 16);
+    let qos = extract_inline_qos(&plain).expect("inline qos");
+    assert_eq!(parse_sample_checksum(qos), None);
+}
+
+#[test]
+fn test_data_packet_type_version_roundtrip() {
+    use crate::protocol::type_version::TypeVersionTag;
+
+    let ctx = RtpsEndpointContext {
+        guid_prefix: [0x11; 12],
+        reader_entity_id: [0, 0, 0, 0x04],
+        writer_entity_id: [0, 0, 0x01, 0x03],
+    };
+    let tag = TypeVersionTag {
+        sample: 1,
+        native: 2,
+        offered: vec![1, 2],
+    };
+    let packet = build_data_packet_with_options(
+        &ctx,
+        "versioned/topic",
+        7,
+        &[1, 2, 3, 4],
+        &DataPacketOptions {
+            trace_id: Some(42),
+            type_version: Some(&tag),
+            ..Default::default()
+        },
+    );
+
+    let qos = extract_inline_qos(&packet).expect("inline qos");
+    assert_eq!(parse_type_version(qos), Some(tag));
+    assert_eq!(parse_trace_id(qos), Some(42));
+    assert_eq!(
+        crate::protocol::discovery::parse_topic_name(qos).as_deref(),
+        Some("versioned/topic")
+    );
+
+    let plain = build_data_packet_with_context(&ctx, "versioned/topic", 7, &[1, 2, 3, 4]);
+    let qos = extract_inline_qos(&plain).expect("inline qos");
+    assert_eq!(parse_type_version(qos), None);
+}
+
+#[test]
+fn test_data_packet_instance_status_roundtrip() {
+    use crate::protocol::constants::{STATUS_INFO_DISPOSED, STATUS_INFO_UNREGISTERED};
+
+    let ctx = RtpsEndpointContext {
+        guid_prefix: [0x11; 12],
+        reader_entity_id: [0, 0, 0, 0x04],
+        writer_entity_id: [0, 0, 0x01, 0x03],
+    };
+    let key_hash = [7u8; 16];
+    let packet = build_data_packet_with_options(
+        &ctx,
+        "fleet/vehicles",
+        3,
+        &[1, 2, 3, 4],
+        &DataPacketOptions {
+            key_hash: Some(key_hash),
+            status_info: STATUS_INFO_DISPOSED | STATUS_INFO_UNREGISTERED,
+            trace_id: Some(9),
+            ..Default::default()
+        },
+    );
+
+    let qos = extract_inline_qos(&packet).expect("inline qos");
+    assert_eq!(parse_key_hash(qos), Some(key_hash));
+    assert_eq!(parse_status_info(qos), Some(0x03));
+    assert_eq!(parse_trace_id(qos), Some(9));
+    // Payload follows the sentinel, behind its PLAIN_CDR_LE encapsulation.
+    assert_eq!(
+        extract_data_payload(&packet),
+        Some(&[0u8, 1, 0, 0, 1, 2, 3, 4][..])
+    );
+
+    let plain = build_data_packet_with_context(&ctx, "fleet/vehicles", 3, &[1, 2, 3, 4]);
+    let qos = extract_inline_qos(&plain).expect("inline qos");
+    assert_eq!(parse_key_hash(qos), None);
+    assert_eq!(parse_status_info(qos), None);
+}
+
+#[test]
+fn test_data_packet_coherent_set_roundtrip() {
+    use crate::protocol::coherent::CoherentSetTag;
+
+    let ctx = RtpsEndpointContext {
+        guid_prefix: [0x11; 12],
+        reader_entity_id: [0, 0, 0, 0x04],
+        writer_entity_id: [0, 0, 0x01, 0x03],
+    };
+    let tag = CoherentSetTag {
+        group_id: 0xDEAD_BEEF_0000_0001,
+        set_seq: 5,
+        order: 42,
+    };
+    let packet = build_data_packet_with_options(
+        &ctx,
+        "robot/pose",
+        11,
+        &[1, 2, 3, 4],
+        &DataPacketOptions {
+            trace_id: Some(3),
+            coherent_set: Some(tag),
+            ..Default::default()
+        },
+    );
+
+    let qos = extract_inline_qos(&packet).expect("inline qos");
+    assert_eq!(parse_coherent_set(qos), Some(tag));
+    assert_eq!(parse_trace_id(qos), Some(3));
+
+    let plain = build_data_packet_with_context(&ctx, "robot/pose", 11, &[1, 2, 3, 4]);
+    let qos = extract_inline_qos(&plain).expect("inline qos");
+    assert_eq!(parse_coherent_set(qos), None);
+}
+
+#[test]
+fn test_data_packet_original_writer_roundtrip() {
+    use crate::core::discovery::GUID;
+    use crate::protocol::original_writer::OriginalWriterInfo;
+
+    let ctx = RtpsEndpointContext {
+        guid_prefix: [0x22; 12],
+        reader_entity_id: [0, 0, 0, 0x04],
+        writer_entity_id: [0, 0, 0x01, 0x03],
+    };
+    let original = OriginalWriterInfo::new(GUID::new([0x33; 12], [0, 0, 0x02, 0x03]), 7);
+    let packet = build_data_packet_with_options(
+        &ctx,
+        "robot/pose",
+        1,
+        &[1, 2, 3, 4],
+        &DataPacketOptions {
+            key_hash: Some([9; 16]),
+            original_writer: Some(original),
+            ..Default::default()
+        },
+    );
+
+    let qos = extract_inline_qos(&packet).expect("inline qos");
+    assert_eq!(parse_original_writer(qos), Some(original));
+    assert_eq!(parse_key_hash(qos), Some([9; 16]));
+
+    let plain = build_data_packet_with_context(&ctx, "robot/pose", 1, &[1, 2, 3, 4]);
+    let qos = extract_inline_qos(&plain).expect("inline qos");
+    assert_eq!(parse_original_writer(qos), None);
+}
+
+#[test]
+fn test_batched_data_split_roundtrip() {
+    use crate::core::discovery::multicast::{classify_rtps, PacketKind};
+    use crate::engine::RxTimestamps;
+
+    let ctx = RtpsEndpointContext {
+        guid_prefix: [0x11; 12],
+        reader_entity_id: [0, 0, 0, 0x04],
+        writer_entity_id: [0, 0, 0x01, 0x03],
+    };
+    let payloads: [&[u8]; 3] = [&[1, 2, 3], &[4, 5, 6, 7], &[8]];
+
+    // Batched as a writer does: first packet whole, then submessages only
+    let mut batch = Vec::new();
+    for (seq, payload) in (1u64..).zip(payloads) {
+        let options = DataPacketOptions {
+            source_timestamp_ns: Some(1_000 * seq),
+            aligned: true,
+            ..Default::default()
+        };
+        let packet = build_data_packet_with_options(&ctx, "batch/topic", seq, payload, &options);
+        assert_eq!(packet.len() % 4, 0);
+        let start = if batch.is_empty() { 0 } else { 20 };
+        batch.extend_from_slice(&packet[start..]);
+    }
+    assert!(is_batched_data(&batch));
+
+    let mut single = build_data_packet_with_context(&ctx, "batch/topic", 1, &[1, 2, 3]);
+    assert!(!is_batched_data(&single));
+
+    let mut seen = Vec::new();
+    let delivered = for_each_batched_data(&batch, &mut single, |message| {
+        let (kind, offset, _, context) = classify_rtps(message);
+        assert_eq!(kind, PacketKind::Data);
+        seen.push((
+            extract_sequence_number(message).expect("sequence"),
+            RxTimestamps::from_context(&context, None).source_ns,
+            message[offset.expect("payload offset")..].to_vec(),
+        ));
+    });
+    assert_eq!(delivered, 3);
+
+    // Encapsulation options record the padding after each payload
+    assert_eq!(
+        seen,
+        [
+            (1, Some(1_000), vec![0, 1, 0, 1, 1, 2, 3, 0]),
+            (2, Some(2_000), vec![0, 1, 0, 0, 4, 5, 6, 7]),
+            (3, Some(3_000), vec![0, 1, 0, 3, 8, 0, 0, 0]),
+        ]
+    );
+}
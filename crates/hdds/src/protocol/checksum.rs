@@ -0,0 +1,282 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Per-sample payload checksums carried in DATA inline QoS.
+//!
+//! UDP checksums are optional on IPv4, are often offloaded to (and trusted
+//! from) the NIC, and only cover 16 bits. A writer can opt into an
+//! end-to-end checksum of the serialized sample; HDDS receivers verify it
+//! and drop corrupted samples instead of handing garbage to the application.
+//!
+//! # Wire format (`PID_HDDS_SAMPLE_CHECKSUM`, 12 bytes)
+//!
+//! | Offset | Size | Field |
+//! |--------|------|-------|
+//! | 0 | 4 | kind (u32 LE: 1 = CRC32C, 2 = XXH64) |
+//! | 4 | 8 | value (u64 LE, CRC32C zero-extended) |
+//!
+//! The checksum covers the serialized payload without the 4-byte CDR
+//! encapsulation header. Unknown kinds are ignored so newer writers stay
+//! readable by older receivers.
+//!
+//! # Test Vectors
+//!
+//! ```
+//! use hdds::protocol::checksum::{crc32c, xxh64};
+//!
+//! assert_eq!(crc32c(b"123456789"), 0xE306_9283);
+//! assert_eq!(xxh64(b"abc", 0), 0x44BC_2CF5_AD77_0999);
+//! ```
+
+/// Size of the encoded checksum parameter value.
+pub const SAMPLE_CHECKSUM_LEN: usize = 12;
+
+/// Checksum algorithm used for a sample.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChecksumKind {
+    /// CRC-32C (Castagnoli), the iSCSI/ext4 polynomial.
+    Crc32c,
+    /// XXH64 with seed 0: faster on large payloads, 64-bit strength.
+    XxHash64,
+}
+
+impl ChecksumKind {
+    fn to_wire(self) -> u32 {
+        match self {
+            Self::Crc32c => 1,
+            Self::XxHash64 => 2,
+        }
+    }
+
+    fn from_wire(value: u32) -> Option<Self> {
+        match value {
+            1 => Some(Self::Crc32c),
+            2 => Some(Self::XxHash64),
+            _ => None,
+        }
+    }
+}
+
+/// A checksum value tagged with its algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SampleChecksum {
+    pub kind: ChecksumKind,
+    pub value: u64,
+}
+
+impl SampleChecksum {
+    /// Checksum `payload` with `kind`.
+    #[must_use]
+    pub fn compute(kind: ChecksumKind, payload: &[u8]) -> Self {
+        let value = match kind {
+            ChecksumKind::Crc32c => u64::from(crc32c(payload)),
+            ChecksumKind::XxHash64 => xxh64(payload, 0),
+        };
+        Self { kind, value }
+    }
+
+    /// True if `payload` matches this checksum.
+    #[must_use]
+    pub fn verify(&self, payload: &[u8]) -> bool {
+        Self::compute(self.kind, payload).value == self.value
+    }
+
+    /// Encode as the inline QoS parameter value.
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; SAMPLE_CHECKSUM_LEN] {
+        let mut out = [0u8; SAMPLE_CHECKSUM_LEN];
+        out[..4].copy_from_slice(&self.kind.to_wire().to_le_bytes());
+        out[4..].copy_from_slice(&self.value.to_le_bytes());
+        out
+    }
+
+    /// Decode an inline QoS parameter value. Returns `None` for short
+    /// values and unknown algorithms.
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < SAMPLE_CHECKSUM_LEN {
+            return None;
+        }
+        let kind = ChecksumKind::from_wire(u32::from_le_bytes(bytes[..4].try_into().ok()?))?;
+        let value = u64::from_le_bytes(bytes[4..12].try_into().ok()?);
+        Some(Self { kind, value })
+    }
+}
+
+// ============================================================================
+// CRC-32C
+// ============================================================================
+
+/// Reflected CRC-32C polynomial.
+const CRC32C_POLY: u32 = 0x82F6_3B78;
+
+/// Precomputed lookup table for CRC-32C.
+const CRC32C_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut j = 0;
+        while j < 8 {
+            if crc & 1 != 0 {
+                crc = (crc >> 1) ^ CRC32C_POLY;
+            } else {
+                crc >>= 1;
+            }
+            j += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Calculate the CRC-32C (Castagnoli) checksum of `data`.
+#[inline]
+#[must_use]
+pub fn crc32c(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc = CRC32C_TABLE[((crc ^ u32::from(byte)) & 0xFF) as usize] ^ (crc >> 8);
+    }
+    !crc
+}
+
+// ============================================================================
+// XXH64
+// ============================================================================
+
+const PRIME64_1: u64 = 0x9E37_79B1_85EB_CA87;
+const PRIME64_2: u64 = 0xC2B2_AE3D_27D4_EB4F;
+const PRIME64_3: u64 = 0x1656_67B1_9E37_79F9;
+const PRIME64_4: u64 = 0x85EB_CA77_C2B2_AE63;
+const PRIME64_5: u64 = 0x27D4_EB2F_1656_67C5;
+
+#[inline]
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    let mut b = [0u8; 8];
+    b.copy_from_slice(&data[offset..offset + 8]);
+    u64::from_le_bytes(b)
+}
+
+#[inline]
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    let mut b = [0u8; 4];
+    b.copy_from_slice(&data[offset..offset + 4]);
+    u32::from_le_bytes(b)
+}
+
+#[inline]
+fn xxh64_round(acc: u64, input: u64) -> u64 {
+    acc.wrapping_add(input.wrapping_mul(PRIME64_2))
+        .rotate_left(31)
+        .wrapping_mul(PRIME64_1)
+}
+
+#[inline]
+fn xxh64_merge(acc: u64, val: u64) -> u64 {
+    (acc ^ xxh64_round(0, val))
+        .wrapping_mul(PRIME64_1)
+        .wrapping_add(PRIME64_4)
+}
+
+/// Calculate the XXH64 hash of `data`.
+#[must_use]
+pub fn xxh64(data: &[u8], seed: u64) -> u64 {
+    let len = data.len();
+    let mut offset = 0;
+
+    let mut h = if len >= 32 {
+        let mut v1 = seed.wrapping_add(PRIME64_1).wrapping_add(PRIME64_2);
+        let mut v2 = seed.wrapping_add(PRIME64_2);
+        let mut v3 = seed;
+        let mut v4 = seed.wrapping_sub(PRIME64_1);
+        while offset + 32 <= len {
+            v1 = xxh64_round(v1, read_u64(data, offset));
+            v2 = xxh64_round(v2, read_u64(data, offset + 8));
+            v3 = xxh64_round(v3, read_u64(data, offset + 16));
+            v4 = xxh64_round(v4, read_u64(data, offset + 24));
+            offset += 32;
+        }
+        let mut h = v1
+            .rotate_left(1)
+            .wrapping_add(v2.rotate_left(7))
+            .wrapping_add(v3.rotate_left(12))
+            .wrapping_add(v4.rotate_left(18));
+        h = xxh64_merge(h, v1);
+        h = xxh64_merge(h, v2);
+        h = xxh64_merge(h, v3);
+        xxh64_merge(h, v4)
+    } else {
+        seed.wrapping_add(PRIME64_5)
+    };
+
+    h = h.wrapping_add(len as u64);
+
+    while offset + 8 <= len {
+        h ^= xxh64_round(0, read_u64(data, offset));
+        h = h
+            .rotate_left(27)
+            .wrapping_mul(PRIME64_1)
+            .wrapping_add(PRIME64_4);
+        offset += 8;
+    }
+    if offset + 4 <= len {
+        h ^= u64::from(read_u32(data, offset)).wrapping_mul(PRIME64_1);
+        h = h
+            .rotate_left(23)
+            .wrapping_mul(PRIME64_2)
+            .wrapping_add(PRIME64_3);
+        offset += 4;
+    }
+    for &byte in &data[offset..] {
+        h ^= u64::from(byte).wrapping_mul(PRIME64_5);
+        h = h.rotate_left(11).wrapping_mul(PRIME64_1);
+    }
+
+    h ^= h >> 33;
+    h = h.wrapping_mul(PRIME64_2);
+    h ^= h >> 29;
+    h = h.wrapping_mul(PRIME64_3);
+    h ^= h >> 32;
+    h
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc32c_vectors() {
+        assert_eq!(crc32c(b""), 0);
+        assert_eq!(crc32c(b"123456789"), 0xE306_9283);
+        assert_eq!(crc32c(&[0u8; 32]), 0x8A91_36AA);
+    }
+
+    #[test]
+    fn test_xxh64_vectors() {
+        assert_eq!(xxh64(b"", 0), 0xEF46_DB37_51D8_E999);
+        assert_eq!(xxh64(b"abc", 0), 0x44BC_2CF5_AD77_0999);
+        // > 32 bytes: exercises the four-lane stripe loop
+        assert_eq!(
+            xxh64(b"Nobody inspects the spammish repetition", 0),
+            0xFBCE_A83C_8A37_8BF1
+        );
+    }
+
+    #[test]
+    fn test_sample_checksum_roundtrip_and_verify() {
+        let payload = b"sensor frame";
+        for kind in [ChecksumKind::Crc32c, ChecksumKind::XxHash64] {
+            let sum = SampleChecksum::compute(kind, payload);
+            assert_eq!(SampleChecksum::from_bytes(&sum.to_bytes()), Some(sum));
+            assert!(sum.verify(payload));
+            assert!(!sum.verify(b"sensor frane"));
+        }
+
+        let mut unknown = SampleChecksum::compute(ChecksumKind::Crc32c, payload).to_bytes();
+        unknown[0] = 9;
+        assert_eq!(SampleChecksum::from_bytes(&unknown), None);
+        assert_eq!(SampleChecksum::from_bytes(&unknown[..8]), None);
+    }
+}
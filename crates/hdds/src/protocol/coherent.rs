@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Group coherent sets and group ordering (PRESENTATION `coherent_access`
+//! and `ordered_access`, GROUP scope).
+//!
+//! Every sample written between `Publisher::begin_coherent_changes()` and
+//! `end_coherent_changes()` is tagged with `PID_HDDS_COHERENT_SET`. Ending the
+//! set publishes a marker listing how many samples each topic received, and
+//! subscribers hold tagged samples back until the marker and every sample it
+//! announces have arrived.
+//!
+//! Publishers with GROUP ordered access tag every sample, in or out of a
+//! set, with its position in the publisher's write order.
+//!
+//! # DATA tag (`PID_HDDS_COHERENT_SET`)
+//!
+//! | Offset | Size | Field |
+//! |--------|------|-------|
+//! | 0 | 8 | publisher group id (u64 LE) |
+//! | 8 | 8 | coherent set number within the group, 0 if none (u64 LE) |
+//! | 16 | 8 | write order within the group, 0 if unordered (u64 LE) |
+//!
+//! 16-byte tags (no write order) are accepted as unordered.
+
+/// Encoded length of a [`CoherentSetTag`] in bytes.
+pub const COHERENT_SET_TAG_LEN: usize = 24;
+
+/// Coherent set and write order of a DATA sample.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CoherentSetTag {
+    /// Identifies the publishing `Publisher` across the domain.
+    pub group_id: u64,
+    /// Set number, increasing per publisher; 0 outside a coherent set.
+    pub set_seq: u64,
+    /// Write order, increasing per publisher; 0 without ordered access.
+    pub order: u64,
+}
+
+impl CoherentSetTag {
+    /// Encode the tag (see the module-level layout).
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; COHERENT_SET_TAG_LEN] {
+        let mut out = [0u8; COHERENT_SET_TAG_LEN];
+        out[..8].copy_from_slice(&self.group_id.to_le_bytes());
+        out[8..16].copy_from_slice(&self.set_seq.to_le_bytes());
+        out[16..].copy_from_slice(&self.order.to_le_bytes());
+        out
+    }
+
+    /// Decode a tag. Returns `None` if truncated.
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        Some(Self {
+            group_id: u64::from_le_bytes(bytes.get(..8)?.try_into().ok()?),
+            set_seq: u64::from_le_bytes(bytes.get(8..16)?.try_into().ok()?),
+            order: match bytes.get(16..24) {
+                Some(order) => u64::from_le_bytes(order.try_into().ok()?),
+                None => 0,
+            },
+        })
+    }
+}
@@ -162,6 +162,65 @@ pub const RTPS_SUBMSG_DATA_FRAG: u8 = 0x16;
 /// HEADER_EXTENSION submessage ID (non-standard, used by some vendors)
 pub const RTPS_SUBMSG_HEADER_EXTENSION: u8 = 0x00;
 
+// ============================================================================
+// Standard DATA inline QoS parameters (RTPS v2.3 Sec.9.6.3)
+// ============================================================================
+
+/// Instance key hash carried in DATA inline QoS (16 bytes).
+pub const PID_KEY_HASH: u16 = 0x0070;
+
+/// Instance status carried in DATA inline QoS (4 bytes, flags in the last octet).
+///
+/// Sent by `DataWriter::dispose()` and `DataWriter::unregister_instance()`.
+pub const PID_STATUS_INFO: u16 = 0x0071;
+
+/// Writer and sequence number a relayed sample was first published with
+/// (GUID + SequenceNumber_t + parameter list, 28 bytes).
+///
+/// Sent with samples replayed by a persistence service; see
+/// `protocol::original_writer`.
+pub const PID_ORIGINAL_WRITER_INFO: u16 = 0x0061;
+
+/// `PID_STATUS_INFO` flag: the writer disposed the instance.
+pub const STATUS_INFO_DISPOSED: u8 = 0x01;
+
+/// `PID_STATUS_INFO` flag: the writer unregistered the instance.
+pub const STATUS_INFO_UNREGISTERED: u8 = 0x02;
+
+// ============================================================================
+// HDDS vendor inline QoS parameters (vendor-specific PID range 0x8000+)
+// ============================================================================
+
+/// Per-sample trace id carried in DATA inline QoS (u64 little-endian).
+///
+/// Set via `DataWriter::write_with_trace_id()` and surfaced in `SampleInfo` on
+/// the reader side. Other vendors ignore it (vendor-specific, not must-understand).
+pub const PID_HDDS_TRACE_ID: u16 = 0x8003;
+
+/// Per-sample payload checksum carried in DATA inline QoS (kind u32 + value u64).
+///
+/// Enabled per writer via `WriterBuilder::sample_checksum()`; HDDS receivers
+/// drop samples that fail verification. See `protocol::checksum`.
+pub const PID_HDDS_SAMPLE_CHECKSUM: u16 = 0x8004;
+
+/// Type version of a DATA sample published side-by-side in several versions.
+///
+/// Carries the copy's version, the writer's native version and the offered
+/// set so readers select statelessly. See `protocol::type_version`.
+pub const PID_HDDS_TYPE_VERSION: u16 = 0x8005;
+
+/// SEDP parameter listing the type versions (and TypeObjects) an endpoint
+/// publishes or accepts. Starts with an `"HDTV"` magic since SEDP vendor
+/// PIDs are not scoped by vendor id in every stack.
+pub const PID_HDDS_TYPE_VERSIONS: u16 = 0x8006;
+
+/// Group coherent set a DATA sample belongs to (group id u64 + set number u64).
+///
+/// Written by publishers with PRESENTATION `coherent_access` between
+/// `begin_coherent_changes()` and `end_coherent_changes()`. See
+/// `protocol::coherent`.
+pub const PID_HDDS_COHERENT_SET: u16 = 0x8007;
+
 // ============================================================================
 // Protocol sizes and offsets (Sec.8.3.3)
 // ============================================================================
@@ -6,9 +6,10 @@
 //! These functions handle low-level byte manipulation and alignment
 //! that are identical across all RTPS implementations.
 
-use std::net::{IpAddr, SocketAddr};
+use std::net::SocketAddr;
 
 use super::error::{EncodeError, EncodeResult};
+use crate::protocol::rtps::udp_locator_fields;
 
 // ===== Byte Writing Utilities =====
 
@@ -248,25 +249,16 @@ pub fn write_unicast_locator(
     *offset += 4;
 
     // Locator_t: kind(4) + port(4) + address(16)
-    buf[*offset..*offset + 4].copy_from_slice(&1u32.to_le_bytes()); // LOCATOR_KIND_UDPV4
+    let (kind, address) = udp_locator_fields(locator);
+    buf[*offset..*offset + 4].copy_from_slice(&kind.to_le_bytes());
     *offset += 4;
 
     let port = u32::from(locator.port());
     buf[*offset..*offset + 4].copy_from_slice(&port.to_le_bytes());
     *offset += 4;
 
-    buf[*offset..*offset + 12].fill(0);
-    *offset += 12;
-
-    match locator.ip() {
-        IpAddr::V4(ipv4) => {
-            buf[*offset..*offset + 4].copy_from_slice(&ipv4.octets());
-        }
-        IpAddr::V6(ipv6) => {
-            buf[*offset..*offset + 4].copy_from_slice(&ipv6.octets()[12..16]);
-        }
-    }
-    *offset += 4;
+    buf[*offset..*offset + 16].copy_from_slice(&address);
+    *offset += 16;
 
     Ok(())
 }
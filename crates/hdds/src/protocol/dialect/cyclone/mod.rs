@@ -70,6 +70,7 @@ impl DialectEncoder for CycloneEncoder {
             default_multicast_locators: multicast_locators.to_vec(),
             metatraffic_multicast_locators: multicast_locators.to_vec(),
             identity_token: None,
+            user_data: Default::default(),
         };
 
         let mut buf = vec![0u8; 2048];
@@ -82,8 +83,8 @@ impl DialectEncoder for CycloneEncoder {
     fn build_sedp(&self, data: &SedpEndpointData) -> EncodeResult<Vec<u8>> {
         // CycloneDDS uses standard RTPS SEDP encoding
         use crate::core::discovery::GUID;
-        use crate::dds::qos::QoS;
         use crate::protocol::discovery::types::SedpData as LegacySedpData;
+        #[cfg(feature = "xtypes")]
         use crate::Cdr2Decode;
 
         let mut endpoint_guid_bytes = [0u8; 16];
@@ -95,21 +96,7 @@ impl DialectEncoder for CycloneEncoder {
         participant_guid_bytes[12..16].copy_from_slice(&data.participant_guid.entity_id);
 
         // Convert QosProfile to DDS QoS
-        let qos = data.qos.map(|q| {
-            let base = match q.reliability_kind {
-                1 => QoS::best_effort(),
-                _ => QoS::reliable(),
-            };
-            let with_durability = match q.durability_kind {
-                0 => base.volatile(),
-                3 => base.persistent(),
-                _ => base.transient_local(),
-            };
-            match q.history_kind {
-                1 => with_durability.keep_all(),
-                _ => with_durability.keep_last(q.history_depth),
-            }
-        });
+        let qos = data.qos.map(QosProfile::to_qos);
 
         #[cfg(feature = "xtypes")]
         let type_object = data
@@ -129,9 +116,11 @@ impl DialectEncoder for CycloneEncoder {
             type_object,
             unicast_locators: data.unicast_locators.to_vec(),
             user_data: None,
+            metadata: data.qos.map(|q| q.metadata.clone()).unwrap_or_default(),
+            type_versions: None,
         };
 
-        let mut buf = vec![0u8; 8192];
+        let mut buf = vec![0u8; 8192 + legacy_data.metadata.len()];
         let len = crate::protocol::discovery::sedp::build::build_sedp(&legacy_data, &mut buf)
             .map_err(|_| EncodeError::BufferTooSmall)?;
         buf.truncate(len);
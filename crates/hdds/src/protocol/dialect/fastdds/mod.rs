@@ -73,10 +73,12 @@ impl DialectEncoder for FastDdsEncoder {
             type_object: None,
             unicast_locators: data.unicast_locators.to_vec(),
             user_data: None,
+            metadata: data.qos.map(|q| q.metadata.clone()).unwrap_or_default(),
+            type_versions: None,
         };
 
         // Use certified builder (8KB buffer matches original)
-        let mut buf = vec![0u8; 8192];
+        let mut buf = vec![0u8; 8192 + legacy_data.metadata.len()];
         let len = crate::protocol::discovery::sedp::build::build_sedp(&legacy_data, &mut buf)
             .map_err(|_| super::error::EncodeError::BufferTooSmall)?;
         buf.truncate(len);
@@ -12,9 +12,10 @@
 //! - port (u32) - UDP port number
 //! - address (16 bytes) - IPv4 in last 4 bytes, IPv6 uses all 16 bytes
 
-use std::net::{IpAddr, SocketAddr};
+use std::net::SocketAddr;
 
 use crate::protocol::dialect::error::{EncodeError, EncodeResult};
+use crate::protocol::rtps::udp_locator_fields;
 
 /// PID for unicast locator
 const PID_UNICAST_LOCATOR: u16 = 0x002f;
@@ -37,29 +38,17 @@ pub fn write_unicast_locator(
     buf[*offset + 2..*offset + 4].copy_from_slice(&24u16.to_le_bytes());
     *offset += 4;
 
-    // Locator_t: kind (4 bytes) + port (4 bytes) + address (16 bytes)
-    buf[*offset..*offset + 4].copy_from_slice(&1u32.to_le_bytes()); // LOCATOR_KIND_UDPV4
+    // Locator_t: kind(4) + port(4) + address(16)
+    let (kind, address) = udp_locator_fields(locator);
+    buf[*offset..*offset + 4].copy_from_slice(&kind.to_le_bytes());
     *offset += 4;
 
     let port = u32::from(locator.port());
     buf[*offset..*offset + 4].copy_from_slice(&port.to_le_bytes());
     *offset += 4;
 
-    // Address: IPv4 in last 4 bytes of 16-byte field
-    buf[*offset..*offset + 12].fill(0);
-    *offset += 12;
-
-    match locator.ip() {
-        IpAddr::V4(ipv4) => {
-            buf[*offset..*offset + 4].copy_from_slice(&ipv4.octets());
-        }
-        IpAddr::V6(ipv6) => {
-            // For IPv6, we'd need LOCATOR_KIND_UDPV6
-            // For now, just use the last 4 bytes (loses info, but rare case)
-            buf[*offset..*offset + 4].copy_from_slice(&ipv6.octets()[12..16]);
-        }
-    }
-    *offset += 4;
+    buf[*offset..*offset + 16].copy_from_slice(&address);
+    *offset += 16;
 
     Ok(())
 }
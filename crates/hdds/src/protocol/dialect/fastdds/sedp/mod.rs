@@ -137,6 +137,7 @@ mod tests {
             multicast_locators: &[],
             qos: None,
             type_object: None,
+            type_versions: None,
         };
 
         let result = build_sedp(&data);
@@ -161,6 +162,7 @@ mod tests {
             multicast_locators: &[],
             qos: None,
             type_object: None,
+            type_versions: None,
         };
 
         let buf = build_sedp(&data).expect("build_sedp should succeed");
@@ -36,6 +36,7 @@ use std::net::SocketAddr;
 use super::error::{EncodeError, EncodeResult};
 use super::{DialectEncoder, Guid, QosProfile, SedpEndpointData};
 use crate::protocol::rtps;
+use crate::protocol::type_version::TypeVersionAnnouncement;
 
 /// HDDS Vendor ID (experimental allocation: 0x01AA)
 pub const HDDS_VENDOR_ID: [u8; 2] = [0x01, 0xAA];
@@ -94,6 +95,7 @@ impl DialectEncoder for HddsEncoder {
             default_multicast_locators: multicast_locators.to_vec(),
             metatraffic_multicast_locators: multicast_locators.to_vec(),
             identity_token: None,
+            user_data: Default::default(),
         };
 
         let mut buf = vec![0u8; 2048];
@@ -106,8 +108,8 @@ impl DialectEncoder for HddsEncoder {
     fn build_sedp(&self, data: &SedpEndpointData) -> EncodeResult<Vec<u8>> {
         // Use the certified SEDP builder directly
         use crate::core::discovery::GUID;
-        use crate::dds::qos::QoS;
         use crate::protocol::discovery::types::SedpData as LegacySedpData;
+        #[cfg(feature = "xtypes")]
         use crate::Cdr2Decode;
 
         let mut endpoint_guid_bytes = [0u8; 16];
@@ -118,21 +120,7 @@ impl DialectEncoder for HddsEncoder {
         participant_guid_bytes[..12].copy_from_slice(&data.participant_guid.prefix);
         participant_guid_bytes[12..16].copy_from_slice(&data.participant_guid.entity_id);
 
-        let qos = data.qos.map(|q| {
-            let base = match q.reliability_kind {
-                1 => QoS::best_effort(),
-                _ => QoS::reliable(),
-            };
-            let with_durability = match q.durability_kind {
-                0 => base.volatile(),
-                3 => base.persistent(),
-                _ => base.transient_local(),
-            };
-            match q.history_kind {
-                1 => with_durability.keep_all(),
-                _ => with_durability.keep_last(q.history_depth),
-            }
-        });
+        let qos = data.qos.map(QosProfile::to_qos);
 
         #[cfg(feature = "xtypes")]
         let type_object = data
@@ -152,9 +140,17 @@ impl DialectEncoder for HddsEncoder {
             type_object,
             unicast_locators: data.unicast_locators.to_vec(),
             user_data: None,
+            metadata: data.qos.map(|q| q.metadata.clone()).unwrap_or_default(),
+            type_versions: data
+                .type_versions
+                .and_then(TypeVersionAnnouncement::from_bytes),
         };
 
-        let mut buf = vec![0u8; 8192];
+        let mut buf = vec![
+            0u8;
+            8192 + data.type_versions.map_or(0, <[u8]>::len)
+                + legacy_data.metadata.len()
+        ];
         let len = crate::protocol::discovery::sedp::build::build_sedp(&legacy_data, &mut buf)
             .map_err(|_| EncodeError::BufferTooSmall)?;
         buf.truncate(len);
@@ -389,4 +385,41 @@ mod tests {
         // Verify submessage ID
         assert_eq!(result[0], 0x06); // ACKNACK submessage ID
     }
+
+    #[test]
+    fn test_build_sedp_announces_time_based_filter() {
+        let encoder = HddsEncoder;
+        let qos = QosProfile {
+            reliability_kind: 1,
+            time_based_filter_sec: 0,
+            time_based_filter_nsec: 100_000_000,
+            ..Default::default()
+        };
+        let guid = Guid {
+            prefix: [1; 12],
+            entity_id: [0, 0, 1, 0x07],
+        };
+        let data = SedpEndpointData {
+            endpoint_guid: guid,
+            participant_guid: Guid {
+                prefix: [1; 12],
+                entity_id: [0, 0, 1, 0xC1],
+            },
+            topic_name: "sensors",
+            type_name: "Sensor",
+            unicast_locators: &[],
+            multicast_locators: &[],
+            qos: Some(&qos),
+            type_object: None,
+            type_versions: None,
+        };
+
+        let payload = encoder.build_sedp(&data).expect("SEDP should build");
+        let parsed = crate::protocol::discovery::parse_sedp(&payload).expect("SEDP should parse");
+        let parsed_qos = parsed.qos.expect("QoS should be parsed");
+        assert_eq!(
+            parsed_qos.time_based_filter.minimum_separation,
+            std::time::Duration::from_millis(100)
+        );
+    }
 }
@@ -28,6 +28,7 @@ use std::net::SocketAddr;
 use super::error::{EncodeError, EncodeResult};
 use super::{DialectEncoder, Guid, QosProfile, SedpEndpointData};
 use crate::protocol::rtps;
+use crate::protocol::type_version::TypeVersionAnnouncement;
 
 /// Hybrid encoder - conservative fallback for unknown vendors
 pub struct HybridEncoder;
@@ -79,8 +80,8 @@ impl DialectEncoder for HybridEncoder {
     fn build_sedp(&self, data: &SedpEndpointData) -> EncodeResult<Vec<u8>> {
         // Use the certified SEDP builder directly
         use crate::core::discovery::GUID;
-        use crate::dds::qos::QoS;
         use crate::protocol::discovery::types::SedpData as LegacySedpData;
+        #[cfg(feature = "xtypes")]
         use crate::Cdr2Decode;
 
         let mut endpoint_guid_bytes = [0u8; 16];
@@ -94,21 +95,7 @@ impl DialectEncoder for HybridEncoder {
         // v176: Convert QosProfile (protocol-level, u32 values) back to DDS QoS (enums).
         // This was previously `qos: None`, causing QoS to be dropped and defaulting to
         // VOLATILE, which broke STATE/EVENT profiles that require TRANSIENT_LOCAL.
-        let qos = data.qos.map(|q| {
-            let base = match q.reliability_kind {
-                1 => QoS::best_effort(),
-                _ => QoS::reliable(),
-            };
-            let with_durability = match q.durability_kind {
-                0 => base.volatile(),
-                3 => base.persistent(),
-                _ => base.transient_local(),
-            };
-            match q.history_kind {
-                1 => with_durability.keep_all(),
-                _ => with_durability.keep_last(q.history_depth),
-            }
-        });
+        let qos = data.qos.map(QosProfile::to_qos);
 
         #[cfg(feature = "xtypes")]
         let type_object = data
@@ -128,9 +115,17 @@ impl DialectEncoder for HybridEncoder {
             type_object,
             unicast_locators: data.unicast_locators.to_vec(),
             user_data: None,
+            metadata: data.qos.map(|q| q.metadata.clone()).unwrap_or_default(),
+            type_versions: data
+                .type_versions
+                .and_then(TypeVersionAnnouncement::from_bytes),
         };
 
-        let mut buf = vec![0u8; 8192];
+        let mut buf = vec![
+            0u8;
+            8192 + data.type_versions.map_or(0, <[u8]>::len)
+                + legacy_data.metadata.len()
+        ];
         let len = crate::protocol::discovery::sedp::build::build_sedp(&legacy_data, &mut buf)
             .map_err(|_| EncodeError::BufferTooSmall)?;
         buf.truncate(len);
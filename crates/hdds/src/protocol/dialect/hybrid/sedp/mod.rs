@@ -93,6 +93,7 @@ mod tests {
             multicast_locators: &[],
             qos: None,
             type_object: None,
+            type_versions: None,
         };
 
         let result = build_sedp(&data);
@@ -125,6 +126,7 @@ mod tests {
             multicast_locators: &[],
             qos: None,
             type_object: None,
+            type_versions: None,
         };
 
         let buf = build_sedp(&data).expect("build_sedp should succeed");
@@ -7,10 +7,11 @@
 //! Should work with any RTPS 2.3+ implementation.
 //!
 
-use std::net::{IpAddr, SocketAddr};
+use std::net::SocketAddr;
 
 use crate::protocol::dialect::error::{EncodeError, EncodeResult};
 use crate::protocol::dialect::Guid;
+use crate::protocol::rtps::udp_locator_fields;
 
 /// PID constants
 mod pids {
@@ -181,24 +182,16 @@ fn write_locator(
     *offset += 4;
 
     // Locator_t: kind(4) + port(4) + address(16)
-    buf[*offset..*offset + 4].copy_from_slice(&1u32.to_le_bytes()); // UDPV4
+    let (kind, address) = udp_locator_fields(addr);
+    buf[*offset..*offset + 4].copy_from_slice(&kind.to_le_bytes());
     *offset += 4;
 
-    buf[*offset..*offset + 4].copy_from_slice(&u32::from(addr.port()).to_le_bytes());
+    let port = u32::from(addr.port());
+    buf[*offset..*offset + 4].copy_from_slice(&port.to_le_bytes());
     *offset += 4;
 
-    buf[*offset..*offset + 12].fill(0);
-    *offset += 12;
-
-    match addr.ip() {
-        IpAddr::V4(ipv4) => {
-            buf[*offset..*offset + 4].copy_from_slice(&ipv4.octets());
-        }
-        IpAddr::V6(ipv6) => {
-            buf[*offset..*offset + 4].copy_from_slice(&ipv6.octets()[12..16]);
-        }
-    }
-    *offset += 4;
+    buf[*offset..*offset + 16].copy_from_slice(&address);
+    *offset += 16;
 
     Ok(())
 }
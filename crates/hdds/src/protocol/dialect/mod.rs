@@ -88,6 +88,7 @@ mod rti;
 
 pub use error::{EncodeError, EncodeResult};
 
+use crate::protocol::discovery::EndpointMetadata;
 use std::net::SocketAddr;
 
 #[allow(dead_code)]
@@ -407,6 +408,9 @@ pub struct SedpEndpointData<'a> {
     pub multicast_locators: &'a [SocketAddr],
     pub qos: Option<&'a QosProfile>,
     pub type_object: Option<&'a [u8]>,
+    /// Encoded `TypeVersionAnnouncement` (`PID_HDDS_TYPE_VERSIONS`); only
+    /// emitted by HDDS-aware dialects
+    pub type_versions: Option<&'a [u8]>,
 }
 
 /// QoS profile for endpoint announcements
@@ -421,7 +425,12 @@ pub struct QosProfile {
     pub liveliness_kind: u32, // 0=AUTOMATIC, 1=MANUAL_BY_PARTICIPANT, 2=MANUAL_BY_TOPIC
     pub liveliness_lease_sec: u32,
     pub liveliness_lease_nsec: u32,
-    pub ownership_kind: u32, // 0=SHARED, 1=EXCLUSIVE
+    pub ownership_kind: u32,        // 0=SHARED, 1=EXCLUSIVE
+    pub time_based_filter_sec: u32, // minimum_separation (readers only)
+    pub time_based_filter_nsec: u32,
+    pub partition: Vec<String>, // PARTITION names, empty = default partition
+    /// USER_DATA, TOPIC_DATA and GROUP_DATA (empty = not announced)
+    pub metadata: EndpointMetadata,
 }
 
 impl QosProfile {
@@ -432,6 +441,32 @@ impl QosProfile {
             || self.history_kind != 0
             || self.history_depth != 1
     }
+
+    /// DDS QoS carrying this profile, for dialects using the standard
+    /// SEDP builder.
+    pub fn to_qos(&self) -> crate::dds::qos::QoS {
+        use crate::dds::qos::{Partition, QoS, TimeBasedFilter};
+
+        let base = match self.reliability_kind {
+            1 => QoS::best_effort(),
+            _ => QoS::reliable(),
+        };
+        let with_durability = match self.durability_kind {
+            0 => base.volatile(),
+            3 => base.persistent(),
+            _ => base.transient_local(),
+        };
+        let with_history = match self.history_kind {
+            1 => with_durability.keep_all(),
+            _ => with_durability.keep_last(self.history_depth),
+        };
+        with_history
+            .time_based_filter(TimeBasedFilter::new(std::time::Duration::new(
+                u64::from(self.time_based_filter_sec),
+                self.time_based_filter_nsec,
+            )))
+            .partition(Partition::new(self.partition.clone()))
+    }
 }
 
 /// Complete RTPS dialect encoder trait
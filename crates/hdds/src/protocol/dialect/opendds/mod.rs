@@ -93,6 +93,7 @@ impl DialectEncoder for OpenDdsEncoder {
             default_multicast_locators: multicast_locators.to_vec(),
             metatraffic_multicast_locators: multicast_locators.to_vec(),
             identity_token: None,
+            user_data: Default::default(),
         };
 
         let mut buf = vec![0u8; 2048];
@@ -150,6 +150,7 @@ mod tests {
             multicast_locators: &[],
             qos: None,
             type_object: None,
+            type_versions: None,
         };
 
         let result = build_sedp(&data);
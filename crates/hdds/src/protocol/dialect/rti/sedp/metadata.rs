@@ -27,6 +27,7 @@ mod pids {
     pub const PID_KEY_HASH: u16 = 0x0070;
     pub const PID_TOPIC_NAME: u16 = 0x0005;
     pub const PID_TYPE_NAME: u16 = 0x0007;
+    pub const PID_ENTITY_NAME: u16 = 0x0062;
     pub const PID_PROTOCOL_VERSION: u16 = 0x0015;
     pub const PID_VENDOR_ID: u16 = 0x0016;
     pub const PID_EXPECTS_INLINE_QOS: u16 = 0x0043;
@@ -160,6 +161,11 @@ pub fn write_type_name(type_name: &str, buf: &mut [u8], offset: &mut usize) -> E
     write_string_param(pids::PID_TYPE_NAME, type_name, buf, offset)
 }
 
+/// Write PID_ENTITY_NAME (0x0062)
+pub fn write_entity_name(name: &str, buf: &mut [u8], offset: &mut usize) -> EncodeResult<()> {
+    write_string_param(pids::PID_ENTITY_NAME, name, buf, offset)
+}
+
 /// Write PID_PROTOCOL_VERSION (0x0015) - 4 bytes
 /// Use RTPS v2.3 to match RTI capture and minimize surprises.
 pub fn write_protocol_version(buf: &mut [u8], offset: &mut usize) -> EncodeResult<()> {
@@ -21,7 +21,7 @@
 //! 1. PID_ENDPOINT_GUID (0x005a) - FIRST, RTI validates this
 //! 2. PID_PARTICIPANT_GUID (0x0050)
 //! 3. PID_KEY_HASH (0x0070) - MANDATORY for RTI
-//! 4. PID_TOPIC_NAME (0x0005), PID_TYPE_NAME (0x0007)
+//! 4. PID_TOPIC_NAME (0x0005), PID_TYPE_NAME (0x0007), PID_ENTITY_NAME (0x0062) if set
 //! 5. PID_PROTOCOL_VERSION (0x0015), PID_VENDOR_ID (0x0016)
 //! 6. PID_UNICAST_LOCATOR (0x002f)
 //! 7. Core QoS PIDs (RELIABILITY, DURABILITY, HISTORY, etc.)
@@ -56,8 +56,8 @@ mod pids {
 /// Does NOT include RTI vendor-specific PIDs (0x8000+).
 // @audit-ok: Sequential builder (cyclo 28, cogni 2) - linear write_xxx calls without complex branching
 pub fn build_sedp(data: &SedpEndpointData) -> EncodeResult<Vec<u8>> {
-    // Pre-allocate buffer (1KB - minimal SEDP)
-    let mut buf = vec![0u8; 1024];
+    // Pre-allocate buffer (1KB - minimal SEDP, plus USER/TOPIC/GROUP_DATA and ENTITY_NAME)
+    let mut buf = vec![0u8; 1024 + data.qos.map_or(0, |q| q.metadata.len())];
     let mut offset = 0;
 
     // CDR encapsulation header (PL_CDR_LE = 0x0003)
@@ -95,6 +95,9 @@ pub fn build_sedp(data: &SedpEndpointData) -> EncodeResult<Vec<u8>> {
     // String parameters
     metadata::write_topic_name(data.topic_name, &mut buf, &mut offset)?;
     metadata::write_type_name(data.type_name, &mut buf, &mut offset)?;
+    if let Some(name) = data.qos.and_then(|q| q.metadata.entity_name.as_deref()) {
+        metadata::write_entity_name(name, &mut buf, &mut offset)?;
+    }
 
     // PID_KEY_HASH - MANDATORY for RTI
     metadata::write_key_hash(&data.endpoint_guid, &mut buf, &mut offset)?;
@@ -116,13 +119,13 @@ pub fn build_sedp(data: &SedpEndpointData) -> EncodeResult<Vec<u8>> {
     qos::write_liveliness(&mut buf, &mut offset)?;
     qos::write_reliability(data.qos, &mut buf, &mut offset)?;
     qos::write_lifespan(&mut buf, &mut offset)?;
-    qos::write_user_data(&mut buf, &mut offset)?;
+    qos::write_user_data(data.qos, &mut buf, &mut offset)?;
     qos::write_ownership(&mut buf, &mut offset)?;
     qos::write_destination_order(&mut buf, &mut offset)?;
     qos::write_presentation(&mut buf, &mut offset)?;
-    qos::write_partition(&mut buf, &mut offset)?;
-    qos::write_topic_data(&mut buf, &mut offset)?;
-    qos::write_group_data(&mut buf, &mut offset)?;
+    qos::write_partition(data.qos, &mut buf, &mut offset)?;
+    qos::write_topic_data(data.qos, &mut buf, &mut offset)?;
+    qos::write_group_data(data.qos, &mut buf, &mut offset)?;
     // Note: We intentionally skip PID_HISTORY as FastDDS doesn't send it for subscribers
 
     // PID_TYPE_CONSISTENCY (0x0074) - FastDDS sends this after all QoS PIDs
@@ -226,7 +229,8 @@ fn write_type_object_lb(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::protocol::dialect::Guid;
+    use crate::protocol::dialect::{Guid, QosProfile};
+    use crate::protocol::discovery::EndpointMetadata;
     use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
 
     fn test_guid() -> Guid {
@@ -261,6 +265,7 @@ mod tests {
             multicast_locators: &[],
             qos: None,
             type_object: None,
+            type_versions: None,
         };
 
         let result = build_sedp(&data);
@@ -282,6 +287,7 @@ mod tests {
             multicast_locators: &[],
             qos: None,
             type_object: None,
+            type_versions: None,
         };
 
         let buf = build_sedp(&data).expect("build_sedp should succeed");
@@ -291,4 +297,59 @@ mod tests {
         let found = buf.windows(2).position(|w| w == pid_bytes);
         assert!(found.is_some(), "PID_KEY_HASH must be present for RTI");
     }
+
+    #[test]
+    fn test_rti_announces_partition() {
+        let qos = QosProfile {
+            reliability_kind: 2,
+            partition: vec!["bench/*".to_string(), "cell7".to_string()],
+            ..Default::default()
+        };
+        let data = SedpEndpointData {
+            endpoint_guid: test_guid(),
+            participant_guid: test_participant_guid(),
+            topic_name: "T",
+            type_name: "T",
+            unicast_locators: &[],
+            multicast_locators: &[],
+            qos: Some(&qos),
+            type_object: None,
+            type_versions: None,
+        };
+
+        let buf = build_sedp(&data).expect("build_sedp should succeed");
+        let parsed = crate::protocol::discovery::parse_sedp(&buf).expect("parse_sedp");
+        let parsed_qos = parsed.qos.expect("QoS should be parsed");
+        assert_eq!(parsed_qos.partition.names, qos.partition);
+    }
+
+    #[test]
+    fn test_rti_announces_entity_name() {
+        let qos = QosProfile {
+            reliability_kind: 2,
+            metadata: EndpointMetadata {
+                entity_name: Some("imu_publisher".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let data = SedpEndpointData {
+            endpoint_guid: test_guid(),
+            participant_guid: test_participant_guid(),
+            topic_name: "T",
+            type_name: "T",
+            unicast_locators: &[],
+            multicast_locators: &[],
+            qos: Some(&qos),
+            type_object: None,
+            type_versions: None,
+        };
+
+        let buf = build_sedp(&data).expect("build_sedp should succeed");
+        let parsed = crate::protocol::discovery::parse_sedp(&buf).expect("parse_sedp");
+        assert_eq!(
+            parsed.metadata.entity_name.as_deref(),
+            Some("imu_publisher")
+        );
+    }
 }
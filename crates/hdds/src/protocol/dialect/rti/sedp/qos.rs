@@ -183,17 +183,38 @@ pub fn write_time_based_filter(buf: &mut [u8], offset: &mut usize) -> EncodeResu
     Ok(())
 }
 
-/// Write PID_PARTITION (0x0029) - 4 bytes (empty sequence)
-pub fn write_partition(buf: &mut [u8], offset: &mut usize) -> EncodeResult<()> {
-    if *offset + 8 > buf.len() {
+/// Write PID_PARTITION (0x0029) - sequence<string> of partition names
+///
+/// An empty sequence (4 bytes) is the default partition.
+pub fn write_partition(
+    qos: Option<&QosProfile>,
+    buf: &mut [u8],
+    offset: &mut usize,
+) -> EncodeResult<()> {
+    let names: &[String] = qos.map(|q| q.partition.as_slice()).unwrap_or(&[]);
+    let payload_len = names
+        .iter()
+        .fold(4usize, |len, name| len + 4 + ((name.len() + 1 + 3) & !3));
+    let param_len = u16::try_from(payload_len)
+        .map_err(|_| EncodeError::InvalidParameter("partition names too long".into()))?;
+    if *offset + 4 + payload_len > buf.len() {
         return Err(EncodeError::BufferTooSmall);
     }
 
     buf[*offset..*offset + 2].copy_from_slice(&pids::PID_PARTITION.to_le_bytes());
-    buf[*offset + 2..*offset + 4].copy_from_slice(&4u16.to_le_bytes());
-    buf[*offset + 4..*offset + 8].copy_from_slice(&0u32.to_le_bytes()); // empty
+    buf[*offset + 2..*offset + 4].copy_from_slice(&param_len.to_le_bytes());
+    buf[*offset + 4..*offset + 8].copy_from_slice(&(names.len() as u32).to_le_bytes());
     *offset += 8;
 
+    for name in names {
+        let len_with_null = name.len() + 1;
+        let padded_len = (len_with_null + 3) & !3;
+        buf[*offset..*offset + 4].copy_from_slice(&(len_with_null as u32).to_le_bytes());
+        buf[*offset + 4..*offset + 4 + name.len()].copy_from_slice(name.as_bytes());
+        buf[*offset + 4 + name.len()..*offset + 4 + padded_len].fill(0);
+        *offset += 4 + padded_len;
+    }
+
     Ok(())
 }
 
@@ -273,18 +294,14 @@ pub fn write_lifespan(buf: &mut [u8], offset: &mut usize) -> EncodeResult<()> {
     Ok(())
 }
 
-/// Write PID_USER_DATA (0x002c) - 4 bytes (empty sequence)
-pub fn write_user_data(buf: &mut [u8], offset: &mut usize) -> EncodeResult<()> {
-    if *offset + 8 > buf.len() {
-        return Err(EncodeError::BufferTooSmall);
-    }
-
-    buf[*offset..*offset + 2].copy_from_slice(&pids::PID_USER_DATA.to_le_bytes());
-    buf[*offset + 2..*offset + 4].copy_from_slice(&4u16.to_le_bytes());
-    buf[*offset + 4..*offset + 8].copy_from_slice(&0u32.to_le_bytes()); // sequenceSize = 0
-    *offset += 8;
-
-    Ok(())
+/// Write PID_USER_DATA (0x002c) - sequence<octet> from the USER_DATA policy
+pub fn write_user_data(
+    qos: Option<&QosProfile>,
+    buf: &mut [u8],
+    offset: &mut usize,
+) -> EncodeResult<()> {
+    let data = qos.map_or(&[][..], |q| q.metadata.user_data.value.as_slice());
+    write_octet_seq(pids::PID_USER_DATA, data, buf, offset)
 }
 
 /// Write PID_DESTINATION_ORDER (0x0025) - 4 bytes
@@ -319,30 +336,41 @@ pub fn write_presentation(buf: &mut [u8], offset: &mut usize) -> EncodeResult<()
     Ok(())
 }
 
-/// Write PID_TOPIC_DATA (0x002e) - 4 bytes (empty sequence)
-pub fn write_topic_data(buf: &mut [u8], offset: &mut usize) -> EncodeResult<()> {
-    if *offset + 8 > buf.len() {
-        return Err(EncodeError::BufferTooSmall);
-    }
-
-    buf[*offset..*offset + 2].copy_from_slice(&pids::PID_TOPIC_DATA.to_le_bytes());
-    buf[*offset + 2..*offset + 4].copy_from_slice(&4u16.to_le_bytes());
-    buf[*offset + 4..*offset + 8].copy_from_slice(&0u32.to_le_bytes()); // sequenceSize = 0
-    *offset += 8;
+/// Write PID_TOPIC_DATA (0x002e) - sequence<octet> from the TOPIC_DATA policy
+pub fn write_topic_data(
+    qos: Option<&QosProfile>,
+    buf: &mut [u8],
+    offset: &mut usize,
+) -> EncodeResult<()> {
+    let data = qos.map_or(&[][..], |q| q.metadata.topic_data.value.as_slice());
+    write_octet_seq(pids::PID_TOPIC_DATA, data, buf, offset)
+}
 
-    Ok(())
+/// Write PID_GROUP_DATA (0x002d) - sequence<octet> from the GROUP_DATA policy
+pub fn write_group_data(
+    qos: Option<&QosProfile>,
+    buf: &mut [u8],
+    offset: &mut usize,
+) -> EncodeResult<()> {
+    let data = qos.map_or(&[][..], |q| q.metadata.group_data.value.as_slice());
+    write_octet_seq(pids::PID_GROUP_DATA, data, buf, offset)
 }
 
-/// Write PID_GROUP_DATA (0x002d) - 4 bytes (empty sequence)
-pub fn write_group_data(buf: &mut [u8], offset: &mut usize) -> EncodeResult<()> {
-    if *offset + 8 > buf.len() {
+/// Write an octet sequence parameter: length (u32) + bytes, padded to 4 bytes.
+fn write_octet_seq(pid: u16, data: &[u8], buf: &mut [u8], offset: &mut usize) -> EncodeResult<()> {
+    let payload_len = 4 + ((data.len() + 3) & !3);
+    let param_len = u16::try_from(payload_len)
+        .map_err(|_| EncodeError::InvalidParameter("octet sequence too long".into()))?;
+    if *offset + 4 + payload_len > buf.len() {
         return Err(EncodeError::BufferTooSmall);
     }
 
-    buf[*offset..*offset + 2].copy_from_slice(&pids::PID_GROUP_DATA.to_le_bytes());
-    buf[*offset + 2..*offset + 4].copy_from_slice(&4u16.to_le_bytes());
-    buf[*offset + 4..*offset + 8].copy_from_slice(&0u32.to_le_bytes()); // sequenceSize = 0
-    *offset += 8;
+    buf[*offset..*offset + 2].copy_from_slice(&pid.to_le_bytes());
+    buf[*offset + 2..*offset + 4].copy_from_slice(&param_len.to_le_bytes());
+    buf[*offset + 4..*offset + 8].copy_from_slice(&(data.len() as u32).to_le_bytes()); // sequenceSize
+    buf[*offset + 8..*offset + 8 + data.len()].copy_from_slice(data);
+    buf[*offset + 8 + data.len()..*offset + 4 + payload_len].fill(0);
+    *offset += 4 + payload_len;
 
     Ok(())
 }
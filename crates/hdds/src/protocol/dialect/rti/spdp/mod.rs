@@ -18,6 +18,7 @@ use std::net::SocketAddr;
 
 use crate::protocol::dialect::error::{EncodeError, EncodeResult};
 use crate::protocol::dialect::Guid;
+use crate::protocol::rtps::udp_locator_fields;
 
 /// PID constants for SPDP encoding
 mod pids {
@@ -216,25 +217,17 @@ fn write_locator(
     buf[*offset + 2..*offset + 4].copy_from_slice(&24u16.to_le_bytes());
     *offset += 4;
 
-    buf[*offset..*offset + 4].copy_from_slice(&1u32.to_le_bytes()); // UDPV4
+    // Locator_t: kind(4) + port(4) + address(16)
+    let (kind, address) = udp_locator_fields(addr);
+    buf[*offset..*offset + 4].copy_from_slice(&kind.to_le_bytes());
     *offset += 4;
 
     let port = u32::from(addr.port());
     buf[*offset..*offset + 4].copy_from_slice(&port.to_le_bytes());
     *offset += 4;
 
-    buf[*offset..*offset + 12].fill(0);
-    *offset += 12;
-
-    match addr {
-        SocketAddr::V4(v4) => {
-            buf[*offset..*offset + 4].copy_from_slice(&v4.ip().octets());
-        }
-        SocketAddr::V6(_) => {
-            buf[*offset..*offset + 4].fill(0);
-        }
-    }
-    *offset += 4;
+    buf[*offset..*offset + 16].copy_from_slice(&address);
+    *offset += 16;
 
     Ok(())
 }
@@ -16,6 +16,8 @@ pub(super) const PID_TOPIC_NAME: u16 = 0x0005; // v109: REVERTED - Correct per R
 pub(super) const PID_TYPE_NAME: u16 = 0x0007; // v109: REVERTED - Correct per RTPS v2.5 spec line 13326-13328
 pub(super) const PID_DOMAIN_ID: u16 = 0x000f; // v80: Domain ID (RTPS v2.3 Table 8.73 - mandatory)
 pub(super) const PID_USER_DATA: u16 = 0x002c; // DDS-RTPS Sec.9.6.2.2.1 - UserDataQosPolicy
+pub(super) const PID_GROUP_DATA: u16 = 0x002d; // DDS-RTPS Sec.9.6.2.2.1 - GroupDataQosPolicy
+pub(super) const PID_TOPIC_DATA: u16 = 0x002e; // DDS-RTPS Sec.9.6.2.2.1 - TopicDataQosPolicy
 pub(super) const PID_PROTOCOL_VERSION: u16 = 0x0015;
 pub(super) const PID_VENDOR_ID: u16 = 0x0016;
 pub(super) const PID_ENDPOINT_GUID: u16 = 0x005a;
@@ -44,6 +46,7 @@ pub(super) const PID_PARTITION: u16 = 0x0029;
 pub(super) const PID_TIME_BASED_FILTER: u16 = 0x0004;
 pub(super) const PID_DURABILITY_SERVICE: u16 = 0x001E; // DDS v1.4 Sec.2.2.3.5
 pub(super) const PID_PRESENTATION: u16 = 0x0021; // DDS v1.4 Sec.2.2.3.6
+pub(super) const PID_DESTINATION_ORDER: u16 = 0x0025; // DDS v1.4 Sec.2.2.3.17
 
 // SEDP locator parameters - required for user data delivery
 pub(super) const PID_UNICAST_LOCATOR: u16 = 0x002f;
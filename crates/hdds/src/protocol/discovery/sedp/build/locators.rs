@@ -14,7 +14,8 @@
 
 use super::super::super::constants::PID_UNICAST_LOCATOR;
 use super::super::super::types::ParseError;
-use std::net::{IpAddr, SocketAddr};
+use crate::protocol::rtps::udp_locator_fields;
+use std::net::SocketAddr;
 
 /// Write PID_UNICAST_LOCATOR (0x002f) - announces where to send user data.
 ///
@@ -36,26 +37,17 @@ pub fn write_unicast_locator(
     buf[*offset + 2..*offset + 4].copy_from_slice(&24u16.to_le_bytes()); // locator size
     *offset += 4;
 
-    // Locator_t: kind (4 bytes) + port (4 bytes) + address (16 bytes)
-    buf[*offset..*offset + 4].copy_from_slice(&1u32.to_le_bytes()); // LOCATOR_KIND_UDPV4 = 1
+    // Locator_t: kind(4) + port(4) + address(16)
+    let (kind, address) = udp_locator_fields(locator);
+    buf[*offset..*offset + 4].copy_from_slice(&kind.to_le_bytes());
     *offset += 4;
 
     let port = u32::from(locator.port());
     buf[*offset..*offset + 4].copy_from_slice(&port.to_le_bytes());
     *offset += 4;
 
-    // IPv4 address in last 4 bytes of 16-byte address field
-    buf[*offset..*offset + 12].fill(0);
-    *offset += 12;
-    match locator.ip() {
-        IpAddr::V4(ipv4) => {
-            buf[*offset..*offset + 4].copy_from_slice(&ipv4.octets());
-        }
-        IpAddr::V6(_) => {
-            buf[*offset..*offset + 4].fill(0);
-        }
-    }
-    *offset += 4;
+    buf[*offset..*offset + 16].copy_from_slice(&address);
+    *offset += 16;
 
     Ok(())
 }
@@ -79,7 +71,7 @@ pub fn write_unicast_locators(
 mod tests {
     use super::*;
     use std::convert::TryInto;
-    use std::net::{Ipv4Addr, SocketAddrV4};
+    use std::net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
 
     #[test]
     fn locator_fields_use_little_endian() {
@@ -113,4 +105,21 @@ mod tests {
             "IPv4 octets must live in the tail of the address field"
         );
     }
+
+    #[test]
+    fn ipv6_locator_uses_udpv6_kind() {
+        let mut buf = [0u8; 64];
+        let mut offset = 0;
+        let ip = Ipv6Addr::new(0xfd00, 0, 0, 0, 0, 0, 0, 2);
+        let addr = SocketAddr::from(SocketAddrV6::new(ip, 7411, 0, 0));
+
+        write_unicast_locator(&addr, &mut buf, &mut offset).expect("write locator");
+
+        assert_eq!(offset, 28);
+        assert_eq!(
+            u32::from_le_bytes(buf[4..8].try_into().expect("kind bytes")),
+            2
+        );
+        assert_eq!(&buf[12..28], &ip.octets());
+    }
 }
@@ -20,8 +20,10 @@ mod metadata;
 /// QoS PID serialization for SEDP endpoint discovery.
 pub mod qos;
 
+use crate::protocol::constants::PID_HDDS_TYPE_VERSIONS;
 use crate::protocol::discovery::constants::{PID_SENTINEL, PID_TYPE_OBJECT};
 use crate::protocol::discovery::types::{ParseError, SedpData};
+use crate::protocol::type_version::TypeVersionAnnouncement;
 use crate::xtypes::CompleteTypeObject;
 use crate::Cdr2Encode;
 use std::convert::TryFrom;
@@ -52,10 +54,12 @@ use std::convert::TryFrom;
 /// 6. PID_GROUP_ENTITY_ID - Publisher/Subscriber ownership
 /// 7. PID_ENTITY_VIRTUAL_GUID, PID_EXPECTS_VIRTUAL_HB - RTI vendor PIDs
 /// 8. PID_TYPE_CONSISTENCY, PID_ENDPOINT_PROPERTY_CHANGE_EPOCH - XTypes compatibility
-/// 9. QoS PIDs - RELIABILITY, DURABILITY, HISTORY, DEADLINE, OWNERSHIP, LIVELINESS, etc.
+/// 9. QoS PIDs - RELIABILITY, DURABILITY, HISTORY, DEADLINE, OWNERSHIP, LIVELINESS, etc.,
+///    then USER_DATA, TOPIC_DATA, GROUP_DATA and ENTITY_NAME when set
 /// 10. PID_UNICAST_LOCATOR - Network locators
 /// 11. PID_TYPE_OBJECT - TypeObject CDR2 (if present)
-/// 12. PID_SENTINEL - Terminator
+/// 12. PID_HDDS_TYPE_VERSIONS - Side-by-side type versions (if present)
+/// 13. PID_SENTINEL - Terminator
 pub fn build_sedp(sedp_data: &SedpData, buf: &mut [u8]) -> Result<usize, ParseError> {
     let mut offset = 0;
     // NOTE: This legacy builder is only used by tests now. Production code uses
@@ -138,10 +142,12 @@ pub fn build_sedp(sedp_data: &SedpData, buf: &mut [u8]) -> Result<usize, ParseEr
     qos::write_deadline(buf, &mut offset)?;
     qos::write_ownership(buf, &mut offset)?;
     qos::write_liveliness(buf, &mut offset)?;
-    qos::write_time_based_filter(buf, &mut offset)?;
-    qos::write_partition(buf, &mut offset)?;
+    qos::write_destination_order(sedp_data.qos.as_ref(), buf, &mut offset)?;
+    qos::write_time_based_filter(sedp_data.qos.as_ref(), buf, &mut offset)?;
+    qos::write_partition(sedp_data.qos.as_ref(), buf, &mut offset)?;
     qos::write_resource_limits(sedp_data.qos.as_ref(), buf, &mut offset)?;
     qos::write_presentation(sedp_data.qos.as_ref(), buf, &mut offset)?;
+    qos::write_metadata(&sedp_data.metadata, buf, &mut offset)?;
 
     // ===== TYPE OBJECT SECTION =====
     // PID_TYPE_OBJECT (0x0072) - CDR2-encoded CompleteTypeObject
@@ -149,6 +155,11 @@ pub fn build_sedp(sedp_data: &SedpData, buf: &mut [u8]) -> Result<usize, ParseEr
         write_type_object(type_obj, buf, &mut offset)?;
     }
 
+    // PID_HDDS_TYPE_VERSIONS (0x8006) - vendor PID, self-identified by magic
+    if let Some(ref versions) = sedp_data.type_versions {
+        write_type_versions(versions, buf, &mut offset)?;
+    }
+
     // ===== SENTINEL =====
     if offset + 4 > buf.len() {
         return Err(ParseError::BufferTooSmall);
@@ -160,6 +171,27 @@ pub fn build_sedp(sedp_data: &SedpData, buf: &mut [u8]) -> Result<usize, ParseEr
     Ok(offset)
 }
 
+/// Write PID_HDDS_TYPE_VERSIONS (0x8006) with the encoded announcement.
+fn write_type_versions(
+    versions: &TypeVersionAnnouncement,
+    buf: &mut [u8],
+    offset: &mut usize,
+) -> Result<(), ParseError> {
+    let payload = versions.to_bytes();
+    let payload_len = u16::try_from(payload.len()).map_err(|_| ParseError::InvalidFormat)?;
+    if *offset + 4 + payload.len() > buf.len() {
+        return Err(ParseError::BufferTooSmall);
+    }
+
+    buf[*offset..*offset + 2].copy_from_slice(&PID_HDDS_TYPE_VERSIONS.to_le_bytes());
+    buf[*offset + 2..*offset + 4].copy_from_slice(&payload_len.to_le_bytes());
+    *offset += 4;
+    buf[*offset..*offset + payload.len()].copy_from_slice(&payload);
+    *offset += payload.len();
+
+    Ok(())
+}
+
 /// Write PID_TYPE_OBJECT (0x0072) with CDR2-encoded TypeObject payload.
 fn write_type_object(
     type_obj: &CompleteTypeObject,
@@ -10,18 +10,23 @@
 //! - PID_DEADLINE (0x0023) - Deadline QoS
 //! - PID_OWNERSHIP (0x001f) - Ownership QoS (SHARED/EXCLUSIVE)
 //! - PID_LIVELINESS (0x001b) - Liveliness QoS
+//! - PID_DESTINATION_ORDER (0x0025) - Destination order QoS
 //! - PID_TIME_BASED_FILTER (0x0004) - Time-based filter QoS
 //! - PID_PARTITION (0x0029) - Partition QoS
 //! - PID_RESOURCE_LIMITS (0x0041) - Resource limits QoS
 //! - PID_DURABILITY_SERVICE (0x001e) - Durability service QoS
+//! - PID_USER_DATA (0x002c), PID_TOPIC_DATA (0x002e), PID_GROUP_DATA (0x002d) - Metadata QoS
+//! - PID_ENTITY_NAME (0x0062) - Endpoint name
 
 use super::super::super::constants::{
-    PID_DEADLINE, PID_DURABILITY, PID_DURABILITY_SERVICE, PID_HISTORY, PID_LIVELINESS,
-    PID_OWNERSHIP, PID_PARTITION, PID_PRESENTATION, PID_RELIABILITY, PID_RESOURCE_LIMITS,
-    PID_TIME_BASED_FILTER,
+    PID_DEADLINE, PID_DESTINATION_ORDER, PID_DURABILITY, PID_DURABILITY_SERVICE, PID_ENTITY_NAME,
+    PID_GROUP_DATA, PID_HISTORY, PID_LIVELINESS, PID_OWNERSHIP, PID_PARTITION, PID_PRESENTATION,
+    PID_RELIABILITY, PID_RESOURCE_LIMITS, PID_TIME_BASED_FILTER, PID_TOPIC_DATA, PID_USER_DATA,
+};
+use super::super::super::types::{EndpointMetadata, ParseError};
+use crate::dds::qos::{
+    DestinationOrderKind, Durability, History, PresentationAccessScope, QoS, Reliability,
 };
-use super::super::super::types::ParseError;
-use crate::dds::qos::{Durability, History, PresentationAccessScope, QoS, Reliability};
 
 /// Write PID_RELIABILITY (0x001a) - 12 bytes.
 /// Format: kind (u32) + max_blocking_time (Duration_t = 2xu32).
@@ -150,6 +155,31 @@ pub fn write_ownership(buf: &mut [u8], offset: &mut usize) -> Result<(), ParseEr
     Ok(())
 }
 
+/// Write PID_DESTINATION_ORDER (0x0025) - 4 bytes.
+/// Format: kind (u32).
+/// BY_RECEPTION_TIMESTAMP=0, BY_SOURCE_TIMESTAMP=1.
+pub fn write_destination_order(
+    qos: Option<&QoS>,
+    buf: &mut [u8],
+    offset: &mut usize,
+) -> Result<(), ParseError> {
+    if *offset + 8 > buf.len() {
+        return Err(ParseError::BufferTooSmall);
+    }
+
+    let kind = match qos.map(|qos| qos.destination_order.kind) {
+        Some(DestinationOrderKind::BySourceTimestamp) => 1u32,
+        _ => 0u32,
+    };
+
+    buf[*offset..*offset + 2].copy_from_slice(&PID_DESTINATION_ORDER.to_le_bytes());
+    buf[*offset + 2..*offset + 4].copy_from_slice(&4u16.to_le_bytes());
+    buf[*offset + 4..*offset + 8].copy_from_slice(&kind.to_le_bytes());
+    *offset += 8;
+
+    Ok(())
+}
+
 /// Write PID_LIVELINESS (0x001b) - 12 bytes.
 /// Format: kind (u32) + lease_duration (Duration_t = 2xu32).
 /// Default: AUTOMATIC (kind=0), lease_duration=INFINITE.
@@ -174,33 +204,107 @@ pub fn write_liveliness(buf: &mut [u8], offset: &mut usize) -> Result<(), ParseE
 /// Write PID_TIME_BASED_FILTER (0x0004) - 8 bytes.
 /// Format: minimum_separation (Duration_t = 2xu32).
 /// Default: 0 (no filtering).
-pub fn write_time_based_filter(buf: &mut [u8], offset: &mut usize) -> Result<(), ParseError> {
+pub fn write_time_based_filter(
+    qos: Option<&QoS>,
+    buf: &mut [u8],
+    offset: &mut usize,
+) -> Result<(), ParseError> {
     if *offset + 12 > buf.len() {
         return Err(ParseError::BufferTooSmall);
     }
 
+    let separation = qos
+        .map(|q| q.time_based_filter.minimum_separation)
+        .unwrap_or_default();
+    let sec = u32::try_from(separation.as_secs()).unwrap_or(u32::MAX);
+    let nanosec = separation.subsec_nanos();
+
     buf[*offset..*offset + 2].copy_from_slice(&PID_TIME_BASED_FILTER.to_le_bytes());
     buf[*offset + 2..*offset + 4].copy_from_slice(&8u16.to_le_bytes());
-    buf[*offset + 4..*offset + 8].copy_from_slice(&0u32.to_le_bytes()); // minimum_separation.sec = 0
-    buf[*offset + 8..*offset + 12].copy_from_slice(&0u32.to_le_bytes()); // minimum_separation.nanosec = 0
+    buf[*offset + 4..*offset + 8].copy_from_slice(&sec.to_le_bytes()); // minimum_separation.sec
+    buf[*offset + 8..*offset + 12].copy_from_slice(&nanosec.to_le_bytes()); // minimum_separation.nanosec
     *offset += 12;
 
     Ok(())
 }
 
-/// Write PID_PARTITION (0x0029) - empty sequence.
-/// Format: sequence_length (u32).
-/// Default: empty (no partitions).
-pub fn write_partition(buf: &mut [u8], offset: &mut usize) -> Result<(), ParseError> {
-    if *offset + 8 > buf.len() {
+/// Write PID_PARTITION (0x0029) - sequence of partition names.
+/// Format: sequence_length (u32) + per name: length (u32, incl. NUL) + chars + NUL,
+/// padded to 4 bytes.
+/// Default: empty (default partition).
+pub fn write_partition(
+    qos: Option<&QoS>,
+    buf: &mut [u8],
+    offset: &mut usize,
+) -> Result<(), ParseError> {
+    let names: &[String] = qos.map(|q| q.partition.names.as_slice()).unwrap_or(&[]);
+    let payload_len = names
+        .iter()
+        .fold(4usize, |len, name| len + 4 + ((name.len() + 1 + 3) & !3));
+    let param_len = u16::try_from(payload_len).map_err(|_| ParseError::InvalidFormat)?;
+    let count = u32::try_from(names.len()).map_err(|_| ParseError::InvalidFormat)?;
+    if *offset + 4 + payload_len > buf.len() {
         return Err(ParseError::BufferTooSmall);
     }
 
     buf[*offset..*offset + 2].copy_from_slice(&PID_PARTITION.to_le_bytes());
-    buf[*offset + 2..*offset + 4].copy_from_slice(&4u16.to_le_bytes());
-    buf[*offset + 4..*offset + 8].copy_from_slice(&0u32.to_le_bytes()); // sequence_length = 0 (empty)
+    buf[*offset + 2..*offset + 4].copy_from_slice(&param_len.to_le_bytes());
+    buf[*offset + 4..*offset + 8].copy_from_slice(&count.to_le_bytes()); // sequence_length
     *offset += 8;
 
+    for name in names {
+        let len_with_null = name.len() + 1;
+        let padded_len = (len_with_null + 3) & !3;
+        buf[*offset..*offset + 4].copy_from_slice(&(len_with_null as u32).to_le_bytes());
+        buf[*offset + 4..*offset + 4 + name.len()].copy_from_slice(name.as_bytes());
+        // NUL terminator and padding
+        buf[*offset + 4 + name.len()..*offset + 4 + padded_len].fill(0);
+        *offset += 4 + padded_len;
+    }
+
+    Ok(())
+}
+
+/// Write PID_USER_DATA, PID_TOPIC_DATA, PID_GROUP_DATA and PID_ENTITY_NAME.
+/// Empty policies and an unset name are left out.
+pub fn write_metadata(
+    metadata: &EndpointMetadata,
+    buf: &mut [u8],
+    offset: &mut usize,
+) -> Result<(), ParseError> {
+    write_octet_seq(PID_USER_DATA, &metadata.user_data.value, buf, offset)?;
+    write_octet_seq(PID_TOPIC_DATA, &metadata.topic_data.value, buf, offset)?;
+    write_octet_seq(PID_GROUP_DATA, &metadata.group_data.value, buf, offset)?;
+    if let Some(name) = &metadata.entity_name {
+        super::metadata::write_string_param(PID_ENTITY_NAME, name, buf, offset)?;
+    }
+    Ok(())
+}
+
+/// Write an octet sequence parameter, skipped when `data` is empty.
+/// Format: length (u32) + bytes, padded to 4 bytes.
+pub fn write_octet_seq(
+    pid: u16,
+    data: &[u8],
+    buf: &mut [u8],
+    offset: &mut usize,
+) -> Result<(), ParseError> {
+    if data.is_empty() {
+        return Ok(());
+    }
+    let payload_len = 4 + ((data.len() + 3) & !3);
+    let param_len = u16::try_from(payload_len).map_err(|_| ParseError::InvalidFormat)?;
+    if *offset + 4 + payload_len > buf.len() {
+        return Err(ParseError::BufferTooSmall);
+    }
+
+    buf[*offset..*offset + 2].copy_from_slice(&pid.to_le_bytes());
+    buf[*offset + 2..*offset + 4].copy_from_slice(&param_len.to_le_bytes());
+    buf[*offset + 4..*offset + 8].copy_from_slice(&(data.len() as u32).to_le_bytes());
+    buf[*offset + 8..*offset + 8 + data.len()].copy_from_slice(data);
+    buf[*offset + 8 + data.len()..*offset + 4 + payload_len].fill(0);
+    *offset += 4 + payload_len;
+
     Ok(())
 }
 
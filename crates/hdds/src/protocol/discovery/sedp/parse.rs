@@ -13,16 +13,19 @@
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
 
 use crate::core::discovery::GUID;
+use crate::protocol::constants::PID_HDDS_TYPE_VERSIONS;
 use crate::protocol::discovery::constants::{
     CDR2_BE, CDR2_LE, CDR_BE, CDR_BE_VENDOR, CDR_LE, CDR_LE_VENDOR, PID_BUILTIN_ENDPOINT_SET,
-    PID_DATA_REPRESENTATION, PID_DURABILITY, PID_DURABILITY_SERVICE, PID_ENDPOINT_GUID,
-    PID_HISTORY, PID_METATRAFFIC_UNICAST_LOCATOR, PID_PARTICIPANT_GUID,
-    PID_PARTICIPANT_LEASE_DURATION, PID_PRESENTATION, PID_RELIABILITY, PID_SENTINEL,
-    PID_TOPIC_NAME, PID_TYPE_NAME, PID_TYPE_OBJECT, PID_TYPE_OBJECT_LB, PID_UNICAST_LOCATOR,
-    PID_USER_DATA,
+    PID_DATA_REPRESENTATION, PID_DESTINATION_ORDER, PID_DURABILITY, PID_DURABILITY_SERVICE,
+    PID_ENDPOINT_GUID, PID_ENTITY_NAME, PID_GROUP_DATA, PID_HISTORY,
+    PID_METATRAFFIC_UNICAST_LOCATOR, PID_PARTICIPANT_GUID, PID_PARTICIPANT_LEASE_DURATION,
+    PID_PARTITION, PID_PRESENTATION, PID_RELIABILITY, PID_SENTINEL, PID_TIME_BASED_FILTER,
+    PID_TOPIC_DATA, PID_TOPIC_NAME, PID_TYPE_NAME, PID_TYPE_OBJECT, PID_TYPE_OBJECT_LB,
+    PID_UNICAST_LOCATOR, PID_USER_DATA,
 };
 use crate::protocol::discovery::hash::simple_hash;
-use crate::protocol::discovery::types::{ParseError, SedpData};
+use crate::protocol::discovery::types::{EndpointMetadata, ParseError, SedpData};
+use crate::protocol::type_version::TypeVersionAnnouncement;
 use crate::xtypes::{decompress_type_object, CompleteTypeObject};
 use crate::Cdr2Decode;
 
@@ -84,6 +87,26 @@ fn parse_user_data_parameter(
     std::str::from_utf8(data).ok().map(|s| s.to_string())
 }
 
+/// Parse an octet sequence parameter (USER_DATA, TOPIC_DATA, GROUP_DATA).
+///
+/// Format: length (u32) + bytes. Returns `None` if the length overruns the
+/// parameter.
+pub(crate) fn parse_octet_seq(
+    buf: &[u8],
+    offset: usize,
+    length: usize,
+    is_little_endian: bool,
+) -> Option<Vec<u8>> {
+    if length < 4 || offset + length > buf.len() {
+        return None;
+    }
+    let data_len = read_u32(buf, offset, is_little_endian) as usize;
+    if data_len > length - 4 {
+        return None;
+    }
+    Some(buf[offset + 4..offset + 4 + data_len].to_vec())
+}
+
 // TypeObject decompression moved to xtypes::type_object::codec module
 // Use decompress_type_object() from crate::xtypes instead
 
@@ -171,6 +194,68 @@ fn parse_durability_service(
     Some(ds)
 }
 
+/// Parse PID_PARTITION (0x0029) from CDR-encoded buffer.
+///
+/// Format: sequence_length (u32) + per name: length (u32, incl. NUL) + chars,
+/// each name padded to 4 bytes.
+fn parse_partition(
+    buf: &[u8],
+    offset: usize,
+    length: usize,
+    is_little_endian: bool,
+) -> Option<crate::dds::qos::Partition> {
+    let end = offset.checked_add(length)?.min(buf.len());
+    if offset + 4 > end {
+        return None;
+    }
+
+    let count = read_u32(buf, offset, is_little_endian) as usize;
+    let mut names = Vec::with_capacity(count.min(16));
+    let mut pos = offset + 4;
+    for _ in 0..count {
+        if pos + 4 > end {
+            return None;
+        }
+        let len = read_u32(buf, pos, is_little_endian) as usize;
+        let start = pos + 4;
+        if len == 0 || start.checked_add(len)? > end {
+            return None;
+        }
+        names.push(
+            std::str::from_utf8(&buf[start..start + len - 1])
+                .ok()?
+                .to_string(),
+        );
+        pos = start + ((len + 3) & !3);
+    }
+
+    log::debug!("[SEDP-QOS] PID_PARTITION parsed: {:?}", names);
+    Some(crate::dds::qos::Partition::new(names))
+}
+
+/// Parse PID_TIME_BASED_FILTER (0x0004) from CDR-encoded buffer.
+///
+/// Format: minimum_separation (Duration_t = 8 bytes).
+fn parse_time_based_filter(
+    buf: &[u8],
+    offset: usize,
+    length: usize,
+    is_little_endian: bool,
+) -> Option<crate::dds::qos::TimeBasedFilter> {
+    if length < 8 {
+        return None;
+    }
+
+    let secs = read_u32(buf, offset, is_little_endian);
+    let nsecs = read_u32(buf, offset + 4, is_little_endian);
+    let separation = std::time::Duration::new(u64::from(secs), nsecs.min(999_999_999));
+    log::debug!(
+        "[SEDP-QOS] PID_TIME_BASED_FILTER parsed: minimum_separation={:?}",
+        separation
+    );
+    Some(crate::dds::qos::TimeBasedFilter::new(separation))
+}
+
 /// Parse PID_PRESENTATION (0x0021) from CDR-encoded buffer.
 ///
 /// Format: access_scope (u32) + coherent_access (u8) + ordered_access (u8) + 2 padding = 8 bytes.
@@ -206,6 +291,27 @@ fn parse_presentation(
     ))
 }
 
+/// Parse PID_DESTINATION_ORDER (0x0025) from CDR-encoded buffer.
+///
+/// Format: kind (u32), BY_RECEPTION_TIMESTAMP=0, BY_SOURCE_TIMESTAMP=1.
+fn parse_destination_order(
+    buf: &[u8],
+    offset: usize,
+    length: usize,
+    is_little_endian: bool,
+) -> Option<crate::dds::qos::DestinationOrder> {
+    if length < 4 {
+        return None;
+    }
+
+    let kind = read_u32(buf, offset, is_little_endian);
+    log::debug!("[SEDP-QOS] PID_DESTINATION_ORDER parsed: kind={}", kind);
+    Some(match kind {
+        1 => crate::dds::qos::DestinationOrder::by_source_timestamp(),
+        _ => crate::dds::qos::DestinationOrder::by_reception_timestamp(),
+    })
+}
+
 /// Parse SEDP DATA submessage payload.
 ///
 /// # Arguments
@@ -328,9 +434,17 @@ pub fn parse_sedp(buf: &[u8]) -> Result<SedpData, ParseError> {
     // v235: Parse PID_DURABILITY_SERVICE for DurabilityService QoS
     let mut qos_durability_service: Option<crate::dds::qos::DurabilityService> = None;
 
+    let mut qos_partition: Option<crate::dds::qos::Partition> = None;
+    let mut qos_destination_order: Option<crate::dds::qos::DestinationOrder> = None;
+
+    // Remote readers announce TIME_BASED_FILTER so writers can downsample
+    let mut qos_time_based_filter: Option<crate::dds::qos::TimeBasedFilter> = None;
+
     // v143: Parse PID_UNICAST_LOCATOR for OpenDDS interop - CRITICAL for knowing where to send user data
     let mut unicast_locators: Vec<SocketAddr> = Vec::new();
     let mut user_data: Option<String> = None;
+    let mut metadata = EndpointMetadata::default();
+    let mut type_versions: Option<TypeVersionAnnouncement> = None;
 
     // =========================================================================
     // PID (Parameter ID) Parsing Loop
@@ -484,6 +598,23 @@ pub fn parse_sedp(buf: &[u8]) -> Result<SedpData, ParseError> {
                     qos_presentation = parse_presentation(buf, offset, length, is_little_endian);
                 }
             }
+            PID_DESTINATION_ORDER => {
+                if qos_destination_order.is_none() {
+                    qos_destination_order =
+                        parse_destination_order(buf, offset, length, is_little_endian);
+                }
+            }
+            PID_PARTITION => {
+                if qos_partition.is_none() {
+                    qos_partition = parse_partition(buf, offset, length, is_little_endian);
+                }
+            }
+            PID_TIME_BASED_FILTER => {
+                if qos_time_based_filter.is_none() {
+                    qos_time_based_filter =
+                        parse_time_based_filter(buf, offset, length, is_little_endian);
+                }
+            }
             PID_DATA_REPRESENTATION => {
                 if length >= 4 {
                     let seq_len = read_u32(buf, offset, is_little_endian);
@@ -497,6 +628,23 @@ pub fn parse_sedp(buf: &[u8]) -> Result<SedpData, ParseError> {
                 if user_data.is_none() {
                     user_data = parse_user_data_parameter(buf, offset, length, is_little_endian);
                 }
+                if let Some(value) = parse_octet_seq(buf, offset, length, is_little_endian) {
+                    metadata.user_data.value = value;
+                }
+            }
+            PID_TOPIC_DATA => {
+                if let Some(value) = parse_octet_seq(buf, offset, length, is_little_endian) {
+                    metadata.topic_data.value = value;
+                }
+            }
+            PID_GROUP_DATA => {
+                if let Some(value) = parse_octet_seq(buf, offset, length, is_little_endian) {
+                    metadata.group_data.value = value;
+                }
+            }
+            PID_ENTITY_NAME => {
+                metadata.entity_name =
+                    parse_string_parameter(buf, offset, length, is_little_endian);
             }
             // v110: Parse PID_PARTICIPANT_GUID - FastDDS/RTI interop requirement
             // Links endpoint to participant for validation in EDPSimpleListeners
@@ -632,6 +780,18 @@ pub fn parse_sedp(buf: &[u8]) -> Result<SedpData, ParseError> {
                     }
                 }
             }
+            PID_HDDS_TYPE_VERSIONS => {
+                // Vendor range: other stacks may reuse 0x8006, the magic
+                // check in from_bytes() filters them out.
+                type_versions = TypeVersionAnnouncement::from_bytes(&buf[offset..offset + length]);
+                if type_versions.is_none() {
+                    log::debug!(
+                        "[SEDP-PARSE] [i]  Ignoring foreign vendor PID 0x{:04x} (length={})",
+                        pid,
+                        length
+                    );
+                }
+            }
             _ => {
                 // Log unknown PIDs so we can see what we're missing!
                 // This is CRITICAL for debugging interop issues.
@@ -688,6 +848,9 @@ pub fn parse_sedp(buf: &[u8]) -> Result<SedpData, ParseError> {
         || qos_history.is_some()
         || qos_presentation.is_some()
         || qos_durability_service.is_some()
+        || qos_time_based_filter.is_some()
+        || qos_partition.is_some()
+        || qos_destination_order.is_some()
     {
         // Start with default QoS and override with parsed values
         let mut qos_obj = crate::dds::qos::QoS::default();
@@ -707,6 +870,15 @@ pub fn parse_sedp(buf: &[u8]) -> Result<SedpData, ParseError> {
         if let Some(ds) = qos_durability_service {
             qos_obj.durability_service = ds;
         }
+        if let Some(filter) = qos_time_based_filter {
+            qos_obj.time_based_filter = filter;
+        }
+        if let Some(partition) = qos_partition {
+            qos_obj.partition = partition;
+        }
+        if let Some(order) = qos_destination_order {
+            qos_obj.destination_order = order;
+        }
 
         log::debug!(
             "[SEDP-QOS] [OK] Built QoS from PIDs: reliability={:?}, durability={:?}, history={:?}, presentation={:?}, durability_service.depth={}",
@@ -739,5 +911,7 @@ pub fn parse_sedp(buf: &[u8]) -> Result<SedpData, ParseError> {
         type_object,
         unicast_locators, // v143: Now parsed from PID_UNICAST_LOCATOR for OpenDDS interop
         user_data,
+        type_versions,
+        metadata,
     })
 }
@@ -10,7 +10,7 @@ use crate::protocol::discovery::constants::{
     BUILTIN_ENDPOINT_SET_DEFAULT, PID_BUILTIN_ENDPOINT_SET, PID_DEFAULT_UNICAST_LOCATOR,
     PID_DOMAIN_ID, PID_METATRAFFIC_UNICAST_LOCATOR, PID_PARTICIPANT_GUID,
     PID_PARTICIPANT_LEASE_DURATION, PID_PROPERTY_LIST, PID_PROTOCOL_VERSION, PID_SENTINEL,
-    PID_VENDOR_ID,
+    PID_USER_DATA, PID_VENDOR_ID,
 };
 // v126: Removed PID_BUILTIN_ENDPOINT_QOS and BUILTIN_ENDPOINT_QOS_DEFAULT
 // FastDDS doesn't send this PID, and RTI accepts FastDDS. Match FastDDS behavior.
@@ -18,6 +18,7 @@ use crate::protocol::discovery::constants::{
 // PID_PRODUCT_VERSION, PID_RTI_DOMAIN_ID, PID_TRANSPORT_INFO_LIST,
 // PID_REACHABILITY_LEASE_DURATION, PID_VENDOR_BUILTIN_ENDPOINT_SET
 // These belong ONLY in the RTI dialect encoder (protocol/dialect/rti/).
+use crate::protocol::discovery::sedp::build::qos::write_octet_seq;
 use crate::protocol::discovery::spdp::types::SpdpData;
 use crate::protocol::discovery::types::ParseError;
 use std::convert::TryFrom;
@@ -122,18 +123,15 @@ pub fn build_spdp(spdp_data: &SpdpData, buf: &mut [u8]) -> Result<usize, ParseEr
         *offset += 4;
 
         // Locator format: kind(4) + port(4) + address(16)
-        // kind = LOCATOR_KIND_UDPv4 = 1 (little-endian inside PL_CDR_LE)
-        buf[*offset..*offset + 4].copy_from_slice(&1u32.to_le_bytes());
+        // kind = LOCATOR_KIND_UDPv4 (1) or LOCATOR_KIND_UDPv6 (2), little-endian inside PL_CDR_LE
+        let (kind, address) = crate::protocol::rtps::udp_locator_fields(addr);
+        buf[*offset..*offset + 4].copy_from_slice(&kind.to_le_bytes());
         *offset += 4;
         let port = addr.port() as u32;
         buf[*offset..*offset + 4].copy_from_slice(&port.to_le_bytes());
         *offset += 4;
-        buf[*offset..*offset + 12].fill(0); // IPv6 prefix (unused for IPv4)
-        *offset += 12;
-        if let std::net::IpAddr::V4(ipv4) = addr.ip() {
-            buf[*offset..*offset + 4].copy_from_slice(&ipv4.octets());
-        }
-        *offset += 4;
+        buf[*offset..*offset + 16].copy_from_slice(&address);
+        *offset += 16;
         Ok(())
     };
 
@@ -160,6 +158,9 @@ pub fn build_spdp(spdp_data: &SpdpData, buf: &mut [u8]) -> Result<usize, ParseEr
     //     write_locator(buf, &mut offset, PID_DEFAULT_MULTICAST_LOCATOR, locator)?;
     // }
 
+    // PID_USER_DATA (0x002c) - participant USER_DATA, only when set
+    write_octet_seq(PID_USER_DATA, &spdp_data.user_data.value, buf, &mut offset)?;
+
     // Position 10+: PID_PROPERTY_LIST (RTI compatibility - FULL standard properties)
     // v94: Add ALL 7 standard properties that RTI expects (was only sending 2, now sending 7)
     // RTI rejects HDDS without these! Critical for interop.
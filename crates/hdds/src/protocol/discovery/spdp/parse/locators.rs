@@ -10,11 +10,11 @@
 //! - PID_DEFAULT_UNICAST_LOCATOR (0x0031)
 //! - PID_DEFAULT_MULTICAST_LOCATOR (0x0048)
 
-use crate::core::string_utils::format_string;
 use crate::protocol::discovery::spdp::types::SpdpData;
 use crate::protocol::discovery::types::ParseError;
+use crate::protocol::rtps::LOCATOR_KIND_UDPV6;
 use std::convert::TryFrom;
-use std::net::Ipv4Addr;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
 
 /// Decode locator port handling both big-endian (spec) and little-endian encodings.
 /// Some vendors emit the Locator_t.port field in LE; be permissive so discovery works.
@@ -36,6 +36,32 @@ fn decode_locator_port(raw: [u8; 4]) -> Option<u16> {
     }
 }
 
+/// Decode the Locator_t address at `offset` according to its kind.
+///
+/// `LOCATOR_KIND_UDPv6` uses all 16 address bytes (kind accepted in either
+/// byte order, like the port); any other kind is read as IPv4 from the last 4.
+pub(crate) fn decode_locator_ip(buf: &[u8], offset: usize) -> IpAddr {
+    let kind = [
+        buf[offset],
+        buf[offset + 1],
+        buf[offset + 2],
+        buf[offset + 3],
+    ];
+    if u32::from_le_bytes(kind) == LOCATOR_KIND_UDPV6
+        || u32::from_be_bytes(kind) == LOCATOR_KIND_UDPV6
+    {
+        let mut octets = [0u8; 16];
+        octets.copy_from_slice(&buf[offset + 8..offset + 24]);
+        return IpAddr::V6(Ipv6Addr::from(octets));
+    }
+    IpAddr::V4(Ipv4Addr::new(
+        buf[offset + 20],
+        buf[offset + 21],
+        buf[offset + 22],
+        buf[offset + 23],
+    ))
+}
+
 /// Parse PID_METATRAFFIC_UNICAST_LOCATOR (0x0032)
 ///
 /// Metatraffic unicast locators (typically port 7410) are used for:
@@ -47,7 +73,7 @@ fn decode_locator_port(raw: [u8; 4]) -> Option<u16> {
 /// struct Locator_t {
 ///   long kind;        // 4 bytes: LOCATOR_KIND_UDPv4 (1), LOCATOR_KIND_UDPv6 (2)
 ///   unsigned long port; // 4 bytes: Network port (big-endian per RTPS spec)
-///   octet address[16]; // 16 bytes: IPv4 in last 4 bytes, first 12 are zeros
+///   octet address[16]; // 16 bytes: IPv4 in last 4 bytes (first 12 zeros), or IPv6
 /// };
 /// ```
 /// Total: 24 bytes (RTPS v2.3 Sec.8.3.4.2)
@@ -65,18 +91,12 @@ pub(super) fn parse_metatraffic_unicast_locator_pid(
             buf[offset + 7],
         ];
 
-        let addr = Ipv4Addr::new(
-            buf[offset + 20],
-            buf[offset + 21],
-            buf[offset + 22],
-            buf[offset + 23],
-        );
+        let addr = decode_locator_ip(buf, offset);
 
         if let Some(port_u16) = decode_locator_port(port_bytes) {
-            if let Ok(socket_addr) = format_string(format_args!("{}:{}", addr, port_u16)).parse() {
-                log::debug!("[spdp] Metatraffic unicast locator: {}", socket_addr);
-                spdp_data.metatraffic_unicast_locators.push(socket_addr); // v79: separate list
-            }
+            let socket_addr = SocketAddr::new(addr, port_u16);
+            log::debug!("[spdp] Metatraffic unicast locator: {}", socket_addr);
+            spdp_data.metatraffic_unicast_locators.push(socket_addr); // v79: separate list
         } else {
             log::debug!(
                 "[spdp] [!]  Failed to decode metatraffic unicast port from bytes: {:02x?}",
@@ -106,17 +126,11 @@ pub(super) fn parse_metatraffic_multicast_locator_pid(
             buf[offset + 6],
             buf[offset + 7],
         ];
-        let addr = Ipv4Addr::new(
-            buf[offset + 20],
-            buf[offset + 21],
-            buf[offset + 22],
-            buf[offset + 23],
-        );
+        let addr = decode_locator_ip(buf, offset);
         if let Some(port_u16) = decode_locator_port(port_bytes) {
-            if let Ok(sock) = format_string(format_args!("{}:{}", addr, port_u16)).parse() {
-                spdp_data.metatraffic_multicast_locators.push(sock);
-                log::debug!("[spdp] Metatraffic multicast locator: {}", sock);
-            }
+            let sock = SocketAddr::new(addr, port_u16);
+            spdp_data.metatraffic_multicast_locators.push(sock);
+            log::debug!("[spdp] Metatraffic multicast locator: {}", sock);
         }
     }
     Ok(())
@@ -140,20 +154,11 @@ pub(super) fn parse_default_unicast_locator_pid(
             buf[offset + 6],
             buf[offset + 7],
         ];
-        let addr = Ipv4Addr::new(
-            buf[offset + 20],
-            buf[offset + 21],
-            buf[offset + 22],
-            buf[offset + 23],
-        );
+        let addr = decode_locator_ip(buf, offset);
         if let Some(port_u16) = decode_locator_port(port_bytes) {
-            if let Ok(sock) =
-                crate::core::string_utils::format_string(format_args!("{}:{}", addr, port_u16))
-                    .parse()
-            {
-                spdp_data.default_unicast_locators.push(sock); // v79: separate list
-                log::debug!("[spdp] Default unicast locator: {}", sock);
-            }
+            let sock = SocketAddr::new(addr, port_u16);
+            spdp_data.default_unicast_locators.push(sock); // v79: separate list
+            log::debug!("[spdp] Default unicast locator: {}", sock);
         }
     }
     Ok(())
@@ -177,17 +182,11 @@ pub(super) fn parse_default_multicast_locator_pid(
             buf[offset + 6],
             buf[offset + 7],
         ];
-        let addr = Ipv4Addr::new(
-            buf[offset + 20],
-            buf[offset + 21],
-            buf[offset + 22],
-            buf[offset + 23],
-        );
+        let addr = decode_locator_ip(buf, offset);
         if let Some(port_u16) = decode_locator_port(port_bytes) {
-            if let Ok(sock) = format_string(format_args!("{}:{}", addr, port_u16)).parse() {
-                spdp_data.default_multicast_locators.push(sock);
-                log::debug!("[spdp] Default multicast locator: {}", sock);
-            }
+            let sock = SocketAddr::new(addr, port_u16);
+            spdp_data.default_multicast_locators.push(sock);
+            log::debug!("[spdp] Default multicast locator: {}", sock);
         }
     }
     Ok(())
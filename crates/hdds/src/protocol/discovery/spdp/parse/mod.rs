@@ -18,13 +18,16 @@ mod locators;
 mod metadata;
 mod properties;
 
+pub(crate) use locators::decode_locator_ip;
+
 use crate::core::discovery::GUID;
 use crate::protocol::discovery::constants::{
     PID_BUILTIN_ENDPOINT_SET, PID_DEFAULT_MULTICAST_LOCATOR, PID_DEFAULT_UNICAST_LOCATOR,
     PID_DOMAIN_ID, PID_ENTITY_NAME, PID_METATRAFFIC_MULTICAST_LOCATOR,
     PID_METATRAFFIC_UNICAST_LOCATOR, PID_PARTICIPANT_GUID, PID_PARTICIPANT_LEASE_DURATION,
-    PID_PROPERTY_LIST, PID_PROTOCOL_VERSION, PID_SENTINEL, PID_VENDOR_ID,
+    PID_PROPERTY_LIST, PID_PROTOCOL_VERSION, PID_SENTINEL, PID_USER_DATA, PID_VENDOR_ID,
 };
+use crate::protocol::discovery::sedp::parse::parse_octet_seq;
 use crate::protocol::discovery::spdp::types::{
     SpdpData, CDR2_BE, CDR2_LE, CDR_BE, CDR_BE_VENDOR, CDR_LE, CDR_LE_VENDOR,
 };
@@ -118,6 +121,7 @@ pub fn parse_spdp(buf: &[u8]) -> Result<SpdpData, ParseError> {
         default_multicast_locators: Vec::new(),
         metatraffic_multicast_locators: Vec::new(),
         identity_token: None,
+        user_data: Default::default(),
     };
 
     // Optional: protocol/vendor/domain info for diagnostics
@@ -194,6 +198,11 @@ pub fn parse_spdp(buf: &[u8]) -> Result<SpdpData, ParseError> {
             PID_ENTITY_NAME => {
                 properties::parse_entity_name_pid(buf, offset, length)?;
             }
+            PID_USER_DATA => {
+                if let Some(value) = parse_octet_seq(buf, offset, length, is_little_endian) {
+                    spdp_data.user_data.value = value;
+                }
+            }
 
             // Locator PIDs
             PID_METATRAFFIC_UNICAST_LOCATOR => {
@@ -428,6 +437,7 @@ pub fn parse_spdp_partial(buf: &[u8]) -> Result<SpdpData, ParseError> {
                 default_multicast_locators: vec![],
                 metatraffic_multicast_locators: vec![],
                 identity_token: None, // Partial fragment = no security data
+                user_data: Default::default(),
             })
         }
         Err(e) => Err(e), // Other errors pass through
@@ -7,6 +7,7 @@
 //! This includes the main SpdpData struct and CDR encapsulation constants.
 
 use crate::core::discovery::GUID;
+use crate::qos::metadata::UserData;
 use std::net::SocketAddr;
 
 // v111: Import CDR constants from canonical location
@@ -37,4 +38,6 @@ pub struct SpdpData {
     /// Present when the remote participant is using DDS Security authentication.
     /// Contains the participant's identity certificate for validation.
     pub identity_token: Option<Vec<u8>>,
+    /// Participant USER_DATA (PID_USER_DATA, empty when not announced)
+    pub user_data: UserData,
 }
@@ -6,8 +6,8 @@ use super::constants::{
     PID_PARTICIPANT_LEASE_DURATION, PID_SENTINEL, PID_TOPIC_NAME, PID_TYPE_NAME, PID_TYPE_OBJECT,
 };
 use super::{
-    build_sedp, build_spdp, parse_sedp, parse_spdp, parse_topic_name, ParseError, SedpData,
-    SpdpData,
+    build_sedp, build_spdp, parse_sedp, parse_spdp, parse_topic_name, EndpointMetadata, ParseError,
+    SedpData, SpdpData,
 };
 use crate::core::discovery::GUID;
 use crate::core::ser::traits::Cdr2Encode;
@@ -299,6 +299,8 @@ fn test_build_sedp_roundtrip_with_type_object() {
         type_object: Some(sample_complete_type_object()),
         unicast_locators: vec![],
         user_data: None,
+        type_versions: None,
+        metadata: Default::default(),
     };
 
     let mut buf = vec![0u8; 2048];
@@ -311,6 +313,90 @@ fn test_build_sedp_roundtrip_with_type_object() {
     assert!(parsed.type_object.is_some());
 }
 
+#[test]
+fn test_build_sedp_roundtrip_with_type_versions() {
+    use crate::protocol::type_version::{AnnouncedTypeVersion, TypeVersionAnnouncement};
+
+    let type_versions = TypeVersionAnnouncement {
+        native: 2,
+        versions: vec![
+            AnnouncedTypeVersion {
+                version: 1,
+                type_name: "TestTypeV1".to_string(),
+                type_object: Some(sample_complete_type_object()),
+            },
+            AnnouncedTypeVersion {
+                version: 2,
+                type_name: "TestType".to_string(),
+                type_object: None,
+            },
+        ],
+    };
+    let sedp_data = SedpData {
+        topic_name: "TestTopic".to_string(),
+        type_name: "TestType".to_string(),
+        participant_guid: GUID::zero(), // Test data
+        endpoint_guid: GUID::from_bytes([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]),
+        qos_hash: 0,
+        qos: None, // Tests use default QoS values
+        type_object: None,
+        unicast_locators: vec![],
+        user_data: None,
+        type_versions: Some(type_versions.clone()),
+        metadata: Default::default(),
+    };
+
+    let mut buf = vec![0u8; 4096];
+    let len = build_sedp(&sedp_data, &mut buf).expect("SEDP build should succeed");
+    let parsed = parse_sedp(&buf[..len]).expect("SEDP parse should succeed");
+
+    assert_eq!(parsed.type_name, sedp_data.type_name);
+    assert_eq!(parsed.type_versions, Some(type_versions));
+}
+
+#[test]
+fn test_build_sedp_roundtrip_metadata() {
+    use crate::qos::metadata::{GroupData, TopicData, UserData};
+
+    let metadata = EndpointMetadata {
+        user_data: UserData::new(b"token=abc".to_vec()),
+        topic_data: TopicData::new(vec![0, 1, 2, 3, 4]),
+        group_data: GroupData::new(b"site".to_vec()),
+        entity_name: Some("imu_publisher".to_string()),
+    };
+    let sedp_data = SedpData {
+        topic_name: "TestTopic".to_string(),
+        type_name: "TestType".to_string(),
+        participant_guid: GUID::zero(), // Test data
+        endpoint_guid: GUID::from_bytes([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]),
+        qos_hash: 0,
+        qos: None, // Tests use default QoS values
+        type_object: None,
+        unicast_locators: vec![],
+        user_data: None,
+        type_versions: None,
+        metadata: metadata.clone(),
+    };
+
+    let mut buf = vec![0u8; 4096];
+    let len = build_sedp(&sedp_data, &mut buf).expect("SEDP build should succeed");
+    let parsed = parse_sedp(&buf[..len]).expect("SEDP parse should succeed");
+
+    assert_eq!(parsed.metadata, metadata);
+
+    // Empty policies are not written
+    let empty = SedpData {
+        metadata: EndpointMetadata::default(),
+        ..sedp_data
+    };
+    let empty_len = build_sedp(&empty, &mut buf).expect("SEDP build should succeed");
+    assert!(empty_len < len);
+    assert!(parse_sedp(&buf[..empty_len])
+        .expect("SEDP parse should succeed")
+        .metadata
+        .is_empty());
+}
+
 #[test]
 fn test_build_sedp_roundtrip_without_type_object() {
     let sedp_data = SedpData {
@@ -323,6 +409,8 @@ fn test_build_sedp_roundtrip_without_type_object() {
         type_object: None,
         unicast_locators: vec![],
         user_data: None,
+        type_versions: None,
+        metadata: Default::default(),
     };
 
     let mut buf = vec![0u8; 1024];
@@ -335,6 +423,84 @@ fn test_build_sedp_roundtrip_without_type_object() {
     assert!(parsed.type_object.is_none());
 }
 
+#[test]
+fn test_build_sedp_roundtrip_time_based_filter() {
+    let qos = crate::dds::qos::QoS::best_effort().time_based_filter_millis(100);
+    let sedp_data = SedpData {
+        topic_name: "SensorTopic".to_string(),
+        type_name: "SensorType".to_string(),
+        participant_guid: GUID::zero(),
+        endpoint_guid: GUID::from_bytes([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]),
+        qos_hash: 0,
+        qos: Some(qos.clone()),
+        type_object: None,
+        unicast_locators: vec![],
+        user_data: None,
+        type_versions: None,
+        metadata: Default::default(),
+    };
+
+    let mut buf = vec![0u8; 1024];
+    let len = build_sedp(&sedp_data, &mut buf).expect("SEDP build should succeed");
+    let parsed = parse_sedp(&buf[..len]).expect("SEDP parse should succeed");
+
+    let parsed_qos = parsed.qos.expect("QoS should be parsed");
+    assert_eq!(parsed_qos.time_based_filter, qos.time_based_filter);
+}
+
+#[test]
+fn test_build_sedp_roundtrip_destination_order() {
+    let qos = crate::dds::qos::QoS::reliable().destination_order_by_source();
+    let sedp_data = SedpData {
+        topic_name: "SensorTopic".to_string(),
+        type_name: "SensorType".to_string(),
+        participant_guid: GUID::zero(),
+        endpoint_guid: GUID::from_bytes([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]),
+        qos_hash: 0,
+        qos: Some(qos.clone()),
+        type_object: None,
+        unicast_locators: vec![],
+        user_data: None,
+        type_versions: None,
+        metadata: Default::default(),
+    };
+
+    let mut buf = vec![0u8; 1024];
+    let len = build_sedp(&sedp_data, &mut buf).expect("SEDP build should succeed");
+    let parsed = parse_sedp(&buf[..len]).expect("SEDP parse should succeed");
+
+    let parsed_qos = parsed.qos.expect("QoS should be parsed");
+    assert_eq!(parsed_qos.destination_order, qos.destination_order);
+}
+
+#[test]
+fn test_build_sedp_roundtrip_partition() {
+    let qos = crate::dds::qos::QoS::reliable().partition(crate::dds::qos::Partition::new(vec![
+        "bench/*".to_string(),
+        "cell7".to_string(),
+    ]));
+    let sedp_data = SedpData {
+        topic_name: "SensorTopic".to_string(),
+        type_name: "SensorType".to_string(),
+        participant_guid: GUID::zero(),
+        endpoint_guid: GUID::from_bytes([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]),
+        qos_hash: 0,
+        qos: Some(qos.clone()),
+        type_object: None,
+        unicast_locators: vec![],
+        user_data: None,
+        type_versions: None,
+        metadata: Default::default(),
+    };
+
+    let mut buf = vec![0u8; 1024];
+    let len = build_sedp(&sedp_data, &mut buf).expect("SEDP build should succeed");
+    let parsed = parse_sedp(&buf[..len]).expect("SEDP parse should succeed");
+
+    let parsed_qos = parsed.qos.expect("QoS should be parsed");
+    assert_eq!(parsed_qos.partition, qos.partition);
+}
+
 #[test]
 fn test_sedp_pid_order_endpoint_then_participant() {
     let sedp_data = SedpData {
@@ -347,6 +513,8 @@ fn test_sedp_pid_order_endpoint_then_participant() {
         type_object: None,
         unicast_locators: vec![],
         user_data: None,
+        type_versions: None,
+        metadata: Default::default(),
     };
 
     let mut buf = vec![0u8; 1024];
@@ -377,6 +545,8 @@ fn test_build_sedp_buffer_too_small() {
         type_object: None,
         unicast_locators: vec![],
         user_data: None,
+        type_versions: None,
+        metadata: Default::default(),
     };
 
     let mut buf = vec![0u8; 16];
@@ -396,6 +566,7 @@ fn test_build_spdp_roundtrip_with_locators() {
         default_multicast_locators: vec![],
         metatraffic_multicast_locators: vec![],
         identity_token: None,
+        user_data: Default::default(),
     };
 
     let mut buf = vec![0u8; 1024];
@@ -414,6 +585,55 @@ fn test_build_spdp_roundtrip_with_locators() {
     );
 }
 
+#[test]
+fn test_build_spdp_roundtrip_user_data() {
+    let spdp_data = SpdpData {
+        participant_guid: GUID::from_bytes([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]),
+        lease_duration_ms: 120_000,
+        domain_id: 0,
+        metatraffic_unicast_locators: vec![],
+        default_unicast_locators: vec![],
+        default_multicast_locators: vec![],
+        metatraffic_multicast_locators: vec![],
+        identity_token: None,
+        user_data: crate::qos::metadata::UserData::new(b"deployment=blue".to_vec()),
+    };
+
+    let mut buf = vec![0u8; 2048];
+    let len = build_spdp(&spdp_data, &mut buf).expect("SPDP build should succeed");
+    let parsed = parse_spdp(&buf[..len]).expect("SPDP parse should succeed");
+
+    assert_eq!(parsed.user_data, spdp_data.user_data);
+}
+
+#[test]
+fn test_build_spdp_roundtrip_ipv6_locators() {
+    let spdp_data = SpdpData {
+        participant_guid: GUID::from_bytes([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]),
+        lease_duration_ms: 120_000,
+        domain_id: 0,
+        metatraffic_unicast_locators: vec!["[fd00::2]:7410".parse().expect("valid addr")],
+        default_unicast_locators: vec!["[fd00::2]:7411".parse().expect("valid addr")],
+        default_multicast_locators: vec![],
+        metatraffic_multicast_locators: vec![],
+        identity_token: None,
+        user_data: Default::default(),
+    };
+
+    let mut buf = vec![0u8; 1024];
+    let len = build_spdp(&spdp_data, &mut buf).expect("SPDP build should succeed");
+    let parsed = parse_spdp(&buf[..len]).expect("SPDP parse should succeed");
+
+    assert_eq!(
+        parsed.metatraffic_unicast_locators,
+        spdp_data.metatraffic_unicast_locators
+    );
+    assert_eq!(
+        parsed.default_unicast_locators,
+        spdp_data.default_unicast_locators
+    );
+}
+
 #[test]
 fn test_build_spdp_roundtrip_without_locators() {
     let spdp_data = SpdpData {
@@ -425,6 +645,7 @@ fn test_build_spdp_roundtrip_without_locators() {
         default_multicast_locators: Vec::new(),
         metatraffic_multicast_locators: Vec::new(),
         identity_token: None,
+        user_data: Default::default(),
     };
 
     let mut buf = vec![0u8; 1024]; // v101: Increased for property list (7 properties ~600 bytes)
@@ -448,6 +669,7 @@ fn test_build_spdp_buffer_too_small() {
         default_multicast_locators: vec![],
         metatraffic_multicast_locators: vec![],
         identity_token: None,
+        user_data: Default::default(),
     };
 
     let mut buf = vec![0u8; 16];
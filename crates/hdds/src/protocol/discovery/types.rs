@@ -2,6 +2,8 @@
 // Copyright (c) 2025-2026 naskel.com
 
 use crate::core::discovery::GUID;
+use crate::protocol::type_version::TypeVersionAnnouncement;
+use crate::qos::metadata::{GroupData, TopicData, UserData};
 use crate::xtypes::CompleteTypeObject;
 use std::net::SocketAddr;
 
@@ -29,4 +31,60 @@ pub struct SedpData {
     /// User data for capability advertisement (e.g., SHM transport)
     /// Format for SHM: "shm=1;host_id=XXXXXXXX;v=1"
     pub user_data: Option<String>,
+    /// Side-by-side type versions (`PID_HDDS_TYPE_VERSIONS`, HDDS only)
+    pub type_versions: Option<TypeVersionAnnouncement>,
+    /// USER_DATA, TOPIC_DATA and GROUP_DATA of the endpoint
+    pub metadata: EndpointMetadata,
+}
+
+/// Opaque metadata QoS announced with an endpoint (empty when absent).
+///
+/// Kept apart from `SedpData::qos` so endpoints announcing only metadata
+/// still get vendor-default QoS policies.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EndpointMetadata {
+    /// PID_USER_DATA (0x002c)
+    pub user_data: UserData,
+    /// PID_TOPIC_DATA (0x002e)
+    pub topic_data: TopicData,
+    /// PID_GROUP_DATA (0x002d)
+    pub group_data: GroupData,
+    /// PID_ENTITY_NAME (0x0062), human-readable endpoint name
+    pub entity_name: Option<String>,
+}
+
+impl EndpointMetadata {
+    /// Metadata policies of an endpoint QoS.
+    #[must_use]
+    pub fn from_qos(qos: &crate::dds::QoS) -> Self {
+        Self {
+            user_data: qos.user_data.clone(),
+            topic_data: qos.topic_data.clone(),
+            group_data: qos.group_data.clone(),
+            entity_name: qos.entity_name.clone(),
+        }
+    }
+
+    /// Total size of the policy values and entity name in bytes.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.user_data.len()
+            + self.topic_data.len()
+            + self.group_data.len()
+            + self.entity_name.as_ref().map_or(0, String::len)
+    }
+
+    /// True if no policy carries data and no entity name is set.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Copy the metadata policies and entity name into `qos`.
+    pub fn apply_to(&self, qos: &mut crate::dds::QoS) {
+        qos.user_data.clone_from(&self.user_data);
+        qos.topic_data.clone_from(&self.topic_data);
+        qos.group_data.clone_from(&self.group_data);
+        qos.entity_name.clone_from(&self.entity_name);
+    }
 }
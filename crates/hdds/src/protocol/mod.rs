@@ -6,6 +6,9 @@
 //! This module contains the core RTPS protocol components:
 //! - Constants: PIDs, entity IDs, vendor IDs
 //! - CDR encoding/decoding
+//! - Per-sample payload checksums
+//! - Side-by-side type versions
+//! - Original writer of relayed samples
 //! - Packet builders for RTPS messages
 //! - Discovery protocol parsers (SPDP/SEDP)
 //! - Dialect encoders for vendor-specific interoperability
@@ -13,10 +16,14 @@
 
 pub mod builder;
 pub mod cdr;
+pub mod checksum;
+pub mod coherent;
 pub mod constants;
 pub mod dialect;
 pub mod discovery;
+pub mod original_writer;
 pub mod rtps;
+pub mod type_version;
 
 // Re-export commonly used items
 pub use constants::*;
@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Original writer of relayed samples (`PID_ORIGINAL_WRITER_INFO`, RTPS
+//! v2.3 Sec.9.6.3).
+//!
+//! A persistence service replaying stored samples publishes them with its
+//! own writer, under its own sequence numbers. The DATA inline QoS then
+//! carries the writer and sequence number each sample was first published
+//! with, so a PERSISTENT reader receiving a sample both from the original
+//! writer and from the replay delivers it once.
+//!
+//! # DATA parameter (`PID_ORIGINAL_WRITER_INFO`)
+//!
+//! | Offset | Size | Field |
+//! |--------|------|-------|
+//! | 0 | 16 | original writer GUID |
+//! | 16 | 8 | original sequence number (`SequenceNumber_t`: high i32 LE, low u32 LE) |
+//! | 24 | 4 | original writer QoS: empty parameter list (`PID_SENTINEL`) |
+
+use crate::core::discovery::GUID;
+
+/// Encoded length of an [`OriginalWriterInfo`] in bytes.
+pub const ORIGINAL_WRITER_INFO_LEN: usize = 28;
+
+/// Writer and sequence number a sample was first published with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OriginalWriterInfo {
+    /// GUID of the writer that first published the sample.
+    pub writer_guid: GUID,
+    /// Sequence number it was published with.
+    pub sequence: u64,
+}
+
+impl OriginalWriterInfo {
+    /// Sample `sequence` of `writer_guid`.
+    #[must_use]
+    pub const fn new(writer_guid: GUID, sequence: u64) -> Self {
+        Self {
+            writer_guid,
+            sequence,
+        }
+    }
+
+    /// Encode the parameter value (see the module-level layout).
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; ORIGINAL_WRITER_INFO_LEN] {
+        let mut out = [0u8; ORIGINAL_WRITER_INFO_LEN];
+        out[..16].copy_from_slice(&self.writer_guid.as_bytes());
+        out[16..20].copy_from_slice(&((self.sequence >> 32) as u32).to_le_bytes());
+        out[20..24].copy_from_slice(&(self.sequence as u32).to_le_bytes());
+        out[24..26].copy_from_slice(&0x0001u16.to_le_bytes());
+        out
+    }
+
+    /// Decode the parameter value. Returns `None` if truncated; the
+    /// original writer QoS is ignored.
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let guid: [u8; 16] = bytes.get(..16)?.try_into().ok()?;
+        let high = u32::from_le_bytes(bytes.get(16..20)?.try_into().ok()?);
+        let low = u32::from_le_bytes(bytes.get(20..24)?.try_into().ok()?);
+        Some(Self::new(
+            GUID::from_bytes(guid),
+            (u64::from(high) << 32) | u64::from(low),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let info =
+            OriginalWriterInfo::new(GUID::new([7; 12], [0, 0, 1, 3]), (5u64 << 32) | 0xdead_beef);
+        let bytes = info.to_bytes();
+        assert_eq!(&bytes[24..], &[0x01, 0x00, 0x00, 0x00]);
+        assert_eq!(OriginalWriterInfo::from_bytes(&bytes), Some(info));
+        assert_eq!(OriginalWriterInfo::from_bytes(&bytes[..23]), None);
+    }
+}
@@ -122,6 +122,23 @@ pub fn encode_heartbeat_final(
     Ok(buf)
 }
 
+/// Encode a liveliness HEARTBEAT (Final and Liveliness flags set).
+///
+/// The Liveliness flag (L) marks a manual liveliness assertion by the
+/// Writer: Readers refresh the Writer's lease without treating it as a
+/// request for acknowledgment.
+pub fn encode_heartbeat_liveliness(
+    reader_id: &[u8; 4],
+    writer_id: &[u8; 4],
+    first_sn: u64,
+    last_sn: u64,
+    count: u32,
+) -> RtpsEncodeResult<Vec<u8>> {
+    let mut buf = encode_heartbeat(reader_id, writer_id, first_sn, last_sn, count)?;
+    buf[1] = 0x07; // Flags: Liveliness=1, Final=1, Endianness=LE
+    Ok(buf)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -146,4 +163,13 @@ mod tests {
         let encoded_count = u32::from_le_bytes([buf[28], buf[29], buf[30], buf[31]]);
         assert_eq!(encoded_count, 5);
     }
+
+    #[test]
+    fn test_heartbeat_liveliness_flags() {
+        let buf = encode_heartbeat_liveliness(&[0; 4], &[0x00, 0x00, 0x01, 0x02], 1, 0, 3)
+            .expect("encode");
+        assert_eq!(buf.len(), 32);
+        assert_eq!(buf[1], 0x07);
+        assert_eq!(u32::from_le_bytes([buf[28], buf[29], buf[30], buf[31]]), 3);
+    }
 }
@@ -31,6 +31,17 @@ pub fn encode_info_ts(timestamp_sec: u32, timestamp_frac: u32) -> Vec<u8> {
     buf
 }
 
+/// Encode the INFO_TS submessage of a timestamp in nanoseconds since the
+/// UNIX epoch.
+///
+/// The fraction is rounded up so that decoding it back to nanoseconds
+/// (truncating) yields `timestamp_ns` exactly.
+pub fn encode_info_ts_ns(timestamp_ns: u64) -> Vec<u8> {
+    let sec = (timestamp_ns / 1_000_000_000).min(i32::MAX as u64) as u32;
+    let frac = ((timestamp_ns % 1_000_000_000) << 32).div_ceil(1_000_000_000) as u32;
+    encode_info_ts(sec, frac)
+}
+
 /// Encode an INFO_DST submessage per RTPS 2.3 specification.
 ///
 /// # Arguments
@@ -73,6 +84,18 @@ mod tests {
         assert_eq!(sec, TEST_INFO_TS_SEC);
     }
 
+    #[test]
+    fn test_info_ts_ns_round_trip() {
+        for ns in [0, 1, 999_999_999, 1_700_000_000_123_456_789] {
+            let buf = encode_info_ts_ns(ns);
+            let sec = u32::from_le_bytes([buf[4], buf[5], buf[6], buf[7]]);
+            let frac = u32::from_le_bytes([buf[8], buf[9], buf[10], buf[11]]);
+            let decoded =
+                u64::from(sec) * 1_000_000_000 + ((u64::from(frac) * 1_000_000_000) >> 32);
+            assert_eq!(decoded, ns);
+        }
+    }
+
     #[test]
     fn test_info_dst_encoding() {
         let guid_prefix = [
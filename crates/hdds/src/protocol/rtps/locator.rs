@@ -9,6 +9,25 @@ use std::net::SocketAddr;
 
 use super::{RtpsEncodeError, RtpsEncodeResult};
 
+/// Locator kind for UDP over IPv4.
+pub const LOCATOR_KIND_UDPV4: u32 = 1;
+/// Locator kind for UDP over IPv6.
+pub const LOCATOR_KIND_UDPV6: u32 = 2;
+
+/// Locator_t `kind` and 16-byte `address` of a UDP socket address.
+///
+/// IPv4 goes in the last 4 bytes (first 12 zeroed), IPv6 uses all 16.
+pub fn udp_locator_fields(addr: &SocketAddr) -> (u32, [u8; 16]) {
+    match addr {
+        SocketAddr::V4(v4) => {
+            let mut address = [0u8; 16];
+            address[12..].copy_from_slice(&v4.ip().octets());
+            (LOCATOR_KIND_UDPV4, address)
+        }
+        SocketAddr::V6(v6) => (LOCATOR_KIND_UDPV6, v6.ip().octets()),
+    }
+}
+
 /// Encode a PID_UNICAST_LOCATOR parameter.
 ///
 /// # Arguments
@@ -36,10 +55,7 @@ pub fn encode_unicast_locator(
     *offset += 4;
 
     // Locator kind (1 = UDP_v4, 2 = UDP_v6)
-    let kind = match addr {
-        SocketAddr::V4(_) => 1u32,
-        SocketAddr::V6(_) => 2u32,
-    };
+    let (kind, address) = udp_locator_fields(addr);
     buf[*offset..*offset + 4].copy_from_slice(&kind.to_le_bytes());
     *offset += 4;
 
@@ -48,15 +64,7 @@ pub fn encode_unicast_locator(
     *offset += 4;
 
     // Address (16 bytes, IPv4 mapped to last 4 bytes)
-    match addr {
-        SocketAddr::V4(v4) => {
-            buf[*offset..*offset + 12].copy_from_slice(&[0u8; 12]);
-            buf[*offset + 12..*offset + 16].copy_from_slice(&v4.ip().octets());
-        }
-        SocketAddr::V6(v6) => {
-            buf[*offset..*offset + 16].copy_from_slice(&v6.ip().octets());
-        }
-    }
+    buf[*offset..*offset + 16].copy_from_slice(&address);
     *offset += 16;
 
     Ok(())
@@ -88,11 +96,8 @@ pub fn encode_multicast_locator(
     buf[*offset + 2..*offset + 4].copy_from_slice(&24u16.to_le_bytes());
     *offset += 4;
 
-    // Locator kind
-    let kind = match addr {
-        SocketAddr::V4(_) => 1u32,
-        SocketAddr::V6(_) => 2u32,
-    };
+    // Locator kind (1 = UDP_v4, 2 = UDP_v6)
+    let (kind, address) = udp_locator_fields(addr);
     buf[*offset..*offset + 4].copy_from_slice(&kind.to_le_bytes());
     *offset += 4;
 
@@ -100,16 +105,8 @@ pub fn encode_multicast_locator(
     buf[*offset..*offset + 4].copy_from_slice(&(addr.port() as u32).to_le_bytes());
     *offset += 4;
 
-    // Address
-    match addr {
-        SocketAddr::V4(v4) => {
-            buf[*offset..*offset + 12].copy_from_slice(&[0u8; 12]);
-            buf[*offset + 12..*offset + 16].copy_from_slice(&v4.ip().octets());
-        }
-        SocketAddr::V6(v6) => {
-            buf[*offset..*offset + 16].copy_from_slice(&v6.ip().octets());
-        }
-    }
+    // Address (16 bytes, IPv4 mapped to last 4 bytes)
+    buf[*offset..*offset + 16].copy_from_slice(&address);
     *offset += 16;
 
     Ok(())
@@ -118,7 +115,7 @@ pub fn encode_multicast_locator(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::net::{Ipv4Addr, SocketAddrV4};
+    use std::net::{Ipv4Addr, Ipv6Addr, SocketAddrV4};
 
     #[test]
     fn test_unicast_locator_encoding() {
@@ -138,4 +135,21 @@ mod tests {
         let port = u32::from_le_bytes([buf[8], buf[9], buf[10], buf[11]]);
         assert_eq!(port, 7410);
     }
+
+    #[test]
+    fn test_udp_locator_fields() {
+        let v4: SocketAddr = "10.0.0.7:7400".parse().expect("valid addr");
+        let (kind, address) = udp_locator_fields(&v4);
+        assert_eq!(kind, LOCATOR_KIND_UDPV4);
+        assert_eq!(&address[..12], &[0u8; 12]);
+        assert_eq!(&address[12..], &[10, 0, 0, 7]);
+
+        let v6: SocketAddr = "[ff02::ffff:efff:1]:7400".parse().expect("valid addr");
+        let (kind, address) = udp_locator_fields(&v6);
+        assert_eq!(kind, LOCATOR_KIND_UDPV6);
+        assert_eq!(
+            Ipv6Addr::from(address),
+            Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0xffff, 0xefff, 0x0001)
+        );
+    }
 }
@@ -40,9 +40,12 @@ mod locator;
 pub use acknack::{encode_acknack, encode_acknack_with_count, encode_acknack_with_final};
 pub use data::{encode_data, encode_data_frag};
 pub use gap::encode_gap;
-pub use heartbeat::{encode_heartbeat, encode_heartbeat_final};
-pub use info::{encode_info_dst, encode_info_ts};
-pub use locator::{encode_multicast_locator, encode_unicast_locator};
+pub use heartbeat::{encode_heartbeat, encode_heartbeat_final, encode_heartbeat_liveliness};
+pub use info::{encode_info_dst, encode_info_ts, encode_info_ts_ns};
+pub use locator::{
+    encode_multicast_locator, encode_unicast_locator, udp_locator_fields, LOCATOR_KIND_UDPV4,
+    LOCATOR_KIND_UDPV6,
+};
 
 /// Result type for RTPS encoding operations.
 pub type RtpsEncodeResult<T> = Result<T, RtpsEncodeError>;
@@ -0,0 +1,331 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Side-by-side type versions on one topic.
+//!
+//! During a rolling upgrade a writer can publish each sample in several
+//! type versions at once (its native version plus down- or up-converted
+//! ones). Every copy shares the writer sequence number and is tagged with
+//! `PID_HDDS_TYPE_VERSION`; a reader keeps only the copy in the highest
+//! version both sides support, so no topic rename is needed.
+//!
+//! Version numbers are the schema registry's subject versions, so the
+//! registry stays the single source of truth for what version N looks like.
+//!
+//! # DATA tag (`PID_HDDS_TYPE_VERSION`)
+//!
+//! | Offset | Size | Field |
+//! |--------|------|-------|
+//! | 0 | 4 | version of this copy (u32 LE) |
+//! | 4 | 4 | writer's native version (u32 LE) |
+//! | 8 | 4 | offered count N (u32 LE) |
+//! | 12 | 4*N | offered versions, ascending (u32 LE) |
+//!
+//! Carrying the offered set in every sample keeps selection stateless: a
+//! reader never depends on having seen the writer's SEDP announcement.
+//!
+//! # SEDP announcement (`PID_HDDS_TYPE_VERSIONS`)
+//!
+//! `"HDTV"` magic, native version (u32 LE), entry count (u32 LE), then per
+//! entry: version (u32 LE), type name (CDR string), TypeObject length
+//! (u32 LE, 0 if absent) and CDR2 TypeObject bytes, each padded to 4.
+
+use crate::xtypes::CompleteTypeObject;
+use crate::{Cdr2Decode, Cdr2Encode};
+
+/// Upper bound on versions offered by one writer.
+pub const MAX_TYPE_VERSIONS: usize = 16;
+
+const ANNOUNCEMENT_MAGIC: &[u8; 4] = b"HDTV";
+
+/// Highest version present in both `offered` and `supported`.
+#[must_use]
+pub fn negotiate_type_version(offered: &[u32], supported: &[u32]) -> Option<u32> {
+    offered
+        .iter()
+        .copied()
+        .filter(|v| supported.contains(v))
+        .max()
+}
+
+// ============================================================================
+// DATA tag
+// ============================================================================
+
+/// Per-sample type version tag carried in DATA inline QoS.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeVersionTag {
+    /// Version this copy of the sample is encoded in.
+    pub sample: u32,
+    /// Version of the writer's own data type.
+    pub native: u32,
+    /// All versions the writer publishes, ascending.
+    pub offered: Vec<u32>,
+}
+
+impl TypeVersionTag {
+    /// Whether a reader supporting `supported` keeps this copy.
+    ///
+    /// Readers without a declared version (`None`) keep the writer's
+    /// native copy, which is what they received before versioning.
+    #[must_use]
+    pub fn accepted_by(&self, supported: Option<&[u32]>) -> bool {
+        match supported {
+            Some(supported) => {
+                negotiate_type_version(&self.offered, supported) == Some(self.sample)
+            }
+            None => self.sample == self.native,
+        }
+    }
+
+    /// Encoded length in bytes.
+    #[must_use]
+    pub fn encoded_len(&self) -> usize {
+        12 + 4 * self.offered.len()
+    }
+
+    /// Append the encoded tag to `out`.
+    pub fn encode_into(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.sample.to_le_bytes());
+        out.extend_from_slice(&self.native.to_le_bytes());
+        out.extend_from_slice(&(self.offered.len() as u32).to_le_bytes());
+        for v in &self.offered {
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+    }
+
+    /// Decode a tag. Returns `None` if truncated, oversized, or if the
+    /// sample or native version is not among the offered ones.
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let sample = read_u32(bytes, 0)?;
+        let native = read_u32(bytes, 4)?;
+        let count = read_u32(bytes, 8)? as usize;
+        if count > MAX_TYPE_VERSIONS {
+            return None;
+        }
+        let offered = (0..count)
+            .map(|i| read_u32(bytes, 12 + 4 * i))
+            .collect::<Option<Vec<_>>>()?;
+        if !offered.contains(&sample) || !offered.contains(&native) {
+            return None;
+        }
+        Some(Self {
+            sample,
+            native,
+            offered,
+        })
+    }
+}
+
+// ============================================================================
+// SEDP announcement
+// ============================================================================
+
+/// One type version announced by an endpoint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnnouncedTypeVersion {
+    pub version: u32,
+    pub type_name: String,
+    pub type_object: Option<CompleteTypeObject>,
+}
+
+/// Type versions an endpoint publishes (writer) or accepts (reader).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeVersionAnnouncement {
+    /// Version of the endpoint's own data type.
+    pub native: u32,
+    /// All versions, including the native one.
+    pub versions: Vec<AnnouncedTypeVersion>,
+}
+
+impl TypeVersionAnnouncement {
+    /// Version numbers, ascending.
+    #[must_use]
+    pub fn version_numbers(&self) -> Vec<u32> {
+        let mut numbers: Vec<u32> = self.versions.iter().map(|v| v.version).collect();
+        numbers.sort_unstable();
+        numbers
+    }
+
+    /// Announced entry for `version`.
+    #[must_use]
+    pub fn get(&self, version: u32) -> Option<&AnnouncedTypeVersion> {
+        self.versions.iter().find(|v| v.version == version)
+    }
+
+    /// Insert or replace the entry for the native version.
+    pub fn set_native_type(&mut self, type_name: &str, type_object: Option<CompleteTypeObject>) {
+        let native = self.native;
+        self.versions.retain(|v| v.version != native);
+        self.versions.push(AnnouncedTypeVersion {
+            version: native,
+            type_name: type_name.to_string(),
+            type_object,
+        });
+    }
+
+    /// Encode as a SEDP parameter value (4-byte aligned).
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(64);
+        out.extend_from_slice(ANNOUNCEMENT_MAGIC);
+        out.extend_from_slice(&self.native.to_le_bytes());
+        out.extend_from_slice(&(self.versions.len() as u32).to_le_bytes());
+        for entry in &self.versions {
+            out.extend_from_slice(&entry.version.to_le_bytes());
+
+            let name = entry.type_name.as_bytes();
+            out.extend_from_slice(&(name.len() as u32 + 1).to_le_bytes());
+            out.extend_from_slice(name);
+            out.push(0);
+            pad4(&mut out);
+
+            let encoded = entry.type_object.as_ref().and_then(|obj| {
+                let mut buf = vec![0u8; obj.max_cdr2_size()];
+                let len = obj.encode_cdr2_le(&mut buf).ok()?;
+                buf.truncate(len);
+                Some(buf)
+            });
+            let encoded = encoded.unwrap_or_default();
+            out.extend_from_slice(&(encoded.len() as u32).to_le_bytes());
+            out.extend_from_slice(&encoded);
+            pad4(&mut out);
+        }
+        out
+    }
+
+    /// Decode a SEDP parameter value. TypeObjects that fail to decode are
+    /// kept as `None` rather than rejecting the whole announcement.
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.get(..4)? != ANNOUNCEMENT_MAGIC {
+            return None;
+        }
+        let native = read_u32(bytes, 4)?;
+        let count = read_u32(bytes, 8)? as usize;
+        if count > MAX_TYPE_VERSIONS {
+            return None;
+        }
+
+        let mut offset = 12;
+        let mut versions = Vec::with_capacity(count);
+        for _ in 0..count {
+            let version = read_u32(bytes, offset)?;
+            let name_len = read_u32(bytes, offset + 4)? as usize;
+            offset += 8;
+            let name = bytes.get(offset..offset + name_len)?;
+            let type_name =
+                String::from_utf8_lossy(name.strip_suffix(&[0]).unwrap_or(name)).into_owned();
+            offset = align4(offset + name_len);
+
+            let obj_len = read_u32(bytes, offset)? as usize;
+            offset += 4;
+            let obj_bytes = bytes.get(offset..offset + obj_len)?;
+            let type_object = if obj_len == 0 {
+                None
+            } else {
+                CompleteTypeObject::decode_cdr2_le(obj_bytes)
+                    .ok()
+                    .map(|(obj, _)| obj)
+            };
+            offset = align4(offset + obj_len);
+
+            versions.push(AnnouncedTypeVersion {
+                version,
+                type_name,
+                type_object,
+            });
+        }
+
+        Some(Self { native, versions })
+    }
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    let b = bytes.get(offset..offset + 4)?;
+    Some(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+fn align4(n: usize) -> usize {
+    (n + 3) & !3
+}
+
+fn pad4(out: &mut Vec<u8>) {
+    out.resize(align4(out.len()), 0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negotiate_picks_highest_common() {
+        assert_eq!(negotiate_type_version(&[1, 2, 3], &[2, 3, 4]), Some(3));
+        assert_eq!(negotiate_type_version(&[1, 2], &[2]), Some(2));
+        assert_eq!(negotiate_type_version(&[1, 2], &[3]), None);
+    }
+
+    #[test]
+    fn test_tag_roundtrip_and_acceptance() {
+        let v1 = TypeVersionTag {
+            sample: 1,
+            native: 2,
+            offered: vec![1, 2],
+        };
+        let v2 = TypeVersionTag {
+            sample: 2,
+            ..v1.clone()
+        };
+
+        let mut buf = Vec::new();
+        v1.encode_into(&mut buf);
+        assert_eq!(buf.len(), v1.encoded_len());
+        assert_eq!(TypeVersionTag::from_bytes(&buf), Some(v1.clone()));
+
+        // Old reader keeps v1 only; new reader keeps v2 only.
+        assert!(v1.accepted_by(Some(&[1])));
+        assert!(!v2.accepted_by(Some(&[1])));
+        assert!(v2.accepted_by(Some(&[1, 2])));
+        assert!(!v1.accepted_by(Some(&[1, 2])));
+        // Unversioned reader keeps the native copy.
+        assert!(v2.accepted_by(None));
+        assert!(!v1.accepted_by(None));
+
+        // Sample version outside the offered set is rejected.
+        buf[0] = 7;
+        assert_eq!(TypeVersionTag::from_bytes(&buf), None);
+        assert_eq!(TypeVersionTag::from_bytes(&buf[..10]), None);
+    }
+
+    #[test]
+    fn test_announcement_roundtrip() {
+        let announcement = TypeVersionAnnouncement {
+            native: 2,
+            versions: vec![
+                AnnouncedTypeVersion {
+                    version: 2,
+                    type_name: "fleet::Pose".to_string(),
+                    type_object: None,
+                },
+                AnnouncedTypeVersion {
+                    version: 1,
+                    type_name: "fleet::PoseV1".to_string(),
+                    type_object: None,
+                },
+            ],
+        };
+
+        let bytes = announcement.to_bytes();
+        assert_eq!(bytes.len() % 4, 0);
+        let decoded = TypeVersionAnnouncement::from_bytes(&bytes).expect("decode");
+        assert_eq!(decoded, announcement);
+        assert_eq!(decoded.version_numbers(), vec![1, 2]);
+        assert_eq!(
+            decoded.get(1).map(|v| v.type_name.as_str()),
+            Some("fleet::PoseV1")
+        );
+
+        assert_eq!(TypeVersionAnnouncement::from_bytes(b"RTI!\0\0\0\0"), None);
+    }
+}
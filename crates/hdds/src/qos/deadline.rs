@@ -37,6 +37,8 @@
 //! assert!(writer_deadline.is_compatible_with(&reader_deadline));
 //! ```
 
+use crate::core::rt::clock::{Clock, SystemClock};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 /// DEADLINE QoS policy
@@ -178,6 +180,7 @@ macro_rules! impl_deadline_tracker {
             deadline: Duration,
             $field: Option<Instant>,
             missed_count: u64,
+            clock: Arc<dyn Clock>,
         }
 
         impl $struct_name {
@@ -187,16 +190,29 @@ macro_rules! impl_deadline_tracker {
             ///
             /// * `deadline` - Maximum time between events
             pub fn new(deadline: Duration) -> Self {
+                Self::with_clock(deadline, SystemClock::shared())
+            }
+
+            /// Create new deadline tracker timed on `clock`
+            ///
+            /// Use the participant's clock (`Participant::clock`) so the
+            /// deadline follows virtual time during replays.
+            pub fn with_clock(deadline: Duration, clock: Arc<dyn Clock>) -> Self {
                 Self {
                     deadline,
                     $field: None,
                     missed_count: 0,
+                    clock,
                 }
             }
 
+            fn elapsed_since(&self, last: Instant) -> Duration {
+                self.clock.instant().saturating_duration_since(last)
+            }
+
             #[doc = $event_doc]
             pub fn $on_event(&mut self) {
-                self.$field = Some(Instant::now());
+                self.$field = Some(self.clock.instant());
             }
 
             #[doc = $is_missed_doc]
@@ -206,7 +222,7 @@ macro_rules! impl_deadline_tracker {
                 }
 
                 if let Some(last) = self.$field {
-                    last.elapsed() > self.deadline
+                    self.elapsed_since(last) > self.deadline
                 } else {
                     false // No event yet, not missed
                 }
@@ -245,7 +261,7 @@ macro_rules! impl_deadline_tracker {
                 }
 
                 self.$field.map(|last| {
-                    let elapsed = last.elapsed();
+                    let elapsed = self.elapsed_since(last);
                     if elapsed < self.deadline {
                         self.deadline - elapsed
                     } else {
@@ -503,4 +519,22 @@ mod tests {
         assert!(!tracker.is_missed());
         assert!(!tracker.check());
     }
+
+    #[test]
+    fn test_tracker_on_virtual_clock() {
+        let clock = Arc::new(crate::core::rt::clock::VirtualClock::new());
+        let mut tracker = DeadlineTracker::with_clock(Duration::from_secs(1), clock.clone());
+        tracker.on_write();
+
+        clock.advance(Duration::from_millis(400));
+        assert_eq!(
+            tracker.time_until_deadline(),
+            Some(Duration::from_millis(600))
+        );
+        assert!(!tracker.check());
+
+        clock.advance(Duration::from_millis(601));
+        assert!(tracker.check());
+        assert_eq!(tracker.missed_count(), 1);
+    }
 }
@@ -43,6 +43,8 @@
 //! assert!(writer_lifespan.is_compatible_with(&reader_lifespan));
 //! ```
 
+use crate::core::rt::clock::{Clock, SystemClock};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 /// LIFESPAN QoS policy
@@ -175,6 +177,8 @@ impl Lifespan {
 pub struct LifespanChecker {
     /// Lifespan duration
     lifespan: Lifespan,
+    /// Time source sample ages are measured on
+    clock: Arc<dyn Clock>,
 }
 
 impl LifespanChecker {
@@ -194,7 +198,30 @@ impl LifespanChecker {
     /// let checker = LifespanChecker::new(lifespan);
     /// ```
     pub fn new(lifespan: Lifespan) -> Self {
-        Self { lifespan }
+        Self::with_clock(lifespan, SystemClock::shared())
+    }
+
+    /// Create new lifespan checker measuring sample ages on `clock`
+    ///
+    /// Sample timestamps must come from the same clock (`Clock::instant`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdds::qos::lifespan::{Lifespan, LifespanChecker};
+    /// use hdds::{Clock, VirtualClock};
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    ///
+    /// let clock = Arc::new(VirtualClock::new());
+    /// let checker = LifespanChecker::with_clock(Lifespan::from_secs(5), clock.clone());
+    ///
+    /// let received = clock.instant();
+    /// clock.advance(Duration::from_secs(6));
+    /// assert!(checker.is_expired(received));
+    /// ```
+    pub fn with_clock(lifespan: Lifespan, clock: Arc<dyn Clock>) -> Self {
+        Self { lifespan, clock }
     }
 
     /// Check if a sample has expired
@@ -229,7 +256,10 @@ impl LifespanChecker {
             return false; // Never expires
         }
 
-        let age = Instant::now().saturating_duration_since(sample_timestamp);
+        let age = self
+            .clock
+            .instant()
+            .saturating_duration_since(sample_timestamp);
         age > self.lifespan.duration
     }
 
@@ -275,7 +305,10 @@ impl LifespanChecker {
             return None; // Never expires
         }
 
-        let age = Instant::now().saturating_duration_since(sample_timestamp);
+        let age = self
+            .clock
+            .instant()
+            .saturating_duration_since(sample_timestamp);
         self.lifespan.duration.checked_sub(age)
     }
 }
@@ -466,6 +499,28 @@ mod tests {
         assert!(checker.remaining_time(timestamp).is_none());
     }
 
+    #[test]
+    fn test_checker_on_virtual_clock() {
+        let clock = Arc::new(crate::core::rt::clock::VirtualClock::new());
+        let checker = LifespanChecker::with_clock(Lifespan::from_secs(10), clock.clone());
+        let timestamp = clock.instant();
+
+        // Wall time does not age the sample
+        thread::sleep(Duration::from_millis(5));
+        assert_eq!(
+            checker.remaining_time(timestamp),
+            Some(Duration::from_secs(10))
+        );
+
+        clock.advance(Duration::from_secs(4));
+        assert_eq!(
+            checker.remaining_time(timestamp),
+            Some(Duration::from_secs(6))
+        );
+        clock.advance(Duration::from_secs(7));
+        assert!(checker.is_expired(timestamp));
+    }
+
     #[test]
     fn test_lifespan_clone() {
         let lifespan1 = Lifespan::new(Duration::from_secs(5));
@@ -15,6 +15,16 @@
 //! - Writer `["sensor"]`, Reader `["actuator"]` -> Incompatible \[X\]
 //! - Writer `[]`, Reader `[]` -> Compatible \[OK\] (both use default partition)
 //! - Writer `["sensor", "actuator"]`, Reader `["actuator"]` -> Compatible \[OK\]
+//! - Writer `["bench/*"]`, Reader `["bench/3"]` -> Compatible \[OK\] (wildcard)
+//!
+//! # Wildcards
+//!
+//! Names may be fnmatch-style patterns: `*` (any sequence), `?` (any
+//! character), `[abc]`, `[a-z]`, `[!abc]` (character sets) and `\` to escape
+//! a special character. A pattern on either side matches plain names on the
+//! other side; two patterns only match if they are identical. The default
+//! partition behaves like the single name `""`, so a `"*"` reader also
+//! receives from writers in the default partition.
 //!
 //! # Use Cases
 //!
@@ -126,7 +136,8 @@ impl Partition {
 
     /// Check QoS compatibility between offered (writer) and requested (reader)
     ///
-    /// **Rule:** Partitions must have at least one common element
+    /// **Rule:** Partitions must have at least one common element, where
+    /// wildcard patterns match the names of the other side (see module docs)
     ///
     /// Special case: If both are default (empty), they are compatible
     ///
@@ -164,20 +175,17 @@ impl Partition {
     /// assert!(writer.is_compatible_with(&reader)); // \[OK\]
     /// ```
     pub fn is_compatible_with(&self, requested: &Partition) -> bool {
-        // Both default partitions -> compatible
-        if self.is_default() && requested.is_default() {
-            return true;
-        }
-
-        // If either is default but not both -> incompatible
-        if self.is_default() || requested.is_default() {
-            return false;
-        }
+        // The default partition is the single empty name
+        self.effective_names().any(|offered| {
+            requested
+                .effective_names()
+                .any(|name| names_match(offered, name))
+        })
+    }
 
-        // Check intersection (at least one common partition)
-        self.names
-            .iter()
-            .any(|writer_partition| requested.names.contains(writer_partition))
+    fn effective_names(&self) -> impl Iterator<Item = &str> {
+        let default = self.names.is_empty().then_some("");
+        self.names.iter().map(String::as_str).chain(default)
     }
 
     /// Add a partition name to the list
@@ -224,6 +232,93 @@ impl Partition {
     }
 }
 
+/// Whether two partition names match (DDS v1.4 Sec.2.2.3.13).
+fn names_match(offered: &str, requested: &str) -> bool {
+    if offered == requested {
+        return true;
+    }
+    match (is_pattern(offered), is_pattern(requested)) {
+        (true, false) => fnmatch(offered, requested),
+        (false, true) => fnmatch(requested, offered),
+        _ => false,
+    }
+}
+
+fn is_pattern(name: &str) -> bool {
+    name.contains(['*', '?', '[', '\\'])
+}
+
+/// POSIX `fnmatch()` without flags: `*`, `?`, bracket sets and `\` escapes.
+fn fnmatch(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    // Position after the last `*` and the name position it currently covers
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        let step = match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p + 1, n));
+                p += 1;
+                continue;
+            }
+            Some('?') => Some(p + 1),
+            Some('[') => match_set(&pattern, p, name[n]),
+            Some('\\') if p + 1 < pattern.len() => (pattern[p + 1] == name[n]).then_some(p + 2),
+            Some(&c) => (c == name[n]).then_some(p + 1),
+            None => None,
+        };
+        match (step, backtrack) {
+            (Some(next), _) => {
+                p = next;
+                n += 1;
+            }
+            // Let the last `*` swallow one more character
+            (None, Some((star_p, star_n))) => {
+                backtrack = Some((star_p, star_n + 1));
+                p = star_p;
+                n = star_n + 1;
+            }
+            (None, None) => return false,
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Match `c` against the bracket set starting at `pattern[start] == '['`.
+///
+/// Returns the pattern position after the set on a match. An unterminated
+/// `[` is a literal character.
+fn match_set(pattern: &[char], start: usize, c: char) -> Option<usize> {
+    let mut i = start + 1;
+    let negated = matches!(pattern.get(i), Some('!' | '^'));
+    if negated {
+        i += 1;
+    }
+
+    let mut matched = false;
+    let mut first = true;
+    loop {
+        let lo = match pattern.get(i) {
+            None => return (c == '[').then_some(start + 1),
+            Some(']') if !first => break,
+            Some(&lo) => lo,
+        };
+        first = false;
+        if pattern.get(i + 1) == Some(&'-') && pattern.get(i + 2).is_some_and(|&hi| hi != ']') {
+            matched |= (lo..=pattern[i + 2]).contains(&c);
+            i += 3;
+        } else {
+            matched |= lo == c;
+            i += 1;
+        }
+    }
+
+    (matched != negated).then_some(i + 1)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -403,4 +498,45 @@ mod tests {
         assert_eq!(partition.len(), 0);
         assert!(partition.is_empty());
     }
+
+    #[test]
+    fn test_fnmatch_patterns() {
+        assert!(fnmatch("bench/*", "bench/3"));
+        assert!(fnmatch("bench/*", "bench/"));
+        assert!(!fnmatch("bench/*", "cell/3"));
+        assert!(fnmatch("*", ""));
+        assert!(fnmatch("cell-?", "cell-7"));
+        assert!(!fnmatch("cell-?", "cell-17"));
+        assert!(fnmatch("*-[0-9]", "cell-7"));
+        assert!(!fnmatch("*-[!0-9]", "cell-7"));
+        assert!(fnmatch("a*b*c", "axxbyyc"));
+        assert!(!fnmatch("a*b*c", "axxbyy"));
+        assert!(fnmatch("\\*", "*"));
+        assert!(!fnmatch("\\*", "x"));
+        assert!(fnmatch("[]]", "]"));
+        assert!(fnmatch("[x", "[x"));
+    }
+
+    #[test]
+    fn test_compatibility_wildcard() {
+        let reader = Partition::single("bench/*");
+        assert!(Partition::single("bench/3").is_compatible_with(&reader));
+        assert!(!Partition::single("production/3").is_compatible_with(&reader));
+
+        // Wildcards work on the writer side too
+        let writer = Partition::single("cell-?");
+        assert!(writer.is_compatible_with(&Partition::single("cell-1")));
+
+        // Two different patterns never match
+        assert!(!Partition::single("bench/*").is_compatible_with(&Partition::single("bench/?")));
+        assert!(Partition::single("bench/*").is_compatible_with(&reader));
+    }
+
+    #[test]
+    fn test_compatibility_wildcard_default_partition() {
+        let reader = Partition::single("*");
+        assert!(Partition::default().is_compatible_with(&reader));
+        assert!(Partition::default().is_compatible_with(&Partition::single("")));
+        assert!(!Partition::default().is_compatible_with(&Partition::single("?*")));
+    }
 }
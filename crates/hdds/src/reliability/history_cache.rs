@@ -12,6 +12,7 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 
 use crate::core::rt::slabpool::{SlabHandle, SlabPool};
+use crate::protocol::original_writer::OriginalWriterInfo;
 use crate::qos::{History, ResourceLimits};
 use crate::telemetry::metrics::current_time_ns;
 use crate::Error;
@@ -26,9 +27,24 @@ pub struct CacheEntry {
     pub seq: u64,
     pub slab: SlabHandle,
     pub len: usize,
+    /// Source timestamp of the message (ns since the UNIX epoch).
     pub ts_ns: u64,
     /// Instance key hash (0 = unkeyed / default instance).
     pub instance_key: u64,
+    /// Original writer of a relayed message.
+    pub original_writer: Option<OriginalWriterInfo>,
+    /// Dispose or unregister carried by the message, if any.
+    pub instance_state: Option<InstanceStateChange>,
+}
+
+/// Instance lifecycle change (dispose or unregister) kept in the history,
+/// so that repairs and replays carry the same inline QoS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InstanceStateChange {
+    /// `PID_KEY_HASH` of the instance.
+    pub key_hash: [u8; 16],
+    /// `PID_STATUS_INFO` flags (`STATUS_INFO_*`).
+    pub status_info: u8,
 }
 
 /// History cache for writer-side message retransmission.
@@ -130,6 +146,49 @@ impl HistoryCache {
     /// The instance_key is a hash that identifies the data instance for keyed topics.
     /// For unkeyed topics, use 0.
     pub fn insert_keyed(&self, seq: u64, payload: &[u8], instance_key: u64) -> Result<(), Error> {
+        self.insert_entry(seq, payload, instance_key, current_time_ns(), None, None)
+    }
+
+    /// Insert message into cache (unkeyed) with the source timestamp it was
+    /// sent with, so that repairs carry the same timestamp.
+    pub fn insert_stamped(&self, seq: u64, payload: &[u8], ts_ns: u64) -> Result<(), Error> {
+        self.insert_entry(seq, payload, 0, ts_ns, None, None)
+    }
+
+    /// Insert a relayed message (unkeyed) with its source timestamp and the
+    /// writer it was first published by, so that repairs carry both.
+    pub fn insert_relayed(
+        &self,
+        seq: u64,
+        payload: &[u8],
+        ts_ns: u64,
+        original_writer: OriginalWriterInfo,
+    ) -> Result<(), Error> {
+        self.insert_entry(seq, payload, 0, ts_ns, Some(original_writer), None)
+    }
+
+    /// Insert a dispose or unregister (unkeyed) with its source timestamp;
+    /// `payload` is the serialized key or sample it was sent with.
+    pub fn insert_instance_state(
+        &self,
+        seq: u64,
+        payload: &[u8],
+        ts_ns: u64,
+        change: InstanceStateChange,
+    ) -> Result<(), Error> {
+        self.insert_entry(seq, payload, 0, ts_ns, None, Some(change))
+    }
+
+    fn insert_entry(
+        &self,
+        seq: u64,
+        payload: &[u8],
+        instance_key: u64,
+        ts_ns: u64,
+        original_writer: Option<OriginalWriterInfo>,
+        instance_state: Option<InstanceStateChange>,
+    ) -> Result<(), Error> {
+        crate::alloc_scope!(Cache);
         let len = payload.len();
         let (handle, buf) = self.slabs.reserve(len).ok_or(Error::WouldBlock)?;
 
@@ -140,8 +199,10 @@ impl HistoryCache {
             seq,
             slab: handle,
             len,
-            ts_ns: current_time_ns(),
+            ts_ns,
             instance_key,
+            original_writer,
+            instance_state,
         };
 
         let should_reject = {
@@ -200,6 +261,46 @@ impl HistoryCache {
         Some(buf[..entry.len].to_vec())
     }
 
+    /// Source timestamp of the message with sequence number `seq`.
+    pub fn timestamp(&self, seq: u64) -> Option<u64> {
+        let ring = match self.ring.lock() {
+            Ok(lock) => lock,
+            Err(e) => {
+                log::debug!("[HistoryCache::timestamp] Lock poisoned, recovering");
+                e.into_inner()
+            }
+        };
+        ring.iter().find(|e| e.seq == seq).map(|e| e.ts_ns)
+    }
+
+    /// Original writer of the relayed message with sequence number `seq`.
+    pub fn original_writer(&self, seq: u64) -> Option<OriginalWriterInfo> {
+        let ring = match self.ring.lock() {
+            Ok(lock) => lock,
+            Err(e) => {
+                log::debug!("[HistoryCache::original_writer] Lock poisoned, recovering");
+                e.into_inner()
+            }
+        };
+        ring.iter()
+            .find(|e| e.seq == seq)
+            .and_then(|e| e.original_writer)
+    }
+
+    /// Dispose or unregister carried by the message with sequence number `seq`.
+    pub fn instance_state(&self, seq: u64) -> Option<InstanceStateChange> {
+        let ring = match self.ring.lock() {
+            Ok(lock) => lock,
+            Err(e) => {
+                log::debug!("[HistoryCache::instance_state] Lock poisoned, recovering");
+                e.into_inner()
+            }
+        };
+        ring.iter()
+            .find(|e| e.seq == seq)
+            .and_then(|e| e.instance_state)
+    }
+
     /// Get number of cached messages.
     pub fn len(&self) -> usize {
         match self.ring.lock() {
@@ -222,6 +323,26 @@ impl HistoryCache {
         }
     }
 
+    /// Number of entries the cache can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        match self.ring.lock() {
+            Ok(lock) => lock.capacity(),
+            Err(e) => e.into_inner().capacity(),
+        }
+    }
+
+    /// Release the capacity not used by cached entries (idle writer
+    /// hibernation). Entries are kept; capacity grows back on insert.
+    pub fn shrink_to_fit(&self) {
+        match self.ring.lock() {
+            Ok(mut lock) => lock.shrink_to_fit(),
+            Err(e) => {
+                log::debug!("[HistoryCache::shrink_to_fit] Lock poisoned, recovering");
+                e.into_inner().shrink_to_fit();
+            }
+        }
+    }
+
     /// Get current quota usage in bytes.
     pub fn quota_bytes(&self) -> usize {
         self.quota_bytes.load(Ordering::Relaxed)
@@ -257,6 +378,18 @@ impl HistoryCache {
         ring.iter().map(|e| (e.seq, e.slab, e.len)).collect()
     }
 
+    /// Snapshot all cache entries, oldest first.
+    pub fn entries(&self) -> Vec<CacheEntry> {
+        let ring = match self.ring.lock() {
+            Ok(lock) => lock,
+            Err(e) => {
+                log::debug!("[HistoryCache::entries] Lock poisoned, recovering");
+                e.into_inner()
+            }
+        };
+        ring.iter().copied().collect()
+    }
+
     /// Snapshot all cached payloads (seq + bytes) for late-joiner replay.
     pub fn snapshot_payloads(&self) -> Vec<(u64, Vec<u8>)> {
         let ring = match self.ring.lock() {
@@ -591,6 +724,23 @@ mod tests {
         assert!(cache.is_empty());
     }
 
+    #[test]
+    fn test_cache_shrink_keeps_entries() {
+        let cache = make_cache();
+        cache
+            .insert(1, b"one")
+            .expect("Cache insert should succeed");
+        assert!(cache.capacity() >= 100);
+
+        cache.shrink_to_fit();
+        assert!(cache.capacity() < 100);
+        assert_eq!(cache.get(1), Some(b"one".to_vec()));
+        cache
+            .insert(2, b"two")
+            .expect("Cache insert should succeed");
+        assert_eq!(cache.len(), 2);
+    }
+
     #[test]
     fn test_cache_insert_get() {
         let cache = make_cache();
@@ -25,6 +25,8 @@ pub const TAG_RETRANSMIT_RECEIVED: u16 = 104;
 pub const TAG_NACKS_SENT: u16 = 105;
 /// Tag for total heartbeats sent
 pub const TAG_HEARTBEATS_SENT: u16 = 106;
+/// Tag for total NACKs received (writer-side)
+pub const TAG_NACKS_RECEIVED: u16 = 107;
 
 /// Reliable QoS metrics collector
 ///
@@ -87,6 +89,11 @@ pub struct ReliableMetrics {
 
     /// Total heartbeats sent (writer-side, future)
     heartbeats_sent: AtomicU64,
+
+    /// Total NACKs received (writer-side)
+    ///
+    /// ACKNACKs addressed to this writer that request missing sequences.
+    nacks_received: AtomicU64,
 }
 
 impl ReliableMetrics {
@@ -100,6 +107,7 @@ impl ReliableMetrics {
             retransmit_received: AtomicU64::new(0),
             nacks_sent: AtomicU64::new(0),
             heartbeats_sent: AtomicU64::new(0),
+            nacks_received: AtomicU64::new(0),
         }
     }
 
@@ -162,6 +170,11 @@ impl ReliableMetrics {
         self.nacks_sent.fetch_add(count, Ordering::Relaxed);
     }
 
+    /// Increment NACKs received (writer-side)
+    pub fn increment_nacks_received(&self, count: u64) {
+        self.nacks_received.fetch_add(count, Ordering::Relaxed);
+    }
+
     /// Increment heartbeats sent (T2.6)
     pub fn increment_heartbeats_sent(&self, count: u64) {
         self.heartbeats_sent.fetch_add(count, Ordering::Relaxed);
@@ -192,6 +205,16 @@ impl ReliableMetrics {
         self.retransmit_received.load(Ordering::Relaxed)
     }
 
+    /// Get NACKs sent count (snapshot)
+    pub fn nacks_sent(&self) -> u64 {
+        self.nacks_sent.load(Ordering::Relaxed)
+    }
+
+    /// Get NACKs received count (snapshot)
+    pub fn nacks_received(&self) -> u64 {
+        self.nacks_received.load(Ordering::Relaxed)
+    }
+
     /// Snapshot current metrics into a Frame
     ///
     /// # Arguments
@@ -255,6 +278,12 @@ impl ReliableMetrics {
             value_u64: self.heartbeats_sent.load(Ordering::Relaxed),
         });
 
+        frame.push_field(Field {
+            tag: TAG_NACKS_RECEIVED,
+            dtype: DType::U64,
+            value_u64: self.nacks_received.load(Ordering::Relaxed),
+        });
+
         frame
     }
 }
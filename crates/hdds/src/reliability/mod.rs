@@ -81,8 +81,8 @@ mod history_cache;
 
 pub use gap_tracker::GapTracker;
 pub use metrics::{
-    ReliableMetrics, TAG_GAPS_DETECTED, TAG_HEARTBEATS_SENT, TAG_MAX_GAP_SIZE, TAG_NACKS_SENT,
-    TAG_OUT_OF_ORDER, TAG_RETRANSMIT_RECEIVED, TAG_RETRANSMIT_SENT,
+    ReliableMetrics, TAG_GAPS_DETECTED, TAG_HEARTBEATS_SENT, TAG_MAX_GAP_SIZE, TAG_NACKS_RECEIVED,
+    TAG_NACKS_SENT, TAG_OUT_OF_ORDER, TAG_RETRANSMIT_RECEIVED, TAG_RETRANSMIT_SENT,
 };
 pub use rtps_range::RtpsRange;
 pub use seq::SeqNumGenerator;
@@ -148,4 +148,4 @@ pub use reader::{
 // Public re-exports: History cache
 // ============================================================================
 
-pub use history_cache::{CacheEntry, HistoryCache, LENGTH_UNLIMITED};
+pub use history_cache::{CacheEntry, HistoryCache, InstanceStateChange, LENGTH_UNLIMITED};
@@ -238,6 +238,8 @@ pub struct NackScheduler {
     backoff: Duration,
     initial_backoff: Duration,
     metrics: Option<Arc<ReliableMetrics>>,
+    /// Sequences given up on since the last `take_lost()`.
+    lost: u64,
 }
 
 /// NackScheduler state machine:
@@ -285,6 +287,7 @@ impl NackScheduler {
             backoff: initial_backoff,
             initial_backoff,
             metrics: None,
+            lost: 0,
         }
     }
 
@@ -304,6 +307,20 @@ impl NackScheduler {
     pub fn on_receive(&mut self, seq: u64) {
         let had_gaps_before = !self.tracker.pending_gaps().is_empty();
 
+        // A missing sequence arriving after a NACK is a retransmission
+        if self.retry_count > 0 {
+            if let Some(ref metrics) = self.metrics {
+                if self
+                    .tracker
+                    .pending_gaps()
+                    .iter()
+                    .any(|gap| gap.contains(&seq))
+                {
+                    metrics.increment_retransmit_received(1);
+                }
+            }
+        }
+
         self.tracker.on_receive(seq);
 
         let has_gaps_now = !self.tracker.pending_gaps().is_empty();
@@ -368,6 +385,12 @@ impl NackScheduler {
 
         // Give up after MAX_RETRIES: data is considered permanently lost
         if self.retry_count >= MAX_RETRIES {
+            // Stop tracking the abandoned gaps so they are counted once
+            let abandoned: Vec<Range<u64>> = self.tracker.pending_gaps().to_vec();
+            for gap in abandoned {
+                self.lost += gap.end - gap.start;
+                self.tracker.mark_filled(RtpsRange::from(gap));
+            }
             self.reset();
         } else {
             // Exponential backoff: double the wait time
@@ -376,6 +399,12 @@ impl NackScheduler {
         }
     }
 
+    /// Number of sequences given up on (MAX_RETRIES NACKs without repair)
+    /// since the previous call. Feeds the reader's SAMPLE_LOST status.
+    pub fn take_lost(&mut self) -> u64 {
+        std::mem::take(&mut self.lost)
+    }
+
     /// Mark a retransmitted sequence as received.
     ///
     /// Called when the writer responds to our NACK with the missing data.
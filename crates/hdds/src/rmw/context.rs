@@ -57,7 +57,8 @@ impl RmwContext {
     /// Transport selection (checked in order):
     /// 1. `HDDS_TRANSPORT=intra` - force intra-process only (no network)
     /// 2. `HDDS_TRANSPORT=udp`   - force UDP multicast (fail if unavailable)
-    /// 3. Default: try UDP multicast, fall back to intra-process on failure
+    /// 3. `HDDS_TRANSPORT=loopback` - RTPS over the in-process loopback bus (no sockets)
+    /// 4. Default: try UDP multicast, fall back to intra-process on failure
     ///
     /// When using UDP, each process gets a unique participant ID (0, 1, 2...)
     /// which maps to different RTPS unicast ports.
@@ -76,6 +77,13 @@ impl RmwContext {
                     .with_transport(TransportMode::UdpMulticast);
                 Self::from_builder(builder)
             }
+            "loopback" => {
+                log::info!("[rmw] HDDS_TRANSPORT=loopback: using the loopback bus");
+                let builder = Participant::builder(name)
+                    .domain_id(0)
+                    .with_transport(TransportMode::LoopbackBus);
+                Self::from_builder(builder)
+            }
             _ => {
                 // Default: try UDP, fall back to intra-process
                 let builder = Participant::builder(name)
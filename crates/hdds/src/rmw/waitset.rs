@@ -12,8 +12,8 @@
 
 use crate::dds::WaitSet;
 use crate::dds::{
-    Condition, DataReader, Error as ApiError, GuardCondition, Participant, Result as ApiResult,
-    StatusCondition, StatusMask, DDS,
+    Condition, DataReader, Error as ApiError, ErrorContext, GuardCondition, Participant,
+    Result as ApiResult, StatusCondition, StatusMask, DDS,
 };
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
@@ -98,7 +98,8 @@ impl RmwWaitSet {
             if registry.contains_key(&key) {
                 keys.push(key);
             } else {
-                return Err(ApiError::Config);
+                return Err(ApiError::config("triggered condition is not registered")
+                    .with_context(ErrorContext::new().entity("rmw waitset")));
             }
         }
 
@@ -125,7 +126,8 @@ impl RmwWaitSet {
             .map_err(|_| ApiError::WouldBlock)?;
 
         if registry.contains_key(&key) {
-            return Err(ApiError::Config);
+            return Err(ApiError::config("condition already attached")
+                .with_context(ErrorContext::new().entity("rmw waitset")));
         }
 
         self.inner
@@ -173,13 +175,11 @@ impl ConditionHandle {
             .map_err(|_| ApiError::WouldBlock)?;
 
         let Some(condition) = registry.remove(&self.key) else {
-            return Err(ApiError::Config);
+            return Err(ApiError::config("condition not attached")
+                .with_context(ErrorContext::new().entity("rmw waitset")));
         };
 
-        self.inner
-            .waitset
-            .detach_condition(condition)
-            .map_err(|_| ApiError::Config)
+        self.inner.waitset.detach_condition(condition)
     }
 }
 
@@ -6,9 +6,9 @@
 //! The ServiceClient sends requests to a service and waits for replies.
 
 use crate::core::discovery::GUID;
-use crate::core::ser::Cdr2Decode;
 use crate::dds::{DataReader, DataWriter, Participant};
 use crate::rpc::error::{RpcError, RpcResult};
+use crate::rpc::message::RpcMessage;
 use crate::rpc::types::{ReplyHeader, RequestHeader, SampleIdentity};
 use dashmap::DashMap;
 use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
@@ -16,9 +16,6 @@ use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::oneshot;
 
-/// RPC type identifier: ASCII "RPC\0" as u32
-const RPC_TYPE_ID: u32 = 0x5250_4300;
-
 /// RPC Client for sending requests to a service.
 ///
 /// # Example
@@ -60,13 +57,6 @@ pub struct ServiceClient {
     shutdown: Arc<AtomicBool>,
 }
 
-/// Internal message type for RPC (header + payload)
-#[derive(Debug, Clone)]
-pub(crate) struct RpcMessage {
-    pub header: Vec<u8>,
-    pub payload: Vec<u8>,
-}
-
 /// Reply data received from service
 #[derive(Debug)]
 struct ReplyData {
@@ -74,41 +64,6 @@ struct ReplyData {
     payload: Vec<u8>,
 }
 
-impl crate::dds::DDS for RpcMessage {
-    fn type_descriptor() -> &'static crate::core::types::TypeDescriptor {
-        static DESC: crate::core::types::TypeDescriptor = crate::core::types::TypeDescriptor {
-            type_id: RPC_TYPE_ID,
-            type_name: "RpcMessage",
-            size_bytes: 0,
-            alignment: 1,
-            is_variable_size: true,
-            fields: &[],
-        };
-        &DESC
-    }
-
-    fn encode_cdr2(&self, buf: &mut [u8]) -> crate::dds::Result<usize> {
-        let total_len = self.header.len() + self.payload.len();
-        if buf.len() < total_len {
-            return Err(crate::dds::Error::BufferTooSmall);
-        }
-        buf[..self.header.len()].copy_from_slice(&self.header);
-        buf[self.header.len()..total_len].copy_from_slice(&self.payload);
-        Ok(total_len)
-    }
-
-    fn decode_cdr2(buf: &[u8]) -> crate::dds::Result<Self> {
-        // For replies, we need at least the header (28 bytes)
-        if buf.len() < 28 {
-            return Err(crate::dds::Error::SerializationError);
-        }
-        Ok(Self {
-            header: buf[..28].to_vec(),
-            payload: buf[28..].to_vec(),
-        })
-    }
-}
-
 impl ServiceClient {
     /// Create a new RPC client for a service.
     ///
@@ -183,23 +138,13 @@ impl ServiceClient {
         let seq = self.sequence.fetch_add(1, Ordering::Relaxed);
         let request_id = SampleIdentity::new(self.client_guid, seq);
 
-        // Create request header
-        let header = RequestHeader::new(request_id);
-        let mut header_bytes = vec![0u8; 48];
-        use crate::core::ser::Cdr2Encode;
-        header
-            .encode_cdr2_le(&mut header_bytes)
-            .map_err(|e| RpcError::SerializationError(format!("{:?}", e)))?;
+        let msg = RpcMessage::request(&RequestHeader::new(request_id), payload)?;
 
         // Create channel for reply
         let (tx, rx) = oneshot::channel();
         self.pending.insert(request_id, tx);
 
         // Send request
-        let msg = RpcMessage {
-            header: header_bytes,
-            payload: payload.to_vec(),
-        };
         if let Err(e) = self.request_writer.write(&msg) {
             self.pending.remove(&request_id);
             return Err(e.into());
@@ -249,7 +194,7 @@ impl Drop for ServiceClient {
 }
 
 /// Generate a unique client GUID for this RPC session.
-fn generate_client_guid() -> GUID {
+pub(crate) fn generate_client_guid() -> GUID {
     use std::time::{SystemTime, UNIX_EPOCH};
 
     let now = SystemTime::now()
@@ -291,18 +236,15 @@ fn start_reply_listener(
 
             // Try to take a reply
             if let Ok(Some(msg)) = reader.take() {
-                // Parse reply header
-                if msg.header.len() >= 28 {
-                    if let Ok((reply_header, _)) = ReplyHeader::decode_cdr2_le(&msg.header) {
-                        // Find pending request and send reply
-                        if let Some((_, tx)) = pending.remove(&reply_header.related_request_id) {
-                            let reply_data = ReplyData {
-                                header: reply_header,
-                                payload: msg.payload,
-                            };
-                            // Ignore send error - receiver may have dropped
-                            drop(tx.send(reply_data));
-                        }
+                if let Ok((reply_header, payload)) = msg.parse_reply() {
+                    // Find pending request and send reply
+                    if let Some((_, tx)) = pending.remove(&reply_header.related_request_id) {
+                        let reply_data = ReplyData {
+                            header: reply_header,
+                            payload: payload.to_vec(),
+                        };
+                        // Ignore send error - receiver may have dropped
+                        drop(tx.send(reply_data));
                     }
                 }
             }
@@ -316,14 +258,6 @@ fn start_reply_listener(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::dds::DDS;
-
-    #[test]
-    fn rpc_message_type_descriptor() {
-        let desc = <RpcMessage as DDS>::type_descriptor();
-        assert_eq!(desc.type_name, "RpcMessage");
-        assert!(desc.is_variable_size);
-    }
 
     #[test]
     fn generate_guid_is_unique() {
@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Payload encoding for the typed [`Client`](super::Client) and
+//! [`Service`](super::Service), and the field helpers used by code generated
+//! with [`#[service]`](super::service).
+
+use crate::core::ser::{Cdr2Decode, Cdr2Encode};
+use crate::dds::DDS;
+use crate::rpc::error::{RpcError, RpcResult};
+
+/// Largest serialized request or reply (the writer's sample limit).
+const MAX_PAYLOAD: usize = 64 * 1024;
+
+/// Serialize a typed request or reply.
+pub(crate) fn encode_payload<T: DDS>(value: &T) -> RpcResult<Vec<u8>> {
+    let mut buf = vec![0u8; MAX_PAYLOAD];
+    let len = value
+        .encode_cdr2(&mut buf)
+        .map_err(|e| RpcError::SerializationError(e.to_string()))?;
+    buf.truncate(len);
+    Ok(buf)
+}
+
+/// Deserialize a typed request or reply.
+pub(crate) fn decode_payload<T: DDS>(payload: &[u8]) -> RpcResult<T> {
+    T::decode_cdr2(payload).map_err(|e| RpcError::DeserializationError(e.to_string()))
+}
+
+/// Append `value` at `*offset`.
+#[doc(hidden)]
+pub fn encode_field<T: Cdr2Encode + ?Sized>(
+    value: &T,
+    buf: &mut [u8],
+    offset: &mut usize,
+) -> crate::Result<()> {
+    let dst = buf.get_mut(*offset..).ok_or(crate::Error::BufferTooSmall)?;
+    *offset += value.encode_cdr2_le(dst)?;
+    Ok(())
+}
+
+/// Read a value from the front of `src` and advance past it.
+#[doc(hidden)]
+pub fn decode_field<T: Cdr2Decode>(src: &mut &[u8]) -> crate::Result<T> {
+    let (value, used) = T::decode_cdr2_le(src)?;
+    *src = src.get(used..).ok_or(crate::Error::SerializationError)?;
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fields_roundtrip() {
+        let mut buf = [0u8; 64];
+        let mut offset = 0;
+        encode_field(&7u32, &mut buf, &mut offset).expect("u32");
+        encode_field(&"hi".to_string(), &mut buf, &mut offset).expect("string");
+        encode_field(&vec![1.5f64], &mut buf, &mut offset).expect("vec");
+
+        let mut src = &buf[..offset];
+        assert_eq!(decode_field::<u32>(&mut src).expect("u32"), 7);
+        assert_eq!(decode_field::<String>(&mut src).expect("string"), "hi");
+        assert_eq!(decode_field::<Vec<f64>>(&mut src).expect("vec"), vec![1.5]);
+        assert!(src.is_empty());
+        assert!(decode_field::<u32>(&mut src).is_err());
+    }
+
+    #[test]
+    fn field_overflow_is_an_error() {
+        let mut buf = [0u8; 2];
+        let mut offset = 0;
+        assert!(encode_field(&1u64, &mut buf, &mut offset).is_err());
+        assert_eq!(offset, 0);
+    }
+}
@@ -74,6 +74,20 @@ impl RpcError {
             },
         }
     }
+
+    /// Exception code sent back to the client when a service handler fails
+    /// with this error.
+    pub fn exception_code(&self) -> RemoteExceptionCode {
+        match self {
+            Self::RemoteException { code, .. } => *code,
+            Self::Timeout => RemoteExceptionCode::Timeout,
+            Self::DeserializationError(_) => RemoteExceptionCode::InvalidArgument,
+            Self::ServiceNotFound(_) => RemoteExceptionCode::UnsupportedService,
+            Self::MethodNotFound(_) => RemoteExceptionCode::UnsupportedMethod,
+            Self::Shutdown => RemoteExceptionCode::ServiceUnavailable,
+            _ => RemoteExceptionCode::InternalError,
+        }
+    }
 }
 
 impl fmt::Display for RpcError {
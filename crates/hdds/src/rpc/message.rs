@@ -0,0 +1,129 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Wire message shared by the request and reply topics.
+//!
+//! A sample is the CDR header (`RequestHeader` on `rq/`, `ReplyHeader` on
+//! `rr/`) followed by the serialized payload. The header size depends on the
+//! topic, so the sample is kept as raw bytes and split by the receiver.
+
+use crate::core::ser::{Cdr2Decode, Cdr2Encode};
+use crate::rpc::error::{RpcError, RpcResult};
+use crate::rpc::types::{ReplyHeader, RequestHeader};
+
+/// RPC type identifier: ASCII "RPC\0" as u32
+const RPC_TYPE_ID: u32 = 0x5250_4300;
+
+/// Internal message type for RPC (header + payload)
+#[derive(Debug, Clone)]
+pub(crate) struct RpcMessage {
+    data: Vec<u8>,
+}
+
+impl RpcMessage {
+    /// Build a request sample.
+    pub(crate) fn request(header: &RequestHeader, payload: &[u8]) -> RpcResult<Self> {
+        Self::encode(header, RequestHeader::CDR_SIZE, payload)
+    }
+
+    /// Build a reply sample.
+    pub(crate) fn reply(header: &ReplyHeader, payload: &[u8]) -> RpcResult<Self> {
+        Self::encode(header, ReplyHeader::CDR_SIZE, payload)
+    }
+
+    /// Split a request sample into its header and payload.
+    pub(crate) fn parse_request(&self) -> RpcResult<(RequestHeader, &[u8])> {
+        self.decode()
+    }
+
+    /// Split a reply sample into its header and payload.
+    pub(crate) fn parse_reply(&self) -> RpcResult<(ReplyHeader, &[u8])> {
+        self.decode()
+    }
+
+    fn encode<H: Cdr2Encode>(header: &H, header_size: usize, payload: &[u8]) -> RpcResult<Self> {
+        let mut data = vec![0u8; header_size + payload.len()];
+        header
+            .encode_cdr2_le(&mut data[..header_size])
+            .map_err(|e| RpcError::SerializationError(e.to_string()))?;
+        data[header_size..].copy_from_slice(payload);
+        Ok(Self { data })
+    }
+
+    fn decode<H: Cdr2Decode>(&self) -> RpcResult<(H, &[u8])> {
+        let (header, used) = H::decode_cdr2_le(&self.data)
+            .map_err(|e| RpcError::DeserializationError(e.to_string()))?;
+        Ok((header, &self.data[used..]))
+    }
+}
+
+impl crate::dds::DDS for RpcMessage {
+    fn type_descriptor() -> &'static crate::core::types::TypeDescriptor {
+        static DESC: crate::core::types::TypeDescriptor = crate::core::types::TypeDescriptor {
+            type_id: RPC_TYPE_ID,
+            type_name: "RpcMessage",
+            size_bytes: 0,
+            alignment: 1,
+            is_variable_size: true,
+            fields: &[],
+        };
+        &DESC
+    }
+
+    fn encode_cdr2(&self, buf: &mut [u8]) -> crate::dds::Result<usize> {
+        let dst = buf
+            .get_mut(..self.data.len())
+            .ok_or(crate::dds::Error::BufferTooSmall)?;
+        dst.copy_from_slice(&self.data);
+        Ok(self.data.len())
+    }
+
+    fn decode_cdr2(buf: &[u8]) -> crate::dds::Result<Self> {
+        Ok(Self { data: buf.to_vec() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::discovery::GUID;
+    use crate::dds::DDS;
+    use crate::rpc::types::{RemoteExceptionCode, SampleIdentity};
+
+    #[test]
+    fn rpc_message_type_descriptor() {
+        let desc = <RpcMessage as DDS>::type_descriptor();
+        assert_eq!(desc.type_name, "RpcMessage");
+        assert!(desc.is_variable_size);
+    }
+
+    #[test]
+    fn request_and_reply_headers_roundtrip() {
+        let id = SampleIdentity::new(GUID::zero(), 7);
+        let mut buf = [0u8; 128];
+
+        let request = RpcMessage::request(&RequestHeader::new(id), b"ping").expect("request");
+        let len = request.encode_cdr2(&mut buf).expect("encode");
+        assert_eq!(len, RequestHeader::CDR_SIZE + 4);
+        let received = RpcMessage::decode_cdr2(&buf[..len]).expect("decode");
+        let (header, payload) = received.parse_request().expect("parse");
+        assert_eq!(header.request_id, id);
+        assert_eq!(payload, b"ping");
+
+        let reply = RpcMessage::reply(&ReplyHeader::error(id, RemoteExceptionCode::Timeout), &[])
+            .expect("reply");
+        let len = reply.encode_cdr2(&mut buf).expect("encode");
+        let received = RpcMessage::decode_cdr2(&buf[..len]).expect("decode");
+        let (header, payload) = received.parse_reply().expect("parse");
+        assert_eq!(header.related_request_id, id);
+        assert_eq!(header.remote_exception_code, RemoteExceptionCode::Timeout);
+        assert!(payload.is_empty());
+    }
+
+    #[test]
+    fn truncated_header_is_rejected() {
+        let msg = RpcMessage::decode_cdr2(&[0u8; 20]).expect("decode");
+        assert!(msg.parse_reply().is_err());
+        assert!(msg.parse_request().is_err());
+    }
+}
@@ -14,16 +14,56 @@
 //! - **Servers** receive requests, process them, and send replies
 //! - Communication uses standard DDS topics with correlation
 //!
-//! # Example
+//! # Typed API
 //!
-//! ```rust,ignore
-//! // Full example requires async runtime and proper request handler implementation
-//! use hdds::rpc::{ServiceClient, ServiceServer, RpcResult};
-//! use hdds::Participant;
+//! [`Service`] and [`Client`] carry any [`DDS`](crate::dds::DDS) request and
+//! reply types, with the semantics of ROS 2 services:
 //!
-//! // See examples/ directory for complete RPC examples
+//! ```rust,no_run
+//! use hdds::rpc::{Client, Service};
+//! use hdds::{Participant, DDS};
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//!
+//! #[derive(DDS)]
+//! struct AddTwoIntsRequest {
+//!     a: i64,
+//!     b: i64,
+//! }
+//!
+//! #[derive(DDS)]
+//! struct AddTwoIntsReply {
+//!     sum: i64,
+//! }
+//!
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let participant = Participant::builder("calc").build()?;
+//!
+//! let service = Arc::new(Service::new(&participant, "add_two_ints", |req: AddTwoIntsRequest| {
+//!     Ok(AddTwoIntsReply { sum: req.a + req.b })
+//! })?);
+//! let spinner = Arc::clone(&service);
+//! std::thread::spawn(move || spinner.spin());
+//!
+//! let client = Client::<AddTwoIntsRequest, AddTwoIntsReply>::new(&participant, "add_two_ints")?;
+//! client.wait_for_service(Duration::from_secs(5));
+//! assert_eq!(client.call(&AddTwoIntsRequest { a: 2, b: 3 })?.sum, 5);
+//! service.shutdown();
+//! # Ok(())
+//! # }
 //! ```
 //!
+//! - Blocking [`Client::call`], or [`Client::call_async`] on tokio
+//! - Concurrent requests with [`Client::send`] and [`PendingReply`]
+//! - Per-client timeout ([`Client::timeout`]), [`RpcError::Timeout`] on expiry
+//! - Handler errors reach the client as [`RpcError::RemoteException`]
+//!
+//! [`#[service]`](service) derives the request/reply types and a client from
+//! a trait, one method per operation.
+//!
+//! [`ServiceClient`] and [`ServiceServer`] are the untyped (raw payload)
+//! variants.
+//!
 //! # Topic Naming
 //!
 //! For a service named "Calculator":
@@ -36,15 +76,27 @@
 //! The reply includes `related_sample_identity` to correlate responses.
 
 mod client;
+mod codec;
 mod error;
+mod message;
+mod replier;
+mod requester;
 mod server;
 mod types;
 
 pub use client::ServiceClient;
 pub use error::{RpcError, RpcResult};
+pub use replier::Service;
+pub use requester::{Client, PendingReply};
 pub use server::{RequestHandler, ServiceServer};
 pub use types::{RemoteExceptionCode, ReplyHeader, RequestHeader, SampleIdentity};
 
+pub use hdds_codegen::service;
+
+// Used by `#[service]` expansions
+#[doc(hidden)]
+pub use codec::{decode_field, encode_field};
+
 /// QoS profile optimized for RPC communication
 ///
 /// - Reliable: ensures requests/replies are not lost
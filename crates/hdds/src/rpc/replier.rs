@@ -0,0 +1,237 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Typed RPC service (DDS-RPC Replier).
+//!
+//! [`Service`] reads typed requests on `rq/<service>`, passes them to its
+//! handler and writes the reply on `rr/<service>` with the request identity
+//! as `related_request_id`. Handler errors are sent back as the matching
+//! [`RemoteExceptionCode`].
+
+use crate::dds::{DataReader, DataWriter, Participant, WaitSet, DDS};
+use crate::rpc::codec::{decode_payload, encode_payload};
+use crate::rpc::error::RpcResult;
+use crate::rpc::message::RpcMessage;
+use crate::rpc::types::{RemoteExceptionCode, ReplyHeader, SampleIdentity};
+use parking_lot::Mutex;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Longest wait of [`Service::spin`] between two shutdown checks.
+const SPIN_SLICE: Duration = Duration::from_millis(100);
+
+/// Poll interval of [`Service::spin_async`].
+const ASYNC_POLL_INTERVAL: Duration = Duration::from_micros(200);
+
+type Handler<Req, Rep> = Box<dyn Fn(Req) -> RpcResult<Rep> + Send + Sync>;
+
+/// Typed RPC service.
+///
+/// Requests are only handled while the owner drives the service, with
+/// [`spin`](Self::spin), [`spin_once`](Self::spin_once),
+/// [`process`](Self::process) or [`spin_async`](Self::spin_async); the
+/// handler runs on that thread or task.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use hdds::rpc::Service;
+/// use hdds::{Participant, DDS};
+///
+/// #[derive(DDS)]
+/// struct AddTwoIntsRequest {
+///     a: i64,
+///     b: i64,
+/// }
+///
+/// #[derive(DDS)]
+/// struct AddTwoIntsReply {
+///     sum: i64,
+/// }
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let participant = Participant::builder("server").build()?;
+/// let service = Service::new(&participant, "add_two_ints", |req: AddTwoIntsRequest| {
+///     Ok(AddTwoIntsReply { sum: req.a + req.b })
+/// })?;
+/// service.spin()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Service<Req, Rep> {
+    service_name: String,
+    request_reader: DataReader<RpcMessage>,
+    /// Locked so that the service can be shared between threads
+    reply_writer: Mutex<DataWriter<RpcMessage>>,
+    /// Wakes on DATA_AVAILABLE of `request_reader`
+    waitset: Option<WaitSet>,
+    handler: Handler<Req, Rep>,
+    shutdown: AtomicBool,
+    requests_processed: AtomicU64,
+    _types: PhantomData<fn(Req) -> Rep>,
+}
+
+impl<Req: DDS, Rep: DDS> Service<Req, Rep> {
+    /// Create the service `service_name`, answering requests with `handler`.
+    pub fn new<F>(participant: &Arc<Participant>, service_name: &str, handler: F) -> RpcResult<Self>
+    where
+        F: Fn(Req) -> RpcResult<Rep> + Send + Sync + 'static,
+    {
+        let qos = crate::rpc::rpc_qos();
+
+        let request_reader = participant
+            .topic::<RpcMessage>(&format!("rq/{}", service_name))?
+            .reader()
+            .qos(qos.clone())
+            .build()?;
+        let reply_writer = participant
+            .topic::<RpcMessage>(&format!("rr/{}", service_name))?
+            .writer()
+            .qos(qos)
+            .build()?;
+
+        let waitset = WaitSet::new();
+        let waitset = match waitset.attach_condition(request_reader.get_status_condition()) {
+            Ok(()) => Some(waitset),
+            Err(e) => {
+                log::debug!(
+                    "[RPC] '{}': waitset attach failed ({:?}), polling",
+                    service_name,
+                    e
+                );
+                None
+            }
+        };
+
+        log::info!("Service '{}' created", service_name);
+
+        Ok(Self {
+            service_name: service_name.to_string(),
+            request_reader,
+            reply_writer: Mutex::new(reply_writer),
+            waitset,
+            handler: Box::new(handler),
+            shutdown: AtomicBool::new(false),
+            requests_processed: AtomicU64::new(0),
+            _types: PhantomData,
+        })
+    }
+
+    /// Handle the requests already received, without blocking.
+    ///
+    /// Returns the number of requests answered.
+    pub fn process(&self) -> RpcResult<usize> {
+        let mut answered = 0;
+        while let Some(msg) = self.request_reader.take()? {
+            if self.answer(&msg)? {
+                answered += 1;
+            }
+        }
+        Ok(answered)
+    }
+
+    /// Wait up to `timeout` for requests, then handle them.
+    ///
+    /// Returns the number of requests answered (0 on timeout).
+    pub fn spin_once(&self, timeout: Duration) -> RpcResult<usize> {
+        let answered = self.process()?;
+        if answered > 0 {
+            return Ok(answered);
+        }
+        match &self.waitset {
+            // WouldBlock = timed out
+            Some(waitset) => match waitset.wait(Some(timeout)) {
+                Ok(_) | Err(crate::dds::Error::WouldBlock) => {}
+                Err(e) => return Err(e.into()),
+            },
+            None => std::thread::sleep(timeout.min(SPIN_SLICE)),
+        }
+        self.process()
+    }
+
+    /// Handle requests on the calling thread until [`shutdown`](Self::shutdown).
+    pub fn spin(&self) -> RpcResult<()> {
+        log::info!("Service '{}' spinning...", self.service_name);
+        while self.is_running() {
+            self.spin_once(SPIN_SLICE)?;
+        }
+        log::info!("Service '{}' stopped", self.service_name);
+        Ok(())
+    }
+
+    /// Handle requests on the current task until [`shutdown`](Self::shutdown).
+    ///
+    /// The handler runs on the task, so it should not block for long.
+    pub async fn spin_async(&self) -> RpcResult<()> {
+        while self.is_running() {
+            if self.process()? == 0 {
+                tokio::time::sleep(ASYNC_POLL_INTERVAL).await;
+            }
+        }
+        Ok(())
+    }
+
+    /// Answer one request; `false` if it was malformed and dropped.
+    fn answer(&self, msg: &RpcMessage) -> RpcResult<bool> {
+        let (header, payload) = match msg.parse_request() {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                // Without a request identity there is nobody to reply to
+                log::warn!("[RPC] '{}': dropping request: {}", self.service_name, e);
+                return Ok(false);
+            }
+        };
+        let request_id = header.request_id;
+
+        let reply = decode_payload::<Req>(payload)
+            .and_then(|request| (self.handler)(request))
+            .and_then(|reply| encode_payload(&reply));
+        let reply = match reply {
+            Ok(payload) => RpcMessage::reply(&ReplyHeader::success(request_id), &payload)?,
+            Err(e) => {
+                log::debug!(
+                    "[RPC] '{}': request seq={} failed: {}",
+                    self.service_name,
+                    request_id.sequence_number,
+                    e
+                );
+                exception_reply(request_id, e.exception_code())?
+            }
+        };
+
+        self.reply_writer.lock().write(&reply)?;
+        self.requests_processed.fetch_add(1, Ordering::Relaxed);
+        Ok(true)
+    }
+
+    /// Stop [`spin`](Self::spin) and [`spin_async`](Self::spin_async).
+    pub fn shutdown(&self) {
+        self.shutdown.store(true, Ordering::Relaxed);
+    }
+
+    /// Check if the service is running
+    pub fn is_running(&self) -> bool {
+        !self.shutdown.load(Ordering::Relaxed)
+    }
+
+    /// Get the service name
+    pub fn service_name(&self) -> &str {
+        &self.service_name
+    }
+
+    /// Get the number of requests processed
+    pub fn requests_processed(&self) -> u64 {
+        self.requests_processed.load(Ordering::Relaxed)
+    }
+}
+
+fn exception_reply(request_id: SampleIdentity, code: RemoteExceptionCode) -> RpcResult<RpcMessage> {
+    // Ok would read as a success with an empty, undecodable reply
+    let code = match code {
+        RemoteExceptionCode::Ok => RemoteExceptionCode::InternalError,
+        code => code,
+    };
+    RpcMessage::reply(&ReplyHeader::error(request_id, code), &[])
+}
@@ -0,0 +1,402 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Typed RPC client (DDS-RPC Requester).
+//!
+//! [`Client`] writes typed requests on `rq/<service>` and correlates the
+//! replies read on `rr/<service>` through their related [`SampleIdentity`].
+//! Any number of requests may be outstanding at once, from any number of
+//! threads or tasks: whichever waiter reads the reply topic routes each
+//! reply to its [`PendingReply`], and replies addressed to other clients of
+//! the same service are dropped.
+
+use crate::core::discovery::GUID;
+use crate::dds::{DataReader, DataWriter, Participant, WaitSet, DDS};
+use crate::rpc::client::generate_client_guid;
+use crate::rpc::codec::{decode_payload, encode_payload};
+use crate::rpc::error::{RpcError, RpcResult};
+use crate::rpc::message::RpcMessage;
+use crate::rpc::types::{RequestHeader, SampleIdentity};
+use parking_lot::{Condvar, Mutex};
+use std::collections::{HashMap, HashSet};
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Default reply timeout of [`Client::call`].
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Longest single wait on the reply topic, so that waiters blocked on the
+/// condition variable take over reading if the current reader returns.
+const WAIT_SLICE: Duration = Duration::from_millis(10);
+
+/// Poll interval of the async variants.
+const ASYNC_POLL_INTERVAL: Duration = Duration::from_micros(200);
+
+/// Replies routed to their request, by request identity.
+#[derive(Default)]
+struct ReplyTable {
+    outstanding: HashSet<SampleIdentity>,
+    ready: HashMap<SampleIdentity, RpcResult<Vec<u8>>>,
+}
+
+/// State shared by a client, its clones and its pending replies.
+struct Requester {
+    service_name: String,
+    /// Locked so that clients can be shared between threads
+    request_writer: Mutex<DataWriter<RpcMessage>>,
+    reply_reader: DataReader<RpcMessage>,
+    /// Wakes on DATA_AVAILABLE of `reply_reader`
+    waitset: Option<WaitSet>,
+    client_guid: GUID,
+    sequence: AtomicI64,
+    replies: Mutex<ReplyTable>,
+    /// Signalled when replies are routed
+    routed: Condvar,
+    /// Held by the thread reading `reply_reader`
+    reading: Mutex<()>,
+}
+
+impl Requester {
+    fn send(&self, payload: &[u8]) -> RpcResult<SampleIdentity> {
+        let seq = self.sequence.fetch_add(1, Ordering::Relaxed);
+        let request_id = SampleIdentity::new(self.client_guid, seq);
+        let msg = RpcMessage::request(&RequestHeader::new(request_id), payload)?;
+
+        // Register first: the reply may be read before write() returns
+        self.replies.lock().outstanding.insert(request_id);
+        if let Err(e) = self.request_writer.lock().write(&msg) {
+            self.cancel(request_id);
+            return Err(e.into());
+        }
+        Ok(request_id)
+    }
+
+    /// Route every reply available on the reply topic.
+    fn route_replies(&self) {
+        let mut routed = false;
+        while let Ok(Some(msg)) = self.reply_reader.take() {
+            let (header, payload) = match msg.parse_reply() {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    log::debug!("[RPC] '{}': dropping reply: {}", self.service_name, e);
+                    continue;
+                }
+            };
+            let mut replies = self.replies.lock();
+            if !replies.outstanding.remove(&header.related_request_id) {
+                // Another client, or a request that timed out
+                continue;
+            }
+            let result = if header.is_success() {
+                Ok(payload.to_vec())
+            } else {
+                Err(RpcError::from_code(header.remote_exception_code))
+            };
+            replies.ready.insert(header.related_request_id, result);
+            routed = true;
+        }
+        if routed {
+            self.routed.notify_all();
+        }
+    }
+
+    /// Non-blocking: the reply of `request_id`, if it arrived.
+    fn poll(&self, request_id: SampleIdentity) -> Option<RpcResult<Vec<u8>>> {
+        if let Some(reading) = self.reading.try_lock() {
+            self.route_replies();
+            drop(reading);
+        }
+        self.replies.lock().ready.remove(&request_id)
+    }
+
+    fn wait(&self, request_id: SampleIdentity, timeout: Duration) -> RpcResult<Vec<u8>> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Some(reply) = self.poll(request_id) {
+                return reply;
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(RpcError::Timeout);
+            }
+            let slice = (deadline - now).min(WAIT_SLICE);
+
+            if let Some(_reading) = self.reading.try_lock() {
+                self.route_replies();
+                if self.replies.lock().ready.contains_key(&request_id) {
+                    continue;
+                }
+                match &self.waitset {
+                    // WouldBlock = timed out; the deadline check above ends the loop.
+                    Some(waitset) => match waitset.wait(Some(slice)) {
+                        Ok(_) | Err(crate::dds::Error::WouldBlock) => {}
+                        Err(e) => return Err(e.into()),
+                    },
+                    None => std::thread::sleep(slice),
+                }
+            } else {
+                // Another thread is reading: wait for it to route our reply
+                let mut replies = self.replies.lock();
+                if !replies.ready.contains_key(&request_id) {
+                    self.routed.wait_for(&mut replies, slice);
+                }
+            }
+        }
+    }
+
+    async fn wait_async(
+        &self,
+        request_id: SampleIdentity,
+        timeout: Duration,
+    ) -> RpcResult<Vec<u8>> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Some(reply) = self.poll(request_id) {
+                return reply;
+            }
+            if Instant::now() >= deadline {
+                return Err(RpcError::Timeout);
+            }
+            tokio::time::sleep(ASYNC_POLL_INTERVAL).await;
+        }
+    }
+
+    /// Forget `request_id`; a late reply is dropped.
+    fn cancel(&self, request_id: SampleIdentity) {
+        let mut replies = self.replies.lock();
+        replies.outstanding.remove(&request_id);
+        replies.ready.remove(&request_id);
+    }
+}
+
+/// Typed RPC client for a service.
+///
+/// `Client` is cheap to clone; clones share the request writer, the reply
+/// reader and the correlation table, so one client can be shared by many
+/// threads or tasks.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use hdds::rpc::Client;
+/// use hdds::{Participant, DDS};
+/// use std::time::Duration;
+///
+/// #[derive(DDS)]
+/// struct AddTwoIntsRequest {
+///     a: i64,
+///     b: i64,
+/// }
+///
+/// #[derive(DDS)]
+/// struct AddTwoIntsReply {
+///     sum: i64,
+/// }
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let participant = Participant::builder("client").build()?;
+/// let client = Client::<AddTwoIntsRequest, AddTwoIntsReply>::new(&participant, "add_two_ints")?
+///     .timeout(Duration::from_secs(2));
+///
+/// // Blocking call
+/// let reply = client.call(&AddTwoIntsRequest { a: 2, b: 3 })?;
+/// assert_eq!(reply.sum, 5);
+///
+/// // Several requests in flight
+/// let pending = (0..4)
+///     .map(|a| client.send(&AddTwoIntsRequest { a, b: 1 }))
+///     .collect::<Result<Vec<_>, _>>()?;
+/// for reply in pending {
+///     println!("{}", reply.wait(Duration::from_secs(2))?.sum);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Client<Req, Rep> {
+    requester: Arc<Requester>,
+    timeout: Duration,
+    _types: PhantomData<fn(Req) -> Rep>,
+}
+
+impl<Req, Rep> Clone for Client<Req, Rep> {
+    fn clone(&self) -> Self {
+        Self {
+            requester: Arc::clone(&self.requester),
+            timeout: self.timeout,
+            _types: PhantomData,
+        }
+    }
+}
+
+impl<Req: DDS, Rep: DDS> Client<Req, Rep> {
+    /// Create a client of the service `service_name`.
+    ///
+    /// Calls time out after 10 seconds unless changed with
+    /// [`timeout`](Self::timeout).
+    pub fn new(participant: &Arc<Participant>, service_name: &str) -> RpcResult<Self> {
+        let qos = crate::rpc::rpc_qos();
+
+        let request_writer = participant
+            .topic::<RpcMessage>(&format!("rq/{}", service_name))?
+            .writer()
+            .qos(qos.clone())
+            .build()?;
+        let reply_reader = participant
+            .topic::<RpcMessage>(&format!("rr/{}", service_name))?
+            .reader()
+            .qos(qos)
+            .build()?;
+
+        let waitset = WaitSet::new();
+        let waitset = match waitset.attach_condition(reply_reader.get_status_condition()) {
+            Ok(()) => Some(waitset),
+            Err(e) => {
+                log::debug!(
+                    "[RPC] '{}': waitset attach failed ({:?}), polling",
+                    service_name,
+                    e
+                );
+                None
+            }
+        };
+
+        Ok(Self {
+            requester: Arc::new(Requester {
+                service_name: service_name.to_string(),
+                request_writer: Mutex::new(request_writer),
+                reply_reader,
+                waitset,
+                client_guid: generate_client_guid(),
+                sequence: AtomicI64::new(1),
+                replies: Mutex::new(ReplyTable::default()),
+                routed: Condvar::new(),
+                reading: Mutex::new(()),
+            }),
+            timeout: DEFAULT_TIMEOUT,
+            _types: PhantomData,
+        })
+    }
+
+    /// Set the reply timeout of [`call`](Self::call) and
+    /// [`call_async`](Self::call_async).
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Send `request` and block until its reply arrives or the timeout
+    /// expires.
+    pub fn call(&self, request: &Req) -> RpcResult<Rep> {
+        self.send(request)?.wait(self.timeout)
+    }
+
+    /// Send `request` and wait for its reply without blocking the executor.
+    ///
+    /// Works with the tokio runtime (the `rpc` feature depends on it).
+    pub async fn call_async(&self, request: &Req) -> RpcResult<Rep> {
+        self.send(request)?.wait_async(self.timeout).await
+    }
+
+    /// Send `request` without waiting; the reply is collected through the
+    /// returned [`PendingReply`].
+    pub fn send(&self, request: &Req) -> RpcResult<PendingReply<Rep>> {
+        let payload = encode_payload(request)?;
+        let request_id = self.requester.send(&payload)?;
+        Ok(PendingReply {
+            requester: Arc::clone(&self.requester),
+            request_id,
+            done: false,
+            _reply: PhantomData,
+        })
+    }
+
+    /// Wait until a service is matched with this client.
+    ///
+    /// Returns `false` if none showed up within `timeout`.
+    pub fn wait_for_service(&self, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+        while !self.is_service_available() {
+            if Instant::now() >= deadline {
+                return false;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        true
+    }
+
+    /// Whether a service is matched with this client.
+    pub fn is_service_available(&self) -> bool {
+        self.requester.request_writer.lock().matched_readers_count() > 0
+    }
+
+    /// Number of requests sent by this client (and its clones) still
+    /// waiting for a reply.
+    pub fn outstanding(&self) -> usize {
+        self.requester.replies.lock().outstanding.len()
+    }
+
+    /// Get the service name
+    pub fn service_name(&self) -> &str {
+        &self.requester.service_name
+    }
+}
+
+/// Reply of a request sent with [`Client::send`].
+///
+/// Dropping it before the reply arrives cancels the correlation; a late
+/// reply is then discarded.
+pub struct PendingReply<Rep> {
+    requester: Arc<Requester>,
+    request_id: SampleIdentity,
+    done: bool,
+    _reply: PhantomData<fn() -> Rep>,
+}
+
+impl<Rep: DDS> PendingReply<Rep> {
+    /// Identity of the request, as seen by the service.
+    pub fn request_id(&self) -> SampleIdentity {
+        self.request_id
+    }
+
+    /// The reply, if it arrived. Does not block.
+    ///
+    /// Returns `Some` once; later calls return `None`.
+    pub fn try_take(&mut self) -> Option<RpcResult<Rep>> {
+        if self.done {
+            return None;
+        }
+        let reply = self.requester.poll(self.request_id)?;
+        self.done = true;
+        Some(reply.and_then(|payload| decode_payload(&payload)))
+    }
+
+    /// Block until the reply arrives or `timeout` expires.
+    pub fn wait(mut self, timeout: Duration) -> RpcResult<Rep> {
+        let reply = self.requester.wait(self.request_id, timeout);
+        self.finish(reply)
+    }
+
+    /// Wait for the reply without blocking the executor.
+    pub async fn wait_async(mut self, timeout: Duration) -> RpcResult<Rep> {
+        let reply = self.requester.wait_async(self.request_id, timeout).await;
+        self.finish(reply)
+    }
+
+    fn finish(&mut self, reply: RpcResult<Vec<u8>>) -> RpcResult<Rep> {
+        // No-op once the reply was taken; drops a late reply otherwise
+        self.requester.cancel(self.request_id);
+        self.done = true;
+        reply.and_then(|payload| decode_payload(&payload))
+    }
+}
+
+impl<Rep> Drop for PendingReply<Rep> {
+    fn drop(&mut self) {
+        if !self.done {
+            self.requester.cancel(self.request_id);
+        }
+    }
+}
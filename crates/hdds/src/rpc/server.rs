@@ -6,11 +6,10 @@
 //! The ServiceServer receives requests, dispatches them to a handler,
 //! and sends replies back to clients.
 
-use crate::core::ser::{Cdr2Decode, Cdr2Encode};
 use crate::dds::{DataReader, DataWriter, Participant};
-use crate::rpc::client::RpcMessage;
 use crate::rpc::error::RpcResult;
-use crate::rpc::types::{RemoteExceptionCode, ReplyHeader, RequestHeader, SampleIdentity};
+use crate::rpc::message::RpcMessage;
+use crate::rpc::types::{RemoteExceptionCode, ReplyHeader, SampleIdentity};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
@@ -167,8 +166,8 @@ impl ServiceServer {
     /// Process a single request message
     async fn process_request(&self, msg: RpcMessage) {
         // Parse request header
-        let request_header = match RequestHeader::decode_cdr2_le(&msg.header) {
-            Ok((header, _)) => header,
+        let (request_header, payload) = match msg.parse_request() {
+            Ok(parsed) => parsed,
             Err(e) => {
                 log::warn!("Failed to parse request header: {:?}", e);
                 return;
@@ -179,7 +178,7 @@ impl ServiceServer {
         log::debug!("Processing request: seq={}", request_id.sequence_number);
 
         // Invoke handler
-        let result = self.handler.handle(request_id, &msg.payload);
+        let result = self.handler.handle(request_id, payload);
 
         // Build reply
         let (reply_header, reply_payload) = match result {
@@ -190,18 +189,15 @@ impl ServiceServer {
             }
         };
 
-        // Encode reply header
-        let mut header_bytes = vec![0u8; 28];
-        if let Err(e) = reply_header.encode_cdr2_le(&mut header_bytes) {
-            log::error!("Failed to encode reply header: {:?}", e);
-            return;
-        }
+        let reply_msg = match RpcMessage::reply(&reply_header, &reply_payload) {
+            Ok(msg) => msg,
+            Err(e) => {
+                log::error!("Failed to encode reply: {}", e);
+                return;
+            }
+        };
 
         // Send reply
-        let reply_msg = RpcMessage {
-            header: header_bytes,
-            payload: reply_payload,
-        };
 
         if let Err(e) = self.reply_writer.write(&reply_msg) {
             log::error!("Failed to send reply: {}", e);
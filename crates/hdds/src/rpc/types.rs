@@ -250,7 +250,7 @@ impl Cdr2Encode for RequestHeader {
 
 impl RequestHeader {
     /// Size: 2 * SampleIdentity = 48 bytes
-    const CDR_SIZE: usize = 48;
+    pub(crate) const CDR_SIZE: usize = 48;
 }
 
 impl Cdr2Decode for RequestHeader {
@@ -294,7 +294,7 @@ impl Cdr2Encode for ReplyHeader {
 
 impl ReplyHeader {
     /// Size: SampleIdentity (24) + i32 (4) = 28 bytes
-    const CDR_SIZE: usize = 28;
+    pub(crate) const CDR_SIZE: usize = 28;
 }
 
 impl Cdr2Decode for ReplyHeader {
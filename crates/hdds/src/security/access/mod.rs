@@ -13,9 +13,9 @@
 //! # Example
 //!
 //! ```ignore
-//! use hdds::security::access::AccessControlPlugin;
+//! use hdds::security::access::PermissionsAccessControl;
 //!
-//! let plugin = AccessControlPlugin::from_xml(
+//! let plugin = PermissionsAccessControl::from_xml(
 //!     "governance.xml",
 //!     "permissions.xml"
 //! )?;
@@ -29,16 +29,29 @@ use crate::security::SecurityError;
 pub mod permissions;
 pub mod rules;
 
-pub use permissions::{DomainRule, GovernanceConfig, Grant, PermissionsConfig, Rule, Validity};
+pub use permissions::{
+    DomainRule, GovernanceConfig, Grant, PermissionsConfig, ProtectionKind, Rule, TopicRule,
+    Validity,
+};
 pub use rules::RulesEngine;
 
 /// Access Control Plugin implementing DDS Security v1.1 Sec.8.4-8.5
-pub struct AccessControlPlugin {
+///
+/// Built-in [`AccessControlPlugin`](crate::security::AccessControlPlugin)
+/// used when no custom plugin is registered.
+pub struct PermissionsAccessControl {
     /// Rules engine (governance + permissions)
     rules: RulesEngine,
 }
 
-impl AccessControlPlugin {
+/// Former name of [`PermissionsAccessControl`]
+#[deprecated(
+    since = "1.0.11",
+    note = "Use `PermissionsAccessControl`; `security::AccessControlPlugin` is now the plugin trait"
+)]
+pub type AccessControlPlugin = PermissionsAccessControl;
+
+impl PermissionsAccessControl {
     /// Create access control plugin from XML files
     pub fn from_xml(governance_xml: &str, permissions_xml: &str) -> Result<Self, SecurityError> {
         let governance = GovernanceConfig::parse(governance_xml)?;
@@ -72,6 +85,11 @@ impl AccessControlPlugin {
         self.rules.check_topic_subscribe(topic, partition)
     }
 
+    /// Protection of `topic`'s data on `domain_id` (governance topic rules)
+    pub fn data_protection(&self, domain_id: u32, topic: &str) -> ProtectionKind {
+        self.rules.data_protection(domain_id, topic)
+    }
+
     /// Check if remote writer is allowed
     pub fn check_remote_writer(&self, topic: &str) -> Result<(), SecurityError> {
         self.rules.check_topic_publish(topic, None)
@@ -83,6 +101,47 @@ impl AccessControlPlugin {
     }
 }
 
+impl crate::security::AccessControlPlugin for PermissionsAccessControl {
+    fn check_create_participant(&self, domain_id: u32) -> Result<(), SecurityError> {
+        PermissionsAccessControl::check_create_participant(self, domain_id)
+    }
+
+    fn check_create_writer(
+        &self,
+        topic: &str,
+        partition: Option<&str>,
+    ) -> Result<(), SecurityError> {
+        PermissionsAccessControl::check_create_writer(self, topic, partition)
+    }
+
+    fn check_create_reader(
+        &self,
+        topic: &str,
+        partition: Option<&str>,
+    ) -> Result<(), SecurityError> {
+        PermissionsAccessControl::check_create_reader(self, topic, partition)
+    }
+
+    fn check_remote_writer(&self, topic: &str) -> Result<(), SecurityError> {
+        PermissionsAccessControl::check_remote_writer(self, topic)
+    }
+
+    fn check_remote_reader(&self, topic: &str) -> Result<(), SecurityError> {
+        PermissionsAccessControl::check_remote_reader(self, topic)
+    }
+
+    fn data_protection(&self, domain_id: u32, topic: &str) -> ProtectionKind {
+        PermissionsAccessControl::data_protection(self, domain_id, topic)
+    }
+}
+
+impl std::fmt::Debug for PermissionsAccessControl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PermissionsAccessControl")
+            .finish_non_exhaustive()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,7 +176,7 @@ mod tests {
   </grant>
 </permissions>"#;
 
-        let plugin = AccessControlPlugin::from_xml(governance, permissions).unwrap();
+        let plugin = PermissionsAccessControl::from_xml(governance, permissions).unwrap();
 
         // Should allow
         assert!(plugin
@@ -158,7 +217,7 @@ mod tests {
   </grant>
 </permissions>"#;
 
-        let plugin = AccessControlPlugin::from_xml(governance, permissions).unwrap();
+        let plugin = PermissionsAccessControl::from_xml(governance, permissions).unwrap();
 
         // Should deny
         assert!(plugin.check_create_writer("admin/shutdown", None).is_err());
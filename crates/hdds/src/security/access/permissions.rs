@@ -3,6 +3,7 @@
 
 //! Governance and Permissions XML parsing for DDS Security v1.1
 
+use crate::security::access::RulesEngine;
 use crate::security::SecurityError;
 
 /// Governance configuration (domain-wide security policies)
@@ -17,6 +18,42 @@ pub struct DomainRule {
     pub allow_unauthenticated: bool,
     pub encrypt_discovery: bool,
     pub encrypt_topics: bool,
+    /// Per-topic protection, first match wins (`encrypt_topics` applies when empty)
+    pub topic_rules: Vec<TopicRule>,
+}
+
+/// Protection of a topic's data or metadata (DDS Security v1.1 Sec.9.4.1.2.7)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProtectionKind {
+    /// Sent in the clear
+    #[default]
+    None,
+    /// Authenticated with AES-256-GMAC, sent in the clear
+    Sign,
+    /// Encrypted and authenticated with AES-256-GCM
+    Encrypt,
+}
+
+impl ProtectionKind {
+    /// Parse a governance `*_protection_kind` value.
+    ///
+    /// The `*_WITH_ORIGIN_AUTHENTICATION` variants map to their base kind.
+    pub fn parse(text: &str) -> Option<Self> {
+        match text.trim() {
+            "NONE" => Some(Self::None),
+            "SIGN" | "SIGN_WITH_ORIGIN_AUTHENTICATION" => Some(Self::Sign),
+            "ENCRYPT" | "ENCRYPT_WITH_ORIGIN_AUTHENTICATION" => Some(Self::Encrypt),
+            _ => None,
+        }
+    }
+}
+
+/// Protection of the topics matching `topic_expression`
+#[derive(Debug, Clone)]
+pub struct TopicRule {
+    pub topic_expression: String,
+    pub metadata_protection_kind: ProtectionKind,
+    pub data_protection_kind: ProtectionKind,
 }
 
 impl GovernanceConfig {
@@ -40,8 +77,13 @@ impl GovernanceConfig {
                     for child in node.children() {
                         match child.tag_name().name() {
                             "domains" => {
-                                if let Some(text) = child.text() {
-                                    if let Ok(domain_id) = text.trim().parse::<u32>() {
+                                // Plain `<domains>0</domains>` or spec `<id>` children
+                                let ids = std::iter::once(child)
+                                    .chain(child.children().filter(|c| c.has_tag_name("id")));
+                                for id in ids {
+                                    if let Some(Ok(domain_id)) =
+                                        id.text().map(|text| text.trim().parse::<u32>())
+                                    {
                                         domains.push(domain_id);
                                     }
                                 }
@@ -65,11 +107,19 @@ impl GovernanceConfig {
                         }
                     }
 
+                    // Directly under the domain rule or inside <topic_access_rules>
+                    let topic_rules = node
+                        .descendants()
+                        .filter(|n| n.has_tag_name("topic_rule"))
+                        .map(|n| Self::parse_topic_rule(&n))
+                        .collect::<Result<Vec<_>, _>>()?;
+
                     domain_rules.push(DomainRule {
                         domains,
                         allow_unauthenticated,
                         encrypt_discovery,
                         encrypt_topics,
+                        topic_rules,
                     });
                 }
             }
@@ -84,6 +134,67 @@ impl GovernanceConfig {
             ))
         }
     }
+
+    #[cfg(feature = "qos-loaders")]
+    fn parse_topic_rule(node: &roxmltree::Node) -> Result<TopicRule, SecurityError> {
+        let mut rule = TopicRule {
+            topic_expression: "*".to_string(),
+            metadata_protection_kind: ProtectionKind::None,
+            data_protection_kind: ProtectionKind::None,
+        };
+
+        for child in node.children() {
+            let text = child.text().unwrap_or_default();
+            let kind = || {
+                ProtectionKind::parse(text).ok_or_else(|| {
+                    SecurityError::ConfigError(format!(
+                        "Invalid {} '{}' in governance XML",
+                        child.tag_name().name(),
+                        text.trim()
+                    ))
+                })
+            };
+            match child.tag_name().name() {
+                "topic_expression" => rule.topic_expression = text.trim().to_string(),
+                "metadata_protection_kind" => rule.metadata_protection_kind = kind()?,
+                "data_protection_kind" => rule.data_protection_kind = kind()?,
+                _ => {}
+            }
+        }
+
+        Ok(rule)
+    }
+
+    /// Protection of `topic`'s serialized data on `domain_id`.
+    ///
+    /// Uses the first domain rule listing `domain_id`, then its first topic
+    /// rule matching `topic`. A domain rule without topic rules encrypts
+    /// every topic when `encrypt_topics` is set. Unmatched topics and
+    /// domains are not protected.
+    pub fn data_protection(&self, domain_id: u32, topic: &str) -> ProtectionKind {
+        let Some(rule) = self
+            .domain_rules
+            .iter()
+            .find(|rule| rule.domains.contains(&domain_id))
+        else {
+            return ProtectionKind::None;
+        };
+
+        if rule.topic_rules.is_empty() {
+            return if rule.encrypt_topics {
+                ProtectionKind::Encrypt
+            } else {
+                ProtectionKind::None
+            };
+        }
+
+        rule.topic_rules
+            .iter()
+            .find(|topic_rule| RulesEngine::wildcard_match(&topic_rule.topic_expression, topic))
+            .map_or(ProtectionKind::None, |topic_rule| {
+                topic_rule.data_protection_kind
+            })
+    }
 }
 
 /// Permissions configuration (per-participant access rules)
@@ -270,6 +381,87 @@ mod tests {
         assert!(config.domain_rules[0].encrypt_topics);
     }
 
+    #[test]
+    #[cfg(feature = "qos-loaders")]
+    fn test_governance_topic_rules() {
+        let xml = r#"<?xml version="1.0"?>
+<governance>
+  <domain_rule>
+    <domains><id>0</id><id>7</id></domains>
+    <encrypt_topics>true</encrypt_topics>
+    <topic_access_rules>
+      <topic_rule>
+        <topic_expression>control/*</topic_expression>
+        <metadata_protection_kind>NONE</metadata_protection_kind>
+        <data_protection_kind>NONE</data_protection_kind>
+      </topic_rule>
+      <topic_rule>
+        <topic_expression>operator/*</topic_expression>
+        <data_protection_kind>ENCRYPT</data_protection_kind>
+      </topic_rule>
+      <topic_rule>
+        <topic_expression>*</topic_expression>
+        <metadata_protection_kind>SIGN</metadata_protection_kind>
+        <data_protection_kind>SIGN_WITH_ORIGIN_AUTHENTICATION</data_protection_kind>
+      </topic_rule>
+    </topic_access_rules>
+  </domain_rule>
+  <domain_rule>
+    <domains>1</domains>
+    <encrypt_topics>true</encrypt_topics>
+  </domain_rule>
+</governance>"#;
+
+        let config = GovernanceConfig::parse(xml).unwrap();
+        assert_eq!(config.domain_rules[0].domains, vec![0, 7]);
+        assert_eq!(config.domain_rules[0].topic_rules.len(), 3);
+        assert_eq!(
+            config.domain_rules[0].topic_rules[2].metadata_protection_kind,
+            ProtectionKind::Sign
+        );
+
+        // First matching topic rule wins
+        assert_eq!(
+            config.data_protection(0, "control/loop"),
+            ProtectionKind::None
+        );
+        assert_eq!(
+            config.data_protection(7, "operator/command"),
+            ProtectionKind::Encrypt
+        );
+        assert_eq!(
+            config.data_protection(0, "status/mode"),
+            ProtectionKind::Sign
+        );
+        // No topic rules: encrypt_topics applies
+        assert_eq!(
+            config.data_protection(1, "control/loop"),
+            ProtectionKind::Encrypt
+        );
+        // Domain without a rule
+        assert_eq!(
+            config.data_protection(2, "operator/command"),
+            ProtectionKind::None
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "qos-loaders")]
+    fn test_governance_invalid_protection_kind() {
+        let xml = r#"<?xml version="1.0"?>
+<governance>
+  <domain_rule>
+    <domains>0</domains>
+    <topic_rule>
+      <topic_expression>*</topic_expression>
+      <data_protection_kind>SCRAMBLE</data_protection_kind>
+    </topic_rule>
+  </domain_rule>
+</governance>"#;
+
+        assert!(GovernanceConfig::parse(xml).is_err());
+    }
+
     #[test]
     #[cfg(feature = "qos-loaders")]
     fn test_parse_permissions_xml() {
@@ -19,7 +19,7 @@
 //!
 //! This model ensures security-by-default even with misconfigured permissions.
 
-use crate::security::access::{GovernanceConfig, PermissionsConfig};
+use crate::security::access::{GovernanceConfig, PermissionsConfig, ProtectionKind};
 use crate::security::SecurityError;
 
 /// Rules engine for access control
@@ -55,6 +55,11 @@ impl RulesEngine {
         )))
     }
 
+    /// Protection governance assigns to `topic`'s data on `domain_id`
+    pub fn data_protection(&self, domain_id: u32, topic: &str) -> ProtectionKind {
+        self.governance.data_protection(domain_id, topic)
+    }
+
     /// Check if topic publish is allowed
     ///
     /// # Security Model
@@ -201,6 +206,7 @@ mod tests {
                 allow_unauthenticated: false,
                 encrypt_discovery: false,
                 encrypt_topics: false,
+                topic_rules: vec![],
             }],
         };
 
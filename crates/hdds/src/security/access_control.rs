@@ -3,18 +3,25 @@
 
 //! Access Control Plugin SPI
 //!
-//! Fine-grained authorization using OMG Permissions XML.
+//! Authorization of domains, topics and partitions.
 //!
 //! # OMG DDS Security v1.1 Sec.8.4 (Access Control)
 //!
-//! **Phase 1 Prep (Mode Nuit 2025-10-26):** Trait defined only
+//! The built-in implementation is
+//! [`PermissionsAccessControl`](super::access::PermissionsAccessControl)
+//! (governance and permissions XML). Register another one with
+//! [`SecurityConfigBuilder::access_control_plugin`](super::SecurityConfigBuilder::access_control_plugin).
 
+use std::fmt;
+
+use super::access::ProtectionKind;
 use super::SecurityError;
 
-/// Access control plugin trait
+/// Access control plugin trait (SPI)
 ///
-/// Enforces permissions policy (Permissions XML) for topic/partition/action authorization.
-pub trait AccessControlPlugin: Send + Sync {
+/// Called when local endpoints are created and remote endpoints matched,
+/// and to decide the protection of each topic's data.
+pub trait AccessControlPlugin: fmt::Debug + Send + Sync {
     /// Check if participant creation is allowed
     fn check_create_participant(&self, domain_id: u32) -> Result<(), SecurityError>;
 
@@ -37,4 +44,10 @@ pub trait AccessControlPlugin: Send + Sync {
 
     /// Check if matching with remote reader is allowed
     fn check_remote_reader(&self, topic: &str) -> Result<(), SecurityError>;
+
+    /// Protection of `topic`'s data on `domain_id`
+    ///
+    /// Only applied when a cryptographic plugin is active (encryption
+    /// enabled or a custom plugin registered).
+    fn data_protection(&self, domain_id: u32, topic: &str) -> ProtectionKind;
 }
@@ -32,6 +32,7 @@
 //! - [X.509 RFC 5280](https://datatracker.ietf.org/doc/html/rfc5280)
 
 use std::fmt;
+use std::path::PathBuf;
 
 use crate::core::discovery::guid::GUID;
 use crate::dds::Error;
@@ -39,6 +40,9 @@ use crate::dds::Error;
 use super::config::SecurityConfig;
 use super::SecurityError;
 
+/// SPIFFE X.509 SVIDs as the identity of X.509 authentication.
+#[cfg(feature = "security")]
+pub mod spiffe;
 /// X.509-based authentication backends and helpers.
 pub mod x509;
 
@@ -118,6 +122,61 @@ pub trait AuthenticationPlugin: fmt::Debug + Send + Sync {
     ) -> Result<Option<HandshakeReplyToken>, SecurityError>;
 }
 
+/// Source of the local X.509 identity (SPI)
+///
+/// Supplies the identity certificate, private key and trust bundle of X.509
+/// authentication instead of the files configured on the
+/// [`SecurityConfigBuilder`](super::SecurityConfigBuilder), for identities
+/// issued and rotated by the platform (e.g. SPIFFE SVIDs, see
+/// [`spiffe::SpiffeSvidSource`]). The X.509 plugin is rebuilt from
+/// [`current()`](Self::current) on every security reload; remote
+/// participants are then re-authenticated against the new trust bundle.
+pub trait IdentitySource: fmt::Debug + Send + Sync {
+    /// Current identity
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the identity is unavailable or is not valid for
+    /// this source (e.g. an SVID without SPIFFE ID).
+    fn current(&self) -> Result<X509Identity, SecurityError>;
+
+    /// Files holding the identity, polled for rotation
+    ///
+    /// Sources receiving their identity otherwise (e.g. from a Workload API
+    /// client) return none and call `Participant::reload_security` after a
+    /// rotation.
+    fn watched_files(&self) -> Vec<PathBuf> {
+        Vec::new()
+    }
+}
+
+/// X.509 identity supplied by an [`IdentitySource`]
+#[derive(Clone)]
+pub struct X509Identity {
+    /// Identity certificate (PEM), optionally followed by its intermediates
+    pub certificate_pem: Vec<u8>,
+
+    /// Private key of the certificate (PKCS#8 PEM)
+    pub private_key_pem: Vec<u8>,
+
+    /// CA certificates trusted for remote participants (PEM, concatenated)
+    pub trust_bundle_pem: Vec<u8>,
+
+    /// Identity name (e.g. the SPIFFE ID); the certificate subject if `None`
+    pub name: Option<String>,
+}
+
+impl fmt::Debug for X509Identity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("X509Identity")
+            .field("certificate_pem", &self.certificate_pem.len())
+            .field("private_key_pem", &"<redacted>")
+            .field("trust_bundle_pem", &self.trust_bundle_pem.len())
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
 /// Identity handle
 ///
 /// Opaque handle to a validated participant identity.
@@ -272,7 +331,8 @@ impl HandshakeReplyToken {
 ///
 /// # Current Implementation
 ///
-/// - X.509 certificate-based authentication (PKI-DH)
+/// - X.509 certificate-based authentication (PKI-DH), from the configured
+///   files or the configured [`IdentitySource`]
 ///
 /// # Future
 ///
@@ -282,7 +342,10 @@ pub fn create_authentication_plugin(
     config: &SecurityConfig,
 ) -> Result<Box<dyn AuthenticationPlugin>, Error> {
     // For now, always use X.509 authentication
-    let plugin = x509::X509AuthenticationPlugin::new(config)?;
+    let plugin = match &config.identity_source {
+        Some(source) => x509::X509AuthenticationPlugin::from_identity(source.current()?, config)?,
+        None => x509::X509AuthenticationPlugin::new(config)?,
+    };
     Ok(Box::new(plugin))
 }
 
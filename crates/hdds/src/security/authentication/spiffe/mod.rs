@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! SPIFFE X.509 SVIDs as participant identity.
+//!
+//! In SPIFFE deployments (SPIRE on Kubernetes, ...) workloads get a
+//! short-lived X.509 SVID whose URI SAN is their SPIFFE ID
+//! (`spiffe://<trust-domain>/<path>`), and the trust bundle of their trust
+//! domain. Both are rotated by the platform well before expiry.
+//!
+//! [`SpiffeSvidSource`] reads the SVID, its key and the bundle from the
+//! files kept up to date by a Workload API client such as `spiffe-helper`
+//! or the SPIRE agent sidecar, and is registered with
+//! `SecurityConfigBuilder::identity_source`. The participant polls the files:
+//! after a rotation, X.509 authentication is rebuilt with the new SVID and
+//! every remote participant is re-authenticated against the new bundle.
+//!
+//! Applications talking to the Workload API directly implement
+//! [`IdentitySource`] over their client instead, and call
+//! `Participant::reload_security` on each update.
+//!
+//! The SVID must be issued by a CA of the bundle; intermediates following
+//! it in the SVID file are not used for validation.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use x509_parser::extensions::GeneralName;
+
+use super::x509::cert::parse_certificate;
+use super::{IdentitySource, X509Identity};
+use crate::security::SecurityError;
+
+/// SVID file name written by `spiffe-helper` by default
+pub const DEFAULT_SVID_FILE: &str = "svid.pem";
+/// SVID private key file name written by `spiffe-helper` by default
+pub const DEFAULT_SVID_KEY_FILE: &str = "svid_key.pem";
+/// Trust bundle file name written by `spiffe-helper` by default
+pub const DEFAULT_BUNDLE_FILE: &str = "svid_bundle.pem";
+
+/// X.509 SVID read from the files maintained by a Workload API client
+#[derive(Debug, Clone)]
+pub struct SpiffeSvidSource {
+    svid: PathBuf,
+    svid_key: PathBuf,
+    bundle: PathBuf,
+}
+
+impl SpiffeSvidSource {
+    /// SVID, key and bundle under `dir`, with the `spiffe-helper` default
+    /// file names (`svid.pem`, `svid_key.pem`, `svid_bundle.pem`).
+    pub fn new<P: AsRef<Path>>(dir: P) -> Self {
+        let dir = dir.as_ref();
+        Self::with_files(
+            dir.join(DEFAULT_SVID_FILE),
+            dir.join(DEFAULT_SVID_KEY_FILE),
+            dir.join(DEFAULT_BUNDLE_FILE),
+        )
+    }
+
+    /// SVID, key and bundle at explicit paths.
+    pub fn with_files<P: Into<PathBuf>>(svid: P, svid_key: P, bundle: P) -> Self {
+        Self {
+            svid: svid.into(),
+            svid_key: svid_key.into(),
+            bundle: bundle.into(),
+        }
+    }
+}
+
+impl IdentitySource for SpiffeSvidSource {
+    fn current(&self) -> Result<X509Identity, SecurityError> {
+        let read = |path: &Path| {
+            fs::read(path).map_err(|e| {
+                SecurityError::ConfigError(format!("SVID file {}: {}", path.display(), e))
+            })
+        };
+        let certificate_pem = read(&self.svid)?;
+        let id = spiffe_id(&certificate_pem)?;
+        log::debug!("[security] loaded X.509 SVID {}", id);
+        Ok(X509Identity {
+            certificate_pem,
+            private_key_pem: read(&self.svid_key)?,
+            trust_bundle_pem: read(&self.bundle)?,
+            name: Some(id),
+        })
+    }
+
+    fn watched_files(&self) -> Vec<PathBuf> {
+        vec![
+            self.svid.clone(),
+            self.svid_key.clone(),
+            self.bundle.clone(),
+        ]
+    }
+}
+
+/// SPIFFE ID of an X.509 SVID (PEM)
+///
+/// # Errors
+///
+/// `SecurityError::CertificateInvalid` unless the certificate has exactly
+/// one URI SAN, holding a `spiffe://` ID with a trust domain.
+pub fn spiffe_id(certificate_pem: &[u8]) -> Result<String, SecurityError> {
+    let invalid =
+        |reason: &str| SecurityError::CertificateInvalid(format!("not an SVID: {}", reason));
+    let certificate = parse_certificate(certificate_pem)?;
+    let san = certificate
+        .subject_alternative_name()
+        .map_err(|_| invalid("malformed subjectAltName"))?
+        .ok_or_else(|| invalid("no subjectAltName"))?;
+    let mut uris = san
+        .value
+        .general_names
+        .iter()
+        .filter_map(|name| match name {
+            GeneralName::URI(uri) => Some(*uri),
+            _ => None,
+        });
+    let (Some(uri), None) = (uris.next(), uris.next()) else {
+        return Err(invalid("expected exactly one URI SAN"));
+    };
+    let trust_domain = uri
+        .strip_prefix("spiffe://")
+        .map(|rest| rest.split('/').next().unwrap_or_default())
+        .ok_or_else(|| invalid("URI SAN is not a SPIFFE ID"))?;
+    let valid_trust_domain = !trust_domain.is_empty()
+        && trust_domain.bytes().all(|b| {
+            b.is_ascii_lowercase() || b.is_ascii_digit() || matches!(b, b'.' | b'-' | b'_')
+        });
+    if !valid_trust_domain {
+        return Err(invalid("invalid trust domain"));
+    }
+    Ok(uri.to_string())
+}
+
+#[cfg(test)]
+mod tests;
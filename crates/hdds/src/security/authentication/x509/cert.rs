@@ -56,6 +56,35 @@ pub(crate) fn validate_certificate_chain(
         }
     }
 
+    let ca_der_data = parse_ca_der(ca_certs_pem)?;
+    let ca_certs = parse_ca_certificates(&ca_der_data)?;
+
+    let issuer = participant_cert.issuer();
+    let issuer_cert = ca_certs
+        .iter()
+        .find(|ca| ca.subject() == issuer)
+        .ok_or_else(|| {
+            SecurityError::CertificateInvalid(
+                "Certificate issuer not found in CA trust store".to_string(),
+            )
+        })?;
+
+    if verify_issuer_signature(
+        issuer_cert,
+        participant_cert.tbs_certificate.as_ref(),
+        participant_cert.signature_value.as_ref(),
+    ) {
+        return Ok(());
+    }
+
+    Err(SecurityError::CertificateInvalid(
+        "Certificate signature verification failed (tried RSA and ECDSA P-256)".to_string(),
+    ))
+}
+
+/// DER blocks of the PEM-encoded CA trust store.
+#[cfg(feature = "security")]
+fn parse_ca_der(ca_certs_pem: &[u8]) -> Result<Vec<Vec<u8>>, SecurityError> {
     let pem_blocks = ::pem::parse_many(ca_certs_pem).map_err(|e| {
         SecurityError::CertificateInvalid(format!("Failed to parse CA certs: {}", e))
     })?;
@@ -70,39 +99,40 @@ pub(crate) fn validate_certificate_chain(
             "No CA certificates in trust store".to_string(),
         ));
     }
+    Ok(ca_der_data)
+}
 
-    let mut ca_certs = Vec::new();
-    for der_data in &ca_der_data {
-        let (_, ca_cert) = X509Certificate::from_der(der_data).map_err(|e| {
-            SecurityError::CertificateInvalid(format!("Failed to parse CA cert: {:?}", e))
-        })?;
-        ca_certs.push(ca_cert);
-    }
-
-    let issuer = participant_cert.issuer();
-    let issuer_cert = ca_certs
+#[cfg(feature = "security")]
+fn parse_ca_certificates(
+    ca_der_data: &[Vec<u8>],
+) -> Result<Vec<X509Certificate<'_>>, SecurityError> {
+    ca_der_data
         .iter()
-        .find(|ca| ca.subject() == issuer)
-        .ok_or_else(|| {
-            SecurityError::CertificateInvalid(
-                "Certificate issuer not found in CA trust store".to_string(),
-            )
-        })?;
+        .map(|der_data| {
+            X509Certificate::from_der(der_data)
+                .map(|(_, ca_cert)| ca_cert)
+                .map_err(|e| {
+                    SecurityError::CertificateInvalid(format!("Failed to parse CA cert: {:?}", e))
+                })
+        })
+        .collect()
+}
 
+/// Check that `signed` carries `signature` from `issuer_cert`'s key.
+#[cfg(feature = "security")]
+fn verify_issuer_signature(issuer_cert: &X509Certificate<'_>, signed: &[u8], sig: &[u8]) -> bool {
     // For ECDSA verification with ring, we need the full SubjectPublicKeyInfo DER encoding,
     // not just the raw key bytes. The .raw field contains only the BIT STRING content (65 bytes
     // for uncompressed P-256), but ring expects the complete SPKI structure including algorithm OID.
     let issuer_public_key_spki = &issuer_cert.public_key().subject_public_key.data;
-    let signature = participant_cert.signature_value.as_ref();
-    let tbs_certificate = participant_cert.tbs_certificate.as_ref();
 
     // Try RSA first (for RSA certificates)
     let rsa_public_key = UnparsedPublicKey::new(
         &signature::RSA_PKCS1_2048_8192_SHA256,
         issuer_public_key_spki.as_ref(),
     );
-    if let Ok(()) = rsa_public_key.verify(tbs_certificate, signature) {
-        return Ok(());
+    if let Ok(()) = rsa_public_key.verify(signed, sig) {
+        return true;
     }
 
     // Try ECDSA P-256 (for EC certificates)
@@ -110,13 +140,127 @@ pub(crate) fn validate_certificate_chain(
         &signature::ECDSA_P256_SHA256_ASN1,
         issuer_public_key_spki.as_ref(),
     );
-    if let Ok(()) = ecdsa_public_key.verify(tbs_certificate, signature) {
+    ecdsa_public_key.verify(signed, sig).is_ok()
+}
+
+/// Certificates revoked by the trusted CAs, from certificate revocation lists.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RevocationList {
+    /// (issuer name DER, serial number DER) of each revoked certificate
+    revoked: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+impl RevocationList {
+    /// Number of revoked certificates
+    pub(crate) fn len(&self) -> usize {
+        self.revoked.len()
+    }
+}
+
+/// Parse certificate revocation lists (PEM `X509 CRL` blocks or one DER CRL).
+///
+/// Each CRL must be signed by one of the CA certificates of `ca_certs_pem`.
+#[cfg(feature = "security")]
+pub(crate) fn parse_revocation_list(
+    crl_data: &[u8],
+    ca_certs_pem: &[u8],
+) -> Result<RevocationList, SecurityError> {
+    let crl_der_data: Vec<Vec<u8>> = if crl_data.starts_with(b"-----BEGIN") {
+        ::pem::parse_many(crl_data)
+            .map_err(|e| SecurityError::ConfigError(format!("Failed to parse CRL PEM: {}", e)))?
+            .into_iter()
+            .filter(|block| block.tag() == "X509 CRL")
+            .map(|block| block.contents().to_vec())
+            .collect()
+    } else {
+        vec![crl_data.to_vec()]
+    };
+    if crl_der_data.is_empty() {
+        return Err(SecurityError::ConfigError(
+            "No X509 CRL block in revocation list".to_string(),
+        ));
+    }
+
+    let ca_der_data = parse_ca_der(ca_certs_pem)?;
+    let ca_certs = parse_ca_certificates(&ca_der_data)?;
+
+    let mut list = RevocationList::default();
+    for der in &crl_der_data {
+        let (_, crl) = x509_parser::parse_x509_crl(der)
+            .map_err(|e| SecurityError::ConfigError(format!("Failed to parse CRL: {:?}", e)))?;
+        let issuer = ca_certs
+            .iter()
+            .find(|ca| ca.subject() == crl.issuer())
+            .ok_or_else(|| {
+                SecurityError::ConfigError(format!(
+                    "CRL issuer '{}' not found in CA trust store",
+                    crl.issuer()
+                ))
+            })?;
+        if !verify_issuer_signature(
+            issuer,
+            crl.tbs_cert_list.as_ref(),
+            crl.signature_value.as_ref(),
+        ) {
+            return Err(SecurityError::ConfigError(format!(
+                "CRL signature verification failed for issuer '{}'",
+                crl.issuer()
+            )));
+        }
+        for revoked in crl.iter_revoked_certificates() {
+            list.revoked.push((
+                crl.issuer().as_raw().to_vec(),
+                revoked.raw_serial().to_vec(),
+            ));
+        }
+    }
+    Ok(list)
+}
+
+#[cfg(not(feature = "security"))]
+pub(crate) fn parse_revocation_list(
+    _crl_data: &[u8],
+    _ca_certs_pem: &[u8],
+) -> Result<RevocationList, SecurityError> {
+    Ok(RevocationList::default())
+}
+
+/// Reject a certificate listed in `revocations`.
+#[cfg(feature = "security")]
+pub(crate) fn check_not_revoked(
+    certificate_pem: &[u8],
+    revocations: &RevocationList,
+) -> Result<(), SecurityError> {
+    if revocations.revoked.is_empty() {
         return Ok(());
     }
+    // Parsed in place: parse_certificate leaks its buffer and this runs per SPDP
+    let (_, pem) = parse_x509_pem(certificate_pem).map_err(|e| {
+        SecurityError::CertificateInvalid(format!("Failed to parse X.509 PEM: {:?}", e))
+    })?;
+    let (_, cert) = x509_parser::parse_x509_certificate(&pem.contents).map_err(|e| {
+        SecurityError::CertificateInvalid(format!("Failed to parse X.509 certificate: {:?}", e))
+    })?;
+    let issuer = cert.issuer().as_raw();
+    let serial = cert.raw_serial();
+    if revocations
+        .revoked
+        .iter()
+        .any(|(revoked_issuer, revoked_serial)| {
+            revoked_issuer == issuer && revoked_serial == serial
+        })
+    {
+        return Err(SecurityError::CertificateRevoked);
+    }
+    Ok(())
+}
 
-    Err(SecurityError::CertificateInvalid(
-        "Certificate signature verification failed (tried RSA and ECDSA P-256)".to_string(),
-    ))
+#[cfg(not(feature = "security"))]
+pub(crate) fn check_not_revoked(
+    _certificate_pem: &[u8],
+    _revocations: &RevocationList,
+) -> Result<(), SecurityError> {
+    Ok(())
 }
 
 #[cfg(not(feature = "security"))]
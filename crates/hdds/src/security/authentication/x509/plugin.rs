@@ -4,16 +4,16 @@
 use std::fs;
 
 use crate::core::discovery::guid::GUID;
-use crate::dds::Error;
+use crate::dds::{Error, ErrorContext};
 use crate::security::config::SecurityConfig;
 use crate::security::SecurityError;
 
 use super::super::{
-    AuthenticationPlugin, HandshakeReplyToken, HandshakeRequestToken, IdentityHandle,
+    AuthenticationPlugin, HandshakeReplyToken, HandshakeRequestToken, IdentityHandle, X509Identity,
 };
 use super::cert::{
-    derive_guid_from_certificate, get_expiration_time, parse_subject_name,
-    validate_certificate_chain,
+    check_not_revoked, derive_guid_from_certificate, get_expiration_time, parse_revocation_list,
+    parse_subject_name, validate_certificate_chain, RevocationList,
 };
 use super::crypto::{generate_challenge, sign_data, verify_signature};
 
@@ -23,41 +23,92 @@ pub struct X509AuthenticationPlugin {
     identity_certificate_pem: Vec<u8>,
     private_key_pem: Vec<u8>,
     ca_certificates_pem: Vec<u8>,
+    revocations: RevocationList,
     #[allow(dead_code)]
     check_revocation: bool,
+    /// Identity name overriding the certificate subject
+    name: Option<String>,
 }
 
 impl X509AuthenticationPlugin {
     /// Create a new X.509 authentication plugin from the provided security configuration.
     pub fn new(config: &SecurityConfig) -> Result<Self, Error> {
-        let identity_certificate_pem =
-            fs::read(&config.identity_certificate).map_err(Error::IoError)?;
-        let private_key_pem = fs::read(&config.private_key).map_err(Error::IoError)?;
-        let ca_certificates_pem = fs::read(&config.ca_certificates).map_err(Error::IoError)?;
+        let identity = X509Identity {
+            certificate_pem: fs::read(&config.identity_certificate).map_err(Error::IoError)?,
+            private_key_pem: fs::read(&config.private_key).map_err(Error::IoError)?,
+            trust_bundle_pem: fs::read(&config.ca_certificates).map_err(Error::IoError)?,
+            name: None,
+        };
+        Self::from_identity(identity, config)
+    }
+
+    /// Create the plugin from an identity supplied by an
+    /// [`IdentitySource`](crate::security::authentication::IdentitySource);
+    /// the revocation settings still come from `config`.
+    pub fn from_identity(identity: X509Identity, config: &SecurityConfig) -> Result<Self, Error> {
+        let X509Identity {
+            certificate_pem: identity_certificate_pem,
+            private_key_pem,
+            trust_bundle_pem: ca_certificates_pem,
+            name,
+        } = identity;
 
         Self::validate_pem_format(&identity_certificate_pem, "identity certificate")?;
         Self::validate_pem_format(&private_key_pem, "private key")?;
         Self::validate_pem_format(&ca_certificates_pem, "CA certificates")?;
 
-        validate_certificate_chain(&identity_certificate_pem, &ca_certificates_pem)
-            .map_err(|_| Error::Config)?;
+        validate_certificate_chain(&identity_certificate_pem, &ca_certificates_pem).map_err(
+            |e| {
+                Error::config(format!("identity certificate chain: {}", e))
+                    .with_context(ErrorContext::new().entity("X.509 authentication"))
+            },
+        )?;
+
+        let revocations = match &config.certificate_revocation_list {
+            Some(path) => {
+                let context = ErrorContext::new()
+                    .entity("X.509 authentication")
+                    .path(path.display().to_string());
+                let crl =
+                    fs::read(path).map_err(|e| Error::IoError(e).with_context(context.clone()))?;
+                let revocations =
+                    parse_revocation_list(&crl, &ca_certificates_pem).map_err(|e| {
+                        log::warn!("[security] revocation list {}: {}", path.display(), e);
+                        Error::config(format!("invalid revocation list: {}", e))
+                            .with_context(context)
+                    })?;
+                log::debug!(
+                    "[security] {} revoked certificates in {}",
+                    revocations.len(),
+                    path.display()
+                );
+                revocations
+            }
+            None => RevocationList::default(),
+        };
 
         Ok(Self {
             identity_certificate_pem,
             private_key_pem,
             ca_certificates_pem,
+            revocations,
             check_revocation: config.check_certificate_revocation,
+            name,
         })
     }
 
     /// Quick validity check that the provided blob appears to be PEM formatted data.
-    pub(super) fn validate_pem_format(data: &[u8], _label: &str) -> Result<(), Error> {
+    pub(super) fn validate_pem_format(data: &[u8], label: &str) -> Result<(), Error> {
+        let invalid = |reason: &str| {
+            Err(Error::config(format!("{} {}", label, reason))
+                .with_context(ErrorContext::new().entity("X.509 authentication")))
+        };
         if data.is_empty() {
-            return Err(Error::Config);
+            return invalid("is empty");
         }
 
         if !data.starts_with(b"-----BEGIN") {
-            return Err(Error::Config);
+            return invalid("is not PEM");
         }
 
         Ok(())
@@ -66,7 +117,10 @@ impl X509AuthenticationPlugin {
 
 impl AuthenticationPlugin for X509AuthenticationPlugin {
     fn validate_identity(&self) -> Result<IdentityHandle, SecurityError> {
-        let subject_name = parse_subject_name(&self.identity_certificate_pem)?;
+        let subject_name = match &self.name {
+            Some(name) => name.clone(),
+            None => parse_subject_name(&self.identity_certificate_pem)?,
+        };
         let expiration_time = get_expiration_time(&self.identity_certificate_pem)?;
         let guid = derive_guid_from_certificate(&self.identity_certificate_pem)?;
 
@@ -112,6 +166,7 @@ impl AuthenticationPlugin for X509AuthenticationPlugin {
         }
 
         validate_certificate_chain(&request.identity_certificate, &self.ca_certificates_pem)?;
+        check_not_revoked(&request.identity_certificate, &self.revocations)?;
         let _remote_subject = parse_subject_name(&request.identity_certificate)
             .unwrap_or_else(|_| "unknown".to_string());
 
@@ -5,9 +5,18 @@
 //!
 //! Provides builder API for configuring DDS Security plugins.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
 
-use crate::dds::Error;
+use crate::dds::{Error, ErrorContext};
+
+use super::authentication::{AuthenticationPlugin, IdentitySource};
+#[cfg(feature = "security")]
+use super::{AccessControlPlugin, CryptographicPlugin};
+
+/// Rotation polling of an identity source without `reload_interval`
+const DEFAULT_IDENTITY_POLL_INTERVAL: Duration = Duration::from_secs(5);
 
 /// Security configuration for DDS Security v1.1 (OMG spec).
 ///
@@ -80,6 +89,10 @@ pub struct SecurityConfig {
     /// Enable AES-256-GCM encryption for RTPS submessages
     pub enable_encryption: bool,
 
+    /// Path to the 32-byte master key shared by participants, from which
+    /// per-topic data keys are derived (random per participant if unset)
+    pub master_key: Option<PathBuf>,
+
     /// Enable audit logging
     pub enable_audit_log: bool,
 
@@ -91,6 +104,29 @@ pub struct SecurityConfig {
 
     /// Validate certificate revocation via CRL/OCSP (default: false, performance)
     pub check_certificate_revocation: bool,
+
+    /// Path to certificate revocation lists (PEM or DER) signed by the CA
+    pub certificate_revocation_list: Option<PathBuf>,
+
+    /// Poll the security documents at this interval and apply changes
+    /// without restarting the participant (default: None, no polling)
+    pub reload_interval: Option<Duration>,
+
+    /// Custom authentication plugin replacing the X.509 one (the identity
+    /// paths are then unused)
+    pub authentication_plugin: Option<Arc<dyn AuthenticationPlugin>>,
+
+    /// Source of the X.509 identity replacing the identity certificate,
+    /// private key and CA paths (e.g. SPIFFE SVIDs rotated by the platform)
+    pub identity_source: Option<Arc<dyn IdentitySource>>,
+
+    /// Custom access control plugin replacing the governance/permissions one
+    #[cfg(feature = "security")]
+    pub access_control_plugin: Option<Arc<dyn AccessControlPlugin>>,
+
+    /// Custom cryptographic plugin replacing the master key one
+    #[cfg(feature = "security")]
+    pub cryptographic_plugin: Option<Arc<dyn CryptographicPlugin>>,
 }
 
 impl SecurityConfig {
@@ -104,7 +140,7 @@ impl SecurityConfig {
 ///
 /// Provides fluent API for building [`SecurityConfig`] with validation.
 /// All required fields must be set before calling [`build()`], otherwise
-/// construction fails with [`Error::Config`].
+/// construction fails with [`Error::InvalidConfig`].
 ///
 /// # Required Fields
 ///
@@ -116,16 +152,34 @@ impl SecurityConfig {
 ///
 /// - [`permissions_xml`]: Access control policies (default: None -> permissive)
 /// - [`enable_encryption`]: AES-256-GCM encryption (default: false)
+/// - [`master_key`]: Shared key for per-topic data keys (default: random)
 /// - [`enable_audit_log`]: Audit logging to syslog (default: false)
 /// - [`require_authentication`]: Require PKI auth (default: true)
 /// - [`check_certificate_revocation`]: CRL/OCSP validation (default: false)
+/// - [`certificate_revocation_list`]: Revoked certificates (default: None)
+/// - [`reload_interval`]: Hot reload of changed documents (default: None,
+///   5 s with an identity source)
+///
+/// # Custom Plugins
+///
+/// The built-in plugins can be replaced by user implementations of the
+/// plugin traits, e.g. an HSM-backed authentication or a certified crypto
+/// provider:
+///
+/// - [`authentication_plugin`]: replaces X.509 authentication; the
+///   identity certificate, private key and CA paths are then optional
+/// - [`identity_source`]: keeps X.509 authentication with an identity
+///   supplied by the platform (e.g. SPIFFE SVIDs); the identity
+///   certificate, private key and CA paths are then optional
+/// - [`access_control_plugin`]: replaces governance/permissions XML
+/// - [`cryptographic_plugin`]: replaces the master key topic protection
 ///
 /// # Validation
 ///
 /// The [`build()`] method validates:
-/// 1. All required fields are set (returns [`Error::Config`] if missing)
-/// 2. All certificate files exist on disk (returns [`Error::Config`] if not found)
-/// 3. Permissions XML exists if specified (returns [`Error::Config`] if not found)
+/// 1. All required fields are set (returns [`Error::InvalidConfig`] if missing)
+/// 2. All certificate files exist on disk (returns [`Error::InvalidConfig`] if not found)
+/// 3. Permissions XML exists if specified (returns [`Error::InvalidConfig`] if not found)
 ///
 /// **Note:** File format validation (PEM parsing, XML schema) is deferred to
 /// plugin initialization at runtime.
@@ -161,9 +215,16 @@ impl SecurityConfig {
 /// [`ca_certificates`]: SecurityConfigBuilder::ca_certificates
 /// [`permissions_xml`]: SecurityConfigBuilder::permissions_xml
 /// [`enable_encryption`]: SecurityConfigBuilder::enable_encryption
+/// [`master_key`]: SecurityConfigBuilder::master_key
 /// [`enable_audit_log`]: SecurityConfigBuilder::enable_audit_log
 /// [`require_authentication`]: SecurityConfigBuilder::require_authentication
 /// [`check_certificate_revocation`]: SecurityConfigBuilder::check_certificate_revocation
+/// [`certificate_revocation_list`]: SecurityConfigBuilder::certificate_revocation_list
+/// [`reload_interval`]: SecurityConfigBuilder::reload_interval
+/// [`authentication_plugin`]: SecurityConfigBuilder::authentication_plugin
+/// [`identity_source`]: SecurityConfigBuilder::identity_source
+/// [`access_control_plugin`]: SecurityConfigBuilder::access_control_plugin
+/// [`cryptographic_plugin`]: SecurityConfigBuilder::cryptographic_plugin
 /// [`build()`]: SecurityConfigBuilder::build
 #[derive(Debug)]
 #[allow(clippy::struct_excessive_bools)] // Configuration builder legitimately has multiple flags
@@ -174,10 +235,19 @@ pub struct SecurityConfigBuilder {
     governance_xml: Option<PathBuf>,
     permissions_xml: Option<PathBuf>,
     enable_encryption: bool,
+    master_key: Option<PathBuf>,
     enable_audit_log: bool,
     audit_log_path: Option<PathBuf>,
     require_authentication: bool,
     check_certificate_revocation: bool,
+    certificate_revocation_list: Option<PathBuf>,
+    reload_interval: Option<Duration>,
+    authentication_plugin: Option<Arc<dyn AuthenticationPlugin>>,
+    identity_source: Option<Arc<dyn IdentitySource>>,
+    #[cfg(feature = "security")]
+    access_control_plugin: Option<Arc<dyn AccessControlPlugin>>,
+    #[cfg(feature = "security")]
+    cryptographic_plugin: Option<Arc<dyn CryptographicPlugin>>,
 }
 
 impl SecurityConfigBuilder {
@@ -271,6 +341,24 @@ impl SecurityConfigBuilder {
         self
     }
 
+    /// Set master key path (optional)
+    ///
+    /// The file holds the 32 raw key bytes. Each protected topic gets its
+    /// own data key derived from it, so participants exchanging protected
+    /// topics must share the same master key. Without one, a random key is
+    /// used (and a warning logged at startup): protected topics are then only
+    /// readable within the participant.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// builder.master_key("keys/domain0.key");
+    /// ```
+    pub fn master_key<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.master_key = Some(path.into());
+        self
+    }
+
     /// Enable audit logging (default: false)
     ///
     /// When enabled, all security events are logged to file.
@@ -332,12 +420,118 @@ impl SecurityConfigBuilder {
         self
     }
 
+    /// Set certificate revocation list path (optional)
+    ///
+    /// The file holds one or more CRLs (PEM `X509 CRL` blocks, or a single
+    /// DER CRL), each signed by one of the CA certificates. Remote
+    /// participants presenting a revoked certificate are rejected.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// builder.certificate_revocation_list("certs/ca.crl");
+    /// ```
+    pub fn certificate_revocation_list<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.certificate_revocation_list = Some(path.into());
+        self
+    }
+
+    /// Reload changed security documents every `interval` (optional)
+    ///
+    /// The participant polls the CA certificates, revocation list,
+    /// governance and permissions files. When one changes, the plugins are
+    /// rebuilt and remote participants that no longer authenticate are
+    /// removed with their endpoints. See `Participant::reload_security`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// builder.reload_interval(std::time::Duration::from_secs(5));
+    /// ```
+    pub fn reload_interval(mut self, interval: Duration) -> Self {
+        self.reload_interval = Some(interval);
+        self
+    }
+
+    /// Use a custom authentication plugin (optional)
+    ///
+    /// Replaces X.509 authentication: the plugin validates the local
+    /// identity and the identity tokens of remote participants. The
+    /// identity certificate, private key and CA paths are not required.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// builder.authentication_plugin(Arc::new(HsmAuthentication::open("slot0")?));
+    /// ```
+    pub fn authentication_plugin(mut self, plugin: Arc<dyn AuthenticationPlugin>) -> Self {
+        self.authentication_plugin = Some(plugin);
+        self
+    }
+
+    /// Take the X.509 identity from `source` (optional)
+    ///
+    /// The identity certificate, private key and CA certificates come from
+    /// the source instead of files, e.g. SPIFFE SVIDs rotated by the
+    /// platform. The source is polled for rotation every
+    /// [`reload_interval`](Self::reload_interval), 5 s unless set: on
+    /// rotation, X.509 authentication is rebuilt with the new identity and
+    /// remote participants are re-authenticated against the new trust
+    /// bundle.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use hdds::security::authentication::spiffe::SpiffeSvidSource;
+    ///
+    /// builder.identity_source(Arc::new(SpiffeSvidSource::new("/run/spiffe")));
+    /// ```
+    pub fn identity_source(mut self, source: Arc<dyn IdentitySource>) -> Self {
+        self.identity_source = Some(source);
+        self
+    }
+
+    /// Use a custom access control plugin (optional)
+    ///
+    /// Replaces the governance and permissions XML: the plugin authorizes
+    /// endpoints and decides the protection of each topic's data.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// builder.access_control_plugin(Arc::new(LdapAccessControl::new(directory)));
+    /// ```
+    #[cfg(feature = "security")]
+    pub fn access_control_plugin(mut self, plugin: Arc<dyn AccessControlPlugin>) -> Self {
+        self.access_control_plugin = Some(plugin);
+        self
+    }
+
+    /// Use a custom cryptographic plugin (optional)
+    ///
+    /// Replaces the master key topic protection and enables encryption:
+    /// the plugin signs or encrypts the data of protected topics.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// builder.cryptographic_plugin(Arc::new(FipsCrypto::load(module)?));
+    /// ```
+    #[cfg(feature = "security")]
+    pub fn cryptographic_plugin(mut self, plugin: Arc<dyn CryptographicPlugin>) -> Self {
+        self.cryptographic_plugin = Some(plugin);
+        self.enable_encryption = true;
+        self
+    }
+
     /// Build the security configuration
     ///
     /// # Errors
     ///
     /// Returns error if:
-    /// - Required fields are missing (identity_certificate, private_key, ca_certificates)
+    /// - Required fields are missing (identity_certificate, private_key,
+    ///   ca_certificates; not required with a custom authentication plugin
+    ///   or an identity source)
     /// - Certificate files do not exist
     /// - Permissions XML is invalid
     ///
@@ -351,37 +545,35 @@ impl SecurityConfigBuilder {
     ///     .build()?;
     /// ```
     pub fn build(self) -> Result<SecurityConfig, Error> {
-        // Validate required fields
-        let identity_certificate = self.identity_certificate.ok_or(Error::Config)?;
-
-        let private_key = self.private_key.ok_or(Error::Config)?;
-
-        let ca_certificates = self.ca_certificates.ok_or(Error::Config)?;
-
-        // Validate files exist
-        if !identity_certificate.exists() {
-            return Err(Error::Config);
-        }
-
-        if !private_key.exists() {
-            return Err(Error::Config);
-        }
-
-        if !ca_certificates.exists() {
-            return Err(Error::Config);
-        }
-
-        // Validate governance XML if provided
-        if let Some(ref governance_xml) = self.governance_xml {
-            if !governance_xml.exists() {
-                return Err(Error::Config);
-            }
-        }
-
-        // Validate permissions XML if provided
-        if let Some(ref permissions_xml) = self.permissions_xml {
-            if !permissions_xml.exists() {
-                return Err(Error::Config);
+        // Validate required fields (the custom authentication plugin or the
+        // identity source owns the identity otherwise)
+        let custom_authentication =
+            self.authentication_plugin.is_some() || self.identity_source.is_some();
+        let required = |path: Option<PathBuf>, what: &str| match path {
+            Some(path) if path.exists() => Ok(path),
+            None if custom_authentication => Ok(PathBuf::new()),
+            Some(path) => Err(missing_file(what, &path)),
+            None => Err(Error::config(format!("{} not set", what))
+                .with_context(ErrorContext::new().entity("SecurityConfig"))),
+        };
+        let identity_certificate = required(self.identity_certificate, "identity certificate")?;
+        let private_key = required(self.private_key, "private key")?;
+        let ca_certificates = required(self.ca_certificates, "CA certificates")?;
+
+        // Validate the optional documents
+        for (path, what) in [
+            (&self.governance_xml, "governance document"),
+            (&self.permissions_xml, "permissions document"),
+            (&self.master_key, "master key"),
+            (
+                &self.certificate_revocation_list,
+                "certificate revocation list",
+            ),
+        ] {
+            if let Some(path) = path {
+                if !path.exists() {
+                    return Err(missing_file(what, path));
+                }
             }
         }
 
@@ -392,10 +584,23 @@ impl SecurityConfigBuilder {
             governance_xml: self.governance_xml,
             permissions_xml: self.permissions_xml,
             enable_encryption: self.enable_encryption,
+            master_key: self.master_key,
             enable_audit_log: self.enable_audit_log,
             audit_log_path: self.audit_log_path,
             require_authentication: self.require_authentication,
             check_certificate_revocation: self.check_certificate_revocation,
+            certificate_revocation_list: self.certificate_revocation_list,
+            reload_interval: self.reload_interval.or_else(|| {
+                self.identity_source
+                    .as_ref()
+                    .map(|_| DEFAULT_IDENTITY_POLL_INTERVAL)
+            }),
+            authentication_plugin: self.authentication_plugin,
+            identity_source: self.identity_source,
+            #[cfg(feature = "security")]
+            access_control_plugin: self.access_control_plugin,
+            #[cfg(feature = "security")]
+            cryptographic_plugin: self.cryptographic_plugin,
         })
     }
 }
@@ -409,14 +614,32 @@ impl Default for SecurityConfigBuilder {
             governance_xml: None,
             permissions_xml: None,
             enable_encryption: false,
+            master_key: None,
             enable_audit_log: false,
             audit_log_path: None,
             require_authentication: true,
             check_certificate_revocation: false,
+            certificate_revocation_list: None,
+            reload_interval: None,
+            authentication_plugin: None,
+            identity_source: None,
+            #[cfg(feature = "security")]
+            access_control_plugin: None,
+            #[cfg(feature = "security")]
+            cryptographic_plugin: None,
         }
     }
 }
 
+/// Configuration error for a security file that does not exist.
+fn missing_file(what: &str, path: &Path) -> Error {
+    Error::config(format!("{} not found", what)).with_context(
+        ErrorContext::new()
+            .entity("SecurityConfig")
+            .path(path.display().to_string()),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -11,6 +11,7 @@
 //! - AES-256-GCM encryption for confidentiality
 //! - ECDH P-256 key exchange for secure session key establishment
 //! - HKDF session key derivation
+//! - Per-topic data keys and governance-driven SIGN/ENCRYPT protection
 //! - SecuredPayload submessage format (RTPS v2.5 Sec.9.6.2)
 //!
 //! # Example
@@ -33,12 +34,14 @@ use zeroize::Zeroize;
 pub mod aes_gcm;
 pub mod key_exchange;
 pub mod session_keys;
+pub mod topic_keys;
 pub mod transform;
 
 // Re-export main types
 pub use aes_gcm::AesGcmCipher;
 pub use key_exchange::{EcdhKeyExchange, ECDH_P256_PUBLIC_KEY_LEN, ECDH_P256_SHARED_SECRET_LEN};
 pub use session_keys::SessionKeyManager;
+pub use topic_keys::{TopicKeys, TopicProtection};
 pub use transform::SecuredPayload;
 
 /// DDS Security v1.1 session key derivation info string
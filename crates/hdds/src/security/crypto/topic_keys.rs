@@ -0,0 +1,354 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Per-topic data keys and selective payload protection
+//!
+//! Governance assigns each topic a data protection kind
+//! ([`ProtectionKind`]). Protected topics get their own AES-256 key, derived
+//! with HKDF from the participant master key and the topic name: the key of
+//! one topic reveals nothing about the others, and a payload protected for
+//! one topic never verifies on another. Unprotected topics skip the crypto
+//! path entirely.
+//!
+//! # Wire Format
+//!
+//! The serialized payload is replaced by a header laid out like the
+//! CryptoHeader of DDS Security v1.1 Sec.9.5.2.3, the body and a tag:
+//!
+//! ```text
+//! +-------------------+
+//! | transform kind    |  4 bytes (0,0,0,3 = AES256-GMAC, 0,0,0,4 = AES256-GCM)
+//! +-------------------+
+//! | topic key id      |  4 bytes
+//! +-------------------+
+//! | nonce             | 12 bytes
+//! +-------------------+
+//! | body              |  N bytes (payload when signed, ciphertext when encrypted)
+//! +-------------------+
+//! | tag               | 16 bytes
+//! +-------------------+
+//! ```
+//!
+//! The header is authenticated with the body.
+//!
+//! # Example
+//!
+//! ```
+//! use hdds::security::access::ProtectionKind;
+//! use hdds::security::crypto::TopicKeys;
+//!
+//! let keys = TopicKeys::new([0x42; 32]);
+//! let command = keys.protection("operator/command", ProtectionKind::Encrypt)?;
+//! let command = command.expect("protected topic");
+//!
+//! let wire = command.protect(b"stop")?;
+//! assert_eq!(command.unprotect(&wire)?, b"stop");
+//!
+//! // The control loop topic stays in the clear
+//! assert!(keys.protection("control/loop", ProtectionKind::None)?.is_none());
+//! # Ok::<(), hdds::security::SecurityError>(())
+//! ```
+
+use super::{AesGcmCipher, SessionKeyManager};
+use crate::security::access::ProtectionKind;
+use crate::security::cryptographic::{CryptographicPlugin, PayloadProtection};
+use crate::security::SecurityError;
+use ring::rand::{SecureRandom, SystemRandom};
+use zeroize::Zeroize;
+
+/// HKDF info for topic data keys
+const TOPIC_KEY_INFO: &[u8] = b"DDS Security v1.1 Topic Key";
+
+/// HKDF info for topic key identifiers
+const TOPIC_KEY_ID_INFO: &[u8] = b"DDS Security v1.1 Topic Key Id";
+
+/// CryptoTransformKind of AES-256-GMAC (signed payloads)
+const TRANSFORM_AES256_GMAC: u8 = 3;
+
+/// CryptoTransformKind of AES-256-GCM (encrypted payloads)
+const TRANSFORM_AES256_GCM: u8 = 4;
+
+/// Transform kind + key id + nonce
+const HEADER_LEN: usize = 4 + 4 + 12;
+
+/// GCM/GMAC authentication tag
+const TAG_LEN: usize = 16;
+
+/// Master key from which per-topic data keys are derived
+pub struct TopicKeys {
+    master_key: [u8; 32],
+}
+
+impl TopicKeys {
+    /// Derive topic keys from `master_key`.
+    ///
+    /// Participants that exchange protected topics must share the master key.
+    #[must_use]
+    pub fn new(master_key: [u8; 32]) -> Self {
+        Self { master_key }
+    }
+
+    /// Derive topic keys from a random master key.
+    ///
+    /// Protected topics are then only readable within this participant.
+    pub fn generate() -> Result<Self, SecurityError> {
+        let mut master_key = [0u8; 32];
+        SystemRandom::new().fill(&mut master_key).map_err(|_| {
+            SecurityError::CryptoError("Failed to generate random master key".to_string())
+        })?;
+        Ok(Self::new(master_key))
+    }
+
+    /// Protection of `topic` with `kind`, `None` when `kind` is
+    /// [`ProtectionKind::None`].
+    pub fn protection(
+        &self,
+        topic: &str,
+        kind: ProtectionKind,
+    ) -> Result<Option<TopicProtection>, SecurityError> {
+        if kind == ProtectionKind::None {
+            return Ok(None);
+        }
+
+        let salt = topic.as_bytes();
+        let mut key =
+            SessionKeyManager::derive_session_key(&self.master_key, salt, TOPIC_KEY_INFO)?;
+        let id = SessionKeyManager::derive_session_key(&self.master_key, salt, TOPIC_KEY_ID_INFO)?;
+        let cipher = AesGcmCipher::new(&key)?;
+        key.zeroize();
+
+        Ok(Some(TopicProtection {
+            topic: topic.to_string(),
+            kind,
+            key_id: u32::from_be_bytes([id[0], id[1], id[2], id[3]]),
+            cipher,
+        }))
+    }
+}
+
+impl CryptographicPlugin for TopicKeys {
+    fn topic_protection(
+        &self,
+        topic: &str,
+        kind: ProtectionKind,
+    ) -> Result<Option<Box<dyn PayloadProtection>>, SecurityError> {
+        Ok(self
+            .protection(topic, kind)?
+            .map(|protection| Box::new(protection) as Box<dyn PayloadProtection>))
+    }
+}
+
+impl std::fmt::Debug for TopicKeys {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TopicKeys").finish_non_exhaustive()
+    }
+}
+
+impl Drop for TopicKeys {
+    fn drop(&mut self) {
+        self.master_key.zeroize();
+    }
+}
+
+/// Data key and protection kind of one topic
+pub struct TopicProtection {
+    topic: String,
+    kind: ProtectionKind,
+    key_id: u32,
+    cipher: AesGcmCipher,
+}
+
+impl TopicProtection {
+    /// Topic this key protects
+    #[must_use]
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+
+    /// Applied protection ([`ProtectionKind::Sign`] or [`ProtectionKind::Encrypt`])
+    #[must_use]
+    pub fn kind(&self) -> ProtectionKind {
+        self.kind
+    }
+
+    /// Identifier of the topic key carried in the header
+    #[must_use]
+    pub fn key_id(&self) -> u32 {
+        self.key_id
+    }
+
+    fn transform_kind(&self) -> u8 {
+        match self.kind {
+            ProtectionKind::Encrypt => TRANSFORM_AES256_GCM,
+            _ => TRANSFORM_AES256_GMAC,
+        }
+    }
+
+    /// Sign or encrypt a serialized payload.
+    pub fn protect(&self, payload: &[u8]) -> Result<Vec<u8>, SecurityError> {
+        let nonce = AesGcmCipher::generate_nonce()?;
+        let mut out = Vec::with_capacity(HEADER_LEN + payload.len() + TAG_LEN);
+        out.extend_from_slice(&[0, 0, 0, self.transform_kind()]);
+        out.extend_from_slice(&self.key_id.to_be_bytes());
+        out.extend_from_slice(&nonce);
+
+        if self.kind == ProtectionKind::Encrypt {
+            let ciphertext = self.cipher.encrypt(payload, &nonce, &out)?;
+            out.extend_from_slice(&ciphertext);
+        } else {
+            // GMAC: GCM over an empty plaintext, header and payload as AAD
+            out.extend_from_slice(payload);
+            let tag = self.cipher.encrypt(&[], &nonce, &out)?;
+            out.extend_from_slice(&tag);
+        }
+        Ok(out)
+    }
+
+    /// Verify (and decrypt) a payload produced by [`protect`](Self::protect).
+    ///
+    /// # Errors
+    ///
+    /// `SecurityError::CryptoError` if the payload is not protected with
+    /// this topic's key and kind, or fails authentication.
+    pub fn unprotect(&self, data: &[u8]) -> Result<Vec<u8>, SecurityError> {
+        if data.len() < HEADER_LEN + TAG_LEN {
+            return Err(SecurityError::CryptoError(format!(
+                "Protected payload too short for topic '{}' ({} bytes)",
+                self.topic,
+                data.len()
+            )));
+        }
+        let (header, rest) = data.split_at(HEADER_LEN);
+        if header[..4] != [0, 0, 0, self.transform_kind()] {
+            return Err(SecurityError::CryptoError(format!(
+                "Unexpected transform kind {:?} for topic '{}' ({:?})",
+                &header[..4],
+                self.topic,
+                self.kind
+            )));
+        }
+        let key_id = u32::from_be_bytes([header[4], header[5], header[6], header[7]]);
+        if key_id != self.key_id {
+            return Err(SecurityError::CryptoError(format!(
+                "Topic key mismatch for topic '{}': expected {:#010x}, got {:#010x}",
+                self.topic, self.key_id, key_id
+            )));
+        }
+        let mut nonce = [0u8; 12];
+        nonce.copy_from_slice(&header[8..]);
+
+        if self.kind == ProtectionKind::Encrypt {
+            return self.cipher.decrypt(rest, &nonce, header);
+        }
+        let (signed, tag) = data.split_at(data.len() - TAG_LEN);
+        self.cipher.decrypt(tag, &nonce, signed)?;
+        Ok(rest[..rest.len() - TAG_LEN].to_vec())
+    }
+}
+
+impl PayloadProtection for TopicProtection {
+    fn kind(&self) -> ProtectionKind {
+        self.kind
+    }
+
+    fn protect(&self, payload: &[u8]) -> Result<Vec<u8>, SecurityError> {
+        TopicProtection::protect(self, payload)
+    }
+
+    fn unprotect(&self, data: &[u8]) -> Result<Vec<u8>, SecurityError> {
+        TopicProtection::unprotect(self, data)
+    }
+}
+
+impl std::fmt::Debug for TopicProtection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TopicProtection")
+            .field("topic", &self.topic)
+            .field("kind", &self.kind)
+            .field("key_id", &format_args!("{:#010x}", self.key_id))
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn protection(keys: &TopicKeys, topic: &str, kind: ProtectionKind) -> TopicProtection {
+        keys.protection(topic, kind).unwrap().unwrap()
+    }
+
+    #[test]
+    fn test_none_is_unprotected() {
+        let keys = TopicKeys::new([1; 32]);
+        assert!(keys
+            .protection("control/loop", ProtectionKind::None)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_encrypt_roundtrip_hides_payload() {
+        let keys = TopicKeys::new([1; 32]);
+        let topic = protection(&keys, "operator/command", ProtectionKind::Encrypt);
+
+        let wire = topic.protect(b"emergency stop").unwrap();
+        assert_eq!(wire.len(), HEADER_LEN + 14 + TAG_LEN);
+        assert!(!wire.windows(9).any(|w| w == b"emergency"));
+        assert_eq!(topic.unprotect(&wire).unwrap(), b"emergency stop");
+
+        // Same master key and topic on the receiving side
+        let peer = protection(
+            &TopicKeys::new([1; 32]),
+            "operator/command",
+            ProtectionKind::Encrypt,
+        );
+        assert_eq!(peer.key_id(), topic.key_id());
+        assert_eq!(peer.unprotect(&wire).unwrap(), b"emergency stop");
+    }
+
+    #[test]
+    fn test_sign_keeps_payload_readable_and_detects_tampering() {
+        let keys = TopicKeys::new([1; 32]);
+        let topic = protection(&keys, "status/mode", ProtectionKind::Sign);
+
+        let mut wire = topic.protect(b"AUTO").unwrap();
+        assert_eq!(&wire[HEADER_LEN..HEADER_LEN + 4], b"AUTO");
+        assert_eq!(topic.unprotect(&wire).unwrap(), b"AUTO");
+
+        wire[HEADER_LEN] ^= 0x01;
+        assert!(topic.unprotect(&wire).is_err());
+    }
+
+    #[test]
+    fn test_keys_are_isolated_per_topic() {
+        let keys = TopicKeys::new([1; 32]);
+        let command = protection(&keys, "operator/command", ProtectionKind::Encrypt);
+        let setpoint = protection(&keys, "operator/setpoint", ProtectionKind::Encrypt);
+        assert_ne!(command.key_id(), setpoint.key_id());
+
+        let wire = command.protect(b"stop").unwrap();
+        assert!(setpoint.unprotect(&wire).is_err());
+
+        // Another master key does not decrypt either
+        let other = protection(
+            &TopicKeys::new([2; 32]),
+            "operator/command",
+            ProtectionKind::Encrypt,
+        );
+        assert!(other.unprotect(&wire).is_err());
+    }
+
+    #[test]
+    fn test_kind_mismatch_rejected() {
+        let keys = TopicKeys::new([1; 32]);
+        let signed = protection(&keys, "operator/command", ProtectionKind::Sign);
+        let encrypted = protection(&keys, "operator/command", ProtectionKind::Encrypt);
+
+        // A plaintext payload, or one signed where encryption is required
+        assert!(encrypted.unprotect(&[0u8; 64]).is_err());
+        assert!(encrypted
+            .unprotect(&signed.protect(b"stop").unwrap())
+            .is_err());
+    }
+}
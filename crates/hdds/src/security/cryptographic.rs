@@ -3,45 +3,53 @@
 
 //! Cryptographic Plugin SPI
 //!
-//! AES-256-GCM encryption for RTPS submessages.
+//! Protection of serialized payloads.
 //!
 //! # OMG DDS Security v1.1 Sec.8.5 (Cryptographic)
 //!
-//! **Phase 1 Prep (Mode Nuit 2025-10-26):** Trait defined only
+//! Writers and readers of a protected topic get a [`PayloadProtection`]
+//! from the plugin when they are created (the crypto handle of
+//! `register_local_datawriter`/`register_local_datareader`), then call it
+//! for every sample (`encode_serialized_payload` /
+//! `decode_serialized_payload`).
+//!
+//! The built-in implementation is [`TopicKeys`](super::crypto::TopicKeys)
+//! (per-topic AES-256 keys derived from a master key). Register another
+//! one, e.g. backed by a certified crypto module, with
+//! [`SecurityConfigBuilder::cryptographic_plugin`](super::SecurityConfigBuilder::cryptographic_plugin).
+
+use std::fmt;
 
+use super::access::ProtectionKind;
 use super::SecurityError;
 
-/// Cryptographic plugin trait
-///
-/// Encrypts RTPS DATA submessages using AES-256-GCM.
-pub trait CryptographicPlugin: Send + Sync {
-    /// Encrypt RTPS DATA payload
-    ///
-    /// # Arguments
-    /// - `plaintext`: Original payload bytes
-    /// - `session_key_id`: Identifier for current session key
+/// Cryptographic plugin trait (SPI)
+pub trait CryptographicPlugin: fmt::Debug + Send + Sync {
+    /// Protection of `topic`'s data with `kind`
     ///
-    /// # Returns
-    /// - `Ok(ciphertext)`: IV + encrypted payload + GMAC tag
-    /// - `Err(SecurityError)`: Encryption failed
-    fn encrypt_data(&self, plaintext: &[u8], session_key_id: u64)
-        -> Result<Vec<u8>, SecurityError>;
+    /// Returns `None` when `kind` is [`ProtectionKind::None`]: the data of
+    /// the topic is sent in the clear. Participants exchanging a protected
+    /// topic must produce protections that [`unprotect`](PayloadProtection::unprotect)
+    /// each other's payloads.
+    fn topic_protection(
+        &self,
+        topic: &str,
+        kind: ProtectionKind,
+    ) -> Result<Option<Box<dyn PayloadProtection>>, SecurityError>;
+}
 
-    /// Decrypt RTPS DATA payload
+/// Protection of one topic's serialized payloads
+pub trait PayloadProtection: fmt::Debug + Send + Sync {
+    /// Applied protection ([`ProtectionKind::Sign`] or [`ProtectionKind::Encrypt`])
+    fn kind(&self) -> ProtectionKind;
+
+    /// Sign or encrypt a serialized payload before it is sent.
+    fn protect(&self, payload: &[u8]) -> Result<Vec<u8>, SecurityError>;
+
+    /// Verify (and decrypt) a received payload.
     ///
-    /// # Arguments
-    /// - `ciphertext`: IV + encrypted payload + GMAC tag
-    /// - `session_key_id`: Identifier for session key
+    /// # Errors
     ///
-    /// # Returns
-    /// - `Ok(plaintext)`: Decrypted payload
-    /// - `Err(SecurityError)`: Decryption or GMAC verification failed
-    fn decrypt_data(
-        &self,
-        ciphertext: &[u8],
-        session_key_id: u64,
-    ) -> Result<Vec<u8>, SecurityError>;
-
-    /// Generate new session key (called periodically for key rotation)
-    fn generate_session_key(&self) -> Result<u64, SecurityError>;
+    /// Any error drops the sample.
+    fn unprotect(&self, data: &[u8]) -> Result<Vec<u8>, SecurityError>;
 }
@@ -21,6 +21,14 @@
 //! +-- LoggingPlugin         (Audit trail + syslog)
 //! ```
 //!
+//! Authentication, access control and cryptographic are traits
+//! ([`AuthenticationPlugin`], [`AccessControlPlugin`], [`CryptographicPlugin`]):
+//! custom implementations are registered on the [`SecurityConfigBuilder`]
+//! and replace the built-in ones. The X.509 identity itself can come from
+//! an [`IdentitySource`](authentication::IdentitySource) instead of files,
+//! e.g. SPIFFE SVIDs rotated by the platform
+//! ([`SpiffeSvidSource`](authentication::spiffe::SpiffeSvidSource)).
+//!
 //! # Usage
 //!
 //! ```ignore
@@ -50,6 +58,9 @@
 /// Access Control plugin (permissions XML, topic allow/deny rules).
 #[cfg(feature = "security")]
 pub mod access;
+/// Access Control plugin SPI.
+#[cfg(feature = "security")]
+pub mod access_control;
 /// Audit logging plugin (audit trail, file backend, ANSSI hash-chain).
 #[cfg(feature = "security")]
 pub mod audit;
@@ -60,10 +71,25 @@ pub mod config;
 /// Cryptographic plugin (AES-256-GCM encryption, ECDH key exchange).
 #[cfg(feature = "security")]
 pub mod crypto;
+/// Cryptographic plugin SPI.
+#[cfg(feature = "security")]
+pub mod cryptographic;
+/// Change detection for hot reload of security documents.
+#[cfg(feature = "security")]
+mod watch;
 
+#[cfg(feature = "security")]
+pub use access_control::AccessControlPlugin;
+pub use authentication::AuthenticationPlugin;
 pub use config::{SecurityConfig, SecurityConfigBuilder};
+#[cfg(feature = "security")]
+pub use cryptographic::{CryptographicPlugin, PayloadProtection};
+#[cfg(feature = "security")]
+pub(crate) use watch::SecurityWatcher;
 
-use crate::dds::Error;
+use crate::dds::{Error, ErrorContext};
+use parking_lot::RwLock;
+use std::sync::Arc;
 
 /// Security plugin suite
 ///
@@ -74,19 +100,22 @@ use crate::dds::Error;
 /// 1. Created via `SecurityConfig::build()`
 /// 2. Attached to `Participant` during creation
 /// 3. Plugins invoked during discovery, data send/receive
+/// 4. Authentication and access control rebuilt by [`reload`](Self::reload)
+///    when their documents change
 pub struct SecurityPluginSuite {
     /// Authentication plugin (X.509 certificate validation)
     ///
     /// Connected to DiscoveryFsm via SecurityValidatorAdapter for automatic
     /// participant authentication during SPDP discovery. Remote participants
     /// with invalid identity_tokens are rejected per DDS Security v1.1 Sec.8.4.
-    pub(crate) authentication: Box<dyn authentication::AuthenticationPlugin>,
+    pub(crate) authentication: RwLock<Arc<dyn authentication::AuthenticationPlugin>>,
 
-    /// Access Control plugin (permissions XML enforcement)
+    /// Access Control plugin (custom, or permissions XML enforcement)
     ///
-    /// Optional: Only created if `governance_xml` and `permissions_xml` are both provided.
+    /// Optional: Only created if a custom plugin is registered, or if
+    /// `governance_xml` and `permissions_xml` are both provided.
     #[cfg(feature = "security")]
-    pub(crate) access_control: Option<access::AccessControlPlugin>,
+    pub(crate) access_control: RwLock<Option<Arc<dyn AccessControlPlugin>>>,
 
     /// Cryptographic plugin (AES-256-GCM encryption, ECDH key exchange)
     ///
@@ -94,6 +123,13 @@ pub struct SecurityPluginSuite {
     #[cfg(feature = "security")]
     pub(crate) cryptographic: Option<crypto::CryptoPlugin>,
 
+    /// Topic data protection (custom plugin, or per-topic keys derived
+    /// from the master key)
+    ///
+    /// Optional: Only created if `enable_encryption` is true.
+    #[cfg(feature = "security")]
+    pub(crate) topic_crypto: Option<Arc<dyn CryptographicPlugin>>,
+
     /// Logging plugin (audit trail with ANSSI-compliant hash-chain)
     ///
     /// Optional: Only created if `enable_audit_log` is true.
@@ -116,28 +152,11 @@ impl SecurityPluginSuite {
     /// - Crypto initialization fails
     pub fn new(config: SecurityConfig) -> Result<Self, Error> {
         // Initialize authentication plugin (always required)
-        let authentication = authentication::create_authentication_plugin(&config)?;
+        let authentication = Self::load_authentication(&config)?;
 
         // Initialize access control plugin if governance and permissions XML are provided
         #[cfg(feature = "security")]
-        let access_control = {
-            match (&config.governance_xml, &config.permissions_xml) {
-                (Some(governance), Some(permissions)) => {
-                    let governance_content =
-                        std::fs::read_to_string(governance).map_err(|_| Error::Config)?;
-                    let permissions_content =
-                        std::fs::read_to_string(permissions).map_err(|_| Error::Config)?;
-                    Some(
-                        access::AccessControlPlugin::from_xml(
-                            &governance_content,
-                            &permissions_content,
-                        )
-                        .map_err(|_| Error::Config)?,
-                    )
-                }
-                _ => None,
-            }
-        };
+        let access_control = Self::load_access_control(&config)?;
 
         // Initialize cryptographic plugin if encryption is enabled
         #[cfg(feature = "security")]
@@ -147,13 +166,46 @@ impl SecurityPluginSuite {
             None
         };
 
+        // Topic data protection: custom plugin if registered, else keys from
+        // the shared master key if configured, random otherwise
+        #[cfg(feature = "security")]
+        let topic_crypto: Option<Arc<dyn CryptographicPlugin>> =
+            match (&config.master_key, config.enable_encryption) {
+                (_, false) => None,
+                _ if config.cryptographic_plugin.is_some() => config.cryptographic_plugin.clone(),
+                (Some(path), true) => {
+                    let bytes = std::fs::read(path)
+                        .map_err(|e| Error::IoError(e).with_context(file_context(path)))?;
+                    let master_key: [u8; 32] = bytes.as_slice().try_into().map_err(|_| {
+                        log::warn!(
+                            "[security] master key {} must be 32 bytes, got {}",
+                            path.display(),
+                            bytes.len()
+                        );
+                        Error::config(format!("master key must be 32 bytes, got {}", bytes.len()))
+                            .with_context(file_context(path))
+                    })?;
+                    Some(Arc::new(crypto::TopicKeys::new(master_key)))
+                }
+                (None, true) => {
+                    // Remote participants cannot derive these keys: every
+                    // protected sample they receive fails verification
+                    log::warn!(
+                        "[security] encryption enabled without a master key: protected topics \
+                         use a random per-participant key and cannot be read remotely"
+                    );
+                    Some(Arc::new(crypto::TopicKeys::generate()?))
+                }
+            };
+
         // Initialize logging plugin if audit log is enabled
         // Wrapped in Mutex for thread-safe concurrent logging
         #[cfg(feature = "security")]
         let logging = if config.enable_audit_log {
             match &config.audit_log_path {
                 Some(path) => Some(std::sync::Mutex::new(
-                    audit::LoggingPlugin::with_file(path).map_err(|_| Error::Config)?,
+                    audit::LoggingPlugin::with_file(path)
+                        .map_err(|e| Error::from(e).with_context(file_context(path)))?,
                 )),
                 None => Some(std::sync::Mutex::new(audit::LoggingPlugin::new())),
             }
@@ -162,17 +214,117 @@ impl SecurityPluginSuite {
         };
 
         Ok(Self {
-            authentication,
+            authentication: RwLock::new(authentication),
             #[cfg(feature = "security")]
-            access_control,
+            access_control: RwLock::new(access_control),
             #[cfg(feature = "security")]
             cryptographic,
             #[cfg(feature = "security")]
+            topic_crypto,
+            #[cfg(feature = "security")]
             logging,
             config,
         })
     }
 
+    /// Registered authentication plugin, X.509 otherwise
+    fn load_authentication(
+        config: &SecurityConfig,
+    ) -> Result<Arc<dyn authentication::AuthenticationPlugin>, Error> {
+        match &config.authentication_plugin {
+            Some(plugin) => Ok(Arc::clone(plugin)),
+            None => authentication::create_authentication_plugin(config).map(Arc::from),
+        }
+    }
+
+    /// Registered access control plugin, governance/permissions otherwise
+    #[cfg(feature = "security")]
+    fn load_access_control(
+        config: &SecurityConfig,
+    ) -> Result<Option<Arc<dyn AccessControlPlugin>>, Error> {
+        if let Some(plugin) = &config.access_control_plugin {
+            return Ok(Some(Arc::clone(plugin)));
+        }
+        match (&config.governance_xml, &config.permissions_xml) {
+            (Some(governance), Some(permissions)) => {
+                let governance_content = std::fs::read_to_string(governance)
+                    .map_err(|e| Error::IoError(e).with_context(file_context(governance)))?;
+                let permissions_content = std::fs::read_to_string(permissions)
+                    .map_err(|e| Error::IoError(e).with_context(file_context(permissions)))?;
+                let plugin = access::PermissionsAccessControl::from_xml(
+                    &governance_content,
+                    &permissions_content,
+                )
+                .map_err(|e| {
+                    log::warn!("[security] governance/permissions: {}", e);
+                    Error::config(format!("invalid governance/permissions: {}", e))
+                        .with_context(ErrorContext::new().entity("access control"))
+                })?;
+                Ok(Some(Arc::new(plugin)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Re-read the certificates, revocation list, governance and
+    /// permissions files and rebuild the authentication and access control
+    /// plugins.
+    ///
+    /// All documents are validated before anything is replaced: on error
+    /// the previous plugins stay in place. Custom plugins are kept as they
+    /// are (they manage their own documents); with an identity source, the
+    /// X.509 plugin is rebuilt from its current identity. Checks made after the reload
+    /// (new endpoints, remote participants) use the new documents; data
+    /// protection of existing writers and readers is unchanged.
+    ///
+    /// # Errors
+    ///
+    /// `Error::InvalidConfig` or `Error::IoError` if a document cannot be read or
+    /// is invalid.
+    #[cfg(feature = "security")]
+    pub fn reload(&self) -> Result<(), Error> {
+        let authentication = Self::load_authentication(&self.config)?;
+        let identity = authentication.validate_identity()?;
+        if self.config.identity_source.is_some() {
+            log::debug!(
+                "[security] identity {} valid until {}",
+                identity.subject_name,
+                identity.expiration_time
+            );
+        }
+        let access_control = Self::load_access_control(&self.config)?;
+
+        *self.authentication.write() = authentication;
+        *self.access_control.write() = access_control;
+        log::info!("[security] reloaded security documents");
+        Ok(())
+    }
+
+    /// Files polled when `reload_interval` is configured
+    #[cfg(feature = "security")]
+    pub(crate) fn watched_files(&self) -> Vec<std::path::PathBuf> {
+        let config = &self.config;
+        [
+            Some(&config.identity_certificate),
+            Some(&config.private_key),
+            Some(&config.ca_certificates),
+            config.certificate_revocation_list.as_ref(),
+            config.governance_xml.as_ref(),
+            config.permissions_xml.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|path| !path.as_os_str().is_empty())
+        .cloned()
+        .chain(
+            config
+                .identity_source
+                .iter()
+                .flat_map(|source| source.watched_files()),
+        )
+        .collect()
+    }
+
     /// Check if security is enabled
     pub fn is_enabled(&self) -> bool {
         true
@@ -191,13 +343,16 @@ impl SecurityPluginSuite {
     /// Check if access control is enabled
     #[cfg(feature = "security")]
     pub fn is_access_control_enabled(&self) -> bool {
-        self.access_control.is_some()
+        self.access_control.read().is_some()
     }
 
-    /// Get reference to access control plugin
+    /// Get the current access control plugin
+    ///
+    /// The returned plugin is a snapshot: a later [`reload`](Self::reload)
+    /// does not change it.
     #[cfg(feature = "security")]
-    pub fn access_control(&self) -> Option<&access::AccessControlPlugin> {
-        self.access_control.as_ref()
+    pub fn access_control(&self) -> Option<Arc<dyn AccessControlPlugin>> {
+        self.access_control.read().clone()
     }
 
     /// Get reference to cryptographic plugin
@@ -212,6 +367,37 @@ impl SecurityPluginSuite {
         self.cryptographic.as_mut()
     }
 
+    /// Protection of `topic`'s data on `domain_id`
+    ///
+    /// Governance topic rules decide when access control is configured;
+    /// otherwise encryption covers every topic. Always
+    /// [`ProtectionKind::None`](access::ProtectionKind::None) when
+    /// encryption is disabled.
+    #[cfg(feature = "security")]
+    pub fn data_protection(&self, domain_id: u32, topic: &str) -> access::ProtectionKind {
+        if self.topic_crypto.is_none() {
+            return access::ProtectionKind::None;
+        }
+        match &*self.access_control.read() {
+            Some(access_control) => access_control.data_protection(domain_id, topic),
+            None => access::ProtectionKind::Encrypt,
+        }
+    }
+
+    /// Protection of `topic`'s payloads on `domain_id`, `None` if its data
+    /// is not protected
+    #[cfg(feature = "security")]
+    pub fn topic_protection(
+        &self,
+        domain_id: u32,
+        topic: &str,
+    ) -> Result<Option<Box<dyn PayloadProtection>>, SecurityError> {
+        match &self.topic_crypto {
+            Some(crypto) => crypto.topic_protection(topic, self.data_protection(domain_id, topic)),
+            None => Ok(None),
+        }
+    }
+
     /// Get reference to logging plugin (Mutex-wrapped for thread-safety)
     #[cfg(feature = "security")]
     pub fn logging(&self) -> Option<&std::sync::Mutex<audit::LoggingPlugin>> {
@@ -236,9 +422,11 @@ impl SecurityPluginSuite {
         Ok(())
     }
 
-    /// Get reference to authentication plugin
-    pub fn authentication(&self) -> &dyn authentication::AuthenticationPlugin {
-        self.authentication.as_ref()
+    /// Get the current authentication plugin
+    ///
+    /// The returned plugin is a snapshot: a later `reload` does not change it.
+    pub fn authentication(&self) -> Arc<dyn authentication::AuthenticationPlugin> {
+        Arc::clone(&self.authentication.read())
     }
 
     /// Get reference to configuration
@@ -250,18 +438,16 @@ impl SecurityPluginSuite {
 impl std::fmt::Debug for SecurityPluginSuite {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut debug = f.debug_struct("SecurityPluginSuite");
-        debug.field("authentication", &self.authentication);
+        debug.field("authentication", &*self.authentication.read());
 
         #[cfg(feature = "security")]
         {
-            debug.field(
-                "access_control",
-                &self.access_control.as_ref().map(|_| "AccessControlPlugin"),
-            );
+            debug.field("access_control", &*self.access_control.read());
             debug.field(
                 "cryptographic",
                 &self.cryptographic.as_ref().map(|_| "CryptoPlugin"),
             );
+            debug.field("topic_crypto", &self.topic_crypto);
             debug.field(
                 "logging",
                 &self.logging.as_ref().map(|_| "Mutex<LoggingPlugin>"),
@@ -319,11 +505,19 @@ impl std::fmt::Display for SecurityError {
 impl std::error::Error for SecurityError {}
 
 impl From<SecurityError> for Error {
-    fn from(_err: SecurityError) -> Self {
-        Error::Config
+    fn from(err: SecurityError) -> Self {
+        Error::config(err.to_string()).with_context(ErrorContext::new().entity("security"))
     }
 }
 
+/// Context of an error on a security file.
+#[cfg(feature = "security")]
+fn file_context(path: &std::path::Path) -> ErrorContext {
+    ErrorContext::new()
+        .entity("security")
+        .path(path.display().to_string())
+}
+
 // ============================================================================
 // Security Validator Adapter (DDS Security v1.1 Sec.8.4)
 // ============================================================================
@@ -374,9 +568,8 @@ mod validator_adapter {
             use super::authentication::HandshakeRequestToken;
 
             // Validate local identity first (ensures our own cert is valid)
-            let local_identity = self
-                .security_suite
-                .authentication()
+            let authentication = self.security_suite.authentication();
+            let local_identity = authentication
                 .validate_identity()
                 .map_err(|e| format!("Local identity validation failed: {}", e))?;
 
@@ -391,11 +584,8 @@ mod validator_adapter {
             // 1. Certificate chain (signed by trusted CA)
             // 2. Certificate expiration
             // 3. Signature verification (if present)
-            match self
-                .security_suite
-                .authentication()
-                .process_handshake(&local_identity, &remote_request)
-            {
+            // 4. Revocation (configured revocation list)
+            match authentication.process_handshake(&local_identity, &remote_request) {
                 Ok(_) => {
                     log::debug!(
                         "[security] Authenticated participant {:?}",
@@ -409,6 +599,14 @@ mod validator_adapter {
                         participant_guid,
                         e
                     );
+                    if e == super::SecurityError::CertificateRevoked {
+                        let event = super::audit::SecurityEvent::Authentication {
+                            participant_guid: participant_guid.as_bytes(),
+                            outcome: super::audit::AuthenticationOutcome::Revoked,
+                            timestamp: crate::telemetry::metrics::current_time_ns(),
+                        };
+                        let _ = self.security_suite.log_security_event(&event);
+                    }
                     Err(format!("{}", e))
                 }
             }
@@ -439,6 +637,186 @@ mod tests {
     fn test_security_error_into_api_error() {
         let sec_err = SecurityError::CertificateExpired;
         let api_err: Error = sec_err.into();
-        assert!(matches!(api_err, Error::Config));
+        assert!(matches!(api_err, Error::InvalidConfig { .. }));
+        assert_eq!(
+            api_err.to_string(),
+            "Configuration error: Certificate expired (security)"
+        );
+    }
+
+    #[cfg(feature = "security")]
+    mod custom_plugins {
+        use super::super::authentication::{
+            HandshakeReplyToken, HandshakeRequestToken, IdentityHandle,
+        };
+        use super::*;
+        use crate::core::discovery::multicast::SecurityValidator;
+        use crate::core::discovery::GUID;
+        use access::ProtectionKind;
+
+        /// Trusts remote identity tokens equal to `b"trusted"`
+        #[derive(Debug)]
+        struct TokenAuthentication;
+
+        impl AuthenticationPlugin for TokenAuthentication {
+            fn validate_identity(&self) -> Result<IdentityHandle, SecurityError> {
+                Ok(IdentityHandle::new(
+                    GUID::zero(),
+                    "CN=hsm".to_string(),
+                    u64::MAX,
+                    Vec::new(),
+                ))
+            }
+
+            fn begin_handshake(
+                &self,
+                _local_identity: &IdentityHandle,
+                _remote_guid: GUID,
+            ) -> Result<HandshakeRequestToken, SecurityError> {
+                Ok(HandshakeRequestToken::new(
+                    "test".to_string(),
+                    b"trusted".to_vec(),
+                ))
+            }
+
+            fn process_handshake(
+                &self,
+                _local_identity: &IdentityHandle,
+                request: &HandshakeRequestToken,
+            ) -> Result<Option<HandshakeReplyToken>, SecurityError> {
+                if request.identity_certificate == b"trusted" {
+                    Ok(None)
+                } else {
+                    Err(SecurityError::AuthenticationFailed("untrusted".to_string()))
+                }
+            }
+        }
+
+        /// Denies writers on `secret`, signs `signed`
+        #[derive(Debug)]
+        struct TopicAccessControl;
+
+        impl AccessControlPlugin for TopicAccessControl {
+            fn check_create_participant(&self, _domain_id: u32) -> Result<(), SecurityError> {
+                Ok(())
+            }
+
+            fn check_create_writer(
+                &self,
+                topic: &str,
+                _partition: Option<&str>,
+            ) -> Result<(), SecurityError> {
+                if topic == "secret" {
+                    return Err(SecurityError::PermissionsDenied(topic.to_string()));
+                }
+                Ok(())
+            }
+
+            fn check_create_reader(
+                &self,
+                _topic: &str,
+                _partition: Option<&str>,
+            ) -> Result<(), SecurityError> {
+                Ok(())
+            }
+
+            fn check_remote_writer(&self, _topic: &str) -> Result<(), SecurityError> {
+                Ok(())
+            }
+
+            fn check_remote_reader(&self, _topic: &str) -> Result<(), SecurityError> {
+                Ok(())
+            }
+
+            fn data_protection(&self, _domain_id: u32, topic: &str) -> ProtectionKind {
+                if topic == "signed" {
+                    ProtectionKind::Sign
+                } else {
+                    ProtectionKind::None
+                }
+            }
+        }
+
+        /// Appends a marker byte, standing in for an external crypto module
+        #[derive(Debug)]
+        struct MarkerCrypto;
+
+        #[derive(Debug)]
+        struct MarkerProtection(ProtectionKind);
+
+        impl CryptographicPlugin for MarkerCrypto {
+            fn topic_protection(
+                &self,
+                _topic: &str,
+                kind: ProtectionKind,
+            ) -> Result<Option<Box<dyn PayloadProtection>>, SecurityError> {
+                Ok((kind != ProtectionKind::None)
+                    .then(|| Box::new(MarkerProtection(kind)) as Box<dyn PayloadProtection>))
+            }
+        }
+
+        impl PayloadProtection for MarkerProtection {
+            fn kind(&self) -> ProtectionKind {
+                self.0
+            }
+
+            fn protect(&self, payload: &[u8]) -> Result<Vec<u8>, SecurityError> {
+                let mut out = payload.to_vec();
+                out.push(0xA5);
+                Ok(out)
+            }
+
+            fn unprotect(&self, data: &[u8]) -> Result<Vec<u8>, SecurityError> {
+                match data.split_last() {
+                    Some((0xA5, payload)) => Ok(payload.to_vec()),
+                    _ => Err(SecurityError::CryptoFailed("missing marker".to_string())),
+                }
+            }
+        }
+
+        fn suite() -> SecurityPluginSuite {
+            let config = SecurityConfig::builder()
+                .authentication_plugin(Arc::new(TokenAuthentication))
+                .access_control_plugin(Arc::new(TopicAccessControl))
+                .cryptographic_plugin(Arc::new(MarkerCrypto))
+                .build()
+                .expect("no identity files needed with a custom authentication plugin");
+            SecurityPluginSuite::new(config).expect("suite")
+        }
+
+        #[test]
+        fn test_custom_authentication_validates_remote_tokens() {
+            let adapter = SecurityValidatorAdapter::new(Arc::new(suite()));
+            assert!(adapter.validate_identity(GUID::zero(), b"trusted").is_ok());
+            assert!(adapter.validate_identity(GUID::zero(), b"forged").is_err());
+        }
+
+        #[test]
+        fn test_custom_access_control_and_crypto() {
+            let suite = suite();
+            let access_control = suite.access_control().expect("custom access control");
+            assert!(access_control.check_create_writer("secret", None).is_err());
+            assert!(access_control.check_create_writer("public", None).is_ok());
+
+            assert!(suite.topic_protection(0, "public").unwrap().is_none());
+            let signed = suite.topic_protection(0, "signed").unwrap().unwrap();
+            assert_eq!(signed.kind(), ProtectionKind::Sign);
+            let wire = signed.protect(b"cmd").unwrap();
+            assert_eq!(signed.unprotect(&wire).unwrap(), b"cmd");
+            assert!(signed.unprotect(b"cmd").is_err());
+        }
+
+        #[test]
+        fn test_reload_keeps_custom_plugins() {
+            let suite = suite();
+            suite.reload().expect("reload");
+            assert!(suite.watched_files().is_empty());
+            assert!(suite
+                .access_control()
+                .unwrap()
+                .check_create_writer("secret", None)
+                .is_err());
+            assert!(suite.authentication().validate_identity().is_ok());
+        }
     }
 }
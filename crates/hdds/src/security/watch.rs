@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Change detection for security documents.
+//!
+//! Background thread polling the modification time and size of the
+//! security files (CA certificates, revocation list, governance,
+//! permissions) and calling a handler when any of them changes, so the
+//! plugins can be rebuilt without restarting the participant.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime};
+
+/// Stop flag granularity, so dropping the watcher does not wait a full interval
+const STOP_CHECK: Duration = Duration::from_millis(100);
+
+/// Modification time and size of a file, `None` while it is missing
+type Stamp = Option<(SystemTime, u64)>;
+
+fn stamp(path: &Path) -> Stamp {
+    let metadata = std::fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// Polls security documents and reports changes
+///
+/// The thread stops when the watcher is dropped.
+pub(crate) struct SecurityWatcher {
+    /// Stop flag for graceful shutdown
+    stop_flag: Arc<AtomicBool>,
+    /// Background thread handle
+    handle: Option<JoinHandle<()>>,
+}
+
+impl SecurityWatcher {
+    /// Poll `files` every `interval`, calling `on_change` after a change.
+    ///
+    /// A file replaced while it is being written may be reported twice;
+    /// the handler must tolerate (and keep its state on) invalid content.
+    pub(crate) fn start(
+        files: Vec<PathBuf>,
+        interval: Duration,
+        on_change: Box<dyn Fn() + Send>,
+    ) -> std::io::Result<Self> {
+        let stop_flag = Arc::new(AtomicBool::new(false));
+        let stop_flag_clone = Arc::clone(&stop_flag);
+
+        let handle = thread::Builder::new()
+            .name("hdds-security-watch".to_string())
+            .spawn(move || {
+                Self::run_loop(&files, interval, &stop_flag_clone, on_change.as_ref())
+            })?;
+
+        Ok(Self {
+            stop_flag,
+            handle: Some(handle),
+        })
+    }
+
+    fn run_loop(
+        files: &[PathBuf],
+        interval: Duration,
+        stop_flag: &AtomicBool,
+        on_change: &dyn Fn(),
+    ) {
+        let mut stamps: Vec<Stamp> = files.iter().map(|path| stamp(path)).collect();
+        loop {
+            let mut waited = Duration::ZERO;
+            while waited < interval {
+                if stop_flag.load(Ordering::Relaxed) {
+                    return;
+                }
+                let step = STOP_CHECK.min(interval - waited);
+                thread::sleep(step);
+                waited += step;
+            }
+
+            let current: Vec<Stamp> = files.iter().map(|path| stamp(path)).collect();
+            if current != stamps {
+                for (path, (old, new)) in files.iter().zip(stamps.iter().zip(&current)) {
+                    if old != new {
+                        log::info!("[security] {} changed", path.display());
+                    }
+                }
+                stamps = current;
+                on_change();
+            }
+        }
+    }
+}
+
+impl Drop for SecurityWatcher {
+    fn drop(&mut self) {
+        self.stop_flag.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    #[test]
+    fn test_reports_changed_file() {
+        let mut file = tempfile::NamedTempFile::new().expect("temp file");
+        let changes = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&changes);
+        let watcher = SecurityWatcher::start(
+            vec![file.path().to_path_buf()],
+            Duration::from_millis(20),
+            Box::new(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }),
+        )
+        .expect("watcher");
+
+        thread::sleep(Duration::from_millis(100));
+        assert_eq!(changes.load(Ordering::SeqCst), 0);
+
+        std::io::Write::write_all(&mut file, b"<permissions/>").expect("write");
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while changes.load(Ordering::SeqCst) == 0 {
+            assert!(std::time::Instant::now() < deadline, "change not reported");
+            thread::sleep(Duration::from_millis(10));
+        }
+        drop(watcher);
+        assert_eq!(changes.load(Ordering::SeqCst), 1);
+    }
+}
@@ -3,12 +3,10 @@
 
 //! Live telemetry capture server for HDDS Viewer integration.
 //!
-//! Provides TCP server that streams telemetry frames to connected clients
-//! and admin API integration for metrics extraction.
+//! Provides TCP server that streams telemetry frames to connected clients.
 
 use super::export::encode_frame;
-use super::metrics::{Frame, MetricsCollector, TAG_LATENCY_P50, TAG_LATENCY_P99};
-use crate::admin::snapshot::MetricsSnapshot;
+use super::metrics::Frame;
 use crate::core::string_utils::format_string;
 use socket2::{Domain, Protocol, Socket, Type};
 use std::io;
@@ -154,69 +152,6 @@ fn accept_loop(
     }
 }
 
-// ===== Admin API Integration =====
-
-/// Parse a telemetry frame into (sent, received, dropped, p50, p99) tuple.
-pub fn parse_frame_fields(frame: &Frame) -> (u64, u64, u64, u64, u64) {
-    let mut sent = 0;
-    let mut recv = 0;
-    let mut dropped = 0;
-    let mut p50 = 0;
-    let mut p99 = 0;
-
-    for field in &frame.fields {
-        match field.tag {
-            10 => sent = field.value_u64,
-            11 => recv = field.value_u64,
-            12 => dropped = field.value_u64,
-            TAG_LATENCY_P50 => p50 = field.value_u64,
-            TAG_LATENCY_P99 => p99 = field.value_u64,
-            unknown_tag => {
-                // Log unknown telemetry tags so we can detect new metrics
-                log::debug!(
-                    "[telemetry] [!]  Unknown field tag: {} (value={})",
-                    unknown_tag,
-                    field.value_u64
-                );
-            }
-        }
-    }
-
-    (sent, recv, dropped, p50, p99)
-}
-
-/// Extract metrics from a shared collector, recovering from poisoned locks.
-pub fn extract_metrics_from_collector(
-    epoch: u64,
-    metrics: &Arc<Mutex<Option<Arc<MetricsCollector>>>>,
-) -> MetricsSnapshot {
-    let metrics_guard = match metrics.lock() {
-        Ok(lock) => lock,
-        Err(e) => {
-            log::debug!("[admin] metrics lock poisoned, recovering");
-            e.into_inner()
-        }
-    };
-
-    if let Some(collector) = &*metrics_guard {
-        let frame = collector.snapshot();
-        let (sent, recv, dropped, p50, p99) = parse_frame_fields(&frame);
-
-        MetricsSnapshot {
-            epoch,
-            messages_sent: sent,
-            messages_received: recv,
-            messages_dropped: dropped,
-            latency_min_ns: 0,
-            latency_p50_ns: p50,
-            latency_p99_ns: p99,
-            latency_max_ns: 0,
-        }
-    } else {
-        MetricsSnapshot::empty(epoch)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,286 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Per-endpoint traffic counters for the admin API.
+//!
+//! Every writer and reader keeps an [`EndpointMetrics`]: samples and bytes
+//! sent or received, samples dropped because the history was full, and for
+//! reliable endpoints the retransmissions and NACKs (from their
+//! [`ReliableMetrics`]). Reliable writers also record the time from writing
+//! a sample to each reader acknowledging it in a fixed-bucket histogram.
+//!
+//! Endpoints attached to a participant register themselves in a process-wide
+//! table read by [`snapshot_endpoints`], for as long as they hold the
+//! returned [`MetricsRegistration`].
+
+use crate::admin::snapshot::{EndpointMetricsView, LatencyHistogram};
+use crate::core::discovery::GUID;
+use crate::reliability::ReliableMetrics;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Instant;
+
+/// Upper bounds of the ACK round-trip histogram buckets, in microseconds.
+///
+/// A last, unbounded bucket counts slower acknowledgments.
+pub const ACK_RTT_BUCKETS_US: [u64; 12] = [
+    100, 250, 500, 1_000, 2_500, 5_000, 10_000, 25_000, 50_000, 100_000, 250_000, 1_000_000,
+];
+
+/// Write times kept to match acknowledgments against.
+const SEND_TIMES_CAPACITY: usize = 1024;
+
+/// Whether an endpoint sends or receives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndpointRole {
+    /// DataWriter.
+    Writer,
+    /// DataReader.
+    Reader,
+}
+
+/// Latency histogram with [`ACK_RTT_BUCKETS_US`] buckets.
+#[derive(Debug, Default)]
+struct RttHistogram {
+    buckets: [AtomicU64; ACK_RTT_BUCKETS_US.len() + 1],
+    sum_ns: AtomicU64,
+}
+
+impl RttHistogram {
+    fn record(&self, rtt_ns: u64) {
+        let rtt_us = rtt_ns / 1_000;
+        let bucket = ACK_RTT_BUCKETS_US
+            .iter()
+            .position(|&bound| rtt_us <= bound)
+            .unwrap_or(ACK_RTT_BUCKETS_US.len());
+        self.buckets[bucket].fetch_add(1, Ordering::Relaxed);
+        self.sum_ns.fetch_add(rtt_ns, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> LatencyHistogram {
+        let counts: Vec<u64> = self
+            .buckets
+            .iter()
+            .map(|b| b.load(Ordering::Relaxed))
+            .collect();
+        LatencyHistogram {
+            bounds_us: ACK_RTT_BUCKETS_US.to_vec(),
+            count: counts.iter().sum(),
+            counts,
+            sum_ns: self.sum_ns.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Traffic counters of one writer or reader.
+#[derive(Debug)]
+pub struct EndpointMetrics {
+    role: EndpointRole,
+    topic: String,
+    guid: OnceLock<GUID>,
+    samples: AtomicU64,
+    bytes: AtomicU64,
+    history_drops: AtomicU64,
+    /// Retransmissions and NACKs of reliable endpoints
+    reliable: Option<Arc<ReliableMetrics>>,
+    /// Write time of the latest samples, by sequence number (writers)
+    send_times: Mutex<VecDeque<(u64, Instant)>>,
+    ack_rtt: RttHistogram,
+}
+
+impl EndpointMetrics {
+    /// Counters for an endpoint on `topic`.
+    pub fn new(role: EndpointRole, topic: &str, reliable: Option<Arc<ReliableMetrics>>) -> Self {
+        Self {
+            role,
+            topic: topic.to_string(),
+            guid: OnceLock::new(),
+            samples: AtomicU64::new(0),
+            bytes: AtomicU64::new(0),
+            history_drops: AtomicU64::new(0),
+            reliable,
+            send_times: Mutex::new(VecDeque::new()),
+            ack_rtt: RttHistogram::default(),
+        }
+    }
+
+    /// List the endpoint in [`snapshot_endpoints`] under `guid` until the
+    /// returned registration is dropped.
+    #[must_use]
+    pub fn register(self: &Arc<Self>, guid: GUID) -> MetricsRegistration {
+        let _ = self.guid.set(guid);
+        registry().push(Arc::clone(self));
+        MetricsRegistration {
+            metrics: Arc::clone(self),
+        }
+    }
+
+    /// Whether the endpoint is a writer or a reader.
+    pub fn role(&self) -> EndpointRole {
+        self.role
+    }
+
+    /// Count a sample of `bytes` bytes sent or received.
+    pub fn record_sample(&self, bytes: usize) {
+        self.samples.fetch_add(1, Ordering::Relaxed);
+        self.bytes.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    /// Count a sample dropped because the history was full.
+    pub fn record_history_drop(&self) {
+        self.history_drops.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Remember when sample `seq` was written, for [`Self::record_ack`].
+    pub fn record_sent(&self, seq: u64) {
+        let mut times = self.send_times.lock().unwrap_or_else(|e| e.into_inner());
+        if times.len() == SEND_TIMES_CAPACITY {
+            times.pop_front();
+        }
+        times.push_back((seq, Instant::now()));
+    }
+
+    /// A reader acknowledged every sample up to `seq`: record the round
+    /// trip of `seq` if its write time is still known.
+    pub fn record_ack(&self, seq: u64) {
+        let sent = {
+            let times = self.send_times.lock().unwrap_or_else(|e| e.into_inner());
+            times
+                .binary_search_by_key(&seq, |(s, _)| *s)
+                .ok()
+                .map(|index| times[index].1)
+        };
+        if let Some(sent) = sent {
+            let rtt = u64::try_from(sent.elapsed().as_nanos()).unwrap_or(u64::MAX);
+            self.ack_rtt.record(rtt);
+        }
+    }
+
+    /// Samples sent (writer) or received (reader).
+    pub fn samples(&self) -> u64 {
+        self.samples.load(Ordering::Relaxed)
+    }
+
+    /// Payload bytes sent (writer) or received (reader).
+    pub fn bytes(&self) -> u64 {
+        self.bytes.load(Ordering::Relaxed)
+    }
+
+    /// Samples dropped because the history was full.
+    pub fn history_drops(&self) -> u64 {
+        self.history_drops.load(Ordering::Relaxed)
+    }
+
+    /// Point-in-time view of the counters.
+    pub fn snapshot(&self) -> EndpointMetricsView {
+        let (retransmissions, nacks) = match (&self.reliable, self.role) {
+            (Some(m), EndpointRole::Writer) => (m.retransmit_sent(), m.nacks_received()),
+            (Some(m), EndpointRole::Reader) => (m.retransmit_received(), m.nacks_sent()),
+            (None, _) => (0, 0),
+        };
+        EndpointMetricsView {
+            guid: self.guid.get().map(ToString::to_string).unwrap_or_default(),
+            topic_name: self.topic.clone(),
+            samples: self.samples(),
+            bytes: self.bytes(),
+            retransmissions,
+            nacks,
+            history_drops: self.history_drops(),
+            ack_rtt: self.ack_rtt.snapshot(),
+        }
+    }
+}
+
+/// Listing of an endpoint in [`snapshot_endpoints`] (removed on drop).
+#[derive(Debug)]
+pub struct MetricsRegistration {
+    metrics: Arc<EndpointMetrics>,
+}
+
+impl Drop for MetricsRegistration {
+    fn drop(&mut self) {
+        registry().retain(|entry| !Arc::ptr_eq(entry, &self.metrics));
+    }
+}
+
+fn registry() -> std::sync::MutexGuard<'static, Vec<Arc<EndpointMetrics>>> {
+    static REGISTRY: OnceLock<Mutex<Vec<Arc<EndpointMetrics>>>> = OnceLock::new();
+    REGISTRY
+        .get_or_init(|| Mutex::new(Vec::new()))
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+}
+
+/// Counters of the live writers and readers of the process, as
+/// `(writers, readers)`.
+pub fn snapshot_endpoints() -> (Vec<EndpointMetricsView>, Vec<EndpointMetricsView>) {
+    let live: Vec<Arc<EndpointMetrics>> = registry().clone();
+    let (mut writers, mut readers) = (Vec::new(), Vec::new());
+    for endpoint in live {
+        match endpoint.role {
+            EndpointRole::Writer => writers.push(endpoint.snapshot()),
+            EndpointRole::Reader => readers.push(endpoint.snapshot()),
+        }
+    }
+    (writers, readers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint_metrics_counts_and_ack_rtt() {
+        let reliable = Arc::new(ReliableMetrics::new());
+        let metrics = Arc::new(EndpointMetrics::new(
+            EndpointRole::Writer,
+            "telemetry/endpoints",
+            Some(Arc::clone(&reliable)),
+        ));
+        let guid = GUID::new([7; 12], [0, 0, 1, 0x03]);
+        let registration = metrics.register(guid);
+
+        metrics.record_sample(100);
+        metrics.record_sample(20);
+        metrics.record_history_drop();
+        reliable.increment_retransmit_sent(2);
+        reliable.increment_nacks_received(1);
+        for seq in 1..=3 {
+            metrics.record_sent(seq);
+        }
+        metrics.record_ack(2);
+        metrics.record_ack(9); // never written: no sample
+
+        let (writers, _) = snapshot_endpoints();
+        let view = writers
+            .into_iter()
+            .find(|w| w.guid == guid.to_string())
+            .expect("registered");
+        assert_eq!(view.topic_name, "telemetry/endpoints");
+        assert_eq!((view.samples, view.bytes), (2, 120));
+        assert_eq!((view.retransmissions, view.nacks), (2, 1));
+        assert_eq!(view.history_drops, 1);
+        assert_eq!(view.ack_rtt.count, 1);
+        assert_eq!(view.ack_rtt.counts.len(), ACK_RTT_BUCKETS_US.len() + 1);
+
+        drop(registration);
+        let (writers, _) = snapshot_endpoints();
+        assert!(writers.iter().all(|w| w.guid != guid.to_string()));
+    }
+
+    #[test]
+    fn test_rtt_histogram_buckets() {
+        let histogram = RttHistogram::default();
+        histogram.record(50_000); // 50 us
+        histogram.record(100_000); // 100 us, on the bound
+        histogram.record(3_000_000); // 3 ms
+        histogram.record(5_000_000_000); // 5 s
+        let view = histogram.snapshot();
+        assert_eq!(view.counts[0], 2);
+        assert_eq!(view.counts[5], 1);
+        assert_eq!(view.counts[ACK_RTT_BUCKETS_US.len()], 1);
+        assert_eq!(view.count, 4);
+        assert_eq!(view.sum_ns, 5_003_150_000);
+    }
+}
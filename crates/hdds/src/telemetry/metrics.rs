@@ -60,6 +60,7 @@ pub const TAG_MERGE_FULL_COUNT: u16 = 40;
 pub const TAG_WOULD_BLOCK_COUNT: u16 = 41;
 pub const TAG_CACHE_INSERT_ERRORS: u16 = 42;
 pub const TAG_TRANSPORT_ERRORS: u16 = 43;
+pub const TAG_CHECKSUM_FAILURES: u16 = 44;
 
 /// Metrics collector with atomic counters and latency histogram
 ///
@@ -79,6 +80,7 @@ pub struct MetricsCollector {
     would_block: AtomicU64,
     cache_insert_errors: AtomicU64,
     transport_errors: AtomicU64,
+    checksum_failures: AtomicU64,
 
     /// Latency histogram (ring buffer of samples)
     latency_samples: Mutex<VecDeque<u64>>,
@@ -105,6 +107,7 @@ impl MetricsCollector {
             would_block: AtomicU64::new(0),
             cache_insert_errors: AtomicU64::new(0),
             transport_errors: AtomicU64::new(0),
+            checksum_failures: AtomicU64::new(0),
             latency_samples: Mutex::new(VecDeque::with_capacity(max_samples)),
             max_samples,
         }
@@ -149,6 +152,11 @@ impl MetricsCollector {
         self.transport_errors.fetch_add(count, Ordering::Relaxed);
     }
 
+    /// Increment checksum failures counter (samples dropped on receive)
+    pub fn increment_checksum_failures(&self, count: u64) {
+        self.checksum_failures.fetch_add(count, Ordering::Relaxed);
+    }
+
     /// Add latency sample
     ///
     /// # Arguments
@@ -257,6 +265,12 @@ fn snapshot_counter_fields(frame: &mut Frame, collector: &MetricsCollector) {
         dtype: DType::U64,
         value_u64: collector.transport_errors.load(Ordering::Relaxed),
     });
+
+    frame.push_field(Field {
+        tag: TAG_CHECKSUM_FAILURES,
+        dtype: DType::U64,
+        value_u64: collector.checksum_failures.load(Ordering::Relaxed),
+    });
 }
 
 /// Compute and add latency percentiles to Frame
@@ -7,8 +7,10 @@
 //!
 //! # Modules
 //! - `metrics`: Thread-safe metrics collection with atomic counters and latency histograms
-//! - `export`: Binary frame encoding/decoding (HDMX format)
-//! - `capture`: Live telemetry streaming server for HDDS Viewer
+//! - `snapshot`: Metrics snapshots for the admin API
+//! - `export`: Binary frame encoding/decoding (HDMX format, feature `telemetry`)
+//! - `capture`: Live telemetry streaming server for HDDS Viewer (feature `telemetry`)
+//! - `alloc`: Per-subsystem allocation tracking (feature `alloc-tracking`)
 //!
 //! # Usage
 //! ```rust,no_run
@@ -30,20 +32,64 @@
 //! let frame = metrics.snapshot();
 //! ```
 
+/// Per-subsystem allocation tracking via a tagging global allocator.
+#[cfg(feature = "alloc-tracking")]
+pub mod alloc;
 /// Live telemetry streaming and capture server.
+#[cfg(feature = "telemetry")]
 pub mod capture;
+/// Per-writer and per-reader traffic counters.
+pub mod endpoints;
 /// Binary telemetry frame encoding/decoding (HDMX format).
+#[cfg(feature = "telemetry")]
 pub mod export;
 /// Thread-safe metrics collection with atomic counters and latency histograms.
 pub mod metrics;
+/// Metrics snapshots for the admin API.
+pub mod snapshot;
 
-pub use capture::{extract_metrics_from_collector, parse_frame_fields, Exporter};
+#[cfg(feature = "telemetry")]
+pub use capture::Exporter;
+pub use endpoints::{snapshot_endpoints, EndpointMetrics, EndpointRole, MetricsRegistration};
+#[cfg(feature = "telemetry")]
 pub use export::{decode_frame, encode_frame, MAGIC, VERSION};
 pub use metrics::{Field, Frame, MetricsCollector};
+pub use snapshot::{extract_metrics_from_collector, parse_frame_fields};
 
 use std::sync::{Arc, OnceLock};
 
+/// Charge allocations made until the end of the enclosing block to a subsystem.
+///
+/// Takes a `telemetry::alloc::Subsystem` variant name. Only active with the
+/// `alloc-tracking` feature; compiles to nothing otherwise.
+///
+/// # Example
+/// ```ignore
+/// fn run_loop() {
+///     crate::alloc_scope!(Discovery);
+///     // ...
+/// }
+/// ```
+#[macro_export]
+#[doc(hidden)]
+#[cfg(feature = "alloc-tracking")]
+macro_rules! alloc_scope {
+    ($subsystem:ident) => {
+        let _alloc_scope =
+            $crate::telemetry::alloc::enter($crate::telemetry::alloc::Subsystem::$subsystem);
+    };
+}
+
+/// No-op allocation scope (when alloc-tracking feature disabled).
+#[macro_export]
+#[doc(hidden)]
+#[cfg(not(feature = "alloc-tracking"))]
+macro_rules! alloc_scope {
+    ($subsystem:ident) => {};
+}
+
 static GLOBAL_METRICS: OnceLock<Arc<MetricsCollector>> = OnceLock::new();
+#[cfg(feature = "telemetry")]
 static GLOBAL_EXPORTER: OnceLock<Arc<Exporter>> = OnceLock::new();
 
 /// Initialize global metrics collector
@@ -64,6 +110,7 @@ pub fn get_metrics_opt() -> Option<Arc<MetricsCollector>> {
 }
 
 /// Initialize global telemetry exporter
+#[cfg(feature = "telemetry")]
 pub fn init_exporter(bind_addr: &str, port: u16) -> std::io::Result<Arc<Exporter>> {
     log::debug!("[hdds] telemetry::init_exporter({bind_addr}:{port}) starting");
     let exporter = Arc::new(Exporter::start(bind_addr, port)?);
@@ -73,6 +120,7 @@ pub fn init_exporter(bind_addr: &str, port: u16) -> std::io::Result<Arc<Exporter
 }
 
 /// Get global exporter (returns None if not initialized)
+#[cfg(feature = "telemetry")]
 pub fn get_exporter() -> Option<Arc<Exporter>> {
     GLOBAL_EXPORTER.get().cloned()
 }
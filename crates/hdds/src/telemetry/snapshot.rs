@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Admin API integration: metrics snapshots from telemetry frames.
+
+use super::metrics::{Frame, MetricsCollector, TAG_LATENCY_P50, TAG_LATENCY_P99};
+use crate::admin::snapshot::MetricsSnapshot;
+use std::sync::{Arc, Mutex};
+
+/// Parse a telemetry frame into (sent, received, dropped, p50, p99) tuple.
+pub fn parse_frame_fields(frame: &Frame) -> (u64, u64, u64, u64, u64) {
+    let mut sent = 0;
+    let mut recv = 0;
+    let mut dropped = 0;
+    let mut p50 = 0;
+    let mut p99 = 0;
+
+    for field in &frame.fields {
+        match field.tag {
+            10 => sent = field.value_u64,
+            11 => recv = field.value_u64,
+            12 => dropped = field.value_u64,
+            TAG_LATENCY_P50 => p50 = field.value_u64,
+            TAG_LATENCY_P99 => p99 = field.value_u64,
+            unknown_tag => {
+                // Log unknown telemetry tags so we can detect new metrics
+                log::debug!(
+                    "[telemetry] [!]  Unknown field tag: {} (value={})",
+                    unknown_tag,
+                    field.value_u64
+                );
+            }
+        }
+    }
+
+    (sent, recv, dropped, p50, p99)
+}
+
+/// Extract metrics from a shared collector, recovering from poisoned locks.
+///
+/// Per-endpoint counters come from [`super::endpoints::snapshot_endpoints`]
+/// and are filled in with or without a collector.
+pub fn extract_metrics_from_collector(
+    epoch: u64,
+    metrics: &Arc<Mutex<Option<Arc<MetricsCollector>>>>,
+) -> MetricsSnapshot {
+    let metrics_guard = match metrics.lock() {
+        Ok(lock) => lock,
+        Err(e) => {
+            log::debug!("[admin] metrics lock poisoned, recovering");
+            e.into_inner()
+        }
+    };
+
+    let mut snapshot = if let Some(collector) = &*metrics_guard {
+        let frame = collector.snapshot();
+        let (sent, recv, dropped, p50, p99) = parse_frame_fields(&frame);
+
+        MetricsSnapshot {
+            epoch,
+            messages_sent: sent,
+            messages_received: recv,
+            messages_dropped: dropped,
+            latency_min_ns: 0,
+            latency_p50_ns: p50,
+            latency_p99_ns: p99,
+            latency_max_ns: 0,
+            writers: Vec::new(),
+            readers: Vec::new(),
+        }
+    } else {
+        MetricsSnapshot::empty(epoch)
+    };
+    drop(metrics_guard);
+    (snapshot.writers, snapshot.readers) = super::endpoints::snapshot_endpoints();
+    snapshot
+}
@@ -44,7 +44,9 @@
 //!
 //! # Environment Variables
 //!
-//! - `HDDS_INTERFACE_ALLOW` - Comma-separated list of interface names or CIDRs
+//! - `HDDS_INTERFACE` / `HDDS_INTERFACE_ALLOW` - Comma-separated interface names
+//!   (`*` wildcards accepted, e.g. `eth*`) or CIDRs to use
+//! - `HDDS_INTERFACE_DENY` - Comma-separated interface names or CIDRs to skip
 //! - `HDDS_SOURCE_ALLOW` - Comma-separated list of allowed source CIDRs
 //! - `HDDS_SOURCE_DENY` - Comma-separated list of denied source CIDRs
 
@@ -78,7 +80,8 @@ impl NetworkFilter {
     /// Create from environment variables.
     ///
     /// Reads:
-    /// - `HDDS_INTERFACE_ALLOW` - Interface names or CIDRs (comma-separated)
+    /// - `HDDS_INTERFACE` / `HDDS_INTERFACE_ALLOW` - Interface names or CIDRs (comma-separated)
+    /// - `HDDS_INTERFACE_DENY` - Excluded interface names or CIDRs (comma-separated)
     /// - `HDDS_SOURCE_ALLOW` - Allowed source CIDRs (comma-separated)
     /// - `HDDS_SOURCE_DENY` - Denied source CIDRs (comma-separated)
     #[must_use]
@@ -102,6 +105,7 @@ impl NetworkFilter {
 #[derive(Debug, Default)]
 pub struct NetworkFilterBuilder {
     interfaces: Vec<InterfaceMatcher>,
+    interface_deny: Vec<InterfaceMatcher>,
     source_allow: Vec<Ipv4Network>,
     source_deny: Vec<Ipv4Network>,
 }
@@ -130,6 +134,13 @@ impl NetworkFilterBuilder {
         self
     }
 
+    /// Exclude an interface by name pattern or CIDR (e.g., "docker*", "172.17.0.0/16").
+    #[must_use]
+    pub fn deny_interface(mut self, pattern: &str) -> Self {
+        self.interface_deny.push(InterfaceMatcher::parse(pattern));
+        self
+    }
+
     /// Add an allowed source CIDR (e.g., "10.0.0.0/8").
     #[must_use]
     pub fn allow_source_cidr(mut self, cidr: &str) -> Self {
@@ -154,6 +165,7 @@ impl NetworkFilterBuilder {
         NetworkFilter {
             interfaces: InterfaceFilter {
                 allow: self.interfaces,
+                deny: self.interface_deny,
             },
             sources: SourceFilter {
                 allow: self.source_allow,
@@ -177,17 +189,23 @@ impl NetworkFilterBuilder {
 ///
 /// - Empty `allow` list = allow all interfaces (no filtering)
 /// - Non-empty `allow` list = only allow interfaces that match at least one entry
+/// - An interface matching any `deny` entry is rejected (deny overrides allow)
+///
+/// Name matchers accept `*` wildcards (`eth*`, `*docker*`). CIDR matchers
+/// only apply to IPv4 addresses; IPv6 interfaces are matched by name.
 #[derive(Debug, Clone, Default)]
 pub struct InterfaceFilter {
     /// Allowed interface matchers. Empty = allow all.
     pub allow: Vec<InterfaceMatcher>,
+    /// Denied interface matchers. Applied after the allow check.
+    pub deny: Vec<InterfaceMatcher>,
 }
 
 impl InterfaceFilter {
     /// Create a filter that allows all interfaces.
     #[must_use]
     pub fn allow_all() -> Self {
-        Self { allow: Vec::new() }
+        Self::default()
     }
 
     /// Create a filter that allows only the specified interface names.
@@ -198,6 +216,7 @@ impl InterfaceFilter {
                 .iter()
                 .map(|n| InterfaceMatcher::Name((*n).to_string()))
                 .collect(),
+            deny: Vec::new(),
         }
     }
 
@@ -206,71 +225,114 @@ impl InterfaceFilter {
     pub fn only_cidrs(cidrs: &[Ipv4Network]) -> Self {
         Self {
             allow: cidrs.iter().map(|c| InterfaceMatcher::Cidr(*c)).collect(),
+            deny: Vec::new(),
         }
     }
 
-    /// Create from environment variable `HDDS_INTERFACE_ALLOW`.
+    /// Create from environment variables.
+    ///
+    /// Reads `HDDS_INTERFACE` and `HDDS_INTERFACE_ALLOW` (allow list, merged)
+    /// and `HDDS_INTERFACE_DENY` (deny list).
     #[must_use]
     pub fn from_env() -> Self {
-        match std::env::var("HDDS_INTERFACE_ALLOW") {
-            Ok(val) => Self::parse_env(&val),
-            Err(_) => Self::allow_all(),
+        let mut filter = Self::allow_all();
+        for var in ["HDDS_INTERFACE", "HDDS_INTERFACE_ALLOW"] {
+            if let Ok(val) = std::env::var(var) {
+                filter.allow.extend(Self::parse_env(&val).allow);
+            }
+        }
+        if let Ok(val) = std::env::var("HDDS_INTERFACE_DENY") {
+            filter.deny = Self::parse_env(&val).allow;
         }
+        filter
     }
 
     fn parse_env(val: &str) -> Self {
-        let mut allow = Vec::new();
-        for part in val.split(',') {
-            let trimmed = part.trim();
-            if trimmed.is_empty() {
-                continue;
-            }
-            // Try to parse as CIDR first
-            if let Ok(network) = trimmed.parse::<Ipv4Network>() {
-                allow.push(InterfaceMatcher::Cidr(network));
-            } else {
-                // Treat as interface name
-                allow.push(InterfaceMatcher::Name(trimmed.to_string()));
-            }
+        let allow = val
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .map(InterfaceMatcher::parse)
+            .collect();
+        Self {
+            allow,
+            deny: Vec::new(),
         }
-        Self { allow }
+    }
+
+    /// Add an allowed interface name pattern or CIDR.
+    #[must_use]
+    pub fn allow(mut self, pattern: &str) -> Self {
+        self.allow.push(InterfaceMatcher::parse(pattern));
+        self
+    }
+
+    /// Add a denied interface name pattern or CIDR.
+    #[must_use]
+    pub fn deny(mut self, pattern: &str) -> Self {
+        self.deny.push(InterfaceMatcher::parse(pattern));
+        self
     }
 
     /// Check if this filter allows everything.
     #[must_use]
     pub fn is_permissive(&self) -> bool {
-        self.allow.is_empty()
+        self.allow.is_empty() && self.deny.is_empty()
     }
 
     /// Check if an interface name is allowed.
     #[must_use]
     pub fn allows_name(&self, name: &str) -> bool {
-        if self.allow.is_empty() {
-            return true; // No filtering
+        if self.deny.iter().any(|m| m.matches_name(name)) {
+            return false;
         }
-        self.allow.iter().any(|m| m.matches_name(name))
+        self.allow.is_empty() || self.allow.iter().any(|m| m.matches_name(name))
     }
 
     /// Check if an interface IP address is allowed.
     #[must_use]
     pub fn allows_ip(&self, ip: Ipv4Addr) -> bool {
-        if self.allow.is_empty() {
-            return true; // No filtering
+        if self.deny.iter().any(|m| m.matches_ip(ip)) {
+            return false;
         }
-        self.allow.iter().any(|m| m.matches_ip(ip))
+        self.allow.is_empty() || self.allow.iter().any(|m| m.matches_ip(ip))
     }
 
     /// Check if an interface (by name and IP) is allowed.
     ///
-    /// Returns true if EITHER the name OR the IP matches any allow rule.
+    /// Returns true if EITHER the name OR the IP matches any allow rule,
+    /// and NEITHER matches a deny rule.
     #[must_use]
     pub fn allows_interface(&self, name: &str, ip: Ipv4Addr) -> bool {
-        if self.allow.is_empty() {
-            return true; // No filtering
-        }
-        self.allow
+        if self
+            .deny
             .iter()
             .any(|m| m.matches_name(name) || m.matches_ip(ip))
+        {
+            return false;
+        }
+        self.allow.is_empty()
+            || self
+                .allow
+                .iter()
+                .any(|m| m.matches_name(name) || m.matches_ip(ip))
+    }
+
+    /// Check if an IPv6 interface is allowed.
+    ///
+    /// CIDR rules are IPv4 only: an allow list made only of CIDRs does not
+    /// restrict IPv6 interfaces, name rules do.
+    #[must_use]
+    pub fn allows_ipv6_interface(&self, name: &str) -> bool {
+        if self.deny.iter().any(|m| m.matches_name(name)) {
+            return false;
+        }
+        let mut name_rules = self
+            .allow
+            .iter()
+            .filter(|m| matches!(m, InterfaceMatcher::Name(_)))
+            .peekable();
+        name_rules.peek().is_none() || name_rules.any(|m| m.matches_name(name))
     }
 }
 
@@ -284,11 +346,23 @@ pub enum InterfaceMatcher {
 }
 
 impl InterfaceMatcher {
+    /// Parse a CIDR or single IPv4 address, else an interface name pattern.
+    #[must_use]
+    pub fn parse(pattern: &str) -> Self {
+        let pattern = pattern.trim();
+        match pattern.parse::<Ipv4Network>() {
+            Ok(network) => Self::Cidr(network),
+            Err(_) => Self::Name(pattern.to_string()),
+        }
+    }
+
     /// Check if this matcher matches an interface name.
+    ///
+    /// `*` in the pattern matches any run of characters.
     #[must_use]
     pub fn matches_name(&self, name: &str) -> bool {
         match self {
-            Self::Name(n) => n == name,
+            Self::Name(pattern) => wildcard_match(pattern, name),
             Self::Cidr(_) => false,
         }
     }
@@ -303,6 +377,27 @@ impl InterfaceMatcher {
     }
 }
 
+/// Match `text` against `pattern`, where `*` matches any run of characters.
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        // No wildcard: exact match
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(pos) => rest = &rest[pos + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
 // ============================================================================
 // Source Filter
 // ============================================================================
@@ -651,6 +746,7 @@ mod tests {
                 InterfaceMatcher::Name("eth0".to_string()),
                 InterfaceMatcher::Cidr("10.128.0.0/16".parse().expect("valid CIDR")),
             ],
+            deny: Vec::new(),
         };
 
         // eth0 matches by name
@@ -670,6 +766,44 @@ mod tests {
         assert!(filter.allows_ip(Ipv4Addr::new(10, 1, 2, 3)));
     }
 
+    #[test]
+    fn test_interface_filter_wildcards_and_deny() {
+        let filter = InterfaceFilter::allow_all()
+            .allow("eth*")
+            .allow("10.0.0.0/8")
+            .deny("*docker*")
+            .deny("10.0.0.99");
+
+        assert!(filter.allows_name("eth0"));
+        assert!(!filter.allows_name("wlan0"));
+        assert!(filter.allows_interface("wlan0", Ipv4Addr::new(10, 0, 0, 5)));
+        assert!(!filter.allows_interface("wlan0", Ipv4Addr::new(10, 0, 0, 99)));
+        assert!(!filter.allows_interface("eth-docker0", Ipv4Addr::new(10, 0, 0, 5)));
+
+        // Deny-only filters keep everything else
+        let filter = InterfaceFilter::allow_all().deny("docker*").deny("tun*");
+        assert!(!filter.is_permissive());
+        assert!(filter.allows_interface("eth0", Ipv4Addr::new(192, 168, 1, 2)));
+        assert!(!filter.allows_interface("docker0", Ipv4Addr::new(172, 17, 0, 1)));
+        assert!(!filter.allows_ipv6_interface("tun0"));
+        assert!(filter.allows_ipv6_interface("eth0"));
+
+        // CIDR-only allow lists do not restrict IPv6 interfaces
+        let filter = InterfaceFilter::allow_all().allow("10.0.0.0/8");
+        assert!(filter.allows_ipv6_interface("eth1"));
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("eth0", "eth0"));
+        assert!(!wildcard_match("eth0", "eth01"));
+        assert!(wildcard_match("eth*", "eth0"));
+        assert!(wildcard_match("*0", "eth0"));
+        assert!(wildcard_match("e*h*0", "eth0"));
+        assert!(wildcard_match("*", "anything"));
+        assert!(!wildcard_match("br-*-x", "br-x"));
+    }
+
     // ========================================================================
     // SourceFilter tests
     // ========================================================================
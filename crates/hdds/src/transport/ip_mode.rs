@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! IP address family selection for the UDP transport.
+//!
+//! - `V4` (default): UDPv4 sockets, 239.255.0.1 multicast, `LOCATOR_KIND_UDPv4`
+//! - `V6`: UDPv6 sockets, ff02::ffff:239.255.0.1 multicast, `LOCATOR_KIND_UDPv6`
+//! - `Dual`: both stacks; discovery goes out on both groups and peers are
+//!   reached over whichever family their locators use
+//!
+//! `HDDS_IP_MODE=v4|v6|dual` selects the mode when the builder does not.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// IP address families used by the UDP transport.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum IpMode {
+    /// IPv4 only (default).
+    #[default]
+    V4,
+    /// IPv6 only, for IPv6-only networks.
+    V6,
+    /// IPv4 and IPv6 side by side.
+    Dual,
+}
+
+impl IpMode {
+    /// Mode from `HDDS_IP_MODE`, IPv4 when unset or invalid.
+    pub fn from_env() -> Self {
+        match std::env::var("HDDS_IP_MODE") {
+            Ok(raw) => raw.parse().unwrap_or_else(|_| {
+                log::warn!(
+                    "[UDP] Invalid HDDS_IP_MODE='{}' (expected v4, v6 or dual), using v4",
+                    raw
+                );
+                Self::V4
+            }),
+            Err(_) => Self::V4,
+        }
+    }
+
+    /// Whether IPv4 sockets are used.
+    pub fn uses_v4(self) -> bool {
+        matches!(self, Self::V4 | Self::Dual)
+    }
+
+    /// Whether IPv6 sockets are used.
+    pub fn uses_v6(self) -> bool {
+        matches!(self, Self::V6 | Self::Dual)
+    }
+}
+
+impl FromStr for IpMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "v4" | "ipv4" | "4" => Ok(Self::V4),
+            "v6" | "ipv6" | "6" => Ok(Self::V6),
+            "dual" | "both" | "dual-stack" => Ok(Self::Dual),
+            other => Err(format!("unknown IP mode '{}'", other)),
+        }
+    }
+}
+
+impl fmt::Display for IpMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::V4 => "v4",
+            Self::V6 => "v6",
+            Self::Dual => "dual",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ip_mode_parse_and_families() {
+        assert_eq!("v6".parse::<IpMode>(), Ok(IpMode::V6));
+        assert_eq!("IPv4".parse::<IpMode>(), Ok(IpMode::V4));
+        assert_eq!(" dual ".parse::<IpMode>(), Ok(IpMode::Dual));
+        assert!("v5".parse::<IpMode>().is_err());
+
+        assert!(IpMode::V4.uses_v4() && !IpMode::V4.uses_v6());
+        assert!(!IpMode::V6.uses_v4() && IpMode::V6.uses_v6());
+        assert!(IpMode::Dual.uses_v4() && IpMode::Dual.uses_v6());
+        assert_eq!(IpMode::Dual.to_string(), "dual");
+    }
+}
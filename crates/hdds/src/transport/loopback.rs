@@ -0,0 +1,332 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! In-memory loopback bus for `TransportMode::LoopbackBus`.
+//!
+//! Participants of one process attach to the bus and exchange complete
+//! RTPS messages through channels; no socket is ever opened, so examples
+//! and CI run in sandboxes that forbid networking. Every member gets
+//! `127.0.0.1` pseudo-locators on the ports of its [`PortMapping`]:
+//! unicast sends are routed by destination locator, multicast sends reach
+//! every other member of the same domain.
+
+use crate::transport::PortMapping;
+use crossbeam::channel::{self, Receiver, Sender, TrySendError};
+use std::io;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Datagrams queued per member before new ones are dropped (like a full
+/// socket receive buffer).
+const PORT_QUEUE_DEPTH: usize = 4096;
+
+/// One RTPS message in flight on the bus.
+#[derive(Debug, Clone)]
+pub struct Datagram {
+    /// Complete RTPS message.
+    pub data: Vec<u8>,
+    /// Sender locator.
+    pub src: SocketAddr,
+}
+
+struct Member {
+    domain_id: u32,
+    participant_id: u8,
+    mapping: PortMapping,
+    tx: Sender<Datagram>,
+}
+
+impl Member {
+    fn owns(&self, addr: &SocketAddr) -> bool {
+        addr.ip() == Ipv4Addr::LOCALHOST
+            && (addr.port() == self.mapping.metatraffic_unicast
+                || addr.port() == self.mapping.user_unicast)
+    }
+}
+
+/// Highest participant ID + 1 accepted by [`PortMapping`].
+const PARTICIPANT_ID_LIMIT: u8 = 120;
+
+/// Process-wide set of loopback participants.
+#[derive(Default)]
+pub struct LoopbackBus {
+    members: Mutex<Vec<Member>>,
+    /// Where the search for the next free participant ID starts
+    next_id: AtomicU8,
+}
+
+impl LoopbackBus {
+    /// Create an empty bus (participants use [`global`](Self::global)).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bus shared by every `TransportMode::LoopbackBus` participant.
+    pub fn global() -> Arc<Self> {
+        static GLOBAL: OnceLock<Arc<LoopbackBus>> = OnceLock::new();
+        Arc::clone(GLOBAL.get_or_init(|| Arc::new(Self::new())))
+    }
+
+    fn members(&self) -> std::sync::MutexGuard<'_, Vec<Member>> {
+        self.members.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Attach a participant to `domain_id`.
+    ///
+    /// `participant_id` pins the participant ID; `None` takes the next ID
+    /// free in every domain, cycling through the ID space. GUID prefixes
+    /// are derived from the participant ID only, so IDs are neither shared
+    /// across domains nor reused right away: peers would take a re-created
+    /// participant for the dropped one. Fails with
+    /// [`io::ErrorKind::AddrInUse`] when the requested (or every) ID is taken.
+    pub fn attach(
+        self: &Arc<Self>,
+        domain_id: u32,
+        participant_id: Option<u8>,
+    ) -> io::Result<LoopbackPort> {
+        let mut members = self.members();
+        let taken_in = |pid: u8, domain: Option<u32>| {
+            members
+                .iter()
+                .any(|m| m.participant_id == pid && domain.is_none_or(|d| m.domain_id == d))
+        };
+        let (participant_id, mapping) = match participant_id {
+            Some(pid) if taken_in(pid, Some(domain_id)) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::AddrInUse,
+                    format!(
+                        "participant_id {} already attached to loopback domain {}",
+                        pid, domain_id
+                    ),
+                ))
+            }
+            Some(pid) => (pid, Self::mapping(domain_id, pid)?),
+            None => {
+                let start = self.next_id.load(Ordering::Relaxed);
+                (0..PARTICIPANT_ID_LIMIT)
+                    .map(|i| (start + i) % PARTICIPANT_ID_LIMIT)
+                    .filter(|&pid| !taken_in(pid, None))
+                    .find_map(|pid| Some((pid, PortMapping::calculate(domain_id, pid).ok()?)))
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::AddrInUse,
+                            format!("no free participant_id on loopback domain {}", domain_id),
+                        )
+                    })?
+            }
+        };
+        self.next_id.store(
+            (participant_id + 1) % PARTICIPANT_ID_LIMIT,
+            Ordering::Relaxed,
+        );
+
+        let (tx, rx) = channel::bounded(PORT_QUEUE_DEPTH);
+        members.push(Member {
+            domain_id,
+            participant_id,
+            mapping,
+            tx,
+        });
+        log::debug!(
+            "[loopback] attached domain={} participant_id={} ({} members)",
+            domain_id,
+            participant_id,
+            members.len()
+        );
+
+        Ok(LoopbackPort {
+            bus: Arc::clone(self),
+            domain_id,
+            participant_id,
+            mapping,
+            rx,
+        })
+    }
+
+    fn mapping(domain_id: u32, participant_id: u8) -> io::Result<PortMapping> {
+        PortMapping::calculate(domain_id, participant_id)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e.to_string()))
+    }
+
+    fn detach(&self, domain_id: u32, participant_id: u8) {
+        self.members()
+            .retain(|m| !(m.domain_id == domain_id && m.participant_id == participant_id));
+    }
+
+    /// Number of attached participants, all domains included.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.members().len()
+    }
+
+    /// Whether no participant is attached.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+fn deliver(member: &Member, datagram: Datagram) {
+    match member.tx.try_send(datagram) {
+        Ok(()) => {}
+        Err(TrySendError::Full(_)) => log::debug!(
+            "[loopback] queue full, dropping message for participant_id={}",
+            member.participant_id
+        ),
+        // Receiver gone: the participant is shutting down
+        Err(TrySendError::Disconnected(_)) => {}
+    }
+}
+
+/// A participant's attachment to the [`LoopbackBus`].
+///
+/// Detaches on drop.
+pub struct LoopbackPort {
+    bus: Arc<LoopbackBus>,
+    domain_id: u32,
+    participant_id: u8,
+    mapping: PortMapping,
+    rx: Receiver<Datagram>,
+}
+
+impl LoopbackPort {
+    /// Participant ID assigned by the bus.
+    #[must_use]
+    pub fn participant_id(&self) -> u8 {
+        self.participant_id
+    }
+
+    /// Port mapping the pseudo-locators are built from.
+    #[must_use]
+    pub fn mapping(&self) -> PortMapping {
+        self.mapping
+    }
+
+    /// Pseudo-locator of `port` (`127.0.0.1:port`).
+    #[must_use]
+    pub fn locator(port: u16) -> SocketAddr {
+        SocketAddr::from((Ipv4Addr::LOCALHOST, port))
+    }
+
+    /// Metatraffic unicast pseudo-locator (source of metatraffic sends).
+    #[must_use]
+    pub fn metatraffic_addr(&self) -> SocketAddr {
+        Self::locator(self.mapping.metatraffic_unicast)
+    }
+
+    /// User data unicast pseudo-locator (source of user data sends).
+    #[must_use]
+    pub fn user_addr(&self) -> SocketAddr {
+        Self::locator(self.mapping.user_unicast)
+    }
+
+    /// Receiving end of the port, drained by the participant's listener.
+    #[must_use]
+    pub fn receiver(&self) -> Receiver<Datagram> {
+        self.rx.clone()
+    }
+
+    /// Send `data` to every other participant of the domain.
+    pub fn multicast(&self, data: &[u8], src: SocketAddr) {
+        for member in
+            self.bus.members().iter().filter(|m| {
+                m.domain_id == self.domain_id && m.participant_id != self.participant_id
+            })
+        {
+            deliver(
+                member,
+                Datagram {
+                    data: data.to_vec(),
+                    src,
+                },
+            );
+        }
+    }
+
+    /// Send `data` to the participant owning locator `dest`.
+    ///
+    /// Like UDP, a destination nobody owns silently drops the message.
+    pub fn unicast(&self, data: &[u8], src: SocketAddr, dest: SocketAddr) {
+        match self.bus.members().iter().find(|m| m.owns(&dest)) {
+            Some(member) => deliver(
+                member,
+                Datagram {
+                    data: data.to_vec(),
+                    src,
+                },
+            ),
+            None => log::debug!("[loopback] no participant at {}, dropping message", dest),
+        }
+    }
+}
+
+impl Drop for LoopbackPort {
+    fn drop(&mut self) {
+        self.bus.detach(self.domain_id, self.participant_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn recv(port: &LoopbackPort) -> Option<Datagram> {
+        port.receiver().recv_timeout(Duration::from_millis(10)).ok()
+    }
+
+    #[test]
+    fn test_attach_assigns_free_participant_ids() {
+        let bus = Arc::new(LoopbackBus::new());
+        let a = bus.attach(0, None).expect("attach");
+        let b = bus.attach(0, None).expect("attach");
+        let other_domain = bus.attach(1, None).expect("attach");
+        assert_eq!((a.participant_id(), b.participant_id()), (0, 1));
+        assert_eq!(other_domain.participant_id(), 2, "IDs are not shared");
+
+        let err = bus.attach(0, Some(1)).err().expect("ID taken");
+        assert_eq!(err.kind(), io::ErrorKind::AddrInUse);
+        let pinned = bus.attach(1, Some(1)).expect("free in domain 1");
+
+        // Released IDs are not handed out again right away
+        drop(b);
+        let c = bus.attach(0, None).expect("attach");
+        assert_eq!(c.participant_id(), 3);
+        assert_eq!(bus.len(), 4);
+        drop(pinned);
+        assert_eq!(bus.len(), 3);
+    }
+
+    #[test]
+    fn test_multicast_skips_sender_and_other_domains() {
+        let bus = Arc::new(LoopbackBus::new());
+        let a = bus.attach(0, None).expect("attach");
+        let b = bus.attach(0, None).expect("attach");
+        let c = bus.attach(1, None).expect("attach");
+
+        a.multicast(b"RTPS-spdp", a.metatraffic_addr());
+        let datagram = recv(&b).expect("delivered");
+        assert_eq!(datagram.data, b"RTPS-spdp");
+        assert_eq!(datagram.src, a.metatraffic_addr());
+        assert!(recv(&a).is_none());
+        assert!(recv(&c).is_none());
+    }
+
+    #[test]
+    fn test_unicast_routes_by_locator() {
+        let bus = Arc::new(LoopbackBus::new());
+        let a = bus.attach(0, None).expect("attach");
+        let b = bus.attach(0, None).expect("attach");
+
+        a.unicast(b"user", a.user_addr(), b.user_addr());
+        a.unicast(b"meta", a.metatraffic_addr(), b.metatraffic_addr());
+        assert_eq!(recv(&b).expect("user").data, b"user");
+        assert_eq!(recv(&b).expect("meta").data, b"meta");
+
+        // Unknown destination is dropped
+        a.unicast(b"lost", a.user_addr(), LoopbackPort::locator(1));
+        assert!(recv(&b).is_none());
+    }
+}
@@ -9,9 +9,15 @@
 //! # Modules
 //!
 //! - `udp` - UDP socket management and send/receive operations
+//! - `ip_mode` - IPv4 / IPv6 / dual-stack selection
+//! - `loopback` - In-memory bus for socket-free participants in one process
 //! - `multicast` - Multicast group joining and interface discovery
 //! - `ports` - RTPS v2.5 port number calculation
 //! - `shm` - Shared memory transport for inter-process zero-copy communication
+//! - `uds` - Unix domain socket transport for same-host peers without shared SHM
+//! - `tap` - Packet tap and pcapng dump of the RTPS traffic (`HDDS_PCAP`)
+//! - `rdma` - RDMA verbs transport for HPC clusters (`rdma` feature, experimental)
+//! - `xdp` - AF_XDP accelerated receive path (`af-xdp` feature, Linux, experimental)
 //!
 //! # Example
 //!
@@ -30,6 +36,10 @@
 pub mod dscp;
 /// IP-based network filtering (whitelist/blacklist).
 pub mod filter;
+/// IP address family selection (IPv4, IPv6, dual-stack).
+pub mod ip_mode;
+/// In-memory loopback bus (no sockets, single process).
+pub mod loopback;
 /// Low Bandwidth Transport for constrained links (9.6 kbps - 2 Mbps).
 pub mod lowbw;
 /// IP mobility detection and locator tracking.
@@ -146,6 +156,11 @@ pub mod shm {
 /// QUIC transport for NAT traversal and connection migration.
 #[cfg(feature = "quic")]
 pub mod quic;
+/// RDMA (verbs) transport for InfiniBand/RoCE clusters (experimental).
+#[cfg(feature = "rdma")]
+pub mod rdma;
+/// Packet tap and pcapng capture of sent and received RTPS messages.
+pub mod tap;
 /// TCP transport for environments where UDP is blocked or unreliable.
 pub mod tcp;
 /// Time-Sensitive Networking (TSN) support for deterministic Ethernet.
@@ -154,6 +169,11 @@ pub mod tsn;
 pub mod ttl;
 /// UDP socket management for RTPS communication.
 pub mod udp;
+/// Unix domain socket transport for same-host containers without shared SHM.
+pub mod uds;
+/// AF_XDP accelerated receive path for user traffic (experimental).
+#[cfg(all(feature = "af-xdp", target_os = "linux"))]
+pub mod xdp;
 
 // Re-export main types
 pub use dscp::{DscpClass, DscpConfig};
@@ -161,7 +181,10 @@ pub use filter::{
     InterfaceFilter, InterfaceMatcher, Ipv4Network, NetworkFilter, NetworkFilterBuilder,
     NetworkParseError, SourceFilter,
 };
+pub use ip_mode::IpMode;
+pub use loopback::{LoopbackBus, LoopbackPort};
 pub use ports::{CustomPortMapping, PortMapping};
+pub use tap::{CapturedPacket, PacketDirection, PacketTap, PcapngWriter};
 pub use tsn::{
     default_backend as tsn_default_backend, DropPolicy, SupportLevel, TrafficPolicy, TsnBackend,
     TsnCapabilities, TsnClockId, TsnConfig, TsnEnforcement, TsnErrorStats, TsnMetrics, TsnProbe,
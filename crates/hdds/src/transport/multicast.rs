@@ -3,11 +3,13 @@
 
 //! Multicast group management and interface discovery.
 //!
-//! Handles joining multicast groups, discovering network interfaces,
+//! Handles joining multicast groups (IPv4 and IPv6), discovering network interfaces,
 //! and configuring multicast settings for RTPS communication.
 
+use crate::config::{MULTICAST_IP_V6, MULTICAST_IP_V6_ALT};
+use crate::transport::filter::InterfaceFilter;
 use std::io;
-use std::net::{Ipv4Addr, UdpSocket};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, UdpSocket};
 
 /// Join RTPS multicast groups (SPDP and SEDP) on all available interfaces.
 ///
@@ -18,6 +20,18 @@ use std::net::{Ipv4Addr, UdpSocket};
 /// per-interface joins fail (common on Windows with virtual adapters like
 /// Hyper-V Default Switch, WSL, Docker Desktop).
 pub fn join_multicast_group(socket: &UdpSocket) -> io::Result<Ipv4Addr> {
+    join_multicast_group_filtered(socket, &InterfaceFilter::allow_all())
+}
+
+/// Join RTPS multicast groups on the interfaces allowed by `filter`.
+///
+/// With a restrictive filter, multicast is also sent out of the first
+/// selected interface, and no matching interface is an error instead of a
+/// fallback to the OS default (which could be a VPN or bridge interface).
+pub fn join_multicast_group_filtered(
+    socket: &UdpSocket,
+    filter: &InterfaceFilter,
+) -> io::Result<Ipv4Addr> {
     let multicast_groups = [
         Ipv4Addr::new(239, 255, 0, 1), // SPDP (common practice)
         Ipv4Addr::new(239, 255, 0, 2), // SEDP (spec-compliant)
@@ -25,10 +39,13 @@ pub fn join_multicast_group(socket: &UdpSocket) -> io::Result<Ipv4Addr> {
 
     // RTI strategy: Join multicast on ALL available interfaces (not just one).
     // strace shows RTI calls IP_ADD_MEMBERSHIP multiple times for each interface.
-    let interfaces = get_multicast_interfaces()?;
+    let interfaces = get_filtered_multicast_interfaces(filter)?;
     let mut any_joined = false;
 
     if interfaces.is_empty() {
+        if !filter.is_permissive() {
+            return Err(no_matching_interface());
+        }
         // No interfaces found -- try UNSPECIFIED (let OS choose)
         log::debug!("[UDP] No suitable interfaces found for multicast, trying UNSPECIFIED");
         for group in &multicast_groups {
@@ -107,8 +124,66 @@ pub fn join_multicast_group(socket: &UdpSocket) -> io::Result<Ipv4Addr> {
     log::debug!("[UDP] multicast loop enabled");
     let _ = socket.set_multicast_ttl_v4(1);
 
+    let send_iface = interfaces.first().copied().unwrap_or(Ipv4Addr::UNSPECIFIED);
+    if !filter.is_permissive() {
+        socket2::SockRef::from(socket).set_multicast_if_v4(&send_iface)?;
+        log::debug!("[UDP] multicast send interface pinned to {}", send_iface);
+    }
+
     // Return first interface for sending (or UNSPECIFIED if none)
-    Ok(interfaces.first().copied().unwrap_or(Ipv4Addr::UNSPECIFIED))
+    Ok(send_iface)
+}
+
+/// Get the IPv4 multicast interfaces allowed by `filter`.
+///
+/// A permissive filter returns [`get_multicast_interfaces`] unchanged. The
+/// `HDDS_MULTICAST_IF` override takes precedence over the filter.
+pub fn get_filtered_multicast_interfaces(filter: &InterfaceFilter) -> io::Result<Vec<Ipv4Addr>> {
+    if filter.is_permissive() || multicast_if_override().is_some() {
+        return get_multicast_interfaces();
+    }
+
+    let addrs: Vec<Ipv4Addr> = named_ipv4_interfaces()
+        .into_iter()
+        .filter(|(name, ip)| {
+            let allowed = filter.allows_interface(name, *ip);
+            if !allowed {
+                log::debug!("[UDP] Interface {} ({}) excluded by filter", name, ip);
+            }
+            allowed
+        })
+        .map(|(_, ip)| ip)
+        .collect();
+    log::debug!("[UDP] Interface filter selected {:?}", addrs);
+    Ok(addrs)
+}
+
+/// Non-loopback IPv4 addresses with their interface names.
+fn named_ipv4_interfaces() -> Vec<(String, Ipv4Addr)> {
+    match local_ip_address::list_afinet_netifas() {
+        Ok(ifs) => ifs
+            .into_iter()
+            .filter_map(|(name, ip)| match ip {
+                IpAddr::V4(ipv4) if !ipv4.is_loopback() => Some((name, ipv4)),
+                _ => None,
+            })
+            .collect(),
+        Err(e) => {
+            log::debug!("[UDP] Failed to list network interfaces: {}", e);
+            vec![]
+        }
+    }
+}
+
+fn no_matching_interface() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        "no network interface matches the interface filter (HDDS_INTERFACE / with_interface)",
+    )
+}
+
+fn multicast_if_override() -> Option<Ipv4Addr> {
+    std::env::var("HDDS_MULTICAST_IF").ok()?.parse().ok()
 }
 
 /// Get all non-loopback IPv4 interfaces suitable for multicast.
@@ -118,11 +193,9 @@ pub fn join_multicast_group(socket: &UdpSocket) -> io::Result<Ipv4Addr> {
 /// - Windows/other: uses `local_ip_address` crate
 pub fn get_multicast_interfaces() -> io::Result<Vec<Ipv4Addr>> {
     // Try env var override first (for testing/debugging)
-    if let Ok(var) = std::env::var("HDDS_MULTICAST_IF") {
-        if let Ok(addr) = var.parse::<Ipv4Addr>() {
-            log::debug!("[UDP] Using HDDS_MULTICAST_IF override: {}", addr);
-            return Ok(vec![addr]);
-        }
+    if let Some(addr) = multicast_if_override() {
+        log::debug!("[UDP] Using HDDS_MULTICAST_IF override: {}", addr);
+        return Ok(vec![addr]);
     }
 
     get_multicast_interfaces_platform()
@@ -236,6 +309,33 @@ pub fn get_primary_interface_ip() -> io::Result<Ipv4Addr> {
     Ok(Ipv4Addr::UNSPECIFIED)
 }
 
+/// Get the primary interface IP among the interfaces allowed by `filter`.
+///
+/// The routed address is kept when its interface is allowed; otherwise the
+/// first allowed interface is used. Fails when no interface is allowed.
+pub fn get_filtered_primary_interface_ip(filter: &InterfaceFilter) -> io::Result<Ipv4Addr> {
+    if filter.is_permissive() {
+        return get_primary_interface_ip();
+    }
+
+    let allowed = get_filtered_multicast_interfaces(filter)?;
+    let routed = get_primary_interface_ip()?;
+    if allowed.contains(&routed) {
+        return Ok(routed);
+    }
+    match allowed.first() {
+        Some(&ip) => {
+            log::debug!(
+                "[UDP] Routed interface {} excluded by filter, using {}",
+                routed,
+                ip
+            );
+            Ok(ip)
+        }
+        None => Err(no_matching_interface()),
+    }
+}
+
 /// Get locators for a given port on all non-loopback interfaces.
 ///
 /// Used to generate unicast locator lists for SPDP/SEDP announcements.
@@ -303,3 +403,192 @@ pub fn get_unicast_locators(primary_iface: Ipv4Addr, port: u16) -> Vec<std::net:
 
     locators
 }
+
+// ===== IPv6 =====
+
+/// Network interface carrying IPv6 addresses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ipv6Interface {
+    /// Interface name (e.g. `eth0`).
+    pub name: String,
+    /// Interface index, used as scope id for link-local addresses and groups.
+    pub index: u32,
+    /// Non-loopback IPv6 addresses of the interface.
+    pub addrs: Vec<Ipv6Addr>,
+}
+
+/// Get the IPv6 interfaces allowed by `filter` (matched by name).
+pub fn get_filtered_ipv6_interfaces(filter: &InterfaceFilter) -> Vec<Ipv6Interface> {
+    let mut interfaces = get_ipv6_interfaces();
+    interfaces.retain(|iface| filter.allows_ipv6_interface(&iface.name));
+    interfaces
+}
+
+/// Get all non-loopback interfaces carrying an IPv6 address.
+pub fn get_ipv6_interfaces() -> Vec<Ipv6Interface> {
+    let netifas = match local_ip_address::list_afinet_netifas() {
+        Ok(ifs) => ifs,
+        Err(e) => {
+            log::debug!("[UDP] Failed to list network interfaces: {}", e);
+            return vec![];
+        }
+    };
+
+    let mut interfaces: Vec<Ipv6Interface> = Vec::new();
+    for (name, ip) in netifas {
+        let IpAddr::V6(ipv6) = ip else { continue };
+        if ipv6.is_loopback() || ipv6.is_unspecified() {
+            continue;
+        }
+        match interfaces.iter_mut().find(|iface| iface.name == name) {
+            Some(iface) => iface.addrs.push(ipv6),
+            None => {
+                let index = interface_index(&name);
+                interfaces.push(Ipv6Interface {
+                    name,
+                    index,
+                    addrs: vec![ipv6],
+                });
+            }
+        }
+    }
+    interfaces
+}
+
+#[cfg(unix)]
+fn interface_index(name: &str) -> u32 {
+    let Ok(c_name) = std::ffi::CString::new(name) else {
+        return 0;
+    };
+    // SAFETY: if_nametoindex reads a valid NUL-terminated string and has no other side effects
+    unsafe { libc::if_nametoindex(c_name.as_ptr()) }
+}
+
+#[cfg(not(unix))]
+fn interface_index(_name: &str) -> u32 {
+    // 0 lets the OS pick the interface
+    0
+}
+
+/// Whether `addr` is a link-local unicast address (fe80::/10).
+pub fn is_ipv6_link_local(addr: &Ipv6Addr) -> bool {
+    (addr.segments()[0] & 0xffc0) == 0xfe80
+}
+
+/// Join the IPv6 RTPS multicast groups (ff02::ffff:239.255.0.1/.2) on all
+/// IPv6 interfaces.
+///
+/// Returns the index of the interface multicast is sent on (0 = OS default).
+pub fn join_multicast_group_v6(
+    socket: &UdpSocket,
+    interfaces: &[Ipv6Interface],
+) -> io::Result<u32> {
+    let multicast_groups = [
+        Ipv6Addr::from(MULTICAST_IP_V6),
+        Ipv6Addr::from(MULTICAST_IP_V6_ALT),
+    ];
+
+    let mut indexes: Vec<u32> = interfaces.iter().map(|iface| iface.index).collect();
+    indexes.dedup();
+    if indexes.is_empty() {
+        log::debug!("[UDP] No IPv6 interfaces found for multicast, trying the default one");
+        indexes.push(0);
+    }
+
+    let mut any_joined = false;
+    for &index in &indexes {
+        for group in &multicast_groups {
+            match socket.join_multicast_v6(group, index) {
+                Ok(()) => {
+                    any_joined = true;
+                    log::debug!("[UDP] join_multicast_v6({}) on interface #{}", group, index);
+                }
+                Err(e) if e.raw_os_error() == Some(98) => {
+                    // EADDRINUSE (98) Linux: already joined
+                    any_joined = true;
+                }
+                Err(e) => {
+                    log::debug!(
+                        "[UDP] join_multicast_v6({}) on interface #{} failed (non-fatal): {}",
+                        group,
+                        index,
+                        e
+                    );
+                }
+            }
+        }
+    }
+
+    if !any_joined {
+        log::warn!(
+            "[UDP] WARNING: Could not join any IPv6 multicast group! Discovery may not work."
+        );
+    }
+
+    socket.set_multicast_loop_v6(true)?;
+    let primary = primary_ipv6_interface(interfaces).map_or(0, |iface| iface.index);
+    if primary != 0 {
+        socket2::SockRef::from(socket).set_multicast_if_v6(primary)?;
+    }
+    Ok(primary)
+}
+
+/// Get the primary IPv6 address (the one used for the default route).
+///
+/// Falls back to the first global/unique-local address, then to the first
+/// link-local one, when there is no IPv6 default route.
+pub fn get_primary_interface_ipv6(interfaces: &[Ipv6Interface]) -> Option<Ipv6Addr> {
+    if let Ok(probe) = UdpSocket::bind((Ipv6Addr::UNSPECIFIED, 0)) {
+        if probe.connect("[2001:4860:4860::8888]:80").is_ok() {
+            if let Ok(SocketAddr::V6(local)) = probe.local_addr() {
+                let ip = *local.ip();
+                // Skip a routed address on an interface left out of `interfaces`
+                let listed = interfaces.is_empty()
+                    || interfaces.iter().any(|iface| iface.addrs.contains(&ip));
+                if !ip.is_unspecified() && !ip.is_loopback() && listed {
+                    log::debug!("[UDP] Primary IPv6 via routing table probe: {}", ip);
+                    return Some(ip);
+                }
+            }
+        }
+    }
+
+    let addrs = || {
+        interfaces
+            .iter()
+            .flat_map(|iface| iface.addrs.iter().copied())
+    };
+    addrs()
+        .find(|ip| !is_ipv6_link_local(ip))
+        .or_else(|| addrs().next())
+}
+
+/// Interface holding the primary IPv6 address.
+fn primary_ipv6_interface(interfaces: &[Ipv6Interface]) -> Option<&Ipv6Interface> {
+    let primary = get_primary_interface_ipv6(interfaces)?;
+    interfaces
+        .iter()
+        .find(|iface| iface.addrs.contains(&primary))
+}
+
+/// Get IPv6 locators for a given port.
+///
+/// Same policy as [`get_unicast_locators`]: the primary address only,
+/// unless HDDS_UNICAST_IF forces an IPv6 address.
+pub fn get_unicast_locators_v6(interfaces: &[Ipv6Interface], port: u16) -> Vec<SocketAddr> {
+    if let Ok(addr_str) = std::env::var("HDDS_UNICAST_IF") {
+        if let Ok(ipv6) = addr_str.parse::<Ipv6Addr>() {
+            return vec![SocketAddr::new(IpAddr::V6(ipv6), port)];
+        }
+    }
+
+    match get_primary_interface_ipv6(interfaces) {
+        Some(ip) => vec![SocketAddr::new(IpAddr::V6(ip), port)],
+        None => {
+            log::debug!(
+                "[UDP] [!]  No IPv6 unicast locator found! Remote peers won't be able to send us data."
+            );
+            vec![]
+        }
+    }
+}
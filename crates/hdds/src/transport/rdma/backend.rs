@@ -0,0 +1,174 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Device abstraction over RDMA verbs.
+//!
+//! [`RdmaDevice`] and [`QueuePair`] expose the handful of verbs the
+//! transport needs (memory registration, RC queue pairs, SEND/RECV and
+//! completion polling). Two backends implement them:
+//!
+//! - `VerbsDevice` - libibverbs, loaded at runtime (Linux only)
+//! - `LoopbackDevice` - in-process software emulation for tests and
+//!   development machines without an RDMA NIC
+
+use std::io;
+
+use super::RdmaConfig;
+
+/// Local/remote keys of a registered memory region.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MemoryKeys {
+    /// Key used in local scatter/gather entries.
+    pub lkey: u32,
+    /// Key remote peers would use for one-sided access.
+    pub rkey: u32,
+}
+
+/// A registered memory region. Dropping it deregisters the memory.
+pub trait MemoryRegion: Send + Sync {
+    /// Registration keys.
+    fn keys(&self) -> MemoryKeys;
+}
+
+/// Scatter/gather entry (`struct ibv_sge`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sge {
+    /// Start address inside a registered region.
+    pub addr: u64,
+    /// Length in bytes.
+    pub length: u32,
+    /// Local key of the region.
+    pub lkey: u32,
+}
+
+/// Queue pair addressing information, exchanged out-of-band before
+/// connecting two RC queue pairs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct QpInfo {
+    /// Queue pair number.
+    pub qp_num: u32,
+    /// Port LID (InfiniBand; 0 on RoCE).
+    pub lid: u16,
+    /// Port GID (required on RoCE).
+    pub gid: [u8; 16],
+    /// Initial packet sequence number.
+    pub psn: u32,
+}
+
+impl QpInfo {
+    /// Serialized size in bytes.
+    pub const WIRE_SIZE: usize = 26;
+
+    /// Serialize (big-endian) for the out-of-band exchange.
+    pub fn to_bytes(&self) -> [u8; Self::WIRE_SIZE] {
+        let mut out = [0u8; Self::WIRE_SIZE];
+        out[0..4].copy_from_slice(&self.qp_num.to_be_bytes());
+        out[4..6].copy_from_slice(&self.lid.to_be_bytes());
+        out[6..22].copy_from_slice(&self.gid);
+        out[22..26].copy_from_slice(&self.psn.to_be_bytes());
+        out
+    }
+
+    /// Parse bytes produced by [`to_bytes`](Self::to_bytes).
+    pub fn from_bytes(buf: &[u8]) -> Option<Self> {
+        if buf.len() < Self::WIRE_SIZE {
+            return None;
+        }
+        let mut gid = [0u8; 16];
+        gid.copy_from_slice(&buf[6..22]);
+        Some(Self {
+            qp_num: u32::from_be_bytes([buf[0], buf[1], buf[2], buf[3]]),
+            lid: u16::from_be_bytes([buf[4], buf[5]]),
+            gid,
+            psn: u32::from_be_bytes([buf[22], buf[23], buf[24], buf[25]]),
+        })
+    }
+}
+
+/// Operation a work completion belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompletionKind {
+    /// A posted SEND finished.
+    Send,
+    /// A posted receive buffer was filled.
+    Recv,
+}
+
+/// Work completion (`struct ibv_wc`, reduced).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WorkCompletion {
+    /// Caller-chosen work request id.
+    pub wr_id: u64,
+    /// Send or receive.
+    pub kind: CompletionKind,
+    /// Bytes received (receive completions only).
+    pub byte_len: u32,
+    /// `enum ibv_wc_status` (0 = success).
+    pub status: u32,
+}
+
+impl WorkCompletion {
+    /// True if the work request succeeded.
+    pub fn is_success(&self) -> bool {
+        self.status == 0
+    }
+}
+
+/// An RDMA-capable device (one opened port).
+pub trait RdmaDevice: Send + Sync {
+    /// Device name.
+    fn name(&self) -> &str;
+
+    /// Register `len` bytes at `addr` for local and remote access.
+    ///
+    /// # Safety
+    /// The memory must stay allocated until the returned region is dropped.
+    unsafe fn register(&self, addr: *mut u8, len: usize) -> io::Result<Box<dyn MemoryRegion>>;
+
+    /// Create an RC queue pair in the INIT state, ready for `post_recv`.
+    fn create_qp(&self, config: &RdmaConfig) -> io::Result<Box<dyn QueuePair>>;
+}
+
+/// A reliable-connected queue pair with its own completion queue.
+pub trait QueuePair: Send {
+    /// Addressing information to hand to the remote side.
+    fn local_info(&self) -> QpInfo;
+
+    /// Connect to the remote queue pair (INIT -> RTR -> RTS).
+    fn connect(&mut self, remote: &QpInfo) -> io::Result<()>;
+
+    /// Post a receive buffer.
+    ///
+    /// # Safety
+    /// `sge` must describe registered memory that stays valid and untouched
+    /// until the matching completion is polled.
+    unsafe fn post_recv(&mut self, wr_id: u64, sge: Sge) -> io::Result<()>;
+
+    /// Post a signaled SEND.
+    ///
+    /// # Safety
+    /// `sge` must describe registered memory that stays valid and unmodified
+    /// until the matching completion is polled.
+    unsafe fn post_send(&mut self, wr_id: u64, sge: Sge) -> io::Result<()>;
+
+    /// Append up to `max` completions to `out`.
+    fn poll_cq(&mut self, out: &mut Vec<WorkCompletion>, max: usize) -> io::Result<()>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qp_info_roundtrip() {
+        let info = QpInfo {
+            qp_num: 0x00AB_CDEF,
+            lid: 7,
+            gid: [0xFE; 16],
+            psn: 0x12_3456,
+        };
+        let bytes = info.to_bytes();
+        assert_eq!(QpInfo::from_bytes(&bytes), Some(info));
+        assert_eq!(QpInfo::from_bytes(&bytes[..10]), None);
+    }
+}
@@ -0,0 +1,192 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! RDMA transport configuration.
+//!
+//! # Example
+//!
+//! ```
+//! use hdds::transport::rdma::{PathMtu, RdmaConfig};
+//!
+//! let config = RdmaConfig {
+//!     device_name: Some("mlx5_0".into()),
+//!     gid_index: 3, // RoCEv2 GID on most ConnectX setups
+//!     path_mtu: PathMtu::Mtu4096,
+//!     ..Default::default()
+//! };
+//! assert!(config.validate().is_ok());
+//! ```
+
+/// Path MTU negotiated on the RC connection (`enum ibv_mtu`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PathMtu {
+    Mtu256,
+    Mtu512,
+    Mtu1024,
+    Mtu2048,
+    /// RoCE on jumbo-frame Ethernet / InfiniBand default.
+    #[default]
+    Mtu4096,
+}
+
+impl PathMtu {
+    /// Value of the matching `enum ibv_mtu` constant.
+    pub fn to_verbs(self) -> u32 {
+        match self {
+            PathMtu::Mtu256 => 1,
+            PathMtu::Mtu512 => 2,
+            PathMtu::Mtu1024 => 3,
+            PathMtu::Mtu2048 => 4,
+            PathMtu::Mtu4096 => 5,
+        }
+    }
+}
+
+/// RDMA transport configuration.
+///
+/// Memory for every send and receive is carved out of a single registered
+/// pool of `pool_slots` x `slot_size` bytes, so `slot_size` is also the
+/// largest RTPS message the transport can carry (larger samples rely on
+/// RTPS fragmentation).
+#[derive(Clone, Debug)]
+pub struct RdmaConfig {
+    /// Enable RDMA transport (opt-in, disabled by default)
+    pub enabled: bool,
+
+    /// Verbs device name (e.g. "mlx5_0"). None = first device found.
+    pub device_name: Option<String>,
+
+    /// Physical port number (1-based).
+    pub port_num: u8,
+
+    /// GID table index. RoCE needs a valid GID (v2 GIDs usually sit at 1 or 3).
+    pub gid_index: u8,
+
+    /// Path MTU for RC queue pairs.
+    pub path_mtu: PathMtu,
+
+    /// Number of slots in the registered buffer pool.
+    pub pool_slots: usize,
+
+    /// Size of one pool slot (= max message size).
+    pub slot_size: usize,
+
+    /// Receive buffers posted per connection.
+    pub recv_depth: u32,
+
+    /// Maximum outstanding sends per connection.
+    pub send_depth: u32,
+}
+
+impl Default for RdmaConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false, // Opt-in
+            device_name: None,
+            port_num: 1,
+            gid_index: 0,
+            path_mtu: PathMtu::Mtu4096,
+            pool_slots: 256,
+            slot_size: 64 * 1024, // 64 KB = max RTPS datagram
+            recv_depth: 32,
+            send_depth: 64,
+        }
+    }
+}
+
+impl RdmaConfig {
+    /// Create a new RDMA config with RDMA enabled.
+    pub fn enabled() -> Self {
+        Self {
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    /// Builder: select a verbs device by name
+    pub fn with_device(mut self, name: impl Into<String>) -> Self {
+        self.device_name = Some(name.into());
+        self
+    }
+
+    /// Builder: set GID index
+    pub fn with_gid_index(mut self, index: u8) -> Self {
+        self.gid_index = index;
+        self
+    }
+
+    /// Builder: size the registered buffer pool
+    pub fn with_pool(mut self, slots: usize, slot_size: usize) -> Self {
+        self.pool_slots = slots;
+        self.slot_size = slot_size;
+        self
+    }
+
+    /// Builder: set per-connection queue depths
+    pub fn with_queue_depths(mut self, recv_depth: u32, send_depth: u32) -> Self {
+        self.recv_depth = recv_depth;
+        self.send_depth = send_depth;
+        self
+    }
+
+    /// Total registered memory in bytes.
+    pub fn pool_bytes(&self) -> usize {
+        self.pool_slots.saturating_mul(self.slot_size)
+    }
+
+    /// Validate configuration, returning error message if invalid.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if self.port_num == 0 {
+            return Err("port_num is 1-based");
+        }
+        if self.slot_size < 64 {
+            return Err("slot_size must be >= 64 bytes");
+        }
+        if self.slot_size > u32::MAX as usize {
+            return Err("slot_size must fit in a u32 scatter/gather length");
+        }
+        if self.recv_depth == 0 || self.send_depth == 0 {
+            return Err("recv_depth and send_depth must be > 0");
+        }
+        if self.pool_slots > u32::MAX as usize {
+            return Err("pool_slots too large");
+        }
+        if self.pool_slots < (self.recv_depth + self.send_depth) as usize {
+            return Err("pool_slots must cover recv_depth + send_depth of one connection");
+        }
+        if self.pool_bytes() > 4 * 1024 * 1024 * 1024 {
+            return Err("registered pool too large (> 4 GB)");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config() {
+        let config = RdmaConfig::default();
+        assert!(!config.enabled);
+        assert_eq!(config.pool_bytes(), 16 * 1024 * 1024);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_undersized_pool() {
+        let config = RdmaConfig::enabled()
+            .with_pool(16, 4096)
+            .with_queue_depths(32, 64);
+        assert!(config.validate().is_err());
+
+        let config = RdmaConfig::enabled().with_pool(256, 16);
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_path_mtu_verbs_values() {
+        assert_eq!(PathMtu::Mtu256.to_verbs(), 1);
+        assert_eq!(PathMtu::Mtu4096.to_verbs(), 5);
+    }
+}
@@ -0,0 +1,241 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! In-process software emulation of an RDMA device.
+//!
+//! Queue pairs created from the same [`LoopbackDevice`] (or its clones) can
+//! connect to each other and exchange SEND/RECV messages with RC semantics:
+//! ordered, reliable, one message per posted receive buffer. A SEND that
+//! finds no posted receive is held until one is posted, mirroring the
+//! infinite RNR retry the verbs backend configures.
+//!
+//! Used by unit tests and for developing against the transport without an
+//! RDMA NIC.
+
+use std::collections::{HashMap, VecDeque};
+use std::io;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+
+use super::backend::{
+    CompletionKind, MemoryKeys, MemoryRegion, QpInfo, QueuePair, RdmaDevice, Sge, WorkCompletion,
+};
+use super::{lock, RdmaConfig};
+
+/// `IBV_WC_LOC_LEN_ERR`: receive buffer too small.
+const WC_LOC_LEN_ERR: u32 = 1;
+/// `IBV_WC_RETRY_EXC_ERR`: remote queue pair unreachable.
+const WC_RETRY_EXC_ERR: u32 = 12;
+
+#[derive(Default)]
+struct QpState {
+    recv_queue: VecDeque<(u64, Sge)>,
+    pending_in: VecDeque<Vec<u8>>,
+    completions: VecDeque<WorkCompletion>,
+}
+
+impl QpState {
+    /// Place `data` into the next posted receive buffer, or hold it.
+    fn deliver(&mut self, data: Vec<u8>) {
+        let Some((wr_id, sge)) = self.recv_queue.pop_front() else {
+            self.pending_in.push_back(data);
+            return;
+        };
+        let status = if data.len() > sge.length as usize {
+            WC_LOC_LEN_ERR
+        } else {
+            // SAFETY: post_recv's contract guarantees `sge` is valid,
+            // writable and exclusively ours until this completion is polled.
+            unsafe {
+                std::ptr::copy_nonoverlapping(data.as_ptr(), sge.addr as *mut u8, data.len());
+            }
+            0
+        };
+        self.completions.push_back(WorkCompletion {
+            wr_id,
+            kind: CompletionKind::Recv,
+            byte_len: data.len() as u32,
+            status,
+        });
+    }
+}
+
+#[derive(Default)]
+struct Fabric {
+    qps: Mutex<HashMap<u32, Arc<Mutex<QpState>>>>,
+    next_qpn: AtomicU32,
+    next_key: AtomicU32,
+}
+
+/// Software RDMA device; clones share the same fabric.
+#[derive(Clone, Default)]
+pub struct LoopbackDevice {
+    fabric: Arc<Fabric>,
+}
+
+impl LoopbackDevice {
+    /// Create a device with its own, empty fabric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+struct LoopbackRegion {
+    keys: MemoryKeys,
+}
+
+impl MemoryRegion for LoopbackRegion {
+    fn keys(&self) -> MemoryKeys {
+        self.keys
+    }
+}
+
+impl RdmaDevice for LoopbackDevice {
+    fn name(&self) -> &str {
+        "loopback"
+    }
+
+    unsafe fn register(&self, _addr: *mut u8, _len: usize) -> io::Result<Box<dyn MemoryRegion>> {
+        let key = self.fabric.next_key.fetch_add(1, Ordering::Relaxed) + 1;
+        Ok(Box::new(LoopbackRegion {
+            keys: MemoryKeys {
+                lkey: key,
+                rkey: key,
+            },
+        }))
+    }
+
+    fn create_qp(&self, _config: &RdmaConfig) -> io::Result<Box<dyn QueuePair>> {
+        let qp_num = self.fabric.next_qpn.fetch_add(1, Ordering::Relaxed) + 1;
+        let state = Arc::new(Mutex::new(QpState::default()));
+        lock(&self.fabric.qps).insert(qp_num, Arc::clone(&state));
+        Ok(Box::new(LoopbackQp {
+            fabric: Arc::clone(&self.fabric),
+            qp_num,
+            remote: None,
+            state,
+        }))
+    }
+}
+
+struct LoopbackQp {
+    fabric: Arc<Fabric>,
+    qp_num: u32,
+    remote: Option<u32>,
+    state: Arc<Mutex<QpState>>,
+}
+
+impl QueuePair for LoopbackQp {
+    fn local_info(&self) -> QpInfo {
+        QpInfo {
+            qp_num: self.qp_num,
+            lid: 0,
+            gid: [0; 16],
+            psn: 0,
+        }
+    }
+
+    fn connect(&mut self, remote: &QpInfo) -> io::Result<()> {
+        if !lock(&self.fabric.qps).contains_key(&remote.qp_num) {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("loopback QP {} does not exist", remote.qp_num),
+            ));
+        }
+        self.remote = Some(remote.qp_num);
+        Ok(())
+    }
+
+    unsafe fn post_recv(&mut self, wr_id: u64, sge: Sge) -> io::Result<()> {
+        let mut state = lock(&self.state);
+        state.recv_queue.push_back((wr_id, sge));
+        if let Some(data) = state.pending_in.pop_front() {
+            state.deliver(data);
+        }
+        Ok(())
+    }
+
+    unsafe fn post_send(&mut self, wr_id: u64, sge: Sge) -> io::Result<()> {
+        let remote_qpn = self
+            .remote
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotConnected, "QP not connected"))?;
+        // SAFETY: post_send's contract guarantees `sge` is valid and readable.
+        let data =
+            unsafe { std::slice::from_raw_parts(sge.addr as *const u8, sge.length as usize) }
+                .to_vec();
+
+        let remote = lock(&self.fabric.qps).get(&remote_qpn).cloned();
+        let status = match remote {
+            Some(remote) => {
+                lock(&remote).deliver(data);
+                0
+            }
+            None => WC_RETRY_EXC_ERR,
+        };
+        lock(&self.state).completions.push_back(WorkCompletion {
+            wr_id,
+            kind: CompletionKind::Send,
+            byte_len: 0,
+            status,
+        });
+        Ok(())
+    }
+
+    fn poll_cq(&mut self, out: &mut Vec<WorkCompletion>, max: usize) -> io::Result<()> {
+        let mut state = lock(&self.state);
+        let n = max.min(state.completions.len());
+        out.extend(state.completions.drain(..n));
+        Ok(())
+    }
+}
+
+impl Drop for LoopbackQp {
+    fn drop(&mut self) {
+        lock(&self.fabric.qps).remove(&self.qp_num);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_send_waits_for_posted_receive() {
+        let device = LoopbackDevice::new();
+        let config = RdmaConfig::enabled();
+        let mut a = device.create_qp(&config).unwrap();
+        let mut b = device.create_qp(&config).unwrap();
+        a.connect(&b.local_info()).unwrap();
+        b.connect(&a.local_info()).unwrap();
+
+        let payload = b"hello".to_vec();
+        let mut rx = vec![0u8; 16];
+        let send_sge = Sge {
+            addr: payload.as_ptr() as u64,
+            length: payload.len() as u32,
+            lkey: 0,
+        };
+        let recv_sge = Sge {
+            addr: rx.as_mut_ptr() as u64,
+            length: rx.len() as u32,
+            lkey: 0,
+        };
+
+        let mut wcs = Vec::new();
+        unsafe { a.post_send(1, send_sge).unwrap() };
+        b.poll_cq(&mut wcs, 8).unwrap();
+        assert!(wcs.is_empty(), "no receive posted yet");
+
+        unsafe { b.post_recv(2, recv_sge).unwrap() };
+        b.poll_cq(&mut wcs, 8).unwrap();
+        assert_eq!(wcs.len(), 1);
+        assert_eq!(wcs[0].kind, CompletionKind::Recv);
+        assert_eq!(wcs[0].byte_len, 5);
+        assert_eq!(&rx[..5], b"hello");
+
+        wcs.clear();
+        a.poll_cq(&mut wcs, 8).unwrap();
+        assert_eq!(wcs[0].kind, CompletionKind::Send);
+        assert!(wcs[0].is_success());
+    }
+}
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! RDMA (verbs) transport for HPC clusters (experimental).
+//!
+//! Carries RTPS messages over InfiniBand or RoCE using reliable-connected
+//! (RC) queue pairs and two-sided SEND/RECV. Every buffer handed to the NIC
+//! comes from a single [`RegisteredPool`], registered once at startup, so
+//! the data path never registers memory.
+//!
+//! libibverbs is loaded at runtime: building with the `rdma` feature adds no
+//! link-time dependency, and hosts without rdma-core simply fail to open a
+//! device with `ErrorKind::Unsupported`.
+//!
+//! # Interoperability
+//!
+//! RTPS over RDMA is **HDDS <-> HDDS** only. Queue pair addressing
+//! ([`QpInfo`]) must be exchanged out-of-band before peers can talk.
+//!
+//! # Modules
+//!
+//! - `config` - Configuration types (`RdmaConfig`, `PathMtu`)
+//! - `backend` - Device abstraction (`RdmaDevice`, `QueuePair`)
+//! - `pool` - Registered buffer pool (`RegisteredPool`)
+//! - `loopback` - Software device for tests (`LoopbackDevice`)
+//! - `verbs` - libibverbs device (`VerbsDevice`, Linux only)
+//! - `transport` - Connection management and data path (`RdmaTransport`)
+
+pub mod backend;
+pub mod config;
+pub mod loopback;
+pub mod pool;
+pub mod transport;
+#[cfg(target_os = "linux")]
+pub mod verbs;
+
+// ============================================================================
+// Re-exports
+// ============================================================================
+
+pub use backend::{
+    CompletionKind, MemoryKeys, MemoryRegion, QpInfo, QueuePair, RdmaDevice, Sge, WorkCompletion,
+};
+pub use config::{PathMtu, RdmaConfig};
+pub use loopback::LoopbackDevice;
+pub use pool::RegisteredPool;
+pub use transport::{RdmaTransport, RdmaTransportEvent, RdmaTransportStats};
+#[cfg(target_os = "linux")]
+pub use verbs::VerbsDevice;
+
+/// Lock a mutex, recovering the data if a previous holder panicked.
+fn lock<T>(mutex: &std::sync::Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
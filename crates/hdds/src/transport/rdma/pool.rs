@@ -0,0 +1,221 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Registered buffer pool for RDMA send/receive.
+//!
+//! Same design as the multicast `RxPool`: fixed-size slots handed out by id
+//! through a lock-free freelist, with an exhaustion counter for diagnostics.
+//! The difference is that all slots live in one page-aligned allocation
+//! registered once with the device, so posting a buffer never requires a
+//! registration (which costs a syscall and pins pages).
+
+use crossbeam::queue::ArrayQueue;
+use std::alloc::{self, Layout};
+use std::io;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::backend::{MemoryRegion, RdmaDevice, Sge};
+
+const PAGE_SIZE: usize = 4096;
+
+/// Pool of registered, fixed-size buffers.
+///
+/// Slots are identified by a `u32` id. A slot is owned by whoever acquired
+/// it until it is released; the pool itself never touches slot contents.
+pub struct RegisteredPool {
+    base: *mut u8,
+    layout: Layout,
+    slot_size: usize,
+    slots: usize,
+    freelist: ArrayQueue<u32>,
+    region: Option<Box<dyn MemoryRegion>>,
+    exhausted_count: AtomicU64,
+}
+
+// SAFETY: `base` is an owned heap allocation; slot access is coordinated by
+// the freelist (one owner per slot), so sharing the pool is sound.
+unsafe impl Send for RegisteredPool {}
+// SAFETY: see above.
+unsafe impl Sync for RegisteredPool {}
+
+impl RegisteredPool {
+    /// Allocate `slots` x `slot_size` bytes and register them with `device`.
+    pub fn new(device: &dyn RdmaDevice, slots: usize, slot_size: usize) -> io::Result<Self> {
+        if slots == 0 || slot_size == 0 || slots > u32::MAX as usize {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "RDMA pool needs 1..=u32::MAX slots of non-zero size",
+            ));
+        }
+        let bytes = slots
+            .checked_mul(slot_size)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "RDMA pool too large"))?;
+        let layout = Layout::from_size_align(bytes, PAGE_SIZE)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e.to_string()))?;
+
+        // SAFETY: layout has non-zero size.
+        let base = unsafe { alloc::alloc_zeroed(layout) };
+        if base.is_null() {
+            return Err(io::Error::new(
+                io::ErrorKind::OutOfMemory,
+                "RDMA pool allocation failed",
+            ));
+        }
+
+        let freelist = ArrayQueue::new(slots);
+        for id in 0..slots as u32 {
+            // Capacity matches the loop count, push cannot fail.
+            let _ = freelist.push(id);
+        }
+
+        let mut pool = Self {
+            base,
+            layout,
+            slot_size,
+            slots,
+            freelist,
+            region: None,
+            exhausted_count: AtomicU64::new(0),
+        };
+        // SAFETY: the allocation lives as long as the pool, and the region
+        // is dropped (deregistered) before it is freed (see Drop).
+        pool.region = Some(unsafe { device.register(base, bytes)? });
+        log::debug!(
+            "[rdma] Registered pool {} x {} bytes on {}",
+            slots,
+            slot_size,
+            device.name()
+        );
+        Ok(pool)
+    }
+
+    /// Total number of slots.
+    pub fn capacity(&self) -> usize {
+        self.slots
+    }
+
+    /// Size of one slot in bytes.
+    pub fn slot_size(&self) -> usize {
+        self.slot_size
+    }
+
+    /// Number of free slots.
+    pub fn available(&self) -> usize {
+        self.freelist.len()
+    }
+
+    /// Number of times `acquire` found the pool empty.
+    pub fn exhausted_count(&self) -> u64 {
+        self.exhausted_count.load(Ordering::Relaxed)
+    }
+
+    /// Acquire a free slot.
+    pub fn acquire(&self) -> Option<u32> {
+        match self.freelist.pop() {
+            Some(id) => Some(id),
+            None => {
+                self.exhausted_count.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    /// Return a slot to the pool.
+    pub fn release(&self, id: u32) {
+        debug_assert!((id as usize) < self.slots, "slot id out of range");
+        if self.freelist.push(id).is_err() {
+            log::debug!("[rdma] Double release of pool slot {}", id);
+        }
+    }
+
+    /// Scatter/gather entry covering the first `len` bytes of a slot.
+    pub fn sge(&self, id: u32, len: usize) -> Sge {
+        debug_assert!(len <= self.slot_size);
+        Sge {
+            addr: self.slot_ptr(id) as u64,
+            length: len.min(self.slot_size) as u32,
+            lkey: self.region.as_ref().map_or(0, |r| r.keys().lkey),
+        }
+    }
+
+    /// Copy `data` into a slot the caller owns.
+    pub fn write(&self, id: u32, data: &[u8]) -> io::Result<()> {
+        if data.len() > self.slot_size {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "message of {} bytes exceeds RDMA slot size {}",
+                    data.len(),
+                    self.slot_size
+                ),
+            ));
+        }
+        // SAFETY: the slot is in bounds and owned by the caller, so no one
+        // else reads or writes it concurrently.
+        unsafe {
+            std::ptr::copy_nonoverlapping(data.as_ptr(), self.slot_ptr(id), data.len());
+        }
+        Ok(())
+    }
+
+    /// Copy the first `len` bytes of a slot the caller owns.
+    pub fn read(&self, id: u32, len: usize) -> Vec<u8> {
+        let len = len.min(self.slot_size);
+        // SAFETY: in-bounds read of a slot owned by the caller.
+        unsafe { std::slice::from_raw_parts(self.slot_ptr(id), len).to_vec() }
+    }
+
+    fn slot_ptr(&self, id: u32) -> *mut u8 {
+        assert!((id as usize) < self.slots, "slot id out of range");
+        // SAFETY: id < slots, so the offset stays inside the allocation.
+        unsafe { self.base.add(id as usize * self.slot_size) }
+    }
+}
+
+impl Drop for RegisteredPool {
+    fn drop(&mut self) {
+        // Deregister before freeing the memory.
+        self.region = None;
+        // SAFETY: base was allocated with this layout in new().
+        unsafe { alloc::dealloc(self.base, self.layout) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::rdma::LoopbackDevice;
+
+    #[test]
+    fn test_acquire_release_and_exhaustion() {
+        let device = LoopbackDevice::new();
+        let pool = RegisteredPool::new(&device, 2, 128).unwrap();
+        assert_eq!(pool.capacity(), 2);
+
+        let a = pool.acquire().unwrap();
+        let b = pool.acquire().unwrap();
+        assert_ne!(a, b);
+        assert!(pool.acquire().is_none());
+        assert_eq!(pool.exhausted_count(), 1);
+
+        pool.release(a);
+        assert_eq!(pool.available(), 1);
+        pool.release(b);
+        assert_eq!(pool.available(), 2);
+    }
+
+    #[test]
+    fn test_slot_io_and_sge() {
+        let device = LoopbackDevice::new();
+        let pool = RegisteredPool::new(&device, 4, 256).unwrap();
+        let id = pool.acquire().unwrap();
+
+        pool.write(id, b"RTPS").unwrap();
+        assert_eq!(pool.read(id, 4), b"RTPS");
+        assert!(pool.write(id, &[0u8; 257]).is_err());
+
+        let sge = pool.sge(id, 4);
+        assert_eq!(sge.length, 4);
+        assert_eq!(sge.addr % 256, 0);
+    }
+}
@@ -0,0 +1,519 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! RDMA transport: one RC queue pair per remote participant.
+//!
+//! Connection setup is two-step because RC queue pairs must know each
+//! other's addressing before they can talk:
+//!
+//! 1. [`RdmaTransport::prepare`] creates the local queue pair, posts its
+//!    receive buffers and returns the [`QpInfo`] to advertise.
+//! 2. [`RdmaTransport::connect`] takes the remote [`QpInfo`] and moves the
+//!    queue pair to RTS.
+//!
+//! How the `QpInfo` travels (discovery property, TCP side channel, ...) is
+//! up to the caller. All buffers come from one [`RegisteredPool`] shared by
+//! every connection.
+
+use std::collections::HashMap;
+use std::io;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use super::backend::{CompletionKind, QpInfo, QueuePair, RdmaDevice, WorkCompletion};
+use super::{lock, RdmaConfig, RegisteredPool};
+
+/// Set on the work request id of receives; the low 32 bits are the slot id.
+const RECV_WR_FLAG: u64 = 1 << 63;
+
+/// Upper bound on completions drained per connection per poll call.
+const MAX_COMPLETIONS_PER_POLL: usize = 256;
+
+// ============================================================================
+// Transport Events
+// ============================================================================
+
+/// Events emitted by the RDMA transport.
+#[derive(Debug)]
+pub enum RdmaTransportEvent {
+    /// Received an RTPS message.
+    MessageReceived {
+        /// Remote participant GUID prefix.
+        from: [u8; 12],
+        /// Message payload.
+        payload: Vec<u8>,
+    },
+    /// A connection failed and was torn down.
+    Disconnected {
+        /// Remote participant GUID prefix.
+        remote_guid: [u8; 12],
+        /// Failure description.
+        reason: String,
+    },
+}
+
+/// RDMA transport counters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RdmaTransportStats {
+    /// Messages sent (completed successfully).
+    pub messages_sent: u64,
+    /// Messages received.
+    pub messages_received: u64,
+    /// Sends rejected or completed with an error.
+    pub send_errors: u64,
+    /// Sends or reposts that found the registered pool empty.
+    pub pool_exhausted: u64,
+}
+
+// ============================================================================
+// RdmaTransport
+// ============================================================================
+
+struct Connection {
+    qp: Box<dyn QueuePair>,
+    connected: bool,
+    /// Slots currently posted as receive buffers.
+    recv_slots: Vec<u32>,
+    /// Slots owned by in-flight sends.
+    send_slots: Vec<u32>,
+}
+
+/// RDMA transport over reliable-connected queue pairs.
+pub struct RdmaTransport {
+    device: Arc<dyn RdmaDevice>,
+    config: RdmaConfig,
+    pool: RegisteredPool,
+    connections: Mutex<HashMap<[u8; 12], Connection>>,
+    messages_sent: AtomicU64,
+    messages_received: AtomicU64,
+    send_errors: AtomicU64,
+}
+
+impl RdmaTransport {
+    /// Create a transport on `device`, registering its buffer pool.
+    pub fn new(device: Arc<dyn RdmaDevice>, config: RdmaConfig) -> io::Result<Self> {
+        config
+            .validate()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        let pool = RegisteredPool::new(device.as_ref(), config.pool_slots, config.slot_size)?;
+        log::debug!(
+            "[rdma] Transport ready on {} ({} bytes registered)",
+            device.name(),
+            config.pool_bytes()
+        );
+        Ok(Self {
+            device,
+            config,
+            pool,
+            connections: Mutex::new(HashMap::new()),
+            messages_sent: AtomicU64::new(0),
+            messages_received: AtomicU64::new(0),
+            send_errors: AtomicU64::new(0),
+        })
+    }
+
+    /// Open the configured verbs device and create a transport on it.
+    ///
+    /// Fails with `ErrorKind::Unsupported` when libibverbs is not installed.
+    #[cfg(target_os = "linux")]
+    pub fn open(config: RdmaConfig) -> io::Result<Self> {
+        let device = super::VerbsDevice::open(&config)?;
+        Self::new(Arc::new(device), config)
+    }
+
+    /// Transport configuration.
+    pub fn config(&self) -> &RdmaConfig {
+        &self.config
+    }
+
+    /// Name of the underlying device.
+    pub fn device_name(&self) -> &str {
+        self.device.name()
+    }
+
+    /// The registered buffer pool.
+    pub fn pool(&self) -> &RegisteredPool {
+        &self.pool
+    }
+
+    /// Create (or return) the local queue pair for `remote_guid`.
+    ///
+    /// Receive buffers are posted before returning, so the remote side may
+    /// start sending as soon as it has connected.
+    pub fn prepare(&self, remote_guid: [u8; 12]) -> io::Result<QpInfo> {
+        let mut conns = lock(&self.connections);
+        if let Some(conn) = conns.get(&remote_guid) {
+            return Ok(conn.qp.local_info());
+        }
+
+        let mut conn = Connection {
+            qp: self.device.create_qp(&self.config)?,
+            connected: false,
+            recv_slots: Vec::with_capacity(self.config.recv_depth as usize),
+            send_slots: Vec::new(),
+        };
+        for _ in 0..self.config.recv_depth {
+            if let Err(e) = self.post_recv(&mut conn) {
+                self.release_all(&mut conn);
+                return Err(e);
+            }
+        }
+        let info = conn.qp.local_info();
+        conns.insert(remote_guid, conn);
+        Ok(info)
+    }
+
+    /// Connect the queue pair for `remote_guid` to the remote `info`.
+    ///
+    /// Calls [`prepare`](Self::prepare) first if needed.
+    pub fn connect(&self, remote_guid: [u8; 12], info: &QpInfo) -> io::Result<()> {
+        self.prepare(remote_guid)?;
+        let mut conns = lock(&self.connections);
+        let conn = conns
+            .get_mut(&remote_guid)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "connection vanished"))?;
+        conn.qp.connect(info)?;
+        conn.connected = true;
+        log::debug!(
+            "[rdma] Connected to {:02x?} (qpn={})",
+            &remote_guid[..4],
+            info.qp_num
+        );
+        Ok(())
+    }
+
+    /// Tear down the connection to `remote_guid`, if any.
+    pub fn disconnect(&self, remote_guid: &[u8; 12]) -> bool {
+        let conn = lock(&self.connections).remove(remote_guid);
+        match conn {
+            Some(mut conn) => {
+                self.release_all(&mut conn);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// True if a connected queue pair exists for `remote_guid`.
+    pub fn has_connection(&self, remote_guid: &[u8; 12]) -> bool {
+        lock(&self.connections)
+            .get(remote_guid)
+            .is_some_and(|c| c.connected)
+    }
+
+    /// Number of connected peers.
+    pub fn connection_count(&self) -> usize {
+        lock(&self.connections)
+            .values()
+            .filter(|c| c.connected)
+            .count()
+    }
+
+    /// Send one RTPS message to `remote_guid`.
+    ///
+    /// The payload is copied into a registered slot; the slot returns to the
+    /// pool once the send completion is seen by [`poll`](Self::poll).
+    pub fn send(&self, remote_guid: &[u8; 12], payload: &[u8]) -> io::Result<()> {
+        let result = self.try_send(remote_guid, payload);
+        if result.is_err() {
+            self.send_errors.fetch_add(1, Ordering::Relaxed);
+        }
+        result
+    }
+
+    fn try_send(&self, remote_guid: &[u8; 12], payload: &[u8]) -> io::Result<()> {
+        if payload.len() > self.pool.slot_size() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "message of {} bytes exceeds RDMA slot size {}",
+                    payload.len(),
+                    self.pool.slot_size()
+                ),
+            ));
+        }
+
+        let mut conns = lock(&self.connections);
+        let conn = conns
+            .get_mut(remote_guid)
+            .filter(|c| c.connected)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotConnected, "no RDMA connection"))?;
+        if conn.send_slots.len() >= self.config.send_depth as usize {
+            return Err(io::Error::new(
+                io::ErrorKind::WouldBlock,
+                "RDMA send queue full",
+            ));
+        }
+
+        let slot = self
+            .pool
+            .acquire()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::WouldBlock, "RDMA pool exhausted"))?;
+        self.pool.write(slot, payload)?;
+        let sge = self.pool.sge(slot, payload.len());
+        // SAFETY: the slot belongs to the pool's registered region and stays
+        // owned by this connection until its send completion is polled.
+        match unsafe { conn.qp.post_send(u64::from(slot), sge) } {
+            Ok(()) => {
+                conn.send_slots.push(slot);
+                Ok(())
+            }
+            Err(e) => {
+                self.pool.release(slot);
+                Err(e)
+            }
+        }
+    }
+
+    /// Drain completions from every connection.
+    ///
+    /// Received buffers are copied out and reposted immediately. A failed
+    /// completion tears its connection down and yields `Disconnected`.
+    pub fn poll(&self) -> Vec<RdmaTransportEvent> {
+        let mut events = Vec::new();
+        let mut failed = Vec::new();
+        let mut wcs = Vec::new();
+
+        let mut conns = lock(&self.connections);
+        for (guid, conn) in conns.iter_mut() {
+            wcs.clear();
+            if let Err(e) = conn.qp.poll_cq(&mut wcs, MAX_COMPLETIONS_PER_POLL) {
+                failed.push((*guid, format!("poll_cq: {}", e)));
+                continue;
+            }
+            for wc in &wcs {
+                if let Err(reason) = self.handle_completion(guid, conn, wc, &mut events) {
+                    failed.push((*guid, reason));
+                    break;
+                }
+            }
+        }
+
+        for (guid, reason) in failed {
+            if let Some(mut conn) = conns.remove(&guid) {
+                self.release_all(&mut conn);
+            }
+            log::debug!("[rdma] Dropped connection {:02x?}: {}", &guid[..4], reason);
+            events.push(RdmaTransportEvent::Disconnected {
+                remote_guid: guid,
+                reason,
+            });
+        }
+        events
+    }
+
+    fn handle_completion(
+        &self,
+        guid: &[u8; 12],
+        conn: &mut Connection,
+        wc: &WorkCompletion,
+        events: &mut Vec<RdmaTransportEvent>,
+    ) -> Result<(), String> {
+        let slot = (wc.wr_id & u64::from(u32::MAX)) as u32;
+        match wc.kind {
+            CompletionKind::Send => {
+                if let Some(pos) = conn.send_slots.iter().position(|&s| s == slot) {
+                    conn.send_slots.swap_remove(pos);
+                    self.pool.release(slot);
+                }
+                if !wc.is_success() {
+                    self.send_errors.fetch_add(1, Ordering::Relaxed);
+                    return Err(format!("send completion status {}", wc.status));
+                }
+                self.messages_sent.fetch_add(1, Ordering::Relaxed);
+            }
+            CompletionKind::Recv => {
+                debug_assert!(wc.wr_id & RECV_WR_FLAG != 0);
+                if let Some(pos) = conn.recv_slots.iter().position(|&s| s == slot) {
+                    conn.recv_slots.swap_remove(pos);
+                }
+                if !wc.is_success() {
+                    self.pool.release(slot);
+                    return Err(format!("receive completion status {}", wc.status));
+                }
+                let payload = self.pool.read(slot, wc.byte_len as usize);
+                self.pool.release(slot);
+                self.messages_received.fetch_add(1, Ordering::Relaxed);
+                events.push(RdmaTransportEvent::MessageReceived {
+                    from: *guid,
+                    payload,
+                });
+                // Keep the receive queue full; if the pool is exhausted the
+                // queue runs shallower until sends complete.
+                if let Err(e) = self.post_recv(conn) {
+                    log::debug!("[rdma] Receive repost failed: {}", e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn post_recv(&self, conn: &mut Connection) -> io::Result<()> {
+        let slot = self
+            .pool
+            .acquire()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::WouldBlock, "RDMA pool exhausted"))?;
+        let sge = self.pool.sge(slot, self.pool.slot_size());
+        // SAFETY: the slot is registered and owned by this connection until
+        // its receive completion is polled or the queue pair is destroyed.
+        match unsafe { conn.qp.post_recv(RECV_WR_FLAG | u64::from(slot), sge) } {
+            Ok(()) => {
+                conn.recv_slots.push(slot);
+                Ok(())
+            }
+            Err(e) => {
+                self.pool.release(slot);
+                Err(e)
+            }
+        }
+    }
+
+    /// Return every slot a connection holds. The queue pair is destroyed
+    /// first so the device can no longer touch them.
+    fn release_all(&self, conn: &mut Connection) {
+        let qp = std::mem::replace(&mut conn.qp, Box::new(DeadQp));
+        drop(qp);
+        for slot in conn.recv_slots.drain(..).chain(conn.send_slots.drain(..)) {
+            self.pool.release(slot);
+        }
+    }
+
+    /// Get a snapshot of transport counters.
+    pub fn stats(&self) -> RdmaTransportStats {
+        RdmaTransportStats {
+            messages_sent: self.messages_sent.load(Ordering::Relaxed),
+            messages_received: self.messages_received.load(Ordering::Relaxed),
+            send_errors: self.send_errors.load(Ordering::Relaxed),
+            pool_exhausted: self.pool.exhausted_count(),
+        }
+    }
+}
+
+impl Drop for RdmaTransport {
+    fn drop(&mut self) {
+        // Destroy queue pairs before the pool deregisters their buffers.
+        let conns = std::mem::take(&mut *lock(&self.connections));
+        for (_, mut conn) in conns {
+            self.release_all(&mut conn);
+        }
+    }
+}
+
+impl std::fmt::Debug for RdmaTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RdmaTransport")
+            .field("device", &self.device.name())
+            .field("connections", &self.connection_count())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Placeholder left behind once a connection's queue pair is destroyed.
+struct DeadQp;
+
+impl QueuePair for DeadQp {
+    fn local_info(&self) -> QpInfo {
+        QpInfo::default()
+    }
+
+    fn connect(&mut self, _remote: &QpInfo) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::NotConnected, "QP destroyed"))
+    }
+
+    unsafe fn post_recv(&mut self, _wr_id: u64, _sge: super::backend::Sge) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::NotConnected, "QP destroyed"))
+    }
+
+    unsafe fn post_send(&mut self, _wr_id: u64, _sge: super::backend::Sge) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::NotConnected, "QP destroyed"))
+    }
+
+    fn poll_cq(&mut self, _out: &mut Vec<WorkCompletion>, _max: usize) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::rdma::LoopbackDevice;
+
+    const GUID_A: [u8; 12] = [0xA; 12];
+    const GUID_B: [u8; 12] = [0xB; 12];
+
+    fn config() -> RdmaConfig {
+        RdmaConfig::enabled()
+            .with_pool(64, 1024)
+            .with_queue_depths(8, 8)
+    }
+
+    fn pair(device: &LoopbackDevice) -> (RdmaTransport, RdmaTransport) {
+        let a = RdmaTransport::new(Arc::new(device.clone()), config()).unwrap();
+        let b = RdmaTransport::new(Arc::new(device.clone()), config()).unwrap();
+        let info_a = a.prepare(GUID_B).unwrap();
+        let info_b = b.prepare(GUID_A).unwrap();
+        a.connect(GUID_B, &info_b).unwrap();
+        b.connect(GUID_A, &info_a).unwrap();
+        (a, b)
+    }
+
+    #[test]
+    fn test_send_and_receive() {
+        let device = LoopbackDevice::new();
+        let (a, b) = pair(&device);
+        assert!(a.has_connection(&GUID_B));
+        assert_eq!(b.pool().available(), 64 - 8);
+
+        for i in 0..20u8 {
+            a.send(&GUID_B, &[b'R', b'T', b'P', b'S', i]).unwrap();
+            let events = b.poll();
+            assert_eq!(events.len(), 1);
+            match &events[0] {
+                RdmaTransportEvent::MessageReceived { from, payload } => {
+                    assert_eq!(*from, GUID_A);
+                    assert_eq!(payload, &[b'R', b'T', b'P', b'S', i]);
+                }
+                other => panic!("unexpected event {:?}", other),
+            }
+            assert!(a.poll().is_empty());
+        }
+
+        // Receive slots are reposted, send slots returned.
+        assert_eq!(b.pool().available(), 64 - 8);
+        assert_eq!(a.pool().available(), 64 - 8);
+        assert_eq!(a.stats().messages_sent, 20);
+        assert_eq!(b.stats().messages_received, 20);
+    }
+
+    #[test]
+    fn test_send_rejects_oversized_and_unconnected() {
+        let device = LoopbackDevice::new();
+        let (a, _b) = pair(&device);
+        assert!(a.send(&GUID_B, &[0u8; 1025]).is_err());
+        assert_eq!(
+            a.send(&[0xC; 12], b"x").unwrap_err().kind(),
+            io::ErrorKind::NotConnected
+        );
+        assert_eq!(a.stats().send_errors, 2);
+    }
+
+    #[test]
+    fn test_peer_loss_reports_disconnect() {
+        let device = LoopbackDevice::new();
+        let (a, b) = pair(&device);
+        drop(b);
+
+        a.send(&GUID_B, b"lost").unwrap();
+        let events = a.poll();
+        assert!(matches!(
+            events.as_slice(),
+            [RdmaTransportEvent::Disconnected { remote_guid, .. }] if *remote_guid == GUID_B
+        ));
+        assert_eq!(a.connection_count(), 0);
+        assert_eq!(a.pool().available(), 64);
+    }
+}
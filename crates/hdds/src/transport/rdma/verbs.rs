@@ -0,0 +1,830 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! libibverbs backend.
+//!
+//! The library is loaded with `dlopen` when a device is opened, so HDDS
+//! builds and runs on hosts without rdma-core installed; only opening a
+//! device fails there. Struct layouts below mirror rdma-core's
+//! `<infiniband/verbs.h>` (stable ABI since rdma-core v13). Only the leading
+//! fields the transport touches are declared for provider-owned structs.
+//!
+//! `ibv_post_send`, `ibv_post_recv` and `ibv_poll_cq` are static inline
+//! functions in the header that dispatch through `context->ops`; they are
+//! called the same way here.
+
+use std::ffi::{c_char, c_int, c_uint, c_void, CStr, CString};
+use std::io;
+use std::ptr;
+use std::sync::Arc;
+
+use super::backend::{
+    CompletionKind, MemoryKeys, MemoryRegion, QpInfo, QueuePair, RdmaDevice, Sge, WorkCompletion,
+};
+use super::RdmaConfig;
+
+const LIBIBVERBS: &str = "libibverbs.so.1";
+
+// enum ibv_access_flags
+const IBV_ACCESS_LOCAL_WRITE: c_int = 1;
+const IBV_ACCESS_REMOTE_WRITE: c_int = 1 << 1;
+const IBV_ACCESS_REMOTE_READ: c_int = 1 << 2;
+
+// enum ibv_qp_type / ibv_qp_state
+const IBV_QPT_RC: u32 = 2;
+const IBV_QPS_INIT: u32 = 1;
+const IBV_QPS_RTR: u32 = 2;
+const IBV_QPS_RTS: u32 = 3;
+
+// enum ibv_qp_attr_mask
+const IBV_QP_STATE: c_int = 1;
+const IBV_QP_ACCESS_FLAGS: c_int = 1 << 3;
+const IBV_QP_PKEY_INDEX: c_int = 1 << 4;
+const IBV_QP_PORT: c_int = 1 << 5;
+const IBV_QP_AV: c_int = 1 << 7;
+const IBV_QP_PATH_MTU: c_int = 1 << 8;
+const IBV_QP_TIMEOUT: c_int = 1 << 9;
+const IBV_QP_RETRY_CNT: c_int = 1 << 10;
+const IBV_QP_RNR_RETRY: c_int = 1 << 11;
+const IBV_QP_RQ_PSN: c_int = 1 << 12;
+const IBV_QP_MAX_QP_RD_ATOMIC: c_int = 1 << 13;
+const IBV_QP_MIN_RNR_TIMER: c_int = 1 << 15;
+const IBV_QP_SQ_PSN: c_int = 1 << 16;
+const IBV_QP_MAX_DEST_RD_ATOMIC: c_int = 1 << 17;
+const IBV_QP_DEST_QPN: c_int = 1 << 20;
+
+// enum ibv_wr_opcode / ibv_send_flags / ibv_wc_opcode
+const IBV_WR_SEND: u32 = 2;
+const IBV_SEND_SIGNALED: c_uint = 1 << 1;
+const IBV_WC_RECV: u32 = 1 << 7;
+
+// Indexes into struct ibv_context_ops
+const OPS_POLL_CQ: usize = 11;
+const OPS_POST_SEND: usize = 25;
+const OPS_POST_RECV: usize = 26;
+const OPS_LEN: usize = 32;
+
+// ============================================================================
+// FFI structs
+// ============================================================================
+
+#[repr(C)]
+struct IbvContext {
+    device: *mut c_void,
+    ops: [*const c_void; OPS_LEN],
+}
+
+#[repr(C)]
+struct IbvCq {
+    context: *mut IbvContext,
+}
+
+#[repr(C)]
+struct IbvQp {
+    context: *mut IbvContext,
+    qp_context: *mut c_void,
+    pd: *mut c_void,
+    send_cq: *mut c_void,
+    recv_cq: *mut c_void,
+    srq: *mut c_void,
+    handle: u32,
+    qp_num: u32,
+}
+
+#[repr(C)]
+struct IbvMr {
+    context: *mut c_void,
+    pd: *mut c_void,
+    addr: *mut c_void,
+    length: usize,
+    handle: u32,
+    lkey: u32,
+    rkey: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+struct IbvQpCap {
+    max_send_wr: u32,
+    max_recv_wr: u32,
+    max_send_sge: u32,
+    max_recv_sge: u32,
+    max_inline_data: u32,
+}
+
+#[repr(C)]
+struct IbvQpInitAttr {
+    qp_context: *mut c_void,
+    send_cq: *mut IbvCq,
+    recv_cq: *mut IbvCq,
+    srq: *mut c_void,
+    cap: IbvQpCap,
+    qp_type: u32,
+    sq_sig_all: c_int,
+}
+
+/// `union ibv_gid` (8-byte aligned through its `global` member).
+#[repr(C, align(8))]
+#[derive(Clone, Copy, Default)]
+struct IbvGid {
+    raw: [u8; 16],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+struct IbvGlobalRoute {
+    dgid: IbvGid,
+    flow_label: u32,
+    sgid_index: u8,
+    hop_limit: u8,
+    traffic_class: u8,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+struct IbvAhAttr {
+    grh: IbvGlobalRoute,
+    dlid: u16,
+    sl: u8,
+    src_path_bits: u8,
+    static_rate: u8,
+    is_global: u8,
+    port_num: u8,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+struct IbvQpAttr {
+    qp_state: u32,
+    cur_qp_state: u32,
+    path_mtu: u32,
+    path_mig_state: u32,
+    qkey: u32,
+    rq_psn: u32,
+    sq_psn: u32,
+    dest_qp_num: u32,
+    qp_access_flags: c_uint,
+    cap: IbvQpCap,
+    ah_attr: IbvAhAttr,
+    alt_ah_attr: IbvAhAttr,
+    pkey_index: u16,
+    alt_pkey_index: u16,
+    en_sqd_async_notify: u8,
+    sq_draining: u8,
+    max_rd_atomic: u8,
+    max_dest_rd_atomic: u8,
+    min_rnr_timer: u8,
+    port_num: u8,
+    timeout: u8,
+    retry_cnt: u8,
+    rnr_retry: u8,
+    alt_port_num: u8,
+    alt_timeout: u8,
+    rate_limit: u32,
+}
+
+/// `struct ibv_port_attr`, padded generously: newer rdma-core appends fields.
+#[repr(C)]
+struct IbvPortAttr {
+    state: u32,
+    max_mtu: u32,
+    active_mtu: u32,
+    gid_tbl_len: c_int,
+    port_cap_flags: u32,
+    max_msg_sz: u32,
+    bad_pkey_cntr: u32,
+    qkey_viol_cntr: u32,
+    pkey_tbl_len: u16,
+    lid: u16,
+    _tail: [u8; 64],
+}
+
+#[repr(C)]
+struct IbvSge {
+    addr: u64,
+    length: u32,
+    lkey: u32,
+}
+
+#[repr(C)]
+struct IbvRecvWr {
+    wr_id: u64,
+    next: *mut IbvRecvWr,
+    sg_list: *mut IbvSge,
+    num_sge: c_int,
+}
+
+/// `struct ibv_send_wr`; the trailing unions are only sized, not used by SEND.
+#[repr(C)]
+struct IbvSendWr {
+    wr_id: u64,
+    next: *mut IbvSendWr,
+    sg_list: *mut IbvSge,
+    num_sge: c_int,
+    opcode: u32,
+    send_flags: c_uint,
+    imm_data: u32,
+    wr: [u64; 4],
+    qp_type: [u64; 1],
+    tail: [u64; 6],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+struct IbvWc {
+    wr_id: u64,
+    status: u32,
+    opcode: u32,
+    vendor_err: u32,
+    byte_len: u32,
+    imm_data: u32,
+    qp_num: u32,
+    src_qp: u32,
+    wc_flags: c_uint,
+    pkey_index: u16,
+    slid: u16,
+    sl: u8,
+    dlid_path_bits: u8,
+}
+
+type PollCqFn = unsafe extern "C" fn(*mut IbvCq, c_int, *mut IbvWc) -> c_int;
+type PostSendFn = unsafe extern "C" fn(*mut IbvQp, *mut IbvSendWr, *mut *mut IbvSendWr) -> c_int;
+type PostRecvFn = unsafe extern "C" fn(*mut IbvQp, *mut IbvRecvWr, *mut *mut IbvRecvWr) -> c_int;
+
+// ============================================================================
+// Library loading
+// ============================================================================
+
+struct Lib {
+    handle: *mut c_void,
+    get_device_list: unsafe extern "C" fn(*mut c_int) -> *mut *mut c_void,
+    free_device_list: unsafe extern "C" fn(*mut *mut c_void),
+    get_device_name: unsafe extern "C" fn(*mut c_void) -> *const c_char,
+    open_device: unsafe extern "C" fn(*mut c_void) -> *mut IbvContext,
+    close_device: unsafe extern "C" fn(*mut IbvContext) -> c_int,
+    alloc_pd: unsafe extern "C" fn(*mut IbvContext) -> *mut c_void,
+    dealloc_pd: unsafe extern "C" fn(*mut c_void) -> c_int,
+    reg_mr: unsafe extern "C" fn(*mut c_void, *mut c_void, usize, c_int) -> *mut IbvMr,
+    dereg_mr: unsafe extern "C" fn(*mut IbvMr) -> c_int,
+    create_cq:
+        unsafe extern "C" fn(*mut IbvContext, c_int, *mut c_void, *mut c_void, c_int) -> *mut IbvCq,
+    destroy_cq: unsafe extern "C" fn(*mut IbvCq) -> c_int,
+    create_qp: unsafe extern "C" fn(*mut c_void, *mut IbvQpInitAttr) -> *mut IbvQp,
+    destroy_qp: unsafe extern "C" fn(*mut IbvQp) -> c_int,
+    modify_qp: unsafe extern "C" fn(*mut IbvQp, *mut IbvQpAttr, c_int) -> c_int,
+    query_port: unsafe extern "C" fn(*mut IbvContext, u8, *mut IbvPortAttr) -> c_int,
+    query_gid: unsafe extern "C" fn(*mut IbvContext, u8, c_int, *mut IbvGid) -> c_int,
+}
+
+impl Lib {
+    fn load() -> io::Result<Self> {
+        let name = CString::new(LIBIBVERBS).map_err(io::Error::other)?;
+        // SAFETY: name is a valid C string.
+        let handle = unsafe { libc::dlopen(name.as_ptr(), libc::RTLD_NOW | libc::RTLD_LOCAL) };
+        if handle.is_null() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("{} not found (install rdma-core)", LIBIBVERBS),
+            ));
+        }
+
+        macro_rules! sym {
+            ($name:literal) => {{
+                // SAFETY: handle is a live dlopen handle and the literal is NUL-terminated.
+                let ptr = unsafe { libc::dlsym(handle, concat!($name, "\0").as_ptr().cast()) };
+                if ptr.is_null() {
+                    // SAFETY: handle came from dlopen above.
+                    unsafe { libc::dlclose(handle) };
+                    return Err(io::Error::new(
+                        io::ErrorKind::Unsupported,
+                        concat!("libibverbs is missing ", $name),
+                    ));
+                }
+                // SAFETY: the symbol has the C signature declared in `Lib`.
+                unsafe { fn_from_ptr(ptr) }
+            }};
+        }
+
+        Ok(Self {
+            handle,
+            get_device_list: sym!("ibv_get_device_list"),
+            free_device_list: sym!("ibv_free_device_list"),
+            get_device_name: sym!("ibv_get_device_name"),
+            open_device: sym!("ibv_open_device"),
+            close_device: sym!("ibv_close_device"),
+            alloc_pd: sym!("ibv_alloc_pd"),
+            dealloc_pd: sym!("ibv_dealloc_pd"),
+            reg_mr: sym!("ibv_reg_mr"),
+            dereg_mr: sym!("ibv_dereg_mr"),
+            create_cq: sym!("ibv_create_cq"),
+            destroy_cq: sym!("ibv_destroy_cq"),
+            create_qp: sym!("ibv_create_qp"),
+            destroy_qp: sym!("ibv_destroy_qp"),
+            modify_qp: sym!("ibv_modify_qp"),
+            query_port: sym!("ibv_query_port"),
+            query_gid: sym!("ibv_query_gid"),
+        })
+    }
+}
+
+/// Reinterpret a symbol address as the function pointer type `T`.
+///
+/// # Safety
+/// `T` must be an `extern "C"` function pointer matching the symbol.
+unsafe fn fn_from_ptr<T: Copy>(ptr: *mut c_void) -> T {
+    debug_assert_eq!(std::mem::size_of::<T>(), std::mem::size_of::<*mut c_void>());
+    // SAFETY: upheld by the caller.
+    unsafe { std::mem::transmute_copy(&ptr) }
+}
+
+impl Drop for Lib {
+    fn drop(&mut self) {
+        // SAFETY: handle came from dlopen and every object using it is gone.
+        unsafe { libc::dlclose(self.handle) };
+    }
+}
+
+fn verbs_error(what: &str) -> io::Error {
+    let os = io::Error::last_os_error();
+    io::Error::new(os.kind(), format!("{} failed: {}", what, os))
+}
+
+// ============================================================================
+// VerbsDevice
+// ============================================================================
+
+struct DeviceInner {
+    lib: Lib,
+    context: *mut IbvContext,
+    pd: *mut c_void,
+    name: String,
+    port_num: u8,
+    gid_index: u8,
+    lid: u16,
+    gid: [u8; 16],
+}
+
+// SAFETY: verbs objects are thread-safe per the libibverbs documentation.
+unsafe impl Send for DeviceInner {}
+// SAFETY: see above.
+unsafe impl Sync for DeviceInner {}
+
+impl Drop for DeviceInner {
+    fn drop(&mut self) {
+        // SAFETY: pd/context were created by this device; QPs, CQs and MRs
+        // hold an Arc to us and are already gone.
+        unsafe {
+            (self.lib.dealloc_pd)(self.pd);
+            (self.lib.close_device)(self.context);
+        }
+    }
+}
+
+/// An opened verbs device port with a protection domain.
+#[derive(Clone)]
+pub struct VerbsDevice {
+    inner: Arc<DeviceInner>,
+}
+
+impl VerbsDevice {
+    /// Load libibverbs and open the configured device and port.
+    pub fn open(config: &RdmaConfig) -> io::Result<Self> {
+        let lib = Lib::load()?;
+
+        // SAFETY: plain libibverbs calls with valid arguments; the device
+        // list is freed on every path once the device is opened.
+        unsafe {
+            let mut count: c_int = 0;
+            let list = (lib.get_device_list)(&mut count);
+            if list.is_null() || count <= 0 {
+                if !list.is_null() {
+                    (lib.free_device_list)(list);
+                }
+                return Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    "no RDMA devices found",
+                ));
+            }
+
+            let mut chosen: Option<(*mut c_void, String)> = None;
+            for i in 0..count as usize {
+                let dev = *list.add(i);
+                let name = CStr::from_ptr((lib.get_device_name)(dev))
+                    .to_string_lossy()
+                    .into_owned();
+                if config
+                    .device_name
+                    .as_deref()
+                    .is_none_or(|want| want == name)
+                {
+                    chosen = Some((dev, name));
+                    break;
+                }
+            }
+            let Some((dev, name)) = chosen else {
+                (lib.free_device_list)(list);
+                return Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("RDMA device {:?} not found", config.device_name),
+                ));
+            };
+
+            let context = (lib.open_device)(dev);
+            (lib.free_device_list)(list);
+            if context.is_null() {
+                return Err(verbs_error("ibv_open_device"));
+            }
+
+            let pd = (lib.alloc_pd)(context);
+            if pd.is_null() {
+                (lib.close_device)(context);
+                return Err(verbs_error("ibv_alloc_pd"));
+            }
+
+            let mut inner = DeviceInner {
+                lib,
+                context,
+                pd,
+                name,
+                port_num: config.port_num,
+                gid_index: config.gid_index,
+                lid: 0,
+                gid: [0; 16],
+            };
+
+            let mut port: IbvPortAttr = std::mem::zeroed();
+            if (inner.lib.query_port)(context, config.port_num, &mut port) != 0 {
+                return Err(verbs_error("ibv_query_port"));
+            }
+            inner.lid = port.lid;
+
+            let mut gid = IbvGid::default();
+            if (inner.lib.query_gid)(
+                context,
+                config.port_num,
+                c_int::from(config.gid_index),
+                &mut gid,
+            ) != 0
+            {
+                return Err(verbs_error("ibv_query_gid"));
+            }
+            inner.gid = gid.raw;
+
+            log::info!(
+                "[rdma] Opened {} port {} (lid={}, gid_index={})",
+                inner.name,
+                inner.port_num,
+                inner.lid,
+                inner.gid_index
+            );
+            Ok(Self {
+                inner: Arc::new(inner),
+            })
+        }
+    }
+}
+
+struct VerbsRegion {
+    device: Arc<DeviceInner>,
+    mr: *mut IbvMr,
+}
+
+// SAFETY: an ibv_mr is immutable after registration.
+unsafe impl Send for VerbsRegion {}
+// SAFETY: see above.
+unsafe impl Sync for VerbsRegion {}
+
+impl MemoryRegion for VerbsRegion {
+    fn keys(&self) -> MemoryKeys {
+        // SAFETY: mr is valid until drop.
+        let mr = unsafe { &*self.mr };
+        MemoryKeys {
+            lkey: mr.lkey,
+            rkey: mr.rkey,
+        }
+    }
+}
+
+impl Drop for VerbsRegion {
+    fn drop(&mut self) {
+        // SAFETY: mr came from ibv_reg_mr on this device.
+        unsafe { (self.device.lib.dereg_mr)(self.mr) };
+    }
+}
+
+impl RdmaDevice for VerbsDevice {
+    fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    unsafe fn register(&self, addr: *mut u8, len: usize) -> io::Result<Box<dyn MemoryRegion>> {
+        let access = IBV_ACCESS_LOCAL_WRITE | IBV_ACCESS_REMOTE_WRITE | IBV_ACCESS_REMOTE_READ;
+        // SAFETY: caller guarantees the memory outlives the region.
+        let mr = unsafe { (self.inner.lib.reg_mr)(self.inner.pd, addr.cast(), len, access) };
+        if mr.is_null() {
+            return Err(verbs_error("ibv_reg_mr"));
+        }
+        Ok(Box::new(VerbsRegion {
+            device: Arc::clone(&self.inner),
+            mr,
+        }))
+    }
+
+    fn create_qp(&self, config: &RdmaConfig) -> io::Result<Box<dyn QueuePair>> {
+        let dev = &self.inner;
+        let cqe = (config.recv_depth + config.send_depth) as c_int;
+
+        // SAFETY: plain libibverbs calls on objects owned by this device.
+        unsafe {
+            let cq = (dev.lib.create_cq)(dev.context, cqe, ptr::null_mut(), ptr::null_mut(), 0);
+            if cq.is_null() {
+                return Err(verbs_error("ibv_create_cq"));
+            }
+
+            let mut init = IbvQpInitAttr {
+                qp_context: ptr::null_mut(),
+                send_cq: cq,
+                recv_cq: cq,
+                srq: ptr::null_mut(),
+                cap: IbvQpCap {
+                    max_send_wr: config.send_depth,
+                    max_recv_wr: config.recv_depth,
+                    max_send_sge: 1,
+                    max_recv_sge: 1,
+                    max_inline_data: 0,
+                },
+                qp_type: IBV_QPT_RC,
+                sq_sig_all: 0,
+            };
+            let qp = (dev.lib.create_qp)(dev.pd, &mut init);
+            if qp.is_null() {
+                (dev.lib.destroy_cq)(cq);
+                return Err(verbs_error("ibv_create_qp"));
+            }
+
+            let mut qp = VerbsQp {
+                device: Arc::clone(dev),
+                qp,
+                cq,
+                path_mtu: config.path_mtu.to_verbs(),
+                psn: initial_psn(),
+            };
+
+            let mut attr = IbvQpAttr {
+                qp_state: IBV_QPS_INIT,
+                port_num: dev.port_num,
+                qp_access_flags: (IBV_ACCESS_LOCAL_WRITE
+                    | IBV_ACCESS_REMOTE_WRITE
+                    | IBV_ACCESS_REMOTE_READ) as c_uint,
+                ..Default::default()
+            };
+            qp.modify(
+                &mut attr,
+                IBV_QP_STATE | IBV_QP_PKEY_INDEX | IBV_QP_PORT | IBV_QP_ACCESS_FLAGS,
+                "INIT",
+            )?;
+            Ok(Box::new(qp))
+        }
+    }
+}
+
+/// Random 24-bit initial packet sequence number.
+fn initial_psn() -> u32 {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    (nanos ^ std::process::id().rotate_left(8)) & 0x00FF_FFFF
+}
+
+// ============================================================================
+// VerbsQp
+// ============================================================================
+
+struct VerbsQp {
+    device: Arc<DeviceInner>,
+    qp: *mut IbvQp,
+    cq: *mut IbvCq,
+    path_mtu: u32,
+    psn: u32,
+}
+
+// SAFETY: a QP is used by one owner at a time (QueuePair takes &mut self).
+unsafe impl Send for VerbsQp {}
+
+impl VerbsQp {
+    fn modify(&mut self, attr: &mut IbvQpAttr, mask: c_int, state: &str) -> io::Result<()> {
+        // SAFETY: qp is valid and attr is a fully initialized ibv_qp_attr.
+        let rc = unsafe { (self.device.lib.modify_qp)(self.qp, attr, mask) };
+        if rc != 0 {
+            return Err(io::Error::other(format!(
+                "ibv_modify_qp({}) failed: {}",
+                state,
+                io::Error::from_raw_os_error(rc)
+            )));
+        }
+        Ok(())
+    }
+
+    fn op<T: Copy>(&self, index: usize) -> io::Result<T> {
+        // SAFETY: qp->context is valid; the ops table is part of it.
+        let ptr = unsafe { (*(*self.qp).context).ops[index] };
+        if ptr.is_null() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "verbs provider lacks data-path op",
+            ));
+        }
+        // SAFETY: T is the matching function pointer type for `index`.
+        Ok(unsafe { fn_from_ptr(ptr.cast_mut()) })
+    }
+}
+
+impl QueuePair for VerbsQp {
+    fn local_info(&self) -> QpInfo {
+        QpInfo {
+            // SAFETY: qp is valid until drop.
+            qp_num: unsafe { (*self.qp).qp_num },
+            lid: self.device.lid,
+            gid: self.device.gid,
+            psn: self.psn,
+        }
+    }
+
+    fn connect(&mut self, remote: &QpInfo) -> io::Result<()> {
+        let is_global = remote.gid != [0; 16];
+        let mut rtr = IbvQpAttr {
+            qp_state: IBV_QPS_RTR,
+            path_mtu: self.path_mtu,
+            dest_qp_num: remote.qp_num,
+            rq_psn: remote.psn,
+            max_dest_rd_atomic: 1,
+            min_rnr_timer: 12,
+            ah_attr: IbvAhAttr {
+                grh: IbvGlobalRoute {
+                    dgid: IbvGid { raw: remote.gid },
+                    sgid_index: self.device.gid_index,
+                    hop_limit: 1,
+                    ..Default::default()
+                },
+                dlid: remote.lid,
+                is_global: u8::from(is_global),
+                port_num: self.device.port_num,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        self.modify(
+            &mut rtr,
+            IBV_QP_STATE
+                | IBV_QP_AV
+                | IBV_QP_PATH_MTU
+                | IBV_QP_DEST_QPN
+                | IBV_QP_RQ_PSN
+                | IBV_QP_MAX_DEST_RD_ATOMIC
+                | IBV_QP_MIN_RNR_TIMER,
+            "RTR",
+        )?;
+
+        let mut rts = IbvQpAttr {
+            qp_state: IBV_QPS_RTS,
+            timeout: 14,
+            retry_cnt: 7,
+            rnr_retry: 7, // infinite RNR retry
+            sq_psn: self.psn,
+            max_rd_atomic: 1,
+            ..Default::default()
+        };
+        self.modify(
+            &mut rts,
+            IBV_QP_STATE
+                | IBV_QP_TIMEOUT
+                | IBV_QP_RETRY_CNT
+                | IBV_QP_RNR_RETRY
+                | IBV_QP_SQ_PSN
+                | IBV_QP_MAX_QP_RD_ATOMIC,
+            "RTS",
+        )
+    }
+
+    unsafe fn post_recv(&mut self, wr_id: u64, sge: Sge) -> io::Result<()> {
+        let post: PostRecvFn = self.op(OPS_POST_RECV)?;
+        let mut sg = IbvSge {
+            addr: sge.addr,
+            length: sge.length,
+            lkey: sge.lkey,
+        };
+        let mut wr = IbvRecvWr {
+            wr_id,
+            next: ptr::null_mut(),
+            sg_list: &mut sg,
+            num_sge: 1,
+        };
+        let mut bad = ptr::null_mut();
+        // SAFETY: wr/sg are valid for the call; buffer validity is the caller's contract.
+        let rc = unsafe { post(self.qp, &mut wr, &mut bad) };
+        if rc != 0 {
+            return Err(io::Error::from_raw_os_error(rc));
+        }
+        Ok(())
+    }
+
+    unsafe fn post_send(&mut self, wr_id: u64, sge: Sge) -> io::Result<()> {
+        let post: PostSendFn = self.op(OPS_POST_SEND)?;
+        let mut sg = IbvSge {
+            addr: sge.addr,
+            length: sge.length,
+            lkey: sge.lkey,
+        };
+        let mut wr = IbvSendWr {
+            wr_id,
+            next: ptr::null_mut(),
+            sg_list: &mut sg,
+            num_sge: 1,
+            opcode: IBV_WR_SEND,
+            send_flags: IBV_SEND_SIGNALED,
+            imm_data: 0,
+            wr: [0; 4],
+            qp_type: [0; 1],
+            tail: [0; 6],
+        };
+        let mut bad = ptr::null_mut();
+        // SAFETY: wr/sg are valid for the call; buffer validity is the caller's contract.
+        let rc = unsafe { post(self.qp, &mut wr, &mut bad) };
+        if rc != 0 {
+            return Err(io::Error::from_raw_os_error(rc));
+        }
+        Ok(())
+    }
+
+    fn poll_cq(&mut self, out: &mut Vec<WorkCompletion>, max: usize) -> io::Result<()> {
+        let poll: PollCqFn = self.op(OPS_POLL_CQ)?;
+        let mut wcs = [IbvWc::default(); 16];
+        let mut remaining = max;
+        while remaining > 0 {
+            let n = remaining.min(wcs.len()) as c_int;
+            // SAFETY: cq is valid and wcs holds at least n entries.
+            let got = unsafe { poll(self.cq, n, wcs.as_mut_ptr()) };
+            if got < 0 {
+                return Err(verbs_error("ibv_poll_cq"));
+            }
+            for wc in &wcs[..got as usize] {
+                out.push(WorkCompletion {
+                    wr_id: wc.wr_id,
+                    kind: if wc.opcode & IBV_WC_RECV != 0 {
+                        CompletionKind::Recv
+                    } else {
+                        CompletionKind::Send
+                    },
+                    byte_len: wc.byte_len,
+                    status: wc.status,
+                });
+            }
+            if got < n {
+                break;
+            }
+            remaining -= got as usize;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for VerbsQp {
+    fn drop(&mut self) {
+        // SAFETY: qp and cq were created by create_qp and are destroyed once.
+        unsafe {
+            (self.device.lib.destroy_qp)(self.qp);
+            (self.device.lib.destroy_cq)(self.cq);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_abi_layouts() {
+        // Offsets from rdma-core <infiniband/verbs.h> on LP64.
+        assert_eq!(std::mem::size_of::<IbvSge>(), 16);
+        assert_eq!(std::mem::size_of::<IbvWc>(), 48);
+        assert_eq!(std::mem::size_of::<IbvAhAttr>(), 32);
+        assert_eq!(std::mem::size_of::<IbvQpAttr>(), 144);
+        assert_eq!(std::mem::offset_of!(IbvQp, qp_num), 52);
+        assert_eq!(std::mem::offset_of!(IbvMr, lkey), 36);
+        assert_eq!(std::mem::offset_of!(IbvSendWr, wr), 40);
+        assert_eq!(std::mem::offset_of!(IbvPortAttr, lid), 34);
+    }
+
+    #[test]
+    fn test_open_without_hardware_fails_cleanly() {
+        // Either the library is missing or no device is present in CI;
+        // both must surface as errors, never as a crash.
+        if let Err(e) = VerbsDevice::open(&RdmaConfig::enabled().with_device("hdds-test-none")) {
+            assert!(matches!(
+                e.kind(),
+                io::ErrorKind::Unsupported | io::ErrorKind::NotFound
+            ));
+        }
+    }
+}
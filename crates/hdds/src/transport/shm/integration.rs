@@ -27,7 +27,7 @@
 //! 3. If both true, attach to SHM segment instead of using UDP
 
 use super::notify::TopicNotify;
-use super::ring::{ShmRingReader, ShmRingWriter};
+use super::ring::{ShmReadLoan, ShmRingReader, ShmRingWriter};
 use super::{segment_name, Result, DEFAULT_RING_CAPACITY};
 use crate::core::discovery::GUID;
 use std::collections::HashMap;
@@ -84,6 +84,36 @@ impl ShmWriterTransport {
         self.ring.push(data)
     }
 
+    /// Loan the next slot for in-place writing (see `ShmRingWriter::loan`).
+    #[inline]
+    pub fn loan(&mut self, len: usize) -> Result<&mut [u8]> {
+        self.ring.loan(len)
+    }
+
+    /// Payload of the pending loan, if any (read-only view).
+    #[inline]
+    pub fn loaned(&self) -> Option<&[u8]> {
+        self.ring.loaned()
+    }
+
+    /// Payload of the pending loan, if any.
+    #[inline]
+    pub fn loaned_mut(&mut self) -> Option<&mut [u8]> {
+        self.ring.loaned_mut()
+    }
+
+    /// Publish the pending loan with its first `len` bytes.
+    #[inline]
+    pub fn commit_loan(&mut self, len: usize) -> bool {
+        self.ring.commit_loan(len)
+    }
+
+    /// Drop the pending loan without publishing it.
+    #[inline]
+    pub fn abort_loan(&mut self) {
+        self.ring.abort_loan();
+    }
+
     /// Get the segment name for this writer
     #[must_use]
     pub fn segment_name(&self) -> String {
@@ -204,6 +234,15 @@ impl ShmReaderTransport {
         None
     }
 
+    /// Borrow the next message from any attached writer without copying.
+    ///
+    /// The writer's slot stays pinned until the loan is dropped.
+    pub fn try_read_loaned(&mut self) -> Option<ShmReadLoan> {
+        self.writers
+            .iter_mut()
+            .find_map(ShmRingReader::try_take_loaned)
+    }
+
     /// Read with blocking wait.
     ///
     /// Waits for data from any attached writer using futex notification.
@@ -26,6 +26,8 @@
 //! - **Lock-free**: Uses atomic operations and futex for synchronization
 //! - **Cache-aligned**: All structures are 64-byte aligned to prevent false sharing
 //! - **Overrun detection**: Readers can detect and recover from being too slow
+//! - **Loans**: Writers can fill a slot in place and readers can borrow it
+//!   without copying (`ShmRingWriter::loan`, `ShmRingReader::try_take_loaned`)
 //!
 //! # Latency Target
 //!
@@ -49,7 +51,7 @@ pub use integration::{
 pub use metrics::{global_metrics, ShmMetrics, ShmMetricsSnapshot};
 pub use notify::{NotifyBucket, TopicNotify};
 pub use policy::{select_transport, ShmPolicy, TransportSelection, TransportSelectionError};
-pub use ring::{ShmRingReader, ShmRingWriter};
+pub use ring::{ShmReadLoan, ShmRingReader, ShmRingWriter};
 pub use segment::{cleanup_domain_segments, cleanup_stale_segments, ShmSegment};
 pub use slot::{ShmControl, ShmSlot, SLOT_PAYLOAD_SIZE};
 
@@ -94,6 +96,9 @@ pub enum ShmError {
 
     /// Invalid ring capacity (must be power of 2)
     InvalidCapacity(usize),
+
+    /// Next slot is still loaned to a reader
+    SlotLoaned,
 }
 
 impl fmt::Display for ShmError {
@@ -115,6 +120,7 @@ impl fmt::Display for ShmError {
             Self::InvalidCapacity(cap) => {
                 write!(f, "Invalid ring capacity: {cap} (must be power of 2)")
             }
+            Self::SlotLoaned => write!(f, "Next ring slot is still loaned to a reader"),
         }
     }
 }
@@ -128,6 +134,19 @@ impl std::error::Error for ShmError {
     }
 }
 
+impl From<ShmError> for crate::dds::Error {
+    fn from(err: ShmError) -> Self {
+        match err {
+            ShmError::SegmentCreate(e) | ShmError::SegmentOpen(e) | ShmError::Mmap(e) => {
+                crate::dds::Error::IoError(e)
+            }
+            ShmError::PayloadTooLarge { .. } => crate::dds::Error::BufferTooSmall,
+            ShmError::SlotLoaned => crate::dds::Error::WouldBlock,
+            other => crate::dds::Error::InvalidState(format!("SHM: {other}")),
+        }
+    }
+}
+
 /// Result type for SHM operations
 pub type Result<T> = std::result::Result<T, ShmError>;
 
@@ -31,13 +31,23 @@
 //! 3. Copy payload
 //! 4. Re-check slot.seq (detect torn read)
 //! 5. Advance local sequence
+//!
+//! # Loans
+//!
+//! `ShmRingWriter::loan` hands out the next slot's payload so the sample
+//! can be serialized straight into shared memory; `commit_loan` then runs
+//! steps 3-5 above. `ShmRingReader::try_take_loaned` pins a committed slot
+//! (see `ShmSlot::try_acquire_loan`) and returns it without copying; the
+//! writer gets `ShmError::SlotLoaned` instead of overwriting a pinned slot.
 
 use super::notify::TopicNotify;
 use super::segment::ShmSegment;
 use super::slot::{ShmControl, ShmSlot, SLOT_PAYLOAD_SIZE};
 use super::{Result, ShmError};
+use std::ops::Deref;
 use std::ptr;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Calculate total segment size for a ring buffer
@@ -63,6 +73,16 @@ pub struct ShmRingWriter {
     topic_notify: Option<TopicNotify>,
     /// Notification bucket index (hash of writer GUID)
     notify_bucket: usize,
+    /// Slot handed out by `loan()` and not yet committed
+    loan: Option<PendingLoan>,
+}
+
+/// Writer-side loan in progress.
+struct PendingLoan {
+    msg_seq: u64,
+    /// Slot marker before the loan, restored on abort
+    prev_seq: u64,
+    len: usize,
 }
 
 impl ShmRingWriter {
@@ -105,6 +125,7 @@ impl ShmRingWriter {
             next_seq: 0,
             topic_notify: None,
             notify_bucket,
+            loan: None,
         })
     }
 
@@ -178,13 +199,16 @@ impl ShmRingWriter {
                 capacity: SLOT_PAYLOAD_SIZE,
             });
         }
+        self.abort_loan();
 
         let msg_seq = self.next_seq;
         let idx = (msg_seq as usize) & self.mask;
         let slot = self.slot(idx);
 
-        // 1. Mark in-progress (odd)
-        slot.seq.store((msg_seq << 1) | 1, Ordering::Relaxed);
+        // 1. Mark in-progress (odd), unless a reader still holds the slot
+        if slot.try_begin_write(msg_seq).is_none() {
+            return Err(ShmError::SlotLoaned);
+        }
 
         // 2. Write payload length
         slot.len.store(data.len() as u32, Ordering::Relaxed);
@@ -205,6 +229,14 @@ impl ShmRingWriter {
             ptr::copy_nonoverlapping(data.as_ptr(), dst, data.len());
         }
 
+        self.publish(msg_seq);
+        Ok(())
+    }
+
+    /// Commit, publish and wake readers for the slot of `msg_seq`.
+    fn publish(&mut self, msg_seq: u64) {
+        let slot = self.slot((msg_seq as usize) & self.mask);
+
         // 4. Commit (even) with Release ordering
         slot.seq.store(msg_seq << 1, Ordering::Release);
 
@@ -217,7 +249,83 @@ impl ShmRingWriter {
         }
 
         self.next_seq = msg_seq + 1;
-        Ok(())
+    }
+
+    /// Loan the next slot's payload for in-place writing.
+    ///
+    /// Returns the first `len` bytes of the slot. Nothing is visible to
+    /// readers until `commit_loan()`; a pending loan is aborted by the next
+    /// `loan()` or `push()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if `len` exceeds the slot size or a reader still holds
+    /// the slot (`ShmError::SlotLoaned`).
+    pub fn loan(&mut self, len: usize) -> Result<&mut [u8]> {
+        if len > SLOT_PAYLOAD_SIZE {
+            return Err(ShmError::PayloadTooLarge {
+                size: len,
+                capacity: SLOT_PAYLOAD_SIZE,
+            });
+        }
+        self.abort_loan();
+
+        let msg_seq = self.next_seq;
+        let prev_seq = self
+            .slot((msg_seq as usize) & self.mask)
+            .try_begin_write(msg_seq)
+            .ok_or(ShmError::SlotLoaned)?;
+        self.loan = Some(PendingLoan {
+            msg_seq,
+            prev_seq,
+            len,
+        });
+        Ok(self.loaned_mut().unwrap_or_default())
+    }
+
+    /// Payload of the pending loan, if any (read-only view).
+    pub fn loaned(&self) -> Option<&[u8]> {
+        let loan = self.loan.as_ref()?;
+        let slot = self.slot((loan.msg_seq as usize) & self.mask);
+        // SAFETY: as in loaned_mut(); the shared borrow of &self excludes
+        // concurrent writes through loaned_mut().
+        Some(unsafe { &(&*slot.payload.get())[..loan.len] })
+    }
+
+    /// Payload of the pending loan, if any.
+    pub fn loaned_mut(&mut self) -> Option<&mut [u8]> {
+        let loan = self.loan.as_ref()?;
+        let len = loan.len;
+        let slot = self.slot((loan.msg_seq as usize) & self.mask);
+        // SAFETY:
+        // - len <= SLOT_PAYLOAD_SIZE was checked in loan()
+        // - The slot is marked in-progress (odd seq) for this loan, so readers
+        //   skip it and no reader holds it (try_begin_write saw loans == 0)
+        // - Single-producer ring: the returned borrow of &mut self prevents
+        //   any other write to the slot while it is alive
+        Some(unsafe { &mut (&mut *slot.payload.get())[..len] })
+    }
+
+    /// Publish the pending loan with its first `len` bytes.
+    ///
+    /// `len` is clamped to the loaned length. Returns `false` if no loan
+    /// was pending.
+    pub fn commit_loan(&mut self, len: usize) -> bool {
+        let Some(loan) = self.loan.take() else {
+            return false;
+        };
+        let slot = self.slot((loan.msg_seq as usize) & self.mask);
+        slot.len.store(len.min(loan.len) as u32, Ordering::Relaxed);
+        self.publish(loan.msg_seq);
+        true
+    }
+
+    /// Drop the pending loan without publishing it.
+    pub fn abort_loan(&mut self) {
+        if let Some(loan) = self.loan.take() {
+            let slot = self.slot((loan.msg_seq as usize) & self.mask);
+            slot.seq.store(loan.prev_seq, Ordering::Release);
+        }
     }
 
     /// Get current sequence number (next to be written)
@@ -265,13 +373,60 @@ impl ReaderMetrics {
     }
 }
 
+/// Payload borrowed in place from a ring slot (see
+/// `ShmRingReader::try_take_loaned`).
+///
+/// The writer cannot reuse the slot until the loan is dropped, so keep it
+/// short: holding it for a full lap of the ring makes writes fail with
+/// `ShmError::SlotLoaned`.
+pub struct ShmReadLoan {
+    /// Keeps the mapping alive for `slot`
+    _segment: Arc<ShmSegment>,
+    slot: *const ShmSlot,
+    len: usize,
+    seq: u64,
+}
+
+// SAFETY: the slot lives in the shared mapping kept alive by `_segment`, and
+// the pinned payload is not written while the loan exists.
+unsafe impl Send for ShmReadLoan {}
+unsafe impl Sync for ShmReadLoan {}
+
+impl ShmReadLoan {
+    /// Ring sequence number of the message
+    #[must_use]
+    pub fn sequence(&self) -> u64 {
+        self.seq
+    }
+}
+
+impl Deref for ShmReadLoan {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        // SAFETY:
+        // - self.slot points into the mapping held by self._segment
+        // - len <= SLOT_PAYLOAD_SIZE was checked in try_take_loaned()
+        // - The slot is pinned (loans > 0), so the writer does not modify the
+        //   payload until this loan is dropped
+        unsafe { &(&*(*self.slot).payload.get())[..self.len] }
+    }
+}
+
+impl Drop for ShmReadLoan {
+    fn drop(&mut self) {
+        // SAFETY: see Deref; the mapping outlives this call.
+        unsafe { (*self.slot).release_loan() };
+    }
+}
+
 /// Shared memory ring buffer reader.
 ///
 /// Attaches to an existing shared memory segment created by `ShmRingWriter`.
 /// Multiple readers can attach to the same segment.
 pub struct ShmRingReader {
-    /// Shared memory segment (opened, not owned)
-    segment: ShmSegment,
+    /// Shared memory segment (opened, not owned; shared with loans)
+    segment: Arc<ShmSegment>,
     /// Ring capacity
     capacity: usize,
     /// Capacity mask
@@ -323,7 +478,7 @@ impl ShmRingReader {
         let start_seq = control.get_head();
 
         Ok(Self {
-            segment,
+            segment: Arc::new(segment),
             capacity,
             mask: capacity - 1,
             next_seq: start_seq,
@@ -410,23 +565,7 @@ impl ShmRingReader {
     ///
     /// Target: < 100 ns per successful pop
     pub fn try_pop(&mut self, buf: &mut [u8]) -> Option<usize> {
-        let head = self.control().get_head();
-
-        // Overrun check: if head has advanced more than capacity, we lost data
-        if head.saturating_sub(self.next_seq) > self.capacity as u64 {
-            // Too slow - jump to latest readable position
-            self.next_seq = head.saturating_sub(1);
-            self.metrics.overruns.fetch_add(1, Ordering::Relaxed);
-            return None;
-        }
-
-        // Nothing to read
-        if self.next_seq >= head {
-            self.metrics.empty_polls.fetch_add(1, Ordering::Relaxed);
-            return None;
-        }
-
-        let expected = self.next_seq;
+        let expected = self.next_readable()?;
         let idx = (expected as usize) & self.mask;
         let slot = self.slot(idx);
         let want = expected << 1; // Expected committed marker
@@ -475,6 +614,62 @@ impl ShmRingReader {
         Some(len)
     }
 
+    /// Try to borrow the next message in place, without copying.
+    ///
+    /// The slot stays pinned until the returned loan is dropped.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(loan)` - Payload of the next message
+    /// * `None` - No data available or slot not ready
+    pub fn try_take_loaned(&mut self) -> Option<ShmReadLoan> {
+        let expected = self.next_readable()?;
+        let slot = self.slot((expected as usize) & self.mask);
+
+        // Pin, then verify the slot still holds `expected`
+        if !slot.try_acquire_loan(expected) {
+            return None;
+        }
+
+        let len = slot.len.load(Ordering::Relaxed) as usize;
+        if len > SLOT_PAYLOAD_SIZE {
+            slot.release_loan();
+            self.metrics.corrupted.fetch_add(1, Ordering::Relaxed);
+            return None;
+        }
+
+        let slot: *const ShmSlot = slot;
+        self.next_seq = expected + 1;
+        self.metrics.messages_read.fetch_add(1, Ordering::Relaxed);
+        Some(ShmReadLoan {
+            _segment: Arc::clone(&self.segment),
+            slot,
+            len,
+            seq: expected,
+        })
+    }
+
+    /// Sequence of the next message to read, handling overrun.
+    fn next_readable(&mut self) -> Option<u64> {
+        let head = self.control().get_head();
+
+        // Overrun check: if head has advanced more than capacity, we lost data
+        if head.saturating_sub(self.next_seq) > self.capacity as u64 {
+            // Too slow - jump to latest readable position
+            self.next_seq = head.saturating_sub(1);
+            self.metrics.overruns.fetch_add(1, Ordering::Relaxed);
+            return None;
+        }
+
+        // Nothing to read
+        if self.next_seq >= head {
+            self.metrics.empty_polls.fetch_add(1, Ordering::Relaxed);
+            return None;
+        }
+
+        Some(self.next_seq)
+    }
+
     /// Read next message, blocking until available.
     ///
     /// Uses double-check pattern to avoid lost wakes:
@@ -721,6 +916,44 @@ mod tests {
         writer.unlink().ok();
     }
 
+    #[test]
+    fn test_loan_roundtrip_without_copy() {
+        let name = unique_name();
+        let mut writer = ShmRingWriter::create(&name, 4, &test_guid()).expect("Failed to create");
+        let bucket = TopicNotify::bucket_for_guid(&test_guid());
+        let mut reader = ShmRingReader::attach_from(&name, 4, bucket, 0).expect("Failed to attach");
+
+        // Aborted loans publish nothing
+        writer.loan(8).expect("loan").fill(0xAA);
+        writer.abort_loan();
+        assert!(!reader.has_data());
+
+        let buf = writer.loan(16).expect("loan");
+        buf[..5].copy_from_slice(b"frame");
+        assert!(writer.commit_loan(5));
+        assert!(!writer.commit_loan(5));
+
+        let loan = reader.try_take_loaned().expect("Should have data");
+        assert_eq!(&*loan, b"frame");
+        assert_eq!(loan.sequence(), 0);
+
+        // The writer laps the ring but cannot reuse the pinned slot
+        for i in 1..4u8 {
+            writer.push(&[i]).expect("Push failed");
+        }
+        assert!(matches!(writer.push(&[4]), Err(ShmError::SlotLoaned)));
+        assert!(matches!(writer.loan(1), Err(ShmError::SlotLoaned)));
+        assert_eq!(&*loan, b"frame");
+
+        drop(loan);
+        writer.push(&[4]).expect("Push failed");
+        let mut buf = [0u8; 8];
+        assert_eq!(reader.try_pop(&mut buf), Some(1));
+        assert_eq!(buf[0], 1);
+
+        writer.unlink().ok();
+    }
+
     #[test]
     fn test_overrun_detection() {
         let name = unique_name();
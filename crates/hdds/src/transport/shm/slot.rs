@@ -40,6 +40,15 @@
 //!
 //! Reader checks: `seq == expected << 1` - if odd or wrong sequence, payload is unsafe.
 //!
+//! # Reader Loans
+//!
+//! A reader can borrow a slot's payload in place instead of copying it
+//! (see `ShmRingReader::try_take_loaned`). While `loans > 0` the writer
+//! refuses to reuse the slot. Both sides use SeqCst so that either the
+//! writer sees the loan, or the reader sees the slot being rewritten:
+//! - Reader: `loans += 1`, then re-check `seq`
+//! - Writer: mark writing (odd `seq`), then check `loans`
+//!
 //! # Sequence Wraparound
 //!
 //! The 64-bit sequence space is practically infinite for messaging:
@@ -61,7 +70,7 @@ pub const SLOT_PAYLOAD_SIZE: usize = 4096;
 /// Offset  Size   Field
 /// 0       8      seq (AtomicU64) - commit marker
 /// 8       4      len (AtomicU32) - payload length
-/// 12      4      _pad
+/// 12      4      loans (AtomicU32) - readers holding the payload
 /// 16      4080   payload (up to SLOT_PAYLOAD_SIZE)
 /// ```
 ///
@@ -78,8 +87,8 @@ pub struct ShmSlot {
     pub seq: AtomicU64,
     /// Payload length in bytes
     pub len: AtomicU32,
-    /// Padding for alignment
-    _pad: u32,
+    /// Number of readers currently borrowing the payload in place
+    pub loans: AtomicU32,
     /// Payload data (UnsafeCell for interior mutability)
     pub payload: UnsafeCell<[u8; SLOT_PAYLOAD_SIZE]>,
 }
@@ -97,7 +106,7 @@ impl ShmSlot {
         Self {
             seq: AtomicU64::new(0),
             len: AtomicU32::new(0),
-            _pad: 0,
+            loans: AtomicU32::new(0),
             payload: UnsafeCell::new([0u8; SLOT_PAYLOAD_SIZE]),
         }
     }
@@ -144,6 +153,41 @@ impl ShmSlot {
         self.seq.store(msg_seq << 1, Ordering::Release);
     }
 
+    /// Claim the slot for writing `msg_seq`.
+    ///
+    /// Returns the previous sequence marker, or `None` (slot untouched) if
+    /// a reader still borrows the payload.
+    #[inline]
+    pub fn try_begin_write(&self, msg_seq: u64) -> Option<u64> {
+        // SeqCst pairs with the loan in try_acquire_loan(): at least one
+        // side observes the other.
+        let prev = self.seq.swap((msg_seq << 1) | 1, Ordering::SeqCst);
+        if self.loans.load(Ordering::SeqCst) > 0 {
+            self.seq.store(prev, Ordering::Release);
+            return None;
+        }
+        Some(prev)
+    }
+
+    /// Borrow the payload of committed message `expected_msg_seq`.
+    ///
+    /// On success the caller must call `release_loan()` when done.
+    #[inline]
+    pub fn try_acquire_loan(&self, expected_msg_seq: u64) -> bool {
+        self.loans.fetch_add(1, Ordering::SeqCst);
+        if self.seq.load(Ordering::SeqCst) == expected_msg_seq << 1 {
+            return true;
+        }
+        self.loans.fetch_sub(1, Ordering::Release);
+        false
+    }
+
+    /// Return a loan taken with `try_acquire_loan()`.
+    #[inline]
+    pub fn release_loan(&self) {
+        self.loans.fetch_sub(1, Ordering::Release);
+    }
+
     /// Get current sequence marker (for corruption detection)
     #[inline]
     pub fn get_seq(&self) -> u64 {
@@ -271,6 +315,25 @@ mod tests {
         assert!(!slot.is_ready(6)); // Wrong sequence
     }
 
+    #[test]
+    fn test_slot_loan_blocks_writer() {
+        let slot = ShmSlot::new();
+        slot.mark_writing(1);
+        slot.commit(1);
+
+        assert!(!slot.try_acquire_loan(2));
+        assert!(slot.try_acquire_loan(1));
+
+        // Writer backs off and leaves the committed message intact.
+        assert_eq!(slot.try_begin_write(5), None);
+        assert!(slot.is_ready(1));
+
+        slot.release_loan();
+        assert_eq!(slot.try_begin_write(5), Some(1 << 1));
+        assert!(slot.is_writing());
+        assert!(!slot.try_acquire_loan(1));
+    }
+
     #[test]
     fn test_control_validation() {
         let ctrl = ShmControl::new(256, 4096);
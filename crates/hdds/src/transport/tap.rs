@@ -0,0 +1,436 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Packet tap: every RTPS message the UDP transport sends or receives.
+//!
+//! A [`PacketTap`] is called with a [`CapturedPacket`] for each datagram
+//! (loopback bus and TCP-relayed traffic included), on the sending or
+//! receiving thread. Install one with
+//! [`ParticipantBuilder::with_packet_tap`](crate::dds::ParticipantBuilder::with_packet_tap),
+//! or set `HDDS_PCAP=<file>` to dump the traffic of every participant of
+//! the process to a `.pcapng` file Wireshark opens directly, no root or
+//! tcpdump needed.
+//!
+//! The pcapng file carries raw IP packets: IPv4/IPv6 and UDP headers are
+//! synthesized from the locators so Wireshark's RTPS dissector picks the
+//! payload up.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::net::{IpAddr, Ipv6Addr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock, Weak};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Environment variable naming the pcapng file traffic is dumped to.
+pub const PCAP_ENV: &str = "HDDS_PCAP";
+
+/// Callback run on every captured datagram.
+///
+/// Runs on transport threads: keep it short and non-blocking.
+pub type PacketTap = Arc<dyn Fn(&CapturedPacket<'_>) + Send + Sync>;
+
+/// Whether a datagram was sent or received.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PacketDirection {
+    /// Sent by this participant.
+    Sent,
+    /// Received by this participant.
+    Received,
+}
+
+/// One datagram seen by the transport.
+#[derive(Debug, Clone, Copy)]
+pub struct CapturedPacket<'a> {
+    /// Sent or received.
+    pub direction: PacketDirection,
+    /// When the datagram was sent or received.
+    pub timestamp: SystemTime,
+    /// Sending socket (`None` when unknown).
+    pub source: Option<SocketAddr>,
+    /// Destination locator (`None` when unknown).
+    pub destination: Option<SocketAddr>,
+    /// RTPS message.
+    pub data: &'a [u8],
+}
+
+/// Single tap running every tap of `taps` (`None` when empty).
+pub(crate) fn combine(mut taps: Vec<PacketTap>) -> Option<PacketTap> {
+    match taps.len() {
+        0 => None,
+        1 => taps.pop(),
+        _ => Some(Arc::new(move |packet: &CapturedPacket<'_>| {
+            for tap in &taps {
+                tap(packet);
+            }
+        })),
+    }
+}
+
+type FileWriter = Mutex<PcapngWriter<BufWriter<File>>>;
+
+/// Writers open in this process, so participants dumping to the same file
+/// append to one capture instead of truncating each other's.
+fn open_files() -> &'static Mutex<HashMap<PathBuf, Weak<FileWriter>>> {
+    static FILES: OnceLock<Mutex<HashMap<PathBuf, Weak<FileWriter>>>> = OnceLock::new();
+    FILES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Tap writing every datagram to the pcapng file at `path`.
+///
+/// The file is created (or truncated) by the first tap on `path` in the
+/// process; later taps on the same path share it. Each packet is flushed
+/// as it is written, so the capture survives a crash.
+pub fn pcapng_file_tap(path: impl AsRef<Path>) -> io::Result<PacketTap> {
+    let path = path.as_ref().to_path_buf();
+    let mut files = open_files()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let writer: Arc<FileWriter> = match files.get(&path).and_then(Weak::upgrade) {
+        Some(writer) => writer,
+        None => {
+            let writer = Arc::new(Mutex::new(PcapngWriter::create(&path)?));
+            files.insert(path.clone(), Arc::downgrade(&writer));
+            writer
+        }
+    };
+    Ok(Arc::new(move |packet: &CapturedPacket<'_>| {
+        let mut writer = writer
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Err(e) = writer.write_packet(packet).and_then(|()| writer.flush()) {
+            log::debug!("[tap] pcapng write to {} failed: {}", path.display(), e);
+        }
+    }))
+}
+
+const BLOCK_SECTION_HEADER: u32 = 0x0A0D_0D0A;
+const BLOCK_INTERFACE_DESCRIPTION: u32 = 0x0000_0001;
+const BLOCK_ENHANCED_PACKET: u32 = 0x0000_0006;
+const BYTE_ORDER_MAGIC: u32 = 0x1A2B_3C4D;
+/// Raw IPv4/IPv6 packets, no link-layer header.
+const LINKTYPE_RAW: u16 = 101;
+const OPT_END: u16 = 0;
+const OPT_SHB_USERAPPL: u16 = 4;
+const OPT_IF_NAME: u16 = 2;
+const OPT_EPB_FLAGS: u16 = 2;
+const EPB_FLAG_INBOUND: u32 = 0b01;
+const EPB_FLAG_OUTBOUND: u32 = 0b10;
+const UDP_PROTOCOL: u8 = 17;
+const DEFAULT_TTL: u8 = 64;
+
+/// pcapng writer for captured packets.
+///
+/// Writes a section header and a single raw-IP interface up front, then
+/// one enhanced packet block per [`write_packet`](Self::write_packet) with
+/// microsecond timestamps and the inbound/outbound direction flag.
+pub struct PcapngWriter<W: Write> {
+    out: W,
+}
+
+impl PcapngWriter<BufWriter<File>> {
+    /// Create (or truncate) the pcapng file at `path`.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?))
+    }
+}
+
+impl<W: Write> PcapngWriter<W> {
+    /// Start a capture on `out` (writes the file header).
+    pub fn new(mut out: W) -> io::Result<Self> {
+        let mut shb = Vec::with_capacity(32);
+        shb.extend_from_slice(&BYTE_ORDER_MAGIC.to_le_bytes());
+        shb.extend_from_slice(&1u16.to_le_bytes()); // major version
+        shb.extend_from_slice(&0u16.to_le_bytes()); // minor version
+        shb.extend_from_slice(&(-1i64).to_le_bytes()); // section length unknown
+        push_option(&mut shb, OPT_SHB_USERAPPL, b"hdds");
+        push_option(&mut shb, OPT_END, &[]);
+        write_block(&mut out, BLOCK_SECTION_HEADER, &shb)?;
+
+        let mut idb = Vec::with_capacity(24);
+        idb.extend_from_slice(&LINKTYPE_RAW.to_le_bytes());
+        idb.extend_from_slice(&0u16.to_le_bytes()); // reserved
+        idb.extend_from_slice(&0u32.to_le_bytes()); // no snapshot limit
+        push_option(&mut idb, OPT_IF_NAME, b"hdds");
+        push_option(&mut idb, OPT_END, &[]);
+        write_block(&mut out, BLOCK_INTERFACE_DESCRIPTION, &idb)?;
+
+        Ok(Self { out })
+    }
+
+    /// Append `packet` as a raw IP/UDP packet.
+    pub fn write_packet(&mut self, packet: &CapturedPacket<'_>) -> io::Result<()> {
+        let frame = ip_udp_frame(packet.source, packet.destination, packet.data);
+        let micros = packet
+            .timestamp
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_micros() as u64)
+            .unwrap_or(0);
+        let flags = match packet.direction {
+            PacketDirection::Sent => EPB_FLAG_OUTBOUND,
+            PacketDirection::Received => EPB_FLAG_INBOUND,
+        };
+
+        let mut epb = Vec::with_capacity(frame.len() + 40);
+        epb.extend_from_slice(&0u32.to_le_bytes()); // interface 0
+        epb.extend_from_slice(&((micros >> 32) as u32).to_le_bytes());
+        epb.extend_from_slice(&(micros as u32).to_le_bytes());
+        epb.extend_from_slice(&(frame.len() as u32).to_le_bytes()); // captured
+        epb.extend_from_slice(&(frame.len() as u32).to_le_bytes()); // original
+        epb.extend_from_slice(&frame);
+        pad4(&mut epb);
+        push_option(&mut epb, OPT_EPB_FLAGS, &flags.to_le_bytes());
+        push_option(&mut epb, OPT_END, &[]);
+        write_block(&mut self.out, BLOCK_ENHANCED_PACKET, &epb)
+    }
+
+    /// Flush buffered packets.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+
+    /// The underlying writer.
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+fn write_block(out: &mut impl Write, block_type: u32, body: &[u8]) -> io::Result<()> {
+    let total = (body.len() + 12) as u32;
+    out.write_all(&block_type.to_le_bytes())?;
+    out.write_all(&total.to_le_bytes())?;
+    out.write_all(body)?;
+    out.write_all(&total.to_le_bytes())
+}
+
+fn push_option(buf: &mut Vec<u8>, code: u16, value: &[u8]) {
+    buf.extend_from_slice(&code.to_le_bytes());
+    buf.extend_from_slice(&(value.len() as u16).to_le_bytes());
+    buf.extend_from_slice(value);
+    pad4(buf);
+}
+
+fn pad4(buf: &mut Vec<u8>) {
+    while !buf.len().is_multiple_of(4) {
+        buf.push(0);
+    }
+}
+
+/// IP + UDP packet carrying `payload` from `source` to `destination`.
+///
+/// IPv4 when both ends are IPv4, IPv6 otherwise (IPv4 ends mapped). An
+/// unknown end is the unspecified address, port 0.
+fn ip_udp_frame(
+    source: Option<SocketAddr>,
+    destination: Option<SocketAddr>,
+    payload: &[u8],
+) -> Vec<u8> {
+    let unspecified = |other: Option<SocketAddr>| match other {
+        Some(SocketAddr::V6(_)) => SocketAddr::from((Ipv6Addr::UNSPECIFIED, 0)),
+        _ => SocketAddr::from(([0, 0, 0, 0], 0)),
+    };
+    let src = source.unwrap_or_else(|| unspecified(destination));
+    let dst = destination.unwrap_or_else(|| unspecified(source));
+    // Lengths are 16-bit on the wire; oversized payloads are cut.
+    let payload = &payload[..payload.len().min(usize::from(u16::MAX) - 48)];
+    let udp_len = (payload.len() + 8) as u16;
+
+    let mut frame = Vec::with_capacity(payload.len() + 48);
+    match (src.ip(), dst.ip()) {
+        (IpAddr::V4(s), IpAddr::V4(d)) => {
+            frame.push(0x45); // IPv4, 20-byte header
+            frame.push(0); // DSCP/ECN
+            frame.extend_from_slice(&(udp_len + 20).to_be_bytes());
+            frame.extend_from_slice(&[0, 0, 0x40, 0]); // id 0, don't fragment
+            frame.push(DEFAULT_TTL);
+            frame.push(UDP_PROTOCOL);
+            frame.extend_from_slice(&[0, 0]); // checksum, filled below
+            frame.extend_from_slice(&s.octets());
+            frame.extend_from_slice(&d.octets());
+            let checksum = internet_checksum(&[&frame]);
+            frame[10..12].copy_from_slice(&checksum.to_be_bytes());
+            push_udp(&mut frame, src.port(), dst.port(), udp_len, payload, None);
+        }
+        (s, d) => {
+            let s = to_v6(s);
+            let d = to_v6(d);
+            frame.extend_from_slice(&[0x60, 0, 0, 0]); // IPv6, no flow label
+            frame.extend_from_slice(&udp_len.to_be_bytes());
+            frame.push(UDP_PROTOCOL);
+            frame.push(DEFAULT_TTL);
+            frame.extend_from_slice(&s.octets());
+            frame.extend_from_slice(&d.octets());
+            // The UDP checksum is mandatory over IPv6.
+            let mut pseudo = Vec::with_capacity(40);
+            pseudo.extend_from_slice(&s.octets());
+            pseudo.extend_from_slice(&d.octets());
+            pseudo.extend_from_slice(&u32::from(udp_len).to_be_bytes());
+            pseudo.extend_from_slice(&[0, 0, 0, UDP_PROTOCOL]);
+            push_udp(
+                &mut frame,
+                src.port(),
+                dst.port(),
+                udp_len,
+                payload,
+                Some(&pseudo),
+            );
+        }
+    }
+    frame
+}
+
+fn push_udp(
+    frame: &mut Vec<u8>,
+    src_port: u16,
+    dst_port: u16,
+    udp_len: u16,
+    payload: &[u8],
+    pseudo_header: Option<&[u8]>,
+) {
+    let mut header = [0u8; 8];
+    header[0..2].copy_from_slice(&src_port.to_be_bytes());
+    header[2..4].copy_from_slice(&dst_port.to_be_bytes());
+    header[4..6].copy_from_slice(&udp_len.to_be_bytes());
+    if let Some(pseudo) = pseudo_header {
+        let checksum = match internet_checksum(&[pseudo, &header, payload]) {
+            0 => 0xFFFF,
+            sum => sum,
+        };
+        header[6..8].copy_from_slice(&checksum.to_be_bytes());
+    }
+    frame.extend_from_slice(&header);
+    frame.extend_from_slice(payload);
+}
+
+fn to_v6(ip: IpAddr) -> Ipv6Addr {
+    match ip {
+        IpAddr::V4(v4) => v4.to_ipv6_mapped(),
+        IpAddr::V6(v6) => v6,
+    }
+}
+
+/// RFC 1071 checksum over the concatenation of `parts`.
+fn internet_checksum(parts: &[&[u8]]) -> u16 {
+    let mut sum = 0u32;
+    let mut pending: Option<u8> = None;
+    for byte in parts.iter().flat_map(|part| part.iter().copied()) {
+        match pending.take() {
+            Some(high) => sum += u32::from(u16::from_be_bytes([high, byte])),
+            None => pending = Some(byte),
+        }
+    }
+    if let Some(high) = pending {
+        sum += u32::from(u16::from_be_bytes([high, 0]));
+    }
+    while sum > 0xFFFF {
+        sum = (sum & 0xFFFF) + (sum >> 16);
+    }
+    !(sum as u16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn packet(direction: PacketDirection, data: &[u8]) -> CapturedPacket<'_> {
+        CapturedPacket {
+            direction,
+            timestamp: UNIX_EPOCH + Duration::from_micros(0x1_0000_0002),
+            source: Some("192.168.1.10:7410".parse().unwrap()),
+            destination: Some("239.255.0.1:7400".parse().unwrap()),
+            data,
+        }
+    }
+
+    fn u32_at(buf: &[u8], at: usize) -> u32 {
+        u32::from_le_bytes(buf[at..at + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn test_pcapng_layout() {
+        let mut writer = PcapngWriter::new(Vec::new()).unwrap();
+        writer
+            .write_packet(&packet(PacketDirection::Received, b"RTPS\x02\x05"))
+            .unwrap();
+        let bytes = writer.into_inner();
+
+        // Section header, then the interface, then the packet.
+        assert_eq!(u32_at(&bytes, 0), BLOCK_SECTION_HEADER);
+        assert_eq!(u32_at(&bytes, 8), BYTE_ORDER_MAGIC);
+        let shb_len = u32_at(&bytes, 4) as usize;
+        assert_eq!(u32_at(&bytes, shb_len - 4) as usize, shb_len);
+
+        assert_eq!(u32_at(&bytes, shb_len), BLOCK_INTERFACE_DESCRIPTION);
+        assert_eq!(
+            u16::from_le_bytes([bytes[shb_len + 8], bytes[shb_len + 9]]),
+            LINKTYPE_RAW
+        );
+        let epb = shb_len + u32_at(&bytes, shb_len + 4) as usize;
+
+        assert_eq!(u32_at(&bytes, epb), BLOCK_ENHANCED_PACKET);
+        assert_eq!(u32_at(&bytes, epb + 12), 1); // timestamp high
+        assert_eq!(u32_at(&bytes, epb + 16), 2); // timestamp low
+        let captured = u32_at(&bytes, epb + 20) as usize;
+        assert_eq!(captured, 20 + 8 + 6);
+        let frame = &bytes[epb + 28..epb + 28 + captured];
+        assert_eq!(frame[0], 0x45);
+        assert_eq!(&frame[12..16], &[192, 168, 1, 10]);
+        assert_eq!(&frame[16..20], &[239, 255, 0, 1]);
+        assert_eq!(internet_checksum(&[&frame[..20]]), 0);
+        assert_eq!(&frame[20..22], &7410u16.to_be_bytes());
+        assert_eq!(&frame[22..24], &7400u16.to_be_bytes());
+        assert_eq!(&frame[28..], b"RTPS\x02\x05");
+
+        // Direction flag, then end of options and the trailing length.
+        let flags_at = epb + 28 + 36;
+        assert_eq!(
+            u16::from_le_bytes([bytes[flags_at], bytes[flags_at + 1]]),
+            OPT_EPB_FLAGS
+        );
+        assert_eq!(u32_at(&bytes, flags_at + 4), EPB_FLAG_INBOUND);
+        assert_eq!(bytes.len(), epb + u32_at(&bytes, epb + 4) as usize);
+    }
+
+    #[test]
+    fn test_ipv6_frame_has_valid_udp_checksum() {
+        let frame = ip_udp_frame(
+            Some("[fe80::1]:7410".parse().unwrap()),
+            Some("10.0.0.2:7411".parse().unwrap()),
+            b"RTPS",
+        );
+        assert_eq!(frame[0] >> 4, 6);
+        assert_eq!(
+            &frame[24..40],
+            &"::ffff:10.0.0.2".parse::<Ipv6Addr>().unwrap().octets()
+        );
+
+        let udp = &frame[40..];
+        let mut pseudo = frame[8..40].to_vec();
+        pseudo.extend_from_slice(&(udp.len() as u32).to_be_bytes());
+        pseudo.extend_from_slice(&[0, 0, 0, UDP_PROTOCOL]);
+        assert_eq!(internet_checksum(&[&pseudo, udp]), 0);
+    }
+
+    #[test]
+    fn test_file_taps_share_one_capture() {
+        let path = std::env::temp_dir().join(format!("hdds_tap_{}.pcapng", std::process::id()));
+        let first = pcapng_file_tap(&path).unwrap();
+        let second = pcapng_file_tap(&path).unwrap();
+        first(&packet(PacketDirection::Sent, b"RTPS-1"));
+        second(&packet(PacketDirection::Received, b"RTPS-2"));
+
+        let bytes = std::fs::read(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        let mut at = 0;
+        let mut packets = 0;
+        while at < bytes.len() {
+            if u32_at(&bytes, at) == BLOCK_ENHANCED_PACKET {
+                packets += 1;
+            }
+            at += u32_at(&bytes, at + 4) as usize;
+        }
+        assert_eq!(packets, 2);
+    }
+}
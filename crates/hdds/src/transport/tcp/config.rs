@@ -6,6 +6,7 @@
 //! Provides configuration types for the TCP transport layer:
 //! - [`TcpConfig`] - Main configuration struct
 //! - [`TcpRole`] - Server/client/auto role selection
+//! - [`TcpFraming`] - Message framing on the stream
 //! - [`TransportPreference`] - Transport selection policy
 //!
 //! # Example
@@ -53,8 +54,13 @@ pub struct TcpConfig {
     pub connect_timeout: Duration,
 
     /// Delay before retry after connection failure
+    ///
+    /// Doubled after each consecutive failure, up to `reconnect_backoff_max`.
     pub reconnect_delay: Duration,
 
+    /// Upper bound for the exponential reconnection backoff
+    pub reconnect_backoff_max: Duration,
+
     /// Maximum number of reconnection attempts (0 = infinite)
     pub max_reconnect_attempts: u32,
 
@@ -62,6 +68,9 @@ pub struct TcpConfig {
     pub role: TcpRole,
 
     // === Framing ===
+    /// Wire framing of RTPS messages on the stream
+    pub framing: TcpFraming,
+
     /// Maximum message size in bytes (anti-OOM protection)
     ///
     /// Messages larger than this will be rejected.
@@ -126,9 +135,11 @@ impl Default for TcpConfig {
 
             connect_timeout: Duration::from_secs(5),
             reconnect_delay: Duration::from_secs(1),
+            reconnect_backoff_max: Duration::from_secs(30),
             max_reconnect_attempts: 10,
             role: TcpRole::Auto,
 
+            framing: TcpFraming::LengthPrefix,
             max_message_size: 16 * 1024 * 1024, // 16 MB
             nodelay: true,                      // Low latency
 
@@ -211,6 +222,23 @@ impl TcpConfig {
         self
     }
 
+    /// Builder: set wire framing
+    ///
+    /// Also caps `max_message_size` to what the framing can carry.
+    pub fn with_framing(mut self, framing: TcpFraming) -> Self {
+        self.framing = framing;
+        self.max_message_size = self.max_message_size.min(framing.max_payload());
+        self
+    }
+
+    /// Builder: set reconnection policy (initial delay, backoff cap, attempts)
+    pub fn with_reconnect(mut self, delay: Duration, max_delay: Duration, attempts: u32) -> Self {
+        self.reconnect_delay = delay;
+        self.reconnect_backoff_max = max_delay;
+        self.max_reconnect_attempts = attempts;
+        self
+    }
+
     /// Builder: set TCP_NODELAY
     pub fn with_nodelay(mut self, nodelay: bool) -> Self {
         self.nodelay = nodelay;
@@ -273,6 +301,12 @@ impl TcpConfig {
         if self.connect_timeout.is_zero() {
             return Err("connect_timeout must be > 0");
         }
+        if self.reconnect_backoff_max < self.reconnect_delay {
+            return Err("reconnect_backoff_max must be >= reconnect_delay");
+        }
+        if self.max_message_size > self.framing.max_payload() {
+            return Err("max_message_size exceeds what the framing can carry");
+        }
         if self.role == TcpRole::ClientOnly && self.initial_peers.is_empty() {
             return Err("ClientOnly role requires initial_peers");
         }
@@ -336,6 +370,47 @@ impl TcpRole {
     }
 }
 
+/// Framing of RTPS messages on a TCP stream.
+///
+/// Both ends of a connection must use the same framing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TcpFraming {
+    /// 4-byte big-endian length prefix (HDDS native).
+    ///
+    /// ```text
+    /// +----------------+--------------+
+    /// | Length (4B BE) | RTPS Message |
+    /// +----------------+--------------+
+    /// ```
+    #[default]
+    LengthPrefix,
+
+    /// RTI Connext TCP transport layout.
+    ///
+    /// A 2-byte message kind (0 for RTPS) and a 2-byte big-endian length
+    /// precede each message. Non-zero kinds are STUN-style control messages
+    /// (20-byte header, length counting the attributes only); they are
+    /// consumed and skipped, so the Connext control handshake itself is not
+    /// performed.
+    ///
+    /// ```text
+    /// +-------------+---------------+--------------+
+    /// | Kind (2B 0) | Length (2B BE)| RTPS Message |
+    /// +-------------+---------------+--------------+
+    /// ```
+    Rti,
+}
+
+impl TcpFraming {
+    /// Largest RTPS message this framing can carry.
+    pub fn max_payload(&self) -> usize {
+        match self {
+            TcpFraming::LengthPrefix => u32::MAX as usize,
+            TcpFraming::Rti => u16::MAX as usize,
+        }
+    }
+}
+
 /// Transport selection preference for the participant.
 ///
 /// Controls which transports are used for discovery and data exchange.
@@ -370,6 +445,12 @@ pub enum TransportPreference {
     ///
     /// Combines zero-copy local with reliable remote.
     ShmLocalTcpRemote,
+
+    /// Unix domain sockets preferred (local IPC without shared SHM).
+    ///
+    /// Use UDS for participants sharing the socket directory (e.g. containers
+    /// with a common volume but separate IPC namespaces), UDP for remote.
+    UdsPreferred,
 }
 
 impl TransportPreference {
@@ -380,6 +461,7 @@ impl TransportPreference {
             TransportPreference::UdpOnly
                 | TransportPreference::UdpDiscoveryTcpData
                 | TransportPreference::ShmPreferred
+                | TransportPreference::UdsPreferred
         )
     }
 
@@ -400,6 +482,11 @@ impl TransportPreference {
             TransportPreference::ShmPreferred | TransportPreference::ShmLocalTcpRemote
         )
     }
+
+    /// Check if UDS is preferred for local communication.
+    pub fn prefers_uds(&self) -> bool {
+        matches!(self, TransportPreference::UdsPreferred)
+    }
 }
 
 // ============================================================================
@@ -419,6 +506,7 @@ mod tests {
         assert_eq!(config.max_message_size, 16 * 1024 * 1024);
         assert!(config.nodelay);
         assert!(config.keepalive);
+        assert_eq!(config.framing, TcpFraming::LengthPrefix);
         assert!(config.validate().is_ok());
     }
 
@@ -517,6 +605,23 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn test_framing_and_reconnect_validation() {
+        // 16 MB messages do not fit the 16-bit RTI length
+        let config = TcpConfig {
+            framing: TcpFraming::Rti,
+            ..TcpConfig::enabled()
+        };
+        assert!(config.validate().is_err());
+        let config = TcpConfig::enabled().with_framing(TcpFraming::Rti);
+        assert_eq!(config.max_message_size, u16::MAX as usize);
+        assert!(config.validate().is_ok());
+
+        let config =
+            TcpConfig::enabled().with_reconnect(Duration::from_secs(2), Duration::from_secs(1), 0);
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn test_tcp_role_capabilities() {
         assert!(TcpRole::Auto.can_listen());
@@ -545,6 +650,11 @@ mod tests {
 
         assert!(TransportPreference::ShmLocalTcpRemote.prefers_shm());
         assert!(TransportPreference::ShmLocalTcpRemote.uses_tcp_data());
+
+        assert!(TransportPreference::UdsPreferred.prefers_uds());
+        assert!(TransportPreference::UdsPreferred.uses_udp_discovery());
+        assert!(!TransportPreference::UdsPreferred.uses_tcp_data());
+        assert!(!TransportPreference::ShmPreferred.prefers_uds());
     }
 
     #[test]
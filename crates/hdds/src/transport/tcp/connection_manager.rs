@@ -7,7 +7,10 @@
 //! - Connection lifecycle (connect, accept, close)
 //! - GUID-based connection lookup
 //! - Tie-breaker for duplicate connections
-//! - Reconnection handling
+//! - Reconnection handling: bootstrap peers are dialled by address and
+//!   redialled with exponential backoff when the connection drops
+//! - Identification: each side opens with a header-only RTPS message so the
+//!   remote GUID prefix is known without waiting for discovery traffic
 //!
 //! # Architecture
 //!
@@ -37,6 +40,7 @@ use std::time::{Duration, Instant};
 use super::connection::{should_keep_connection, ConnectionState};
 use super::io_thread::{IoThreadHandle, TcpEvent};
 use super::TcpConfig;
+use crate::protocol::constants::{HDDS_VENDOR_ID, RTPS_VERSION_MAJOR, RTPS_VERSION_MINOR};
 
 // ============================================================================
 // Types
@@ -58,14 +62,20 @@ pub struct ConnectionManagerConfig {
     /// Connection timeout
     pub connect_timeout: Duration,
 
-    /// Reconnection delay
+    /// Reconnection delay (doubled after each consecutive failure)
     pub reconnect_delay: Duration,
 
-    /// Maximum reconnection attempts
+    /// Upper bound for the reconnection backoff
+    pub reconnect_backoff_max: Duration,
+
+    /// Maximum consecutive reconnection attempts (0 = infinite)
     pub max_reconnect_attempts: u32,
 
     /// Enable automatic reconnection
     pub auto_reconnect: bool,
+
+    /// Bootstrap peers to dial by address
+    pub initial_peers: Vec<SocketAddr>,
 }
 
 impl Default for ConnectionManagerConfig {
@@ -74,8 +84,10 @@ impl Default for ConnectionManagerConfig {
             max_connections: 1000,
             connect_timeout: Duration::from_secs(5),
             reconnect_delay: Duration::from_secs(1),
+            reconnect_backoff_max: Duration::from_secs(30),
             max_reconnect_attempts: 10,
             auto_reconnect: true,
+            initial_peers: Vec::new(),
         }
     }
 }
@@ -86,8 +98,14 @@ impl From<&TcpConfig> for ConnectionManagerConfig {
             max_connections: 1000,
             connect_timeout: tcp.connect_timeout,
             reconnect_delay: tcp.reconnect_delay,
+            reconnect_backoff_max: tcp.reconnect_backoff_max,
             max_reconnect_attempts: tcp.max_reconnect_attempts,
-            auto_reconnect: tcp.max_reconnect_attempts > 0,
+            auto_reconnect: true,
+            initial_peers: if tcp.role.can_connect() {
+                tcp.initial_peers.clone()
+            } else {
+                Vec::new()
+            },
         }
     }
 }
@@ -149,6 +167,56 @@ impl PendingConnection {
     }
 }
 
+// ============================================================================
+// Peer Dialer
+// ============================================================================
+
+/// A bootstrap peer known only by address.
+///
+/// Dialled until a connection to it is up, then redialled with backoff
+/// whenever that connection is lost.
+#[derive(Debug)]
+struct PeerDialer {
+    /// Peer address
+    addr: SocketAddr,
+
+    /// GUID prefix learned from the peer's first message
+    remote_guid: Option<GuidPrefix>,
+
+    /// Connection attempt in flight
+    conn_id: Option<u64>,
+
+    /// Consecutive failed attempts
+    failures: u32,
+
+    /// Earliest time for the next attempt
+    next_attempt: Instant,
+
+    /// Attempts exhausted
+    gave_up: bool,
+}
+
+/// Delay before the next attempt after `failures` consecutive failures.
+fn backoff_delay(base: Duration, max: Duration, failures: u32) -> Duration {
+    let doublings = failures.saturating_sub(1).min(16);
+    base.saturating_mul(1 << doublings).min(max)
+}
+
+/// Check for a header-only RTPS message (carries no submessages).
+fn is_identification_message(payload: &[u8]) -> bool {
+    payload.len() == 20 && payload.starts_with(b"RTPS")
+}
+
+/// Header-only RTPS message identifying the local participant.
+fn identification_message(local_guid: &GuidPrefix) -> Vec<u8> {
+    let mut message = Vec::with_capacity(20);
+    message.extend_from_slice(b"RTPS");
+    message.extend_from_slice(&[RTPS_VERSION_MAJOR, RTPS_VERSION_MINOR]);
+    message.extend_from_slice(&HDDS_VENDOR_ID);
+    message.extend_from_slice(local_guid);
+    message
+}
+
 // ============================================================================
 // Managed Connection
 // ============================================================================
@@ -205,9 +273,13 @@ pub struct ConnectionManager {
     /// Pending outbound connections by address
     pending_outbound: HashMap<SocketAddr, PendingConnection>,
 
-    /// Pending inbound connections by conn_id
+    /// Connections awaiting the remote GUID, by conn_id
+    /// (inbound, and outbound dials to bootstrap peers)
     pending_inbound: HashMap<u64, PendingConnection>,
 
+    /// Bootstrap peers dialled by address
+    peers: Vec<PeerDialer>,
+
     /// I/O thread handle
     io_handle: IoThreadHandle,
 }
@@ -219,6 +291,19 @@ impl ConnectionManager {
         config: ConnectionManagerConfig,
         io_handle: IoThreadHandle,
     ) -> Self {
+        let now = Instant::now();
+        let peers = config
+            .initial_peers
+            .iter()
+            .map(|&addr| PeerDialer {
+                addr,
+                remote_guid: None,
+                conn_id: None,
+                failures: 0,
+                next_attempt: now,
+                gave_up: false,
+            })
+            .collect();
         Self {
             config,
             local_guid,
@@ -226,6 +311,7 @@ impl ConnectionManager {
             conn_id_to_guid: HashMap::new(),
             pending_outbound: HashMap::new(),
             pending_inbound: HashMap::new(),
+            peers,
             io_handle,
         }
     }
@@ -314,6 +400,17 @@ impl ConnectionManager {
         self.io_handle.send(conn.conn_id, payload)
     }
 
+    /// Send a message to every active connection.
+    ///
+    /// Returns the number of connections the message was queued on.
+    pub fn broadcast(&self, payload: &[u8]) -> usize {
+        self.connections
+            .values()
+            .filter(|conn| conn.state == ConnectionState::Connected)
+            .filter(|conn| self.io_handle.send(conn.conn_id, payload.to_vec()).is_ok())
+            .count()
+    }
+
     /// Close a connection to a remote participant.
     pub fn disconnect(&mut self, remote_guid: &GuidPrefix) -> io::Result<()> {
         if let Some(conn) = self.connections.remove(remote_guid) {
@@ -361,6 +458,9 @@ impl ConnectionManager {
         // Check for connection timeouts
         self.check_timeouts(&mut events);
 
+        // (Re)dial bootstrap peers that are due
+        self.dial_peers(&mut events);
+
         events
     }
 
@@ -375,6 +475,7 @@ impl ConnectionManager {
                 // New inbound connection - we don't know the GUID yet
                 let pending = PendingConnection::inbound(remote_addr, conn_id);
                 self.pending_inbound.insert(conn_id, pending);
+                self.identify(conn_id);
                 vec![]
             }
 
@@ -384,6 +485,7 @@ impl ConnectionManager {
             } => {
                 // Outbound connection established
                 if let Some(pending) = self.pending_outbound.remove(&remote_addr) {
+                    self.identify(conn_id);
                     if let Some(guid) = pending.expected_guid {
                         self.promote_connection(conn_id, remote_addr, guid, true);
                         return vec![ConnectionEvent::Connected {
@@ -391,6 +493,8 @@ impl ConnectionManager {
                             remote_addr,
                         }];
                     }
+                    // Dialled by address: wait for the peer to identify itself
+                    self.pending_inbound.insert(conn_id, pending);
                 }
                 vec![]
             }
@@ -405,14 +509,18 @@ impl ConnectionManager {
                 self.pending_inbound.remove(&conn_id);
 
                 // Remove from active
-                if let Some(guid) = self.conn_id_to_guid.remove(&conn_id) {
+                let removed = self.conn_id_to_guid.remove(&conn_id);
+                if let Some(guid) = removed {
                     self.connections.remove(&guid);
-                    return vec![ConnectionEvent::Disconnected {
+                }
+                self.peer_attempt_ended(conn_id, removed.is_some());
+                match removed {
+                    Some(guid) => vec![ConnectionEvent::Disconnected {
                         remote_guid: guid,
                         reason,
-                    }];
+                    }],
+                    None => vec![],
                 }
-                vec![]
             }
 
             TcpEvent::MessageReceived {
@@ -426,6 +534,9 @@ impl ConnectionManager {
                         conn.messages_received += 1;
                         conn.last_activity = Instant::now();
                     }
+                    if is_identification_message(&payload) {
+                        return vec![];
+                    }
                     return vec![ConnectionEvent::MessageReceived {
                         remote_guid: *guid,
                         payload,
@@ -440,7 +551,29 @@ impl ConnectionManager {
                         // Promote the pending connection to active using the extracted GUID
                         if let Some(pending) = self.pending_inbound.remove(&conn_id) {
                             let peer_addr = pending.addr;
-                            self.promote_connection(conn_id, peer_addr, guid_prefix, false);
+                            if guid_prefix == self.local_guid {
+                                // A bootstrap peer address that points back at us
+                                log::debug!("[TCP] Closing connection {} to self", conn_id);
+                                if let Some(peer) =
+                                    self.peers.iter_mut().find(|p| p.conn_id == Some(conn_id))
+                                {
+                                    peer.gave_up = true;
+                                }
+                                let _ = self.io_handle.close(conn_id);
+                                return vec![];
+                            }
+                            if let Some(peer) =
+                                self.peers.iter_mut().find(|p| p.conn_id == Some(conn_id))
+                            {
+                                peer.remote_guid = Some(guid_prefix);
+                                peer.failures = 0;
+                            }
+                            self.promote_connection(
+                                conn_id,
+                                peer_addr,
+                                guid_prefix,
+                                pending.is_initiator,
+                            );
 
                             // Update message stats on the newly promoted connection
                             if let Some(conn) = self.connections.get_mut(&guid_prefix) {
@@ -456,16 +589,17 @@ impl ConnectionManager {
 
                             // v233: Emit BOTH Connected and MessageReceived events
                             // This ensures the application knows a new client connected
-                            return vec![
-                                ConnectionEvent::Connected {
-                                    remote_guid: guid_prefix,
-                                    remote_addr: peer_addr,
-                                },
-                                ConnectionEvent::MessageReceived {
+                            let mut events = vec![ConnectionEvent::Connected {
+                                remote_guid: guid_prefix,
+                                remote_addr: peer_addr,
+                            }];
+                            if !is_identification_message(&payload) {
+                                events.push(ConnectionEvent::MessageReceived {
                                     remote_guid: guid_prefix,
                                     payload,
-                                },
-                            ];
+                                });
+                            }
+                            return events;
                         }
                     } else {
                         log::warn!(
@@ -490,6 +624,17 @@ impl ConnectionManager {
 
             TcpEvent::Error { conn_id, error } => {
                 if let Some(cid) = conn_id {
+                    // Immediate connect failure of a bootstrap peer dial
+                    let failed_dial = self
+                        .pending_outbound
+                        .iter()
+                        .find(|(_, p)| p.conn_id == cid && p.expected_guid.is_none())
+                        .map(|(addr, _)| *addr);
+                    if let Some(addr) = failed_dial {
+                        self.pending_outbound.remove(&addr);
+                        self.peer_attempt_ended(cid, false);
+                    }
+
                     // Connection-specific error
                     if let Some(guid) = self.conn_id_to_guid.get(&cid) {
                         return vec![ConnectionEvent::Error {
@@ -571,6 +716,7 @@ impl ConnectionManager {
         for addr in timed_out {
             if let Some(pending) = self.pending_outbound.remove(&addr) {
                 let _ = self.io_handle.close(pending.conn_id);
+                self.peer_attempt_ended(pending.conn_id, false);
 
                 if let Some(guid) = pending.expected_guid {
                     events.push(ConnectionEvent::ConnectTimeout { remote_guid: guid });
@@ -589,10 +735,94 @@ impl ConnectionManager {
         for conn_id in timed_out_inbound {
             if let Some(pending) = self.pending_inbound.remove(&conn_id) {
                 let _ = self.io_handle.close(pending.conn_id);
+                self.peer_attempt_ended(pending.conn_id, false);
             }
         }
     }
 
+    /// Send the identification message on a fresh connection.
+    fn identify(&self, conn_id: u64) {
+        let message = identification_message(&self.local_guid);
+        if let Err(e) = self.io_handle.send(conn_id, message) {
+            log::debug!("[TCP] Failed to identify on connection {}: {}", conn_id, e);
+        }
+    }
+
+    /// Dial bootstrap peers whose next attempt is due.
+    fn dial_peers(&mut self, events: &mut Vec<ConnectionEvent>) {
+        let now = Instant::now();
+        for i in 0..self.peers.len() {
+            let peer = &self.peers[i];
+            if peer.gave_up || peer.conn_id.is_some() || now < peer.next_attempt {
+                continue;
+            }
+            if peer
+                .remote_guid
+                .is_some_and(|guid| self.connections.contains_key(&guid))
+            {
+                continue;
+            }
+            let addr = peer.addr;
+            if self.pending_outbound.contains_key(&addr) {
+                continue;
+            }
+            match self.io_handle.connect(addr) {
+                Ok(conn_id) => {
+                    self.peers[i].conn_id = Some(conn_id);
+                    self.pending_outbound
+                        .insert(addr, PendingConnection::outbound(addr, conn_id, None));
+                }
+                Err(e) => {
+                    events.push(ConnectionEvent::Error {
+                        remote_guid: None,
+                        error: format!("dial {} failed: {}", addr, e),
+                    });
+                    self.schedule_redial(i, false);
+                }
+            }
+        }
+    }
+
+    /// Note the end of a peer's connection attempt or link, if `conn_id` is one.
+    fn peer_attempt_ended(&mut self, conn_id: u64, was_connected: bool) {
+        if let Some(index) = self.peers.iter().position(|p| p.conn_id == Some(conn_id)) {
+            self.schedule_redial(index, was_connected);
+        }
+    }
+
+    /// Schedule the next dial of a peer, backing off after failures.
+    fn schedule_redial(&mut self, index: usize, was_connected: bool) {
+        let peer = &mut self.peers[index];
+        peer.conn_id = None;
+
+        // A duplicate closed by the tie-breaker is not a failure
+        let reachable = peer
+            .remote_guid
+            .is_some_and(|guid| self.connections.contains_key(&guid));
+        if was_connected || reachable {
+            peer.failures = 0;
+        } else {
+            peer.failures += 1;
+        }
+
+        let max = self.config.max_reconnect_attempts;
+        if !self.config.auto_reconnect || (max > 0 && peer.failures >= max) {
+            log::warn!(
+                "[TCP] Giving up on peer {} after {} failed attempts",
+                peer.addr,
+                peer.failures
+            );
+            peer.gave_up = true;
+            return;
+        }
+        peer.next_attempt = Instant::now()
+            + backoff_delay(
+                self.config.reconnect_delay,
+                self.config.reconnect_backoff_max,
+                peer.failures,
+            );
+    }
+
     // ========================================================================
     // Utilities
     // ========================================================================
@@ -853,6 +1083,25 @@ mod tests {
         assert_eq!(info.messages_sent, 10);
     }
 
+    #[test]
+    fn test_backoff_delay_doubles_up_to_cap() {
+        let base = Duration::from_millis(100);
+        let max = Duration::from_millis(500);
+        assert_eq!(backoff_delay(base, max, 0), base);
+        assert_eq!(backoff_delay(base, max, 1), base);
+        assert_eq!(backoff_delay(base, max, 2), Duration::from_millis(200));
+        assert_eq!(backoff_delay(base, max, 3), Duration::from_millis(400));
+        assert_eq!(backoff_delay(base, max, 4), max);
+        assert_eq!(backoff_delay(base, max, u32::MAX), max);
+    }
+
+    #[test]
+    fn test_identification_message() {
+        let message = identification_message(&make_guid(7));
+        assert!(is_identification_message(&message));
+        assert_eq!(extract_guid_prefix_from_rtps(&message), Some(make_guid(7)));
+    }
+
     // Integration tests would require I/O thread setup which is complex
     // for unit tests. Focus on data structure tests here.
 }
@@ -15,6 +15,10 @@
 //! The length field is a 32-bit big-endian integer specifying the size
 //! of the RTPS message payload (not including the 4-byte header).
 //!
+//! With [`TcpFraming::Rti`] the 4-byte header is instead a 16-bit message
+//! kind (0 for RTPS) followed by a 16-bit big-endian length. Messages with a
+//! non-zero kind are control messages (20-byte header) and are skipped.
+//!
 //! # Wire Format
 //!
 //! - **Length**: `u32` big-endian (network byte order)
@@ -37,6 +41,8 @@
 
 use std::io::{self, Read};
 
+use super::config::TcpFraming;
+
 /// Frame header size (4 bytes for length).
 pub const FRAME_HEADER_SIZE: usize = 4;
 
@@ -46,15 +52,25 @@ pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 16 * 1024 * 1024;
 /// Minimum valid RTPS message size (header only).
 pub const MIN_RTPS_MESSAGE_SIZE: usize = 20; // RTPS header
 
+/// Bytes of an RTI control message header after the 4-byte frame header
+/// (magic cookie + transaction id).
+const RTI_CONTROL_HEADER_REST: usize = 16;
+
 /// Length-prefix frame codec for TCP transport.
 ///
 /// Handles framing/deframing of RTPS messages over TCP streams.
 /// The codec maintains partial read state to handle TCP's streaming nature.
 #[derive(Debug)]
 pub struct FrameCodec {
+    /// Wire framing
+    framing: TcpFraming,
+
     /// Current read state
     state: ReadState,
 
+    /// Whether the body being read is a control message to skip
+    discard_body: bool,
+
     /// Buffer for accumulating bytes
     buffer: Vec<u8>,
 
@@ -70,6 +86,9 @@ pub struct FrameCodec {
     /// Statistics: frames too large (rejected)
     frames_rejected: u64,
 
+    /// Statistics: control messages skipped
+    control_frames_skipped: u64,
+
     /// v233: Accumulation buffer for TLS plaintext data
     /// When using TLS, we read plaintext chunks and accumulate them here
     accumulator: Vec<u8>,
@@ -100,13 +119,21 @@ impl Default for ReadState {
 impl FrameCodec {
     /// Create a new frame codec with the specified max message size.
     pub fn new(max_size: usize) -> Self {
+        Self::with_framing(max_size, TcpFraming::LengthPrefix)
+    }
+
+    /// Create a frame codec for the given wire framing.
+    pub fn with_framing(max_size: usize, framing: TcpFraming) -> Self {
         Self {
+            framing,
             state: ReadState::default(),
+            discard_body: false,
             buffer: vec![0u8; FRAME_HEADER_SIZE], // Start with header buffer
             max_size,
             frames_decoded: 0,
             bytes_decoded: 0,
             frames_rejected: 0,
+            control_frames_skipped: 0,
             accumulator: Vec::with_capacity(16384),
             accumulator_pos: 0,
         }
@@ -117,6 +144,11 @@ impl FrameCodec {
         Self::new(DEFAULT_MAX_MESSAGE_SIZE)
     }
 
+    /// Get the wire framing.
+    pub fn framing(&self) -> TcpFraming {
+        self.framing
+    }
+
     /// Get maximum allowed message size.
     pub fn max_size(&self) -> usize {
         self.max_size
@@ -137,12 +169,89 @@ impl FrameCodec {
         self.frames_rejected
     }
 
+    /// Get number of control messages skipped (RTI framing).
+    pub fn control_frames_skipped(&self) -> u64 {
+        self.control_frames_skipped
+    }
+
     /// Reset the codec state (e.g., after connection reset).
     pub fn reset(&mut self) {
         self.state = ReadState::default();
+        self.discard_body = false;
         self.buffer.resize(FRAME_HEADER_SIZE, 0);
     }
 
+    /// Parse the complete header in `buffer`, returning the body length.
+    ///
+    /// Flags control messages so their body is consumed but not returned.
+    fn body_len(&mut self) -> usize {
+        let header = [
+            self.buffer[0],
+            self.buffer[1],
+            self.buffer[2],
+            self.buffer[3],
+        ];
+        match self.framing {
+            TcpFraming::LengthPrefix => {
+                self.discard_body = false;
+                u32::from_be_bytes(header) as usize
+            }
+            TcpFraming::Rti => {
+                let kind = u16::from_be_bytes([header[0], header[1]]);
+                let len = u16::from_be_bytes([header[2], header[3]]) as usize;
+                self.discard_body = kind != 0;
+                if self.discard_body {
+                    RTI_CONTROL_HEADER_REST + len
+                } else {
+                    len
+                }
+            }
+        }
+    }
+
+    /// Finish a body: returns the message, or `None` for a skipped control message.
+    fn complete_body(&mut self, expected_len: usize) -> Option<Vec<u8>> {
+        let message = if self.discard_body {
+            self.control_frames_skipped += 1;
+            None
+        } else {
+            self.frames_decoded += 1;
+            self.bytes_decoded += expected_len as u64;
+            Some(self.buffer[..expected_len].to_vec())
+        };
+
+        // Reset for next message
+        self.discard_body = false;
+        self.buffer.resize(FRAME_HEADER_SIZE, 0);
+        self.state = ReadState::default();
+        message
+    }
+
+    /// Encode a message with the given framing into an existing buffer.
+    ///
+    /// Fails if the payload is too large for the framing's length field.
+    pub fn encode_framed_into(
+        framing: TcpFraming,
+        payload: &[u8],
+        buf: &mut Vec<u8>,
+    ) -> io::Result<()> {
+        match framing {
+            TcpFraming::LengthPrefix => Self::encode_into(payload, buf),
+            TcpFraming::Rti => {
+                let len = u16::try_from(payload.len()).map_err(|_| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("message of {} bytes exceeds RTI framing", payload.len()),
+                    )
+                })?;
+                buf.extend_from_slice(&0u16.to_be_bytes());
+                buf.extend_from_slice(&len.to_be_bytes());
+                buf.extend_from_slice(payload);
+            }
+        }
+        Ok(())
+    }
+
     /// Encode a message into a framed buffer.
     ///
     /// Returns a new Vec containing: `[length: u32 BE][payload]`
@@ -216,15 +325,10 @@ impl FrameCodec {
                             }
 
                             // Header complete - parse length
-                            let len = u32::from_be_bytes([
-                                self.buffer[0],
-                                self.buffer[1],
-                                self.buffer[2],
-                                self.buffer[3],
-                            ]) as usize;
+                            let len = self.body_len();
 
                             // Validate length
-                            if len > self.max_size {
+                            if !self.discard_body && len > self.max_size {
                                 self.frames_rejected += 1;
                                 self.state = ReadState::default();
                                 return Err(io::Error::new(
@@ -238,9 +342,10 @@ impl FrameCodec {
 
                             if len == 0 {
                                 // Empty message - valid but unusual
-                                self.frames_decoded += 1;
-                                self.state = ReadState::default();
-                                return Ok(Some(Vec::new()));
+                                match self.complete_body(0) {
+                                    Some(message) => return Ok(Some(message)),
+                                    None => continue,
+                                }
                             }
 
                             // Prepare body buffer
@@ -284,16 +389,11 @@ impl FrameCodec {
                                 continue;
                             }
 
-                            // Message complete
-                            let message = self.buffer[..expected_len].to_vec();
-                            self.frames_decoded += 1;
-                            self.bytes_decoded += expected_len as u64;
-
-                            // Reset for next message
-                            self.buffer.resize(FRAME_HEADER_SIZE, 0);
-                            self.state = ReadState::default();
-
-                            return Ok(Some(message));
+                            // Message complete (control messages are skipped)
+                            match self.complete_body(expected_len) {
+                                Some(message) => return Ok(Some(message)),
+                                None => continue,
+                            }
                         }
                         Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
                             self.state = ReadState::ReadingBody {
@@ -369,15 +469,10 @@ impl FrameCodec {
                     self.accumulator_pos += needed;
 
                     // Parse length
-                    let len = u32::from_be_bytes([
-                        self.buffer[0],
-                        self.buffer[1],
-                        self.buffer[2],
-                        self.buffer[3],
-                    ]) as usize;
+                    let len = self.body_len();
 
                     // Validate length
-                    if len > self.max_size {
+                    if !self.discard_body && len > self.max_size {
                         self.frames_rejected += 1;
                         self.state = ReadState::default();
                         // Skip this frame - in practice this is an error condition
@@ -387,9 +482,10 @@ impl FrameCodec {
 
                     if len == 0 {
                         // Empty message
-                        self.frames_decoded += 1;
-                        self.state = ReadState::default();
-                        return Some(Vec::new());
+                        match self.complete_body(0) {
+                            Some(message) => return Some(message),
+                            None => continue,
+                        }
                     }
 
                     // Prepare for body
@@ -414,16 +510,10 @@ impl FrameCodec {
                     self.buffer[bytes_read..expected_len].copy_from_slice(&available[..needed]);
                     self.accumulator_pos += needed;
 
-                    // Message complete
-                    let message = self.buffer[..expected_len].to_vec();
-                    self.frames_decoded += 1;
-                    self.bytes_decoded += expected_len as u64;
-
-                    // Reset for next message
-                    self.buffer.resize(FRAME_HEADER_SIZE, 0);
-                    self.state = ReadState::default();
-
-                    return Some(message);
+                    // Message complete (control messages are skipped)
+                    if let Some(message) = self.complete_body(expected_len) {
+                        return Some(message);
+                    }
                 }
             }
         }
@@ -710,6 +800,34 @@ mod tests {
         assert_eq!(codec.frames_rejected(), 1);
     }
 
+    #[test]
+    fn test_rti_framing_skips_control_messages() {
+        let mut buf = Vec::new();
+        FrameCodec::encode_framed_into(TcpFraming::Rti, b"first", &mut buf).unwrap();
+        assert_eq!(&buf[..4], &[0, 0, 0, 5]);
+
+        // Control message: kind, attribute length, cookie + transaction id, attributes
+        buf.extend_from_slice(&[0x0c, 0x01, 0x00, 0x04]);
+        buf.extend_from_slice(&[0xAB; RTI_CONTROL_HEADER_REST]);
+        buf.extend_from_slice(&[1, 2, 3, 4]);
+        FrameCodec::encode_framed_into(TcpFraming::Rti, b"second", &mut buf).unwrap();
+
+        let mut codec = FrameCodec::with_framing(1024, TcpFraming::Rti);
+        let mut cursor = Cursor::new(buf.clone());
+        assert_eq!(codec.decode(&mut cursor).unwrap(), Some(b"first".to_vec()));
+        assert_eq!(codec.decode(&mut cursor).unwrap(), Some(b"second".to_vec()));
+        assert_eq!(codec.control_frames_skipped(), 1);
+
+        let mut codec = FrameCodec::with_framing(1024, TcpFraming::Rti);
+        codec.feed(&buf);
+        assert_eq!(codec.decode_buffered(), Some(b"first".to_vec()));
+        assert_eq!(codec.decode_buffered(), Some(b"second".to_vec()));
+        assert_eq!(codec.frames_decoded(), 2);
+
+        let oversized = vec![0u8; u16::MAX as usize + 1];
+        assert!(FrameCodec::encode_framed_into(TcpFraming::Rti, &oversized, &mut buf).is_err());
+    }
+
     #[test]
     fn test_statistics() {
         let mut codec = FrameCodec::new(1024);
@@ -559,6 +559,7 @@ impl IoThread {
 
     /// Run the I/O event loop.
     pub fn run(mut self) {
+        crate::alloc_scope!(Transport);
         // Send started event
         let local_addr = self.listener.as_ref().and_then(|l| l.local_addr().ok());
         let _ = self.event_tx.send(TcpEvent::Started { local_addr });
@@ -641,6 +642,7 @@ impl IoThread {
 
                     // Configure stream
                     let _ = stream.set_nodelay(self.config.nodelay);
+                    apply_keepalive(&stream, &self.config);
 
                     // v233: Create TLS server connection if TLS enabled
                     #[cfg(feature = "tcp-tls")]
@@ -677,7 +679,10 @@ impl IoThread {
                         conn_id,
                         remote_addr,
                         state: initial_state,
-                        codec: FrameCodec::new(self.config.max_message_size),
+                        codec: FrameCodec::with_framing(
+                            self.config.max_message_size,
+                            self.config.framing,
+                        ),
                         send_queue: Vec::new(),
                         send_offset: 0,
                         is_initiator: false,
@@ -765,6 +770,7 @@ impl IoThread {
                 }
 
                 let _ = stream.set_nodelay(self.config.nodelay);
+                apply_keepalive(&stream, &self.config);
 
                 // v233: Create TLS client connection if TLS enabled
                 #[cfg(feature = "tcp-tls")]
@@ -805,7 +811,10 @@ impl IoThread {
                     conn_id,
                     remote_addr: addr,
                     state: ConnectionState::Connecting,
-                    codec: FrameCodec::new(self.config.max_message_size),
+                    codec: FrameCodec::with_framing(
+                        self.config.max_message_size,
+                        self.config.framing,
+                    ),
                     send_queue: Vec::new(),
                     send_offset: 0,
                     is_initiator: true,
@@ -853,7 +862,15 @@ impl IoThread {
         }
 
         // Frame and queue the message
-        FrameCodec::encode_into(&payload, &mut conn.send_queue);
+        if let Err(e) =
+            FrameCodec::encode_framed_into(self.config.framing, &payload, &mut conn.send_queue)
+        {
+            let _ = self.event_tx.send(TcpEvent::Error {
+                conn_id: Some(conn_id),
+                error: e.to_string(),
+            });
+            return;
+        }
 
         // Try to send immediately
         self.try_flush(token);
@@ -1283,6 +1300,22 @@ impl IoThread {
     }
 }
 
+/// Enable TCP keep-alive probes on a connection when configured.
+///
+/// Dead peers behind NATs or powered-off hosts are otherwise only noticed on
+/// the next write.
+fn apply_keepalive(stream: &TcpStream, config: &TcpConfig) {
+    if !config.keepalive {
+        return;
+    }
+    let keepalive = socket2::TcpKeepalive::new().with_time(config.keepalive_interval);
+    #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
+    let keepalive = keepalive.with_interval(config.keepalive_interval);
+    if let Err(e) = socket2::SockRef::from(stream).set_tcp_keepalive(&keepalive) {
+        log::debug!("[TCP] failed to enable keep-alive: {}", e);
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -41,8 +41,9 @@
 //!
 //! # Wire Format
 //!
-//! TCP requires framing since it's a stream protocol. Each RTPS message
-//! is prefixed with a 4-byte big-endian length:
+//! TCP requires framing since it's a stream protocol. By default
+//! ([`TcpFraming::LengthPrefix`]) each RTPS message is prefixed with a
+//! 4-byte big-endian length:
 //!
 //! ```text
 //! +----------------+-------------------+
@@ -50,14 +51,27 @@
 //! +----------------+-------------------+
 //! ```
 //!
+//! [`TcpFraming::Rti`] uses the RTI Connext layout instead: a 2-byte
+//! message kind (0 = RTPS) and a 2-byte big-endian length, which caps
+//! messages at 64 KB.
+//!
 //! # Interoperability
 //!
-//! **Important**: RTPS over TCP is not standardized and is **not interoperable**
-//! across DDS vendors. Each vendor (RTI, FastDDS, CycloneDDS) uses different
-//! framing and connection protocols.
+//! **Important**: RTPS over TCP is not standardized. Each vendor (RTI,
+//! FastDDS, CycloneDDS) uses different framing and connection protocols.
+//!
+//! The default framing is for **HDDS <-> HDDS** communication only.
+//! [`TcpFraming::Rti`] reads and writes RTI's RTPS frames and skips its
+//! control messages; the Connext control handshake itself is not performed.
+//!
+//! # Participant Integration
 //!
-//! This TCP transport is for **HDDS <-> HDDS** communication only.
-//! For cross-vendor interop, use UDP transport or a gateway.
+//! `ParticipantBuilder::with_transport(TransportMode::Tcp)` runs discovery
+//! and user data over TCP only. Clients dial `TcpConfig::initial_peers`
+//! and redial with exponential backoff (`reconnect_delay` doubling up to
+//! `reconnect_backoff_max`); idle connections are probed with TCP
+//! keep-alive. Traffic is sent to every directly connected peer, so the
+//! peers of one participant are not relayed to each other.
 //!
 //! # Example
 //!
@@ -116,7 +130,7 @@ pub mod tls;
 // ============================================================================
 
 // Config types
-pub use config::{TcpConfig, TcpRole, TransportPreference};
+pub use config::{TcpConfig, TcpFraming, TcpRole, TransportPreference};
 
 // Frame codec
 pub use frame_codec::{
@@ -317,6 +317,29 @@ impl TcpTransport {
         Ok(())
     }
 
+    /// Send an RTPS message to every connected participant.
+    ///
+    /// Returns the number of connections the message was queued on.
+    pub fn broadcast(&self, payload: &[u8]) -> io::Result<usize> {
+        if !self.running.load(Ordering::Acquire) {
+            return Err(io::Error::new(
+                io::ErrorKind::NotConnected,
+                "transport not running",
+            ));
+        }
+
+        let sent = self
+            .conn_manager
+            .lock()
+            .map_err(|_| io::Error::other("lock poisoned"))?
+            .broadcast(payload);
+        for _ in 0..sent {
+            self.metrics.record_message_sent(payload.len() + 4);
+        }
+
+        Ok(sent)
+    }
+
     /// Send an RTPS message using a TCP locator.
     ///
     /// Requires an existing connection to the locator's address.
@@ -366,6 +366,14 @@ pub fn apply_socket2_ttl_config(socket: &Socket, config: &TtlConfig) -> io::Resu
     Ok(())
 }
 
+/// Apply TTL configuration to an IPv6 UDP socket (multicast and unicast hop limits).
+pub fn apply_ttl_config_v6(socket: &UdpSocket, config: &TtlConfig) -> io::Result<()> {
+    let sock_ref = socket2::SockRef::from(socket);
+    sock_ref.set_multicast_hops_v6(u32::from(config.multicast))?;
+    sock_ref.set_unicast_hops_v6(u32::from(config.unicast))?;
+    Ok(())
+}
+
 #[cfg(windows)]
 pub fn set_multicast_ttl(socket: &UdpSocket, ttl: u8) -> io::Result<()> {
     let sock_ref = socket2::SockRef::from(socket);
@@ -4,18 +4,29 @@
 //! UDP transport for RTPS multicast send/receive.
 //!
 //! Consolidates socket management, multicast configuration, and send/receive operations.
+//! Depending on its [`IpMode`], the transport runs on IPv4, IPv6 or both
+//! (dual-stack: the IPv6 sockets run next to the IPv4 ones).
 
-use crate::config::{MULTICAST_GROUP, PORT_BASE, SEDP_UNICAST_OFFSET};
+use crate::config::{
+    MULTICAST_GROUP, MULTICAST_IP, MULTICAST_IP_V6, PORT_BASE, SEDP_UNICAST_OFFSET,
+};
 use crate::core::string_utils::format_string;
+use crate::transport::filter::InterfaceFilter;
+use crate::transport::loopback::LoopbackPort;
 use crate::transport::multicast::{
-    get_primary_interface_ip, get_unicast_locators, join_multicast_group,
+    get_filtered_ipv6_interfaces, get_filtered_primary_interface_ip, get_primary_interface_ip,
+    get_unicast_locators, get_unicast_locators_v6, is_ipv6_link_local, join_multicast_group,
+    join_multicast_group_filtered, join_multicast_group_v6, Ipv6Interface,
 };
+use crate::transport::tap::{CapturedPacket, PacketDirection, PacketTap};
 use crate::transport::ttl::{self, TtlConfig};
-use crate::transport::PortMapping;
+use crate::transport::{IpMode, PortMapping};
 use socket2::{Domain, Protocol, Socket, Type};
 use std::io;
-use std::net::{Ipv4Addr, SocketAddr, UdpSocket};
-use std::sync::Arc;
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV6, UdpSocket};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::SystemTime;
 
 /// UDP Transport for bidirectional multicast communication.
 ///
@@ -30,11 +41,12 @@ pub struct UdpTransport {
     #[allow(dead_code)]
     pub(super) participant_id: u8,
     /// Shared UDP socket (Arc for multi-thread access) - multicast/metatraffic
-    pub(super) socket: Arc<UdpSocket>,
+    /// (`None` on a loopback bus)
+    pub(super) socket: Option<Arc<UdpSocket>>,
     /// Metatraffic unicast socket (bound to 7410 for SEDP/ACKNACK unicast sends)
-    pub(super) metatraffic_unicast_socket: Arc<UdpSocket>,
+    pub(super) metatraffic_unicast_socket: Option<Arc<UdpSocket>>,
     /// User data unicast socket (bound to 7411 for USER DATA unicast sends) v103
-    pub(super) user_unicast_socket: Arc<UdpSocket>,
+    pub(super) user_unicast_socket: Option<Arc<UdpSocket>>,
     /// Multicast destination address for SPDP (239.255.0.1:7400)
     pub(super) multicast_addr: SocketAddr,
     /// Multicast destination address for SEDP (239.255.0.1:7400, RTI compatible)
@@ -49,6 +61,91 @@ pub struct UdpTransport {
     pub(super) metatraffic_unicast_port: u16,
     /// TTL configuration (multicast/unicast)
     pub(super) ttl_config: TtlConfig,
+    /// Local stream relay receiving every outbound datagram (TCP transport mode)
+    pub(super) relay: Option<SocketAddr>,
+    /// In-memory bus carrying all traffic instead of sockets (loopback bus mode)
+    pub(super) loopback: Option<LoopbackPort>,
+    /// Address families in use
+    pub(super) ip_mode: IpMode,
+    /// IPv6 sockets (V6 mode: shared with the primary fields, dual-stack: extra set)
+    pub(super) ipv6: Option<Ipv6Stack>,
+    /// Interfaces multicast is joined on and locators are announced from
+    pub(super) interface_filter: InterfaceFilter,
+    /// Sends are dropped while set (see `Participant::suspend`)
+    pub(super) suspended: AtomicBool,
+    /// Called on every datagram sent (see [`set_packet_tap`](Self::set_packet_tap))
+    pub(super) packet_tap: OnceLock<PacketTap>,
+}
+
+/// IPv6 sockets of the transport.
+pub(super) struct Ipv6Stack {
+    /// Metatraffic multicast socket joined to ff02::ffff:239.255.0.1
+    pub(super) socket: Arc<UdpSocket>,
+    /// Metatraffic unicast socket ([::]:7410 for domain 0)
+    pub(super) metatraffic_unicast_socket: Arc<UdpSocket>,
+    /// User data unicast send socket (ephemeral port)
+    pub(super) user_unicast_socket: Arc<UdpSocket>,
+    /// SPDP multicast destination (scoped to `scope_id`)
+    pub(super) multicast_addr: SocketAddr,
+    /// SEDP multicast destination (scoped to `scope_id`)
+    pub(super) sedp_multicast_addr: SocketAddr,
+    /// Interface index multicast and link-local unicast go out on (0 = OS default)
+    pub(super) scope_id: u32,
+    /// IPv6 interfaces found at creation (locator selection)
+    pub(super) interfaces: Vec<Ipv6Interface>,
+}
+
+impl Ipv6Stack {
+    /// Bind the IPv6 sockets for `mapping` and join the IPv6 multicast groups.
+    fn bind(
+        mapping: PortMapping,
+        reuseport: bool,
+        ttl_config: &TtlConfig,
+        filter: &InterfaceFilter,
+    ) -> io::Result<Self> {
+        let interfaces = get_filtered_ipv6_interfaces(filter);
+        let socket = bind_v6(mapping.metatraffic_multicast, reuseport)?;
+        let scope_id = join_multicast_group_v6(&socket, &interfaces)?;
+        let metatraffic_unicast_socket = bind_v6(mapping.metatraffic_unicast, reuseport)?;
+        let user_unicast_socket = bind_v6(0, reuseport)?;
+        for sock in [&socket, &metatraffic_unicast_socket, &user_unicast_socket] {
+            ttl::apply_ttl_config_v6(sock, ttl_config)?;
+        }
+
+        let group = |port| {
+            SocketAddr::V6(SocketAddrV6::new(
+                Ipv6Addr::from(MULTICAST_IP_V6),
+                port,
+                0,
+                scope_id,
+            ))
+        };
+        log::debug!(
+            "[UDP] IPv6 stack bound multicast=[::]:{} unicast=[::]:{} scope_id={} interfaces={}",
+            mapping.metatraffic_multicast,
+            mapping.metatraffic_unicast,
+            scope_id,
+            interfaces.len()
+        );
+
+        Ok(Self {
+            socket: Arc::new(socket),
+            metatraffic_unicast_socket: Arc::new(metatraffic_unicast_socket),
+            user_unicast_socket: Arc::new(user_unicast_socket),
+            multicast_addr: group(mapping.metatraffic_multicast),
+            sedp_multicast_addr: group(mapping.sedp_multicast),
+            scope_id,
+            interfaces,
+        })
+    }
+
+    fn sockets(&self) -> [&Arc<UdpSocket>; 3] {
+        [
+            &self.socket,
+            &self.metatraffic_unicast_socket,
+            &self.user_unicast_socket,
+        ]
+    }
 }
 
 // ===== Construction (builder functionality) =====
@@ -57,8 +154,97 @@ impl UdpTransport {
     /// Create new UDP transport with RTPS v2.5 port mapping.
     ///
     /// Binds to the metatraffic multicast port and joins the standard multicast group.
+    /// The address families come from `HDDS_IP_MODE` (IPv4 by default).
     pub fn new(domain_id: u32, participant_id: u8, mapping: PortMapping) -> io::Result<Self> {
-        crate::trace_fn!("UdpTransport::new");
+        Self::with_ip_mode(domain_id, participant_id, mapping, IpMode::from_env())
+    }
+
+    /// Create new UDP transport on the address families of `ip_mode`.
+    ///
+    /// IPv6 sockets bind `[::]` (IPv6 only) on the same ports as IPv4 and
+    /// join ff02::ffff:239.255.0.1 on every IPv6 interface. The interfaces
+    /// come from `HDDS_INTERFACE` / `HDDS_INTERFACE_DENY` (all by default).
+    pub fn with_ip_mode(
+        domain_id: u32,
+        participant_id: u8,
+        mapping: PortMapping,
+        ip_mode: IpMode,
+    ) -> io::Result<Self> {
+        Self::with_interface_filter(
+            domain_id,
+            participant_id,
+            mapping,
+            ip_mode,
+            InterfaceFilter::from_env(),
+        )
+    }
+
+    /// Create new UDP transport restricted to the interfaces allowed by `filter`.
+    ///
+    /// Multicast groups are joined and sent on the allowed interfaces only,
+    /// and unicast locators are announced from them, so VPN or container
+    /// bridge addresses never reach remote participants. Fails with
+    /// [`io::ErrorKind::NotFound`] when a restrictive filter matches no
+    /// IPv4 interface.
+    pub fn with_interface_filter(
+        domain_id: u32,
+        participant_id: u8,
+        mapping: PortMapping,
+        ip_mode: IpMode,
+        filter: InterfaceFilter,
+    ) -> io::Result<Self> {
+        crate::trace_fn!("UdpTransport::with_interface_filter");
+        let reuseport_enabled = std::env::var("HDDS_REUSEPORT")
+            .map(|v| v == "1")
+            .unwrap_or(false);
+        match ip_mode {
+            IpMode::V4 => Self::new_v4(domain_id, participant_id, mapping, filter),
+            IpMode::V6 => {
+                let ttl_config = TtlConfig::from_env();
+                let stack = Ipv6Stack::bind(mapping, reuseport_enabled, &ttl_config, &filter)?;
+                Ok(Self {
+                    domain_id,
+                    participant_id,
+                    socket: Some(Arc::clone(&stack.socket)),
+                    metatraffic_unicast_socket: Some(Arc::clone(&stack.metatraffic_unicast_socket)),
+                    user_unicast_socket: Some(Arc::clone(&stack.user_unicast_socket)),
+                    multicast_addr: stack.multicast_addr,
+                    sedp_multicast_addr: stack.sedp_multicast_addr,
+                    data_multicast_addr: None,
+                    force_data_mc: false,
+                    iface: Ipv4Addr::UNSPECIFIED,
+                    metatraffic_unicast_port: mapping.metatraffic_unicast,
+                    ttl_config,
+                    relay: None,
+                    loopback: None,
+                    ip_mode,
+                    ipv6: Some(stack),
+                    interface_filter: filter,
+                    suspended: AtomicBool::new(false),
+                    packet_tap: OnceLock::new(),
+                })
+            }
+            IpMode::Dual => {
+                let mut transport = Self::new_v4(domain_id, participant_id, mapping, filter)?;
+                transport.ipv6 = Some(Ipv6Stack::bind(
+                    mapping,
+                    reuseport_enabled,
+                    &transport.ttl_config,
+                    &transport.interface_filter,
+                )?);
+                transport.ip_mode = ip_mode;
+                Ok(transport)
+            }
+        }
+    }
+
+    /// IPv4 transport (default mode).
+    fn new_v4(
+        domain_id: u32,
+        participant_id: u8,
+        mapping: PortMapping,
+        filter: InterfaceFilter,
+    ) -> io::Result<Self> {
         // Create socket with SO_REUSEADDR for port reuse.
         // We deliberately do NOT use SO_REUSEPORT by default because:
         // 1. RTI Connext doesn't use SO_REUSEPORT
@@ -93,7 +279,7 @@ impl UdpTransport {
         );
 
         let socket: UdpSocket = socket2.into();
-        let iface = match join_multicast_group(&socket) {
+        let iface = match join_multicast_group_filtered(&socket, &filter) {
             Ok(iface) => {
                 log::debug!(
                     "[UDP] join_multicast_group success multicast=239.255.0.1 iface={}",
@@ -127,7 +313,7 @@ impl UdpTransport {
         if reuseport_enabled {
             set_reuseport(&unicast_socket2)?;
         }
-        let primary_ip = get_primary_interface_ip()?;
+        let primary_ip = get_filtered_primary_interface_ip(&filter)?;
         let unicast_bind_addr = parse_socket_addr(
             format_string(format_args!("0.0.0.0:{}", mapping.metatraffic_unicast)),
             "metatraffic unicast bind address",
@@ -212,9 +398,9 @@ impl UdpTransport {
         Ok(Self {
             domain_id,
             participant_id,
-            socket: Arc::new(socket),
-            metatraffic_unicast_socket: Arc::new(metatraffic_unicast_socket),
-            user_unicast_socket: Arc::new(user_unicast_socket),
+            socket: Some(Arc::new(socket)),
+            metatraffic_unicast_socket: Some(Arc::new(metatraffic_unicast_socket)),
+            user_unicast_socket: Some(Arc::new(user_unicast_socket)),
             multicast_addr,
             sedp_multicast_addr,
             data_multicast_addr,
@@ -222,9 +408,86 @@ impl UdpTransport {
             iface,
             metatraffic_unicast_port: mapping.metatraffic_unicast,
             ttl_config,
+            relay: None,
+            loopback: None,
+            ip_mode: IpMode::V4,
+            ipv6: None,
+            interface_filter: filter,
+            suspended: AtomicBool::new(false),
+            packet_tap: OnceLock::new(),
         })
     }
 
+    /// Create a loopback-only transport whose traffic is carried by a relay.
+    ///
+    /// Used by TCP transport mode: nothing is bound on the network, the
+    /// sockets listen on 127.0.0.1 and every send, multicast or unicast,
+    /// goes to `relay`, which forwards it over its own stream. The unicast
+    /// sockets still take the mapping's ports so participant IDs (and thus
+    /// GUID prefixes) stay unique per host.
+    pub fn relayed(
+        domain_id: u32,
+        participant_id: u8,
+        mapping: PortMapping,
+        relay: SocketAddr,
+    ) -> io::Result<Self> {
+        crate::trace_fn!("UdpTransport::relayed");
+        let bind = |port| UdpSocket::bind((Ipv4Addr::LOCALHOST, port)).map(Arc::new);
+        Ok(Self {
+            domain_id,
+            participant_id,
+            socket: Some(bind(0)?),
+            metatraffic_unicast_socket: Some(bind(mapping.metatraffic_unicast)?),
+            user_unicast_socket: Some(bind(mapping.user_unicast)?),
+            multicast_addr: relay,
+            sedp_multicast_addr: relay,
+            data_multicast_addr: None,
+            force_data_mc: false,
+            iface: Ipv4Addr::LOCALHOST,
+            metatraffic_unicast_port: mapping.metatraffic_unicast,
+            ttl_config: TtlConfig::default(),
+            relay: Some(relay),
+            loopback: None,
+            ip_mode: IpMode::V4,
+            ipv6: None,
+            interface_filter: InterfaceFilter::allow_all(),
+            suspended: AtomicBool::new(false),
+            packet_tap: OnceLock::new(),
+        })
+    }
+
+    /// Create a socket-free transport on the in-memory loopback bus.
+    ///
+    /// Used by loopback bus transport mode: every send goes through `port`,
+    /// multicast to the other participants of the domain, unicast to the
+    /// participant owning the destination locator. Unicast locators are the
+    /// `127.0.0.1` pseudo-locators of the port.
+    pub fn loopback(domain_id: u32, port: LoopbackPort) -> Self {
+        crate::trace_fn!("UdpTransport::loopback");
+        let mapping = port.mapping();
+        Self {
+            domain_id,
+            participant_id: port.participant_id(),
+            socket: None,
+            metatraffic_unicast_socket: None,
+            user_unicast_socket: None,
+            multicast_addr: SocketAddr::from((MULTICAST_IP, mapping.metatraffic_multicast)),
+            sedp_multicast_addr: SocketAddr::from((MULTICAST_IP, mapping.sedp_multicast)),
+            data_multicast_addr: None,
+            force_data_mc: false,
+            iface: Ipv4Addr::LOCALHOST,
+            metatraffic_unicast_port: mapping.metatraffic_unicast,
+            ttl_config: TtlConfig::default(),
+            relay: None,
+            loopback: Some(port),
+            ip_mode: IpMode::V4,
+            ipv6: None,
+            interface_filter: InterfaceFilter::allow_all(),
+            suspended: AtomicBool::new(false),
+            packet_tap: OnceLock::new(),
+        }
+    }
+
     /// Create transport with custom port (legacy/testing helper).
     #[deprecated(since = "0.4.0", note = "Use new() with PortMapping instead")]
     // @audit-ok: Sequential initialization (cyclo 13, cogni 0) - linear socket setup without branching
@@ -283,9 +546,9 @@ impl UdpTransport {
         Ok(Self {
             domain_id: 0,
             participant_id: 0,
-            socket: Arc::new(socket),
-            metatraffic_unicast_socket: Arc::new(metatraffic_unicast_socket),
-            user_unicast_socket: Arc::new(user_unicast_socket),
+            socket: Some(Arc::new(socket)),
+            metatraffic_unicast_socket: Some(Arc::new(metatraffic_unicast_socket)),
+            user_unicast_socket: Some(Arc::new(user_unicast_socket)),
             multicast_addr,
             sedp_multicast_addr,
             data_multicast_addr,
@@ -293,6 +556,13 @@ impl UdpTransport {
             force_data_mc,
             iface,
             ttl_config,
+            relay: None,
+            loopback: None,
+            ip_mode: IpMode::V4,
+            ipv6: None,
+            interface_filter: InterfaceFilter::allow_all(),
+            suspended: AtomicBool::new(false),
+            packet_tap: OnceLock::new(),
         })
     }
 }
@@ -303,6 +573,14 @@ impl UdpTransport {
     /// Send data to multicast group.
     pub fn send(&self, data: &[u8]) -> io::Result<()> {
         crate::trace_fn!("UdpTransport::send");
+        if self.is_suspended() {
+            return Ok(());
+        }
+        if let Some(port) = &self.loopback {
+            port.multicast(data, port.metatraffic_addr());
+            self.tap_sent(data, || Some(port.metatraffic_addr()), self.multicast_addr);
+            return Ok(());
+        }
         let mut dest = self.multicast_addr;
 
         if self.force_data_mc {
@@ -323,7 +601,8 @@ impl UdpTransport {
             self.data_multicast_addr
         );
 
-        let sent = match self.socket.send_to(data, dest) {
+        let socket = Self::udp(&self.socket)?;
+        let sent = match socket.send_to(data, dest) {
             Ok(n) => n,
             Err(err) => {
                 log::debug!(
@@ -336,6 +615,7 @@ impl UdpTransport {
                 return Err(err);
             }
         };
+        self.tap_sent(data, || socket.local_addr().ok(), dest);
 
         if Self::should_log_debug() {
             let kind = Self::parse_submessage_kind(data);
@@ -348,6 +628,13 @@ impl UdpTransport {
             );
         }
 
+        if let Some(v6) = self.dual_stack() {
+            match v6.socket.send_to(data, v6.multicast_addr) {
+                Ok(_) => self.tap_sent(data, || v6.socket.local_addr().ok(), v6.multicast_addr),
+                Err(err) => log::debug!("[UDP] send error={} dest={}", err, v6.multicast_addr),
+            }
+        }
+
         Ok(())
     }
 
@@ -356,6 +643,18 @@ impl UdpTransport {
     /// Sends endpoint discovery packets to 239.255.0.1:7400 (SEDP multicast address, RTI compatible).
     pub fn send_sedp(&self, data: &[u8]) -> io::Result<()> {
         crate::trace_fn!("UdpTransport::send_sedp");
+        if self.is_suspended() {
+            return Ok(());
+        }
+        if let Some(port) = &self.loopback {
+            port.multicast(data, port.metatraffic_addr());
+            self.tap_sent(
+                data,
+                || Some(port.metatraffic_addr()),
+                self.sedp_multicast_addr,
+            );
+            return Ok(());
+        }
         log::debug!(
             "[UDP-SEDP] Attempting to send {} bytes to {}",
             data.len(),
@@ -395,7 +694,21 @@ impl UdpTransport {
             log::debug!("  octetsToNextHeader: {}", octets);
         }
 
-        let sent = self.socket.send_to(data, self.sedp_multicast_addr)?;
+        let socket = Self::udp(&self.socket)?;
+        let sent = socket.send_to(data, self.sedp_multicast_addr)?;
+        self.tap_sent(data, || socket.local_addr().ok(), self.sedp_multicast_addr);
+        if let Some(v6) = self.dual_stack() {
+            match v6.socket.send_to(data, v6.sedp_multicast_addr) {
+                Ok(_) => {
+                    self.tap_sent(data, || v6.socket.local_addr().ok(), v6.sedp_multicast_addr)
+                }
+                Err(err) => log::debug!(
+                    "[UDP-SEDP] send error={} dest={}",
+                    err,
+                    v6.sedp_multicast_addr
+                ),
+            }
+        }
 
         log::debug!(
             "[UDP-SEDP] [OK] Sent {} bytes to {} (iface={})",
@@ -413,8 +726,24 @@ impl UdpTransport {
     /// per RTPS v2.5 Sec.9.6.1 port mapping.
     pub fn send_to_endpoint(&self, data: &[u8], endpoint: &SocketAddr) -> io::Result<usize> {
         crate::trace_fn!("UdpTransport::send_to_endpoint");
+        if self.is_suspended() {
+            return Ok(data.len());
+        }
+        if let Some(port) = &self.loopback {
+            port.unicast(data, port.metatraffic_addr(), *endpoint);
+            self.tap_sent(data, || Some(port.metatraffic_addr()), *endpoint);
+            return Ok(data.len());
+        }
+        // The tap records the peer, not the TCP relay standing in for it
+        let target = *endpoint;
         // v73: Use dedicated unicast socket bound to 7410 for RTPS spec compliance
-        let sent = self.metatraffic_unicast_socket.send_to(data, endpoint)?;
+        let endpoint = self.scoped(self.relay.as_ref().unwrap_or(endpoint));
+        let socket = match (&self.ipv6, endpoint) {
+            (Some(v6), SocketAddr::V6(_)) => &v6.metatraffic_unicast_socket,
+            _ => Self::udp(&self.metatraffic_unicast_socket)?,
+        };
+        let sent = socket.send_to(data, endpoint)?;
+        self.tap_sent(data, || socket.local_addr().ok(), target);
 
         if Self::should_log_debug() {
             let kind = Self::parse_submessage_kind(data);
@@ -436,14 +765,25 @@ impl UdpTransport {
     /// USER DATA must not use metatraffic_unicast_socket (port 7410).
     pub fn send_user_data_unicast(&self, data: &[u8], endpoint: &SocketAddr) -> io::Result<usize> {
         crate::trace_fn!("UdpTransport::send_user_data_unicast");
-        let sent = self.user_unicast_socket.send_to(data, endpoint)?;
+        if self.is_suspended() {
+            return Ok(data.len());
+        }
+        if let Some(port) = &self.loopback {
+            port.unicast(data, port.user_addr(), *endpoint);
+            self.tap_sent(data, || Some(port.user_addr()), *endpoint);
+            return Ok(data.len());
+        }
+        let target = *endpoint;
+        let endpoint = self.scoped(self.relay.as_ref().unwrap_or(endpoint));
+        let socket = match (&self.ipv6, endpoint) {
+            (Some(v6), SocketAddr::V6(_)) => &v6.user_unicast_socket,
+            _ => Self::udp(&self.user_unicast_socket)?,
+        };
+        let sent = socket.send_to(data, endpoint)?;
+        self.tap_sent(data, || socket.local_addr().ok(), target);
 
         if Self::should_log_debug() {
-            let src_port = self
-                .user_unicast_socket
-                .local_addr()
-                .map(|addr| addr.port())
-                .unwrap_or(0);
+            let src_port = socket.local_addr().map(|addr| addr.port()).unwrap_or(0);
             log::debug!(
                 "[hdds/udp] v104: send_user_data_unicast -> {} len={} src_port={} iface={}",
                 endpoint,
@@ -460,6 +800,16 @@ impl UdpTransport {
 // ===== Accessors =====
 
 impl UdpTransport {
+    /// Get the stream relay address, if this transport is relayed.
+    pub fn relay_addr(&self) -> Option<SocketAddr> {
+        self.relay
+    }
+
+    /// Get the loopback bus port, if this transport runs on the loopback bus.
+    pub fn loopback_port(&self) -> Option<&LoopbackPort> {
+        self.loopback.as_ref()
+    }
+
     /// Get unicast locators for SPDP announcements.
     ///
     /// Returns a list of SocketAddr (IP:port) for unicast communication.
@@ -467,7 +817,7 @@ impl UdpTransport {
     /// and user data directly to this participant.
     pub fn get_unicast_locators(&self) -> Vec<SocketAddr> {
         crate::trace_fn!("UdpTransport::get_unicast_locators");
-        get_unicast_locators(self.iface, self.metatraffic_unicast_port)
+        self.locators_for_port(self.metatraffic_unicast_port)
     }
 
     /// Get user data unicast locators for SEDP announcements.
@@ -476,13 +826,84 @@ impl UdpTransport {
     /// RTI and other DDS implementations use these locators to send user data
     /// directly to this participant.
     pub fn get_user_unicast_locators(&self, user_port: u16) -> Vec<SocketAddr> {
-        get_unicast_locators(self.iface, user_port)
+        self.locators_for_port(user_port)
+    }
+
+    /// Get multicast locators for `port` (one group per address family).
+    pub fn multicast_locators(&self, port: u16) -> Vec<SocketAddr> {
+        let mut locators = Vec::with_capacity(2);
+        if self.ip_mode.uses_v4() {
+            locators.push(SocketAddr::from((MULTICAST_IP, port)));
+        }
+        if self.ip_mode.uses_v6() {
+            locators.push(SocketAddr::from((Ipv6Addr::from(MULTICAST_IP_V6), port)));
+        }
+        locators
+    }
+
+    /// Get the address families in use.
+    #[must_use]
+    pub fn ip_mode(&self) -> IpMode {
+        self.ip_mode
+    }
+
+    /// Get the interface filter the transport was created with.
+    pub fn interface_filter(&self) -> &InterfaceFilter {
+        &self.interface_filter
+    }
+
+    /// Drop (`true`) or resume (`false`) every send of the transport.
+    ///
+    /// While suspended, the send methods report success without touching
+    /// the network; the sockets stay bound and receiving.
+    pub fn set_suspended(&self, suspended: bool) {
+        self.suspended.store(suspended, Ordering::Relaxed);
+    }
+
+    /// Whether sends are currently dropped.
+    #[must_use]
+    pub fn is_suspended(&self) -> bool {
+        self.suspended.load(Ordering::Relaxed)
+    }
+
+    /// Install the tap called on every datagram sent.
+    ///
+    /// Set once, before the transport is shared; returns `false` (and
+    /// keeps the installed tap) when one is already set. Listeners get the
+    /// tap for received datagrams from [`packet_tap`](Self::packet_tap).
+    pub fn set_packet_tap(&self, tap: PacketTap) -> bool {
+        self.packet_tap.set(tap).is_ok()
+    }
+
+    /// The installed packet tap, if any.
+    #[must_use]
+    pub fn packet_tap(&self) -> Option<PacketTap> {
+        self.packet_tap.get().cloned()
+    }
+
+    /// Get the IPv6 metatraffic sockets (multicast, unicast) of a dual-stack transport.
+    ///
+    /// They need their own listeners: [`socket()`](Self::socket) and
+    /// [`metatraffic_unicast_socket()`](Self::metatraffic_unicast_socket)
+    /// only cover IPv4. Empty in the single-family modes.
+    #[must_use]
+    pub fn dual_stack_sockets(&self) -> Vec<Arc<UdpSocket>> {
+        self.dual_stack()
+            .map(|v6| {
+                vec![
+                    Arc::clone(&v6.socket),
+                    Arc::clone(&v6.metatraffic_unicast_socket),
+                ]
+            })
+            .unwrap_or_default()
     }
 
     /// Get shared socket reference for MulticastListener.
+    ///
+    /// `None` on a loopback bus transport, which has no socket.
     #[must_use]
-    pub fn socket(&self) -> Arc<UdpSocket> {
-        Arc::clone(&self.socket)
+    pub fn socket(&self) -> Option<Arc<UdpSocket>> {
+        self.socket.clone()
     }
 
     /// Get metatraffic unicast socket reference for SEDP reception.
@@ -491,9 +912,11 @@ impl UdpTransport {
     /// and should be used for BOTH sending AND receiving SEDP unicast traffic.
     /// Using a separate listener socket on 0.0.0.0:7410 causes packet delivery issues
     /// because Linux delivers packets to the more specific socket first.
+    ///
+    /// `None` on a loopback bus transport, which has no socket.
     #[must_use]
-    pub fn metatraffic_unicast_socket(&self) -> Arc<UdpSocket> {
-        Arc::clone(&self.metatraffic_unicast_socket)
+    pub fn metatraffic_unicast_socket(&self) -> Option<Arc<UdpSocket>> {
+        self.metatraffic_unicast_socket.clone()
     }
 
     /// Get multicast destination address.
@@ -513,15 +936,102 @@ impl UdpTransport {
     /// Use this to change the multicast hop limit at runtime.
     /// Returns error if setting fails on any socket.
     pub fn set_multicast_ttl(&self, ttl: u8) -> io::Result<()> {
-        ttl::set_multicast_ttl(&self.socket, ttl)?;
-        ttl::set_multicast_ttl(&self.metatraffic_unicast_socket, ttl)?;
-        ttl::set_multicast_ttl(&self.user_unicast_socket, ttl)?;
+        if self.ip_mode.uses_v4() {
+            for sock in [
+                &self.socket,
+                &self.metatraffic_unicast_socket,
+                &self.user_unicast_socket,
+            ]
+            .into_iter()
+            .flatten()
+            {
+                ttl::set_multicast_ttl(sock, ttl)?;
+            }
+        }
+        if let Some(v6) = &self.ipv6 {
+            for sock in v6.sockets() {
+                socket2::SockRef::from(sock.as_ref()).set_multicast_hops_v6(u32::from(ttl))?;
+            }
+        }
         log::debug!("[UDP] Set multicast TTL={} on all sockets", ttl);
         Ok(())
     }
 
+    /// IPv6 stack running next to the IPv4 one (dual-stack mode only).
+    fn dual_stack(&self) -> Option<&Ipv6Stack> {
+        match self.ip_mode {
+            IpMode::Dual => self.ipv6.as_ref(),
+            IpMode::V4 | IpMode::V6 => None,
+        }
+    }
+
+    /// Unicast locators of every address family in use.
+    fn locators_for_port(&self, port: u16) -> Vec<SocketAddr> {
+        if self.loopback.is_some() {
+            return vec![LoopbackPort::locator(port)];
+        }
+        let mut locators = Vec::new();
+        if self.ip_mode.uses_v4() {
+            locators.extend(get_unicast_locators(self.iface, port));
+        }
+        if let Some(v6) = &self.ipv6 {
+            locators.extend(get_unicast_locators_v6(&v6.interfaces, port));
+        }
+        locators
+    }
+
+    /// Socket of a socket-backed transport (every mode but the loopback bus).
+    /// Hand a sent datagram to the packet tap (`source` is only resolved
+    /// when one is installed).
+    fn tap_sent(
+        &self,
+        data: &[u8],
+        source: impl FnOnce() -> Option<SocketAddr>,
+        destination: SocketAddr,
+    ) {
+        if let Some(tap) = self.packet_tap.get() {
+            tap(&CapturedPacket {
+                direction: PacketDirection::Sent,
+                timestamp: SystemTime::now(),
+                source: source(),
+                destination: Some(destination),
+                data,
+            });
+        }
+    }
+
+    fn udp(socket: &Option<Arc<UdpSocket>>) -> io::Result<&Arc<UdpSocket>> {
+        socket.as_ref().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::Unsupported,
+                "loopback bus transport has no socket",
+            )
+        })
+    }
+
+    /// Add our interface as scope of an unscoped link-local IPv6 destination.
+    ///
+    /// Locators carry no scope id, so a peer announcing a link-local address
+    /// is reached through the interface we multicast on.
+    fn scoped(&self, endpoint: &SocketAddr) -> SocketAddr {
+        match (endpoint, &self.ipv6) {
+            (SocketAddr::V6(v6), Some(stack))
+                if v6.scope_id() == 0 && is_ipv6_link_local(v6.ip()) =>
+            {
+                let mut scoped = *v6;
+                scoped.set_scope_id(stack.scope_id);
+                SocketAddr::V6(scoped)
+            }
+            _ => *endpoint,
+        }
+    }
+
     /// Format interface name for logging.
     fn format_iface(&self) -> String {
+        if self.ip_mode == IpMode::V6 {
+            let scope_id = self.ipv6.as_ref().map_or(0, |v6| v6.scope_id);
+            return format!("if#{}", scope_id);
+        }
         if self.iface.octets() == [0, 0, 0, 0] {
             "default".to_string()
         } else {
@@ -594,6 +1104,21 @@ fn setup_data_multicast() -> io::Result<(bool, Option<SocketAddr>)> {
     Ok((force_data_mc, data_multicast_addr))
 }
 
+/// Bind an IPv6-only UDP socket on `[::]:port` (0 = ephemeral).
+fn bind_v6(port: u16, reuseport: bool) -> io::Result<UdpSocket> {
+    let socket2 = Socket::new(Domain::IPV6, Type::DGRAM, Some(Protocol::UDP))?;
+    socket2.set_only_v6(true)?;
+    socket2.set_reuse_address(true)?;
+    #[cfg(unix)]
+    if reuseport {
+        set_reuseport(&socket2)?;
+    }
+    #[cfg(not(unix))]
+    let _ = reuseport;
+    socket2.bind(&SocketAddr::from((Ipv6Addr::UNSPECIFIED, port)).into())?;
+    Ok(socket2.into())
+}
+
 /// v242: Set SO_REUSEPORT on a socket for multi-process port sharing.
 ///
 /// This enables multiple processes to bind to the same port, which is required
@@ -692,9 +1217,110 @@ mod tests {
             PortMapping::calculate(0, 3).expect("Port mapping calculation should succeed");
         let transport =
             UdpTransport::new(0, 3, mapping).expect("Transport creation should succeed");
-        let socket1 = transport.socket();
-        let socket2 = transport.socket();
+        let socket1 = transport.socket().expect("UDP socket");
+        let socket2 = transport.socket().expect("UDP socket");
 
         assert!(Arc::ptr_eq(&socket1, &socket2));
     }
+
+    #[test]
+    fn test_suspended_transport_drops_sends() {
+        let mapping =
+            PortMapping::calculate(97, 0).expect("Port mapping calculation should succeed");
+        let transport =
+            UdpTransport::new(97, 0, mapping).expect("Transport creation should succeed");
+        let peer = UdpSocket::bind("127.0.0.1:0").expect("bind peer");
+        peer.set_read_timeout(Some(std::time::Duration::from_millis(200)))
+            .expect("read timeout");
+        let dest = peer.local_addr().expect("peer addr");
+        let mut buf = [0u8; 64];
+
+        transport.set_suspended(true);
+        assert!(transport.is_suspended());
+        assert_eq!(transport.send_to_endpoint(b"asleep", &dest).ok(), Some(6));
+        assert!(transport.send(b"asleep").is_ok());
+        assert!(
+            peer.recv_from(&mut buf).is_err(),
+            "suspended send reached peer"
+        );
+
+        transport.set_suspended(false);
+        transport
+            .send_to_endpoint(b"awake", &dest)
+            .expect("send after resume");
+        let (len, _) = peer.recv_from(&mut buf).expect("datagram after resume");
+        assert_eq!(&buf[..len], b"awake");
+    }
+
+    #[test]
+    fn test_transport_ipv6_mode() {
+        let mapping =
+            PortMapping::calculate(0, 4).expect("Port mapping calculation should succeed");
+        let transport = UdpTransport::with_ip_mode(0, 4, mapping, IpMode::V6)
+            .expect("IPv6 transport creation should succeed");
+        assert_eq!(transport.ip_mode(), IpMode::V6);
+        assert!(transport.multicast_addr.ip().is_multicast());
+        assert!(transport.multicast_addr.is_ipv6());
+        assert!(transport
+            .get_unicast_locators()
+            .iter()
+            .all(|locator| locator.is_ipv6()));
+        assert!(transport.dual_stack_sockets().is_empty());
+        assert!(transport.send(b"RTPS test packet").is_ok());
+    }
+
+    #[test]
+    fn test_transport_interface_filter() {
+        let mapping =
+            PortMapping::calculate(0, 6).expect("Port mapping calculation should succeed");
+
+        // A filter matching nothing is an error, not a fallback to the OS default
+        let result = UdpTransport::with_interface_filter(
+            0,
+            6,
+            mapping,
+            IpMode::V4,
+            InterfaceFilter::allow_all().deny("*"),
+        );
+        assert_eq!(
+            result.err().map(|err| err.kind()),
+            Some(io::ErrorKind::NotFound)
+        );
+
+        let netifas = local_ip_address::list_afinet_netifas().unwrap_or_default();
+        let Some(name) = netifas.into_iter().find_map(|(name, ip)| match ip {
+            std::net::IpAddr::V4(v4) if !v4.is_loopback() => Some(name),
+            _ => None,
+        }) else {
+            return; // No network interface to select
+        };
+        let transport = UdpTransport::with_interface_filter(
+            0,
+            6,
+            mapping,
+            IpMode::V4,
+            InterfaceFilter::allow_all().allow(&name),
+        )
+        .expect("filtered transport creation should succeed");
+        assert!(transport.interface_filter().allows_name(&name));
+        if std::env::var("HDDS_UNICAST_IF").is_err() && std::env::var("HDDS_MULTICAST_IF").is_err()
+        {
+            let allowed: Vec<Ipv4Addr> = netifas_v4_of(&name);
+            assert!(transport.get_unicast_locators().iter().all(
+                |locator| matches!(locator.ip(), std::net::IpAddr::V4(v4) if allowed.contains(&v4))
+            ));
+        }
+    }
+
+    fn netifas_v4_of(name: &str) -> Vec<Ipv4Addr> {
+        local_ip_address::list_afinet_netifas()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(iface, _)| iface == name)
+            .filter_map(|(_, ip)| match ip {
+                std::net::IpAddr::V4(v4) => Some(v4),
+                std::net::IpAddr::V6(_) => None,
+            })
+            .collect()
+    }
 }
@@ -0,0 +1,164 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Unix domain socket transport configuration.
+//!
+//! # Example
+//!
+//! ```
+//! use hdds::transport::uds::UdsConfig;
+//!
+//! // Directory bind-mounted into every container of the pod
+//! let config = UdsConfig::enabled().with_socket_dir("/var/run/hdds");
+//! assert!(config.validate().is_ok());
+//! ```
+
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding the default socket directory.
+pub const UDS_DIR_ENV: &str = "HDDS_UDS_DIR";
+
+/// Default maximum datagram size (256 KB).
+pub const DEFAULT_UDS_MAX_MESSAGE_SIZE: usize = 256 * 1024;
+
+/// Unix domain socket transport configuration.
+///
+/// Every participant binds one datagram socket named after its domain and
+/// GUID prefix inside `socket_dir`. Peers on the same host (or in containers
+/// sharing that directory through a volume) address each other by path, so
+/// no port allocation or SHM segment sharing is required.
+#[derive(Clone, Debug)]
+pub struct UdsConfig {
+    /// Enable UDS transport (opt-in, disabled by default)
+    pub enabled: bool,
+
+    /// Directory holding the participant sockets.
+    ///
+    /// Defaults to `$HDDS_UDS_DIR`, or `<tmp>/hdds` when unset.
+    pub socket_dir: PathBuf,
+
+    /// Maximum message size in bytes.
+    ///
+    /// Datagrams larger than this are rejected on send and truncated
+    /// datagrams are dropped on receive.
+    pub max_message_size: usize,
+
+    /// SO_SNDBUF socket option (0 = OS default)
+    pub socket_send_buffer: usize,
+
+    /// SO_RCVBUF socket option (0 = OS default)
+    pub socket_recv_buffer: usize,
+}
+
+impl Default for UdsConfig {
+    fn default() -> Self {
+        let socket_dir = std::env::var_os(UDS_DIR_ENV)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| std::env::temp_dir().join("hdds"));
+
+        Self {
+            enabled: false, // Opt-in
+            socket_dir,
+            max_message_size: DEFAULT_UDS_MAX_MESSAGE_SIZE,
+            socket_send_buffer: 0, // OS default
+            socket_recv_buffer: 0, // OS default
+        }
+    }
+}
+
+impl UdsConfig {
+    /// Create a new UDS config with UDS enabled.
+    pub fn enabled() -> Self {
+        Self {
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    /// Builder: set socket directory
+    pub fn with_socket_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.socket_dir = dir.into();
+        self
+    }
+
+    /// Builder: set max message size
+    pub fn with_max_message_size(mut self, size: usize) -> Self {
+        self.max_message_size = size;
+        self
+    }
+
+    /// Builder: set SO_SNDBUF / SO_RCVBUF
+    pub fn with_socket_buffers(mut self, send: usize, recv: usize) -> Self {
+        self.socket_send_buffer = send;
+        self.socket_recv_buffer = recv;
+        self
+    }
+
+    /// Socket path of the participant `guid_prefix` in `domain_id`.
+    pub fn socket_path(&self, domain_id: u32, guid_prefix: &[u8; 12]) -> PathBuf {
+        socket_path_in(&self.socket_dir, domain_id, guid_prefix)
+    }
+
+    /// Validate configuration, returning error message if invalid.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if self.max_message_size == 0 {
+            return Err("max_message_size must be > 0");
+        }
+        if self.max_message_size > 16 * 1024 * 1024 {
+            return Err("max_message_size too large (> 16 MB)");
+        }
+        if self.socket_dir.as_os_str().is_empty() {
+            return Err("socket_dir must not be empty");
+        }
+        // sun_path is 108 bytes on Linux (104 on BSD/macOS), including NUL.
+        let probe = self.socket_path(u32::MAX, &[0; 12]);
+        if probe.as_os_str().len() >= 104 {
+            return Err("socket_dir too long for a Unix socket path");
+        }
+        Ok(())
+    }
+}
+
+fn socket_path_in(dir: &Path, domain_id: u32, guid_prefix: &[u8; 12]) -> PathBuf {
+    let mut name = format!("hdds-d{}-", domain_id);
+    for byte in guid_prefix {
+        name.push_str(&format!("{:02x}", byte));
+    }
+    name.push_str(".sock");
+    dir.join(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config() {
+        let config = UdsConfig::default();
+        assert!(!config.enabled);
+        assert_eq!(config.max_message_size, DEFAULT_UDS_MAX_MESSAGE_SIZE);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_socket_path_naming() {
+        let config = UdsConfig::enabled().with_socket_dir("/run/hdds");
+        let path = config.socket_path(3, &[0xAB; 12]);
+        assert_eq!(
+            path,
+            PathBuf::from("/run/hdds/hdds-d3-abababababababababababab.sock")
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_values() {
+        assert!(UdsConfig::enabled()
+            .with_max_message_size(0)
+            .validate()
+            .is_err());
+        assert!(UdsConfig::enabled()
+            .with_socket_dir("/".repeat(100))
+            .validate()
+            .is_err());
+    }
+}
@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Unix domain socket transport for RTPS communication.
+//!
+//! Middle ground between SHM and loopback UDP for participants on the same
+//! host that cannot share SHM segments, typically containers with separate
+//! IPC namespaces. Bind-mounting one directory into each container is
+//! enough for them to exchange RTPS messages over `AF_UNIX` datagrams,
+//! skipping the IP stack (checksums, routing, loopback device).
+//!
+//! Select it with [`TransportPreference::UdsPreferred`]: UDP still handles
+//! SPDP discovery and remote peers, UDS carries traffic between peers that
+//! have a socket in the shared directory.
+//!
+//! # Interoperability
+//!
+//! Like TCP, RTPS over UDS is **HDDS <-> HDDS** only.
+//!
+//! # Modules
+//!
+//! - `config` - Configuration types (`UdsConfig`)
+//! - `transport` - Datagram transport (`UdsTransport`, Unix only)
+//!
+//! [`TransportPreference::UdsPreferred`]: crate::transport::tcp::TransportPreference::UdsPreferred
+
+pub mod config;
+#[cfg(unix)]
+pub mod transport;
+
+// ============================================================================
+// Re-exports
+// ============================================================================
+
+pub use config::{UdsConfig, DEFAULT_UDS_MAX_MESSAGE_SIZE, UDS_DIR_ENV};
+#[cfg(unix)]
+pub use transport::{UdsTransport, UdsTransportEvent, UdsTransportStats};
@@ -0,0 +1,336 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! High-level Unix domain socket transport interface.
+//!
+//! Provides [`UdsTransport`], a datagram transport between participants on
+//! the same host. Unlike TCP no framing or connection state is needed:
+//! `SOCK_DGRAM` on `AF_UNIX` is reliable and preserves message boundaries,
+//! so one RTPS message maps to one datagram.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use hdds::transport::uds::{UdsConfig, UdsTransport, UdsTransportEvent};
+//!
+//! let transport = UdsTransport::new(0, local_guid, UdsConfig::enabled())?;
+//!
+//! // Peers are addressed by GUID prefix (learned from SPDP)
+//! transport.send(&remote_guid, &rtps_message)?;
+//!
+//! for event in transport.poll_timeout(Duration::from_millis(50)) {
+//!     if let UdsTransportEvent::MessageReceived { payload, .. } = event {
+//!         // Handle RTPS message
+//!     }
+//! }
+//! ```
+
+use std::io;
+use std::os::unix::io::AsRawFd;
+use std::os::unix::net::UnixDatagram;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use super::UdsConfig;
+
+/// Upper bound on datagrams drained per poll call.
+const MAX_MESSAGES_PER_POLL: usize = 256;
+
+// ============================================================================
+// Transport Events
+// ============================================================================
+
+/// Events emitted by the UDS transport.
+#[derive(Debug)]
+pub enum UdsTransportEvent {
+    /// Received an RTPS message.
+    MessageReceived {
+        /// Message payload.
+        payload: Vec<u8>,
+        /// Sender socket path (None for unnamed senders).
+        from: Option<PathBuf>,
+    },
+}
+
+/// UDS transport counters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UdsTransportStats {
+    /// Datagrams sent.
+    pub messages_sent: u64,
+    /// Datagrams received.
+    pub messages_received: u64,
+    /// Sends that failed (peer gone, queue full, oversized).
+    pub send_errors: u64,
+    /// Received datagrams dropped because they exceeded `max_message_size`.
+    pub oversized_dropped: u64,
+}
+
+// ============================================================================
+// UdsTransport
+// ============================================================================
+
+/// Unix domain socket transport for same-host participants.
+///
+/// Binds `<socket_dir>/hdds-d<domain>-<guid_prefix>.sock`; the file is
+/// removed when the transport is dropped.
+pub struct UdsTransport {
+    socket: UnixDatagram,
+    path: PathBuf,
+    domain_id: u32,
+    local_guid: [u8; 12],
+    config: UdsConfig,
+    messages_sent: AtomicU64,
+    messages_received: AtomicU64,
+    send_errors: AtomicU64,
+    oversized_dropped: AtomicU64,
+}
+
+impl UdsTransport {
+    /// Create and bind a UDS transport.
+    ///
+    /// Creates `socket_dir` if needed and replaces a stale socket file left
+    /// behind by a crashed participant with the same GUID prefix.
+    pub fn new(domain_id: u32, local_guid: [u8; 12], config: UdsConfig) -> io::Result<Self> {
+        config
+            .validate()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        std::fs::create_dir_all(&config.socket_dir)?;
+        let path = config.socket_path(domain_id, &local_guid);
+        match std::fs::remove_file(&path) {
+            Ok(()) => log::debug!("[uds] Removed stale socket {}", path.display()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+
+        let socket = UnixDatagram::bind(&path)?;
+        socket.set_nonblocking(true)?;
+
+        let sock_ref = socket2::SockRef::from(&socket);
+        if config.socket_send_buffer > 0 {
+            sock_ref.set_send_buffer_size(config.socket_send_buffer)?;
+        }
+        if config.socket_recv_buffer > 0 {
+            sock_ref.set_recv_buffer_size(config.socket_recv_buffer)?;
+        }
+
+        log::debug!("[uds] Bound {}", path.display());
+
+        Ok(Self {
+            socket,
+            path,
+            domain_id,
+            local_guid,
+            config,
+            messages_sent: AtomicU64::new(0),
+            messages_received: AtomicU64::new(0),
+            send_errors: AtomicU64::new(0),
+            oversized_dropped: AtomicU64::new(0),
+        })
+    }
+
+    /// Local GUID prefix.
+    pub fn local_guid(&self) -> &[u8; 12] {
+        &self.local_guid
+    }
+
+    /// Path of the bound socket.
+    pub fn local_path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Get configuration.
+    pub fn config(&self) -> &UdsConfig {
+        &self.config
+    }
+
+    /// Socket path a remote participant would be bound to.
+    pub fn peer_path(&self, remote_guid: &[u8; 12]) -> PathBuf {
+        self.config.socket_path(self.domain_id, remote_guid)
+    }
+
+    /// Check whether a remote participant has a socket in our directory.
+    ///
+    /// A `true` result means the peer is reachable over UDS (same host, or a
+    /// container sharing the socket directory).
+    pub fn has_peer(&self, remote_guid: &[u8; 12]) -> bool {
+        self.peer_path(remote_guid).exists()
+    }
+
+    /// Send an RTPS message to a remote participant.
+    pub fn send(&self, remote_guid: &[u8; 12], payload: &[u8]) -> io::Result<()> {
+        self.send_to_path(&self.peer_path(remote_guid), payload)
+    }
+
+    /// Send an RTPS message to an explicit socket path.
+    pub fn send_to_path(&self, path: &Path, payload: &[u8]) -> io::Result<()> {
+        if payload.len() > self.config.max_message_size {
+            self.send_errors.fetch_add(1, Ordering::Relaxed);
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "message of {} bytes exceeds UDS max_message_size {}",
+                    payload.len(),
+                    self.config.max_message_size
+                ),
+            ));
+        }
+
+        match self.socket.send_to(payload, path) {
+            Ok(_) => {
+                self.messages_sent.fetch_add(1, Ordering::Relaxed);
+                Ok(())
+            }
+            Err(e) => {
+                self.send_errors.fetch_add(1, Ordering::Relaxed);
+                Err(e)
+            }
+        }
+    }
+
+    /// Poll for received messages without blocking.
+    pub fn poll(&self) -> Vec<UdsTransportEvent> {
+        self.poll_timeout(Duration::ZERO)
+    }
+
+    /// Poll for received messages, waiting up to `timeout` for the first one.
+    pub fn poll_timeout(&self, timeout: Duration) -> Vec<UdsTransportEvent> {
+        let mut events = Vec::new();
+        if !self.wait_readable(timeout) {
+            return events;
+        }
+
+        // One extra byte detects datagrams larger than max_message_size.
+        let mut buf = vec![0u8; self.config.max_message_size + 1];
+        while events.len() < MAX_MESSAGES_PER_POLL {
+            match self.socket.recv_from(&mut buf) {
+                Ok((len, addr)) => {
+                    if len > self.config.max_message_size {
+                        self.oversized_dropped.fetch_add(1, Ordering::Relaxed);
+                        log::debug!("[uds] Dropped oversized datagram ({}+ bytes)", len);
+                        continue;
+                    }
+                    self.messages_received.fetch_add(1, Ordering::Relaxed);
+                    events.push(UdsTransportEvent::MessageReceived {
+                        payload: buf[..len].to_vec(),
+                        from: addr.as_pathname().map(Path::to_path_buf),
+                    });
+                }
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => {
+                    log::debug!("[uds] recv_from failed: {}", e);
+                    break;
+                }
+            }
+        }
+        events
+    }
+
+    /// Get a snapshot of transport counters.
+    pub fn stats(&self) -> UdsTransportStats {
+        UdsTransportStats {
+            messages_sent: self.messages_sent.load(Ordering::Relaxed),
+            messages_received: self.messages_received.load(Ordering::Relaxed),
+            send_errors: self.send_errors.load(Ordering::Relaxed),
+            oversized_dropped: self.oversized_dropped.load(Ordering::Relaxed),
+        }
+    }
+
+    fn wait_readable(&self, timeout: Duration) -> bool {
+        let mut pfd = libc::pollfd {
+            fd: self.socket.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        let timeout_ms = timeout.as_millis().min(i32::MAX as u128) as i32;
+        // SAFETY: pfd is a valid pollfd for the lifetime of the call and nfds is 1.
+        let ready = unsafe { libc::poll(&mut pfd, 1, timeout_ms) };
+        ready > 0 && (pfd.revents & libc::POLLIN) != 0
+    }
+}
+
+impl Drop for UdsTransport {
+    fn drop(&mut self) {
+        if let Err(e) = std::fs::remove_file(&self.path) {
+            log::debug!("[uds] Failed to remove {}: {}", self.path.display(), e);
+        }
+    }
+}
+
+impl std::fmt::Debug for UdsTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UdsTransport")
+            .field("path", &self.path)
+            .field("domain_id", &self.domain_id)
+            .finish_non_exhaustive()
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(dir: &Path) -> UdsConfig {
+        UdsConfig::enabled()
+            .with_socket_dir(dir)
+            .with_max_message_size(1024)
+    }
+
+    #[test]
+    fn test_send_and_receive_between_participants() {
+        let dir = tempfile::tempdir().unwrap();
+        let a = UdsTransport::new(0, [0x0A; 12], config(dir.path())).unwrap();
+        let b = UdsTransport::new(0, [0x0B; 12], config(dir.path())).unwrap();
+
+        assert!(a.has_peer(&[0x0B; 12]));
+        assert!(!a.has_peer(&[0x0C; 12]));
+
+        a.send(&[0x0B; 12], b"RTPS-one").unwrap();
+        a.send(&[0x0B; 12], b"RTPS-two").unwrap();
+
+        let events = b.poll_timeout(Duration::from_millis(500));
+        let payloads: Vec<_> = events
+            .into_iter()
+            .map(|UdsTransportEvent::MessageReceived { payload, from }| {
+                assert_eq!(from.as_deref(), Some(a.local_path()));
+                payload
+            })
+            .collect();
+        assert_eq!(payloads, vec![b"RTPS-one".to_vec(), b"RTPS-two".to_vec()]);
+        assert_eq!(a.stats().messages_sent, 2);
+        assert_eq!(b.stats().messages_received, 2);
+    }
+
+    #[test]
+    fn test_oversized_send_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let a = UdsTransport::new(0, [0x01; 12], config(dir.path())).unwrap();
+        let _b = UdsTransport::new(0, [0x02; 12], config(dir.path())).unwrap();
+
+        let err = a.send(&[0x02; 12], &[0u8; 2048]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(a.stats().send_errors, 1);
+    }
+
+    #[test]
+    fn test_socket_file_lifecycle() {
+        let dir = tempfile::tempdir().unwrap();
+        let cfg = config(dir.path());
+        let path = cfg.socket_path(7, &[0x11; 12]);
+
+        // A leftover file from a crashed participant is replaced.
+        std::fs::write(&path, b"stale").unwrap();
+        let a = UdsTransport::new(7, [0x11; 12], cfg).unwrap();
+        assert_eq!(a.local_path(), path);
+        assert!(a.poll().is_empty());
+
+        drop(a);
+        assert!(!path.exists());
+    }
+}
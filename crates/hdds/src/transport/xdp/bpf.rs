@@ -0,0 +1,267 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Minimal eBPF plumbing for the AF_XDP path, via the raw `bpf(2)` syscall.
+//!
+//! The built-in program is assembled here instead of shipping an ELF object
+//! so HDDS needs neither clang nor libbpf. It redirects unfragmented,
+//! option-less IPv4/UDP packets whose destination port is in a range to the
+//! XSKMAP slot of the receiving queue, and passes everything else (ARP,
+//! SSH, discovery traffic, ...) to the kernel.
+
+use std::ffi::CString;
+use std::io;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::path::Path;
+
+// enum bpf_cmd
+const BPF_MAP_CREATE: libc::c_long = 0;
+const BPF_MAP_UPDATE_ELEM: libc::c_long = 2;
+const BPF_PROG_LOAD: libc::c_long = 5;
+const BPF_OBJ_GET: libc::c_long = 7;
+const BPF_LINK_CREATE: libc::c_long = 28;
+
+const BPF_MAP_TYPE_XSKMAP: u32 = 17;
+const BPF_PROG_TYPE_XDP: u32 = 6;
+const BPF_XDP: u32 = 37;
+const BPF_FUNC_REDIRECT_MAP: i32 = 51;
+const BPF_PSEUDO_MAP_FD: u8 = 1;
+
+/// `XDP_FLAGS_SKB_MODE`
+pub(super) const XDP_FLAGS_SKB_MODE: u32 = 1 << 1;
+/// `XDP_FLAGS_DRV_MODE`
+pub(super) const XDP_FLAGS_DRV_MODE: u32 = 1 << 2;
+
+const XDP_PASS: i32 = 2;
+
+/// `union bpf_attr` is passed by size; 128 bytes covers every command used.
+const ATTR_SIZE: usize = 128;
+
+#[repr(C, align(8))]
+struct BpfAttr([u8; ATTR_SIZE]);
+
+impl BpfAttr {
+    fn new() -> Self {
+        Self([0; ATTR_SIZE])
+    }
+
+    fn put_u32(&mut self, offset: usize, value: u32) -> &mut Self {
+        self.0[offset..offset + 4].copy_from_slice(&value.to_ne_bytes());
+        self
+    }
+
+    fn put_u64(&mut self, offset: usize, value: u64) -> &mut Self {
+        self.0[offset..offset + 8].copy_from_slice(&value.to_ne_bytes());
+        self
+    }
+}
+
+fn sys_bpf(cmd: libc::c_long, attr: &mut BpfAttr) -> io::Result<libc::c_long> {
+    // SAFETY: attr is a valid, writable bpf_attr of ATTR_SIZE bytes.
+    let ret = unsafe { libc::syscall(libc::SYS_bpf, cmd, attr.0.as_mut_ptr(), ATTR_SIZE) };
+    if ret < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(ret)
+}
+
+fn sys_bpf_fd(cmd: libc::c_long, attr: &mut BpfAttr) -> io::Result<OwnedFd> {
+    let fd = sys_bpf(cmd, attr)? as libc::c_int;
+    // SAFETY: the kernel returned a fresh file descriptor we now own.
+    Ok(unsafe { OwnedFd::from_raw_fd(fd) })
+}
+
+// ============================================================================
+// Maps
+// ============================================================================
+
+/// Create an XSKMAP with `max_entries` queue slots.
+pub(super) fn create_xsk_map(max_entries: u32) -> io::Result<OwnedFd> {
+    let mut attr = BpfAttr::new();
+    attr.put_u32(0, BPF_MAP_TYPE_XSKMAP)
+        .put_u32(4, 4) // key_size
+        .put_u32(8, 4) // value_size
+        .put_u32(12, max_entries);
+    sys_bpf_fd(BPF_MAP_CREATE, &mut attr)
+}
+
+/// Open a map pinned in bpffs.
+pub(super) fn open_pinned(path: &Path) -> io::Result<OwnedFd> {
+    let cpath = CString::new(path.as_os_str().as_encoded_bytes())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    let mut attr = BpfAttr::new();
+    attr.put_u64(0, cpath.as_ptr() as u64);
+    sys_bpf_fd(BPF_OBJ_GET, &mut attr)
+}
+
+/// Point XSKMAP slot `queue_id` at socket `xsk_fd`.
+pub(super) fn map_insert_socket(
+    map: &OwnedFd,
+    queue_id: u32,
+    xsk_fd: libc::c_int,
+) -> io::Result<()> {
+    let key = queue_id;
+    let value = xsk_fd as u32;
+    let mut attr = BpfAttr::new();
+    attr.put_u32(0, map.as_raw_fd() as u32)
+        .put_u64(8, &key as *const u32 as u64)
+        .put_u64(16, &value as *const u32 as u64);
+    sys_bpf(BPF_MAP_UPDATE_ELEM, &mut attr).map(|_| ())
+}
+
+// ============================================================================
+// Program
+// ============================================================================
+
+/// One eBPF instruction (`struct bpf_insn`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct Insn {
+    code: u8,
+    regs: u8,
+    off: i16,
+    imm: i32,
+}
+
+const fn insn(code: u8, dst: u8, src: u8, off: i16, imm: i32) -> Insn {
+    Insn {
+        code,
+        regs: (src << 4) | dst,
+        off,
+        imm,
+    }
+}
+
+// Opcodes used by the program (class | op | source/size)
+const LDX_W: u8 = 0x61;
+const LDX_H: u8 = 0x69;
+const LDX_B: u8 = 0x71;
+const MOV64_X: u8 = 0xbf;
+const MOV64_K: u8 = 0xb7;
+const ADD64_K: u8 = 0x07;
+const AND64_K: u8 = 0x57;
+const END_TO_BE: u8 = 0xdc;
+const JGT_X: u8 = 0x2d;
+const JGT_K: u8 = 0x25;
+const JLT_K: u8 = 0xa5;
+const JNE_K: u8 = 0x55;
+const LD_IMM64: u8 = 0x18;
+const CALL: u8 = 0x85;
+const EXIT: u8 = 0x95;
+
+/// Assemble the port-filtering redirect program.
+///
+/// Multi-byte header fields are compared as the little-endian loads the
+/// VM performs (e.g. ethertype 0x0800 reads as 0x0008), except the
+/// destination port, which is byte-swapped first to compare against the
+/// host-order range.
+pub(super) fn redirect_program(map_fd: libc::c_int, first_port: u16, last_port: u16) -> Vec<Insn> {
+    // (instruction, jumps to PASS)
+    let body: Vec<(Insn, bool)> = vec![
+        (insn(LDX_W, 2, 1, 0, 0), false),        // r2 = ctx->data
+        (insn(LDX_W, 3, 1, 4, 0), false),        // r3 = ctx->data_end
+        (insn(MOV64_X, 4, 2, 0, 0), false),      // r4 = r2
+        (insn(ADD64_K, 4, 0, 0, 42), false),     // r4 += eth(14) + ip(20) + udp(8)
+        (insn(JGT_X, 4, 3, 0, 0), true),         // if r4 > data_end -> PASS
+        (insn(LDX_H, 5, 2, 12, 0), false),       // r5 = ethertype
+        (insn(JNE_K, 5, 0, 0, 0x0008), true),    // != IPv4 -> PASS
+        (insn(LDX_B, 5, 2, 14, 0), false),       // r5 = version/IHL
+        (insn(JNE_K, 5, 0, 0, 0x45), true),      // IP options -> PASS
+        (insn(LDX_H, 5, 2, 20, 0), false),       // r5 = flags/fragment offset
+        (insn(AND64_K, 5, 0, 0, 0xFF3F), false), // MF | offset
+        (insn(JNE_K, 5, 0, 0, 0), true),         // fragment -> PASS
+        (insn(LDX_B, 5, 2, 23, 0), false),       // r5 = protocol
+        (insn(JNE_K, 5, 0, 0, 17), true),        // != UDP -> PASS
+        (insn(LDX_H, 5, 2, 36, 0), false),       // r5 = UDP dst port (network order)
+        (insn(END_TO_BE, 5, 0, 0, 16), false),   // r5 = ntohs(r5)
+        (insn(JLT_K, 5, 0, 0, i32::from(first_port)), true),
+        (insn(JGT_K, 5, 0, 0, i32::from(last_port)), true),
+        (insn(LDX_W, 2, 1, 16, 0), false), // r2 = ctx->rx_queue_index
+        (insn(LD_IMM64, 1, BPF_PSEUDO_MAP_FD, 0, map_fd), false), // r1 = &xsk_map
+        (insn(0, 0, 0, 0, 0), false),      // (ld_imm64 upper half)
+        (insn(MOV64_K, 3, 0, 0, XDP_PASS), false), // fallback if slot is empty
+        (insn(CALL, 0, 0, 0, BPF_FUNC_REDIRECT_MAP), false),
+        (insn(EXIT, 0, 0, 0, 0), false),
+    ];
+
+    let pass = body.len() as i16;
+    let mut prog: Vec<Insn> = body
+        .into_iter()
+        .enumerate()
+        .map(|(i, (mut ins, to_pass))| {
+            if to_pass {
+                ins.off = pass - (i as i16 + 1);
+            }
+            ins
+        })
+        .collect();
+    // PASS:
+    prog.push(insn(MOV64_K, 0, 0, 0, XDP_PASS));
+    prog.push(insn(EXIT, 0, 0, 0, 0));
+    prog
+}
+
+/// Load an XDP program and return its fd. The verifier log is included in
+/// the error on rejection.
+pub(super) fn load_xdp_program(prog: &[Insn]) -> io::Result<OwnedFd> {
+    const LOG_SIZE: usize = 64 * 1024;
+    let license = b"Dual MIT/GPL\0";
+    let name = b"hdds_xsk\0";
+    let mut log = vec![0u8; LOG_SIZE];
+
+    let mut attr = BpfAttr::new();
+    attr.put_u32(0, BPF_PROG_TYPE_XDP)
+        .put_u32(4, prog.len() as u32)
+        .put_u64(8, prog.as_ptr() as u64)
+        .put_u64(16, license.as_ptr() as u64)
+        .put_u32(24, 1) // log_level
+        .put_u32(28, LOG_SIZE as u32)
+        .put_u64(32, log.as_mut_ptr() as u64)
+        .put_u32(68, BPF_XDP); // expected_attach_type
+    attr.0[48..48 + name.len()].copy_from_slice(name);
+
+    sys_bpf_fd(BPF_PROG_LOAD, &mut attr).map_err(|e| {
+        let end = log.iter().position(|&b| b == 0).unwrap_or(0);
+        let verifier = String::from_utf8_lossy(&log[..end]);
+        io::Error::new(
+            e.kind(),
+            format!("BPF_PROG_LOAD failed: {} {}", e, verifier.trim()),
+        )
+    })
+}
+
+/// Attach `prog` to `ifindex` with a BPF link; closing the returned fd
+/// detaches it, so the program never outlives the process.
+pub(super) fn attach_xdp(prog: &OwnedFd, ifindex: u32, flags: u32) -> io::Result<OwnedFd> {
+    let mut attr = BpfAttr::new();
+    attr.put_u32(0, prog.as_raw_fd() as u32)
+        .put_u32(4, ifindex)
+        .put_u32(8, BPF_XDP)
+        .put_u32(12, flags);
+    sys_bpf_fd(BPF_LINK_CREATE, &mut attr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redirect_program_shape() {
+        let prog = redirect_program(7, 7411, 7411);
+        assert_eq!(std::mem::size_of::<Insn>(), 8);
+        assert_eq!(prog.last().unwrap().code, EXIT);
+
+        // Every conditional jump lands on the PASS block.
+        let pass = prog.len() - 2;
+        for (i, ins) in prog.iter().enumerate() {
+            if matches!(ins.code, JGT_X | JGT_K | JLT_K | JNE_K) {
+                assert_eq!(i as i64 + 1 + i64::from(ins.off), pass as i64, "insn {}", i);
+            }
+        }
+
+        let ld = prog.iter().find(|i| i.code == LD_IMM64).unwrap();
+        assert_eq!(ld.imm, 7);
+        assert_eq!(ld.regs >> 4, BPF_PSEUDO_MAP_FD);
+        assert!(prog.iter().any(|i| i.code == JLT_K && i.imm == 7411));
+    }
+}
@@ -0,0 +1,216 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! AF_XDP receive path configuration.
+//!
+//! # Example
+//!
+//! ```
+//! use hdds::transport::xdp::{XdpBindMode, XdpConfig};
+//!
+//! let config = XdpConfig::enabled("ens1f0")
+//!     .with_queue(3)
+//!     .with_bind_mode(XdpBindMode::ZeroCopy);
+//! assert!(config.validate().is_ok());
+//! ```
+
+use std::path::PathBuf;
+
+/// How the socket is bound to the NIC queue.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum XdpBindMode {
+    /// Try zero-copy, fall back to copy mode if the driver lacks support.
+    #[default]
+    Auto,
+    /// Require driver zero-copy support (frames DMA'd straight into UMEM).
+    ZeroCopy,
+    /// Copy mode (works on any driver, including generic/SKB XDP).
+    Copy,
+}
+
+/// Where the XDP program that redirects packets comes from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum XdpProgramSource {
+    /// Load and attach HDDS's built-in program: IPv4/UDP packets whose
+    /// destination port is in the configured range go to the socket,
+    /// everything else continues to the kernel stack.
+    #[default]
+    BuiltIn,
+    /// Use an XSKMAP pinned in bpffs by an externally managed program
+    /// (e.g. loaded with `xdp-loader`); HDDS only inserts its socket.
+    PinnedMap(PathBuf),
+}
+
+/// AF_XDP receive configuration.
+///
+/// The UMEM (`frame_count` x `frame_size` bytes) is the receive buffer pool:
+/// the NIC writes packets into its frames and HDDS parses RTPS straight out
+/// of them before handing the frame back to the fill ring.
+#[derive(Clone, Debug)]
+pub struct XdpConfig {
+    /// Enable the AF_XDP receive path (opt-in, disabled by default)
+    pub enabled: bool,
+
+    /// Network interface name (e.g. "ens1f0").
+    pub interface: String,
+
+    /// NIC receive queue to bind. Only packets steered to this queue (RSS or
+    /// `ethtool -N` flow rules) take the XDP path; others use the kernel.
+    pub queue_id: u32,
+
+    /// Zero-copy / copy mode selection.
+    pub bind_mode: XdpBindMode,
+
+    /// Attach the built-in program in generic (SKB) mode instead of driver
+    /// mode. Slower, but works on NICs without native XDP.
+    pub generic_mode: bool,
+
+    /// Program source.
+    pub program: XdpProgramSource,
+
+    /// Inclusive UDP destination port range to redirect.
+    /// None = the participant's user unicast port.
+    pub ports: Option<(u16, u16)>,
+
+    /// Number of UMEM frames.
+    pub frame_count: u32,
+
+    /// Size of one UMEM frame (power of two, 2048..=4096).
+    pub frame_size: u32,
+
+    /// Fill ring entries (power of two).
+    pub fill_ring_size: u32,
+
+    /// RX ring entries (power of two).
+    pub rx_ring_size: u32,
+
+    /// Request `XDP_USE_NEED_WAKEUP` so the driver only gets kicked when the
+    /// fill ring actually ran dry.
+    pub need_wakeup: bool,
+}
+
+impl Default for XdpConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false, // Opt-in
+            interface: String::new(),
+            queue_id: 0,
+            bind_mode: XdpBindMode::Auto,
+            generic_mode: false,
+            program: XdpProgramSource::BuiltIn,
+            ports: None,
+            frame_count: 8192,
+            frame_size: 4096,
+            fill_ring_size: 4096,
+            rx_ring_size: 4096,
+            need_wakeup: true,
+        }
+    }
+}
+
+impl XdpConfig {
+    /// Create a new XDP config with the receive path enabled on `interface`.
+    pub fn enabled(interface: impl Into<String>) -> Self {
+        Self {
+            enabled: true,
+            interface: interface.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Builder: bind a NIC receive queue
+    pub fn with_queue(mut self, queue_id: u32) -> Self {
+        self.queue_id = queue_id;
+        self
+    }
+
+    /// Builder: set bind mode
+    pub fn with_bind_mode(mut self, mode: XdpBindMode) -> Self {
+        self.bind_mode = mode;
+        self
+    }
+
+    /// Builder: attach the built-in program in generic (SKB) mode
+    pub fn with_generic_mode(mut self, generic: bool) -> Self {
+        self.generic_mode = generic;
+        self
+    }
+
+    /// Builder: use an externally pinned XSKMAP
+    pub fn with_pinned_map(mut self, path: impl Into<PathBuf>) -> Self {
+        self.program = XdpProgramSource::PinnedMap(path.into());
+        self
+    }
+
+    /// Builder: set the inclusive UDP destination port range to redirect
+    pub fn with_ports(mut self, first: u16, last: u16) -> Self {
+        self.ports = Some((first, last));
+        self
+    }
+
+    /// Builder: size UMEM and rings
+    pub fn with_umem(mut self, frame_count: u32, frame_size: u32) -> Self {
+        self.frame_count = frame_count;
+        self.frame_size = frame_size;
+        self
+    }
+
+    /// Builder: set fill and RX ring sizes
+    pub fn with_rings(mut self, fill_ring_size: u32, rx_ring_size: u32) -> Self {
+        self.fill_ring_size = fill_ring_size;
+        self.rx_ring_size = rx_ring_size;
+        self
+    }
+
+    /// Total UMEM size in bytes.
+    pub fn umem_bytes(&self) -> usize {
+        self.frame_count as usize * self.frame_size as usize
+    }
+
+    /// Validate configuration, returning error message if invalid.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if self.interface.is_empty() {
+            return Err("interface must be set");
+        }
+        if !self.frame_size.is_power_of_two() || !(2048..=4096).contains(&self.frame_size) {
+            return Err("frame_size must be 2048 or 4096");
+        }
+        if !self.fill_ring_size.is_power_of_two() || !self.rx_ring_size.is_power_of_two() {
+            return Err("ring sizes must be powers of two");
+        }
+        if self.frame_count < self.fill_ring_size {
+            return Err("frame_count must be >= fill_ring_size");
+        }
+        if let Some((first, last)) = self.ports {
+            if first == 0 || first > last {
+                return Err("port range must be non-empty and exclude 0");
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config() {
+        let config = XdpConfig::default();
+        assert!(!config.enabled);
+        assert_eq!(config.umem_bytes(), 32 * 1024 * 1024);
+        // No interface yet
+        assert!(config.validate().is_err());
+        assert!(XdpConfig::enabled("eth0").validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_geometry() {
+        let base = XdpConfig::enabled("eth0");
+        assert!(base.clone().with_umem(8192, 3000).validate().is_err());
+        assert!(base.clone().with_umem(1024, 2048).validate().is_err());
+        assert!(base.clone().with_rings(4096, 1000).validate().is_err());
+        assert!(base.clone().with_ports(7411, 7410).validate().is_err());
+        assert!(base.with_ports(7410, 7420).validate().is_ok());
+    }
+}
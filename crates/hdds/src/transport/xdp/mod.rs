@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! AF_XDP accelerated receive path for user traffic (Linux, experimental).
+//!
+//! For ingest-heavy nodes (market-data fan-in, sensor aggregation) the
+//! kernel UDP stack becomes the bottleneck well before the NIC does. AF_XDP
+//! lets the NIC place frames directly into a user-space memory area (UMEM)
+//! that doubles as the receive buffer pool; HDDS strips the Ethernet/IP/UDP
+//! headers and routes the RTPS message without a syscall or copy per packet.
+//!
+//! Only user traffic is redirected (by default the participant's user
+//! unicast port). Discovery and everything else keep using the kernel, and
+//! so do user packets the NIC steers to queues without an XDP socket: bind
+//! one socket per queue, or pin the flow with `ethtool -N ... action <q>`.
+//!
+//! Requires Linux >= 5.9 (BPF links), `CAP_NET_RAW` + `CAP_BPF` (or root),
+//! and driver XDP support for zero-copy.
+//!
+//! # Modules
+//!
+//! - `config` - Configuration types (`XdpConfig`, `XdpBindMode`)
+//! - `packet` - Ethernet/IPv4/UDP parsing (`XdpPacket`)
+//! - `socket` - AF_XDP socket and UMEM (`XdpSocket`)
+//! - `bpf` - Built-in redirect program and map management (internal)
+//! - `ring` - Kernel-shared rings (internal)
+
+mod bpf;
+pub mod config;
+pub mod packet;
+mod ring;
+pub mod socket;
+
+// ============================================================================
+// Re-exports
+// ============================================================================
+
+pub use config::{XdpBindMode, XdpConfig, XdpProgramSource};
+pub use packet::{parse_udp, ParseError, XdpPacket};
+pub use socket::{XdpSocket, XdpStats};
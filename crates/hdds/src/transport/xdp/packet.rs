@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Ethernet/IPv4/UDP header parsing for raw XDP frames.
+//!
+//! AF_XDP hands over whole L2 frames; the kernel's UDP stack never sees
+//! them, so HDDS strips the headers itself. Only what the built-in program
+//! redirects is accepted: untagged IPv4, unfragmented, UDP.
+
+use std::net::{Ipv4Addr, SocketAddrV4};
+
+const ETH_HDR_LEN: usize = 14;
+const ETH_P_IPV4: u16 = 0x0800;
+const IPPROTO_UDP: u8 = 17;
+const UDP_HDR_LEN: usize = 8;
+
+/// A UDP datagram borrowed from a UMEM frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct XdpPacket<'a> {
+    /// Sender address.
+    pub src: SocketAddrV4,
+    /// Destination UDP port.
+    pub dst_port: u16,
+    /// UDP payload (the RTPS message).
+    pub payload: &'a [u8],
+}
+
+/// Why a frame was not accepted as a UDP datagram.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// Frame shorter than its headers claim.
+    Truncated,
+    /// Not IPv4 (or VLAN-tagged).
+    NotIpv4,
+    /// IPv4 but not UDP.
+    NotUdp,
+    /// IPv4 fragment (reassembly is left to the kernel).
+    Fragment,
+}
+
+/// Parse an Ethernet frame into its UDP payload.
+pub fn parse_udp(frame: &[u8]) -> Result<XdpPacket<'_>, ParseError> {
+    if frame.len() < ETH_HDR_LEN + 20 {
+        return Err(ParseError::Truncated);
+    }
+    if u16::from_be_bytes([frame[12], frame[13]]) != ETH_P_IPV4 {
+        return Err(ParseError::NotIpv4);
+    }
+
+    let ip = &frame[ETH_HDR_LEN..];
+    if ip[0] >> 4 != 4 {
+        return Err(ParseError::NotIpv4);
+    }
+    let ihl = usize::from(ip[0] & 0x0F) * 4;
+    if ihl < 20 || ip.len() < ihl + UDP_HDR_LEN {
+        return Err(ParseError::Truncated);
+    }
+    // MF flag or non-zero fragment offset
+    if u16::from_be_bytes([ip[6], ip[7]]) & 0x3FFF != 0 {
+        return Err(ParseError::Fragment);
+    }
+    if ip[9] != IPPROTO_UDP {
+        return Err(ParseError::NotUdp);
+    }
+    let total_len = usize::from(u16::from_be_bytes([ip[2], ip[3]]));
+    let src_ip = Ipv4Addr::new(ip[12], ip[13], ip[14], ip[15]);
+
+    let udp = &ip[ihl..];
+    let src_port = u16::from_be_bytes([udp[0], udp[1]]);
+    let dst_port = u16::from_be_bytes([udp[2], udp[3]]);
+    let udp_len = usize::from(u16::from_be_bytes([udp[4], udp[5]]));
+    // Ethernet padding may follow the datagram; trust the UDP length but
+    // never read past the IP packet or the frame.
+    if udp_len < UDP_HDR_LEN || ihl + udp_len > total_len || udp_len > udp.len() {
+        return Err(ParseError::Truncated);
+    }
+
+    Ok(XdpPacket {
+        src: SocketAddrV4::new(src_ip, src_port),
+        dst_port,
+        payload: &udp[UDP_HDR_LEN..udp_len],
+    })
+}
+
+#[cfg(test)]
+pub(super) fn build_frame(src: SocketAddrV4, dst_port: u16, payload: &[u8]) -> Vec<u8> {
+    let udp_len = (UDP_HDR_LEN + payload.len()) as u16;
+    let total_len = 20 + udp_len;
+    let mut f = vec![0u8; ETH_HDR_LEN];
+    f[12..14].copy_from_slice(&ETH_P_IPV4.to_be_bytes());
+    f.extend_from_slice(&[0x45, 0]);
+    f.extend_from_slice(&total_len.to_be_bytes());
+    f.extend_from_slice(&[0, 0, 0x40, 0, 64, IPPROTO_UDP, 0, 0]); // DF set
+    f.extend_from_slice(&src.ip().octets());
+    f.extend_from_slice(&[10, 0, 0, 2]);
+    f.extend_from_slice(&src.port().to_be_bytes());
+    f.extend_from_slice(&dst_port.to_be_bytes());
+    f.extend_from_slice(&udp_len.to_be_bytes());
+    f.extend_from_slice(&[0, 0]);
+    f.extend_from_slice(payload);
+    f
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_udp_payload() {
+        let src = SocketAddrV4::new(Ipv4Addr::new(10, 0, 0, 1), 40000);
+        let mut frame = build_frame(src, 7411, b"RTPS\x02\x05");
+        frame.extend_from_slice(&[0u8; 10]); // Ethernet padding
+
+        let pkt = parse_udp(&frame).unwrap();
+        assert_eq!(pkt.src, src);
+        assert_eq!(pkt.dst_port, 7411);
+        assert_eq!(pkt.payload, b"RTPS\x02\x05");
+    }
+
+    #[test]
+    fn test_parse_rejects_non_udp_and_fragments() {
+        let src = SocketAddrV4::new(Ipv4Addr::LOCALHOST, 1);
+        let frame = build_frame(src, 7411, b"RTPS");
+
+        let mut tcp = frame.clone();
+        tcp[ETH_HDR_LEN + 9] = 6;
+        assert_eq!(parse_udp(&tcp), Err(ParseError::NotUdp));
+
+        let mut frag = frame.clone();
+        frag[ETH_HDR_LEN + 6] = 0x20; // MF
+        assert_eq!(parse_udp(&frag), Err(ParseError::Fragment));
+
+        let mut arp = frame.clone();
+        arp[12..14].copy_from_slice(&0x0806u16.to_be_bytes());
+        assert_eq!(parse_udp(&arp), Err(ParseError::NotIpv4));
+
+        assert_eq!(
+            parse_udp(&frame[..frame.len() - 2]),
+            Err(ParseError::Truncated)
+        );
+    }
+}
@@ -0,0 +1,216 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Single-producer/single-consumer rings shared with the kernel.
+//!
+//! Layout per `struct xdp_ring_offset`: a producer index, a consumer index,
+//! a flags word and a power-of-two descriptor array. Indices run freely and
+//! are masked on access. The kernel owns one side of each ring: it produces
+//! into RX and completion, and consumes from fill.
+
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// `XDP_RING_NEED_WAKEUP`
+const RING_NEED_WAKEUP: u32 = 1;
+
+/// Descriptor of a received frame (`struct xdp_desc`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(super) struct XdpDesc {
+    pub addr: u64,
+    pub len: u32,
+    pub options: u32,
+}
+
+/// One mapped ring of `T` entries.
+pub(super) struct Ring<T: Copy> {
+    producer: *const AtomicU32,
+    consumer: *const AtomicU32,
+    flags: *const AtomicU32,
+    descs: *mut T,
+    mask: u32,
+    size: u32,
+    cached_prod: u32,
+    cached_cons: u32,
+    _marker: PhantomData<T>,
+}
+
+// SAFETY: the ring memory is owned by the socket mapping that outlives the
+// ring, and each ring has a single user-space owner.
+unsafe impl<T: Copy> Send for Ring<T> {}
+
+impl<T: Copy> Ring<T> {
+    /// Wrap a mapped ring.
+    ///
+    /// # Safety
+    /// All pointers must reference the same live ring mapping of `size`
+    /// (power of two) entries and stay valid for the ring's lifetime.
+    pub unsafe fn from_raw(
+        producer: *const AtomicU32,
+        consumer: *const AtomicU32,
+        flags: *const AtomicU32,
+        descs: *mut T,
+        size: u32,
+    ) -> Self {
+        debug_assert!(size.is_power_of_two());
+        // SAFETY: pointers are valid per the caller's contract.
+        let (prod, cons) = unsafe {
+            (
+                (*producer).load(Ordering::Acquire),
+                (*consumer).load(Ordering::Acquire),
+            )
+        };
+        Self {
+            producer,
+            consumer,
+            flags,
+            descs,
+            mask: size - 1,
+            size,
+            cached_prod: prod,
+            cached_cons: cons,
+            _marker: PhantomData,
+        }
+    }
+
+    fn prod(&self) -> &AtomicU32 {
+        // SAFETY: valid for the ring's lifetime (see from_raw).
+        unsafe { &*self.producer }
+    }
+
+    fn cons(&self) -> &AtomicU32 {
+        // SAFETY: valid for the ring's lifetime (see from_raw).
+        unsafe { &*self.consumer }
+    }
+
+    /// True if the kernel asked to be woken up for this ring.
+    pub fn needs_wakeup(&self) -> bool {
+        // SAFETY: valid for the ring's lifetime (see from_raw).
+        unsafe { (*self.flags).load(Ordering::Relaxed) & RING_NEED_WAKEUP != 0 }
+    }
+
+    // ------------------------------------------------------------------
+    // Consumer side (RX, completion)
+    // ------------------------------------------------------------------
+
+    /// Number of entries ready to consume (at most `max`).
+    pub fn peek(&mut self, max: u32) -> u32 {
+        let mut avail = self.cached_prod.wrapping_sub(self.cached_cons);
+        if avail == 0 {
+            self.cached_prod = self.prod().load(Ordering::Acquire);
+            avail = self.cached_prod.wrapping_sub(self.cached_cons);
+        }
+        avail.min(max)
+    }
+
+    /// Read the `i`-th entry past the consumer index (after `peek`).
+    pub fn read(&self, i: u32) -> T {
+        let idx = self.cached_cons.wrapping_add(i) & self.mask;
+        // SAFETY: idx <= mask, inside the descriptor array.
+        unsafe { self.descs.add(idx as usize).read_volatile() }
+    }
+
+    /// Hand `n` consumed entries back to the producer.
+    pub fn release(&mut self, n: u32) {
+        self.cached_cons = self.cached_cons.wrapping_add(n);
+        self.cons().store(self.cached_cons, Ordering::Release);
+    }
+
+    // ------------------------------------------------------------------
+    // Producer side (fill)
+    // ------------------------------------------------------------------
+
+    /// Number of free slots (at most `max`).
+    pub fn free(&mut self, max: u32) -> u32 {
+        let mut free = self.size - self.cached_prod.wrapping_sub(self.cached_cons);
+        if free < max {
+            self.cached_cons = self.cons().load(Ordering::Acquire);
+            free = self.size - self.cached_prod.wrapping_sub(self.cached_cons);
+        }
+        free.min(max)
+    }
+
+    /// Write the `i`-th entry past the producer index (after `free`).
+    pub fn write(&mut self, i: u32, value: T) {
+        let idx = self.cached_prod.wrapping_add(i) & self.mask;
+        // SAFETY: idx <= mask, inside the descriptor array.
+        unsafe { self.descs.add(idx as usize).write_volatile(value) }
+    }
+
+    /// Publish `n` written entries to the consumer.
+    pub fn submit(&mut self, n: u32) {
+        self.cached_prod = self.cached_prod.wrapping_add(n);
+        self.prod().store(self.cached_prod, Ordering::Release);
+    }
+}
+
+#[cfg(test)]
+pub(super) struct HeapRing<T: Copy + Default> {
+    pub producer: Box<AtomicU32>,
+    pub consumer: Box<AtomicU32>,
+    pub flags: Box<AtomicU32>,
+    pub descs: Vec<T>,
+}
+
+#[cfg(test)]
+impl<T: Copy + Default> HeapRing<T> {
+    pub fn new(size: u32) -> Self {
+        Self {
+            producer: Box::new(AtomicU32::new(0)),
+            consumer: Box::new(AtomicU32::new(0)),
+            flags: Box::new(AtomicU32::new(0)),
+            descs: vec![T::default(); size as usize],
+        }
+    }
+
+    pub fn ring(&mut self) -> Ring<T> {
+        let size = self.descs.len() as u32;
+        // SAFETY: the heap ring outlives the view in tests.
+        unsafe {
+            Ring::from_raw(
+                &*self.producer,
+                &*self.consumer,
+                &*self.flags,
+                self.descs.as_mut_ptr(),
+                size,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_producer_consumer_wraparound() {
+        let mut mem = HeapRing::<u64>::new(4);
+        let mut tx = mem.ring();
+        let mut rx = mem.ring();
+
+        for round in 0..5u64 {
+            assert_eq!(tx.free(8), 4);
+            for i in 0..3 {
+                tx.write(i, round * 10 + u64::from(i));
+            }
+            tx.submit(3);
+            assert_eq!(tx.free(8), 1);
+
+            assert_eq!(rx.peek(8), 3);
+            assert_eq!(rx.read(0), round * 10);
+            assert_eq!(rx.read(2), round * 10 + 2);
+            rx.release(3);
+            assert_eq!(rx.peek(8), 0);
+        }
+    }
+
+    #[test]
+    fn test_need_wakeup_flag() {
+        let mut mem = HeapRing::<u64>::new(2);
+        let ring = mem.ring();
+        assert!(!ring.needs_wakeup());
+        mem.flags.store(RING_NEED_WAKEUP, Ordering::Relaxed);
+        assert!(ring.needs_wakeup());
+    }
+}
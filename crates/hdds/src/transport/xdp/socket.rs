@@ -0,0 +1,679 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! AF_XDP socket bound to one NIC queue.
+//!
+//! Receive path: NIC -> UMEM frame -> RX ring -> [`XdpSocket::recv_batch`]
+//! callback (borrowing the frame) -> frame back on the fill ring. RTPS
+//! payloads are never copied on the way in; the callback decides whether
+//! to copy (e.g. to keep a sample) or to parse in place.
+
+use std::ffi::CString;
+use std::io;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::ptr;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use super::bpf;
+use super::packet::{parse_udp, XdpPacket};
+use super::ring::{Ring, XdpDesc};
+use super::{XdpBindMode, XdpConfig, XdpProgramSource};
+
+const AF_XDP: libc::c_int = 44;
+const SOL_XDP: libc::c_int = 283;
+
+// Socket options
+const XDP_MMAP_OFFSETS: libc::c_int = 1;
+const XDP_RX_RING: libc::c_int = 2;
+const XDP_UMEM_REG: libc::c_int = 4;
+const XDP_UMEM_FILL_RING: libc::c_int = 5;
+const XDP_UMEM_COMPLETION_RING: libc::c_int = 6;
+const XDP_STATISTICS: libc::c_int = 7;
+const XDP_OPTIONS: libc::c_int = 8;
+
+// mmap page offsets
+const XDP_PGOFF_RX_RING: libc::off_t = 0;
+const XDP_UMEM_PGOFF_FILL_RING: libc::off_t = 0x1_0000_0000;
+const XDP_UMEM_PGOFF_COMPLETION_RING: libc::off_t = 0x1_8000_0000;
+
+// sockaddr_xdp flags
+const XDP_COPY: u16 = 1 << 1;
+const XDP_ZEROCOPY: u16 = 1 << 2;
+const XDP_USE_NEED_WAKEUP: u16 = 1 << 3;
+
+const XDP_OPTIONS_ZEROCOPY: u32 = 1;
+
+/// Upper bound on frames handled per `recv_batch` call.
+const DEFAULT_BATCH: u32 = 64;
+
+// ============================================================================
+// Kernel ABI
+// ============================================================================
+
+#[repr(C)]
+struct XdpUmemReg {
+    addr: u64,
+    len: u64,
+    chunk_size: u32,
+    headroom: u32,
+    flags: u32,
+    tx_metadata_len: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+struct XdpRingOffset {
+    producer: u64,
+    consumer: u64,
+    desc: u64,
+    flags: u64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+struct XdpMmapOffsets {
+    rx: XdpRingOffset,
+    tx: XdpRingOffset,
+    fr: XdpRingOffset,
+    cr: XdpRingOffset,
+}
+
+#[repr(C)]
+struct SockaddrXdp {
+    family: u16,
+    flags: u16,
+    ifindex: u32,
+    queue_id: u32,
+    shared_umem_fd: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+struct XdpStatistics {
+    rx_dropped: u64,
+    rx_invalid_descs: u64,
+    tx_invalid_descs: u64,
+    rx_ring_full: u64,
+    rx_fill_ring_empty_descs: u64,
+    tx_ring_empty_descs: u64,
+}
+
+// ============================================================================
+// Statistics
+// ============================================================================
+
+/// AF_XDP receive counters (ours plus the kernel's `XDP_STATISTICS`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct XdpStats {
+    /// UDP datagrams delivered to the callback.
+    pub packets_received: u64,
+    /// UDP payload bytes delivered.
+    pub bytes_received: u64,
+    /// Frames that were not plain IPv4/UDP (only possible with an external program).
+    pub frames_rejected: u64,
+    /// Frames dropped by the kernel (e.g. no UMEM frame available).
+    pub kernel_rx_dropped: u64,
+    /// Frames dropped because the RX ring was full.
+    pub kernel_rx_ring_full: u64,
+    /// Times the kernel found the fill ring empty.
+    pub kernel_fill_ring_empty: u64,
+}
+
+// ============================================================================
+// Memory mappings
+// ============================================================================
+
+struct Mapping {
+    addr: *mut libc::c_void,
+    len: usize,
+}
+
+impl Mapping {
+    fn map(fd: libc::c_int, len: usize, offset: libc::off_t) -> io::Result<Self> {
+        let flags = libc::MAP_SHARED | libc::MAP_POPULATE;
+        // SAFETY: fresh shared mapping of a socket region; checked below.
+        let addr = unsafe {
+            libc::mmap(
+                ptr::null_mut(),
+                len,
+                libc::PROT_READ | libc::PROT_WRITE,
+                flags,
+                fd,
+                offset,
+            )
+        };
+        if addr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self { addr, len })
+    }
+
+    fn anonymous(len: usize) -> io::Result<Self> {
+        let flags = libc::MAP_PRIVATE | libc::MAP_ANONYMOUS | libc::MAP_POPULATE;
+        // SAFETY: fresh anonymous mapping; checked below.
+        let addr = unsafe {
+            libc::mmap(
+                ptr::null_mut(),
+                len,
+                libc::PROT_READ | libc::PROT_WRITE,
+                flags,
+                -1,
+                0,
+            )
+        };
+        if addr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self { addr, len })
+    }
+
+    fn at<T>(&self, offset: u64) -> *mut T {
+        debug_assert!((offset as usize) < self.len);
+        // SAFETY: offsets come from XDP_MMAP_OFFSETS and lie in the mapping.
+        unsafe { self.addr.cast::<u8>().add(offset as usize).cast() }
+    }
+}
+
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        // SAFETY: addr/len describe a mapping we created.
+        unsafe { libc::munmap(self.addr, self.len) };
+    }
+}
+
+/// Build a ring view over a mapped ring region.
+///
+/// # Safety
+/// `map` must be the mapping described by `off` with `size` entries of `T`,
+/// and must outlive the ring.
+unsafe fn ring_view<T: Copy>(map: &Mapping, off: &XdpRingOffset, size: u32) -> Ring<T> {
+    // SAFETY: forwarded to the caller.
+    unsafe {
+        Ring::from_raw(
+            map.at::<AtomicU32>(off.producer),
+            map.at::<AtomicU32>(off.consumer),
+            map.at::<AtomicU32>(off.flags),
+            map.at::<T>(off.desc),
+            size,
+        )
+    }
+}
+
+// ============================================================================
+// XdpSocket
+// ============================================================================
+
+struct RxState {
+    fill: Ring<u64>,
+    rx: Ring<XdpDesc>,
+    /// Frames owned by user space and not on the fill ring.
+    free_frames: Vec<u64>,
+}
+
+/// An AF_XDP socket receiving UDP traffic from one NIC queue.
+///
+/// Field order is drop order: the XDP program is detached before the
+/// socket and its memory go away.
+pub struct XdpSocket {
+    _link: Option<OwnedFd>,
+    _prog: Option<OwnedFd>,
+    _map: OwnedFd,
+    state: Mutex<RxState>,
+    _rx_map: Mapping,
+    _fill_map: Mapping,
+    _comp_map: Mapping,
+    fd: OwnedFd,
+    umem: Mapping,
+    config: XdpConfig,
+    ifindex: u32,
+    ports: (u16, u16),
+    zero_copy: bool,
+    packets_received: AtomicU64,
+    bytes_received: AtomicU64,
+    frames_rejected: AtomicU64,
+}
+
+// SAFETY: ring state is behind a Mutex; the UMEM is only read for frames
+// this socket currently owns (between RX consume and fill submit).
+unsafe impl Send for XdpSocket {}
+// SAFETY: see above.
+unsafe impl Sync for XdpSocket {}
+
+impl XdpSocket {
+    /// Create the socket, register UMEM, bind the queue and install the
+    /// redirect (built-in program or pinned map).
+    ///
+    /// `default_port` is used when `config.ports` is None. Requires
+    /// `CAP_NET_RAW` and `CAP_BPF` (or root).
+    pub fn open(config: XdpConfig, default_port: u16) -> io::Result<Self> {
+        config
+            .validate()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        let ports = config.ports.unwrap_or((default_port, default_port));
+
+        let ifname = CString::new(config.interface.as_str())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        // SAFETY: ifname is a valid C string.
+        let ifindex = unsafe { libc::if_nametoindex(ifname.as_ptr()) };
+        if ifindex == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("interface {} not found", config.interface),
+            ));
+        }
+
+        // SAFETY: plain socket(2) call; the fd is owned below.
+        let raw = unsafe { libc::socket(AF_XDP, libc::SOCK_RAW | libc::SOCK_CLOEXEC, 0) };
+        if raw < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        // SAFETY: raw is a fresh, valid fd.
+        let fd = unsafe { OwnedFd::from_raw_fd(raw) };
+
+        let umem = Mapping::anonymous(config.umem_bytes())?;
+        let reg = XdpUmemReg {
+            addr: umem.addr as u64,
+            len: umem.len as u64,
+            chunk_size: config.frame_size,
+            headroom: 0,
+            flags: 0,
+            tx_metadata_len: 0,
+        };
+        setsockopt(&fd, XDP_UMEM_REG, &reg)?;
+        setsockopt(&fd, XDP_UMEM_FILL_RING, &config.fill_ring_size)?;
+        // RX-only: the completion ring is mandatory but stays unused.
+        setsockopt(&fd, XDP_UMEM_COMPLETION_RING, &config.fill_ring_size)?;
+        setsockopt(&fd, XDP_RX_RING, &config.rx_ring_size)?;
+
+        let mut off = XdpMmapOffsets::default();
+        let len = getsockopt(&fd, XDP_MMAP_OFFSETS, &mut off)?;
+        if len != std::mem::size_of::<XdpMmapOffsets>() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "kernel too old for AF_XDP ring flags (need Linux >= 5.4)",
+            ));
+        }
+
+        let fill_map = Mapping::map(
+            fd.as_raw_fd(),
+            off.fr.desc as usize + config.fill_ring_size as usize * 8,
+            XDP_UMEM_PGOFF_FILL_RING,
+        )?;
+        let comp_map = Mapping::map(
+            fd.as_raw_fd(),
+            off.cr.desc as usize + config.fill_ring_size as usize * 8,
+            XDP_UMEM_PGOFF_COMPLETION_RING,
+        )?;
+        let rx_map = Mapping::map(
+            fd.as_raw_fd(),
+            off.rx.desc as usize + config.rx_ring_size as usize * std::mem::size_of::<XdpDesc>(),
+            XDP_PGOFF_RX_RING,
+        )?;
+        // SAFETY: each mapping matches its offsets and lives in `Self`
+        // next to the ring (and is dropped after it).
+        let (mut fill, rx) = unsafe {
+            (
+                ring_view::<u64>(&fill_map, &off.fr, config.fill_ring_size),
+                ring_view::<XdpDesc>(&rx_map, &off.rx, config.rx_ring_size),
+            )
+        };
+
+        // Hand the first fill_ring_size frames to the kernel, keep the rest.
+        let frame_size = u64::from(config.frame_size);
+        let mut free_frames: Vec<u64> = (0..u64::from(config.frame_count))
+            .rev()
+            .map(|i| i * frame_size)
+            .collect();
+        let n = fill.free(config.fill_ring_size);
+        for i in 0..n {
+            // free_frames holds frame_count >= fill_ring_size entries (validated).
+            let addr = free_frames.pop().unwrap_or_default();
+            fill.write(i, addr);
+        }
+        fill.submit(n);
+
+        let zero_copy = bind(&fd, ifindex, &config)?;
+
+        let (map, prog, link) = match &config.program {
+            XdpProgramSource::BuiltIn => {
+                let map = bpf::create_xsk_map(config.queue_id + 1)?;
+                bpf::map_insert_socket(&map, config.queue_id, fd.as_raw_fd())?;
+                let insns = bpf::redirect_program(map.as_raw_fd(), ports.0, ports.1);
+                let prog = bpf::load_xdp_program(&insns)?;
+                let mode = if config.generic_mode {
+                    bpf::XDP_FLAGS_SKB_MODE
+                } else {
+                    bpf::XDP_FLAGS_DRV_MODE
+                };
+                let link = bpf::attach_xdp(&prog, ifindex, mode)?;
+                (map, Some(prog), Some(link))
+            }
+            XdpProgramSource::PinnedMap(path) => {
+                let map = bpf::open_pinned(path)?;
+                bpf::map_insert_socket(&map, config.queue_id, fd.as_raw_fd())?;
+                (map, None, None)
+            }
+        };
+
+        log::info!(
+            "[xdp] Bound {} queue {} ({}, ports {}-{}, {} x {} byte frames)",
+            config.interface,
+            config.queue_id,
+            if zero_copy { "zero-copy" } else { "copy" },
+            ports.0,
+            ports.1,
+            config.frame_count,
+            config.frame_size
+        );
+
+        Ok(Self {
+            _link: link,
+            _prog: prog,
+            _map: map,
+            state: Mutex::new(RxState {
+                fill,
+                rx,
+                free_frames,
+            }),
+            _rx_map: rx_map,
+            _fill_map: fill_map,
+            _comp_map: comp_map,
+            fd,
+            umem,
+            config,
+            ifindex,
+            ports,
+            zero_copy,
+            packets_received: AtomicU64::new(0),
+            bytes_received: AtomicU64::new(0),
+            frames_rejected: AtomicU64::new(0),
+        })
+    }
+
+    /// Socket configuration.
+    pub fn config(&self) -> &XdpConfig {
+        &self.config
+    }
+
+    /// Interface index the socket is bound to.
+    pub fn ifindex(&self) -> u32 {
+        self.ifindex
+    }
+
+    /// Inclusive UDP destination port range redirected to this socket.
+    pub fn ports(&self) -> (u16, u16) {
+        self.ports
+    }
+
+    /// True if the driver runs in zero-copy mode.
+    pub fn is_zero_copy(&self) -> bool {
+        self.zero_copy
+    }
+
+    /// Process up to `max` received frames without blocking.
+    ///
+    /// `on_packet` sees each UDP datagram in place in UMEM; the frame is
+    /// recycled when the callback returns. Returns the number of datagrams
+    /// delivered.
+    pub fn recv_batch<F>(&self, max: u32, mut on_packet: F) -> usize
+    where
+        F: FnMut(&XdpPacket<'_>),
+    {
+        let mut state = self.lock_state();
+        let n = state.rx.peek(max);
+        let mask = !(u64::from(self.config.frame_size) - 1);
+        let mut delivered = 0;
+
+        for i in 0..n {
+            let desc = state.rx.read(i);
+            let end = desc.addr.saturating_add(u64::from(desc.len));
+            if end <= self.umem.len as u64 {
+                // SAFETY: the kernel handed this frame to us via the RX ring;
+                // it stays ours until it goes back on the fill ring below.
+                let frame = unsafe {
+                    std::slice::from_raw_parts(
+                        self.umem.at::<u8>(desc.addr).cast_const(),
+                        desc.len as usize,
+                    )
+                };
+                match parse_udp(frame) {
+                    Ok(pkt) => {
+                        self.bytes_received
+                            .fetch_add(pkt.payload.len() as u64, Ordering::Relaxed);
+                        on_packet(&pkt);
+                        delivered += 1;
+                    }
+                    Err(_) => {
+                        self.frames_rejected.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+            }
+            state.free_frames.push(desc.addr & mask);
+        }
+        if n > 0 {
+            state.rx.release(n);
+            self.packets_received
+                .fetch_add(delivered as u64, Ordering::Relaxed);
+        }
+
+        self.refill(&mut state);
+        delivered
+    }
+
+    /// Wait up to `timeout` for traffic, then behave like
+    /// [`recv_batch`](Self::recv_batch) with the default batch size.
+    pub fn poll_timeout<F>(&self, timeout: Duration, on_packet: F) -> usize
+    where
+        F: FnMut(&XdpPacket<'_>),
+    {
+        let mut pfd = libc::pollfd {
+            fd: self.fd.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        let timeout_ms = timeout.as_millis().min(i32::MAX as u128) as i32;
+        // SAFETY: pfd is a valid pollfd for the lifetime of the call and nfds is 1.
+        unsafe { libc::poll(&mut pfd, 1, timeout_ms) };
+        self.recv_batch(DEFAULT_BATCH, on_packet)
+    }
+
+    /// Get a snapshot of receive counters.
+    pub fn stats(&self) -> XdpStats {
+        let mut kernel = XdpStatistics::default();
+        if let Err(e) = getsockopt(&self.fd, XDP_STATISTICS, &mut kernel) {
+            log::debug!("[xdp] XDP_STATISTICS failed: {}", e);
+        }
+        XdpStats {
+            packets_received: self.packets_received.load(Ordering::Relaxed),
+            bytes_received: self.bytes_received.load(Ordering::Relaxed),
+            frames_rejected: self.frames_rejected.load(Ordering::Relaxed),
+            kernel_rx_dropped: kernel.rx_dropped,
+            kernel_rx_ring_full: kernel.rx_ring_full,
+            kernel_fill_ring_empty: kernel.rx_fill_ring_empty_descs,
+        }
+    }
+
+    fn refill(&self, state: &mut RxState) {
+        let want = state.free_frames.len() as u32;
+        let n = state.fill.free(want);
+        for i in 0..n {
+            let addr = state.free_frames.pop().unwrap_or_default();
+            state.fill.write(i, addr);
+        }
+        if n > 0 {
+            state.fill.submit(n);
+        }
+        if self.config.need_wakeup && state.fill.needs_wakeup() {
+            // SAFETY: zero-length non-blocking recvfrom only kicks the driver.
+            unsafe {
+                libc::recvfrom(
+                    self.fd.as_raw_fd(),
+                    ptr::null_mut(),
+                    0,
+                    libc::MSG_DONTWAIT,
+                    ptr::null_mut(),
+                    ptr::null_mut(),
+                )
+            };
+        }
+    }
+
+    fn lock_state(&self) -> MutexGuard<'_, RxState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl std::fmt::Debug for XdpSocket {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("XdpSocket")
+            .field("interface", &self.config.interface)
+            .field("queue_id", &self.config.queue_id)
+            .field("zero_copy", &self.zero_copy)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Bind the socket, honouring the bind mode. Returns true for zero-copy.
+fn bind(fd: &OwnedFd, ifindex: u32, config: &XdpConfig) -> io::Result<bool> {
+    let wakeup = if config.need_wakeup {
+        XDP_USE_NEED_WAKEUP
+    } else {
+        0
+    };
+    let attempts: &[u16] = match config.bind_mode {
+        XdpBindMode::ZeroCopy => &[XDP_ZEROCOPY],
+        XdpBindMode::Copy => &[XDP_COPY],
+        XdpBindMode::Auto => &[XDP_ZEROCOPY, XDP_COPY],
+    };
+
+    let mut last_err = None;
+    for &mode in attempts {
+        let addr = SockaddrXdp {
+            family: AF_XDP as u16,
+            flags: mode | wakeup,
+            ifindex,
+            queue_id: config.queue_id,
+            shared_umem_fd: 0,
+        };
+        // SAFETY: addr is a valid sockaddr_xdp of the given size.
+        let rc = unsafe {
+            libc::bind(
+                fd.as_raw_fd(),
+                (&addr as *const SockaddrXdp).cast(),
+                std::mem::size_of::<SockaddrXdp>() as libc::socklen_t,
+            )
+        };
+        if rc == 0 {
+            let mut opts: u32 = 0;
+            getsockopt(fd, XDP_OPTIONS, &mut opts)?;
+            return Ok(opts & XDP_OPTIONS_ZEROCOPY != 0);
+        }
+        let err = io::Error::last_os_error();
+        if mode == XDP_ZEROCOPY && attempts.len() > 1 {
+            log::debug!(
+                "[xdp] Zero-copy bind failed ({}), retrying in copy mode",
+                err
+            );
+        }
+        last_err = Some(err);
+    }
+    Err(last_err.unwrap_or_else(|| io::Error::other("AF_XDP bind failed")))
+}
+
+fn setsockopt<T>(fd: &OwnedFd, opt: libc::c_int, value: &T) -> io::Result<()> {
+    // SAFETY: value points to a live T of the given size.
+    let rc = unsafe {
+        libc::setsockopt(
+            fd.as_raw_fd(),
+            SOL_XDP,
+            opt,
+            (value as *const T).cast(),
+            std::mem::size_of::<T>() as libc::socklen_t,
+        )
+    };
+    if rc != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+fn getsockopt<T>(fd: &OwnedFd, opt: libc::c_int, value: &mut T) -> io::Result<usize> {
+    let mut len = std::mem::size_of::<T>() as libc::socklen_t;
+    // SAFETY: value points to a writable T of `len` bytes.
+    let rc = unsafe {
+        libc::getsockopt(
+            fd.as_raw_fd(),
+            SOL_XDP,
+            opt,
+            (value as *mut T).cast(),
+            &mut len,
+        )
+    };
+    if rc != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(len as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_abi_sizes() {
+        assert_eq!(std::mem::size_of::<XdpUmemReg>(), 32);
+        assert_eq!(std::mem::size_of::<XdpMmapOffsets>(), 128);
+        assert_eq!(std::mem::size_of::<SockaddrXdp>(), 16);
+        assert_eq!(std::mem::size_of::<XdpDesc>(), 16);
+        assert_eq!(std::mem::size_of::<XdpStatistics>(), 48);
+    }
+
+    #[test]
+    fn test_open_unknown_interface_fails_cleanly() {
+        let err = XdpSocket::open(XdpConfig::enabled("hdds-no-such-if0"), 7411).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_loopback_generic_mode_receive() {
+        // Needs CAP_NET_RAW + CAP_BPF; skip quietly elsewhere.
+        let config = XdpConfig::enabled("lo")
+            .with_bind_mode(XdpBindMode::Copy)
+            .with_generic_mode(true)
+            .with_umem(1024, 2048)
+            .with_rings(512, 512);
+        let xdp = match XdpSocket::open(config, 47411) {
+            Ok(xdp) => xdp,
+            Err(e) => {
+                eprintln!("skipping AF_XDP loopback test: {}", e);
+                return;
+            }
+        };
+
+        let sender = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let mut received = 0u32;
+        // More packets than UMEM frames: frames must be recycled.
+        for round in 0..8u32 {
+            for i in 0..200u32 {
+                let seq = round * 200 + i;
+                sender
+                    .send_to(&seq.to_be_bytes(), "127.0.0.1:47411")
+                    .unwrap();
+            }
+            for _ in 0..20 {
+                xdp.poll_timeout(Duration::from_millis(20), |pkt| {
+                    assert_eq!(pkt.dst_port, 47411);
+                    assert_eq!(pkt.payload, &received.to_be_bytes());
+                    received += 1;
+                });
+                if received == (round + 1) * 200 {
+                    break;
+                }
+            }
+        }
+        assert_eq!(received, 1600);
+        assert_eq!(xdp.stats().packets_received, 1600);
+    }
+}
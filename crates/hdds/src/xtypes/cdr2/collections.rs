@@ -215,10 +215,12 @@ impl Cdr2Decode for CompleteSequenceType {
         let mut offset = 0;
 
         // Decode header
-        let header = decode_complete_collection_header_internal(src, &mut offset)?;
+        let (header, used) = CompleteCollectionHeader::decode_cdr2_le(&src[offset..])?;
+        offset += used;
 
-        // Decode element
-        let element = decode_complete_collection_element_internal(src, &mut offset)?;
+        // Decode element (encoded with its own alignment origin, like the header)
+        let (element, used) = CompleteCollectionElement::decode_cdr2_le(&src[offset..])?;
+        offset += used;
 
         Ok((CompleteSequenceType { header, element }, offset))
     }
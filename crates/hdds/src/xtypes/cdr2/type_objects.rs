@@ -410,6 +410,34 @@ mod tests {
         assert_eq!(decoded, type_id);
     }
 
+    #[test]
+    fn test_typeid_inline_sequence_roundtrip() {
+        // hdds_gen inlines anonymous sequences with an empty name, which
+        // leaves the element at an odd offset within the sequence.
+        let type_id = TypeIdentifier::Inline(Box::new(CompleteTypeObject::Sequence(
+            CompleteSequenceType {
+                header: CompleteCollectionHeader {
+                    bound: 16,
+                    detail: CompleteTypeDetail::new(""),
+                },
+                element: CompleteCollectionElement {
+                    flags: CollectionElementFlag::empty(),
+                    type_id: TypeIdentifier::Primitive(TypeKind::TK_FLOAT64),
+                },
+            },
+        )));
+        let mut buf = vec![0u8; 256];
+
+        let encoded_len = type_id
+            .encode_cdr2_le(&mut buf)
+            .expect("CDR2 internal TypeIdentifier (Inline): encode should succeed");
+        let (decoded, used) = TypeIdentifier::decode_cdr2_le(&buf[..encoded_len])
+            .expect("CDR2 internal TypeIdentifier (Inline): decode should succeed");
+
+        assert_eq!(used, encoded_len);
+        assert_eq!(decoded, type_id);
+    }
+
     #[test]
     fn test_typeid_minimal_hash_roundtrip() {
         let hash = EquivalenceHash::from_bytes([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14]);
@@ -0,0 +1,186 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Public API stability checks
+//!
+//! Every name of `hdds::prelude::v1` must keep compiling for the whole 1.x
+//! series, and every `#[deprecated]` attribute must say since when and what
+//! replaces the item (see the stability policy in `hdds::prelude`).
+
+use hdds::prelude::v1;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, v1::DDS)]
+struct Sample {
+    id: u32,
+}
+
+fn assert_type<T: ?Sized>() {}
+
+fn assert_dds<T: v1::DDS>() {}
+
+fn assert_has_status_condition<T: v1::HasStatusCondition>() {}
+
+#[test]
+fn test_prelude_v1_surface() {
+    // Entities
+    assert_type::<v1::ContentFilteredTopic<Sample>>();
+    assert_type::<v1::DataReader<Sample>>();
+    assert_type::<v1::DataWriter<Sample>>();
+    assert_type::<v1::Participant>();
+    assert_type::<v1::ParticipantBuilder>();
+    assert_type::<v1::Publisher>();
+    assert_type::<v1::Subscriber>();
+    assert_type::<v1::Topic<Sample>>();
+    assert_type::<v1::TransportMode>();
+
+    // Data types
+    assert_dds::<Sample>();
+    assert_type::<v1::InstanceHandle>();
+    assert_type::<v1::InstanceState>();
+    assert_type::<v1::SampleInfo>();
+
+    // QoS
+    assert_type::<v1::Deadline>();
+    assert_type::<v1::Durability>();
+    assert_type::<v1::History>();
+    assert_type::<v1::Liveliness>();
+    assert_type::<v1::LivelinessKind>();
+    assert_type::<v1::Ownership>();
+    assert_type::<v1::OwnershipKind>();
+    assert_type::<v1::Partition>();
+    assert_type::<v1::QoS>();
+    assert_type::<v1::Reliability>();
+
+    // Conditions and WaitSet
+    assert_type::<dyn v1::Condition>();
+    assert_type::<v1::GuardCondition>();
+    assert_has_status_condition::<v1::DataReader<Sample>>();
+    assert_type::<v1::ReadCondition>();
+    assert_type::<v1::StatusCondition>();
+    assert_type::<v1::StatusMask>();
+    assert_type::<v1::WaitSet>();
+
+    // Listeners
+    assert_type::<dyn v1::DataReaderListener<Sample>>();
+    assert_type::<dyn v1::DataWriterListener<Sample>>();
+    assert_type::<dyn v1::ParticipantListener>();
+    assert_type::<v1::PublicationMatchedStatus>();
+    assert_type::<v1::SubscriptionMatchedStatus>();
+
+    // Errors
+    assert_type::<v1::Error>();
+    assert_type::<v1::Result<()>>();
+}
+
+/// Variants of 1.0 keep their shape: matching on them keeps compiling.
+#[test]
+#[allow(deprecated)]
+fn test_error_variants_keep_their_shape() {
+    let describe = |err: &v1::Error| match err {
+        v1::Error::Config => "config",
+        v1::Error::Unsupported => "unsupported",
+        _ => "other",
+    };
+    assert_eq!(describe(&v1::Error::Config), "config");
+    assert_eq!(describe(&v1::Error::Unsupported), "unsupported");
+    assert_eq!(describe(&v1::Error::config("bad depth")), "other");
+}
+
+#[test]
+fn test_prelude_is_latest_version() {
+    fn same<T>(_: fn() -> T, _: fn() -> T) {}
+    same(hdds::prelude::QoS::reliable, v1::QoS::reliable);
+}
+
+fn rust_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).expect("read source dir") {
+        let path = entry.expect("dir entry").path();
+        if path.is_dir() {
+            rust_files(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+/// Contents of every `#[deprecated...]` attribute in `source`.
+fn deprecated_attributes(source: &str) -> Vec<&str> {
+    let mut attributes = Vec::new();
+    let mut rest = source;
+    while let Some(start) = rest.find("#[deprecated") {
+        let attribute = &rest[start..];
+        let mut depth = 0usize;
+        let mut end = attribute.len();
+        for (i, c) in attribute.char_indices() {
+            match c {
+                '[' => depth += 1,
+                ']' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        end = i + 1;
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+        attributes.push(&attribute[..end]);
+        rest = &attribute[end..];
+    }
+    attributes
+}
+
+fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let mut parts = version.split('.').map(|part| part.parse::<u64>().ok());
+    Some((parts.next()??, parts.next()??, parts.next()??))
+}
+
+fn since_version(attribute: &str) -> Option<(u64, u64, u64)> {
+    let value = attribute.split("since = \"").nth(1)?.split('"').next()?;
+    parse_version(value)
+}
+
+#[test]
+fn test_deprecations_follow_policy() {
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let mut files = Vec::new();
+    rust_files(&src, &mut files);
+    let current = parse_version(env!("CARGO_PKG_VERSION")).expect("crate version");
+
+    let mut violations = Vec::new();
+    for file in files {
+        let source = fs::read_to_string(&file).expect("read source file");
+        // Attributes only, not mentions in comments and docs
+        let code: String = source
+            .lines()
+            .filter(|line| !line.trim_start().starts_with("//"))
+            .map(|line| format!("{}\n", line))
+            .collect();
+        for attribute in deprecated_attributes(&code) {
+            let since = since_version(attribute);
+            let ok = since.is_some_and(|since| since <= current) && attribute.contains("note = \"");
+            if !ok {
+                violations.push(format!("{}: {}", file.display(), attribute));
+            }
+        }
+    }
+
+    assert!(
+        violations.is_empty(),
+        "#[deprecated] needs since = \"<released version>\" and note = \"<replacement>\":\n{}",
+        violations.join("\n")
+    );
+}
+
+#[test]
+fn test_deprecated_attribute_parsing() {
+    let source = "#[deprecated(since = \"1.0.10\", note = \"use [`b`] instead\")]\nfn a() {}\n\
+                  #[deprecated]\nfn c() {}";
+    let attributes = deprecated_attributes(source);
+    assert_eq!(attributes.len(), 2);
+    assert_eq!(since_version(attributes[0]), Some((1, 0, 10)));
+    assert!(attributes[0].ends_with(")]"));
+    assert_eq!(since_version(attributes[1]), None);
+}
@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Writer-side batching over UDP
+//!
+//! Small samples of a batching writer share RTPS messages; the reader must
+//! still get every sample, in order, each with its own source timestamp.
+//!
+//! Both participants live in this process, so readers also get each sample
+//! intra-process; only the copies received over UDP, the ones carrying a
+//! kernel receive timestamp (Linux), are checked.
+
+#![cfg(target_os = "linux")]
+
+use hdds::{DataReader, Participant, QoS, TransportMode};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const DOMAIN: u32 = 86;
+const PROBE: u32 = u32::MAX;
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Tick {
+    id: u32,
+    value: f32,
+}
+
+fn participant(name: &str) -> Arc<Participant> {
+    Participant::builder(name)
+        .domain_id(DOMAIN)
+        .with_transport(TransportMode::UdpMulticast)
+        .build()
+        .expect("participant")
+}
+
+/// Take samples until `count` non-probe ones arrived over UDP, or 10 s pass.
+fn collect(reader: &DataReader<Tick>, count: usize) -> Vec<(Tick, hdds::SampleInfo)> {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    let mut samples = Vec::new();
+    while samples.len() < count {
+        assert!(
+            Instant::now() < deadline,
+            "timed out with {} of {} samples",
+            samples.len(),
+            count
+        );
+        samples.extend(
+            reader
+                .take_n(count)
+                .expect("take")
+                .into_iter()
+                .filter(|(tick, info)| tick.id != PROBE && info.kernel_rx_timestamp_ns.is_some()),
+        );
+        thread::sleep(Duration::from_millis(20));
+    }
+    samples
+}
+
+#[test]
+fn test_batched_samples_delivered_in_order() {
+    let topic = "batching/ticks";
+    let publisher = participant("batch_pub");
+    let subscriber = participant("batch_sub");
+
+    // Only the size threshold and flush() send batches here
+    let writer = publisher
+        .topic::<Tick>(topic)
+        .expect("topic")
+        .writer()
+        .qos(
+            QoS::reliable()
+                .keep_last(1000)
+                .batching(4096, Duration::from_secs(3600)),
+        )
+        .build()
+        .expect("writer");
+    let reader = subscriber
+        .topic::<Tick>(topic)
+        .expect("topic")
+        .reader()
+        .qos(QoS::reliable().keep_last(1000))
+        .build()
+        .expect("reader");
+
+    // Wait until the writer reaches the reader
+    let deadline = Instant::now() + Duration::from_secs(10);
+    loop {
+        writer
+            .write(&Tick {
+                id: PROBE,
+                value: 0.0,
+            })
+            .expect("probe");
+        writer.flush().expect("flush");
+        thread::sleep(Duration::from_millis(50));
+        if !reader.take_n(100).expect("take").is_empty() {
+            break;
+        }
+        assert!(Instant::now() < deadline, "timed out waiting for match");
+    }
+
+    const BASE_NS: u64 = 1_700_000_000_000_000_000;
+    for id in 0..100u32 {
+        let tick = Tick {
+            id,
+            value: id as f32,
+        };
+        writer
+            .write_with_timestamp(&tick, BASE_NS + u64::from(id) * 1_000)
+            .expect("write");
+    }
+    writer.flush().expect("flush");
+
+    let samples = collect(&reader, 100);
+    for (id, (tick, info)) in (0..100u32).zip(&samples) {
+        assert_eq!(tick.id, id);
+        assert_eq!(
+            info.source_timestamp_ns,
+            Some(BASE_NS + u64::from(id) * 1_000)
+        );
+    }
+}
+
+#[test]
+fn test_batch_sent_after_max_delay() {
+    let topic = "batching/delay";
+    let publisher = participant("delay_pub");
+    let subscriber = participant("delay_sub");
+
+    let writer = publisher
+        .topic::<Tick>(topic)
+        .expect("topic")
+        .writer()
+        .qos(
+            QoS::reliable()
+                .keep_last(100)
+                .batching(60_000, Duration::from_millis(20)),
+        )
+        .build()
+        .expect("writer");
+    let reader = subscriber
+        .topic::<Tick>(topic)
+        .expect("topic")
+        .reader()
+        .qos(QoS::reliable().keep_last(100))
+        .build()
+        .expect("reader");
+
+    // Never flushed: the delay alone sends each batch
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while reader.take_n(100).expect("take").is_empty() {
+        assert!(Instant::now() < deadline, "timed out waiting for match");
+        writer
+            .write(&Tick {
+                id: PROBE,
+                value: 0.0,
+            })
+            .expect("probe");
+        thread::sleep(Duration::from_millis(50));
+    }
+
+    for id in 0..3u32 {
+        writer.write(&Tick { id, value: 1.5 }).expect("write");
+    }
+    let ids: Vec<u32> = collect(&reader, 3)
+        .into_iter()
+        .map(|(tick, _)| tick.id)
+        .collect();
+    assert_eq!(ids, [0, 1, 2]);
+}
@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Deployment manifest integration tests
+//!
+//! A manifest creates two participants over the loopback bus, a writer on
+//! one and a reader with a named callback on the other; data written
+//! through the registry reaches the callback and the reader cache.
+
+#![cfg(feature = "qos-loaders")]
+
+use hdds::deploy::Deployer;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const MANIFEST: &str = r#"
+profiles:
+  sensor:
+    reliability: RELIABLE
+    history: { kind: KEEP_LAST, depth: 10 }
+topics:
+  - name: deploy/pose
+    type: Pose
+    qos: sensor
+participants:
+  - name: deploy_robot
+    domain_id: 84
+    transport: loopback_bus
+    writers:
+      - name: pose_out
+        topic: deploy/pose
+  - name: deploy_monitor
+    domain_id: 84
+    transport: loopback_bus
+    readers:
+      - name: pose_in
+        topic: deploy/pose
+        on_data: forward
+      - name: pose_log
+        topic: deploy/pose
+"#;
+
+#[test]
+fn test_manifest_wires_writer_to_callback() {
+    let (tx, rx) = mpsc::channel();
+    let tx = std::sync::Mutex::new(tx);
+    let deployment = Deployer::new()
+        .on_data("forward", move |payload| {
+            let _ = tx.lock().expect("sender").send(payload.to_vec());
+        })
+        .deploy_str(MANIFEST)
+        .expect("deployment");
+
+    let mut names: Vec<_> = deployment.reader_names().collect();
+    names.sort_unstable();
+    assert_eq!(names, ["pose_in", "pose_log"]);
+    let robot = deployment.participant("deploy_robot").expect("participant");
+    assert_eq!(robot.domain_id(), 84);
+    let writer = deployment.writer("pose_out").expect("writer");
+    assert_eq!(writer.topic_name(), "deploy/pose");
+
+    let payload = [1u8, 2, 3, 4, 5, 6, 7, 8];
+    let deadline = Instant::now() + Duration::from_secs(10);
+    let received = loop {
+        writer.write_raw(&payload).expect("write");
+        if let Ok(received) = rx.recv_timeout(Duration::from_millis(50)) {
+            break received;
+        }
+        assert!(Instant::now() < deadline, "callback never called");
+    };
+    assert_eq!(received, payload);
+
+    // The reader without callback caches the samples
+    let log = deployment.reader("pose_log").expect("reader");
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while log.try_take_raw().expect("take").is_empty() {
+        assert!(Instant::now() < deadline, "no sample cached");
+        thread::sleep(Duration::from_millis(20));
+    }
+}
+
+#[test]
+fn test_manifest_load_errors() {
+    let err = hdds::deploy::from_manifest("/nonexistent/app.yaml");
+    assert!(matches!(err, Err(hdds::Error::ConfigFileNotFound(_))));
+
+    let err = Deployer::new().deploy_str(MANIFEST);
+    assert!(matches!(err, Err(hdds::Error::InvalidConfig { .. })));
+}
@@ -0,0 +1,262 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! `#[derive(DDS)]` on nested structs, enums, arrays, sequences and
+//! optional members; member IDs and bounds
+
+use hdds::core::ser::idl::{array_type_id, decode_sample, sequence_type_id};
+use hdds::dds::DDS;
+use hdds::xtypes::{CompleteTypeObject, MemberFlag, TypeIdentifier};
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+enum Mode {
+    Idle,
+    Tracking = 5,
+}
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Target {
+    #[key]
+    id: u32,
+    #[key]
+    origin: Point,
+    mode: Mode,
+    corners: [Point; 2],
+    matrix: [[i16; 3]; 2],
+    path: Vec<Point>,
+    ranges: Vec<f32>,
+    label: Option<String>,
+    estimate: Option<Point>,
+    raw: Vec<u8>,
+    valid: bool,
+}
+
+fn target() -> Target {
+    Target {
+        id: 7,
+        origin: Point { x: 1.0, y: -2.0 },
+        mode: Mode::Tracking,
+        corners: [Point { x: 0.0, y: 0.0 }, Point { x: 4.0, y: 3.0 }],
+        matrix: [[1, 2, 3], [4, 5, 6]],
+        path: vec![Point { x: 0.5, y: 0.25 }],
+        ranges: vec![10.0, 20.5],
+        label: Some("drone".to_string()),
+        estimate: None,
+        raw: vec![0xAB, 0xCD, 0xEF],
+        valid: true,
+    }
+}
+
+fn round_trip<T: DDS>(sample: &T) -> T {
+    let mut buf = [0u8; 512];
+    let len = sample.encode_cdr2(&mut buf).expect("encode");
+    T::decode_cdr2(&buf[..len]).expect("decode")
+}
+
+#[test]
+fn test_round_trip() {
+    let sample = target();
+    assert_eq!(round_trip(&sample), sample);
+
+    let mut sample = target();
+    sample.mode = Mode::Idle;
+    sample.label = None;
+    sample.estimate = Some(Point { x: 9.0, y: 8.0 });
+    sample.path.clear();
+    assert_eq!(round_trip(&sample), sample);
+}
+
+#[test]
+fn test_alignment_is_relative_to_sample_start() {
+    #[derive(hdds::DDS, Debug, PartialEq)]
+    struct Aligned {
+        flag: u8,
+        point: Point,
+        mode: Mode,
+        count: Option<u16>,
+    }
+
+    let sample = Aligned {
+        flag: 1,
+        point: Point { x: 2.0, y: 3.0 },
+        mode: Mode::Tracking,
+        count: Some(9),
+    };
+    let mut buf = [0u8; 64];
+    let len = sample.encode_cdr2(&mut buf).expect("encode");
+    // flag, padding to 8 for the nested f64s, enum as u32, presence flag,
+    // padding to 2 for the u16
+    assert_eq!(len, 1 + 7 + 16 + 4 + 1 + 1 + 2);
+    assert_eq!(&buf[..8], &[1, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(&buf[8..16], &2.0f64.to_le_bytes());
+    assert_eq!(&buf[24..32], &[5, 0, 0, 0, 1, 0, 9, 0]);
+    assert_eq!(Aligned::decode_cdr2(&buf[..len]).expect("decode"), sample);
+}
+
+#[test]
+fn test_invalid_enum_value_is_rejected() {
+    assert_eq!(
+        decode_sample::<Mode>(&[5, 0, 0, 0]).expect("decode"),
+        Mode::Tracking
+    );
+    assert!(decode_sample::<Mode>(&[1, 0, 0, 0]).is_err());
+}
+
+#[test]
+fn test_nested_key() {
+    assert!(Target::has_key());
+    let a = target();
+    let mut b = target();
+    b.mode = Mode::Idle;
+    b.path.clear();
+    assert_eq!(a.compute_key(), b.compute_key());
+
+    b.origin.y = 2.0;
+    assert_ne!(a.compute_key(), b.compute_key());
+}
+
+#[test]
+fn test_type_object() {
+    let Some(CompleteTypeObject::Struct(target)) = Target::get_type_object() else {
+        panic!("struct type object expected");
+    };
+    let members = &target.member_seq;
+    assert_eq!(members.len(), 11);
+
+    let member = |name: &str| {
+        members
+            .iter()
+            .find(|member| member.detail.name == name)
+            .map(|member| &member.common)
+            .expect("member")
+    };
+    let origin = member("origin");
+    assert!(origin.member_flags.contains(MemberFlag::IS_KEY));
+    assert_eq!(
+        origin.member_type_id,
+        TypeIdentifier::Inline(Box::new(Point::type_object()))
+    );
+    assert_eq!(
+        member("mode").member_type_id,
+        TypeIdentifier::Inline(Box::new(Mode::type_object()))
+    );
+    assert_eq!(
+        member("matrix").member_type_id,
+        array_type_id(TypeIdentifier::TK_INT16, vec![2, 3])
+    );
+    assert_eq!(
+        member("path").member_type_id,
+        sequence_type_id(TypeIdentifier::Inline(Box::new(Point::type_object())), 0)
+    );
+
+    let label = member("label");
+    assert_eq!(label.member_flags, MemberFlag::IS_OPTIONAL);
+    assert_eq!(label.member_type_id, TypeIdentifier::TK_STRING8);
+    assert_eq!(member("valid").member_flags, MemberFlag::empty());
+
+    let CompleteTypeObject::Enumerated(mode) = Mode::type_object() else {
+        panic!("enumerated type object expected");
+    };
+    let literals: Vec<_> = mode
+        .literal_seq
+        .iter()
+        .map(|literal| (literal.detail.name.as_str(), literal.common.value))
+        .collect();
+    assert_eq!(literals, [("Idle", 0), ("Tracking", 5)]);
+}
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Labeled {
+    #[dds(key, bound = 8)]
+    name: String,
+    #[dds(id = 10)]
+    count: u32,
+    #[dds(bound = 2)]
+    points: Vec<Point>,
+    #[dds(id = 4, bound = 4)]
+    note: Option<String>,
+    raw: Vec<u8>,
+}
+
+fn labeled() -> Labeled {
+    Labeled {
+        name: "gate".to_string(),
+        count: 3,
+        points: vec![Point { x: 1.0, y: 2.0 }],
+        note: Some("ok".to_string()),
+        raw: vec![1],
+    }
+}
+
+#[test]
+fn test_member_ids() {
+    let Some(CompleteTypeObject::Struct(labeled)) = Labeled::get_type_object() else {
+        panic!("struct type object expected");
+    };
+    let ids: Vec<_> = labeled
+        .member_seq
+        .iter()
+        .map(|member| (member.detail.name.as_str(), member.common.member_id))
+        .collect();
+    assert_eq!(
+        ids,
+        [
+            ("name", 0),
+            ("count", 10),
+            ("points", 11),
+            ("note", 4),
+            ("raw", 5)
+        ]
+    );
+    assert!(labeled.member_seq[0]
+        .common
+        .member_flags
+        .contains(MemberFlag::IS_KEY));
+}
+
+#[test]
+fn test_bounds_are_announced_and_enforced() {
+    let Some(CompleteTypeObject::Struct(object)) = Labeled::get_type_object() else {
+        panic!("struct type object expected");
+    };
+    let type_ids: Vec<_> = object
+        .member_seq
+        .iter()
+        .map(|member| member.common.member_type_id.clone())
+        .collect();
+    assert_eq!(type_ids[0], TypeIdentifier::string(8));
+    assert_eq!(
+        type_ids[2],
+        sequence_type_id(TypeIdentifier::Inline(Box::new(Point::type_object())), 2)
+    );
+    assert_eq!(type_ids[3], TypeIdentifier::string(4));
+
+    let sample = labeled();
+    assert_eq!(round_trip(&sample), sample);
+
+    let mut buf = [0u8; 256];
+    let mut long_name = labeled();
+    long_name.name = "too long name".to_string();
+    assert!(long_name.encode_cdr2(&mut buf).is_err());
+
+    let mut long_note = labeled();
+    long_note.note = Some("too long".to_string());
+    assert!(long_note.encode_cdr2(&mut buf).is_err());
+
+    // A peer ignoring the bound is rejected on decode
+    let mut many_points = labeled();
+    many_points.points = vec![Point { x: 0.0, y: 0.0 }; 2];
+    let len = many_points.encode_cdr2(&mut buf).expect("at the bound");
+    let offset = buf[..len]
+        .windows(4)
+        .position(|window| window == [2, 0, 0, 0])
+        .expect("sequence length");
+    buf[offset] = 3;
+    assert!(Labeled::decode_cdr2(&buf[..len]).is_err());
+}
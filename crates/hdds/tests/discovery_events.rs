@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Participant::discovery_events over the loopback bus.
+
+use hdds::{DiscoveryEvent, DiscoveryEvents, Participant, QoS, TransportMode};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const DOMAIN: u32 = 90;
+const TOPIC: &str = "discovery_events/reading";
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Reading {
+    value: u32,
+}
+
+fn participant(name: &str) -> Arc<Participant> {
+    Participant::builder(name)
+        .domain_id(DOMAIN)
+        .with_transport(TransportMode::LoopbackBus)
+        .build()
+        .expect("participant")
+}
+
+/// Take events until one satisfies `pick`, for up to 10 s.
+fn next_matching<T>(
+    events: &DiscoveryEvents,
+    mut pick: impl FnMut(&DiscoveryEvent) -> Option<T>,
+) -> T {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    loop {
+        let left = deadline.saturating_duration_since(Instant::now());
+        let event = events
+            .next_timeout(left)
+            .expect("timed out waiting for event");
+        if let Some(value) = pick(&event) {
+            return value;
+        }
+    }
+}
+
+#[test]
+fn test_discovery_events_follow_remote_participant() {
+    let alice = participant("discovery_events_alice");
+    let events = alice.discovery_events().expect("events");
+
+    let bob = participant("discovery_events_bob");
+    let bob_prefix = bob.guid().prefix;
+    let _writer = bob
+        .topic::<Reading>(TOPIC)
+        .expect("topic")
+        .writer()
+        .qos(QoS::reliable().keep_last(4))
+        .build()
+        .expect("writer");
+
+    let peer = next_matching(&events, |event| match event {
+        DiscoveryEvent::ParticipantDiscovered(p) if p.guid.prefix == bob_prefix => Some(p.clone()),
+        _ => None,
+    });
+    assert!(!peer.endpoints.is_empty(), "participant locators");
+
+    let writer = next_matching(&events, |event| match event {
+        DiscoveryEvent::WriterDiscovered(w) if w.topic_name == TOPIC => Some(w.clone()),
+        _ => None,
+    });
+    assert_eq!(writer.endpoint_guid.prefix, bob_prefix);
+    assert_eq!(writer.qos.reliability, QoS::reliable().reliability);
+
+    // A late subscription is primed with what is already known
+    let late = alice.discovery_events().expect("events");
+    next_matching(&late, |event| match event {
+        DiscoveryEvent::WriterDiscovered(w) if w.endpoint_guid == writer.endpoint_guid => Some(()),
+        _ => None,
+    });
+
+    // The writer goes before its participant
+    bob.shutdown();
+    let mut lost = Vec::new();
+    next_matching(&events, |event| {
+        if event.is_lost() && event.guid().prefix == bob_prefix {
+            lost.push(event.clone());
+        }
+        matches!(event, DiscoveryEvent::ParticipantLost(_)).then_some(())
+    });
+    assert!(
+        matches!(&lost[..], [DiscoveryEvent::WriterLost(w), DiscoveryEvent::ParticipantLost(_)]
+            if w.endpoint_guid == writer.endpoint_guid),
+        "unexpected lost events: {:?}",
+        lost
+    );
+}
+
+#[test]
+fn test_local_endpoints_are_not_reported() {
+    let carol = participant("discovery_events_carol");
+    let events = carol.discovery_events().expect("events");
+    let _reader = carol
+        .topic::<Reading>("discovery_events/local")
+        .expect("topic")
+        .reader()
+        .build()
+        .expect("reader");
+
+    while let Some(event) = events.next_timeout(Duration::from_millis(500)) {
+        assert!(
+            !matches!(event, DiscoveryEvent::ReaderDiscovered(_)),
+            "own reader reported: {:?}",
+            event
+        );
+    }
+}
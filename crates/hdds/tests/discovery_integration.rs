@@ -308,6 +308,39 @@ fn test_sedp_endpoint_announcement() {
     println!("---------------------------------------------\n");
 }
 
+#[test]
+#[ignore] // Ignore by default (requires UDP multicast permissions)
+fn test_wait_for_topic_and_publisher() {
+    let participant = Participant::builder("graph_wait")
+        .domain_id(44)
+        .with_transport(TransportMode::UdpMulticast)
+        .build()
+        .expect("Failed to create participant");
+
+    let _writer = participant
+        .topic::<Temperature>("sensor/room1/temp")
+        .expect("Failed to create topic")
+        .writer()
+        .qos(QoS::best_effort())
+        .build()
+        .expect("Failed to create writer");
+
+    let topic = participant
+        .wait_for_topic("sensor/+/temp", Duration::from_secs(2))
+        .expect("topic should be discovered");
+    assert_eq!(topic.name, "sensor/room1/temp");
+
+    let count = participant
+        .wait_for_publisher("sensor/room1/temp", 1, Duration::from_secs(2))
+        .expect("writer should be discovered");
+    assert_eq!(count, 1);
+
+    assert!(matches!(
+        participant.wait_for_topic("sensor/+/humidity", Duration::from_millis(50)),
+        Err(hdds::Error::DiscoveryTimeout)
+    ));
+}
+
 #[test]
 fn test_discovery_api_intraprocess_mode() {
     // Test that discovery API returns None for IntraProcess mode
@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Dynamic writers and readers against generated-type endpoints.
+
+#![cfg(feature = "dynamic")]
+
+mod common;
+
+use common::wait_for;
+use hdds::dynamic::{DynamicData, PrimitiveKind, TypeDescriptor, TypeDescriptorBuilder};
+use hdds::{Participant, QoS, TransportMode};
+use std::sync::Arc;
+
+const DOMAIN: u32 = 98;
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Reading {
+    flags: u32,
+    value: f64,
+    label: String,
+}
+
+fn participant(name: &str) -> Arc<Participant> {
+    Participant::builder(name)
+        .domain_id(DOMAIN)
+        .with_transport(TransportMode::UdpMulticast)
+        .build()
+        .expect("participant")
+}
+
+/// Runtime twin of [`Reading`].
+fn reading_type() -> Arc<TypeDescriptor> {
+    Arc::new(
+        TypeDescriptorBuilder::new("Reading")
+            .field("flags", PrimitiveKind::U32)
+            .field("value", PrimitiveKind::F64)
+            .string_field("label")
+            .build(),
+    )
+}
+
+#[test]
+fn test_dynamic_writer_to_typed_reader() {
+    let topic = "dynamic/to_typed";
+    let publisher = participant("dynamic_pub");
+    let subscriber = participant("typed_sub");
+
+    let reading = reading_type();
+    let writer = publisher
+        .create_dynamic_writer(topic, &reading, Some(QoS::reliable()))
+        .expect("dynamic writer");
+    let reader = subscriber
+        .topic::<Reading>(topic)
+        .expect("topic")
+        .reader()
+        .qos(QoS::reliable())
+        .build()
+        .expect("typed reader");
+
+    let mut data = DynamicData::new(&reading);
+    data.set("flags", 3u32).expect("flags");
+    data.set("value", 21.5f64).expect("value");
+    data.set("label", "north".to_string()).expect("label");
+
+    let sample = wait_for(|| {
+        writer.write(&data).expect("write");
+        reader.take().expect("take")
+    });
+    assert_eq!(
+        sample,
+        Reading {
+            flags: 3,
+            value: 21.5,
+            label: "north".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_typed_writer_to_dynamic_reader() {
+    let topic = "dynamic/from_typed";
+    let publisher = participant("typed_pub");
+    let subscriber = participant("dynamic_sub");
+
+    let reading = reading_type();
+    let writer = publisher
+        .topic::<Reading>(topic)
+        .expect("topic")
+        .writer()
+        .qos(QoS::reliable())
+        .build()
+        .expect("typed writer");
+    let reader = subscriber
+        .create_dynamic_reader(topic, &reading, Some(QoS::reliable()))
+        .expect("dynamic reader");
+
+    let sample = Reading {
+        flags: 7,
+        value: -4.25,
+        label: "south".to_string(),
+    };
+    let data = wait_for(|| {
+        writer.write(&sample).expect("write");
+        reader.take().expect("take")
+    });
+    assert_eq!(data.get::<u32>("flags").expect("flags"), 7);
+    assert_eq!(data.get::<f64>("value").expect("value"), -4.25);
+    assert_eq!(data.get::<String>("label").expect("label"), "south");
+}
+
+#[test]
+fn test_dynamic_writer_rejects_other_type() {
+    let publisher = participant("dynamic_mismatch");
+    let writer = publisher
+        .create_dynamic_writer("dynamic/mismatch", &reading_type(), None)
+        .expect("dynamic writer");
+
+    let other = Arc::new(
+        TypeDescriptorBuilder::new("Other")
+            .field("id", PrimitiveKind::U32)
+            .build(),
+    );
+    assert!(matches!(
+        writer.write(&DynamicData::new(&other)),
+        Err(hdds::Error::TypeMismatch)
+    ));
+}
@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+#![cfg(feature = "discovery-server")]
+
+//! Embedded Discovery Server integration tests
+//!
+//! Two participants configured as the discovery server on the same port: the
+//! first serves, the second stands by and takes the clients over when the
+//! first one is dropped.
+
+use hdds::discovery_server::{ClientEvent, DiscoveryServerClient, DiscoveryServerConfig};
+use hdds::{Participant, TransportMode};
+use std::net::{SocketAddr, TcpListener};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const SERVER_GUID: [u8; 12] = [0x44, 0x53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+
+fn free_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0")
+        .and_then(|listener| listener.local_addr())
+        .map(|addr| addr.port())
+        .expect("free port")
+}
+
+fn server_participant(name: &str, port: u16) -> Arc<Participant> {
+    Participant::builder(name)
+        .with_transport(TransportMode::IntraProcess)
+        .as_discovery_server(SERVER_GUID, port)
+        .build()
+        .expect("participant")
+}
+
+fn wait_for(
+    client: &mut DiscoveryServerClient,
+    pred: impl Fn(&ClientEvent) -> bool,
+) -> Option<ClientEvent> {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while Instant::now() < deadline {
+        if let Ok(Some(event)) = client.read_message() {
+            if pred(&event) {
+                return Some(event);
+            }
+        }
+    }
+    None
+}
+
+#[test]
+fn test_embedded_server_fails_over_to_standby() {
+    let port = free_port();
+    let active = server_participant("ds_active", port);
+    let standby = server_participant("ds_standby", port);
+
+    assert!(active.is_discovery_server());
+    assert!(!standby.is_discovery_server());
+    let standby_port = standby
+        .embedded_discovery_server()
+        .expect("standby server")
+        .local_addr()
+        .port();
+    assert_eq!(
+        standby
+            .discovery_server_config()
+            .and_then(|config| config.standby_port),
+        Some(standby_port)
+    );
+
+    // The standby registers with the active server
+    let mut standby_client = standby
+        .create_discovery_server_client()
+        .expect("standby client");
+    standby_client.connect().expect("connect standby");
+    standby_client
+        .announce_participant(0, Some("ds_standby".into()), vec![], 0x3f)
+        .expect("announce standby");
+
+    let server: SocketAddr = ([127, 0, 0, 1], port).into();
+    let mut app = DiscoveryServerClient::new(DiscoveryServerConfig::new(server), [0x0a; 12])
+        .expect("app client");
+    app.connect().expect("connect app");
+    app.announce_participant(0, Some("app".into()), vec![], 0x3f)
+        .expect("announce app");
+    assert!(wait_for(&mut app, |e| matches!(
+        e,
+        ClientEvent::ParticipantDiscovered { guid_prefix, .. } if *guid_prefix == standby.guid().prefix
+    ))
+    .is_some());
+
+    // Shutting the active participant down moves the app to the standby
+    drop(active);
+    let redirected = wait_for(&mut app, |e| matches!(e, ClientEvent::Redirected { .. }));
+    assert!(
+        matches!(
+            redirected,
+            Some(ClientEvent::Redirected { server_address }) if server_address.port() == standby_port
+        ),
+        "{:?}",
+        redirected
+    );
+    assert!(wait_for(&mut app, |e| matches!(
+        e,
+        ClientEvent::ParticipantAcknowledged
+    ))
+    .is_some());
+    assert!(
+        standby
+            .embedded_discovery_server()
+            .expect("standby server")
+            .participant_count()
+            >= 1
+    );
+}
@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Idle-endpoint hibernation integration tests
+//!
+//! Endpoints of a participant built with_endpoint_hibernation() hibernate
+//! once idle, stay matched, and wake up on the next sample.
+
+use hdds::{HibernationConfig, Participant, QoS, TransportMode};
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Reading {
+    value: u32,
+}
+
+fn participant(name: &str) -> std::sync::Arc<Participant> {
+    Participant::builder(name)
+        .with_transport(TransportMode::IntraProcess)
+        .with_endpoint_hibernation(
+            HibernationConfig::new(Duration::from_millis(200))
+                .check_interval(Duration::from_millis(20)),
+        )
+        .build()
+        .expect("participant")
+}
+
+fn wait_until(participant: &Participant, cond: impl Fn(hdds::HibernationStats) -> bool) -> bool {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while Instant::now() < deadline {
+        if cond(
+            participant
+                .hibernation_stats()
+                .expect("hibernation enabled"),
+        ) {
+            return true;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    false
+}
+
+#[test]
+fn test_idle_endpoints_hibernate_and_wake_on_traffic() {
+    let participant = participant("hibernation_idle");
+    let topic = participant.topic::<Reading>("idle/topic").expect("topic");
+    let reader = topic
+        .reader()
+        .qos(QoS::reliable().keep_last(100))
+        .build()
+        .expect("reader");
+    let writer = topic
+        .writer()
+        .qos(QoS::reliable().keep_last(100))
+        .build()
+        .expect("writer");
+
+    let stats = participant.hibernation_stats().expect("stats");
+    assert_eq!((stats.writers, stats.readers), (1, 1));
+    assert!(wait_until(&participant, |s| {
+        s.hibernating_writers == 1 && s.hibernating_readers == 1
+    }));
+
+    // Traffic wakes both sides; the sample is delivered as usual
+    writer.write(&Reading { value: 7 }).expect("write");
+    assert!(wait_until(&participant, |s| {
+        s.hibernating_writers == 0 && s.hibernating_readers == 0
+    }));
+    assert_eq!(reader.take().expect("take"), Some(Reading { value: 7 }));
+    assert!(
+        participant
+            .hibernation_stats()
+            .expect("stats")
+            .reactivations
+            >= 2
+    );
+
+    // And they hibernate again once idle
+    assert!(wait_until(&participant, |s| s.hibernating_writers == 1));
+}
+
+#[test]
+fn test_hibernation_is_opt_in() {
+    let participant = Participant::builder("hibernation_off")
+        .with_transport(TransportMode::IntraProcess)
+        .build()
+        .expect("participant");
+    assert!(participant.hibernation_config().is_none());
+    assert!(participant.hibernation_stats().is_none());
+}
+
+#[test]
+fn test_dropped_endpoints_leave_the_sweep() {
+    let participant = participant("hibernation_drop");
+    let topic = participant.topic::<Reading>("drop/topic").expect("topic");
+    let writer = topic.writer().qos(QoS::reliable()).build().expect("writer");
+    assert_eq!(participant.hibernation_stats().expect("stats").writers, 1);
+
+    drop(writer);
+    assert_eq!(participant.hibernation_stats().expect("stats").writers, 0);
+}
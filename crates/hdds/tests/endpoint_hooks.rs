@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Endpoint creation hook integration tests
+//!
+//! Process-wide and participant hooks see every writer and reader created,
+//! may adjust their QoS and reject their creation.
+
+use hdds::dds::{EndpointKind, Reliability};
+use hdds::{register_endpoint_hook, EndpointCreation, Error, Participant, QoS, TransportMode};
+use std::sync::{Arc, Mutex};
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Reading {
+    value: u32,
+}
+
+#[test]
+fn test_participant_hook_adjusts_qos_and_rejects() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let log = Arc::clone(&seen);
+    let participant = Participant::builder("hooked")
+        .with_transport(TransportMode::IntraProcess)
+        .with_endpoint_hook(move |endpoint: &mut EndpointCreation<'_>| {
+            log.lock().expect("log").push((
+                endpoint.kind(),
+                endpoint.topic().to_string(),
+                endpoint.participant_name().to_string(),
+            ));
+            if endpoint.topic().contains(char::is_uppercase) {
+                return Err(endpoint.reject("topic names are lowercase"));
+            }
+            if endpoint.topic().starts_with("cmd/") {
+                endpoint.qos_mut().reliability = Reliability::Reliable;
+            }
+            Ok(())
+        })
+        .build()
+        .expect("participant");
+
+    let writer = participant
+        .topic::<Reading>("cmd/reading")
+        .expect("topic")
+        .writer()
+        .qos(QoS::best_effort())
+        .build()
+        .expect("writer");
+    assert_eq!(writer.qos().reliability, Reliability::Reliable);
+
+    let reader = participant
+        .topic::<Reading>("telemetry/reading")
+        .expect("topic")
+        .reader()
+        .qos(QoS::best_effort())
+        .build()
+        .expect("reader");
+    assert_eq!(reader.qos().reliability, Reliability::BestEffort);
+
+    let rejected = participant
+        .topic::<Reading>("Telemetry/Reading")
+        .expect("topic")
+        .reader()
+        .build();
+    match rejected {
+        Err(Error::EndpointRejected(msg)) => {
+            assert_eq!(
+                msg,
+                "reader on 'Telemetry/Reading': topic names are lowercase"
+            )
+        }
+        Err(e) => panic!("unexpected error {}", e),
+        Ok(_) => panic!("reader should be rejected"),
+    }
+
+    let seen = seen.lock().expect("log");
+    assert_eq!(
+        *seen,
+        [
+            (EndpointKind::Writer, "cmd/reading".into(), "hooked".into()),
+            (
+                EndpointKind::Reader,
+                "telemetry/reading".into(),
+                "hooked".into()
+            ),
+            (
+                EndpointKind::Reader,
+                "Telemetry/Reading".into(),
+                "hooked".into()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_process_hook_applies_to_every_participant() {
+    // Only touches this test's topics: other tests share the process
+    register_endpoint_hook(|endpoint: &mut EndpointCreation<'_>| {
+        if endpoint.topic() == "hooks/forbidden" {
+            return Err(endpoint.reject("reserved topic"));
+        }
+        Ok(())
+    });
+
+    for name in ["first", "second"] {
+        let participant = Participant::builder(name)
+            .with_transport(TransportMode::IntraProcess)
+            .build()
+            .expect("participant");
+        let topic = participant
+            .topic::<Reading>("hooks/forbidden")
+            .expect("topic");
+        assert!(matches!(
+            topic.writer().build(),
+            Err(Error::EndpointRejected(_))
+        ));
+        participant
+            .topic::<Reading>("hooks/allowed")
+            .expect("topic")
+            .writer()
+            .build()
+            .expect("writer");
+    }
+}
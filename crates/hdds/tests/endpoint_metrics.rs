@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Per-endpoint metrics integration tests
+//!
+//! A reliable writer and reader on two loopback bus participants exchange
+//! samples; their counters show up in `stats()`, in the admin API metrics
+//! snapshot and in its Prometheus rendering.
+
+mod common;
+
+use common::wait_for;
+use hdds::{AdminApi, Participant, QoS, TransportMode};
+use std::sync::Arc;
+
+const DOMAIN: u32 = 83;
+const TOPIC: &str = "metrics/reading";
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Reading {
+    id: u32,
+    value: f64,
+}
+
+fn participant(name: &str) -> Arc<Participant> {
+    Participant::builder(name)
+        .domain_id(DOMAIN)
+        .with_transport(TransportMode::LoopbackBus)
+        .build()
+        .expect("participant")
+}
+
+#[test]
+fn test_endpoint_counters_in_admin_snapshot() {
+    let alice = participant("metrics_alice");
+    let bob = participant("metrics_bob");
+    let writer = alice
+        .topic::<Reading>(TOPIC)
+        .expect("topic")
+        .writer()
+        .qos(QoS::reliable())
+        .build()
+        .expect("writer");
+    let reader = bob
+        .topic::<Reading>(TOPIC)
+        .expect("topic")
+        .reader()
+        .qos(QoS::reliable())
+        .build()
+        .expect("reader");
+
+    let sample = Reading { id: 1, value: 0.5 };
+    wait_for(|| {
+        writer.write(&sample).expect("write");
+        reader.take().expect("take")
+    });
+
+    let written = writer.stats();
+    assert!(written.messages_sent >= 1);
+    assert!(written.bytes_sent >= written.messages_sent);
+    let received = reader.stats();
+    assert!(received.messages_received >= 1);
+    assert!(received.bytes_received >= received.messages_received);
+
+    let api = AdminApi::bind("127.0.0.1", 0, None).expect("admin api");
+    let snapshot = api.snapshot_metrics();
+    let view = snapshot
+        .writers
+        .iter()
+        .find(|w| w.topic_name == TOPIC)
+        .expect("writer listed");
+    assert!(view.samples >= written.messages_sent);
+    assert_eq!(view.ack_rtt.counts.len(), view.ack_rtt.bounds_us.len() + 1);
+    assert!(snapshot.readers.iter().any(|r| r.topic_name == TOPIC));
+
+    let text = api.prometheus_metrics();
+    assert!(text.contains("# TYPE hdds_writer_ack_rtt_seconds histogram"));
+    assert!(text.contains(&format!(r#",topic="{}"}}"#, TOPIC)));
+    api.shutdown();
+
+    // Dropped endpoints leave the snapshot
+    drop(writer);
+    drop(reader);
+    let snapshot = api.snapshot_metrics();
+    assert!(snapshot.writers.iter().all(|w| w.topic_name != TOPIC));
+    assert!(snapshot.readers.iter().all(|r| r.topic_name != TOPIC));
+}
@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! ENTITY_NAME of writers/readers propagated through SEDP.
+
+mod common;
+
+use common::wait_for;
+use hdds::{Participant, QoS, TransportMode};
+use std::sync::Arc;
+
+const DOMAIN: u32 = 93;
+const TOPIC: &str = "entity_name/reading";
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Reading {
+    value: u32,
+}
+
+fn participant(name: &str, domain_id: u32) -> Arc<Participant> {
+    Participant::builder(name)
+        .domain_id(domain_id)
+        .with_transport(TransportMode::LoopbackBus)
+        .build()
+        .expect("participant")
+}
+
+#[test]
+fn test_entity_names_visible_to_remote_participant() {
+    let alice = participant("entity_name_alice", DOMAIN);
+    let bob = participant("entity_name_bob", DOMAIN);
+
+    // Builder name wins over the QoS one
+    let _writer = alice
+        .topic::<Reading>(TOPIC)
+        .expect("topic")
+        .writer()
+        .qos(QoS::reliable().entity_name("from_qos"))
+        .name("imu_publisher")
+        .build()
+        .expect("writer");
+    let _reader = bob
+        .topic::<Reading>(TOPIC)
+        .expect("topic")
+        .reader()
+        .qos(QoS::reliable().entity_name("imu_logger"))
+        .build()
+        .expect("reader");
+
+    let bob_fsm = bob.discovery().expect("discovery");
+    let writer = wait_for(|| bob_fsm.find_writers_for_topic(TOPIC).into_iter().next());
+    assert_eq!(writer.qos.entity_name.as_deref(), Some("imu_publisher"));
+
+    let alice_fsm = alice.discovery().expect("discovery");
+    let reader = wait_for(|| alice_fsm.find_readers_for_topic(TOPIC).into_iter().next());
+    assert_eq!(reader.qos.entity_name.as_deref(), Some("imu_logger"));
+}
+
+#[test]
+fn test_unnamed_endpoint_has_no_entity_name() {
+    let carol = participant("entity_name_carol", DOMAIN + 1);
+    let dave = participant("entity_name_dave", DOMAIN + 1);
+
+    let _writer = carol
+        .topic::<Reading>("entity_name/unnamed")
+        .expect("topic")
+        .writer()
+        .build()
+        .expect("writer");
+
+    let dave_fsm = dave.discovery().expect("discovery");
+    let writer = wait_for(|| {
+        dave_fsm
+            .find_writers_for_topic("entity_name/unnamed")
+            .into_iter()
+            .next()
+    });
+    assert_eq!(writer.qos.entity_name, None);
+}
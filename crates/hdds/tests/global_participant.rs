@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Process-wide participants shared per domain.
+
+use hdds::dds::ParticipantBuilder;
+use hdds::{global_participant_with, Participant, TransportMode};
+use std::sync::Arc;
+use std::thread;
+
+fn intra(builder: ParticipantBuilder) -> ParticipantBuilder {
+    builder.with_transport(TransportMode::IntraProcess)
+}
+
+fn global(domain_id: u32) -> Arc<Participant> {
+    global_participant_with(domain_id, intra).expect("global participant")
+}
+
+#[test]
+fn test_one_participant_per_domain() {
+    let a = global(90);
+    let b = global(90);
+    assert!(Arc::ptr_eq(&a, &b));
+    assert_eq!(a.domain_id(), 90);
+
+    let other = global(91);
+    assert!(!Arc::ptr_eq(&a, &other));
+    assert_eq!(other.domain_id(), 91);
+}
+
+#[test]
+fn test_configure_applies_only_when_building() {
+    let first = global_participant_with(92, |builder| intra(builder.domain_id(7)))
+        .expect("global participant");
+    // The requested domain wins over the builder's
+    assert_eq!(first.domain_id(), 92);
+
+    let again = global_participant_with(92, |_| panic!("already built")).expect("again");
+    assert!(Arc::ptr_eq(&first, &again));
+}
+
+#[test]
+fn test_rebuilt_after_last_handle_dropped() {
+    let first = global(93);
+    let weak = Arc::downgrade(&first);
+    drop(first);
+    // The registry does not keep the participant alive
+    assert!(weak.upgrade().is_none());
+
+    let second = global(93);
+    assert_eq!(second.domain_id(), 93);
+    assert_eq!(Arc::strong_count(&second), 1);
+}
+
+#[test]
+fn test_concurrent_first_calls_share() {
+    let handles: Vec<_> = (0..8).map(|_| thread::spawn(|| global(94))).collect();
+    let participants: Vec<_> = handles
+        .into_iter()
+        .map(|h| h.join().expect("thread"))
+        .collect();
+    assert!(participants
+        .iter()
+        .all(|p| Arc::ptr_eq(p, &participants[0])));
+}
@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Graceful participant shutdown integration tests
+//!
+//! A participant shutting down disposes its endpoints and itself, so its
+//! peers forget them at once instead of waiting 100 s for its lease.
+
+use hdds::{Participant, TransportMode};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const DOMAIN: u32 = 88;
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Beat {
+    count: u32,
+}
+
+fn participant(name: &str) -> Arc<Participant> {
+    Participant::builder(name)
+        .domain_id(DOMAIN)
+        .with_transport(TransportMode::UdpMulticast)
+        .build()
+        .expect("participant")
+}
+
+/// Poll `condition` every 20 ms for up to `timeout`.
+fn within(timeout: Duration, condition: impl Fn() -> bool) -> bool {
+    let deadline = Instant::now() + timeout;
+    while Instant::now() < deadline {
+        if condition() {
+            return true;
+        }
+        thread::sleep(Duration::from_millis(20));
+    }
+    condition()
+}
+
+#[test]
+fn test_shutdown_disposes_participant_and_endpoints() {
+    let topic = "shutdown/beats";
+    let leaving = participant("leaving");
+    let staying = participant("staying");
+    let _writer = leaving
+        .topic::<Beat>(topic)
+        .expect("topic")
+        .writer()
+        .build()
+        .expect("writer");
+
+    let fsm = staying.discovery().expect("discovery");
+    let prefix = leaving.guid().prefix;
+    let knows_leaving = || {
+        fsm.get_participants()
+            .iter()
+            .any(|info| info.guid.prefix == prefix)
+    };
+    let knows_writer = || {
+        fsm.find_writers_for_topic(topic)
+            .iter()
+            .any(|writer| writer.endpoint_guid.prefix == prefix)
+    };
+    let discovered = || knows_leaving() && knows_writer();
+    assert!(
+        within(Duration::from_secs(10), discovered),
+        "peer not discovered"
+    );
+
+    leaving.shutdown();
+    assert!(leaving.is_shut_down());
+    assert!(leaving.transport().expect("udp").is_suspended());
+    let forgotten = || !knows_leaving() && !knows_writer();
+    assert!(
+        within(Duration::from_secs(2), forgotten),
+        "peer still known after its shutdown"
+    );
+
+    // Silent from now on: it does not come back
+    thread::sleep(Duration::from_millis(500));
+    assert!(!knows_leaving());
+}
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Initial peers integration tests
+//!
+//! A participant configured with `with_initial_peers` sends its SPDP
+//! announcements via unicast to each peer, multicast or not.
+
+use hdds::{Participant, TransportMode};
+use std::net::UdpSocket;
+use std::time::{Duration, Instant};
+
+const DOMAIN: u32 = 93;
+
+#[test]
+fn test_spdp_sent_to_initial_peer() {
+    let peer = UdpSocket::bind("127.0.0.1:0").expect("peer socket");
+    peer.set_read_timeout(Some(Duration::from_millis(200)))
+        .expect("read timeout");
+    let peer_addr = peer.local_addr().expect("peer addr").to_string();
+
+    let participant = Participant::builder("initial_peers")
+        .domain_id(DOMAIN)
+        .with_transport(TransportMode::UdpMulticast)
+        .with_initial_peers(&[peer_addr.as_str(), "not a peer"])
+        .build()
+        .expect("participant");
+
+    let prefix = &participant.guid().as_bytes()[..12];
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let mut buf = [0u8; 2048];
+    let mut announced = false;
+    while !announced && Instant::now() < deadline {
+        if let Ok((len, _)) = peer.recv_from(&mut buf) {
+            // RTPS header: magic, version, vendor id, then the GUID prefix
+            announced = len > 20 && &buf[..4] == b"RTPS" && &buf[8..20] == prefix;
+        }
+    }
+    assert!(announced, "no unicast SPDP received by the initial peer");
+}
@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Network interface selection integration tests
+//!
+//! Participants restricted with `with_interface` / `deny_interfaces` still
+//! discover each other, and a selection matching no interface fails to build.
+
+use hdds::{Participant, QoS, TransportMode};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const DOMAIN: u32 = 94;
+const TOPIC: &str = "iface/reading";
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Reading {
+    value: u32,
+}
+
+#[test]
+fn test_filtered_participants_exchange_data() {
+    let publisher = Participant::builder("iface_pub")
+        .domain_id(DOMAIN)
+        .with_transport(TransportMode::UdpMulticast)
+        .with_interface("*")
+        .deny_interfaces(&["docker*", "hdds-test-vpn*"])
+        .build()
+        .expect("publisher");
+    let subscriber = Participant::builder("iface_sub")
+        .domain_id(DOMAIN)
+        .with_transport(TransportMode::UdpMulticast)
+        .allow_interfaces(&["*"])
+        .build()
+        .expect("subscriber");
+
+    let writer = publisher
+        .topic::<Reading>(TOPIC)
+        .expect("topic")
+        .writer()
+        .qos(QoS::reliable())
+        .build()
+        .expect("writer");
+    let reader = subscriber
+        .topic::<Reading>(TOPIC)
+        .expect("topic")
+        .reader()
+        .qos(QoS::reliable())
+        .build()
+        .expect("reader");
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+    let mut received = None;
+    while received.is_none() && Instant::now() < deadline {
+        writer.write(&Reading { value: 4 }).expect("write");
+        thread::sleep(Duration::from_millis(100));
+        received = reader.take().expect("take");
+    }
+    assert_eq!(received, Some(Reading { value: 4 }));
+}
+
+#[test]
+fn test_unmatched_interface_fails_to_build() {
+    let result = Participant::builder("iface_none")
+        .domain_id(DOMAIN)
+        .with_transport(TransportMode::UdpMulticast)
+        .with_interface("hdds-no-such-iface0")
+        .build();
+    assert!(result.is_err());
+}
@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! IPv6 transport integration tests
+//!
+//! Two participants on `IpMode::V6` discover each other over the
+//! ff02::ffff:239.255.0.1 group and exchange data over UDPv6 locators.
+
+use hdds::{IpMode, Participant, QoS, TransportMode};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const DOMAIN: u32 = 92;
+const TOPIC: &str = "ipv6/reading";
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Reading {
+    value: u32,
+}
+
+fn participant(name: &str) -> Arc<Participant> {
+    Participant::builder(name)
+        .domain_id(DOMAIN)
+        .with_transport(TransportMode::UdpMulticast)
+        .with_ip_mode(IpMode::V6)
+        .build()
+        .expect("participant")
+}
+
+#[test]
+fn test_ipv6_participants_exchange_data() {
+    let publisher = participant("ipv6_pub");
+    let subscriber = participant("ipv6_sub");
+
+    let writer = publisher
+        .topic::<Reading>(TOPIC)
+        .expect("topic")
+        .writer()
+        .qos(QoS::reliable())
+        .build()
+        .expect("writer");
+    let reader = subscriber
+        .topic::<Reading>(TOPIC)
+        .expect("topic")
+        .reader()
+        .qos(QoS::reliable())
+        .build()
+        .expect("reader");
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+    let mut received = None;
+    while received.is_none() && Instant::now() < deadline {
+        writer.write(&Reading { value: 6 }).expect("write");
+        thread::sleep(Duration::from_millis(100));
+        received = reader.take().expect("take");
+    }
+    assert_eq!(received, Some(Reading { value: 6 }));
+
+    let fsm = subscriber.discovery().expect("discovery");
+    let remote = fsm
+        .get_participants()
+        .into_iter()
+        .find(|p| p.guid == publisher.guid())
+        .expect("publisher discovered");
+    assert!(!remote.endpoints.is_empty());
+    assert!(remote.endpoints.iter().all(|locator| locator.is_ipv6()));
+}
@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Repair of lost instance lifecycle changes.
+//!
+//! A dispose is kept in the writer's history like any sample: a reliable
+//! reader that NACKs it gets the DATA again, with its key hash and status
+//! info, instead of a GAP.
+
+mod common;
+
+use common::wait_for;
+use hdds::protocol::builder::{
+    build_acknack_packet, extract_inline_qos, extract_sequence_number, extract_writer_guid,
+    parse_key_hash, parse_status_info,
+};
+use hdds::protocol::STATUS_INFO_DISPOSED;
+use hdds::transport::LoopbackBus;
+use hdds::{Participant, QoS, TransportMode};
+use std::time::Duration;
+
+const DOMAIN: u32 = 93;
+const TOPIC: &str = "lifecycle/track";
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Track {
+    #[key]
+    id: u32,
+    range: i32,
+}
+
+#[test]
+fn test_lost_dispose_is_repaired() {
+    let alice = Participant::builder("lifecycle_alice")
+        .domain_id(DOMAIN)
+        .with_transport(TransportMode::LoopbackBus)
+        .build()
+        .expect("participant");
+    let alice_port = alice
+        .transport()
+        .expect("transport")
+        .loopback_port()
+        .expect("loopback port")
+        .user_addr();
+    // Stands in for a remote reader: sees what alice multicasts
+    let sniffer = LoopbackBus::global()
+        .attach(DOMAIN, None)
+        .expect("sniffer port");
+    let traffic = sniffer.receiver();
+
+    let writer = alice
+        .topic::<Track>(TOPIC)
+        .expect("topic")
+        .writer()
+        .qos(QoS::reliable().keep_last(8))
+        .build()
+        .expect("writer");
+    let track = Track { id: 4, range: 10 };
+    writer.write(&track).expect("write");
+    writer.dispose(&track).expect("dispose");
+    let handle = writer.lookup_instance(&track).expect("registered");
+
+    // The dispose as first sent (seq 2), which the reader then "loses"
+    let disposed = wait_for(|| {
+        traffic
+            .recv_timeout(Duration::from_millis(50))
+            .ok()
+            .filter(|datagram| extract_sequence_number(&datagram.data) == Some(2))
+    });
+    let writer_guid = extract_writer_guid(&disposed.data).expect("writer GUID");
+    let mut alice_prefix = [0u8; 12];
+    alice_prefix.copy_from_slice(&writer_guid[..12]);
+    let mut writer_entity = [0u8; 4];
+    writer_entity.copy_from_slice(&writer_guid[12..]);
+    while traffic.try_recv().is_ok() {}
+
+    let acknack = build_acknack_packet(
+        [0x5a; 12],
+        alice_prefix,
+        [0x00, 0x00, 0x01, 0x07],
+        writer_entity,
+        2,
+        &[2],
+        1,
+    );
+    let repaired = wait_for(|| {
+        sniffer.unicast(&acknack, sniffer.user_addr(), alice_port);
+        traffic
+            .recv_timeout(Duration::from_millis(50))
+            .ok()
+            .filter(|datagram| extract_sequence_number(&datagram.data) == Some(2))
+    });
+
+    let qos = extract_inline_qos(&repaired.data).expect("inline qos");
+    assert_eq!(parse_status_info(qos), Some(STATUS_INFO_DISPOSED));
+    assert_eq!(parse_key_hash(qos), Some(handle.0));
+}
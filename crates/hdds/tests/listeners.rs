@@ -0,0 +1,201 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Listener callback integration tests
+//!
+//! Two UDP participants: the participant listener reports the remote one,
+//! readers report matched writers, a BestEffort writer facing a Reliable
+//! reader is reported as incompatible on both sides, and a writer of another
+//! type on the same topic is reported as inconsistent.
+
+use hdds::core::discovery::GUID;
+use hdds::dds::{
+    DataReaderListener, DataWriterListener, InconsistentTopicStatus, ParticipantListener,
+    QosPolicyId, RequestedIncompatibleQosStatus, SubscriptionMatchedStatus,
+};
+use hdds::{Participant, QoS, TransportMode};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const DOMAIN: u32 = 95;
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Reading {
+    value: u32,
+}
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Alarm {
+    code: u32,
+}
+
+#[derive(Default)]
+struct Events {
+    participants: Mutex<Vec<GUID>>,
+    matched: Mutex<Vec<SubscriptionMatchedStatus>>,
+    requested: Mutex<Vec<RequestedIncompatibleQosStatus>>,
+    offered: Mutex<Vec<u32>>,
+    inconsistent: Mutex<Vec<InconsistentTopicStatus>>,
+}
+
+impl ParticipantListener for Events {
+    fn on_participant_discovered(&self, participant: GUID) {
+        self.participants.lock().expect("lock").push(participant);
+    }
+}
+
+impl DataReaderListener<Reading> for Events {
+    fn on_subscription_matched(&self, status: SubscriptionMatchedStatus) {
+        self.matched.lock().expect("lock").push(status);
+    }
+
+    fn on_requested_incompatible_qos(&self, status: RequestedIncompatibleQosStatus) {
+        self.requested.lock().expect("lock").push(status);
+    }
+
+    fn on_inconsistent_topic(&self, status: InconsistentTopicStatus) {
+        self.inconsistent.lock().expect("lock").push(status);
+    }
+}
+
+impl DataWriterListener<Reading> for Events {
+    fn on_offered_incompatible_qos(&self, policy_id: u32, _policy_name: &str) {
+        self.offered.lock().expect("lock").push(policy_id);
+    }
+}
+
+fn wait_for(condition: impl Fn() -> bool) -> bool {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while Instant::now() < deadline {
+        if condition() {
+            return true;
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+    condition()
+}
+
+fn participant(name: &str, events: Option<Arc<Events>>) -> Arc<Participant> {
+    let mut builder = Participant::builder(name)
+        .domain_id(DOMAIN)
+        .with_transport(TransportMode::UdpMulticast);
+    if let Some(events) = events {
+        builder = builder.with_listener(events);
+    }
+    builder.build().expect("participant")
+}
+
+#[test]
+fn test_discovery_and_qos_callbacks() {
+    let events = Arc::new(Events::default());
+    let local = participant("listeners_local", Some(Arc::clone(&events)));
+    let remote = participant("listeners_remote", None);
+
+    let remote_guid = remote.guid();
+    assert!(
+        wait_for(|| events
+            .participants
+            .lock()
+            .expect("lock")
+            .iter()
+            .any(|guid| guid.prefix == remote_guid.prefix)),
+        "remote participant not reported"
+    );
+
+    // Compatible pair: the reader reports its matched writer
+    let _writer = remote
+        .topic::<Reading>("listeners/matched")
+        .expect("topic")
+        .writer()
+        .qos(QoS::reliable())
+        .build()
+        .expect("writer");
+    let reader = local
+        .topic::<Reading>("listeners/matched")
+        .expect("topic")
+        .reader()
+        .qos(QoS::reliable())
+        .with_listener(Arc::clone(&events) as Arc<dyn DataReaderListener<Reading>>)
+        .build()
+        .expect("reader");
+    assert!(
+        wait_for(|| events
+            .matched
+            .lock()
+            .expect("lock")
+            .iter()
+            .any(|status| status.current_count == 1)),
+        "subscription match not reported"
+    );
+    assert_eq!(reader.subscription_matched_status().current_count, 1);
+
+    // BestEffort writer, Reliable reader: RELIABILITY is incompatible
+    let _strict = local
+        .topic::<Reading>("listeners/incompatible")
+        .expect("topic")
+        .reader()
+        .qos(QoS::reliable())
+        .with_listener(Arc::clone(&events) as Arc<dyn DataReaderListener<Reading>>)
+        .build()
+        .expect("reader");
+    let lax = remote
+        .topic::<Reading>("listeners/incompatible")
+        .expect("topic")
+        .writer()
+        .qos(QoS::best_effort())
+        .build()
+        .expect("writer");
+    assert!(
+        wait_for(|| !events.requested.lock().expect("lock").is_empty()),
+        "requested incompatible QoS not reported"
+    );
+    let status = events.requested.lock().expect("lock")[0].clone();
+    assert_eq!(status.total_count, 1);
+    assert_eq!(status.last_policy_id, QosPolicyId::Reliability.id());
+    assert!(wait_for(|| lax
+        .offered_incompatible_qos_status()
+        .total_count
+        == 1));
+    assert_eq!(lax.matched_readers_count(), 0);
+
+    // Writer-side callback, for a writer created after the reader
+    let _other = remote
+        .topic::<Reading>("listeners/incompatible")
+        .expect("topic")
+        .writer()
+        .qos(QoS::best_effort())
+        .with_listener(Arc::clone(&events) as Arc<dyn DataWriterListener<Reading>>)
+        .build()
+        .expect("writer");
+    assert!(
+        wait_for(|| events.offered.lock().expect("lock").contains(&11)),
+        "offered incompatible QoS not reported"
+    );
+
+    // Same topic, another type: both sides report an inconsistent topic
+    let _typed = local
+        .topic::<Reading>("listeners/inconsistent")
+        .expect("topic")
+        .reader()
+        .with_listener(Arc::clone(&events) as Arc<dyn DataReaderListener<Reading>>)
+        .build()
+        .expect("reader");
+    let alarms = remote
+        .topic::<Alarm>("listeners/inconsistent")
+        .expect("topic")
+        .writer()
+        .build()
+        .expect("writer");
+    assert!(
+        wait_for(|| !events.inconsistent.lock().expect("lock").is_empty()),
+        "inconsistent topic not reported"
+    );
+    let status = events.inconsistent.lock().expect("lock")[0].clone();
+    assert_eq!(status.total_count, 1);
+    assert!(status.last_type_name.contains("Alarm"));
+    assert!(wait_for(
+        || alarms.inconsistent_topic_status().total_count == 1
+    ));
+    assert_eq!(alarms.offered_incompatible_qos_status().total_count, 0);
+}
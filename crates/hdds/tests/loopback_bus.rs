@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! TransportMode::LoopbackBus integration tests
+//!
+//! Participants of this process run the full RTPS stack (SPDP, SEDP, user
+//! data) over the in-memory bus, without opening a socket.
+
+mod common;
+
+use common::wait_for;
+use hdds::transport::LoopbackBus;
+use hdds::{Participant, QoS, TransportMode};
+use std::sync::Arc;
+
+const DOMAIN: u32 = 91;
+const TOPIC: &str = "loopback/reading";
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Reading {
+    id: u32,
+    value: f64,
+}
+
+fn participant(name: &str) -> Arc<Participant> {
+    Participant::builder(name)
+        .domain_id(DOMAIN)
+        .with_transport(TransportMode::LoopbackBus)
+        .build()
+        .expect("participant")
+}
+
+#[test]
+fn test_loopback_bus_discovery_and_data() {
+    let alice = participant("loopback_alice");
+    let bob = participant("loopback_bob");
+    assert_ne!(alice.guid(), bob.guid());
+    let transport = alice.transport().expect("transport");
+    assert!(transport.socket().is_none(), "no socket on the bus");
+    assert!(transport.loopback_port().is_some());
+
+    // SPDP over the bus
+    let bob_fsm = bob.discovery().expect("discovery");
+    wait_for(|| {
+        bob_fsm
+            .get_participants()
+            .into_iter()
+            .find(|p| p.guid == alice.guid())
+    });
+
+    let writer = alice
+        .topic::<Reading>(TOPIC)
+        .expect("topic")
+        .writer()
+        .qos(QoS::reliable())
+        .build()
+        .expect("writer");
+    let reader = bob
+        .topic::<Reading>(TOPIC)
+        .expect("topic")
+        .reader()
+        .qos(QoS::reliable())
+        .build()
+        .expect("reader");
+
+    // SEDP over the bus
+    wait_for(|| bob_fsm.find_writers_for_topic(TOPIC).into_iter().next());
+
+    let sample = Reading { id: 7, value: 21.5 };
+    let received = wait_for(|| {
+        writer.write(&sample).expect("write");
+        reader.take().expect("take")
+    });
+    assert_eq!(received, sample);
+}
+
+#[test]
+fn test_loopback_bus_requested_participant_id() {
+    let pinned = Participant::builder("loopback_pinned")
+        .domain_id(DOMAIN + 1)
+        .participant_id(Some(5))
+        .with_transport(TransportMode::LoopbackBus)
+        .build()
+        .expect("participant");
+    assert_eq!(pinned.participant_id(), 5);
+
+    let clash = Participant::builder("loopback_clash")
+        .domain_id(DOMAIN + 1)
+        .participant_id(Some(5))
+        .with_transport(TransportMode::LoopbackBus)
+        .build();
+    assert!(clash.is_err(), "participant ID already on the bus");
+
+    // Dropping the participant releases its ID
+    drop(pinned);
+    let port = LoopbackBus::global()
+        .attach(DOMAIN + 1, Some(5))
+        .expect("participant ID released");
+    assert_eq!(port.participant_id(), 5);
+}
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Frame {
+    id: u32,
+    pixels: Vec<u8>,
+}
+
+#[test]
+fn test_loopback_bus_fragmented_sample_keeps_trace_id() {
+    let domain = DOMAIN + 2;
+    let build = |name: &str| {
+        Participant::builder(name)
+            .domain_id(domain)
+            .with_transport(TransportMode::LoopbackBus)
+            .build()
+            .expect("participant")
+    };
+    let alice = build("loopback_frag_alice");
+    let bob = build("loopback_frag_bob");
+
+    let writer = alice
+        .topic::<Frame>("loopback/frame")
+        .expect("topic")
+        .writer()
+        .qos(QoS::reliable())
+        .build()
+        .expect("writer");
+    let reader = bob
+        .topic::<Frame>("loopback/frame")
+        .expect("topic")
+        .reader()
+        .qos(QoS::reliable())
+        .build()
+        .expect("reader");
+    let bob_fsm = bob.discovery().expect("discovery");
+    wait_for(|| {
+        bob_fsm
+            .find_writers_for_topic("loopback/frame")
+            .into_iter()
+            .next()
+    });
+
+    // Well past the 8 KB DATA_FRAG threshold
+    let frame = Frame {
+        id: 3,
+        pixels: (0..20_000).map(|i| (i % 251) as u8).collect(),
+    };
+    let (received, info) = wait_for(|| {
+        writer
+            .write_with_trace_id(&frame, 0x00c0_ffee)
+            .expect("write");
+        reader.take_with_info().expect("take")
+    });
+    assert_eq!(received, frame);
+    assert_eq!(info.trace_id, Some(0x00c0_ffee));
+}
@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! USER_DATA, TOPIC_DATA and GROUP_DATA propagation through SPDP/SEDP.
+
+mod common;
+
+use common::wait_for;
+use hdds::dds::UserData;
+use hdds::{Participant, QoS, TransportMode};
+use std::sync::Arc;
+
+const DOMAIN: u32 = 97;
+const TOPIC: &str = "metadata/reading";
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Reading {
+    value: u32,
+}
+
+fn participant(name: &str, user_data: &[u8]) -> Arc<Participant> {
+    Participant::builder(name)
+        .domain_id(DOMAIN)
+        .with_transport(TransportMode::UdpMulticast)
+        .user_data(UserData::new(user_data.to_vec()))
+        .build()
+        .expect("participant")
+}
+
+#[test]
+fn test_metadata_visible_to_remote_participant() {
+    let alice = participant("metadata_alice", b"token=alice");
+    let bob = participant("metadata_bob", b"token=bob");
+    assert_eq!(alice.user_data().value, b"token=alice");
+
+    let publisher = alice
+        .create_publisher(QoS::default().group_data_bytes(b"pub-group"))
+        .expect("publisher");
+    let _writer = alice
+        .topic::<Reading>(TOPIC)
+        .expect("topic")
+        .writer()
+        .qos(
+            QoS::reliable()
+                .user_data_bytes(b"writer-meta")
+                .topic_data_bytes(b"schema=v2"),
+        )
+        .publisher(&publisher)
+        .build()
+        .expect("writer");
+
+    let subscriber = bob
+        .create_subscriber(QoS::default().group_data_bytes(b"sub-group"))
+        .expect("subscriber");
+    let _reader = bob
+        .topic::<Reading>(TOPIC)
+        .expect("topic")
+        .reader()
+        .qos(QoS::reliable())
+        .subscriber(&subscriber)
+        .build()
+        .expect("reader");
+
+    // Participant USER_DATA via SPDP
+    let bob_fsm = bob.discovery().expect("discovery");
+    let remote = wait_for(|| {
+        bob_fsm
+            .get_participants()
+            .into_iter()
+            .find(|p| p.guid == alice.guid())
+    });
+    assert_eq!(remote.user_data.value, b"token=alice");
+
+    // Endpoint metadata via SEDP, GROUP_DATA inherited from the Publisher
+    let writer = wait_for(|| bob_fsm.find_writers_for_topic(TOPIC).into_iter().next());
+    assert_eq!(writer.qos.user_data.value, b"writer-meta");
+    assert_eq!(writer.qos.topic_data.value, b"schema=v2");
+    assert_eq!(writer.qos.group_data.value, b"pub-group");
+
+    let alice_fsm = alice.discovery().expect("discovery");
+    let reader = wait_for(|| alice_fsm.find_readers_for_topic(TOPIC).into_iter().next());
+    assert!(reader.qos.user_data.is_empty());
+    assert_eq!(reader.qos.group_data.value, b"sub-group");
+}
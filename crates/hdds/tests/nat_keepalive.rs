@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! NAT keepalive integration tests
+//!
+//! A peer "behind a NAT" announces a private address it cannot be reached
+//! at and sends from a public one (loopback aliases here). A participant
+//! with `nat_keepalive` reaches it at the public address, keeps the path
+//! open with keepalives and follows the peer when the NAT rebinds it.
+
+#![cfg(target_os = "linux")]
+
+use hdds::core::discovery::multicast::build_spdp_rtps_packet;
+use hdds::core::discovery::GUID;
+use hdds::protocol::discovery::SpdpData;
+use hdds::{Participant, TransportMode};
+use std::net::{SocketAddr, UdpSocket};
+use std::time::{Duration, Instant};
+
+const DOMAIN: u32 = 85;
+const PEER_PREFIX: [u8; 12] = [0x4e, 0x41, 0x54, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+
+/// SPDP of the peer announcing only its private address.
+fn spdp_announcement(port: u16, seq: u64) -> Vec<u8> {
+    let private = |port| SocketAddr::from(([10, 99, 0, 1], port));
+    let data = SpdpData {
+        participant_guid: GUID::new(PEER_PREFIX, [0, 0, 1, 0xc1]),
+        lease_duration_ms: 100_000,
+        domain_id: DOMAIN,
+        metatraffic_unicast_locators: vec![private(port)],
+        default_unicast_locators: vec![private(port)],
+        default_multicast_locators: vec![],
+        metatraffic_multicast_locators: vec![],
+        identity_token: None,
+        user_data: Default::default(),
+    };
+    build_spdp_rtps_packet(&data, seq, None).expect("spdp")
+}
+
+/// Wait up to 5 s for a keepalive from `prefix` on `socket`.
+fn keepalive_received(socket: &UdpSocket, prefix: &[u8]) -> bool {
+    socket
+        .set_read_timeout(Some(Duration::from_millis(100)))
+        .expect("read timeout");
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let mut buf = [0u8; 2048];
+    while Instant::now() < deadline {
+        if let Ok((len, _)) = socket.recv_from(&mut buf) {
+            // RTPS header + INFO_DST to the peer, nothing else
+            if len == 36 && &buf[8..20] == prefix && buf[20] == 0x0e && buf[24..36] == PEER_PREFIX {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+#[test]
+fn test_keepalive_follows_peer_behind_nat() {
+    let participant = Participant::builder("nat_keepalive")
+        .domain_id(DOMAIN)
+        .with_transport(TransportMode::UdpMulticast)
+        .nat_keepalive(Duration::from_millis(50))
+        .build()
+        .expect("participant");
+    let prefix = participant.guid().prefix;
+    let metatraffic = participant
+        .port_mapping()
+        .expect("port mapping")
+        .metatraffic_unicast;
+    let target = SocketAddr::from(([127, 0, 0, 1], metatraffic));
+
+    // Public address given by the NAT, on the announced port
+    let public = UdpSocket::bind("127.0.0.1:0").expect("peer socket");
+    let port = public.local_addr().expect("addr").port();
+    public
+        .send_to(&spdp_announcement(port, 1), target)
+        .expect("send spdp");
+    assert!(
+        keepalive_received(&public, &prefix),
+        "no keepalive at the reflexive address"
+    );
+
+    // The NAT rebinds the peer to another public address
+    let rebound = UdpSocket::bind(("127.0.0.2", port)).expect("rebound socket");
+    rebound
+        .send_to(&spdp_announcement(port, 2), target)
+        .expect("send spdp");
+    assert!(
+        keepalive_received(&rebound, &prefix),
+        "no keepalive at the new reflexive address"
+    );
+    let fsm = participant.discovery().expect("discovery");
+    let endpoints = fsm
+        .get_participants()
+        .into_iter()
+        .find(|info| info.guid.prefix == PEER_PREFIX)
+        .expect("peer known")
+        .endpoints;
+    assert_eq!(endpoints, [SocketAddr::from(([127, 0, 0, 2], port))]);
+}
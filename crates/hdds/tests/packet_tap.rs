@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! ParticipantBuilder::with_packet_tap over the loopback bus.
+
+mod common;
+
+use common::wait_for;
+use hdds::{PacketDirection, Participant, TransportMode};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+const DOMAIN: u32 = 89;
+
+#[derive(Default)]
+struct Counts {
+    sent: AtomicUsize,
+    received: AtomicUsize,
+    not_rtps: AtomicUsize,
+}
+
+fn tapped_participant(name: &str, counts: &Arc<Counts>) -> Arc<Participant> {
+    let counts = counts.clone();
+    Participant::builder(name)
+        .domain_id(DOMAIN)
+        .with_transport(TransportMode::LoopbackBus)
+        .with_packet_tap(move |packet| {
+            if !packet.data.starts_with(b"RTPS") || packet.source.is_none() {
+                counts.not_rtps.fetch_add(1, Ordering::Relaxed);
+            }
+            match packet.direction {
+                PacketDirection::Sent => counts.sent.fetch_add(1, Ordering::Relaxed),
+                PacketDirection::Received => counts.received.fetch_add(1, Ordering::Relaxed),
+            };
+        })
+        .build()
+        .expect("participant")
+}
+
+#[test]
+fn test_tap_sees_sent_and_received_rtps() {
+    let alice_counts = Arc::new(Counts::default());
+    let bob_counts = Arc::new(Counts::default());
+    let alice = tapped_participant("packet_tap_alice", &alice_counts);
+    let bob = tapped_participant("packet_tap_bob", &bob_counts);
+
+    // SPDP over the bus goes through both taps
+    let bob_fsm = bob.discovery().expect("discovery");
+    wait_for(|| {
+        bob_fsm
+            .get_participants()
+            .into_iter()
+            .find(|p| p.guid == alice.guid())
+    });
+    wait_for(|| {
+        [&alice_counts, &bob_counts]
+            .iter()
+            .all(|counts| {
+                counts.sent.load(Ordering::Relaxed) > 0
+                    && counts.received.load(Ordering::Relaxed) > 0
+            })
+            .then_some(())
+    });
+    assert_eq!(alice_counts.not_rtps.load(Ordering::Relaxed), 0);
+    assert_eq!(bob_counts.not_rtps.load(Ordering::Relaxed), 0);
+}
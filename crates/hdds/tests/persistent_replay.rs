@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! PERSISTENT readers receiving samples both from their writer and from a
+//! relaying persistence service, over the loopback bus.
+
+use hdds::{Participant, QoS, RawSample, TransportMode};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const DOMAIN: u32 = 101;
+const TOPIC: &str = "persistent_replay/reading";
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Reading {
+    value: u32,
+}
+
+fn participant(name: &str) -> Arc<Participant> {
+    Participant::builder(name)
+        .domain_id(DOMAIN)
+        .with_transport(TransportMode::LoopbackBus)
+        .build()
+        .expect("participant")
+}
+
+/// Poll `take` until `count` items arrived, for up to 10 s.
+fn take_at_least<T>(count: usize, mut take: impl FnMut() -> Vec<T>) -> Vec<T> {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    let mut taken = Vec::new();
+    while taken.len() < count {
+        assert!(Instant::now() < deadline, "timed out with {}", taken.len());
+        taken.extend(take());
+        thread::sleep(Duration::from_millis(20));
+    }
+    taken
+}
+
+#[test]
+fn test_persistent_reader_drops_relayed_copies() {
+    let qos = QoS::reliable().persistent().keep_all();
+
+    let alice = participant("persistent_replay_alice");
+    let writer = alice
+        .topic::<Reading>(TOPIC)
+        .expect("topic")
+        .writer()
+        .qos(qos.clone())
+        .build()
+        .expect("writer");
+
+    // The service stores what it receives, with the original writer
+    let service = participant("persistent_replay_service");
+    let store = service
+        .create_raw_reader_with_type(TOPIC, "Reading", Some(qos.clone()), None)
+        .expect("raw reader");
+    let relay = service
+        .create_raw_writer_with_type(TOPIC, "Reading", Some(qos.clone()), None)
+        .expect("raw writer");
+
+    thread::sleep(Duration::from_millis(300));
+    for value in 1..=3 {
+        writer
+            .write_with_timestamp(&Reading { value }, u64::from(value) * 1_000)
+            .expect("write");
+    }
+    let stored: Vec<RawSample> = take_at_least(3, || store.try_take_raw().expect("take"));
+    for (sample, timestamp) in stored.iter().zip([1_000, 2_000, 3_000]) {
+        let original = sample.original_writer.expect("original writer");
+        assert_eq!(Some(original.writer_guid), Some(sample.writer_guid));
+        assert_eq!(sample.sequence_number, Some(original.sequence));
+        assert_eq!(
+            sample.source_timestamp,
+            std::time::UNIX_EPOCH + Duration::from_nanos(timestamp)
+        );
+    }
+
+    // A late joiner takes alice's history
+    let carol = participant("persistent_replay_carol");
+    let reader = carol
+        .topic::<Reading>(TOPIC)
+        .expect("topic")
+        .reader()
+        .qos(qos)
+        .build()
+        .expect("reader");
+    assert_eq!(
+        take_at_least(3, || reader.take_batch(16).expect("take")),
+        [1, 2, 3].map(|value| Reading { value })
+    );
+
+    // Then the service replays everything it stored, and publishes the
+    // last payload once more as a sample of its own
+    for sample in &stored {
+        let timestamp = sample
+            .source_timestamp
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("timestamp")
+            .as_nanos() as u64;
+        relay
+            .write_relayed(
+                &sample.payload,
+                timestamp,
+                sample.original_writer.expect("original writer"),
+            )
+            .expect("relay");
+    }
+    relay.write_raw(&stored[2].payload).expect("write");
+
+    // Only the new sample is delivered, and after the copies
+    assert_eq!(
+        take_at_least(1, || reader.take_batch(16).expect("take")),
+        [Reading { value: 3 }]
+    );
+}
@@ -320,15 +320,143 @@ fn test_destination_order_kind_ordering() {
 }
 
 // ============================================================================
-// Behavior tests: Deferred
+// Behavior tests
 // ============================================================================
-//
-// TODO: DESTINATION_ORDER behavior tests are deferred.
-// DESTINATION_ORDER sample ordering (BY_RECEPTION vs BY_SOURCE timestamp)
-// is not yet wired into the reader cache delivery path.
-// Once integrated, add tests for:
-// 1. Two writers publishing with different source timestamps
-// 2. Reader with BY_SOURCE_TIMESTAMP receiving samples in source order
-// 3. Reader with BY_RECEPTION_TIMESTAMP receiving in arrival order
-//
-// For now, these tests validate the QoS API surface only.
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Fused {
+    #[key]
+    sensor: u32,
+    value: f64,
+}
+
+fn intra_participant(name: &str) -> std::sync::Arc<Participant> {
+    Participant::builder(name)
+        .with_transport(hdds::TransportMode::IntraProcess)
+        .build()
+        .expect("participant")
+}
+
+#[test]
+fn test_by_source_timestamp_orders_redundant_writers() {
+    let participant = intra_participant("destination_order_by_source");
+    let topic = participant
+        .topic::<Fused>("destination_order/by_source")
+        .expect("topic");
+    let qos = QoS::reliable().keep_last(10).destination_order_by_source();
+    let reader = topic.reader().qos(qos.clone()).build().expect("reader");
+    let primary = topic.writer().qos(qos.clone()).build().expect("writer");
+    let backup = topic.writer().qos(qos).build().expect("writer");
+
+    // The backup publisher lags behind: its samples arrive after newer ones
+    primary
+        .write_with_timestamp(
+            &Fused {
+                sensor: 1,
+                value: 1.0,
+            },
+            1_000,
+        )
+        .expect("write");
+    primary
+        .write_with_timestamp(
+            &Fused {
+                sensor: 1,
+                value: 3.0,
+            },
+            3_000,
+        )
+        .expect("write");
+    backup
+        .write_with_timestamp(
+            &Fused {
+                sensor: 1,
+                value: 2.0,
+            },
+            2_000,
+        )
+        .expect("write");
+    backup
+        .write_with_timestamp(
+            &Fused {
+                sensor: 2,
+                value: 2.5,
+            },
+            2_500,
+        )
+        .expect("write");
+    backup
+        .write_with_timestamp(
+            &Fused {
+                sensor: 1,
+                value: 4.0,
+            },
+            4_000,
+        )
+        .expect("write");
+
+    let samples = reader.take_n(10).expect("take");
+    let delivered: Vec<(u32, f64, Option<u64>)> = samples
+        .iter()
+        .map(|(s, info)| (s.sensor, s.value, info.source_timestamp_ns))
+        .collect();
+    assert_eq!(
+        delivered,
+        [
+            (1, 1.0, Some(1_000)),
+            (1, 3.0, Some(3_000)),
+            (2, 2.5, Some(2_500)),
+            (1, 4.0, Some(4_000)),
+        ]
+    );
+}
+
+#[test]
+fn test_by_reception_timestamp_keeps_arrival_order() {
+    let participant = intra_participant("destination_order_by_reception");
+    let topic = participant
+        .topic::<Fused>("destination_order/by_reception")
+        .expect("topic");
+    let reader = topic
+        .reader()
+        .qos(QoS::reliable().keep_last(10))
+        .build()
+        .expect("reader");
+    let writer = topic
+        .writer()
+        .qos(QoS::reliable().keep_last(10))
+        .build()
+        .expect("writer");
+
+    writer
+        .write_with_timestamp(
+            &Fused {
+                sensor: 1,
+                value: 3.0,
+            },
+            3_000,
+        )
+        .expect("write");
+    writer
+        .write_with_timestamp(
+            &Fused {
+                sensor: 1,
+                value: 2.0,
+            },
+            2_000,
+        )
+        .expect("write");
+    writer
+        .write(&Fused {
+            sensor: 1,
+            value: 5.0,
+        })
+        .expect("write");
+
+    let samples = reader.take_n(10).expect("take");
+    let values: Vec<f64> = samples.iter().map(|(s, _)| s.value).collect();
+    assert_eq!(values, [3.0, 2.0, 5.0]);
+    assert_eq!(samples[1].1.source_timestamp_ns, Some(2_000));
+    // write() stamps the current time
+    assert!(samples[2].1.source_timestamp_ns > Some(1_000_000_000_000_000_000));
+}
@@ -263,7 +263,161 @@ fn test_liveliness_behavior_manual_participant_data_flow() {
     );
 }
 
-// Note: Testing lost_liveliness detection requires listener callbacks
-// or waiting for the lease to expire after dropping the writer.
-// Full lost_liveliness behavior tests depend on LivelinessMonitor
-// integration with DataReader status conditions.
+#[test]
+fn test_liveliness_manual_topic_lease_expiry() {
+    use hdds::generated::temperature::Temperature;
+    use std::thread;
+
+    let participant = Participant::builder("liveliness_lease_test")
+        .with_transport(hdds::TransportMode::IntraProcess)
+        .build()
+        .expect("participant");
+
+    let topic = participant
+        .topic::<Temperature>("LivelinessLeaseTopic")
+        .expect("topic");
+    let writer = topic
+        .writer()
+        .qos(QoS::reliable().liveliness_manual_topic_millis(100))
+        .build()
+        .expect("writer");
+    let reader = topic
+        .reader()
+        .qos(QoS::reliable().liveliness_manual_topic_millis(100))
+        .build()
+        .expect("reader");
+
+    writer.assert_liveliness().expect("assert_liveliness");
+    let status = reader.liveliness_changed_status();
+    assert_eq!(status.alive_count, 1);
+    assert_eq!(status.alive_count_change, 1);
+    assert_eq!(status.not_alive_count, 0);
+
+    // No data and no assertion for three leases: the writer is not alive
+    thread::sleep(Duration::from_millis(300));
+    let status = reader.liveliness_changed_status();
+    assert_eq!(status.alive_count, 0);
+    assert_eq!(status.not_alive_count, 1);
+    assert_eq!(status.not_alive_count_change, 1);
+
+    writer.assert_liveliness().expect("assert_liveliness");
+    let status = reader.liveliness_changed_status();
+    assert_eq!(status.alive_count, 1);
+    assert_eq!(status.not_alive_count, 0);
+
+    // A write asserts liveliness too
+    thread::sleep(Duration::from_millis(300));
+    writer
+        .write(&Temperature {
+            value: 21.0,
+            timestamp: 1,
+        })
+        .expect("write");
+    let status = reader.liveliness_changed_status();
+    assert_eq!(status.alive_count, 1);
+    assert_eq!(status.not_alive_count, 0);
+}
+
+#[test]
+fn test_liveliness_automatic_writer_stays_alive() {
+    use hdds::generated::temperature::Temperature;
+    use std::thread;
+
+    let participant = Participant::builder("liveliness_automatic_lease_test")
+        .with_transport(hdds::TransportMode::IntraProcess)
+        .build()
+        .expect("participant");
+
+    let topic = participant
+        .topic::<Temperature>("LivelinessAutomaticLeaseTopic")
+        .expect("topic");
+    let _writer = topic
+        .writer()
+        .qos(QoS::reliable().liveliness_automatic_millis(100))
+        .build()
+        .expect("writer");
+    let reader = topic
+        .reader()
+        .qos(QoS::reliable().liveliness_automatic_millis(100))
+        .build()
+        .expect("reader");
+
+    // The writer never writes, its liveliness thread asserts for it
+    thread::sleep(Duration::from_millis(400));
+    let status = reader.liveliness_changed_status();
+    assert_eq!(status.alive_count, 1);
+    assert_eq!(status.not_alive_count, 0);
+}
+
+#[test]
+fn test_liveliness_dropped_writer_leaves_counts() {
+    use hdds::generated::temperature::Temperature;
+
+    let participant = Participant::builder("liveliness_drop_test")
+        .with_transport(hdds::TransportMode::IntraProcess)
+        .build()
+        .expect("participant");
+
+    let topic = participant
+        .topic::<Temperature>("LivelinessDropTopic")
+        .expect("topic");
+    let writer = topic
+        .writer()
+        .qos(QoS::reliable().liveliness_manual_topic_secs(5))
+        .build()
+        .expect("writer");
+    let reader = topic
+        .reader()
+        .qos(QoS::reliable().liveliness_manual_topic_secs(5))
+        .build()
+        .expect("reader");
+
+    assert_eq!(reader.liveliness_changed_status().alive_count, 1);
+    drop(writer);
+    let status = reader.liveliness_changed_status();
+    assert_eq!(status.alive_count, 0);
+    assert_eq!(status.alive_count_change, -1);
+    assert_eq!(status.not_alive_count, 0);
+}
+
+#[test]
+fn test_liveliness_lease_on_virtual_clock() {
+    use hdds::generated::temperature::Temperature;
+    use hdds::VirtualClock;
+    use std::sync::Arc;
+    use std::thread;
+
+    let clock = Arc::new(VirtualClock::new());
+    let participant = Participant::builder("liveliness_virtual_clock_test")
+        .with_transport(hdds::TransportMode::IntraProcess)
+        .clock(clock.clone())
+        .build()
+        .expect("participant");
+
+    let topic = participant
+        .topic::<Temperature>("LivelinessVirtualClockTopic")
+        .expect("topic");
+    let writer = topic
+        .writer()
+        .qos(QoS::reliable().liveliness_manual_topic_secs(10))
+        .build()
+        .expect("writer");
+    let reader = topic
+        .reader()
+        .qos(QoS::reliable().liveliness_manual_topic_secs(10))
+        .build()
+        .expect("reader");
+    assert_eq!(reader.liveliness_changed_status().alive_count, 1);
+
+    // Wall time does not run the lease down, recorded time does
+    thread::sleep(Duration::from_millis(50));
+    clock.advance(Duration::from_secs(9));
+    assert_eq!(reader.liveliness_changed_status().alive_count, 1);
+    clock.advance(Duration::from_secs(2));
+    let status = reader.liveliness_changed_status();
+    assert_eq!(status.alive_count, 0);
+    assert_eq!(status.not_alive_count, 1);
+
+    writer.assert_liveliness().expect("assert_liveliness");
+    assert_eq!(reader.liveliness_changed_status().alive_count, 1);
+}
@@ -269,15 +269,10 @@ fn test_partition_behavior_different_partition_no_data() {
     thread::sleep(Duration::from_millis(200));
 
     // Reader in partition "B" should NOT receive data from writer in partition "A"
-    let msg = reader_b.take();
-    match msg {
-        Ok(None) => { /* expected: no data */ }
-        Ok(Some(_)) => {
-            // Partition isolation may not be enforced in IntraProcess mode yet.
-            // This is acceptable - just log that isolation is not yet enforced.
-        }
-        Err(_) => { /* also acceptable */ }
-    }
+    assert!(
+        reader_b.take().expect("take").is_none(),
+        "Reader in partition B should not receive data from writer in partition A"
+    );
 }
 
 #[test]
@@ -309,7 +304,7 @@ fn test_partition_behavior_writer_and_two_readers() {
         .qos(reader_a_qos)
         .build()
         .expect("reader_a");
-    let _reader_b = participant
+    let reader_b = participant
         .topic::<Temperature>("PartitionTwoReadersTopic")
         .expect("topic")
         .reader()
@@ -334,8 +329,60 @@ fn test_partition_behavior_writer_and_two_readers() {
     } else {
         panic!("Reader in partition A should receive data from writer in partition A");
     }
+    assert!(reader_b.take().expect("take").is_none());
 }
 
-// Note: Full partition isolation (cross-partition blocking) depends on
-// the partition matcher implementation in the DomainRegistry.
-// The above tests verify data flows when partitions match.
+#[test]
+fn test_partition_behavior_wildcard() {
+    use hdds::generated::temperature::Temperature;
+    use std::thread;
+
+    // Reader in "bench/*" receives from "bench/cell1" but not from "production"
+    let participant = Participant::builder("partition_wildcard_test")
+        .with_transport(hdds::TransportMode::IntraProcess)
+        .build()
+        .expect("participant");
+
+    let topic = participant
+        .topic::<Temperature>("PartitionWildcardTopic")
+        .expect("topic");
+    let bench_writer = topic
+        .writer()
+        .qos(QoS::reliable().partition_single("bench/cell1"))
+        .build()
+        .expect("bench writer");
+    let production_writer = topic
+        .writer()
+        .qos(QoS::reliable().partition_single("production"))
+        .build()
+        .expect("production writer");
+    let reader = topic
+        .reader()
+        .qos(QoS::reliable().partition_single("bench/*"))
+        .build()
+        .expect("reader");
+
+    thread::sleep(Duration::from_millis(50));
+
+    production_writer
+        .write(&Temperature {
+            value: 1.0,
+            timestamp: 1,
+        })
+        .expect("write production");
+    bench_writer
+        .write(&Temperature {
+            value: 2.0,
+            timestamp: 2,
+        })
+        .expect("write bench");
+
+    thread::sleep(Duration::from_millis(100));
+
+    let msg = reader
+        .take()
+        .expect("take")
+        .expect("sample from bench/cell1");
+    assert_eq!(msg.value, 2.0);
+    assert!(reader.take().expect("take").is_none());
+}
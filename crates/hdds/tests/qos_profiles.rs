@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+#![cfg(feature = "qos-loaders")]
+
+//! Named QoS profiles loaded by the participant builder
+//!
+//! Vendor XML (OMG DDS-XML, FastDDS) and YAML profile files are loaded into
+//! the global registry at build and looked up with `QoS::from_profile`.
+
+use hdds::dds::qos::{Durability, History, Reliability};
+use hdds::{Participant, QoS, TransportMode};
+use std::io::Write;
+use std::time::Duration;
+
+const DDS_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<dds xmlns="http://www.omg.org/dds">
+  <qos_library name="ProfilesTestLib">
+    <qos_profile name="profiles_test_base">
+      <datawriter_qos>
+        <reliability><kind>RELIABLE_RELIABILITY_QOS</kind></reliability>
+      </datawriter_qos>
+    </qos_profile>
+    <qos_profile name="profiles_test_telemetry" base_name="profiles_test_base">
+      <datawriter_qos>
+        <durability><kind>TRANSIENT_LOCAL_DURABILITY_QOS</kind></durability>
+        <history><kind>KEEP_LAST_HISTORY_QOS</kind><depth>5</depth></history>
+        <deadline><period><sec>1</sec><nanosec>0</nanosec></period></deadline>
+      </datawriter_qos>
+    </qos_profile>
+  </qos_library>
+</dds>"#;
+
+const FASTDDS_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<dds xmlns="http://www.eprosima.com/XMLSchemas/fastRTPS_Profiles">
+  <profiles>
+    <data_reader profile_name="profiles_test_fastdds">
+      <qos><reliability><kind>RELIABLE</kind></reliability></qos>
+      <topic><historyQos><kind>KEEP_ALL</kind></historyQos></topic>
+    </data_reader>
+  </profiles>
+</dds>"#;
+
+const YAML: &str = r#"
+profiles:
+  profiles_test_yaml:
+    reliability: best_effort
+    history_depth: 3
+"#;
+
+fn profile_file(suffix: &str, content: &str) -> tempfile::NamedTempFile {
+    let mut file = tempfile::Builder::new()
+        .suffix(suffix)
+        .tempfile()
+        .expect("temp file");
+    file.write_all(content.as_bytes()).expect("write profiles");
+    file
+}
+
+#[test]
+fn test_participant_loads_profile_files() {
+    let dds_xml = profile_file(".xml", DDS_XML);
+    let fastdds = profile_file(".xml", FASTDDS_XML);
+    let yaml = profile_file(".yaml", YAML);
+
+    let _participant = Participant::builder("qos_profiles")
+        .with_transport(TransportMode::IntraProcess)
+        .with_qos_profile_file(dds_xml.path())
+        .with_qos_profile_file(fastdds.path())
+        .with_qos_profile_file(yaml.path())
+        .build()
+        .expect("participant");
+
+    let qos = QoS::from_profile("profiles_test_telemetry").expect("bare name");
+    assert!(matches!(qos.reliability, Reliability::Reliable));
+    assert!(matches!(qos.durability, Durability::TransientLocal));
+    assert!(matches!(qos.history, History::KeepLast(5)));
+    assert_eq!(qos.deadline.period, Duration::from_secs(1));
+    assert!(QoS::from_profile("ProfilesTestLib::profiles_test_telemetry").is_ok());
+
+    let qos = QoS::from_profile("profiles_test_fastdds").expect("FastDDS profile");
+    assert!(matches!(qos.history, History::KeepAll));
+
+    let qos = QoS::from_profile("profiles_test_yaml").expect("YAML profile");
+    assert!(matches!(qos.reliability, Reliability::BestEffort));
+    assert!(matches!(qos.history, History::KeepLast(3)));
+
+    assert!(QoS::from_profile("profiles_test_missing").is_err());
+}
+
+#[test]
+fn test_invalid_profile_file_fails_build() {
+    let broken = profile_file(".xml", "<dds><qos_library name=\"L\"><qos_profile>");
+    let result = Participant::builder("qos_profiles_broken")
+        .with_transport(TransportMode::IntraProcess)
+        .with_qos_profile_file(broken.path())
+        .build();
+    assert!(matches!(result, Err(hdds::Error::InvalidQos(_))));
+
+    let result = Participant::builder("qos_profiles_missing")
+        .with_transport(TransportMode::IntraProcess)
+        .with_qos_profile_file("/nonexistent/qos_profiles.xml")
+        .build();
+    assert!(matches!(result, Err(hdds::Error::ConfigFileNotFound(_))));
+}
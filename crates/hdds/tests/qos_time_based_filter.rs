@@ -404,19 +404,13 @@ fn test_time_based_filter_behavior_filtered_reader_receives_fewer() {
         count_all
     );
 
-    // Filtered reader (500ms separation) should receive significantly fewer
-    // Over ~1s with 500ms filter: expect ~2-4 samples
-    // If time-based filtering is not yet enforced, both may receive the same count
-    if count_filtered < count_all {
-        // Filtering is working
-        assert!(
-            count_filtered <= 10,
-            "Filtered reader should receive <= 10 samples, got {}",
-            count_filtered
-        );
-    }
-    // If count_filtered == count_all, filtering is not yet enforced in the cache,
-    // which is acceptable - the QoS is correctly configured either way.
+    // Filtered reader (500ms separation) over ~1s: expect ~2-3 samples
+    assert!(
+        (1..=4).contains(&count_filtered),
+        "Filtered reader should receive 1..=4 samples, got {}",
+        count_filtered
+    );
+    assert!(count_filtered < count_all);
 }
 
 // Note: Full TIME_BASED_FILTER enforcement depends on the reader cache
@@ -0,0 +1,185 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! ReadCondition and QueryCondition integration tests
+//!
+//! Conditions created on an intra-process reader wake a WaitSet only for
+//! the samples their state masks and query select, and take_w_condition()
+//! returns exactly those samples.
+
+use hdds::dds::{InstanceStateMask, SampleStateMask, ViewStateMask, WaitSet};
+use hdds::{Participant, QoS, TransportMode};
+use std::time::Duration;
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Sensor {
+    #[key]
+    id: u32,
+    value: f64,
+}
+
+const WAIT: Option<Duration> = Some(Duration::from_secs(5));
+
+fn participant(name: &str) -> std::sync::Arc<Participant> {
+    Participant::builder(name)
+        .with_transport(TransportMode::IntraProcess)
+        .build()
+        .expect("participant")
+}
+
+#[test]
+fn test_readcondition_wakes_on_new_instances_only() {
+    let participant = participant("read_condition_new");
+    let topic = participant
+        .topic::<Sensor>("conditions/sensor")
+        .expect("topic");
+    let reader = topic
+        .reader()
+        .qos(QoS::reliable().keep_last(10))
+        .build()
+        .expect("reader");
+    let writer = topic
+        .writer()
+        .qos(QoS::reliable().keep_last(10))
+        .build()
+        .expect("writer");
+
+    let cond = reader.create_readcondition(
+        SampleStateMask::NOT_READ,
+        ViewStateMask::NEW,
+        InstanceStateMask::ALIVE,
+    );
+    let waitset = WaitSet::new();
+    waitset.attach_condition(cond.clone()).expect("attach");
+    assert!(waitset.wait(Some(Duration::from_millis(50))).is_err());
+
+    writer.write(&Sensor { id: 1, value: 1.0 }).expect("write");
+    let triggered = waitset.wait(WAIT).expect("wait");
+    assert_eq!(triggered.len(), 1);
+    let taken = reader.take_w_condition(&*cond, 10).expect("take");
+    assert_eq!(taken.len(), 1);
+    assert_eq!(taken[0].0, Sensor { id: 1, value: 1.0 });
+
+    // A second sample of a known instance is not NEW
+    writer.write(&Sensor { id: 1, value: 2.0 }).expect("write");
+    assert!(reader
+        .take_w_condition(&*cond, 10)
+        .expect("take")
+        .is_empty());
+    assert!(waitset.wait(Some(Duration::from_millis(50))).is_err());
+
+    writer.write(&Sensor { id: 2, value: 3.0 }).expect("write");
+    waitset.wait(WAIT).expect("wait");
+    let taken = reader.take_w_condition(&*cond, 10).expect("take");
+    assert_eq!(taken.len(), 1);
+    assert_eq!(taken[0].0.id, 2);
+
+    // The NOT_NEW sample is still there for a plain take
+    assert_eq!(reader.take().expect("take").map(|s| s.value), Some(2.0));
+}
+
+#[test]
+fn test_read_w_condition_marks_samples_read() {
+    let participant = participant("read_condition_read");
+    let topic = participant
+        .topic::<Sensor>("conditions/read")
+        .expect("topic");
+    let reader = topic.reader().build().expect("reader");
+    let writer = topic.writer().build().expect("writer");
+
+    let not_read = reader.create_readcondition(
+        SampleStateMask::NOT_READ,
+        ViewStateMask::ANY,
+        InstanceStateMask::ANY,
+    );
+    let read = reader.create_readcondition(
+        SampleStateMask::READ,
+        ViewStateMask::ANY,
+        InstanceStateMask::ANY,
+    );
+    writer.write(&Sensor { id: 1, value: 1.0 }).expect("write");
+
+    assert_eq!(
+        reader.read_w_condition(&*not_read, 10).expect("read").len(),
+        1
+    );
+    assert!(reader
+        .read_w_condition(&*not_read, 10)
+        .expect("read")
+        .is_empty());
+    let again = reader.read_w_condition(&*read, 10).expect("read");
+    assert_eq!(again.len(), 1);
+    assert_eq!(again[0].1.instance_state, hdds::InstanceState::Alive);
+}
+
+#[test]
+fn test_querycondition_filters_by_content() {
+    let participant = participant("query_condition");
+    let topic = participant
+        .topic::<Sensor>("conditions/query")
+        .expect("topic");
+    let reader = topic
+        .reader()
+        .qos(QoS::reliable().keep_last(10))
+        .build()
+        .expect("reader");
+    let writer = topic
+        .writer()
+        .qos(QoS::reliable().keep_last(10))
+        .build()
+        .expect("writer");
+
+    let cond = reader
+        .create_querycondition(
+            SampleStateMask::ANY,
+            ViewStateMask::ANY,
+            InstanceStateMask::ANY,
+            "value > %0",
+            vec!["10".to_string()],
+        )
+        .expect("query condition");
+    let waitset = WaitSet::new();
+    waitset.attach_condition(cond.clone()).expect("attach");
+
+    writer.write(&Sensor { id: 1, value: 5.0 }).expect("write");
+    writer.write(&Sensor { id: 2, value: 20.0 }).expect("write");
+    waitset.wait(WAIT).expect("wait");
+
+    let taken = reader.take_w_condition(&*cond, 10).expect("take");
+    assert_eq!(taken.len(), 1);
+    assert_eq!(taken[0].0.value, 20.0);
+    assert!(waitset.wait(Some(Duration::from_millis(50))).is_err());
+
+    cond.set_query_parameters(vec!["1".to_string()]);
+    waitset.wait(WAIT).expect("wait");
+    let taken = reader.take_w_condition(&*cond, 10).expect("take");
+    assert_eq!(taken.len(), 1);
+    assert_eq!(taken[0].0.value, 5.0);
+}
+
+#[test]
+fn test_condition_errors() {
+    let participant = participant("condition_errors");
+    let topic = participant
+        .topic::<Sensor>("conditions/errors")
+        .expect("topic");
+    let reader = topic.reader().build().expect("reader");
+    let other = topic.reader().build().expect("reader");
+
+    assert!(reader
+        .create_querycondition(
+            SampleStateMask::ANY,
+            ViewStateMask::ANY,
+            InstanceStateMask::ANY,
+            "value >",
+            Vec::new(),
+        )
+        .is_err());
+
+    let cond = other.create_readcondition(
+        SampleStateMask::ANY,
+        ViewStateMask::ANY,
+        InstanceStateMask::ANY,
+    );
+    assert!(reader.take_w_condition(&*cond, 10).is_err());
+}
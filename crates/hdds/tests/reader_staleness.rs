@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Reader arrival metrics and staleness watchdog integration tests
+//!
+//! An intra-process writer publishes, pauses past the watchdog window and
+//! publishes again: the reader reports stale then fresh through its
+//! listener and status, and tracks the age of the last sample.
+
+use hdds::dds::{DataReaderListener, SampleStalenessStatus, StatusMask};
+use hdds::{Participant, QoS, TransportMode};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Heartbeat {
+    count: u32,
+}
+
+#[derive(Default)]
+struct Events {
+    staleness: Mutex<Vec<SampleStalenessStatus>>,
+}
+
+impl DataReaderListener<Heartbeat> for Events {
+    fn on_sample_staleness_changed(&self, status: SampleStalenessStatus) {
+        self.staleness.lock().expect("lock").push(status);
+    }
+}
+
+fn wait_for(condition: impl Fn() -> bool) -> bool {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while Instant::now() < deadline {
+        if condition() {
+            return true;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    condition()
+}
+
+#[test]
+fn test_staleness_watchdog_reports_stale_and_fresh() {
+    let participant = Participant::builder("reader_staleness")
+        .with_transport(TransportMode::IntraProcess)
+        .build()
+        .expect("participant");
+    let topic = participant
+        .topic::<Heartbeat>("staleness/heartbeat")
+        .expect("topic");
+    let events = Arc::new(Events::default());
+    let reader = topic
+        .reader()
+        .qos(QoS::best_effort())
+        .with_staleness_watchdog(Duration::from_millis(150))
+        .with_staleness_watchdog(Duration::from_secs(60))
+        .with_listener(Arc::clone(&events) as Arc<dyn DataReaderListener<Heartbeat>>)
+        .build()
+        .expect("reader");
+    let writer = topic
+        .writer()
+        .qos(QoS::best_effort())
+        .build()
+        .expect("writer");
+    assert_eq!(reader.last_sample_age(), None);
+
+    writer.write(&Heartbeat { count: 1 }).expect("write");
+    assert!(wait_for(|| reader.last_sample_age().is_some()));
+    assert_eq!(reader.rate_metrics().samples_received, 1);
+
+    // No sample for longer than the short window
+    assert!(
+        wait_for(|| events
+            .staleness
+            .lock()
+            .expect("lock")
+            .iter()
+            .any(|s| s.stale)),
+        "staleness not reported"
+    );
+    assert!(reader.last_sample_age().expect("age") >= Duration::from_millis(150));
+    assert!(reader
+        .get_status_condition()
+        .get_active_statuses()
+        .contains(StatusMask::SAMPLE_STALENESS_CHANGED));
+    let statuses = reader.staleness_status();
+    assert_eq!(statuses.len(), 2);
+    assert!(statuses[0].stale);
+    assert!(statuses[0].total_count >= 1);
+    assert!(!statuses[1].stale);
+
+    writer.write(&Heartbeat { count: 2 }).expect("write");
+    assert!(
+        wait_for(|| events
+            .staleness
+            .lock()
+            .expect("lock")
+            .last()
+            .is_some_and(|s| !s.stale)),
+        "recovery not reported"
+    );
+    let recovered = events.staleness.lock().expect("lock").last().cloned();
+    assert_eq!(
+        recovered.map(|s| s.window),
+        Some(Duration::from_millis(150))
+    );
+    assert!(!reader.staleness_status()[0].stale);
+    assert_eq!(reader.rate_metrics().samples_received, 2);
+}
+
+#[test]
+fn test_zero_staleness_window_is_rejected() {
+    let participant = Participant::builder("reader_staleness_zero")
+        .with_transport(TransportMode::IntraProcess)
+        .build()
+        .expect("participant");
+    let result = participant
+        .topic::<Heartbeat>("staleness/zero")
+        .expect("topic")
+        .reader()
+        .with_staleness_watchdog(Duration::ZERO)
+        .build();
+    assert!(result.is_err());
+}
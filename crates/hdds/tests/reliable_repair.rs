@@ -102,6 +102,23 @@ fn test_gap_stops_nack_loop() {
     assert_eq!(scheduler.retry_count(), 0);
 }
 
+#[test]
+fn test_abandoned_gaps_count_as_lost_once() {
+    let mut scheduler = NackScheduler::with_window_ms(0);
+    for seq in [1u64, 2, 5] {
+        scheduler.on_receive(seq);
+    }
+
+    for _ in 0..5 {
+        scheduler.on_nack_sent();
+    }
+
+    // Sequences 3 and 4 were never repaired
+    assert_eq!(scheduler.take_lost(), 2);
+    assert!(scheduler.pending_gaps().is_empty());
+    assert_eq!(scheduler.take_lost(), 0);
+}
+
 #[test]
 fn test_repair_with_10_percent_loss() {
     const TOTAL: u64 = 1_000;
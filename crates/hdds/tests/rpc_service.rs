@@ -0,0 +1,313 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+#![cfg(feature = "rpc")]
+
+//! DDS-RPC request/reply over intra-process transport.
+
+use hdds::rpc::{Client, RemoteExceptionCode, RpcError, RpcResult, Service};
+use hdds::{Participant, TransportMode, DDS};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(DDS, Debug, Clone, PartialEq)]
+struct Number {
+    value: f64,
+}
+
+#[derive(DDS, Debug, Clone, PartialEq)]
+struct Count {
+    value: u64,
+}
+
+#[derive(DDS, Debug, Clone, PartialEq)]
+struct Text {
+    text: String,
+}
+
+fn participant(name: &str) -> Arc<Participant> {
+    Participant::builder(name)
+        .with_transport(TransportMode::IntraProcess)
+        .domain_id(99)
+        .build()
+        .expect("participant")
+}
+
+/// Spin `service` on a thread until the returned guard is dropped.
+struct Spinning<Req: hdds::dds::DDS, Rep: hdds::dds::DDS> {
+    service: Arc<Service<Req, Rep>>,
+    thread: Option<thread::JoinHandle<RpcResult<()>>>,
+}
+
+impl<Req: hdds::dds::DDS, Rep: hdds::dds::DDS> Spinning<Req, Rep> {
+    fn start(service: Service<Req, Rep>) -> Self {
+        let service = Arc::new(service);
+        let spinner = Arc::clone(&service);
+        Self {
+            service,
+            thread: Some(thread::spawn(move || spinner.spin())),
+        }
+    }
+}
+
+impl<Req: hdds::dds::DDS, Rep: hdds::dds::DDS> Drop for Spinning<Req, Rep> {
+    fn drop(&mut self) {
+        self.service.shutdown();
+        if let Some(thread) = self.thread.take() {
+            thread.join().expect("spin thread").expect("spin");
+        }
+    }
+}
+
+#[test]
+fn test_typed_call_and_exceptions() {
+    let participant = participant("rpc-typed");
+    let service = Service::new(&participant, "typed_sqrt", |n: Number| {
+        if n.value < 0.0 {
+            Err(RpcError::remote(RemoteExceptionCode::InvalidArgument))
+        } else {
+            Ok(Number {
+                value: n.value.sqrt(),
+            })
+        }
+    })
+    .expect("service");
+    let spinning = Spinning::start(service);
+
+    let client = Client::<Number, Number>::new(&participant, "typed_sqrt")
+        .expect("client")
+        .timeout(TIMEOUT);
+    assert!(client.wait_for_service(TIMEOUT));
+
+    assert_eq!(
+        client.call(&Number { value: 16.0 }).expect("call").value,
+        4.0
+    );
+    match client.call(&Number { value: -1.0 }) {
+        Err(RpcError::RemoteException { code, .. }) => {
+            assert_eq!(code, RemoteExceptionCode::InvalidArgument);
+        }
+        other => panic!("expected a remote exception, got {:?}", other),
+    }
+    assert_eq!(client.outstanding(), 0);
+    drop(spinning);
+}
+
+#[test]
+fn test_timeout_without_service() {
+    let participant = participant("rpc-timeout");
+    let client = Client::<Count, Count>::new(&participant, "typed_nobody")
+        .expect("client")
+        .timeout(Duration::from_millis(100));
+
+    assert!(!client.is_service_available());
+    assert!(matches!(
+        client.call(&Count { value: 1 }),
+        Err(RpcError::Timeout)
+    ));
+    // The timed-out request is forgotten
+    assert_eq!(client.outstanding(), 0);
+}
+
+#[test]
+fn test_concurrent_outstanding_requests() {
+    let participant = participant("rpc-concurrent");
+    let service = Service::new(&participant, "typed_double", |n: Count| {
+        Ok(Count { value: n.value * 2 })
+    })
+    .expect("service");
+    let _spinning = Spinning::start(service);
+
+    let client = Client::<Count, Count>::new(&participant, "typed_double").expect("client");
+    assert!(client.wait_for_service(TIMEOUT));
+
+    // Many requests in flight, collected in reverse order
+    let pending: Vec<_> = (0..16u64)
+        .map(|value| client.send(&Count { value }).expect("send"))
+        .collect();
+    let ids: std::collections::HashSet<_> = pending.iter().map(|p| p.request_id()).collect();
+    assert_eq!(ids.len(), 16);
+    for (n, reply) in pending.into_iter().enumerate().rev() {
+        assert_eq!(reply.wait(TIMEOUT).expect("reply").value, n as u64 * 2);
+    }
+
+    // Clones on several threads share the client
+    let threads: Vec<_> = (0..4u64)
+        .map(|t| {
+            let client = client.clone().timeout(TIMEOUT);
+            thread::spawn(move || {
+                for n in 0..10 {
+                    let value = t * 100 + n;
+                    assert_eq!(
+                        client.call(&Count { value }).expect("call").value,
+                        value * 2
+                    );
+                }
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().expect("client thread");
+    }
+    assert_eq!(client.outstanding(), 0);
+}
+
+#[test]
+fn test_async_call() {
+    let participant = participant("rpc-async");
+    let service = Service::new(&participant, "typed_len", |text: Text| {
+        Ok(Count {
+            value: text.text.len() as u64,
+        })
+    })
+    .expect("service");
+    let client = Client::<Text, Count>::new(&participant, "typed_len")
+        .expect("client")
+        .timeout(TIMEOUT);
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .expect("runtime");
+    runtime.block_on(async {
+        let service = Arc::new(service);
+        let spinner = Arc::clone(&service);
+        let spin = tokio::spawn(async move { spinner.spin_async().await });
+
+        assert_eq!(
+            client
+                .call_async(&Text {
+                    text: "hello".to_string()
+                })
+                .await
+                .expect("call")
+                .value,
+            5
+        );
+        let pending = client
+            .send(&Text {
+                text: "four".to_string(),
+            })
+            .expect("send");
+        assert_eq!(pending.wait_async(TIMEOUT).await.expect("reply").value, 4);
+
+        service.shutdown();
+        spin.await.expect("join").expect("spin");
+    });
+}
+
+#[hdds::rpc::service]
+pub trait Calculator {
+    fn add(&self, a: i64, b: i64) -> i64;
+    fn divide(&self, a: f64, b: f64) -> RpcResult<f64>;
+    fn describe(&self, values: Vec<i32>, label: String) -> String;
+    fn reset(&self);
+}
+
+struct Calc {
+    resets: std::sync::atomic::AtomicU32,
+}
+
+impl Calculator for Calc {
+    fn add(&self, a: i64, b: i64) -> i64 {
+        a + b
+    }
+
+    fn divide(&self, a: f64, b: f64) -> RpcResult<f64> {
+        if b == 0.0 {
+            return Err(RpcError::remote_with_message(
+                RemoteExceptionCode::InvalidArgument,
+                "division by zero",
+            ));
+        }
+        Ok(a / b)
+    }
+
+    fn describe(&self, values: Vec<i32>, label: String) -> String {
+        format!("{}: {:?}", label, values)
+    }
+
+    fn reset(&self) {
+        self.resets
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn test_service_macro() {
+    let participant = participant("rpc-macro");
+    let service = Calc {
+        resets: Default::default(),
+    }
+    .into_service(&participant, "calculator")
+    .expect("service");
+    let _spinning = Spinning::start(service);
+
+    let client = CalculatorClient::new(&participant, "calculator")
+        .expect("client")
+        .timeout(TIMEOUT);
+    assert!(client.client().wait_for_service(TIMEOUT));
+
+    assert_eq!(client.add(2, 40).expect("add"), 42);
+    assert_eq!(client.divide(1.0, 4.0).expect("divide"), 0.25);
+    assert!(matches!(
+        client.divide(1.0, 0.0),
+        Err(RpcError::RemoteException {
+            code: RemoteExceptionCode::InvalidArgument,
+            ..
+        })
+    ));
+    assert_eq!(
+        client
+            .describe(vec![1, 2], "values".to_string())
+            .expect("describe"),
+        "values: [1, 2]"
+    );
+    client.reset().expect("reset");
+
+    // Request encoding round trip
+    use hdds::dds::DDS as _;
+    let mut buf = [0u8; 64];
+    let request = CalculatorRequest::Describe {
+        values: vec![7],
+        label: "x".to_string(),
+    };
+    let len = request.encode_cdr2(&mut buf).expect("encode");
+    match CalculatorRequest::decode_cdr2(&buf[..len]).expect("decode") {
+        CalculatorRequest::Describe { values, label } => {
+            assert_eq!(values, vec![7]);
+            assert_eq!(label, "x");
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    assert!(CalculatorReply::decode_cdr2(&[9, 0, 0, 0]).is_err());
+}
+
+#[test]
+fn test_raw_service_server() {
+    use hdds::rpc::{SampleIdentity, ServiceClient, ServiceServer};
+
+    let participant = participant("rpc-raw");
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .expect("runtime");
+    runtime.block_on(async {
+        let handler = |_id: SampleIdentity, payload: &[u8]| {
+            let mut reply = payload.to_vec();
+            reply.reverse();
+            Ok(reply)
+        };
+        let server = ServiceServer::new(&participant, "raw_reverse", handler).expect("server");
+        let client = ServiceClient::new(&participant, "raw_reverse").expect("client");
+        // The server is not Send: drive it next to the call on this task
+        tokio::select! {
+            () = server.spin() => panic!("server stopped"),
+            reply = client.call_raw(b"abc", TIMEOUT) => assert_eq!(reply.expect("call"), b"cba"),
+        }
+        client.shutdown();
+    });
+}
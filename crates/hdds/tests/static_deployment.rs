@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Static deployment integration tests
+//!
+//! Two participants declare their endpoints upfront, discover each other
+//! once and seal. A participant joining afterwards is not admitted.
+
+use hdds::{
+    DataReader, DataWriter, Participant, QoS, Result, StaticDeployment, StaticTopic, TransportMode,
+};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const DOMAIN: u32 = 91;
+const TOPIC: &str = "static/reading";
+
+#[derive(hdds::DDS, Debug, Clone, PartialEq)]
+struct Reading {
+    value: u32,
+}
+
+fn make_writer(participant: &Arc<Participant>) -> Result<DataWriter<Reading>> {
+    participant
+        .topic::<Reading>(TOPIC)?
+        .writer()
+        .qos(QoS::reliable())
+        .build()
+}
+
+fn make_reader(participant: &Arc<Participant>) -> Result<DataReader<Reading>> {
+    participant
+        .topic::<Reading>(TOPIC)?
+        .reader()
+        .qos(QoS::reliable())
+        .build()
+}
+
+fn participant(name: &str, topic: StaticTopic) -> Arc<Participant> {
+    Participant::builder(name)
+        .domain_id(DOMAIN)
+        .with_transport(TransportMode::UdpMulticast)
+        .with_static_deployment(
+            StaticDeployment::new()
+                .topic(topic)
+                .discovery_timeout(Duration::from_secs(10)),
+        )
+        .build()
+        .expect("participant")
+}
+
+#[test]
+fn test_static_deployment_discovers_once_then_seals() {
+    let publisher = participant(
+        "static_pub",
+        StaticTopic::new(TOPIC).writers(1).remote_readers(1),
+    );
+    let subscriber = participant(
+        "static_sub",
+        StaticTopic::new(TOPIC).readers(1).remote_writers(1),
+    );
+
+    let writer = make_writer(&publisher).expect("writer");
+    let reader = make_reader(&subscriber).expect("reader");
+
+    let sub_handle = {
+        let subscriber = Arc::clone(&subscriber);
+        thread::spawn(move || subscriber.complete_static_discovery())
+    };
+    publisher
+        .complete_static_discovery()
+        .expect("publisher static discovery");
+    sub_handle
+        .join()
+        .expect("subscriber thread")
+        .expect("subscriber static discovery");
+    assert!(publisher.is_static_sealed());
+
+    // No endpoint can be created once sealed
+    assert!(make_writer(&publisher).is_err());
+
+    // A late participant is ignored by the sealed ones
+    let late = Participant::builder("static_late")
+        .domain_id(DOMAIN)
+        .with_transport(TransportMode::UdpMulticast)
+        .build()
+        .expect("late participant");
+    let _late_reader = make_reader(&late).expect("late reader");
+    thread::sleep(Duration::from_millis(500));
+    let fsm = publisher.discovery().expect("discovery");
+    assert!(fsm.get_participants().iter().all(|p| p.guid != late.guid()));
+    assert_eq!(
+        fsm.find_readers_for_topic(TOPIC)
+            .iter()
+            .filter(|r| r.endpoint_guid.prefix != publisher.guid().prefix)
+            .count(),
+        1
+    );
+
+    // Data still flows on the sealed graph
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let mut received = None;
+    while received.is_none() && Instant::now() < deadline {
+        writer.write(&Reading { value: 7 }).expect("write");
+        thread::sleep(Duration::from_millis(50));
+        received = reader.take().expect("take");
+    }
+    assert_eq!(received, Some(Reading { value: 7 }));
+}
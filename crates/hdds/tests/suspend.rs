@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Participant suspend mode integration tests
+//!
+//! A participant announcing to an initial peer goes silent while suspended
+//! and announces again right after resuming.
+
+use hdds::{Participant, TransportMode};
+use std::net::UdpSocket;
+use std::time::{Duration, Instant};
+
+const DOMAIN: u32 = 96;
+
+/// Wait up to `timeout` for an SPDP datagram from `prefix`.
+fn spdp_within(peer: &UdpSocket, prefix: &[u8], timeout: Duration) -> bool {
+    let deadline = Instant::now() + timeout;
+    let mut buf = [0u8; 2048];
+    while Instant::now() < deadline {
+        if let Ok((len, _)) = peer.recv_from(&mut buf) {
+            // RTPS header: magic, version, vendor id, then the GUID prefix
+            if len > 20 && &buf[..4] == b"RTPS" && &buf[8..20] == prefix {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+#[test]
+fn test_suspended_participant_stops_announcing() {
+    let peer = UdpSocket::bind("127.0.0.1:0").expect("peer socket");
+    peer.set_read_timeout(Some(Duration::from_millis(50)))
+        .expect("read timeout");
+    let peer_addr = peer.local_addr().expect("peer addr").to_string();
+
+    let participant = Participant::builder("suspend")
+        .domain_id(DOMAIN)
+        .with_transport(TransportMode::UdpMulticast)
+        .with_initial_peers(&[peer_addr.as_str()])
+        .build()
+        .expect("participant");
+    let prefix = participant.guid().as_bytes()[..12].to_vec();
+    assert!(spdp_within(&peer, &prefix, Duration::from_secs(5)));
+
+    participant.suspend();
+    assert!(participant.is_suspended());
+    assert!(participant.transport().expect("udp").is_suspended());
+    // Let an announcement already in flight arrive
+    spdp_within(&peer, &prefix, Duration::from_millis(100));
+    assert!(
+        !spdp_within(&peer, &prefix, Duration::from_secs(1)),
+        "suspended participant still announcing"
+    );
+
+    participant.resume();
+    assert!(!participant.is_suspended());
+    assert!(
+        spdp_within(&peer, &prefix, Duration::from_millis(500)),
+        "no announcement after resume"
+    );
+}
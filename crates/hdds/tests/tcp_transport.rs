@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! TransportMode::Tcp integration tests
+//!
+//! Participants discover each other with SPDP carried over TCP only: a
+//! server listens, a client dials it and redials with backoff until the
+//! server comes up.
+
+use hdds::{Participant, TcpConfig, TcpFraming, TransportMode};
+use std::net::{SocketAddr, TcpListener};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const DOMAIN: u32 = 87;
+
+fn free_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0")
+        .and_then(|listener| listener.local_addr())
+        .map(|addr| addr.port())
+        .expect("free port")
+}
+
+fn tcp_participant(name: &str, config: TcpConfig) -> Arc<Participant> {
+    Participant::builder(name)
+        .domain_id(DOMAIN)
+        .with_transport(TransportMode::Tcp)
+        .tcp_config(config)
+        .build()
+        .expect("participant")
+}
+
+fn discovers(participant: &Participant, remote: &Participant, timeout: Duration) -> bool {
+    let deadline = Instant::now() + timeout;
+    while Instant::now() < deadline {
+        let found = participant.discovery().is_some_and(|fsm| {
+            fsm.get_participants()
+                .iter()
+                .any(|p| p.guid == remote.guid())
+        });
+        if found {
+            return true;
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+    false
+}
+
+fn assert_mutual_discovery(framing: TcpFraming) {
+    let server = tcp_participant(
+        "tcp_server",
+        TcpConfig::server_only(0).with_framing(framing),
+    );
+    let port = server.tcp_listen_addr().expect("listen addr").port();
+    let server_addr: SocketAddr = ([127, 0, 0, 1], port).into();
+    let client = tcp_participant(
+        "tcp_client",
+        TcpConfig::client_only(vec![server_addr]).with_framing(framing),
+    );
+
+    assert!(discovers(&server, &client, Duration::from_secs(10)));
+    assert!(discovers(&client, &server, Duration::from_secs(10)));
+    assert_eq!(client.tcp_transport().expect("tcp").connection_count(), 1);
+}
+
+#[test]
+fn test_tcp_mode_discovery() {
+    assert_mutual_discovery(TcpFraming::LengthPrefix);
+}
+
+#[test]
+fn test_tcp_mode_discovery_rti_framing() {
+    assert_mutual_discovery(TcpFraming::Rti);
+}
+
+#[test]
+fn test_tcp_client_redials_until_server_starts() {
+    let port = free_port();
+    let server_addr: SocketAddr = ([127, 0, 0, 1], port).into();
+    let client = tcp_participant(
+        "tcp_early_client",
+        TcpConfig::client_only(vec![server_addr]).with_reconnect(
+            Duration::from_millis(50),
+            Duration::from_millis(400),
+            0,
+        ),
+    );
+
+    // Let a few dial attempts fail before the server shows up
+    thread::sleep(Duration::from_millis(500));
+    let server = tcp_participant("tcp_late_server", TcpConfig::server_only(port));
+
+    assert!(discovers(&client, &server, Duration::from_secs(10)));
+    assert!(discovers(&server, &client, Duration::from_secs(10)));
+}
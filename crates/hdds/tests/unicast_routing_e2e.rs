@@ -40,6 +40,7 @@
 //! 3. A raw TCP connection is accepted by the listener
 //! 4. A length-prefixed RTPS-like packet is consumed without hanging or crashing
 //! 5. The routing thread processes events (even if the packet is dropped/orphaned)
+//! 6. The same routing thread drains the UDS transport when configured
 
 use std::io::Write;
 use std::net::TcpStream;
@@ -222,6 +223,53 @@ fn tcp_garbage_data_does_not_crash_router() {
     );
 }
 
+#[cfg(unix)]
+#[test]
+fn unicast_routing_thread_consumes_uds_datagrams() {
+    use hdds::transport::uds::UdsConfig;
+    use std::os::unix::net::UnixDatagram;
+
+    let dir = tempfile::tempdir().expect("tempdir");
+    let participant = Participant::builder("uds_e2e_test")
+        .with_transport(TransportMode::UdpMulticast)
+        .uds_config(UdsConfig::enabled().with_socket_dir(dir.path()))
+        .build()
+        .expect("participant build should succeed with UDS");
+
+    assert_eq!(
+        participant.transport_preference(),
+        hdds::TransportPreference::UdsPreferred
+    );
+    let path = participant
+        .uds_socket_path()
+        .expect("UDS transport should be bound");
+    assert!(path.starts_with(dir.path()));
+
+    thread::sleep(Duration::from_millis(100));
+    assert!(
+        check_thread_exists("hdds-unicast-router"),
+        "Expected 'hdds-unicast-router' thread to be spawned"
+    );
+
+    // One datagram per RTPS message, no framing
+    let client = UnixDatagram::unbound().expect("unbound socket");
+    client
+        .send_to(&build_minimal_rtps_data_packet(), &path)
+        .expect("send RTPS datagram");
+    client
+        .send_to(&[0xDE, 0xAD, 0xBE, 0xEF], &path)
+        .expect("send garbage datagram");
+
+    thread::sleep(Duration::from_millis(300));
+
+    let uds = participant.uds_transport().expect("UDS transport");
+    assert_eq!(uds.stats().messages_received, 2);
+
+    drop(uds);
+    drop(participant);
+    assert!(!path.exists(), "socket file should be removed on drop");
+}
+
 // ============================================================================
 // Helpers
 // ============================================================================
@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+#![cfg(feature = "wasmtime")]
+
+//! WASM filter/transform plugin integration tests
+//!
+//! Runs plugins on the read and write paths of intra-process endpoints.
+
+use hdds::dds::WasmLimits;
+use hdds::generated::temperature::Temperature;
+use hdds::{DataReader, Participant, QoS, TransportMode};
+use std::thread;
+use std::time::Duration;
+
+/// Keeps samples whose trailing i32 (the timestamp) is even.
+const EVEN_TIMESTAMPS: &str = r#"(module
+    (memory (export "memory") 1)
+    (func (export "hdds_alloc") (param i32) (result i32) i32.const 1024)
+    (func (export "hdds_filter") (param $ptr i32) (param $len i32) (result i32)
+        (i32.eqz (i32.and
+            (i32.load (i32.sub (i32.add (local.get $ptr) (local.get $len)) (i32.const 4)))
+            (i32.const 1)))))"#;
+
+/// Doubles the trailing i32 (the timestamp) in place.
+const DOUBLE_TIMESTAMP: &str = r#"(module
+    (memory (export "memory") 1)
+    (func (export "hdds_alloc") (param i32) (result i32) i32.const 1024)
+    (func (export "hdds_transform") (param $ptr i32) (param $len i32) (result i64)
+        (local $at i32)
+        (local.set $at (i32.sub (i32.add (local.get $ptr) (local.get $len)) (i32.const 4)))
+        (i32.store (local.get $at) (i32.mul (i32.load (local.get $at)) (i32.const 2)))
+        (i64.or
+            (i64.shl (i64.extend_i32_u (local.get $ptr)) (i64.const 32))
+            (i64.extend_i32_u (local.get $len)))))"#;
+
+fn participant(name: &str) -> std::sync::Arc<Participant> {
+    Participant::builder(name)
+        .with_transport(TransportMode::IntraProcess)
+        .build()
+        .expect("participant")
+}
+
+fn plugin_file(dir: &tempfile::TempDir, name: &str, wat: &str) -> std::path::PathBuf {
+    let path = dir.path().join(name);
+    std::fs::write(&path, wat).expect("write plugin");
+    path
+}
+
+fn timestamps(reader: &DataReader<Temperature>) -> Vec<i32> {
+    thread::sleep(Duration::from_millis(50));
+    let mut received = Vec::new();
+    while let Ok(Some(sample)) = reader.take() {
+        received.push(sample.timestamp);
+    }
+    received
+}
+
+#[test]
+fn test_reader_filter_plugin() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let participant = participant("wasm_reader_filter");
+    let plugin = participant
+        .load_wasm_plugin(
+            plugin_file(&dir, "even.wat", EVEN_TIMESTAMPS),
+            WasmLimits::default(),
+        )
+        .expect("plugin");
+
+    let topic = participant
+        .topic::<Temperature>("WasmReaderFilterTopic")
+        .expect("topic");
+    let writer = topic.writer().qos(QoS::reliable()).build().expect("writer");
+    let reader = topic
+        .reader()
+        .qos(QoS::reliable())
+        .wasm_plugin(plugin.clone())
+        .build()
+        .expect("reader");
+
+    for timestamp in 1..=6 {
+        writer
+            .write(&Temperature {
+                value: 20.0,
+                timestamp,
+            })
+            .expect("write");
+    }
+    assert_eq!(timestamps(&reader), vec![2, 4, 6]);
+    assert_eq!(plugin.failures(), 0);
+}
+
+#[test]
+fn test_writer_transform_plugin_reload() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let participant = participant("wasm_writer_transform");
+    let plugin = participant
+        .load_wasm_plugin(
+            plugin_file(&dir, "double.wat", DOUBLE_TIMESTAMP),
+            WasmLimits::default(),
+        )
+        .expect("plugin");
+
+    let topic = participant
+        .topic::<Temperature>("WasmWriterTransformTopic")
+        .expect("topic");
+    let writer = topic
+        .writer()
+        .qos(QoS::reliable())
+        .wasm_plugin(plugin.clone())
+        .build()
+        .expect("writer");
+    let reader = topic.reader().qos(QoS::reliable()).build().expect("reader");
+
+    let write = |timestamp| {
+        writer
+            .write(&Temperature {
+                value: 20.0,
+                timestamp,
+            })
+            .expect("write");
+    };
+    for timestamp in 1..=3 {
+        write(timestamp);
+    }
+    assert_eq!(timestamps(&reader), vec![2, 4, 6]);
+
+    // Deploy a new plugin to the running writer
+    plugin
+        .reload_file(plugin_file(&dir, "even.wat", EVEN_TIMESTAMPS))
+        .expect("reload");
+    for timestamp in 1..=4 {
+        write(timestamp);
+    }
+    assert_eq!(timestamps(&reader), vec![2, 4]);
+}
@@ -0,0 +1,80 @@
+#!/usr/bin/env bash
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+# Copyright (c) 2025-2026 naskel.com
+#
+# Binary size tracking for the hdds crate.
+# Builds the basic_pubsub example with the `release-size` profile for each
+# feature profile, reports the stripped size and compares it with the
+# baseline in scripts/size-baseline.txt.
+#
+# Usage:
+#   ./scripts/size-report.sh            # report, fail on regression
+#   ./scripts/size-report.sh --update   # rewrite the baseline
+#
+# Environment:
+#   SIZE_TOLERANCE_PCT   allowed growth over the baseline (default: 5)
+#
+# Feature profiles:
+#   default   hdds default features
+#   minimal   --no-default-features (no Discovery Server, XTypes TypeObjects,
+#             dynamic types, telemetry exporter or QoS file loaders)
+
+set -euo pipefail
+
+UPDATE=false
+for arg in "$@"; do
+    [ "$arg" = "--update" ] && UPDATE=true
+done
+
+SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
+PROJECT_DIR="$(cd "$SCRIPT_DIR/.." && pwd)"
+BASELINE="$SCRIPT_DIR/size-baseline.txt"
+TOLERANCE="${SIZE_TOLERANCE_PCT:-5}"
+EXAMPLE="basic_pubsub"
+
+declare -A PROFILE_ARGS=(
+    [default]=""
+    [minimal]="--no-default-features"
+)
+PROFILES=(default minimal)
+
+declare -A SIZES
+for profile in "${PROFILES[@]}"; do
+    target_dir="$PROJECT_DIR/target/size/$profile"
+    echo "Building $EXAMPLE ($profile)..."
+    # shellcheck disable=SC2086
+    cargo build -p hdds --profile release-size --example "$EXAMPLE" \
+        ${PROFILE_ARGS[$profile]} \
+        --manifest-path "$PROJECT_DIR/Cargo.toml" --target-dir "$target_dir" --quiet
+    SIZES[$profile]=$(wc -c < "$target_dir/release-size/examples/$EXAMPLE")
+done
+
+if [ "$UPDATE" = true ]; then
+    {
+        echo "# Stripped size in bytes of examples/$EXAMPLE (profile release-size)."
+        echo "# Regenerate with: ./scripts/size-report.sh --update"
+        for profile in "${PROFILES[@]}"; do
+            echo "$profile ${SIZES[$profile]}"
+        done
+    } > "$BASELINE"
+    echo "Baseline written to $BASELINE"
+fi
+
+status=0
+printf "\n%-10s %12s %12s %8s\n" "profile" "bytes" "baseline" "delta"
+for profile in "${PROFILES[@]}"; do
+    size=${SIZES[$profile]}
+    base=$(awk -v p="$profile" '$1 == p { print $2 }' "$BASELINE" 2>/dev/null || true)
+    if [ -z "$base" ]; then
+        printf "%-10s %12d %12s %8s\n" "$profile" "$size" "-" "-"
+        continue
+    fi
+    delta=$(awk -v s="$size" -v b="$base" 'BEGIN { printf "%+.1f%%", (s - b) * 100 / b }')
+    printf "%-10s %12d %12d %8s\n" "$profile" "$size" "$base" "$delta"
+    if awk -v s="$size" -v b="$base" -v t="$TOLERANCE" 'BEGIN { exit !(s > b * (1 + t / 100)) }'; then
+        echo "ERROR: $profile grew more than ${TOLERANCE}% over the baseline" >&2
+        status=1
+    fi
+done
+
+exit $status
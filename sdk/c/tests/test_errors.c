@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+/**
+ * HDDS Test: Last Error Message
+ *
+ * Tests:
+ *   - No message before any failure
+ *   - A failing call records its message
+ *   - Successful calls leave the message in place
+ *   - Clearing forgets the message
+ *   - The message is per thread
+ */
+
+#include <hdds.h>
+#include <assert.h>
+#include <pthread.h>
+#include <stdio.h>
+#include <string.h>
+
+static int passed = 0;
+static int failed = 0;
+
+#define RUN_TEST(fn) do {          \
+    printf("  %-50s", #fn);        \
+    fn();                          \
+    printf("[PASS]\n");            \
+    passed++;                      \
+} while (0)
+
+/* ---- Helpers ---- */
+
+static struct HddsTypeDescriptor *point_type(void) {
+    struct HddsTypeBuilder *b = hdds_type_builder_new("Point");
+    assert(b != NULL);
+    assert(hdds_type_builder_add_field(b, "x", HDDS_PRIM_I32) == HDDS_OK);
+    struct HddsTypeDescriptor *desc = hdds_type_builder_build(b);
+    assert(desc != NULL);
+    return desc;
+}
+
+/* Fails: Point has no "speed" field */
+static void fail_unknown_field(struct HddsDynamicData *data) {
+    assert(hdds_dynamic_data_set_i32(data, "speed", 42) == HDDS_INVALID_ARGUMENT);
+}
+
+/* ---- Tests ---- */
+
+static void test_no_error_initially(void) {
+    hdds_clear_last_error();
+    assert(hdds_last_error_message() == NULL);
+}
+
+static void test_failure_records_message(void) {
+    struct HddsTypeDescriptor *desc = point_type();
+    struct HddsDynamicData *data = hdds_dynamic_data_new(desc);
+    assert(data != NULL);
+
+    fail_unknown_field(data);
+    const char *message = hdds_last_error_message();
+    assert(message != NULL);
+    assert(strstr(message, "speed") != NULL);
+
+    hdds_dynamic_data_destroy(data);
+    hdds_type_descriptor_destroy(desc);
+}
+
+static void test_success_keeps_message(void) {
+    struct HddsTypeDescriptor *desc = point_type();
+    struct HddsDynamicData *data = hdds_dynamic_data_new(desc);
+    assert(data != NULL);
+
+    fail_unknown_field(data);
+    assert(hdds_dynamic_data_set_i32(data, "x", 7) == HDDS_OK);
+    const char *message = hdds_last_error_message();
+    assert(message != NULL);
+    assert(strstr(message, "speed") != NULL);
+
+    hdds_dynamic_data_destroy(data);
+    hdds_type_descriptor_destroy(desc);
+}
+
+static void test_clear_forgets_message(void) {
+    struct HddsTypeDescriptor *desc = point_type();
+    struct HddsDynamicData *data = hdds_dynamic_data_new(desc);
+    assert(data != NULL);
+
+    fail_unknown_field(data);
+    hdds_clear_last_error();
+    assert(hdds_last_error_message() == NULL);
+
+    hdds_dynamic_data_destroy(data);
+    hdds_type_descriptor_destroy(desc);
+}
+
+static void *read_last_error(void *arg) {
+    (void)arg;
+    return (void *)hdds_last_error_message();
+}
+
+static void test_message_is_per_thread(void) {
+    struct HddsTypeDescriptor *desc = point_type();
+    struct HddsDynamicData *data = hdds_dynamic_data_new(desc);
+    assert(data != NULL);
+
+    fail_unknown_field(data);
+
+    pthread_t thread;
+    void *other = (void *)1;
+    assert(pthread_create(&thread, NULL, read_last_error, NULL) == 0);
+    assert(pthread_join(thread, &other) == 0);
+    assert(other == NULL);
+    assert(hdds_last_error_message() != NULL);
+
+    hdds_dynamic_data_destroy(data);
+    hdds_type_descriptor_destroy(desc);
+}
+
+/* ---- Main ---- */
+
+int main(void) {
+    printf("test_errors\n");
+
+    RUN_TEST(test_no_error_initially);
+    RUN_TEST(test_failure_records_message);
+    RUN_TEST(test_success_keeps_message);
+    RUN_TEST(test_clear_forgets_message);
+    RUN_TEST(test_message_is_per_thread);
+
+    printf("\nResults: %d passed, %d failed\n", passed, failed);
+    return failed > 0 ? 1 : 0;
+}
@@ -19,6 +19,7 @@
 
 /* List of test executables to run (must be in the same directory) */
 static const char *test_suites[] = {
+    "./test_errors",
     "./test_participant",
     "./test_qos",
     "./test_waitset",
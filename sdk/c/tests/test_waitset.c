@@ -11,6 +11,7 @@
  *   - Wait with timeout (should timeout with no data)
  *   - Trigger guard condition, verify wait returns
  *   - Multiple conditions attached simultaneously
+ *   - Poll the waitset descriptor (Unix)
  */
 
 #include <hdds.h>
@@ -18,6 +19,10 @@
 #include <stdio.h>
 #include <string.h>
 
+#ifndef _WIN32
+#include <poll.h>
+#endif
+
 static int passed = 0;
 static int failed = 0;
 
@@ -202,6 +207,38 @@ static void test_data_triggers_status_condition(void) {
     hdds_participant_destroy(p);
 }
 
+#ifndef _WIN32
+static void test_poll_waitset_fd(void) {
+    struct HddsWaitSet *ws = hdds_waitset_create();
+    const struct HddsGuardCondition *gc = hdds_guard_condition_create();
+    hdds_waitset_attach_guard_condition(ws, gc);
+
+    struct pollfd pfd = { .fd = hdds_waitset_get_fd(ws), .events = POLLIN };
+    assert(pfd.fd >= 0);
+    assert(poll(&pfd, 1, 0) == 0);
+
+    /* Readable once triggered; a zero-timeout wait collects the condition */
+    hdds_guard_condition_set_trigger(gc, true);
+    assert(poll(&pfd, 1, 1000) == 1);
+
+    const void *triggered[4];
+    size_t count = 0;
+    enum HddsError err = hdds_waitset_wait(ws, 0, triggered, 4, &count);
+    assert(err == HDDS_OK);
+    assert(count == 1 && triggered[0] == (const void *)gc);
+
+    /* Once nothing is triggered, a wait clears the descriptor */
+    hdds_guard_condition_set_trigger(gc, false);
+    err = hdds_waitset_wait(ws, 0, triggered, 4, &count);
+    assert(err == HDDS_OK && count == 0);
+    assert(poll(&pfd, 1, 0) == 0);
+
+    hdds_waitset_detach_condition(ws, (const void *)gc);
+    hdds_guard_condition_release(gc);
+    hdds_waitset_destroy(ws);
+}
+#endif
+
 /* ---- Main ---- */
 
 int main(void) {
@@ -215,6 +252,9 @@ int main(void) {
     RUN_TEST(test_guard_trigger_wakes_wait);
     RUN_TEST(test_multiple_guards);
     RUN_TEST(test_data_triggers_status_condition);
+#ifndef _WIN32
+    RUN_TEST(test_poll_waitset_fd);
+#endif
 
     printf("\nResults: %d passed, %d failed\n", passed, failed);
     return failed > 0 ? 1 : 0;
@@ -53,14 +53,16 @@ std::string version();
 /**
  * @brief Transport mode for participant creation
  *
- * The C++ SDK currently supports IntraProcess and UdpMulticast transports.
- * Additional transports (TCP, QUIC, LowBandwidth) are available through
- * the Rust and C APIs. For TCP/QUIC from C++, use the C FFI directly
+ * The C++ SDK supports IntraProcess, UdpMulticast, Tcp and LoopbackBus transports.
+ * Additional transports (QUIC, LowBandwidth) are available through
+ * the Rust and C APIs. For QUIC from C++, use the C FFI directly
  * or configure transport via the HDDS_TRANSPORT environment variable.
  */
 enum class TransportMode {
     IntraProcess = 0,
     UdpMulticast = 1,
+    Tcp = 2,
+    LoopbackBus = 3,
 };
 
 /**
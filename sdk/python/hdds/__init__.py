@@ -57,6 +57,18 @@ def version() -> str:
     return "unknown"
 
 
+def abi_version() -> tuple:
+    """
+    Get the C ABI version of the loaded native library.
+
+    Returns:
+        ``(major, minor)`` tuple (e.g., ``(1, 0)``)
+    """
+    from ._native import get_lib
+    raw = get_lib().hdds_abi_version()
+    return (raw >> 16, raw & 0xFFFF)
+
+
 __all__ = [
     # Core classes
     "Participant",
@@ -70,6 +82,7 @@ __all__ = [
     "GuardCondition",
     # Functions
     "version",
+    "abi_version",
     # Errors
     "HddsException",
     "HddsError",
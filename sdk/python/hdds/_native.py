@@ -84,6 +84,37 @@ def _load_library() -> ctypes.CDLL:
         )
 
 
+# ABI these bindings were written against (see hdds_abi_version() in hdds.h)
+ABI_VERSION_MAJOR = 1
+ABI_VERSION_MINOR = 0
+
+
+def _check_abi(lib: ctypes.CDLL) -> None:
+    """Reject a library whose C ABI differs from the one these bindings use.
+
+    Struct layouts and signatures only stay stable within an ABI major, so a
+    mismatch is reported here instead of crashing on the first call.
+    """
+    marker = f"hdds_abi_v{ABI_VERSION_MAJOR}"
+    if not hasattr(lib, marker) or not hasattr(lib, "hdds_abi_version"):
+        raise ImportError(
+            f"hdds-c library does not provide C ABI {ABI_VERSION_MAJOR}.x "
+            f"(missing {marker}); rebuild the bindings for this library"
+        )
+    lib.hdds_abi_version.argtypes = []
+    lib.hdds_abi_version.restype = c_uint32
+    lib.hdds_abi_compatible.argtypes = [c_uint32]
+    lib.hdds_abi_compatible.restype = c_bool
+
+    expected = (ABI_VERSION_MAJOR << 16) | ABI_VERSION_MINOR
+    if not lib.hdds_abi_compatible(expected):
+        found = lib.hdds_abi_version()
+        raise ImportError(
+            f"hdds-c library C ABI {found >> 16}.{found & 0xFFFF} is older than "
+            f"{ABI_VERSION_MAJOR}.{ABI_VERSION_MINOR} required by these bindings"
+        )
+
+
 # Load library
 _lib: Optional[ctypes.CDLL] = None
 
@@ -98,12 +129,15 @@ def get_lib() -> ctypes.CDLL:
         The loaded ctypes.CDLL handle.
 
     Raises:
-        ImportError: If the hdds-c shared library cannot be found or loaded.
+        ImportError: If the hdds-c shared library cannot be found or loaded,
+            or its C ABI is incompatible with these bindings.
     """
     global _lib
     if _lib is None:
-        _lib = _load_library()
-        _setup_signatures(_lib)
+        lib = _load_library()
+        _check_abi(lib)
+        _setup_signatures(lib)
+        _lib = lib
     return _lib
 
 
@@ -35,6 +35,7 @@ class TransportMode(IntEnum):
     """Transport mode for participant creation."""
     INTRA_PROCESS = 0
     UDP_MULTICAST = 1
+    TCP = 2
 
 
 class Participant:
@@ -55,6 +55,12 @@ public class HelloWorld {
         HDDS = SymbolLookup.loaderLookup();
     }
 
+    // ABI check: only C ABI 1.x libraries export hdds_abi_v1, so an
+    // incompatible libhdds_c is rejected here, when the class loads
+    private static final MethodHandle hdds_abi_v1 = downcall(
+        "hdds_abi_v1",
+        FunctionDescriptor.of(ValueLayout.JAVA_INT));
+
     // Logging
     private static final MethodHandle hdds_logging_init = downcall(
         "hdds_logging_init",
@@ -124,7 +124,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             Ok(samples) => {
                 for sample in samples {
                     event_count += 1;
-                    if let Ok(data) = String::from_utf8(sample.payload) {
+                    if let Ok(data) = String::from_utf8(sample.payload.to_vec()) {
                         println!("[EVENT {}] Received: {}", event_count, data);
                     }
                 }
@@ -118,7 +118,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         match reader.try_take_raw() {
             Ok(samples) => {
                 for sample in samples {
-                    if let Ok(data) = String::from_utf8(sample.payload) {
+                    if let Ok(data) = String::from_utf8(sample.payload.to_vec()) {
                         println!("[RECV] {}", data);
                     }
                 }
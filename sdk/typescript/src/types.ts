@@ -152,6 +152,8 @@ export const TransportMode = {
   INTRA_PROCESS: 0,
   /** UDP multicast for network discovery and communication (default for DDS interop) */
   UDP_MULTICAST: 1,
+  /** RTPS over TCP for discovery and communication (no UDP) */
+  TCP: 2,
 } as const;
 
 export type TransportModeValue = (typeof TransportMode)[keyof typeof TransportMode];
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Joining the domain and waiting on discovery.
+
+use crate::CliResult;
+use hdds::core::discovery::multicast::DiscoveryFsm;
+use hdds::dynamic::{type_descriptor_from_xtypes, TypeDescriptor};
+use hdds::Participant;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Create the tool's participant on `domain`.
+pub fn join(domain: u32) -> CliResult<Arc<Participant>> {
+    Ok(Participant::builder("hdds-cli").domain_id(domain).build()?)
+}
+
+/// The participant's discovery state machine.
+pub fn fsm(participant: &Participant) -> CliResult<Arc<DiscoveryFsm>> {
+    participant
+        .discovery()
+        .ok_or_else(|| "discovery is not running on this participant".into())
+}
+
+/// Sleep for the whole discovery window.
+pub fn settle(wait: Duration) {
+    thread::sleep(wait);
+}
+
+/// Type of `topic` from the TypeObject its endpoints announce.
+///
+/// Polls until one is found or `wait` elapses. `Ok(None)` means the topic
+/// was seen but no endpoint announced a TypeObject.
+pub fn topic_type(
+    participant: &Participant,
+    topic: &str,
+    wait: Duration,
+) -> CliResult<Option<(String, Arc<TypeDescriptor>)>> {
+    let deadline = Instant::now() + wait;
+    let mut type_name = None;
+    loop {
+        if let Some(info) = participant
+            .discover_topics()?
+            .into_iter()
+            .find(|t| t.name == topic)
+        {
+            if let Some(type_object) = &info.type_object {
+                return Ok(Some((
+                    info.type_name,
+                    type_descriptor_from_xtypes(type_object),
+                )));
+            }
+            type_name = Some(info.type_name);
+        }
+        if Instant::now() >= deadline {
+            break;
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+    match type_name {
+        Some(name) => {
+            eprintln!(
+                "warning: no endpoint of '{}' announced a TypeObject for '{}'",
+                topic, name
+            );
+            Ok(None)
+        }
+        None => Err(format!("topic '{}' not discovered within {:?}", topic, wait).into()),
+    }
+}
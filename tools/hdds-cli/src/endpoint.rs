@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! `hdds endpoint info`.
+
+use crate::{discovery, CliResult};
+use hdds::core::discovery::multicast::fsm::EndpointInfo;
+use hdds::Participant;
+use std::time::Duration;
+
+pub fn info(participant: &Participant, wait: Duration, topic: Option<&str>) -> CliResult {
+    let fsm = discovery::fsm(participant)?;
+    discovery::settle(wait);
+
+    let mut topics: Vec<_> = fsm
+        .get_all_topics()
+        .into_iter()
+        .filter(|(name, _)| topic.is_none_or(|t| t == name))
+        .collect();
+    if topics.is_empty() {
+        match topic {
+            Some(t) => return Err(format!("no endpoints discovered for '{}'", t).into()),
+            None => {
+                eprintln!(
+                    "no endpoints discovered on domain {}",
+                    participant.domain_id()
+                );
+                return Ok(());
+            }
+        }
+    }
+    topics.sort_by(|a, b| a.0.cmp(&b.0));
+
+    for (i, (name, (writers, readers))) in topics.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!("{}", name);
+        for endpoint in writers {
+            print_endpoint("writer", endpoint);
+        }
+        for endpoint in readers {
+            print_endpoint("reader", endpoint);
+        }
+    }
+    Ok(())
+}
+
+fn print_endpoint(kind: &str, endpoint: &EndpointInfo) {
+    let qos = &endpoint.qos;
+    println!("  {} {}", kind, endpoint.endpoint_guid);
+    println!("    type:        {}", endpoint.type_name);
+    println!(
+        "    typeobject:  {}",
+        if endpoint.type_object.is_some() {
+            "yes"
+        } else {
+            "no"
+        }
+    );
+    println!("    reliability: {:?}", qos.reliability);
+    println!("    durability:  {:?}", qos.durability);
+    println!("    history:     {:?}", qos.history);
+    if !qos.partition.names.is_empty() {
+        println!("    partitions:  {}", qos.partition.names.join(","));
+    }
+}
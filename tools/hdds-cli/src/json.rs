@@ -0,0 +1,392 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! JSON <-> `DynamicValue` conversion, driven by the type descriptor.
+//!
+//! Samples are rendered as compact JSON with struct fields in declaration
+//! order. Enums use their variant name, unions are `{"case": value}` and
+//! `long double` is the hex string of its raw bytes.
+
+use hdds::dynamic::{DynamicData, DynamicValue, PrimitiveKind, TypeDescriptor, TypeKind};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Render `value` as compact JSON.
+pub fn to_json(value: &DynamicValue, desc: &TypeDescriptor) -> String {
+    let mut out = String::new();
+    write_value(&mut out, value, &desc.kind);
+    out
+}
+
+/// Parse `json` into a value of type `desc`.
+///
+/// Missing struct fields take their default (or stay unset when optional);
+/// unknown fields and out-of-range numbers are errors.
+pub fn from_json(json: &Value, desc: &TypeDescriptor) -> Result<DynamicValue, String> {
+    parse_value(json, &desc.kind, "$")
+}
+
+fn write_value(out: &mut String, value: &DynamicValue, kind: &TypeKind) {
+    match (value, kind) {
+        (_, TypeKind::Nested(inner)) => write_value(out, value, &inner.kind),
+        (DynamicValue::Struct(fields), TypeKind::Struct(descs)) => {
+            out.push('{');
+            let mut first = true;
+            for field in descs {
+                let Some(v) = fields.get(&field.name) else {
+                    continue;
+                };
+                if field.optional && matches!(v, DynamicValue::Null) {
+                    continue;
+                }
+                if !first {
+                    out.push(',');
+                }
+                first = false;
+                out.push_str(&Value::from(field.name.as_str()).to_string());
+                out.push(':');
+                write_value(out, v, &field.type_desc.kind);
+            }
+            out.push('}');
+        }
+        (DynamicValue::Sequence(items), TypeKind::Sequence(seq)) => {
+            write_items(out, items, &seq.element_type.kind);
+        }
+        (DynamicValue::Array(items), TypeKind::Array(arr)) => {
+            write_items(out, items, &arr.element_type.kind);
+        }
+        (DynamicValue::Union(_, case, inner), TypeKind::Union(union)) => {
+            out.push('{');
+            out.push_str(&Value::from(case.as_str()).to_string());
+            out.push(':');
+            match union
+                .cases
+                .iter()
+                .chain(union.default_case.as_deref())
+                .find(|c| &c.name == case)
+            {
+                Some(c) => write_value(out, inner, &c.type_desc.kind),
+                None => out.push_str(&untyped(inner).to_string()),
+            }
+            out.push('}');
+        }
+        _ => out.push_str(&untyped(value).to_string()),
+    }
+}
+
+fn write_items(out: &mut String, items: &[DynamicValue], kind: &TypeKind) {
+    out.push('[');
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write_value(out, item, kind);
+    }
+    out.push(']');
+}
+
+/// Conversion without a descriptor (struct keys come out sorted).
+fn untyped(value: &DynamicValue) -> Value {
+    match value {
+        DynamicValue::Bool(v) => Value::from(*v),
+        DynamicValue::U8(v) => Value::from(*v),
+        DynamicValue::U16(v) => Value::from(*v),
+        DynamicValue::U32(v) => Value::from(*v),
+        DynamicValue::U64(v) => Value::from(*v),
+        DynamicValue::I8(v) => Value::from(*v),
+        DynamicValue::I16(v) => Value::from(*v),
+        DynamicValue::I32(v) => Value::from(*v),
+        DynamicValue::I64(v) => Value::from(*v),
+        // Non-finite floats have no JSON form and become null. An f32 goes
+        // through its shortest decimal form so 0.1 does not print as
+        // 0.10000000149011612.
+        DynamicValue::F32(v) => Value::from(v.to_string().parse::<f64>().unwrap_or(f64::NAN)),
+        DynamicValue::F64(v) => Value::from(*v),
+        DynamicValue::LongDouble(bytes) => Value::from(hex(bytes)),
+        DynamicValue::Char(c) => Value::from(c.to_string()),
+        DynamicValue::String(s) | DynamicValue::WString(s) => Value::from(s.as_str()),
+        DynamicValue::Struct(fields) => Value::Object(
+            fields
+                .iter()
+                .map(|(name, v)| (name.clone(), untyped(v)))
+                .collect(),
+        ),
+        DynamicValue::Sequence(items) | DynamicValue::Array(items) => {
+            Value::Array(items.iter().map(untyped).collect())
+        }
+        DynamicValue::Enum(_, name) => Value::from(name.as_str()),
+        DynamicValue::Union(_, case, inner) => {
+            Value::Object([(case.clone(), untyped(inner))].into_iter().collect())
+        }
+        DynamicValue::Null => Value::Null,
+    }
+}
+
+fn parse_value(json: &Value, kind: &TypeKind, path: &str) -> Result<DynamicValue, String> {
+    match kind {
+        TypeKind::Primitive(p) => parse_primitive(json, *p, path),
+        TypeKind::Nested(inner) => parse_value(json, &inner.kind, path),
+        TypeKind::Struct(fields) => {
+            let obj = json
+                .as_object()
+                .ok_or_else(|| expected(path, "an object", json))?;
+            if let Some(unknown) = obj.keys().find(|k| !fields.iter().any(|f| &f.name == *k)) {
+                return Err(format!("{}: unknown field '{}'", path, unknown));
+            }
+            let mut map = HashMap::with_capacity(fields.len());
+            for field in fields {
+                let value = match obj.get(&field.name) {
+                    Some(Value::Null) if field.optional => DynamicValue::Null,
+                    Some(v) => parse_value(
+                        v,
+                        &field.type_desc.kind,
+                        &format!("{}.{}", path, field.name),
+                    )?,
+                    None if field.optional => DynamicValue::Null,
+                    None => DynamicData::new(&field.type_desc).value().clone(),
+                };
+                map.insert(field.name.clone(), value);
+            }
+            Ok(DynamicValue::Struct(map))
+        }
+        TypeKind::Sequence(seq) => {
+            let items = json
+                .as_array()
+                .ok_or_else(|| expected(path, "an array", json))?;
+            if let Some(max) = seq.max_length {
+                if items.len() > max {
+                    return Err(format!(
+                        "{}: {} elements exceed the bound of {}",
+                        path,
+                        items.len(),
+                        max
+                    ));
+                }
+            }
+            Ok(DynamicValue::Sequence(parse_items(
+                items,
+                &seq.element_type.kind,
+                path,
+            )?))
+        }
+        TypeKind::Array(arr) => {
+            let items = json
+                .as_array()
+                .ok_or_else(|| expected(path, "an array", json))?;
+            if items.len() != arr.length {
+                return Err(format!(
+                    "{}: expected {} elements, got {}",
+                    path,
+                    arr.length,
+                    items.len()
+                ));
+            }
+            Ok(DynamicValue::Array(parse_items(
+                items,
+                &arr.element_type.kind,
+                path,
+            )?))
+        }
+        TypeKind::Enum(e) => {
+            let variant = match json {
+                Value::String(name) => e.variants.iter().find(|v| &v.name == name),
+                Value::Number(n) => n
+                    .as_i64()
+                    .and_then(|value| e.variants.iter().find(|v| v.value == value)),
+                _ => return Err(expected(path, "an enum name or value", json)),
+            };
+            let variant =
+                variant.ok_or_else(|| format!("{}: {} is not an enumerator", path, json))?;
+            Ok(DynamicValue::Enum(variant.value, variant.name.clone()))
+        }
+        TypeKind::Union(union) => {
+            let (case_name, inner) = match json.as_object() {
+                Some(obj) if obj.len() == 1 => obj.iter().next().expect("one entry"),
+                _ => return Err(expected(path, "an object with a single case", json)),
+            };
+            let case = union
+                .cases
+                .iter()
+                .chain(union.default_case.as_deref())
+                .find(|c| &c.name == case_name)
+                .ok_or_else(|| format!("{}: unknown union case '{}'", path, case_name))?;
+            let value = parse_value(
+                inner,
+                &case.type_desc.kind,
+                &format!("{}.{}", path, case_name),
+            )?;
+            let discriminator = case.labels.first().copied().unwrap_or(0);
+            Ok(DynamicValue::Union(
+                discriminator,
+                case.name.clone(),
+                Box::new(value),
+            ))
+        }
+    }
+}
+
+fn parse_items(items: &[Value], kind: &TypeKind, path: &str) -> Result<Vec<DynamicValue>, String> {
+    items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| parse_value(item, kind, &format!("{}[{}]", path, i)))
+        .collect()
+}
+
+fn parse_primitive(json: &Value, kind: PrimitiveKind, path: &str) -> Result<DynamicValue, String> {
+    macro_rules! int {
+        ($variant:ident, $ty:ty) => {{
+            let value = json
+                .as_i64()
+                .and_then(|v| <$ty>::try_from(v).ok())
+                .or_else(|| json.as_u64().and_then(|v| <$ty>::try_from(v).ok()))
+                .ok_or_else(|| expected(path, stringify!($ty), json))?;
+            DynamicValue::$variant(value)
+        }};
+    }
+
+    let value = match kind {
+        PrimitiveKind::Bool => DynamicValue::Bool(
+            json.as_bool()
+                .ok_or_else(|| expected(path, "a boolean", json))?,
+        ),
+        PrimitiveKind::U8 => int!(U8, u8),
+        PrimitiveKind::U16 => int!(U16, u16),
+        PrimitiveKind::U32 => int!(U32, u32),
+        PrimitiveKind::U64 => int!(U64, u64),
+        PrimitiveKind::I8 => int!(I8, i8),
+        PrimitiveKind::I16 => int!(I16, i16),
+        PrimitiveKind::I32 => int!(I32, i32),
+        PrimitiveKind::I64 => int!(I64, i64),
+        PrimitiveKind::F32 => DynamicValue::F32(
+            json.as_f64()
+                .ok_or_else(|| expected(path, "a number", json))? as f32,
+        ),
+        PrimitiveKind::F64 => DynamicValue::F64(
+            json.as_f64()
+                .ok_or_else(|| expected(path, "a number", json))?,
+        ),
+        PrimitiveKind::LongDouble => {
+            let text = json
+                .as_str()
+                .ok_or_else(|| expected(path, "a hex string", json))?;
+            let mut bytes = [0u8; hdds::dynamic::LONG_DOUBLE_SIZE];
+            if text.len() != bytes.len() * 2 {
+                return Err(expected(path, "a hex string", json));
+            }
+            for (i, byte) in bytes.iter_mut().enumerate() {
+                *byte = u8::from_str_radix(&text[i * 2..i * 2 + 2], 16)
+                    .map_err(|_| expected(path, "a hex string", json))?;
+            }
+            DynamicValue::LongDouble(bytes)
+        }
+        PrimitiveKind::Char => {
+            let mut chars = json.as_str().unwrap_or_default().chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => DynamicValue::Char(c),
+                _ => return Err(expected(path, "a single character", json)),
+            }
+        }
+        PrimitiveKind::String { max_length } | PrimitiveKind::WString { max_length } => {
+            let text = json
+                .as_str()
+                .ok_or_else(|| expected(path, "a string", json))?;
+            if let Some(max) = max_length {
+                if text.chars().count() > max {
+                    return Err(format!("{}: string longer than its bound of {}", path, max));
+                }
+            }
+            if matches!(kind, PrimitiveKind::WString { .. }) {
+                DynamicValue::WString(text.to_string())
+            } else {
+                DynamicValue::String(text.to_string())
+            }
+        }
+    };
+    Ok(value)
+}
+
+fn expected(path: &str, what: &str, got: &Value) -> String {
+    format!("{}: expected {}, got {}", path, what, got)
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hdds::dynamic::{EnumBuilder, SequenceBuilder, TypeDescriptorBuilder, UnionBuilder};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    fn pose() -> TypeDescriptor {
+        let mode = Arc::new(
+            EnumBuilder::new("Mode")
+                .variant("IDLE")
+                .variant("MOVING")
+                .build(),
+        );
+        let tags = Arc::new(SequenceBuilder::of_primitive("Tags", PrimitiveKind::U16).build());
+        let target = Arc::new(
+            UnionBuilder::with_u32_discriminator("Target")
+                .primitive_case("id", 1, PrimitiveKind::U32)
+                .primitive_case("name", 2, PrimitiveKind::String { max_length: None })
+                .build(),
+        );
+        TypeDescriptorBuilder::new("Pose")
+            .string_field("frame")
+            .field("x", PrimitiveKind::F64)
+            .field("seq", PrimitiveKind::I32)
+            .nested_field("mode", mode)
+            .nested_field("tags", tags)
+            .nested_field("target", target)
+            .optional_field("note", PrimitiveKind::U8)
+            .build()
+    }
+
+    #[test]
+    fn test_round_trip_keeps_field_order() {
+        let desc = pose();
+        let input = json!({
+            "frame": "map",
+            "x": 1.5,
+            "seq": -3,
+            "mode": "MOVING",
+            "tags": [1, 2],
+            "target": {"name": "dock"}
+        });
+
+        let value = from_json(&input, &desc).unwrap();
+        assert_eq!(
+            to_json(&value, &desc),
+            r#"{"frame":"map","x":1.5,"seq":-3,"mode":"MOVING","tags":[1,2],"target":{"name":"dock"}}"#
+        );
+    }
+
+    #[test]
+    fn test_missing_fields_take_defaults() {
+        let desc = pose();
+        let value = from_json(&json!({"seq": 7}), &desc).unwrap();
+        let DynamicValue::Struct(fields) = &value else {
+            panic!("expected a struct");
+        };
+        assert_eq!(fields["frame"], DynamicValue::String(String::new()));
+        assert_eq!(fields["mode"], DynamicValue::Enum(0, "IDLE".to_string()));
+        assert_eq!(fields["note"], DynamicValue::Null);
+    }
+
+    #[test]
+    fn test_rejects_bad_input_with_path() {
+        let desc = pose();
+        let err = from_json(&json!({"tags": [1, 70000]}), &desc).unwrap_err();
+        assert_eq!(err, "$.tags[1]: expected u16, got 70000");
+
+        let err = from_json(&json!({"speed": 1}), &desc).unwrap_err();
+        assert_eq!(err, "$: unknown field 'speed'");
+
+        let err = from_json(&json!({"mode": "FLYING"}), &desc).unwrap_err();
+        assert_eq!(err, "$.mode: \"FLYING\" is not an enumerator");
+    }
+}
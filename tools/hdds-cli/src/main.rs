@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! hdds - command-line tool for DDS domains
+//!
+//! Joins a domain, waits for discovery, then lists topics, participants and
+//! endpoints, or echoes and publishes samples as JSON. Sample types come
+//! from the XTypes TypeObjects announced during discovery.
+
+mod discovery;
+mod endpoint;
+mod json;
+mod participant;
+mod topic;
+
+use clap::{Parser, Subcommand};
+use std::time::Duration;
+
+type CliResult<T = ()> = Result<T, Box<dyn std::error::Error>>;
+
+/// Command-line tool for DDS domains
+#[derive(Parser, Debug)]
+#[command(name = "hdds")]
+#[command(version = "0.1.0")]
+#[command(about = "Inspect and exercise a DDS domain")]
+struct Cli {
+    /// DDS domain ID
+    #[arg(short, long, default_value = "0", global = true)]
+    domain: u32,
+
+    /// Seconds to wait for discovery before answering
+    #[arg(short, long, default_value = "2", global = true)]
+    wait: f64,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Topics in the domain
+    #[command(subcommand)]
+    Topic(TopicCommand),
+    /// Remote participants
+    #[command(subcommand)]
+    Participant(ParticipantCommand),
+    /// Remote writers and readers
+    #[command(subcommand)]
+    Endpoint(EndpointCommand),
+}
+
+#[derive(Subcommand, Debug)]
+enum TopicCommand {
+    /// List discovered topics with their type and endpoint counts
+    List,
+    /// Print samples of a topic as JSON, one per line
+    Echo {
+        /// Topic name
+        topic: String,
+        /// Stop after this many samples (0 = until Ctrl+C)
+        #[arg(short = 'n', long, default_value = "0")]
+        count: u64,
+        /// Subscribe best-effort instead of reliable
+        #[arg(long)]
+        best_effort: bool,
+    },
+    /// Publish a JSON sample on a topic
+    Pub {
+        /// Topic name
+        topic: String,
+        /// Sample as JSON (missing fields take their default)
+        sample: String,
+        /// Number of samples to publish (0 = until Ctrl+C)
+        #[arg(short = 'n', long, default_value = "1")]
+        count: u64,
+        /// Publish rate in Hz
+        #[arg(short, long, default_value = "1")]
+        rate: f64,
+        /// Publish best-effort instead of reliable
+        #[arg(long)]
+        best_effort: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum ParticipantCommand {
+    /// List discovered participants
+    List,
+}
+
+#[derive(Subcommand, Debug)]
+enum EndpointCommand {
+    /// Show writers and readers, optionally for one topic
+    Info {
+        /// Only show endpoints of this topic
+        topic: Option<String>,
+    },
+}
+
+fn main() {
+    let cli = Cli::parse();
+    if let Err(e) = run(cli) {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+fn run(cli: Cli) -> CliResult {
+    if !cli.wait.is_finite() || cli.wait < 0.0 {
+        return Err("--wait must be a non-negative number of seconds".into());
+    }
+    let wait = Duration::from_secs_f64(cli.wait);
+    let participant = discovery::join(cli.domain)?;
+
+    match cli.command {
+        Command::Topic(TopicCommand::List) => topic::list(&participant, wait),
+        Command::Topic(TopicCommand::Echo {
+            topic,
+            count,
+            best_effort,
+        }) => topic::echo(&participant, wait, &topic, count, best_effort),
+        Command::Topic(TopicCommand::Pub {
+            topic,
+            sample,
+            count,
+            rate,
+            best_effort,
+        }) => topic::publish(
+            &participant,
+            wait,
+            &topic,
+            &sample,
+            count,
+            rate,
+            best_effort,
+        ),
+        Command::Participant(ParticipantCommand::List) => participant::list(&participant, wait),
+        Command::Endpoint(EndpointCommand::Info { topic }) => {
+            endpoint::info(&participant, wait, topic.as_deref())
+        }
+    }
+}
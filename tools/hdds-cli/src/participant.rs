@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! `hdds participant list`.
+
+use crate::{discovery, CliResult};
+use hdds::Participant;
+use std::time::Duration;
+
+pub fn list(participant: &Participant, wait: Duration) -> CliResult {
+    let fsm = discovery::fsm(participant)?;
+    discovery::settle(wait);
+
+    let own = participant.guid().prefix;
+    let mut participants: Vec<_> = fsm
+        .get_participants()
+        .into_iter()
+        .filter(|p| p.guid.prefix != own)
+        .collect();
+    if participants.is_empty() {
+        eprintln!(
+            "no participants discovered on domain {}",
+            participant.domain_id()
+        );
+        return Ok(());
+    }
+    participants.sort_by_key(|p| p.guid.prefix);
+
+    println!(
+        "{:<47}  {:<10}  {:>8}  {:>9}  LOCATORS",
+        "GUID PREFIX", "STATE", "LEASE", "LAST SEEN"
+    );
+    for p in &participants {
+        let prefix = p
+            .guid
+            .prefix
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<Vec<_>>()
+            .join(".");
+        let locators = p
+            .endpoints
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        println!(
+            "{:<47}  {:<10}  {:>7}s  {:>8.1}s  {}",
+            prefix,
+            format!("{:?}", p.state),
+            p.lease_duration_ms / 1000,
+            p.last_seen.elapsed().as_secs_f64(),
+            locators
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,172 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! `hdds topic list | echo | pub`.
+
+use crate::{discovery, json, CliResult};
+use hdds::dynamic::DynamicData;
+use hdds::{Participant, QoS};
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub fn list(participant: &Participant, wait: Duration) -> CliResult {
+    discovery::settle(wait);
+    let mut topics = participant.discover_topics()?;
+    if topics.is_empty() {
+        eprintln!("no topics discovered on domain {}", participant.domain_id());
+        return Ok(());
+    }
+    topics.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let name_width = topics
+        .iter()
+        .map(|t| t.name.len())
+        .max()
+        .unwrap_or(0)
+        .max(5);
+    let type_width = topics
+        .iter()
+        .map(|t| t.type_name.len())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+    println!(
+        "{:<nw$}  {:<tw$}  {:>4}  {:>4}  TYPEOBJECT",
+        "TOPIC",
+        "TYPE",
+        "PUB",
+        "SUB",
+        nw = name_width,
+        tw = type_width
+    );
+    for t in &topics {
+        println!(
+            "{:<nw$}  {:<tw$}  {:>4}  {:>4}  {}",
+            t.name,
+            t.type_name,
+            t.publisher_count,
+            t.subscriber_count,
+            if t.type_object.is_some() { "yes" } else { "no" },
+            nw = name_width,
+            tw = type_width
+        );
+    }
+    Ok(())
+}
+
+pub fn echo(
+    participant: &Arc<Participant>,
+    wait: Duration,
+    topic: &str,
+    count: u64,
+    best_effort: bool,
+) -> CliResult {
+    let running = stop_flag()?;
+    let qos = qos(best_effort);
+    let mut received = 0u64;
+    let mut out = io::stdout().lock();
+
+    match discovery::topic_type(participant, topic, wait)? {
+        Some((_, desc)) => {
+            let reader = participant.create_dynamic_reader(topic, &desc, Some(qos))?;
+            while running.load(Ordering::SeqCst) && (count == 0 || received < count) {
+                match reader.take() {
+                    Ok(Some(sample)) => {
+                        writeln!(out, "{}", json::to_json(sample.value(), &desc))?;
+                        out.flush()?;
+                        received += 1;
+                    }
+                    Ok(None) => thread::sleep(Duration::from_millis(10)),
+                    Err(e) => eprintln!("warning: sample dropped: {}", e),
+                }
+            }
+        }
+        None => {
+            eprintln!("printing payloads as hex");
+            let reader = participant.create_raw_reader(topic, Some(qos))?;
+            while running.load(Ordering::SeqCst) && (count == 0 || received < count) {
+                let samples = reader.try_take_raw()?;
+                if samples.is_empty() {
+                    thread::sleep(Duration::from_millis(10));
+                }
+                for sample in samples {
+                    if count != 0 && received >= count {
+                        break;
+                    }
+                    let hex: String = sample
+                        .payload
+                        .iter()
+                        .map(|b| format!("{:02x}", b))
+                        .collect();
+                    writeln!(out, "{}", hex)?;
+                    received += 1;
+                }
+                out.flush()?;
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn publish(
+    participant: &Arc<Participant>,
+    wait: Duration,
+    topic: &str,
+    sample: &str,
+    count: u64,
+    rate: f64,
+    best_effort: bool,
+) -> CliResult {
+    if !rate.is_finite() || rate <= 0.0 {
+        return Err("--rate must be a positive number".into());
+    }
+    let input: serde_json::Value =
+        serde_json::from_str(sample).map_err(|e| format!("sample is not valid JSON: {}", e))?;
+
+    let (type_name, desc) = discovery::topic_type(participant, topic, wait)?.ok_or_else(|| {
+        format!(
+            "cannot publish on '{}' without the type: no TypeObject was announced",
+            topic
+        )
+    })?;
+    let value = json::from_json(&input, &desc)
+        .map_err(|e| format!("sample does not match '{}': {}", type_name, e))?;
+    let data = DynamicData::from_value(&desc, value)?;
+
+    let running = stop_flag()?;
+    let writer = participant.create_dynamic_writer(topic, &desc, Some(qos(best_effort)))?;
+    // Give the remote readers the same window to match the new writer.
+    discovery::settle(wait);
+
+    let period = Duration::from_secs_f64(1.0 / rate);
+    let mut sent = 0u64;
+    while running.load(Ordering::SeqCst) && (count == 0 || sent < count) {
+        let started = Instant::now();
+        writer.write(&data)?;
+        sent += 1;
+        if count == 0 || sent < count {
+            thread::sleep(period.saturating_sub(started.elapsed()));
+        }
+    }
+    eprintln!("published {} sample(s) on '{}'", sent, topic);
+    Ok(())
+}
+
+fn qos(best_effort: bool) -> QoS {
+    if best_effort {
+        QoS::best_effort()
+    } else {
+        QoS::reliable()
+    }
+}
+
+/// Flag cleared by Ctrl+C.
+fn stop_flag() -> Result<Arc<AtomicBool>, ctrlc::Error> {
+    let running = Arc::new(AtomicBool::new(true));
+    let flag = running.clone();
+    ctrlc::set_handler(move || flag.store(false, Ordering::SeqCst))?;
+    Ok(running)
+}
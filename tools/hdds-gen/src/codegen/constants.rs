@@ -0,0 +1,203 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Rust emission for IDL constants and enums.
+//!
+//! Items are grouped by IDL module into nested `pub mod` blocks, so
+//! `module proto { const long MAX_PEERS = 8; };` becomes
+//! `pub mod proto { pub const MAX_PEERS: i32 = 8; }`.
+
+use crate::codegen::types::{has_codec, render_decl, render_enum_codec};
+use hdds_idl::{qualified, ConstSpec, ConstType, ConstValue, EnumSpec, PrimitiveConst, TypeDecl};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+fn const_rust_type(ty: PrimitiveConst) -> &'static str {
+    match ty {
+        PrimitiveConst::I8 => "i8",
+        PrimitiveConst::U8 => "u8",
+        PrimitiveConst::I16 => "i16",
+        PrimitiveConst::U16 => "u16",
+        PrimitiveConst::I32 => "i32",
+        PrimitiveConst::U32 => "u32",
+        PrimitiveConst::I64 => "i64",
+        PrimitiveConst::U64 => "u64",
+        PrimitiveConst::F32 => "f32",
+        PrimitiveConst::F64 => "f64",
+        PrimitiveConst::Bool => "bool",
+        PrimitiveConst::Char => "char",
+    }
+}
+
+/// Inclusive integer range, `None` for non-integer types.
+fn int_range(ty: PrimitiveConst) -> Option<(i128, i128)> {
+    let range = match ty {
+        PrimitiveConst::I8 => (i128::from(i8::MIN), i128::from(i8::MAX)),
+        PrimitiveConst::U8 => (0, i128::from(u8::MAX)),
+        PrimitiveConst::I16 => (i128::from(i16::MIN), i128::from(i16::MAX)),
+        PrimitiveConst::U16 => (0, i128::from(u16::MAX)),
+        PrimitiveConst::I32 => (i128::from(i32::MIN), i128::from(i32::MAX)),
+        PrimitiveConst::U32 => (0, i128::from(u32::MAX)),
+        PrimitiveConst::I64 => (i128::from(i64::MIN), i128::from(i64::MAX)),
+        PrimitiveConst::U64 => (0, i128::from(u64::MAX)),
+        _ => return None,
+    };
+    Some(range)
+}
+
+fn render_const(spec: &ConstSpec) -> Result<String, String> {
+    let (ty, value) = match (&spec.ty, &spec.value) {
+        (ConstType::Primitive(p), value) => (const_rust_type(*p).to_string(), literal(*p, value)?),
+        (ConstType::String, ConstValue::Str(s)) => ("&str".to_string(), format!("{:?}", s)),
+        (ConstType::Enum(path), ConstValue::Enumerator(variant)) => {
+            (path.clone(), format!("{}::{}", path, variant))
+        }
+        (ty, value) => return Err(format!("value {:?} does not fit {:?}", value, ty)),
+    };
+    Ok(format!("pub const {}: {} = {};", spec.name, ty, value))
+}
+
+fn literal(ty: PrimitiveConst, value: &ConstValue) -> Result<String, String> {
+    let out_of_range = || format!("value {:?} out of range for {}", value, const_rust_type(ty));
+    match (ty, value) {
+        (PrimitiveConst::Bool, ConstValue::Bool(b)) => Ok(b.to_string()),
+        (PrimitiveConst::Char, ConstValue::Char(c)) => Ok(format!("{:?}", c)),
+        (PrimitiveConst::F32 | PrimitiveConst::F64, ConstValue::Float(f)) => Ok(float_literal(*f)),
+        #[allow(clippy::cast_precision_loss)]
+        (PrimitiveConst::F32 | PrimitiveConst::F64, ConstValue::Int(n)) => {
+            Ok(float_literal(*n as f64))
+        }
+        (_, ConstValue::Int(n)) => {
+            let (min, max) = int_range(ty).ok_or_else(out_of_range)?;
+            if (min..=max).contains(n) {
+                Ok(n.to_string())
+            } else {
+                Err(out_of_range())
+            }
+        }
+        _ => Err(out_of_range()),
+    }
+}
+
+fn float_literal(f: f64) -> String {
+    let text = format!("{:?}", f);
+    if text.contains(['.', 'e', 'E']) || !f.is_finite() {
+        text
+    } else {
+        format!("{}.0", text)
+    }
+}
+
+fn render_enum(spec: &EnumSpec) -> String {
+    let mut out = String::new();
+    out.push_str("#[allow(non_camel_case_types, clippy::upper_case_acronyms)]\n");
+    out.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]\n");
+    out.push_str("#[repr(u32)]\n");
+    let _ = writeln!(out, "pub enum {} {{", spec.name);
+    for (index, (variant, value)) in spec.variants.iter().enumerate() {
+        if index == 0 {
+            out.push_str("    #[default]\n");
+        }
+        let _ = writeln!(out, "    {} = {},", variant, value);
+    }
+    out.push_str("}\n\n");
+
+    let _ = writeln!(out, "impl {} {{", spec.name);
+    out.push_str("    /// Wire value of the enumerator.\n");
+    out.push_str("    pub const fn value(self) -> u32 {\n        self as u32\n    }\n\n");
+    out.push_str("    /// Enumerator for a wire value.\n");
+    out.push_str("    pub const fn from_value(value: u32) -> Option<Self> {\n");
+    out.push_str("        match value {\n");
+    for (variant, value) in &spec.variants {
+        let _ = writeln!(out, "            {} => Some(Self::{}),", value, variant);
+    }
+    out.push_str("            _ => None,\n        }\n    }\n}");
+    out
+}
+
+/// Items of one IDL module, with its nested modules.
+#[derive(Default)]
+pub(crate) struct ModuleItems<'a> {
+    pub(crate) consts: Vec<&'a ConstSpec>,
+    pub(crate) enums: Vec<&'a EnumSpec>,
+    pub(crate) types: Vec<&'a TypeDecl>,
+    children: BTreeMap<String, ModuleItems<'a>>,
+}
+
+impl<'a> ModuleItems<'a> {
+    pub(crate) fn module(&mut self, namespace: &[String]) -> &mut ModuleItems<'a> {
+        namespace.iter().fold(self, |module, name| {
+            module.children.entry(name.clone()).or_default()
+        })
+    }
+
+    /// Render the module; `codecs` adds the enum codecs and the types.
+    pub(crate) fn render(&self, codecs: bool) -> Result<Vec<String>, String> {
+        let mut blocks = Vec::new();
+        if codecs && (!self.enums.is_empty() || self.types.iter().any(|decl| has_codec(decl))) {
+            blocks.push(
+                "#[allow(unused_imports)]\nuse ::hdds::core::ser::idl::IdlCdr as _;".to_string(),
+            );
+        }
+        for spec in &self.enums {
+            blocks.push(render_enum(spec));
+            if codecs {
+                blocks.push(render_enum_codec(spec));
+            }
+        }
+        for decl in &self.types {
+            blocks.push(render_decl(decl)?);
+        }
+        let consts = self
+            .consts
+            .iter()
+            .map(|spec| {
+                render_const(spec).map_err(|err| {
+                    format!("const {}: {}", qualified(&spec.namespace, &spec.name), err)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if !consts.is_empty() {
+            blocks.push(consts.join("\n"));
+        }
+        for (name, child) in &self.children {
+            let inner = child.render(codecs)?.join("\n\n");
+            blocks.push(format!(
+                "#[allow(non_snake_case)]\npub mod {} {{\n{}\n}}",
+                name,
+                indent(&inner)
+            ));
+        }
+        Ok(blocks)
+    }
+}
+
+pub(crate) fn indent(block: &str) -> String {
+    block
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!("    {line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Emit Rust items for IDL constants and enums, nested by module.
+///
+/// Fails when a constant value does not fit its declared type.
+pub fn emit_module_items(consts: &[ConstSpec], enums: &[EnumSpec]) -> Result<String, String> {
+    let mut root = ModuleItems::default();
+    for spec in enums {
+        root.module(&spec.namespace).enums.push(spec);
+    }
+    for spec in consts {
+        root.module(&spec.namespace).consts.push(spec);
+    }
+    let mut out = root.render(false)?.join("\n\n");
+    out.push('\n');
+    Ok(out)
+}
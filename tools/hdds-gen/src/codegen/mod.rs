@@ -1,8 +1,16 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 // Copyright (c) 2025-2026 naskel.com
 
+pub mod constants;
 pub mod rust_backend;
 pub mod type_hash;
+pub mod types;
 
+pub use constants::emit_module_items;
+pub use hdds_idl::{
+    ConstSpec, ConstType, ConstValue, EnumSpec, Extensibility, IdlType, MemberDef, PrimitiveConst,
+    StructDef, TypeDecl, TypedefDef, UnionCase, UnionDef,
+};
 pub use rust_backend::{emit_type_descriptor, FieldKind, FieldSpec, PrimitiveType, StructSpec};
 pub use type_hash::compute_type_id;
+pub use types::emit_rust_items;
@@ -0,0 +1,615 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! Rust emission for IDL structs, unions and typedefs (`hdds-gen rust`).
+//!
+//! Structs become plain Rust structs implementing `hdds::dds::DDS`, unions
+//! become Rust enums with one variant per case, and typedefs become
+//! `pub type` aliases (members use the aliased type directly). Structs,
+//! unions and enums implement the XCDR1 codec
+//! `hdds::core::ser::idl::IdlCdr` and get a `type_object()` returning their
+//! XTypes `CompleteTypeObject`. Nested types are inlined there
+//! (`TypeIdentifier::Inline`), so an announced TypeObject is self-contained.
+
+use crate::codegen::constants::{indent, ModuleItems};
+use crate::codegen::type_hash::compute_type_id;
+use hdds_idl::{
+    qualified, relative_path, ConstSpec, EnumSpec, Extensibility, IdlType, PrimitiveKind,
+    StructDef, TypeDecl, TypedefDef, UnionDef,
+};
+use std::fmt::Write;
+
+const XTYPES: &str = "::hdds::xtypes";
+const IDL: &str = "::hdds::core::ser::idl";
+const SER: &str = "::hdds::core::ser";
+
+fn extensibility_flag(extensibility: Extensibility) -> &'static str {
+    match extensibility {
+        Extensibility::Final => "IS_FINAL",
+        Extensibility::Appendable => "IS_APPENDABLE",
+        Extensibility::Mutable => "IS_MUTABLE",
+    }
+}
+
+/// Whether the declaration implements `IdlCdr` (needs the trait in scope).
+pub(crate) fn has_codec(decl: &TypeDecl) -> bool {
+    !matches!(decl, TypeDecl::Typedef(_))
+}
+
+pub(crate) fn render_decl(decl: &TypeDecl) -> Result<String, String> {
+    match decl {
+        TypeDecl::Struct(def) => render_struct(def),
+        TypeDecl::Union(def) => render_union(def),
+        TypeDecl::Typedef(def) => render_typedef(def),
+    }
+    .map_err(|err| format!("{}: {}", decl.qualified_name(), err))
+}
+
+/// Rust identifier for an IDL name (raw identifier for Rust keywords).
+fn ident(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do",
+        "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in",
+        "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+        "return", "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe",
+        "unsized", "use", "virtual", "where", "while", "yield",
+    ];
+    if KEYWORDS.contains(&name) {
+        format!("r#{name}")
+    } else {
+        name.to_string()
+    }
+}
+
+/// Rust type, with named types relative to module `from`.
+fn rust_type(ty: &IdlType, from: &[String]) -> Result<String, String> {
+    Ok(match ty {
+        IdlType::Primitive(kind) => primitive_rust_type(*kind)?.to_string(),
+        IdlType::Octet => "u8".to_string(),
+        IdlType::Sequence { element, .. } => format!("Vec<{}>", rust_type(element, from)?),
+        IdlType::Array { element, length } => {
+            format!("[{}; {}]", rust_type(element, from)?, length)
+        }
+        IdlType::Named { namespace, name } => relative_path(from, namespace, name),
+    })
+}
+
+/// XTypes `TypeIdentifier` expression.
+fn type_id(ty: &IdlType, from: &[String]) -> Result<String, String> {
+    Ok(match ty {
+        IdlType::Primitive(PrimitiveKind::String {
+            max_length: Some(bound),
+        }) => format!("{XTYPES}::TypeIdentifier::string({bound})"),
+        IdlType::Primitive(kind) => {
+            format!("{XTYPES}::TypeIdentifier::{}", primitive_type_kind(*kind)?)
+        }
+        IdlType::Octet => format!("{XTYPES}::TypeIdentifier::TK_BYTE"),
+        IdlType::Sequence { element, bound } => format!(
+            "{IDL}::sequence_type_id({}, {})",
+            type_id(element, from)?,
+            bound.unwrap_or(0)
+        ),
+        IdlType::Array { .. } => {
+            let mut dims = Vec::new();
+            let mut element = ty;
+            while let IdlType::Array {
+                element: inner,
+                length,
+            } = element
+            {
+                dims.push(length.to_string());
+                element = inner;
+            }
+            format!(
+                "{IDL}::array_type_id({}, vec![{}])",
+                type_id(element, from)?,
+                dims.join(", ")
+            )
+        }
+        IdlType::Named { namespace, name } => format!(
+            "{XTYPES}::TypeIdentifier::Inline(Box::new({}::type_object()))",
+            relative_path(from, namespace, name)
+        ),
+    })
+}
+
+fn primitive_rust_type(kind: PrimitiveKind) -> Result<&'static str, String> {
+    Ok(match kind {
+        PrimitiveKind::Bool => "bool",
+        PrimitiveKind::U8 => "u8",
+        PrimitiveKind::U16 => "u16",
+        PrimitiveKind::U32 => "u32",
+        PrimitiveKind::U64 => "u64",
+        PrimitiveKind::I8 => "i8",
+        PrimitiveKind::I16 => "i16",
+        PrimitiveKind::I32 => "i32",
+        PrimitiveKind::I64 => "i64",
+        PrimitiveKind::F32 => "f32",
+        PrimitiveKind::F64 => "f64",
+        PrimitiveKind::Char => "char",
+        PrimitiveKind::String { .. } => "String",
+        PrimitiveKind::WChar => return Err("wchar is not supported".into()),
+        PrimitiveKind::LongDouble => return Err("long double is not supported".into()),
+        PrimitiveKind::WString { .. } => return Err("wstring is not supported".into()),
+    })
+}
+
+fn primitive_type_kind(kind: PrimitiveKind) -> Result<&'static str, String> {
+    Ok(match kind {
+        PrimitiveKind::Bool => "TK_BOOLEAN",
+        PrimitiveKind::U8 => "TK_UINT8",
+        PrimitiveKind::U16 => "TK_UINT16",
+        PrimitiveKind::U32 => "TK_UINT32",
+        PrimitiveKind::U64 => "TK_UINT64",
+        PrimitiveKind::I8 => "TK_INT8",
+        PrimitiveKind::I16 => "TK_INT16",
+        PrimitiveKind::I32 => "TK_INT32",
+        PrimitiveKind::I64 => "TK_INT64",
+        PrimitiveKind::F32 => "TK_FLOAT32",
+        PrimitiveKind::F64 => "TK_FLOAT64",
+        PrimitiveKind::Char => "TK_CHAR8",
+        PrimitiveKind::String { .. } => "TK_STRING8",
+        other => return primitive_rust_type(other),
+    })
+}
+
+/// Statements failing when `expr` (or anything nested in it) exceeds its
+/// string or sequence bound. Named types check their own members.
+fn bound_checks(expr: &str, ty: &IdlType, depth: usize) -> Vec<String> {
+    let mut checks = Vec::new();
+    let element = match ty {
+        IdlType::Primitive(PrimitiveKind::String {
+            max_length: Some(bound),
+        }) => {
+            checks.push(format!("{IDL}::check_bound({expr}.len(), {bound})?;"));
+            return checks;
+        }
+        IdlType::Sequence { element, bound } => {
+            if let Some(bound) = bound {
+                checks.push(format!("{IDL}::check_bound({expr}.len(), {bound})?;"));
+            }
+            element
+        }
+        IdlType::Array { element, .. } => element,
+        _ => return checks,
+    };
+    let item = format!("item{depth}");
+    let inner = bound_checks(&item, element, depth + 1);
+    if !inner.is_empty() {
+        checks.push(format!(
+            "for {item} in &{expr} {{\n{}\n}}",
+            indent(&inner.join("\n"))
+        ));
+    }
+    checks
+}
+
+/// `type_object()` plus `impl IdlCdr` for a type whose codec bodies are given.
+fn codec_impls(
+    name: &str,
+    qualified_name: &str,
+    type_object: &str,
+    encode: &str,
+    decode: &str,
+    encode_key: &str,
+) -> String {
+    format!(
+        "impl {name} {{
+    /// XTypes TypeObject of `{qualified_name}`.
+    pub fn type_object() -> {XTYPES}::CompleteTypeObject {{
+{type_object}
+    }}
+}}
+
+impl {IDL}::IdlCdr for {name} {{
+    fn encode_cdr(&self, cursor: &mut {SER}::cursor::CursorMut<'_>) -> {SER}::SerResult<()> {{
+{encode}
+    }}
+
+    fn decode_cdr(cursor: &mut {SER}::cursor::Cursor<'_>) -> {SER}::SerResult<Self> {{
+{decode}
+    }}
+
+    fn encode_key(&self, key: &mut Vec<u8>) {{
+{encode_key}
+    }}
+}}",
+        type_object = indent(&indent(type_object)),
+        encode = indent(&indent(encode)),
+        decode = indent(&indent(decode)),
+        encode_key = indent(&indent(encode_key)),
+    )
+}
+
+fn render_struct(def: &StructDef) -> Result<String, String> {
+    let from = &def.namespace;
+    let qualified_name = qualified(&def.namespace, &def.name);
+    let mut out = String::new();
+
+    let _ = writeln!(out, "/// IDL struct `{qualified_name}`.");
+    out.push_str("#[derive(Debug, Clone, PartialEq)]\n");
+    let _ = writeln!(out, "pub struct {} {{", def.name);
+    for member in &def.members {
+        let ty = rust_type(&member.ty, from)
+            .map_err(|err| format!("member {}: {}", member.name, err))?;
+        let _ = writeln!(out, "    pub {}: {},", ident(&member.name), ty);
+    }
+    out.push_str("}\n\n");
+
+    let mut members = Vec::new();
+    for (index, member) in def.members.iter().enumerate() {
+        let flags = if member.key { "IS_KEY" } else { "empty()" };
+        members.push(format!(
+            "{XTYPES}::CompleteStructMember {{
+    common: {XTYPES}::CommonStructMember {{
+        member_id: {index},
+        member_flags: {XTYPES}::MemberFlag::{flags},
+        member_type_id: {},
+    }},
+    detail: {XTYPES}::CompleteMemberDetail::new({:?}),
+}},",
+            type_id(&member.ty, from)?,
+            member.name
+        ));
+    }
+    let type_object = format!(
+        "{XTYPES}::CompleteTypeObject::Struct({XTYPES}::CompleteStructType {{
+    struct_flags: {XTYPES}::StructTypeFlag::{},
+    header: {XTYPES}::CompleteStructHeader {{
+        base_type: None,
+        detail: {XTYPES}::CompleteTypeDetail::new({qualified_name:?}),
+    }},
+    member_seq: vec![
+{}
+    ],
+}})",
+        extensibility_flag(def.extensibility),
+        indent(&indent(&members.join("\n")))
+    );
+
+    let mut encode = Vec::new();
+    for member in &def.members {
+        encode.extend(bound_checks(
+            &format!("self.{}", ident(&member.name)),
+            &member.ty,
+            0,
+        ));
+    }
+    for member in &def.members {
+        encode.push(format!("self.{}.encode_cdr(cursor)?;", ident(&member.name)));
+    }
+    encode.push("Ok(())".to_string());
+
+    let mut fields = Vec::new();
+    let mut checks = Vec::new();
+    for member in &def.members {
+        fields.push(format!(
+            "{}: <{}>::decode_cdr(cursor)?,",
+            ident(&member.name),
+            rust_type(&member.ty, from)?
+        ));
+        checks.extend(bound_checks(
+            &format!("value.{}", ident(&member.name)),
+            &member.ty,
+            0,
+        ));
+    }
+    let literal = format!("Self {{\n{}\n}}", indent(&fields.join("\n")));
+    let decode = if checks.is_empty() {
+        format!("Ok({literal})")
+    } else {
+        format!("let value = {literal};\n{}\nOk(value)", checks.join("\n"))
+    };
+
+    // Key members, or the whole struct when used as (part of) a key
+    let keyed = def.members.iter().any(|member| member.key);
+    let encode_key = def
+        .members
+        .iter()
+        .filter(|member| member.key || !keyed)
+        .map(|member| format!("self.{}.encode_key(key);", ident(&member.name)))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    out.push_str(&codec_impls(
+        &def.name,
+        &qualified_name,
+        &type_object,
+        &encode.join("\n"),
+        &decode,
+        &encode_key,
+    ));
+    out.push_str("\n\n");
+    out.push_str(&render_struct_dds(def, keyed));
+    Ok(out)
+}
+
+fn render_struct_dds(def: &StructDef, keyed: bool) -> String {
+    let qualified_name = qualified(&def.namespace, &def.name);
+    let key_methods = if keyed {
+        format!(
+            "
+
+    fn compute_key(&self) -> [u8; 16] {{
+        {IDL}::key_hash(self)
+    }}
+
+    fn has_key() -> bool {{
+        true
+    }}"
+        )
+    } else {
+        String::new()
+    };
+    format!(
+        "impl ::hdds::dds::DDS for {name} {{
+    fn type_descriptor() -> &'static ::hdds::core::types::TypeDescriptor {{
+        static DESCRIPTOR: ::hdds::core::types::TypeDescriptor =
+            ::hdds::core::types::TypeDescriptor {{
+                type_id: {type_id:#010X},
+                type_name: {qualified_name:?},
+                size_bytes: 0,
+                alignment: 1,
+                is_variable_size: true,
+                fields: &[],
+            }};
+        &DESCRIPTOR
+    }}
+
+    fn encode_cdr2(&self, buf: &mut [u8]) -> ::hdds::dds::Result<usize> {{
+        {IDL}::encode_sample(self, buf)
+    }}
+
+    fn decode_cdr2(buf: &[u8]) -> ::hdds::dds::Result<Self> {{
+        {IDL}::decode_sample(buf)
+    }}
+
+    fn get_type_object() -> Option<{XTYPES}::CompleteTypeObject> {{
+        Some(Self::type_object())
+    }}{key_methods}
+}}",
+        name = def.name,
+        type_id = compute_type_id(&qualified_name),
+    )
+}
+
+/// Rust type the discriminator is encoded as (enums: their `u32` value).
+fn union_wire_type(def: &UnionDef) -> Result<&'static str, String> {
+    match &def.discriminator {
+        IdlType::Primitive(kind) => primitive_rust_type(*kind),
+        IdlType::Octet => Ok("u8"),
+        IdlType::Named { .. } => Ok("u32"),
+        other => Err(format!("unsupported discriminator {:?}", other)),
+    }
+}
+
+/// Match pattern and typed literal of a label value.
+fn union_label(def: &UnionDef, value: i32) -> Result<(String, String), String> {
+    let wire = union_wire_type(def)?;
+    Ok(match wire {
+        "bool" => ((value != 0).to_string(), (value != 0).to_string()),
+        "char" => {
+            let c = u8::try_from(value)
+                .map(char::from)
+                .map_err(|_| format!("char label {} out of range", value))?;
+            (format!("{c:?}"), format!("{c:?}"))
+        }
+        _ => (value.to_string(), format!("{value}{wire}")),
+    })
+}
+
+fn render_union(def: &UnionDef) -> Result<String, String> {
+    let from = &def.namespace;
+    let qualified_name = qualified(&def.namespace, &def.name);
+    let wire = union_wire_type(def)?;
+    let mut out = String::new();
+
+    let _ = writeln!(out, "/// IDL union `{qualified_name}`.");
+    if def.cases.iter().any(|case| case.is_default) {
+        out.push_str("///\n/// The `default:` case keeps the received discriminator.\n");
+    }
+    out.push_str("#[allow(non_camel_case_types, clippy::upper_case_acronyms)]\n");
+    out.push_str("#[derive(Debug, Clone, PartialEq)]\n");
+    let _ = writeln!(out, "pub enum {} {{", def.name);
+    for case in &def.cases {
+        let ty = rust_type(&case.ty, from).map_err(|err| format!("case {}: {}", case.name, err))?;
+        if case.is_default {
+            let _ = writeln!(out, "    {}({}, {}),", ident(&case.name), wire, ty);
+        } else {
+            let _ = writeln!(out, "    {}({}),", ident(&case.name), ty);
+        }
+    }
+    out.push_str("}\n\n");
+
+    let mut members = Vec::new();
+    for (index, case) in def.cases.iter().enumerate() {
+        let flags = if case.is_default {
+            "IS_DEFAULT"
+        } else {
+            "empty()"
+        };
+        let labels: Vec<String> = case.labels.iter().map(i32::to_string).collect();
+        members.push(format!(
+            "{XTYPES}::CompleteUnionMember {{
+    common: {XTYPES}::CommonUnionMember {{
+        member_id: {index},
+        member_flags: {XTYPES}::MemberFlag::{flags},
+        member_type_id: {},
+        label_seq: vec![{}],
+    }},
+    detail: {XTYPES}::CompleteMemberDetail::new({:?}),
+}},",
+            type_id(&case.ty, from)?,
+            labels.join(", "),
+            case.name
+        ));
+    }
+    let type_object = format!(
+        "{XTYPES}::CompleteTypeObject::Union({XTYPES}::CompleteUnionType {{
+    union_flags: {XTYPES}::UnionTypeFlag::{},
+    header: {XTYPES}::CompleteUnionHeader {{
+        discriminator: {},
+        detail: {XTYPES}::CompleteTypeDetail::new({qualified_name:?}),
+    }},
+    member_seq: vec![
+{}
+    ],
+}})",
+        extensibility_flag(def.extensibility),
+        type_id(&def.discriminator, from)?,
+        indent(&indent(&members.join("\n")))
+    );
+
+    let mut encode_arms = Vec::new();
+    let mut decode_arms = Vec::new();
+    let mut key_arms = Vec::new();
+    for case in &def.cases {
+        let variant = ident(&case.name);
+        let (pattern, discriminator) = if case.is_default {
+            (
+                format!("Self::{variant}(discriminator, value)"),
+                "discriminator".to_string(),
+            )
+        } else {
+            let (_, literal) = union_label(def, case.labels[0])?;
+            (format!("Self::{variant}(value)"), literal)
+        };
+
+        let mut encode = bound_checks("value", &case.ty, 0);
+        encode.push(format!("{discriminator}.encode_cdr(cursor)?;"));
+        encode.push("value.encode_cdr(cursor)".to_string());
+        encode_arms.push(format!(
+            "{pattern} => {{\n{}\n}}",
+            indent(&encode.join("\n"))
+        ));
+        key_arms.push(format!(
+            "{pattern} => {{\n    {discriminator}.encode_key(key);\n    value.encode_key(key);\n}}"
+        ));
+
+        let decode_pattern = if case.is_default {
+            "_".to_string()
+        } else {
+            case.labels
+                .iter()
+                .map(|value| union_label(def, *value).map(|(pattern, _)| pattern))
+                .collect::<Result<Vec<_>, _>>()?
+                .join(" | ")
+        };
+        let construct = if case.is_default {
+            format!("Self::{variant}(discriminator, value)")
+        } else {
+            format!("Self::{variant}(value)")
+        };
+        let mut decode = vec![format!(
+            "let value = <{}>::decode_cdr(cursor)?;",
+            rust_type(&case.ty, from)?
+        )];
+        decode.extend(bound_checks("value", &case.ty, 0));
+        decode.push(construct);
+        decode_arms.push(format!(
+            "{decode_pattern} => {{\n{}\n}}",
+            indent(&decode.join("\n"))
+        ));
+    }
+    if !def.cases.iter().any(|case| case.is_default) {
+        decode_arms.push(format!(
+                "_ => {{\n    return Err({SER}::SerError::InvalidData {{\n        reason: format!(\"unknown discriminator {{}} for union {qualified_name}\", discriminator),\n    }})\n}}"
+            ));
+    }
+
+    let encode = format!("match self {{\n{}\n}}", indent(&encode_arms.join("\n")));
+    let decode = format!(
+        "let discriminator = <{wire}>::decode_cdr(cursor)?;\nOk(match discriminator {{\n{}\n}})",
+        indent(&decode_arms.join("\n"))
+    );
+    let encode_key = format!("match self {{\n{}\n}}", indent(&key_arms.join("\n")));
+
+    out.push_str(&codec_impls(
+        &def.name,
+        &qualified_name,
+        &type_object,
+        &encode,
+        &decode,
+        &encode_key,
+    ));
+    Ok(out)
+}
+
+fn render_typedef(def: &TypedefDef) -> Result<String, String> {
+    Ok(format!(
+        "/// IDL typedef `{}`.\npub type {} = {};",
+        qualified(&def.namespace, &def.name),
+        def.name,
+        rust_type(&def.ty, &def.namespace)?
+    ))
+}
+
+/// `type_object()` and `impl IdlCdr` of a generated enum (encoded as `u32`).
+pub(crate) fn render_enum_codec(spec: &EnumSpec) -> String {
+    let qualified_name = qualified(&spec.namespace, &spec.name);
+    let literals: Vec<String> = spec
+        .variants
+        .iter()
+        .map(|(variant, value)| {
+            format!(
+                "{XTYPES}::CompleteEnumeratedLiteral {{
+    common: {XTYPES}::CommonEnumeratedLiteral {{
+        value: {},
+        flags: {XTYPES}::EnumeratedLiteralFlag::empty(),
+    }},
+    detail: {XTYPES}::CompleteMemberDetail::new({variant:?}),
+}},",
+                // XTypes literal values are signed
+                *value as i32
+            )
+        })
+        .collect();
+    let type_object = format!(
+        "{XTYPES}::CompleteTypeObject::Enumerated({XTYPES}::CompleteEnumeratedType {{
+    header: {XTYPES}::CompleteEnumeratedHeader {{
+        bit_bound: 32,
+        detail: {XTYPES}::CompleteTypeDetail::new({qualified_name:?}),
+    }},
+    literal_seq: vec![
+{}
+    ],
+}})",
+        indent(&indent(&literals.join("\n")))
+    );
+    let decode = format!(
+        "let value = <u32>::decode_cdr(cursor)?;
+Self::from_value(value).ok_or_else(|| {SER}::SerError::InvalidData {{
+    reason: format!(\"invalid {qualified_name} value {{}}\", value),
+}})"
+    );
+    codec_impls(
+        &spec.name,
+        &qualified_name,
+        &type_object,
+        "self.value().encode_cdr(cursor)",
+        &decode,
+        "self.value().encode_key(key);",
+    )
+}
+
+/// Emit Rust items for IDL constants, enums, structs, unions and typedefs,
+/// nested by module.
+pub fn emit_rust_items(
+    consts: &[ConstSpec],
+    enums: &[EnumSpec],
+    types: &[TypeDecl],
+) -> Result<String, String> {
+    let mut root = ModuleItems::default();
+    for spec in enums {
+        root.module(&spec.namespace).enums.push(spec);
+    }
+    for decl in types {
+        root.module(decl.namespace()).types.push(decl);
+    }
+    for spec in consts {
+        root.module(&spec.namespace).consts.push(spec);
+    }
+    let mut out = root.render(true)?.join("\n\n");
+    out.push('\n');
+    Ok(out)
+}
@@ -0,0 +1,264 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! IDL constants, enums, structs, unions and typedefs for code generation.
+//!
+//! Parsing is done by the shared `hdds-idl` front-end; this module keeps
+//! what the Rust emitter supports. Structs (FINAL or APPENDABLE, with `@key`
+//! members), unions and typedefs are collected in [`IdlItems::types`] for
+//! `hdds-gen rust`. Members may be primitives, (bounded) strings,
+//! sequences, arrays, enums, and earlier structs and unions; a declaration
+//! using anything else (inheritance, `@optional`, `wchar`, types declared in
+//! another file, ...) is skipped and listed in [`IdlItems::skipped`].
+//!
+//! FINAL structs are also turned into dynamic type descriptors (used by
+//! `hdds-gen vectors`); structs that cannot be described (appendable, or
+//! using a union) are listed in [`IdlItems::skipped`] as well.
+
+use crate::codegen::constants::emit_module_items;
+use crate::codegen::types::emit_rust_items;
+use anyhow::{anyhow, bail, Result};
+use hdds::dynamic::{
+    self, ArrayDescriptor, EnumDescriptor, EnumVariant, FieldDescriptor, SequenceDescriptor,
+    TypeDescriptor, TypeKind,
+};
+use hdds_idl::{
+    qualified, ConstSpec, EnumSpec, Extensibility, IdlType, PrimitiveKind, StructDef, TypeDecl,
+};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+pub use hdds_idl::SkippedType;
+
+/// Constants, enums and structs found in an IDL file.
+#[derive(Debug, Clone, Default)]
+pub struct IdlItems {
+    pub consts: Vec<ConstSpec>,
+    pub enums: Vec<EnumSpec>,
+    /// Struct descriptors, named by their qualified name (`proto::Reading`).
+    pub structs: Vec<Arc<TypeDescriptor>>,
+    /// Structs, unions and typedefs, in declaration order.
+    pub types: Vec<TypeDecl>,
+    /// Declarations that could not be parsed or are not supported, and
+    /// structs without a descriptor.
+    pub skipped: Vec<SkippedType>,
+}
+
+impl IdlItems {
+    /// Render the items as Rust source (one `pub mod` per IDL module).
+    pub fn to_rust(&self) -> Result<String> {
+        let body = emit_module_items(&self.consts, &self.enums).map_err(|err| anyhow!(err))?;
+        Ok(format!(
+            "// Generated by hdds-gen from IDL constants and enums. Do not edit.\n\n{body}"
+        ))
+    }
+
+    /// Render constants, enums and types as Rust source implementing
+    /// `hdds::dds::DDS` (one `pub mod` per IDL module).
+    pub fn to_rust_types(&self) -> Result<String> {
+        let body =
+            emit_rust_items(&self.consts, &self.enums, &self.types).map_err(|err| anyhow!(err))?;
+        Ok(format!(
+            "// Generated by hdds-gen from IDL. Do not edit.\n\n{body}"
+        ))
+    }
+}
+
+/// Parse the constants, enums and types of an IDL text.
+pub fn parse_idl_items(text: &str) -> Result<IdlItems> {
+    let parsed = hdds_idl::parse_idl(text)?;
+    let mut items = IdlItems {
+        consts: parsed.consts,
+        enums: parsed.enums,
+        skipped: parsed.skipped,
+        ..IdlItems::default()
+    };
+
+    // Enums, structs and unions kept so far, and the descriptors of the
+    // enums and FINAL structs among them
+    let mut known: HashSet<String> = HashSet::new();
+    let mut descriptors: HashMap<String, Arc<TypeDescriptor>> = HashMap::new();
+    for spec in &items.enums {
+        let path = qualified(&spec.namespace, &spec.name);
+        known.insert(path.clone());
+        descriptors.insert(path.clone(), enum_descriptor(path, spec));
+    }
+
+    for decl in parsed.types {
+        let path = decl.qualified_name();
+        if let Err(err) = check_decl(&decl, &known) {
+            items.skipped.push(SkippedType {
+                name: path,
+                reason: err.to_string(),
+            });
+            continue;
+        }
+        if let TypeDecl::Struct(def) = &decl {
+            match struct_descriptor(&path, def, &descriptors) {
+                Ok(descriptor) => {
+                    descriptors.insert(path.clone(), Arc::clone(&descriptor));
+                    items.structs.push(descriptor);
+                }
+                Err(err) => items.skipped.push(SkippedType {
+                    name: path.clone(),
+                    reason: err.to_string(),
+                }),
+            }
+        }
+        if !matches!(decl, TypeDecl::Typedef(_)) {
+            known.insert(path);
+        }
+        items.types.push(decl);
+    }
+    Ok(items)
+}
+
+/// Reject the declarations the Rust emitter cannot handle.
+fn check_decl(decl: &TypeDecl, known: &HashSet<String>) -> Result<()> {
+    match decl {
+        TypeDecl::Struct(def) => {
+            check_extensibility(def.extensibility)?;
+            if def.base.is_some() {
+                bail!("struct inheritance is not supported");
+            }
+            for member in &def.members {
+                if member.optional {
+                    bail!("@optional members are not supported");
+                }
+                if member.external {
+                    bail!("@external members are not supported");
+                }
+                check_type(&member.ty, known)?;
+            }
+            if def.members.is_empty() {
+                bail!("empty structs are not supported");
+            }
+        }
+        TypeDecl::Union(def) => {
+            check_extensibility(def.extensibility)?;
+            for case in &def.cases {
+                check_type(&case.ty, known)?;
+            }
+        }
+        TypeDecl::Typedef(def) => check_type(&def.ty, known)?,
+    }
+    Ok(())
+}
+
+fn check_extensibility(extensibility: Extensibility) -> Result<()> {
+    if extensibility == Extensibility::Mutable {
+        bail!("mutable extensibility is not supported");
+    }
+    Ok(())
+}
+
+/// Member types are primitives, strings, sequences, arrays, and enums,
+/// structs and unions declared earlier in the file.
+fn check_type(ty: &IdlType, known: &HashSet<String>) -> Result<()> {
+    match ty {
+        IdlType::Primitive(PrimitiveKind::WChar) => bail!("unsupported member type 'wchar'"),
+        IdlType::Primitive(PrimitiveKind::LongDouble) => {
+            bail!("long double members are not supported")
+        }
+        IdlType::Primitive(_) | IdlType::Octet => Ok(()),
+        IdlType::Sequence { element, .. } | IdlType::Array { element, .. } => {
+            check_type(element, known)
+        }
+        IdlType::Named { namespace, name } => {
+            let path = qualified(namespace, name);
+            if !known.contains(&path) {
+                bail!("unknown or unsupported type '{}'", path);
+            }
+            Ok(())
+        }
+    }
+}
+
+fn enum_descriptor(path: String, spec: &EnumSpec) -> Arc<TypeDescriptor> {
+    let descriptor = EnumDescriptor::new(
+        spec.variants
+            .iter()
+            .map(|(variant, value)| EnumVariant::new(variant.clone(), i64::from(*value)))
+            .collect(),
+    );
+    Arc::new(TypeDescriptor::new(path, TypeKind::Enum(descriptor)))
+}
+
+fn struct_descriptor(
+    path: &str,
+    def: &StructDef,
+    descriptors: &HashMap<String, Arc<TypeDescriptor>>,
+) -> Result<Arc<TypeDescriptor>> {
+    if def.extensibility != Extensibility::Final {
+        bail!("appendable extensibility is not supported (FINAL only)");
+    }
+    let fields = def
+        .members
+        .iter()
+        .map(|member| {
+            Ok(FieldDescriptor::new(
+                member.name.clone(),
+                descriptor(&member.ty, descriptors)?,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Arc::new(TypeDescriptor::struct_type(path, fields)))
+}
+
+/// Dynamic descriptor of a member type (enums and FINAL structs only).
+fn descriptor(
+    ty: &IdlType,
+    descriptors: &HashMap<String, Arc<TypeDescriptor>>,
+) -> Result<Arc<TypeDescriptor>> {
+    let kind = match ty {
+        IdlType::Primitive(kind) => {
+            return Ok(Arc::new(TypeDescriptor::primitive(
+                "",
+                dynamic_primitive(*kind)?,
+            )))
+        }
+        IdlType::Octet => {
+            return Ok(Arc::new(TypeDescriptor::primitive(
+                "",
+                dynamic::PrimitiveKind::U8,
+            )))
+        }
+        IdlType::Sequence { element, bound } => TypeKind::Sequence(SequenceDescriptor {
+            element_type: descriptor(element, descriptors)?,
+            max_length: *bound,
+        }),
+        IdlType::Array { element, length } => TypeKind::Array(ArrayDescriptor::new(
+            descriptor(element, descriptors)?,
+            *length,
+        )),
+        IdlType::Named { namespace, name } => {
+            let path = qualified(namespace, name);
+            return descriptors
+                .get(&path)
+                .cloned()
+                .ok_or_else(|| anyhow!("unsupported member type '{}'", path));
+        }
+    };
+    Ok(Arc::new(TypeDescriptor::new("", kind)))
+}
+
+fn dynamic_primitive(kind: PrimitiveKind) -> Result<dynamic::PrimitiveKind> {
+    Ok(match kind {
+        PrimitiveKind::Bool => dynamic::PrimitiveKind::Bool,
+        PrimitiveKind::Char => dynamic::PrimitiveKind::Char,
+        PrimitiveKind::I8 => dynamic::PrimitiveKind::I8,
+        PrimitiveKind::U8 => dynamic::PrimitiveKind::U8,
+        PrimitiveKind::I16 => dynamic::PrimitiveKind::I16,
+        PrimitiveKind::U16 => dynamic::PrimitiveKind::U16,
+        PrimitiveKind::I32 => dynamic::PrimitiveKind::I32,
+        PrimitiveKind::U32 => dynamic::PrimitiveKind::U32,
+        PrimitiveKind::I64 => dynamic::PrimitiveKind::I64,
+        PrimitiveKind::U64 => dynamic::PrimitiveKind::U64,
+        PrimitiveKind::F32 => dynamic::PrimitiveKind::F32,
+        PrimitiveKind::F64 => dynamic::PrimitiveKind::F64,
+        PrimitiveKind::LongDouble => dynamic::PrimitiveKind::LongDouble,
+        PrimitiveKind::String { max_length } => dynamic::PrimitiveKind::String { max_length },
+        PrimitiveKind::WString { max_length } => dynamic::PrimitiveKind::WString { max_length },
+        PrimitiveKind::WChar => bail!("unsupported member type 'wchar'"),
+    })
+}
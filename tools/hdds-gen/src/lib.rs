@@ -2,4 +2,6 @@
 // Copyright (c) 2025-2026 naskel.com
 
 pub mod codegen;
+pub mod idl;
 pub mod qos_generator;
+pub mod vectors;
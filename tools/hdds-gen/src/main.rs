@@ -1,9 +1,11 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 // Copyright (c) 2025-2026 naskel.com
 
+use hdds_gen::idl::parse_idl_items;
 use hdds_gen::qos_generator::QosGenerator;
+use hdds_gen::vectors;
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 fn main() {
     // Initialize tracing for diagnostics
@@ -25,6 +27,24 @@ fn main() {
                 std::process::exit(1);
             }
         }
+        "consts" => {
+            if let Err(e) = generate_consts(&args[2..]) {
+                eprintln!("[ERROR] {}", e);
+                std::process::exit(1);
+            }
+        }
+        "rust" => {
+            if let Err(e) = generate_rust(&args[2..]) {
+                eprintln!("[ERROR] {}", e);
+                std::process::exit(1);
+            }
+        }
+        "vectors" => {
+            if let Err(e) = generate_vectors(&args[2..]) {
+                eprintln!("[ERROR] {}", e);
+                std::process::exit(1);
+            }
+        }
         "--help" | "-h" | "help" => {
             print_help();
         }
@@ -52,6 +72,138 @@ fn generate_qos_validator() -> anyhow::Result<()> {
     Ok(())
 }
 
+fn generate_consts(args: &[String]) -> anyhow::Result<()> {
+    let (input, output) = match args {
+        [input] => (input, None),
+        [input, flag, output] if flag == "-o" || flag == "--output" => (input, Some(output)),
+        _ => anyhow::bail!("usage: hdds-gen consts <input.idl> [-o <output.rs>]"),
+    };
+
+    let idl = std::fs::read_to_string(input)
+        .map_err(|e| anyhow::anyhow!("cannot read {}: {}", input, e))?;
+    let items = parse_idl_items(&idl).map_err(|e| anyhow::anyhow!("{}: {}", input, e))?;
+    let code = items.to_rust()?;
+
+    match output {
+        Some(path) => {
+            std::fs::write(path, code)?;
+            tracing::info!(
+                "Wrote {} constant(s) and {} enum(s) to {}",
+                items.consts.len(),
+                items.enums.len(),
+                path
+            );
+        }
+        None => print!("{}", code),
+    }
+    Ok(())
+}
+
+fn generate_rust(args: &[String]) -> anyhow::Result<()> {
+    let (input, output) = match args {
+        [input] => (input, None),
+        [input, flag, output] if flag == "-o" || flag == "--output" => (input, Some(output)),
+        _ => anyhow::bail!("usage: hdds-gen rust <input.idl> [-o <output.rs>]"),
+    };
+
+    let idl = std::fs::read_to_string(input)
+        .map_err(|e| anyhow::anyhow!("cannot read {}: {}", input, e))?;
+    let items = parse_idl_items(&idl).map_err(|e| anyhow::anyhow!("{}: {}", input, e))?;
+    for skipped in &items.skipped {
+        // Structs without a dynamic descriptor are still generated
+        if !items
+            .types
+            .iter()
+            .any(|decl| decl.qualified_name() == skipped.name)
+        {
+            tracing::warn!("Skipping {}: {}", skipped.name, skipped.reason);
+        }
+    }
+    let code = items.to_rust_types()?;
+
+    match output {
+        Some(path) => {
+            std::fs::write(path, code)?;
+            tracing::info!(
+                "Wrote {} type(s) and {} enum(s) to {}",
+                items.types.len(),
+                items.enums.len(),
+                path
+            );
+        }
+        None => print!("{}", code),
+    }
+    Ok(())
+}
+
+fn generate_vectors(args: &[String]) -> anyhow::Result<()> {
+    let usage = "usage: hdds-gen vectors <input.idl> (-o <dir> | --check <dir>)";
+    let (input, flag, dir) = match args {
+        [input, flag, dir] => (input, flag.as_str(), Path::new(dir)),
+        _ => anyhow::bail!(usage),
+    };
+
+    let idl = std::fs::read_to_string(input)
+        .map_err(|e| anyhow::anyhow!("cannot read {}: {}", input, e))?;
+    let items = parse_idl_items(&idl).map_err(|e| anyhow::anyhow!("{}: {}", input, e))?;
+    for skipped in &items.skipped {
+        tracing::warn!("Skipping {}: {}", skipped.name, skipped.reason);
+    }
+
+    match flag {
+        "-o" | "--output" => {
+            std::fs::create_dir_all(dir)?;
+            let mut written = 0;
+            for descriptor in &items.structs {
+                for vector in vectors::test_vectors(descriptor)? {
+                    let path = dir.join(vector.file_name());
+                    std::fs::write(&path, &vector.payload)?;
+                    std::fs::write(
+                        path.with_extension("hex"),
+                        vectors::hex_dump(&vector.payload),
+                    )?;
+                    written += 1;
+                }
+            }
+            tracing::info!("Wrote {} vector(s) to {}", written, dir.display());
+            Ok(())
+        }
+        "--check" => {
+            let mut checked = 0;
+            let mut failures = Vec::new();
+            let mut names: Vec<_> = std::fs::read_dir(dir)?
+                .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                .filter(|name| name.ends_with(".bin"))
+                .collect();
+            names.sort();
+            for name in names {
+                let Some((type_name, encoding)) = vectors::parse_vector_file_name(&name) else {
+                    failures.push(format!("{}: unrecognized file name", name));
+                    continue;
+                };
+                let Some(descriptor) = items.structs.iter().find(|d| d.name == type_name) else {
+                    failures.push(format!("{}: no struct {} in {}", name, type_name, input));
+                    continue;
+                };
+                let payload = std::fs::read(dir.join(&name))?;
+                checked += 1;
+                if let Err(e) = vectors::check_roundtrip(descriptor, encoding, &payload) {
+                    failures.push(format!("{}: {}", name, e));
+                }
+            }
+            for failure in &failures {
+                eprintln!("[FAIL] {}", failure);
+            }
+            if !failures.is_empty() {
+                anyhow::bail!("{} of {} vector(s) failed", failures.len(), checked);
+            }
+            tracing::info!("{} vector(s) round-trip", checked);
+            Ok(())
+        }
+        _ => anyhow::bail!(usage),
+    }
+}
+
 fn print_help() {
     println!("hdds-gen v0.1");
     println!();
@@ -60,9 +212,16 @@ fn print_help() {
     println!();
     println!("COMMANDS:");
     println!("    qos-validator  Generate 22-policy QoS validator (48 profiles + 96 scripts)");
+    println!("    consts         Emit IDL constants and enums as Rust items");
+    println!("    rust           Emit IDL types as Rust types implementing hdds::DDS");
+    println!("    vectors        Write or check XCDR1/XCDR2 test vectors of IDL structs");
     println!("    help           Print this help message");
     println!();
     println!("EXAMPLES:");
     println!("    hdds-gen qos-validator");
+    println!("    hdds-gen consts protocol.idl -o src/protocol_consts.rs");
+    println!("    hdds-gen rust sensors.idl -o src/sensors.rs");
+    println!("    hdds-gen vectors types.idl -o vectors/hdds");
+    println!("    hdds-gen vectors types.idl --check vectors/fastdds");
     println!();
 }
@@ -0,0 +1,267 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! CDR compatibility test vectors.
+//!
+//! For each IDL struct, a deterministic sample value is encoded in XCDR1 and
+//! XCDR2, little- and big-endian. Each vector is a serialized payload: the
+//! 4-byte encapsulation header followed by the CDR body, as carried in a DATA
+//! submessage, so captures from other DDS implementations can be compared
+//! byte for byte.
+//!
+//! File names are `<type>.<encoding>.bin`, with `::` in the type name
+//! replaced by `.` (e.g. `proto.Reading.xcdr2_le.bin`). A `.hex` dump is
+//! written next to each `.bin` for review.
+
+use anyhow::{anyhow, bail, Result};
+use hdds::dynamic::{
+    decode_dynamic_as, encode_dynamic_as, CdrEncoding, DynamicData, DynamicValue, PrimitiveKind,
+    TypeDescriptor, TypeKind,
+};
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::Arc;
+
+/// Serialized payload of one type in one encoding.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestVector {
+    pub type_name: String,
+    pub encoding: CdrEncoding,
+    /// Encapsulation header and CDR body.
+    pub payload: Vec<u8>,
+}
+
+impl TestVector {
+    /// File name of the vector (without directory).
+    pub fn file_name(&self) -> String {
+        vector_file_name(&self.type_name, self.encoding)
+    }
+}
+
+/// `<type>.<encoding>.bin` for a qualified type name.
+pub fn vector_file_name(type_name: &str, encoding: CdrEncoding) -> String {
+    format!("{}.{}.bin", type_name.replace("::", "."), encoding.name())
+}
+
+/// Split a vector file name into type and encoding.
+pub fn parse_vector_file_name(file_name: &str) -> Option<(String, CdrEncoding)> {
+    let stem = file_name.strip_suffix(".bin")?;
+    let (type_part, encoding) = stem.rsplit_once('.')?;
+    let encoding = CdrEncoding::ALL
+        .into_iter()
+        .find(|candidate| candidate.name() == encoding)?;
+    Some((type_part.replace('.', "::"), encoding))
+}
+
+/// Deterministic sample values: every primitive gets the next value of a
+/// counter, spread over all of its bytes so byte-order mistakes show.
+struct Sampler {
+    next: u32,
+}
+
+impl Sampler {
+    fn tick(&mut self) -> u32 {
+        self.next += 1;
+        self.next
+    }
+
+    fn primitive(&mut self, kind: PrimitiveKind) -> Result<DynamicValue> {
+        let n = self.tick();
+        let value = match kind {
+            PrimitiveKind::Bool => DynamicValue::Bool(n % 2 == 1),
+            PrimitiveKind::U8 => DynamicValue::U8(n as u8),
+            PrimitiveKind::I8 => DynamicValue::I8(-(n as i8)),
+            PrimitiveKind::U16 => DynamicValue::U16(0x0100 | n as u16),
+            PrimitiveKind::I16 => DynamicValue::I16(-(0x0100 | n as i16)),
+            PrimitiveKind::U32 => DynamicValue::U32(0x0102_0300 | n),
+            PrimitiveKind::I32 => DynamicValue::I32(-(0x0102_0300 | n as i32)),
+            PrimitiveKind::U64 => DynamicValue::U64(0x0102_0304_0506_0700 | u64::from(n)),
+            PrimitiveKind::I64 => DynamicValue::I64(-(0x0102_0304_0506_0700 | i64::from(n))),
+            PrimitiveKind::F32 => DynamicValue::F32(n as f32 + 0.5),
+            PrimitiveKind::F64 => DynamicValue::F64(f64::from(n) + 0.25),
+            PrimitiveKind::Char => DynamicValue::Char(char::from(b'a' + (n % 26) as u8)),
+            PrimitiveKind::String { max_length } => {
+                DynamicValue::String(bounded(format!("hdds-{n}"), max_length))
+            }
+            PrimitiveKind::WString { max_length } => {
+                DynamicValue::WString(bounded(format!("\u{3b4}ds-{n}"), max_length))
+            }
+            PrimitiveKind::LongDouble => bail!("long double has no portable encoding"),
+        };
+        Ok(value)
+    }
+
+    fn value(&mut self, descriptor: &TypeDescriptor) -> Result<DynamicValue> {
+        match &descriptor.kind {
+            TypeKind::Primitive(kind) => self.primitive(*kind),
+            TypeKind::Struct(fields) => fields
+                .iter()
+                .map(|field| Ok((field.name.clone(), self.value(&field.type_desc)?)))
+                .collect::<Result<HashMap<_, _>>>()
+                .map(DynamicValue::Struct),
+            TypeKind::Sequence(sequence) => {
+                let length = sequence.max_length.unwrap_or(2).min(2);
+                (0..length)
+                    .map(|_| self.value(&sequence.element_type))
+                    .collect::<Result<Vec<_>>>()
+                    .map(DynamicValue::Sequence)
+            }
+            TypeKind::Array(array) => (0..array.length)
+                .map(|_| self.value(&array.element_type))
+                .collect::<Result<Vec<_>>>()
+                .map(DynamicValue::Array),
+            TypeKind::Enum(descriptor) => {
+                let n = self.tick() as usize;
+                let variant = descriptor
+                    .variants
+                    .get(n % descriptor.variants.len().max(1))
+                    .ok_or_else(|| anyhow!("enum without enumerators"))?;
+                Ok(DynamicValue::Enum(variant.value, variant.name.clone()))
+            }
+            TypeKind::Union(_) => bail!("unions are not supported"),
+            TypeKind::Nested(inner) => self.value(inner),
+        }
+    }
+}
+
+fn bounded(text: String, max_length: Option<usize>) -> String {
+    match max_length {
+        Some(max) => text.chars().take(max).collect(),
+        None => text,
+    }
+}
+
+/// The sample value of a type used by its test vectors.
+pub fn sample_value(descriptor: &Arc<TypeDescriptor>) -> Result<DynamicData> {
+    let value = Sampler { next: 0 }.value(descriptor)?;
+    DynamicData::from_value(descriptor, value).map_err(|err| anyhow!("{}", err))
+}
+
+/// Encapsulation header followed by the encoded body.
+fn encapsulate(encoding: CdrEncoding, body: &[u8]) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(4 + body.len());
+    payload.extend_from_slice(&encoding.encapsulation_id().to_be_bytes());
+    payload.extend_from_slice(&[0, 0]);
+    payload.extend_from_slice(body);
+    payload
+}
+
+/// Test vectors of a struct, one per encoding.
+pub fn test_vectors(descriptor: &Arc<TypeDescriptor>) -> Result<Vec<TestVector>> {
+    let sample = sample_value(descriptor)?;
+    CdrEncoding::ALL
+        .into_iter()
+        .map(|encoding| {
+            let body = encode_dynamic_as(&sample, encoding)
+                .map_err(|err| anyhow!("{} ({}): {}", descriptor.name, encoding.name(), err))?;
+            Ok(TestVector {
+                type_name: descriptor.name.clone(),
+                encoding,
+                payload: encapsulate(encoding, &body),
+            })
+        })
+        .collect()
+}
+
+/// Decode a payload and check that re-encoding it gives the same bytes.
+///
+/// The encapsulation identifier must match `encoding`; trailing padding
+/// announced in the encapsulation options is ignored. Returns the decoded
+/// value.
+pub fn check_roundtrip(
+    descriptor: &Arc<TypeDescriptor>,
+    encoding: CdrEncoding,
+    payload: &[u8],
+) -> Result<DynamicData> {
+    let [id_hi, id_lo, _, options, body @ ..] = payload else {
+        bail!("payload shorter than the encapsulation header");
+    };
+    let id = u16::from_be_bytes([*id_hi, *id_lo]);
+    if CdrEncoding::from_encapsulation_id(id) != Some(encoding) {
+        bail!(
+            "encapsulation 0x{:04x} does not match {}",
+            id,
+            encoding.name()
+        );
+    }
+    let padding = usize::from(options & 0x03);
+    let body = &body[..body.len().saturating_sub(padding)];
+
+    let decoded = decode_dynamic_as(body, descriptor, encoding)
+        .map_err(|err| anyhow!("decode failed: {}", err))?;
+    let encoded = encode_dynamic_as(&decoded, encoding)
+        .map_err(|err| anyhow!("re-encode failed: {}", err))?;
+    if encoded != body {
+        let at = encoded
+            .iter()
+            .zip(body)
+            .position(|(a, b)| a != b)
+            .unwrap_or(encoded.len().min(body.len()));
+        bail!(
+            "re-encoded body differs at byte {} ({} vs {} bytes)",
+            at,
+            encoded.len(),
+            body.len()
+        );
+    }
+    Ok(decoded)
+}
+
+/// Hex dump of a payload, 16 bytes per line with an ASCII column.
+pub fn hex_dump(bytes: &[u8]) -> String {
+    let mut out = String::new();
+    for (i, chunk) in bytes.chunks(16).enumerate() {
+        let _ = write!(out, "{:08x} ", i * 16);
+        for byte in chunk {
+            let _ = write!(out, " {byte:02x}");
+        }
+        out.push_str(&" ".repeat((16 - chunk.len()) * 3));
+        out.push_str("  |");
+        out.extend(chunk.iter().map(|&byte| {
+            if byte.is_ascii_graphic() || byte == b' ' {
+                char::from(byte)
+            } else {
+                '.'
+            }
+        }));
+        out.push_str("|\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hdds::dynamic::TypeDescriptorBuilder;
+
+    #[test]
+    fn test_file_names_roundtrip() {
+        let name = vector_file_name("proto::Reading", CdrEncoding::Xcdr2Be);
+        assert_eq!(name, "proto.Reading.xcdr2_be.bin");
+        assert_eq!(
+            parse_vector_file_name(&name),
+            Some(("proto::Reading".to_string(), CdrEncoding::Xcdr2Be))
+        );
+        assert_eq!(parse_vector_file_name("Reading.cdr.bin"), None);
+    }
+
+    #[test]
+    fn test_vectors_roundtrip_and_reject_wrong_encoding() {
+        let descriptor = Arc::new(
+            TypeDescriptorBuilder::new("Reading")
+                .field("id", PrimitiveKind::U16)
+                .field("stamp", PrimitiveKind::I64)
+                .build(),
+        );
+        let vectors = test_vectors(&descriptor).expect("vectors");
+        assert_eq!(vectors.len(), 4);
+        for vector in &vectors {
+            check_roundtrip(&descriptor, vector.encoding, &vector.payload).expect("roundtrip");
+        }
+        // XCDR1 pads the i64 to 8, XCDR2 to 4
+        assert_eq!(vectors[0].payload.len(), 4 + 16);
+        assert_eq!(vectors[2].payload.len(), 4 + 12);
+        assert_eq!(&vectors[1].payload[..2], &[0x00, 0x00]);
+        assert!(check_roundtrip(&descriptor, CdrEncoding::Xcdr2Le, &vectors[0].payload).is_err());
+    }
+}
@@ -0,0 +1,53 @@
+// Generated by hdds-gen from IDL constants and enums. Do not edit.
+
+pub const GLOBAL_MAGIC: u32 = 1212433491;
+
+#[allow(non_snake_case)]
+pub mod proto {
+    #[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    #[repr(u32)]
+    pub enum Kind {
+        #[default]
+        KIND_DATA = 0,
+        KIND_HEARTBEAT = 1,
+        KIND_GAP = 10,
+        KIND_ACKNACK = 11,
+    }
+
+    impl Kind {
+        /// Wire value of the enumerator.
+        pub const fn value(self) -> u32 {
+            self as u32
+        }
+
+        /// Enumerator for a wire value.
+        pub const fn from_value(value: u32) -> Option<Self> {
+            match value {
+                0 => Some(Self::KIND_DATA),
+                1 => Some(Self::KIND_HEARTBEAT),
+                10 => Some(Self::KIND_GAP),
+                11 => Some(Self::KIND_ACKNACK),
+                _ => None,
+            }
+        }
+    }
+
+    pub const VERSION_MAJOR: u16 = 2;
+    pub const VERSION_MINOR: u16 = 5;
+    pub const VERSION: u32 = 131077;
+    pub const DEFAULT_KIND: Kind = Kind::KIND_HEARTBEAT;
+
+    #[allow(non_snake_case)]
+    pub mod limits {
+        pub const MAX_PEERS: i32 = 32;
+        pub const MIN_OFFSET: i64 = -1099511627776;
+        pub const FLAGS: u8 = 13;
+        pub const TIMEOUT_S: f64 = 0.3;
+        pub const RATIO: f32 = 3.0;
+        pub const STRICT: bool = true;
+        pub const SEPARATOR: char = '/';
+        pub const PREFIX: &str = "hdds\t";
+        pub const LAST_KIND: super::Kind = super::Kind::KIND_ACKNACK;
+    }
+}
@@ -0,0 +1,580 @@
+// Generated by hdds-gen from IDL. Do not edit.
+
+#[allow(non_snake_case)]
+pub mod sensors {
+    #[allow(unused_imports)]
+    use ::hdds::core::ser::idl::IdlCdr as _;
+
+    #[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    #[repr(u32)]
+    pub enum Unit {
+        #[default]
+        CELSIUS = 0,
+        PASCAL = 10,
+    }
+
+    impl Unit {
+        /// Wire value of the enumerator.
+        pub const fn value(self) -> u32 {
+            self as u32
+        }
+
+        /// Enumerator for a wire value.
+        pub const fn from_value(value: u32) -> Option<Self> {
+            match value {
+                0 => Some(Self::CELSIUS),
+                10 => Some(Self::PASCAL),
+                _ => None,
+            }
+        }
+    }
+
+    impl Unit {
+        /// XTypes TypeObject of `sensors::Unit`.
+        pub fn type_object() -> ::hdds::xtypes::CompleteTypeObject {
+            ::hdds::xtypes::CompleteTypeObject::Enumerated(::hdds::xtypes::CompleteEnumeratedType {
+                header: ::hdds::xtypes::CompleteEnumeratedHeader {
+                    bit_bound: 32,
+                    detail: ::hdds::xtypes::CompleteTypeDetail::new("sensors::Unit"),
+                },
+                literal_seq: vec![
+                    ::hdds::xtypes::CompleteEnumeratedLiteral {
+                        common: ::hdds::xtypes::CommonEnumeratedLiteral {
+                            value: 0,
+                            flags: ::hdds::xtypes::EnumeratedLiteralFlag::empty(),
+                        },
+                        detail: ::hdds::xtypes::CompleteMemberDetail::new("CELSIUS"),
+                    },
+                    ::hdds::xtypes::CompleteEnumeratedLiteral {
+                        common: ::hdds::xtypes::CommonEnumeratedLiteral {
+                            value: 10,
+                            flags: ::hdds::xtypes::EnumeratedLiteralFlag::empty(),
+                        },
+                        detail: ::hdds::xtypes::CompleteMemberDetail::new("PASCAL"),
+                    },
+                ],
+            })
+        }
+    }
+
+    impl ::hdds::core::ser::idl::IdlCdr for Unit {
+        fn encode_cdr(&self, cursor: &mut ::hdds::core::ser::cursor::CursorMut<'_>) -> ::hdds::core::ser::SerResult<()> {
+            self.value().encode_cdr(cursor)
+        }
+
+        fn decode_cdr(cursor: &mut ::hdds::core::ser::cursor::Cursor<'_>) -> ::hdds::core::ser::SerResult<Self> {
+            let value = <u32>::decode_cdr(cursor)?;
+            Self::from_value(value).ok_or_else(|| ::hdds::core::ser::SerError::InvalidData {
+                reason: format!("invalid sensors::Unit value {}", value),
+            })
+        }
+
+        fn encode_key(&self, key: &mut Vec<u8>) {
+            self.value().encode_key(key);
+        }
+    }
+
+    /// IDL typedef `sensors::Label`.
+    pub type Label = String;
+
+    /// IDL typedef `sensors::Samples`.
+    pub type Samples = Vec<f64>;
+
+    /// IDL union `sensors::Value`.
+    #[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Value {
+        celsius(f32),
+        raw(Vec<u8>),
+    }
+
+    impl Value {
+        /// XTypes TypeObject of `sensors::Value`.
+        pub fn type_object() -> ::hdds::xtypes::CompleteTypeObject {
+            ::hdds::xtypes::CompleteTypeObject::Union(::hdds::xtypes::CompleteUnionType {
+                union_flags: ::hdds::xtypes::UnionTypeFlag::IS_FINAL,
+                header: ::hdds::xtypes::CompleteUnionHeader {
+                    discriminator: ::hdds::xtypes::TypeIdentifier::Inline(Box::new(Unit::type_object())),
+                    detail: ::hdds::xtypes::CompleteTypeDetail::new("sensors::Value"),
+                },
+                member_seq: vec![
+                    ::hdds::xtypes::CompleteUnionMember {
+                        common: ::hdds::xtypes::CommonUnionMember {
+                            member_id: 0,
+                            member_flags: ::hdds::xtypes::MemberFlag::empty(),
+                            member_type_id: ::hdds::xtypes::TypeIdentifier::TK_FLOAT32,
+                            label_seq: vec![0],
+                        },
+                        detail: ::hdds::xtypes::CompleteMemberDetail::new("celsius"),
+                    },
+                    ::hdds::xtypes::CompleteUnionMember {
+                        common: ::hdds::xtypes::CommonUnionMember {
+                            member_id: 1,
+                            member_flags: ::hdds::xtypes::MemberFlag::empty(),
+                            member_type_id: ::hdds::core::ser::idl::sequence_type_id(::hdds::xtypes::TypeIdentifier::TK_BYTE, 2),
+                            label_seq: vec![10],
+                        },
+                        detail: ::hdds::xtypes::CompleteMemberDetail::new("raw"),
+                    },
+                ],
+            })
+        }
+    }
+
+    impl ::hdds::core::ser::idl::IdlCdr for Value {
+        fn encode_cdr(&self, cursor: &mut ::hdds::core::ser::cursor::CursorMut<'_>) -> ::hdds::core::ser::SerResult<()> {
+            match self {
+                Self::celsius(value) => {
+                    0u32.encode_cdr(cursor)?;
+                    value.encode_cdr(cursor)
+                }
+                Self::raw(value) => {
+                    ::hdds::core::ser::idl::check_bound(value.len(), 2)?;
+                    10u32.encode_cdr(cursor)?;
+                    value.encode_cdr(cursor)
+                }
+            }
+        }
+
+        fn decode_cdr(cursor: &mut ::hdds::core::ser::cursor::Cursor<'_>) -> ::hdds::core::ser::SerResult<Self> {
+            let discriminator = <u32>::decode_cdr(cursor)?;
+            Ok(match discriminator {
+                0 => {
+                    let value = <f32>::decode_cdr(cursor)?;
+                    Self::celsius(value)
+                }
+                10 => {
+                    let value = <Vec<u8>>::decode_cdr(cursor)?;
+                    ::hdds::core::ser::idl::check_bound(value.len(), 2)?;
+                    Self::raw(value)
+                }
+                _ => {
+                    return Err(::hdds::core::ser::SerError::InvalidData {
+                        reason: format!("unknown discriminator {} for union sensors::Value", discriminator),
+                    })
+                }
+            })
+        }
+
+        fn encode_key(&self, key: &mut Vec<u8>) {
+            match self {
+                Self::celsius(value) => {
+                    0u32.encode_key(key);
+                    value.encode_key(key);
+                }
+                Self::raw(value) => {
+                    10u32.encode_key(key);
+                    value.encode_key(key);
+                }
+            }
+        }
+    }
+
+    /// IDL union `sensors::Extra`.
+    ///
+    /// The `default:` case keeps the received discriminator.
+    #[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Extra {
+        tag(String),
+        code(i16, i32),
+    }
+
+    impl Extra {
+        /// XTypes TypeObject of `sensors::Extra`.
+        pub fn type_object() -> ::hdds::xtypes::CompleteTypeObject {
+            ::hdds::xtypes::CompleteTypeObject::Union(::hdds::xtypes::CompleteUnionType {
+                union_flags: ::hdds::xtypes::UnionTypeFlag::IS_FINAL,
+                header: ::hdds::xtypes::CompleteUnionHeader {
+                    discriminator: ::hdds::xtypes::TypeIdentifier::TK_INT16,
+                    detail: ::hdds::xtypes::CompleteTypeDetail::new("sensors::Extra"),
+                },
+                member_seq: vec![
+                    ::hdds::xtypes::CompleteUnionMember {
+                        common: ::hdds::xtypes::CommonUnionMember {
+                            member_id: 0,
+                            member_flags: ::hdds::xtypes::MemberFlag::empty(),
+                            member_type_id: ::hdds::xtypes::TypeIdentifier::string(8),
+                            label_seq: vec![1, 2],
+                        },
+                        detail: ::hdds::xtypes::CompleteMemberDetail::new("tag"),
+                    },
+                    ::hdds::xtypes::CompleteUnionMember {
+                        common: ::hdds::xtypes::CommonUnionMember {
+                            member_id: 1,
+                            member_flags: ::hdds::xtypes::MemberFlag::IS_DEFAULT,
+                            member_type_id: ::hdds::xtypes::TypeIdentifier::TK_INT32,
+                            label_seq: vec![],
+                        },
+                        detail: ::hdds::xtypes::CompleteMemberDetail::new("code"),
+                    },
+                ],
+            })
+        }
+    }
+
+    impl ::hdds::core::ser::idl::IdlCdr for Extra {
+        fn encode_cdr(&self, cursor: &mut ::hdds::core::ser::cursor::CursorMut<'_>) -> ::hdds::core::ser::SerResult<()> {
+            match self {
+                Self::tag(value) => {
+                    ::hdds::core::ser::idl::check_bound(value.len(), 8)?;
+                    1i16.encode_cdr(cursor)?;
+                    value.encode_cdr(cursor)
+                }
+                Self::code(discriminator, value) => {
+                    discriminator.encode_cdr(cursor)?;
+                    value.encode_cdr(cursor)
+                }
+            }
+        }
+
+        fn decode_cdr(cursor: &mut ::hdds::core::ser::cursor::Cursor<'_>) -> ::hdds::core::ser::SerResult<Self> {
+            let discriminator = <i16>::decode_cdr(cursor)?;
+            Ok(match discriminator {
+                1 | 2 => {
+                    let value = <String>::decode_cdr(cursor)?;
+                    ::hdds::core::ser::idl::check_bound(value.len(), 8)?;
+                    Self::tag(value)
+                }
+                _ => {
+                    let value = <i32>::decode_cdr(cursor)?;
+                    Self::code(discriminator, value)
+                }
+            })
+        }
+
+        fn encode_key(&self, key: &mut Vec<u8>) {
+            match self {
+                Self::tag(value) => {
+                    1i16.encode_key(key);
+                    value.encode_key(key);
+                }
+                Self::code(discriminator, value) => {
+                    discriminator.encode_key(key);
+                    value.encode_key(key);
+                }
+            }
+        }
+    }
+
+    /// IDL struct `sensors::Reading`.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Reading {
+        pub sensor_id: u32,
+        pub zone: String,
+        pub position: geo::Point,
+        pub unit: Unit,
+        pub samples: Vec<f64>,
+        pub value: Value,
+        pub extra: Extra,
+        pub matrix: [[i16; 3]; 2],
+        pub tags: Vec<String>,
+        pub valid: bool,
+        pub grade: char,
+        pub flags: u8,
+        pub stamp: i64,
+    }
+
+    impl Reading {
+        /// XTypes TypeObject of `sensors::Reading`.
+        pub fn type_object() -> ::hdds::xtypes::CompleteTypeObject {
+            ::hdds::xtypes::CompleteTypeObject::Struct(::hdds::xtypes::CompleteStructType {
+                struct_flags: ::hdds::xtypes::StructTypeFlag::IS_APPENDABLE,
+                header: ::hdds::xtypes::CompleteStructHeader {
+                    base_type: None,
+                    detail: ::hdds::xtypes::CompleteTypeDetail::new("sensors::Reading"),
+                },
+                member_seq: vec![
+                    ::hdds::xtypes::CompleteStructMember {
+                        common: ::hdds::xtypes::CommonStructMember {
+                            member_id: 0,
+                            member_flags: ::hdds::xtypes::MemberFlag::IS_KEY,
+                            member_type_id: ::hdds::xtypes::TypeIdentifier::TK_UINT32,
+                        },
+                        detail: ::hdds::xtypes::CompleteMemberDetail::new("sensor_id"),
+                    },
+                    ::hdds::xtypes::CompleteStructMember {
+                        common: ::hdds::xtypes::CommonStructMember {
+                            member_id: 1,
+                            member_flags: ::hdds::xtypes::MemberFlag::IS_KEY,
+                            member_type_id: ::hdds::xtypes::TypeIdentifier::string(8),
+                        },
+                        detail: ::hdds::xtypes::CompleteMemberDetail::new("zone"),
+                    },
+                    ::hdds::xtypes::CompleteStructMember {
+                        common: ::hdds::xtypes::CommonStructMember {
+                            member_id: 2,
+                            member_flags: ::hdds::xtypes::MemberFlag::empty(),
+                            member_type_id: ::hdds::xtypes::TypeIdentifier::Inline(Box::new(geo::Point::type_object())),
+                        },
+                        detail: ::hdds::xtypes::CompleteMemberDetail::new("position"),
+                    },
+                    ::hdds::xtypes::CompleteStructMember {
+                        common: ::hdds::xtypes::CommonStructMember {
+                            member_id: 3,
+                            member_flags: ::hdds::xtypes::MemberFlag::empty(),
+                            member_type_id: ::hdds::xtypes::TypeIdentifier::Inline(Box::new(Unit::type_object())),
+                        },
+                        detail: ::hdds::xtypes::CompleteMemberDetail::new("unit"),
+                    },
+                    ::hdds::xtypes::CompleteStructMember {
+                        common: ::hdds::xtypes::CommonStructMember {
+                            member_id: 4,
+                            member_flags: ::hdds::xtypes::MemberFlag::empty(),
+                            member_type_id: ::hdds::core::ser::idl::sequence_type_id(::hdds::xtypes::TypeIdentifier::TK_FLOAT64, 4),
+                        },
+                        detail: ::hdds::xtypes::CompleteMemberDetail::new("samples"),
+                    },
+                    ::hdds::xtypes::CompleteStructMember {
+                        common: ::hdds::xtypes::CommonStructMember {
+                            member_id: 5,
+                            member_flags: ::hdds::xtypes::MemberFlag::empty(),
+                            member_type_id: ::hdds::xtypes::TypeIdentifier::Inline(Box::new(Value::type_object())),
+                        },
+                        detail: ::hdds::xtypes::CompleteMemberDetail::new("value"),
+                    },
+                    ::hdds::xtypes::CompleteStructMember {
+                        common: ::hdds::xtypes::CommonStructMember {
+                            member_id: 6,
+                            member_flags: ::hdds::xtypes::MemberFlag::empty(),
+                            member_type_id: ::hdds::xtypes::TypeIdentifier::Inline(Box::new(Extra::type_object())),
+                        },
+                        detail: ::hdds::xtypes::CompleteMemberDetail::new("extra"),
+                    },
+                    ::hdds::xtypes::CompleteStructMember {
+                        common: ::hdds::xtypes::CommonStructMember {
+                            member_id: 7,
+                            member_flags: ::hdds::xtypes::MemberFlag::empty(),
+                            member_type_id: ::hdds::core::ser::idl::array_type_id(::hdds::xtypes::TypeIdentifier::TK_INT16, vec![2, 3]),
+                        },
+                        detail: ::hdds::xtypes::CompleteMemberDetail::new("matrix"),
+                    },
+                    ::hdds::xtypes::CompleteStructMember {
+                        common: ::hdds::xtypes::CommonStructMember {
+                            member_id: 8,
+                            member_flags: ::hdds::xtypes::MemberFlag::empty(),
+                            member_type_id: ::hdds::core::ser::idl::sequence_type_id(::hdds::xtypes::TypeIdentifier::string(8), 2),
+                        },
+                        detail: ::hdds::xtypes::CompleteMemberDetail::new("tags"),
+                    },
+                    ::hdds::xtypes::CompleteStructMember {
+                        common: ::hdds::xtypes::CommonStructMember {
+                            member_id: 9,
+                            member_flags: ::hdds::xtypes::MemberFlag::empty(),
+                            member_type_id: ::hdds::xtypes::TypeIdentifier::TK_BOOLEAN,
+                        },
+                        detail: ::hdds::xtypes::CompleteMemberDetail::new("valid"),
+                    },
+                    ::hdds::xtypes::CompleteStructMember {
+                        common: ::hdds::xtypes::CommonStructMember {
+                            member_id: 10,
+                            member_flags: ::hdds::xtypes::MemberFlag::empty(),
+                            member_type_id: ::hdds::xtypes::TypeIdentifier::TK_CHAR8,
+                        },
+                        detail: ::hdds::xtypes::CompleteMemberDetail::new("grade"),
+                    },
+                    ::hdds::xtypes::CompleteStructMember {
+                        common: ::hdds::xtypes::CommonStructMember {
+                            member_id: 11,
+                            member_flags: ::hdds::xtypes::MemberFlag::empty(),
+                            member_type_id: ::hdds::xtypes::TypeIdentifier::TK_BYTE,
+                        },
+                        detail: ::hdds::xtypes::CompleteMemberDetail::new("flags"),
+                    },
+                    ::hdds::xtypes::CompleteStructMember {
+                        common: ::hdds::xtypes::CommonStructMember {
+                            member_id: 12,
+                            member_flags: ::hdds::xtypes::MemberFlag::empty(),
+                            member_type_id: ::hdds::xtypes::TypeIdentifier::TK_INT64,
+                        },
+                        detail: ::hdds::xtypes::CompleteMemberDetail::new("stamp"),
+                    },
+                ],
+            })
+        }
+    }
+
+    impl ::hdds::core::ser::idl::IdlCdr for Reading {
+        fn encode_cdr(&self, cursor: &mut ::hdds::core::ser::cursor::CursorMut<'_>) -> ::hdds::core::ser::SerResult<()> {
+            ::hdds::core::ser::idl::check_bound(self.zone.len(), 8)?;
+            ::hdds::core::ser::idl::check_bound(self.samples.len(), 4)?;
+            ::hdds::core::ser::idl::check_bound(self.tags.len(), 2)?;
+            for item0 in &self.tags {
+                ::hdds::core::ser::idl::check_bound(item0.len(), 8)?;
+            }
+            self.sensor_id.encode_cdr(cursor)?;
+            self.zone.encode_cdr(cursor)?;
+            self.position.encode_cdr(cursor)?;
+            self.unit.encode_cdr(cursor)?;
+            self.samples.encode_cdr(cursor)?;
+            self.value.encode_cdr(cursor)?;
+            self.extra.encode_cdr(cursor)?;
+            self.matrix.encode_cdr(cursor)?;
+            self.tags.encode_cdr(cursor)?;
+            self.valid.encode_cdr(cursor)?;
+            self.grade.encode_cdr(cursor)?;
+            self.flags.encode_cdr(cursor)?;
+            self.stamp.encode_cdr(cursor)?;
+            Ok(())
+        }
+
+        fn decode_cdr(cursor: &mut ::hdds::core::ser::cursor::Cursor<'_>) -> ::hdds::core::ser::SerResult<Self> {
+            let value = Self {
+                sensor_id: <u32>::decode_cdr(cursor)?,
+                zone: <String>::decode_cdr(cursor)?,
+                position: <geo::Point>::decode_cdr(cursor)?,
+                unit: <Unit>::decode_cdr(cursor)?,
+                samples: <Vec<f64>>::decode_cdr(cursor)?,
+                value: <Value>::decode_cdr(cursor)?,
+                extra: <Extra>::decode_cdr(cursor)?,
+                matrix: <[[i16; 3]; 2]>::decode_cdr(cursor)?,
+                tags: <Vec<String>>::decode_cdr(cursor)?,
+                valid: <bool>::decode_cdr(cursor)?,
+                grade: <char>::decode_cdr(cursor)?,
+                flags: <u8>::decode_cdr(cursor)?,
+                stamp: <i64>::decode_cdr(cursor)?,
+            };
+            ::hdds::core::ser::idl::check_bound(value.zone.len(), 8)?;
+            ::hdds::core::ser::idl::check_bound(value.samples.len(), 4)?;
+            ::hdds::core::ser::idl::check_bound(value.tags.len(), 2)?;
+            for item0 in &value.tags {
+                ::hdds::core::ser::idl::check_bound(item0.len(), 8)?;
+            }
+            Ok(value)
+        }
+
+        fn encode_key(&self, key: &mut Vec<u8>) {
+            self.sensor_id.encode_key(key);
+            self.zone.encode_key(key);
+        }
+    }
+
+    impl ::hdds::dds::DDS for Reading {
+        fn type_descriptor() -> &'static ::hdds::core::types::TypeDescriptor {
+            static DESCRIPTOR: ::hdds::core::types::TypeDescriptor =
+                ::hdds::core::types::TypeDescriptor {
+                    type_id: 0xB6519360,
+                    type_name: "sensors::Reading",
+                    size_bytes: 0,
+                    alignment: 1,
+                    is_variable_size: true,
+                    fields: &[],
+                };
+            &DESCRIPTOR
+        }
+
+        fn encode_cdr2(&self, buf: &mut [u8]) -> ::hdds::dds::Result<usize> {
+            ::hdds::core::ser::idl::encode_sample(self, buf)
+        }
+
+        fn decode_cdr2(buf: &[u8]) -> ::hdds::dds::Result<Self> {
+            ::hdds::core::ser::idl::decode_sample(buf)
+        }
+
+        fn get_type_object() -> Option<::hdds::xtypes::CompleteTypeObject> {
+            Some(Self::type_object())
+        }
+
+        fn compute_key(&self) -> [u8; 16] {
+            ::hdds::core::ser::idl::key_hash(self)
+        }
+
+        fn has_key() -> bool {
+            true
+        }
+    }
+
+    pub const MAX_SAMPLES: i32 = 4;
+
+    #[allow(non_snake_case)]
+    pub mod geo {
+        #[allow(unused_imports)]
+        use ::hdds::core::ser::idl::IdlCdr as _;
+
+        /// IDL struct `sensors::geo::Point`.
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Point {
+            pub lat: f64,
+            pub lon: f64,
+        }
+
+        impl Point {
+            /// XTypes TypeObject of `sensors::geo::Point`.
+            pub fn type_object() -> ::hdds::xtypes::CompleteTypeObject {
+                ::hdds::xtypes::CompleteTypeObject::Struct(::hdds::xtypes::CompleteStructType {
+                    struct_flags: ::hdds::xtypes::StructTypeFlag::IS_FINAL,
+                    header: ::hdds::xtypes::CompleteStructHeader {
+                        base_type: None,
+                        detail: ::hdds::xtypes::CompleteTypeDetail::new("sensors::geo::Point"),
+                    },
+                    member_seq: vec![
+                        ::hdds::xtypes::CompleteStructMember {
+                            common: ::hdds::xtypes::CommonStructMember {
+                                member_id: 0,
+                                member_flags: ::hdds::xtypes::MemberFlag::empty(),
+                                member_type_id: ::hdds::xtypes::TypeIdentifier::TK_FLOAT64,
+                            },
+                            detail: ::hdds::xtypes::CompleteMemberDetail::new("lat"),
+                        },
+                        ::hdds::xtypes::CompleteStructMember {
+                            common: ::hdds::xtypes::CommonStructMember {
+                                member_id: 1,
+                                member_flags: ::hdds::xtypes::MemberFlag::empty(),
+                                member_type_id: ::hdds::xtypes::TypeIdentifier::TK_FLOAT64,
+                            },
+                            detail: ::hdds::xtypes::CompleteMemberDetail::new("lon"),
+                        },
+                    ],
+                })
+            }
+        }
+
+        impl ::hdds::core::ser::idl::IdlCdr for Point {
+            fn encode_cdr(&self, cursor: &mut ::hdds::core::ser::cursor::CursorMut<'_>) -> ::hdds::core::ser::SerResult<()> {
+                self.lat.encode_cdr(cursor)?;
+                self.lon.encode_cdr(cursor)?;
+                Ok(())
+            }
+
+            fn decode_cdr(cursor: &mut ::hdds::core::ser::cursor::Cursor<'_>) -> ::hdds::core::ser::SerResult<Self> {
+                Ok(Self {
+                    lat: <f64>::decode_cdr(cursor)?,
+                    lon: <f64>::decode_cdr(cursor)?,
+                })
+            }
+
+            fn encode_key(&self, key: &mut Vec<u8>) {
+                self.lat.encode_key(key);
+                self.lon.encode_key(key);
+            }
+        }
+
+        impl ::hdds::dds::DDS for Point {
+            fn type_descriptor() -> &'static ::hdds::core::types::TypeDescriptor {
+                static DESCRIPTOR: ::hdds::core::types::TypeDescriptor =
+                    ::hdds::core::types::TypeDescriptor {
+                        type_id: 0xCBFD92CD,
+                        type_name: "sensors::geo::Point",
+                        size_bytes: 0,
+                        alignment: 1,
+                        is_variable_size: true,
+                        fields: &[],
+                    };
+                &DESCRIPTOR
+            }
+
+            fn encode_cdr2(&self, buf: &mut [u8]) -> ::hdds::dds::Result<usize> {
+                ::hdds::core::ser::idl::encode_sample(self, buf)
+            }
+
+            fn decode_cdr2(buf: &[u8]) -> ::hdds::dds::Result<Self> {
+                ::hdds::core::ser::idl::decode_sample(buf)
+            }
+
+            fn get_type_object() -> Option<::hdds::xtypes::CompleteTypeObject> {
+                Some(Self::type_object())
+            }
+        }
+    }
+}
@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+use hdds_gen::codegen::{ConstType, ConstValue, PrimitiveConst};
+use hdds_gen::idl::parse_idl_items;
+
+/// Output of `hdds-gen consts tests/fixtures/protocol.idl`, compiled here.
+#[allow(dead_code)]
+mod generated {
+    include!("fixtures/protocol_consts.rs");
+}
+
+const PROTOCOL_IDL: &str = include_str!("fixtures/protocol.idl");
+
+#[test]
+fn test_generated_fixture_is_up_to_date() {
+    let items = parse_idl_items(PROTOCOL_IDL).expect("valid IDL");
+    let code = items.to_rust().expect("renderable items");
+    assert_eq!(code, include_str!("fixtures/protocol_consts.rs"));
+}
+
+#[test]
+fn test_generated_values() {
+    use generated::proto::{self, limits, Kind};
+
+    assert_eq!(generated::GLOBAL_MAGIC, 0x4844_4453);
+    assert_eq!(proto::VERSION, (2 << 16) | 5);
+    assert_eq!(proto::DEFAULT_KIND, Kind::KIND_HEARTBEAT);
+    assert_eq!(Kind::KIND_GAP.value(), 10);
+    assert_eq!(Kind::from_value(11), Some(Kind::KIND_ACKNACK));
+    assert_eq!(Kind::from_value(2), None);
+    assert_eq!(Kind::default(), Kind::KIND_DATA);
+
+    assert_eq!(limits::MAX_PEERS, 32);
+    assert_eq!(limits::MIN_OFFSET, -(1_i64 << 40));
+    assert_eq!(limits::FLAGS, 0x0D);
+    assert!((limits::TIMEOUT_S - 0.3).abs() < f64::EPSILON);
+    assert_eq!(limits::PREFIX, "hdds\t");
+    assert_eq!(limits::LAST_KIND, Kind::KIND_ACKNACK);
+}
+
+#[test]
+fn test_parse_const_types() {
+    let items = parse_idl_items(
+        "module m { const unsigned long long BIG = 0xFFFFFFFFFFFFFFFF; const wchar C = 'x'; };",
+    )
+    .expect("valid IDL");
+    assert_eq!(items.consts.len(), 2);
+    assert_eq!(items.consts[0].namespace, vec!["m".to_string()]);
+    assert_eq!(
+        items.consts[0].ty,
+        ConstType::Primitive(PrimitiveConst::U64)
+    );
+    assert_eq!(items.consts[0].value, ConstValue::Int(i128::from(u64::MAX)));
+    assert_eq!(items.consts[1].value, ConstValue::Char('x'));
+}
+
+#[test]
+fn test_invalid_constants_are_rejected() {
+    // Unknown identifier
+    assert!(parse_idl_items("const long A = B + 1;").is_err());
+    // Division by zero
+    assert!(parse_idl_items("const long A = 1 / 0;").is_err());
+    // Duplicate enumerator value
+    assert!(parse_idl_items("enum E { @value(1) A, @value(1) B };").is_err());
+    // Out of range for the declared type (detected when rendering)
+    let items = parse_idl_items("const octet A = 256;").expect("parses");
+    assert!(items.to_rust().is_err());
+}
@@ -0,0 +1,195 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+use hdds::core::ser::idl::{decode_sample, encode_sample};
+use hdds::dds::DDS;
+use hdds::xtypes::{CompleteTypeObject, MemberFlag, StructTypeFlag, TypeIdentifier};
+use hdds_gen::codegen::TypeDecl;
+use hdds_gen::idl::parse_idl_items;
+
+/// Output of `hdds-gen rust tests/fixtures/sensors.idl`, compiled here.
+#[allow(dead_code)]
+mod generated {
+    include!("fixtures/sensors.rs");
+}
+
+use generated::sensors::{geo, Extra, Reading, Unit, Value};
+
+const SENSORS_IDL: &str = include_str!("fixtures/sensors.idl");
+
+fn reading() -> Reading {
+    Reading {
+        sensor_id: 7,
+        zone: "lab".to_string(),
+        position: geo::Point {
+            lat: 48.85,
+            lon: 2.35,
+        },
+        unit: Unit::PASCAL,
+        samples: vec![1.5, -2.0, 3.25],
+        value: Value::raw(vec![0xAB, 0xCD]),
+        extra: Extra::code(-3, 42),
+        matrix: [[1, 2, 3], [4, 5, 6]],
+        tags: vec!["a".to_string(), "bcdefgh".to_string()],
+        valid: true,
+        grade: 'B',
+        flags: 0x81,
+        stamp: -1_700_000_000_000,
+    }
+}
+
+fn round_trip(sample: &Reading) -> Reading {
+    let mut buf = [0u8; 512];
+    let len = sample.encode_cdr2(&mut buf).expect("encode");
+    Reading::decode_cdr2(&buf[..len]).expect("decode")
+}
+
+#[test]
+fn test_generated_fixture_is_up_to_date() {
+    let items = parse_idl_items(SENSORS_IDL).expect("valid IDL");
+    let code = items.to_rust_types().expect("renderable items");
+    assert_eq!(code, include_str!("fixtures/sensors.rs"));
+}
+
+#[test]
+fn test_round_trip() {
+    let sample = reading();
+    assert_eq!(round_trip(&sample), sample);
+
+    let mut sample = reading();
+    sample.value = Value::celsius(21.5);
+    sample.extra = Extra::tag("door".to_string());
+    assert_eq!(round_trip(&sample), sample);
+}
+
+#[test]
+fn test_union_discriminators() {
+    // `Extra` case `tag` has labels 1 and 2
+    let tag = [2, 0, 0, 0, 4, 0, 0, 0, b'd', b'o', b'r', 0];
+    assert_eq!(
+        decode_sample::<Extra>(&tag).expect("decode"),
+        Extra::tag("dor".to_string())
+    );
+
+    // The default case keeps its discriminator
+    let code = [5, 0, 0, 0, 9, 0, 0, 0];
+    let extra = decode_sample::<Extra>(&code).expect("decode");
+    assert_eq!(extra, Extra::code(5, 9));
+    let mut buf = [0u8; 8];
+    assert_eq!(encode_sample(&extra, &mut buf).expect("encode"), 8);
+    assert_eq!(buf, code);
+
+    // `Value` has no default case
+    assert!(decode_sample::<Value>(&[3, 0, 0, 0, 0, 0, 0, 0]).is_err());
+}
+
+#[test]
+fn test_bounds_are_enforced() {
+    let mut buf = [0u8; 512];
+
+    let mut sample = reading();
+    sample.zone = "too long zone".to_string();
+    assert!(sample.encode_cdr2(&mut buf).is_err());
+
+    let mut sample = reading();
+    sample.samples = vec![0.0; 5];
+    assert!(sample.encode_cdr2(&mut buf).is_err());
+
+    let mut sample = reading();
+    sample.tags = vec!["123456789".to_string()];
+    assert!(sample.encode_cdr2(&mut buf).is_err());
+
+    let mut sample = reading();
+    sample.value = Value::raw(vec![1, 2, 3]);
+    assert!(sample.encode_cdr2(&mut buf).is_err());
+}
+
+#[test]
+fn test_key_hash() {
+    assert!(Reading::has_key());
+    assert!(!geo::Point::has_key());
+
+    let a = reading();
+    let mut b = reading();
+    b.samples.clear();
+    b.stamp = 0;
+    assert_eq!(a.compute_key(), b.compute_key());
+
+    b.zone = "hall".to_string();
+    assert_ne!(a.compute_key(), b.compute_key());
+}
+
+#[test]
+fn test_type_object() {
+    let Some(CompleteTypeObject::Struct(reading)) = Reading::get_type_object() else {
+        panic!("struct type object expected");
+    };
+    assert_eq!(reading.struct_flags, StructTypeFlag::IS_APPENDABLE);
+    assert_eq!(reading.header.detail.type_name, "sensors::Reading");
+    assert_eq!(reading.member_seq.len(), 13);
+
+    let keys: Vec<_> = reading
+        .member_seq
+        .iter()
+        .filter(|member| member.common.member_flags.contains(MemberFlag::IS_KEY))
+        .map(|member| member.detail.name.as_str())
+        .collect();
+    assert_eq!(keys, ["sensor_id", "zone"]);
+    assert_eq!(
+        reading.member_seq[1].common.member_type_id,
+        TypeIdentifier::string(8)
+    );
+
+    let TypeIdentifier::Inline(position) = &reading.member_seq[2].common.member_type_id else {
+        panic!("nested struct is inlined");
+    };
+    assert_eq!(**position, geo::Point::type_object());
+
+    let Some(CompleteTypeObject::Union(extra)) = (match &reading.member_seq[6].common.member_type_id
+    {
+        TypeIdentifier::Inline(object) => Some((**object).clone()),
+        _ => None,
+    }) else {
+        panic!("union type object expected");
+    };
+    assert_eq!(extra.header.discriminator, TypeIdentifier::TK_INT16);
+    assert_eq!(extra.member_seq[0].common.label_seq, vec![1, 2]);
+    assert!(extra.member_seq[1]
+        .common
+        .member_flags
+        .contains(MemberFlag::IS_DEFAULT));
+}
+
+#[test]
+fn test_parse_type_declarations() {
+    let items = parse_idl_items(SENSORS_IDL).expect("valid IDL");
+    let names: Vec<_> = items.types.iter().map(TypeDecl::qualified_name).collect();
+    assert_eq!(
+        names,
+        [
+            "sensors::Label",
+            "sensors::Samples",
+            "sensors::geo::Point",
+            "sensors::Value",
+            "sensors::Extra",
+            "sensors::Reading",
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_unions_are_skipped() {
+    for idl in [
+        // Duplicate label
+        "union U switch(long) { case 1: long a; case 1: long b; };",
+        // Two default cases
+        "union U switch(long) { default: long a; default: long b; };",
+        // Unsupported discriminator
+        "union U switch(double) { case 1: long a; };",
+    ] {
+        let items = parse_idl_items(idl).expect("valid IDL");
+        assert!(items.types.is_empty(), "accepted: {idl}");
+        assert_eq!(items.skipped.len(), 1);
+        assert_eq!(items.skipped[0].name, "U");
+    }
+}
@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (c) 2025-2026 naskel.com
+
+//! CDR compatibility test vectors (see `tests/vectors/README.md`).
+//!
+//! Default mode: VERIFY -- the hdds set must match the current encoder.
+//! Regeneration: set env VECTORS_REGEN=1 to overwrite `tests/vectors/hdds`.
+
+use hdds::dynamic::{CdrEncoding, DynamicValue, TypeKind};
+use hdds_gen::idl::{parse_idl_items, IdlItems};
+use hdds_gen::vectors::{check_roundtrip, hex_dump, parse_vector_file_name, test_vectors};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const VECTORS_IDL: &str = include_str!("fixtures/vectors.idl");
+const VECTORS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/vectors");
+
+fn items() -> IdlItems {
+    parse_idl_items(VECTORS_IDL).expect("valid IDL")
+}
+
+fn bin_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .expect("vector directory")
+        .map(|entry| entry.expect("entry").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "bin"))
+        .collect();
+    files.sort();
+    files
+}
+
+#[test]
+fn test_hdds_vectors_are_up_to_date() {
+    let dir = Path::new(VECTORS_DIR).join("hdds");
+    let regen = std::env::var("VECTORS_REGEN").is_ok();
+    let mut expected = Vec::new();
+
+    for descriptor in &items().structs {
+        for vector in test_vectors(descriptor).expect("vectors") {
+            let path = dir.join(vector.file_name());
+            if regen {
+                fs::write(&path, &vector.payload).expect("write vector");
+                fs::write(path.with_extension("hex"), hex_dump(&vector.payload))
+                    .expect("write hex");
+            }
+            let stored = fs::read(&path).unwrap_or_else(|_| panic!("missing {}", path.display()));
+            assert_eq!(
+                stored,
+                vector.payload,
+                "{} changed; regenerate with VECTORS_REGEN=1 if intended",
+                vector.file_name()
+            );
+            expected.push(path);
+        }
+    }
+
+    expected.sort();
+    assert_eq!(
+        bin_files(&dir),
+        expected,
+        "stale vectors in {}",
+        dir.display()
+    );
+}
+
+#[test]
+fn test_all_vector_sets_roundtrip() {
+    let items = items();
+    let mut checked = 0;
+    for entry in fs::read_dir(VECTORS_DIR).expect("vectors directory") {
+        let dir = entry.expect("entry").path();
+        if !dir.is_dir() {
+            continue;
+        }
+        for path in bin_files(&dir) {
+            let name = path.file_name().and_then(|n| n.to_str()).expect("name");
+            let (type_name, encoding) =
+                parse_vector_file_name(name).unwrap_or_else(|| panic!("bad file name {name}"));
+            let descriptor = items
+                .structs
+                .iter()
+                .find(|descriptor| descriptor.name == type_name)
+                .unwrap_or_else(|| panic!("{name}: unknown type {type_name}"));
+            let payload = fs::read(&path).expect("read vector");
+            check_roundtrip(descriptor, encoding, &payload)
+                .unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+            checked += 1;
+        }
+    }
+    assert!(checked >= 16, "only {checked} vectors found");
+}
+
+#[test]
+fn test_encodings_decode_to_same_sample() {
+    let items = items();
+    let nested = items
+        .structs
+        .iter()
+        .find(|descriptor| descriptor.name == "vectors::Nested")
+        .expect("Nested");
+    let dir = Path::new(VECTORS_DIR).join("hdds");
+    let values: Vec<DynamicValue> = CdrEncoding::ALL
+        .into_iter()
+        .map(|encoding| {
+            let payload = fs::read(dir.join(format!("vectors.Nested.{}.bin", encoding.name())))
+                .expect("read vector");
+            check_roundtrip(nested, encoding, &payload)
+                .expect("roundtrip")
+                .value()
+                .clone()
+        })
+        .collect();
+    assert!(values.windows(2).all(|pair| pair[0] == pair[1]));
+}
+
+#[test]
+fn test_parse_structs() {
+    let items = items();
+    let names: Vec<&str> = items.structs.iter().map(|d| d.name.as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "vectors::Primitives",
+            "vectors::Strings",
+            "vectors::Collections",
+            "vectors::Nested"
+        ]
+    );
+    assert_eq!(items.skipped.len(), 1);
+    assert_eq!(items.skipped[0].name, "vectors::Extensible");
+    // Constants and enums are still extracted
+    assert_eq!(items.consts.len(), 1);
+    assert_eq!(items.enums.len(), 1);
+
+    let collections = &items.structs[2];
+    let matrix = collections.field("matrix").expect("matrix");
+    let TypeKind::Array(outer) = &matrix.type_desc.kind else {
+        panic!("matrix is not an array");
+    };
+    let TypeKind::Array(inner) = &outer.element_type.kind else {
+        panic!("matrix is not two-dimensional");
+    };
+    assert_eq!((outer.length, inner.length), (2, 3));
+    let TypeKind::Sequence(blob) = &collections.field("blob").expect("blob").type_desc.kind else {
+        panic!("typedef not resolved");
+    };
+    assert_eq!(blob.max_length, Some(8));
+}
+
+#[test]
+fn test_unsupported_members_skip_struct() {
+    let items = parse_idl_items(
+        "union U switch (long) { case 1: long a; };
+         struct WithUnion { U u; };
+         struct WithOptional { @optional long a; };
+         struct Derived : Base { long b; };
+         struct Plain { long a; };",
+    )
+    .expect("valid IDL");
+    let skipped: Vec<&str> = items.skipped.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(skipped, ["WithUnion", "WithOptional", "Derived"]);
+    assert_eq!(items.structs.len(), 1);
+}
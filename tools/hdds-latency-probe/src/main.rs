@@ -291,7 +291,7 @@ fn run_pong(
 
                 for sample in samples {
                     // Echo back immediately
-                    if let Err(e) = writer.write_raw(&sample.payload) {
+                    if let Err(e) = writer.write_bytes(sample.payload) {
                         if !quiet {
                             eprintln!("{}: {}", "Warning".yellow(), e);
                         }
@@ -56,6 +56,8 @@ impl DdsBridge {
         let transport_mode = match transport {
             "udp" | "multicast" => TransportMode::UdpMulticast,
             "intra" | "intraprocess" => TransportMode::IntraProcess,
+            "tcp" => TransportMode::Tcp,
+            "loopback" => TransportMode::LoopbackBus,
             other => {
                 warn!("Unknown transport '{}', defaulting to UDP multicast", other);
                 TransportMode::UdpMulticast
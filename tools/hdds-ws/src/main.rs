@@ -75,7 +75,7 @@ struct Args {
     #[arg(long, default_value = "hdds-ws-bridge")]
     name: String,
 
-    /// Transport mode: udp (multicast) or intra (in-process)
+    /// Transport mode: udp (multicast), tcp, intra (in-process) or loopback (in-process RTPS, no sockets)
     #[arg(short, long, default_value = "udp")]
     transport: String,
 